@@ -0,0 +1,458 @@
+//! Startup reconciliation of rollup withdrawal events against sequencer state.
+//!
+//! When enabled, the [`Auditor`] runs once before the watcher and submitter start. It collects
+//! every withdrawal event the `AstriaWithdrawer` contract emitted in a range of rollup heights and
+//! every withdrawal the bridge account submitted to the sequencer for that range, and reports
+//! events that were never submitted and events that were submitted more than once.
+//!
+//! The sequencer does not index withdrawals by the rollup event that caused them. Submissions are
+//! therefore found by walking sequencer blocks backwards from the latest height and reading the
+//! memos of the bridge account's withdrawal actions, which record the rollup block number and
+//! transaction hash of the originating event.
+
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+    time::Duration,
+};
+
+use astria_core::primitive::v1::Address;
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    WrapErr as _,
+};
+use ethers::{
+    contract::LogMeta,
+    providers::{
+        Middleware as _,
+        Provider,
+        ProviderError,
+        Ws,
+    },
+};
+use prost::Message as _;
+use sequencer_client::{
+    tendermint_rpc::{
+        self,
+        Client as _,
+    },
+    SignedTransaction,
+};
+use tracing::{
+    info,
+    instrument,
+    warn,
+};
+
+use super::{
+    batch::WithdrawalId,
+    ethereum::{
+        astria_withdrawer_interface::IAstriaWithdrawer,
+        watcher::address_from_string,
+    },
+};
+
+/// The maximum number of rollup blocks requested in a single `eth_getLogs` call.
+const EVENT_QUERY_CHUNK_SIZE: u64 = 1000;
+
+pub(crate) struct Builder {
+    pub(crate) sequencer_cometbft_endpoint: String,
+    pub(crate) ethereum_contract_address: String,
+    pub(crate) ethereum_rpc_endpoint: String,
+    pub(crate) bridge_address: Address,
+    pub(crate) from_rollup_height: u64,
+    pub(crate) to_rollup_height: u64,
+    pub(crate) max_sequencer_blocks: u64,
+}
+
+impl Builder {
+    pub(crate) fn build(self) -> eyre::Result<Auditor> {
+        let Self {
+            sequencer_cometbft_endpoint,
+            ethereum_contract_address,
+            ethereum_rpc_endpoint,
+            bridge_address,
+            from_rollup_height,
+            to_rollup_height,
+            max_sequencer_blocks,
+        } = self;
+
+        ensure!(
+            to_rollup_height == 0 || from_rollup_height <= to_rollup_height,
+            "the first rollup height to audit must not be greater than the last"
+        );
+
+        let contract_address = address_from_string(&ethereum_contract_address)
+            .wrap_err("failed to parse ethereum contract address")?;
+        let sequencer_cometbft_client =
+            sequencer_client::HttpClient::new(&*sequencer_cometbft_endpoint)
+                .wrap_err("failed constructing cometbft http client")?;
+
+        Ok(Auditor {
+            sequencer_cometbft_client,
+            contract_address,
+            ethereum_rpc_endpoint,
+            bridge_address,
+            from_rollup_height,
+            to_rollup_height,
+            max_sequencer_blocks,
+        })
+    }
+}
+
+/// Reconciles the withdrawal events in a range of rollup heights against the withdrawals the
+/// bridge account submitted to the sequencer.
+pub(crate) struct Auditor {
+    sequencer_cometbft_client: sequencer_client::HttpClient,
+    contract_address: ethers::types::Address,
+    ethereum_rpc_endpoint: String,
+    bridge_address: Address,
+    from_rollup_height: u64,
+    to_rollup_height: u64,
+    max_sequencer_blocks: u64,
+}
+
+impl Auditor {
+    #[instrument(skip_all, err)]
+    pub(crate) async fn run(self) -> eyre::Result<Report> {
+        let provider = connect_to_rollup(&self.ethereum_rpc_endpoint)
+            .await
+            .wrap_err("failed to connect to rollup")?;
+        let to_rollup_height = if self.to_rollup_height == 0 {
+            provider
+                .get_block_number()
+                .await
+                .wrap_err("failed to get latest rollup block number")?
+                .as_u64()
+        } else {
+            self.to_rollup_height
+        };
+        info!(
+            from_rollup_height = self.from_rollup_height,
+            to_rollup_height, "starting withdrawal audit"
+        );
+
+        let contract = IAstriaWithdrawer::new(self.contract_address, provider);
+        let events = get_withdrawal_events(&contract, self.from_rollup_height, to_rollup_height)
+            .await
+            .wrap_err("failed to get withdrawal events from rollup")?;
+
+        let submissions = self
+            .get_submitted_withdrawals()
+            .await
+            .wrap_err("failed to get submitted withdrawals from sequencer")?
+            .into_iter()
+            .filter(|withdrawal| {
+                (self.from_rollup_height..=to_rollup_height).contains(&withdrawal.rollup_height)
+            });
+
+        Ok(reconcile(events, submissions))
+    }
+
+    /// Walks sequencer blocks backwards from the latest height and collects the withdrawals
+    /// submitted by the bridge account.
+    ///
+    /// The walk stops once a withdrawal for a rollup height below the audited range was found,
+    /// because all earlier submissions are for earlier rollup heights, or once
+    /// `max_sequencer_blocks` were visited.
+    async fn get_submitted_withdrawals(&self) -> eyre::Result<Vec<WithdrawalId>> {
+        let latest_height = retry_cometbft("latest block", || {
+            self.sequencer_cometbft_client.latest_block()
+        })
+        .await?
+        .block
+        .header
+        .height
+        .value();
+
+        let mut withdrawals = Vec::new();
+        let mut height = latest_height;
+        let mut visited = 0;
+        let mut reached_range_start = false;
+        while height > 0 && visited < self.max_sequencer_blocks && !reached_range_start {
+            let block = retry_cometbft("block", || {
+                self.sequencer_cometbft_client.block(
+                    tendermint::block::Height::try_from(height)
+                        .expect("height was obtained from cometbft and must fit"),
+                )
+            })
+            .await?
+            .block;
+            for withdrawal in block
+                .data
+                .iter()
+                .filter_map(|bytes| decode_transaction(bytes))
+                .filter(|tx| tx.address_bytes() == self.bridge_address.bytes())
+                .flat_map(|tx| withdrawals_in_transaction(&tx))
+            {
+                reached_range_start |= withdrawal.rollup_height < self.from_rollup_height;
+                withdrawals.push(withdrawal);
+            }
+            height -= 1;
+            visited += 1;
+        }
+
+        if !reached_range_start && height > 0 {
+            warn!(
+                latest_sequencer_height = latest_height,
+                max_sequencer_blocks = self.max_sequencer_blocks,
+                "did not find a submission before the audited rollup height range within the \
+                 searched sequencer blocks; earlier submissions might be reported as missing"
+            );
+        }
+        Ok(withdrawals)
+    }
+}
+
+impl From<&LogMeta> for WithdrawalId {
+    fn from(meta: &LogMeta) -> Self {
+        Self {
+            rollup_height: meta.block_number.as_u64(),
+            rollup_transaction_hash: meta.transaction_hash.0,
+        }
+    }
+}
+
+/// The outcome of a withdrawal audit.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct Report {
+    /// The number of withdrawal events emitted on the rollup.
+    pub(crate) events: usize,
+    /// The number of withdrawals submitted to the sequencer.
+    pub(crate) submissions: usize,
+    /// Withdrawals with fewer submissions than events, and by how many.
+    pub(crate) missing: Vec<(WithdrawalId, usize)>,
+    /// Withdrawals with more submissions than events, and by how many.
+    pub(crate) duplicates: Vec<(WithdrawalId, usize)>,
+}
+
+impl Report {
+    pub(crate) fn is_reconciled(&self) -> bool {
+        self.missing.is_empty() && self.duplicates.is_empty()
+    }
+
+    pub(crate) fn log(&self) {
+        for (id, count) in &self.missing {
+            warn!(
+                rollup_height = id.rollup_height,
+                rollup_transaction_hash = %telemetry::display::hex(&id.rollup_transaction_hash),
+                count,
+                "withdrawal event was not submitted to the sequencer"
+            );
+        }
+        for (id, count) in &self.duplicates {
+            warn!(
+                rollup_height = id.rollup_height,
+                rollup_transaction_hash = %telemetry::display::hex(&id.rollup_transaction_hash),
+                count,
+                "withdrawal event was submitted to the sequencer more than once"
+            );
+        }
+        info!(
+            events = self.events,
+            submissions = self.submissions,
+            missing = self.missing.len(),
+            duplicates = self.duplicates.len(),
+            reconciled = self.is_reconciled(),
+            "withdrawal audit finished"
+        );
+    }
+}
+
+/// Matches withdrawal events against submissions.
+///
+/// A single rollup transaction can emit several withdrawal events, so events and submissions are
+/// counted per [`WithdrawalId`] rather than matched one to one.
+pub(crate) fn reconcile<E, S>(events: E, submissions: S) -> Report
+where
+    E: IntoIterator<Item = WithdrawalId>,
+    S: IntoIterator<Item = WithdrawalId>,
+{
+    let mut counts: BTreeMap<WithdrawalId, (usize, usize)> = BTreeMap::new();
+    let mut report = Report::default();
+    for id in events {
+        counts.entry(id).or_default().0 += 1;
+        report.events += 1;
+    }
+    for id in submissions {
+        counts.entry(id).or_default().1 += 1;
+        report.submissions += 1;
+    }
+    for (id, (events, submissions)) in counts {
+        if events > submissions {
+            report.missing.push((id, events - submissions));
+        } else if submissions > events {
+            report.duplicates.push((id, submissions - events));
+        }
+    }
+    report
+}
+
+async fn connect_to_rollup(ethereum_rpc_endpoint: &str) -> eyre::Result<Arc<Provider<Ws>>> {
+    let retry_config = tryhard::RetryFutureConfig::new(1024)
+        .exponential_backoff(Duration::from_millis(500))
+        .max_delay(Duration::from_secs(60))
+        .on_retry(
+            |attempt, next_delay: Option<Duration>, error: &ProviderError| {
+                let wait_duration = next_delay
+                    .map(humantime::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    attempt,
+                    wait_duration,
+                    error = error as &dyn std::error::Error,
+                    "attempt to connect to rollup node failed; retrying after backoff",
+                );
+                futures::future::ready(())
+            },
+        );
+
+    let provider = tryhard::retry_fn(|| {
+        let url = ethereum_rpc_endpoint.to_string();
+        async move {
+            let websocket_client = Ws::connect_with_reconnects(url, 0).await?;
+            Ok(Provider::new(websocket_client))
+        }
+    })
+    .with_config(retry_config)
+    .await
+    .wrap_err("failed connecting to rollup after several retries; giving up")?;
+    Ok(Arc::new(provider))
+}
+
+async fn get_withdrawal_events(
+    contract: &IAstriaWithdrawer<Provider<Ws>>,
+    from_rollup_height: u64,
+    to_rollup_height: u64,
+) -> eyre::Result<Vec<WithdrawalId>> {
+    let mut ids = Vec::new();
+    let mut chunk_start = from_rollup_height;
+    while chunk_start <= to_rollup_height {
+        let chunk_end = chunk_start
+            .saturating_add(EVENT_QUERY_CHUNK_SIZE - 1)
+            .min(to_rollup_height);
+        let sequencer_withdrawals = contract
+            .sequencer_withdrawal_filter()
+            .from_block(chunk_start)
+            .to_block(chunk_end)
+            .address(contract.address().into())
+            .query_with_meta()
+            .await
+            .wrap_err("failed to query sequencer withdrawal events")?;
+        let ics20_withdrawals = contract
+            .ics_20_withdrawal_filter()
+            .from_block(chunk_start)
+            .to_block(chunk_end)
+            .address(contract.address().into())
+            .query_with_meta()
+            .await
+            .wrap_err("failed to query ics20 withdrawal events")?;
+        ids.extend(
+            sequencer_withdrawals
+                .iter()
+                .map(|(_, meta)| WithdrawalId::from(meta)),
+        );
+        ids.extend(
+            ics20_withdrawals
+                .iter()
+                .map(|(_, meta)| WithdrawalId::from(meta)),
+        );
+        let Some(next_chunk_start) = chunk_end.checked_add(1) else {
+            break;
+        };
+        chunk_start = next_chunk_start;
+    }
+    Ok(ids)
+}
+
+fn decode_transaction(bytes: &[u8]) -> Option<SignedTransaction> {
+    // Not every entry of a sequencer block's data is a transaction, so failing to decode is
+    // expected and not an error.
+    let raw =
+        astria_core::generated::protocol::transaction::v1alpha1::SignedTransaction::decode(bytes)
+            .ok()?;
+    SignedTransaction::try_from_raw(raw).ok()
+}
+
+fn withdrawals_in_transaction(tx: &SignedTransaction) -> Vec<WithdrawalId> {
+    let tx_hash = tx.sha256_of_proto_encoding();
+    tx.actions()
+        .iter()
+        .filter_map(|action| {
+            WithdrawalId::from_action(action)
+                .map_err(|error| {
+                    warn!(
+                        tx_hash = %telemetry::display::hex(&tx_hash),
+                        %error,
+                        "failed to read withdrawal id from action; skipping action"
+                    );
+                })
+                .ok()
+                .flatten()
+        })
+        .collect()
+}
+
+async fn retry_cometbft<T, F, Fut>(what: &'static str, f: F) -> eyre::Result<T>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = Result<T, tendermint_rpc::Error>>,
+{
+    let retry_config = tryhard::RetryFutureConfig::new(u32::MAX)
+        .exponential_backoff(Duration::from_millis(100))
+        .max_delay(Duration::from_secs(20))
+        .on_retry(
+            |attempt: u32, next_delay: Option<Duration>, error: &tendermint_rpc::Error| {
+                let wait_duration = next_delay
+                    .map(humantime::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    attempt,
+                    wait_duration,
+                    what,
+                    error = error as &dyn std::error::Error,
+                    "attempt to fetch from Sequencer failed; retrying after backoff",
+                );
+                futures::future::ready(())
+            },
+        );
+
+    tryhard::retry_fn(f)
+        .with_config(retry_config)
+        .await
+        .wrap_err_with(|| format!("failed to fetch {what} from Sequencer after a lot of attempts"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn id(rollup_height: u64, hash: u8) -> WithdrawalId {
+        WithdrawalId {
+            rollup_height,
+            rollup_transaction_hash: [hash; 32],
+        }
+    }
+
+    #[test]
+    fn matching_events_and_submissions_are_reconciled() {
+        let report = reconcile(
+            [id(1, 1), id(1, 1), id(2, 2)],
+            [id(2, 2), id(1, 1), id(1, 1)],
+        );
+        assert!(report.is_reconciled());
+        assert_eq!(3, report.events);
+        assert_eq!(3, report.submissions);
+    }
+
+    #[test]
+    fn missing_and_duplicate_submissions_are_reported() {
+        let report = reconcile(
+            [id(1, 1), id(1, 1), id(2, 2), id(3, 3)],
+            [id(1, 1), id(2, 2), id(2, 2), id(2, 2)],
+        );
+        assert_eq!(vec![(id(1, 1), 1), (id(3, 3), 1)], report.missing);
+        assert_eq!(vec![(id(2, 2), 2)], report.duplicates);
+    }
+}
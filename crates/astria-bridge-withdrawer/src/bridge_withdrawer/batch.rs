@@ -1,4 +1,19 @@
-use astria_core::protocol::transaction::v1alpha1::Action;
+use astria_core::{
+    bridge::{
+        memo::{
+            self,
+            ParseMode,
+        },
+        Ics20WithdrawalFromRollupMemo,
+    },
+    protocol::transaction::v1alpha1::Action,
+};
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+
+use super::ethereum::convert::BridgeUnlockMemo;
 
 pub(crate) struct Batch {
     /// The withdrawal payloads
@@ -6,3 +21,42 @@ pub(crate) struct Batch {
     /// The corresponding rollup block height
     pub(crate) rollup_height: u64,
 }
+
+/// Identifies a withdrawal by the rollup block and transaction that emitted its event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct WithdrawalId {
+    pub(crate) rollup_height: u64,
+    pub(crate) rollup_transaction_hash: [u8; 32],
+}
+
+impl WithdrawalId {
+    /// Reads the id of the withdrawal from the memo of a withdrawal action.
+    ///
+    /// Returns `None` if `action` is not a withdrawal action.
+    ///
+    /// # Errors
+    /// Returns an error if the memo of the withdrawal action cannot be parsed.
+    pub(crate) fn from_action(action: &Action) -> eyre::Result<Option<Self>> {
+        let id = match action {
+            Action::BridgeUnlock(action) => {
+                let memo: BridgeUnlockMemo = serde_json::from_slice(&action.memo)
+                    .wrap_err("failed to parse bridge unlock memo")?;
+                Self {
+                    rollup_height: memo.block_number.as_u64(),
+                    rollup_transaction_hash: memo.transaction_hash.0,
+                }
+            }
+            Action::Ics20Withdrawal(action) => {
+                let memo =
+                    memo::parse::<Ics20WithdrawalFromRollupMemo>(&action.memo, ParseMode::Lenient)
+                        .wrap_err("failed to parse ics20 withdrawal memo")?;
+                Self {
+                    rollup_height: memo.memo().block_number,
+                    rollup_transaction_hash: memo.memo().transaction_hash,
+                }
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(id))
+    }
+}
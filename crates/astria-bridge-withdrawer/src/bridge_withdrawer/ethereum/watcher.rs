@@ -31,6 +31,7 @@ use ethers::{
     },
     utils::hex,
 };
+use futures::future::BoxFuture;
 use tokio::{
     select,
     sync::mpsc,
@@ -52,6 +53,7 @@ use crate::bridge_withdrawer::{
             WithdrawalEvent,
         },
     },
+    event_source::WithdrawalEventSource,
     state::State,
     submitter,
     SequencerStartupInfo,
@@ -269,6 +271,12 @@ impl Watcher {
     }
 }
 
+impl WithdrawalEventSource for Watcher {
+    fn run(self: Box<Self>) -> BoxFuture<'static, Result<()>> {
+        Box::pin(Watcher::run(*self))
+    }
+}
+
 async fn watch_for_sequencer_withdrawal_events(
     contract: IAstriaWithdrawer<Provider<Ws>>,
     event_tx: mpsc::Sender<(WithdrawalEvent, LogMeta)>,
@@ -431,7 +439,7 @@ impl Batcher {
 
 // converts an ethereum address string to an `ethers::types::Address`.
 // the input string may be prefixed with "0x" or not.
-fn address_from_string(s: &str) -> Result<ethers::types::Address> {
+pub(crate) fn address_from_string(s: &str) -> Result<ethers::types::Address> {
     let s = s.strip_prefix("0x").unwrap_or(s);
     let bytes = hex::decode(s).wrap_err("failed to parse ethereum address as hex")?;
     let address: [u8; 20] = bytes.try_into().map_err(|bytes: Vec<u8>| {
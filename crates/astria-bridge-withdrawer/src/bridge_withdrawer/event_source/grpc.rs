@@ -0,0 +1,287 @@
+//! A [`WithdrawalEventSource`] to which rollups push their withdrawals over gRPC.
+//!
+//! This allows rollups that do not emit the `AstriaWithdrawer` contract's events, such as non-EVM
+//! rollups, to use the withdrawer. The rollup converts its withdrawals to sequencer actions itself
+//! and submits them through `astria.bridge.v1alpha1.WithdrawalEventService`.
+
+use std::{
+    net::SocketAddr,
+    sync::Arc,
+};
+
+use astria_core::{
+    generated::{
+        bridge::v1alpha1::{
+            withdrawal_event_service_server::{
+                WithdrawalEventService,
+                WithdrawalEventServiceServer,
+            },
+            GetNextRollupHeightRequest,
+            GetNextRollupHeightResponse,
+            SubmitWithdrawalBatchRequest,
+            SubmitWithdrawalBatchResponse,
+        },
+        protocol::transaction::v1alpha1 as raw,
+    },
+    primitive::v1::asset,
+    protocol::transaction::v1alpha1::Action,
+};
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use futures::future::BoxFuture;
+use prost::Message as _;
+use tokio::{
+    net::TcpListener,
+    sync::Mutex,
+};
+use tokio_util::sync::CancellationToken;
+use tonic::{
+    Request,
+    Response,
+    Status,
+};
+use tracing::{
+    debug,
+    info,
+    instrument,
+};
+
+use super::WithdrawalEventSource;
+use crate::bridge_withdrawer::{
+    batch::Batch,
+    state::State,
+    submitter,
+    SequencerStartupInfo,
+};
+
+pub(crate) struct Builder {
+    pub(crate) grpc_addr: SocketAddr,
+    pub(crate) submitter_handle: submitter::Handle,
+    pub(crate) shutdown_token: CancellationToken,
+    pub(crate) state: Arc<State>,
+}
+
+impl Builder {
+    pub(crate) fn build(self) -> GrpcSource {
+        let Self {
+            grpc_addr,
+            submitter_handle,
+            shutdown_token,
+            state,
+        } = self;
+        GrpcSource {
+            grpc_addr,
+            submitter_handle,
+            shutdown_token,
+            state,
+        }
+    }
+}
+
+/// Serves `astria.bridge.v1alpha1.WithdrawalEventService` and forwards the pushed withdrawals to
+/// the submitter.
+pub(crate) struct GrpcSource {
+    grpc_addr: SocketAddr,
+    submitter_handle: submitter::Handle,
+    shutdown_token: CancellationToken,
+    state: Arc<State>,
+}
+
+impl GrpcSource {
+    async fn run(mut self) -> eyre::Result<()> {
+        let SequencerStartupInfo {
+            fee_asset_id,
+            next_batch_rollup_height,
+        } = self
+            .submitter_handle
+            .recv_startup_info()
+            .await
+            .wrap_err("failed to get sequencer startup info")?;
+
+        let listener = TcpListener::bind(self.grpc_addr)
+            .await
+            .wrap_err("failed to bind socket address")?;
+        info!(
+            grpc_addr = %self.grpc_addr,
+            next_rollup_height = next_batch_rollup_height,
+            "serving withdrawal event service",
+        );
+
+        let service = WithdrawalEventServiceServer::new(EventService {
+            fee_asset_id,
+            inner: Mutex::new(Inner {
+                next_rollup_height: next_batch_rollup_height,
+                submitter_handle: self.submitter_handle,
+            }),
+        });
+        self.state.set_watcher_ready();
+
+        tonic::transport::Server::builder()
+            .add_service(service)
+            .serve_with_incoming_shutdown(
+                tokio_stream::wrappers::TcpListenerStream::new(listener),
+                self.shutdown_token.cancelled(),
+            )
+            .await
+            .wrap_err("failed to run grpc server")
+    }
+}
+
+impl WithdrawalEventSource for GrpcSource {
+    fn run(self: Box<Self>) -> BoxFuture<'static, eyre::Result<()>> {
+        Box::pin(GrpcSource::run(*self))
+    }
+}
+
+struct EventService {
+    fee_asset_id: asset::Id,
+    // Held while a batch is forwarded, so that batches reach the submitter in order of height.
+    inner: Mutex<Inner>,
+}
+
+struct Inner {
+    next_rollup_height: u64,
+    submitter_handle: submitter::Handle,
+}
+
+#[tonic::async_trait]
+impl WithdrawalEventService for EventService {
+    async fn get_next_rollup_height(
+        self: Arc<Self>,
+        _request: Request<GetNextRollupHeightRequest>,
+    ) -> Result<Response<GetNextRollupHeightResponse>, Status> {
+        let rollup_height = self.inner.lock().await.next_rollup_height;
+        Ok(Response::new(GetNextRollupHeightResponse {
+            rollup_height,
+        }))
+    }
+
+    #[instrument(skip_all, fields(rollup_height = request.get_ref().rollup_height))]
+    async fn submit_withdrawal_batch(
+        self: Arc<Self>,
+        request: Request<SubmitWithdrawalBatchRequest>,
+    ) -> Result<Response<SubmitWithdrawalBatchResponse>, Status> {
+        let SubmitWithdrawalBatchRequest {
+            rollup_height,
+            actions,
+        } = request.into_inner();
+        let actions = actions
+            .iter()
+            .map(|bytes| decode_withdrawal_action(bytes, self.fee_asset_id))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut inner = self.inner.lock().await;
+        if rollup_height < inner.next_rollup_height {
+            debug!(
+                next_rollup_height = inner.next_rollup_height,
+                "batch was already accepted; skipping"
+            );
+            return Ok(Response::new(SubmitWithdrawalBatchResponse {}));
+        }
+        if !actions.is_empty() {
+            inner
+                .submitter_handle
+                .send_batch(Batch {
+                    actions,
+                    rollup_height,
+                })
+                .await
+                .map_err(|_| Status::unavailable("the withdrawer is shutting down"))?;
+        }
+        inner.next_rollup_height = rollup_height.saturating_add(1);
+        Ok(Response::new(SubmitWithdrawalBatchResponse {}))
+    }
+}
+
+/// Decodes a pushed withdrawal, which must be a bridge unlock or an ics20 withdrawal paying fees
+/// in `fee_asset_id`.
+fn decode_withdrawal_action(bytes: &[u8], fee_asset_id: asset::Id) -> Result<Action, Status> {
+    let raw = raw::Action::decode(bytes)
+        .map_err(|e| Status::invalid_argument(format!("failed to decode action: {e}")))?;
+    let action = Action::try_from_raw(raw)
+        .map_err(|e| Status::invalid_argument(format!("failed to validate action: {e}")))?;
+    let action_fee_asset_id = match &action {
+        Action::BridgeUnlock(action) => action.fee_asset_id,
+        Action::Ics20Withdrawal(action) => *action.fee_asset_id(),
+        _ => {
+            return Err(Status::invalid_argument(
+                "only bridge unlock and ics20 withdrawal actions are accepted",
+            ));
+        }
+    };
+    if action_fee_asset_id != fee_asset_id {
+        return Err(Status::invalid_argument(format!(
+            "actions must pay fees in `{fee_asset_id}`"
+        )));
+    }
+    Ok(action)
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::asset::default_native_asset,
+        protocol::transaction::v1alpha1::action::{
+            BridgeUnlockAction,
+            TransferAction,
+        },
+    };
+    use tonic::Code;
+
+    use super::*;
+    use crate::bridge_withdrawer::astria_address;
+
+    fn encode(action: Action) -> Vec<u8> {
+        action.into_raw().encode_to_vec()
+    }
+
+    fn bridge_unlock(fee_asset_id: asset::Id) -> Action {
+        Action::BridgeUnlock(BridgeUnlockAction {
+            to: astria_address([1; 20]),
+            amount: 99,
+            fee_asset_id,
+            memo: b"memo".to_vec(),
+            bridge_address: None,
+        })
+    }
+
+    #[test]
+    fn withdrawal_action_is_accepted() {
+        let fee_asset_id = default_native_asset().id();
+        let action =
+            decode_withdrawal_action(&encode(bridge_unlock(fee_asset_id)), fee_asset_id).unwrap();
+        assert!(matches!(action, Action::BridgeUnlock(_)));
+    }
+
+    #[test]
+    fn other_actions_are_rejected() {
+        let fee_asset_id = default_native_asset().id();
+        let transfer = Action::Transfer(TransferAction {
+            to: astria_address([1; 20]),
+            amount: 99,
+            asset_id: fee_asset_id,
+            fee_asset_id,
+        });
+        let status = decode_withdrawal_action(&encode(transfer), fee_asset_id).unwrap_err();
+        assert_eq!(Code::InvalidArgument, status.code());
+    }
+
+    #[test]
+    fn other_fee_asset_is_rejected() {
+        let fee_asset_id = default_native_asset().id();
+        let other_fee_asset_id = asset::Id::from_str_unchecked("other");
+        let status =
+            decode_withdrawal_action(&encode(bridge_unlock(other_fee_asset_id)), fee_asset_id)
+                .unwrap_err();
+        assert_eq!(Code::InvalidArgument, status.code());
+    }
+
+    #[test]
+    fn garbage_is_rejected() {
+        let status =
+            decode_withdrawal_action(b"not an action", default_native_asset().id()).unwrap_err();
+        assert_eq!(Code::InvalidArgument, status.code());
+    }
+}
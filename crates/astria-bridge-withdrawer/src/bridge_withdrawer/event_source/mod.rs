@@ -0,0 +1,25 @@
+//! Sources of the withdrawals that are submitted to the sequencer.
+//!
+//! The submitter does not care how withdrawals are found on the rollup. Any type implementing
+//! [`WithdrawalEventSource`] can feed them into the submission pipeline:
+//!
+//! - the [`Watcher`](super::ethereum::watcher::Watcher) scrapes the logs of the `AstriaWithdrawer`
+//!   contract on an EVM rollup;
+//! - the [`GrpcSource`](grpc::GrpcSource) serves `astria.bridge.v1alpha1.WithdrawalEventService`,
+//!   to which rollups push their withdrawals.
+
+use astria_eyre::eyre;
+use futures::future::BoxFuture;
+
+pub(crate) mod grpc;
+
+/// A source of the withdrawals made on a rollup.
+///
+/// A source waits for the submitter's startup info and, from its `next_batch_rollup_height`
+/// onwards, forwards the withdrawals made on the rollup to the submitter as one batch per rollup
+/// height in order of increasing height. It marks the watcher as ready in the withdrawer's state
+/// once it has started.
+pub(crate) trait WithdrawalEventSource: Send + 'static {
+    /// Runs the source until it fails or the withdrawer is shut down.
+    fn run(self: Box<Self>) -> BoxFuture<'static, eyre::Result<()>>;
+}
@@ -13,6 +13,7 @@ use astria_core::primitive::v1::asset::{
 };
 use astria_eyre::eyre::{
     self,
+    ensure,
     WrapErr as _,
 };
 use tokio::{
@@ -28,22 +29,30 @@ use tokio_util::sync::CancellationToken;
 use tracing::{
     error,
     info,
+    warn,
 };
 
 pub(crate) use self::state::StateSnapshot;
 use self::{
+    audit::Auditor,
     ethereum::watcher,
+    event_source::WithdrawalEventSource,
     state::State,
     submitter::Submitter,
 };
 use crate::{
     api,
-    config::Config,
+    config::{
+        Config,
+        RollupEventSource,
+    },
     metrics::Metrics,
 };
 
+mod audit;
 mod batch;
 mod ethereum;
+mod event_source;
 mod state;
 mod submitter;
 
@@ -52,7 +61,8 @@ pub struct BridgeWithdrawer {
     shutdown_token: CancellationToken,
     api_server: api::ApiServer,
     submitter: Submitter,
-    ethereum_watcher: watcher::Watcher,
+    event_source: Box<dyn WithdrawalEventSource>,
+    auditor: Option<Auditor>,
     state: Arc<State>,
 }
 
@@ -62,6 +72,9 @@ impl BridgeWithdrawer {
     /// # Errors
     ///
     /// - If the provided `api_addr` string cannot be parsed as a socket address.
+    /// - If the provided `grpc_event_source_addr` string cannot be parsed as a socket address.
+    /// - If the startup audit is enabled with an invalid rollup height range, or with a rollup
+    ///   event source other than `Ethereum`.
     pub fn new(cfg: Config) -> eyre::Result<(Self, ShutdownHandle)> {
         static METRICS: OnceLock<Metrics> = OnceLock::new();
         let metrics = METRICS.get_or_init(Metrics::new);
@@ -78,11 +91,46 @@ impl BridgeWithdrawer {
             ethereum_rpc_endpoint,
             rollup_asset_denomination,
             min_expected_fee_asset_balance,
+            rollup_event_source,
+            grpc_event_source_addr,
+            fee_asset_reserve_balance,
+            fee_asset_balance_check_interval_ms,
+            processed_events_path,
+            audit_from_rollup_height,
+            audit_to_rollup_height,
+            audit_max_sequencer_blocks,
             ..
         } = cfg;
 
         let state = Arc::new(State::new());
 
+        let sequencer_bridge_address = cfg
+            .sequencer_bridge_address
+            .parse()
+            .wrap_err("failed to parse sequencer bridge address")?;
+
+        // make the startup auditor if enabled
+        let auditor = if audit_from_rollup_height > 0 {
+            ensure!(
+                rollup_event_source == RollupEventSource::Ethereum,
+                "the startup audit requires the `Ethereum` rollup event source"
+            );
+            let auditor = audit::Builder {
+                sequencer_cometbft_endpoint: sequencer_cometbft_endpoint.clone(),
+                ethereum_contract_address: ethereum_contract_address.clone(),
+                ethereum_rpc_endpoint: ethereum_rpc_endpoint.clone(),
+                bridge_address: sequencer_bridge_address,
+                from_rollup_height: audit_from_rollup_height,
+                to_rollup_height: audit_to_rollup_height,
+                max_sequencer_blocks: audit_max_sequencer_blocks,
+            }
+            .build()
+            .wrap_err("failed to build startup auditor")?;
+            Some(auditor)
+        } else {
+            None
+        };
+
         // make submitter object
         let (submitter, submitter_handle) = submitter::Builder {
             shutdown_token: shutdown_handle.token(),
@@ -93,30 +141,49 @@ impl BridgeWithdrawer {
             state: state.clone(),
             expected_fee_asset_id: asset::Id::from_str_unchecked(&fee_asset_denomination),
             min_expected_fee_asset_balance: u128::from(min_expected_fee_asset_balance),
+            fee_asset_reserve_balance: u128::from(fee_asset_reserve_balance),
+            fee_asset_balance_check_interval: Duration::from_millis(
+                fee_asset_balance_check_interval_ms,
+            ),
+            processed_events_path: (!processed_events_path.is_empty())
+                .then(|| processed_events_path.into()),
             metrics,
         }
         .build()
         .wrap_err("failed to initialize submitter")?;
 
-        let sequencer_bridge_address = cfg
-            .sequencer_bridge_address
-            .parse()
-            .wrap_err("failed to parse sequencer bridge address")?;
-
-        let ethereum_watcher = watcher::Builder {
-            ethereum_contract_address,
-            ethereum_rpc_endpoint,
-            submitter_handle,
-            shutdown_token: shutdown_handle.token(),
-            state: state.clone(),
-            rollup_asset_denom: rollup_asset_denomination
-                .parse::<Denom>()
-                .wrap_err("failed to parse ROLLUP_ASSET_DENOMINATION as Denom")?,
-            bridge_address: sequencer_bridge_address,
-            sequencer_address_prefix: sequencer_address_prefix.clone(),
-        }
-        .build()
-        .wrap_err("failed to build ethereum watcher")?;
+        let event_source: Box<dyn WithdrawalEventSource> = match rollup_event_source {
+            RollupEventSource::Ethereum => Box::new(
+                watcher::Builder {
+                    ethereum_contract_address,
+                    ethereum_rpc_endpoint,
+                    submitter_handle,
+                    shutdown_token: shutdown_handle.token(),
+                    state: state.clone(),
+                    rollup_asset_denom: rollup_asset_denomination
+                        .parse::<Denom>()
+                        .wrap_err("failed to parse ROLLUP_ASSET_DENOMINATION as Denom")?,
+                    bridge_address: sequencer_bridge_address,
+                    sequencer_address_prefix: sequencer_address_prefix.clone(),
+                }
+                .build()
+                .wrap_err("failed to build ethereum watcher")?,
+            ),
+            RollupEventSource::Grpc => Box::new(
+                event_source::grpc::Builder {
+                    grpc_addr: grpc_event_source_addr.parse().wrap_err_with(|| {
+                        format!(
+                            "failed to parse provided `grpc_event_source_addr` string as socket \
+                             address: `{grpc_event_source_addr}`"
+                        )
+                    })?,
+                    submitter_handle,
+                    shutdown_token: shutdown_handle.token(),
+                    state: state.clone(),
+                }
+                .build(),
+            ),
+        };
 
         // make api server
         let state_rx = state.subscribe();
@@ -129,7 +196,8 @@ impl BridgeWithdrawer {
             shutdown_token: shutdown_handle.token(),
             api_server,
             submitter,
-            ethereum_watcher,
+            event_source,
+            auditor,
             state,
         };
 
@@ -141,7 +209,8 @@ impl BridgeWithdrawer {
             shutdown_token,
             api_server,
             submitter,
-            ethereum_watcher,
+            event_source,
+            auditor,
             state: _state,
         } = self;
 
@@ -158,10 +227,24 @@ impl BridgeWithdrawer {
         });
         info!("spawned API server");
 
+        // Reconcile past withdrawals before any new ones are submitted. A failed audit is
+        // reported but does not prevent the withdrawer from resuming normal operation.
+        if let Some(auditor) = auditor {
+            select!(
+                () = shutdown_token.cancelled() => {
+                    info!("received shutdown signal during startup audit");
+                }
+                res = auditor.run() => match res {
+                    Ok(report) => report.log(),
+                    Err(error) => warn!(%error, "startup audit failed; resuming normal operation"),
+                }
+            );
+        }
+
         let mut submitter_task = tokio::spawn(submitter.run());
         info!("spawned submitter task");
-        let mut ethereum_watcher_task = tokio::spawn(ethereum_watcher.run());
-        info!("spawned ethereum watcher task");
+        let mut event_source_task = tokio::spawn(event_source.run());
+        info!("spawned event source task");
 
         let shutdown = select!(
             o = &mut api_task => {
@@ -169,7 +252,7 @@ impl BridgeWithdrawer {
                 Shutdown {
                     api_task: None,
                     submitter_task: Some(submitter_task),
-                    ethereum_watcher_task: Some(ethereum_watcher_task),
+                    event_source_task: Some(event_source_task),
                     api_shutdown_signal,
                    token: shutdown_token
                 }
@@ -179,17 +262,17 @@ impl BridgeWithdrawer {
                 Shutdown {
                     api_task: Some(api_task),
                     submitter_task: None,
-                    ethereum_watcher_task:Some(ethereum_watcher_task),
+                    event_source_task: Some(event_source_task),
                     api_shutdown_signal,
                     token: shutdown_token
                 }
             }
-            o = &mut ethereum_watcher_task => {
-                report_exit("ethereum watcher", o);
+            o = &mut event_source_task => {
+                report_exit("event source", o);
                 Shutdown {
                     api_task: Some(api_task),
                     submitter_task: Some(submitter_task),
-                    ethereum_watcher_task: None,
+                    event_source_task: None,
                     api_shutdown_signal,
                     token: shutdown_token
                 }
@@ -263,21 +346,21 @@ fn report_exit(task_name: &str, outcome: Result<eyre::Result<()>, JoinError>) {
 struct Shutdown {
     api_task: Option<JoinHandle<eyre::Result<()>>>,
     submitter_task: Option<JoinHandle<eyre::Result<()>>>,
-    ethereum_watcher_task: Option<JoinHandle<eyre::Result<()>>>,
+    event_source_task: Option<JoinHandle<eyre::Result<()>>>,
     api_shutdown_signal: oneshot::Sender<()>,
     token: CancellationToken,
 }
 
 impl Shutdown {
     const API_SHUTDOWN_TIMEOUT_SECONDS: u64 = 4;
-    const ETHEREUM_WATCHER_SHUTDOWN_TIMEOUT_SECONDS: u64 = 5;
+    const EVENT_SOURCE_SHUTDOWN_TIMEOUT_SECONDS: u64 = 5;
     const SUBMITTER_SHUTDOWN_TIMEOUT_SECONDS: u64 = 20;
 
     async fn run(self) {
         let Self {
             api_task,
             submitter_task,
-            ethereum_watcher_task,
+            event_source_task,
             api_shutdown_signal,
             token,
         } = self;
@@ -306,31 +389,31 @@ impl Shutdown {
             info!("submitter task was already dead");
         }
 
-        // Giving ethereum watcher 5 seconds to shutdown because Kubernetes issues a SIGKILL after
+        // Giving the event source 5 seconds to shutdown because Kubernetes issues a SIGKILL after
         // 30.
-        if let Some(mut ethereum_watcher_task) = ethereum_watcher_task {
-            info!("waiting for watcher task to shut down");
-            let limit = Duration::from_secs(Self::ETHEREUM_WATCHER_SHUTDOWN_TIMEOUT_SECONDS);
-            match timeout(limit, &mut ethereum_watcher_task)
+        if let Some(mut event_source_task) = event_source_task {
+            info!("waiting for event source task to shut down");
+            let limit = Duration::from_secs(Self::EVENT_SOURCE_SHUTDOWN_TIMEOUT_SECONDS);
+            match timeout(limit, &mut event_source_task)
                 .await
                 .map(flatten_result)
             {
-                Ok(Ok(())) => info!("watcher exited gracefully"),
-                Ok(Err(error)) => error!(%error, "watcher exited with an error"),
+                Ok(Ok(())) => info!("event source exited gracefully"),
+                Ok(Err(error)) => error!(%error, "event source exited with an error"),
                 Err(_) => {
                     error!(
                         timeout_secs = limit.as_secs(),
-                        "watcher did not shut down within timeout; killing it"
+                        "event source did not shut down within timeout; killing it"
                     );
-                    ethereum_watcher_task.abort();
+                    event_source_task.abort();
                 }
             }
         } else {
-            info!("watcher task was already dead");
+            info!("event source task was already dead");
         }
 
-        // Giving the API task 4 seconds. 5s for watcher + 20 for submitter + 4s = 29s (out of 30s
-        // for k8s).
+        // Giving the API task 4 seconds. 5s for event source + 20 for submitter + 4s = 29s (out of
+        // 30s for k8s).
         if let Some(mut api_task) = api_task {
             info!("sending shutdown signal to API server");
             let _ = api_shutdown_signal.send(());
@@ -40,6 +40,7 @@ macro_rules! forward_setter {
 
 forward_setter!(
     [set_sequencer_connected <- bool],
+    [set_submissions_paused <- bool],
     [set_last_rollup_height_submitted <- u64],
     [set_last_sequencer_height <- u64],
     [set_last_sequencer_tx_hash <- tendermint::Hash],
@@ -51,6 +52,7 @@ pub(crate) struct StateSnapshot {
     submitter_ready: bool,
 
     sequencer_connected: bool,
+    submissions_paused: bool,
 
     last_rollup_height_submitted: Option<u64>,
     last_sequencer_block: Option<u64>,
@@ -81,6 +83,13 @@ impl StateSnapshot {
         changed
     }
 
+    /// Sets whether submissions are paused because of a low fee asset balance.
+    fn set_submissions_paused(&mut self, paused: bool) -> bool {
+        let changed = self.submissions_paused ^ paused;
+        self.submissions_paused = paused;
+        changed
+    }
+
     fn set_last_rollup_height_submitted(&mut self, height: u64) -> bool {
         let changed = self
             .last_rollup_height_submitted
@@ -1,8 +1,13 @@
-use std::sync::Arc;
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
 
 use astria_core::primitive::v1::asset;
 use astria_eyre::eyre::{
     self,
+    ensure,
     Context as _,
 };
 use tokio::sync::{
@@ -12,7 +17,11 @@ use tokio::sync::{
 use tokio_util::sync::CancellationToken;
 use tracing::info;
 
-use super::state::State;
+use super::{
+    fee_watchdog::FeeWatchdog,
+    processed_events::ProcessedEvents,
+    state::State,
+};
 use crate::{
     bridge_withdrawer::{
         submitter::Batch,
@@ -64,6 +73,9 @@ pub(crate) struct Builder {
     pub(crate) state: Arc<State>,
     pub(crate) expected_fee_asset_id: asset::Id,
     pub(crate) min_expected_fee_asset_balance: u128,
+    pub(crate) fee_asset_reserve_balance: u128,
+    pub(crate) fee_asset_balance_check_interval: Duration,
+    pub(crate) processed_events_path: Option<PathBuf>,
     pub(crate) metrics: &'static Metrics,
 }
 
@@ -79,9 +91,17 @@ impl Builder {
             state,
             expected_fee_asset_id,
             min_expected_fee_asset_balance,
+            fee_asset_reserve_balance,
+            fee_asset_balance_check_interval,
+            processed_events_path,
             metrics,
         } = self;
 
+        ensure!(
+            !fee_asset_balance_check_interval.is_zero(),
+            "the fee asset balance check interval must not be zero"
+        );
+
         let signer = super::signer::SequencerKey::builder()
             .path(sequencer_key_path)
             .prefix(sequencer_address_prefix)
@@ -89,6 +109,11 @@ impl Builder {
             .wrap_err("failed to load sequencer private key")?;
         info!(address = %signer.address(), "loaded sequencer signer");
 
+        let processed_events = processed_events_path
+            .map(ProcessedEvents::load)
+            .transpose()
+            .wrap_err("failed to load processed withdrawal events")?;
+
         let sequencer_cometbft_client =
             sequencer_client::HttpClient::new(&*sequencer_cometbft_endpoint)
                 .wrap_err("failed constructing cometbft http client")?;
@@ -108,6 +133,9 @@ impl Builder {
                 startup_tx,
                 expected_fee_asset_id,
                 min_expected_fee_asset_balance,
+                fee_watchdog: FeeWatchdog::new(fee_asset_reserve_balance, metrics),
+                processed_events,
+                fee_asset_balance_check_interval,
                 metrics,
             },
             handle,
@@ -0,0 +1,180 @@
+//! Tracking of the bridge account's fee asset balance.
+//!
+//! The [`FeeWatchdog`] is fed the balances the submitter periodically fetches from the sequencer
+//! and the batches it submits in between. From these it estimates the fees paid per batch and the
+//! rate at which the balance is burned, and pauses submissions before a batch would take the
+//! balance below the configured reserve.
+
+use std::time::Instant;
+
+use tracing::{
+    error,
+    info,
+};
+
+use crate::metrics::Metrics;
+
+/// The weight of the latest observation in the moving average of the fees paid per batch.
+const FEE_PER_BATCH_SMOOTHING: u128 = 4;
+
+pub(super) struct FeeWatchdog {
+    reserve: u128,
+    balance: Option<u128>,
+    last_observed_at: Option<Instant>,
+    batches_since_observation: u32,
+    fee_per_batch: Option<u128>,
+    paused: bool,
+    metrics: &'static Metrics,
+}
+
+impl FeeWatchdog {
+    pub(super) fn new(reserve: u128, metrics: &'static Metrics) -> Self {
+        Self {
+            reserve,
+            balance: None,
+            last_observed_at: None,
+            batches_since_observation: 0,
+            fee_per_batch: None,
+            paused: false,
+            metrics,
+        }
+    }
+
+    /// Returns if submissions are paused because the next batch could take the balance below the
+    /// reserve.
+    pub(super) fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    /// Records the fee asset `balance` fetched from the sequencer at `now`.
+    ///
+    /// A decrease since the last observation updates the estimated fee per batch and the burn
+    /// rate. An increase is taken as a top-up and leaves the estimates unchanged.
+    pub(super) fn observe_balance(&mut self, balance: u128, now: Instant) {
+        if let (Some(previous), Some(observed_at)) = (self.balance, self.last_observed_at) {
+            let burned = previous.saturating_sub(balance);
+            if burned > 0 {
+                let elapsed = now.saturating_duration_since(observed_at).as_secs_f64();
+                if elapsed > 0.0 {
+                    // allow: the burn rate is only reported as a metric, precision loss is fine.
+                    #[allow(clippy::cast_precision_loss)]
+                    self.metrics
+                        .set_fee_asset_burn_rate(burned as f64 / elapsed);
+                }
+                if self.batches_since_observation > 0 {
+                    let observed = burned / u128::from(self.batches_since_observation);
+                    self.fee_per_batch = Some(self.fee_per_batch.map_or(observed, |average| {
+                        (average * (FEE_PER_BATCH_SMOOTHING - 1) + observed)
+                            / FEE_PER_BATCH_SMOOTHING
+                    }));
+                }
+            }
+        }
+        self.balance = Some(balance);
+        self.last_observed_at = Some(now);
+        self.batches_since_observation = 0;
+        self.metrics.set_fee_asset_balance(balance);
+        self.update_paused();
+    }
+
+    /// Records that a batch was submitted since the last balance observation.
+    pub(super) fn record_batch_submitted(&mut self) {
+        self.batches_since_observation = self.batches_since_observation.saturating_add(1);
+        self.update_paused();
+    }
+
+    /// Returns the balance expected after the batches submitted since the last observation, or
+    /// `None` if no balance was observed yet.
+    pub(super) fn projected_balance(&self) -> Option<u128> {
+        let fees = self
+            .fee_per_batch
+            .unwrap_or(0)
+            .saturating_mul(u128::from(self.batches_since_observation));
+        self.balance.map(|balance| balance.saturating_sub(fees))
+    }
+
+    fn can_afford_next_batch(&self) -> bool {
+        self.projected_balance().map_or(true, |projected| {
+            projected.saturating_sub(self.fee_per_batch.unwrap_or(0)) >= self.reserve
+        })
+    }
+
+    fn update_paused(&mut self) {
+        let paused = !self.can_afford_next_batch();
+        if paused && !self.paused {
+            error!(
+                projected_balance = self.projected_balance(),
+                estimated_fee_per_batch = self.fee_per_batch,
+                reserve = self.reserve,
+                "fee asset balance of the bridge account would drop below the reserve; pausing \
+                 submissions until it is topped up"
+            );
+        } else if !paused && self.paused {
+            info!(
+                balance = self.balance,
+                reserve = self.reserve,
+                "fee asset balance of the bridge account is above the reserve; resuming \
+                 submissions"
+            );
+        }
+        self.paused = paused;
+        self.metrics.set_submissions_paused(paused);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn watchdog(reserve: u128) -> FeeWatchdog {
+        FeeWatchdog::new(reserve, Box::leak(Box::new(Metrics::new())))
+    }
+
+    #[test]
+    fn fee_per_batch_is_estimated_from_balance_decrease() {
+        let mut watchdog = watchdog(0);
+        let now = Instant::now();
+        watchdog.observe_balance(1000, now);
+        watchdog.record_batch_submitted();
+        watchdog.record_batch_submitted();
+        watchdog.observe_balance(800, now + Duration::from_secs(10));
+        assert_eq!(Some(100), watchdog.fee_per_batch);
+
+        watchdog.record_batch_submitted();
+        assert_eq!(Some(700), watchdog.projected_balance());
+    }
+
+    #[test]
+    fn top_up_does_not_change_estimate() {
+        let mut watchdog = watchdog(0);
+        let now = Instant::now();
+        watchdog.observe_balance(1000, now);
+        watchdog.record_batch_submitted();
+        watchdog.observe_balance(900, now + Duration::from_secs(1));
+        watchdog.record_batch_submitted();
+        watchdog.observe_balance(5000, now + Duration::from_secs(2));
+        assert_eq!(Some(100), watchdog.fee_per_batch);
+    }
+
+    #[test]
+    fn submissions_pause_before_reserve_and_resume_after_top_up() {
+        let mut watchdog = watchdog(650);
+        let now = Instant::now();
+        watchdog.observe_balance(1000, now);
+        watchdog.record_batch_submitted();
+        watchdog.observe_balance(900, now + Duration::from_secs(1));
+        assert!(!watchdog.is_paused());
+
+        // 900 - 100 - 100 = 700 left after the next batch
+        watchdog.record_batch_submitted();
+        assert!(!watchdog.is_paused());
+        // 900 - 200 - 100 = 600 would be left after the next batch
+        watchdog.record_batch_submitted();
+        assert!(watchdog.is_paused());
+
+        watchdog.observe_balance(2000, now + Duration::from_secs(2));
+        assert!(!watchdog.is_paused());
+    }
+}
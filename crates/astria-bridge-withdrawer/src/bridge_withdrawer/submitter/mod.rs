@@ -4,7 +4,13 @@ use std::{
 };
 
 use astria_core::{
-    bridge::Ics20WithdrawalFromRollupMemo,
+    bridge::{
+        memo::{
+            self,
+            ParseMode,
+        },
+        Ics20WithdrawalFromRollupMemo,
+    },
     primitive::v1::asset,
     protocol::{
         asset::v1alpha1::AllowedFeeAssetIdsResponse,
@@ -25,6 +31,8 @@ use astria_eyre::eyre::{
 };
 pub(crate) use builder::Builder;
 pub(super) use builder::Handle;
+use fee_watchdog::FeeWatchdog;
+use processed_events::ProcessedEvents;
 use prost::Message as _;
 use sequencer_client::{
     tendermint_rpc::{
@@ -50,7 +58,10 @@ use tokio::{
             self,
         },
     },
-    time::Instant,
+    time::{
+        interval_at,
+        Instant,
+    },
 };
 use tokio_util::sync::CancellationToken;
 use tracing::{
@@ -75,6 +86,8 @@ use crate::{
 };
 
 mod builder;
+mod fee_watchdog;
+mod processed_events;
 mod signer;
 #[cfg(test)]
 mod tests;
@@ -89,6 +102,9 @@ pub(super) struct Submitter {
     startup_tx: oneshot::Sender<SequencerStartupInfo>,
     expected_fee_asset_id: asset::Id,
     min_expected_fee_asset_balance: u128,
+    fee_watchdog: FeeWatchdog,
+    processed_events: Option<ProcessedEvents>,
+    fee_asset_balance_check_interval: Duration,
     metrics: &'static Metrics,
 }
 
@@ -103,6 +119,11 @@ impl Submitter {
             .send(startup)
             .map_err(|_startup| eyre!("failed to send startup info to watcher"))?;
 
+        let mut fee_asset_balance_check = interval_at(
+            Instant::now() + self.fee_asset_balance_check_interval,
+            self.fee_asset_balance_check_interval,
+        );
+
         let reason = loop {
             select!(
                 biased;
@@ -112,11 +133,30 @@ impl Submitter {
                     break Ok("shutdown requested");
                 }
 
-                batch = self.batches_rx.recv() => {
+                _ = fee_asset_balance_check.tick() => {
+                    if let Err(e) = self.check_fee_asset_balance().await {
+                        break Err(e);
+                    }
+                }
+
+                // batches are left in the queue while submissions are paused
+                batch = self.batches_rx.recv(), if !self.fee_watchdog.is_paused() => {
                     let Some(Batch { actions, rollup_height }) = batch else {
                         info!("received None from batch channel, shutting down");
                         break Err(eyre!("batch channel closed"));
                     };
+                    let actions = match &self.processed_events {
+                        Some(processed_events) => processed_events.retain_unprocessed(actions),
+                        None => actions,
+                    };
+                    if actions.is_empty() {
+                        info!(
+                            rollup.height = rollup_height,
+                            "all withdrawals of the batch were already submitted; skipping it"
+                        );
+                        continue;
+                    }
+                    let submitted = self.processed_events.is_some().then(|| actions.clone());
                     // if batch submission fails, halt the submitter
                     if let Err(e) = process_batch(
                         self.sequencer_cometbft_client.clone(),
@@ -129,6 +169,23 @@ impl Submitter {
                     ).await {
                         break Err(e);
                     }
+                    if let (Some(processed_events), Some(submitted)) =
+                        (&mut self.processed_events, submitted)
+                    {
+                        // the sequencer still rejects duplicates, so failing to persist them
+                        // only risks wasted fees after a restart
+                        if let Err(error) = processed_events.record(&submitted).await {
+                            warn!(%error, "failed to record submitted withdrawals");
+                        }
+                    }
+                    self.fee_watchdog.record_batch_submitted();
+                    // confirm a projected shortfall against the actual balance right away
+                    // instead of waiting for the next scheduled check
+                    if self.fee_watchdog.is_paused() {
+                        if let Err(e) = self.check_fee_asset_balance().await {
+                            break Err(e);
+                        }
+                    }
                 }
             );
         };
@@ -214,6 +271,10 @@ impl Submitter {
             fee_asset_balance >= self.min_expected_fee_asset_balance,
             "sequencer key does not have a sufficient balance of the fee asset"
         );
+        self.fee_watchdog
+            .observe_balance(fee_asset_balance, std::time::Instant::now());
+        self.state
+            .set_submissions_paused(self.fee_watchdog.is_paused());
 
         // sync to latest on-chain state
         let next_batch_rollup_height = self
@@ -231,6 +292,29 @@ impl Submitter {
         Ok(startup)
     }
 
+    /// Fetches the fee asset balance of the bridge account and passes it to the fee watchdog.
+    ///
+    /// A missing balance entry is treated as a balance of zero.
+    async fn check_fee_asset_balance(&mut self) -> eyre::Result<()> {
+        let balances = get_latest_balance(
+            self.sequencer_cometbft_client.clone(),
+            self.state.clone(),
+            *self.signer.address(),
+        )
+        .await
+        .wrap_err("failed to get latest balance")?;
+        let balance = balances
+            .balances
+            .into_iter()
+            .find(|balance| balance.denom.id() == self.expected_fee_asset_id)
+            .map_or(0, |balance| balance.balance);
+        self.fee_watchdog
+            .observe_balance(balance, std::time::Instant::now());
+        self.state
+            .set_submissions_paused(self.fee_watchdog.is_paused());
+        Ok(())
+    }
+
     /// Gets the data necessary for syncing to the latest on-chain state from the sequencer. Since
     /// we batch all events from a given rollup block into a single sequencer transaction, we
     /// get the last tx finalized by the bridge account on the sequencer and extract the rollup
@@ -718,9 +802,10 @@ fn rollup_height_from_signed_transaction(
             Some(memo.block_number.as_u64())
         }
         Action::Ics20Withdrawal(action) => {
-            let memo: Ics20WithdrawalFromRollupMemo = serde_json::from_str(&action.memo)
-                .wrap_err("failed to parse memo from last transaction by the bridge account")?;
-            Some(memo.block_number)
+            let memo =
+                memo::parse::<Ics20WithdrawalFromRollupMemo>(&action.memo, ParseMode::Lenient)
+                    .wrap_err("failed to parse memo from last transaction by the bridge account")?;
+            Some(memo.memo().block_number)
         }
         _ => None,
     }
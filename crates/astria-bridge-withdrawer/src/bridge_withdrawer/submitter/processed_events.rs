@@ -0,0 +1,249 @@
+//! A local record of the withdrawals submitted to the sequencer.
+//!
+//! The sequencer rejects withdrawals whose rollup event was already processed, but only after the
+//! transaction was checked and its fees charged. The [`ProcessedEvents`] store persists the ids of
+//! successfully submitted withdrawals to a file so that, after a restart, withdrawals that were
+//! already submitted are dropped before a transaction is built.
+//!
+//! The file contains one withdrawal per line, made up of the rollup height and the hex-encoded
+//! rollup transaction hash separated by a space. Lines are only ever appended.
+
+use std::{
+    collections::BTreeSet,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use astria_core::protocol::transaction::v1alpha1::Action;
+use astria_eyre::eyre::{
+    self,
+    bail,
+    ensure,
+    WrapErr as _,
+};
+use tokio::io::AsyncWriteExt as _;
+use tracing::{
+    info,
+    warn,
+};
+
+use crate::bridge_withdrawer::batch::WithdrawalId;
+
+pub(super) struct ProcessedEvents {
+    path: PathBuf,
+    ids: BTreeSet<WithdrawalId>,
+}
+
+impl ProcessedEvents {
+    /// Loads the store from the file at `path`, which is created on the first write if it does
+    /// not exist.
+    ///
+    /// # Errors
+    /// Returns an error if the file exists but cannot be read or contains a malformed line.
+    pub(super) fn load(path: impl Into<PathBuf>) -> eyre::Result<Self> {
+        let path = path.into();
+        let contents = match std::fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(error) if error.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(error) => {
+                return Err(error).wrap_err_with(|| {
+                    format!("failed to read processed events file `{}`", path.display())
+                });
+            }
+        };
+        let ids = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(index, line)| {
+                parse_line(line).wrap_err_with(|| {
+                    format!(
+                        "failed to parse line {} of processed events file `{}`",
+                        index + 1,
+                        path.display()
+                    )
+                })
+            })
+            .collect::<eyre::Result<BTreeSet<_>>>()?;
+        info!(
+            path = %path.display(),
+            processed_events = ids.len(),
+            "loaded processed withdrawal events"
+        );
+        Ok(Self {
+            path,
+            ids,
+        })
+    }
+
+    /// Removes the withdrawal actions whose events were already submitted.
+    ///
+    /// Actions that are not withdrawals or whose memo cannot be read are kept and left for the
+    /// sequencer to judge.
+    pub(super) fn retain_unprocessed(&self, actions: Vec<Action>) -> Vec<Action> {
+        actions
+            .into_iter()
+            .filter(|action| match WithdrawalId::from_action(action) {
+                Ok(Some(id)) if self.ids.contains(&id) => {
+                    warn!(
+                        rollup.height = id.rollup_height,
+                        rollup.transaction_hash = %telemetry::display::hex(&id.rollup_transaction_hash),
+                        "withdrawal was already submitted; dropping it from the batch"
+                    );
+                    false
+                }
+                Ok(_) => true,
+                Err(error) => {
+                    warn!(
+                        %error,
+                        "failed to read withdrawal id from action; keeping it in the batch"
+                    );
+                    true
+                }
+            })
+            .collect()
+    }
+
+    /// Records the withdrawals among `actions` as submitted and persists them to the file.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be written.
+    pub(super) async fn record(&mut self, actions: &[Action]) -> eyre::Result<()> {
+        let new_ids: Vec<_> = actions
+            .iter()
+            .filter_map(|action| WithdrawalId::from_action(action).ok().flatten())
+            .filter(|id| !self.ids.contains(id))
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        if new_ids.is_empty() {
+            return Ok(());
+        }
+
+        let mut lines = String::new();
+        for id in &new_ids {
+            lines.push_str(&format_line(id));
+            lines.push('\n');
+        }
+        append(&self.path, lines.as_bytes())
+            .await
+            .wrap_err_with(|| {
+                format!(
+                    "failed to write to processed events file `{}`",
+                    self.path.display()
+                )
+            })?;
+        self.ids.extend(new_ids);
+        Ok(())
+    }
+}
+
+async fn append(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    let mut file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .await?;
+    file.write_all(bytes).await?;
+    file.sync_data().await
+}
+
+fn format_line(id: &WithdrawalId) -> String {
+    format!(
+        "{} {}",
+        id.rollup_height,
+        hex::encode(id.rollup_transaction_hash)
+    )
+}
+
+fn parse_line(line: &str) -> eyre::Result<WithdrawalId> {
+    let Some((height, hash)) = line.trim().split_once(' ') else {
+        bail!("expected a rollup height and a transaction hash separated by a space");
+    };
+    let rollup_height = height.parse().wrap_err("failed to parse rollup height")?;
+    let hash = hex::decode(hash).wrap_err("failed to decode rollup transaction hash as hex")?;
+    ensure!(
+        hash.len() == 32,
+        "rollup transaction hash must be 32 bytes, but was {} bytes",
+        hash.len()
+    );
+    let mut rollup_transaction_hash = [0; 32];
+    rollup_transaction_hash.copy_from_slice(&hash);
+    Ok(WithdrawalId {
+        rollup_height,
+        rollup_transaction_hash,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::asset,
+        protocol::transaction::v1alpha1::action::BridgeUnlockAction,
+    };
+    use ethers::types::{
+        TxHash,
+        U64,
+    };
+
+    use super::*;
+    use crate::bridge_withdrawer::{
+        astria_address,
+        ethereum::convert::BridgeUnlockMemo,
+    };
+
+    fn bridge_unlock(rollup_height: u64, hash: u8) -> Action {
+        let memo = BridgeUnlockMemo {
+            block_number: U64::from(rollup_height),
+            transaction_hash: TxHash::from([hash; 32]),
+        };
+        Action::BridgeUnlock(BridgeUnlockAction {
+            to: astria_address([1; 20]),
+            amount: 1,
+            memo: serde_json::to_vec(&memo).unwrap(),
+            fee_asset_id: asset::Id::from_str_unchecked("nria"),
+            bridge_address: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn recorded_withdrawals_are_dropped_after_reload() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("processed-events");
+
+        let mut store = ProcessedEvents::load(&path).unwrap();
+        store
+            .record(&[bridge_unlock(1, 1), bridge_unlock(1, 2)])
+            .await
+            .unwrap();
+        store.record(&[bridge_unlock(2, 3)]).await.unwrap();
+
+        let store = ProcessedEvents::load(&path).unwrap();
+        let remaining = store.retain_unprocessed(vec![
+            bridge_unlock(1, 1),
+            bridge_unlock(2, 3),
+            bridge_unlock(3, 4),
+        ]);
+        let remaining: Vec<_> = remaining
+            .iter()
+            .map(|action| WithdrawalId::from_action(action).unwrap().unwrap())
+            .collect();
+        assert_eq!(
+            vec![WithdrawalId {
+                rollup_height: 3,
+                rollup_transaction_hash: [4; 32],
+            }],
+            remaining
+        );
+    }
+
+    #[test]
+    fn malformed_file_is_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("processed-events");
+        std::fs::write(&path, "1 abcd\n").unwrap();
+        assert!(ProcessedEvents::load(&path).is_err());
+    }
+}
@@ -155,6 +155,9 @@ impl TestSubmitter {
             state,
             expected_fee_asset_id: default_native_asset().id(),
             min_expected_fee_asset_balance: 1_000_000,
+            fee_asset_reserve_balance: 0,
+            fee_asset_balance_check_interval: Duration::from_secs(60),
+            processed_events_path: None,
             metrics,
         }
         .build()
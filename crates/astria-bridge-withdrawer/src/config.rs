@@ -3,6 +3,15 @@ use serde::{
     Serialize,
 };
 
+/// Where the withdrawer gets the rollup's withdrawals from.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub enum RollupEventSource {
+    /// Watch the events of the `AstriaWithdrawer` contract on an EVM rollup.
+    Ethereum,
+    /// Serve a gRPC service to which the rollup pushes its withdrawals.
+    Grpc,
+}
+
 // Allowed `struct_excessive_bools` because this is used as a container
 // for deserialization. Making this a builder-pattern is not actionable.
 #[allow(clippy::struct_excessive_bools)]
@@ -19,16 +28,38 @@ pub struct Config {
     pub fee_asset_denomination: String,
     // The minimum expected balance of the fee asset in the bridge account.
     pub min_expected_fee_asset_balance: u64,
+    // The fee asset balance the bridge account keeps in reserve. Submissions are paused while the
+    // next batch is projected to take the balance below it.
+    pub fee_asset_reserve_balance: u64,
+    // The interval in milliseconds at which the bridge account's fee asset balance is checked.
+    pub fee_asset_balance_check_interval_ms: u64,
+    // The path to the file recording the rollup withdrawal events already submitted to the
+    // sequencer. Set to an empty string to disable the record.
+    pub processed_events_path: String,
     // The asset denomination being withdrawn from the rollup.
     pub rollup_asset_denomination: String,
     // The bridge address corresponding to the bridged rollup asset on the sequencer.
     pub sequencer_bridge_address: String,
+    // Where the withdrawals of the rollup are read from.
+    pub rollup_event_source: RollupEventSource,
+    // The socket address at which the withdrawal event gRPC service is served if
+    // `rollup_event_source` is `Grpc`.
+    pub grpc_event_source_addr: String,
     // The address of the AstriaWithdrawer contract on the evm rollup.
     pub ethereum_contract_address: String,
     // The rpc endpoint of the evm rollup.
     pub ethereum_rpc_endpoint: String,
     // The address prefix to use when constructing sequencer addresses using the signing key.
     pub sequencer_address_prefix: String,
+    // The first rollup height whose withdrawal events are reconciled against the sequencer on
+    // startup. Set to 0 to disable the startup audit.
+    pub audit_from_rollup_height: u64,
+    // The last rollup height whose withdrawal events are reconciled against the sequencer on
+    // startup. Set to 0 to audit up to the latest rollup block.
+    pub audit_to_rollup_height: u64,
+    // The maximum number of sequencer blocks searched for the bridge account's withdrawals during
+    // the startup audit.
+    pub audit_max_sequencer_blocks: u64,
     // The socket address at which the bridge service will server healthz, readyz, and status
     // calls.
     pub api_addr: String,
@@ -21,6 +21,9 @@ pub(crate) struct Metrics {
     current_nonce: Gauge,
     sequencer_submission_failure_count: Counter,
     sequencer_submission_latency: Histogram,
+    fee_asset_balance: Gauge,
+    fee_asset_burn_rate: Gauge,
+    submissions_paused: Gauge,
 }
 
 impl Metrics {
@@ -64,6 +67,28 @@ impl Metrics {
         );
         let sequencer_submission_latency = histogram!(SEQUENCER_SUBMISSION_LATENCY);
 
+        describe_gauge!(
+            FEE_ASSET_BALANCE,
+            Unit::Count,
+            "The last observed fee asset balance of the bridge account"
+        );
+        let fee_asset_balance = gauge!(FEE_ASSET_BALANCE);
+
+        describe_gauge!(
+            FEE_ASSET_BURN_RATE,
+            Unit::CountPerSecond,
+            "The rate at which the fee asset balance of the bridge account is spent"
+        );
+        let fee_asset_burn_rate = gauge!(FEE_ASSET_BURN_RATE);
+
+        describe_gauge!(
+            SUBMISSIONS_PAUSED,
+            Unit::Count,
+            "Set to 1 while submissions are paused because the fee asset balance would drop below \
+             the reserve, 0 otherwise"
+        );
+        let submissions_paused = gauge!(SUBMISSIONS_PAUSED);
+
         Self {
             nonce_fetch_count,
             nonce_fetch_failure_count,
@@ -71,6 +96,9 @@ impl Metrics {
             current_nonce,
             sequencer_submission_failure_count,
             sequencer_submission_latency,
+            fee_asset_balance,
+            fee_asset_burn_rate,
+            submissions_paused,
         }
     }
 
@@ -97,26 +125,46 @@ impl Metrics {
     pub(crate) fn increment_sequencer_submission_failure_count(&self) {
         self.sequencer_submission_failure_count.increment(1);
     }
+
+    // allow: the balance is only reported as a metric, precision loss is fine.
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn set_fee_asset_balance(&self, balance: u128) {
+        self.fee_asset_balance.set(balance as f64);
+    }
+
+    pub(crate) fn set_fee_asset_burn_rate(&self, rate_per_second: f64) {
+        self.fee_asset_burn_rate.set(rate_per_second);
+    }
+
+    pub(crate) fn set_submissions_paused(&self, paused: bool) {
+        self.submissions_paused.set(u8::from(paused));
+    }
 }
 
 metric_names!(pub const METRICS_NAMES:
     CURRENT_NONCE,
+    FEE_ASSET_BALANCE,
+    FEE_ASSET_BURN_RATE,
     NONCE_FETCH_COUNT,
     NONCE_FETCH_FAILURE_COUNT,
     NONCE_FETCH_LATENCY,
     SEQUENCER_SUBMISSION_FAILURE_COUNT,
-    SEQUENCER_SUBMISSION_LATENCY
+    SEQUENCER_SUBMISSION_LATENCY,
+    SUBMISSIONS_PAUSED
 );
 
 #[cfg(test)]
 mod tests {
     use super::{
         CURRENT_NONCE,
+        FEE_ASSET_BALANCE,
+        FEE_ASSET_BURN_RATE,
         NONCE_FETCH_COUNT,
         NONCE_FETCH_FAILURE_COUNT,
         NONCE_FETCH_LATENCY,
         SEQUENCER_SUBMISSION_FAILURE_COUNT,
         SEQUENCER_SUBMISSION_LATENCY,
+        SUBMISSIONS_PAUSED,
     };
 
     #[track_caller]
@@ -130,6 +178,8 @@ mod tests {
     #[test]
     fn metrics_are_as_expected() {
         assert_const(CURRENT_NONCE, "current_nonce");
+        assert_const(FEE_ASSET_BALANCE, "fee_asset_balance");
+        assert_const(FEE_ASSET_BURN_RATE, "fee_asset_burn_rate");
         assert_const(NONCE_FETCH_COUNT, "nonce_fetch_count");
         assert_const(NONCE_FETCH_FAILURE_COUNT, "nonce_fetch_failure_count");
         assert_const(NONCE_FETCH_LATENCY, "nonce_fetch_latency");
@@ -138,5 +188,6 @@ mod tests {
             "sequencer_submission_failure_count",
         );
         assert_const(SEQUENCER_SUBMISSION_LATENCY, "sequencer_submission_latency");
+        assert_const(SUBMISSIONS_PAUSED, "submissions_paused");
     }
 }
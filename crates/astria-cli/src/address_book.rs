@@ -0,0 +1,229 @@
+//! A store of named Sequencer addresses.
+//!
+//! The address book is a JSON file mapping names to bech32m addresses, kept at
+//! `address_book.json` in the [`astria_home`] directory. Every command taking an address accepts
+//! `@<name>` in its place, which is resolved through the address book.
+use std::{
+    collections::BTreeMap,
+    env,
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use astria_core::primitive::v1::Address;
+use color_eyre::{
+    eyre,
+    eyre::{
+        ensure,
+        eyre,
+        Context,
+    },
+};
+
+use crate::home::astria_home;
+
+/// The environment variable overriding the location of the address book file.
+pub(crate) const PATH_ENV_VAR: &str = "ASTRIA_ADDRESS_BOOK";
+
+const FILE_NAME: &str = "address_book.json";
+
+pub(crate) struct AddressBook {
+    path: PathBuf,
+    entries: BTreeMap<String, Address>,
+}
+
+impl AddressBook {
+    /// Opens the address book at `path`, falling back to [`PATH_ENV_VAR`] and then to the
+    /// default location if `path` is `None`.
+    ///
+    /// A missing file is treated as an empty address book.
+    ///
+    /// # Errors
+    ///
+    /// * If the location of the address book cannot be determined
+    /// * If the address book file cannot be read or contains invalid entries
+    pub(crate) fn open(path: Option<&Path>) -> eyre::Result<Self> {
+        let path = match path {
+            Some(path) => path.to_path_buf(),
+            None => match env::var_os(PATH_ENV_VAR) {
+                Some(path) => PathBuf::from(path),
+                None => astria_home()?.join(FILE_NAME),
+            },
+        };
+        let mut entries = BTreeMap::new();
+        if path.exists() {
+            let contents = fs::read(&path)
+                .wrap_err_with(|| format!("failed reading address book `{}`", path.display()))?;
+            let raw: BTreeMap<String, String> = serde_json::from_slice(&contents)
+                .wrap_err_with(|| format!("failed parsing address book `{}`", path.display()))?;
+            for (name, address) in raw {
+                let address = address.parse().wrap_err_with(|| {
+                    format!("address book entry `{name}` holds an invalid address")
+                })?;
+                entries.insert(name, address);
+            }
+        }
+        Ok(Self {
+            path,
+            entries,
+        })
+    }
+
+    /// Stores `address` under `name` and writes the address book to disk.
+    ///
+    /// # Errors
+    ///
+    /// * If `name` is not a valid entry name
+    /// * If the prefix of `address` is not `prefix`
+    /// * If an entry named `name` already exists and `overwrite` is not set
+    /// * If the address book cannot be written to disk
+    pub(crate) fn insert(
+        &mut self,
+        name: &str,
+        address: Address,
+        prefix: &str,
+        overwrite: bool,
+    ) -> eyre::Result<()> {
+        validate_name(name)?;
+        ensure!(
+            address.prefix() == prefix,
+            "address `{address}` does not have the expected prefix `{prefix}`"
+        );
+        ensure!(
+            overwrite || !self.entries.contains_key(name),
+            "an entry named `{name}` already exists in the address book"
+        );
+        self.entries.insert(name.to_string(), address);
+        self.save()
+    }
+
+    /// Removes the entry named `name` and writes the address book to disk.
+    ///
+    /// # Errors
+    ///
+    /// * If there is no entry named `name`
+    /// * If the address book cannot be written to disk
+    pub(crate) fn remove(&mut self, name: &str) -> eyre::Result<Address> {
+        let address = self
+            .entries
+            .remove(name)
+            .ok_or_else(|| eyre!("no entry named `{name}` in the address book"))?;
+        self.save()?;
+        Ok(address)
+    }
+
+    /// Returns the address stored under `name`.
+    ///
+    /// # Errors
+    ///
+    /// * If there is no entry named `name`
+    pub(crate) fn get(&self, name: &str) -> eyre::Result<Address> {
+        self.entries
+            .get(name)
+            .copied()
+            .ok_or_else(|| eyre!("no entry named `{name}` in the address book"))
+    }
+
+    /// Returns all entries, sorted by name.
+    pub(crate) fn entries(&self) -> impl Iterator<Item = (&str, Address)> {
+        self.entries
+            .iter()
+            .map(|(name, address)| (name.as_str(), *address))
+    }
+
+    fn save(&self) -> eyre::Result<()> {
+        if let Some(dir) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir).wrap_err_with(|| {
+                format!("failed creating address book directory `{}`", dir.display())
+            })?;
+        }
+        let raw: BTreeMap<_, _> = self
+            .entries
+            .iter()
+            .map(|(name, address)| (name, address.to_string()))
+            .collect();
+        let contents =
+            serde_json::to_vec_pretty(&raw).wrap_err("failed serializing address book")?;
+        fs::write(&self.path, contents)
+            .wrap_err_with(|| format!("failed writing address book `{}`", self.path.display()))
+    }
+}
+
+/// Parses `input` as a bech32m address, or as `@<name>` to look up the address stored under
+/// `name` in the address book.
+///
+/// This is intended as the `value_parser` of every command line argument taking an address.
+///
+/// # Errors
+///
+/// * If `input` is not a valid address
+/// * If `input` names an address book entry that does not exist
+pub(crate) fn parse_address(input: &str) -> eyre::Result<Address> {
+    match input.strip_prefix('@') {
+        Some(name) => AddressBook::open(None)?.get(name),
+        None => input
+            .parse()
+            .wrap_err("invalid address; use `@<name>` to refer to an address book entry"),
+    }
+}
+
+fn validate_name(name: &str) -> eyre::Result<()> {
+    ensure!(
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+        "invalid entry name `{name}`; only ASCII letters, digits, `-` and `_` are permitted"
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use test_utils::with_temp_directory;
+
+    use super::AddressBook;
+
+    const ALICE: &str = "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm";
+
+    #[tokio::test]
+    async fn entries_persist_across_opens() {
+        with_temp_directory(|_dir| async {
+            let path = Path::new("book").join("address_book.json");
+            let mut book = AddressBook::open(Some(&path)).unwrap();
+            book.insert("alice", ALICE.parse().unwrap(), "astria", false)
+                .unwrap();
+
+            let mut book = AddressBook::open(Some(&path)).unwrap();
+            assert_eq!(ALICE, book.get("alice").unwrap().to_string());
+            book.insert("alice", ALICE.parse().unwrap(), "astria", false)
+                .unwrap_err();
+            book.insert("alice", ALICE.parse().unwrap(), "astria", true)
+                .unwrap();
+
+            book.remove("alice").unwrap();
+            let book = AddressBook::open(Some(&path)).unwrap();
+            book.get("alice").unwrap_err();
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn invalid_entries_are_rejected() {
+        with_temp_directory(|_dir| async {
+            let mut book = AddressBook::open(Some(Path::new("address_book.json"))).unwrap();
+            for name in ["", "@alice", "alice bob"] {
+                book.insert(name, ALICE.parse().unwrap(), "astria", false)
+                    .unwrap_err();
+            }
+            book.insert("alice", ALICE.parse().unwrap(), "celestia", false)
+                .unwrap_err();
+        })
+        .await;
+    }
+}
@@ -0,0 +1,51 @@
+use std::path::PathBuf;
+
+use astria_sequencer_client::Address;
+use clap::{
+    Args,
+    Subcommand,
+};
+
+/// Manage named addresses, usable as `@<name>` wherever an address is expected
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Store an address under a name
+    Add(AddArgs),
+    /// Remove a named address
+    Remove(NameArgs),
+    /// Print the address stored under a name
+    Show(NameArgs),
+    /// List all named addresses
+    List(AddressBookArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct AddressBookArgs {
+    /// The address book file [default: $HOME/.astria/address_book.json]
+    #[arg(long, env = "ASTRIA_ADDRESS_BOOK")]
+    pub(crate) address_book: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct AddArgs {
+    /// The name to store the address under
+    pub(crate) name: String,
+    /// The address to store
+    pub(crate) address: Address,
+    /// The bech32m prefix the address is expected to have
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    /// Replace the address if the name is already taken
+    #[arg(long)]
+    pub(crate) force: bool,
+    #[command(flatten)]
+    pub(crate) address_book: AddressBookArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct NameArgs {
+    /// The name of the address
+    pub(crate) name: String,
+    #[command(flatten)]
+    pub(crate) address_book: AddressBookArgs,
+}
@@ -0,0 +1,88 @@
+use astria_core::primitive::v1::asset::Denom;
+use astria_sequencer_client::Address;
+use clap::{
+    Args,
+    Subcommand,
+};
+
+use crate::{
+    address_book::parse_address,
+    cli::sequencer::{
+        SignerArgs,
+        WaitArgs,
+    },
+};
+
+/// Operate Sequencer bridge accounts
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Register the signer as the bridge account of a rollup
+    Register(RegisterArgs),
+    /// List the deposits into and withdrawals out of a bridge account over a range of blocks
+    History(HistoryArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct RegisterArgs {
+    /// Plaintext rollup name (to be hashed into a rollup ID)
+    pub(crate) rollup_name: String,
+    /// The asset accepted by the bridge account
+    #[arg(long, default_value = "nria")]
+    pub(crate) asset: Denom,
+    /// The asset used to pay the fee of the registration
+    #[arg(long, default_value = "nria")]
+    pub(crate) fee_asset: Denom,
+    /// The address allowed to change the sudo and withdrawer addresses of the bridge account.
+    /// Defaults to the bridge account itself
+    #[arg(long, value_parser = parse_address)]
+    pub(crate) sudo_address: Option<Address>,
+    /// The address allowed to withdraw funds from the bridge account. Defaults to the bridge
+    /// account itself
+    #[arg(long, value_parser = parse_address)]
+    pub(crate) withdrawer_address: Option<Address>,
+    /// The bech32m prefix that will be used for constructing addresses using the private key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// Sign the transaction and print it instead of broadcasting it without
+    /// simulating its execution
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+    #[command(flatten)]
+    pub(crate) wait: WaitArgs,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+}
+
+#[derive(Args, Debug)]
+pub struct HistoryArgs {
+    /// The address of the bridge account
+    #[arg(value_parser = parse_address)]
+    pub(crate) address: Address,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The last height to scan. Defaults to the latest height
+    #[arg(long)]
+    pub(crate) to_height: Option<u32>,
+    /// The number of blocks to scan, ending at `--to-height`
+    #[arg(long, default_value = "100")]
+    pub(crate) blocks: u32,
+}
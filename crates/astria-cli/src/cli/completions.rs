@@ -0,0 +1,29 @@
+use std::path::PathBuf;
+
+use clap::{
+    Args,
+    ValueEnum,
+};
+
+#[derive(Args, Debug)]
+pub struct CompletionsArgs {
+    /// The shell to generate completions for, or `man` to generate man pages
+    #[arg(value_enum)]
+    pub(crate) target: Target,
+    /// The directory to write the completions or man pages to. Completions are printed to
+    /// stdout if not given
+    #[arg(long, required_if_eq("target", "man"))]
+    pub(crate) output_dir: Option<PathBuf>,
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Target {
+    Bash,
+    Elvish,
+    Fish,
+    #[value(name = "powershell")]
+    PowerShell,
+    Zsh,
+    /// A man page for every command and subcommand
+    Man,
+}
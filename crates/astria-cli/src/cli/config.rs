@@ -0,0 +1,121 @@
+use std::path::PathBuf;
+
+use clap::{
+    Args,
+    Subcommand,
+};
+
+/// Generate configs for Astria services
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Generate a validated env-file config for a service
+    Generate {
+        #[command(subcommand)]
+        command: GenerateCommand,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+pub enum GenerateCommand {
+    /// Generate the config of a conductor driving a rollup node
+    Conductor(GenerateConductorArgs),
+    /// Generate the config of a composer submitting a rollup's transactions to the Sequencer
+    Composer(GenerateComposerArgs),
+}
+
+/// Arguments shared by all config generators
+#[derive(Args, Clone, Debug)]
+pub struct GenerateArgs {
+    /// Prompt for every value, using the value given by flag as the default
+    #[arg(long, short)]
+    pub(crate) interactive: bool,
+    /// Skip cross-checking the config against the live Sequencer and rollup nodes
+    #[arg(long)]
+    pub(crate) offline: bool,
+    /// The file to write the env-file config to. The config is only printed if not set
+    #[arg(long)]
+    pub(crate) output_file: Option<PathBuf>,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GenerateConductorArgs {
+    #[command(flatten)]
+    pub(crate) generate: GenerateArgs,
+    /// The name of the rollup. If set, the rollup ID served by the execution node is checked
+    /// against the ID derived from it
+    #[arg(long)]
+    pub(crate) rollup_name: Option<String>,
+    /// The url of the execution API of the rollup node that conductor drives
+    #[arg(long, default_value = "http://127.0.0.1:50051")]
+    pub(crate) execution_rpc_url: String,
+    /// Where blocks are read from: `SoftOnly`, `FirmOnly`, or `SoftAndFirm`
+    #[arg(long, default_value = "SoftAndFirm")]
+    pub(crate) execution_commit_level: String,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The gRPC endpoint of the Sequencer node
+    #[arg(
+        long = "sequencer.grpc",
+        env = "SEQUENCER_GRPC",
+        default_value = crate::cli::DEFAULT_SEQUENCER_GRPC
+    )]
+    pub(crate) sequencer_grpc: String,
+    /// The chain id of the sequencing chain the Sequencer node is expected to be on
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// The Sequencer block time in milliseconds
+    #[arg(long, default_value = "2000")]
+    pub(crate) sequencer_block_time_ms: u64,
+    /// The url of the Celestia node that firm blocks are read from
+    #[arg(long, default_value = "http://127.0.0.1:26658")]
+    pub(crate) celestia_node_http_url: String,
+    /// The bearer token to authenticate with the Celestia node
+    #[arg(long, env = "CELESTIA_BEARER_TOKEN", default_value = "")]
+    pub(crate) celestia_bearer_token: String,
+    /// The Celestia block time in milliseconds
+    #[arg(long, default_value = "12000")]
+    pub(crate) celestia_block_time_ms: u64,
+}
+
+#[derive(Args, Clone, Debug)]
+pub struct GenerateComposerArgs {
+    #[command(flatten)]
+    pub(crate) generate: GenerateArgs,
+    /// The name of the rollup, from which its ID is derived
+    pub(crate) rollup_name: String,
+    /// The websocket url of the rollup node that composer collects transactions from
+    #[arg(long, default_value = "ws://127.0.0.1:8545")]
+    pub(crate) rollup_ws_url: String,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// The bech32m prefix of Sequencer addresses
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    /// The path to the file holding the key composer signs its transactions with
+    #[arg(long, default_value = "/path/to/priv_sequencer_key.json")]
+    pub(crate) private_key_file: String,
+    /// The maximum number of bytes of rollup data bundled into a single transaction
+    #[arg(long, default_value = "200000")]
+    pub(crate) max_bytes_per_bundle: usize,
+}
@@ -0,0 +1,41 @@
+use clap::{
+    Args,
+    ValueEnum,
+};
+
+#[derive(Args, Debug)]
+pub struct DecodeArgs {
+    /// The base64- or hex-encoded protobuf message. Read from stdin if not given or `-`
+    pub(crate) input: Option<String>,
+    /// The type of the message. Detected from the message if not given
+    #[arg(long = "type", value_enum)]
+    pub(crate) message_type: Option<MessageType>,
+    /// The encoding of the message. Detected from the message if not given
+    #[arg(long, value_enum)]
+    pub(crate) encoding: Option<Encoding>,
+}
+
+/// The Astria protobuf messages that can be decoded
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum MessageType {
+    /// A Sequencer block, as served by the Sequencer's gRPC API
+    SequencerBlock,
+    /// A signed Sequencer transaction, as included in CometBFT blocks
+    SignedTransaction,
+    /// An unsigned Sequencer transaction, which is what gets signed
+    UnsignedTransaction,
+    /// The metadata of a Sequencer block, as posted to Celestia
+    SubmittedMetadata,
+    /// The data of a single rollup in a Sequencer block, as posted to Celestia
+    SubmittedRollupData,
+    /// A deposit of funds to a rollup through a bridge account
+    Deposit,
+    /// A rollup transaction or deposit, as executed by a rollup
+    RollupData,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum Encoding {
+    Hex,
+    Base64,
+}
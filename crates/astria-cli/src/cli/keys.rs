@@ -0,0 +1,95 @@
+use std::path::PathBuf;
+
+use astria_core::crypto::hd::DerivationPath;
+use clap::{
+    Args,
+    Subcommand,
+};
+
+/// Manage signing keys in the encrypted keystore
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Generate a new key and store it in the keystore
+    Generate(GenerateArgs),
+    /// Import an existing private key, or a key derived from a BIP39 mnemonic, into the keystore
+    Import(ImportArgs),
+    /// Print the private key of a key in the keystore
+    Export(ExportArgs),
+    /// List all keys in the keystore
+    List(ListArgs),
+    /// Sign an arbitrary message with a key in the keystore
+    Sign(SignArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct KeystoreArgs {
+    /// The directory of the keystore [default: $HOME/.astria/keystore]
+    #[arg(long, env = "ASTRIA_KEYSTORE_DIR")]
+    pub(crate) keystore_dir: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct GenerateArgs {
+    /// The name to store the key under
+    pub(crate) name: String,
+    #[command(flatten)]
+    pub(crate) keystore: KeystoreArgs,
+    /// The bech32m prefix used to display the address of the key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+}
+
+#[derive(Args, Debug)]
+pub struct ImportArgs {
+    /// The name to store the key under
+    pub(crate) name: String,
+    #[command(flatten)]
+    pub(crate) keystore: KeystoreArgs,
+    /// The hex-encoded private key to import
+    #[arg(
+        long,
+        env = "SEQUENCER_PRIVATE_KEY",
+        required_unless_present = "mnemonic"
+    )]
+    pub(crate) private_key: Option<String>,
+    /// Derive the key from a BIP39 mnemonic instead, read from `ASTRIA_MNEMONIC` or prompted
+    /// for together with its optional passphrase. Takes precedence over `--private-key`
+    #[arg(long)]
+    pub(crate) mnemonic: bool,
+    /// The SLIP-10 derivation path of the key derived from the mnemonic
+    #[arg(long, default_value = "m/44'/118'/0'/0'/0'", requires = "mnemonic")]
+    pub(crate) derivation_path: DerivationPath,
+    /// The bech32m prefix used to display the address of the key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+}
+
+#[derive(Args, Debug)]
+pub struct ExportArgs {
+    /// The name of the key to export
+    pub(crate) name: String,
+    #[command(flatten)]
+    pub(crate) keystore: KeystoreArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct ListArgs {
+    #[command(flatten)]
+    pub(crate) keystore: KeystoreArgs,
+    /// The bech32m prefix used to display the addresses of the keys
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+}
+
+#[derive(Args, Debug)]
+pub struct SignArgs {
+    /// The name of the key to sign with
+    pub(crate) name: String,
+    #[command(flatten)]
+    pub(crate) keystore: KeystoreArgs,
+    /// The message to sign
+    pub(crate) message: String,
+    /// Interpret the message as hex-encoded bytes instead of a UTF-8 string
+    #[arg(long)]
+    pub(crate) hex: bool,
+}
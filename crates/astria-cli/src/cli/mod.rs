@@ -1,5 +1,12 @@
+pub(crate) mod address_book;
+pub(crate) mod bridge;
+pub(crate) mod completions;
+pub(crate) mod config;
+pub(crate) mod decode;
+pub(crate) mod keys;
 pub(crate) mod rollup;
 pub(crate) mod sequencer;
+pub(crate) mod status;
 
 use clap::{
     Parser,
@@ -7,13 +14,24 @@ use clap::{
 };
 use color_eyre::eyre;
 
-use crate::cli::{
-    rollup::Command as RollupCommand,
-    sequencer::Command as SequencerCommand,
+use crate::{
+    cli::{
+        address_book::Command as AddressBookCommand,
+        bridge::Command as BridgeCommand,
+        completions::CompletionsArgs,
+        config::Command as ConfigCommand,
+        decode::DecodeArgs,
+        keys::Command as KeysCommand,
+        rollup::Command as RollupCommand,
+        sequencer::Command as SequencerCommand,
+        status::StatusArgs,
+    },
+    output::Format,
 };
 
 const DEFAULT_SEQUENCER_RPC: &str = "https://rpc.sequencer.dusk-7.devnet.astria.org";
 const DEFAULT_SEQUENCER_CHAIN_ID: &str = "astria-dusk-7";
+const DEFAULT_SEQUENCER_GRPC: &str = "https://grpc.sequencer.dusk-7.devnet.astria.org/";
 
 /// A CLI for deploying and managing Astria services and related infrastructure.
 #[derive(Debug, Parser)]
@@ -21,6 +39,9 @@ const DEFAULT_SEQUENCER_CHAIN_ID: &str = "astria-dusk-7";
 pub struct Cli {
     #[command(subcommand)]
     pub command: Option<Command>,
+    /// The format to print command results in
+    #[arg(long, global = true, value_enum, default_value_t)]
+    pub output: Format,
 }
 
 impl Cli {
@@ -38,6 +59,27 @@ impl Cli {
 /// Commands that can be run
 #[derive(Debug, Subcommand)]
 pub enum Command {
+    #[command(name = "addressbook")]
+    AddressBook {
+        #[command(subcommand)]
+        command: AddressBookCommand,
+    },
+    Bridge {
+        #[command(subcommand)]
+        command: BridgeCommand,
+    },
+    /// Generate shell completions or man pages
+    Completions(CompletionsArgs),
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+    /// Decode and pretty-print a base64- or hex-encoded Astria protobuf message
+    Decode(DecodeArgs),
+    Keys {
+        #[command(subcommand)]
+        command: KeysCommand,
+    },
     Rollup {
         #[command(subcommand)]
         command: RollupCommand,
@@ -46,4 +88,6 @@ pub enum Command {
         #[command(subcommand)]
         command: SequencerCommand,
     },
+    /// Print a health and lag overview of the Sequencer, relayer, and a rollup node
+    Status(StatusArgs),
 }
@@ -1,5 +1,9 @@
-use std::str::FromStr;
+use std::{
+    path::PathBuf,
+    str::FromStr,
+};
 
+use astria_core::primitive::v1::asset::Denom;
 use clap::{
     Args,
     Subcommand,
@@ -7,9 +11,13 @@ use clap::{
 use color_eyre::eyre;
 use serde::Serialize;
 
+use crate::cli::sequencer::{
+    SignerArgs,
+    WaitArgs,
+};
+
 const DEFAULT_ROLLUP_CHART_PATH: &str =
     "https://github.com/astriaorg/charts/releases/download/evm-rollup-0.18.4/evm-rollup-0.18.4.tgz";
-const DEFAULT_SEQUENCER_GRPC: &str = "https://grpc.sequencer.dusk-7.devnet.astria.org/";
 const DEFAULT_LOG_LEVEL: &str = "debug";
 const DEFAULT_NETWORK_ID: u64 = 1337;
 const DEFAULT_EXECUTION_COMMIT_LEVEL: &str = "SoftOnly";
@@ -39,6 +47,8 @@ pub enum Command {
         #[command(subcommand)]
         command: DeploymentCommand,
     },
+    /// Derive the ID of a new rollup and generate composer and conductor config for it
+    Init(InitArgs),
 }
 
 /// Commands for managing rollup configs.
@@ -112,7 +122,7 @@ pub struct ConfigCreateArgs {
     #[arg(
         long = "sequencer.grpc",
         env = "ROLLUP_SEQUENCER_GRPC",
-        default_value = DEFAULT_SEQUENCER_GRPC
+        default_value = crate::cli::DEFAULT_SEQUENCER_GRPC
     )]
     pub sequencer_grpc: String,
     /// Optional. If not set, will be default to the devnet sequencer rpc address
@@ -270,6 +280,64 @@ pub struct DeploymentDeleteArgs {
     pub(crate) config_path: String,
 }
 
+#[derive(Args, Debug)]
+pub struct InitArgs {
+    /// The name of the rollup, from which its ID is derived
+    pub(crate) name: String,
+    /// The websocket url of the rollup node that composer collects transactions from
+    #[arg(long, default_value = "ws://127.0.0.1:8545")]
+    pub(crate) rollup_ws_url: String,
+    /// The url of the execution API of the rollup node that conductor drives
+    #[arg(long, default_value = "http://127.0.0.1:50051")]
+    pub(crate) execution_rpc_url: String,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The gRPC endpoint of the Sequencer node
+    #[arg(
+        long = "sequencer.grpc",
+        env = "SEQUENCER_GRPC",
+        default_value = crate::cli::DEFAULT_SEQUENCER_GRPC
+    )]
+    pub(crate) sequencer_grpc: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// The bech32m prefix of Sequencer addresses
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    /// The directory to write `<NAME>-composer.env` and `<NAME>-conductor.env` to. The config
+    /// is only printed if not set
+    #[arg(long)]
+    pub(crate) output_dir: Option<PathBuf>,
+    /// Submit an initial sequence action for the rollup so that it shows up as active on the
+    /// Sequencer
+    #[arg(long)]
+    pub(crate) submit: bool,
+    /// The hex-encoded data of the initial sequence action
+    #[arg(long, default_value = "", requires = "submit")]
+    pub(crate) data: String,
+    /// The asset used to pay the fee of the initial sequence action
+    #[arg(long, default_value = "nria", requires = "submit")]
+    pub(crate) fee_asset: Denom,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// Sign the initial sequence action and print it instead of broadcasting it without
+    /// simulating its execution
+    #[arg(long, requires = "submit")]
+    pub(crate) dry_run: bool,
+    #[command(flatten)]
+    pub(crate) wait: WaitArgs,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,9 +1,20 @@
+use std::{
+    path::PathBuf,
+    time::Duration,
+};
+
+use astria_core::primitive::v1::asset::Denom;
 use astria_sequencer_client::Address;
 use clap::{
     Args,
     Subcommand,
 };
 
+use crate::{
+    address_book::parse_address,
+    cli::keys::KeystoreArgs,
+};
+
 /// Interact with a Sequencer node
 #[derive(Debug, Subcommand)]
 pub enum Command {
@@ -35,10 +46,35 @@ pub enum Command {
     },
     /// Command for sending balance between accounts
     Transfer(TransferArgs),
+    /// Send the transfers listed in a CSV file of `address,amount[,asset]` rows
+    TransferBatch(TransferBatchArgs),
     /// Command for initializing a bridge account
     InitBridgeAccount(InitBridgeAccountArgs),
     /// Command for transferring to a bridge account
     BridgeLock(BridgeLockArgs),
+    /// Interactively compose a multi-action transaction without signing or submitting it
+    Compose(ComposeArgs),
+    /// Sign a transaction written by `compose`
+    Sign(SignArgs),
+    /// Broadcast a transaction signed with `sign`
+    Submit(SubmitArgs),
+    /// Query Sequencer state
+    Query {
+        #[command(subcommand)]
+        command: QueryCommand,
+    },
+    /// Inspect the IBC clients, connections, and channels of the Sequencer
+    Ibc {
+        #[command(subcommand)]
+        command: IbcCommand,
+    },
+    /// Inspect the validator set of the Sequencer
+    Validator {
+        #[command(subcommand)]
+        command: ValidatorCommand,
+    },
+    /// Show the fee components of the Sequencer and the fees paid over a range of blocks
+    Fees(FeesArgs),
 }
 
 #[derive(Debug, Subcommand)]
@@ -59,6 +95,8 @@ pub enum AddressCommand {
 pub enum BalanceCommand {
     /// Get the balance of a Sequencer account
     Get(BasicAccountArgs),
+    /// Watch the balance of a Sequencer account, printing changes as they happen
+    Watch(BalanceWatchArgs),
 }
 
 #[derive(Debug, Subcommand)]
@@ -73,6 +111,8 @@ pub enum SudoCommand {
     },
     SudoAddressChange(SudoAddressChangeArgs),
     ValidatorUpdate(ValidatorUpdateArgs),
+    /// Submit a sudo action previously printed with `--preview`
+    Submit(SudoSubmitArgs),
 }
 
 #[derive(Debug, Subcommand)]
@@ -91,6 +131,87 @@ pub enum FeeAssetChangeCommand {
     Remove(FeeAssetChangeArgs),
 }
 
+#[derive(Debug, Subcommand)]
+pub enum QueryCommand {
+    /// Get the denomination of an asset
+    Denom(DenomQueryArgs),
+    /// List the assets allowed for fee payment
+    FeeAssets(QueryArgs),
+    /// Get the hash of the last transaction sent by a bridge account
+    BridgeLastTxHash(BridgeLastTxHashQueryArgs),
+    /// Send an arbitrary ABCI query and print the raw response
+    Raw(RawQueryArgs),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum IbcCommand {
+    /// List the IBC light clients, flagging expired and frozen ones
+    Clients(IbcArgs),
+    /// List the IBC connections
+    Connections(IbcArgs),
+    /// List the IBC channels
+    Channels(IbcArgs),
+    /// Withdraw funds over an ICS20 channel after checking the channel, the balances, the
+    /// destination address, and the timeout
+    Withdraw(Ics20WithdrawalArgs),
+}
+
+#[derive(Debug, Subcommand)]
+pub enum ValidatorCommand {
+    /// List the validator set at a height, or at the latest height if none is given
+    List(ValidatorListArgs),
+    /// List the validator updates that were committed but have not taken effect yet, together
+    /// with the heights at which they take effect
+    Pending(QueryArgs),
+}
+
+/// The key used to sign a transaction
+///
+/// Either a Ledger device, the name of a key in the keystore, or a raw private key must be
+/// provided.
+#[derive(Args, Debug)]
+pub struct SignerArgs {
+    /// The name of the keystore key to sign with
+    #[arg(long, env = "SEQUENCER_KEY_NAME", conflicts_with = "private_key")]
+    pub(crate) key: Option<String>,
+    #[command(flatten)]
+    pub(crate) keystore: KeystoreArgs,
+    /// The hex-encoded private key to sign with
+    // TODO: https://github.com/astriaorg/astria/issues/594
+    // Don't use a plain text private, prefer wrapper like from
+    // the secrecy crate with specialized `Debug` and `Drop` implementations
+    // that overwrite the key on drop and don't reveal it when printing.
+    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
+    pub(crate) private_key: Option<String>,
+    /// Sign with the Astria app on a Ledger hardware wallet. Takes precedence over `--key` and
+    /// `--private-key`
+    #[arg(long)]
+    pub(crate) ledger: bool,
+    /// The BIP-44 derivation path of the Ledger key. All components must be hardened
+    #[arg(long, default_value = "m/44'/118'/0'/0'/0'")]
+    pub(crate) ledger_path: String,
+}
+
+/// Whether to wait for a submitted transaction to be included in a block
+#[derive(Args, Debug)]
+pub struct WaitArgs {
+    /// Wait until the transaction is included in a block and print the gas it used and the
+    /// events it emitted. Otherwise the command returns once the transaction was accepted into
+    /// the mempool
+    #[arg(long)]
+    pub(crate) wait: bool,
+    /// How long to wait for the transaction to be included when `--wait` is set (e.g. `30s`)
+    #[arg(long, default_value = "30s", value_parser = humantime::parse_duration)]
+    pub(crate) timeout: Duration,
+}
+
+impl WaitArgs {
+    /// Returns how long to wait for inclusion, or `None` if `--wait` is not set.
+    pub(crate) fn inclusion_timeout(&self) -> Option<Duration> {
+        self.wait.then_some(self.timeout)
+    }
+}
+
 #[derive(Args, Debug)]
 pub struct BasicAccountArgs {
     /// The url of the Sequencer node
@@ -101,7 +222,25 @@ pub struct BasicAccountArgs {
     )]
     pub(crate) sequencer_url: String,
     /// The address of the Sequencer account
+    #[arg(value_parser = parse_address)]
+    pub(crate) address: Address,
+}
+
+#[derive(Args, Debug)]
+pub struct BalanceWatchArgs {
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The address of the Sequencer account
+    #[arg(value_parser = parse_address)]
     pub(crate) address: Address,
+    /// The interval at which the balance is polled (e.g. `500ms`, `2s`)
+    #[arg(long, default_value = "2s", value_parser = humantime::parse_duration)]
+    pub(crate) interval: Duration,
 }
 
 #[derive(Args, Debug)]
@@ -117,6 +256,7 @@ pub struct Bech32mAddressArgs {
 #[derive(Args, Debug)]
 pub struct TransferArgs {
     // The address of the Sequencer account to send amount to
+    #[arg(value_parser = parse_address)]
     pub(crate) to_address: Address,
     // The amount being sent
     #[arg(long)]
@@ -124,13 +264,14 @@ pub struct TransferArgs {
     /// The bech32m prefix that will be used for constructing addresses using the private key
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    /// The private key of account being sent from
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    pub(crate) private_key: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// Sign the transaction and print it instead of broadcasting it without
+    /// simulating its execution
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+    #[command(flatten)]
+    pub(crate) wait: WaitArgs,
     /// The url of the Sequencer node
     #[arg(
         long,
@@ -147,17 +288,65 @@ pub struct TransferArgs {
     pub sequencer_chain_id: String,
 }
 
+#[derive(Args, Debug)]
+pub struct TransferBatchArgs {
+    /// The CSV file listing the transfers as `address,amount[,asset]` rows. The asset
+    /// defaults to the native asset
+    pub(crate) csv: PathBuf,
+    /// The asset used to pay the fees of the transfers
+    #[arg(long, default_value = "nria")]
+    pub(crate) fee_asset: Denom,
+    /// The maximum size of the encoded actions packed into a single transaction
+    #[arg(long, default_value = "250000")]
+    pub(crate) max_tx_bytes: usize,
+    /// Send the transfers without asking for confirmation
+    #[arg(long, short)]
+    pub(crate) yes: bool,
+    /// The bech32m prefix that will be used for constructing addresses using the private key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// Sign the transactions and print them instead of broadcasting them without
+    /// simulating their execution
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+    /// How long to wait for each transaction to be included in a block (e.g. `30s`)
+    #[arg(long, default_value = "30s", value_parser = humantime::parse_duration)]
+    pub(crate) timeout: Duration,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+}
+
 #[derive(Args, Debug)]
 pub struct FeeAssetChangeArgs {
     /// The bech32m prefix that will be used for constructing addresses using the private key
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    pub(crate) private_key: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// Sign the transaction and print it instead of broadcasting it without
+    /// simulating its execution
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+    #[command(flatten)]
+    pub(crate) wait: WaitArgs,
+    /// Print the encoded action as pbjson and base64-encoded protobuf instead of signing and
+    /// submitting it. The printed action can be submitted later with `sudo submit --from-file`
+    #[arg(long, conflicts_with = "dry_run")]
+    pub(crate) preview: bool,
     /// The url of the Sequencer node
     #[arg(
         long,
@@ -182,12 +371,18 @@ pub struct IbcRelayerChangeArgs {
     /// The prefix to construct a bech32m address given the private key.
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    pub(crate) private_key: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// Sign the transaction and print it instead of broadcasting it without
+    /// simulating its execution
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+    #[command(flatten)]
+    pub(crate) wait: WaitArgs,
+    /// Print the encoded action as pbjson and base64-encoded protobuf instead of signing and
+    /// submitting it. The printed action can be submitted later with `sudo submit --from-file`
+    #[arg(long, conflicts_with = "dry_run")]
+    pub(crate) preview: bool,
     /// The url of the Sequencer node
     #[arg(
         long,
@@ -203,7 +398,7 @@ pub struct IbcRelayerChangeArgs {
     )]
     pub sequencer_chain_id: String,
     /// The address to add or remove as an IBC relayer
-    #[arg(long)]
+    #[arg(long, value_parser = parse_address)]
     pub(crate) address: Address,
 }
 
@@ -212,12 +407,14 @@ pub struct InitBridgeAccountArgs {
     /// The bech32m prefix that will be used for constructing addresses using the private key
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    pub(crate) private_key: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// Sign the transaction and print it instead of broadcasting it without
+    /// simulating its execution
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+    #[command(flatten)]
+    pub(crate) wait: WaitArgs,
     /// The url of the Sequencer node
     #[arg(
         long,
@@ -241,6 +438,7 @@ pub struct InitBridgeAccountArgs {
 #[derive(Args, Debug)]
 pub struct BridgeLockArgs {
     /// The address of the Sequencer account to lock amount to
+    #[arg(value_parser = parse_address)]
     pub(crate) to_address: Address,
     /// The amount being locked
     #[arg(long)]
@@ -250,12 +448,14 @@ pub struct BridgeLockArgs {
     /// The prefix to construct a bech32m address given the private key.
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    pub(crate) private_key: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// Sign the transaction and print it instead of broadcasting it without
+    /// simulating its execution
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+    #[command(flatten)]
+    pub(crate) wait: WaitArgs,
     /// The url of the Sequencer node
     #[arg(
         long,
@@ -276,6 +476,8 @@ pub struct BridgeLockArgs {
 pub enum BlockHeightCommand {
     /// Get the current block height of the Sequencer node
     Get(BlockHeightGetArgs),
+    /// Watch the block height of the Sequencer node, printing each new height
+    Watch(BlockHeightWatchArgs),
 }
 
 #[derive(Args, Debug)]
@@ -296,17 +498,41 @@ pub struct BlockHeightGetArgs {
     pub sequencer_chain_id: String,
 }
 
+#[derive(Args, Debug)]
+pub struct BlockHeightWatchArgs {
+    /// The url of the Sequencer node.
+    ///
+    /// If a websocket url (`ws://` or `wss://`) is given, new blocks are subscribed to instead of
+    /// polled.
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The interval at which the block height is polled (e.g. `500ms`, `2s`). Ignored when
+    /// subscribing over a websocket.
+    #[arg(long, default_value = "1s", value_parser = humantime::parse_duration)]
+    pub(crate) interval: Duration,
+}
+
 #[derive(Args, Debug)]
 pub struct SudoAddressChangeArgs {
     /// The bech32m prefix that will be used for constructing addresses using the private key
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    pub(crate) private_key: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// Sign the transaction and print it instead of broadcasting it without
+    /// simulating its execution
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+    #[command(flatten)]
+    pub(crate) wait: WaitArgs,
+    /// Print the encoded action as pbjson and base64-encoded protobuf instead of signing and
+    /// submitting it. The printed action can be submitted later with `sudo submit --from-file`
+    #[arg(long, conflicts_with = "dry_run")]
+    pub(crate) preview: bool,
     /// The url of the Sequencer node
     #[arg(
         long,
@@ -322,7 +548,7 @@ pub struct SudoAddressChangeArgs {
     )]
     pub sequencer_chain_id: String,
     /// The new address to take over sudo privileges
-    #[arg(long)]
+    #[arg(long, value_parser = parse_address)]
     pub(crate) address: Address,
 }
 
@@ -345,17 +571,283 @@ pub struct ValidatorUpdateArgs {
     /// The bech32m prefix that will be used for constructing addresses using the private key
     #[arg(long, default_value = "astria")]
     pub(crate) prefix: String,
-    /// The private key of the sudo account authorizing change
-    #[arg(long, env = "SEQUENCER_PRIVATE_KEY")]
-    // TODO: https://github.com/astriaorg/astria/issues/594
-    // Don't use a plain text private, prefer wrapper like from
-    // the secrecy crate with specialized `Debug` and `Drop` implementations
-    // that overwrite the key on drop and don't reveal it when printing.
-    pub(crate) private_key: String,
-    /// The address of the Validator being updated
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// Sign the transaction and print it instead of broadcasting it without
+    /// simulating its execution
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+    #[command(flatten)]
+    pub(crate) wait: WaitArgs,
+    /// Print the encoded action as pbjson and base64-encoded protobuf instead of signing and
+    /// submitting it. The printed action can be submitted later with `sudo submit --from-file`
+    #[arg(long, conflicts_with = "dry_run")]
+    pub(crate) preview: bool,
+    /// The ed25519 public key of the validator being updated, hex or base64 encoded (as found
+    /// in CometBFT's `priv_validator_key.json`)
     #[arg(long)]
     pub(crate) validator_public_key: String,
-    /// The power the validator is being updated to
+    /// The power the validator is being updated to. A power of 0 removes the validator from
+    /// the validator set
     #[arg(long)]
     pub(crate) power: u32,
 }
+
+#[derive(Args, Debug)]
+pub struct SudoSubmitArgs {
+    /// The file holding the action as pbjson or as base64-encoded protobuf, as printed by
+    /// `--preview`
+    #[arg(long)]
+    pub(crate) from_file: PathBuf,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// The bech32m prefix that will be used for constructing addresses using the private key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// Sign the transaction and print it instead of broadcasting it without
+    /// simulating its execution
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+    #[command(flatten)]
+    pub(crate) wait: WaitArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct ComposeArgs {
+    /// The url of the Sequencer node, used to fetch account nonces
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain the transaction is composed for
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub(crate) sequencer_chain_id: String,
+    /// The initial nonce of the transaction
+    #[arg(long, default_value = "0")]
+    pub(crate) nonce: u32,
+    /// The file to write the composed transaction to as pbjson. Printed to stdout if not set
+    #[arg(long)]
+    pub(crate) output_file: Option<PathBuf>,
+}
+
+#[derive(Args, Debug)]
+pub struct SignArgs {
+    /// The file holding the unsigned transaction as pbjson, as written by `compose`
+    pub(crate) input: PathBuf,
+    /// The file to write the signed transaction to as pbjson. Printed to stdout if not set
+    #[arg(long)]
+    pub(crate) output_file: Option<PathBuf>,
+    /// The sha256 of the unsigned transaction printed by `compose`. Signing fails if the
+    /// transaction read from `INPUT` does not match
+    #[arg(long)]
+    pub(crate) expect_sha256: Option<String>,
+    /// Sign the transaction as is without contacting a Sequencer node. Otherwise the nonce of
+    /// the transaction is replaced by the latest nonce of the signer
+    #[arg(long)]
+    pub(crate) offline: bool,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// The bech32m prefix that will be used for constructing addresses using the private key
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    /// The url of the Sequencer node to fetch the nonce from. Ignored with `--offline`
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+}
+
+#[derive(Args, Debug)]
+pub struct SubmitArgs {
+    /// The file holding the signed transaction as pbjson, as written by `sign`
+    pub(crate) input: PathBuf,
+    /// The transaction hash printed by `sign`. Submission fails if the transaction read from
+    /// `INPUT` does not match
+    #[arg(long)]
+    pub(crate) expect_sha256: Option<String>,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    #[command(flatten)]
+    pub(crate) wait: WaitArgs,
+}
+
+#[derive(Args, Debug)]
+pub struct QueryArgs {
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+}
+
+#[derive(Args, Debug)]
+pub struct ValidatorListArgs {
+    #[command(flatten)]
+    pub(crate) query: QueryArgs,
+    /// The height to list the validator set at
+    #[arg(long)]
+    pub(crate) height: Option<u32>,
+}
+
+#[derive(Args, Debug)]
+pub struct FeesArgs {
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The last height to scan. Defaults to the latest height
+    #[arg(long)]
+    pub(crate) to_height: Option<u32>,
+    /// The number of blocks to scan, ending at `--to-height`
+    #[arg(long, default_value = "100")]
+    pub(crate) blocks: u32,
+}
+
+#[derive(Args, Debug)]
+pub struct IbcArgs {
+    /// The url of the gRPC server of the Sequencer node
+    #[arg(
+        long = "sequencer.grpc",
+        env = "SEQUENCER_GRPC",
+        default_value = crate::cli::DEFAULT_SEQUENCER_GRPC
+    )]
+    pub(crate) sequencer_grpc: String,
+}
+
+#[derive(Args, Debug)]
+pub struct Ics20WithdrawalArgs {
+    /// The address on the destination chain to send the funds to
+    pub(crate) destination_chain_address: String,
+    /// The amount being withdrawn
+    #[arg(long)]
+    pub(crate) amount: u128,
+    /// The denomination of the asset being withdrawn
+    #[arg(long, default_value = "nria")]
+    pub(crate) denom: Denom,
+    /// The ICS20 channel to withdraw over, e.g. `channel-0`
+    #[arg(long)]
+    pub(crate) source_channel: String,
+    /// The asset used to pay the fee of the withdrawal
+    #[arg(long, default_value = "nria")]
+    pub(crate) fee_asset: Denom,
+    /// The Sequencer address funds are returned to if the withdrawal fails. Defaults to the
+    /// address of the signer
+    #[arg(long, value_parser = parse_address)]
+    pub(crate) return_address: Option<Address>,
+    /// The bridge account to withdraw from if the signer is its withdrawer
+    #[arg(long, value_parser = parse_address)]
+    pub(crate) bridge_address: Option<Address>,
+    /// The time after which the withdrawal times out on the destination chain
+    #[arg(long, default_value = "1h", value_parser = humantime::parse_duration)]
+    pub(crate) timeout: Duration,
+    /// The height of the destination chain at which the withdrawal times out, given as
+    /// `<revision-number>-<revision-height>`. Only the timeout time applies if not set
+    #[arg(long)]
+    pub(crate) timeout_height: Option<String>,
+    /// A memo to include with the withdrawal
+    #[arg(long, default_value = "")]
+    pub(crate) memo: String,
+    /// The bech32 prefix of addresses on the destination chain. Inferred from the chain ID of
+    /// the counterparty for well-known chains
+    #[arg(long)]
+    pub(crate) destination_prefix: Option<String>,
+    /// Only run the preflight checks without submitting the withdrawal
+    #[arg(long)]
+    pub(crate) preflight_only: bool,
+    /// Submit the withdrawal even if a preflight check failed
+    #[arg(long, conflicts_with = "preflight_only")]
+    pub(crate) force: bool,
+    #[command(flatten)]
+    pub(crate) ibc: IbcArgs,
+    /// The prefix to construct a bech32m address given the private key.
+    #[arg(long, default_value = "astria")]
+    pub(crate) prefix: String,
+    #[command(flatten)]
+    pub(crate) signer: SignerArgs,
+    /// Sign the transaction and print it instead of broadcasting it without
+    /// simulating its execution
+    #[arg(long)]
+    pub(crate) dry_run: bool,
+    #[command(flatten)]
+    pub(crate) wait: WaitArgs,
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The chain id of the sequencing chain being used
+    #[arg(
+        long = "sequencer.chain-id",
+        env = "ROLLUP_SEQUENCER_CHAIN_ID",
+        default_value = crate::cli::DEFAULT_SEQUENCER_CHAIN_ID
+    )]
+    pub sequencer_chain_id: String,
+}
+
+#[derive(Args, Debug)]
+pub struct DenomQueryArgs {
+    #[command(flatten)]
+    pub(crate) query: QueryArgs,
+    /// The hex-encoded asset ID, or a denomination whose ID is looked up
+    pub(crate) asset: String,
+}
+
+#[derive(Args, Debug)]
+pub struct BridgeLastTxHashQueryArgs {
+    #[command(flatten)]
+    pub(crate) query: QueryArgs,
+    /// The address of the bridge account
+    #[arg(value_parser = parse_address)]
+    pub(crate) address: Address,
+}
+
+#[derive(Args, Debug)]
+pub struct RawQueryArgs {
+    #[command(flatten)]
+    pub(crate) query: QueryArgs,
+    /// The ABCI query path, e.g. `accounts/nonce/<ADDRESS>`
+    pub(crate) path: String,
+    /// Hex-encoded data sent with the query
+    #[arg(long, default_value = "")]
+    pub(crate) data: String,
+    /// The height to query at. Queries the latest height if not set
+    #[arg(long)]
+    pub(crate) height: Option<u32>,
+    /// Request a proof of the response
+    #[arg(long)]
+    pub(crate) prove: bool,
+}
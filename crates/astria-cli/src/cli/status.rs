@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+use clap::Args;
+
+#[derive(Args, Debug)]
+pub struct StatusArgs {
+    /// The url of the Sequencer node
+    #[arg(
+        long,
+        env = "SEQUENCER_URL",
+        default_value = crate::cli::DEFAULT_SEQUENCER_RPC
+    )]
+    pub(crate) sequencer_url: String,
+    /// The url of the API of the sequencer-relayer posting the Sequencer's blocks to Celestia.
+    /// The relayer is left out of the overview if not set
+    #[arg(long, env = "RELAYER_API_URL")]
+    pub(crate) relayer_url: Option<String>,
+    /// The url of the execution API of a rollup node driven by conductor. The rollup is left
+    /// out of the overview if not set
+    #[arg(long, env = "ROLLUP_EXECUTION_RPC_URL")]
+    pub(crate) execution_rpc_url: Option<String>,
+    /// The age of the latest block above which a chain is reported as lagging
+    #[arg(long, default_value = "30s", value_parser = humantime::parse_duration)]
+    pub(crate) max_block_age: Duration,
+}
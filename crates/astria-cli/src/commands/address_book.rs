@@ -0,0 +1,168 @@
+use std::fmt::Display;
+
+use astria_core::primitive::v1::Address;
+use color_eyre::eyre;
+use serde::Serialize;
+
+use crate::{
+    address_book::AddressBook,
+    cli::address_book::{
+        AddArgs,
+        AddressBookArgs,
+        NameArgs,
+    },
+    output::{
+        self,
+        Report,
+    },
+};
+
+/// Stores an address under a name in the address book
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the address book cannot be opened
+/// * If the name is invalid or taken, or the address has an unexpected prefix
+/// * If the address book cannot be written to disk
+pub(crate) fn add(args: &AddArgs) -> eyre::Result<()> {
+    let mut book = AddressBook::open(args.address_book.address_book.as_deref())?;
+    book.insert(&args.name, args.address, &args.prefix, args.force)?;
+    output::emit(&EntryAction {
+        action: "Stored",
+        entry: Entry {
+            name: args.name.clone(),
+            address: args.address,
+        },
+    })
+}
+
+/// Removes a named address from the address book
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the address book cannot be opened
+/// * If there is no address with the given name
+/// * If the address book cannot be written to disk
+pub(crate) fn remove(args: &NameArgs) -> eyre::Result<()> {
+    let mut book = AddressBook::open(args.address_book.address_book.as_deref())?;
+    let address = book.remove(&args.name)?;
+    output::emit(&EntryAction {
+        action: "Removed",
+        entry: Entry {
+            name: args.name.clone(),
+            address,
+        },
+    })
+}
+
+/// Prints the address stored under a name in the address book
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the address book cannot be opened
+/// * If there is no address with the given name
+pub(crate) fn show(args: &NameArgs) -> eyre::Result<()> {
+    let book = AddressBook::open(args.address_book.address_book.as_deref())?;
+    output::emit(&Entry {
+        name: args.name.clone(),
+        address: book.get(&args.name)?,
+    })
+}
+
+/// Lists all named addresses in the address book
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the address book cannot be opened
+pub(crate) fn list(args: &AddressBookArgs) -> eyre::Result<()> {
+    let book = AddressBook::open(args.address_book.as_deref())?;
+    output::emit(&Entries {
+        entries: book
+            .entries()
+            .map(|(name, address)| Entry {
+                name: name.to_string(),
+                address,
+            })
+            .collect(),
+    })
+}
+
+#[derive(Serialize)]
+struct Entry {
+    name: String,
+    #[serde(serialize_with = "output::display")]
+    address: Address,
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.address)
+    }
+}
+
+impl Report for Entry {
+    const KIND: &'static str = "address_book_entry";
+}
+
+/// An entry that was added to or removed from the address book
+#[derive(Serialize)]
+struct EntryAction {
+    #[serde(skip)]
+    action: &'static str,
+    #[serde(flatten)]
+    entry: Entry,
+}
+
+impl Display for EntryAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} `{}` as @{}",
+            self.action, self.entry.address, self.entry.name
+        )
+    }
+}
+
+impl Report for EntryAction {
+    const KIND: &'static str = "address_book_entry";
+}
+
+#[derive(Serialize)]
+struct Entries {
+    entries: Vec<Entry>,
+}
+
+impl Display for Entries {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.entries.is_empty() {
+            return write!(f, "The address book is empty");
+        }
+        for (index, entry) in self.entries.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "@{}: {}", entry.name, entry.address)?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for Entries {
+    const KIND: &'static str = "address_book";
+}
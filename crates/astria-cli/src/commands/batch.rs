@@ -0,0 +1,342 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    io::{
+        self,
+        Write as _,
+    },
+};
+
+use astria_core::{
+    primitive::v1::{
+        asset::{
+            self,
+            Denom,
+        },
+        Address,
+    },
+    protocol::transaction::v1alpha1::{
+        action::TransferAction,
+        Action,
+    },
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        bail,
+        ensure,
+        Context,
+    },
+};
+use prost::Message as _;
+use serde::Serialize;
+
+use super::sequencer::{
+    submit_actions,
+    Submitted,
+};
+use crate::{
+    cli::sequencer::TransferBatchArgs,
+    output::{
+        self,
+        Report,
+    },
+    signer::Signer,
+};
+
+/// A single transfer read from the CSV file
+#[derive(Debug, PartialEq, Serialize)]
+struct Row {
+    /// The 1-based line number of the row in the CSV file
+    line: usize,
+    #[serde(serialize_with = "output::display")]
+    to: Address,
+    #[serde(serialize_with = "output::display")]
+    amount: u128,
+    #[serde(serialize_with = "output::display")]
+    asset: Denom,
+}
+
+/// The result of submitting one transaction of the batch
+#[derive(Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum Outcome {
+    Included { height: u64, tx_hash: String },
+    Failed { error: String },
+}
+
+#[derive(Serialize)]
+struct BatchTransaction {
+    index: usize,
+    #[serde(flatten)]
+    outcome: Outcome,
+    transfers: Vec<Row>,
+}
+
+impl Display for BatchTransaction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let verb = match &self.outcome {
+            Outcome::Included {
+                height,
+                tx_hash,
+            } => {
+                write!(
+                    f,
+                    "transaction {} included in block {height} with hash {tx_hash}",
+                    self.index
+                )?;
+                "sent"
+            }
+            Outcome::Failed {
+                error,
+            } => {
+                write!(f, "transaction {} failed: {error}", self.index)?;
+                "not sent"
+            }
+        };
+        for row in &self.transfers {
+            write!(
+                f,
+                "\n    line {}: {verb} {} {} to {}",
+                row.line, row.amount, row.asset, row.to
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for BatchTransaction {
+    const KIND: &'static str = "batch_transaction";
+}
+
+/// Sends the transfers listed in a CSV file, packing as many as fit into each transaction
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the CSV file cannot be read or contains an invalid row
+/// * If the user does not confirm the transfers
+/// * If any of the transactions failed to be included
+pub(crate) async fn transfer_batch(args: &TransferBatchArgs) -> eyre::Result<()> {
+    let contents = std::fs::read_to_string(&args.csv)
+        .wrap_err_with(|| format!("failed reading `{}`", args.csv.display()))?;
+    let rows = parse_csv(&contents)?;
+    ensure!(
+        !rows.is_empty(),
+        "`{}` contains no transfers",
+        args.csv.display()
+    );
+    let fee_asset_id = args.fee_asset.id();
+    let batches = into_batches(rows, fee_asset_id, args.max_tx_bytes)?;
+
+    print_summary(&batches);
+    if !args.yes && !args.dry_run && !confirm()? {
+        bail!("aborted by user");
+    }
+
+    let signer = Signer::from_args(&args.signer)?;
+    let mut failed_rows = 0usize;
+    for (index, batch) in batches.into_iter().enumerate() {
+        let (rows, actions): (Vec<_>, Vec<_>) = batch.into_iter().unzip();
+        let result = submit_actions(
+            args.sequencer_url.as_str(),
+            args.sequencer_chain_id.clone(),
+            &args.prefix,
+            &signer,
+            args.dry_run,
+            Some(args.timeout),
+            actions,
+        )
+        .await;
+        let outcome = match result {
+            Ok(Some(Submitted {
+                hash,
+                inclusion: Some(res),
+            })) => Outcome::Included {
+                height: res.height.value(),
+                tx_hash: hex::encode(hash.as_bytes()),
+            },
+            Ok(Some(Submitted {
+                inclusion: None, ..
+            })) => unreachable!("the inclusion of every batch transaction is waited for"),
+            Ok(None) => continue,
+            Err(err) => {
+                failed_rows = failed_rows.saturating_add(rows.len());
+                Outcome::Failed {
+                    error: format!("{err:#}"),
+                }
+            }
+        };
+        output::emit_event(&BatchTransaction {
+            index,
+            outcome,
+            transfers: rows,
+        })?;
+    }
+    ensure!(failed_rows == 0, "{failed_rows} transfers failed");
+    Ok(())
+}
+
+/// Parses rows of the form `address,amount[,asset]`.
+///
+/// Empty lines, lines starting with `#`, and a leading `address,amount[,asset]` header are
+/// skipped. The asset defaults to the native asset.
+fn parse_csv(contents: &str) -> eyre::Result<Vec<Row>> {
+    let mut rows = vec![];
+    for (index, line) in contents.lines().enumerate() {
+        let line_number = index.saturating_add(1);
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<_> = line.split(',').map(str::trim).collect();
+        if rows.is_empty() && fields.first() == Some(&"address") {
+            continue;
+        }
+        let (to, amount, asset) = match fields[..] {
+            [to, amount] => (to, amount, None),
+            [to, amount, asset] => (to, amount, Some(asset)),
+            _ => bail!("line {line_number}: expected `address,amount[,asset]`"),
+        };
+        let to = to
+            .parse()
+            .wrap_err_with(|| format!("line {line_number}: invalid address `{to}`"))?;
+        let amount = amount
+            .parse()
+            .wrap_err_with(|| format!("line {line_number}: invalid amount `{amount}`"))?;
+        let asset = match asset {
+            Some(asset) => asset
+                .parse()
+                .wrap_err_with(|| format!("line {line_number}: invalid asset `{asset}`"))?,
+            None => asset::default_native_asset(),
+        };
+        rows.push(Row {
+            line: line_number,
+            to,
+            amount,
+            asset,
+        });
+    }
+    Ok(rows)
+}
+
+/// Groups `rows` into batches whose encoded actions do not exceed `max_tx_bytes` in total.
+fn into_batches(
+    rows: Vec<Row>,
+    fee_asset_id: asset::Id,
+    max_tx_bytes: usize,
+) -> eyre::Result<Vec<Vec<(Row, Action)>>> {
+    let mut batches: Vec<Vec<(Row, Action)>> = vec![];
+    let mut batch_bytes = 0usize;
+    for row in rows {
+        let action = Action::Transfer(TransferAction {
+            to: row.to,
+            amount: row.amount,
+            asset_id: row.asset.id(),
+            fee_asset_id,
+        });
+        let action_bytes = action.to_raw().encoded_len();
+        ensure!(
+            action_bytes <= max_tx_bytes,
+            "line {}: transfer does not fit into a transaction of {max_tx_bytes} bytes",
+            row.line
+        );
+        let next_batch_bytes = batch_bytes.saturating_add(action_bytes);
+        match batches.last_mut() {
+            Some(batch) if next_batch_bytes <= max_tx_bytes => {
+                batch.push((row, action));
+                batch_bytes = next_batch_bytes;
+            }
+            _ => {
+                batches.push(vec![(row, action)]);
+                batch_bytes = action_bytes;
+            }
+        }
+    }
+    Ok(batches)
+}
+
+fn print_summary(batches: &[Vec<(Row, Action)>]) {
+    let mut totals: BTreeMap<String, u128> = BTreeMap::new();
+    let mut transfers = 0usize;
+    for (row, _) in batches.iter().flatten() {
+        let total = totals.entry(row.asset.to_string()).or_default();
+        *total = total.saturating_add(row.amount);
+        transfers = transfers.saturating_add(1);
+    }
+    output::note(format_args!(
+        "{transfers} transfers in {} transactions, totalling:",
+        batches.len()
+    ));
+    for (asset, total) in totals {
+        output::note(format_args!("    {total} {asset}"));
+    }
+}
+
+fn confirm() -> eyre::Result<bool> {
+    eprint!("Send these transfers? [y/N] ");
+    io::stderr().flush().wrap_err("failed flushing stderr")?;
+    let mut answer = String::new();
+    io::stdin()
+        .read_line(&mut answer)
+        .wrap_err("failed reading confirmation from stdin")?;
+    Ok(matches!(answer.trim(), "y" | "Y" | "yes"))
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::asset;
+    use prost::Message as _;
+
+    use super::{
+        into_batches,
+        parse_csv,
+    };
+
+    const ALICE: &str = "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm";
+    const BOB: &str = "astria1xnlvg0rle2u6auane79t4p27g8hxnj36ja960z";
+
+    #[test]
+    fn csv_rows_are_parsed() {
+        let contents = format!(
+            "address,amount,asset\n# payouts\n{ALICE},10\n\n{BOB}, 20 ,transfer/channel-0/utia\n"
+        );
+        let rows = parse_csv(&contents).unwrap();
+        assert_eq!(2, rows.len());
+        assert_eq!(3, rows[0].line);
+        assert_eq!(10, rows[0].amount);
+        assert_eq!(asset::default_native_asset(), rows[0].asset);
+        assert_eq!(5, rows[1].line);
+        assert_eq!(20, rows[1].amount);
+        assert_eq!("transfer/channel-0/utia", rows[1].asset.to_string());
+    }
+
+    #[test]
+    fn invalid_csv_rows_are_rejected() {
+        parse_csv(&format!("{ALICE}")).unwrap_err();
+        parse_csv(&format!("{ALICE},ten")).unwrap_err();
+        parse_csv("not-an-address,10").unwrap_err();
+        parse_csv(&format!("{ALICE},10,nria,extra")).unwrap_err();
+    }
+
+    #[test]
+    fn batches_respect_size_limit() {
+        let contents: String = (0..10).map(|_| format!("{ALICE},10\n")).collect();
+        let rows = parse_csv(&contents).unwrap();
+        let fee_asset_id = asset::default_native_asset().id();
+        let batches = into_batches(rows, fee_asset_id, usize::MAX).unwrap();
+        assert_eq!(1, batches.len());
+
+        let rows = parse_csv(&contents).unwrap();
+        let action_bytes = batches[0][0].1.to_raw().encoded_len();
+        let batches = into_batches(rows, fee_asset_id, action_bytes * 3).unwrap();
+        let sizes: Vec<_> = batches.iter().map(Vec::len).collect();
+        assert_eq!(vec![3, 3, 3, 1], sizes);
+
+        let rows = parse_csv(&contents).unwrap();
+        into_batches(rows, fee_asset_id, action_bytes - 1).unwrap_err();
+    }
+}
@@ -0,0 +1,443 @@
+use std::fmt::Display;
+
+use astria_core::{
+    generated::protocol::transaction::v1alpha1 as raw,
+    primitive::v1::{
+        asset,
+        Address,
+        RollupId,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1alpha1::{
+        action::InitBridgeAccountAction,
+        Action,
+        SignedTransaction,
+    },
+    sequencerblock::v1alpha1::block_commitment_count_of_data,
+};
+use astria_sequencer_client::{
+    tendermint,
+    Client as _,
+    HttpClient,
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        ensure,
+        Context,
+    },
+};
+use prost::Message as _;
+use serde::Serialize;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+
+use super::sequencer::{
+    submit_transaction,
+    TransactionReceipt,
+};
+use crate::{
+    cli::bridge::{
+        HistoryArgs,
+        RegisterArgs,
+    },
+    output::{
+        self,
+        Report,
+    },
+};
+
+/// Registers the signer as the bridge account of a rollup
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the http client cannot be created
+/// * If the transaction failed to be included
+pub(crate) async fn register(args: &RegisterArgs) -> eyre::Result<()> {
+    let rollup_id = RollupId::from_unhashed_bytes(args.rollup_name.as_bytes());
+    let Some(res) = submit_transaction(
+        args.sequencer_url.as_str(),
+        args.sequencer_chain_id.clone(),
+        &args.prefix,
+        &args.signer,
+        args.dry_run,
+        args.wait.inclusion_timeout(),
+        Action::InitBridgeAccount(InitBridgeAccountAction {
+            rollup_id,
+            asset_id: args.asset.id(),
+            fee_asset_id: args.fee_asset.id(),
+            sudo_address: args.sudo_address,
+            withdrawer_address: args.withdrawer_address,
+        }),
+    )
+    .await
+    .wrap_err("failed to submit InitBridgeAccount transaction")?
+    else {
+        return Ok(());
+    };
+
+    output::emit(&Registration {
+        receipt: TransactionReceipt::new("Bridge account registration", &res),
+        rollup_name: args.rollup_name.clone(),
+        rollup_id,
+        asset: args.asset.to_string(),
+        sudo_address: args.sudo_address.map(|address| address.to_string()),
+        withdrawer_address: args.withdrawer_address.map(|address| address.to_string()),
+    })
+}
+
+#[derive(Serialize)]
+struct Registration {
+    #[serde(flatten)]
+    receipt: TransactionReceipt,
+    rollup_name: String,
+    #[serde(serialize_with = "output::display")]
+    rollup_id: RollupId,
+    asset: String,
+    sudo_address: Option<String>,
+    withdrawer_address: Option<String>,
+}
+
+impl Display for Registration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.receipt)?;
+        writeln!(f, "Rollup name: {}", self.rollup_name)?;
+        writeln!(f, "Rollup ID: {}", self.rollup_id)?;
+        write!(f, "Accepted asset: {}", self.asset)?;
+        if let Some(sudo_address) = &self.sudo_address {
+            write!(f, "\nSudo address: {sudo_address}")?;
+        }
+        if let Some(withdrawer_address) = &self.withdrawer_address {
+            write!(f, "\nWithdrawer address: {withdrawer_address}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for Registration {
+    const KIND: &'static str = "bridge_registration";
+    const SCHEMA_VERSION: u32 = 2;
+}
+
+/// Lists the deposits into and withdrawals out of a bridge account over a range of blocks
+///
+/// Only actions of successfully executed transactions are listed.
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the http client cannot be created
+/// * If a block or its results cannot be retrieved
+/// * If a transaction in a block cannot be decoded
+pub(crate) async fn history(args: &HistoryArgs) -> eyre::Result<()> {
+    ensure!(args.blocks > 0, "`--blocks` must be at least 1");
+    let sequencer_client = HttpClient::new(args.sequencer_url.as_str())
+        .wrap_err("failed constructing http sequencer client")?;
+
+    let to_height = match args.to_height {
+        Some(height) => height,
+        None => sequencer_client
+            .latest_block()
+            .await
+            .wrap_err("failed to get latest cometbft block")?
+            .block
+            .header
+            .height
+            .value()
+            .try_into()
+            .wrap_err("latest height does not fit into u32")?,
+    };
+    let from_height = to_height
+        .saturating_sub(args.blocks.saturating_sub(1))
+        .max(1);
+
+    let mut entries = vec![];
+    for height in from_height..=to_height {
+        let height = tendermint::block::Height::from(height);
+        let block = sequencer_client
+            .block(height)
+            .await
+            .wrap_err_with(|| format!("failed to get block at height {height}"))?
+            .block;
+        let results = sequencer_client
+            .block_results(height)
+            .await
+            .wrap_err_with(|| format!("failed to get block results at height {height}"))?
+            .txs_results
+            .unwrap_or_default();
+
+        // the block data starts with the block commitments, which are not transactions
+        let commitment_count = block_commitment_count_of_data(&block.data);
+        for (tx_bytes, result) in block.data.iter().zip(&results).skip(commitment_count) {
+            if result.code.is_err() {
+                continue;
+            }
+            let raw_tx = raw::SignedTransaction::decode(&**tx_bytes)
+                .wrap_err_with(|| format!("failed decoding transaction at height {height}"))?;
+            let tx = SignedTransaction::try_from_raw(raw_tx)
+                .wrap_err_with(|| format!("invalid transaction at height {height}"))?;
+            let tx_hash = hex::encode(Sha256::digest(tx_bytes));
+            let signer = tx.address_bytes();
+            for event in bridge_events(args.address, signer, tx.actions()) {
+                entries.push(HistoryEntry {
+                    height: height.value(),
+                    tx_hash: tx_hash.clone(),
+                    event,
+                });
+            }
+        }
+    }
+
+    output::emit(&History {
+        address: args.address,
+        from_height,
+        to_height,
+        entries,
+    })
+}
+
+#[derive(Serialize)]
+struct History {
+    #[serde(serialize_with = "output::display")]
+    address: Address,
+    from_height: u32,
+    to_height: u32,
+    entries: Vec<HistoryEntry>,
+}
+
+#[derive(Serialize)]
+struct HistoryEntry {
+    height: u64,
+    tx_hash: String,
+    #[serde(flatten)]
+    event: BridgeEvent,
+}
+
+impl Display for History {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "History of bridge account {} from height {} to {}:",
+            self.address, self.from_height, self.to_height
+        )?;
+        if self.entries.is_empty() {
+            write!(f, "\n    no deposits or withdrawals")?;
+        }
+        for entry in &self.entries {
+            write!(
+                f,
+                "\n    [{}] {} {}",
+                entry.height, entry.tx_hash, entry.event
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for History {
+    const KIND: &'static str = "bridge_history";
+}
+
+/// A movement of funds into or out of a bridge account
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum BridgeEvent {
+    Deposit {
+        #[serde(serialize_with = "output::display")]
+        amount: u128,
+        #[serde(serialize_with = "serialize_asset_id")]
+        asset_id: asset::Id,
+        destination_chain_address: String,
+    },
+    Unlock {
+        #[serde(serialize_with = "output::display")]
+        amount: u128,
+        #[serde(serialize_with = "output::display")]
+        to: Address,
+    },
+    Ics20Withdrawal {
+        #[serde(serialize_with = "output::display")]
+        amount: u128,
+        denom: String,
+        destination_chain_address: String,
+    },
+}
+
+fn serialize_asset_id<S: serde::Serializer>(
+    asset_id: &asset::Id,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&hex::encode(asset_id))
+}
+
+impl std::fmt::Display for BridgeEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Deposit {
+                amount,
+                asset_id,
+                destination_chain_address,
+            } => write!(
+                f,
+                "deposit of {amount} {} for `{destination_chain_address}`",
+                hex::encode(asset_id)
+            ),
+            Self::Unlock {
+                amount,
+                to,
+            } => write!(f, "unlock of {amount} to {to}"),
+            Self::Ics20Withdrawal {
+                amount,
+                denom,
+                destination_chain_address,
+            } => write!(
+                f,
+                "ics20 withdrawal of {amount} {denom} to `{destination_chain_address}`"
+            ),
+        }
+    }
+}
+
+/// Returns the deposits into and withdrawals out of `bridge` made by `actions` of a transaction
+/// signed by `signer`.
+///
+/// Withdrawals either name the bridge account explicitly (when signed by its withdrawer) or are
+/// signed by the bridge account itself.
+fn bridge_events(
+    bridge: Address,
+    signer: [u8; ADDRESS_LEN],
+    actions: &[Action],
+) -> Vec<BridgeEvent> {
+    let withdraws_from_bridge = |bridge_address: Option<Address>| match bridge_address {
+        Some(address) => address.bytes() == bridge.bytes(),
+        None => signer == bridge.bytes(),
+    };
+    actions
+        .iter()
+        .filter_map(|action| match action {
+            Action::BridgeLock(lock) if lock.to.bytes() == bridge.bytes() => {
+                Some(BridgeEvent::Deposit {
+                    amount: lock.amount,
+                    asset_id: lock.asset_id,
+                    destination_chain_address: lock.destination_chain_address.clone(),
+                })
+            }
+            Action::BridgeUnlock(unlock) if withdraws_from_bridge(unlock.bridge_address) => {
+                Some(BridgeEvent::Unlock {
+                    amount: unlock.amount,
+                    to: unlock.to,
+                })
+            }
+            Action::Ics20Withdrawal(withdrawal)
+                if withdraws_from_bridge(withdrawal.bridge_address) =>
+            {
+                Some(BridgeEvent::Ics20Withdrawal {
+                    amount: withdrawal.amount,
+                    denom: withdrawal.denom.to_string(),
+                    destination_chain_address: withdrawal.destination_chain_address.clone(),
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::{
+            asset::default_native_asset,
+            Address,
+        },
+        protocol::transaction::v1alpha1::{
+            action::{
+                BridgeLockAction,
+                BridgeUnlockAction,
+                TransferAction,
+            },
+            Action,
+        },
+    };
+
+    use super::{
+        bridge_events,
+        BridgeEvent,
+    };
+
+    fn address(byte: u8) -> Address {
+        Address::builder()
+            .array([byte; 20])
+            .prefix("astria")
+            .try_build()
+            .unwrap()
+    }
+
+    #[test]
+    fn deposits_and_withdrawals_of_bridge_are_found() {
+        let bridge = address(1);
+        let other = address(2);
+        let asset_id = default_native_asset().id();
+        let lock = |to| {
+            Action::BridgeLock(BridgeLockAction {
+                to,
+                amount: 10,
+                asset_id,
+                fee_asset_id: asset_id,
+                destination_chain_address: "0xrollup".to_string(),
+            })
+        };
+        let unlock = |bridge_address| {
+            Action::BridgeUnlock(BridgeUnlockAction {
+                to: other,
+                amount: 5,
+                fee_asset_id: asset_id,
+                memo: vec![],
+                bridge_address,
+            })
+        };
+        let transfer = Action::Transfer(TransferAction {
+            to: bridge,
+            amount: 1,
+            asset_id,
+            fee_asset_id: asset_id,
+        });
+
+        let actions = [
+            lock(bridge),
+            lock(other),
+            unlock(Some(bridge)),
+            unlock(None),
+            transfer,
+        ];
+
+        // signed by a withdrawer: only the unlock naming the bridge is a withdrawal from it
+        let events = bridge_events(bridge, other.bytes(), &actions);
+        assert_eq!(
+            vec![
+                BridgeEvent::Deposit {
+                    amount: 10,
+                    asset_id,
+                    destination_chain_address: "0xrollup".to_string(),
+                },
+                BridgeEvent::Unlock {
+                    amount: 5,
+                    to: other,
+                },
+            ],
+            events
+        );
+
+        // signed by the bridge itself: unlocks without an explicit bridge address count too
+        let events = bridge_events(bridge, bridge.bytes(), &actions);
+        assert_eq!(3, events.len());
+    }
+}
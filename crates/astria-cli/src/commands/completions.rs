@@ -0,0 +1,166 @@
+use std::{
+    fmt::Display,
+    path::Path,
+};
+
+use clap::CommandFactory as _;
+use clap_complete::Shell;
+use color_eyre::{
+    eyre,
+    eyre::Context,
+};
+use serde::Serialize;
+
+use crate::{
+    cli::{
+        completions::{
+            CompletionsArgs,
+            Target,
+        },
+        Cli,
+    },
+    output::{
+        self,
+        Report,
+    },
+};
+
+const BIN_NAME: &str = "astria-cli";
+
+#[derive(Serialize)]
+struct Written {
+    files: Vec<String>,
+}
+
+impl Display for Written {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Wrote {} files:", self.files.len())?;
+        for file in &self.files {
+            write!(f, "\n    {file}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for Written {
+    const KIND: &'static str = "completions";
+}
+
+/// Generates shell completions or man pages from the command line definitions
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the completions or man pages cannot be written
+pub(crate) fn completions(args: &CompletionsArgs) -> eyre::Result<()> {
+    let mut cmd = Cli::command();
+    let shell = match args.target {
+        Target::Bash => Shell::Bash,
+        Target::Elvish => Shell::Elvish,
+        Target::Fish => Shell::Fish,
+        Target::PowerShell => Shell::PowerShell,
+        Target::Zsh => Shell::Zsh,
+        Target::Man => {
+            let dir = args
+                .output_dir
+                .as_deref()
+                .expect("clap requires `--output-dir` for man pages");
+            let files = write_man_pages(cmd, dir)?;
+            return output::emit(&Written {
+                files,
+            });
+        }
+    };
+
+    match &args.output_dir {
+        Some(dir) => {
+            let path = clap_complete::generate_to(shell, &mut cmd, BIN_NAME, dir)
+                .wrap_err_with(|| format!("failed writing completions to `{}`", dir.display()))?;
+            output::emit(&Written {
+                files: vec![path.display().to_string()],
+            })
+        }
+        None => {
+            clap_complete::generate(shell, &mut cmd, BIN_NAME, &mut std::io::stdout());
+            Ok(())
+        }
+    }
+}
+
+fn write_man_pages(cmd: clap::Command, dir: &Path) -> eyre::Result<Vec<String>> {
+    std::fs::create_dir_all(dir)
+        .wrap_err_with(|| format!("failed creating `{}`", dir.display()))?;
+    let mut files = vec![];
+    for (name, page) in man_pages(cmd)? {
+        let path = dir.join(name);
+        std::fs::write(&path, page)
+            .wrap_err_with(|| format!("failed writing man page to `{}`", path.display()))?;
+        files.push(path.display().to_string());
+    }
+    Ok(files)
+}
+
+/// Renders a man page for `cmd` and each of its subcommands, recursively, named
+/// `<bin>-<subcommand>-...-<subcommand>.1`.
+fn man_pages(mut cmd: clap::Command) -> eyre::Result<Vec<(String, Vec<u8>)>> {
+    // building propagates global arguments and sets the display names of the subcommands
+    cmd.build();
+    let mut pages = vec![];
+    render_man_pages(&cmd, &mut pages)?;
+    Ok(pages)
+}
+
+fn render_man_pages(cmd: &clap::Command, pages: &mut Vec<(String, Vec<u8>)>) -> eyre::Result<()> {
+    let name = cmd.get_display_name().unwrap_or_else(|| cmd.get_name());
+    let mut page = vec![];
+    clap_mangen::Man::new(cmd.clone())
+        .render(&mut page)
+        .wrap_err_with(|| format!("failed rendering man page for `{name}`"))?;
+    pages.push((format!("{name}.1"), page));
+    for subcommand in cmd
+        .get_subcommands()
+        .filter(|subcommand| !subcommand.is_hide_set() && subcommand.get_name() != "help")
+    {
+        render_man_pages(subcommand, pages)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::CommandFactory as _;
+    use clap_complete::Shell;
+
+    use super::{
+        man_pages,
+        BIN_NAME,
+    };
+    use crate::cli::Cli;
+
+    #[test]
+    fn man_pages_cover_nested_subcommands() {
+        let pages = man_pages(Cli::command()).unwrap();
+        let names: Vec<_> = pages.iter().map(|(name, _)| name.as_str()).collect();
+        for expected in [
+            "astria-cli.1",
+            "astria-cli-sequencer.1",
+            "astria-cli-sequencer-sudo-fee-asset-add.1",
+            "astria-cli-sequencer-sudo-validator-update.1",
+        ] {
+            assert!(names.contains(&expected), "missing man page `{expected}`");
+        }
+        assert!(!names.iter().any(|name| name.ends_with("-help.1")));
+    }
+
+    #[test]
+    fn completions_include_sudo_subcommands() {
+        let mut completions = vec![];
+        clap_complete::generate(Shell::Bash, &mut Cli::command(), BIN_NAME, &mut completions);
+        let completions = String::from_utf8(completions).unwrap();
+        assert!(completions.contains("validator-update"));
+        assert!(completions.contains("sudo-address-change"));
+    }
+}
@@ -0,0 +1,354 @@
+use std::io::{
+    self,
+    Write as _,
+};
+
+use astria_core::{
+    primitive::v1::{
+        asset::Denom,
+        Address,
+        RollupId,
+    },
+    protocol::transaction::v1alpha1::{
+        action::{
+            SequenceAction,
+            TransferAction,
+        },
+        Action,
+        TransactionParams,
+        UnsignedTransaction,
+    },
+};
+use astria_sequencer_client::{
+    HttpClient,
+    SequencerClientExt as _,
+};
+use clap::{
+    Parser,
+    Subcommand,
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        ensure,
+        Context,
+    },
+};
+
+use crate::{
+    address_book::parse_address,
+    cli::sequencer::ComposeArgs,
+};
+
+const PROMPT: &str = "compose> ";
+
+/// A single line entered at the compose prompt
+#[derive(Debug, Parser)]
+#[command(multicall = true)]
+struct Line {
+    #[command(subcommand)]
+    step: Step,
+}
+
+#[derive(Debug, Subcommand)]
+enum Step {
+    /// Add a transfer action
+    Transfer {
+        /// The address to transfer to
+        #[arg(value_parser = parse_address)]
+        to: Address,
+        /// The amount to transfer
+        amount: u128,
+        /// The asset to transfer
+        #[arg(long, default_value = "nria")]
+        asset: Denom,
+        /// The asset used to pay the fee of the action
+        #[arg(long, default_value = "nria")]
+        fee_asset: Denom,
+    },
+    /// Add a rollup data submission
+    Data {
+        /// Plaintext rollup name (to be hashed into a rollup ID)
+        rollup_name: String,
+        /// The hex-encoded data to submit
+        data: String,
+        /// The asset used to pay the fee of the action
+        #[arg(long, default_value = "nria")]
+        fee_asset: Denom,
+    },
+    /// Set the nonce of the transaction
+    Nonce {
+        /// The nonce to set
+        #[arg(required_unless_present = "fetch")]
+        nonce: Option<u32>,
+        /// Fetch the latest nonce of this address from the Sequencer instead
+        #[arg(long, conflicts_with = "nonce", value_parser = parse_address)]
+        fetch: Option<Address>,
+    },
+    /// Set the chain id of the transaction
+    ChainId { chain_id: String },
+    /// Remove the action at the given position
+    Remove { index: usize },
+    /// Print the transaction composed so far
+    Show,
+    /// Finish composing and write out the transaction
+    Done,
+    /// Abort without writing out the transaction
+    Quit,
+}
+
+/// The transaction being composed
+struct Composer {
+    nonce: u32,
+    chain_id: String,
+    actions: Vec<Action>,
+}
+
+impl Composer {
+    /// Applies a step that does not require network access or ends the session.
+    fn apply(&mut self, step: Step) -> eyre::Result<()> {
+        match step {
+            Step::Transfer {
+                to,
+                amount,
+                asset,
+                fee_asset,
+            } => self.actions.push(Action::Transfer(TransferAction {
+                to,
+                amount,
+                asset_id: asset.id(),
+                fee_asset_id: fee_asset.id(),
+            })),
+            Step::Data {
+                rollup_name,
+                data,
+                fee_asset,
+            } => {
+                let data = hex::decode(data).wrap_err("failed decoding data from hex")?;
+                self.actions.push(Action::Sequence(SequenceAction {
+                    rollup_id: RollupId::from_unhashed_bytes(rollup_name),
+                    data,
+                    fee_asset_id: fee_asset.id(),
+                }));
+            }
+            Step::Nonce {
+                nonce: Some(nonce),
+                ..
+            } => self.nonce = nonce,
+            Step::ChainId {
+                chain_id,
+            } => self.chain_id = chain_id,
+            Step::Remove {
+                index,
+            } => {
+                ensure!(
+                    index < self.actions.len(),
+                    "no action at position {index}; the transaction has {} actions",
+                    self.actions.len()
+                );
+                self.actions.remove(index);
+            }
+            Step::Nonce {
+                nonce: None, ..
+            }
+            | Step::Show
+            | Step::Done
+            | Step::Quit => {}
+        }
+        Ok(())
+    }
+
+    fn transaction(&self) -> UnsignedTransaction {
+        UnsignedTransaction {
+            params: TransactionParams::builder()
+                .nonce(self.nonce)
+                .chain_id(self.chain_id.clone())
+                .build(),
+            actions: self.actions.clone(),
+        }
+    }
+
+    fn to_pbjson(&self) -> eyre::Result<String> {
+        serde_json::to_string_pretty(&self.transaction().into_raw())
+            .wrap_err("failed serializing transaction to pbjson")
+    }
+
+    fn print_summary(&self) {
+        println!(
+            "nonce: {}, chain id: {}, actions: {}",
+            self.nonce,
+            self.chain_id,
+            self.actions.len()
+        );
+        for (index, action) in self.actions.iter().enumerate() {
+            match action {
+                Action::Transfer(transfer) => println!(
+                    "  [{index}] transfer {} of {} to {}",
+                    transfer.amount, transfer.asset_id, transfer.to
+                ),
+                Action::Sequence(sequence) => println!(
+                    "  [{index}] {} bytes of data for rollup {}",
+                    sequence.data.len(),
+                    sequence.rollup_id
+                ),
+                other => println!("  [{index}] {other:?}"),
+            }
+        }
+    }
+}
+
+/// Interactively composes an unsigned transaction and writes it out as pbjson
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If reading from stdin fails
+/// * If the end of input is reached before any actions were added
+/// * If the composed transaction cannot be written out
+pub(crate) async fn compose(args: &ComposeArgs) -> eyre::Result<()> {
+    let mut composer = Composer {
+        nonce: args.nonce,
+        chain_id: args.sequencer_chain_id.clone(),
+        actions: vec![],
+    };
+    println!("Composing a transaction; type `help` for the available commands");
+
+    loop {
+        print!("{PROMPT}");
+        io::stdout().flush().wrap_err("failed flushing stdout")?;
+        let mut input = String::new();
+        // reaching the end of input finishes the transaction so that steps can be piped in
+        if io::stdin()
+            .read_line(&mut input)
+            .wrap_err("failed reading from stdin")?
+            == 0
+        {
+            println!();
+            ensure!(
+                !composer.actions.is_empty(),
+                "reached the end of input before any actions were added to the transaction"
+            );
+            break;
+        }
+        if input.trim().is_empty() {
+            continue;
+        }
+        let step = match Line::try_parse_from(input.split_whitespace()) {
+            Ok(line) => line.step,
+            Err(err) => {
+                println!("{err}");
+                continue;
+            }
+        };
+
+        let result = match step {
+            Step::Nonce {
+                fetch: Some(address),
+                ..
+            } => fetch_nonce(&args.sequencer_url, address)
+                .await
+                .map(|nonce| composer.nonce = nonce),
+            Step::Show => composer.to_pbjson().map(|json| println!("{json}")),
+            Step::Done => {
+                if composer.actions.is_empty() {
+                    println!("error: the transaction has no actions");
+                    continue;
+                }
+                break;
+            }
+            Step::Quit => return Ok(()),
+            step => composer.apply(step),
+        };
+        match result {
+            Ok(()) => composer.print_summary(),
+            Err(err) => println!("error: {err:#}"),
+        }
+    }
+
+    let json = composer.to_pbjson()?;
+    if let Some(path) = &args.output_file {
+        std::fs::write(path, json)
+            .wrap_err_with(|| format!("failed writing transaction to `{}`", path.display()))?;
+        println!("Wrote unsigned transaction to `{}`", path.display());
+    } else {
+        println!("{json}");
+    }
+    println!(
+        "Unsigned transaction sha256: {}",
+        super::offline::unsigned_sha256(&composer.transaction())
+    );
+    Ok(())
+}
+
+async fn fetch_nonce(sequencer_url: &str, address: Address) -> eyre::Result<u32> {
+    let sequencer_client =
+        HttpClient::new(sequencer_url).wrap_err("failed constructing http sequencer client")?;
+    let res = sequencer_client
+        .get_latest_nonce(address)
+        .await
+        .wrap_err("failed to get nonce")?;
+    Ok(res.nonce)
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::protocol::transaction::v1alpha1::Action;
+    use clap::Parser as _;
+
+    use super::{
+        Composer,
+        Line,
+    };
+
+    fn apply(composer: &mut Composer, input: &str) {
+        let line = Line::try_parse_from(input.split_whitespace()).unwrap();
+        composer.apply(line.step).unwrap();
+    }
+
+    #[test]
+    fn steps_build_up_transaction() {
+        let mut composer = Composer {
+            nonce: 0,
+            chain_id: "astria".to_string(),
+            actions: vec![],
+        };
+        apply(
+            &mut composer,
+            "transfer astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm 10",
+        );
+        apply(&mut composer, "data my-rollup deadbeef");
+        apply(&mut composer, "nonce 4");
+        apply(&mut composer, "chain-id astria-dusk-7");
+
+        let tx = composer.transaction();
+        assert_eq!(4, tx.nonce());
+        assert_eq!("astria-dusk-7", tx.chain_id());
+        assert!(matches!(tx.actions[0], Action::Transfer(_)));
+        let Action::Sequence(sequence) = &tx.actions[1] else {
+            panic!("second action must be a rollup data submission");
+        };
+        assert_eq!(vec![0xde, 0xad, 0xbe, 0xef], sequence.data);
+
+        apply(&mut composer, "remove 0");
+        assert_eq!(1, composer.actions.len());
+        assert!(matches!(composer.actions[0], Action::Sequence(_)));
+    }
+
+    #[test]
+    fn invalid_steps_are_rejected() {
+        let mut composer = Composer {
+            nonce: 0,
+            chain_id: "astria".to_string(),
+            actions: vec![],
+        };
+        Line::try_parse_from("transfer not-an-address 10".split_whitespace()).unwrap_err();
+        Line::try_parse_from("nonce".split_whitespace()).unwrap_err();
+        let line = Line::try_parse_from("remove 0".split_whitespace()).unwrap();
+        composer.apply(line.step).unwrap_err();
+        let line = Line::try_parse_from("data my-rollup xyz".split_whitespace()).unwrap();
+        composer.apply(line.step).unwrap_err();
+    }
+}
@@ -0,0 +1,654 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    future::Future,
+    io::{
+        self,
+        Write as _,
+    },
+    path::Path,
+    str::FromStr,
+    time::Duration,
+};
+
+use astria_core::{
+    generated::execution::v1alpha2::{
+        execution_service_client::ExecutionServiceClient,
+        GetGenesisInfoRequest,
+    },
+    primitive::v1::RollupId,
+};
+use astria_sequencer_client::{
+    Client as _,
+    HttpClient,
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        ensure,
+        Context,
+    },
+};
+use serde::Serialize;
+use tonic::transport::Uri;
+
+use super::rollup::render_env;
+use crate::{
+    cli::config::{
+        GenerateComposerArgs,
+        GenerateConductorArgs,
+    },
+    output::Report,
+};
+
+/// How long to wait for a live node to answer a cross-check.
+const LIVE_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+const COMMIT_LEVELS: [&str; 3] = ["SoftOnly", "FirmOnly", "SoftAndFirm"];
+
+/// Generates the env-file config of a conductor
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If reading a prompted value from stdin fails
+/// * If a value of the config is invalid
+/// * If a cross-check against a live node fails
+/// * If the config file cannot be written
+pub(crate) async fn generate_conductor(args: &GenerateConductorArgs) -> eyre::Result<()> {
+    let mut args = args.clone();
+    if args.generate.interactive {
+        prompt_conductor(&mut args)?;
+    }
+    validate_conductor(&args)?;
+
+    let mut checks = vec![];
+    if !args.generate.offline {
+        checks.push(check_sequencer_chain_id(&args.sequencer_url, &args.sequencer_chain_id).await);
+        checks.push(
+            check_execution_rollup_id(&args.execution_rpc_url, args.rollup_name.as_deref()).await,
+        );
+    }
+    finish(
+        "conductor",
+        conductor_env(&args),
+        checks,
+        args.generate.output_file.as_deref(),
+    )
+}
+
+/// Generates the env-file config of a composer
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If reading a prompted value from stdin fails
+/// * If a value of the config is invalid
+/// * If a cross-check against a live node fails
+/// * If the config file cannot be written
+pub(crate) async fn generate_composer(args: &GenerateComposerArgs) -> eyre::Result<()> {
+    let mut args = args.clone();
+    if args.generate.interactive {
+        prompt_composer(&mut args)?;
+    }
+    validate_composer(&args)?;
+
+    let mut checks = vec![check_private_key_file(&args.private_key_file)];
+    if !args.generate.offline {
+        checks.push(check_sequencer_chain_id(&args.sequencer_url, &args.sequencer_chain_id).await);
+    }
+    finish(
+        "composer",
+        composer_env(&args),
+        checks,
+        args.generate.output_file.as_deref(),
+    )
+}
+
+/// Fails if any check failed, and otherwise writes the config to `output_file` and prints it.
+fn finish(
+    service: &'static str,
+    env: BTreeMap<&'static str, String>,
+    checks: Vec<Check>,
+    output_file: Option<&Path>,
+) -> eyre::Result<()> {
+    let failed: Vec<_> = checks
+        .iter()
+        .filter(|check| matches!(check.outcome, Outcome::Failed(_)))
+        .map(ToString::to_string)
+        .collect();
+    ensure!(
+        failed.is_empty(),
+        "the {service} config does not match the live nodes:\n{}",
+        failed.join("\n")
+    );
+
+    let file = if let Some(path) = output_file {
+        std::fs::write(path, render_env(&env))
+            .wrap_err_with(|| format!("failed writing `{}`", path.display()))?;
+        Some(path.display().to_string())
+    } else {
+        None
+    };
+
+    crate::output::emit(&GeneratedConfig {
+        service,
+        env,
+        checks,
+        file,
+    })
+}
+
+fn prompt_conductor(args: &mut GenerateConductorArgs) -> eyre::Result<()> {
+    let mut rollup_name = args.rollup_name.take().unwrap_or_default();
+    prompt(
+        "Rollup name (empty to skip the rollup ID check)",
+        &mut rollup_name,
+    )?;
+    args.rollup_name = Some(rollup_name).filter(|name| !name.is_empty());
+    prompt("Execution API url", &mut args.execution_rpc_url)?;
+    prompt(
+        "Execution commit level (SoftOnly, FirmOnly, SoftAndFirm)",
+        &mut args.execution_commit_level,
+    )?;
+    prompt("Sequencer url", &mut args.sequencer_url)?;
+    prompt("Sequencer gRPC url", &mut args.sequencer_grpc)?;
+    prompt("Sequencer chain id", &mut args.sequencer_chain_id)?;
+    prompt(
+        "Sequencer block time (ms)",
+        &mut args.sequencer_block_time_ms,
+    )?;
+    if args.execution_commit_level != "SoftOnly" {
+        prompt("Celestia node url", &mut args.celestia_node_http_url)?;
+        prompt("Celestia bearer token", &mut args.celestia_bearer_token)?;
+        prompt("Celestia block time (ms)", &mut args.celestia_block_time_ms)?;
+    }
+    Ok(())
+}
+
+fn prompt_composer(args: &mut GenerateComposerArgs) -> eyre::Result<()> {
+    prompt("Rollup name", &mut args.rollup_name)?;
+    prompt("Rollup websocket url", &mut args.rollup_ws_url)?;
+    prompt("Sequencer url", &mut args.sequencer_url)?;
+    prompt("Sequencer chain id", &mut args.sequencer_chain_id)?;
+    prompt("Sequencer address prefix", &mut args.prefix)?;
+    prompt("Private key file", &mut args.private_key_file)?;
+    prompt("Max bytes per bundle", &mut args.max_bytes_per_bundle)?;
+    Ok(())
+}
+
+/// Prompts for `label`, keeping the current `value` if the answer is empty.
+///
+/// Reaching the end of input keeps the current value so that answers can be piped in.
+fn prompt<T>(label: &str, value: &mut T) -> eyre::Result<()>
+where
+    T: FromStr + Display,
+    T::Err: Display,
+{
+    loop {
+        print!("{label} [{value}]: ");
+        io::stdout().flush().wrap_err("failed flushing stdout")?;
+        let mut input = String::new();
+        if io::stdin()
+            .read_line(&mut input)
+            .wrap_err("failed reading from stdin")?
+            == 0
+        {
+            println!();
+            return Ok(());
+        }
+        let input = input.trim();
+        if input.is_empty() {
+            return Ok(());
+        }
+        match input.parse() {
+            Ok(parsed) => {
+                *value = parsed;
+                return Ok(());
+            }
+            Err(err) => println!("error: {err}"),
+        }
+    }
+}
+
+fn validate_conductor(args: &GenerateConductorArgs) -> eyre::Result<()> {
+    if let Some(rollup_name) = &args.rollup_name {
+        validate_rollup_name(rollup_name)?;
+    }
+    validate_url(
+        "execution API url",
+        &args.execution_rpc_url,
+        &["http", "https"],
+    )?;
+    ensure!(
+        COMMIT_LEVELS.contains(&args.execution_commit_level.as_str()),
+        "execution commit level must be one of {COMMIT_LEVELS:?}, but is `{}`",
+        args.execution_commit_level,
+    );
+    validate_url("Sequencer url", &args.sequencer_url, &["http", "https"])?;
+    validate_url(
+        "Sequencer gRPC url",
+        &args.sequencer_grpc,
+        &["http", "https"],
+    )?;
+    ensure!(
+        !args.sequencer_chain_id.is_empty(),
+        "Sequencer chain id must not be empty"
+    );
+    ensure!(
+        args.sequencer_block_time_ms > 0,
+        "Sequencer block time must not be zero"
+    );
+    if args.execution_commit_level != "SoftOnly" {
+        validate_url(
+            "Celestia node url",
+            &args.celestia_node_http_url,
+            &["http", "https"],
+        )?;
+        ensure!(
+            args.celestia_block_time_ms > 0,
+            "Celestia block time must not be zero"
+        );
+    }
+    Ok(())
+}
+
+fn validate_composer(args: &GenerateComposerArgs) -> eyre::Result<()> {
+    validate_rollup_name(&args.rollup_name)?;
+    validate_url("rollup websocket url", &args.rollup_ws_url, &["ws", "wss"])?;
+    validate_url("Sequencer url", &args.sequencer_url, &["http", "https"])?;
+    ensure!(
+        !args.sequencer_chain_id.is_empty(),
+        "Sequencer chain id must not be empty"
+    );
+    bech32::Hrp::parse(&args.prefix)
+        .wrap_err_with(|| format!("`{}` is not a valid address prefix", args.prefix))?;
+    ensure!(
+        !args.private_key_file.is_empty(),
+        "private key file must not be empty"
+    );
+    ensure!(
+        args.max_bytes_per_bundle > 0,
+        "max bytes per bundle must not be zero"
+    );
+    Ok(())
+}
+
+/// Rejects rollup names that would break the `<rollup_name>::<url>` list of composer.
+fn validate_rollup_name(rollup_name: &str) -> eyre::Result<()> {
+    ensure!(!rollup_name.is_empty(), "rollup name must not be empty");
+    ensure!(
+        !rollup_name.contains("::") && !rollup_name.contains(','),
+        "rollup name must not contain `::` or `,`, but is `{rollup_name}`"
+    );
+    Ok(())
+}
+
+fn validate_url(name: &str, url: &str, schemes: &[&str]) -> eyre::Result<()> {
+    let uri: Uri = url
+        .parse()
+        .wrap_err_with(|| format!("{name} `{url}` is not a valid url"))?;
+    ensure!(
+        uri.scheme_str()
+            .is_some_and(|scheme| schemes.contains(&scheme)),
+        "{name} `{url}` must use one of the schemes {schemes:?}"
+    );
+    ensure!(uri.host().is_some(), "{name} `{url}` has no host");
+    Ok(())
+}
+
+/// Runs `request` against a live node, giving up after [`LIVE_CHECK_TIMEOUT`].
+async fn live<T, E: Display>(request: impl Future<Output = Result<T, E>>) -> Result<T, String> {
+    match tokio::time::timeout(LIVE_CHECK_TIMEOUT, request).await {
+        Ok(Ok(response)) => Ok(response),
+        Ok(Err(err)) => Err(err.to_string()),
+        Err(_) => Err(format!(
+            "no response within {}",
+            humantime::format_duration(LIVE_CHECK_TIMEOUT)
+        )),
+    }
+}
+
+async fn check_sequencer_chain_id(sequencer_url: &str, chain_id: &str) -> Check {
+    const NAME: &str = "Sequencer chain id";
+    let status = match HttpClient::new(sequencer_url) {
+        Ok(client) => live(client.status()).await,
+        Err(err) => Err(err.to_string()),
+    };
+    let outcome = match status {
+        Ok(status) if status.node_info.network.as_str() == chain_id => {
+            Outcome::Passed(format!("`{sequencer_url}` is on chain `{chain_id}`"))
+        }
+        Ok(status) => Outcome::Failed(format!(
+            "`{sequencer_url}` is on chain `{}`, not `{chain_id}`",
+            status.node_info.network,
+        )),
+        Err(err) => Outcome::Unverified(format!("`{sequencer_url}` is unreachable: {err}")),
+    };
+    Check::new(NAME, outcome)
+}
+
+async fn check_execution_rollup_id(execution_rpc_url: &str, rollup_name: Option<&str>) -> Check {
+    const NAME: &str = "rollup id";
+    let genesis_info = live(async {
+        let mut client = ExecutionServiceClient::connect(execution_rpc_url.to_string()).await?;
+        let genesis_info = client
+            .get_genesis_info(GetGenesisInfoRequest {})
+            .await?
+            .into_inner();
+        eyre::Ok(genesis_info)
+    })
+    .await;
+    let outcome = match genesis_info {
+        Ok(genesis_info) => match (
+            RollupId::try_from_slice(&genesis_info.rollup_id),
+            rollup_name,
+        ) {
+            (Err(err), _) => Outcome::Failed(format!(
+                "`{execution_rpc_url}` serves an invalid rollup id: {err}"
+            )),
+            (Ok(served), Some(rollup_name))
+                if served != RollupId::from_unhashed_bytes(rollup_name) =>
+            {
+                Outcome::Failed(format!(
+                    "`{execution_rpc_url}` serves rollup `{served}`, not rollup `{rollup_name}` \
+                     with ID `{}`",
+                    RollupId::from_unhashed_bytes(rollup_name),
+                ))
+            }
+            (Ok(served), Some(rollup_name)) => Outcome::Passed(format!(
+                "`{execution_rpc_url}` serves rollup `{rollup_name}` with ID `{served}`"
+            )),
+            (Ok(served), None) => {
+                Outcome::Passed(format!("`{execution_rpc_url}` serves rollup ID `{served}`"))
+            }
+        },
+        Err(err) => Outcome::Unverified(format!("`{execution_rpc_url}` is unreachable: {err}")),
+    };
+    Check::new(NAME, outcome)
+}
+
+fn check_private_key_file(private_key_file: &str) -> Check {
+    const NAME: &str = "private key file";
+    let outcome = if Path::new(private_key_file).is_file() {
+        Outcome::Passed(format!("`{private_key_file}` exists"))
+    } else {
+        Outcome::Unverified(format!(
+            "`{private_key_file}` does not exist on this machine"
+        ))
+    };
+    Check::new(NAME, outcome)
+}
+
+/// The environment of a conductor driving the rollup.
+fn conductor_env(args: &GenerateConductorArgs) -> BTreeMap<&'static str, String> {
+    BTreeMap::from([
+        (
+            "ASTRIA_CONDUCTOR_CELESTIA_BLOCK_TIME_MS",
+            args.celestia_block_time_ms.to_string(),
+        ),
+        (
+            "ASTRIA_CONDUCTOR_CELESTIA_BEARER_TOKEN",
+            args.celestia_bearer_token.clone(),
+        ),
+        (
+            "ASTRIA_CONDUCTOR_CELESTIA_NODE_HTTP_URL",
+            args.celestia_node_http_url.clone(),
+        ),
+        ("ASTRIA_CONDUCTOR_CELESTIA_TRUSTED_HEIGHT", "0".to_string()),
+        ("ASTRIA_CONDUCTOR_CELESTIA_TRUSTED_HASH", String::new()),
+        (
+            "ASTRIA_CONDUCTOR_CELESTIA_TRUSTING_PERIOD_MS",
+            "1209600000".to_string(),
+        ),
+        (
+            "ASTRIA_CONDUCTOR_CELESTIA_MAX_BISECTION_STEPS",
+            "20".to_string(),
+        ),
+        (
+            "ASTRIA_CONDUCTOR_EXECUTION_RPC_URL",
+            args.execution_rpc_url.clone(),
+        ),
+        (
+            "ASTRIA_CONDUCTOR_EXECUTION_COMMIT_LEVEL",
+            args.execution_commit_level.clone(),
+        ),
+        (
+            "ASTRIA_CONDUCTOR_EXECUTE_OPTIMISTIC_BLOCKS",
+            "false".to_string(),
+        ),
+        ("ASTRIA_CONDUCTOR_NO_EXECUTION_RECEIPTS", "true".to_string()),
+        (
+            "ASTRIA_CONDUCTOR_EXECUTION_RECEIPTS_SIGNING_KEY_PATH",
+            String::new(),
+        ),
+        ("ASTRIA_CONDUCTOR_EXECUTION_RECEIPTS_DIR", String::new()),
+        (
+            "ASTRIA_CONDUCTOR_EXECUTION_RECEIPTS_POST_URL",
+            String::new(),
+        ),
+        ("ASTRIA_CONDUCTOR_BLOCK_FILTER_PATH", String::new()),
+        (
+            "ASTRIA_CONDUCTOR_SEQUENCER_GRPC_URL",
+            args.sequencer_grpc.clone(),
+        ),
+        (
+            "ASTRIA_CONDUCTOR_SEQUENCER_COMETBFT_URL",
+            args.sequencer_url.clone(),
+        ),
+        (
+            "ASTRIA_CONDUCTOR_SEQUENCER_BLOCK_TIME_MS",
+            args.sequencer_block_time_ms.to_string(),
+        ),
+        (
+            "ASTRIA_CONDUCTOR_SEQUENCER_REQUESTS_PER_SECOND",
+            "500".to_string(),
+        ),
+        ("ASTRIA_CONDUCTOR_LOG", "astria_conductor=info".to_string()),
+        ("ASTRIA_CONDUCTOR_FORCE_STDOUT", "false".to_string()),
+        ("ASTRIA_CONDUCTOR_PRETTY_PRINT", "false".to_string()),
+        ("ASTRIA_CONDUCTOR_NO_OTEL", "true".to_string()),
+        ("ASTRIA_CONDUCTOR_NO_METRICS", "true".to_string()),
+        (
+            "ASTRIA_CONDUCTOR_METRICS_HTTP_LISTENER_ADDR",
+            "127.0.0.1:9000".to_string(),
+        ),
+    ])
+}
+
+/// The environment of a composer collecting transactions for the rollup.
+fn composer_env(args: &GenerateComposerArgs) -> BTreeMap<&'static str, String> {
+    BTreeMap::from([
+        ("ASTRIA_COMPOSER_API_LISTEN_ADDR", "0.0.0.0:0".to_string()),
+        ("ASTRIA_COMPOSER_GRPC_ADDR", "0.0.0.0:0".to_string()),
+        ("ASTRIA_COMPOSER_SEQUENCER_URL", args.sequencer_url.clone()),
+        (
+            "ASTRIA_COMPOSER_SEQUENCER_CHAIN_ID",
+            args.sequencer_chain_id.clone(),
+        ),
+        (
+            "ASTRIA_COMPOSER_SEQUENCER_ADDRESS_PREFIX",
+            args.prefix.clone(),
+        ),
+        (
+            "ASTRIA_COMPOSER_ROLLUPS",
+            format!("{}::{}", args.rollup_name, args.rollup_ws_url),
+        ),
+        (
+            "ASTRIA_COMPOSER_PRIVATE_KEY_FILE",
+            args.private_key_file.clone(),
+        ),
+        ("ASTRIA_COMPOSER_MAX_SUBMIT_INTERVAL_MS", "2000".to_string()),
+        (
+            "ASTRIA_COMPOSER_MAX_BYTES_PER_BUNDLE",
+            args.max_bytes_per_bundle.to_string(),
+        ),
+        ("ASTRIA_COMPOSER_BUNDLE_QUEUE_CAPACITY", "40000".to_string()),
+        ("ASTRIA_COMPOSER_CHAIN_HALT_TIMEOUT_MS", "30000".to_string()),
+        (
+            "ASTRIA_COMPOSER_CHAIN_ID_CHECK_INTERVAL_MS",
+            "60000".to_string(),
+        ),
+        ("ASTRIA_COMPOSER_INCLUSION_CHECK_BLOCKS", "10".to_string()),
+        ("ASTRIA_COMPOSER_LOG", "astria_composer=info".to_string()),
+        ("ASTRIA_COMPOSER_FORCE_STDOUT", "false".to_string()),
+        ("ASTRIA_COMPOSER_PRETTY_PRINT", "false".to_string()),
+        ("ASTRIA_COMPOSER_NO_OTEL", "true".to_string()),
+        ("ASTRIA_COMPOSER_NO_METRICS", "true".to_string()),
+        (
+            "ASTRIA_COMPOSER_METRICS_HTTP_LISTENER_ADDR",
+            "127.0.0.1:9000".to_string(),
+        ),
+    ])
+}
+
+/// The result of cross-checking a config value against a live node or the local machine
+#[derive(Serialize)]
+struct Check {
+    name: &'static str,
+    #[serde(flatten)]
+    outcome: Outcome,
+}
+
+impl Check {
+    fn new(name: &'static str, outcome: Outcome) -> Self {
+        Self {
+            name,
+            outcome,
+        }
+    }
+}
+
+#[derive(Serialize)]
+#[serde(tag = "outcome", content = "detail", rename_all = "snake_case")]
+enum Outcome {
+    Passed(String),
+    Failed(String),
+    /// The value could not be checked, e.g. because the node was unreachable
+    Unverified(String),
+}
+
+impl Display for Check {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (outcome, detail) = match &self.outcome {
+            Outcome::Passed(detail) => ("passed", detail),
+            Outcome::Failed(detail) => ("FAILED", detail),
+            Outcome::Unverified(detail) => ("unverified", detail),
+        };
+        write!(f, "[{outcome}] {}: {detail}", self.name)
+    }
+}
+
+/// A generated service config
+#[derive(Serialize)]
+struct GeneratedConfig {
+    service: &'static str,
+    env: BTreeMap<&'static str, String>,
+    checks: Vec<Check>,
+    /// The file the config was written to
+    file: Option<String>,
+}
+
+impl Display for GeneratedConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for check in &self.checks {
+            writeln!(f, "{check}")?;
+        }
+        if !self.checks.is_empty() {
+            writeln!(f)?;
+        }
+        writeln!(f, "# {}", self.service)?;
+        write!(f, "{}", render_env(&self.env))?;
+        if let Some(file) = &self.file {
+            write!(f, "\nWrote {file}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for GeneratedConfig {
+    const KIND: &'static str = "generated_config";
+}
+
+#[cfg(test)]
+mod tests {
+    use clap::Parser;
+    use test_utils::with_temp_directory;
+
+    use super::*;
+
+    #[derive(Parser)]
+    struct Conductor {
+        #[command(flatten)]
+        args: GenerateConductorArgs,
+    }
+
+    #[derive(Parser)]
+    struct Composer {
+        #[command(flatten)]
+        args: GenerateComposerArgs,
+    }
+
+    #[test]
+    fn urls_must_have_an_allowed_scheme_and_a_host() {
+        assert!(validate_url("url", "http://127.0.0.1:26657", &["http"]).is_ok());
+        assert!(validate_url("url", "ws://127.0.0.1:8545", &["http"]).is_err());
+        assert!(validate_url("url", "127.0.0.1:26657", &["http"]).is_err());
+        assert!(validate_url("url", "not a url", &["http"]).is_err());
+    }
+
+    #[test]
+    fn invalid_conductor_configs_are_rejected() {
+        let Conductor {
+            args,
+        } = Conductor::try_parse_from(["conductor", "--execution-commit-level", "Soft"]).unwrap();
+        assert!(validate_conductor(&args).is_err());
+
+        let Conductor {
+            args,
+        } = Conductor::try_parse_from(["conductor", "--rollup-name", "a::b"]).unwrap();
+        assert!(validate_conductor(&args).is_err());
+
+        let Conductor {
+            args,
+        } = Conductor::try_parse_from(["conductor", "--rollup-name", "test"]).unwrap();
+        assert!(validate_conductor(&args).is_ok());
+    }
+
+    #[tokio::test]
+    async fn offline_composer_config_is_written() {
+        with_temp_directory(|_dir| async {
+            let Composer {
+                args,
+            } = Composer::try_parse_from([
+                "composer",
+                "test",
+                "--offline",
+                "--output-file",
+                "composer.env",
+            ])
+            .unwrap();
+            generate_composer(&args).await.unwrap();
+
+            let composer = std::fs::read_to_string("composer.env").unwrap();
+            assert!(composer.contains("ASTRIA_COMPOSER_ROLLUPS=\"test::ws://127.0.0.1:8545\"\n"));
+            assert!(composer.contains("ASTRIA_COMPOSER_MAX_BYTES_PER_BUNDLE=\"200000\"\n"));
+        })
+        .await;
+    }
+
+    #[test]
+    fn failed_checks_prevent_writing_the_config() {
+        let checks = vec![Check::new(
+            "Sequencer chain id",
+            Outcome::Failed("wrong chain".to_string()),
+        )];
+        assert!(finish("composer", BTreeMap::new(), checks, None).is_err());
+    }
+}
@@ -0,0 +1,466 @@
+//! Decoding of Astria protobuf messages copied from logs, RPC responses or Celestia blobs.
+//!
+//! If the type of a message is not given, it is detected: a message wrapped in a
+//! `google.protobuf.Any` is decoded as the type named by its type URL. Otherwise the known types
+//! are tried in turn, starting with those whose embedded type URLs occur in the message, and the
+//! first type that re-encodes to exactly the input and passes validation is taken.
+use std::{
+    fmt::Display,
+    io,
+};
+
+use astria_core::{
+    generated::{
+        protocol::transaction::v1alpha1 as raw_transaction,
+        sequencerblock::v1alpha1 as raw_block,
+    },
+    protocol::transaction::v1alpha1::{
+        SignedTransaction,
+        UnsignedTransaction,
+    },
+    sequencerblock::v1alpha1::{
+        block::{
+            Deposit,
+            RollupData,
+        },
+        SequencerBlock,
+        SubmittedMetadata,
+        SubmittedRollupData,
+    },
+};
+use base64::{
+    engine::general_purpose::STANDARD,
+    Engine as _,
+};
+use clap::ValueEnum as _;
+use color_eyre::{
+    eyre,
+    eyre::{
+        bail,
+        ensure,
+        Context,
+    },
+};
+use ibc_proto::google::protobuf::Any;
+use prost::{
+    Message as _,
+    Name as _,
+};
+use serde::Serialize;
+
+use crate::{
+    cli::decode::{
+        DecodeArgs,
+        Encoding,
+        MessageType,
+    },
+    output::{
+        self,
+        Report,
+    },
+};
+
+/// The order in which the known types are tried if the type of a message is not given.
+///
+/// More specific types come first, as the encoding of a message can often also be decoded as a
+/// less specific type.
+const DETECTION_ORDER: [MessageType; 7] = [
+    MessageType::SequencerBlock,
+    MessageType::SignedTransaction,
+    MessageType::SubmittedMetadata,
+    MessageType::SubmittedRollupData,
+    MessageType::Deposit,
+    MessageType::UnsignedTransaction,
+    MessageType::RollupData,
+];
+
+#[derive(Serialize)]
+struct Decoded {
+    /// The full protobuf name of the type of the message.
+    #[serde(rename = "type")]
+    type_name: String,
+    #[serde(serialize_with = "output::display")]
+    encoding: Encoding,
+    /// Whether the message was wrapped in a `google.protobuf.Any`.
+    wrapped_in_any: bool,
+    /// Why the message is not a valid instance of its type, if it is not.
+    validation_error: Option<String>,
+    /// The message as pbjson.
+    message: serde_json::Value,
+}
+
+impl Display for Decoded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-encoded {}", self.encoding, self.type_name)?;
+        if self.wrapped_in_any {
+            write!(f, " wrapped in google.protobuf.Any")?;
+        }
+        if let Some(error) = &self.validation_error {
+            write!(f, "\nfailed validation: {error}")?;
+        }
+        let message = serde_json::to_string_pretty(&self.message).map_err(|_| std::fmt::Error)?;
+        write!(f, "\n{message}")
+    }
+}
+
+impl Report for Decoded {
+    const KIND: &'static str = "decoded";
+}
+
+impl Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Hex => f.write_str("hex"),
+            Self::Base64 => f.write_str("base64"),
+        }
+    }
+}
+
+/// Decodes a base64- or hex-encoded Astria protobuf message and prints it as pbjson
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the input cannot be read from stdin
+/// * If the input is not a message of a known type, or not of the requested type
+pub(crate) fn decode(args: &DecodeArgs) -> eyre::Result<()> {
+    let input = match args.input.as_deref() {
+        None | Some("-") => {
+            io::read_to_string(io::stdin()).wrap_err("failed reading input from stdin")?
+        }
+        Some(input) => input.to_string(),
+    };
+    let decoded = decode_message(input.trim(), args.encoding, args.message_type)?;
+    output::emit(&decoded)
+}
+
+fn decode_message(
+    input: &str,
+    encoding: Option<Encoding>,
+    message_type: Option<MessageType>,
+) -> eyre::Result<Decoded> {
+    let mut candidates: Vec<_> = decode_encodings(input, encoding)?
+        .into_iter()
+        .filter_map(|(encoding, bytes)| {
+            find_message(&bytes, message_type).map(|found| (encoding, found))
+        })
+        .collect();
+    // input like `deadbeef` is valid in both encodings; prefer the one that makes sense
+    candidates.sort_by_key(|(_, found)| !found.message.is_plausible());
+    let Some((encoding, found)) = candidates.into_iter().next() else {
+        match message_type {
+            Some(message_type) => bail!("failed decoding input as `{}`", full_name(message_type)),
+            None => bail!(
+                "input is not a known Astria protobuf message; use `--type` to decode it as a \
+                 specific type"
+            ),
+        }
+    };
+    Ok(Decoded {
+        type_name: full_name(found.message_type),
+        encoding,
+        wrapped_in_any: found.wrapped_in_any,
+        validation_error: found.message.validation_error,
+        message: found.message.json,
+    })
+}
+
+/// Returns the bytes encoded by `input` in each of the encodings it is valid in, or only in
+/// `encoding` if given.
+fn decode_encodings(
+    input: &str,
+    encoding: Option<Encoding>,
+) -> eyre::Result<Vec<(Encoding, Vec<u8>)>> {
+    if let Some(encoding) = encoding {
+        let bytes = decode_encoding(input, encoding)
+            .wrap_err_with(|| format!("input is not valid {encoding}"))?;
+        return Ok(vec![(encoding, bytes)]);
+    }
+    let decoded: Vec<_> = [Encoding::Hex, Encoding::Base64]
+        .into_iter()
+        .filter_map(|encoding| {
+            decode_encoding(input, encoding)
+                .ok()
+                .map(|bytes| (encoding, bytes))
+        })
+        .collect();
+    ensure!(
+        !decoded.is_empty(),
+        "input is neither valid hex nor valid base64"
+    );
+    Ok(decoded)
+}
+
+fn decode_encoding(input: &str, encoding: Encoding) -> eyre::Result<Vec<u8>> {
+    match encoding {
+        Encoding::Hex => Ok(hex::decode(input.strip_prefix("0x").unwrap_or(input))?),
+        Encoding::Base64 => Ok(STANDARD.decode(input)?),
+    }
+}
+
+/// A message found in the decoded input.
+struct Found {
+    message_type: MessageType,
+    wrapped_in_any: bool,
+    message: Message,
+}
+
+/// Looks for a message of type `requested` in `bytes`, or for a message of any known type if not
+/// given.
+fn find_message(bytes: &[u8], requested: Option<MessageType>) -> Option<Found> {
+    if let Some((message_type, value)) = unwrap_any(bytes) {
+        if requested.map_or(true, |requested| requested == message_type) {
+            if let Ok(message) = decode_as(message_type, &value) {
+                return Some(Found {
+                    message_type,
+                    wrapped_in_any: true,
+                    message,
+                });
+            }
+        }
+    }
+    let found = |message_type, message| Found {
+        message_type,
+        wrapped_in_any: false,
+        message,
+    };
+    match requested {
+        Some(message_type) => decode_as(message_type, bytes)
+            .ok()
+            .map(|message| found(message_type, message)),
+        None => detection_order(bytes).into_iter().find_map(|message_type| {
+            decode_as(message_type, bytes)
+                .ok()
+                .filter(Message::is_plausible)
+                .map(|message| found(message_type, message))
+        }),
+    }
+}
+
+/// Returns the type and value of `bytes` if they are a `google.protobuf.Any` wrapping a message
+/// of a known type.
+fn unwrap_any(bytes: &[u8]) -> Option<(MessageType, Vec<u8>)> {
+    let any = Any::decode(bytes).ok()?;
+    let name = any
+        .type_url
+        .rsplit_once('/')
+        .map_or(any.type_url.as_str(), |(_, name)| name);
+    let message_type = MessageType::value_variants()
+        .iter()
+        .copied()
+        .find(|message_type| full_name(*message_type) == name)?;
+    Some((message_type, any.value))
+}
+
+/// Returns the known types in the order in which they are tried for `bytes`, moving the types
+/// whose embedded type URLs occur in `bytes` to the front.
+fn detection_order(bytes: &[u8]) -> Vec<MessageType> {
+    let (mut hinted, rest): (Vec<_>, Vec<_>) =
+        DETECTION_ORDER.into_iter().partition(|message_type| {
+            embedded_type_names(*message_type)
+                .iter()
+                .any(|name| contains(bytes, name.as_bytes()))
+        });
+    hinted.extend(rest);
+    hinted
+}
+
+/// Returns the full names of the messages that `message_type` embeds as `google.protobuf.Any`.
+fn embedded_type_names(message_type: MessageType) -> Vec<String> {
+    match message_type {
+        MessageType::SignedTransaction => vec![raw_transaction::UnsignedTransaction::full_name()],
+        _ => vec![],
+    }
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+fn full_name(message_type: MessageType) -> String {
+    match message_type {
+        MessageType::SequencerBlock => raw_block::SequencerBlock::full_name(),
+        MessageType::SignedTransaction => raw_transaction::SignedTransaction::full_name(),
+        MessageType::UnsignedTransaction => raw_transaction::UnsignedTransaction::full_name(),
+        MessageType::SubmittedMetadata => raw_block::SubmittedMetadata::full_name(),
+        MessageType::SubmittedRollupData => raw_block::SubmittedRollupData::full_name(),
+        MessageType::Deposit => raw_block::Deposit::full_name(),
+        MessageType::RollupData => raw_block::RollupData::full_name(),
+    }
+}
+
+/// A message decoded as one of the known types.
+struct Message {
+    json: serde_json::Value,
+    /// Whether the message re-encodes to exactly the decoded bytes. Decoding skips unknown
+    /// fields, so bytes of a different type often decode without error.
+    is_canonical: bool,
+    validation_error: Option<String>,
+}
+
+impl Message {
+    fn is_plausible(&self) -> bool {
+        self.is_canonical && self.validation_error.is_none()
+    }
+}
+
+fn decode_as(message_type: MessageType, bytes: &[u8]) -> eyre::Result<Message> {
+    match message_type {
+        MessageType::SequencerBlock => decode_with(bytes, SequencerBlock::try_from_raw),
+        MessageType::SignedTransaction => decode_with(bytes, SignedTransaction::try_from_raw),
+        MessageType::UnsignedTransaction => decode_with(bytes, UnsignedTransaction::try_from_raw),
+        MessageType::SubmittedMetadata => decode_with(bytes, SubmittedMetadata::try_from_raw),
+        MessageType::SubmittedRollupData => decode_with(bytes, SubmittedRollupData::try_from_raw),
+        MessageType::Deposit => decode_with(bytes, Deposit::try_from_raw),
+        MessageType::RollupData => decode_with(bytes, RollupData::try_from_raw),
+    }
+}
+
+/// Decodes `bytes` as the raw type `R` and checks it with `validate`, the conversion to its
+/// domain type.
+fn decode_with<R, T, E>(
+    bytes: &[u8],
+    validate: impl FnOnce(R) -> Result<T, E>,
+) -> eyre::Result<Message>
+where
+    R: prost::Message + Default + Serialize,
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let raw = R::decode(bytes).wrap_err("failed decoding bytes as protobuf")?;
+    let is_canonical = raw.encode_to_vec() == bytes;
+    let json = serde_json::to_value(&raw).wrap_err("failed converting message to pbjson")?;
+    let validation_error = validate(raw)
+        .err()
+        .map(|error| format!("{:#}", eyre::Report::new(error)));
+    Ok(Message {
+        json,
+        is_canonical,
+        validation_error,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        primitive::v1::{
+            asset,
+            Address,
+            RollupId,
+        },
+        protocol::transaction::v1alpha1::{
+            action::TransferAction,
+            Action,
+            TransactionParams,
+        },
+    };
+    use prost::Message as _;
+
+    use super::*;
+
+    fn address() -> Address {
+        Address::builder()
+            .array([2; 20])
+            .prefix("astria")
+            .try_build()
+            .unwrap()
+    }
+
+    fn unsigned_transaction() -> UnsignedTransaction {
+        UnsignedTransaction {
+            params: TransactionParams::builder()
+                .nonce(1)
+                .chain_id("astria-dusk-7")
+                .build(),
+            actions: vec![Action::Transfer(TransferAction {
+                to: address(),
+                amount: 10,
+                asset_id: asset::default_native_asset().id(),
+                fee_asset_id: asset::default_native_asset().id(),
+            })],
+        }
+    }
+
+    fn signed_transaction() -> Vec<u8> {
+        unsigned_transaction()
+            .into_signed(&SigningKey::from([1; 32]))
+            .into_raw()
+            .encode_to_vec()
+    }
+
+    #[test]
+    fn signed_transaction_is_detected_in_either_encoding() {
+        let bytes = signed_transaction();
+        for (input, encoding) in [
+            (hex::encode(&bytes), Encoding::Hex),
+            (format!("0x{}", hex::encode(&bytes)), Encoding::Hex),
+            (STANDARD.encode(&bytes), Encoding::Base64),
+        ] {
+            let decoded = decode_message(&input, None, None).unwrap();
+            assert_eq!(
+                raw_transaction::SignedTransaction::full_name(),
+                decoded.type_name
+            );
+            assert_eq!(encoding, decoded.encoding);
+            assert!(!decoded.wrapped_in_any);
+            assert_eq!(None, decoded.validation_error);
+        }
+    }
+
+    #[test]
+    fn message_wrapped_in_any_is_decoded_as_named_type() {
+        let input = hex::encode(unsigned_transaction().into_any().encode_to_vec());
+        let decoded = decode_message(&input, None, None).unwrap();
+        assert_eq!(
+            raw_transaction::UnsignedTransaction::full_name(),
+            decoded.type_name
+        );
+        assert!(decoded.wrapped_in_any);
+        assert_eq!(serde_json::json!(1), decoded.message["params"]["nonce"]);
+    }
+
+    #[test]
+    fn deposit_is_detected() {
+        let deposit = Deposit::new(
+            address(),
+            RollupId::from_unhashed_bytes(b"test-rollup"),
+            500,
+            asset::default_native_asset().id(),
+            "0x0000000000000000000000000000000000000001".to_string(),
+        );
+        let input = STANDARD.encode(deposit.into_raw().encode_to_vec());
+        let decoded = decode_message(&input, None, None).unwrap();
+        assert_eq!(raw_block::Deposit::full_name(), decoded.type_name);
+        assert_eq!(None, decoded.validation_error);
+    }
+
+    #[test]
+    fn requested_type_is_decoded_even_if_invalid() {
+        let mut raw = raw_transaction::SignedTransaction::decode(&*signed_transaction()).unwrap();
+        raw.signature = vec![1; 3];
+        let input = hex::encode(raw.encode_to_vec());
+        let decoded = decode_message(
+            &input,
+            Some(Encoding::Hex),
+            Some(MessageType::SignedTransaction),
+        )
+        .unwrap();
+        assert_eq!(
+            raw_transaction::SignedTransaction::full_name(),
+            decoded.type_name
+        );
+        assert!(decoded.validation_error.is_some());
+    }
+
+    #[test]
+    fn unknown_input_is_rejected() {
+        decode_message("not hex or base64!", None, None).unwrap_err();
+        decode_message("0xzz", Some(Encoding::Hex), None).unwrap_err();
+        decode_message(&hex::encode(b"some log line"), None, None).unwrap_err();
+    }
+}
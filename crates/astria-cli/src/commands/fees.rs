@@ -0,0 +1,437 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+};
+
+use astria_core::{
+    generated::protocol::transaction::v1alpha1 as raw,
+    primitive::v1::asset,
+    protocol::transaction::v1alpha1::{
+        action::{
+            FeeChange,
+            FeeChangeAction,
+        },
+        Action,
+        SignedTransaction,
+    },
+    sequencerblock::v1alpha1::block_commitment_count_of_data,
+};
+use astria_sequencer_client::{
+    tendermint,
+    Client as _,
+    HttpClient,
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        ensure,
+        Context,
+    },
+};
+use prost::Message as _;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::{
+    cli::sequencer::FeesArgs,
+    output::{
+        self,
+        Report,
+    },
+};
+
+/// The fee components of the Sequencer, named as in its genesis file
+#[derive(Clone, Debug, Deserialize, Serialize)]
+struct FeeComponents {
+    #[serde(serialize_with = "output::display")]
+    transfer_base_fee: u128,
+    #[serde(serialize_with = "output::display")]
+    sequence_base_fee: u128,
+    #[serde(serialize_with = "output::display")]
+    sequence_byte_cost_multiplier: u128,
+    #[serde(serialize_with = "output::display")]
+    init_bridge_account_base_fee: u128,
+    #[serde(serialize_with = "output::display")]
+    bridge_lock_byte_cost_multiplier: u128,
+    #[serde(serialize_with = "output::display")]
+    bridge_sudo_change_fee: u128,
+    #[serde(serialize_with = "output::display")]
+    ics20_withdrawal_base_fee: u128,
+}
+
+impl FeeComponents {
+    fn apply(&mut self, change: &FeeChangeAction) {
+        let component = match change.fee_change {
+            FeeChange::TransferBaseFee => &mut self.transfer_base_fee,
+            FeeChange::SequenceBaseFee => &mut self.sequence_base_fee,
+            FeeChange::SequenceByteCostMultiplier => &mut self.sequence_byte_cost_multiplier,
+            FeeChange::InitBridgeAccountBaseFee => &mut self.init_bridge_account_base_fee,
+            FeeChange::BridgeLockByteCostMultiplier => &mut self.bridge_lock_byte_cost_multiplier,
+            FeeChange::BridgeSudoChangeBaseFee => &mut self.bridge_sudo_change_fee,
+            FeeChange::Ics20WithdrawalBaseFee => &mut self.ics20_withdrawal_base_fee,
+        };
+        *component = change.new_value;
+    }
+
+    /// Returns the asset and amount of the fee charged for `action`, mirroring the fee
+    /// calculation of the Sequencer.
+    fn fee(&self, action: &Action) -> Option<(asset::Id, u128)> {
+        let fee = match action {
+            Action::Transfer(act) => (act.fee_asset_id, self.transfer_base_fee),
+            Action::AllowanceChange(act) => (act.fee_asset_id, self.transfer_base_fee),
+            Action::TransferFrom(act) => (act.fee_asset_id, self.transfer_base_fee),
+            Action::Sequence(act) => (
+                act.fee_asset_id,
+                self.sequence_byte_cost_multiplier
+                    .saturating_mul(byte_len(act.fee_weight()))
+                    .saturating_add(self.sequence_base_fee),
+            ),
+            Action::Ics20Withdrawal(act) => (*act.fee_asset_id(), self.ics20_withdrawal_base_fee),
+            Action::InitBridgeAccount(act) => (act.fee_asset_id, self.init_bridge_account_base_fee),
+            Action::BridgeLock(act) => (
+                act.fee_asset_id,
+                self.bridge_lock_byte_cost_multiplier
+                    .saturating_mul(byte_len(act.fee_weight()))
+                    .saturating_add(self.transfer_base_fee),
+            ),
+            Action::BridgeUnlock(act) => (act.fee_asset_id, self.transfer_base_fee),
+            Action::BridgeSudoChange(act) => (act.fee_asset_id, self.bridge_sudo_change_fee),
+            Action::BridgeRecoveryVeto(act) => (act.fee_asset_id, self.bridge_sudo_change_fee),
+            Action::ValidatorUpdate(_)
+            | Action::SudoAddressChange(_)
+            | Action::Ibc(_)
+            | Action::IbcRelayerChange(_)
+            | Action::FeeAssetChange(_)
+            | Action::FeeChange(_)
+            | Action::AddressPrefixChange(_)
+            | Action::BridgeRecovery(_)
+            | Action::FeeDistributionChange(_) => return None,
+        };
+        Some(fee)
+    }
+}
+
+impl Display for FeeComponents {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "    transfer: {}", self.transfer_base_fee)?;
+        writeln!(
+            f,
+            "    sequence: {} + {} per byte of data",
+            self.sequence_base_fee, self.sequence_byte_cost_multiplier
+        )?;
+        writeln!(
+            f,
+            "    init bridge account: {}",
+            self.init_bridge_account_base_fee
+        )?;
+        writeln!(
+            f,
+            "    bridge lock: {} + {} per byte of deposit",
+            self.transfer_base_fee, self.bridge_lock_byte_cost_multiplier
+        )?;
+        writeln!(f, "    bridge unlock: {}", self.transfer_base_fee)?;
+        writeln!(f, "    bridge sudo change: {}", self.bridge_sudo_change_fee)?;
+        write!(
+            f,
+            "    ics20 withdrawal: {}",
+            self.ics20_withdrawal_base_fee
+        )
+    }
+}
+
+fn byte_len(len: usize) -> u128 {
+    len.try_into()
+        .expect("a usize should always convert to a u128")
+}
+
+/// The subset of the Sequencer's genesis app state holding the fee components
+#[derive(Debug, Deserialize, Serialize)]
+struct AppState {
+    fees: FeeComponents,
+}
+
+#[derive(Serialize)]
+struct FeeChangeEntry {
+    height: u64,
+    fee: String,
+    #[serde(serialize_with = "output::display")]
+    new_value: u128,
+}
+
+#[derive(Serialize)]
+struct BlockFees {
+    height: u64,
+    transactions: usize,
+    /// The fees paid in the block by fee asset
+    #[serde(serialize_with = "serialize_amounts")]
+    fees: BTreeMap<String, u128>,
+}
+
+#[derive(Serialize)]
+struct Fees {
+    from_height: u32,
+    to_height: u32,
+    /// The fee components in effect at `to_height`
+    components: FeeComponents,
+    /// Whether all fee changes since genesis were taken into account. If not, fee changes
+    /// before `from_height` may have made `components` out of date
+    complete: bool,
+    changes: Vec<FeeChangeEntry>,
+    blocks: Vec<BlockFees>,
+}
+
+fn serialize_amounts<S: serde::Serializer>(
+    amounts: &BTreeMap<String, u128>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(
+        amounts
+            .iter()
+            .map(|(asset, amount)| (asset, amount.to_string())),
+    )
+}
+
+impl Display for Fees {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Fee components at height {}:", self.to_height)?;
+        writeln!(f, "{}", self.components)?;
+        if !self.complete {
+            writeln!(
+                f,
+                "warning: fee changes before height {} were not scanned; the components above \
+                 only reflect the genesis fees and the changes listed below",
+                self.from_height
+            )?;
+        }
+        if !self.changes.is_empty() {
+            writeln!(f, "Fee changes:")?;
+            for change in &self.changes {
+                writeln!(
+                    f,
+                    "    [{}] {} set to {}",
+                    change.height, change.fee, change.new_value
+                )?;
+            }
+        }
+
+        let mut totals: BTreeMap<&str, u128> = BTreeMap::new();
+        for block in &self.blocks {
+            for (asset, amount) in &block.fees {
+                let total = totals.entry(asset.as_str()).or_default();
+                *total = total.saturating_add(*amount);
+            }
+        }
+        write!(
+            f,
+            "Fees paid from height {} to {}:",
+            self.from_height, self.to_height
+        )?;
+        for block in self.blocks.iter().filter(|block| !block.fees.is_empty()) {
+            write!(
+                f,
+                "\n    [{}] {} transactions:",
+                block.height, block.transactions
+            )?;
+            for (asset, amount) in &block.fees {
+                write!(f, " {amount} {asset}")?;
+            }
+        }
+        if totals.is_empty() {
+            write!(f, "\n    no fees paid")?;
+        }
+        let blocks = byte_len(self.blocks.len().max(1));
+        for (asset, total) in totals {
+            write!(
+                f,
+                "\n    total: {total} {asset} (average {} per block)",
+                total / blocks
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for Fees {
+    const KIND: &'static str = "fees";
+}
+
+/// Shows the fee components of the Sequencer and the fees paid over a range of blocks
+///
+/// The fee components are read from the genesis file and updated with the fee changes found in
+/// the scanned blocks. Only transactions that executed successfully are charged fees.
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the http client cannot be created
+/// * If the genesis file does not contain the fee components
+/// * If a block or its results cannot be retrieved
+/// * If a transaction in a block cannot be decoded
+pub(crate) async fn fees(args: &FeesArgs) -> eyre::Result<()> {
+    ensure!(args.blocks > 0, "`--blocks` must be at least 1");
+    let sequencer_client = HttpClient::new(args.sequencer_url.as_str())
+        .wrap_err("failed constructing http sequencer client")?;
+
+    let mut components = sequencer_client
+        .genesis::<AppState>()
+        .await
+        .wrap_err("failed to get fee components from genesis")?
+        .app_state
+        .fees;
+
+    let to_height = match args.to_height {
+        Some(height) => height,
+        None => sequencer_client
+            .latest_block()
+            .await
+            .wrap_err("failed to get latest cometbft block")?
+            .block
+            .header
+            .height
+            .value()
+            .try_into()
+            .wrap_err("latest height does not fit into u32")?,
+    };
+    let from_height = to_height
+        .saturating_sub(args.blocks.saturating_sub(1))
+        .max(1);
+
+    let mut changes = vec![];
+    let mut blocks = vec![];
+    for height in from_height..=to_height {
+        let height = tendermint::block::Height::from(height);
+        let block = sequencer_client
+            .block(height)
+            .await
+            .wrap_err_with(|| format!("failed to get block at height {height}"))?
+            .block;
+        let results = sequencer_client
+            .block_results(height)
+            .await
+            .wrap_err_with(|| format!("failed to get block results at height {height}"))?
+            .txs_results
+            .unwrap_or_default();
+
+        let mut block_fees = BlockFees {
+            height: height.value(),
+            transactions: 0,
+            fees: BTreeMap::new(),
+        };
+        // the block data starts with the block commitments, which are not transactions
+        let commitment_count = block_commitment_count_of_data(&block.data);
+        for (tx_bytes, result) in block.data.iter().zip(&results).skip(commitment_count) {
+            block_fees.transactions = block_fees.transactions.saturating_add(1);
+            if result.code.is_err() {
+                continue;
+            }
+            let raw_tx = raw::SignedTransaction::decode(&**tx_bytes)
+                .wrap_err_with(|| format!("failed decoding transaction at height {height}"))?;
+            let tx = SignedTransaction::try_from_raw(raw_tx)
+                .wrap_err_with(|| format!("invalid transaction at height {height}"))?;
+            for action in tx.actions() {
+                if let Some((asset_id, amount)) = components.fee(action) {
+                    let total = block_fees.fees.entry(asset_label(asset_id)).or_default();
+                    *total = total.saturating_add(amount);
+                }
+                // fee changes take effect for the actions following them
+                if let Action::FeeChange(change) = action {
+                    components.apply(change);
+                    changes.push(FeeChangeEntry {
+                        height: height.value(),
+                        fee: format!("{:?}", change.fee_change),
+                        new_value: change.new_value,
+                    });
+                }
+            }
+        }
+        blocks.push(block_fees);
+    }
+
+    output::emit(&Fees {
+        from_height,
+        to_height,
+        components,
+        complete: from_height == 1,
+        changes,
+        blocks,
+    })
+}
+
+/// Returns the denomination of the native asset for its ID, and the hex-encoded ID otherwise.
+fn asset_label(asset_id: asset::Id) -> String {
+    let native = asset::default_native_asset();
+    if asset_id == native.id() {
+        native.to_string()
+    } else {
+        hex::encode(asset_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::{
+            asset,
+            RollupId,
+        },
+        protocol::transaction::v1alpha1::{
+            action::{
+                FeeChange,
+                FeeChangeAction,
+                SequenceAction,
+                TransferAction,
+            },
+            Action,
+        },
+    };
+
+    use super::FeeComponents;
+
+    const ALICE: &str = "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm";
+
+    fn components() -> FeeComponents {
+        serde_json::from_str(
+            r#"{
+                "transfer_base_fee": 12,
+                "sequence_base_fee": 32,
+                "sequence_byte_cost_multiplier": 1,
+                "init_bridge_account_base_fee": 48,
+                "bridge_lock_byte_cost_multiplier": 1,
+                "bridge_sudo_change_fee": 24,
+                "ics20_withdrawal_base_fee": 24
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn fees_follow_components_and_changes() {
+        let fee_asset_id = asset::default_native_asset().id();
+        let transfer = Action::Transfer(TransferAction {
+            to: ALICE.parse().unwrap(),
+            amount: 100,
+            asset_id: fee_asset_id,
+            fee_asset_id,
+        });
+        let sequence = Action::Sequence(SequenceAction {
+            rollup_id: RollupId::from_unhashed_bytes(b"rollup"),
+            data: vec![0; 10],
+            fee_asset_id,
+        });
+
+        let mut components = components();
+        assert_eq!(Some((fee_asset_id, 12)), components.fee(&transfer));
+        assert_eq!(Some((fee_asset_id, 42)), components.fee(&sequence));
+
+        components.apply(&FeeChangeAction {
+            fee_change: FeeChange::SequenceByteCostMultiplier,
+            new_value: 3,
+        });
+        assert_eq!(Some((fee_asset_id, 62)), components.fee(&sequence));
+    }
+}
@@ -0,0 +1,1183 @@
+use std::{
+    fmt::Display,
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+use astria_core::{
+    generated::protocol::transaction::v1alpha1 as raw,
+    primitive::v1::{
+        asset::Denom,
+        Address,
+    },
+    protocol::transaction::v1alpha1::{
+        action::Ics20Withdrawal,
+        Action,
+    },
+};
+use astria_sequencer_client::{
+    HttpClient,
+    SequencerClientExt as _,
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        bail,
+        eyre,
+        Context,
+    },
+};
+use ibc_proto::ibc::{
+    core::{
+        channel::v1::{
+            query_client::QueryClient as ChannelQueryClient,
+            Order,
+            QueryChannelRequest,
+            QueryChannelsRequest,
+            State as ChannelState,
+        },
+        client::v1::{
+            query_client::QueryClient as ClientQueryClient,
+            Height,
+            IdentifiedClientState,
+            QueryClientStateRequest,
+            QueryClientStatesRequest,
+            QueryConsensusStateRequest,
+        },
+        connection::v1::{
+            query_client::QueryClient as ConnectionQueryClient,
+            QueryConnectionRequest,
+            QueryConnectionsRequest,
+            State as ConnectionState,
+        },
+    },
+    lightclients::tendermint::v1::{
+        ClientState as TendermintClientState,
+        ConsensusState as TendermintConsensusState,
+    },
+};
+use prost::Message as _;
+use serde::{
+    Serialize,
+    Serializer,
+};
+
+use super::sequencer::{
+    submit_actions,
+    TransactionReceipt,
+};
+use crate::{
+    cli::sequencer::{
+        IbcArgs,
+        Ics20WithdrawalArgs,
+    },
+    output::{
+        self,
+        Report,
+    },
+    signer::Signer,
+};
+
+const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
+
+/// The port of ICS20 transfer channels
+const TRANSFER_PORT: &str = "transfer";
+
+/// Timeouts shorter than this risk expiring before a relayer picks up the withdrawal.
+const MIN_SAFE_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// The bech32 prefixes of well-known counterparty chains, keyed by the prefix of their chain ID
+const KNOWN_ADDRESS_PREFIXES: &[(&str, &str)] = &[
+    ("celestia", "celestia"),
+    ("mocha", "celestia"),
+    ("arabica", "celestia"),
+    ("cosmoshub", "cosmos"),
+    ("theta-testnet", "cosmos"),
+    ("osmosis", "osmo"),
+    ("osmo-test", "osmo"),
+    ("noble", "noble"),
+    ("grand", "noble"),
+];
+
+/// The status of a light client, following the statuses reported by ibc-go
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum ClientStatus {
+    Active,
+    Expired,
+    Frozen,
+    Unknown,
+}
+
+impl ClientStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Active => "active",
+            Self::Expired => "expired",
+            Self::Frozen => "frozen",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+/// A light client tracked by the Sequencer
+#[derive(Serialize)]
+struct Client {
+    client_id: String,
+    client_type: String,
+    chain_id: Option<String>,
+    #[serde(serialize_with = "serialize_height")]
+    latest_height: Option<Height>,
+    #[serde(
+        rename = "trusting_period_secs",
+        serialize_with = "serialize_duration_secs"
+    )]
+    trusting_period: Option<Duration>,
+    #[serde(serialize_with = "serialize_time")]
+    last_update: Option<SystemTime>,
+    status: ClientStatus,
+}
+
+#[derive(Serialize)]
+struct Clients {
+    clients: Vec<Client>,
+}
+
+impl Display for Clients {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IBC clients:")?;
+        if self.clients.is_empty() {
+            write!(f, "\n    no clients")?;
+        }
+        for client in &self.clients {
+            write!(
+                f,
+                "\n    {} ({}) tracking `{}` at height {}: {}",
+                client.client_id,
+                client.client_type,
+                client.chain_id.as_deref().unwrap_or("<unknown chain>"),
+                client
+                    .latest_height
+                    .as_ref()
+                    .map_or_else(|| "<unknown>".to_string(), format_height),
+                client.status.as_str(),
+            )?;
+            if let (Some(last_update), Some(trusting_period)) =
+                (client.last_update, client.trusting_period)
+            {
+                write!(
+                    f,
+                    "\n        last updated at {}, trusting period {}",
+                    format_time(last_update),
+                    humantime::format_duration(trusting_period),
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Report for Clients {
+    const KIND: &'static str = "ibc_clients";
+}
+
+#[derive(Serialize)]
+struct Connection {
+    connection_id: String,
+    client_id: String,
+    state: &'static str,
+    counterparty_client_id: Option<String>,
+    counterparty_connection_id: Option<String>,
+    delay_period: u64,
+}
+
+#[derive(Serialize)]
+struct Connections {
+    connections: Vec<Connection>,
+}
+
+impl Display for Connections {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IBC connections:")?;
+        if self.connections.is_empty() {
+            write!(f, "\n    no connections")?;
+        }
+        for connection in &self.connections {
+            write!(
+                f,
+                "\n    {} on {} ({}) <-> {} on {}",
+                connection.connection_id,
+                connection.client_id,
+                connection.state,
+                connection
+                    .counterparty_connection_id
+                    .as_deref()
+                    .unwrap_or("<unknown>"),
+                connection
+                    .counterparty_client_id
+                    .as_deref()
+                    .unwrap_or("<unknown>"),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for Connections {
+    const KIND: &'static str = "ibc_connections";
+}
+
+#[derive(Serialize)]
+struct Channel {
+    port_id: String,
+    channel_id: String,
+    state: &'static str,
+    ordering: &'static str,
+    connection_hops: Vec<String>,
+    version: String,
+    counterparty_port_id: Option<String>,
+    counterparty_channel_id: Option<String>,
+}
+
+#[derive(Serialize)]
+struct Channels {
+    channels: Vec<Channel>,
+}
+
+impl Display for Channels {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IBC channels:")?;
+        if self.channels.is_empty() {
+            write!(f, "\n    no channels")?;
+        }
+        for channel in &self.channels {
+            write!(
+                f,
+                "\n    {}/{} ({}, {}) <-> {}/{} via {}",
+                channel.port_id,
+                channel.channel_id,
+                channel.state,
+                channel.ordering,
+                channel
+                    .counterparty_port_id
+                    .as_deref()
+                    .unwrap_or("<unknown>"),
+                channel
+                    .counterparty_channel_id
+                    .as_deref()
+                    .unwrap_or("<unknown>"),
+                channel.connection_hops.join(","),
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for Channels {
+    const KIND: &'static str = "ibc_channels";
+}
+
+/// Lists the IBC light clients of the Sequencer, flagging expired and frozen ones
+///
+/// A client is expired if its latest consensus state is older than its trusting period.
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the gRPC client cannot connect to the Sequencer
+/// * If the client states cannot be retrieved
+pub(crate) async fn clients(args: &IbcArgs) -> eyre::Result<()> {
+    let mut grpc_client = ClientQueryClient::connect(args.sequencer_grpc.clone())
+        .await
+        .wrap_err("failed connecting to sequencer grpc server")?;
+    let client_states = grpc_client
+        .client_states(QueryClientStatesRequest {
+            pagination: None,
+        })
+        .await
+        .wrap_err("failed to get ibc client states")?
+        .into_inner()
+        .client_states;
+
+    let now = SystemTime::now();
+    let mut clients = vec![];
+    for client_state in client_states {
+        clients.push(resolve_client(&mut grpc_client, client_state, now).await?);
+    }
+
+    output::emit(&Clients {
+        clients,
+    })
+}
+
+/// Lists the IBC connections of the Sequencer
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the gRPC client cannot connect to the Sequencer
+/// * If the connections cannot be retrieved
+pub(crate) async fn connections(args: &IbcArgs) -> eyre::Result<()> {
+    let connections = ConnectionQueryClient::connect(args.sequencer_grpc.clone())
+        .await
+        .wrap_err("failed connecting to sequencer grpc server")?
+        .connections(QueryConnectionsRequest {
+            pagination: None,
+        })
+        .await
+        .wrap_err("failed to get ibc connections")?
+        .into_inner()
+        .connections
+        .into_iter()
+        .map(|connection| {
+            let counterparty = connection.counterparty;
+            Connection {
+                state: connection_state(connection.state),
+                connection_id: connection.id,
+                client_id: connection.client_id,
+                counterparty_client_id: counterparty.as_ref().map(|c| c.client_id.clone()),
+                counterparty_connection_id: counterparty.map(|c| c.connection_id),
+                delay_period: connection.delay_period,
+            }
+        })
+        .collect();
+    output::emit(&Connections {
+        connections,
+    })
+}
+
+/// Lists the IBC channels of the Sequencer
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the gRPC client cannot connect to the Sequencer
+/// * If the channels cannot be retrieved
+pub(crate) async fn channels(args: &IbcArgs) -> eyre::Result<()> {
+    let channels = ChannelQueryClient::connect(args.sequencer_grpc.clone())
+        .await
+        .wrap_err("failed connecting to sequencer grpc server")?
+        .channels(QueryChannelsRequest {
+            pagination: None,
+        })
+        .await
+        .wrap_err("failed to get ibc channels")?
+        .into_inner()
+        .channels
+        .into_iter()
+        .map(|channel| {
+            let counterparty = channel.counterparty;
+            Channel {
+                state: channel_state(channel.state),
+                ordering: channel_order(channel.ordering),
+                port_id: channel.port_id,
+                channel_id: channel.channel_id,
+                connection_hops: channel.connection_hops,
+                version: channel.version,
+                counterparty_port_id: counterparty.as_ref().map(|c| c.port_id.clone()),
+                counterparty_channel_id: counterparty.map(|c| c.channel_id),
+            }
+        })
+        .collect();
+    output::emit(&Channels {
+        channels,
+    })
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum CheckStatus {
+    Passed,
+    Warning,
+    Failed,
+}
+
+/// The result of one of the checks run before submitting an ICS20 withdrawal
+#[derive(Debug, Serialize)]
+struct Check {
+    name: &'static str,
+    status: CheckStatus,
+    detail: String,
+}
+
+impl Check {
+    fn passed(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Passed,
+            detail: detail.into(),
+        }
+    }
+
+    fn warning(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Warning,
+            detail: detail.into(),
+        }
+    }
+
+    fn failed(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Failed,
+            detail: detail.into(),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct Preflight {
+    counterparty_chain_id: Option<String>,
+    /// Whether the withdrawn funds are held in escrow on the channel until the withdrawal is
+    /// acknowledged, rather than burned
+    escrowed: bool,
+    checks: Vec<Check>,
+}
+
+impl Preflight {
+    fn failed(&self) -> bool {
+        self.checks
+            .iter()
+            .any(|check| check.status == CheckStatus::Failed)
+    }
+}
+
+impl Display for Preflight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Preflight checks for withdrawal to `{}` ({}):",
+            self.counterparty_chain_id
+                .as_deref()
+                .unwrap_or("<unknown chain>"),
+            if self.escrowed {
+                "funds are escrowed"
+            } else {
+                "funds are burned"
+            },
+        )?;
+        for check in &self.checks {
+            let status = match check.status {
+                CheckStatus::Passed => "ok",
+                CheckStatus::Warning => "warning",
+                CheckStatus::Failed => "FAILED",
+            };
+            write!(f, "\n    [{status}] {}: {}", check.name, check.detail)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+struct Withdrawal {
+    preflight: Preflight,
+    /// The receipt of the submitted withdrawal; not set if it was not submitted
+    receipt: Option<TransactionReceipt>,
+}
+
+impl Display for Withdrawal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.preflight)?;
+        if let Some(receipt) = &self.receipt {
+            write!(f, "\n{receipt}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for Withdrawal {
+    const KIND: &'static str = "ics20_withdrawal";
+}
+
+/// Withdraws funds over an ICS20 channel after running preflight checks
+///
+/// The checks verify that the channel is open and its light client is active, that the signer
+/// holds enough of the withdrawn and the fee asset, that the destination address matches the
+/// address prefix of the counterparty chain, and that the timeout leaves relayers enough time
+/// without outliving the light client. Withdrawals that time out after the light client expired
+/// cannot be refunded, leaving the funds stuck in escrow.
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the gRPC client cannot connect to the Sequencer
+/// * If the channel, its connection, or its light client cannot be retrieved
+/// * If the balances of the signer cannot be retrieved
+/// * If a preflight check failed and `--force` was not given
+/// * If the transaction failed to be included
+pub(crate) async fn withdraw(args: &Ics20WithdrawalArgs) -> eyre::Result<()> {
+    let signer = Signer::from_args(&args.signer)?;
+    let from_address = Address::builder()
+        .array(signer.verification_key().address_bytes())
+        .prefix(&args.prefix)
+        .try_build()
+        .wrap_err("failed constructing a valid signer address from the provided prefix")?;
+    let timeout_height = args
+        .timeout_height
+        .as_deref()
+        .map(parse_height)
+        .transpose()?;
+    let timeout_time = SystemTime::now()
+        .checked_add(args.timeout)
+        .ok_or_else(|| eyre!("`--timeout` is too large"))?;
+
+    let mut checks = vec![];
+    let client = channel_client(&args.ibc, &args.source_channel, &mut checks).await?;
+    let counterparty_chain_id = client.as_ref().and_then(|client| client.chain_id.clone());
+    if let Some(client) = &client {
+        checks.extend(check_timeout(
+            client,
+            timeout_time,
+            timeout_height.as_ref(),
+            SystemTime::now(),
+        ));
+    }
+    let expected_prefix = args.destination_prefix.as_deref().or_else(|| {
+        counterparty_chain_id
+            .as_deref()
+            .and_then(known_address_prefix)
+    });
+    checks.push(check_destination_address(
+        &args.destination_chain_address,
+        expected_prefix,
+    ));
+    checks.extend(check_route(&args.denom, &args.source_channel));
+
+    let sender = args.bridge_address.unwrap_or(from_address);
+    let sequencer_client = HttpClient::new(args.sequencer_url.as_str())
+        .wrap_err("failed constructing http sequencer client")?;
+    checks.extend(check_balances(&sequencer_client, sender, args).await?);
+
+    let preflight = Preflight {
+        counterparty_chain_id,
+        escrowed: is_escrowed(&args.denom, &args.source_channel),
+        checks,
+    };
+    if args.preflight_only || (preflight.failed() && !args.force) {
+        let failed = preflight.failed();
+        output::emit(&Withdrawal {
+            preflight,
+            receipt: None,
+        })?;
+        if failed {
+            bail!("preflight checks failed; pass `--force` to submit the withdrawal anyway");
+        }
+        return Ok(());
+    }
+
+    let withdrawal = Ics20Withdrawal::try_from_raw(raw::Ics20Withdrawal {
+        amount: Some(args.amount.into()),
+        denom: args.denom.to_string(),
+        destination_chain_address: args.destination_chain_address.clone(),
+        return_address: Some(args.return_address.unwrap_or(from_address).into_raw()),
+        timeout_height: Some(
+            timeout_height
+                .map(|height| raw::IbcHeight {
+                    revision_number: height.revision_number,
+                    revision_height: height.revision_height,
+                })
+                .unwrap_or_default(),
+        ),
+        timeout_time: timeout_time
+            .duration_since(UNIX_EPOCH)
+            .wrap_err("timeout is before the unix epoch")?
+            .as_nanos()
+            .try_into()
+            .wrap_err("timeout does not fit into u64 nanoseconds")?,
+        source_channel: args.source_channel.clone(),
+        fee_asset_id: args.fee_asset.id().get().to_vec(),
+        memo: args.memo.clone(),
+        bridge_address: args.bridge_address.map(Address::into_raw),
+    })
+    .wrap_err("invalid ics20 withdrawal")?;
+
+    let Some(res) = submit_actions(
+        args.sequencer_url.as_str(),
+        args.sequencer_chain_id.clone(),
+        &args.prefix,
+        &signer,
+        args.dry_run,
+        args.wait.inclusion_timeout(),
+        vec![Action::Ics20Withdrawal(withdrawal)],
+    )
+    .await
+    .wrap_err("failed to submit ics20 withdrawal transaction")?
+    else {
+        return Ok(());
+    };
+
+    output::emit(&Withdrawal {
+        preflight,
+        receipt: Some(TransactionReceipt::new("Ics20Withdrawal", &res)),
+    })
+}
+
+/// Checks that `channel_id` is an open transfer channel and resolves the light client of its
+/// counterparty.
+///
+/// Returns `None` if the channel or its connection do not exist, recording a failed check.
+async fn channel_client(
+    args: &IbcArgs,
+    channel_id: &str,
+    checks: &mut Vec<Check>,
+) -> eyre::Result<Option<Client>> {
+    let Some(channel) = ChannelQueryClient::connect(args.sequencer_grpc.clone())
+        .await
+        .wrap_err("failed connecting to sequencer grpc server")?
+        .channel(QueryChannelRequest {
+            port_id: TRANSFER_PORT.to_string(),
+            channel_id: channel_id.to_string(),
+        })
+        .await
+        .ok()
+        .and_then(|res| res.into_inner().channel)
+    else {
+        checks.push(Check::failed(
+            "channel",
+            format!("`{TRANSFER_PORT}/{channel_id}` does not exist"),
+        ));
+        return Ok(None);
+    };
+    if ChannelState::try_from(channel.state) == Ok(ChannelState::Open) {
+        checks.push(Check::passed(
+            "channel",
+            format!("`{TRANSFER_PORT}/{channel_id}` is open"),
+        ));
+    } else {
+        checks.push(Check::failed(
+            "channel",
+            format!(
+                "`{TRANSFER_PORT}/{channel_id}` is {}",
+                channel_state(channel.state)
+            ),
+        ));
+    }
+
+    let Some(connection_id) = channel.connection_hops.first() else {
+        checks.push(Check::failed("client", "the channel has no connection"));
+        return Ok(None);
+    };
+    let Some(connection) = ConnectionQueryClient::connect(args.sequencer_grpc.clone())
+        .await
+        .wrap_err("failed connecting to sequencer grpc server")?
+        .connection(QueryConnectionRequest {
+            connection_id: connection_id.clone(),
+        })
+        .await
+        .wrap_err_with(|| format!("failed to get ibc connection `{connection_id}`"))?
+        .into_inner()
+        .connection
+    else {
+        checks.push(Check::failed(
+            "client",
+            format!("connection `{connection_id}` does not exist"),
+        ));
+        return Ok(None);
+    };
+
+    let mut grpc_client = ClientQueryClient::connect(args.sequencer_grpc.clone())
+        .await
+        .wrap_err("failed connecting to sequencer grpc server")?;
+    let client_state = grpc_client
+        .client_state(QueryClientStateRequest {
+            client_id: connection.client_id.clone(),
+        })
+        .await
+        .wrap_err_with(|| format!("failed to get ibc client `{}`", connection.client_id))?
+        .into_inner()
+        .client_state;
+    let client = resolve_client(
+        &mut grpc_client,
+        IdentifiedClientState {
+            client_id: connection.client_id,
+            client_state,
+        },
+        SystemTime::now(),
+    )
+    .await?;
+    checks.push(match client.status {
+        ClientStatus::Active => {
+            Check::passed("client", format!("`{}` is active", client.client_id))
+        }
+        ClientStatus::Unknown => Check::warning(
+            "client",
+            format!("the status of `{}` is unknown", client.client_id),
+        ),
+        ClientStatus::Expired | ClientStatus::Frozen => Check::failed(
+            "client",
+            format!(
+                "`{}` is {}; packets cannot be relayed",
+                client.client_id,
+                client.status.as_str()
+            ),
+        ),
+    });
+    Ok(Some(client))
+}
+
+/// Checks that the timeout leaves relayers enough time, that the light client does not expire
+/// before the timeout, and that the timeout height has not passed already.
+fn check_timeout(
+    client: &Client,
+    timeout_time: SystemTime,
+    timeout_height: Option<&Height>,
+    now: SystemTime,
+) -> Vec<Check> {
+    let mut checks = vec![];
+    let remaining = timeout_time.duration_since(now).unwrap_or_default();
+    if remaining < MIN_SAFE_TIMEOUT {
+        checks.push(Check::warning(
+            "timeout",
+            format!(
+                "the withdrawal times out in {}, which may not leave relayers enough time",
+                humantime::format_duration(remaining)
+            ),
+        ));
+    } else {
+        checks.push(Check::passed(
+            "timeout",
+            format!("the withdrawal times out at {}", format_time(timeout_time)),
+        ));
+    }
+
+    if let (Some(last_update), Some(trusting_period)) = (client.last_update, client.trusting_period)
+    {
+        let expiry = last_update.checked_add(trusting_period);
+        if expiry.map_or(true, |expiry| expiry < timeout_time) {
+            checks.push(Check::warning(
+                "client expiry",
+                format!(
+                    "`{}` expires before the timeout unless it is updated; an unrelayed \
+                     withdrawal could then not be refunded, leaving the funds in escrow",
+                    client.client_id
+                ),
+            ));
+        }
+    }
+
+    if let (Some(timeout_height), Some(latest_height)) = (timeout_height, &client.latest_height) {
+        let passed = (
+            timeout_height.revision_number,
+            timeout_height.revision_height,
+        ) <= (latest_height.revision_number, latest_height.revision_height);
+        if passed {
+            checks.push(Check::failed(
+                "timeout height",
+                format!(
+                    "timeout height {} is not after the latest height {} of the counterparty",
+                    format_height(timeout_height),
+                    format_height(latest_height)
+                ),
+            ));
+        }
+    }
+    checks
+}
+
+/// Checks that `address` is a bech32 address with the prefix of the destination chain.
+///
+/// The prefix can only be checked if it is known; other chains may not use bech32 addresses at
+/// all.
+fn check_destination_address(address: &str, expected_prefix: Option<&str>) -> Check {
+    const NAME: &str = "destination address";
+    match (bech32::decode(address), expected_prefix) {
+        (Ok((hrp, _)), Some(expected)) if hrp.as_str().eq_ignore_ascii_case(expected) => {
+            Check::passed(NAME, format!("has the expected prefix `{expected}`"))
+        }
+        (Ok((hrp, _)), Some(expected)) => Check::failed(
+            NAME,
+            format!(
+                "has prefix `{}`, but the destination chain uses `{expected}`",
+                hrp.as_str()
+            ),
+        ),
+        (Err(_), Some(expected)) => Check::failed(
+            NAME,
+            format!("is not a bech32 address with prefix `{expected}`"),
+        ),
+        (Ok((hrp, _)), None) => Check::warning(
+            NAME,
+            format!(
+                "has prefix `{}`, but the prefix of the destination chain is unknown; pass \
+                 `--destination-prefix` to check it",
+                hrp.as_str()
+            ),
+        ),
+        (Err(_), None) => Check::warning(NAME, "is not a bech32 address"),
+    }
+}
+
+/// Warns if `denom` is sent over a different channel than it was received over, in which case
+/// the destination chain receives a voucher instead of its native asset.
+fn check_route(denom: &Denom, channel_id: &str) -> Option<Check> {
+    let trace = denom.as_trace_prefixed()?;
+    let last_channel = trace.last_channel()?;
+    if last_channel == channel_id {
+        return Some(Check::passed(
+            "route",
+            format!("`{denom}` returns to its origin over `{channel_id}`"),
+        ));
+    }
+    Some(Check::warning(
+        "route",
+        format!(
+            "`{denom}` was received over `{last_channel}`; withdrawing over `{channel_id}` sends \
+             a voucher rather than the original asset"
+        ),
+    ))
+}
+
+/// Checks that the sender holds the withdrawn amount and some of the fee asset.
+async fn check_balances(
+    sequencer_client: &HttpClient,
+    sender: Address,
+    args: &Ics20WithdrawalArgs,
+) -> eyre::Result<Vec<Check>> {
+    let balances = sequencer_client
+        .get_latest_balance(sender)
+        .await
+        .wrap_err_with(|| format!("failed to get balances of {sender}"))?
+        .balances;
+    let balance_of = |denom: &Denom| {
+        balances
+            .iter()
+            .find(|balance| balance.denom.id() == denom.id())
+            .map_or(0, |balance| balance.balance)
+    };
+
+    let mut checks = vec![];
+    let balance = balance_of(&args.denom);
+    checks.push(if balance >= args.amount {
+        Check::passed(
+            "balance",
+            format!("{sender} holds {balance} {}", args.denom),
+        )
+    } else {
+        Check::failed(
+            "balance",
+            format!(
+                "{sender} holds {balance} {}, less than the withdrawn {}",
+                args.denom, args.amount
+            ),
+        )
+    });
+
+    let allowed = sequencer_client
+        .get_allowed_fee_asset_ids()
+        .await
+        .wrap_err("failed to get allowed fee assets")?
+        .fee_asset_ids;
+    let fee_balance = balance_of(&args.fee_asset);
+    let spare = if args.fee_asset.id() == args.denom.id() {
+        fee_balance.saturating_sub(args.amount)
+    } else {
+        fee_balance
+    };
+    checks.push(if !allowed.contains(&args.fee_asset.id()) {
+        Check::failed(
+            "fee",
+            format!("`{}` is not an allowed fee asset", args.fee_asset),
+        )
+    } else if spare == 0 {
+        Check::failed(
+            "fee",
+            format!("{sender} has no `{}` left to pay the fee", args.fee_asset),
+        )
+    } else {
+        Check::passed(
+            "fee",
+            format!("{spare} {} are available for the fee", args.fee_asset),
+        )
+    });
+    Ok(checks)
+}
+
+/// Returns whether withdrawing `denom` over `channel_id` moves the funds into escrow, following
+/// the Sequencer: only assets that did not arrive over the channel are escrowed, all others are
+/// burned.
+fn is_escrowed(denom: &Denom, channel_id: &str) -> bool {
+    denom
+        .as_trace_prefixed()
+        .is_some_and(|trace| !trace.starts_with_str(&format!("{TRANSFER_PORT}/{channel_id}")))
+}
+
+fn known_address_prefix(chain_id: &str) -> Option<&'static str> {
+    KNOWN_ADDRESS_PREFIXES
+        .iter()
+        .find(|(chain, _)| chain_id.starts_with(chain))
+        .map(|(_, prefix)| *prefix)
+}
+
+/// Parses an IBC height given as `<revision-number>-<revision-height>`.
+fn parse_height(height: &str) -> eyre::Result<Height> {
+    let (revision_number, revision_height) = height
+        .split_once('-')
+        .ok_or_else(|| eyre!("height `{height}` is not of the form `<revision>-<height>`"))?;
+    Ok(Height {
+        revision_number: revision_number
+            .parse()
+            .wrap_err("invalid revision number")?,
+        revision_height: revision_height
+            .parse()
+            .wrap_err("invalid revision height")?,
+    })
+}
+
+/// Decodes a client state and fetches its latest consensus state to determine its status.
+///
+/// Only tendermint light clients are decoded; the status of any other client is unknown.
+async fn resolve_client(
+    grpc_client: &mut ClientQueryClient<tonic::transport::Channel>,
+    identified: IdentifiedClientState,
+    now: SystemTime,
+) -> eyre::Result<Client> {
+    let client_id = identified.client_id;
+    let client_type = identified
+        .client_state
+        .as_ref()
+        .map_or_else(String::new, |any| any.type_url.clone());
+    let mut client = Client {
+        client_id,
+        client_type,
+        chain_id: None,
+        latest_height: None,
+        trusting_period: None,
+        last_update: None,
+        status: ClientStatus::Unknown,
+    };
+    let Some(any) = identified
+        .client_state
+        .filter(|any| any.type_url == TENDERMINT_CLIENT_STATE_TYPE_URL)
+    else {
+        return Ok(client);
+    };
+    let client_state = TendermintClientState::decode(&*any.value).wrap_err_with(|| {
+        format!(
+            "failed decoding tendermint client state of `{}`",
+            client.client_id
+        )
+    })?;
+    client.chain_id = Some(client_state.chain_id);
+    client.trusting_period = client_state
+        .trusting_period
+        .and_then(|period| Some(Duration::from_secs(u64::try_from(period.seconds).ok()?)));
+    let frozen = client_state
+        .frozen_height
+        .is_some_and(|height| height.revision_number != 0 || height.revision_height != 0);
+
+    if let Some(latest_height) = client_state.latest_height {
+        let consensus_state = grpc_client
+            .consensus_state(QueryConsensusStateRequest {
+                client_id: client.client_id.clone(),
+                revision_number: latest_height.revision_number,
+                revision_height: latest_height.revision_height,
+                latest_height: false,
+            })
+            .await
+            .wrap_err_with(|| {
+                format!(
+                    "failed to get latest consensus state of `{}`",
+                    client.client_id
+                )
+            })?
+            .into_inner()
+            .consensus_state;
+        if let Some(any) = consensus_state {
+            let consensus_state =
+                TendermintConsensusState::decode(&*any.value).wrap_err_with(|| {
+                    format!(
+                        "failed decoding tendermint consensus state of `{}`",
+                        client.client_id
+                    )
+                })?;
+            client.last_update = consensus_state.timestamp.and_then(|timestamp| {
+                Some(UNIX_EPOCH + Duration::from_secs(u64::try_from(timestamp.seconds).ok()?))
+            });
+        }
+        client.latest_height = Some(latest_height);
+    }
+
+    client.status = client_status(frozen, client.last_update, client.trusting_period, now);
+    Ok(client)
+}
+
+/// Determines the status of a tendermint light client.
+///
+/// A frozen client stays frozen regardless of its age. Otherwise a client whose last update is
+/// older than its trusting period is expired.
+fn client_status(
+    frozen: bool,
+    last_update: Option<SystemTime>,
+    trusting_period: Option<Duration>,
+    now: SystemTime,
+) -> ClientStatus {
+    if frozen {
+        return ClientStatus::Frozen;
+    }
+    let (Some(last_update), Some(trusting_period)) = (last_update, trusting_period) else {
+        return ClientStatus::Unknown;
+    };
+    match last_update.checked_add(trusting_period) {
+        Some(expiry) if expiry > now => ClientStatus::Active,
+        _ => ClientStatus::Expired,
+    }
+}
+
+fn format_height(height: &Height) -> String {
+    format!("{}-{}", height.revision_number, height.revision_height)
+}
+
+fn format_time(time: SystemTime) -> String {
+    humantime::format_rfc3339_seconds(time).to_string()
+}
+
+fn connection_state(state: i32) -> &'static str {
+    ConnectionState::try_from(state).map_or("STATE_UNKNOWN", |state| state.as_str_name())
+}
+
+fn channel_state(state: i32) -> &'static str {
+    ChannelState::try_from(state).map_or("STATE_UNKNOWN", |state| state.as_str_name())
+}
+
+fn channel_order(order: i32) -> &'static str {
+    Order::try_from(order).map_or("ORDER_UNKNOWN", |order| order.as_str_name())
+}
+
+fn serialize_height<S: Serializer>(
+    height: &Option<Height>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match height {
+        Some(height) => serializer.serialize_str(&format_height(height)),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn serialize_duration_secs<S: Serializer>(
+    duration: &Option<Duration>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match duration {
+        Some(duration) => serializer.serialize_u64(duration.as_secs()),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn serialize_time<S: Serializer>(
+    time: &Option<SystemTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match time {
+        Some(time) => serializer.serialize_str(&format_time(*time)),
+        None => serializer.serialize_none(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{
+        Duration,
+        SystemTime,
+    };
+
+    use super::{
+        check_destination_address,
+        check_timeout,
+        client_status,
+        parse_height,
+        CheckStatus,
+        Client,
+        ClientStatus,
+    };
+
+    #[test]
+    fn client_status_follows_trusting_period() {
+        let now = SystemTime::now();
+        let day = Duration::from_secs(24 * 60 * 60);
+        let updated = Some(now - 2 * day);
+
+        assert_eq!(
+            ClientStatus::Active,
+            client_status(false, updated, Some(3 * day), now)
+        );
+        assert_eq!(
+            ClientStatus::Expired,
+            client_status(false, updated, Some(day), now)
+        );
+        assert_eq!(
+            ClientStatus::Frozen,
+            client_status(true, updated, Some(3 * day), now)
+        );
+        assert_eq!(
+            ClientStatus::Unknown,
+            client_status(false, None, Some(3 * day), now)
+        );
+    }
+
+    #[test]
+    fn destination_addresses_are_checked_against_the_prefix() {
+        let address = "celestia1qqqsyqcyq5rqwzqfpg9scrgwpugpzysnjuq2t5";
+        let status = |address, prefix| check_destination_address(address, prefix).status;
+        assert_eq!(CheckStatus::Passed, status(address, Some("celestia")));
+        assert_eq!(CheckStatus::Failed, status(address, Some("osmo")));
+        assert_eq!(CheckStatus::Failed, status("0xdeadbeef", Some("osmo")));
+        assert_eq!(CheckStatus::Warning, status(address, None));
+    }
+
+    #[test]
+    fn timeouts_outliving_the_client_or_in_the_past_are_flagged() {
+        let now = SystemTime::now();
+        let hour = Duration::from_secs(60 * 60);
+        let client = Client {
+            client_id: "07-tendermint-0".to_string(),
+            client_type: String::new(),
+            chain_id: Some("celestia".to_string()),
+            latest_height: Some(parse_height("1-100").unwrap()),
+            trusting_period: Some(2 * hour),
+            last_update: Some(now - hour),
+            status: ClientStatus::Active,
+        };
+        let statuses = |timeout, height: Option<&str>| {
+            let height = height.map(|height| parse_height(height).unwrap());
+            check_timeout(&client, now + timeout, height.as_ref(), now)
+                .into_iter()
+                .map(|check| (check.name, check.status))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            vec![("timeout", CheckStatus::Passed)],
+            statuses(hour / 2, Some("1-101"))
+        );
+        assert_eq!(
+            vec![
+                ("timeout", CheckStatus::Warning),
+                ("timeout height", CheckStatus::Failed),
+            ],
+            statuses(Duration::from_secs(60), Some("1-100"))
+        );
+        assert_eq!(
+            vec![
+                ("timeout", CheckStatus::Passed),
+                ("client expiry", CheckStatus::Warning),
+            ],
+            statuses(2 * hour, None)
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use std::{
+    env,
+    fmt::Display,
+};
+
+use age::secrecy::{
+    ExposeSecret as _,
+    Secret,
+    SecretString,
+};
+use astria_core::{
+    crypto::{
+        hd,
+        SigningKey,
+        VerificationKey,
+    },
+    primitive::v1::Address,
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        eyre,
+        Context,
+    },
+};
+use rand::rngs::OsRng;
+use serde::Serialize;
+
+use crate::{
+    cli::keys::{
+        ExportArgs,
+        GenerateArgs,
+        ImportArgs,
+        ListArgs,
+        SignArgs,
+    },
+    keystore::{
+        self,
+        Keystore,
+    },
+    output::{
+        self,
+        Report,
+    },
+    signer::signing_key_from_hex,
+};
+
+/// Generates a new signing key and stores it in the keystore
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the keystore cannot be opened
+/// * If the passphrase cannot be read
+/// * If the key cannot be stored
+pub(crate) fn generate(args: &GenerateArgs) -> eyre::Result<()> {
+    let keystore = Keystore::open(args.keystore.keystore_dir.as_deref())?;
+    let passphrase = keystore::read_passphrase(true)?;
+    let entry = keystore
+        .insert(&args.name, &SigningKey::new(OsRng), passphrase)
+        .wrap_err("failed storing generated key")?;
+
+    output::emit(&KeyAction {
+        action: "Generated",
+        key: Key::new(&entry.name, &entry.verification_key, &args.prefix)?,
+    })
+}
+
+/// The environment variable holding the mnemonic to derive an imported key from. If unset, the
+/// mnemonic is prompted for.
+const MNEMONIC_ENV_VAR: &str = "ASTRIA_MNEMONIC";
+
+/// The environment variable holding the BIP39 passphrase of the mnemonic. If unset, the
+/// passphrase is prompted for.
+const MNEMONIC_PASSPHRASE_ENV_VAR: &str = "ASTRIA_MNEMONIC_PASSPHRASE";
+
+/// Imports a hex-encoded private key, or a key derived from a BIP39 mnemonic, into the keystore
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the private key or mnemonic is not valid
+/// * If the keystore cannot be opened
+/// * If the passphrase cannot be read
+/// * If the key cannot be stored
+pub(crate) fn import(args: &ImportArgs) -> eyre::Result<()> {
+    let signing_key = if args.mnemonic {
+        let (mnemonic, passphrase) = read_mnemonic()?;
+        hd::signing_key_from_mnemonic(
+            mnemonic.expose_secret(),
+            passphrase.expose_secret(),
+            &args.derivation_path,
+        )
+        .wrap_err("failed deriving key from mnemonic")?
+    } else {
+        let private_key = args
+            .private_key
+            .as_deref()
+            .ok_or_else(|| eyre!("either `--private-key` or `--mnemonic` must be set"))?;
+        signing_key_from_hex(private_key)?
+    };
+    let keystore = Keystore::open(args.keystore.keystore_dir.as_deref())?;
+    let passphrase = keystore::read_passphrase(true)?;
+    let entry = keystore
+        .insert(&args.name, &signing_key, passphrase)
+        .wrap_err("failed storing imported key")?;
+
+    output::emit(&KeyAction {
+        action: "Imported",
+        key: Key::new(&entry.name, &entry.verification_key, &args.prefix)?,
+    })
+}
+
+/// Reads a BIP39 mnemonic and its passphrase from [`MNEMONIC_ENV_VAR`] and
+/// [`MNEMONIC_PASSPHRASE_ENV_VAR`], prompting for each that is unset.
+fn read_mnemonic() -> eyre::Result<(SecretString, SecretString)> {
+    let mnemonic = match env::var(MNEMONIC_ENV_VAR) {
+        Ok(mnemonic) => mnemonic,
+        Err(_) => rpassword::prompt_password("Mnemonic: ").wrap_err("failed reading mnemonic")?,
+    };
+    let passphrase = match env::var(MNEMONIC_PASSPHRASE_ENV_VAR) {
+        Ok(passphrase) => passphrase,
+        Err(_) => rpassword::prompt_password("Mnemonic passphrase (empty for none): ")
+            .wrap_err("failed reading mnemonic passphrase")?,
+    };
+    Ok((Secret::new(mnemonic), Secret::new(passphrase)))
+}
+
+/// Decrypts a key from the keystore and prints its private key
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the keystore cannot be opened
+/// * If the key cannot be decrypted
+pub(crate) fn export(args: &ExportArgs) -> eyre::Result<()> {
+    let keystore = Keystore::open(args.keystore.keystore_dir.as_deref())?;
+    let passphrase = keystore::read_passphrase(false)?;
+    let signing_key = keystore.signing_key(&args.name, &passphrase)?;
+    output::emit(&PrivateKey {
+        name: args.name.clone(),
+        private_key: hex::encode(signing_key.to_bytes()),
+    })
+}
+
+/// Lists all keys in the keystore with their public keys and addresses
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the keystore cannot be opened or read
+pub(crate) fn list(args: &ListArgs) -> eyre::Result<()> {
+    let keystore = Keystore::open(args.keystore.keystore_dir.as_deref())?;
+    let keys = keystore
+        .list()?
+        .iter()
+        .map(|entry| Key::new(&entry.name, &entry.verification_key, &args.prefix))
+        .collect::<eyre::Result<_>>()?;
+    output::emit(&Keys {
+        keys,
+    })
+}
+
+/// Signs a message with a key from the keystore and prints the hex-encoded signature
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the message is not valid hex when `--hex` is set
+/// * If the keystore cannot be opened
+/// * If the key cannot be decrypted
+pub(crate) fn sign(args: &SignArgs) -> eyre::Result<()> {
+    let message = if args.hex {
+        hex::decode(&args.message).wrap_err("failed decoding message from hex")?
+    } else {
+        args.message.as_bytes().to_vec()
+    };
+    let keystore = Keystore::open(args.keystore.keystore_dir.as_deref())?;
+    let passphrase = keystore::read_passphrase(false)?;
+    let signing_key = keystore.signing_key(&args.name, &passphrase)?;
+    output::emit(&Signature {
+        name: args.name.clone(),
+        signature: hex::encode(signing_key.sign(&message).to_bytes()),
+    })
+}
+
+#[derive(Serialize)]
+struct Key {
+    name: String,
+    public_key: String,
+    #[serde(serialize_with = "output::display")]
+    address: Address,
+}
+
+impl Key {
+    fn new(name: &str, verification_key: &VerificationKey, prefix: &str) -> eyre::Result<Self> {
+        let address = Address::builder()
+            .array(verification_key.address_bytes())
+            .prefix(prefix)
+            .try_build()
+            .wrap_err("failed constructing a valid address from the provided prefix")?;
+        Ok(Self {
+            name: name.to_string(),
+            public_key: hex::encode(verification_key.to_bytes()),
+            address,
+        })
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "    Public Key: {}", self.public_key)?;
+        write!(f, "    Address:    {}", self.address)
+    }
+}
+
+/// A key that was added to the keystore
+#[derive(Serialize)]
+struct KeyAction {
+    #[serde(skip)]
+    action: &'static str,
+    #[serde(flatten)]
+    key: Key,
+}
+
+impl Display for KeyAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{} key `{}`", self.action, self.key.name)?;
+        write!(f, "{}", self.key)
+    }
+}
+
+impl Report for KeyAction {
+    const KIND: &'static str = "key";
+}
+
+#[derive(Serialize)]
+struct Keys {
+    keys: Vec<Key>,
+}
+
+impl Display for Keys {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.keys.is_empty() {
+            return write!(f, "No keys in keystore");
+        }
+        for (index, key) in self.keys.iter().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "{}", key.name)?;
+            write!(f, "{key}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for Keys {
+    const KIND: &'static str = "keys";
+}
+
+#[derive(Serialize)]
+struct PrivateKey {
+    name: String,
+    private_key: String,
+}
+
+impl Display for PrivateKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.private_key)
+    }
+}
+
+impl Report for PrivateKey {
+    const KIND: &'static str = "private_key";
+}
+
+#[derive(Serialize)]
+struct Signature {
+    name: String,
+    signature: String,
+}
+
+impl Display for Signature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.signature)
+    }
+}
+
+impl Report for Signature {
+    const KIND: &'static str = "signature";
+}
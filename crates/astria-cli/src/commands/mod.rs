@@ -1,5 +1,20 @@
+mod address_book;
+mod batch;
+mod bridge;
+mod completions;
+mod compose;
+mod config;
+mod decode;
+mod fees;
+mod ibc;
+mod keys;
+mod offline;
+mod query;
 mod rollup;
 mod sequencer;
+mod status;
+mod sudo;
+mod validator;
 
 use color_eyre::{
     eyre,
@@ -7,24 +22,37 @@ use color_eyre::{
 };
 use tracing::instrument;
 
-use crate::cli::{
-    rollup::{
-        Command as RollupCommand,
-        ConfigCommand,
-        DeploymentCommand,
+use crate::{
+    cli::{
+        address_book::Command as AddressBookCommand,
+        bridge::Command as BridgeCommand,
+        config::{
+            Command as ServiceConfigCommand,
+            GenerateCommand,
+        },
+        keys::Command as KeysCommand,
+        rollup::{
+            Command as RollupCommand,
+            ConfigCommand,
+            DeploymentCommand,
+        },
+        sequencer::{
+            AccountCommand,
+            AddressCommand,
+            BalanceCommand,
+            BlockHeightCommand,
+            Command as SequencerCommand,
+            FeeAssetChangeCommand,
+            IbcCommand,
+            IbcRelayerChangeCommand,
+            QueryCommand,
+            SudoCommand,
+            ValidatorCommand,
+        },
+        Cli,
+        Command,
     },
-    sequencer::{
-        AccountCommand,
-        AddressCommand,
-        BalanceCommand,
-        BlockHeightCommand,
-        Command as SequencerCommand,
-        FeeAssetChangeCommand,
-        IbcRelayerChangeCommand,
-        SudoCommand,
-    },
-    Cli,
-    Command,
+    output,
 };
 
 /// Checks what function needs to be run and calls it with the appropriate arguments
@@ -42,8 +70,44 @@ use crate::cli::{
 /// * If the command is not recognized
 #[instrument]
 pub async fn run(cli: Cli) -> eyre::Result<()> {
+    output::set_format(cli.output);
     if let Some(command) = cli.command {
         match command {
+            Command::AddressBook {
+                command,
+            } => match command {
+                AddressBookCommand::Add(args) => address_book::add(&args)?,
+                AddressBookCommand::Remove(args) => address_book::remove(&args)?,
+                AddressBookCommand::Show(args) => address_book::show(&args)?,
+                AddressBookCommand::List(args) => address_book::list(&args)?,
+            },
+            Command::Bridge {
+                command,
+            } => match command {
+                BridgeCommand::Register(args) => bridge::register(&args).await?,
+                BridgeCommand::History(args) => bridge::history(&args).await?,
+            },
+            Command::Completions(args) => completions::completions(&args)?,
+            Command::Config {
+                command,
+            } => match command {
+                ServiceConfigCommand::Generate {
+                    command,
+                } => match command {
+                    GenerateCommand::Conductor(args) => config::generate_conductor(&args).await?,
+                    GenerateCommand::Composer(args) => config::generate_composer(&args).await?,
+                },
+            },
+            Command::Decode(args) => decode::decode(&args)?,
+            Command::Keys {
+                command,
+            } => match command {
+                KeysCommand::Generate(args) => keys::generate(&args)?,
+                KeysCommand::Import(args) => keys::import(&args)?,
+                KeysCommand::Export(args) => keys::export(&args)?,
+                KeysCommand::List(args) => keys::list(&args)?,
+                KeysCommand::Sign(args) => keys::sign(&args)?,
+            },
             Command::Rollup {
                 command,
             } => match command {
@@ -61,6 +125,7 @@ pub async fn run(cli: Cli) -> eyre::Result<()> {
                     DeploymentCommand::Delete(args) => rollup::delete_deployment(&args)?,
                     DeploymentCommand::List => rollup::list_deployments(),
                 },
+                RollupCommand::Init(args) => rollup::init(&args).await?,
             },
             Command::Sequencer {
                 command,
@@ -68,7 +133,7 @@ pub async fn run(cli: Cli) -> eyre::Result<()> {
                 SequencerCommand::Account {
                     command,
                 } => match command {
-                    AccountCommand::Create => sequencer::create_account(),
+                    AccountCommand::Create => sequencer::create_account()?,
                     AccountCommand::Balance(args) => sequencer::get_balance(&args).await?,
                     AccountCommand::Nonce(args) => sequencer::get_nonce(&args).await?,
                 },
@@ -81,6 +146,7 @@ pub async fn run(cli: Cli) -> eyre::Result<()> {
                     command,
                 } => match command {
                     BalanceCommand::Get(args) => sequencer::get_balance(&args).await?,
+                    BalanceCommand::Watch(args) => sequencer::watch_balance(&args).await?,
                 },
                 SequencerCommand::Sudo {
                     command,
@@ -109,18 +175,52 @@ pub async fn run(cli: Cli) -> eyre::Result<()> {
                     SudoCommand::SudoAddressChange(args) => {
                         sequencer::sudo_address_change(&args).await?;
                     }
+                    SudoCommand::Submit(args) => sudo::submit_from_file(&args).await?,
                 },
                 SequencerCommand::Transfer(args) => sequencer::send_transfer(&args).await?,
+                SequencerCommand::TransferBatch(args) => batch::transfer_batch(&args).await?,
                 SequencerCommand::BlockHeight {
                     command,
                 } => match command {
                     BlockHeightCommand::Get(args) => sequencer::get_block_height(&args).await?,
+                    BlockHeightCommand::Watch(args) => {
+                        sequencer::watch_block_height(&args).await?;
+                    }
                 },
                 SequencerCommand::InitBridgeAccount(args) => {
                     sequencer::init_bridge_account(&args).await?;
                 }
                 SequencerCommand::BridgeLock(args) => sequencer::bridge_lock(&args).await?,
+                SequencerCommand::Compose(args) => compose::compose(&args).await?,
+                SequencerCommand::Sign(args) => offline::sign(&args).await?,
+                SequencerCommand::Submit(args) => offline::submit(&args).await?,
+                SequencerCommand::Query {
+                    command,
+                } => match command {
+                    QueryCommand::Denom(args) => query::denom(&args).await?,
+                    QueryCommand::FeeAssets(args) => query::fee_assets(&args).await?,
+                    QueryCommand::BridgeLastTxHash(args) => {
+                        query::bridge_last_tx_hash(&args).await?;
+                    }
+                    QueryCommand::Raw(args) => query::raw(&args).await?,
+                },
+                SequencerCommand::Ibc {
+                    command,
+                } => match command {
+                    IbcCommand::Clients(args) => ibc::clients(&args).await?,
+                    IbcCommand::Connections(args) => ibc::connections(&args).await?,
+                    IbcCommand::Channels(args) => ibc::channels(&args).await?,
+                    IbcCommand::Withdraw(args) => ibc::withdraw(&args).await?,
+                },
+                SequencerCommand::Validator {
+                    command,
+                } => match command {
+                    ValidatorCommand::List(args) => validator::list(&args).await?,
+                    ValidatorCommand::Pending(args) => validator::pending(&args).await?,
+                },
+                SequencerCommand::Fees(args) => fees::fees(&args).await?,
             },
+            Command::Status(args) => status::status(&args).await?,
         }
     } else {
         return Err(eyre!("Error: No command specified"));
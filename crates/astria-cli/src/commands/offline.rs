@@ -0,0 +1,293 @@
+//! Signing and submission of transactions on separate machines.
+//!
+//! `compose` writes an unsigned transaction, `sign` signs it (without any network access if
+//! `--offline` is given), and `submit` broadcasts the signed transaction. Each step prints the
+//! sha256 of what it produced, which the next step can be told to check with `--expect-sha256`.
+use std::{
+    fmt::Display,
+    path::Path,
+};
+
+use astria_core::{
+    generated::protocol::transaction::v1alpha1 as raw,
+    primitive::v1::Address,
+    protocol::transaction::v1alpha1::{
+        SignedTransaction,
+        TransactionParams,
+        UnsignedTransaction,
+    },
+};
+use astria_sequencer_client::{
+    HttpClient,
+    SequencerClientExt as _,
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        ensure,
+        Context,
+    },
+};
+use prost::Message as _;
+use serde::Serialize;
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+
+use super::sequencer::{
+    broadcast,
+    TransactionReceipt,
+};
+use crate::{
+    cli::sequencer::{
+        SignArgs,
+        SubmitArgs,
+    },
+    output::{
+        self,
+        Report,
+    },
+    signer::Signer,
+};
+
+/// Returns the hex-encoded sha256 of the protobuf encoding of `tx`, which are the bytes that get
+/// signed.
+pub(super) fn unsigned_sha256(tx: &UnsignedTransaction) -> String {
+    hex::encode(Sha256::digest(tx.to_raw().encode_to_vec()))
+}
+
+#[derive(Serialize)]
+struct Signed {
+    nonce: u32,
+    unsigned_sha256: String,
+    tx_hash: String,
+    /// The file the signed transaction was written to
+    output_file: Option<String>,
+    /// The signed transaction as pbjson if it was not written to a file
+    transaction: Option<serde_json::Value>,
+}
+
+impl Display for Signed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some(transaction) = &self.transaction {
+            let json = serde_json::to_string_pretty(transaction).map_err(|_| std::fmt::Error)?;
+            writeln!(f, "{json}")?;
+        }
+        if let Some(output_file) = &self.output_file {
+            writeln!(f, "Wrote signed transaction to `{output_file}`")?;
+        }
+        writeln!(f, "Nonce: {}", self.nonce)?;
+        writeln!(f, "Unsigned transaction sha256: {}", self.unsigned_sha256)?;
+        write!(f, "Transaction hash: {}", self.tx_hash)
+    }
+}
+
+impl Report for Signed {
+    const KIND: &'static str = "signed_transaction";
+}
+
+/// Signs an unsigned transaction read from a file
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the file does not hold a valid unsigned transaction
+/// * If the transaction does not match `--expect-sha256`
+/// * If the nonce cannot be fetched without `--offline`
+/// * If the transaction cannot be signed or written out
+pub(crate) async fn sign(args: &SignArgs) -> eyre::Result<()> {
+    let tx = parse_unsigned(&read(&args.input)?)?;
+    check_sha256(
+        "unsigned transaction",
+        &unsigned_sha256(&tx),
+        args.expect_sha256.as_deref(),
+    )?;
+    let signer = Signer::from_args(&args.signer)?;
+
+    let tx = if args.offline {
+        tx
+    } else {
+        let address = Address::builder()
+            .array(signer.verification_key().address_bytes())
+            .prefix(&args.prefix)
+            .try_build()
+            .wrap_err("failed constructing a valid signer address from the provided prefix")?;
+        let nonce = HttpClient::new(args.sequencer_url.as_str())
+            .wrap_err("failed constructing http sequencer client")?
+            .get_latest_nonce(address)
+            .await
+            .wrap_err("failed to get nonce")?
+            .nonce;
+        if nonce != tx.nonce() {
+            output::note(format_args!(
+                "replacing nonce {} of the transaction by the latest nonce {nonce} of {address}",
+                tx.nonce()
+            ));
+        }
+        UnsignedTransaction {
+            params: TransactionParams::builder()
+                .nonce(nonce)
+                .chain_id(tx.chain_id().to_string())
+                .build(),
+            actions: tx.actions,
+        }
+    };
+
+    let nonce = tx.nonce();
+    let unsigned_sha256 = unsigned_sha256(&tx);
+    let tx = signer
+        .sign_transaction(tx)
+        .wrap_err("failed to sign transaction")?;
+    let tx_hash = hex::encode(tx.sha256_of_proto_encoding());
+    let json = serde_json::to_value(tx.into_raw())
+        .wrap_err("failed serializing signed transaction to pbjson")?;
+
+    let (output_file, transaction) = match &args.output_file {
+        Some(path) => {
+            let contents = serde_json::to_string_pretty(&json)
+                .wrap_err("failed serializing signed transaction to pbjson")?;
+            std::fs::write(path, contents).wrap_err_with(|| {
+                format!("failed writing signed transaction to `{}`", path.display())
+            })?;
+            (Some(path.display().to_string()), None)
+        }
+        None => (None, Some(json)),
+    };
+    output::emit(&Signed {
+        nonce,
+        unsigned_sha256,
+        tx_hash,
+        output_file,
+        transaction,
+    })
+}
+
+/// Broadcasts a signed transaction read from a file
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the file does not hold a validly signed transaction
+/// * If the transaction does not match `--expect-sha256`
+/// * If the transaction failed to be included
+pub(crate) async fn submit(args: &SubmitArgs) -> eyre::Result<()> {
+    let tx = parse_signed(&read(&args.input)?)?;
+    let tx_hash = hex::encode(tx.sha256_of_proto_encoding());
+    check_sha256(
+        "signed transaction",
+        &tx_hash,
+        args.expect_sha256.as_deref(),
+    )?;
+    output::note(format_args!(
+        "submitting tx {tx_hash} with nonce {} on chain {}",
+        tx.nonce(),
+        tx.chain_id()
+    ));
+
+    let sequencer_client = HttpClient::new(args.sequencer_url.as_str())
+        .wrap_err("failed constructing http sequencer client")?;
+    let submitted = broadcast(&sequencer_client, tx, args.wait.inclusion_timeout()).await?;
+    output::emit(&TransactionReceipt::new("Transaction", &submitted))
+}
+
+fn read(path: &Path) -> eyre::Result<String> {
+    std::fs::read_to_string(path).wrap_err_with(|| format!("failed reading `{}`", path.display()))
+}
+
+fn parse_unsigned(contents: &str) -> eyre::Result<UnsignedTransaction> {
+    let raw: raw::UnsignedTransaction =
+        serde_json::from_str(contents).wrap_err("failed parsing unsigned transaction as pbjson")?;
+    UnsignedTransaction::try_from_raw(raw).wrap_err("invalid unsigned transaction")
+}
+
+/// Parses a signed transaction, verifying its signature.
+fn parse_signed(contents: &str) -> eyre::Result<SignedTransaction> {
+    let raw: raw::SignedTransaction =
+        serde_json::from_str(contents).wrap_err("failed parsing signed transaction as pbjson")?;
+    SignedTransaction::try_from_raw(raw).wrap_err("invalid signed transaction")
+}
+
+fn check_sha256(what: &str, actual: &str, expected: Option<&str>) -> eyre::Result<()> {
+    if let Some(expected) = expected {
+        ensure!(
+            actual.eq_ignore_ascii_case(expected),
+            "{what} has sha256 {actual}, but {expected} was expected"
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        primitive::v1::asset,
+        protocol::transaction::v1alpha1::{
+            action::TransferAction,
+            Action,
+            TransactionParams,
+            UnsignedTransaction,
+        },
+    };
+
+    use super::{
+        check_sha256,
+        parse_signed,
+        parse_unsigned,
+        unsigned_sha256,
+    };
+
+    const ALICE: &str = "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm";
+
+    fn unsigned() -> UnsignedTransaction {
+        UnsignedTransaction {
+            params: TransactionParams::builder()
+                .nonce(3)
+                .chain_id("test-1")
+                .build(),
+            actions: vec![Action::Transfer(TransferAction {
+                to: ALICE.parse().unwrap(),
+                amount: 10,
+                asset_id: asset::default_native_asset().id(),
+                fee_asset_id: asset::default_native_asset().id(),
+            })],
+        }
+    }
+
+    #[test]
+    fn hashes_survive_pbjson_round_trips() {
+        let tx = unsigned();
+        let sha256 = unsigned_sha256(&tx);
+        let json = serde_json::to_string(&tx.to_raw()).unwrap();
+        let tx = parse_unsigned(&json).unwrap();
+        check_sha256(
+            "unsigned",
+            &unsigned_sha256(&tx),
+            Some(sha256.to_uppercase().as_str()),
+        )
+        .unwrap();
+
+        let signed = tx.into_signed(&SigningKey::from([1; 32]));
+        let tx_hash = hex::encode(signed.sha256_of_proto_encoding());
+        let json = serde_json::to_string(&signed.into_raw()).unwrap();
+        let signed = parse_signed(&json).unwrap();
+        assert_eq!(tx_hash, hex::encode(signed.sha256_of_proto_encoding()));
+        check_sha256("signed", &tx_hash, Some(sha256.as_str())).unwrap_err();
+    }
+
+    #[test]
+    fn tampered_signed_transactions_are_rejected() {
+        let mut raw = unsigned()
+            .into_signed(&SigningKey::from([1; 32]))
+            .into_raw();
+        raw.signature[0] ^= 1;
+        parse_signed(&serde_json::to_string(&raw).unwrap()).unwrap_err();
+    }
+}
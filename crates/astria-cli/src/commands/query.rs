@@ -0,0 +1,294 @@
+use std::fmt::Display;
+
+use astria_core::primitive::v1::{
+    asset,
+    Address,
+};
+use astria_sequencer_client::{
+    tendermint,
+    tendermint_rpc::endpoint,
+    Client as _,
+    HttpClient,
+    SequencerClientExt as _,
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        ensure,
+        Context,
+    },
+};
+use serde::Serialize;
+
+use crate::{
+    cli::sequencer::{
+        BridgeLastTxHashQueryArgs,
+        DenomQueryArgs,
+        QueryArgs,
+        RawQueryArgs,
+    },
+    output::{
+        self,
+        Report,
+    },
+};
+
+/// Gets the denomination of an asset
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the asset is neither a valid asset ID nor a valid denomination
+/// * If the http client cannot be created
+/// * If the denomination cannot be retrieved
+pub(crate) async fn denom(args: &DenomQueryArgs) -> eyre::Result<()> {
+    let asset_id = parse_asset_id(&args.asset)?;
+    let res = client(&args.query)?
+        .get_denom(asset_id)
+        .await
+        .wrap_err("failed to get denomination")?;
+
+    output::emit(&Denom {
+        height: res.height,
+        asset_id: hex::encode(asset_id),
+        denom: res.denom.to_string(),
+    })
+}
+
+#[derive(Serialize)]
+struct Denom {
+    height: u64,
+    asset_id: String,
+    denom: String,
+}
+
+impl Display for Denom {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Denomination of asset {} at height {}:",
+            self.asset_id, self.height
+        )?;
+        write!(f, "    {}", self.denom)
+    }
+}
+
+impl Report for Denom {
+    const KIND: &'static str = "denom";
+}
+
+/// Lists the assets allowed for fee payment
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the http client cannot be created
+/// * If the fee assets cannot be retrieved
+pub(crate) async fn fee_assets(args: &QueryArgs) -> eyre::Result<()> {
+    let client = client(args)?;
+    let res = client
+        .get_allowed_fee_asset_ids()
+        .await
+        .wrap_err("failed to get allowed fee assets")?;
+
+    // resolving denominations is best effort: assets that were never bridged in via IBC have no
+    // recorded denomination
+    let mut fee_assets = vec![];
+    for asset_id in res.fee_asset_ids {
+        let denom = client
+            .get_denom(asset_id)
+            .await
+            .ok()
+            .map(|res| res.denom.to_string());
+        fee_assets.push(FeeAsset {
+            asset_id: hex::encode(asset_id),
+            denom,
+        });
+    }
+
+    output::emit(&FeeAssets {
+        height: res.height,
+        fee_assets,
+    })
+}
+
+#[derive(Serialize)]
+struct FeeAssets {
+    height: u64,
+    fee_assets: Vec<FeeAsset>,
+}
+
+#[derive(Serialize)]
+struct FeeAsset {
+    asset_id: String,
+    denom: Option<String>,
+}
+
+impl Display for FeeAssets {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Allowed fee assets at height {}:", self.height)?;
+        for asset in &self.fee_assets {
+            write!(
+                f,
+                "\n    {} {}",
+                asset.asset_id,
+                asset.denom.as_deref().unwrap_or("<unknown denomination>")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for FeeAssets {
+    const KIND: &'static str = "fee_assets";
+}
+
+/// Gets the hash of the last transaction sent by a bridge account
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the http client cannot be created
+/// * If the transaction hash cannot be retrieved
+pub(crate) async fn bridge_last_tx_hash(args: &BridgeLastTxHashQueryArgs) -> eyre::Result<()> {
+    let res = client(&args.query)?
+        .get_bridge_account_last_transaction_hash(args.address)
+        .await
+        .wrap_err("failed to get last transaction hash of bridge account")?;
+    output::emit(&BridgeLastTxHash {
+        height: res.height,
+        address: args.address,
+        tx_hash: res.tx_hash.map(hex::encode),
+    })
+}
+
+#[derive(Serialize)]
+struct BridgeLastTxHash {
+    height: u64,
+    #[serde(serialize_with = "output::display")]
+    address: Address,
+    tx_hash: Option<String>,
+}
+
+impl Display for BridgeLastTxHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Last transaction of bridge account {} at height {}:",
+            self.address, self.height
+        )?;
+        write!(
+            f,
+            "    {}",
+            self.tx_hash.as_deref().unwrap_or("<no transactions>")
+        )
+    }
+}
+
+impl Report for BridgeLastTxHash {
+    const KIND: &'static str = "bridge_last_tx_hash";
+}
+
+/// Sends an arbitrary ABCI query and prints the raw response
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the query data is not valid hex
+/// * If the http client cannot be created
+/// * If the query fails or returns a non-zero code
+pub(crate) async fn raw(args: &RawQueryArgs) -> eyre::Result<()> {
+    let data = hex::decode(&args.data).wrap_err("failed decoding query data from hex")?;
+    let res = client(&args.query)?
+        .abci_query(
+            Some(args.path.clone()),
+            data,
+            args.height.map(tendermint::block::Height::from),
+            args.prove,
+        )
+        .await
+        .wrap_err("failed to send abci query")?;
+
+    output::emit(&RawResponse {
+        path: &args.path,
+        response: &res,
+    })?;
+    ensure!(
+        res.code.is_ok(),
+        "query failed with code {}: {}",
+        res.code.value(),
+        res.log
+    );
+    Ok(())
+}
+
+#[derive(Serialize)]
+struct RawResponse<'a> {
+    path: &'a str,
+    response: &'a endpoint::abci_query::AbciQuery,
+}
+
+impl<'a> Display for RawResponse<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let res = self.response;
+        writeln!(f, "Response to `{}` at height {}:", self.path, res.height)?;
+        writeln!(f, "    code:  {}", res.code.value())?;
+        writeln!(f, "    log:   {}", res.log)?;
+        writeln!(f, "    key:   {}", hex::encode(&res.key))?;
+        write!(f, "    value: {}", hex::encode(&res.value))?;
+        if let Some(proof) = &res.proof {
+            write!(f, "\n    proof: {} ops", proof.ops.len())?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Report for RawResponse<'a> {
+    const KIND: &'static str = "abci_query";
+}
+
+fn client(args: &QueryArgs) -> eyre::Result<HttpClient> {
+    HttpClient::new(args.sequencer_url.as_str())
+        .wrap_err("failed constructing http sequencer client")
+}
+
+/// Parses `asset` as a hex-encoded asset ID, falling back to computing the ID of `asset` as a
+/// denomination.
+fn parse_asset_id(asset: &str) -> eyre::Result<asset::Id> {
+    if let Ok(bytes) = hex::decode(asset) {
+        if let Ok(asset_id) = asset::Id::try_from_slice(&bytes) {
+            return Ok(asset_id);
+        }
+    }
+    let denom: asset::Denom = asset
+        .parse()
+        .wrap_err("asset is neither a hex-encoded asset ID nor a valid denomination")?;
+    Ok(denom.id())
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::asset;
+
+    use super::parse_asset_id;
+
+    #[test]
+    fn asset_can_be_given_as_id_or_denom() {
+        let denom: asset::Denom = "transfer/channel-0/utia".parse().unwrap();
+        let expected = denom.id();
+        assert_eq!(expected, parse_asset_id("transfer/channel-0/utia").unwrap());
+        assert_eq!(expected, parse_asset_id(&hex::encode(expected)).unwrap());
+    }
+}
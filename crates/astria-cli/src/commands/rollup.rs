@@ -1,4 +1,5 @@
 use std::{
+    collections::BTreeMap,
     env::{
         self,
         consts::OS,
@@ -12,6 +13,13 @@ use std::{
     process::Command,
 };
 
+use astria_core::{
+    primitive::v1::RollupId,
+    protocol::transaction::v1alpha1::{
+        action::SequenceAction,
+        Action,
+    },
+};
 use astria_sequencer_client::{
     Client,
     HttpClient,
@@ -20,7 +28,12 @@ use color_eyre::{
     eyre,
     eyre::Context,
 };
+use serde::Serialize;
 
+use super::sequencer::{
+    submit_transaction,
+    TransactionReceipt,
+};
 use crate::{
     cli::rollup::{
         ConfigCreateArgs,
@@ -28,7 +41,9 @@ use crate::{
         ConfigEditArgs,
         DeploymentCreateArgs,
         DeploymentDeleteArgs,
+        InitArgs,
     },
+    output::Report,
     types::Rollup,
 };
 
@@ -134,9 +149,10 @@ pub(crate) async fn create_config(args: &ConfigCreateArgs) -> eyre::Result<()> {
     let yaml_str: String = rollup.try_into()?;
     write!(output, "{yaml_str}")?;
 
-    println!("Created rollup config file {filename}");
-
-    Ok(())
+    crate::output::emit(&ConfigFile {
+        action: "Created",
+        path: filename,
+    })
 }
 
 /// Deletes a config file
@@ -152,9 +168,28 @@ pub(crate) fn delete_config(args: &ConfigDeleteArgs) -> eyre::Result<()> {
     let path = PathBuf::from(args.config_path.clone());
     std::fs::remove_file(path).wrap_err("could not delete the config file")?;
 
-    println!("Deleted rollup config file {}", args.config_path);
+    crate::output::emit(&ConfigFile {
+        action: "Deleted",
+        path: args.config_path.clone(),
+    })
+}
 
-    Ok(())
+/// A rollup config file that was created or deleted
+#[derive(Serialize)]
+struct ConfigFile {
+    #[serde(skip)]
+    action: &'static str,
+    path: String,
+}
+
+impl std::fmt::Display for ConfigFile {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} rollup config file {}", self.action, self.path)
+    }
+}
+
+impl Report for ConfigFile {
+    const KIND: &'static str = "rollup_config_file";
 }
 
 pub(crate) fn edit_config(args: &ConfigEditArgs) -> eyre::Result<()> {
@@ -281,10 +316,10 @@ pub(crate) fn delete_deployment(args: &DeploymentDeleteArgs) -> eyre::Result<()>
             );
         }
         Ok(_) => {
-            println!(
+            crate::output::note(format_args!(
                 "Deleted deployment created from rollup config {}",
                 args.config_path
-            );
+            ));
         }
     };
 
@@ -320,6 +355,148 @@ pub(crate) fn list_deployments() {
     };
 }
 
+/// Derives the ID of a new rollup, generates composer and conductor config for it, and
+/// optionally submits an initial sequence action for it
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the config files cannot be written
+/// * If `--data` is not valid hex
+/// * If the initial sequence action failed to be submitted
+pub(crate) async fn init(args: &InitArgs) -> eyre::Result<()> {
+    let rollup_id = RollupId::from_unhashed_bytes(args.name.as_bytes());
+    let composer_env = composer_env(args);
+    let conductor_env = conductor_env(args);
+
+    let mut files = vec![];
+    if let Some(dir) = &args.output_dir {
+        std::fs::create_dir_all(dir)
+            .wrap_err_with(|| format!("failed creating `{}`", dir.display()))?;
+        for (component, env) in [("composer", &composer_env), ("conductor", &conductor_env)] {
+            let path = dir.join(format!("{}-{component}.env", args.name));
+            std::fs::write(&path, render_env(env))
+                .wrap_err_with(|| format!("failed writing `{}`", path.display()))?;
+            files.push(path.display().to_string());
+        }
+    }
+
+    let receipt = if args.submit {
+        let data = hex::decode(&args.data).wrap_err("`--data` is not valid hex")?;
+        submit_transaction(
+            args.sequencer_url.as_str(),
+            args.sequencer_chain_id.clone(),
+            &args.prefix,
+            &args.signer,
+            args.dry_run,
+            args.wait.inclusion_timeout(),
+            Action::Sequence(SequenceAction {
+                rollup_id,
+                data,
+                fee_asset_id: args.fee_asset.id(),
+            }),
+        )
+        .await
+        .wrap_err("failed to submit initial sequence action")?
+        .map(|submitted| TransactionReceipt::new("Initial sequence action", &submitted))
+    } else {
+        None
+    };
+
+    crate::output::emit(&RollupInit {
+        name: args.name.clone(),
+        rollup_id,
+        composer_env,
+        conductor_env,
+        files,
+        receipt,
+    })
+}
+
+/// The environment of a composer collecting transactions for the rollup.
+fn composer_env(args: &InitArgs) -> BTreeMap<&'static str, String> {
+    BTreeMap::from([
+        ("ASTRIA_COMPOSER_SEQUENCER_URL", args.sequencer_url.clone()),
+        (
+            "ASTRIA_COMPOSER_SEQUENCER_CHAIN_ID",
+            args.sequencer_chain_id.clone(),
+        ),
+        (
+            "ASTRIA_COMPOSER_SEQUENCER_ADDRESS_PREFIX",
+            args.prefix.clone(),
+        ),
+        (
+            "ASTRIA_COMPOSER_ROLLUPS",
+            format!("{}::{}", args.name, args.rollup_ws_url),
+        ),
+    ])
+}
+
+/// The environment of a conductor driving the rollup.
+fn conductor_env(args: &InitArgs) -> BTreeMap<&'static str, String> {
+    BTreeMap::from([
+        (
+            "ASTRIA_CONDUCTOR_SEQUENCER_GRPC_URL",
+            args.sequencer_grpc.clone(),
+        ),
+        (
+            "ASTRIA_CONDUCTOR_SEQUENCER_COMETBFT_URL",
+            args.sequencer_url.clone(),
+        ),
+        (
+            "ASTRIA_CONDUCTOR_EXECUTION_RPC_URL",
+            args.execution_rpc_url.clone(),
+        ),
+    ])
+}
+
+pub(super) fn render_env(env: &BTreeMap<&'static str, String>) -> String {
+    env.iter()
+        .map(|(key, value)| format!("{key}=\"{value}\"\n"))
+        .collect()
+}
+
+/// A newly initialized rollup
+#[derive(Serialize)]
+struct RollupInit {
+    name: String,
+    #[serde(serialize_with = "crate::output::display")]
+    rollup_id: RollupId,
+    composer_env: BTreeMap<&'static str, String>,
+    conductor_env: BTreeMap<&'static str, String>,
+    /// The config files written to `--output-dir`
+    files: Vec<String>,
+    /// Set if the initial sequence action was submitted
+    receipt: Option<TransactionReceipt>,
+}
+
+impl std::fmt::Display for RollupInit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Rollup name: {}", self.name)?;
+        writeln!(f, "Rollup ID: {}", self.rollup_id)?;
+        writeln!(f)?;
+        writeln!(f, "# composer")?;
+        write!(f, "{}", render_env(&self.composer_env))?;
+        writeln!(f)?;
+        writeln!(f, "# conductor")?;
+        write!(f, "{}", render_env(&self.conductor_env))?;
+        for file in &self.files {
+            write!(f, "\nWrote {file}")?;
+        }
+        if let Some(receipt) = &self.receipt {
+            write!(f, "\n{receipt}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for RollupInit {
+    const KIND: &'static str = "rollup_init";
+}
+
 #[cfg(test)]
 mod test {
     use test_utils::with_temp_directory;
@@ -461,4 +638,27 @@ mod test {
         assert!(update_yaml_value(&mut yaml_value, "config.rollup.0", "bugbug").is_err());
         assert!(update_yaml_value(&mut yaml_value, "config.rollup.[0]", "bugbug").is_err());
     }
+
+    #[tokio::test]
+    async fn init_writes_composer_and_conductor_config() {
+        #[derive(clap::Parser)]
+        struct Wrapper {
+            #[command(flatten)]
+            args: InitArgs,
+        }
+
+        with_temp_directory(|_dir| async {
+            let Wrapper {
+                args,
+            } = clap::Parser::try_parse_from(["init", "test", "--output-dir", "out"]).unwrap();
+            init(&args).await.unwrap();
+
+            let composer = std::fs::read_to_string("out/test-composer.env").unwrap();
+            assert!(composer.contains("ASTRIA_COMPOSER_ROLLUPS=\"test::ws://127.0.0.1:8545\"\n"));
+            let conductor = std::fs::read_to_string("out/test-conductor.env").unwrap();
+            assert!(conductor
+                .contains("ASTRIA_CONDUCTOR_EXECUTION_RPC_URL=\"http://127.0.0.1:50051\"\n"));
+        })
+        .await;
+    }
 }
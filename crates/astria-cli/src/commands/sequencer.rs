@@ -1,3 +1,12 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
 use astria_core::{
     crypto::SigningKey,
     primitive::v1::{
@@ -6,6 +15,7 @@ use astria_core::{
             default_native_asset,
         },
         Address,
+        RollupId,
         ADDRESS_LEN,
     },
     protocol::transaction::v1alpha1::{
@@ -18,6 +28,7 @@ use astria_core::{
             SudoAddressChangeAction,
             TransferAction,
         },
+        SignedTransaction,
         TransactionParams,
         UnsignedTransaction,
     },
@@ -28,6 +39,9 @@ use astria_sequencer_client::{
     Client,
     HttpClient,
     SequencerClientExt,
+    SequencerSubscriptionClientExt as _,
+    StreamLatestHeight as _,
+    WebSocketClient,
 };
 use color_eyre::{
     eyre,
@@ -37,19 +51,32 @@ use color_eyre::{
         Context,
     },
 };
+use futures::StreamExt as _;
 use rand::rngs::OsRng;
+use serde::Serialize;
+use tokio::time::MissedTickBehavior;
 
-use crate::cli::sequencer::{
-    BasicAccountArgs,
-    Bech32mAddressArgs,
-    BlockHeightGetArgs,
-    BridgeLockArgs,
-    FeeAssetChangeArgs,
-    IbcRelayerChangeArgs,
-    InitBridgeAccountArgs,
-    SudoAddressChangeArgs,
-    TransferArgs,
-    ValidatorUpdateArgs,
+use crate::{
+    cli::sequencer::{
+        BalanceWatchArgs,
+        BasicAccountArgs,
+        Bech32mAddressArgs,
+        BlockHeightGetArgs,
+        BlockHeightWatchArgs,
+        BridgeLockArgs,
+        FeeAssetChangeArgs,
+        IbcRelayerChangeArgs,
+        InitBridgeAccountArgs,
+        SignerArgs,
+        SudoAddressChangeArgs,
+        TransferArgs,
+        ValidatorUpdateArgs,
+    },
+    output::{
+        self,
+        Report,
+    },
+    signer::Signer,
 };
 
 /// Generate a new signing key (this is also called a secret key by other implementations)
@@ -74,20 +101,41 @@ fn get_address_pretty(signing_key: &SigningKey) -> String {
     hex::encode(signing_key.verification_key().address_bytes())
 }
 
+#[derive(Serialize)]
+struct NewAccount {
+    private_key: String,
+    public_key: String,
+    address: String,
+}
+
+impl Display for NewAccount {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Create Sequencer Account")?;
+        writeln!(f)?;
+        writeln!(f, "Private Key: {:?}", self.private_key)?;
+        writeln!(f, "Public Key:  {:?}", self.public_key)?;
+        write!(f, "Address:     {:?}", self.address)
+    }
+}
+
+impl Report for NewAccount {
+    const KIND: &'static str = "new_account";
+}
+
 /// Generates a new ED25519 keypair and prints the public key, private key, and address
-pub(crate) fn create_account() {
+///
+/// # Errors
+///
+/// * If the output cannot be serialized
+pub(crate) fn create_account() -> eyre::Result<()> {
     let signing_key = get_new_signing_key();
-    let public_key_pretty = get_public_key_pretty(&signing_key);
-    let private_key_pretty = get_private_key_pretty(&signing_key);
-    let address_pretty = get_address_pretty(&signing_key);
-
-    println!("Create Sequencer Account");
-    println!();
     // TODO: don't print private keys to CLI, prefer writing to file:
     // https://github.com/astriaorg/astria/issues/594
-    println!("Private Key: {private_key_pretty:?}");
-    println!("Public Key:  {public_key_pretty:?}");
-    println!("Address:     {address_pretty:?}");
+    output::emit(&NewAccount {
+        private_key: get_private_key_pretty(&signing_key),
+        public_key: get_public_key_pretty(&signing_key),
+        address: get_address_pretty(&signing_key),
+    })
 }
 
 /// Gets the balance of a Sequencer account
@@ -109,13 +157,189 @@ pub(crate) async fn get_balance(args: &BasicAccountArgs) -> eyre::Result<()> {
         .await
         .wrap_err("failed to get balance")?;
 
-    println!("Balances for address: {}", args.address);
-    for balance in res.balances {
-        println!("    asset ID: {}", balance.denom.id());
-        println!("    {} {}", balance.balance, balance.denom);
+    output::emit(&Balances {
+        address: args.address,
+        height: res.height,
+        balances: res
+            .balances
+            .into_iter()
+            .map(|balance| AssetBalance {
+                asset_id: balance.denom.id(),
+                denom: balance.denom.to_string(),
+                balance: balance.balance,
+            })
+            .collect(),
+    })
+}
+
+#[derive(Serialize)]
+struct Balances {
+    #[serde(serialize_with = "output::display")]
+    address: Address,
+    height: u64,
+    balances: Vec<AssetBalance>,
+}
+
+#[derive(Serialize)]
+struct AssetBalance {
+    #[serde(serialize_with = "output::display")]
+    asset_id: asset::Id,
+    denom: String,
+    #[serde(serialize_with = "output::display")]
+    balance: u128,
+}
+
+impl Display for Balances {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Balances for address: {}", self.address)?;
+        for balance in &self.balances {
+            write!(f, "\n    asset ID: {}", balance.asset_id)?;
+            write!(f, "\n    {} {}", balance.balance, balance.denom)?;
+        }
+        Ok(())
     }
+}
 
-    Ok(())
+impl Report for Balances {
+    const KIND: &'static str = "balances";
+}
+
+/// Polls the balance of a Sequencer account, printing every change with a timestamp
+///
+/// Runs until the process is interrupted. Failures to fetch the balance are reported but do not
+/// stop the watch.
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the http client cannot be created
+pub(crate) async fn watch_balance(args: &BalanceWatchArgs) -> eyre::Result<()> {
+    let sequencer_client = HttpClient::new(args.sequencer_url.as_str())
+        .wrap_err("failed constructing http sequencer client")?;
+
+    output::note(format_args!(
+        "Watching balances for address: {}",
+        args.address
+    ));
+    let mut interval = watch_interval(args.interval);
+    let mut last_balances: Option<BTreeMap<String, u128>> = None;
+    loop {
+        interval.tick().await;
+        let res = match sequencer_client.get_latest_balance(args.address).await {
+            Ok(res) => res,
+            Err(error) => {
+                eprintln!(
+                    "[{}] failed to get balance: {:#}",
+                    timestamp(),
+                    eyre::Report::new(error)
+                );
+                continue;
+            }
+        };
+        let balances: BTreeMap<String, u128> = res
+            .balances
+            .into_iter()
+            .map(|balance| (balance.denom.to_string(), balance.balance))
+            .collect();
+        if let Some(last_balances) = &last_balances {
+            for (denom, (old_balance, new_balance)) in balance_changes(last_balances, &balances) {
+                output::emit_event(&BalanceChange {
+                    timestamp: timestamp(),
+                    height: res.height,
+                    denom,
+                    old_balance,
+                    new_balance,
+                })?;
+            }
+        } else {
+            output::emit_event(&BalanceSnapshot {
+                timestamp: timestamp(),
+                height: res.height,
+                balances: &balances,
+            })?;
+        }
+        last_balances = Some(balances);
+    }
+}
+
+#[derive(Serialize)]
+struct BalanceSnapshot<'a> {
+    #[serde(serialize_with = "output::display")]
+    timestamp: humantime::Rfc3339Timestamp,
+    height: u64,
+    #[serde(serialize_with = "serialize_balances")]
+    balances: &'a BTreeMap<String, u128>,
+}
+
+impl<'a> Display for BalanceSnapshot<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] height {}:", self.timestamp, self.height)?;
+        for (denom, balance) in self.balances {
+            write!(f, "\n    {balance} {denom}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Report for BalanceSnapshot<'a> {
+    const KIND: &'static str = "balance_snapshot";
+}
+
+#[derive(Serialize)]
+struct BalanceChange<'a> {
+    #[serde(serialize_with = "output::display")]
+    timestamp: humantime::Rfc3339Timestamp,
+    height: u64,
+    denom: &'a str,
+    #[serde(serialize_with = "output::display")]
+    old_balance: u128,
+    #[serde(serialize_with = "output::display")]
+    new_balance: u128,
+}
+
+impl<'a> Display for BalanceChange<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{}] height {}: {} {} -> {}",
+            self.timestamp, self.height, self.denom, self.old_balance, self.new_balance
+        )
+    }
+}
+
+impl<'a> Report for BalanceChange<'a> {
+    const KIND: &'static str = "balance_change";
+}
+
+fn serialize_balances<S: serde::Serializer>(
+    balances: &&BTreeMap<String, u128>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(
+        balances
+            .iter()
+            .map(|(denom, balance)| (denom, balance.to_string())),
+    )
+}
+
+/// Returns the `(old, new)` balance of every denom whose balance differs between `old` and `new`.
+///
+/// Denoms missing from either map are treated as having a balance of zero.
+fn balance_changes<'a>(
+    old: &'a BTreeMap<String, u128>,
+    new: &'a BTreeMap<String, u128>,
+) -> BTreeMap<&'a str, (u128, u128)> {
+    old.keys()
+        .chain(new.keys())
+        .filter_map(|denom| {
+            let old_balance = old.get(denom).copied().unwrap_or_default();
+            let new_balance = new.get(denom).copied().unwrap_or_default();
+            (old_balance != new_balance).then_some((denom.as_str(), (old_balance, new_balance)))
+        })
+        .collect()
 }
 
 // Gets the balance of a Sequencer account
@@ -136,10 +360,30 @@ pub(crate) async fn get_nonce(args: &BasicAccountArgs) -> eyre::Result<()> {
         .await
         .wrap_err("failed to get nonce")?;
 
-    println!("Nonce for address {}", args.address);
-    println!("    {} at height {}", res.nonce, res.height);
+    output::emit(&Nonce {
+        address: args.address,
+        height: res.height,
+        nonce: res.nonce,
+    })
+}
 
-    Ok(())
+#[derive(Serialize)]
+struct Nonce {
+    #[serde(serialize_with = "output::display")]
+    address: Address,
+    height: u64,
+    nonce: u32,
+}
+
+impl Display for Nonce {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Nonce for address {}", self.address)?;
+        write!(f, "    {} at height {}", self.nonce, self.height)
+    }
+}
+
+impl Report for Nonce {
+    const KIND: &'static str = "nonce";
 }
 
 /// Gets the latest block height of a Sequencer node
@@ -161,12 +405,114 @@ pub(crate) async fn get_block_height(args: &BlockHeightGetArgs) -> eyre::Result<
         .await
         .wrap_err("failed to get cometbft block")?;
 
-    println!("Block Height:");
-    println!("    {}", res.block.header.height);
+    output::emit(&BlockHeight {
+        timestamp: None,
+        height: res.block.header.height.value(),
+    })
+}
+
+#[derive(Serialize)]
+struct BlockHeight {
+    #[serde(
+        serialize_with = "serialize_timestamp",
+        skip_serializing_if = "Option::is_none"
+    )]
+    timestamp: Option<humantime::Rfc3339Timestamp>,
+    height: u64,
+}
+
+impl Display for BlockHeight {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.timestamp {
+            Some(timestamp) => write!(f, "[{timestamp}] block height: {}", self.height),
+            None => write!(f, "Block Height:\n    {}", self.height),
+        }
+    }
+}
+
+impl Report for BlockHeight {
+    const KIND: &'static str = "block_height";
+}
+
+fn serialize_timestamp<S: serde::Serializer>(
+    timestamp: &Option<humantime::Rfc3339Timestamp>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match timestamp {
+        Some(timestamp) => serializer.collect_str(timestamp),
+        None => serializer.serialize_none(),
+    }
+}
 
+/// Prints every new block height of a Sequencer node with a timestamp
+///
+/// New blocks are subscribed to if `sequencer_url` is a websocket url, and polled otherwise. Runs
+/// until the process is interrupted or the subscription ends.
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the http or websocket client cannot be created
+/// * If subscribing to new blocks fails
+pub(crate) async fn watch_block_height(args: &BlockHeightWatchArgs) -> eyre::Result<()> {
+    let url = args.sequencer_url.as_str();
+    // The websocket client is held until the end of the watch to keep the subscription alive.
+    let (websocket_client, mut heights) = if url.starts_with("ws://") || url.starts_with("wss://") {
+        let (client, driver) = WebSocketClient::new(url)
+            .await
+            .wrap_err("failed constructing websocket sequencer client")?;
+        tokio::spawn(driver.run());
+        let heights = client
+            .subscribe_latest_height()
+            .await
+            .wrap_err("failed subscribing to new blocks")?
+            .map(|res| res.map_err(eyre::Report::new))
+            .boxed();
+        (Some(client), heights)
+    } else {
+        let client = HttpClient::new(url).wrap_err("failed constructing http sequencer client")?;
+        let heights = client
+            .stream_latest_height(args.interval)
+            .map(|res| res.map_err(eyre::Report::new))
+            .boxed();
+        (None, heights)
+    };
+
+    output::note(format_args!("Watching block height of: {url}"));
+    let mut last_height = None;
+    while let Some(res) = heights.next().await {
+        match res {
+            Ok(height) if Some(height) != last_height => {
+                output::emit_event(&BlockHeight {
+                    timestamp: Some(timestamp()),
+                    height: height.value(),
+                })?;
+                last_height = Some(height);
+            }
+            Ok(_) => {}
+            Err(error) => eprintln!("[{}] failed to get block height: {error:#}", timestamp()),
+        }
+    }
+    output::note(format_args!("[{}] block height stream ended", timestamp()));
+    drop(websocket_client);
     Ok(())
 }
 
+/// Returns an interval for polling that skips ticks missed due to slow responses.
+fn watch_interval(period: Duration) -> tokio::time::Interval {
+    let mut interval = tokio::time::interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    interval
+}
+
+/// Returns the current time formatted as an RFC 3339 timestamp with second precision.
+fn timestamp() -> humantime::Rfc3339Timestamp {
+    humantime::format_rfc3339_seconds(SystemTime::now())
+}
+
 /// Returns a bech32m sequencer address given a prefix and hex-encoded byte slice
 pub(crate) fn make_bech32m(args: &Bech32mAddressArgs) -> eyre::Result<()> {
     use hex::FromHex as _;
@@ -179,8 +525,25 @@ pub(crate) fn make_bech32m(args: &Bech32mAddressArgs) -> eyre::Result<()> {
         .wrap_err(
             "failed constructing a valid bech32m address from the provided hex bytes and prefix",
         )?;
-    println!("{address}");
-    Ok(())
+    output::emit(&Bech32mAddress {
+        address,
+    })
+}
+
+#[derive(Serialize)]
+struct Bech32mAddress {
+    #[serde(serialize_with = "output::display")]
+    address: Address,
+}
+
+impl Display for Bech32mAddress {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.address)
+    }
+}
+
+impl Report for Bech32mAddress {
+    const KIND: &'static str = "address";
 }
 
 /// Gets the latest block height of a Sequencer node
@@ -194,11 +557,13 @@ pub(crate) fn make_bech32m(args: &Bech32mAddressArgs) -> eyre::Result<()> {
 /// * If the http client cannot be created
 /// * If the latest block height cannot be retrieved
 pub(crate) async fn send_transfer(args: &TransferArgs) -> eyre::Result<()> {
-    let res = submit_transaction(
+    let Some(res) = submit_transaction(
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
+        &args.signer,
+        args.dry_run,
+        args.wait.inclusion_timeout(),
         Action::Transfer(TransferAction {
             to: args.to_address,
             amount: args.amount,
@@ -207,11 +572,12 @@ pub(crate) async fn send_transfer(args: &TransferArgs) -> eyre::Result<()> {
         }),
     )
     .await
-    .wrap_err("failed to submit transfer transaction")?;
+    .wrap_err("failed to submit transfer transaction")?
+    else {
+        return Ok(());
+    };
 
-    println!("Transfer completed!");
-    println!("Included in block: {}", res.height);
-    Ok(())
+    output::emit(&TransactionReceipt::new("Transfer", &res))
 }
 
 /// Adds an address to the Ibc Relayer set
@@ -225,19 +591,30 @@ pub(crate) async fn send_transfer(args: &TransferArgs) -> eyre::Result<()> {
 /// * If the http client cannot be created
 /// * If the transaction failed to be included
 pub(crate) async fn ibc_relayer_add(args: &IbcRelayerChangeArgs) -> eyre::Result<()> {
-    let res = submit_transaction(
+    let action = Action::IbcRelayerChange(IbcRelayerChangeAction::Addition(args.address));
+    if args.preview {
+        return super::sudo::preview(action);
+    }
+
+    let Some(res) = submit_transaction(
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::IbcRelayerChange(IbcRelayerChangeAction::Addition(args.address)),
+        &args.signer,
+        args.dry_run,
+        args.wait.inclusion_timeout(),
+        action,
     )
     .await
-    .wrap_err("failed to submit IbcRelayerChangeAction::Addition transaction")?;
+    .wrap_err("failed to submit IbcRelayerChangeAction::Addition transaction")?
+    else {
+        return Ok(());
+    };
 
-    println!("IbcRelayerChangeAction::Addition completed!");
-    println!("Included in block: {}", res.height);
-    Ok(())
+    output::emit(&TransactionReceipt::new(
+        "IbcRelayerChangeAction::Addition",
+        &res,
+    ))
 }
 
 /// Removes an address to the Ibc Relayer set
@@ -251,19 +628,30 @@ pub(crate) async fn ibc_relayer_add(args: &IbcRelayerChangeArgs) -> eyre::Result
 /// * If the http client cannot be created
 /// * If the transaction failed to be included
 pub(crate) async fn ibc_relayer_remove(args: &IbcRelayerChangeArgs) -> eyre::Result<()> {
-    let res = submit_transaction(
+    let action = Action::IbcRelayerChange(IbcRelayerChangeAction::Removal(args.address));
+    if args.preview {
+        return super::sudo::preview(action);
+    }
+
+    let Some(res) = submit_transaction(
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::IbcRelayerChange(IbcRelayerChangeAction::Removal(args.address)),
+        &args.signer,
+        args.dry_run,
+        args.wait.inclusion_timeout(),
+        action,
     )
     .await
-    .wrap_err("failed to submit IbcRelayerChangeAction::Removal transaction")?;
+    .wrap_err("failed to submit IbcRelayerChangeAction::Removal transaction")?
+    else {
+        return Ok(());
+    };
 
-    println!("IbcRelayerChangeAction::Removal completed!");
-    println!("Included in block: {}", res.height);
-    Ok(())
+    output::emit(&TransactionReceipt::new(
+        "IbcRelayerChangeAction::Removal",
+        &res,
+    ))
 }
 
 /// Inits a bridge account
@@ -277,14 +665,14 @@ pub(crate) async fn ibc_relayer_remove(args: &IbcRelayerChangeArgs) -> eyre::Res
 /// * If the http client cannot be created
 /// * If the transaction failed to be included
 pub(crate) async fn init_bridge_account(args: &InitBridgeAccountArgs) -> eyre::Result<()> {
-    use astria_core::primitive::v1::RollupId;
-
     let rollup_id = RollupId::from_unhashed_bytes(args.rollup_name.as_bytes());
-    let res = submit_transaction(
+    let Some(res) = submit_transaction(
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
+        &args.signer,
+        args.dry_run,
+        args.wait.inclusion_timeout(),
         Action::InitBridgeAccount(InitBridgeAccountAction {
             rollup_id,
             asset_id: default_native_asset().id(),
@@ -294,13 +682,38 @@ pub(crate) async fn init_bridge_account(args: &InitBridgeAccountArgs) -> eyre::R
         }),
     )
     .await
-    .wrap_err("failed to submit InitBridgeAccount transaction")?;
+    .wrap_err("failed to submit InitBridgeAccount transaction")?
+    else {
+        return Ok(());
+    };
 
-    println!("InitBridgeAccount completed!");
-    println!("Included in block: {}", res.height);
-    println!("Rollup name: {}", args.rollup_name);
-    println!("Rollup ID: {rollup_id}");
-    Ok(())
+    output::emit(&InitBridgeAccountReceipt {
+        receipt: TransactionReceipt::new("InitBridgeAccount", &res),
+        rollup_name: args.rollup_name.clone(),
+        rollup_id,
+    })
+}
+
+#[derive(Serialize)]
+struct InitBridgeAccountReceipt {
+    #[serde(flatten)]
+    receipt: TransactionReceipt,
+    rollup_name: String,
+    #[serde(serialize_with = "output::display")]
+    rollup_id: RollupId,
+}
+
+impl Display for InitBridgeAccountReceipt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.receipt)?;
+        writeln!(f, "Rollup name: {}", self.rollup_name)?;
+        write!(f, "Rollup ID: {}", self.rollup_id)
+    }
+}
+
+impl Report for InitBridgeAccountReceipt {
+    const KIND: &'static str = "init_bridge_account_receipt";
+    const SCHEMA_VERSION: u32 = 2;
 }
 
 /// Bridge Lock action
@@ -314,11 +727,13 @@ pub(crate) async fn init_bridge_account(args: &InitBridgeAccountArgs) -> eyre::R
 /// * If the http client cannot be created
 /// * If the transaction failed to be included
 pub(crate) async fn bridge_lock(args: &BridgeLockArgs) -> eyre::Result<()> {
-    let res = submit_transaction(
+    let Some(res) = submit_transaction(
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
+        &args.signer,
+        args.dry_run,
+        args.wait.inclusion_timeout(),
         Action::BridgeLock(BridgeLockAction {
             to: args.to_address,
             asset_id: default_native_asset().id(),
@@ -328,11 +743,12 @@ pub(crate) async fn bridge_lock(args: &BridgeLockArgs) -> eyre::Result<()> {
         }),
     )
     .await
-    .wrap_err("failed to submit BridgeLock transaction")?;
+    .wrap_err("failed to submit BridgeLock transaction")?
+    else {
+        return Ok(());
+    };
 
-    println!("BridgeLock completed!");
-    println!("Included in block: {}", res.height);
-    Ok(())
+    output::emit(&TransactionReceipt::new("BridgeLock", &res))
 }
 
 /// Adds a fee asset
@@ -346,21 +762,32 @@ pub(crate) async fn bridge_lock(args: &BridgeLockArgs) -> eyre::Result<()> {
 /// * If the http client cannot be created
 /// * If the transaction failed to be included
 pub(crate) async fn fee_asset_add(args: &FeeAssetChangeArgs) -> eyre::Result<()> {
-    let res = submit_transaction(
+    let action = Action::FeeAssetChange(FeeAssetChangeAction::Addition(
+        asset::Id::from_str_unchecked(&args.asset),
+    ));
+    if args.preview {
+        return super::sudo::preview(action);
+    }
+
+    let Some(res) = submit_transaction(
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::FeeAssetChange(FeeAssetChangeAction::Addition(
-            asset::Id::from_str_unchecked(&args.asset),
-        )),
+        &args.signer,
+        args.dry_run,
+        args.wait.inclusion_timeout(),
+        action,
     )
     .await
-    .wrap_err("failed to submit FeeAssetChangeAction::Addition transaction")?;
+    .wrap_err("failed to submit FeeAssetChangeAction::Addition transaction")?
+    else {
+        return Ok(());
+    };
 
-    println!("FeeAssetChangeAction::Addition completed!");
-    println!("Included in block: {}", res.height);
-    Ok(())
+    output::emit(&TransactionReceipt::new(
+        "FeeAssetChangeAction::Addition",
+        &res,
+    ))
 }
 
 /// Removes a fee asset
@@ -374,21 +801,32 @@ pub(crate) async fn fee_asset_add(args: &FeeAssetChangeArgs) -> eyre::Result<()>
 /// * If the http client cannot be created
 /// * If the transaction failed to be included
 pub(crate) async fn fee_asset_remove(args: &FeeAssetChangeArgs) -> eyre::Result<()> {
-    let res = submit_transaction(
+    let action = Action::FeeAssetChange(FeeAssetChangeAction::Removal(
+        asset::Id::from_str_unchecked(&args.asset),
+    ));
+    if args.preview {
+        return super::sudo::preview(action);
+    }
+
+    let Some(res) = submit_transaction(
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::FeeAssetChange(FeeAssetChangeAction::Removal(
-            asset::Id::from_str_unchecked(&args.asset),
-        )),
+        &args.signer,
+        args.dry_run,
+        args.wait.inclusion_timeout(),
+        action,
     )
     .await
-    .wrap_err("failed to submit FeeAssetChangeAction::Removal transaction")?;
+    .wrap_err("failed to submit FeeAssetChangeAction::Removal transaction")?
+    else {
+        return Ok(());
+    };
 
-    println!("FeeAssetChangeAction::Removal completed!");
-    println!("Included in block: {}", res.height);
-    Ok(())
+    output::emit(&TransactionReceipt::new(
+        "FeeAssetChangeAction::Removal",
+        &res,
+    ))
 }
 
 /// Changes the Sequencer's sudo address to a new address
@@ -402,21 +840,29 @@ pub(crate) async fn fee_asset_remove(args: &FeeAssetChangeArgs) -> eyre::Result<
 /// * If the http client cannot be created
 /// * If the sudo address was not changed
 pub(crate) async fn sudo_address_change(args: &SudoAddressChangeArgs) -> eyre::Result<()> {
-    let res = submit_transaction(
+    let action = Action::SudoAddressChange(SudoAddressChangeAction {
+        new_address: args.address,
+    });
+    if args.preview {
+        return super::sudo::preview(action);
+    }
+
+    let Some(res) = submit_transaction(
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::SudoAddressChange(SudoAddressChangeAction {
-            new_address: args.address,
-        }),
+        &args.signer,
+        args.dry_run,
+        args.wait.inclusion_timeout(),
+        action,
     )
     .await
-    .wrap_err("failed to submit SudoAddressChange transaction")?;
+    .wrap_err("failed to submit SudoAddressChange transaction")?
+    else {
+        return Ok(());
+    };
 
-    println!("SudoAddressChange completed!");
-    println!("Included in block: {}", res.height);
-    Ok(())
+    output::emit(&TransactionReceipt::new("SudoAddressChange", &res))
 }
 
 /// Updates a validator
@@ -427,55 +873,122 @@ pub(crate) async fn sudo_address_change(args: &SudoAddressChangeArgs) -> eyre::R
 ///
 /// # Errors
 ///
+/// * If the validator public key is invalid
 /// * If the http client cannot be created
 /// * If the transaction failed to be submitted
 pub(crate) async fn validator_update(args: &ValidatorUpdateArgs) -> eyre::Result<()> {
-    let public_key_raw = hex::decode(args.validator_public_key.as_str())
-        .wrap_err("failed to decode public key into bytes")?;
-    let pub_key = tendermint::PublicKey::from_raw_ed25519(&public_key_raw)
-        .expect("failed to parse public key from parsed bytes");
+    let pub_key = super::validator::parse_public_key(&args.validator_public_key)
+        .wrap_err("invalid validator public key")?;
     let validator_update = tendermint::validator::Update {
         pub_key,
         power: args.power.into(),
     };
 
-    let res = submit_transaction(
+    let action = Action::ValidatorUpdate(validator_update);
+    if args.preview {
+        return super::sudo::preview(action);
+    }
+
+    let Some(res) = submit_transaction(
         args.sequencer_url.as_str(),
         args.sequencer_chain_id.clone(),
         &args.prefix,
-        args.private_key.as_str(),
-        Action::ValidatorUpdate(validator_update),
+        &args.signer,
+        args.dry_run,
+        args.wait.inclusion_timeout(),
+        action,
     )
     .await
-    .wrap_err("failed to submit ValidatorUpdate transaction")?;
+    .wrap_err("failed to submit ValidatorUpdate transaction")?
+    else {
+        return Ok(());
+    };
 
-    println!("ValidatorUpdate completed!");
-    println!("Included in block: {}", res.height);
-    Ok(())
+    output::emit(&ValidatorUpdateReceipt {
+        activation_height: res.inclusion.as_ref().map(|inclusion| {
+            inclusion
+                .height
+                .value()
+                .saturating_add(super::validator::ACTIVATION_DELAY)
+        }),
+        receipt: TransactionReceipt::new("ValidatorUpdate", &res),
+    })
+}
+
+#[derive(Serialize)]
+struct ValidatorUpdateReceipt {
+    #[serde(flatten)]
+    receipt: TransactionReceipt,
+    /// The height from which the update is in effect; only known once the transaction was
+    /// included
+    activation_height: Option<u64>,
+}
+
+impl Display for ValidatorUpdateReceipt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.receipt)?;
+        if let Some(activation_height) = self.activation_height {
+            write!(f, "\nTakes effect at height: {activation_height}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for ValidatorUpdateReceipt {
+    const KIND: &'static str = "validator_update_receipt";
 }
 
-async fn submit_transaction(
+/// Signs a transaction containing `action` and broadcasts it.
+///
+/// If `wait` is set, waits up to the given duration for the transaction to be included in a
+/// block. If `dry_run` is set, the signed transaction is printed instead of broadcast and `None`
+/// is returned.
+pub(super) async fn submit_transaction(
     sequencer_url: &str,
     chain_id: String,
     prefix: &str,
-    private_key: &str,
+    signer: &SignerArgs,
+    dry_run: bool,
+    wait: Option<Duration>,
     action: Action,
-) -> eyre::Result<endpoint::broadcast::tx_commit::Response> {
+) -> eyre::Result<Option<Submitted>> {
+    let signer = Signer::from_args(signer)?;
+    submit_actions(
+        sequencer_url,
+        chain_id,
+        prefix,
+        &signer,
+        dry_run,
+        wait,
+        vec![action],
+    )
+    .await
+}
+
+/// Signs a transaction containing all of `actions` and broadcasts it.
+///
+/// If `wait` is set, waits up to the given duration for the transaction to be included in a
+/// block. If `dry_run` is set, the signed transaction is printed instead of broadcast and `None`
+/// is returned. The transaction is not simulated, as the Sequencer has no endpoint to execute
+/// a transaction without committing it.
+pub(super) async fn submit_actions(
+    sequencer_url: &str,
+    chain_id: String,
+    prefix: &str,
+    signer: &Signer,
+    dry_run: bool,
+    wait: Option<Duration>,
+    actions: Vec<Action>,
+) -> eyre::Result<Option<Submitted>> {
     let sequencer_client =
         HttpClient::new(sequencer_url).wrap_err("failed constructing http sequencer client")?;
 
-    let private_key_bytes: [u8; 32] = hex::decode(private_key)
-        .wrap_err("failed to decode private key bytes from hex string")?
-        .try_into()
-        .map_err(|_| eyre!("invalid private key length; must be 32 bytes"))?;
-    let sequencer_key = SigningKey::from(private_key_bytes);
-
     let from_address = Address::builder()
-        .array(sequencer_key.verification_key().address_bytes())
+        .array(signer.verification_key().address_bytes())
         .prefix(prefix)
         .try_build()
         .wrap_err("failed constructing a valid from address from the provided prefix")?;
-    println!("sending tx from address: {from_address}");
+    output::note(format_args!("sending tx from address: {from_address}"));
 
     let nonce_res = sequencer_client
         .get_latest_nonce(from_address)
@@ -487,18 +1000,78 @@ async fn submit_transaction(
             .nonce(nonce_res.nonce)
             .chain_id(chain_id)
             .build(),
-        actions: vec![action],
+        actions,
+    };
+    let tx = signer
+        .sign_transaction(tx)
+        .wrap_err("failed to sign transaction")?;
+    if dry_run {
+        output::emit(&DryRun {
+            tx_hash: hex::encode(tx.sha256_of_proto_encoding()),
+            transaction: serde_json::to_value(tx.to_raw())
+                .wrap_err("failed serializing signed transaction to pbjson")?,
+        })?;
+        return Ok(None);
     }
-    .into_signed(&sequencer_key);
+    broadcast(&sequencer_client, tx, wait).await.map(Some)
+}
+
+/// Broadcasts the signed transaction `tx`.
+///
+/// If `wait` is set, waits up to the given duration for the transaction to be included in a
+/// block.
+pub(super) async fn broadcast(
+    sequencer_client: &HttpClient,
+    tx: SignedTransaction,
+    wait: Option<Duration>,
+) -> eyre::Result<Submitted> {
     let res = sequencer_client
-        .submit_transaction_commit(tx)
+        .submit_transaction_sync(tx)
         .await
         .wrap_err("failed to submit transaction")?;
-    ensure!(
-        res.check_tx.code.is_ok(),
-        "failed to check tx: {}",
-        res.check_tx.log
-    );
+    ensure!(res.code.is_ok(), "failed to check tx: {}", res.log);
+
+    let inclusion = match wait {
+        Some(timeout) => Some(wait_for_inclusion(sequencer_client, res.hash, timeout).await?),
+        None => None,
+    };
+    Ok(Submitted {
+        hash: res.hash,
+        inclusion,
+    })
+}
+
+/// The interval at which the Sequencer node is polled for a submitted transaction.
+const INCLUSION_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Polls the Sequencer node until the transaction with `hash` is included in a block, failing if
+/// this takes longer than `timeout` or if the transaction failed to execute.
+async fn wait_for_inclusion(
+    sequencer_client: &HttpClient,
+    hash: tendermint::Hash,
+    timeout: Duration,
+) -> eyre::Result<endpoint::tx::Response> {
+    let tx_hash = hex::encode(hash.as_bytes());
+    output::note(format_args!(
+        "waiting up to {} for tx {tx_hash} to be included",
+        humantime::format_duration(timeout)
+    ));
+    // the node responds with an error until the transaction was included in a block
+    let res = tokio::time::timeout(timeout, async {
+        loop {
+            if let Ok(res) = sequencer_client.tx(hash, false).await {
+                break res;
+            }
+            tokio::time::sleep(INCLUSION_POLL_INTERVAL).await;
+        }
+    })
+    .await
+    .map_err(|_| {
+        eyre!(
+            "tx {tx_hash} was not included within {}",
+            humantime::format_duration(timeout)
+        )
+    })?;
     ensure!(
         res.tx_result.code.is_ok(),
         "failed to execute tx: {}",
@@ -507,6 +1080,124 @@ async fn submit_transaction(
     Ok(res)
 }
 
+/// A transaction that was accepted into the mempool of the Sequencer node
+pub(super) struct Submitted {
+    pub(super) hash: tendermint::Hash,
+    /// The result of executing the transaction if waiting for its inclusion was requested
+    pub(super) inclusion: Option<endpoint::tx::Response>,
+}
+
+/// The result of a submitted transaction
+#[derive(Serialize)]
+pub(super) struct TransactionReceipt {
+    action: &'static str,
+    tx_hash: String,
+    /// Set if the command waited for the transaction to be included
+    inclusion: Option<Inclusion>,
+}
+
+#[derive(Serialize)]
+struct Inclusion {
+    height: u64,
+    gas_wanted: i64,
+    gas_used: i64,
+    events: Vec<Event>,
+}
+
+#[derive(Serialize)]
+struct Event {
+    kind: String,
+    attributes: Vec<EventAttribute>,
+}
+
+#[derive(Serialize)]
+struct EventAttribute {
+    key: String,
+    value: String,
+}
+
+impl TransactionReceipt {
+    pub(super) fn new(action: &'static str, submitted: &Submitted) -> Self {
+        let inclusion = submitted.inclusion.as_ref().map(|res| Inclusion {
+            height: res.height.value(),
+            gas_wanted: res.tx_result.gas_wanted,
+            gas_used: res.tx_result.gas_used,
+            events: res
+                .tx_result
+                .events
+                .iter()
+                .map(|event| Event {
+                    kind: event.kind.clone(),
+                    attributes: event
+                        .attributes
+                        .iter()
+                        .map(|attribute| EventAttribute {
+                            key: attribute.key.clone(),
+                            value: attribute.value.clone(),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        });
+        Self {
+            action,
+            tx_hash: hex::encode(submitted.hash.as_bytes()),
+            inclusion,
+        }
+    }
+}
+
+impl Display for TransactionReceipt {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(inclusion) = &self.inclusion else {
+            writeln!(f, "{} submitted!", self.action)?;
+            return write!(f, "Transaction hash: {}", self.tx_hash);
+        };
+        writeln!(f, "{} completed!", self.action)?;
+        writeln!(f, "Transaction hash: {}", self.tx_hash)?;
+        writeln!(f, "Included in block: {}", inclusion.height)?;
+        write!(
+            f,
+            "Gas used: {} (wanted: {})",
+            inclusion.gas_used, inclusion.gas_wanted
+        )?;
+        if !inclusion.events.is_empty() {
+            write!(f, "\nEvents:")?;
+        }
+        for event in &inclusion.events {
+            write!(f, "\n    {}", event.kind)?;
+            for attribute in &event.attributes {
+                write!(f, "\n        {}: {}", attribute.key, attribute.value)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Report for TransactionReceipt {
+    const KIND: &'static str = "transaction_receipt";
+    const SCHEMA_VERSION: u32 = 2;
+}
+
+/// A signed transaction that was not broadcast because of `--dry-run`
+#[derive(Serialize)]
+struct DryRun {
+    tx_hash: String,
+    transaction: serde_json::Value,
+}
+
+impl Display for DryRun {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json = serde_json::to_string_pretty(&self.transaction).map_err(|_| std::fmt::Error)?;
+        writeln!(f, "{json}")?;
+        write!(f, "dry run: transaction {} was not broadcast", self.tx_hash)
+    }
+}
+
+impl Report for DryRun {
+    const KIND: &'static str = "dry_run";
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -553,6 +1244,17 @@ mod test {
         assert_eq!(private_key_pretty.len(), 64);
     }
 
+    #[test]
+    fn balance_changes_include_added_removed_and_updated_denoms() {
+        let old = BTreeMap::from([("nria".to_string(), 10), ("utia".to_string(), 5)]);
+        let new = BTreeMap::from([("nria".to_string(), 7), ("uosmo".to_string(), 3)]);
+        let changes = balance_changes(&old, &new);
+        assert_eq!(
+            BTreeMap::from([("nria", (10, 7)), ("uosmo", (0, 3)), ("utia", (5, 0))]),
+            changes
+        );
+    }
+
     #[test]
     fn test_get_address_pretty() {
         let signing_key = get_new_signing_key();
@@ -0,0 +1,507 @@
+use std::{
+    fmt::Display,
+    future::Future,
+    time::{
+        Duration,
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+use astria_core::{
+    execution::v1alpha2::CommitmentState,
+    generated::execution::v1alpha2::{
+        execution_service_client::ExecutionServiceClient,
+        GetCommitmentStateRequest,
+        GetGenesisInfoRequest,
+    },
+    primitive::v1::RollupId,
+    Protobuf as _,
+};
+use astria_sequencer_client::{
+    Client as _,
+    HttpClient,
+};
+use color_eyre::eyre::{
+    self,
+    eyre,
+    WrapErr as _,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+    Serializer,
+};
+
+use crate::{
+    cli::status::StatusArgs,
+    output::{
+        self,
+        Report,
+    },
+};
+
+/// How long to wait for a service to answer before reporting it as unreachable.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Prints a health and lag overview of the Sequencer, the relayer, and a rollup node
+///
+/// Services that cannot be reached are reported as such instead of failing the command.
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the overview cannot be printed
+pub(crate) async fn status(args: &StatusArgs) -> eyre::Result<()> {
+    let now = SystemTime::now();
+    let sequencer = sequencer_status(&args.sequencer_url, args.max_block_age, now).await;
+    let sequencer_height = sequencer.as_ref().ok().map(|sequencer| sequencer.height);
+
+    let relayer = match &args.relayer_url {
+        Some(url) => Some(relayer_status(url, sequencer_height).await),
+        None => None,
+    };
+    let rollup = match &args.execution_rpc_url {
+        Some(url) => Some(rollup_status(url, args.max_block_age, now).await),
+        None => None,
+    };
+
+    output::emit(&Overview {
+        sequencer: Service::new(&args.sequencer_url, sequencer),
+        relayer: relayer
+            .zip(args.relayer_url.as_deref())
+            .map(Service::from_pair),
+        rollup: rollup
+            .zip(args.execution_rpc_url.as_deref())
+            .map(Service::from_pair),
+    })
+}
+
+/// Runs `request`, giving up after [`REQUEST_TIMEOUT`].
+async fn with_timeout<T>(request: impl Future<Output = eyre::Result<T>>) -> eyre::Result<T> {
+    tokio::time::timeout(REQUEST_TIMEOUT, request)
+        .await
+        .map_err(|_| {
+            eyre!(
+                "no response within {}",
+                humantime::format_duration(REQUEST_TIMEOUT)
+            )
+        })?
+}
+
+/// Returns how long ago `unix_seconds` was, or zero if it lies in the future.
+fn age(now: SystemTime, unix_seconds: i64) -> Duration {
+    let then = UNIX_EPOCH + Duration::from_secs(u64::try_from(unix_seconds).unwrap_or_default());
+    now.duration_since(then).unwrap_or_default()
+}
+
+async fn sequencer_status(
+    url: &str,
+    max_block_age: Duration,
+    now: SystemTime,
+) -> eyre::Result<SequencerStatus> {
+    let client = HttpClient::new(url).wrap_err("failed constructing http sequencer client")?;
+    let (status, info) = with_timeout(async {
+        let status = client
+            .status()
+            .await
+            .wrap_err("failed getting node status")?;
+        let info = client
+            .abci_info()
+            .await
+            .wrap_err("failed getting application info")?;
+        Ok((status, info))
+    })
+    .await?;
+
+    // the application info is only informational, so a node without it is still reported on
+    let app_info: AppInfo = serde_json::from_str(&info.data).unwrap_or_default();
+    let block_age = age(now, status.sync_info.latest_block_time.unix_timestamp());
+    let mut issues = vec![];
+    if status.sync_info.catching_up {
+        issues.push("the node is catching up".to_string());
+    }
+    if block_age > max_block_age {
+        issues.push(format!(
+            "the latest block is {} old",
+            humantime::format_duration(block_age)
+        ));
+    }
+    Ok(SequencerStatus {
+        chain_id: status.node_info.network.to_string(),
+        height: status.sync_info.latest_block_height.value(),
+        block_age,
+        app_version: info.app_version,
+        applied_upgrades: app_info.applied_upgrades,
+        issues,
+    })
+}
+
+async fn relayer_status(url: &str, sequencer_height: Option<u64>) -> eyre::Result<RelayerStatus> {
+    let status_url = format!("{}/status", url.trim_end_matches('/'));
+    let snapshot: RelayerSnapshot = with_timeout(async {
+        reqwest::get(&status_url)
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .wrap_err_with(|| format!("failed requesting `{status_url}`"))?
+            .json()
+            .await
+            .wrap_err("failed decoding relayer status")
+    })
+    .await?;
+
+    let blocks_behind = sequencer_height
+        .zip(snapshot.latest_fetched_sequencer_height)
+        .map(|(sequencer, fetched)| sequencer.saturating_sub(fetched));
+    let mut issues = vec![];
+    if !snapshot.ready {
+        issues.push("the relayer is not ready".to_string());
+    }
+    if !snapshot.sequencer_connected {
+        issues.push("the relayer is not connected to the Sequencer".to_string());
+    }
+    if !snapshot.celestia_connected {
+        issues.push("the relayer is not connected to Celestia".to_string());
+    }
+    Ok(RelayerStatus {
+        latest_fetched_sequencer_height: snapshot.latest_fetched_sequencer_height,
+        latest_confirmed_celestia_height: snapshot.latest_confirmed_celestia_height,
+        blocks_behind,
+        issues,
+    })
+}
+
+async fn rollup_status(
+    url: &str,
+    max_block_age: Duration,
+    now: SystemTime,
+) -> eyre::Result<RollupStatus> {
+    let (genesis_info, commitment_state) = with_timeout(async {
+        let mut client = ExecutionServiceClient::connect(url.to_string())
+            .await
+            .wrap_err("failed connecting to the execution API")?;
+        let genesis_info = client
+            .get_genesis_info(GetGenesisInfoRequest {})
+            .await
+            .wrap_err("failed getting genesis info")?
+            .into_inner();
+        let commitment_state = client
+            .get_commitment_state(GetCommitmentStateRequest {})
+            .await
+            .wrap_err("failed getting commitment state")?
+            .into_inner();
+        Ok((genesis_info, commitment_state))
+    })
+    .await?;
+
+    let rollup_id = RollupId::try_from_slice(&genesis_info.rollup_id)
+        .wrap_err("the execution node served an invalid rollup ID")?;
+    let commitment_state = CommitmentState::try_from_raw(commitment_state)
+        .wrap_err("the execution node served an invalid commitment state")?;
+    let soft = commitment_state.soft();
+    let firm = commitment_state.firm();
+    let soft_block_age = age(now, soft.timestamp().seconds);
+    let mut issues = vec![];
+    if soft_block_age > max_block_age {
+        issues.push(format!(
+            "the latest soft block is {} old",
+            humantime::format_duration(soft_block_age)
+        ));
+    }
+    Ok(RollupStatus {
+        rollup_id,
+        soft_height: soft.number(),
+        firm_height: firm.number(),
+        firm_blocks_behind: soft.number().saturating_sub(firm.number()),
+        soft_block_age,
+        base_celestia_height: commitment_state.base_celestia_height(),
+        issues,
+    })
+}
+
+/// The application details the Sequencer encodes into its ABCI `Info` response
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct AppInfo {
+    applied_upgrades: Vec<String>,
+}
+
+/// The state served by the relayer's `/status` endpoint
+#[derive(Default, Deserialize)]
+#[serde(default)]
+struct RelayerSnapshot {
+    ready: bool,
+    celestia_connected: bool,
+    sequencer_connected: bool,
+    latest_confirmed_celestia_height: Option<u64>,
+    latest_fetched_sequencer_height: Option<u64>,
+}
+
+fn serialize_secs<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_secs())
+}
+
+#[derive(Serialize)]
+struct SequencerStatus {
+    chain_id: String,
+    height: u64,
+    #[serde(rename = "block_age_secs", serialize_with = "serialize_secs")]
+    block_age: Duration,
+    app_version: u64,
+    applied_upgrades: Vec<String>,
+    issues: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct RelayerStatus {
+    latest_fetched_sequencer_height: Option<u64>,
+    latest_confirmed_celestia_height: Option<u64>,
+    /// The number of Sequencer blocks that the relayer has not fetched yet
+    blocks_behind: Option<u64>,
+    issues: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct RollupStatus {
+    #[serde(serialize_with = "crate::output::display")]
+    rollup_id: RollupId,
+    soft_height: u32,
+    firm_height: u32,
+    /// The number of soft blocks that are not firm yet
+    firm_blocks_behind: u32,
+    #[serde(rename = "soft_block_age_secs", serialize_with = "serialize_secs")]
+    soft_block_age: Duration,
+    base_celestia_height: u64,
+    issues: Vec<String>,
+}
+
+/// A service of the overview, which is unreachable if its status could not be retrieved
+#[derive(Serialize)]
+struct Service<T> {
+    url: String,
+    reachable: bool,
+    /// Set if the service is unreachable
+    error: Option<String>,
+    status: Option<T>,
+}
+
+impl<T> Service<T> {
+    fn new(url: &str, status: eyre::Result<T>) -> Self {
+        match status {
+            Ok(status) => Self {
+                url: url.to_string(),
+                reachable: true,
+                error: None,
+                status: Some(status),
+            },
+            Err(err) => Self {
+                url: url.to_string(),
+                reachable: false,
+                error: Some(format!("{err:#}")),
+                status: None,
+            },
+        }
+    }
+
+    fn from_pair((status, url): (eyre::Result<T>, &str)) -> Self {
+        Self::new(url, status)
+    }
+}
+
+trait Issues {
+    fn issues(&self) -> &[String];
+}
+
+impl Issues for SequencerStatus {
+    fn issues(&self) -> &[String] {
+        &self.issues
+    }
+}
+
+impl Issues for RelayerStatus {
+    fn issues(&self) -> &[String] {
+        &self.issues
+    }
+}
+
+impl Issues for RollupStatus {
+    fn issues(&self) -> &[String] {
+        &self.issues
+    }
+}
+
+impl<T: Issues> Service<T> {
+    fn is_healthy(&self) -> bool {
+        self.status
+            .as_ref()
+            .is_some_and(|status| status.issues().is_empty())
+    }
+}
+
+/// The health and lag of the services of an environment
+#[derive(Serialize)]
+struct Overview {
+    sequencer: Service<SequencerStatus>,
+    relayer: Option<Service<RelayerStatus>>,
+    rollup: Option<Service<RollupStatus>>,
+}
+
+impl Overview {
+    fn is_healthy(&self) -> bool {
+        self.sequencer.is_healthy()
+            && self.relayer.as_ref().map_or(true, Service::is_healthy)
+            && self.rollup.as_ref().map_or(true, Service::is_healthy)
+    }
+}
+
+/// Writes the header line of a service, followed by its details if it is reachable.
+fn write_service<T: Issues>(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    service: &Service<T>,
+    details: impl FnOnce(&mut std::fmt::Formatter<'_>, &T) -> std::fmt::Result,
+) -> std::fmt::Result {
+    let health = match (&service.status, service.is_healthy()) {
+        (None, _) => "unreachable",
+        (Some(_), true) => "healthy",
+        (Some(_), false) => "degraded",
+    };
+    writeln!(f, "{name} ({}): {health}", service.url)?;
+    if let Some(error) = &service.error {
+        writeln!(f, "  error: {error}")?;
+    }
+    if let Some(status) = &service.status {
+        details(f, status)?;
+        for issue in status.issues() {
+            writeln!(f, "  issue: {issue}")?;
+        }
+    }
+    Ok(())
+}
+
+fn display_or_unknown<T: Display>(value: Option<T>) -> String {
+    value.map_or_else(|| "unknown".to_string(), |value| value.to_string())
+}
+
+impl Display for Overview {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_service(f, "Sequencer", &self.sequencer, |f, status| {
+            writeln!(f, "  chain id: {}", status.chain_id)?;
+            writeln!(
+                f,
+                "  height: {} (latest block {} old)",
+                status.height,
+                humantime::format_duration(status.block_age)
+            )?;
+            writeln!(f, "  app version: {}", status.app_version)?;
+            if status.applied_upgrades.is_empty() {
+                writeln!(f, "  applied upgrades: none")
+            } else {
+                writeln!(
+                    f,
+                    "  applied upgrades: {}",
+                    status.applied_upgrades.join(", ")
+                )
+            }
+        })?;
+        if let Some(relayer) = &self.relayer {
+            write_service(f, "Relayer", relayer, |f, status| {
+                writeln!(
+                    f,
+                    "  latest fetched Sequencer height: {} ({} blocks behind)",
+                    display_or_unknown(status.latest_fetched_sequencer_height),
+                    display_or_unknown(status.blocks_behind),
+                )?;
+                writeln!(
+                    f,
+                    "  latest confirmed Celestia height: {}",
+                    display_or_unknown(status.latest_confirmed_celestia_height),
+                )
+            })?;
+        }
+        if let Some(rollup) = &self.rollup {
+            write_service(f, "Rollup", rollup, |f, status| {
+                writeln!(f, "  rollup id: {}", status.rollup_id)?;
+                writeln!(
+                    f,
+                    "  soft height: {} (latest soft block {} old)",
+                    status.soft_height,
+                    humantime::format_duration(status.soft_block_age)
+                )?;
+                writeln!(
+                    f,
+                    "  firm height: {} ({} blocks behind soft)",
+                    status.firm_height, status.firm_blocks_behind
+                )?;
+                writeln!(f, "  base Celestia height: {}", status.base_celestia_height)
+            })?;
+        }
+        let overall = if self.is_healthy() {
+            "healthy"
+        } else {
+            "degraded"
+        };
+        write!(f, "Overall: {overall}")
+    }
+}
+
+impl Report for Overview {
+    const KIND: &'static str = "status";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sequencer(issues: Vec<String>) -> Service<SequencerStatus> {
+        Service::new(
+            "http://127.0.0.1:26657",
+            Ok(SequencerStatus {
+                chain_id: "astria".to_string(),
+                height: 10,
+                block_age: Duration::from_secs(1),
+                app_version: 1,
+                applied_upgrades: vec![],
+                issues,
+            }),
+        )
+    }
+
+    #[test]
+    fn block_age_is_zero_for_future_blocks() {
+        let now = UNIX_EPOCH + Duration::from_secs(100);
+        assert_eq!(Duration::from_secs(40), age(now, 60));
+        assert_eq!(Duration::ZERO, age(now, 160));
+    }
+
+    #[test]
+    fn unreachable_or_degraded_services_degrade_the_overview() {
+        let overview = Overview {
+            sequencer: sequencer(vec![]),
+            relayer: None,
+            rollup: None,
+        };
+        assert!(overview.is_healthy());
+
+        let overview = Overview {
+            sequencer: sequencer(vec!["the node is catching up".to_string()]),
+            relayer: None,
+            rollup: None,
+        };
+        assert!(!overview.is_healthy());
+
+        let overview = Overview {
+            sequencer: sequencer(vec![]),
+            relayer: Some(Service::new(
+                "http://127.0.0.1:2450",
+                Err(eyre!("connection refused")),
+            )),
+            rollup: None,
+        };
+        assert!(!overview.is_healthy());
+        assert!(overview.to_string().contains("unreachable"));
+    }
+}
@@ -0,0 +1,221 @@
+use std::fmt::Display;
+
+use astria_core::{
+    generated::protocol::transaction::v1alpha1 as raw,
+    protocol::transaction::v1alpha1::{
+        action::{
+            AddressPrefixChangeAction,
+            FeeAssetChangeAction,
+            IbcRelayerChangeAction,
+        },
+        Action,
+    },
+};
+use base64::{
+    prelude::BASE64_STANDARD,
+    Engine as _,
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        eyre,
+        Context,
+    },
+};
+use prost::Message as _;
+use serde::Serialize;
+
+use super::sequencer::{
+    submit_transaction,
+    TransactionReceipt,
+};
+use crate::{
+    cli::sequencer::SudoSubmitArgs,
+    output::{
+        self,
+        Report,
+    },
+};
+
+/// A sudo action encoded for review instead of being submitted
+#[derive(Serialize)]
+struct Preview {
+    /// The action as pbjson
+    action: serde_json::Value,
+    /// The action as base64-encoded protobuf
+    protobuf: String,
+}
+
+impl Preview {
+    fn new(action: Action) -> eyre::Result<Self> {
+        let raw = action.into_raw();
+        Ok(Self {
+            protobuf: BASE64_STANDARD.encode(raw.encode_to_vec()),
+            action: serde_json::to_value(raw).wrap_err("failed serializing action to pbjson")?,
+        })
+    }
+}
+
+impl Display for Preview {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let json = serde_json::to_string_pretty(&self.action).map_err(|_| std::fmt::Error)?;
+        writeln!(f, "{json}")?;
+        write!(f, "base64 protobuf: {}", self.protobuf)
+    }
+}
+
+impl Report for Preview {
+    const KIND: &'static str = "sudo_action_preview";
+}
+
+/// Prints `action` as pbjson and base64-encoded protobuf without signing or submitting it.
+///
+/// # Errors
+///
+/// * If the action cannot be serialized
+pub(super) fn preview(action: Action) -> eyre::Result<()> {
+    output::emit(&Preview::new(action)?)
+}
+
+/// Submits a sudo action read from a file
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the file cannot be read
+/// * If the file does not hold a valid sudo action
+/// * If the transaction failed to be included
+pub(crate) async fn submit_from_file(args: &SudoSubmitArgs) -> eyre::Result<()> {
+    let contents = std::fs::read_to_string(&args.from_file)
+        .wrap_err_with(|| format!("failed reading `{}`", args.from_file.display()))?;
+    let action = parse_action(&contents)
+        .wrap_err_with(|| format!("`{}` does not hold a sudo action", args.from_file.display()))?;
+    let name = sudo_action_name(&action).expect("parsed actions are sudo actions");
+    let Some(res) = submit_transaction(
+        args.sequencer_url.as_str(),
+        args.sequencer_chain_id.clone(),
+        &args.prefix,
+        &args.signer,
+        args.dry_run,
+        args.wait.inclusion_timeout(),
+        action,
+    )
+    .await
+    .wrap_err_with(|| format!("failed to submit {name} transaction"))?
+    else {
+        return Ok(());
+    };
+
+    output::emit(&TransactionReceipt::new(name, &res))
+}
+
+/// Parses a sudo action from pbjson, base64-encoded protobuf, or a preview printed with
+/// `--output json`.
+fn parse_action(contents: &str) -> eyre::Result<Action> {
+    let contents = contents.trim();
+    let raw = if contents.starts_with('{') {
+        let mut json: serde_json::Value =
+            serde_json::from_str(contents).wrap_err("failed parsing json")?;
+        if json.get("kind").and_then(serde_json::Value::as_str) == Some(Preview::KIND) {
+            json = json["data"]["action"].take();
+        }
+        serde_json::from_value::<raw::Action>(json).wrap_err("failed parsing action as pbjson")?
+    } else {
+        let bytes = BASE64_STANDARD
+            .decode(contents)
+            .wrap_err("failed decoding action as base64")?;
+        raw::Action::decode(&*bytes).wrap_err("failed decoding action as protobuf")?
+    };
+    let action = Action::try_from_raw(raw).wrap_err("invalid action")?;
+    sudo_action_name(&action).ok_or_else(|| eyre!("not a sudo action"))?;
+    Ok(action)
+}
+
+/// Returns the name of `action` if it is only permitted for the sudo address.
+fn sudo_action_name(action: &Action) -> Option<&'static str> {
+    let name = match action {
+        Action::ValidatorUpdate(_) => "ValidatorUpdate",
+        Action::SudoAddressChange(_) => "SudoAddressChange",
+        Action::IbcRelayerChange(IbcRelayerChangeAction::Addition(_)) => {
+            "IbcRelayerChangeAction::Addition"
+        }
+        Action::IbcRelayerChange(IbcRelayerChangeAction::Removal(_)) => {
+            "IbcRelayerChangeAction::Removal"
+        }
+        Action::FeeAssetChange(FeeAssetChangeAction::Addition(_)) => {
+            "FeeAssetChangeAction::Addition"
+        }
+        Action::FeeAssetChange(FeeAssetChangeAction::Removal(_)) => "FeeAssetChangeAction::Removal",
+        Action::FeeChange(_) => "FeeChange",
+        Action::FeeDistributionChange(_) => "FeeDistributionChange",
+        Action::AddressPrefixChange(AddressPrefixChangeAction::Addition(_)) => {
+            "AddressPrefixChangeAction::Addition"
+        }
+        Action::AddressPrefixChange(AddressPrefixChangeAction::Removal(_)) => {
+            "AddressPrefixChangeAction::Removal"
+        }
+        _ => return None,
+    };
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::asset,
+        protocol::transaction::v1alpha1::{
+            action::{
+                SudoAddressChangeAction,
+                TransferAction,
+            },
+            Action,
+        },
+    };
+
+    use super::{
+        parse_action,
+        Preview,
+        Report as _,
+    };
+
+    const ALICE: &str = "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm";
+
+    fn sudo_address_change() -> Action {
+        Action::SudoAddressChange(SudoAddressChangeAction {
+            new_address: ALICE.parse().unwrap(),
+        })
+    }
+
+    #[test]
+    fn previewed_actions_are_parsed_back() {
+        let expected = sudo_address_change().into_raw();
+        let preview = Preview::new(sudo_address_change()).unwrap();
+        let envelope = serde_json::json!({
+            "kind": Preview::KIND,
+            "schema_version": Preview::SCHEMA_VERSION,
+            "data": &preview,
+        });
+        for contents in [
+            preview.action.to_string(),
+            format!("{}\n", preview.protobuf),
+            envelope.to_string(),
+        ] {
+            assert_eq!(expected, parse_action(&contents).unwrap().into_raw());
+        }
+    }
+
+    #[test]
+    fn non_sudo_actions_are_rejected() {
+        let transfer = Action::Transfer(TransferAction {
+            to: ALICE.parse().unwrap(),
+            amount: 1,
+            asset_id: asset::default_native_asset().id(),
+            fee_asset_id: asset::default_native_asset().id(),
+        });
+        let preview = Preview::new(transfer).unwrap();
+        parse_action(&preview.protobuf).unwrap_err();
+    }
+}
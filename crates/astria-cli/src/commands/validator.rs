@@ -0,0 +1,321 @@
+use std::{
+    collections::BTreeMap,
+    fmt::Display,
+};
+
+use astria_sequencer_client::{
+    tendermint,
+    tendermint_rpc::Paging,
+    Client as _,
+    HttpClient,
+};
+use base64::{
+    prelude::BASE64_STANDARD,
+    Engine as _,
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        eyre,
+        Context,
+    },
+};
+use serde::Serialize;
+
+use crate::{
+    cli::sequencer::{
+        QueryArgs,
+        ValidatorListArgs,
+    },
+    output::{
+        self,
+        Report,
+    },
+};
+
+/// The number of blocks after which a validator update takes effect.
+///
+/// CometBFT applies the updates returned from `EndBlock` of block `H` to the validator set of
+/// block `H + 2`.
+pub(super) const ACTIVATION_DELAY: u64 = 2;
+
+/// Parses an ed25519 public key given either hex or base64 encoded.
+///
+/// # Errors
+///
+/// * If `input` is neither valid hex nor valid base64
+/// * If the decoded bytes are not an ed25519 public key
+pub(super) fn parse_public_key(input: &str) -> eyre::Result<tendermint::PublicKey> {
+    let bytes = hex::decode(input)
+        .or_else(|_| BASE64_STANDARD.decode(input))
+        .wrap_err("public key is neither valid hex nor valid base64")?;
+    tendermint::PublicKey::from_raw_ed25519(&bytes)
+        .ok_or_else(|| eyre!("public key is not a valid ed25519 public key"))
+}
+
+#[derive(Debug, Serialize)]
+struct Validator {
+    /// The hex-encoded ed25519 public key
+    public_key: String,
+    power: u64,
+}
+
+#[derive(Serialize)]
+struct ValidatorSet {
+    height: u64,
+    total_power: u64,
+    validators: Vec<Validator>,
+}
+
+impl Display for ValidatorSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} validators at height {} with a total power of {}",
+            self.validators.len(),
+            self.height,
+            self.total_power
+        )?;
+        for validator in &self.validators {
+            write!(f, "\n    {}: {}", validator.public_key, validator.power)?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for ValidatorSet {
+    const KIND: &'static str = "validator_set";
+}
+
+/// Lists the validator set of the Sequencer
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the http client cannot be created
+/// * If the validator set cannot be retrieved
+pub(crate) async fn list(args: &ValidatorListArgs) -> eyre::Result<()> {
+    let sequencer_client = client(&args.query)?;
+    let height = match args.height {
+        Some(height) => u64::from(height),
+        None => latest_height(&sequencer_client).await?,
+    };
+    let validators = validators_at(&sequencer_client, height).await?;
+    output::emit(&ValidatorSet {
+        height,
+        total_power: validators.iter().map(|validator| validator.power).sum(),
+        validators,
+    })
+}
+
+/// A change to the validator set that was committed but has not taken effect yet
+#[derive(Debug, PartialEq, Serialize)]
+struct PendingUpdate {
+    /// The height of the block whose execution returned the update
+    committed_height: u64,
+    activation_height: u64,
+    public_key: String,
+    /// The power before the update; 0 if the validator is being added
+    old_power: u64,
+    /// The power after the update; 0 if the validator is being removed
+    new_power: u64,
+}
+
+#[derive(Serialize)]
+struct PendingUpdates {
+    height: u64,
+    updates: Vec<PendingUpdate>,
+}
+
+impl Display for PendingUpdates {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.updates.is_empty() {
+            return write!(f, "no pending validator updates at height {}", self.height);
+        }
+        write!(f, "pending validator updates at height {}:", self.height)?;
+        for update in &self.updates {
+            write!(
+                f,
+                "\n    {}: {} -> {} at height {} (committed at height {})",
+                update.public_key,
+                update.old_power,
+                update.new_power,
+                update.activation_height,
+                update.committed_height
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Report for PendingUpdates {
+    const KIND: &'static str = "pending_validator_updates";
+}
+
+/// Lists the validator updates that were committed but have not taken effect yet
+///
+/// # Arguments
+///
+/// * `args` - The arguments passed to the command
+///
+/// # Errors
+///
+/// * If the http client cannot be created
+/// * If the validator set or the block results cannot be retrieved
+pub(crate) async fn pending(args: &QueryArgs) -> eyre::Result<()> {
+    let sequencer_client = client(args)?;
+    let height = latest_height(&sequencer_client).await?;
+    let current = validators_at(&sequencer_client, height).await?;
+
+    // the updates of the last `ACTIVATION_DELAY` blocks are not part of the current set yet
+    let mut committed = vec![];
+    let first_pending = height
+        .saturating_sub(ACTIVATION_DELAY)
+        .saturating_add(1)
+        .max(1);
+    for committed_height in first_pending..=height {
+        let block_height = tendermint::block::Height::try_from(committed_height)
+            .wrap_err("height is not a valid cometbft height")?;
+        let res = sequencer_client
+            .block_results(block_height)
+            .await
+            .wrap_err_with(|| {
+                format!("failed to get block results at height {committed_height}")
+            })?;
+        let updates = res
+            .validator_updates
+            .into_iter()
+            .map(|update| Validator {
+                public_key: hex::encode(update.pub_key.to_bytes()),
+                power: update.power.value(),
+            })
+            .collect();
+        committed.push((committed_height, updates));
+    }
+
+    output::emit(&PendingUpdates {
+        height,
+        updates: pending_updates(&current, committed),
+    })
+}
+
+/// Applies the validator updates `committed` at the given heights in order to the validator set
+/// `current`, returning each update with the power it changes.
+fn pending_updates(
+    current: &[Validator],
+    committed: Vec<(u64, Vec<Validator>)>,
+) -> Vec<PendingUpdate> {
+    let mut powers: BTreeMap<String, u64> = current
+        .iter()
+        .map(|validator| (validator.public_key.clone(), validator.power))
+        .collect();
+    let mut pending = vec![];
+    for (committed_height, updates) in committed {
+        for update in updates {
+            let old_power = powers
+                .insert(update.public_key.clone(), update.power)
+                .unwrap_or_default();
+            pending.push(PendingUpdate {
+                committed_height,
+                activation_height: committed_height.saturating_add(ACTIVATION_DELAY),
+                public_key: update.public_key,
+                old_power,
+                new_power: update.power,
+            });
+        }
+    }
+    pending
+}
+
+fn client(args: &QueryArgs) -> eyre::Result<HttpClient> {
+    HttpClient::new(args.sequencer_url.as_str())
+        .wrap_err("failed constructing http sequencer client")
+}
+
+async fn latest_height(sequencer_client: &HttpClient) -> eyre::Result<u64> {
+    Ok(sequencer_client
+        .latest_block()
+        .await
+        .wrap_err("failed to get latest cometbft block")?
+        .block
+        .header
+        .height
+        .value())
+}
+
+async fn validators_at(sequencer_client: &HttpClient, height: u64) -> eyre::Result<Vec<Validator>> {
+    let height = tendermint::block::Height::try_from(height)
+        .wrap_err("height is not a valid cometbft height")?;
+    let res = sequencer_client
+        .validators(height, Paging::All)
+        .await
+        .wrap_err_with(|| format!("failed to get validator set at height {height}"))?;
+    Ok(res
+        .validators
+        .into_iter()
+        .map(|info| Validator {
+            public_key: hex::encode(info.pub_key.to_bytes()),
+            power: info.power.value(),
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::crypto::SigningKey;
+    use base64::{
+        prelude::BASE64_STANDARD,
+        Engine as _,
+    };
+
+    use super::{
+        parse_public_key,
+        pending_updates,
+        PendingUpdate,
+        Validator,
+    };
+
+    fn validator(public_key: &str, power: u64) -> Validator {
+        Validator {
+            public_key: public_key.to_string(),
+            power,
+        }
+    }
+
+    #[test]
+    fn pending_updates_track_power_across_blocks() {
+        let current = [validator("aa", 10), validator("bb", 10)];
+        let committed = vec![
+            (11, vec![validator("bb", 0), validator("cc", 5)]),
+            (12, vec![validator("cc", 7)]),
+        ];
+        let update = |committed_height, public_key: &str, old_power, new_power| PendingUpdate {
+            committed_height,
+            activation_height: committed_height + 2,
+            public_key: public_key.to_string(),
+            old_power,
+            new_power,
+        };
+        assert_eq!(
+            vec![
+                update(11, "bb", 10, 0),
+                update(11, "cc", 0, 5),
+                update(12, "cc", 5, 7),
+            ],
+            pending_updates(&current, committed)
+        );
+    }
+
+    #[test]
+    fn public_keys_are_parsed_from_hex_and_base64() {
+        let bytes = SigningKey::from([1; 32]).verification_key().to_bytes();
+        let from_hex = parse_public_key(&hex::encode(bytes)).unwrap();
+        let from_base64 = parse_public_key(&BASE64_STANDARD.encode(bytes)).unwrap();
+        assert_eq!(from_hex, from_base64);
+        assert_eq!(bytes.to_vec(), from_hex.to_bytes());
+        parse_public_key("not a key").unwrap_err();
+    }
+}
@@ -0,0 +1,30 @@
+//! The directory holding the files the CLI keeps between invocations, such as the keystore and
+//! the address book.
+use std::{
+    env,
+    path::PathBuf,
+};
+
+use color_eyre::{
+    eyre,
+    eyre::eyre,
+};
+
+/// The environment variable overriding the default `$HOME/.astria` directory.
+pub(crate) const HOME_ENV_VAR: &str = "ASTRIA_HOME";
+
+/// Returns the value of [`HOME_ENV_VAR`] if set, or `$HOME/.astria` otherwise.
+///
+/// # Errors
+///
+/// * If neither [`HOME_ENV_VAR`] nor `$HOME` is set
+pub(crate) fn astria_home() -> eyre::Result<PathBuf> {
+    if let Some(dir) = env::var_os(HOME_ENV_VAR) {
+        return Ok(PathBuf::from(dir));
+    }
+    env::var_os("HOME")
+        .map(|home| PathBuf::from(home).join(".astria"))
+        .ok_or_else(|| {
+            eyre!("neither `{HOME_ENV_VAR}` nor `HOME` is set; provide the path explicitly")
+        })
+}
@@ -0,0 +1,337 @@
+//! An encrypted on-disk store of Sequencer signing keys.
+//!
+//! Every key is stored as a JSON file `<name>.json` in the keystore directory. The file holds the
+//! hex-encoded verification key in plain text so that keys can be listed without a passphrase,
+//! and the signing key encrypted with [age] using a scrypt-derived key from the passphrase.
+//!
+//! [age]: https://age-encryption.org
+use std::{
+    fs,
+    io::{
+        Read as _,
+        Write as _,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use age::secrecy::{
+    ExposeSecret as _,
+    Secret,
+    SecretString,
+};
+use astria_core::crypto::{
+    SigningKey,
+    VerificationKey,
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        bail,
+        ensure,
+        eyre,
+        Context,
+    },
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::home::astria_home;
+
+/// The environment variable holding the keystore passphrase. If unset, the passphrase is
+/// prompted for.
+pub(crate) const PASSPHRASE_ENV_VAR: &str = "ASTRIA_KEYSTORE_PASSPHRASE";
+
+const KEY_FILE_EXTENSION: &str = "json";
+
+/// The contents of a key file.
+#[derive(Debug, Deserialize, Serialize)]
+struct KeyFile {
+    /// The hex-encoded verification key.
+    verification_key: String,
+    /// The hex-encoded age ciphertext of the signing key bytes.
+    encrypted_signing_key: String,
+}
+
+/// A key stored in the keystore.
+#[derive(Debug)]
+pub(crate) struct KeyEntry {
+    pub(crate) name: String,
+    pub(crate) verification_key: VerificationKey,
+}
+
+pub(crate) struct Keystore {
+    dir: PathBuf,
+}
+
+impl Keystore {
+    /// Opens the keystore at `dir`, or at `keystore` in the [`astria_home`] directory if `dir` is
+    /// `None`.
+    ///
+    /// The directory is created if it does not exist.
+    ///
+    /// # Errors
+    ///
+    /// * If `dir` is `None` and the home directory cannot be determined
+    /// * If the keystore directory cannot be created
+    pub(crate) fn open(dir: Option<&Path>) -> eyre::Result<Self> {
+        let dir = match dir {
+            Some(dir) => dir.to_path_buf(),
+            None => astria_home()?.join("keystore"),
+        };
+        fs::create_dir_all(&dir)
+            .wrap_err_with(|| format!("failed creating keystore directory `{}`", dir.display()))?;
+        Ok(Self {
+            dir,
+        })
+    }
+
+    /// Encrypts `signing_key` with `passphrase` and stores it under `name`.
+    ///
+    /// # Errors
+    ///
+    /// * If `name` is not a valid key name
+    /// * If a key named `name` already exists
+    /// * If the key cannot be encrypted or written to disk
+    pub(crate) fn insert(
+        &self,
+        name: &str,
+        signing_key: &SigningKey,
+        passphrase: SecretString,
+    ) -> eyre::Result<KeyEntry> {
+        let path = self.key_path(name)?;
+        ensure!(
+            !path.exists(),
+            "a key named `{name}` already exists in the keystore"
+        );
+
+        let mut ciphertext = vec![];
+        let mut writer = age::Encryptor::with_user_passphrase(passphrase)
+            .wrap_output(&mut ciphertext)
+            .wrap_err("failed initializing encryption of signing key")?;
+        writer
+            .write_all(signing_key.as_bytes())
+            .wrap_err("failed encrypting signing key")?;
+        writer
+            .finish()
+            .wrap_err("failed finishing encryption of signing key")?;
+
+        let verification_key = signing_key.verification_key();
+        let key_file = KeyFile {
+            verification_key: hex::encode(verification_key.to_bytes()),
+            encrypted_signing_key: hex::encode(ciphertext),
+        };
+        let contents =
+            serde_json::to_vec_pretty(&key_file).wrap_err("failed serializing key file")?;
+        write_private_file(&path, &contents)
+            .wrap_err_with(|| format!("failed writing key file `{}`", path.display()))?;
+
+        Ok(KeyEntry {
+            name: name.to_string(),
+            verification_key,
+        })
+    }
+
+    /// Decrypts the signing key stored under `name` using `passphrase`.
+    ///
+    /// # Errors
+    ///
+    /// * If there is no key named `name`
+    /// * If the key cannot be decrypted with `passphrase`
+    pub(crate) fn signing_key(
+        &self,
+        name: &str,
+        passphrase: &SecretString,
+    ) -> eyre::Result<SigningKey> {
+        let key_file = self.read_key_file(name)?;
+        let ciphertext = hex::decode(key_file.encrypted_signing_key)
+            .wrap_err("failed decoding encrypted signing key from hex")?;
+
+        let age::Decryptor::Passphrase(decryptor) = age::Decryptor::new(&ciphertext[..])
+            .wrap_err("failed reading encrypted signing key")?
+        else {
+            bail!("signing key of `{name}` was not encrypted with a passphrase");
+        };
+        let mut reader = decryptor
+            .decrypt(passphrase, None)
+            .wrap_err_with(|| format!("failed decrypting signing key of `{name}`"))?;
+        let mut plaintext = vec![];
+        reader
+            .read_to_end(&mut plaintext)
+            .wrap_err_with(|| format!("failed decrypting signing key of `{name}`"))?;
+
+        let signing_key = SigningKey::try_from(&plaintext[..])
+            .wrap_err("decrypted bytes are not a valid signing key")?;
+        ensure!(
+            hex::encode(signing_key.verification_key().to_bytes()) == key_file.verification_key,
+            "decrypted signing key of `{name}` does not match its stored verification key"
+        );
+        Ok(signing_key)
+    }
+
+    /// Returns the entry of the key stored under `name`.
+    ///
+    /// # Errors
+    ///
+    /// * If there is no key named `name` or its key file is invalid
+    pub(crate) fn get(&self, name: &str) -> eyre::Result<KeyEntry> {
+        let key_file = self.read_key_file(name)?;
+        let verification_key = parse_verification_key(&key_file.verification_key)
+            .wrap_err_with(|| format!("key file of `{name}` is invalid"))?;
+        Ok(KeyEntry {
+            name: name.to_string(),
+            verification_key,
+        })
+    }
+
+    /// Returns all keys in the keystore, sorted by name.
+    ///
+    /// # Errors
+    ///
+    /// * If the keystore directory cannot be read
+    /// * If any key file is invalid
+    pub(crate) fn list(&self) -> eyre::Result<Vec<KeyEntry>> {
+        let mut names = vec![];
+        for dir_entry in fs::read_dir(&self.dir).wrap_err_with(|| {
+            format!("failed reading keystore directory `{}`", self.dir.display())
+        })? {
+            let path = dir_entry
+                .wrap_err("failed reading keystore directory entry")?
+                .path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some(KEY_FILE_EXTENSION) {
+                continue;
+            }
+            if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+                names.push(name.to_string());
+            }
+        }
+        names.sort();
+        names.iter().map(|name| self.get(name)).collect()
+    }
+
+    fn read_key_file(&self, name: &str) -> eyre::Result<KeyFile> {
+        let path = self.key_path(name)?;
+        ensure!(path.exists(), "no key named `{name}` in the keystore");
+        let contents = fs::read(&path)
+            .wrap_err_with(|| format!("failed reading key file `{}`", path.display()))?;
+        serde_json::from_slice(&contents)
+            .wrap_err_with(|| format!("failed parsing key file `{}`", path.display()))
+    }
+
+    fn key_path(&self, name: &str) -> eyre::Result<PathBuf> {
+        ensure!(
+            !name.is_empty()
+                && name
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+            "invalid key name `{name}`; only ASCII letters, digits, `-` and `_` are permitted"
+        );
+        Ok(self.dir.join(format!("{name}.{KEY_FILE_EXTENSION}")))
+    }
+}
+
+/// Reads the keystore passphrase from [`PASSPHRASE_ENV_VAR`], prompting for it if unset.
+///
+/// If `confirm` is set and the passphrase is prompted for, it must be entered twice.
+///
+/// # Errors
+///
+/// * If the passphrase cannot be read from the terminal
+/// * If `confirm` is set and the two entered passphrases differ
+pub(crate) fn read_passphrase(confirm: bool) -> eyre::Result<SecretString> {
+    if let Ok(passphrase) = env::var(PASSPHRASE_ENV_VAR) {
+        return Ok(Secret::new(passphrase));
+    }
+    let passphrase = Secret::new(
+        rpassword::prompt_password("Keystore passphrase: ")
+            .wrap_err("failed reading passphrase")?,
+    );
+    if confirm {
+        let confirmation = rpassword::prompt_password("Confirm keystore passphrase: ")
+            .wrap_err("failed reading passphrase")?;
+        ensure!(
+            passphrase.expose_secret() == &confirmation,
+            "passphrases do not match"
+        );
+    }
+    Ok(passphrase)
+}
+
+fn parse_verification_key(hex_key: &str) -> eyre::Result<VerificationKey> {
+    let bytes: [u8; 32] = hex::decode(hex_key)
+        .wrap_err("failed decoding verification key from hex")?
+        .try_into()
+        .map_err(|_| eyre!("invalid verification key length; must be 32 bytes"))?;
+    VerificationKey::try_from(bytes).wrap_err("invalid verification key")
+}
+
+/// Writes `contents` to a new file at `path` that is only readable by the current user.
+fn write_private_file(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt as _;
+        options.mode(0o600);
+    }
+    options.open(path)?.write_all(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use age::secrecy::Secret;
+    use astria_core::crypto::SigningKey;
+    use rand::rngs::OsRng;
+    use test_utils::with_temp_directory;
+
+    use super::Keystore;
+
+    #[tokio::test]
+    async fn inserted_key_can_be_listed_and_decrypted() {
+        with_temp_directory(|_dir| async {
+            let keystore = Keystore::open(Some(Path::new("keystore"))).unwrap();
+            let signing_key = SigningKey::new(OsRng);
+            keystore
+                .insert("alice", &signing_key, Secret::new("hunter2".to_string()))
+                .unwrap();
+
+            let entries = keystore.list().unwrap();
+            assert_eq!(1, entries.len());
+            assert_eq!("alice", entries[0].name);
+            assert_eq!(signing_key.verification_key(), entries[0].verification_key);
+
+            let decrypted = keystore
+                .signing_key("alice", &Secret::new("hunter2".to_string()))
+                .unwrap();
+            assert_eq!(signing_key.to_bytes(), decrypted.to_bytes());
+
+            keystore
+                .signing_key("alice", &Secret::new("wrong".to_string()))
+                .unwrap_err();
+            keystore
+                .insert("alice", &signing_key, Secret::new("hunter2".to_string()))
+                .unwrap_err();
+        })
+        .await;
+    }
+
+    #[tokio::test]
+    async fn invalid_key_names_are_rejected() {
+        with_temp_directory(|_dir| async {
+            let keystore = Keystore::open(Some(Path::new("keystore"))).unwrap();
+            let signing_key = SigningKey::new(OsRng);
+            for name in ["", "../alice", "alice bob", "alice.json"] {
+                keystore
+                    .insert(name, &signing_key, Secret::new("hunter2".to_string()))
+                    .unwrap_err();
+            }
+        })
+        .await;
+    }
+}
@@ -1,3 +1,8 @@
+pub(crate) mod address_book;
 pub mod cli;
 pub mod commands;
+pub(crate) mod home;
+pub(crate) mod keystore;
+pub mod output;
+pub(crate) mod signer;
 pub mod types;
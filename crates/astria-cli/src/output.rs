@@ -0,0 +1,185 @@
+//! Printing of command results.
+//!
+//! Commands print their results as [`Report`]s, rendered as human-readable text by default or as
+//! JSON or YAML documents if selected with `--output`. Machine-readable documents wrap the report
+//! in an envelope naming its kind and schema version so that scripts can detect changes to the
+//! schema of a report:
+//!
+//! ```json
+//! { "kind": "nonce", "schema_version": 1, "data": { "address": "astria1...", ... } }
+//! ```
+//!
+//! Commands that print a report per event, such as the watch commands, print a JSON document per
+//! line or a YAML stream.
+use std::{
+    fmt::Display,
+    sync::OnceLock,
+};
+
+use clap::ValueEnum;
+use color_eyre::{
+    eyre,
+    eyre::Context,
+};
+use serde::{
+    Serialize,
+    Serializer,
+};
+
+static FORMAT: OnceLock<Format> = OnceLock::new();
+
+/// The format command results are printed in
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human-readable text
+    #[default]
+    Text,
+    /// JSON documents wrapped in a versioned envelope
+    Json,
+    /// YAML documents wrapped in a versioned envelope
+    Yaml,
+}
+
+/// A command result with a stable machine-readable schema.
+///
+/// `SCHEMA_VERSION` must be incremented whenever a field of the report is removed, renamed, or
+/// changes its meaning. Adding fields does not require a new version.
+pub(crate) trait Report: Serialize + Display {
+    /// The name identifying the schema of the report.
+    const KIND: &'static str;
+    const SCHEMA_VERSION: u32 = 1;
+}
+
+#[derive(Serialize)]
+struct Envelope<'a, T> {
+    kind: &'static str,
+    schema_version: u32,
+    data: &'a T,
+}
+
+impl<'a, T: Report> Envelope<'a, T> {
+    fn new(data: &'a T) -> Self {
+        Self {
+            kind: T::KIND,
+            schema_version: T::SCHEMA_VERSION,
+            data,
+        }
+    }
+}
+
+/// Sets the format of all subsequent output. Only the first call has an effect.
+pub(crate) fn set_format(format: Format) {
+    let _ = FORMAT.set(format);
+}
+
+fn format() -> Format {
+    FORMAT.get().copied().unwrap_or_default()
+}
+
+/// Prints `report` as the result of a command.
+///
+/// # Errors
+///
+/// * If the report cannot be serialized
+pub(crate) fn emit<T: Report>(report: &T) -> eyre::Result<()> {
+    match format() {
+        Format::Text => println!("{report}"),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&Envelope::new(report))
+                .wrap_err("failed serializing output as json")?
+        ),
+        Format::Yaml => print!(
+            "{}",
+            serde_yaml::to_string(&Envelope::new(report))
+                .wrap_err("failed serializing output as yaml")?
+        ),
+    }
+    Ok(())
+}
+
+/// Prints `report` as one of a stream of results, such as the events of a watch command.
+///
+/// # Errors
+///
+/// * If the report cannot be serialized
+pub(crate) fn emit_event<T: Report>(report: &T) -> eyre::Result<()> {
+    match format() {
+        Format::Text => println!("{report}"),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(&Envelope::new(report))
+                .wrap_err("failed serializing output as json")?
+        ),
+        Format::Yaml => print!(
+            "---\n{}",
+            serde_yaml::to_string(&Envelope::new(report))
+                .wrap_err("failed serializing output as yaml")?
+        ),
+    }
+    Ok(())
+}
+
+/// Prints a progress message that is not part of the result of a command.
+///
+/// The message goes to stdout for text output and to stderr otherwise so that it does not
+/// corrupt machine-readable output.
+pub(crate) fn note(message: impl Display) {
+    match format() {
+        Format::Text => println!("{message}"),
+        Format::Json | Format::Yaml => eprintln!("{message}"),
+    }
+}
+
+/// Serializes `value` through its `Display` implementation.
+///
+/// Used for addresses, asset IDs and the like, and for `u128` amounts which many JSON parsers
+/// cannot represent as numbers.
+pub(crate) fn display<T: Display, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Serialize;
+
+    use super::{
+        Envelope,
+        Report,
+    };
+
+    #[derive(Serialize)]
+    struct Balance {
+        #[serde(serialize_with = "super::display")]
+        amount: u128,
+    }
+
+    impl std::fmt::Display for Balance {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.amount)
+        }
+    }
+
+    impl Report for Balance {
+        const KIND: &'static str = "balance";
+    }
+
+    #[test]
+    fn reports_are_wrapped_in_versioned_envelope() {
+        let report = Balance {
+            amount: u128::MAX,
+        };
+        let json = serde_json::to_value(Envelope::new(&report)).unwrap();
+        assert_eq!(
+            serde_json::json!({
+                "kind": "balance",
+                "schema_version": 1,
+                "data": { "amount": u128::MAX.to_string() },
+            }),
+            json
+        );
+    }
+}
@@ -0,0 +1,268 @@
+//! Signing with the Astria app on a Ledger hardware wallet.
+//!
+//! The app follows the APDU conventions of other ed25519 Ledger apps: the verification key is
+//! requested with the BIP-44 derivation path as payload, and a message is signed by sending the
+//! derivation path in an initial chunk followed by the message in chunks of at most
+//! [`CHUNK_SIZE`] bytes. The signature is returned in the response to the last chunk, after the
+//! user has approved it on the device.
+use std::str::FromStr;
+
+use astria_core::crypto::{
+    Signature,
+    VerificationKey,
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        bail,
+        ensure,
+        eyre,
+        Context,
+    },
+};
+use ledger_transport::APDUCommand;
+use ledger_transport_hid::{
+    hidapi::HidApi,
+    TransportNativeHID,
+};
+
+const CLA: u8 = 0x80;
+const INS_GET_VERIFICATION_KEY: u8 = 0x01;
+const INS_SIGN: u8 = 0x02;
+
+const P1_NO_CONFIRM: u8 = 0x00;
+
+const PAYLOAD_INIT: u8 = 0x00;
+const PAYLOAD_ADD: u8 = 0x01;
+const PAYLOAD_LAST: u8 = 0x02;
+
+const CHUNK_SIZE: usize = 250;
+
+const RETCODE_OK: u16 = 0x9000;
+const RETCODE_REJECTED: u16 = 0x6986;
+
+const HARDENED: u32 = 0x8000_0000;
+const DERIVATION_PATH_LEN: usize = 5;
+
+/// A connection to the Astria app on a Ledger device, bound to the key at one derivation path.
+pub(crate) struct Ledger {
+    transport: TransportNativeHID,
+    derivation_path: DerivationPath,
+    verification_key: VerificationKey,
+}
+
+impl Ledger {
+    /// Connects to the first Ledger device found and fetches the verification key at
+    /// `derivation_path`.
+    ///
+    /// # Errors
+    ///
+    /// * If `derivation_path` is not a valid, fully hardened BIP-44 path
+    /// * If no Ledger device with the Astria app open can be found
+    /// * If the device does not return a valid verification key
+    pub(crate) fn connect(derivation_path: &str) -> eyre::Result<Self> {
+        let derivation_path: DerivationPath = derivation_path.parse()?;
+        let api = HidApi::new().wrap_err("failed initializing HID API")?;
+        let transport = TransportNativeHID::new(&api).wrap_err(
+            "failed connecting to Ledger device; make sure it is plugged in, unlocked, and the \
+             Astria app is open",
+        )?;
+        let response = exchange(
+            &transport,
+            INS_GET_VERIFICATION_KEY,
+            P1_NO_CONFIRM,
+            derivation_path.to_bytes(),
+        )
+        .wrap_err("failed fetching verification key from Ledger device")?;
+        let verification_key_bytes: [u8; 32] = response
+            .get(..32)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| eyre!("Ledger device returned a truncated verification key"))?;
+        let verification_key = VerificationKey::try_from(verification_key_bytes)
+            .wrap_err("Ledger device returned an invalid verification key")?;
+        Ok(Self {
+            transport,
+            derivation_path,
+            verification_key,
+        })
+    }
+
+    pub(crate) fn verification_key(&self) -> VerificationKey {
+        self.verification_key
+    }
+
+    /// Signs `message` on the device, blocking until the user approves or rejects it.
+    ///
+    /// # Errors
+    ///
+    /// * If the user rejects the message on the device
+    /// * If communication with the device fails
+    /// * If the device returns a signature that does not verify
+    pub(crate) fn sign(&self, message: &[u8]) -> eyre::Result<Signature> {
+        println!("review and approve the transaction on your Ledger device");
+        let mut response = vec![];
+        for (p1, payload) in sign_requests(&self.derivation_path, message)? {
+            response = exchange(&self.transport, INS_SIGN, p1, payload)
+                .wrap_err("failed signing with Ledger device")?;
+        }
+        let signature_bytes: [u8; 64] = response
+            .get(..64)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| eyre!("Ledger device returned a truncated signature"))?;
+        let signature = Signature::from(signature_bytes);
+        self.verification_key
+            .verify(&signature, message)
+            .wrap_err("Ledger device returned a signature that does not verify")?;
+        Ok(signature)
+    }
+}
+
+/// Splits signing `message` into the sequence of `(p1, payload)` requests sent to the device.
+fn sign_requests(
+    derivation_path: &DerivationPath,
+    message: &[u8],
+) -> eyre::Result<Vec<(u8, Vec<u8>)>> {
+    ensure!(!message.is_empty(), "cannot sign an empty message");
+    let mut requests = vec![(PAYLOAD_INIT, derivation_path.to_bytes())];
+    let mut chunks = message.chunks(CHUNK_SIZE).peekable();
+    while let Some(chunk) = chunks.next() {
+        let p1 = if chunks.peek().is_some() {
+            PAYLOAD_ADD
+        } else {
+            PAYLOAD_LAST
+        };
+        requests.push((p1, chunk.to_vec()));
+    }
+    Ok(requests)
+}
+
+fn exchange(
+    transport: &TransportNativeHID,
+    ins: u8,
+    p1: u8,
+    data: Vec<u8>,
+) -> eyre::Result<Vec<u8>> {
+    let answer = transport
+        .exchange(&APDUCommand {
+            cla: CLA,
+            ins,
+            p1,
+            p2: 0,
+            data,
+        })
+        .wrap_err("failed communicating with Ledger device")?;
+    match answer.retcode() {
+        RETCODE_OK => Ok(answer.data().to_vec()),
+        RETCODE_REJECTED => bail!("request was rejected on the Ledger device"),
+        code => bail!("Ledger device returned error code {code:#06x}"),
+    }
+}
+
+/// A fully hardened BIP-44 derivation path, e.g. `m/44'/118'/0'/0'/0'`.
+///
+/// ed25519 keys only support hardened derivation, so every component must be hardened.
+#[derive(Debug, PartialEq, Eq)]
+struct DerivationPath([u32; DERIVATION_PATH_LEN]);
+
+impl DerivationPath {
+    /// Encodes the path as the little endian components expected by the device.
+    fn to_bytes(&self) -> Vec<u8> {
+        self.0
+            .iter()
+            .flat_map(|index| index.to_le_bytes())
+            .collect()
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let components = s
+            .strip_prefix("m/")
+            .ok_or_else(|| eyre!("derivation path `{s}` must start with `m/`"))?;
+        let indices = components
+            .split('/')
+            .map(|component| {
+                let index = component.strip_suffix('\'').ok_or_else(|| {
+                    eyre!("derivation path component `{component}` must be hardened")
+                })?;
+                let index: u32 = index.parse().wrap_err_with(|| {
+                    format!("derivation path component `{component}` is not a valid index")
+                })?;
+                ensure!(
+                    index < HARDENED,
+                    "derivation path component `{component}` is out of range"
+                );
+                Ok(index | HARDENED)
+            })
+            .collect::<eyre::Result<Vec<_>>>()?;
+        let indices = indices.try_into().map_err(|_| {
+            eyre!("derivation path `{s}` must have exactly {DERIVATION_PATH_LEN} components")
+        })?;
+        Ok(Self(indices))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        sign_requests,
+        DerivationPath,
+        CHUNK_SIZE,
+        HARDENED,
+        PAYLOAD_ADD,
+        PAYLOAD_INIT,
+        PAYLOAD_LAST,
+    };
+
+    #[test]
+    fn derivation_path_is_parsed_and_encoded() {
+        let path: DerivationPath = "m/44'/118'/0'/0'/1'".parse().unwrap();
+        assert_eq!(
+            DerivationPath([
+                44 | HARDENED,
+                118 | HARDENED,
+                HARDENED,
+                HARDENED,
+                1 | HARDENED
+            ]),
+            path
+        );
+        assert_eq!(
+            [0x2c, 0, 0, 0x80],
+            path.to_bytes()[..4],
+            "components must be encoded little endian"
+        );
+    }
+
+    #[test]
+    fn invalid_derivation_paths_are_rejected() {
+        for path in [
+            "44'/118'/0'/0'/0'",
+            "m/44'/118'/0'/0/0",
+            "m/44'/118'/0'/0'",
+            "m/44'/118'/0'/0'/2147483648'",
+            "m/44'/x'/0'/0'/0'",
+        ] {
+            path.parse::<DerivationPath>().unwrap_err();
+        }
+    }
+
+    #[test]
+    fn message_is_split_into_chunks() {
+        let path: DerivationPath = "m/44'/118'/0'/0'/0'".parse().unwrap();
+        let message = vec![0; CHUNK_SIZE * 2 + 1];
+        let requests = sign_requests(&path, &message).unwrap();
+
+        let p1s: Vec<_> = requests.iter().map(|(p1, _)| *p1).collect();
+        assert_eq!(
+            vec![PAYLOAD_INIT, PAYLOAD_ADD, PAYLOAD_ADD, PAYLOAD_LAST],
+            p1s
+        );
+        assert_eq!(path.to_bytes(), requests[0].1);
+        assert_eq!(1, requests[3].1.len());
+
+        sign_requests(&path, &[]).unwrap_err();
+    }
+}
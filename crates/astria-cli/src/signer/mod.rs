@@ -0,0 +1,130 @@
+//! The backends that can sign Sequencer transactions.
+//!
+//! A [`Signer`] is selected from the [`SignerArgs`] shared by all commands that submit
+//! transactions. It is either a key held in memory on the host (read from the keystore or given
+//! as a raw private key), or a key that never leaves a Ledger hardware wallet.
+#[cfg(feature = "ledger")]
+mod ledger;
+
+use astria_core::{
+    crypto::{
+        SigningKey,
+        VerificationKey,
+    },
+    protocol::transaction::v1alpha1::{
+        SignedTransaction,
+        UnsignedTransaction,
+    },
+};
+use color_eyre::{
+    eyre,
+    eyre::{
+        eyre,
+        Context,
+    },
+};
+
+use crate::{
+    cli::sequencer::SignerArgs,
+    keystore::{
+        self,
+        Keystore,
+    },
+};
+
+pub(crate) enum Signer {
+    /// A signing key held in memory on the host.
+    Local(SigningKey),
+    /// A key held by the Astria app on a Ledger device.
+    #[cfg(feature = "ledger")]
+    Ledger(ledger::Ledger),
+}
+
+impl Signer {
+    /// Returns the signer selected by `args`.
+    ///
+    /// A Ledger device takes precedence over a named key from the keystore or a raw private key,
+    /// which cannot both be given.
+    ///
+    /// # Errors
+    ///
+    /// * If a Ledger was requested but cannot be connected to
+    /// * If neither a Ledger, a key name nor a private key was provided
+    /// * If the named key cannot be decrypted
+    /// * If the private key is not valid
+    pub(crate) fn from_args(args: &SignerArgs) -> eyre::Result<Self> {
+        if args.ledger {
+            return connect_ledger(&args.ledger_path);
+        }
+        if let Some(name) = &args.key {
+            let keystore = Keystore::open(args.keystore.keystore_dir.as_deref())?;
+            let passphrase = keystore::read_passphrase(false)?;
+            return keystore.signing_key(name, &passphrase).map(Self::Local);
+        }
+        let private_key = args.private_key.as_deref().ok_or_else(|| {
+            eyre!(
+                "either a Ledger (`--ledger`), a keystore key name (`--key`) or a private key \
+                 (`--private-key`) is required"
+            )
+        })?;
+        signing_key_from_hex(private_key).map(Self::Local)
+    }
+
+    pub(crate) fn verification_key(&self) -> VerificationKey {
+        match self {
+            Self::Local(signing_key) => signing_key.verification_key(),
+            #[cfg(feature = "ledger")]
+            Self::Ledger(ledger) => ledger.verification_key(),
+        }
+    }
+
+    /// Signs `transaction`.
+    ///
+    /// # Errors
+    ///
+    /// * If the signer is a Ledger and the device fails to sign or the user rejects the transaction
+    pub(crate) fn sign_transaction(
+        &self,
+        transaction: UnsignedTransaction,
+    ) -> eyre::Result<SignedTransaction> {
+        match self {
+            Self::Local(signing_key) => Ok(transaction.into_signed(signing_key)),
+            #[cfg(feature = "ledger")]
+            Self::Ledger(ledger) => {
+                use astria_core::generated::protocol::transaction::v1alpha1 as raw;
+
+                let transaction = transaction.into_any();
+                let signature = ledger.sign(&transaction.value)?;
+                SignedTransaction::try_from_raw(raw::SignedTransaction {
+                    signature: signature.to_bytes().to_vec(),
+                    public_key: ledger.verification_key().to_bytes().to_vec(),
+                    transaction: Some(transaction),
+                })
+                .wrap_err("failed constructing signed transaction from Ledger signature")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "ledger")]
+fn connect_ledger(derivation_path: &str) -> eyre::Result<Signer> {
+    ledger::Ledger::connect(derivation_path).map(Signer::Ledger)
+}
+
+#[cfg(not(feature = "ledger"))]
+fn connect_ledger(_derivation_path: &str) -> eyre::Result<Signer> {
+    eyre::bail!("astria-cli was built without Ledger support; rebuild it with `--features ledger`")
+}
+
+/// Parses a hex-encoded 32 byte private key.
+///
+/// # Errors
+///
+/// * If `private_key` is not valid hex or not 32 bytes long
+pub(crate) fn signing_key_from_hex(private_key: &str) -> eyre::Result<SigningKey> {
+    let private_key_bytes: [u8; 32] = hex::decode(private_key)
+        .wrap_err("failed to decode private key bytes from hex string")?
+        .try_into()
+        .map_err(|_| eyre!("invalid private key length; must be 32 bytes"))?;
+    Ok(SigningKey::from(private_key_bytes))
+}
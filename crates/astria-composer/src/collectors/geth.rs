@@ -12,6 +12,13 @@
 //! ``` json
 //! { "id": 1, "jsonrpc": "2.0", "method": "eth_subscribe", "params": ["newPendingTransactions"] }
 //! ```
+//!
+//! ## Priority
+//! The collector periodically reads the fee levels suggested by the rollup node through the
+//! `eth_maxPriorityFeePerGas` and `eth_blobBaseFee` JSON-RPCs. Transactions offering at least
+//! [`URGENT_FEE_MULTIPLIER`] times the suggested priority fee, or for EIP-4844 blob transactions
+//! the current blob base fee, are forwarded to the executor with [`Priority::High`]. Nodes which
+//! do not serve these RPCs are tolerated, in which case all transactions have normal priority.
 
 use std::time::Duration;
 
@@ -28,10 +35,16 @@ use astria_eyre::eyre::{
     Report,
     WrapErr as _,
 };
-use ethers::providers::{
-    Provider,
-    ProviderError,
-    Ws,
+use ethers::{
+    providers::{
+        Provider,
+        ProviderError,
+        Ws,
+    },
+    types::{
+        Transaction,
+        U256,
+    },
 };
 use metrics::Counter;
 use tokio::{
@@ -52,7 +65,10 @@ use tracing::{
 
 use crate::{
     collectors::EXECUTOR_SEND_TIMEOUT,
-    executor,
+    executor::{
+        self,
+        Priority,
+    },
     metrics::Metrics,
 };
 
@@ -60,6 +76,15 @@ type StdError = dyn std::error::Error;
 
 const WSS_UNSUBSCRIBE_TIMEOUT: Duration = Duration::from_secs(2);
 
+/// The interval at which the fee levels suggested by the rollup node are refreshed.
+const FEE_SIGNAL_REFRESH_INTERVAL: Duration = Duration::from_secs(2);
+
+/// The timeout for each request of a fee level suggested by the rollup node.
+const FEE_SIGNAL_REQUEST_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// The multiple of a suggested fee level a transaction must offer to have [`Priority::High`].
+const URGENT_FEE_MULTIPLIER: u64 = 2;
+
 /// `GethCollector` Collects transactions submitted to a Geth rollup node and passes
 /// them downstream for further processing.
 ///
@@ -218,6 +243,9 @@ impl Geth {
 
         status.send_modify(|status| status.is_connected = true);
 
+        let (fee_signal_tx, fee_signal) = watch::channel(FeeSignal::default());
+        let fee_signal_task = tokio::spawn(refresh_fee_signal(client.clone(), fee_signal_tx));
+
         let reason = loop {
             select! {
                 biased;
@@ -227,7 +255,12 @@ impl Geth {
                 tx_res = tx_stream.next() => {
                     if let Some(tx) = tx_res {
                         let tx_hash = tx.hash;
-                        debug!(transaction.hash = %tx_hash, "collected transaction from rollup");
+                        let priority = fee_signal.borrow().priority_of(&tx);
+                        debug!(
+                            transaction.hash = %tx_hash,
+                            ?priority,
+                            "collected transaction from rollup",
+                        );
                         let data = tx.rlp().to_vec();
                         let seq_action = SequenceAction {
                             rollup_id,
@@ -238,7 +271,11 @@ impl Geth {
                         txs_received_counter.increment(1);
 
                         match executor_handle
-                            .send_timeout(seq_action, EXECUTOR_SEND_TIMEOUT)
+                            .send_timeout_with_priority(
+                                seq_action,
+                                priority,
+                                EXECUTOR_SEND_TIMEOUT,
+                            )
                             .await
                         {
                             Ok(()) => {},
@@ -277,6 +314,7 @@ impl Geth {
         };
 
         status.send_modify(|status| status.is_connected = false);
+        fee_signal_task.abort();
 
         // if the loop exits with an error, we can still proceed with unsubscribing the WSS
         // stream as we could have exited due to an error in sending messages via the executor
@@ -298,3 +336,127 @@ impl Geth {
         reason.map(|_| ())
     }
 }
+
+/// The fee levels currently suggested by the rollup node, against which the fees offered by
+/// collected transactions are compared.
+#[derive(Clone, Copy, Debug, Default)]
+struct FeeSignal {
+    /// The suggested priority fee per gas, as returned by `eth_maxPriorityFeePerGas`.
+    max_priority_fee_per_gas: Option<U256>,
+    /// The current blob base fee, as returned by `eth_blobBaseFee`.
+    blob_base_fee: Option<U256>,
+}
+
+impl FeeSignal {
+    /// Returns [`Priority::High`] if `tx` offers at least [`URGENT_FEE_MULTIPLIER`] times the
+    /// suggested priority fee, or times the current blob base fee.
+    ///
+    /// Legacy transactions only have a gas price, which includes the base fee, so their priority
+    /// fee is unknown.
+    fn priority_of(&self, tx: &Transaction) -> Priority {
+        let max_fee_per_blob_gas = tx
+            .other
+            .get("maxFeePerBlobGas")
+            .and_then(|fee| serde_json::from_value(fee.clone()).ok());
+        if is_urgent(tx.max_priority_fee_per_gas, self.max_priority_fee_per_gas)
+            || is_urgent(max_fee_per_blob_gas, self.blob_base_fee)
+        {
+            Priority::High
+        } else {
+            Priority::Normal
+        }
+    }
+}
+
+fn is_urgent(offered: Option<U256>, suggested: Option<U256>) -> bool {
+    match (offered, suggested) {
+        (Some(offered), Some(suggested)) if !suggested.is_zero() => {
+            offered >= suggested.saturating_mul(U256::from(URGENT_FEE_MULTIPLIER))
+        }
+        _ => false,
+    }
+}
+
+/// Refreshes `fee_signal` from the rollup node every [`FEE_SIGNAL_REFRESH_INTERVAL`].
+async fn refresh_fee_signal(client: Provider<Ws>, fee_signal: watch::Sender<FeeSignal>) {
+    let mut interval = tokio::time::interval(FEE_SIGNAL_REFRESH_INTERVAL);
+    loop {
+        interval.tick().await;
+        let max_priority_fee_per_gas = request_fee(&client, "eth_maxPriorityFeePerGas").await;
+        let blob_base_fee = request_fee(&client, "eth_blobBaseFee").await;
+        fee_signal.send_replace(FeeSignal {
+            max_priority_fee_per_gas,
+            blob_base_fee,
+        });
+    }
+}
+
+/// Requests a suggested fee level from the rollup node, returning `None` if it is unavailable.
+async fn request_fee(client: &Provider<Ws>, method: &'static str) -> Option<U256> {
+    match tokio::time::timeout(
+        FEE_SIGNAL_REQUEST_TIMEOUT,
+        client.request::<_, U256>(method, ()),
+    )
+    .await
+    {
+        Ok(Ok(fee)) => Some(fee),
+        Ok(Err(error)) => {
+            debug!(
+                method,
+                error = &error as &StdError,
+                "failed to get suggested fee from geth; ignoring it",
+            );
+            None
+        }
+        Err(_) => {
+            debug!(
+                method,
+                "timed out getting suggested fee from geth; ignoring it"
+            );
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signal(max_priority_fee_per_gas: u64, blob_base_fee: u64) -> FeeSignal {
+        FeeSignal {
+            max_priority_fee_per_gas: Some(max_priority_fee_per_gas.into()),
+            blob_base_fee: Some(blob_base_fee.into()),
+        }
+    }
+
+    #[test]
+    fn transactions_without_fee_signal_have_normal_priority() {
+        let tx = Transaction {
+            max_priority_fee_per_gas: Some(1_000.into()),
+            ..Transaction::default()
+        };
+        assert_eq!(Priority::Normal, FeeSignal::default().priority_of(&tx));
+    }
+
+    #[test]
+    fn transactions_offering_multiple_of_suggested_tip_have_high_priority() {
+        let mut tx = Transaction {
+            max_priority_fee_per_gas: Some(19.into()),
+            ..Transaction::default()
+        };
+        assert_eq!(Priority::Normal, signal(10, 10).priority_of(&tx));
+        tx.max_priority_fee_per_gas = Some(20.into());
+        assert_eq!(Priority::High, signal(10, 10).priority_of(&tx));
+    }
+
+    #[test]
+    fn blob_transactions_offering_multiple_of_blob_base_fee_have_high_priority() {
+        let mut tx = Transaction::default();
+        tx.other =
+            serde_json::from_value(serde_json::json!({ "maxFeePerBlobGas": "0x13" })).unwrap();
+        assert_eq!(Priority::Normal, signal(10, 10).priority_of(&tx));
+        tx.other =
+            serde_json::from_value(serde_json::json!({ "maxFeePerBlobGas": "0x14" })).unwrap();
+        assert_eq!(Priority::High, signal(10, 10).priority_of(&tx));
+    }
+}
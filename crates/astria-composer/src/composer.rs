@@ -127,11 +127,14 @@ impl Composer {
         let (executor, executor_handle) = executor::Builder {
             sequencer_url: cfg.sequencer_url.clone(),
             sequencer_chain_id: cfg.sequencer_chain_id.clone(),
+            chain_id_check_interval_ms: cfg.chain_id_check_interval_ms,
             private_key_file: cfg.private_key_file.clone(),
             sequencer_address_prefix: cfg.sequencer_address_prefix.clone(),
             block_time_ms: cfg.block_time_ms,
             max_bytes_per_bundle: cfg.max_bytes_per_bundle,
             bundle_queue_capacity: cfg.bundle_queue_capacity,
+            chain_halt_timeout_ms: cfg.chain_halt_timeout_ms,
+            inclusion_check_blocks: cfg.inclusion_check_blocks,
             shutdown_token: shutdown_token.clone(),
             metrics,
         }
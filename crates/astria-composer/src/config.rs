@@ -28,6 +28,10 @@ pub struct Config {
     /// The chain ID of the sequencer chain
     pub sequencer_chain_id: String,
 
+    /// The interval in milliseconds at which the chain ID of the sequencer node is re-verified
+    /// after being confirmed on startup. 0 disables re-verification.
+    pub chain_id_check_interval_ms: u64,
+
     /// A list of `<rollup_name>::<url>` pairs
     pub rollups: String,
 
@@ -48,6 +52,15 @@ pub struct Config {
     /// Max amount of `SizedBundle`s to allow to accrue in the `BundleFactory`'s finished queue.
     pub bundle_queue_capacity: usize,
 
+    /// The duration in milliseconds without a new sequencer block after which the sequencer is
+    /// considered halted, and bundles are buffered instead of submitted until it produces blocks
+    /// again. 0 disables halt detection.
+    pub chain_halt_timeout_ms: u64,
+
+    /// The number of sequencer blocks after a submission within which its rollup data must be
+    /// included. Data that is not is resubmitted once. 0 disables inclusion verification.
+    pub inclusion_check_blocks: u64,
+
     /// Forces writing trace data to stdout no matter if connected to a tty or not.
     pub force_stdout: bool,
 
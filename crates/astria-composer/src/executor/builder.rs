@@ -19,18 +19,24 @@ use tokio_util::sync::CancellationToken;
 
 use crate::{
     executor,
-    executor::Status,
+    executor::{
+        Priority,
+        Status,
+    },
     metrics::Metrics,
 };
 
 pub(crate) struct Builder {
     pub(crate) sequencer_url: String,
     pub(crate) sequencer_chain_id: String,
+    pub(crate) chain_id_check_interval_ms: u64,
     pub(crate) private_key_file: String,
     pub(crate) sequencer_address_prefix: String,
     pub(crate) block_time_ms: u64,
     pub(crate) max_bytes_per_bundle: usize,
     pub(crate) bundle_queue_capacity: usize,
+    pub(crate) chain_halt_timeout_ms: u64,
+    pub(crate) inclusion_check_blocks: u64,
     pub(crate) shutdown_token: CancellationToken,
     pub(crate) metrics: &'static Metrics,
 }
@@ -40,11 +46,14 @@ impl Builder {
         let Self {
             sequencer_url,
             sequencer_chain_id,
+            chain_id_check_interval_ms,
             private_key_file,
             sequencer_address_prefix,
             block_time_ms,
             max_bytes_per_bundle,
             bundle_queue_capacity,
+            chain_halt_timeout_ms,
+            inclusion_check_blocks,
             shutdown_token,
             metrics,
         } = self;
@@ -63,7 +72,7 @@ impl Builder {
             .wrap_err("failed constructing a sequencer address from private key")?;
 
         let (serialized_rollup_transaction_tx, serialized_rollup_transaction_rx) =
-            tokio::sync::mpsc::channel::<SequenceAction>(256);
+            tokio::sync::mpsc::channel::<(SequenceAction, Priority)>(256);
 
         Ok((
             super::Executor {
@@ -71,11 +80,14 @@ impl Builder {
                 serialized_rollup_transactions: serialized_rollup_transaction_rx,
                 sequencer_client,
                 sequencer_chain_id,
+                chain_id_check_interval: Duration::from_millis(chain_id_check_interval_ms),
                 sequencer_key,
                 address: sequencer_address,
                 block_time: Duration::from_millis(block_time_ms),
                 max_bytes_per_bundle,
                 bundle_queue_capacity,
+                chain_halt_timeout: Duration::from_millis(chain_halt_timeout_ms),
+                inclusion_check_blocks,
                 shutdown_token,
                 metrics,
             },
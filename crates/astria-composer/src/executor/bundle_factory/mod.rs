@@ -9,11 +9,7 @@ use std::{
 };
 
 use astria_core::{
-    primitive::v1::{
-        RollupId,
-        FEE_ASSET_ID_LEN,
-        ROLLUP_ID_LEN,
-    },
+    primitive::v1::RollupId,
     protocol::transaction::v1alpha1::{
         action::SequenceAction,
         Action,
@@ -25,6 +21,8 @@ use serde::ser::{
 };
 use tracing::trace;
 
+use super::Priority;
+
 mod tests;
 
 #[derive(Debug, thiserror::Error)]
@@ -56,8 +54,8 @@ impl<'a> Serialize for SizedBundleReport<'a> {
 pub(super) struct SizedBundle {
     /// The buffer of actions
     buffer: Vec<Action>,
-    /// The current size of the bundle in bytes. This is equal to the sum of the size of the
-    /// `seq_action`s + `ROLLUP_ID_LEN` for each.
+    /// The current size of the bundle in bytes. This is equal to the sum of the payload sizes of
+    /// the `seq_action`s, see [`SequenceAction::payload_size`].
     curr_size: usize,
     /// The max bundle size in bytes to enforce.
     max_size: usize,
@@ -81,7 +79,7 @@ impl SizedBundle {
     /// - `seq_action` is beyond the max size allowed for the entire bundle
     /// - `seq_action` does not fit in the remaining space in the bundle
     fn try_push(&mut self, seq_action: SequenceAction) -> Result<(), SizedBundleError> {
-        let seq_action_size = estimate_size_of_sequence_action(&seq_action);
+        let seq_action_size = seq_action.payload_size();
 
         if seq_action_size > self.max_size {
             return Err(SizedBundleError::SequenceActionTooLarge(seq_action));
@@ -150,11 +148,19 @@ pub(super) enum BundleFactoryError {
 /// added to the `finished` queue when an incoming `SequenceAction` won't fit in the current bundle.
 /// The `finished` queue operates in FIFO order, where `Vec<Action>`s are added to the back and
 /// taken off from the front.
+///
+/// High priority sequence actions are bundled separately. Their bundles are added to the
+/// `finished` queue behind the other high priority bundles but ahead of all normal priority
+/// bundles, so they skip the queue when bundles are contended.
 pub(super) struct BundleFactory {
     /// The current bundle being built.
     curr_bundle: SizedBundle,
+    /// The current bundle of high priority sequence actions being built.
+    curr_priority_bundle: SizedBundle,
     /// The queue of bundles that have been built but not yet sent to the sequencer.
     finished: VecDeque<SizedBundle>,
+    /// The number of high priority bundles at the front of the `finished` queue.
+    finished_priority_count: usize,
     /// Max amount of `SizedBundle`s that can be in the `finished` queue.
     finished_queue_capacity: usize,
 }
@@ -163,7 +169,9 @@ impl BundleFactory {
     pub(super) fn new(max_bytes_per_bundle: usize, finished_queue_capacity: usize) -> Self {
         Self {
             curr_bundle: SizedBundle::new(max_bytes_per_bundle),
+            curr_priority_bundle: SizedBundle::new(max_bytes_per_bundle),
             finished: VecDeque::new(),
+            finished_priority_count: 0,
             finished_queue_capacity,
         }
     }
@@ -175,33 +183,56 @@ impl BundleFactory {
         &mut self,
         seq_action: SequenceAction,
     ) -> Result<(), BundleFactoryError> {
-        let seq_action_size = estimate_size_of_sequence_action(&seq_action);
+        self.try_push_with_priority(seq_action, Priority::Normal)
+    }
 
-        match self.curr_bundle.try_push(seq_action) {
+    /// Buffer `seq_action` into the current bundle of the given `priority`, flushing that bundle
+    /// into the `finished` queue as in [`BundleFactory::try_push`].
+    pub(super) fn try_push_with_priority(
+        &mut self,
+        seq_action: SequenceAction,
+        priority: Priority,
+    ) -> Result<(), BundleFactoryError> {
+        let seq_action_size = seq_action.payload_size();
+        let curr_bundle = match priority {
+            Priority::Normal => &mut self.curr_bundle,
+            Priority::High => &mut self.curr_priority_bundle,
+        };
+
+        match curr_bundle.try_push(seq_action) {
             Err(SizedBundleError::SequenceActionTooLarge(_seq_action)) => {
                 // reject the sequence action if it is larger than the max bundle size
                 Err(BundleFactoryError::SequenceActionTooLarge {
                     size: seq_action_size,
-                    max_size: self.curr_bundle.max_size,
+                    max_size: curr_bundle.max_size,
                 })
             }
             Err(SizedBundleError::NotEnoughSpace(seq_action)) => {
                 if self.finished.len() >= self.finished_queue_capacity {
                     Err(BundleFactoryError::FinishedQueueFull {
-                        curr_bundle_size: self.curr_bundle.curr_size,
+                        curr_bundle_size: curr_bundle.curr_size,
                         finished_queue_capacity: self.finished_queue_capacity,
                         sequence_action_size: seq_action_size,
                         seq_action,
                     })
                 } else {
                     // if the bundle is full, flush it and start a new one
-                    self.finished.push_back(self.curr_bundle.flush());
-                    self.curr_bundle.try_push(seq_action).expect(
+                    let bundle = curr_bundle.flush();
+                    curr_bundle.try_push(seq_action).expect(
                         "seq_action should not be larger than max bundle size, this is a bug",
                     );
+                    match priority {
+                        Priority::Normal => self.finished.push_back(bundle),
+                        Priority::High => {
+                            self.finished.insert(self.finished_priority_count, bundle);
+                            self.finished_priority_count =
+                                self.finished_priority_count.saturating_add(1);
+                        }
+                    }
                     trace!(
-                        new_bundle_size = self.curr_bundle.curr_size,
+                        new_bundle_size = curr_bundle.curr_size,
                         seq_action_size = seq_action_size,
+                        ?priority,
                         finished_queue.current_size = self.finished.len(),
                         finished_queue.capacity = self.finished_queue_capacity,
                         "created new bundle and bundled new sequence action"
@@ -211,8 +242,9 @@ impl BundleFactory {
             }
             Ok(()) => {
                 trace!(
-                    new_bundle_size = self.curr_bundle.curr_size,
+                    new_bundle_size = curr_bundle.curr_size,
                     seq_action_size = seq_action_size,
+                    ?priority,
                     "bundled new sequence action"
                 );
                 Ok(())
@@ -234,14 +266,36 @@ impl BundleFactory {
         }
     }
 
-    /// Immediately the currently aggregating bundle.
+    /// Immediately the currently aggregating bundle, preferring the high priority one.
     ///
     /// Returns an empty bundle if there are no bundled transactions.
     pub(super) fn pop_now(&mut self) -> SizedBundle {
-        self.finished
-            .pop_front()
-            .or_else(|| Some(self.curr_bundle.flush()))
-            .unwrap_or(SizedBundle::new(self.curr_bundle.max_size))
+        if let Some(bundle) = self.pop_finished() {
+            bundle
+        } else if self.curr_priority_bundle.is_empty() {
+            self.curr_bundle.flush()
+        } else {
+            self.curr_priority_bundle.flush()
+        }
+    }
+
+    /// Puts `bundle` back at the front of the `finished` queue so that it is submitted next, even
+    /// if this exceeds the queue's capacity.
+    pub(super) fn requeue(&mut self, bundle: SizedBundle) {
+        self.finished.push_front(bundle);
+        // counted as high priority so that high priority bundles are queued behind it
+        self.finished_priority_count = self.finished_priority_count.saturating_add(1);
+    }
+
+    /// Returns the number of bundles in the `finished` queue.
+    pub(super) fn finished_len(&self) -> usize {
+        self.finished.len()
+    }
+
+    fn pop_finished(&mut self) -> Option<SizedBundle> {
+        let bundle = self.finished.pop_front()?;
+        self.finished_priority_count = self.finished_priority_count.saturating_sub(1);
+        Some(bundle)
     }
 
     pub(super) fn is_full(&self) -> bool {
@@ -256,17 +310,7 @@ pub(super) struct NextFinishedBundle<'a> {
 impl<'a> NextFinishedBundle<'a> {
     pub(super) fn pop(self) -> SizedBundle {
         self.bundle_factory
-            .finished
-            .pop_front()
+            .pop_finished()
             .expect("next bundle exists. this is a bug.")
     }
 }
-
-/// The size of the `seq_action` in bytes, including the rollup id.
-fn estimate_size_of_sequence_action(seq_action: &SequenceAction) -> usize {
-    seq_action
-        .data
-        .len()
-        .saturating_add(ROLLUP_ID_LEN)
-        .saturating_add(FEE_ASSET_ID_LEN)
-}
@@ -15,7 +15,6 @@ mod sized_bundle_tests {
     };
 
     use crate::executor::bundle_factory::{
-        estimate_size_of_sequence_action,
         SizedBundle,
         SizedBundleError,
     };
@@ -31,7 +30,7 @@ mod sized_bundle_tests {
             data: vec![0; 100 - ROLLUP_ID_LEN - FEE_ASSET_ID_LEN],
             fee_asset_id: default_native_asset().id(),
         };
-        let seq_action_size = estimate_size_of_sequence_action(&seq_action);
+        let seq_action_size = seq_action.payload_size();
 
         assert_eq!(seq_action_size, 100);
         bundle.try_push(seq_action).unwrap();
@@ -49,7 +48,7 @@ mod sized_bundle_tests {
             fee_asset_id: default_native_asset().id(),
         };
 
-        assert!(estimate_size_of_sequence_action(&seq_action) > 100);
+        assert!(seq_action.payload_size() > 100);
         assert!(matches!(
             bundle.try_push(seq_action),
             Err(SizedBundleError::SequenceActionTooLarge(_))
@@ -77,7 +76,7 @@ mod sized_bundle_tests {
             fee_asset_id: default_native_asset().id(),
         };
 
-        assert!(estimate_size_of_sequence_action(&seq_action) < 100);
+        assert!(seq_action.payload_size() < 100);
         assert!(matches!(
             bundle.try_push(seq_action.clone()),
             Err(SizedBundleError::NotEnoughSpace(actual_seq_action))
@@ -160,10 +159,12 @@ mod bundle_factory_tests {
         protocol::transaction::v1alpha1::action::SequenceAction,
     };
 
-    use crate::executor::bundle_factory::{
-        estimate_size_of_sequence_action,
-        BundleFactory,
-        BundleFactoryError,
+    use crate::executor::{
+        bundle_factory::{
+            BundleFactory,
+            BundleFactoryError,
+        },
+        Priority,
     };
 
     #[test]
@@ -194,7 +195,7 @@ mod bundle_factory_tests {
             data: vec![0; 100 - ROLLUP_ID_LEN - FEE_ASSET_ID_LEN + 1],
             fee_asset_id: default_native_asset().id(),
         };
-        let actual_size = estimate_size_of_sequence_action(&seq_action);
+        let actual_size = seq_action.payload_size();
 
         assert!(matches!(
             bundle_factory.try_push(seq_action),
@@ -473,4 +474,81 @@ mod bundle_factory_tests {
         assert_eq!(bundle_factory.finished.len(), 0);
         assert!(!bundle_factory.is_full());
     }
+
+    #[test]
+    fn priority_bundles_skip_the_finished_queue() {
+        // create a bundle factory with max bundle size as 100 bytes
+        let mut bundle_factory = BundleFactory::new(100, 10);
+
+        // each sequence action is 100 bytes total, so each fills a bundle
+        let seq_action = |rollup_byte: u8| SequenceAction {
+            rollup_id: RollupId::new([rollup_byte; ROLLUP_ID_LEN]),
+            data: vec![0; 100 - ROLLUP_ID_LEN - FEE_ASSET_ID_LEN],
+            fee_asset_id: default_native_asset().id(),
+        };
+        for rollup_byte in 0..3 {
+            bundle_factory.try_push(seq_action(rollup_byte)).unwrap();
+        }
+        for rollup_byte in 10..13 {
+            bundle_factory
+                .try_push_with_priority(seq_action(rollup_byte), Priority::High)
+                .unwrap();
+        }
+
+        // the finished high priority bundles are ahead of the finished normal priority ones, and
+        // the current high priority bundle is popped before the current normal priority one
+        let popped_rollup_bytes: Vec<_> = std::iter::from_fn(|| {
+            let bundle = bundle_factory.pop_now();
+            (!bundle.is_empty()).then(|| {
+                bundle.into_actions()[0]
+                    .as_sequence()
+                    .unwrap()
+                    .rollup_id
+                    .get()[0]
+            })
+        })
+        .collect();
+        assert_eq!(popped_rollup_bytes, vec![10, 11, 0, 1, 12, 2]);
+        assert_eq!(bundle_factory.finished_priority_count, 0);
+    }
+
+    #[test]
+    fn requeued_bundle_is_popped_first() {
+        // create a bundle factory with max bundle size as 100 bytes
+        let mut bundle_factory = BundleFactory::new(100, 10);
+
+        // each sequence action is 100 bytes total, so each fills a bundle
+        let seq_action = |rollup_byte: u8| SequenceAction {
+            rollup_id: RollupId::new([rollup_byte; ROLLUP_ID_LEN]),
+            data: vec![0; 100 - ROLLUP_ID_LEN - FEE_ASSET_ID_LEN],
+            fee_asset_id: default_native_asset().id(),
+        };
+        for rollup_byte in 0..3 {
+            bundle_factory.try_push(seq_action(rollup_byte)).unwrap();
+        }
+
+        // requeue the bundle as if its submission was cancelled, then add high priority bundles
+        let bundle = bundle_factory.pop_now();
+        bundle_factory.requeue(bundle);
+        for rollup_byte in 10..12 {
+            bundle_factory
+                .try_push_with_priority(seq_action(rollup_byte), Priority::High)
+                .unwrap();
+        }
+
+        // the requeued bundle is still ahead of the high priority bundles
+        let popped_rollup_bytes: Vec<_> = std::iter::from_fn(|| {
+            let bundle = bundle_factory.pop_now();
+            (!bundle.is_empty()).then(|| {
+                bundle.into_actions()[0]
+                    .as_sequence()
+                    .unwrap()
+                    .rollup_id
+                    .get()[0]
+            })
+        })
+        .collect();
+        assert_eq!(popped_rollup_bytes, vec![0, 10, 1, 11, 2]);
+        assert_eq!(bundle_factory.finished_priority_count, 0);
+    }
 }
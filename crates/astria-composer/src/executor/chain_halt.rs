@@ -0,0 +1,116 @@
+//! Detection of sequencer chain halts.
+//!
+//! The sequencer is considered halted once its latest block height has not advanced for the
+//! configured timeout. This includes the sequencer being unreachable, as its nodes are typically
+//! stopped during an upgrade halt.
+
+use std::time::Duration;
+
+use sequencer_client::tendermint_rpc::Client as _;
+use tokio::{
+    sync::watch,
+    time::{
+        self,
+        Instant,
+    },
+};
+use tracing::{
+    debug,
+    instrument,
+};
+
+/// Polls the sequencer's latest block height every `poll_interval`, and sets `halted` to whether
+/// the height has not advanced for `halt_timeout`.
+#[instrument(skip_all)]
+pub(super) async fn detect(
+    client: sequencer_client::HttpClient,
+    poll_interval: Duration,
+    halt_timeout: Duration,
+    halted: watch::Sender<bool>,
+) {
+    let mut detector = HaltDetector::new(halt_timeout, Instant::now());
+    let mut interval = time::interval(poll_interval);
+    loop {
+        interval.tick().await;
+        let height = match time::timeout(poll_interval, client.abci_info()).await {
+            Ok(Ok(info)) => Some(info.last_block_height.value()),
+            Ok(Err(error)) => {
+                debug!(
+                    error = &error as &dyn std::error::Error,
+                    "failed getting latest block height from sequencer",
+                );
+                None
+            }
+            Err(_) => {
+                debug!("timed out getting latest block height from sequencer");
+                None
+            }
+        };
+        let is_halted = detector.observe(height, Instant::now());
+        halted.send_if_modified(|halted| {
+            let changed = *halted != is_halted;
+            *halted = is_halted;
+            changed
+        });
+    }
+}
+
+/// Tracks the progress of the sequencer's block height.
+struct HaltDetector {
+    halt_timeout: Duration,
+    latest_height: Option<u64>,
+    last_progress_at: Instant,
+}
+
+impl HaltDetector {
+    fn new(halt_timeout: Duration, now: Instant) -> Self {
+        Self {
+            halt_timeout,
+            latest_height: None,
+            last_progress_at: now,
+        }
+    }
+
+    /// Records the sequencer's latest block `height`, or `None` if it could not be fetched, and
+    /// returns whether the sequencer is considered halted.
+    fn observe(&mut self, height: Option<u64>, now: Instant) -> bool {
+        if let Some(height) = height {
+            if self.latest_height.map_or(true, |latest| height > latest) {
+                self.latest_height = Some(height);
+                self.last_progress_at = now;
+            }
+        }
+        now.saturating_duration_since(self.last_progress_at) >= self.halt_timeout
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn halt_is_detected_after_timeout_without_progress() {
+        let timeout = Duration::from_secs(10);
+        let start = Instant::now();
+        let mut detector = HaltDetector::new(timeout, start);
+
+        assert!(!detector.observe(Some(1), start));
+        assert!(!detector.observe(Some(1), start + Duration::from_secs(9)));
+        assert!(detector.observe(Some(1), start + timeout));
+        // failing to reach the sequencer is no progress
+        assert!(detector.observe(None, start + Duration::from_secs(11)));
+    }
+
+    #[test]
+    fn halt_ends_once_height_advances() {
+        let timeout = Duration::from_secs(10);
+        let start = Instant::now();
+        let mut detector = HaltDetector::new(timeout, start);
+
+        assert!(!detector.observe(Some(1), start));
+        assert!(detector.observe(None, start + Duration::from_secs(20)));
+        assert!(!detector.observe(Some(2), start + Duration::from_secs(21)));
+        assert!(!detector.observe(Some(2), start + Duration::from_secs(30)));
+        assert!(detector.observe(Some(2), start + Duration::from_secs(31)));
+    }
+}
@@ -0,0 +1,117 @@
+//! Verification of the sequencer's chain ID.
+//!
+//! Transactions are signed for the configured sequencer chain ID. If the composer is pointed at a
+//! node of a different network, every submission would be rejected and its rollup data lost. The
+//! node's chain ID is therefore confirmed once on startup, and optionally re-verified
+//! periodically while running, as the node behind the configured URL can change (e.g. through a
+//! DNS change or a load balancer).
+
+use std::time::Duration;
+
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    WrapErr as _,
+};
+use sequencer_client::tendermint_rpc::Client as _;
+use tokio::{
+    sync::watch,
+    time,
+};
+use tracing::{
+    debug,
+    info,
+    instrument,
+    warn,
+    Instrument as _,
+    Span,
+};
+
+/// Fetches the chain ID of the sequencer node with an exponential backoff, and returns an error
+/// if it differs from `expected`.
+#[instrument(skip_all, fields(%expected))]
+pub(super) async fn confirm(
+    client: sequencer_client::HttpClient,
+    expected: &str,
+) -> eyre::Result<()> {
+    let span = Span::current();
+    let retry_config = tryhard::RetryFutureConfig::new(1024)
+        .exponential_backoff(Duration::from_millis(200))
+        .max_delay(Duration::from_secs(60))
+        .on_retry(
+            |attempt,
+             next_delay: Option<Duration>,
+             err: &sequencer_client::tendermint_rpc::Error| {
+                let wait_duration = next_delay
+                    .map(humantime::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    parent: span.clone(),
+                    error = err as &dyn std::error::Error,
+                    attempt,
+                    wait_duration,
+                    "failed getting chain ID from sequencer; retrying after backoff",
+                );
+                async move {}
+            },
+        );
+    let received = tryhard::retry_fn(|| fetch(client.clone()))
+        .with_config(retry_config)
+        .in_current_span()
+        .await
+        .wrap_err("failed getting chain ID from sequencer after 1024 attempts")?;
+
+    ensure!(
+        received == expected,
+        "configured sequencer chain ID does not match the chain ID of the sequencer node; \
+         configured: `{expected}`, received: `{received}`"
+    );
+    info!(sequencer_chain_id = %expected, "confirmed sequencer chain ID");
+    Ok(())
+}
+
+/// Fetches the chain ID of the sequencer node every `interval`, and sends it on `mismatch` once it
+/// differs from `expected`.
+///
+/// Failures to reach the sequencer are ignored, as they are covered by the submission logic.
+#[instrument(skip_all, fields(%expected))]
+pub(super) async fn verify_periodically(
+    client: sequencer_client::HttpClient,
+    expected: String,
+    interval: Duration,
+    mismatch: watch::Sender<Option<String>>,
+) {
+    let mut interval = time::interval(interval);
+    // the chain ID was confirmed on startup, so the immediate first tick is skipped
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let received = match time::timeout(interval.period(), fetch(client.clone())).await {
+            Ok(Ok(received)) => received,
+            Ok(Err(error)) => {
+                debug!(
+                    error = &error as &dyn std::error::Error,
+                    "failed getting chain ID from sequencer",
+                );
+                continue;
+            }
+            Err(_) => {
+                debug!("timed out getting chain ID from sequencer");
+                continue;
+            }
+        };
+        if received != expected {
+            let _ = mismatch.send(Some(received));
+            return;
+        }
+    }
+}
+
+async fn fetch(
+    client: sequencer_client::HttpClient,
+) -> Result<String, sequencer_client::tendermint_rpc::Error> {
+    client
+        .status()
+        .await
+        .map(|status| status.node_info.network.to_string())
+}
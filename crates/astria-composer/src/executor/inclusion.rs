@@ -0,0 +1,372 @@
+//! Verification that submitted rollup data is included in sequencer blocks.
+//!
+//! A transaction accepted by the sequencer's mempool can still fail to make it into a block, for
+//! example if it is evicted from the mempool. After every submission the executor hands the
+//! submitted bundle to [`verify`], which scans the following sequencer blocks for the hashes of
+//! the bundle's rollup data. Data that does not appear within the configured number of blocks is
+//! sent back to the executor to be resubmitted once. If it is still missing after its
+//! resubmission, it is given up on.
+
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    time::Duration,
+};
+
+use astria_core::{
+    generated::protocol::transaction::v1alpha1 as raw,
+    protocol::transaction::v1alpha1::{
+        action::SequenceAction,
+        Action,
+    },
+    sequencerblock::v1alpha1::block_commitment_count_of_data,
+};
+use prost::Message as _;
+use sequencer_client::{
+    tendermint::block::Height,
+    tendermint_rpc::Client as _,
+    SignedTransaction,
+};
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tokio::{
+    sync::mpsc,
+    time::{
+        self,
+        Instant,
+    },
+};
+use tracing::{
+    debug,
+    error,
+    instrument,
+    warn,
+};
+
+use super::bundle_factory::SizedBundle;
+use crate::metrics::Metrics;
+
+/// Polls the sequencer for new blocks every `poll_interval` and checks them for the rollup data
+/// of the bundles received on `submitted`.
+///
+/// Rollup data that is not included within `inclusion_blocks` blocks of its submission is sent to
+/// `resubmit`.
+#[instrument(skip_all)]
+pub(super) async fn verify(
+    client: sequencer_client::HttpClient,
+    poll_interval: Duration,
+    inclusion_blocks: u64,
+    mut submitted: mpsc::UnboundedReceiver<SizedBundle>,
+    resubmit: mpsc::Sender<SequenceAction>,
+    metrics: &'static Metrics,
+) {
+    let mut tracker = InclusionTracker::new(inclusion_blocks);
+    let mut next_height: Option<u64> = None;
+    let mut interval = time::interval(poll_interval);
+    loop {
+        tokio::select! {
+            biased;
+
+            Some(bundle) = submitted.recv() => {
+                tracker.track(sequence_actions(bundle), Instant::now());
+                continue;
+            }
+            _ = interval.tick() => {}
+        }
+        // track all submissions before scanning blocks, as their data might be in the next one
+        while let Ok(bundle) = submitted.try_recv() {
+            tracker.track(sequence_actions(bundle), Instant::now());
+        }
+
+        let latest_height = match time::timeout(poll_interval, client.abci_info()).await {
+            Ok(Ok(info)) => info.last_block_height.value(),
+            Ok(Err(error)) => {
+                debug!(
+                    error = &error as &dyn std::error::Error,
+                    "failed getting latest block height from sequencer",
+                );
+                continue;
+            }
+            Err(_) => {
+                debug!("timed out getting latest block height from sequencer");
+                continue;
+            }
+        };
+        // blocks before the first poll are not scanned
+        let from_height = *next_height.get_or_insert(latest_height);
+
+        for height in from_height..=latest_height {
+            let data_hashes = match fetch_rollup_data_hashes(&client, height).await {
+                Ok(data_hashes) => data_hashes,
+                Err(error) => {
+                    debug!(
+                        height,
+                        error = &error as &dyn std::error::Error,
+                        "failed getting block from sequencer; retrying on next poll",
+                    );
+                    break;
+                }
+            };
+            let now = Instant::now();
+            for latency in tracker.observe_block(height, &data_hashes, now) {
+                metrics.record_inclusion_latency(latency);
+            }
+            for missing in tracker.expire(height) {
+                metrics.increment_sequence_actions_not_included(&missing.action.rollup_id);
+                if missing.was_resubmitted {
+                    error!(
+                        rollup_id = %missing.action.rollup_id,
+                        data.hash = %telemetry::display::hex(&missing.data_hash),
+                        inclusion_blocks,
+                        "resubmitted rollup data was not included in a sequencer block; giving up",
+                    );
+                } else {
+                    warn!(
+                        rollup_id = %missing.action.rollup_id,
+                        data.hash = %telemetry::display::hex(&missing.data_hash),
+                        inclusion_blocks,
+                        "rollup data was not included in a sequencer block; resubmitting it",
+                    );
+                    if resubmit.send(missing.action).await.is_err() {
+                        debug!("executor stopped receiving resubmissions; stopping verification");
+                        return;
+                    }
+                }
+            }
+            next_height = Some(height.saturating_add(1));
+        }
+    }
+}
+
+/// Returns the hashes of the rollup data in the sequencer block at `height`.
+async fn fetch_rollup_data_hashes(
+    client: &sequencer_client::HttpClient,
+    height: u64,
+) -> Result<HashSet<[u8; 32]>, sequencer_client::tendermint_rpc::Error> {
+    let height = Height::try_from(height).expect("sequencer block heights fit into an i64");
+    let block = client.block(height).await?.block;
+    let mut data_hashes = HashSet::new();
+    // the block data starts with the block commitments, which are not transactions
+    let commitment_count = block_commitment_count_of_data(&block.data);
+    for tx_bytes in block.data.iter().skip(commitment_count) {
+        let Some(tx) = raw::SignedTransaction::decode(&**tx_bytes)
+            .ok()
+            .and_then(|raw_tx| SignedTransaction::try_from_raw(raw_tx).ok())
+        else {
+            continue;
+        };
+        for action in tx.actions() {
+            if let Action::Sequence(action) = action {
+                data_hashes.insert(data_hash(action));
+            }
+        }
+    }
+    Ok(data_hashes)
+}
+
+fn sequence_actions(bundle: SizedBundle) -> impl Iterator<Item = SequenceAction> {
+    bundle
+        .into_actions()
+        .into_iter()
+        .filter_map(|action| match action {
+            Action::Sequence(action) => Some(action),
+            _ => None,
+        })
+}
+
+fn data_hash(action: &SequenceAction) -> [u8; 32] {
+    Sha256::digest(&action.data).into()
+}
+
+/// Rollup data that was not included within the configured number of blocks.
+struct Missing {
+    action: SequenceAction,
+    data_hash: [u8; 32],
+    was_resubmitted: bool,
+}
+
+struct Pending {
+    action: SequenceAction,
+    submitted_at: Instant,
+    /// The height of the last block the data may be included in, or `None` if no block height
+    /// was observed yet when the data was submitted.
+    deadline_height: Option<u64>,
+}
+
+/// Tracks submitted rollup data until it is included in a block or its deadline passes.
+struct InclusionTracker {
+    inclusion_blocks: u64,
+    latest_height: Option<u64>,
+    pending: HashMap<[u8; 32], Pending>,
+    /// The hashes of the pending data that is tracked for the second time.
+    resubmitted: HashSet<[u8; 32]>,
+}
+
+impl InclusionTracker {
+    fn new(inclusion_blocks: u64) -> Self {
+        Self {
+            inclusion_blocks,
+            latest_height: None,
+            pending: HashMap::new(),
+            resubmitted: HashSet::new(),
+        }
+    }
+
+    fn track(&mut self, actions: impl IntoIterator<Item = SequenceAction>, now: Instant) {
+        let deadline_height = self.deadline_height();
+        for action in actions {
+            self.pending.entry(data_hash(&action)).or_insert(Pending {
+                action,
+                submitted_at: now,
+                deadline_height,
+            });
+        }
+    }
+
+    fn deadline_height(&self) -> Option<u64> {
+        self.latest_height
+            .map(|height| height.saturating_add(self.inclusion_blocks))
+    }
+
+    /// Records that `height` is the latest block height, setting the deadline of data that was
+    /// submitted before any height was known.
+    fn observe_height(&mut self, height: u64) {
+        if self.latest_height.is_some_and(|latest| latest >= height) {
+            return;
+        }
+        self.latest_height = Some(height);
+        let deadline_height = self.deadline_height();
+        for pending in self.pending.values_mut() {
+            pending.deadline_height = pending.deadline_height.or(deadline_height);
+        }
+    }
+
+    /// Stops tracking the data included in the block at `height`, returning the time it took to
+    /// be included since its submission.
+    fn observe_block(
+        &mut self,
+        height: u64,
+        data_hashes: &HashSet<[u8; 32]>,
+        now: Instant,
+    ) -> Vec<Duration> {
+        let mut latencies = vec![];
+        for data_hash in data_hashes {
+            if let Some(pending) = self.pending.remove(data_hash) {
+                self.resubmitted.remove(data_hash);
+                latencies.push(now.saturating_duration_since(pending.submitted_at));
+            }
+        }
+        self.observe_height(height);
+        latencies
+    }
+
+    /// Stops tracking the data whose deadline is at or before `height`, returning it.
+    ///
+    /// Data returned with `was_resubmitted` unset is expected to be resubmitted and tracked
+    /// again.
+    fn expire(&mut self, height: u64) -> Vec<Missing> {
+        let expired: Vec<_> = self
+            .pending
+            .iter()
+            .filter(|(_, pending)| {
+                pending
+                    .deadline_height
+                    .is_some_and(|deadline| deadline <= height)
+            })
+            .map(|(data_hash, _)| *data_hash)
+            .collect();
+        expired
+            .into_iter()
+            .filter_map(|data_hash| {
+                let pending = self.pending.remove(&data_hash)?;
+                let was_resubmitted = !self.resubmitted.insert(data_hash);
+                if was_resubmitted {
+                    self.resubmitted.remove(&data_hash);
+                }
+                Some(Missing {
+                    action: pending.action,
+                    data_hash,
+                    was_resubmitted,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::{
+        asset::default_native_asset,
+        RollupId,
+    };
+
+    use super::*;
+
+    fn action(data: &[u8]) -> SequenceAction {
+        SequenceAction {
+            rollup_id: RollupId::new([0; 32]),
+            data: data.to_vec(),
+            fee_asset_id: default_native_asset().id(),
+        }
+    }
+
+    fn hashes(actions: &[&SequenceAction]) -> HashSet<[u8; 32]> {
+        actions.iter().map(|action| data_hash(action)).collect()
+    }
+
+    #[test]
+    fn included_data_is_no_longer_tracked() {
+        let start = Instant::now();
+        let mut tracker = InclusionTracker::new(2);
+        tracker.observe_height(10);
+        let (a, b) = (action(b"a"), action(b"b"));
+        tracker.track([a.clone(), b.clone()], start);
+
+        let latencies = tracker.observe_block(11, &hashes(&[&a]), start + Duration::from_secs(1));
+        assert_eq!(latencies, vec![Duration::from_secs(1)]);
+        assert!(tracker.expire(11).is_empty());
+
+        let latencies = tracker.observe_block(12, &hashes(&[&b]), start + Duration::from_secs(2));
+        assert_eq!(latencies, vec![Duration::from_secs(2)]);
+        assert!(tracker.expire(12).is_empty());
+        assert!(tracker.pending.is_empty());
+    }
+
+    #[test]
+    fn missing_data_is_resubmitted_once() {
+        let start = Instant::now();
+        let mut tracker = InclusionTracker::new(2);
+        tracker.observe_height(10);
+        tracker.track([action(b"a")], start);
+
+        tracker.observe_block(11, &HashSet::new(), start);
+        assert!(tracker.expire(11).is_empty());
+        tracker.observe_block(12, &HashSet::new(), start);
+        let missing = tracker.expire(12);
+        assert_eq!(missing.len(), 1);
+        assert!(!missing[0].was_resubmitted);
+
+        tracker.track([missing[0].action.clone()], start);
+        tracker.observe_block(14, &HashSet::new(), start);
+        let missing = tracker.expire(14);
+        assert_eq!(missing.len(), 1);
+        assert!(missing[0].was_resubmitted);
+        assert!(tracker.resubmitted.is_empty());
+    }
+
+    #[test]
+    fn deadline_starts_at_first_observed_height() {
+        let start = Instant::now();
+        let mut tracker = InclusionTracker::new(1);
+        tracker.track([action(b"a")], start);
+        assert!(tracker.expire(100).is_empty());
+
+        tracker.observe_height(100);
+        assert!(tracker.expire(100).is_empty());
+        assert_eq!(tracker.expire(101).len(), 1);
+    }
+}
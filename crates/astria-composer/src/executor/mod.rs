@@ -80,6 +80,9 @@ use crate::{
 };
 
 mod bundle_factory;
+mod chain_halt;
+mod chain_id;
+mod inclusion;
 
 pub(crate) mod builder;
 #[cfg(test)]
@@ -103,13 +106,16 @@ type StdError = dyn std::error::Error;
 pub(super) struct Executor {
     // The status of this executor
     status: watch::Sender<Status>,
-    // Channel for receiving `SequenceAction`s to be bundled.
-    serialized_rollup_transactions: mpsc::Receiver<SequenceAction>,
+    // Channel for receiving `SequenceAction`s to be bundled, and their priorities.
+    serialized_rollup_transactions: mpsc::Receiver<(SequenceAction, Priority)>,
     // The client for submitting wrapped and signed pending eth transactions to the astria
     // sequencer.
     sequencer_client: sequencer_client::HttpClient,
     // The chain id used for submission of transactions to the sequencer.
     sequencer_chain_id: String,
+    // Interval at which the chain id of the sequencer node is re-verified. Zero disables
+    // re-verification after startup.
+    chain_id_check_interval: Duration,
     // Private key used to sign sequencer transactions
     sequencer_key: SigningKey,
     // The sequencer address associated with the private key
@@ -120,18 +126,34 @@ pub(super) struct Executor {
     max_bytes_per_bundle: usize,
     // Max amount of `SizedBundle`s that can be in the `BundleFactory`'s `finished` queue.
     bundle_queue_capacity: usize,
+    // Duration without a new sequencer block after which bundles are buffered instead of
+    // submitted. Zero disables halt detection.
+    chain_halt_timeout: Duration,
+    // Number of sequencer blocks after a submission within which its rollup data must be
+    // included, or it is resubmitted. Zero disables inclusion verification.
+    inclusion_check_blocks: u64,
     // Token to signal the executor to stop upon shutdown.
     shutdown_token: CancellationToken,
     metrics: &'static Metrics,
 }
 
+/// The urgency a rollup expressed for a sequence action.
+///
+/// When bundles are contended, bundles of high priority sequence actions are submitted ahead of
+/// the bundles of normal priority actions queued before them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(super) enum Priority {
+    Normal,
+    High,
+}
+
 #[derive(Clone)]
 pub(super) struct Handle {
-    serialized_rollup_transactions_tx: mpsc::Sender<SequenceAction>,
+    serialized_rollup_transactions_tx: mpsc::Sender<(SequenceAction, Priority)>,
 }
 
 impl Handle {
-    fn new(serialized_rollup_transactions_tx: mpsc::Sender<SequenceAction>) -> Self {
+    fn new(serialized_rollup_transactions_tx: mpsc::Sender<(SequenceAction, Priority)>) -> Self {
         Self {
             serialized_rollup_transactions_tx,
         }
@@ -141,10 +163,28 @@ impl Handle {
         &self,
         sequence_action: SequenceAction,
         timeout: Duration,
+    ) -> Result<(), SendTimeoutError<SequenceAction>> {
+        self.send_timeout_with_priority(sequence_action, Priority::Normal, timeout)
+            .await
+    }
+
+    pub(super) async fn send_timeout_with_priority(
+        &self,
+        sequence_action: SequenceAction,
+        priority: Priority,
+        timeout: Duration,
     ) -> Result<(), SendTimeoutError<SequenceAction>> {
         self.serialized_rollup_transactions_tx
-            .send_timeout(sequence_action, timeout)
+            .send_timeout((sequence_action, priority), timeout)
             .await
+            .map_err(|error| match error {
+                SendTimeoutError::Timeout((sequence_action, _)) => {
+                    SendTimeoutError::Timeout(sequence_action)
+                }
+                SendTimeoutError::Closed((sequence_action, _)) => {
+                    SendTimeoutError::Closed(sequence_action)
+                }
+            })
     }
 }
 
@@ -196,10 +236,14 @@ impl Executor {
     /// Run the Executor loop, calling `process_bundle` on each bundle received from the channel.
     ///
     /// # Errors
-    /// An error is returned if connecting to the sequencer fails.
+    /// An error is returned if connecting to the sequencer fails, or if the chain id of the
+    /// sequencer node does not match the configured one.
     #[instrument(skip_all, fields(address = %self.address))]
     pub(super) async fn run_until_stopped(mut self) -> eyre::Result<()> {
         let mut submission_fut: Fuse<Instrumented<SubmitFut>> = Fuse::terminated();
+        chain_id::confirm(self.sequencer_client.clone(), &self.sequencer_chain_id)
+            .await
+            .wrap_err("failed confirming the chain id of the sequencer")?;
         let mut nonce = get_latest_nonce(self.sequencer_client.clone(), self.address, self.metrics)
             .await
             .wrap_err("failed getting initial nonce from sequencer")?;
@@ -212,6 +256,42 @@ impl Executor {
         tokio::pin!(block_timer);
         let mut bundle_factory =
             BundleFactory::new(self.max_bytes_per_bundle, self.bundle_queue_capacity);
+        // the bundle being submitted, kept so it can be requeued if the sequencer halts
+        let mut in_flight_bundle: Option<SizedBundle> = None;
+
+        let (chain_halted_tx, mut chain_halted) = watch::channel(false);
+        let chain_halt_task = (!self.chain_halt_timeout.is_zero()).then(|| {
+            tokio::spawn(chain_halt::detect(
+                self.sequencer_client.clone(),
+                self.block_time,
+                self.chain_halt_timeout,
+                chain_halted_tx,
+            ))
+        });
+        let mut is_halted = false;
+
+        let (chain_id_mismatch_tx, mut chain_id_mismatch) = watch::channel(None);
+        let chain_id_task = (!self.chain_id_check_interval.is_zero()).then(|| {
+            tokio::spawn(chain_id::verify_periodically(
+                self.sequencer_client.clone(),
+                self.sequencer_chain_id.clone(),
+                self.chain_id_check_interval,
+                chain_id_mismatch_tx,
+            ))
+        });
+
+        let (submitted_bundles, submitted_bundles_rx) = mpsc::unbounded_channel();
+        let (resubmissions_tx, mut resubmissions) = mpsc::channel(256);
+        let inclusion_task = (self.inclusion_check_blocks > 0).then(|| {
+            tokio::spawn(inclusion::verify(
+                self.sequencer_client.clone(),
+                self.block_time,
+                self.inclusion_check_blocks,
+                submitted_bundles_rx,
+                resubmissions_tx,
+                self.metrics,
+            ))
+        });
 
         let reset_time = || {
             Instant::now()
@@ -228,8 +308,15 @@ impl Executor {
                 }
                 // process submission result and update nonce
                 rsp = &mut submission_fut, if !submission_fut.is_terminated() => {
+                    let submitted_bundle = in_flight_bundle.take();
                     match rsp {
-                        Ok(new_nonce) => nonce = new_nonce,
+                        Ok(new_nonce) => {
+                            nonce = new_nonce;
+                            if let (Some(bundle), Some(_)) = (submitted_bundle, &inclusion_task) {
+                                // the verification task only stops after the executor does
+                                let _ = submitted_bundles.send(bundle);
+                            }
+                        }
                         Err(error) => {
                             error!(%error, "failed submitting bundle to sequencer; aborting executor");
                             break Err(error).wrap_err("failed submitting bundle to sequencer");
@@ -238,18 +325,79 @@ impl Executor {
                     block_timer.as_mut().reset(reset_time());
                 }
 
-                Some(next_bundle) = future::ready(bundle_factory.next_finished()), if submission_fut.is_terminated() => {
+                // the sequencer node is on a different network and would reject all submissions
+                Ok(()) = chain_id_mismatch.changed(), if chain_id_task.is_some() => {
+                    let received = chain_id_mismatch
+                        .borrow_and_update()
+                        .clone()
+                        .unwrap_or_default();
+                    error!(
+                        configured = %self.sequencer_chain_id,
+                        %received,
+                        "chain id of the sequencer node no longer matches the configured one; \
+                         aborting executor"
+                    );
+                    break Err(eyre!(
+                        "configured sequencer chain id `{}` does not match the chain id of the \
+                         sequencer node `{received}`",
+                        self.sequencer_chain_id,
+                    ));
+                }
+
+                // stop submitting while the sequencer is halted, and resume once it produces blocks
+                Ok(()) = chain_halted.changed(), if chain_halt_task.is_some() => {
+                    is_halted = *chain_halted.borrow_and_update();
+                    self.metrics.set_chain_halted(is_halted);
+                    if is_halted {
+                        warn!(
+                            timeout_ms = self.chain_halt_timeout.as_millis(),
+                            "sequencer has not produced a block within the chain halt timeout; \
+                             buffering bundles until it resumes"
+                        );
+                        // the submission would only keep failing until the sequencer resumes, so
+                        // it is cancelled and its bundle is submitted first once it does
+                        if let Some(bundle) = in_flight_bundle.take() {
+                            submission_fut = Fuse::terminated();
+                            bundle_factory.requeue(bundle);
+                        }
+                    } else {
+                        info!(
+                            buffered_bundles = bundle_factory.finished_len(),
+                            "sequencer resumed producing blocks; submitting buffered bundles"
+                        );
+                        block_timer.as_mut().reset(reset_time());
+                    }
+                }
+
+                Some(next_bundle) = future::ready(bundle_factory.next_finished()), if submission_fut.is_terminated() && !is_halted => {
                     let bundle = next_bundle.pop();
                     if !bundle.is_empty() {
+                        in_flight_bundle = Some(bundle.clone());
                         submission_fut = self.submit_bundle(nonce, bundle, self.metrics);
                     }
                 }
 
+                // bundle rollup data that was not included in a sequencer block in time again
+                Some(seq_action) = resubmissions.recv(), if inclusion_task.is_some() && !bundle_factory.is_full() => {
+                    let rollup_id = seq_action.rollup_id;
+
+                    if let Err(e) =
+                        bundle_factory.try_push_with_priority(seq_action, Priority::High)
+                    {
+                        self.metrics.increment_txs_dropped_too_large(&rollup_id);
+                        warn!(
+                            rollup_id = %rollup_id,
+                            error = &e as &StdError,
+                            "failed to bundle resubmitted transaction, dropping it."
+                        );
+                    }
+                }
+
                 // receive new seq_action and bundle it. will not pull from the channel if `bundle_factory` is full
-                Some(seq_action) = self.serialized_rollup_transactions.recv(), if !bundle_factory.is_full() => {
+                Some((seq_action, priority)) = self.serialized_rollup_transactions.recv(), if !bundle_factory.is_full() => {
                     let rollup_id = seq_action.rollup_id;
 
-                    if let Err(e) = bundle_factory.try_push(seq_action) {
+                    if let Err(e) = bundle_factory.try_push_with_priority(seq_action, priority) {
                         self.metrics.increment_txs_dropped_too_large(&rollup_id);
                         warn!(
                             rollup_id = %rollup_id,
@@ -260,7 +408,7 @@ impl Executor {
                 }
 
                 // try to preempt current bundle if the timer has ticked without submitting the next bundle
-                () = &mut block_timer, if submission_fut.is_terminated() => {
+                () = &mut block_timer, if submission_fut.is_terminated() && !is_halted => {
                     let bundle = bundle_factory.pop_now();
                     if bundle.is_empty() {
                         debug!("block timer ticked, but no bundle to submit to sequencer");
@@ -269,12 +417,23 @@ impl Executor {
                         debug!(
                             "forcing bundle submission to sequencer due to block timer"
                         );
+                        in_flight_bundle = Some(bundle.clone());
                         submission_fut = self.submit_bundle(nonce, bundle, self.metrics);
                     }
                 }
             }
         };
 
+        if let Some(chain_halt_task) = chain_halt_task {
+            chain_halt_task.abort();
+        }
+        if let Some(inclusion_task) = inclusion_task {
+            inclusion_task.abort();
+        }
+        if let Some(chain_id_task) = chain_id_task {
+            chain_id_task.abort();
+        }
+
         self.status
             .send_modify(|status| status.is_connected = false);
 
@@ -300,10 +459,10 @@ impl Executor {
         info!("draining already received transactions");
 
         // drain the receiver channel
-        while let Ok(seq_action) = self.serialized_rollup_transactions.try_recv() {
+        while let Ok((seq_action, priority)) = self.serialized_rollup_transactions.try_recv() {
             let rollup_id = seq_action.rollup_id;
 
-            if let Err(e) = bundle_factory.try_push(seq_action) {
+            if let Err(e) = bundle_factory.try_push_with_priority(seq_action, priority) {
                 self.metrics.increment_txs_dropped_too_large(&rollup_id);
                 warn!(
                     rollup_id = %rollup_id,
@@ -530,7 +689,8 @@ pin_project! {
     /// The future will fetch a new nonce from the sequencer if a submission returned an `INVALID_NONCE` error code.
     ///
     /// The future will only return an error if it ultimately failed submitting a transaction due to the underlying
-    /// transport failing. This can be taken as a break condition to exit the executor loop.
+    /// transport failing, or if the sequencer rejected the transaction's chain id. This can be taken as a
+    /// break condition to exit the executor loop.
     ///
     /// If the sequencer returned a non-zero abci code (albeit not `INVALID_NONCE`), this future will return with
     /// that nonce it used to submit the non-zero abci code request.
@@ -625,6 +785,21 @@ impl Future for SubmitFut {
                                     .boxed(),
                                 }
                             }
+                            AbciErrorCode::INVALID_CHAIN_ID => {
+                                error!(
+                                    chain_id = %this.chain_id,
+                                    abci.log = rsp.log,
+                                    "sequencer rejected the transaction's chain id; the sequencer \
+                                     node is likely on a different network",
+                                );
+
+                                this.metrics.increment_sequencer_submission_failure_count();
+
+                                return Poll::Ready(Err(eyre!(
+                                    "sequencer rejected the configured chain id `{}`",
+                                    this.chain_id,
+                                )));
+                            }
                             _other => {
                                 warn!(
                                     abci.code = rsp.code.value(),
@@ -10,7 +10,10 @@ use astria_core::{
         FEE_ASSET_ID_LEN,
         ROLLUP_ID_LEN,
     },
-    protocol::transaction::v1alpha1::action::SequenceAction,
+    protocol::{
+        abci::AbciErrorCode,
+        transaction::v1alpha1::action::SequenceAction,
+    },
 };
 use astria_eyre::eyre;
 use once_cell::sync::Lazy;
@@ -48,6 +51,46 @@ use crate::{
     Config,
 };
 
+const STATUS_RESPONSE: &str = r#"
+{
+  "node_info": {
+    "protocol_version": {
+      "p2p": "8",
+      "block": "11",
+      "app": "0"
+    },
+    "id": "a1d3bbddb7800c6da2e64169fec281494e963ba3",
+    "listen_addr": "tcp://0.0.0.0:26656",
+    "network": "test",
+    "version": "0.38.6",
+    "channels": "40202122233038606100",
+    "moniker": "fullnode",
+    "other": {
+      "tx_index": "on",
+      "rpc_address": "tcp://0.0.0.0:26657"
+    }
+  },
+  "sync_info": {
+    "latest_block_hash": "A4202E4E367712AC2A797860265A7EBEA8A3ACE513CB0105C2C9058449641202",
+    "latest_app_hash": "BCC9C9B82A49EC37AADA41D32B4FBECD2441563703955413195BDA2236775A68",
+    "latest_block_height": "452605",
+    "latest_block_time": "2024-05-09T15:59:17.849713071Z",
+    "earliest_block_hash": "C34B7B0B82423554B844F444044D7D08A026D6E413E6F72848DB2F8C77ACE165",
+    "earliest_app_hash": "6B776065775471CEF46AC75DE09A4B869A0E0EB1D7725A04A342C0E46C16F472",
+    "earliest_block_height": "1",
+    "earliest_block_time": "2024-04-23T00:49:11.964127Z",
+    "catching_up": false
+  },
+  "validator_info": {
+    "address": "0B46F33BA2FA5C2E2AD4C4C4E5ECE3F1CA03D195",
+    "pub_key": {
+      "type": "tendermint/PubKeyEd25519",
+      "value": "bA6GipHUijVuiYhv+4XymdePBsn8EeTqjGqNQrBGZ4I="
+    },
+    "voting_power": "0"
+  }
+}"#;
+
 static TELEMETRY: Lazy<()> = Lazy::new(|| {
     if std::env::var_os("TEST_LOG").is_some() {
         let filter_directives = std::env::var("RUST_LOG").unwrap_or_else(|_| "info".into());
@@ -69,8 +112,7 @@ static TELEMETRY: Lazy<()> = Lazy::new(|| {
 /// Start a mock sequencer server and mount a mock for the `accounts/nonce` query.
 async fn setup() -> (MockServer, MockGuard, Config, NamedTempFile) {
     use astria_core::generated::protocol::account::v1alpha1::NonceResponse;
-    Lazy::force(&TELEMETRY);
-    let server = MockServer::start().await;
+    let (server, cfg, keyfile) = setup_without_nonce_mock().await;
     let startup_guard = mount_nonce_query_mock(
         &server,
         "accounts/nonce",
@@ -80,6 +122,13 @@ async fn setup() -> (MockServer, MockGuard, Config, NamedTempFile) {
         },
     )
     .await;
+    (server, startup_guard, cfg, keyfile)
+}
+
+/// Start a mock sequencer server reporting the configured chain ID.
+async fn setup_without_nonce_mock() -> (MockServer, Config, NamedTempFile) {
+    Lazy::force(&TELEMETRY);
+    let server = MockServer::start().await;
 
     let keyfile = NamedTempFile::new().unwrap();
     (&keyfile)
@@ -92,11 +141,14 @@ async fn setup() -> (MockServer, MockGuard, Config, NamedTempFile) {
         rollups: String::new(),
         sequencer_url: server.uri(),
         sequencer_chain_id: "test-chain-1".to_string(),
+        chain_id_check_interval_ms: 0,
         private_key_file: keyfile.path().to_string_lossy().to_string(),
         sequencer_address_prefix: "astria".into(),
         block_time_ms: 2000,
         max_bytes_per_bundle: 1000,
         bundle_queue_capacity: 10,
+        chain_halt_timeout_ms: 0,
+        inclusion_check_blocks: 0,
         no_otel: false,
         force_stdout: false,
         no_metrics: false,
@@ -104,7 +156,8 @@ async fn setup() -> (MockServer, MockGuard, Config, NamedTempFile) {
         pretty_print: true,
         grpc_addr: "127.0.0.1:0".parse().unwrap(),
     };
-    (server, startup_guard, cfg, keyfile)
+    mount_status_mock(&server, &cfg.sequencer_chain_id).await;
+    (server, cfg, keyfile)
 }
 
 /// Mount a mock for the `abci_query` endpoint.
@@ -136,6 +189,22 @@ async fn mount_nonce_query_mock(
         .await
 }
 
+/// Mount a mock for the `status` endpoint, reporting `chain_id` as the network of the node.
+async fn mount_status_mock(server: &MockServer, chain_id: &str) {
+    let mut response: tendermint_rpc::endpoint::status::Response =
+        serde_json::from_str(STATUS_RESPONSE).unwrap();
+    response.node_info.network = chain_id.parse().unwrap();
+    let wrapper = response::Wrapper::new_with_id(Id::Num(1), Some(response), None);
+    Mock::given(body_partial_json(json!({"method": "status"})))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(&wrapper)
+                .append_header("Content-Type", "application/json"),
+        )
+        .mount(server)
+        .await;
+}
+
 /// Convert a `Request` object to a `SignedTransaction`
 fn signed_tx_from_request(request: &Request) -> SignedTransaction {
     use astria_core::generated::protocol::transaction::v1alpha1::SignedTransaction as RawSignedTransaction;
@@ -214,11 +283,14 @@ async fn full_bundle() {
     let (executor, executor_handle) = executor::Builder {
         sequencer_url: cfg.sequencer_url.clone(),
         sequencer_chain_id: cfg.sequencer_chain_id.clone(),
+        chain_id_check_interval_ms: cfg.chain_id_check_interval_ms,
         private_key_file: cfg.private_key_file.clone(),
         sequencer_address_prefix: "astria".into(),
         block_time_ms: cfg.block_time_ms,
         max_bytes_per_bundle: cfg.max_bytes_per_bundle,
         bundle_queue_capacity: cfg.bundle_queue_capacity,
+        chain_halt_timeout_ms: cfg.chain_halt_timeout_ms,
+        inclusion_check_blocks: cfg.inclusion_check_blocks,
         shutdown_token: shutdown_token.clone(),
         metrics,
     }
@@ -308,11 +380,14 @@ async fn bundle_triggered_by_block_timer() {
     let (executor, executor_handle) = executor::Builder {
         sequencer_url: cfg.sequencer_url.clone(),
         sequencer_chain_id: cfg.sequencer_chain_id.clone(),
+        chain_id_check_interval_ms: cfg.chain_id_check_interval_ms,
         private_key_file: cfg.private_key_file.clone(),
         sequencer_address_prefix: "astria".into(),
         block_time_ms: cfg.block_time_ms,
         max_bytes_per_bundle: cfg.max_bytes_per_bundle,
         bundle_queue_capacity: cfg.bundle_queue_capacity,
+        chain_halt_timeout_ms: cfg.chain_halt_timeout_ms,
+        inclusion_check_blocks: cfg.inclusion_check_blocks,
         shutdown_token: shutdown_token.clone(),
         metrics,
     }
@@ -395,11 +470,14 @@ async fn two_seq_actions_single_bundle() {
     let (executor, executor_handle) = executor::Builder {
         sequencer_url: cfg.sequencer_url.clone(),
         sequencer_chain_id: cfg.sequencer_chain_id.clone(),
+        chain_id_check_interval_ms: cfg.chain_id_check_interval_ms,
         private_key_file: cfg.private_key_file.clone(),
         sequencer_address_prefix: "astria".into(),
         block_time_ms: cfg.block_time_ms,
         max_bytes_per_bundle: cfg.max_bytes_per_bundle,
         bundle_queue_capacity: cfg.bundle_queue_capacity,
+        chain_halt_timeout_ms: cfg.chain_halt_timeout_ms,
+        inclusion_check_blocks: cfg.inclusion_check_blocks,
         shutdown_token: shutdown_token.clone(),
         metrics,
     }
@@ -480,3 +558,110 @@ async fn two_seq_actions_single_bundle() {
         );
     }
 }
+
+/// Test to check that the executor refuses to start if the sequencer node reports a different
+/// chain ID than the configured one.
+#[tokio::test]
+async fn chain_id_mismatch_on_startup() {
+    let (_sequencer, cfg, _keyfile) = setup_without_nonce_mock().await;
+    let metrics = Box::leak(Box::new(Metrics::new(cfg.parse_rollups().unwrap().keys())));
+    let (executor, _executor_handle) = executor::Builder {
+        sequencer_url: cfg.sequencer_url.clone(),
+        sequencer_chain_id: "other-chain-1".to_string(),
+        chain_id_check_interval_ms: cfg.chain_id_check_interval_ms,
+        private_key_file: cfg.private_key_file.clone(),
+        sequencer_address_prefix: "astria".into(),
+        block_time_ms: cfg.block_time_ms,
+        max_bytes_per_bundle: cfg.max_bytes_per_bundle,
+        bundle_queue_capacity: cfg.bundle_queue_capacity,
+        chain_halt_timeout_ms: cfg.chain_halt_timeout_ms,
+        inclusion_check_blocks: cfg.inclusion_check_blocks,
+        shutdown_token: CancellationToken::new(),
+        metrics,
+    }
+    .build()
+    .unwrap();
+
+    let status = executor.subscribe();
+
+    let error = tokio::time::timeout(Duration::from_secs(1), executor.run_until_stopped())
+        .await
+        .expect("the executor should exit immediately")
+        .expect_err("the executor should fail on a chain ID mismatch");
+    assert!(
+        format!("{error:#}").contains("other-chain-1"),
+        "unexpected error: {error:#}"
+    );
+    assert!(!status.borrow().is_connected());
+}
+
+/// Test to check that the executor exits if the sequencer rejects a submission because of its
+/// chain ID.
+#[tokio::test]
+async fn chain_id_rejected_on_submission() {
+    let (sequencer, nonce_guard, cfg, _keyfile) = setup().await;
+    let metrics = Box::leak(Box::new(Metrics::new(cfg.parse_rollups().unwrap().keys())));
+    let (executor, executor_handle) = executor::Builder {
+        sequencer_url: cfg.sequencer_url.clone(),
+        sequencer_chain_id: cfg.sequencer_chain_id.clone(),
+        chain_id_check_interval_ms: cfg.chain_id_check_interval_ms,
+        private_key_file: cfg.private_key_file.clone(),
+        sequencer_address_prefix: "astria".into(),
+        block_time_ms: cfg.block_time_ms,
+        max_bytes_per_bundle: cfg.max_bytes_per_bundle,
+        bundle_queue_capacity: cfg.bundle_queue_capacity,
+        chain_halt_timeout_ms: cfg.chain_halt_timeout_ms,
+        inclusion_check_blocks: cfg.inclusion_check_blocks,
+        shutdown_token: CancellationToken::new(),
+        metrics,
+    }
+    .build()
+    .unwrap();
+
+    let status = executor.subscribe();
+
+    let executor_task = tokio::spawn(executor.run_until_stopped());
+    wait_for_startup(status, nonce_guard).await.unwrap();
+
+    let jsonrpc_rsp = response::Wrapper::new_with_id(
+        Id::Num(1),
+        Some(tx_sync::Response {
+            code: AbciErrorCode::INVALID_CHAIN_ID.into(),
+            data: vec![].into(),
+            log: "chain id mismatch".to_string(),
+            hash: tendermint::Hash::Sha256([0; 32]),
+        }),
+        None,
+    );
+    let _response_guard = Mock::given(body_partial_json(json!({"method": "broadcast_tx_sync"})))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&jsonrpc_rsp))
+        .expect(1)
+        .mount_as_scoped(&sequencer)
+        .await;
+
+    // fill the bundle so that it is submitted immediately
+    let seq0 = SequenceAction {
+        rollup_id: RollupId::new([0; ROLLUP_ID_LEN]),
+        data: vec![0u8; cfg.max_bytes_per_bundle - ROLLUP_ID_LEN - FEE_ASSET_ID_LEN],
+        fee_asset_id: default_native_asset().id(),
+    };
+    let seq1 = SequenceAction {
+        rollup_id: RollupId::new([1; ROLLUP_ID_LEN]),
+        data: vec![1u8; 1],
+        fee_asset_id: default_native_asset().id(),
+    };
+    executor_handle
+        .send_timeout(seq0, Duration::from_millis(1000))
+        .await
+        .unwrap();
+    executor_handle
+        .send_timeout(seq1, Duration::from_millis(1000))
+        .await
+        .unwrap();
+
+    tokio::time::timeout(Duration::from_secs(1), executor_task)
+        .await
+        .expect("the executor should exit after the submission was rejected")
+        .unwrap()
+        .expect_err("the executor should fail on a rejected chain ID");
+}
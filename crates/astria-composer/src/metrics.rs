@@ -16,12 +16,17 @@ use metrics::{
     Histogram,
     Unit,
 };
-use telemetry::metric_names;
+use telemetry::{
+    metric_names,
+    metrics::{
+        labels,
+        Scope,
+    },
+};
 use tracing::error;
 
-const ROLLUP_CHAIN_NAME_LABEL: &str = "rollup_chain_name";
-const ROLLUP_ID_LABEL: &str = "rollup_id";
-const COLLECTOR_TYPE_LABEL: &str = "collector_type";
+const GETH_COLLECTOR: (&str, &str) = (labels::COLLECTOR_TYPE, "geth");
+const GRPC_COLLECTOR: (&str, &str) = (labels::COLLECTOR_TYPE, "grpc");
 
 pub(crate) struct Metrics {
     geth_txs_received: HashMap<String, Counter>,
@@ -37,6 +42,9 @@ pub(crate) struct Metrics {
     sequencer_submission_failure_count: Counter,
     txs_per_submission: Histogram,
     bytes_per_submission: Histogram,
+    chain_halted: Gauge,
+    inclusion_latency: Histogram,
+    sequence_actions_not_included: HashMap<RollupId, Counter>,
 }
 
 impl Metrics {
@@ -45,7 +53,9 @@ impl Metrics {
         let (geth_txs_received, grpc_txs_received) =
             register_txs_received(rollup_chain_names.clone());
         let (geth_txs_dropped, grpc_txs_dropped) = register_txs_dropped(rollup_chain_names.clone());
-        let txs_dropped_too_large = register_txs_dropped_too_large(rollup_chain_names);
+        let txs_dropped_too_large = register_txs_dropped_too_large(rollup_chain_names.clone());
+        let sequence_actions_not_included =
+            register_sequence_actions_not_included(rollup_chain_names);
 
         describe_counter!(
             NONCE_FETCH_COUNT,
@@ -100,6 +110,21 @@ impl Metrics {
         );
         let bytes_per_submission = histogram!(BYTES_PER_SUBMISSION);
 
+        describe_gauge!(
+            CHAIN_HALTED,
+            Unit::Count,
+            "Whether the sequencer chain is considered halted (1) or not (0)"
+        );
+        let chain_halted = gauge!(CHAIN_HALTED);
+
+        describe_histogram!(
+            INCLUSION_LATENCY,
+            Unit::Seconds,
+            "The time from submitting rollup data to the sequencer until it was observed in a \
+             sequencer block, in seconds"
+        );
+        let inclusion_latency = histogram!(INCLUSION_LATENCY);
+
         Self {
             geth_txs_received,
             geth_txs_dropped,
@@ -114,6 +139,9 @@ impl Metrics {
             sequencer_submission_failure_count,
             txs_per_submission,
             bytes_per_submission,
+            chain_halted,
+            inclusion_latency,
+            sequence_actions_not_included,
         }
     }
 
@@ -184,6 +212,22 @@ impl Metrics {
         #[allow(clippy::cast_precision_loss)]
         self.bytes_per_submission.record(byte_count as f64);
     }
+
+    pub(crate) fn set_chain_halted(&self, is_halted: bool) {
+        self.chain_halted.set(u8::from(is_halted));
+    }
+
+    pub(crate) fn record_inclusion_latency(&self, latency: Duration) {
+        self.inclusion_latency.record(latency);
+    }
+
+    pub(crate) fn increment_sequence_actions_not_included(&self, id: &RollupId) {
+        let Some(counter) = self.sequence_actions_not_included.get(id) else {
+            error!(rollup_id = %id, "failed to get sequence_actions_not_included counter");
+            return;
+        };
+        counter.increment(1);
+    }
 }
 
 fn register_txs_received<'a>(
@@ -200,22 +244,10 @@ fn register_txs_received<'a>(
     let mut grpc_counters = HashMap::new();
 
     for chain_name in rollup_chain_names {
-        let rollup_id = RollupId::from_unhashed_bytes(chain_name.as_bytes());
-
-        let geth_counter = counter!(
-            TRANSACTIONS_RECEIVED,
-            ROLLUP_CHAIN_NAME_LABEL => chain_name.clone(),
-            ROLLUP_ID_LABEL => rollup_id.to_string(),
-            COLLECTOR_TYPE_LABEL => "geth",
-        );
-        geth_counters.insert(chain_name.clone(), geth_counter.clone());
-
-        let grpc_counter = counter!(
-            TRANSACTIONS_RECEIVED,
-            ROLLUP_CHAIN_NAME_LABEL => chain_name.clone(),
-            ROLLUP_ID_LABEL => rollup_id.to_string(),
-            COLLECTOR_TYPE_LABEL => "grpc",
-        );
+        let (rollup_id, rollup) = rollup_scope(chain_name);
+        let geth_counter = rollup.counter_with(TRANSACTIONS_RECEIVED, &[GETH_COLLECTOR]);
+        geth_counters.insert(chain_name.clone(), geth_counter);
+        let grpc_counter = rollup.counter_with(TRANSACTIONS_RECEIVED, &[GRPC_COLLECTOR]);
         grpc_counters.insert(rollup_id, grpc_counter);
     }
     (geth_counters, grpc_counters)
@@ -235,22 +267,10 @@ fn register_txs_dropped<'a>(
     let mut grpc_counters = HashMap::new();
 
     for chain_name in rollup_chain_names {
-        let rollup_id = RollupId::from_unhashed_bytes(chain_name.as_bytes());
-
-        let geth_counter = counter!(
-            TRANSACTIONS_DROPPED,
-            ROLLUP_CHAIN_NAME_LABEL => chain_name.clone(),
-            ROLLUP_ID_LABEL => rollup_id.to_string(),
-            COLLECTOR_TYPE_LABEL => "geth",
-        );
-        geth_counters.insert(chain_name.clone(), geth_counter.clone());
-
-        let grpc_counter = counter!(
-            TRANSACTIONS_DROPPED,
-            ROLLUP_CHAIN_NAME_LABEL => chain_name.clone(),
-            ROLLUP_ID_LABEL => rollup_id.to_string(),
-            COLLECTOR_TYPE_LABEL => "grpc",
-        );
+        let (rollup_id, rollup) = rollup_scope(chain_name);
+        let geth_counter = rollup.counter_with(TRANSACTIONS_DROPPED, &[GETH_COLLECTOR]);
+        geth_counters.insert(chain_name.clone(), geth_counter);
+        let grpc_counter = rollup.counter_with(TRANSACTIONS_DROPPED, &[GRPC_COLLECTOR]);
         grpc_counters.insert(rollup_id, grpc_counter);
     }
     (geth_counters, grpc_counters)
@@ -268,21 +288,46 @@ fn register_txs_dropped_too_large<'a>(
     let mut counters = HashMap::new();
 
     for chain_name in rollup_chain_names {
-        let rollup_id = RollupId::from_unhashed_bytes(chain_name.as_bytes());
+        let (rollup_id, rollup) = rollup_scope(chain_name);
+        counters.insert(rollup_id, rollup.counter(TRANSACTIONS_DROPPED_TOO_LARGE));
+    }
+    counters
+}
 
-        let counter = counter!(
-            TRANSACTIONS_DROPPED_TOO_LARGE,
-            ROLLUP_CHAIN_NAME_LABEL => chain_name.clone(),
-            ROLLUP_ID_LABEL => rollup_id.to_string(),
-        );
-        counters.insert(rollup_id, counter);
+fn register_sequence_actions_not_included<'a>(
+    rollup_chain_names: impl Iterator<Item = &'a String>,
+) -> HashMap<RollupId, Counter> {
+    describe_counter!(
+        SEQUENCE_ACTIONS_NOT_INCLUDED,
+        Unit::Count,
+        "The number of submitted sequence actions whose rollup data was not included in a \
+         sequencer block within the configured number of blocks, labelled by rollup"
+    );
+
+    let mut counters = HashMap::new();
+
+    for chain_name in rollup_chain_names {
+        let (rollup_id, rollup) = rollup_scope(chain_name);
+        counters.insert(rollup_id, rollup.counter(SEQUENCE_ACTIONS_NOT_INCLUDED));
     }
     counters
 }
 
-metric_names!(pub const METRICS_NAMES:
+/// Returns the ID of the rollup named `chain_name` and a scope labelling metrics with both.
+fn rollup_scope(chain_name: &str) -> (RollupId, Scope) {
+    let rollup_id = RollupId::from_unhashed_bytes(chain_name.as_bytes());
+    let scope = Scope::new()
+        .with_label(labels::ROLLUP_CHAIN_NAME, chain_name)
+        .with_label(labels::ROLLUP_ID, rollup_id.to_string());
+    (rollup_id, scope)
+}
+
+metric_names!(pub const COLLECTOR_METRICS_NAMES in collectors:
     TRANSACTIONS_RECEIVED,
     TRANSACTIONS_DROPPED,
+);
+
+metric_names!(pub const EXECUTOR_METRICS_NAMES in executor:
     TRANSACTIONS_DROPPED_TOO_LARGE,
     NONCE_FETCH_COUNT,
     NONCE_FETCH_FAILURE_COUNT,
@@ -291,19 +336,25 @@ metric_names!(pub const METRICS_NAMES:
     SEQUENCER_SUBMISSION_LATENCY,
     SEQUENCER_SUBMISSION_FAILURE_COUNT,
     TRANSACTIONS_PER_SUBMISSION,
-    BYTES_PER_SUBMISSION
+    BYTES_PER_SUBMISSION,
+    CHAIN_HALTED,
+    INCLUSION_LATENCY,
+    SEQUENCE_ACTIONS_NOT_INCLUDED
 );
 
 #[cfg(test)]
 mod tests {
     use super::{
         BYTES_PER_SUBMISSION,
+        CHAIN_HALTED,
         CURRENT_NONCE,
+        INCLUSION_LATENCY,
         NONCE_FETCH_COUNT,
         NONCE_FETCH_FAILURE_COUNT,
         NONCE_FETCH_LATENCY,
         SEQUENCER_SUBMISSION_FAILURE_COUNT,
         SEQUENCER_SUBMISSION_LATENCY,
+        SEQUENCE_ACTIONS_NOT_INCLUDED,
         TRANSACTIONS_DROPPED,
         TRANSACTIONS_DROPPED_TOO_LARGE,
         TRANSACTIONS_PER_SUBMISSION,
@@ -320,22 +371,37 @@ mod tests {
 
     #[test]
     fn metrics_are_as_expected() {
-        assert_const(TRANSACTIONS_RECEIVED, "transactions_received");
-        assert_const(TRANSACTIONS_DROPPED, "transactions_dropped");
+        assert_const(TRANSACTIONS_RECEIVED, "collectors_transactions_received");
+        assert_const(TRANSACTIONS_DROPPED, "collectors_transactions_dropped");
         assert_const(
             TRANSACTIONS_DROPPED_TOO_LARGE,
-            "transactions_dropped_too_large",
+            "executor_transactions_dropped_too_large",
+        );
+        assert_const(NONCE_FETCH_COUNT, "executor_nonce_fetch_count");
+        assert_const(
+            NONCE_FETCH_FAILURE_COUNT,
+            "executor_nonce_fetch_failure_count",
+        );
+        assert_const(NONCE_FETCH_LATENCY, "executor_nonce_fetch_latency");
+        assert_const(CURRENT_NONCE, "executor_current_nonce");
+        assert_const(
+            SEQUENCER_SUBMISSION_LATENCY,
+            "executor_sequencer_submission_latency",
         );
-        assert_const(NONCE_FETCH_COUNT, "nonce_fetch_count");
-        assert_const(NONCE_FETCH_FAILURE_COUNT, "nonce_fetch_failure_count");
-        assert_const(NONCE_FETCH_LATENCY, "nonce_fetch_latency");
-        assert_const(CURRENT_NONCE, "current_nonce");
-        assert_const(SEQUENCER_SUBMISSION_LATENCY, "sequencer_submission_latency");
         assert_const(
             SEQUENCER_SUBMISSION_FAILURE_COUNT,
-            "sequencer_submission_failure_count",
+            "executor_sequencer_submission_failure_count",
+        );
+        assert_const(
+            TRANSACTIONS_PER_SUBMISSION,
+            "executor_transactions_per_submission",
+        );
+        assert_const(BYTES_PER_SUBMISSION, "executor_bytes_per_submission");
+        assert_const(CHAIN_HALTED, "executor_chain_halted");
+        assert_const(INCLUSION_LATENCY, "executor_inclusion_latency");
+        assert_const(
+            SEQUENCE_ACTIONS_NOT_INCLUDED,
+            "executor_sequence_actions_not_included",
         );
-        assert_const(TRANSACTIONS_PER_SUBMISSION, "transactions_per_submission");
-        assert_const(BYTES_PER_SUBMISSION, "bytes_per_submission");
     }
 }
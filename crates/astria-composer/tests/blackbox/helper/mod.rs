@@ -91,7 +91,8 @@ pub async fn spawn_composer(rollup_ids: &[&str]) -> TestComposer {
     let config = Config {
         log: String::new(),
         api_listen_addr: "127.0.0.1:0".parse().unwrap(),
-        sequencer_chain_id: "test-chain-1".to_string(),
+        sequencer_chain_id: mock_sequencer::SEQUENCER_CHAIN_ID.to_string(),
+        chain_id_check_interval_ms: 0,
         rollups,
         sequencer_url,
         private_key_file: keyfile.path().to_string_lossy().to_string(),
@@ -99,6 +100,8 @@ pub async fn spawn_composer(rollup_ids: &[&str]) -> TestComposer {
         block_time_ms: 2000,
         max_bytes_per_bundle: 200_000,
         bundle_queue_capacity: 10,
+        chain_halt_timeout_ms: 0,
+        inclusion_check_blocks: 0,
         no_otel: false,
         force_stdout: false,
         no_metrics: true,
@@ -0,0 +1,405 @@
+//! Operator-defined rules for filtering the rollup data forwarded to the execution node.
+//!
+//! The rules are read from a JSON file of the form
+//!
+//! ```json
+//! {
+//!   "rules": [
+//!     { "min_deposit_amount": { "amount": 1000 } },
+//!     { "allowed_bridge_addresses": { "addresses": ["astria1..."] } },
+//!     { "allowed_deposit_assets": { "denoms": ["nria"] } },
+//!     { "max_sequenced_data_bytes": { "bytes": 131072 } }
+//!   ]
+//! }
+//! ```
+//!
+//! Rollup data is only forwarded if it is accepted by every rule. Data that cannot be decoded is
+//! always forwarded, leaving it to the execution node to reject it.
+//!
+//! The [`Watcher`] re-reads the file periodically and publishes the rules whenever its contents
+//! change, so that they can be updated without restarting conductor. A file that fails to parse
+//! is logged and ignored, keeping the previous rules in place.
+//!
+//! Note that the filter changes the blocks that are executed: all conductors of a rollup must use
+//! the same rules at the same rollup heights, or their rollup nodes will diverge.
+
+use std::{
+    collections::HashSet,
+    fs,
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
+};
+
+use astria_core::{
+    generated::sequencerblock::v1alpha1 as raw,
+    primitive::v1::{
+        asset,
+        Address,
+    },
+    sequencerblock::v1alpha1::block::RollupData,
+};
+use astria_eyre::eyre::{
+    self,
+    WrapErr as _,
+};
+use prost::Message as _;
+use serde::Deserialize;
+use tokio::{
+    select,
+    sync::watch,
+    time::{
+        interval,
+        MissedTickBehavior,
+    },
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{
+    info,
+    instrument,
+    warn,
+};
+
+/// How often the filter file is checked for changes.
+const RELOAD_INTERVAL: Duration = Duration::from_secs(5);
+
+pub(crate) struct Builder {
+    pub(crate) path: String,
+    pub(crate) shutdown: CancellationToken,
+}
+
+impl Builder {
+    pub(crate) fn build(self) -> eyre::Result<(Watcher, Handle)> {
+        let Self {
+            path,
+            shutdown,
+        } = self;
+
+        let path = PathBuf::from(path);
+        let contents = fs::read(&path).wrap_err_with(|| {
+            format!("failed reading block filter file at `{}`", path.display())
+        })?;
+        let filter = BlockFilter::from_json(&contents).wrap_err_with(|| {
+            format!("failed parsing block filter file at `{}`", path.display())
+        })?;
+        info!(
+            path = %path.display(),
+            rules = filter.rules.len(),
+            "loaded block filter",
+        );
+
+        let (tx, rx) = watch::channel(Arc::new(filter));
+        let watcher = Watcher {
+            path,
+            contents,
+            filter: tx,
+            shutdown,
+        };
+        let handle = Handle {
+            filter: rx,
+        };
+        Ok((watcher, handle))
+    }
+}
+
+/// A handle to the latest rules published by the [`Watcher`].
+#[derive(Clone, Debug)]
+pub(crate) struct Handle {
+    filter: watch::Receiver<Arc<BlockFilter>>,
+}
+
+impl Handle {
+    /// Returns the latest rules.
+    pub(crate) fn current(&self) -> Arc<BlockFilter> {
+        self.filter.borrow().clone()
+    }
+}
+
+/// Reloads the filter file whenever its contents change.
+pub(crate) struct Watcher {
+    path: PathBuf,
+    contents: Vec<u8>,
+    filter: watch::Sender<Arc<BlockFilter>>,
+    shutdown: CancellationToken,
+}
+
+impl Watcher {
+    #[instrument(skip_all)]
+    pub(crate) async fn run_until_stopped(mut self) -> eyre::Result<()> {
+        let mut reload = interval(RELOAD_INTERVAL);
+        reload.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            select!(
+                biased;
+
+                () = self.shutdown.cancelled() => break,
+
+                _ = reload.tick() => self.reload(),
+            );
+        }
+        info!("block filter watcher shut down");
+        Ok(())
+    }
+
+    fn reload(&mut self) {
+        let contents = match fs::read(&self.path) {
+            Ok(contents) => contents,
+            Err(error) => {
+                warn!(
+                    path = %self.path.display(),
+                    %error,
+                    "failed reading block filter file; keeping the current rules",
+                );
+                return;
+            }
+        };
+        if contents == self.contents {
+            return;
+        }
+        match BlockFilter::from_json(&contents) {
+            Ok(filter) => {
+                info!(
+                    path = %self.path.display(),
+                    rules = filter.rules.len(),
+                    "block filter file changed; applying the new rules",
+                );
+                self.filter.send_replace(Arc::new(filter));
+            }
+            Err(error) => warn!(
+                path = %self.path.display(),
+                %error,
+                "failed parsing changed block filter file; keeping the current rules",
+            ),
+        }
+        // only report a broken file once instead of on every reload
+        self.contents = contents;
+    }
+}
+
+/// The rules that rollup data must satisfy to be forwarded to the execution node.
+#[derive(Debug)]
+pub(crate) struct BlockFilter {
+    rules: Vec<Rule>,
+}
+
+impl BlockFilter {
+    fn from_json(json: &[u8]) -> eyre::Result<Self> {
+        let RawBlockFilter {
+            rules,
+        } = serde_json::from_slice(json).wrap_err("failed deserializing JSON")?;
+        let rules = rules
+            .into_iter()
+            .map(Rule::try_from_raw)
+            .collect::<eyre::Result<_>>()?;
+        Ok(Self {
+            rules,
+        })
+    }
+
+    /// Removes the transactions that are rejected by any rule, returning the remaining
+    /// transactions and the number of removed ones.
+    pub(crate) fn apply(&self, mut transactions: Vec<Vec<u8>>) -> (Vec<Vec<u8>>, usize) {
+        if self.rules.is_empty() {
+            return (transactions, 0);
+        }
+        let before = transactions.len();
+        transactions.retain(|transaction| self.accepts(transaction));
+        let removed = before.saturating_sub(transactions.len());
+        (transactions, removed)
+    }
+
+    fn accepts(&self, transaction: &[u8]) -> bool {
+        let Some(data) = raw::RollupData::decode(transaction)
+            .ok()
+            .and_then(|raw| RollupData::try_from_raw(raw).ok())
+        else {
+            return true;
+        };
+        self.rules.iter().all(|rule| rule.accepts(&data))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawBlockFilter {
+    rules: Vec<RawRule>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+enum RawRule {
+    MinDepositAmount { amount: u128 },
+    AllowedBridgeAddresses { addresses: Vec<String> },
+    AllowedDepositAssets { denoms: Vec<String> },
+    MaxSequencedDataBytes { bytes: usize },
+}
+
+#[derive(Debug)]
+enum Rule {
+    /// Rejects deposits of less than the given amount.
+    MinDepositAmount(u128),
+    /// Rejects deposits to bridge addresses not in the set.
+    AllowedBridgeAddresses(HashSet<Address>),
+    /// Rejects deposits of assets not in the set.
+    AllowedDepositAssets(HashSet<asset::Id>),
+    /// Rejects sequenced data longer than the given number of bytes.
+    MaxSequencedDataBytes(usize),
+}
+
+impl Rule {
+    fn try_from_raw(raw: RawRule) -> eyre::Result<Self> {
+        let rule = match raw {
+            RawRule::MinDepositAmount {
+                amount,
+            } => Self::MinDepositAmount(amount),
+            RawRule::AllowedBridgeAddresses {
+                addresses,
+            } => Self::AllowedBridgeAddresses(
+                addresses
+                    .iter()
+                    .map(|address| {
+                        address
+                            .parse()
+                            .wrap_err_with(|| format!("invalid bridge address `{address}`"))
+                    })
+                    .collect::<eyre::Result<_>>()?,
+            ),
+            RawRule::AllowedDepositAssets {
+                denoms,
+            } => Self::AllowedDepositAssets(
+                denoms
+                    .iter()
+                    .map(|denom| {
+                        denom
+                            .parse::<asset::Denom>()
+                            .map(|denom| denom.id())
+                            .wrap_err_with(|| format!("invalid denom `{denom}`"))
+                    })
+                    .collect::<eyre::Result<_>>()?,
+            ),
+            RawRule::MaxSequencedDataBytes {
+                bytes,
+            } => Self::MaxSequencedDataBytes(bytes),
+        };
+        Ok(rule)
+    }
+
+    fn accepts(&self, data: &RollupData) -> bool {
+        match (self, data) {
+            (Self::MinDepositAmount(min), RollupData::Deposit(deposit)) => deposit.amount() >= *min,
+            (Self::AllowedBridgeAddresses(allowed), RollupData::Deposit(deposit)) => {
+                allowed.contains(deposit.bridge_address())
+            }
+            (Self::AllowedDepositAssets(allowed), RollupData::Deposit(deposit)) => {
+                allowed.contains(deposit.asset_id())
+            }
+            (Self::MaxSequencedDataBytes(max), RollupData::SequencedData(data)) => {
+                data.len() <= *max
+            }
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        primitive::v1::{
+            asset::default_native_asset,
+            RollupId,
+        },
+        sequencerblock::v1alpha1::block::Deposit,
+    };
+
+    use super::*;
+
+    fn bridge_address(byte: u8) -> Address {
+        Address::builder()
+            .array([byte; 20])
+            .prefix("astria")
+            .try_build()
+            .unwrap()
+    }
+
+    fn deposit(bridge_address: Address, amount: u128, denom: &str) -> Vec<u8> {
+        RollupData::Deposit(Deposit::new(
+            bridge_address,
+            RollupId::new([1; 32]),
+            amount,
+            denom.parse::<asset::Denom>().unwrap().id(),
+            "0xdeadbeef".to_string(),
+        ))
+        .into_raw()
+        .encode_to_vec()
+    }
+
+    fn sequenced_data(len: usize) -> Vec<u8> {
+        RollupData::SequencedData(vec![0; len])
+            .into_raw()
+            .encode_to_vec()
+    }
+
+    fn filter(json: &str) -> BlockFilter {
+        BlockFilter::from_json(json.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn empty_filter_forwards_everything() {
+        let transactions = vec![deposit(bridge_address(1), 0, "nria"), sequenced_data(10)];
+        let (kept, removed) = filter(r#"{"rules": []}"#).apply(transactions.clone());
+        assert_eq!(transactions, kept);
+        assert_eq!(0, removed);
+    }
+
+    #[test]
+    fn deposits_are_filtered_by_amount_bridge_address_and_asset() {
+        let allowed = bridge_address(1);
+        let filter = filter(&format!(
+            r#"{{"rules": [
+                {{"min_deposit_amount": {{"amount": 100}}}},
+                {{"allowed_bridge_addresses": {{"addresses": ["{allowed}"]}}}},
+                {{"allowed_deposit_assets": {{"denoms": ["{}"]}}}}
+            ]}}"#,
+            default_native_asset(),
+        ));
+        let native = default_native_asset().to_string();
+        let accepted = deposit(allowed, 100, &native);
+        let transactions = vec![
+            accepted.clone(),
+            deposit(allowed, 99, &native),
+            deposit(bridge_address(2), 100, &native),
+            deposit(allowed, 100, "other"),
+        ];
+        let (kept, removed) = filter.apply(transactions);
+        assert_eq!(vec![accepted], kept);
+        assert_eq!(3, removed);
+    }
+
+    #[test]
+    fn sequenced_data_is_filtered_by_length() {
+        let filter = filter(r#"{"rules": [{"max_sequenced_data_bytes": {"bytes": 4}}]}"#);
+        let (kept, removed) = filter.apply(vec![sequenced_data(4), sequenced_data(5)]);
+        assert_eq!(vec![sequenced_data(4)], kept);
+        assert_eq!(1, removed);
+    }
+
+    #[test]
+    fn undecodable_transactions_are_forwarded() {
+        let filter = filter(r#"{"rules": [{"min_deposit_amount": {"amount": 100}}]}"#);
+        let garbage = vec![0xff; 8];
+        let (kept, removed) = filter.apply(vec![garbage.clone()]);
+        assert_eq!(vec![garbage], kept);
+        assert_eq!(0, removed);
+    }
+
+    #[test]
+    fn invalid_rules_are_rejected() {
+        assert!(BlockFilter::from_json(br#"{"rules": [{"unknown_rule": {}}]}"#).is_err());
+        assert!(
+            BlockFilter::from_json(
+                br#"{"rules": [{"allowed_bridge_addresses": {"addresses": ["nope"]}}]}"#
+            )
+            .is_err()
+        );
+    }
+}
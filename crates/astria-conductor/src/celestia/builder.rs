@@ -10,7 +10,10 @@ use jsonrpsee::http_client::HttpClient as CelestiaClient;
 use tendermint_rpc::HttpClient as SequencerClient;
 use tokio_util::sync::CancellationToken;
 
-use super::Reader;
+use super::{
+    Reader,
+    TrustOptions,
+};
 use crate::{
     executor,
     metrics::Metrics,
@@ -24,6 +27,7 @@ pub(crate) struct Builder {
     pub(crate) sequencer_cometbft_client: SequencerClient,
     pub(crate) sequencer_requests_per_second: u32,
     pub(crate) shutdown: CancellationToken,
+    pub(crate) trust_options: Option<TrustOptions>,
     pub(crate) metrics: &'static Metrics,
 }
 
@@ -38,6 +42,7 @@ impl Builder {
             sequencer_cometbft_client,
             sequencer_requests_per_second,
             shutdown,
+            trust_options,
             metrics,
         } = self;
 
@@ -51,6 +56,7 @@ impl Builder {
             sequencer_cometbft_client,
             sequencer_requests_per_second,
             shutdown,
+            trust_options,
             metrics,
         })
     }
@@ -1,5 +1,5 @@
 use astria_core::{
-    brotli::decompress_bytes,
+    celestia::Blob,
     generated::sequencerblock::v1alpha1::{
         SubmittedMetadataList,
         SubmittedRollupDataList,
@@ -13,14 +13,8 @@ use astria_core::{
         SubmittedRollupData,
     },
 };
-use celestia_types::{
-    nmt::Namespace,
-    Blob,
-};
-use prost::{
-    Message as _,
-    Name as _,
-};
+use celestia_types::nmt::Namespace;
+use prost::Name as _;
 use telemetry::display::base64;
 use tracing::{
     info,
@@ -41,7 +35,7 @@ pub(super) fn decode_raw_blobs(
     let mut converted_blobs = ConvertedBlobs::new(raw_blobs.celestia_height);
     for blob in raw_blobs.header_blobs {
         if blob.namespace == sequencer_namespace {
-            if let Some(header_list) = convert_blob_to_header_list(&blob) {
+            if let Some(header_list) = convert_blob_to_header_list(blob) {
                 converted_blobs.extend_from_header_list_if_well_formed(header_list);
             }
         } else {
@@ -55,7 +49,7 @@ pub(super) fn decode_raw_blobs(
 
     for blob in raw_blobs.rollup_blobs {
         if blob.namespace == rollup_namespace {
-            if let Some(rollup_list) = convert_blob_to_rollup_data_list(&blob) {
+            if let Some(rollup_list) = convert_blob_to_rollup_data_list(blob) {
                 converted_blobs.extend_from_rollup_data_list_if_well_formed(rollup_list);
             }
         } else {
@@ -138,16 +132,9 @@ impl ConvertedBlobs {
     }
 }
 
-fn convert_blob_to_header_list(blob: &Blob) -> Option<SubmittedMetadataList> {
-    let data = decompress_bytes(&blob.data)
-        .inspect_err(|err| {
-            info!(
-                error = err as &StdError,
-                "failed decompressing blob data; dropping the blob",
-            );
-        })
-        .ok()?;
-    let raw = SubmittedMetadataList::decode(&*data)
+fn convert_blob_to_header_list(blob: celestia_types::Blob) -> Option<SubmittedMetadataList> {
+    let raw = Blob::try_from(blob)
+        .and_then(|blob| blob.try_decode_message::<SubmittedMetadataList>())
         .inspect_err(|err| {
             info!(
                 error = err as &StdError,
@@ -159,16 +146,9 @@ fn convert_blob_to_header_list(blob: &Blob) -> Option<SubmittedMetadataList> {
     Some(raw)
 }
 
-fn convert_blob_to_rollup_data_list(blob: &Blob) -> Option<SubmittedRollupDataList> {
-    let data = decompress_bytes(&blob.data)
-        .inspect_err(|err| {
-            info!(
-                error = err as &StdError,
-                "failed decompressing rollup blob data; dropping the blob",
-            );
-        })
-        .ok()?;
-    let raw = SubmittedRollupDataList::decode(&*data)
+fn convert_blob_to_rollup_data_list(blob: celestia_types::Blob) -> Option<SubmittedRollupDataList> {
+    let raw = Blob::try_from(blob)
+        .and_then(|blob| blob.try_decode_message::<SubmittedRollupDataList>())
         .inspect_err(|err| {
             info!(
                 error = err as &StdError,
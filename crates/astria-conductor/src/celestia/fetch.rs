@@ -129,12 +129,13 @@ async fn fetch_blobs_with_retry(
     .wrap_err("failed fetching blocks without being able to recover")
 }
 
-struct FetchBlobsRetryStrategy {
+/// Retries requests to Celestia with an exponential backoff if the underlying transport failed.
+pub(super) struct FetchBlobsRetryStrategy {
     delay: Duration,
 }
 
 impl FetchBlobsRetryStrategy {
-    fn new(initial_duration: Duration) -> Self {
+    pub(super) fn new(initial_duration: Duration) -> Self {
         Self {
             delay: initial_duration,
         }
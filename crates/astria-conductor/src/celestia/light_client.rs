@@ -0,0 +1,369 @@
+//! Verification of Celestia headers against a trusted state.
+//!
+//! Without it, conductor trusts the Celestia node it is connected to to only serve headers (and
+//! the blobs committed to by them) of the canonical Celestia chain. With it, the header at every
+//! Celestia height is verified before the blobs at that height are read, following the light
+//! client verification of CometBFT:
+//!
+//! + headers above a verified header are verified by skipping: the header is trusted if its commit
+//!   was signed by validators holding at least 1/3 of the voting power of the verified header's
+//!   validator set. If that is not the case, the range between the two headers is bisected and the
+//!   header at its midpoint is verified first, until adjacent headers are reached, which must chain
+//!   through their validator set hashes.
+//! + headers below a verified header are verified by following the hashes of the previous headers
+//!   backwards from it.
+//!
+//! Verification starts at a configured root of trust, a header height and hash obtained from a
+//! source trusted by the operator. Headers older than the trusting period are not used as a base
+//! for verifying newer ones, as their validators could have since unbonded without consequence.
+
+use std::{
+    collections::BTreeMap,
+    time::Duration,
+};
+
+use astria_eyre::eyre::{
+    self,
+    bail,
+    ensure,
+    eyre,
+    WrapErr as _,
+};
+use celestia_types::ExtendedHeader;
+use jsonrpsee::http_client::HttpClient as CelestiaClient;
+use tokio::sync::Mutex;
+use tracing::{
+    debug,
+    info,
+    instrument,
+    warn,
+};
+
+use super::fetch::FetchBlobsRetryStrategy;
+
+/// The number of verified headers kept to verify the next headers from.
+const MAX_VERIFIED_HEADERS: usize = 256;
+
+/// The root of trust of the light client and its verification parameters.
+#[derive(Clone, Debug)]
+pub(crate) struct TrustOptions {
+    /// The height of the trusted header.
+    pub(crate) trusted_height: u64,
+    /// The hash of the trusted header.
+    pub(crate) trusted_hash: [u8; 32],
+    /// The duration after its timestamp during which a verified header is used to verify newer
+    /// headers.
+    pub(crate) trusting_period: Duration,
+    /// The maximum number of bisections performed to verify a single header.
+    pub(crate) max_bisection_steps: u32,
+}
+
+impl TrustOptions {
+    /// Constructs the trust options from their configured values, or returns `None` if no
+    /// trusted hash is configured, disabling header verification.
+    pub(crate) fn from_config(
+        trusted_height: u64,
+        trusted_hash: &str,
+        trusting_period: Duration,
+        max_bisection_steps: u32,
+    ) -> eyre::Result<Option<Self>> {
+        if trusted_hash.is_empty() {
+            return Ok(None);
+        }
+        let trusted_hash = hex::decode(trusted_hash)
+            .wrap_err("trusted Celestia header hash is not hex encoded")?
+            .try_into()
+            .map_err(|bytes: Vec<u8>| {
+                eyre!(
+                    "trusted Celestia header hash must be 32 bytes long, but was {} bytes long",
+                    bytes.len()
+                )
+            })?;
+        ensure!(
+            trusted_height > 0,
+            "trusted Celestia height must be set if a trusted hash is set"
+        );
+        ensure!(
+            !trusting_period.is_zero(),
+            "Celestia trusting period must be set if a trusted hash is set"
+        );
+        Ok(Some(Self {
+            trusted_height,
+            trusted_hash,
+            trusting_period,
+            max_bisection_steps,
+        }))
+    }
+}
+
+pub(super) struct LightClient {
+    client: CelestiaClient,
+    trusting_period: Duration,
+    max_bisection_steps: u32,
+    /// The most recently verified headers, keyed by their heights. Never empty.
+    verified: Mutex<BTreeMap<u64, ExtendedHeader>>,
+}
+
+impl LightClient {
+    /// Fetches the trusted header and checks it against the trust options.
+    #[instrument(skip_all, fields(
+        trusted_height = options.trusted_height,
+        trusted_hash = %hex::encode(options.trusted_hash),
+    ))]
+    pub(super) async fn initialize(
+        client: CelestiaClient,
+        options: TrustOptions,
+    ) -> eyre::Result<Self> {
+        let TrustOptions {
+            trusted_height,
+            trusted_hash,
+            trusting_period,
+            max_bisection_steps,
+        } = options;
+        let trusted = fetch_validated_header(client.clone(), trusted_height).await?;
+        ensure!(
+            trusted.hash().as_bytes() == trusted_hash,
+            "hash of Celestia header at trusted height `{trusted_height}` does not match the \
+             trusted hash; received: `{}`",
+            trusted.hash(),
+        );
+        ensure_within_trusting_period(&trusted, trusting_period)?;
+        info!(
+            chain_id = %trusted.chain_id(),
+            "initialized Celestia light client from trusted header",
+        );
+        Ok(Self {
+            client,
+            trusting_period,
+            max_bisection_steps,
+            verified: Mutex::new(BTreeMap::from([(trusted_height, trusted)])),
+        })
+    }
+
+    /// Verifies the Celestia header at `height`, returning an error if it cannot be verified.
+    #[instrument(skip(self))]
+    pub(super) async fn verify(&self, height: u64) -> eyre::Result<()> {
+        let mut verified = self.verified.lock().await;
+        if verified.contains_key(&height) {
+            return Ok(());
+        }
+        let below = verified
+            .range(..height)
+            .next_back()
+            .map(|(_, header)| header.clone())
+            .filter(|header| is_within_trusting_period(header, self.trusting_period));
+        let above = verified
+            .range(height..)
+            .next()
+            .map(|(_, header)| header.clone());
+        match (below, above) {
+            (Some(trusted), _) => self.verify_forward(trusted, height, &mut verified).await?,
+            (None, Some(trusted)) => self.verify_backward(trusted, height, &mut verified).await?,
+            (None, None) => bail!(
+                "all verified Celestia headers below height `{height}` are older than the \
+                 trusting period; configure a more recent trusted header"
+            ),
+        }
+        while verified.len() > MAX_VERIFIED_HEADERS {
+            verified.pop_first();
+        }
+        Ok(())
+    }
+
+    /// Verifies the header at `target` from the lower `trusted` header, bisecting the range
+    /// between them if the validator sets overlap too little to skip ahead.
+    async fn verify_forward(
+        &self,
+        mut trusted: ExtendedHeader,
+        target: u64,
+        verified: &mut BTreeMap<u64, ExtendedHeader>,
+    ) -> eyre::Result<()> {
+        let mut pending = vec![fetch_validated_header(self.client.clone(), target).await?];
+        let mut bisections = 0u32;
+        while let Some(untrusted) = pending.last() {
+            ensure_within_trusting_period(&trusted, self.trusting_period)?;
+            let trusted_height = trusted.height().value();
+            let untrusted_height = untrusted.height().value();
+            match trusted.verify(untrusted) {
+                Ok(()) => {
+                    trusted = pending.pop().expect("checked to be not empty above");
+                    verified.insert(untrusted_height, trusted.clone());
+                    debug!(height = untrusted_height, "verified Celestia header");
+                }
+                Err(error) if untrusted_height <= trusted_height.saturating_add(1) => {
+                    return Err(error).wrap_err_with(|| {
+                        format!(
+                            "Celestia header at height `{untrusted_height}` failed verification \
+                             against its verified predecessor"
+                        )
+                    });
+                }
+                Err(error) => {
+                    ensure!(
+                        bisections < self.max_bisection_steps,
+                        "failed verifying Celestia header at height `{target}` within the \
+                         configured maximum of {} bisections",
+                        self.max_bisection_steps,
+                    );
+                    bisections = bisections.saturating_add(1);
+                    let pivot = bisection_pivot(trusted_height, untrusted_height);
+                    debug!(
+                        error = &error as &dyn std::error::Error,
+                        trusted_height,
+                        untrusted_height,
+                        pivot,
+                        "failed skipping to Celestia header; bisecting",
+                    );
+                    pending.push(fetch_validated_header(self.client.clone(), pivot).await?);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Verifies the header at `target` by following the previous header hashes down from the
+    /// higher `trusted` header.
+    async fn verify_backward(
+        &self,
+        mut trusted: ExtendedHeader,
+        target: u64,
+        verified: &mut BTreeMap<u64, ExtendedHeader>,
+    ) -> eyre::Result<()> {
+        while let Some(height) = trusted
+            .height()
+            .value()
+            .checked_sub(1)
+            .filter(|height| *height >= target)
+        {
+            let header = fetch_validated_header(self.client.clone(), height).await?;
+            ensure!(
+                header.hash() == trusted.last_header_hash(),
+                "hash of Celestia header at height `{height}` does not match the previous header \
+                 hash recorded in its verified successor; received: `{}`, expected: `{}`",
+                header.hash(),
+                trusted.last_header_hash(),
+            );
+            verified.insert(height, header.clone());
+            debug!(height, "verified Celestia header");
+            trusted = header;
+        }
+        Ok(())
+    }
+}
+
+/// Fetches the header at `height` and checks that it is internally consistent.
+async fn fetch_validated_header(
+    client: CelestiaClient,
+    height: u64,
+) -> eyre::Result<ExtendedHeader> {
+    let header = fetch_header_with_retry(client, height)
+        .await
+        .wrap_err_with(|| format!("failed fetching Celestia header at height `{height}`"))?;
+    ensure!(
+        header.height().value() == height,
+        "requested Celestia header at height `{height}`, but received one at height `{}`",
+        header.height(),
+    );
+    header
+        .validate()
+        .wrap_err_with(|| format!("Celestia header at height `{height}` is invalid"))?;
+    Ok(header)
+}
+
+async fn fetch_header_with_retry(
+    client: CelestiaClient,
+    height: u64,
+) -> Result<ExtendedHeader, jsonrpsee::core::Error> {
+    use celestia_rpc::HeaderClient as _;
+
+    let retry_config = tryhard::RetryFutureConfig::new(u32::MAX)
+        .custom_backoff(FetchBlobsRetryStrategy::new(Duration::from_millis(100)))
+        .max_delay(Duration::from_secs(20))
+        .on_retry(
+            |attempt: u32, next_delay: Option<Duration>, error: &jsonrpsee::core::Error| {
+                let wait_duration = next_delay
+                    .map(humantime::format_duration)
+                    .map(tracing::field::display);
+                warn!(
+                    attempt,
+                    wait_duration,
+                    error = error as &dyn std::error::Error,
+                    "attempt to fetch Celestia header failed; retrying after delay",
+                );
+                futures::future::ready(())
+            },
+        );
+
+    tryhard::retry_fn(move || {
+        let client = client.clone();
+        async move { client.header_get_by_height(height).await }
+    })
+    .with_config(retry_config)
+    .await
+}
+
+fn ensure_within_trusting_period(
+    header: &ExtendedHeader,
+    trusting_period: Duration,
+) -> eyre::Result<()> {
+    ensure!(
+        is_within_trusting_period(header, trusting_period),
+        "verified Celestia header at height `{}` with timestamp `{}` is older than the trusting \
+         period of {}; configure a more recent trusted header",
+        header.height(),
+        header.time(),
+        humantime::format_duration(trusting_period),
+    );
+    Ok(())
+}
+
+fn is_within_trusting_period(header: &ExtendedHeader, trusting_period: Duration) -> bool {
+    // a header from the future has an age of zero; rejecting those is left to verification
+    let age = celestia_tendermint::Time::now()
+        .duration_since(header.time())
+        .unwrap_or_default();
+    age < trusting_period
+}
+
+/// Returns the height halfway between `trusted` and `untrusted`.
+fn bisection_pivot(trusted: u64, untrusted: u64) -> u64 {
+    trusted.saturating_add(untrusted.saturating_sub(trusted) / 2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn no_trusted_hash_disables_verification() {
+        let options = TrustOptions::from_config(0, "", Duration::ZERO, 0).unwrap();
+        assert!(options.is_none());
+    }
+
+    #[test]
+    fn trust_options_are_parsed() {
+        let hash = hex::encode([42; 32]);
+        let options = TrustOptions::from_config(10, &hash, Duration::from_secs(60), 20)
+            .unwrap()
+            .unwrap();
+        assert_eq!(options.trusted_height, 10);
+        assert_eq!(options.trusted_hash, [42; 32]);
+    }
+
+    #[test]
+    fn invalid_trust_options_are_rejected() {
+        let hash = hex::encode([42; 32]);
+        let period = Duration::from_secs(60);
+        assert!(TrustOptions::from_config(10, "not hex", period, 20).is_err());
+        assert!(TrustOptions::from_config(10, &hex::encode([42; 31]), period, 20).is_err());
+        assert!(TrustOptions::from_config(0, &hash, period, 20).is_err());
+        assert!(TrustOptions::from_config(10, &hash, Duration::ZERO, 20).is_err());
+    }
+
+    #[test]
+    fn bisection_pivot_is_between_heights() {
+        assert_eq!(bisection_pivot(10, 20), 15);
+        assert_eq!(bisection_pivot(10, 13), 11);
+        assert_eq!(bisection_pivot(10, 12), 11);
+    }
+}
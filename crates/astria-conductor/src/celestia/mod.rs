@@ -69,12 +69,15 @@ mod builder;
 mod convert;
 mod fetch;
 mod latest_height_stream;
+mod light_client;
 mod reconstruct;
 mod reporting;
 mod verify;
 
 pub(crate) use builder::Builder;
 use latest_height_stream::LatestHeightStream;
+use light_client::LightClient;
+pub(crate) use light_client::TrustOptions;
 use reporting::ReportReconstructedBlocks;
 
 use self::{
@@ -140,12 +143,15 @@ pub(crate) struct Reader {
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
 
+    /// The root of trust for verifying Celestia headers. Headers are not verified if unset.
+    trust_options: Option<TrustOptions>,
+
     metrics: &'static Metrics,
 }
 
 impl Reader {
     pub(crate) async fn run_until_stopped(mut self) -> eyre::Result<()> {
-        let (executor, sequencer_chain_id) = select!(
+        let (executor, sequencer_chain_id, light_client) = select!(
             () = self.shutdown.clone().cancelled_owned() => {
                 info!("received shutdown signal while waiting for Celestia reader task to initialize");
                 return Ok(());
@@ -156,7 +162,7 @@ impl Reader {
             }
         );
 
-        RunningReader::from_parts(self, executor, sequencer_chain_id)
+        RunningReader::from_parts(self, executor, sequencer_chain_id, light_client)
             .wrap_err("failed entering run loop")?
             .run_until_stopped()
             .await
@@ -164,7 +170,11 @@ impl Reader {
 
     async fn initialize(
         &mut self,
-    ) -> eyre::Result<(executor::Handle<StateIsInit>, tendermint::chain::Id)> {
+    ) -> eyre::Result<(
+        executor::Handle<StateIsInit>,
+        tendermint::chain::Id,
+        Option<LightClient>,
+    )> {
         let wait_for_init_executor = async {
             self.executor
                 .wait_for_init()
@@ -178,7 +188,21 @@ impl Reader {
                 .wrap_err("failed to get sequencer chain ID")
         };
 
-        try_join!(wait_for_init_executor, get_sequencer_chain_id)
+        let initialize_light_client = async {
+            match self.trust_options.clone() {
+                Some(options) => LightClient::initialize(self.celestia_client.clone(), options)
+                    .await
+                    .map(Some)
+                    .wrap_err("failed to initialize Celestia light client"),
+                None => Ok(None),
+            }
+        };
+
+        try_join!(
+            wait_for_init_executor,
+            get_sequencer_chain_id,
+            initialize_light_client
+        )
     }
 }
 
@@ -187,6 +211,10 @@ struct RunningReader {
 
     blob_verifier: Arc<BlobVerifier>,
 
+    /// Verifies the Celestia header at each height before its blobs are read. Unset if header
+    /// verification is disabled.
+    light_client: Option<Arc<LightClient>>,
+
     // Client to fetch heights and blocks from Celestia.
     celestia_client: CelestiaClient,
 
@@ -252,6 +280,7 @@ impl RunningReader {
         exposed_reader: Reader,
         mut executor: executor::Handle<StateIsInit>,
         sequencer_chain_id: tendermint::chain::Id,
+        light_client: Option<LightClient>,
     ) -> eyre::Result<Self> {
         let Reader {
             celestia_block_time,
@@ -268,9 +297,9 @@ impl RunningReader {
 
         let latest_heights = stream_latest_heights(celestia_client.clone(), celestia_block_time);
         let rollup_id = executor.rollup_id();
-        let rollup_namespace = astria_core::celestia::namespace_v0_from_rollup_id(rollup_id);
+        let rollup_namespace = astria_core::celestia::Namespace::from_rollup_id(rollup_id).get();
         let sequencer_namespace =
-            astria_core::celestia::namespace_v0_from_sha256_of_bytes(sequencer_chain_id.as_bytes());
+            astria_core::celestia::Namespace::from_chain_id(&sequencer_chain_id).get();
 
         let celestia_next_height = executor.celestia_base_block_height();
         let celestia_reference_height = executor.celestia_base_block_height();
@@ -282,6 +311,7 @@ impl RunningReader {
                 BlobVerifier::try_new(sequencer_cometbft_client, sequencer_requests_per_second)
                     .wrap_err("failed to construct blob verifier")?,
             ),
+            light_client: light_client.map(Arc::new),
             celestia_client,
             enqueued_block: Fuse::terminated(),
             executor,
@@ -419,6 +449,7 @@ impl RunningReader {
             self.celestia_next_height = self.celestia_next_height.saturating_add(1);
             let task = FetchConvertVerifyAndReconstruct {
                 blob_verifier: self.blob_verifier.clone(),
+                light_client: self.light_client.clone(),
                 celestia_client: self.celestia_client.clone(),
                 celestia_height: height,
                 rollup_id: self.rollup_id,
@@ -492,6 +523,7 @@ impl RunningReader {
 
 struct FetchConvertVerifyAndReconstruct {
     blob_verifier: Arc<BlobVerifier>,
+    light_client: Option<Arc<LightClient>>,
     celestia_client: CelestiaClient,
     celestia_height: u64,
     rollup_id: RollupId,
@@ -510,6 +542,7 @@ impl FetchConvertVerifyAndReconstruct {
     async fn execute(self) -> eyre::Result<ReconstructedBlocks> {
         let Self {
             blob_verifier,
+            light_client,
             celestia_client,
             celestia_height,
             rollup_id,
@@ -519,6 +552,13 @@ impl FetchConvertVerifyAndReconstruct {
             metrics,
         } = self;
 
+        if let Some(light_client) = light_client {
+            light_client
+                .verify(celestia_height)
+                .await
+                .wrap_err("failed verifying Celestia header")?;
+        }
+
         let new_blobs = fetch_new_blobs(
             celestia_client,
             celestia_height,
@@ -4,10 +4,14 @@ use std::{
     time::Duration,
 };
 
-use astria_eyre::eyre::{
-    self,
-    eyre,
-    WrapErr as _,
+use astria_eyre::{
+    eyre::{
+        self,
+        eyre,
+        WrapErr as _,
+    },
+    Classify as _,
+    ErrorClass,
 };
 use itertools::Itertools as _;
 use pin_project_lite::pin_project;
@@ -28,9 +32,11 @@ use tracing::{
 };
 
 use crate::{
+    block_filter,
     celestia,
     executor,
     metrics::Metrics,
+    receipts,
     sequencer,
     utils::flatten,
     Config,
@@ -40,7 +46,7 @@ pin_project! {
     /// A handle returned by [`Conductor::spawn`].
     pub struct Handle {
         shutdown_token: CancellationToken,
-        task: Option<tokio::task::JoinHandle<()>>,
+        task: Option<tokio::task::JoinHandle<eyre::Result<()>>>,
     }
 }
 
@@ -55,12 +61,15 @@ impl Handle {
     pub async fn shutdown(&mut self) -> Result<(), tokio::task::JoinError> {
         self.shutdown_token.cancel();
         let task = self.task.take().expect("shutdown must not be called twice");
-        task.await
+        if let Err(error) = task.await? {
+            warn!(%error, "conductor failed before it could be shut down");
+        }
+        Ok(())
     }
 }
 
 impl Future for Handle {
-    type Output = Result<(), tokio::task::JoinError>;
+    type Output = Result<eyre::Result<()>, tokio::task::JoinError>;
 
     fn poll(
         self: std::pin::Pin<&mut Self>,
@@ -85,8 +94,10 @@ pub struct Conductor {
 }
 
 impl Conductor {
+    const BLOCK_FILTER: &'static str = "block_filter";
     const CELESTIA: &'static str = "celestia";
     const EXECUTOR: &'static str = "executor";
+    const RECEIPTS: &'static str = "receipts";
     const SEQUENCER: &'static str = "sequencer";
 
     /// Create a new [`Conductor`] from a [`Config`].
@@ -102,19 +113,58 @@ impl Conductor {
         let mut tasks = JoinMap::new();
 
         let sequencer_cometbft_client = HttpClient::new(&*cfg.sequencer_cometbft_url)
+            .classify(ErrorClass::Config)
             .wrap_err("failed constructing sequencer cometbft RPC client")?;
 
         let shutdown = CancellationToken::new();
 
+        // Spawn the receipt writer task if enabled.
+        let receipts_handle = if cfg.no_execution_receipts {
+            None
+        } else {
+            let (writer, handle) = receipts::Builder {
+                signing_key_path: cfg.execution_receipts_signing_key_path,
+                receipts_dir: cfg.execution_receipts_dir,
+                post_url: cfg.execution_receipts_post_url,
+                shutdown: shutdown.clone(),
+            }
+            .build()
+            .classify(ErrorClass::Config)
+            .wrap_err("failed constructing execution receipt writer")?;
+
+            tasks.spawn(Self::RECEIPTS, writer.run_until_stopped());
+            Some(handle)
+        };
+
+        // Spawn the block filter watcher task if enabled.
+        let block_filter_handle = if cfg.block_filter_path.is_empty() {
+            None
+        } else {
+            let (watcher, handle) = block_filter::Builder {
+                path: cfg.block_filter_path,
+                shutdown: shutdown.clone(),
+            }
+            .build()
+            .classify(ErrorClass::Config)
+            .wrap_err("failed constructing block filter")?;
+
+            tasks.spawn(Self::BLOCK_FILTER, watcher.run_until_stopped());
+            Some(handle)
+        };
+
         // Spawn the executor task.
         let executor_handle = {
             let (executor, handle) = executor::Builder {
                 mode: cfg.execution_commit_level,
+                execute_optimistic_blocks: cfg.execute_optimistic_blocks,
                 rollup_address: cfg.execution_rpc_url,
+                receipts: receipts_handle,
+                block_filter: block_filter_handle,
                 shutdown: shutdown.clone(),
                 metrics,
             }
             .build()
+            .classify(ErrorClass::Config)
             .wrap_err("failed constructing executor")?;
 
             tasks.spawn(Self::EXECUTOR, executor.run_until_stopped());
@@ -124,6 +174,7 @@ impl Conductor {
         if cfg.execution_commit_level.is_with_soft() {
             let sequencer_grpc_client =
                 sequencer::SequencerGrpcClient::new(&cfg.sequencer_grpc_url)
+                    .classify(ErrorClass::Config)
                     .wrap_err("failed constructing grpc client for Sequencer")?;
 
             // The `sync_start_block_height` represents the height of the next
@@ -133,14 +184,28 @@ impl Conductor {
                 sequencer_grpc_client,
                 sequencer_cometbft_client: sequencer_cometbft_client.clone(),
                 sequencer_block_time: Duration::from_millis(cfg.sequencer_block_time_ms),
+                execute_optimistic_blocks: cfg.execute_optimistic_blocks,
                 shutdown: shutdown.clone(),
                 executor: executor_handle.clone(),
             }
             .build();
             tasks.spawn(Self::SEQUENCER, sequencer_reader.run_until_stopped());
+        } else if cfg.execute_optimistic_blocks {
+            warn!(
+                "optimistic execution requires soft commitments, but conductor is configured for \
+                 firm commitments only; not executing optimistic blocks"
+            );
         }
 
         if cfg.execution_commit_level.is_with_firm() {
+            let trust_options = celestia::TrustOptions::from_config(
+                cfg.celestia_trusted_height,
+                &cfg.celestia_trusted_hash,
+                Duration::from_millis(cfg.celestia_trusting_period_ms),
+                cfg.celestia_max_bisection_steps,
+            )
+            .classify(ErrorClass::Config)
+            .wrap_err("invalid Celestia light client configuration")?;
             let reader = celestia::Builder {
                 celestia_http_endpoint: cfg.celestia_node_http_url,
                 celestia_token: cfg.celestia_bearer_token,
@@ -149,9 +214,11 @@ impl Conductor {
                 sequencer_cometbft_client: sequencer_cometbft_client.clone(),
                 sequencer_requests_per_second: cfg.sequencer_requests_per_second,
                 shutdown: shutdown.clone(),
+                trust_options,
                 metrics,
             }
             .build()
+            .classify(ErrorClass::Config)
             .wrap_err("failed to build Celestia Reader")?;
 
             tasks.spawn(Self::CELESTIA, reader.run_until_stopped());
@@ -165,10 +232,13 @@ impl Conductor {
 
     /// Runs [`Conductor`] until it receives an exit signal.
     ///
+    /// Returns the error of the task that caused conductor to exit, if any, so that its
+    /// [`ErrorClass`] can be mapped to an exit code.
+    ///
     /// # Panics
     /// Panics if it could not install a signal handler.
     #[instrument(skip_all)]
-    async fn run_until_stopped(mut self) {
+    async fn run_until_stopped(mut self) -> eyre::Result<()> {
         info!("conductor is running");
 
         let exit_reason = select! {
@@ -181,17 +251,18 @@ impl Conductor {
             Some((name, res)) = self.tasks.join_next() => {
                 match flatten(res) {
                     Ok(()) => Err(eyre!("task `{name}` exited unexpectedly")),
-                    Err(err) => Err(err).wrap_err_with(|| "task `{name}` failed"),
+                    Err(err) => Err(err).wrap_err_with(|| format!("task `{name}` failed")),
                 }
             }
         };
 
         let message = "initiating shutdown";
-        match exit_reason {
+        match &exit_reason {
             Ok(reason) => info!(reason, message),
             Err(reason) => error!(%reason, message),
         }
         self.shutdown().await;
+        exit_reason.map(|_| ())
     }
 
     /// Spawns Conductor on the tokio runtime.
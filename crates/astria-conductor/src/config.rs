@@ -47,6 +47,21 @@ pub struct Config {
     /// The JWT bearer token supplied with each jsonrpc call
     pub celestia_bearer_token: String,
 
+    /// The height of the Celestia header that is trusted to verify all other Celestia headers
+    /// from.
+    pub celestia_trusted_height: u64,
+
+    /// The hex encoded hash of the trusted Celestia header. Celestia headers are not verified if
+    /// empty.
+    pub celestia_trusted_hash: String,
+
+    /// The duration in milliseconds after its timestamp during which a verified Celestia header
+    /// is trusted to verify newer headers.
+    pub celestia_trusting_period_ms: u64,
+
+    /// The maximum number of bisections performed to verify a single Celestia header.
+    pub celestia_max_bisection_steps: u32,
+
     /// URL of the Sequencer Cometbft gRPC service.
     pub sequencer_grpc_url: String,
 
@@ -68,6 +83,29 @@ pub struct Config {
     /// the execution layer.
     pub execution_commit_level: CommitLevel,
 
+    /// Executes the blocks proposed to the Sequencer network before they are committed, so that
+    /// only the commitment state needs updating once the same block is committed. Has no effect
+    /// unless `execution_commit_level` includes soft commitments.
+    pub execute_optimistic_blocks: bool,
+
+    /// Disables writing signed receipts of the executed blocks.
+    pub no_execution_receipts: bool,
+
+    /// Path to the hex encoded ed25519 private key used to sign execution receipts.
+    pub execution_receipts_signing_key_path: String,
+
+    /// Directory that signed execution receipts are written to.
+    pub execution_receipts_dir: String,
+
+    /// HTTP endpoint that signed execution receipts are posted to. Receipts are not posted if
+    /// empty.
+    pub execution_receipts_post_url: String,
+
+    /// Path to a JSON file of rules that the rollup data of every block must satisfy to be
+    /// forwarded to the execution node. The file is reloaded when it changes. No rollup data is
+    /// filtered if empty.
+    pub block_filter_path: String,
+
     /// Forces writing trace data to stdout no matter if connected to a tty or not.
     pub force_stdout: bool,
 
@@ -14,13 +14,18 @@ use super::{
     StateNotInit,
 };
 use crate::{
+    block_filter,
     config::CommitLevel,
     metrics::Metrics,
+    receipts,
 };
 
 pub(crate) struct Builder {
     pub(crate) mode: CommitLevel,
+    pub(crate) execute_optimistic_blocks: bool,
     pub(crate) rollup_address: String,
+    pub(crate) receipts: Option<receipts::Handle>,
+    pub(crate) block_filter: Option<block_filter::Handle>,
     pub(crate) shutdown: CancellationToken,
     pub(crate) metrics: &'static Metrics,
 }
@@ -29,7 +34,10 @@ impl Builder {
     pub(crate) fn build(self) -> eyre::Result<(Executor, Handle)> {
         let Self {
             mode,
+            execute_optimistic_blocks,
             rollup_address,
+            receipts,
+            block_filter,
             shutdown,
             metrics,
         } = self;
@@ -57,6 +65,14 @@ impl Builder {
             soft_block_rx = Some(rx);
         }
 
+        let mut optimistic_block_tx = None;
+        let mut optimistic_block_rx = None;
+        if mode.is_with_soft() && execute_optimistic_blocks {
+            let (tx, rx) = mpsc::channel(16);
+            optimistic_block_tx = Some(tx);
+            optimistic_block_rx = Some(rx);
+        }
+
         let (state_tx, state_rx) = state::channel();
 
         let executor = Executor {
@@ -66,6 +82,11 @@ impl Builder {
 
             firm_blocks: firm_block_rx,
             soft_blocks: soft_block_rx,
+            optimistic_blocks: optimistic_block_rx,
+            optimistic_block: None,
+
+            receipts,
+            block_filter,
 
             shutdown,
             state: state_tx,
@@ -77,6 +98,7 @@ impl Builder {
         let handle = Handle {
             firm_blocks: firm_block_tx,
             soft_blocks: soft_block_tx,
+            optimistic_blocks: optimistic_block_tx,
             state: state_rx,
             _state_init: StateNotInit,
         };
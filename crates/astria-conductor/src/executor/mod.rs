@@ -11,11 +11,15 @@ use astria_core::{
         FilteredSequencerBlockParts,
     },
 };
-use astria_eyre::eyre::{
-    self,
-    bail,
-    ensure,
-    WrapErr as _,
+use astria_eyre::{
+    eyre::{
+        self,
+        bail,
+        ensure,
+        WrapErr as _,
+    },
+    Classify as _,
+    ErrorClass,
 };
 use bytes::Bytes;
 use sequencer_client::tendermint::{
@@ -38,9 +42,16 @@ use tracing::{
 };
 
 use crate::{
+    block_filter,
     celestia::ReconstructedBlock,
     config::CommitLevel,
     metrics::Metrics,
+    receipts::{
+        self,
+        Commitment,
+        Execution,
+        Receipt,
+    },
 };
 
 mod builder;
@@ -106,6 +117,16 @@ pub(crate) enum SoftTrySendError {
     },
 }
 
+#[derive(Debug, thiserror::Error)]
+pub(crate) enum OptimisticTrySendError {
+    #[error("executor was configured without optimistic execution")]
+    NotSet,
+    #[error("failed sending blocks to executor")]
+    Channel {
+        source: Box<mpsc::error::TrySendError<FilteredSequencerBlock>>,
+    },
+}
+
 /// A handle to the executor.
 ///
 /// To be useful, [`Handle<StateNotInit>::wait_for_init`] must be called in
@@ -116,6 +137,7 @@ pub(crate) enum SoftTrySendError {
 pub(crate) struct Handle<TStateInit = StateNotInit> {
     firm_blocks: Option<mpsc::Sender<ReconstructedBlock>>,
     soft_blocks: Option<channel::Sender<FilteredSequencerBlock>>,
+    optimistic_blocks: Option<mpsc::Sender<FilteredSequencerBlock>>,
     state: StateReceiver,
     _state_init: TStateInit,
 }
@@ -129,12 +151,14 @@ impl<T: Clone> Handle<T> {
         let Self {
             firm_blocks,
             soft_blocks,
+            optimistic_blocks,
             state,
             ..
         } = self.clone();
         Ok(Handle {
             firm_blocks,
             soft_blocks,
+            optimistic_blocks,
             state,
             _state_init: StateIsInit,
         })
@@ -189,6 +213,24 @@ impl Handle<StateIsInit> {
         Ok(())
     }
 
+    // allow: this is mimicking tokio's `SendError` that returns the stack-allocated object.
+    #[allow(clippy::result_large_err)]
+    pub(crate) fn try_send_optimistic_block(
+        &self,
+        block: FilteredSequencerBlock,
+    ) -> Result<(), OptimisticTrySendError> {
+        let sender = self
+            .optimistic_blocks
+            .as_ref()
+            .ok_or(OptimisticTrySendError::NotSet)?;
+        sender
+            .try_send(block)
+            .map_err(|source| OptimisticTrySendError::Channel {
+                source: Box::new(source),
+            })?;
+        Ok(())
+    }
+
     pub(crate) fn next_expected_firm_sequencer_height(&mut self) -> SequencerHeight {
         self.state.next_expected_firm_sequencer_height()
     }
@@ -233,6 +275,25 @@ pub(crate) struct Executor {
     /// Only set if `mode` is `SoftOnly` or `SoftAndFirm`.
     soft_blocks: Option<channel::Receiver<FilteredSequencerBlock>>,
 
+    /// The channel of which this executor receives blocks proposed to Sequencer
+    /// for optimistic execution.
+    /// Only set if optimistic execution is enabled and `mode` is `SoftOnly` or `SoftAndFirm`.
+    optimistic_blocks: Option<mpsc::Receiver<FilteredSequencerBlock>>,
+
+    /// The block executed optimistically at the next expected soft height, if any.
+    ///
+    /// Used to mark the committed block at that height as executed without
+    /// re-executing it if it is the same block.
+    optimistic_block: Option<OptimisticBlock>,
+
+    /// The handle to send receipts of the committed blocks to.
+    /// Only set if execution receipts are enabled.
+    receipts: Option<receipts::Handle>,
+
+    /// The handle to the operator-defined rules applied to the rollup data of every block before
+    /// it is executed. Only set if a block filter is configured.
+    block_filter: Option<block_filter::Handle>,
+
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
 
@@ -307,6 +368,19 @@ impl Executor {
                         break Err(error).wrap_err("failed executing soft block");
                     }
                 }
+
+                Some(block) = async { self.optimistic_blocks.as_mut().unwrap().recv().await },
+                              if self.optimistic_blocks.is_some() && spread_not_too_large =>
+                {
+                    debug!(
+                        block.height = %block.height(),
+                        block.hash = %telemetry::display::base64(&block.block_hash()),
+                        "received optimistic block from sequencer reader",
+                    );
+                    if let Err(error) = self.execute_optimistic(block).await {
+                        break Err(error).wrap_err("failed executing optimistic block");
+                    }
+                }
             );
         };
 
@@ -437,17 +511,38 @@ impl Executor {
 
         // The parent hash of the next block is the hash of the block at the current head.
         let parent_hash = self.state.soft_hash();
-        let executed_block = self
-            .execute_block(parent_hash, executable_block)
-            .await
-            .wrap_err("failed to execute block")?;
+        let sequencer_block_hash = executable_block.hash;
+        let (executed_block, execution) = if let Some(block) =
+            self.take_optimistic_block(&executable_block, &parent_hash)
+        {
+            debug!(
+                block_number,
+                "block was already executed optimistically; updating state but not re-executing it"
+            );
+            (block, Execution::Optimistic)
+        } else {
+            let block = self
+                .execute_block(parent_hash, executable_block)
+                .await
+                .wrap_err("failed to execute block")?;
+            (block, Execution::Executed)
+        };
 
         self.does_block_response_fulfill_contract(ExecutionKind::Soft, &executed_block)
+            .classify(ErrorClass::ConsensusFatal)
             .wrap_err("execution API server violated contract")?;
 
+        let receipt = self.make_receipt(
+            &executed_block,
+            block_height,
+            &sequencer_block_hash,
+            Commitment::Soft,
+            execution,
+        );
         self.update_commitment_state(Update::OnlySoft(executed_block.clone()))
             .await
             .wrap_err("failed to update soft commitment state")?;
+        self.send_receipt(receipt).await;
 
         self.blocks_pending_finalization
             .insert(block_number, executed_block);
@@ -460,6 +555,85 @@ impl Executor {
         Ok(())
     }
 
+    /// Executes a block proposed to Sequencer on top of the soft commitment without updating the
+    /// commitment state.
+    ///
+    /// The executed block is kept until the committed block at its height is received in
+    /// [`Executor::execute_soft`], which uses it instead of executing the committed block if both
+    /// are the same.
+    #[instrument(skip_all, fields(
+        block.hash = %telemetry::display::base64(&block.block_hash()),
+        block.height = block.height().value(),
+    ))]
+    async fn execute_optimistic(&mut self, block: FilteredSequencerBlock) -> eyre::Result<()> {
+        let executable_block = ExecutableBlock::from_sequencer(block, self.state.rollup_id());
+
+        let expected_height = self.state.next_expected_soft_sequencer_height();
+        if executable_block.height != expected_height {
+            debug!(
+                expected_height.sequencer_block = %expected_height,
+                "optimistic block is not at the next expected soft height; dropping",
+            );
+            return Ok(());
+        }
+
+        let parent_hash = self.state.soft_hash();
+        if self
+            .optimistic_block
+            .as_ref()
+            .is_some_and(|optimistic| optimistic.is_same_as(&executable_block, &parent_hash))
+        {
+            debug!("optimistic block was already executed; dropping");
+            return Ok(());
+        }
+
+        let sequencer_block_hash = executable_block.hash;
+        let executed_block = self
+            .execute_block(parent_hash.clone(), executable_block)
+            .await
+            .wrap_err("failed to execute block")?;
+
+        self.does_block_response_fulfill_contract(ExecutionKind::Soft, &executed_block)
+            .classify(ErrorClass::ConsensusFatal)
+            .wrap_err("execution API server violated contract")?;
+
+        self.metrics.increment_optimistic_blocks_executed_count();
+        self.optimistic_block = Some(OptimisticBlock {
+            sequencer_height: expected_height,
+            sequencer_block_hash,
+            parent_hash,
+            executed_block,
+        });
+
+        Ok(())
+    }
+
+    /// Takes the optimistically executed block if it was executed from `block` on top of
+    /// `parent_hash`.
+    ///
+    /// An optimistically executed block at the same height as `block` that does not match it is
+    /// discarded. The rollup's commitment state never pointed to it, so it is rolled back by
+    /// executing `block` on top of the same parent instead.
+    fn take_optimistic_block(
+        &mut self,
+        block: &ExecutableBlock,
+        parent_hash: &Bytes,
+    ) -> Option<Block> {
+        let optimistic = self.optimistic_block.take()?;
+        if optimistic.is_same_as(block, parent_hash) {
+            return Some(optimistic.executed_block);
+        }
+        if optimistic.sequencer_height == block.height {
+            info!(
+                optimistic_block.hash =
+                    %telemetry::display::base64(&optimistic.sequencer_block_hash),
+                "optimistically executed block differs from committed block; discarding it",
+            );
+            self.metrics.increment_optimistic_blocks_discarded_count();
+        }
+        None
+    }
+
     #[instrument(skip_all, fields(
         block.hash = %telemetry::display::base64(&block.block_hash),
         block.height = block.sequencer_height().value(),
@@ -486,21 +660,29 @@ impl Executor {
             )
         };
 
-        let update = if self.should_execute_firm_block() {
+        let sequencer_block_hash = executable_block.hash;
+        let (update, execution) = if self.should_execute_firm_block() {
             let parent_hash = self.state.firm_hash();
             let executed_block = self
                 .execute_block(parent_hash, executable_block)
                 .await
                 .wrap_err("failed to execute block")?;
             self.does_block_response_fulfill_contract(ExecutionKind::Firm, &executed_block)
+                .classify(ErrorClass::ConsensusFatal)
                 .wrap_err("execution API server violated contract")?;
-            Update::ToSame(executed_block, celestia_height)
+            (
+                Update::ToSame(executed_block, celestia_height),
+                Execution::Executed,
+            )
         } else if let Some(block) = self.blocks_pending_finalization.remove(&block_number) {
             debug!(
                 block_number,
                 "found pending block in cache; updating state but not not re-executing it"
             );
-            Update::OnlyFirm(block, celestia_height)
+            (
+                Update::OnlyFirm(block, celestia_height),
+                Execution::PreviouslySoft,
+            )
         } else {
             debug!(
                 block_number,
@@ -508,7 +690,10 @@ impl Executor {
                  Trying to fetch the already-executed block from the rollup before giving up."
             );
             match self.client.get_block_with_retry(block_number).await {
-                Ok(block) => Update::OnlyFirm(block, celestia_height),
+                Ok(block) => (
+                    Update::OnlyFirm(block, celestia_height),
+                    Execution::PreviouslySoft,
+                ),
                 Err(error) => {
                     error!(
                         block_number,
@@ -523,9 +708,19 @@ impl Executor {
             }
         };
 
+        let receipt = self.make_receipt(
+            update.block(),
+            block_height,
+            &sequencer_block_hash,
+            Commitment::Firm {
+                celestia_height,
+            },
+            execution,
+        );
         self.update_commitment_state(update)
             .await
             .wrap_err("failed to setting both commitment states to executed block")?;
+        self.send_receipt(receipt).await;
 
         // XXX: We set an absolute number value here to avoid any potential issues of the remote
         // rollup state and the local state falling out of lock-step.
@@ -535,6 +730,33 @@ impl Executor {
         Ok(())
     }
 
+    /// Returns a receipt of committing `executed_block`, or `None` if receipts are disabled.
+    fn make_receipt(
+        &self,
+        executed_block: &Block,
+        sequencer_height: SequencerHeight,
+        sequencer_block_hash: &[u8; 32],
+        commitment: Commitment,
+        execution: Execution,
+    ) -> Option<Receipt> {
+        self.receipts.as_ref()?;
+        Some(Receipt::new(
+            self.state.rollup_id(),
+            executed_block,
+            sequencer_height,
+            sequencer_block_hash,
+            commitment,
+            execution,
+        ))
+    }
+
+    /// Sends `receipt` to the receipt writer once its block was committed.
+    async fn send_receipt(&self, receipt: Option<Receipt>) {
+        if let (Some(receipts), Some(receipt)) = (&self.receipts, receipt) {
+            receipts.send(receipt).await;
+        }
+    }
+
     /// Executes `block` on top of its `parent_hash`.
     ///
     /// This function is called via [`Executor::execute_firm`] or [`Executor::execute_soft`],
@@ -556,12 +778,14 @@ impl Executor {
             ..
         } = block;
 
+        let transactions = self.filter_transactions(transactions);
         let n_transactions = transactions.len();
 
         let executed_block = self
             .client
             .execute_block_with_retry(parent_hash, transactions, timestamp)
             .await
+            .classify(ErrorClass::Network)
             .wrap_err("failed to run execute_block RPC")?;
 
         self.metrics
@@ -576,6 +800,22 @@ impl Executor {
         Ok(executed_block)
     }
 
+    /// Removes the transactions rejected by the block filter, if one is configured.
+    fn filter_transactions(&self, transactions: Vec<Vec<u8>>) -> Vec<Vec<u8>> {
+        let Some(block_filter) = &self.block_filter else {
+            return transactions;
+        };
+        let (transactions, removed) = block_filter.current().apply(transactions);
+        if removed > 0 {
+            debug!(
+                removed,
+                "removed rollup data rejected by the block filter before execution"
+            );
+            self.metrics.increment_rollup_data_filtered_count(removed);
+        }
+        transactions
+    }
+
     #[instrument(skip_all)]
     async fn set_initial_node_state(&mut self) -> eyre::Result<()> {
         let genesis_info = {
@@ -639,6 +879,7 @@ impl Executor {
             .client
             .update_commitment_state_with_retry(commitment_state)
             .await
+            .classify(ErrorClass::Network)
             .wrap_err("failed updating remote commitment state")?;
         info!(
             soft.number = new_state.soft().number(),
@@ -681,6 +922,34 @@ enum Update {
     ToSame(Block, CelestiaHeight),
 }
 
+impl Update {
+    /// Returns the block the commitment state is updated to.
+    fn block(&self) -> &Block {
+        match self {
+            Self::OnlyFirm(block, _) | Self::OnlySoft(block) | Self::ToSame(block, _) => block,
+        }
+    }
+}
+
+/// A block proposed to Sequencer that was executed before it was committed.
+#[derive(Debug)]
+struct OptimisticBlock {
+    sequencer_height: SequencerHeight,
+    sequencer_block_hash: [u8; 32],
+    parent_hash: Bytes,
+    executed_block: Block,
+}
+
+impl OptimisticBlock {
+    /// Returns whether this was executed from the same Sequencer block as `block`, on top of
+    /// `parent_hash`.
+    fn is_same_as(&self, block: &ExecutableBlock, parent_hash: &Bytes) -> bool {
+        self.sequencer_height == block.height
+            && self.sequencer_block_hash == block.hash
+            && self.parent_hash == *parent_hash
+    }
+}
+
 #[derive(Debug)]
 struct ExecutableBlock {
     hash: [u8; 32],
@@ -16,7 +16,10 @@ use super::{
         StateReceiver,
         StateSender,
     },
+    ExecutableBlock,
+    OptimisticBlock,
     RollupId,
+    SequencerHeight,
 };
 use crate::config::CommitLevel;
 
@@ -206,3 +209,37 @@ fn should_execute_firm() {
          don't match"
     );
 }
+
+#[test]
+fn optimistic_block_only_matches_same_block_on_same_parent() {
+    let parent_hash = Bytes::from_static(&[1u8; 32]);
+    let optimistic = OptimisticBlock {
+        sequencer_height: SequencerHeight::from(2u32),
+        sequencer_block_hash: [2u8; 32],
+        parent_hash: parent_hash.clone(),
+        executed_block: Block::try_from_raw(make_block(3)).unwrap(),
+    };
+    let committed = |height: u32, hash: [u8; 32]| ExecutableBlock {
+        hash,
+        height: SequencerHeight::from(height),
+        timestamp: pbjson_types::Timestamp::default(),
+        transactions: vec![],
+    };
+
+    assert!(optimistic.is_same_as(&committed(2, [2u8; 32]), &parent_hash));
+    assert!(
+        !optimistic.is_same_as(&committed(2, [3u8; 32]), &parent_hash),
+        "a different block committed at the same height must not match"
+    );
+    assert!(
+        !optimistic.is_same_as(&committed(3, [2u8; 32]), &parent_hash),
+        "a block at a different height must not match"
+    );
+    assert!(
+        !optimistic.is_same_as(
+            &committed(2, [2u8; 32]),
+            &Bytes::from_static(&[4u8; 32])
+        ),
+        "a block executed on top of a different parent must not match"
+    );
+}
@@ -9,12 +9,14 @@
 //! execution layer until it's received from the data availability layer. In the second case, the
 //! execution layer is notified to mark the block as finalized.
 pub(crate) mod block_cache;
+pub(crate) mod block_filter;
 mod build_info;
 pub(crate) mod celestia;
 pub mod conductor;
 pub mod config;
 pub(crate) mod executor;
 pub(crate) mod metrics;
+pub(crate) mod receipts;
 pub(crate) mod sequencer;
 mod utils;
 
@@ -5,7 +5,10 @@ use astria_conductor::{
     Config,
     BUILD_INFO,
 };
-use astria_eyre::eyre::WrapErr as _;
+use astria_eyre::{
+    eyre::WrapErr as _,
+    ErrorClass,
+};
 use tokio::{
     select,
     signal::unix::{
@@ -19,10 +22,6 @@ use tracing::{
     warn,
 };
 
-// Following the BSD convention for failing to read config
-// See here: https://freedesktop.org/software/systemd/man/systemd.exec.html#Process%20Exit%20Codes
-const EX_CONFIG: u8 = 78;
-
 #[tokio::main]
 async fn main() -> ExitCode {
     astria_eyre::install().expect("astria eyre hook must be the first hook installed");
@@ -36,10 +35,7 @@ async fn main() -> ExitCode {
     let cfg: Config = match config::get().wrap_err("failed reading config") {
         Err(e) => {
             eprintln!("failed to start conductor:\n{e}");
-            // FIXME (https://github.com/astriaorg/astria/issues/368):
-            //       might have to bubble up exit codes, since we might need
-            //       to exit with other exit codes if something else fails
-            return ExitCode::from(EX_CONFIG);
+            return ExitCode::from(ErrorClass::Config.exit_code());
         }
         Ok(cfg) => cfg,
     };
@@ -75,7 +71,7 @@ async fn main() -> ExitCode {
     let conductor = match Conductor::new(cfg) {
         Err(error) => {
             error!(%error, "failed initializing conductor");
-            return ExitCode::FAILURE;
+            return astria_eyre::exit_code(&error);
         }
         Ok(conductor) => conductor,
     };
@@ -95,11 +91,20 @@ async fn main() -> ExitCode {
         }
 
         res = &mut handle => {
-            error!(
-                error = res.err().map(tracing::field::display),
-                "conductor task exited unexpectedly",
-            );
-            ExitCode::FAILURE
+            match res {
+                Ok(Ok(())) => {
+                    error!("conductor task exited unexpectedly");
+                    ExitCode::FAILURE
+                }
+                Ok(Err(error)) => {
+                    error!(%error, "conductor task exited unexpectedly");
+                    astria_eyre::exit_code(&error)
+                }
+                Err(error) => {
+                    error!(%error, "conductor task panicked");
+                    ExitCode::FAILURE
+                }
+            }
         }
     )
 }
@@ -24,6 +24,9 @@ pub(crate) struct Metrics {
     executed_firm_block_number: Counter,
     executed_soft_block_number: Counter,
     transactions_per_executed_block: Histogram,
+    optimistic_blocks_executed_count: Counter,
+    optimistic_blocks_discarded_count: Counter,
+    rollup_data_filtered_count: Counter,
 }
 
 impl Metrics {
@@ -104,6 +107,30 @@ impl Metrics {
         );
         let transactions_per_executed_block = histogram!(TRANSACTIONS_PER_EXECUTED_BLOCK);
 
+        describe_counter!(
+            OPTIMISTIC_BLOCKS_EXECUTED_COUNT,
+            Unit::Count,
+            "The number of blocks executed optimistically from sequencer proposals before they \
+             were committed"
+        );
+        let optimistic_blocks_executed_count = counter!(OPTIMISTIC_BLOCKS_EXECUTED_COUNT);
+
+        describe_counter!(
+            OPTIMISTIC_BLOCKS_DISCARDED_COUNT,
+            Unit::Count,
+            "The number of optimistically executed blocks that were discarded because a different \
+             block was committed at their height"
+        );
+        let optimistic_blocks_discarded_count = counter!(OPTIMISTIC_BLOCKS_DISCARDED_COUNT);
+
+        describe_counter!(
+            ROLLUP_DATA_FILTERED_COUNT,
+            Unit::Count,
+            "The number of rollup data items removed from blocks by the block filter before \
+             execution"
+        );
+        let rollup_data_filtered_count = counter!(ROLLUP_DATA_FILTERED_COUNT);
+
         Self {
             metadata_blobs_per_celestia_fetch,
             rollup_data_blobs_per_celestia_fetch,
@@ -115,6 +142,9 @@ impl Metrics {
             executed_firm_block_number,
             executed_soft_block_number,
             transactions_per_executed_block,
+            optimistic_blocks_executed_count,
+            optimistic_blocks_discarded_count,
+            rollup_data_filtered_count,
         }
     }
 
@@ -185,6 +215,19 @@ impl Metrics {
         #[allow(clippy::cast_precision_loss)]
         self.transactions_per_executed_block.record(tx_count as f64);
     }
+
+    pub(crate) fn increment_optimistic_blocks_executed_count(&self) {
+        self.optimistic_blocks_executed_count.increment(1);
+    }
+
+    pub(crate) fn increment_optimistic_blocks_discarded_count(&self) {
+        self.optimistic_blocks_discarded_count.increment(1);
+    }
+
+    pub(crate) fn increment_rollup_data_filtered_count(&self, count: usize) {
+        self.rollup_data_filtered_count
+            .increment(u64::try_from(count).unwrap_or(u64::MAX));
+    }
 }
 
 metric_names!(pub const METRICS_NAMES:
@@ -196,7 +239,12 @@ metric_names!(pub const METRICS_NAMES:
 
     EXECUTED_FIRM_BLOCK_NUMBER,
     EXECUTED_SOFT_BLOCK_NUMBER,
-    TRANSACTIONS_PER_EXECUTED_BLOCK
+    TRANSACTIONS_PER_EXECUTED_BLOCK,
+
+    OPTIMISTIC_BLOCKS_EXECUTED_COUNT,
+    OPTIMISTIC_BLOCKS_DISCARDED_COUNT,
+
+    ROLLUP_DATA_FILTERED_COUNT
 );
 
 #[cfg(test)]
@@ -208,6 +256,9 @@ mod tests {
         DECODED_ITEMS_PER_CELESTIA_FETCH,
         EXECUTED_FIRM_BLOCK_NUMBER,
         EXECUTED_SOFT_BLOCK_NUMBER,
+        OPTIMISTIC_BLOCKS_DISCARDED_COUNT,
+        OPTIMISTIC_BLOCKS_EXECUTED_COUNT,
+        ROLLUP_DATA_FILTERED_COUNT,
         SEQUENCER_BLOCKS_METADATA_VERIFIED_PER_CELESTIA_FETCH,
         SEQUENCER_BLOCK_INFORMATION_RECONSTRUCTED_PER_CELESTIA_FETCH,
     };
@@ -247,5 +298,14 @@ mod tests {
             TRANSACTIONS_PER_EXECUTED_BLOCK,
             "transactions_per_executed_block",
         );
+        assert_const(
+            OPTIMISTIC_BLOCKS_EXECUTED_COUNT,
+            "optimistic_blocks_executed_count",
+        );
+        assert_const(
+            OPTIMISTIC_BLOCKS_DISCARDED_COUNT,
+            "optimistic_blocks_discarded_count",
+        );
+        assert_const(ROLLUP_DATA_FILTERED_COUNT, "rollup_data_filtered_count");
     }
 }
@@ -0,0 +1,389 @@
+//! Signed receipts of the blocks executed by conductor.
+//!
+//! After a block is committed to the rollup, the executor sends a [`Receipt`] of it to the
+//! [`Writer`], which signs it and writes it to disk. If configured, the signed receipt is also
+//! posted to an HTTP endpoint. Receipts give rollup operators an audit trail of which blocks
+//! conductor executed, at which commitment level, and how they came to be executed.
+
+use std::{
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+    time::Duration,
+};
+
+use astria_core::{
+    crypto::SigningKey,
+    execution::v1alpha2::Block,
+    primitive::v1::RollupId,
+};
+use astria_eyre::eyre::{
+    self,
+    eyre,
+    WrapErr as _,
+};
+use base64::{
+    engine::general_purpose::STANDARD,
+    Engine as _,
+};
+use sequencer_client::tendermint::block::Height as SequencerHeight;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use tokio::{
+    select,
+    sync::mpsc,
+};
+use tokio_util::sync::CancellationToken;
+use tracing::{
+    error,
+    info,
+    instrument,
+    warn,
+};
+
+/// The number of receipts that can be queued for the writer before the executor has to wait.
+const RECEIPT_CHANNEL_CAPACITY: usize = 1024;
+
+/// The timeout for posting a single receipt to the configured HTTP endpoint.
+const POST_TIMEOUT: Duration = Duration::from_secs(5);
+
+pub(crate) struct Builder {
+    pub(crate) signing_key_path: String,
+    pub(crate) receipts_dir: String,
+    pub(crate) post_url: String,
+    pub(crate) shutdown: CancellationToken,
+}
+
+impl Builder {
+    pub(crate) fn build(self) -> eyre::Result<(Writer, Handle)> {
+        let Self {
+            signing_key_path,
+            receipts_dir,
+            post_url,
+            shutdown,
+        } = self;
+
+        let signing_key = read_signing_key_from_file(&signing_key_path).wrap_err_with(|| {
+            format!("failed reading receipt signing key from file at path `{signing_key_path}`")
+        })?;
+
+        let receipts_dir = PathBuf::from(receipts_dir);
+        fs::create_dir_all(&receipts_dir).wrap_err_with(|| {
+            format!(
+                "failed creating receipts directory at `{}`",
+                receipts_dir.display()
+            )
+        })?;
+
+        let post = if post_url.is_empty() {
+            None
+        } else {
+            let url = post_url
+                .parse::<reqwest::Url>()
+                .wrap_err_with(|| format!("failed parsing receipt post URL `{post_url}`"))?;
+            let client = reqwest::Client::builder()
+                .timeout(POST_TIMEOUT)
+                .build()
+                .wrap_err("failed constructing HTTP client to post receipts")?;
+            Some((client, url))
+        };
+
+        let (tx, rx) = mpsc::channel(RECEIPT_CHANNEL_CAPACITY);
+        let writer = Writer {
+            signing_key,
+            receipts_dir,
+            post,
+            receipts: rx,
+            shutdown,
+        };
+        let handle = Handle {
+            receipts: tx,
+        };
+        Ok((writer, handle))
+    }
+}
+
+/// A handle to send receipts to the [`Writer`].
+#[derive(Clone, Debug)]
+pub(crate) struct Handle {
+    receipts: mpsc::Sender<Receipt>,
+}
+
+impl Handle {
+    /// Sends `receipt` to the writer, waiting if its queue is full.
+    ///
+    /// Failing to send a receipt does not affect execution, so it is only logged.
+    pub(crate) async fn send(&self, receipt: Receipt) {
+        if self.receipts.send(receipt).await.is_err() {
+            warn!("receipt writer has shut down; dropping execution receipt");
+        }
+    }
+}
+
+/// Signs the receipts of executed blocks, writes them to disk, and optionally posts them to an
+/// HTTP endpoint.
+pub(crate) struct Writer {
+    signing_key: SigningKey,
+    receipts_dir: PathBuf,
+    post: Option<(reqwest::Client, reqwest::Url)>,
+    receipts: mpsc::Receiver<Receipt>,
+    shutdown: CancellationToken,
+}
+
+impl Writer {
+    #[instrument(skip_all)]
+    pub(crate) async fn run_until_stopped(mut self) -> eyre::Result<()> {
+        info!(
+            verification_key = %STANDARD.encode(self.signing_key.verification_key().as_bytes()),
+            receipts_dir = %self.receipts_dir.display(),
+            post_url = self.post.as_ref().map(|(_, url)| url.as_str()),
+            "writing signed execution receipts",
+        );
+        loop {
+            select!(
+                biased;
+
+                () = self.shutdown.cancelled() => break,
+
+                Some(receipt) = self.receipts.recv() => {
+                    let signed = SignedReceipt::sign(receipt, &self.signing_key);
+                    self.write(&signed).await;
+                    self.post(&signed).await;
+                }
+            );
+        }
+
+        // Receipts of blocks that were committed before shutdown are still written to disk so
+        // that the audit trail is complete, but they are no longer posted.
+        self.receipts.close();
+        while let Some(receipt) = self.receipts.recv().await {
+            let signed = SignedReceipt::sign(receipt, &self.signing_key);
+            self.write(&signed).await;
+        }
+        info!("receipt writer shut down");
+        Ok(())
+    }
+
+    /// Writes `receipt` to a file in the receipts directory, replacing any receipt of the same
+    /// rollup block and commitment level.
+    async fn write(&self, receipt: &SignedReceipt) {
+        let path = self.receipts_dir.join(receipt.file_name());
+        if let Err(error) = write_atomically(&path, receipt).await {
+            error!(
+                path = %path.display(),
+                %error,
+                "failed writing execution receipt to disk",
+            );
+        }
+    }
+
+    /// Posts `receipt` as JSON to the configured HTTP endpoint, if any.
+    async fn post(&self, receipt: &SignedReceipt) {
+        let Some((client, url)) = &self.post else {
+            return;
+        };
+        let result = client
+            .post(url.clone())
+            .json(receipt)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status);
+        if let Err(error) = result {
+            warn!(
+                rollup_block_number = receipt.receipt.rollup_block_number,
+                error = &error as &dyn std::error::Error,
+                "failed posting execution receipt",
+            );
+        }
+    }
+}
+
+/// The commitment level at which a block was committed to the rollup.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "level", rename_all = "snake_case")]
+pub(crate) enum Commitment {
+    /// The block was committed by the Sequencer network.
+    Soft,
+    /// The block was read from Celestia and is final.
+    Firm { celestia_height: u64 },
+}
+
+impl Commitment {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Soft => "soft",
+            Self::Firm {
+                ..
+            } => "firm",
+        }
+    }
+}
+
+/// How the committed block came to be executed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Execution {
+    /// The block was executed when it was received at this commitment level.
+    Executed,
+    /// The block was executed optimistically before it was committed by the Sequencer network.
+    Optimistic,
+    /// The block was executed earlier as a soft commitment and only marked as firm.
+    PreviouslySoft,
+}
+
+/// A record of a block committed to the rollup.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) struct Receipt {
+    /// The base64 encoded ID of the rollup.
+    rollup_id: String,
+    rollup_block_number: u32,
+    /// The base64 encoded hash of the rollup block.
+    rollup_block_hash: String,
+    sequencer_height: u64,
+    /// The base64 encoded hash of the Sequencer block the rollup block was executed from.
+    sequencer_block_hash: String,
+    commitment: Commitment,
+    execution: Execution,
+}
+
+impl Receipt {
+    pub(crate) fn new(
+        rollup_id: RollupId,
+        executed_block: &Block,
+        sequencer_height: SequencerHeight,
+        sequencer_block_hash: &[u8; 32],
+        commitment: Commitment,
+        execution: Execution,
+    ) -> Self {
+        Self {
+            rollup_id: rollup_id.to_string(),
+            rollup_block_number: executed_block.number(),
+            rollup_block_hash: STANDARD.encode(executed_block.hash()),
+            sequencer_height: sequencer_height.value(),
+            sequencer_block_hash: STANDARD.encode(sequencer_block_hash),
+            commitment,
+            execution,
+        }
+    }
+
+    /// The bytes covered by the receipt's signature: its compact JSON encoding, with fields in
+    /// the order they are declared.
+    fn to_signing_bytes(&self) -> Vec<u8> {
+        serde_json::to_vec(self).expect("a receipt is always serializable to JSON")
+    }
+}
+
+/// A [`Receipt`] signed by conductor's receipt signing key.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct SignedReceipt {
+    receipt: Receipt,
+    /// The base64 encoded ed25519 verification key of the signer.
+    verification_key: String,
+    /// The base64 encoded ed25519 signature over the compact JSON encoding of `receipt`.
+    signature: String,
+}
+
+impl SignedReceipt {
+    fn sign(receipt: Receipt, signing_key: &SigningKey) -> Self {
+        let signature = signing_key.sign(&receipt.to_signing_bytes());
+        Self {
+            receipt,
+            verification_key: STANDARD.encode(signing_key.verification_key().as_bytes()),
+            signature: STANDARD.encode(signature.to_bytes()),
+        }
+    }
+
+    fn file_name(&self) -> String {
+        format!(
+            "{}-{}.json",
+            self.receipt.rollup_block_number,
+            self.receipt.commitment.as_str(),
+        )
+    }
+}
+
+/// Writes `receipt` to a temporary file next to `path` and moves it to `path`, so that readers
+/// never observe a partially written receipt.
+async fn write_atomically(path: &Path, receipt: &SignedReceipt) -> eyre::Result<()> {
+    let contents =
+        serde_json::to_vec_pretty(receipt).wrap_err("failed serializing receipt to JSON")?;
+    let tmp_path = path.with_extension("json.tmp");
+    tokio::fs::write(&tmp_path, contents)
+        .await
+        .wrap_err("failed writing receipt to temporary file")?;
+    tokio::fs::rename(&tmp_path, path)
+        .await
+        .wrap_err("failed moving temporary file to receipt path")
+}
+
+fn read_signing_key_from_file<P: AsRef<Path>>(path: P) -> eyre::Result<SigningKey> {
+    let private_key_hex = fs::read_to_string(path)?;
+    let private_key_bytes: [u8; 32] = hex::decode(private_key_hex.trim())?
+        .try_into()
+        .map_err(|_| eyre!("invalid private key length; must be 32 bytes"))?;
+    Ok(SigningKey::from(private_key_bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::crypto::{
+        Signature,
+        VerificationKey,
+    };
+
+    use super::*;
+
+    /// Verifies that the receipt's signature was made by its verification key over the receipt.
+    fn verify(signed: &SignedReceipt) -> eyre::Result<()> {
+        let verification_key_bytes = STANDARD
+            .decode(&signed.verification_key)
+            .wrap_err("verification key is not valid base64")?;
+        let verification_key = VerificationKey::try_from(&*verification_key_bytes)
+            .wrap_err("verification key is not a valid ed25519 key")?;
+        let signature_bytes = STANDARD
+            .decode(&signed.signature)
+            .wrap_err("signature is not valid base64")?;
+        let signature = Signature::try_from(&*signature_bytes)
+            .wrap_err("signature is not a valid ed25519 signature")?;
+        verification_key
+            .verify(&signature, &signed.receipt.to_signing_bytes())
+            .wrap_err("signature does not match receipt")
+    }
+
+    fn receipt() -> Receipt {
+        Receipt {
+            rollup_id: RollupId::new([1; 32]).to_string(),
+            rollup_block_number: 5,
+            rollup_block_hash: STANDARD.encode([2; 32]),
+            sequencer_height: 10,
+            sequencer_block_hash: STANDARD.encode([3; 32]),
+            commitment: Commitment::Firm {
+                celestia_height: 3,
+            },
+            execution: Execution::PreviouslySoft,
+        }
+    }
+
+    #[test]
+    fn signed_receipt_verifies_after_json_roundtrip() {
+        let signing_key = SigningKey::from([7; 32]);
+        let signed = SignedReceipt::sign(receipt(), &signing_key);
+        let json = serde_json::to_string_pretty(&signed).unwrap();
+        let decoded: SignedReceipt = serde_json::from_str(&json).unwrap();
+        verify(&decoded).unwrap();
+        assert_eq!(decoded.receipt, receipt());
+        assert_eq!(decoded.file_name(), "5-firm.json");
+    }
+
+    #[test]
+    fn tampered_receipt_fails_verification() {
+        let signing_key = SigningKey::from([7; 32]);
+        let mut signed = SignedReceipt::sign(receipt(), &signing_key);
+        signed.receipt.execution = Execution::Executed;
+        assert!(verify(&signed).is_err());
+    }
+}
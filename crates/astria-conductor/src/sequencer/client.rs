@@ -1,11 +1,18 @@
-//! A thin wrapper around a [`SequencerServiceClient`].
+//! A thin wrapper around a [`SequencerServiceClient`] and an [`OptimisticBlockServiceClient`].
 
 use std::time::Duration;
 
 use astria_core::{
-    generated::sequencerblock::v1alpha1::{
-        sequencer_service_client::SequencerServiceClient,
-        GetFilteredSequencerBlockRequest,
+    generated::sequencerblock::{
+        optimistic::v1alpha1::{
+            optimistic_block_service_client::OptimisticBlockServiceClient,
+            StreamOptimisticBlockRequest,
+            StreamOptimisticBlockResponse,
+        },
+        v1alpha1::{
+            sequencer_service_client::SequencerServiceClient,
+            GetFilteredSequencerBlockRequest,
+        },
     },
     primitive::v1::RollupId,
     sequencerblock::v1alpha1::block::FilteredSequencerBlock,
@@ -14,10 +21,13 @@ use astria_eyre::eyre::{
     self,
     WrapErr as _,
 };
-use tonic::transport::{
-    Channel,
-    Endpoint,
-    Uri,
+use tonic::{
+    codec::Streaming,
+    transport::{
+        Channel,
+        Endpoint,
+        Uri,
+    },
 };
 use tracing::{
     debug,
@@ -29,6 +39,7 @@ use tracing::{
 #[derive(Clone)]
 pub(crate) struct SequencerGrpcClient {
     inner: SequencerServiceClient<Channel>,
+    optimistic: OptimisticBlockServiceClient<Channel>,
     uri: Uri,
 }
 
@@ -38,14 +49,37 @@ impl SequencerGrpcClient {
         let uri: Uri = sequencer_uri
             .parse()
             .wrap_err("failed parsing provided string as Uri")?;
-        let endpoint = Endpoint::from(uri.clone());
-        let inner = SequencerServiceClient::new(endpoint.connect_lazy());
+        let channel = Endpoint::from(uri.clone()).connect_lazy();
+        let inner = SequencerServiceClient::new(channel.clone());
+        let optimistic = OptimisticBlockServiceClient::new(channel);
         Ok(Self {
             inner,
+            optimistic,
             uri,
         })
     }
 
+    /// Opens a stream of the blocks proposed to the sequencer network, filtered by `rollup_id`.
+    ///
+    /// Unlike [`SequencerGrpcClient::get`] this does not retry, as a failed stream is reopened by
+    /// its consumer.
+    #[instrument(skip_all, fields(uri = %self.uri, %rollup_id))]
+    pub(super) async fn stream_optimistic_blocks(
+        &mut self,
+        rollup_id: RollupId,
+    ) -> eyre::Result<Streaming<StreamOptimisticBlockResponse>> {
+        let req = StreamOptimisticBlockRequest {
+            rollup_id: Some(rollup_id.to_raw()),
+        };
+        let stream = self
+            .optimistic
+            .stream_optimistic_block(req)
+            .await
+            .wrap_err("failed opening stream of optimistic blocks")?
+            .into_inner();
+        Ok(stream)
+    }
+
     /// Fetch a sequencer block filtered by `rollup_id`.
     ///
     /// This method includes retry logic with a maximum delay
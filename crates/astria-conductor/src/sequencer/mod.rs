@@ -15,6 +15,7 @@ use futures::{
         BoxFuture,
         Fuse,
     },
+    stream::BoxStream,
     FutureExt as _,
     StreamExt as _,
 };
@@ -24,7 +25,10 @@ use sequencer_client::{
     LatestHeightStream,
     StreamLatestHeight as _,
 };
-use tokio::select;
+use tokio::{
+    select,
+    sync::mpsc,
+};
 use tokio_util::sync::CancellationToken;
 use tracing::{
     debug,
@@ -38,6 +42,7 @@ use crate::{
     block_cache::BlockCache,
     executor::{
         self,
+        OptimisticTrySendError,
         SoftSendError,
         SoftTrySendError,
         StateIsInit,
@@ -48,6 +53,7 @@ use crate::{
 mod block_stream;
 mod builder;
 mod client;
+mod optimistic_block_stream;
 mod reporting;
 pub(crate) use builder::Builder;
 pub(crate) use client::SequencerGrpcClient;
@@ -74,6 +80,10 @@ pub(crate) struct Reader {
     /// height.
     sequencer_block_time: Duration,
 
+    /// Whether to stream the blocks proposed to the Sequencer network and forward them to the
+    /// executor for optimistic execution.
+    execute_optimistic_blocks: bool,
+
     /// Token to listen for Conductor being shut down.
     shutdown: CancellationToken,
 }
@@ -123,6 +133,10 @@ struct RunningReader {
     /// the latest observed sequencer height (as obtained from the `latest_height_stream`) field.
     blocks_from_heights: BlocksFromHeightStream,
 
+    /// A stream of the blocks proposed to the Sequencer network before they are committed.
+    /// Only set if optimistic execution is enabled.
+    optimistic_blocks: Option<BoxStream<'static, FilteredSequencerBlock>>,
+
     /// An enqueued block waiting for executor to free up. Set if the executor exhibits
     /// backpressure.
     enqueued_block: Fuse<BoxFuture<'static, Result<(), SoftSendError>>>,
@@ -140,6 +154,7 @@ impl RunningReader {
             sequencer_grpc_client,
             sequencer_cometbft_client,
             sequencer_block_time,
+            execute_optimistic_blocks,
             shutdown,
             ..
         } = reader;
@@ -152,6 +167,13 @@ impl RunningReader {
         let block_cache = BlockCache::with_next_height(next_expected_height)
             .wrap_err("failed constructing sequential block cache")?;
 
+        let optimistic_blocks = execute_optimistic_blocks.then(|| {
+            optimistic_block_stream::optimistic_blocks(
+                sequencer_grpc_client.clone(),
+                executor.rollup_id(),
+            )
+        });
+
         let blocks_from_heights = BlocksFromHeightStream::new(
             executor.rollup_id(),
             next_expected_height,
@@ -164,6 +186,7 @@ impl RunningReader {
             block_cache,
             latest_height_stream,
             blocks_from_heights,
+            optimistic_blocks,
             enqueued_block,
             shutdown,
         })
@@ -213,6 +236,13 @@ impl RunningReader {
                     self.send_to_executor(block)?;
                 }
 
+                // Forward blocks proposed to Sequencer to the executor for optimistic execution.
+                Some(block) = async { self.optimistic_blocks.as_mut().unwrap().next().await },
+                              if self.optimistic_blocks.is_some() =>
+                {
+                    self.send_optimistic_to_executor(block)?;
+                }
+
                 // Pull a block from the stream and put it in the block cache.
                 Some(block) = self.blocks_from_heights.next() => {
                     // XXX: blocks_from_heights stream uses self::client::SequencerGrpcClient::get, which has
@@ -282,6 +312,42 @@ impl RunningReader {
         Ok(())
     }
 
+    /// Sends the optimistic `block` to the executor task.
+    ///
+    /// Drops the block if the channel to the executor is full, as optimistic blocks are not
+    /// required for the executor to make progress.
+    fn send_optimistic_to_executor(&mut self, block: FilteredSequencerBlock) -> eyre::Result<()> {
+        if let Err(err) = self.executor.try_send_optimistic_block(block) {
+            match err {
+                OptimisticTrySendError::Channel {
+                    source,
+                } => match *source {
+                    mpsc::error::TrySendError::Closed(_) => {
+                        bail!(
+                            "could not send optimistic block to executor because its channel was \
+                             closed"
+                        );
+                    }
+
+                    mpsc::error::TrySendError::Full(block) => {
+                        debug!(
+                            block.height = %block.height(),
+                            "executor channel for optimistic blocks is full; dropping block",
+                        );
+                    }
+                },
+
+                OptimisticTrySendError::NotSet => {
+                    bail!(
+                        "conductor was configured without optimistic execution; the optimistic \
+                         block stream should have never been opened",
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
     /// Updates the next expected height to forward to the executor.
     ///
     /// This will all older heights from the cache and advance the stream of blocks
@@ -0,0 +1,95 @@
+//! A stream of the blocks proposed to the Sequencer network before they are committed.
+
+use std::time::Duration;
+
+use astria_core::{
+    generated::sequencerblock::optimistic::v1alpha1::StreamOptimisticBlockResponse,
+    primitive::v1::RollupId,
+    sequencerblock::v1alpha1::block::FilteredSequencerBlock,
+};
+use astria_eyre::eyre::{
+    self,
+    eyre,
+    WrapErr as _,
+};
+use futures::{
+    stream::{
+        self,
+        BoxStream,
+    },
+    StreamExt as _,
+};
+use tonic::codec::Streaming;
+use tracing::warn;
+
+use super::SequencerGrpcClient;
+
+/// The duration to wait before reopening the stream after it failed or was closed by Sequencer.
+const REOPEN_DELAY: Duration = Duration::from_secs(1);
+
+/// Returns a never ending stream of the blocks proposed to the Sequencer network, filtered by
+/// `rollup_id`.
+///
+/// The underlying gRPC stream is reopened after [`REOPEN_DELAY`] whenever it fails or ends.
+/// Optimistic blocks only serve to speed up execution, so failures are logged but not returned.
+pub(super) fn optimistic_blocks(
+    client: SequencerGrpcClient,
+    rollup_id: RollupId,
+) -> BoxStream<'static, FilteredSequencerBlock> {
+    let state = OptimisticBlocks {
+        client,
+        rollup_id,
+        stream: None,
+    };
+    stream::unfold(state, |mut state| async move {
+        let block = state.next_block().await;
+        Some((block, state))
+    })
+    .boxed()
+}
+
+struct OptimisticBlocks {
+    client: SequencerGrpcClient,
+    rollup_id: RollupId,
+    stream: Option<Streaming<StreamOptimisticBlockResponse>>,
+}
+
+impl OptimisticBlocks {
+    async fn next_block(&mut self) -> FilteredSequencerBlock {
+        loop {
+            match self.try_next_block().await {
+                Ok(block) => return block,
+                Err(error) => {
+                    warn!(
+                        %error,
+                        rollup_id = %self.rollup_id,
+                        "failed receiving optimistic block from sequencer; retrying after delay",
+                    );
+                    tokio::time::sleep(REOPEN_DELAY).await;
+                }
+            }
+        }
+    }
+
+    /// Returns the next block of the stream, opening it first if necessary.
+    ///
+    /// The stream is dropped if opening or reading from it fails, so that it is reopened on the
+    /// next call.
+    async fn try_next_block(&mut self) -> eyre::Result<FilteredSequencerBlock> {
+        let mut stream = match self.stream.take() {
+            Some(stream) => stream,
+            None => self.client.stream_optimistic_blocks(self.rollup_id).await?,
+        };
+        let response = stream
+            .message()
+            .await
+            .wrap_err("failed reading optimistic block from stream")?
+            .ok_or_else(|| eyre!("sequencer closed the stream of optimistic blocks"))?;
+        self.stream = Some(stream);
+        let raw_block = response
+            .block
+            .ok_or_else(|| eyre!("optimistic block response did not contain a block"))?;
+        FilteredSequencerBlock::try_from_raw(raw_block)
+            .wrap_err("failed validating optimistic block")
+    }
+}
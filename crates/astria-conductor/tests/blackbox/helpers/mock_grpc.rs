@@ -14,6 +14,8 @@ use astria_core::generated::{
         Block,
         CommitmentState,
         ExecuteBlockRequest,
+        ExecuteBlocksRequest,
+        ExecuteBlocksResponse,
         GenesisInfo,
         GetBlockRequest,
         GetCommitmentStateRequest,
@@ -149,6 +151,7 @@ define_and_impl_service!(impl ExecutionService for ExecutionServiceImpl {
     (get_genesis_info: GetGenesisInfoRequest => GenesisInfo)
     (batch_get_blocks: BatchGetBlocksRequest => BatchGetBlocksResponse)
     (execute_block: ExecuteBlockRequest => Block)
+    (execute_blocks: ExecuteBlocksRequest => ExecuteBlocksResponse)
     (get_commitment_state: GetCommitmentStateRequest => CommitmentState)
     (update_commitment_state: UpdateCommitmentStateRequest => CommitmentState)
 });
@@ -456,6 +456,10 @@ fn make_config() -> Config {
         celestia_block_time_ms: 12000,
         celestia_node_http_url: "http://127.0.0.1:26658".into(),
         celestia_bearer_token: CELESTIA_BEARER_TOKEN.into(),
+        celestia_trusted_height: 0,
+        celestia_trusted_hash: String::new(),
+        celestia_trusting_period_ms: 0,
+        celestia_max_bisection_steps: 0,
         sequencer_grpc_url: "http://127.0.0.1:8080".into(),
         sequencer_cometbft_url: "http://127.0.0.1:26657".into(),
         sequencer_requests_per_second: 500,
@@ -463,6 +467,12 @@ fn make_config() -> Config {
         execution_rpc_url: "http://127.0.0.1:50051".into(),
         log: "info".into(),
         execution_commit_level: astria_conductor::config::CommitLevel::SoftAndFirm,
+        execute_optimistic_blocks: false,
+        no_execution_receipts: true,
+        execution_receipts_signing_key_path: String::new(),
+        execution_receipts_dir: String::new(),
+        execution_receipts_post_url: String::new(),
+        block_filter_path: String::new(),
         force_stdout: false,
         no_otel: false,
         no_metrics: true,
@@ -0,0 +1,72 @@
+use astria_core::{
+    crypto::SigningKey,
+    generated::protocol::transaction::v1alpha1 as raw,
+    primitive::v1::{
+        asset::default_native_asset,
+        RollupId,
+    },
+    protocol::transaction::v1alpha1::{
+        action::SequenceAction,
+        SignedTransaction,
+        TransactionParams,
+        UnsignedTransaction,
+    },
+};
+use divan::{
+    black_box,
+    Bencher,
+};
+
+const TX_COUNTS: [usize; 4] = [1, 10, 100, 1000];
+
+/// Returns `count` raw transactions, each signed by a different key.
+fn signed_transactions(count: usize) -> Vec<raw::SignedTransaction> {
+    (0..count)
+        .map(|i| {
+            let signing_key = SigningKey::new(rand::rngs::OsRng);
+            UnsignedTransaction {
+                actions: vec![
+                    SequenceAction {
+                        rollup_id: RollupId::from_unhashed_bytes(b"rollup"),
+                        data: vec![u8::try_from(i % 256).unwrap(); 256],
+                        fee_asset_id: default_native_asset().id(),
+                    }
+                    .into(),
+                ],
+                params: TransactionParams::builder()
+                    .nonce(1)
+                    .chain_id("benchmark")
+                    .build(),
+            }
+            .into_signed(&signing_key)
+            .into_raw()
+        })
+        .collect()
+}
+
+#[divan::bench(args = TX_COUNTS)]
+fn verify_one_by_one(bencher: Bencher, count: usize) {
+    bencher
+        .with_inputs(|| signed_transactions(count))
+        .bench_values(|raws| {
+            for raw in raws {
+                black_box(SignedTransaction::try_from_raw(raw).unwrap());
+            }
+        });
+}
+
+#[divan::bench(args = TX_COUNTS)]
+fn verify_batch(bencher: Bencher, count: usize) {
+    bencher
+        .with_inputs(|| signed_transactions(count))
+        .bench_values(|raws| {
+            for result in SignedTransaction::try_from_raw_batch(raws) {
+                black_box(result.unwrap());
+            }
+        });
+}
+
+fn main() {
+    // Run registered benchmarks.
+    divan::main();
+}
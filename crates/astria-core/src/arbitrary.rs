@@ -0,0 +1,513 @@
+//! [`proptest`] strategies and [`Arbitrary`] implementations for the protocol types.
+//!
+//! All generated values are valid, i.e. they survive a roundtrip through their raw protobuf
+//! types. Transactions are signed by generated signing keys, and sequencer blocks are
+//! constructed from generated sequence data and deposits, so that their merkle proofs are
+//! consistent with their contents.
+//!
+//! Actions wrapping foreign types, namely [`Action::ValidatorUpdate`] and [`Action::Ibc`], are
+//! not generated.
+
+use ibc_types::core::{
+    channel::ChannelId,
+    client::Height as IbcHeight,
+};
+use proptest::{
+    collection::vec,
+    option,
+    prelude::*,
+};
+
+use crate::{
+    crypto::SigningKey,
+    primitive::v1::{
+        asset::{
+            self,
+            Denom,
+        },
+        Address,
+        RollupId,
+    },
+    protocol::{
+        test_utils::ConfigureSequencerBlock,
+        transaction::v1alpha1::{
+            action::{
+                AddressPrefixChangeAction,
+                AllowanceChangeAction,
+                BridgeLockAction,
+                BridgeRecoveryAction,
+                BridgeRecoveryVetoAction,
+                BridgeSudoChangeAction,
+                BridgeUnlockAction,
+                FeeAssetChangeAction,
+                FeeChange,
+                FeeChangeAction,
+                FeeDistributionChangeAction,
+                IbcRelayerChangeAction,
+                Ics20Withdrawal,
+                InitBridgeAccountAction,
+                SequenceAction,
+                SudoAddressChangeAction,
+                TransferAction,
+                TransferFromAction,
+            },
+            Action,
+            SignedTransaction,
+            TransactionParams,
+            UnsignedTransaction,
+        },
+    },
+    sequencerblock::v1alpha1::{
+        block::Deposit,
+        SequencerBlock,
+    },
+};
+
+/// The bech32m prefix of all generated addresses.
+pub const ADDRESS_PREFIX: &str = "astria";
+
+/// Generates addresses with the prefix [`ADDRESS_PREFIX`].
+pub fn address() -> impl Strategy<Value = Address> {
+    any::<[u8; 20]>().prop_map(|bytes| {
+        Address::builder()
+            .array(bytes)
+            .prefix(ADDRESS_PREFIX)
+            .try_build()
+            .expect("the address prefix is a valid bech32m prefix")
+    })
+}
+
+pub fn rollup_id() -> impl Strategy<Value = RollupId> {
+    any::<[u8; 32]>().prop_map(RollupId::new)
+}
+
+pub fn asset_id() -> impl Strategy<Value = asset::Id> {
+    any::<[u8; 32]>().prop_map(asset::Id::new)
+}
+
+/// Generates denominations, either without a trace or with a single ics20 transfer hop.
+pub fn denom() -> impl Strategy<Value = Denom> {
+    prop_oneof!["[a-z]{1,16}", "transfer/channel-[0-9]{1,4}/[a-z]{1,16}"].prop_map(|denom| {
+        denom
+            .parse()
+            .expect("the generated string is a valid denomination")
+    })
+}
+
+pub fn signing_key() -> impl Strategy<Value = SigningKey> {
+    any::<[u8; 32]>().prop_map(SigningKey::from)
+}
+
+pub fn sequence_action() -> impl Strategy<Value = SequenceAction> {
+    (rollup_id(), vec(any::<u8>(), 0..256), asset_id()).prop_map(
+        |(rollup_id, data, fee_asset_id)| SequenceAction {
+            rollup_id,
+            data,
+            fee_asset_id,
+        },
+    )
+}
+
+pub fn transfer_action() -> impl Strategy<Value = TransferAction> {
+    (address(), any::<u128>(), asset_id(), asset_id()).prop_map(
+        |(to, amount, asset_id, fee_asset_id)| TransferAction {
+            to,
+            amount,
+            asset_id,
+            fee_asset_id,
+        },
+    )
+}
+
+pub fn allowance_change_action() -> impl Strategy<Value = AllowanceChangeAction> {
+    (
+        address(),
+        any::<u128>(),
+        asset_id(),
+        any::<u64>(),
+        asset_id(),
+    )
+        .prop_map(|(spender, amount, asset_id, expiry_height, fee_asset_id)| {
+            AllowanceChangeAction {
+                spender,
+                amount,
+                asset_id,
+                expiry_height,
+                fee_asset_id,
+            }
+        })
+}
+
+pub fn transfer_from_action() -> impl Strategy<Value = TransferFromAction> {
+    (address(), address(), any::<u128>(), asset_id(), asset_id()).prop_map(
+        |(owner, to, amount, asset_id, fee_asset_id)| TransferFromAction {
+            owner,
+            to,
+            amount,
+            asset_id,
+            fee_asset_id,
+        },
+    )
+}
+
+pub fn sudo_address_change_action() -> impl Strategy<Value = SudoAddressChangeAction> {
+    address().prop_map(|new_address| SudoAddressChangeAction {
+        new_address,
+    })
+}
+
+pub fn ics20_withdrawal() -> impl Strategy<Value = Ics20Withdrawal> {
+    (
+        (any::<u128>(), denom(), "[a-z0-9]{0,64}", address()),
+        (any::<u64>(), 1..u64::MAX, any::<u64>(), any::<u64>()),
+        (asset_id(), ".{0,64}", option::of(address())),
+    )
+        .prop_map(
+            |(
+                (amount, denom, destination_chain_address, return_address),
+                (revision_number, revision_height, timeout_time, source_channel),
+                (fee_asset_id, memo, bridge_address),
+            )| Ics20Withdrawal {
+                amount,
+                denom,
+                destination_chain_address,
+                return_address,
+                timeout_height: IbcHeight::new(revision_number, revision_height)
+                    .expect("the revision height is not zero"),
+                timeout_time,
+                source_channel: ChannelId::new(source_channel),
+                fee_asset_id,
+                memo,
+                bridge_address,
+            },
+        )
+}
+
+pub fn ibc_relayer_change_action() -> impl Strategy<Value = IbcRelayerChangeAction> {
+    prop_oneof![
+        address().prop_map(IbcRelayerChangeAction::Addition),
+        address().prop_map(IbcRelayerChangeAction::Removal),
+    ]
+}
+
+pub fn fee_asset_change_action() -> impl Strategy<Value = FeeAssetChangeAction> {
+    prop_oneof![
+        asset_id().prop_map(FeeAssetChangeAction::Addition),
+        asset_id().prop_map(FeeAssetChangeAction::Removal),
+    ]
+}
+
+pub fn init_bridge_account_action() -> impl Strategy<Value = InitBridgeAccountAction> {
+    (
+        rollup_id(),
+        asset_id(),
+        asset_id(),
+        option::of(address()),
+        option::of(address()),
+    )
+        .prop_map(
+            |(rollup_id, asset_id, fee_asset_id, sudo_address, withdrawer_address)| {
+                InitBridgeAccountAction {
+                    rollup_id,
+                    asset_id,
+                    fee_asset_id,
+                    sudo_address,
+                    withdrawer_address,
+                }
+            },
+        )
+}
+
+pub fn bridge_lock_action() -> impl Strategy<Value = BridgeLockAction> {
+    (
+        address(),
+        any::<u128>(),
+        asset_id(),
+        asset_id(),
+        "[a-z0-9]{0,64}",
+    )
+        .prop_map(
+            |(to, amount, asset_id, fee_asset_id, destination_chain_address)| BridgeLockAction {
+                to,
+                amount,
+                asset_id,
+                fee_asset_id,
+                destination_chain_address,
+            },
+        )
+}
+
+pub fn bridge_unlock_action() -> impl Strategy<Value = BridgeUnlockAction> {
+    (
+        address(),
+        any::<u128>(),
+        asset_id(),
+        vec(any::<u8>(), 0..64),
+        option::of(address()),
+    )
+        .prop_map(
+            |(to, amount, fee_asset_id, memo, bridge_address)| BridgeUnlockAction {
+                to,
+                amount,
+                fee_asset_id,
+                memo,
+                bridge_address,
+            },
+        )
+}
+
+pub fn bridge_sudo_change_action() -> impl Strategy<Value = BridgeSudoChangeAction> {
+    (
+        address(),
+        option::of(address()),
+        option::of(address()),
+        asset_id(),
+    )
+        .prop_map(
+            |(bridge_address, new_sudo_address, new_withdrawer_address, fee_asset_id)| {
+                BridgeSudoChangeAction {
+                    bridge_address,
+                    new_sudo_address,
+                    new_withdrawer_address,
+                    fee_asset_id,
+                }
+            },
+        )
+}
+
+pub fn bridge_recovery_action() -> impl Strategy<Value = BridgeRecoveryAction> {
+    (address(), option::of(address()), option::of(address())).prop_map(
+        |(bridge_address, new_sudo_address, new_withdrawer_address)| BridgeRecoveryAction {
+            bridge_address,
+            new_sudo_address,
+            new_withdrawer_address,
+        },
+    )
+}
+
+pub fn bridge_recovery_veto_action() -> impl Strategy<Value = BridgeRecoveryVetoAction> {
+    (address(), asset_id()).prop_map(|(bridge_address, fee_asset_id)| BridgeRecoveryVetoAction {
+        bridge_address,
+        fee_asset_id,
+    })
+}
+
+pub fn fee_change_action() -> impl Strategy<Value = FeeChangeAction> {
+    let fee_change = prop_oneof![
+        Just(FeeChange::TransferBaseFee),
+        Just(FeeChange::SequenceBaseFee),
+        Just(FeeChange::SequenceByteCostMultiplier),
+        Just(FeeChange::InitBridgeAccountBaseFee),
+        Just(FeeChange::BridgeLockByteCostMultiplier),
+        Just(FeeChange::BridgeSudoChangeBaseFee),
+        Just(FeeChange::Ics20WithdrawalBaseFee),
+    ];
+    (fee_change, any::<u128>()).prop_map(|(fee_change, new_value)| FeeChangeAction {
+        fee_change,
+        new_value,
+    })
+}
+
+pub fn address_prefix_change_action() -> impl Strategy<Value = AddressPrefixChangeAction> {
+    prop_oneof![
+        "[a-z]{1,16}".prop_map(AddressPrefixChangeAction::Addition),
+        "[a-z]{1,16}".prop_map(AddressPrefixChangeAction::Removal),
+    ]
+}
+
+/// Generates fee distributions whose shares sum to 100, with a treasury address if the treasury
+/// share is not zero.
+pub fn fee_distribution_change_action() -> impl Strategy<Value = FeeDistributionChangeAction> {
+    (0..=100u32)
+        .prop_flat_map(|proposer_share| (Just(proposer_share), 0..=100 - proposer_share))
+        .prop_flat_map(|(proposer_share, treasury_share)| {
+            let treasury_address = if treasury_share > 0 {
+                address().prop_map(Some).boxed()
+            } else {
+                option::of(address()).boxed()
+            };
+            (Just(proposer_share), Just(treasury_share), treasury_address)
+        })
+        .prop_map(|(proposer_share, treasury_share, treasury_address)| {
+            FeeDistributionChangeAction {
+                proposer_share,
+                treasury_share,
+                burn_share: 100 - proposer_share - treasury_share,
+                treasury_address,
+            }
+        })
+}
+
+/// Generates any action except [`Action::ValidatorUpdate`] and [`Action::Ibc`].
+pub fn action() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        sequence_action().prop_map(Action::Sequence),
+        transfer_action().prop_map(Action::Transfer),
+        allowance_change_action().prop_map(Action::AllowanceChange),
+        transfer_from_action().prop_map(Action::TransferFrom),
+        sudo_address_change_action().prop_map(Action::SudoAddressChange),
+        ics20_withdrawal().prop_map(Action::Ics20Withdrawal),
+        ibc_relayer_change_action().prop_map(Action::IbcRelayerChange),
+        fee_asset_change_action().prop_map(Action::FeeAssetChange),
+        init_bridge_account_action().prop_map(Action::InitBridgeAccount),
+        bridge_lock_action().prop_map(Action::BridgeLock),
+        bridge_unlock_action().prop_map(Action::BridgeUnlock),
+        bridge_sudo_change_action().prop_map(Action::BridgeSudoChange),
+        bridge_recovery_veto_action().prop_map(Action::BridgeRecoveryVeto),
+        fee_change_action().prop_map(Action::FeeChange),
+        address_prefix_change_action().prop_map(Action::AddressPrefixChange),
+        bridge_recovery_action().prop_map(Action::BridgeRecovery),
+        fee_distribution_change_action().prop_map(Action::FeeDistributionChange),
+    ]
+}
+
+pub fn unsigned_transaction() -> impl Strategy<Value = UnsignedTransaction> {
+    (vec(action(), 0..8), any::<u32>(), "[a-z]{1,16}-[0-9]{1,4}").prop_map(
+        |(actions, nonce, chain_id)| UnsignedTransaction {
+            actions,
+            params: TransactionParams::builder()
+                .nonce(nonce)
+                .chain_id(chain_id)
+                .build(),
+        },
+    )
+}
+
+/// Generates transactions signed by a generated signing key.
+pub fn signed_transaction() -> impl Strategy<Value = SignedTransaction> {
+    (unsigned_transaction(), signing_key())
+        .prop_map(|(transaction, signing_key)| transaction.into_signed(&signing_key))
+}
+
+pub fn deposit() -> impl Strategy<Value = Deposit> {
+    (
+        address(),
+        rollup_id(),
+        any::<u128>(),
+        asset_id(),
+        "[a-z0-9]{0,64}",
+    )
+        .prop_map(
+            |(bridge_address, rollup_id, amount, asset_id, destination_chain_address)| {
+                Deposit::new(
+                    bridge_address,
+                    rollup_id,
+                    amount,
+                    asset_id,
+                    destination_chain_address,
+                )
+            },
+        )
+}
+
+/// Generates sequencer blocks holding a single transaction of sequence actions and a number of
+/// deposits.
+///
+/// The blocks are built by [`ConfigureSequencerBlock`], so their rollup transactions and rollup
+/// IDs proofs are valid.
+pub fn sequencer_block() -> impl Strategy<Value = SequencerBlock> {
+    (
+        (any::<[u8; 32]>(), "[a-z]{1,16}-[0-9]{1,4}", 1..u32::MAX),
+        signing_key(),
+        vec((rollup_id(), vec(any::<u8>(), 0..256)), 0..8),
+        vec(deposit(), 0..4),
+        (0..4_000_000_000_i64, 0..1_000_000_000_u32),
+    )
+        .prop_map(
+            |(
+                (block_hash, chain_id, height),
+                signing_key,
+                sequence_data,
+                deposits,
+                unix_timestamp,
+            )| {
+                ConfigureSequencerBlock {
+                    block_hash: Some(block_hash),
+                    chain_id: Some(chain_id),
+                    height,
+                    signing_key: Some(signing_key),
+                    sequence_data,
+                    deposits,
+                    unix_timestamp: unix_timestamp.into(),
+                    ..ConfigureSequencerBlock::default()
+                }
+                .make()
+            },
+        )
+}
+
+macro_rules! impl_arbitrary {
+    ($($ty:ty => $strategy:ident),* $(,)?) => {
+        $(
+            impl Arbitrary for $ty {
+                type Parameters = ();
+                type Strategy = BoxedStrategy<Self>;
+
+                fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+                    $strategy().boxed()
+                }
+            }
+        )*
+    };
+}
+
+impl_arbitrary!(
+    Address => address,
+    RollupId => rollup_id,
+    asset::Id => asset_id,
+    Denom => denom,
+    SequenceAction => sequence_action,
+    TransferAction => transfer_action,
+    AllowanceChangeAction => allowance_change_action,
+    TransferFromAction => transfer_from_action,
+    SudoAddressChangeAction => sudo_address_change_action,
+    Ics20Withdrawal => ics20_withdrawal,
+    IbcRelayerChangeAction => ibc_relayer_change_action,
+    FeeAssetChangeAction => fee_asset_change_action,
+    InitBridgeAccountAction => init_bridge_account_action,
+    BridgeLockAction => bridge_lock_action,
+    BridgeUnlockAction => bridge_unlock_action,
+    BridgeSudoChangeAction => bridge_sudo_change_action,
+    BridgeRecoveryVetoAction => bridge_recovery_veto_action,
+    FeeChangeAction => fee_change_action,
+    AddressPrefixChangeAction => address_prefix_change_action,
+    BridgeRecoveryAction => bridge_recovery_action,
+    FeeDistributionChangeAction => fee_distribution_change_action,
+    Action => action,
+    UnsignedTransaction => unsigned_transaction,
+    SignedTransaction => signed_transaction,
+    Deposit => deposit,
+    SequencerBlock => sequencer_block,
+);
+
+#[cfg(test)]
+mod tests {
+    use prost::Message as _;
+
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn signed_transaction_roundtrips_through_raw(transaction in signed_transaction()) {
+            let raw = transaction.to_raw();
+            let roundtripped = SignedTransaction::try_from_raw(raw.clone()).unwrap();
+            prop_assert_eq!(raw.encode_to_vec(), roundtripped.to_raw().encode_to_vec());
+        }
+
+        #[test]
+        fn deposit_roundtrips_through_raw(deposit in deposit()) {
+            prop_assert_eq!(
+                deposit.clone(),
+                Deposit::try_from_raw(deposit.into_raw()).unwrap()
+            );
+        }
+
+        #[test]
+        fn sequencer_block_roundtrips_through_raw(block in sequencer_block()) {
+            prop_assert_eq!(
+                block.clone(),
+                SequencerBlock::try_from_raw(block.into_raw()).unwrap()
+            );
+        }
+    }
+}
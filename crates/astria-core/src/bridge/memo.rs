@@ -0,0 +1,334 @@
+//! Versioned JSON encoding of the bridge memos.
+//!
+//! Memos are stored on chain as part of transactions and IBC packets, so a memo written by an
+//! older release must remain readable after its format evolves. Every memo is a JSON object
+//! that may carry an explicit version tag in its [`VERSION_FIELD`]:
+//!
+//! ```json
+//! { "version": 1, "rollupAddress": "..." }
+//! ```
+//!
+//! Memos without the tag predate versioning and are read as [`MemoVersion::Legacy`].
+//!
+//! Memos can be parsed in one of two [`ParseMode`]s:
+//!
+//! - [`ParseMode::Strict`] only accepts known versions and rejects fields that are not part of the
+//!   memo's schema;
+//! - [`ParseMode::Lenient`] ignores unknown fields and reads memos tagged with a newer version than
+//!   this crate knows about as the [`MemoVersion::CURRENT`] version.
+
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+use serde_json::Value;
+
+use super::{
+    Ics20TransferDepositMemo,
+    Ics20WithdrawalFromRollupMemo,
+};
+
+/// The name of the JSON field holding the version tag of a memo.
+pub const VERSION_FIELD: &str = "version";
+
+/// A memo format that is encoded with a version tag.
+pub trait Memo: Serialize + DeserializeOwned {
+    /// The name of the memo used in errors.
+    const NAME: &'static str;
+    /// The JSON fields of the memo, excluding the version tag.
+    const FIELDS: &'static [&'static str];
+}
+
+impl Memo for Ics20WithdrawalFromRollupMemo {
+    const FIELDS: &'static [&'static str] =
+        &["memo", "bridge_address", "block_number", "transaction_hash"];
+    const NAME: &'static str = "Ics20WithdrawalFromRollupMemo";
+}
+
+impl Memo for Ics20TransferDepositMemo {
+    const FIELDS: &'static [&'static str] = &["rollupAddress"];
+    const NAME: &'static str = "Ics20TransferDepositMemo";
+}
+
+/// The version of a memo encoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum MemoVersion {
+    /// A memo without version tag, as written before memos were versioned.
+    Legacy,
+    /// A memo with the version tag `1`.
+    V1,
+}
+
+impl MemoVersion {
+    /// The version new memos are written with.
+    pub const CURRENT: Self = Self::V1;
+
+    /// Returns the value of the version tag, or `None` for [`MemoVersion::Legacy`].
+    #[must_use]
+    pub fn tag(self) -> Option<u64> {
+        match self {
+            Self::Legacy => None,
+            Self::V1 => Some(1),
+        }
+    }
+
+    fn from_tag(tag: u64) -> Option<Self> {
+        match tag {
+            1 => Some(Self::V1),
+            _ => None,
+        }
+    }
+}
+
+/// How tolerant memo parsing is of input it does not fully understand.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseMode {
+    /// Reject unknown versions and unknown fields.
+    Strict,
+    /// Ignore unknown fields and read unknown newer versions as [`MemoVersion::CURRENT`].
+    Lenient,
+}
+
+/// A memo together with the version it was encoded with.
+#[derive(Clone, Debug)]
+pub struct ParsedMemo<T> {
+    version: MemoVersion,
+    memo: T,
+}
+
+impl<T> ParsedMemo<T> {
+    /// Returns the version the memo was encoded with.
+    #[must_use]
+    pub fn version(&self) -> MemoVersion {
+        self.version
+    }
+
+    /// Returns the parsed memo.
+    #[must_use]
+    pub fn memo(&self) -> &T {
+        &self.memo
+    }
+
+    /// Returns the parsed memo, dropping its version.
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.memo
+    }
+}
+
+/// Parses `input` as memo `T` of any known version.
+///
+/// # Errors
+/// Returns an error if:
+/// - `input` is not a JSON object
+/// - the version tag is not an unsigned integer
+/// - the version is unknown and `mode` is [`ParseMode::Strict`], or the version is older than all
+///   known versions
+/// - `input` contains fields not in [`Memo::FIELDS`] and `mode` is [`ParseMode::Strict`]
+/// - the fields of `input` do not match the schema of `T`
+pub fn parse<T: Memo>(input: &str, mode: ParseMode) -> Result<ParsedMemo<T>, MemoError> {
+    let value: Value =
+        serde_json::from_str(input).map_err(|source| MemoError::json(T::NAME, source))?;
+    let Value::Object(mut fields) = value else {
+        return Err(MemoError::not_an_object(T::NAME));
+    };
+    let version = match fields.remove(VERSION_FIELD) {
+        None => MemoVersion::Legacy,
+        Some(tag) => {
+            let tag = tag
+                .as_u64()
+                .ok_or_else(|| MemoError::invalid_version(T::NAME, tag))?;
+            match (MemoVersion::from_tag(tag), mode) {
+                (Some(version), _) => version,
+                (None, ParseMode::Lenient) if Some(tag) > MemoVersion::CURRENT.tag() => {
+                    MemoVersion::CURRENT
+                }
+                (None, _) => return Err(MemoError::unknown_version(T::NAME, tag)),
+            }
+        }
+    };
+    match mode {
+        ParseMode::Strict => {
+            if let Some(field) = fields.keys().find(|key| !T::FIELDS.contains(&key.as_str())) {
+                return Err(MemoError::unknown_field(T::NAME, field.clone()));
+            }
+        }
+        ParseMode::Lenient => fields.retain(|key, _| T::FIELDS.contains(&key.as_str())),
+    }
+    let memo = serde_json::from_value(Value::Object(fields))
+        .map_err(|source| MemoError::json(T::NAME, source))?;
+    Ok(ParsedMemo {
+        version,
+        memo,
+    })
+}
+
+/// Encodes `memo` as JSON tagged with `version`.
+///
+/// The version tag is written as the first field of the JSON object.
+///
+/// # Errors
+/// Returns an error if `memo` could not be serialized as a JSON object.
+pub fn to_json<T: Memo>(memo: &T, version: MemoVersion) -> Result<String, MemoError> {
+    #[derive(Serialize)]
+    struct Tagged<'a, T> {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        version: Option<u64>,
+        #[serde(flatten)]
+        memo: &'a T,
+    }
+    serde_json::to_string(&Tagged {
+        version: version.tag(),
+        memo,
+    })
+    .map_err(|source| MemoError::json(T::NAME, source))
+}
+
+/// Re-encodes the memo `input` with version `to`.
+///
+/// This allows components to rewrite memos they have read from old transactions in the format
+/// they emit today.
+///
+/// # Errors
+/// Returns an error if `input` could not be parsed with `mode`, see [`parse`].
+pub fn migrate<T: Memo>(
+    input: &str,
+    mode: ParseMode,
+    to: MemoVersion,
+) -> Result<String, MemoError> {
+    let parsed = parse::<T>(input, mode)?;
+    to_json(&parsed.memo, to)
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct MemoError(MemoErrorKind);
+
+impl MemoError {
+    fn json(memo: &'static str, source: serde_json::Error) -> Self {
+        Self(MemoErrorKind::Json {
+            memo,
+            source,
+        })
+    }
+
+    fn not_an_object(memo: &'static str) -> Self {
+        Self(MemoErrorKind::NotAnObject {
+            memo,
+        })
+    }
+
+    fn invalid_version(memo: &'static str, tag: Value) -> Self {
+        Self(MemoErrorKind::InvalidVersion {
+            memo,
+            tag,
+        })
+    }
+
+    fn unknown_version(memo: &'static str, tag: u64) -> Self {
+        Self(MemoErrorKind::UnknownVersion {
+            memo,
+            tag,
+        })
+    }
+
+    fn unknown_field(memo: &'static str, field: String) -> Self {
+        Self(MemoErrorKind::UnknownField {
+            memo,
+            field,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum MemoErrorKind {
+    #[error("failed to convert `{memo}` from or to JSON")]
+    Json {
+        memo: &'static str,
+        source: serde_json::Error,
+    },
+    #[error("`{memo}` must be a JSON object")]
+    NotAnObject { memo: &'static str },
+    #[error("the version tag of `{memo}` must be an unsigned integer, but was `{tag}`")]
+    InvalidVersion { memo: &'static str, tag: Value },
+    #[error("`{memo}` has unknown version `{tag}`")]
+    UnknownVersion { memo: &'static str, tag: u64 },
+    #[error("`{memo}` contains unknown field `{field}`")]
+    UnknownField { memo: &'static str, field: String },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primitive::v1::Address;
+
+    fn withdrawal_memo() -> Ics20WithdrawalFromRollupMemo {
+        Ics20WithdrawalFromRollupMemo {
+            memo: "hello".to_string(),
+            bridge_address: Address::builder()
+                .array([99; 20])
+                .prefix("astria")
+                .try_build()
+                .unwrap(),
+            block_number: 1,
+            transaction_hash: [88; 32],
+        }
+    }
+
+    #[test]
+    fn legacy_memo_is_parsed_in_both_modes() {
+        let legacy = serde_json::to_string(&withdrawal_memo()).unwrap();
+        for mode in [ParseMode::Strict, ParseMode::Lenient] {
+            let parsed = parse::<Ics20WithdrawalFromRollupMemo>(&legacy, mode).unwrap();
+            assert_eq!(MemoVersion::Legacy, parsed.version());
+            assert_eq!(1, parsed.memo().block_number);
+        }
+    }
+
+    #[test]
+    fn tagged_memo_roundtrips() {
+        let json = to_json(&withdrawal_memo(), MemoVersion::V1).unwrap();
+        assert!(json.starts_with(r#"{"version":1,"#));
+        let parsed = parse::<Ics20WithdrawalFromRollupMemo>(&json, ParseMode::Strict).unwrap();
+        assert_eq!(MemoVersion::V1, parsed.version());
+        assert_eq!(
+            serde_json::to_value(withdrawal_memo()).unwrap(),
+            serde_json::to_value(parsed.into_inner()).unwrap(),
+        );
+    }
+
+    #[test]
+    fn unknown_field_is_only_accepted_in_lenient_mode() {
+        let json = r#"{"version":1,"rollupAddress":"rollup","extra":true}"#;
+        parse::<Ics20TransferDepositMemo>(json, ParseMode::Strict).unwrap_err();
+        let parsed = parse::<Ics20TransferDepositMemo>(json, ParseMode::Lenient).unwrap();
+        assert_eq!("rollup", parsed.memo().rollup_address);
+    }
+
+    #[test]
+    fn newer_version_is_only_accepted_in_lenient_mode() {
+        let json = r#"{"version":2,"rollupAddress":"rollup"}"#;
+        parse::<Ics20TransferDepositMemo>(json, ParseMode::Strict).unwrap_err();
+        let parsed = parse::<Ics20TransferDepositMemo>(json, ParseMode::Lenient).unwrap();
+        assert_eq!(MemoVersion::CURRENT, parsed.version());
+    }
+
+    #[test]
+    fn invalid_version_tag_is_rejected() {
+        for json in [
+            r#"{"version":"1","rollupAddress":"rollup"}"#,
+            r#"{"version":0,"rollupAddress":"rollup"}"#,
+        ] {
+            parse::<Ics20TransferDepositMemo>(json, ParseMode::Lenient).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn legacy_memo_is_migrated_to_current_version() {
+        let legacy = r#"{"rollupAddress":"rollup"}"#;
+        let migrated =
+            migrate::<Ics20TransferDepositMemo>(legacy, ParseMode::Strict, MemoVersion::CURRENT)
+                .unwrap();
+        assert_eq!(r#"{"version":1,"rollupAddress":"rollup"}"#, migrated);
+    }
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "serde")]
+pub mod memo;
+
 use crate::primitive::v1::Address;
 
 /// Memo format for a ICS20 withdrawal from the rollup which is sent to
@@ -1,36 +1,363 @@
-pub use celestia_types::nmt::Namespace;
+//! Celestia namespaces and blobs under which Astria data is posted.
+//!
+//! The sequencer relayer posts the block metadata of a sequencer network under a namespace
+//! derived from its chain ID, and the data of each rollup under a namespace derived from the
+//! rollup's ID. The data is encoded as protobuf and compressed with brotli before being written
+//! to a blob.
+
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use base64::{
+    display::Base64Display,
+    engine::general_purpose::STANDARD,
+};
+
+use crate::{
+    brotli::{
+        compress_bytes,
+        decompress_bytes,
+    },
+    primitive::v1::RollupId,
+};
+
+/// The number of bytes of a Celestia namespace, including its version byte.
+const NAMESPACE_LEN: usize = celestia_types::nmt::NS_SIZE;
+
+/// The number of leading zero bytes of the ID of a version 0 namespace.
+const NAMESPACE_V0_LEADING_ZEROS: usize = NAMESPACE_LEN - 1 - celestia_types::nmt::NS_ID_V0_SIZE;
+
+/// A version 0 Celestia namespace.
+///
+/// Version 0 namespaces consist of a zero version byte, followed by an ID of 18 zero bytes and 10
+/// user-chosen bytes. The namespaces used by Astria are derived from rollup IDs and chain IDs by
+/// [`Namespace::from_rollup_id`] and [`Namespace::from_chain_id`] respectively.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Namespace(celestia_types::nmt::Namespace);
+
+impl Namespace {
+    /// Constructs a namespace from the first 10 bytes of a byte slice.
+    ///
+    /// # Panics
+    /// Panics if `bytes` contains less then 10 bytes.
+    #[must_use = "a celestia namespace must be used in order to be useful"]
+    pub const fn from_first_10_bytes(bytes: &[u8]) -> Self {
+        #[allow(clippy::assertions_on_constants)]
+        const _: () = assert!(
+            10 == celestia_types::nmt::NS_ID_V0_SIZE,
+            "verify that the celestia v0 namespace was changed from 10 bytes"
+        );
+        let first_10_bytes = [
+            bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7],
+            bytes[8], bytes[9],
+        ];
+        Self(celestia_types::nmt::Namespace::const_v0(first_10_bytes))
+    }
+
+    /// Constructs the namespace under which the data of the rollup `rollup_id` is posted, from
+    /// the first 10 bytes of the rollup ID.
+    #[must_use = "a celestia namespace must be used in order to be useful"]
+    pub const fn from_rollup_id(rollup_id: RollupId) -> Self {
+        Self::from_first_10_bytes(&rollup_id.get())
+    }
+
+    /// Constructs the namespace under which the block metadata of the sequencer network
+    /// `chain_id` is posted, from the first 10 bytes of the sha256 hash of the chain ID.
+    #[must_use = "a celestia namespace must be used in order to be useful"]
+    pub fn from_chain_id(chain_id: &str) -> Self {
+        Self::from_sha256_of_bytes(chain_id)
+    }
+
+    /// Constructs a namespace from the first 10 bytes of the sha256 hash of `bytes`.
+    #[must_use = "a celestia namespace must be used in order to be useful"]
+    pub fn from_sha256_of_bytes<T: AsRef<[u8]>>(bytes: T) -> Self {
+        use sha2::{
+            Digest as _,
+            Sha256,
+        };
+        Self::from_first_10_bytes(&Sha256::digest(bytes))
+    }
+
+    /// Converts the raw bytes of a namespace, including its version byte, to a [`Namespace`].
+    ///
+    /// # Errors
+    /// Returns an error if `bytes` are not 29 bytes long, or are not a version 0 namespace.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, NamespaceError> {
+        if bytes.len() != NAMESPACE_LEN {
+            return Err(NamespaceError::incorrect_length(bytes.len()));
+        }
+        if bytes[0] != 0 {
+            return Err(NamespaceError::unsupported_version(bytes[0]));
+        }
+        let (leading_zeros, id) = bytes[1..].split_at(NAMESPACE_V0_LEADING_ZEROS);
+        if leading_zeros.iter().any(|byte| *byte != 0) {
+            return Err(NamespaceError::invalid_v0_id());
+        }
+        Ok(Self::from_first_10_bytes(id))
+    }
+
+    /// Returns the raw bytes of the namespace, including its version byte.
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    /// Returns the underlying [`celestia_types::nmt::Namespace`].
+    #[must_use]
+    pub const fn get(self) -> celestia_types::nmt::Namespace {
+        self.0
+    }
+}
+
+impl AsRef<[u8]> for Namespace {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+/// Formats the namespace as base64, as accepted by Celestia nodes.
+impl Display for Namespace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Base64Display::new(self.as_bytes(), &STANDARD).fmt(f)
+    }
+}
+
+impl From<Namespace> for celestia_types::nmt::Namespace {
+    fn from(value: Namespace) -> Self {
+        value.get()
+    }
+}
+
+impl TryFrom<celestia_types::nmt::Namespace> for Namespace {
+    type Error = NamespaceError;
+
+    fn try_from(value: celestia_types::nmt::Namespace) -> Result<Self, Self::Error> {
+        Self::try_from_bytes(value.as_bytes())
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct NamespaceError(NamespaceErrorKind);
+
+impl NamespaceError {
+    fn incorrect_length(received: usize) -> Self {
+        Self(NamespaceErrorKind::IncorrectLength {
+            received,
+        })
+    }
+
+    fn unsupported_version(version: u8) -> Self {
+        Self(NamespaceErrorKind::UnsupportedVersion(version))
+    }
+
+    fn invalid_v0_id() -> Self {
+        Self(NamespaceErrorKind::InvalidV0Id)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum NamespaceErrorKind {
+    #[error("expected {NAMESPACE_LEN} bytes, got {received}")]
+    IncorrectLength { received: usize },
+    #[error("expected a version 0 namespace, got version {0}")]
+    UnsupportedVersion(u8),
+    #[error(
+        "the first {NAMESPACE_V0_LEADING_ZEROS} bytes of a version 0 namespace ID must be zero"
+    )]
+    InvalidV0Id,
+}
+
+/// A Celestia blob holding a brotli-compressed, protobuf-encoded message under a version 0
+/// [`Namespace`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Blob {
+    namespace: Namespace,
+    inner: celestia_types::Blob,
+}
+
+impl Blob {
+    /// Encodes `message` as protobuf, compresses it, and creates a blob of it under `namespace`.
+    ///
+    /// # Errors
+    /// Returns an error if compressing the encoded message fails, or if Celestia rejects the
+    /// blob.
+    pub fn try_from_message<T: prost::Message>(
+        namespace: Namespace,
+        message: &T,
+    ) -> Result<Self, BlobError> {
+        let compressed = compress_bytes(&message.encode_to_vec()).map_err(BlobError::compress)?;
+        let inner =
+            celestia_types::Blob::new(namespace.get(), compressed).map_err(BlobError::celestia)?;
+        Ok(Self {
+            namespace,
+            inner,
+        })
+    }
+
+    /// Decompresses the blob data and decodes it as the protobuf message `T`.
+    ///
+    /// # Errors
+    /// Returns an error if the data cannot be decompressed, or cannot be decoded as `T`.
+    pub fn try_decode_message<T: prost::Message + Default>(&self) -> Result<T, BlobError> {
+        let decompressed = decompress_bytes(&self.inner.data).map_err(BlobError::decompress)?;
+        T::decode(&*decompressed).map_err(BlobError::decode)
+    }
+
+    #[must_use]
+    pub fn namespace(&self) -> Namespace {
+        self.namespace
+    }
+
+    /// Returns the compressed data of the blob.
+    #[must_use]
+    pub fn data(&self) -> &[u8] {
+        &self.inner.data
+    }
+
+    /// Returns the underlying [`celestia_types::Blob`].
+    #[must_use]
+    pub fn into_inner(self) -> celestia_types::Blob {
+        self.inner
+    }
+}
+
+impl From<Blob> for celestia_types::Blob {
+    fn from(value: Blob) -> Self {
+        value.into_inner()
+    }
+}
+
+impl TryFrom<celestia_types::Blob> for Blob {
+    type Error = BlobError;
+
+    fn try_from(value: celestia_types::Blob) -> Result<Self, Self::Error> {
+        let namespace = Namespace::try_from(value.namespace).map_err(BlobError::namespace)?;
+        Ok(Self {
+            namespace,
+            inner: value,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BlobError(BlobErrorKind);
+
+impl BlobError {
+    fn compress(source: std::io::Error) -> Self {
+        Self(BlobErrorKind::Compress(source))
+    }
+
+    fn decompress(source: std::io::Error) -> Self {
+        Self(BlobErrorKind::Decompress(source))
+    }
+
+    fn decode(source: prost::DecodeError) -> Self {
+        Self(BlobErrorKind::Decode(source))
+    }
+
+    fn celestia(source: celestia_types::Error) -> Self {
+        Self(BlobErrorKind::Celestia(source))
+    }
+
+    fn namespace(source: NamespaceError) -> Self {
+        Self(BlobErrorKind::Namespace(source))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum BlobErrorKind {
+    #[error("failed compressing the protobuf encoded message")]
+    Compress(#[source] std::io::Error),
+    #[error("failed decompressing the blob data")]
+    Decompress(#[source] std::io::Error),
+    #[error("failed decoding the decompressed blob data as protobuf")]
+    Decode(#[source] prost::DecodeError),
+    #[error("failed creating a Celestia blob from the compressed message")]
+    Celestia(#[source] celestia_types::Error),
+    #[error("the blob's namespace is not a valid version 0 namespace")]
+    Namespace(#[source] NamespaceError),
+}
+
 /// Constructs a [`celestia_types::nmt::Namespace`] from the first 10 bytes of a byte slice.
 ///
+/// See [`Namespace::from_first_10_bytes`].
+///
 /// # Panics
 /// Panics if `bytes` contains less then 10 bytes.
 #[must_use = "a celestia namespace must be used in order to be useful"]
-pub const fn namespace_v0_from_first_10_bytes(bytes: &[u8]) -> Namespace {
-    #[allow(clippy::assertions_on_constants)]
-    const _: () = assert!(
-        10 == celestia_types::nmt::NS_ID_V0_SIZE,
-        "verify that the celestia v0 namespace was changed from 10 bytes"
-    );
-    let first_10_bytes = [
-        bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], bytes[5], bytes[6], bytes[7], bytes[8],
-        bytes[9],
-    ];
-    Namespace::const_v0(first_10_bytes)
+pub const fn namespace_v0_from_first_10_bytes(bytes: &[u8]) -> celestia_types::nmt::Namespace {
+    Namespace::from_first_10_bytes(bytes).get()
 }
 
 /// Constructs a [`celestia_types::nmt::Namespace`] from the first
 /// 10 bytes of [`crate::primitive::v1::RollupId`].
+///
+/// See [`Namespace::from_rollup_id`].
 #[must_use = "a celestia namespace must be used in order to be useful"]
-pub const fn namespace_v0_from_rollup_id(rollup_id: crate::primitive::v1::RollupId) -> Namespace {
-    namespace_v0_from_first_10_bytes(&rollup_id.get())
+pub const fn namespace_v0_from_rollup_id(rollup_id: RollupId) -> celestia_types::nmt::Namespace {
+    Namespace::from_rollup_id(rollup_id).get()
 }
 
 /// Constructs a [`celestia_types::nmt::Namespace`] from the first 10 bytes of the sha256 hash of
 /// `bytes`.
+///
+/// See [`Namespace::from_sha256_of_bytes`].
 #[must_use = "a celestia namespace must be used in order to be useful"]
-pub fn namespace_v0_from_sha256_of_bytes<T: AsRef<[u8]>>(bytes: T) -> Namespace {
-    use sha2::{
-        Digest as _,
-        Sha256,
-    };
-    namespace_v0_from_first_10_bytes(&Sha256::digest(bytes))
+pub fn namespace_v0_from_sha256_of_bytes<T: AsRef<[u8]>>(
+    bytes: T,
+) -> celestia_types::nmt::Namespace {
+    Namespace::from_sha256_of_bytes(bytes).get()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn namespace_is_derived_from_rollup_id() {
+        let rollup_id = RollupId::new([7; 32]);
+        let namespace = Namespace::from_rollup_id(rollup_id);
+        let mut expected = [0; NAMESPACE_LEN];
+        expected[NAMESPACE_LEN - 10..].copy_from_slice(&[7; 10]);
+        assert_eq!(&expected[..], namespace.as_bytes());
+        assert_eq!(
+            namespace,
+            Namespace::try_from_bytes(namespace.as_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_namespace_bytes_are_rejected() {
+        let valid = Namespace::from_chain_id("astria");
+        Namespace::try_from_bytes(&valid.as_bytes()[1..]).unwrap_err();
+
+        let mut wrong_version = valid.as_bytes().to_vec();
+        wrong_version[0] = 1;
+        Namespace::try_from_bytes(&wrong_version).unwrap_err();
+
+        let mut nonzero_prefix = valid.as_bytes().to_vec();
+        nonzero_prefix[1] = 1;
+        Namespace::try_from_bytes(&nonzero_prefix).unwrap_err();
+    }
+
+    #[test]
+    fn blob_message_roundtrips() {
+        let message = crate::generated::primitive::v1::RollupId {
+            inner: vec![1; 32].into(),
+        };
+        let namespace = Namespace::from_chain_id("astria");
+        let blob = Blob::try_from_message(namespace, &message).unwrap();
+        let blob = Blob::try_from(blob.into_inner()).unwrap();
+        assert_eq!(namespace, blob.namespace());
+        assert_eq!(
+            message,
+            blob.try_decode_message::<crate::generated::primitive::v1::RollupId>()
+                .unwrap()
+        );
+    }
 }
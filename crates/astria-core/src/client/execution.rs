@@ -0,0 +1,205 @@
+use bytes::Bytes;
+use pbjson_types::Timestamp;
+use tonic::transport::Channel;
+use tracing::instrument;
+
+use super::{
+    call_with_retry,
+    connect_lazy,
+    Error,
+    RetryConfig,
+};
+use crate::{
+    execution::v1alpha2::{
+        Block,
+        CommitmentState,
+        ExecuteBlocksResponse,
+        GenesisInfo,
+    },
+    generated::{
+        execution::v1alpha2::{
+            self as raw,
+            execution_service_client::ExecutionServiceClient,
+        },
+        sequencerblock::v1alpha1::RollupData,
+    },
+    Protobuf as _,
+};
+
+/// A client of a rollup's `astria.execution.v1alpha2.ExecutionService`.
+#[derive(Clone)]
+pub struct ExecutionClient {
+    inner: ExecutionServiceClient<Channel>,
+    uri: String,
+    config: RetryConfig,
+}
+
+impl ExecutionClient {
+    /// Creates a client that connects to `uri` on first use.
+    ///
+    /// # Errors
+    /// Returns an error if `uri` is not a valid endpoint.
+    pub fn connect_lazy(uri: &str, config: RetryConfig) -> Result<Self, Error> {
+        let inner = ExecutionServiceClient::new(connect_lazy(uri.to_string())?);
+        Ok(Self {
+            inner,
+            uri: uri.to_string(),
+            config,
+        })
+    }
+
+    /// Calls `astria.execution.v1alpha2.ExecutionService/GetGenesisInfo`.
+    ///
+    /// # Errors
+    /// Returns an error if the request failed after all retries, or if the returned genesis info
+    /// failed validation.
+    #[instrument(skip_all, fields(uri = %self.uri), err)]
+    pub async fn get_genesis_info(&self) -> Result<GenesisInfo, Error> {
+        const RPC: &str = "astria.execution.v1alpha2.ExecutionService/GetGenesisInfo";
+        let raw = call_with_retry(
+            &self.config,
+            RPC,
+            raw::GetGenesisInfoRequest {},
+            |request| {
+                let mut client = self.inner.clone();
+                async move { client.get_genesis_info(request).await }
+            },
+        )
+        .await?;
+        GenesisInfo::try_from_raw(raw).map_err(|e| Error::invalid_response(RPC, e))
+    }
+
+    /// Calls `astria.execution.v1alpha2.ExecutionService/GetBlock` for the block at `number`.
+    ///
+    /// # Errors
+    /// Returns an error if the request failed after all retries, or if the returned block failed
+    /// validation.
+    #[instrument(skip_all, fields(uri = %self.uri, block_number = number), err)]
+    pub async fn get_block(&self, number: u32) -> Result<Block, Error> {
+        const RPC: &str = "astria.execution.v1alpha2.ExecutionService/GetBlock";
+        let raw = call_with_retry(
+            &self.config,
+            RPC,
+            raw::GetBlockRequest {
+                identifier: Some(raw::BlockIdentifier {
+                    identifier: Some(raw::block_identifier::Identifier::BlockNumber(number)),
+                }),
+            },
+            |request| {
+                let mut client = self.inner.clone();
+                async move { client.get_block(request).await }
+            },
+        )
+        .await?;
+        Block::try_from_raw(raw).map_err(|e| Error::invalid_response(RPC, e))
+    }
+
+    /// Calls `astria.execution.v1alpha2.ExecutionService/ExecuteBlock`.
+    ///
+    /// # Errors
+    /// Returns an error if the request failed after all retries, or if the returned block failed
+    /// validation.
+    #[instrument(skip_all, fields(uri = %self.uri), err)]
+    pub async fn execute_block(
+        &self,
+        prev_block_hash: Bytes,
+        transactions: Vec<RollupData>,
+        timestamp: Timestamp,
+    ) -> Result<Block, Error> {
+        const RPC: &str = "astria.execution.v1alpha2.ExecutionService/ExecuteBlock";
+        let raw = call_with_retry(
+            &self.config,
+            RPC,
+            raw::ExecuteBlockRequest {
+                prev_block_hash,
+                transactions,
+                timestamp: Some(timestamp),
+            },
+            |request| {
+                let mut client = self.inner.clone();
+                async move { client.execute_block(request).await }
+            },
+        )
+        .await?;
+        Block::try_from_raw(raw).map_err(|e| Error::invalid_response(RPC, e))
+    }
+
+    /// Calls `astria.execution.v1alpha2.ExecutionService/ExecuteBlocks`.
+    ///
+    /// The first of `blocks` is executed on top of `prev_block_hash`, and every following block
+    /// on top of the one before it.
+    ///
+    /// # Errors
+    /// Returns an error if the request failed after all retries, or if the returned results failed
+    /// validation. A block that could not be executed is reported in the returned response
+    /// instead.
+    #[instrument(skip_all, fields(uri = %self.uri, num_blocks = blocks.len()), err)]
+    pub async fn execute_blocks(
+        &self,
+        prev_block_hash: Bytes,
+        blocks: Vec<raw::BlockToExecute>,
+    ) -> Result<ExecuteBlocksResponse, Error> {
+        const RPC: &str = "astria.execution.v1alpha2.ExecutionService/ExecuteBlocks";
+        let raw = call_with_retry(
+            &self.config,
+            RPC,
+            raw::ExecuteBlocksRequest {
+                prev_block_hash,
+                blocks,
+            },
+            |request| {
+                let mut client = self.inner.clone();
+                async move { client.execute_blocks(request).await }
+            },
+        )
+        .await?;
+        ExecuteBlocksResponse::try_from_raw(raw).map_err(|e| Error::invalid_response(RPC, e))
+    }
+
+    /// Calls `astria.execution.v1alpha2.ExecutionService/GetCommitmentState`.
+    ///
+    /// # Errors
+    /// Returns an error if the request failed after all retries, or if the returned commitment
+    /// state failed validation.
+    #[instrument(skip_all, fields(uri = %self.uri), err)]
+    pub async fn get_commitment_state(&self) -> Result<CommitmentState, Error> {
+        const RPC: &str = "astria.execution.v1alpha2.ExecutionService/GetCommitmentState";
+        let raw = call_with_retry(
+            &self.config,
+            RPC,
+            raw::GetCommitmentStateRequest {},
+            |request| {
+                let mut client = self.inner.clone();
+                async move { client.get_commitment_state(request).await }
+            },
+        )
+        .await?;
+        CommitmentState::try_from_raw(raw).map_err(|e| Error::invalid_response(RPC, e))
+    }
+
+    /// Calls `astria.execution.v1alpha2.ExecutionService/UpdateCommitmentState`.
+    ///
+    /// # Errors
+    /// Returns an error if the request failed after all retries, or if the returned commitment
+    /// state failed validation.
+    #[instrument(skip_all, fields(uri = %self.uri), err)]
+    pub async fn update_commitment_state(
+        &self,
+        commitment_state: CommitmentState,
+    ) -> Result<CommitmentState, Error> {
+        const RPC: &str = "astria.execution.v1alpha2.ExecutionService/UpdateCommitmentState";
+        let raw = call_with_retry(
+            &self.config,
+            RPC,
+            raw::UpdateCommitmentStateRequest {
+                commitment_state: Some(commitment_state.into_raw()),
+            },
+            |request| {
+                let mut client = self.inner.clone();
+                async move { client.update_commitment_state(request).await }
+            },
+        )
+        .await?;
+        CommitmentState::try_from_raw(raw).map_err(|e| Error::invalid_response(RPC, e))
+    }
+}
@@ -0,0 +1,369 @@
+//! High-level gRPC clients for the Astria services.
+//!
+//! The clients in this module wrap the generated tonic clients and take care of what every
+//! service talking to the sequencer or a rollup's execution API needs:
+//!
+//! - retrying requests that failed with a transient [`tonic::Code`], using exponential backoff as
+//!   configured by [`RetryConfig`];
+//! - bounding each attempt by a deadline, which is also sent to the server as `grpc-timeout`;
+//! - propagating the OpenTelemetry context of the current [`tracing::Span`] in the request
+//!   metadata;
+//! - validating responses by converting them to their native Astria types.
+
+use std::{
+    future::Future,
+    time::Duration,
+};
+
+use opentelemetry::propagation::Injector;
+use tonic::metadata::{
+    MetadataKey,
+    MetadataMap,
+    MetadataValue,
+};
+use tracing::{
+    warn,
+    Instrument as _,
+    Span,
+};
+use tracing_opentelemetry::OpenTelemetrySpanExt as _;
+use tryhard::{
+    backoff_strategies::BackoffStrategy,
+    RetryPolicy,
+};
+
+mod execution;
+mod sequencer;
+
+pub use execution::ExecutionClient;
+pub use sequencer::SequencerClient;
+
+/// The retry and deadline policy of a client.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// The number of times a failed request is retried before giving up.
+    pub max_retries: u32,
+    /// The delay before the first retry. The delay is doubled on every subsequent retry.
+    pub initial_delay: Duration,
+    /// The upper bound of the delay between two retries.
+    pub max_delay: Duration,
+    /// The deadline of a single attempt. `None` means that attempts are not bounded.
+    pub request_timeout: Option<Duration>,
+}
+
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: u32::MAX,
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            request_timeout: Some(Duration::from_secs(10)),
+        }
+    }
+}
+
+impl RetryConfig {
+    /// Returns a config that does not retry failed requests.
+    #[must_use]
+    pub fn no_retries() -> Self {
+        Self {
+            max_retries: 0,
+            ..Self::default()
+        }
+    }
+}
+
+/// An error returned by the clients in this module.
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Error(ErrorKind);
+
+impl Error {
+    fn invalid_uri(source: tonic::transport::Error) -> Self {
+        Self(ErrorKind::InvalidUri(source))
+    }
+
+    fn rpc(rpc: &'static str, source: tonic::Status) -> Self {
+        Self(ErrorKind::Rpc {
+            rpc,
+            source,
+        })
+    }
+
+    fn invalid_response<E>(rpc: &'static str, source: E) -> Self
+    where
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        Self(ErrorKind::InvalidResponse {
+            rpc,
+            source: Box::new(source),
+        })
+    }
+
+    /// Returns the gRPC status of the last failed attempt, if the request failed at the gRPC
+    /// layer.
+    #[must_use]
+    pub fn status(&self) -> Option<&tonic::Status> {
+        match &self.0 {
+            ErrorKind::Rpc {
+                source, ..
+            } => Some(source),
+            ErrorKind::InvalidUri(..)
+            | ErrorKind::InvalidResponse {
+                ..
+            } => None,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum ErrorKind {
+    #[error("failed to construct an endpoint from the provided URI")]
+    InvalidUri(#[source] tonic::transport::Error),
+    #[error(
+        "failed to execute `{rpc}` because of its gRPC status code or because the number of \
+         retries was exhausted"
+    )]
+    Rpc {
+        rpc: &'static str,
+        #[source]
+        source: tonic::Status,
+    },
+    #[error("the response of `{rpc}` failed validation")]
+    InvalidResponse {
+        rpc: &'static str,
+        #[source]
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+/// Creates a lazily connecting channel to `uri`.
+fn connect_lazy(uri: String) -> Result<tonic::transport::Channel, Error> {
+    let endpoint = tonic::transport::Endpoint::from_shared(uri).map_err(Error::invalid_uri)?;
+    Ok(endpoint.connect_lazy())
+}
+
+/// Executes `rpc` with `message`, retrying according to `config`.
+///
+/// `call` is invoked once per attempt with a fresh request carrying the deadline and the
+/// OpenTelemetry context of the current span.
+async fn call_with_retry<TMsg, TResp, TFut, TCall>(
+    config: &RetryConfig,
+    rpc: &'static str,
+    message: TMsg,
+    mut call: TCall,
+) -> Result<TResp, Error>
+where
+    TMsg: Clone,
+    TCall: FnMut(tonic::Request<TMsg>) -> TFut,
+    TFut: Future<Output = Result<tonic::Response<TResp>, tonic::Status>>,
+{
+    let retry_config = tryhard::RetryFutureConfig::new(config.max_retries)
+        .custom_backoff(GrpcRetryStrategy {
+            delay: config.initial_delay,
+        })
+        .max_delay(config.max_delay)
+        .on_retry(OnRetry {
+            parent: Span::current(),
+            rpc,
+        });
+    tryhard::retry_fn(|| call(make_request(message.clone(), config.request_timeout)))
+        .with_config(retry_config)
+        .in_current_span()
+        .await
+        .map(tonic::Response::into_inner)
+        .map_err(|status| Error::rpc(rpc, status))
+}
+
+/// Wraps `message` in a request with the deadline `timeout` and the OpenTelemetry context of the
+/// current span.
+fn make_request<T>(message: T, timeout: Option<Duration>) -> tonic::Request<T> {
+    let mut request = tonic::Request::new(message);
+    if let Some(timeout) = timeout {
+        request.set_timeout(timeout);
+    }
+    let context = Span::current().context();
+    opentelemetry::global::get_text_map_propagator(|propagator| {
+        propagator.inject_context(&context, &mut MetadataInjector(request.metadata_mut()));
+    });
+    request
+}
+
+struct MetadataInjector<'a>(&'a mut MetadataMap);
+
+impl<'a> Injector for MetadataInjector<'a> {
+    fn set(&mut self, key: &str, value: String) {
+        // Keys and values that are not valid gRPC metadata are dropped: failing to propagate the
+        // trace context must not fail the request.
+        if let (Ok(key), Ok(value)) = (
+            MetadataKey::from_bytes(key.as_bytes()),
+            value.parse::<MetadataValue<_>>(),
+        ) {
+            self.0.insert(key, value);
+        }
+    }
+}
+
+struct OnRetry {
+    parent: Span,
+    rpc: &'static str,
+}
+
+impl tryhard::OnRetry<tonic::Status> for OnRetry {
+    type Future = std::future::Ready<()>;
+
+    fn on_retry(
+        &mut self,
+        attempt: u32,
+        next_delay: Option<Duration>,
+        previous_error: &tonic::Status,
+    ) -> Self::Future {
+        let wait_duration = next_delay.map(tracing::field::debug);
+        warn!(
+            parent: self.parent.id(),
+            rpc = self.rpc,
+            attempt,
+            wait_duration,
+            error = previous_error as &dyn std::error::Error,
+            "failed executing RPC; retrying after backoff"
+        );
+        std::future::ready(())
+    }
+}
+
+/// An exponential retry strategy branching on [`tonic::Status::code`].
+///
+/// This retry strategy behaves exactly like
+/// [`tryhard::backoff_strategies::ExponentialBackoff`], but only retries requests that failed
+/// with one of the codes accepted by [`should_retry`].
+struct GrpcRetryStrategy {
+    delay: Duration,
+}
+
+impl<'a> BackoffStrategy<'a, tonic::Status> for GrpcRetryStrategy {
+    type Output = RetryPolicy;
+
+    fn delay(&mut self, _attempt: u32, error: &'a tonic::Status) -> Self::Output {
+        if should_retry(error) {
+            let prev_delay = self.delay;
+            self.delay = self.delay.saturating_mul(2);
+            RetryPolicy::Delay(prev_delay)
+        } else {
+            RetryPolicy::Break
+        }
+    }
+}
+
+/// Returns if a request that failed with `status` should be retried.
+///
+/// Requests are retried if they failed with one of:
+///
+/// ```text
+/// Code::Cancelled
+/// Code::Unknown
+/// Code::DeadlineExceeded
+/// Code::NotFound
+/// Code::ResourceExhausted
+/// Code::Aborted
+/// Code::Unavailable
+/// ```
+///
+/// See <https://github.com/grpc/grpc/blob/1309eb283c3e11c471191f286ceab01b75477ffc/doc/statuscodes.md>
+/// for the meaning of the codes.
+#[must_use]
+pub fn should_retry(status: &tonic::Status) -> bool {
+    use tonic::Code;
+    matches!(
+        status.code(),
+        Code::Cancelled
+            | Code::Unknown
+            | Code::DeadlineExceeded
+            | Code::NotFound
+            | Code::ResourceExhausted
+            | Code::Aborted
+            | Code::Unavailable
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use tonic::{
+        Code,
+        Status,
+    };
+
+    use super::*;
+
+    #[track_caller]
+    fn assert_retry_policy<const SHOULD_RETRY: bool>(code: Code) {
+        let mut strat = GrpcRetryStrategy {
+            delay: Duration::from_secs(1),
+        };
+        let status = Status::new(code, "");
+        let actual = strat.delay(1, &status);
+        if SHOULD_RETRY {
+            let expected = RetryPolicy::Delay(Duration::from_secs(1));
+            assert_eq!(
+                expected, actual,
+                "gRPC code `{code}` should lead to retry, but instead gave break"
+            );
+        } else {
+            let expected = RetryPolicy::Break;
+            assert_eq!(
+                expected, actual,
+                "gRPC code `{code}` should lead to break, but instead gave delay"
+            );
+        }
+    }
+
+    #[test]
+    fn status_codes_lead_to_expected_retry_policy() {
+        const SHOULD_RETRY: bool = true;
+        const SHOULD_BREAK: bool = false;
+        assert_retry_policy::<SHOULD_BREAK>(Code::Ok);
+        assert_retry_policy::<SHOULD_RETRY>(Code::Cancelled);
+        assert_retry_policy::<SHOULD_RETRY>(Code::Unknown);
+        assert_retry_policy::<SHOULD_BREAK>(Code::InvalidArgument);
+        assert_retry_policy::<SHOULD_RETRY>(Code::DeadlineExceeded);
+        assert_retry_policy::<SHOULD_RETRY>(Code::NotFound);
+        assert_retry_policy::<SHOULD_BREAK>(Code::AlreadyExists);
+        assert_retry_policy::<SHOULD_BREAK>(Code::PermissionDenied);
+        assert_retry_policy::<SHOULD_RETRY>(Code::ResourceExhausted);
+        assert_retry_policy::<SHOULD_BREAK>(Code::FailedPrecondition);
+        assert_retry_policy::<SHOULD_RETRY>(Code::Aborted);
+        assert_retry_policy::<SHOULD_BREAK>(Code::OutOfRange);
+        assert_retry_policy::<SHOULD_BREAK>(Code::Unimplemented);
+        assert_retry_policy::<SHOULD_BREAK>(Code::Internal);
+        assert_retry_policy::<SHOULD_RETRY>(Code::Unavailable);
+        assert_retry_policy::<SHOULD_BREAK>(Code::DataLoss);
+        assert_retry_policy::<SHOULD_BREAK>(Code::Unauthenticated);
+    }
+
+    #[test]
+    fn retry_delay_doubles() {
+        let mut strat = GrpcRetryStrategy {
+            delay: Duration::from_millis(100),
+        };
+        let status = Status::unavailable("");
+        for expected in [100, 200, 400] {
+            assert_eq!(
+                RetryPolicy::Delay(Duration::from_millis(expected)),
+                strat.delay(1, &status),
+            );
+        }
+    }
+
+    #[test]
+    fn request_carries_deadline() {
+        let request = make_request((), Some(Duration::from_secs(3)));
+        assert_eq!(
+            "3000000u",
+            request
+                .metadata()
+                .get("grpc-timeout")
+                .unwrap()
+                .to_str()
+                .unwrap(),
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use tonic::transport::Channel;
+use tracing::instrument;
+
+use super::{
+    call_with_retry,
+    connect_lazy,
+    Error,
+    RetryConfig,
+};
+use crate::{
+    generated::sequencerblock::v1alpha1::{
+        sequencer_service_client::SequencerServiceClient,
+        GetFilteredSequencerBlockRequest,
+        GetPendingNonceRequest,
+        GetSequencerBlockRequest,
+    },
+    primitive::v1::{
+        Address,
+        RollupId,
+    },
+    sequencerblock::v1alpha1::{
+        block::FilteredSequencerBlock,
+        SequencerBlock,
+    },
+};
+
+/// A client of the sequencer's `astria.sequencerblock.v1alpha1.SequencerService`.
+#[derive(Clone)]
+pub struct SequencerClient {
+    inner: SequencerServiceClient<Channel>,
+    uri: String,
+    config: RetryConfig,
+}
+
+impl SequencerClient {
+    /// Creates a client that connects to `uri` on first use.
+    ///
+    /// # Errors
+    /// Returns an error if `uri` is not a valid endpoint.
+    pub fn connect_lazy(uri: &str, config: RetryConfig) -> Result<Self, Error> {
+        let inner = SequencerServiceClient::new(connect_lazy(uri.to_string())?);
+        Ok(Self {
+            inner,
+            uri: uri.to_string(),
+            config,
+        })
+    }
+
+    /// Calls `astria.sequencerblock.v1alpha1.SequencerService/GetSequencerBlock`.
+    ///
+    /// # Errors
+    /// Returns an error if the request failed after all retries, or if the returned block failed
+    /// validation.
+    #[instrument(skip_all, fields(uri = %self.uri, height = height), err)]
+    pub async fn get_sequencer_block(&self, height: u64) -> Result<SequencerBlock, Error> {
+        const RPC: &str = "astria.sequencerblock.v1alpha1.SequencerService/GetSequencerBlock";
+        let raw = call_with_retry(
+            &self.config,
+            RPC,
+            GetSequencerBlockRequest {
+                height,
+            },
+            |request| {
+                let mut client = self.inner.clone();
+                async move { client.get_sequencer_block(request).await }
+            },
+        )
+        .await?;
+        SequencerBlock::try_from_raw(raw).map_err(|e| Error::invalid_response(RPC, e))
+    }
+
+    /// Calls `astria.sequencerblock.v1alpha1.SequencerService/GetFilteredSequencerBlock`.
+    ///
+    /// # Errors
+    /// Returns an error if the request failed after all retries, or if the returned block failed
+    /// validation.
+    #[instrument(skip_all, fields(uri = %self.uri, height = height), err)]
+    pub async fn get_filtered_sequencer_block<I>(
+        &self,
+        height: u64,
+        rollup_ids: I,
+    ) -> Result<FilteredSequencerBlock, Error>
+    where
+        I: IntoIterator<Item = RollupId>,
+    {
+        const RPC: &str =
+            "astria.sequencerblock.v1alpha1.SequencerService/GetFilteredSequencerBlock";
+        let raw = call_with_retry(
+            &self.config,
+            RPC,
+            GetFilteredSequencerBlockRequest {
+                height,
+                rollup_ids: rollup_ids.into_iter().map(RollupId::into_raw).collect(),
+            },
+            |request| {
+                let mut client = self.inner.clone();
+                async move { client.get_filtered_sequencer_block(request).await }
+            },
+        )
+        .await?;
+        FilteredSequencerBlock::try_from_raw(raw).map_err(|e| Error::invalid_response(RPC, e))
+    }
+
+    /// Calls `astria.sequencerblock.v1alpha1.SequencerService/GetPendingNonce`.
+    ///
+    /// # Errors
+    /// Returns an error if the request failed after all retries.
+    #[instrument(skip_all, fields(uri = %self.uri, address = %address), err)]
+    pub async fn get_pending_nonce(&self, address: Address) -> Result<u32, Error> {
+        const RPC: &str = "astria.sequencerblock.v1alpha1.SequencerService/GetPendingNonce";
+        let response = call_with_retry(
+            &self.config,
+            RPC,
+            GetPendingNonceRequest {
+                address: Some(address.into_raw()),
+            },
+            |request| {
+                let mut client = self.inner.clone();
+                async move { client.get_pending_nonce(request).await }
+            },
+        )
+        .await?;
+        Ok(response.inner)
+    }
+}
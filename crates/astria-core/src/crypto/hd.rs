@@ -0,0 +1,379 @@
+//! Hierarchical deterministic derivation of signing keys.
+//!
+//! Signing keys are derived from a BIP39 mnemonic following [SLIP-10] for Ed25519. Ed25519 only
+//! supports hardened derivation, so every index of a [`DerivationPath`] is hardened, whether or
+//! not it is written with a trailing `'`.
+//!
+//! [SLIP-10]: https://github.com/satoshilabs/slips/blob/master/slip-0010.md
+
+use std::{
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+    str::FromStr,
+};
+
+use hmac::{
+    Hmac,
+    Mac as _,
+};
+use sha2::Sha512;
+use zeroize::{
+    Zeroize,
+    ZeroizeOnDrop,
+};
+
+use super::SigningKey;
+
+/// The bit set on hardened child indices.
+pub const HARDENED_BIT: u32 = 1 << 31;
+
+/// The SLIP-44 coin type under which sequencer keys are derived.
+///
+/// This is the coin type registered for the Cosmos Hub, so that mnemonics
+/// held in Cosmos wallets derive the same sequencer accounts in all Astria tooling.
+pub const COIN_TYPE: u32 = 118;
+
+const BIP39_ROUNDS: u32 = 2048;
+const SLIP10_CURVE_KEY: &[u8] = b"ed25519 seed";
+
+/// A SLIP-10 derivation path of hardened child indices, such as `m/44'/118'/0'/0'/0'`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DerivationPath {
+    indices: Vec<u32>,
+}
+
+impl DerivationPath {
+    /// Returns the BIP44 path `m/44'/118'/<account>'/0'/0'` used for sequencer accounts.
+    #[must_use]
+    pub fn sequencer_account(account: u32) -> Self {
+        Self {
+            indices: [44, COIN_TYPE, account, 0, 0]
+                .into_iter()
+                .map(|index| index | HARDENED_BIT)
+                .collect(),
+        }
+    }
+
+    /// Returns the hardened child indices of the path.
+    #[must_use]
+    pub fn indices(&self) -> &[u32] {
+        &self.indices
+    }
+}
+
+impl FromStr for DerivationPath {
+    type Err = HdError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut segments = s.split('/');
+        if segments.next() != Some("m") {
+            return Err(HdError::invalid_path(s));
+        }
+        let indices = segments
+            .map(|segment| {
+                let digits = segment
+                    .strip_suffix('\'')
+                    .or_else(|| segment.strip_suffix('h'))
+                    .unwrap_or(segment);
+                match digits.parse::<u32>() {
+                    Ok(index) if index < HARDENED_BIT => Ok(index | HARDENED_BIT),
+                    _ => Err(HdError::invalid_path(s)),
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            indices,
+        })
+    }
+}
+
+impl Display for DerivationPath {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("m")?;
+        for index in &self.indices {
+            write!(f, "/{}'", index & !HARDENED_BIT)?;
+        }
+        Ok(())
+    }
+}
+
+/// A 64 byte BIP39 seed.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct Seed([u8; 64]);
+
+impl Seed {
+    /// Derives the seed of a BIP39 mnemonic and optional passphrase.
+    ///
+    /// The words of the mnemonic are joined by single spaces before the seed is derived. They
+    /// must be taken from the BIP39 English word list and end in a valid checksum, so that a
+    /// mistyped word is rejected rather than deriving an unrelated key. Only ASCII mnemonics and
+    /// passphrases are accepted, which makes the Unicode normalization required by BIP39 a no-op.
+    ///
+    /// # Errors
+    /// Returns an error if the mnemonic or passphrase contain non-ASCII characters, or if the
+    /// mnemonic is not a valid BIP39 mnemonic.
+    pub fn from_mnemonic(mnemonic: &str, passphrase: &str) -> Result<Self, HdError> {
+        if !mnemonic.is_ascii() || !passphrase.is_ascii() {
+            return Err(HdError::non_ascii());
+        }
+        let mut mnemonic = mnemonic.split_whitespace().collect::<Vec<_>>().join(" ");
+        if let Err(source) =
+            bip39::Mnemonic::parse_in_normalized(bip39::Language::English, &mnemonic)
+        {
+            mnemonic.zeroize();
+            return Err(HdError::invalid_mnemonic(source));
+        }
+        let mut salt = format!("mnemonic{passphrase}");
+        let mut seed = [0; 64];
+        pbkdf2::pbkdf2_hmac::<Sha512>(
+            mnemonic.as_bytes(),
+            salt.as_bytes(),
+            BIP39_ROUNDS,
+            &mut seed,
+        );
+        mnemonic.zeroize();
+        salt.zeroize();
+        Ok(Self(seed))
+    }
+
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8; 64] {
+        &self.0
+    }
+}
+
+impl From<[u8; 64]> for Seed {
+    fn from(seed: [u8; 64]) -> Self {
+        Self(seed)
+    }
+}
+
+/// An Ed25519 signing key together with its SLIP-10 chain code.
+#[derive(Clone, Zeroize, ZeroizeOnDrop)]
+pub struct ExtendedSigningKey {
+    key: [u8; 32],
+    chain_code: [u8; 32],
+}
+
+impl ExtendedSigningKey {
+    /// Returns the SLIP-10 master key of `seed`.
+    #[must_use]
+    pub fn master(seed: &Seed) -> Self {
+        Self::from_hmac(SLIP10_CURVE_KEY, &[seed.as_bytes()])
+    }
+
+    /// Derives the hardened child key at `index`.
+    ///
+    /// The hardened bit is set on `index` if it is not already set.
+    #[must_use]
+    pub fn derive_child(&self, index: u32) -> Self {
+        let index = (index | HARDENED_BIT).to_be_bytes();
+        Self::from_hmac(&self.chain_code, &[&[0], &self.key, &index])
+    }
+
+    /// Derives the key at `path` relative to this key.
+    #[must_use]
+    pub fn derive_path(&self, path: &DerivationPath) -> Self {
+        path.indices()
+            .iter()
+            .fold(self.clone(), |key, index| key.derive_child(*index))
+    }
+
+    #[must_use]
+    pub fn chain_code(&self) -> &[u8; 32] {
+        &self.chain_code
+    }
+
+    #[must_use]
+    pub fn signing_key(&self) -> SigningKey {
+        SigningKey::from(self.key)
+    }
+
+    fn from_hmac(key: &[u8], data: &[&[u8]]) -> Self {
+        let mut mac = Hmac::<Sha512>::new_from_slice(key).expect("HMAC accepts keys of any length");
+        for chunk in data {
+            mac.update(chunk);
+        }
+        let mut output = mac.finalize().into_bytes();
+        let mut key = [0; 32];
+        let mut chain_code = [0; 32];
+        key.copy_from_slice(&output[..32]);
+        chain_code.copy_from_slice(&output[32..]);
+        output.as_mut_slice().zeroize();
+        Self {
+            key,
+            chain_code,
+        }
+    }
+}
+
+impl fmt::Debug for ExtendedSigningKey {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExtendedSigningKey")
+            .field("verification_key", &self.signing_key().verification_key())
+            .finish_non_exhaustive() // avoids printing secret fields
+    }
+}
+
+/// Derives the signing key at `path` from a BIP39 mnemonic and optional passphrase.
+///
+/// # Errors
+/// Returns an error if the mnemonic or passphrase contain non-ASCII characters, or if the
+/// mnemonic is not a valid BIP39 mnemonic. See [`Seed::from_mnemonic`].
+pub fn signing_key_from_mnemonic(
+    mnemonic: &str,
+    passphrase: &str,
+    path: &DerivationPath,
+) -> Result<SigningKey, HdError> {
+    let seed = Seed::from_mnemonic(mnemonic, passphrase)?;
+    Ok(ExtendedSigningKey::master(&seed)
+        .derive_path(path)
+        .signing_key())
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct HdError(HdErrorKind);
+
+impl HdError {
+    fn invalid_path(path: &str) -> Self {
+        Self(HdErrorKind::InvalidPath {
+            path: path.to_string(),
+        })
+    }
+
+    fn non_ascii() -> Self {
+        Self(HdErrorKind::NonAscii)
+    }
+
+    fn invalid_mnemonic(reason: bip39::Error) -> Self {
+        Self(HdErrorKind::InvalidMnemonic {
+            reason,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum HdErrorKind {
+    #[error(
+        "`{path}` is not a derivation path of the form `m/<index>'/<index>'/...` with indices \
+         below 2^31"
+    )]
+    InvalidPath { path: String },
+    #[error("the mnemonic and passphrase must only contain ASCII characters")]
+    NonAscii,
+    #[error("the mnemonic is not a valid BIP39 mnemonic: {reason}")]
+    InvalidMnemonic { reason: bip39::Error },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon \
+                            abandon abandon abandon about";
+
+    // Test vector 1 for ed25519 of SLIP-10.
+    #[test]
+    fn slip10_test_vector() {
+        let mut seed = [0; 64];
+        seed[..16].copy_from_slice(&hex::decode("000102030405060708090a0b0c0d0e0f").unwrap());
+        // the test vector seed is only 16 bytes long, so compute the master key directly
+        let master = ExtendedSigningKey::from_hmac(SLIP10_CURVE_KEY, &[&seed[..16]]);
+        assert_eq!(
+            "2b4be7f19ee27bbf30c667b642d5f4aa69fd169872f8fc3059c08ebae2eb19e7",
+            hex::encode(master.key)
+        );
+        assert_eq!(
+            "90046a93de5380a72b5e45010748567d5ea02bbf6522f979e05c0d8d8ca9fffb",
+            hex::encode(master.chain_code())
+        );
+        let child = master.derive_path(&"m/0'".parse().unwrap());
+        assert_eq!(
+            "68e0fe46dfb67e368c75379acec591dad19df3cde26e63b93a8e704f1dade7a3",
+            hex::encode(child.key)
+        );
+        assert_eq!(
+            "8b59aa11380b624e81507a27fedda59fea6d0b779a778918a2fd3590e16e9c69",
+            hex::encode(child.chain_code())
+        );
+    }
+
+    // Test vector of BIP39 with passphrase "TREZOR".
+    #[test]
+    fn bip39_seed_test_vector() {
+        let seed = Seed::from_mnemonic(MNEMONIC, "TREZOR").unwrap();
+        assert_eq!(
+            "c55257c360c07c72029aebc1b53c05ed0362ada38ead3e3e9efa3708e53495531f09a6987599d18264c1e1\
+             c92f2cf141630c7a3c4ab7c81b2f001698e7463b04",
+            hex::encode(seed.as_bytes())
+        );
+    }
+
+    #[test]
+    fn sequencer_account_key_is_derived_from_mnemonic() {
+        let path = DerivationPath::sequencer_account(0);
+        assert_eq!("m/44'/118'/0'/0'/0'", path.to_string());
+        let signing_key = signing_key_from_mnemonic(MNEMONIC, "", &path).unwrap();
+        assert_eq!(
+            "bb749d9a2787819674d3c3447f5f2d245de2ccdec42e62aa6c89bf8761be14bd",
+            hex::encode(signing_key.as_bytes())
+        );
+        // extra whitespace between words does not change the derived key
+        let spaced = MNEMONIC.replace(' ', "  ");
+        let same_key = signing_key_from_mnemonic(&spaced, "", &path).unwrap();
+        assert_eq!(
+            signing_key.to_address("astria").unwrap(),
+            same_key.to_address("astria").unwrap()
+        );
+    }
+
+    fn mnemonic_error(mnemonic: &str) -> HdErrorKind {
+        let Err(error) = Seed::from_mnemonic(mnemonic, "") else {
+            panic!("mnemonic should be rejected");
+        };
+        error.0
+    }
+
+    #[test]
+    fn invalid_mnemonics_are_rejected() {
+        // a word that is not in the word list
+        let typo = MNEMONIC.replace("about", "abuot");
+        assert!(matches!(
+            mnemonic_error(&typo),
+            HdErrorKind::InvalidMnemonic {
+                reason: bip39::Error::UnknownWord(11)
+            }
+        ));
+        // a valid word that breaks the checksum
+        let swapped = MNEMONIC.replace("about", "above");
+        assert!(matches!(
+            mnemonic_error(&swapped),
+            HdErrorKind::InvalidMnemonic {
+                reason: bip39::Error::InvalidChecksum
+            }
+        ));
+        // a word missing
+        let short = MNEMONIC.replacen("abandon ", "", 1);
+        assert!(matches!(
+            mnemonic_error(&short),
+            HdErrorKind::InvalidMnemonic {
+                reason: bip39::Error::BadWordCount(11)
+            }
+        ));
+    }
+
+    #[test]
+    fn derivation_paths_are_parsed() {
+        assert_eq!(
+            DerivationPath::sequencer_account(3),
+            "m/44'/118'/3'/0h/0".parse().unwrap()
+        );
+        assert!("44'/118'".parse::<DerivationPath>().is_err());
+        assert!("m/44'/x".parse::<DerivationPath>().is_err());
+        assert!("m/2147483648".parse::<DerivationPath>().is_err());
+    }
+}
@@ -36,7 +36,13 @@ use zeroize::{
     ZeroizeOnDrop,
 };
 
-use crate::primitive::v1::ADDRESS_LEN;
+use crate::primitive::v1::{
+    Address,
+    AddressError,
+    ADDRESS_LEN,
+};
+
+pub mod hd;
 
 /// An Ed25519 signing key.
 // *Implementation note*: this is currently a refinement type around
@@ -82,6 +88,15 @@ impl SigningKey {
     pub fn address_bytes(&self) -> [u8; ADDRESS_LEN] {
         self.verification_key().address_bytes()
     }
+
+    /// Returns the bech32m encoded sequencer address of the verification key associated with
+    /// this signing key.
+    ///
+    /// # Errors
+    /// Returns an error if `prefix` is not a valid bech32 human readable prefix.
+    pub fn to_address(&self, prefix: &str) -> Result<Address, AddressError> {
+        self.verification_key().to_address(prefix)
+    }
 }
 
 impl Debug for SigningKey {
@@ -162,6 +177,17 @@ impl VerificationKey {
         let bytes: [u8; 32] = Sha256::digest(self).into();
         first_20(bytes)
     }
+
+    /// Returns the bech32m encoded sequencer address of this verification key.
+    ///
+    /// # Errors
+    /// Returns an error if `prefix` is not a valid bech32 human readable prefix.
+    pub fn to_address(&self, prefix: &str) -> Result<Address, AddressError> {
+        Address::builder()
+            .array(self.address_bytes())
+            .prefix(prefix)
+            .try_build()
+    }
 }
 
 impl Debug for VerificationKey {
@@ -271,6 +297,37 @@ impl TryFrom<&[u8]> for Signature {
     }
 }
 
+/// Verifies many Ed25519 signatures at once.
+///
+/// Batch verification is significantly faster than verifying each signature on its own, but
+/// only reports whether all queued signatures are valid. It accepts exactly the signatures that
+/// [`VerificationKey::verify`] accepts, so callers can fall back to verifying each signature on
+/// its own to find the invalid ones.
+#[derive(Default)]
+pub struct BatchVerifier(ed25519_consensus::batch::Verifier);
+
+impl BatchVerifier {
+    /// Creates an empty batch.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues `signature` on `msg` by `verification_key` for verification.
+    pub fn queue(&mut self, verification_key: &VerificationKey, signature: &Signature, msg: &[u8]) {
+        let key_bytes = ed25519_consensus::VerificationKeyBytes::from(verification_key.to_bytes());
+        self.0.queue((key_bytes, signature.0, msg));
+    }
+
+    /// Verifies all queued signatures.
+    ///
+    /// # Errors
+    /// Returns an error if at least one of the queued signatures is invalid.
+    pub fn verify<R: RngCore + CryptoRng>(self, rng: R) -> Result<(), Error> {
+        self.0.verify(rng).map_err(Error)
+    }
+}
+
 /// An error related to Ed25519 signing.
 #[derive(Copy, Clone, Eq, PartialEq, thiserror::Error, Debug)]
 #[error(transparent)]
@@ -425,4 +482,32 @@ mod tests {
         assert!(std_hash(&key0) == std_hash(&other_key0));
         assert!(std_hash(&key0) != std_hash(&key1));
     }
+
+    #[test]
+    fn batch_verification_matches_single_verification() {
+        let messages: Vec<Vec<u8>> = (0..10u8).map(|i| vec![i; 32]).collect();
+        let keys: Vec<_> = messages
+            .iter()
+            .map(|_| SigningKey::new(rand::rngs::OsRng))
+            .collect();
+
+        let mut batch = BatchVerifier::new();
+        for (key, msg) in keys.iter().zip(&messages) {
+            batch.queue(&key.verification_key(), &key.sign(msg), msg);
+        }
+        batch.verify(rand::rngs::OsRng).unwrap();
+
+        let mut batch = BatchVerifier::new();
+        for (key, msg) in keys.iter().zip(&messages) {
+            batch.queue(&key.verification_key(), &key.sign(msg), msg);
+        }
+        batch.queue(
+            &keys[0].verification_key(),
+            &keys[0].sign(b"other"),
+            &messages[0],
+        );
+        batch.verify(rand::rngs::OsRng).unwrap_err();
+
+        BatchVerifier::new().verify(rand::rngs::OsRng).unwrap();
+    }
 }
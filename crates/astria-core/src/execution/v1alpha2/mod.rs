@@ -457,3 +457,282 @@ impl Protobuf for CommitmentState {
         }
     }
 }
+
+/// An error when transforming a [`raw::ExecuteBlocksResponse`] into a [`ExecuteBlocksResponse`].
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct ExecuteBlocksResponseError(ExecuteBlocksResponseErrorKind);
+
+impl ExecuteBlocksResponseError {
+    fn field_not_set(field: &'static str, index: usize) -> Self {
+        Self(ExecuteBlocksResponseErrorKind::FieldNotSet {
+            field,
+            index,
+        })
+    }
+
+    fn block(source: BlockError, index: usize) -> Self {
+        Self(ExecuteBlocksResponseErrorKind::Block {
+            source,
+            index,
+        })
+    }
+
+    fn error_not_last(index: usize) -> Self {
+        Self(ExecuteBlocksResponseErrorKind::ErrorNotLast {
+            index,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum ExecuteBlocksResponseErrorKind {
+    #[error("{field} field not set in result at index `{index}`")]
+    FieldNotSet { field: &'static str, index: usize },
+    #[error(".block field of result at index `{index}` did not contain a valid block")]
+    Block { source: BlockError, index: usize },
+    #[error(
+        "result at index `{index}` is an error but is followed by further results; only the last \
+         result can be an error"
+    )]
+    ErrorNotLast { index: usize },
+}
+
+/// The reason a rollup block requested through `ExecuteBlocks` could not be created.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExecuteBlockError {
+    /// The gRPC status code `ExecuteBlock` would have returned for the block.
+    code: u32,
+    /// A description of the error.
+    message: String,
+}
+
+impl ExecuteBlockError {
+    #[must_use]
+    pub fn new(code: u32, message: String) -> Self {
+        Self {
+            code,
+            message,
+        }
+    }
+
+    #[must_use]
+    pub fn code(&self) -> u32 {
+        self.code
+    }
+
+    #[must_use]
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// The results of a call to `ExecuteBlocks`.
+///
+/// Contains the blocks that were created, in the order they were requested, followed by the
+/// reason the next block could not be created if execution stopped early. If `failure` is
+/// not set then either all requested blocks were created, or the rollup stopped without
+/// reporting an error; callers must compare the number of executed blocks against the
+/// number of requested blocks.
+///
+/// Usually constructed its [`Protobuf`] implementation from a
+/// [`raw::ExecuteBlocksResponse`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "crate::generated::execution::v1alpha2::ExecuteBlocksResponse")
+)]
+pub struct ExecuteBlocksResponse {
+    /// The blocks that were created.
+    executed: Vec<Block>,
+    /// The reason the block after the last executed one could not be created.
+    failure: Option<ExecuteBlockError>,
+}
+
+impl ExecuteBlocksResponse {
+    #[must_use]
+    pub fn new(executed: Vec<Block>, failure: Option<ExecuteBlockError>) -> Self {
+        Self {
+            executed,
+            failure,
+        }
+    }
+
+    #[must_use]
+    pub fn executed(&self) -> &[Block] {
+        &self.executed
+    }
+
+    #[must_use]
+    pub fn failure(&self) -> Option<&ExecuteBlockError> {
+        self.failure.as_ref()
+    }
+
+    #[must_use]
+    pub fn into_parts(self) -> (Vec<Block>, Option<ExecuteBlockError>) {
+        (self.executed, self.failure)
+    }
+}
+
+impl From<ExecuteBlocksResponse> for raw::ExecuteBlocksResponse {
+    fn from(value: ExecuteBlocksResponse) -> Self {
+        value.to_raw()
+    }
+}
+
+impl Protobuf for ExecuteBlocksResponse {
+    type Error = ExecuteBlocksResponseError;
+    type Raw = raw::ExecuteBlocksResponse;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        use raw::execute_block_result::Value;
+
+        let Self::Raw {
+            results,
+        } = raw;
+        let mut executed = Vec::with_capacity(results.len());
+        let mut failure = None;
+        let mut results = results.iter().enumerate().peekable();
+        while let Some((index, result)) = results.next() {
+            match &result.value {
+                Some(Value::Block(block)) => executed.push(
+                    Block::try_from_raw_ref(block).map_err(|e| Self::Error::block(e, index))?,
+                ),
+                Some(Value::Error(raw::ExecuteBlockError {
+                    code,
+                    message,
+                })) => {
+                    if results.peek().is_some() {
+                        return Err(Self::Error::error_not_last(index));
+                    }
+                    failure = Some(ExecuteBlockError::new(*code, message.clone()));
+                }
+                None => return Err(Self::Error::field_not_set(".value", index)),
+            }
+        }
+        Ok(Self {
+            executed,
+            failure,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        use raw::execute_block_result::Value;
+
+        let Self {
+            executed,
+            failure,
+        } = self;
+        let results = executed
+            .iter()
+            .map(|block| Value::Block(block.to_raw()))
+            .chain(failure.iter().map(|failure| {
+                Value::Error(raw::ExecuteBlockError {
+                    code: failure.code,
+                    message: failure.message.clone(),
+                })
+            }))
+            .map(|value| raw::ExecuteBlockResult {
+                value: Some(value),
+            })
+            .collect();
+        Self::Raw {
+            results,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn raw_block(number: u32) -> raw::Block {
+        raw::Block {
+            number,
+            hash: Bytes::copy_from_slice(&number.to_be_bytes()),
+            parent_block_hash: Bytes::copy_from_slice(&number.saturating_sub(1).to_be_bytes()),
+            timestamp: Some(Timestamp {
+                seconds: i64::from(number),
+                nanos: 0,
+            }),
+        }
+    }
+
+    fn raw_result(value: raw::execute_block_result::Value) -> raw::ExecuteBlockResult {
+        raw::ExecuteBlockResult {
+            value: Some(value),
+        }
+    }
+
+    #[test]
+    fn execute_blocks_response_roundtrips() {
+        use raw::execute_block_result::Value;
+
+        let raw = raw::ExecuteBlocksResponse {
+            results: vec![
+                raw_result(Value::Block(raw_block(1))),
+                raw_result(Value::Block(raw_block(2))),
+                raw_result(Value::Error(raw::ExecuteBlockError {
+                    code: 3,
+                    message: "invalid transaction".to_string(),
+                })),
+            ],
+        };
+        let response = ExecuteBlocksResponse::try_from_raw_ref(&raw).unwrap();
+        assert_eq!(2, response.executed().len());
+        assert_eq!(3, response.failure().unwrap().code());
+        assert_eq!(raw, response.to_raw());
+    }
+
+    #[test]
+    fn execute_blocks_response_rejects_error_before_last_result() {
+        use raw::execute_block_result::Value;
+
+        let raw = raw::ExecuteBlocksResponse {
+            results: vec![
+                raw_result(Value::Block(raw_block(1))),
+                raw_result(Value::Error(raw::ExecuteBlockError {
+                    code: 3,
+                    message: "invalid transaction".to_string(),
+                })),
+                raw_result(Value::Block(raw_block(2))),
+            ],
+        };
+        let error = ExecuteBlocksResponse::try_from_raw_ref(&raw).unwrap_err();
+        assert!(
+            matches!(
+                error.0,
+                ExecuteBlocksResponseErrorKind::ErrorNotLast {
+                    index: 1
+                }
+            ),
+            "{error:?}"
+        );
+    }
+
+    #[test]
+    fn execute_blocks_response_rejects_unset_result() {
+        use raw::execute_block_result::Value;
+
+        let raw = raw::ExecuteBlocksResponse {
+            results: vec![
+                raw_result(Value::Block(raw_block(1))),
+                raw::ExecuteBlockResult {
+                    value: None,
+                },
+            ],
+        };
+        let error = ExecuteBlocksResponse::try_from_raw_ref(&raw).unwrap_err();
+        assert!(
+            matches!(
+                error.0,
+                ExecuteBlocksResponseErrorKind::FieldNotSet {
+                    index: 1,
+                    ..
+                }
+            ),
+            "{error:?}"
+        );
+    }
+}
@@ -0,0 +1,474 @@
+/// GetNextRollupHeightRequest is sent by a rollup to learn from which height onwards it has to
+/// push withdrawals to the Bridge Withdrawer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNextRollupHeightRequest {}
+impl ::prost::Name for GetNextRollupHeightRequest {
+    const NAME: &'static str = "GetNextRollupHeightRequest";
+    const PACKAGE: &'static str = "astria.bridge.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.bridge.v1alpha1.{}", Self::NAME)
+    }
+}
+/// GetNextRollupHeightResponse contains the lowest rollup height whose withdrawals have not yet
+/// been accepted by the Bridge Withdrawer.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetNextRollupHeightResponse {
+    #[prost(uint64, tag = "1")]
+    pub rollup_height: u64,
+}
+impl ::prost::Name for GetNextRollupHeightResponse {
+    const NAME: &'static str = "GetNextRollupHeightResponse";
+    const PACKAGE: &'static str = "astria.bridge.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.bridge.v1alpha1.{}", Self::NAME)
+    }
+}
+/// SubmitWithdrawalBatchRequest contains all withdrawals a rollup made at a single height.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitWithdrawalBatchRequest {
+    /// the rollup height at which the withdrawals were made
+    #[prost(uint64, tag = "1")]
+    pub rollup_height: u64,
+    /// the withdrawals as protobuf encoded `astria.protocol.transactions.v1alpha1.Action`s. Only
+    /// `BridgeUnlockAction` and `Ics20Withdrawal` are accepted.
+    #[prost(bytes = "vec", repeated, tag = "2")]
+    pub actions: ::prost::alloc::vec::Vec<::prost::alloc::vec::Vec<u8>>,
+}
+impl ::prost::Name for SubmitWithdrawalBatchRequest {
+    const NAME: &'static str = "SubmitWithdrawalBatchRequest";
+    const PACKAGE: &'static str = "astria.bridge.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.bridge.v1alpha1.{}", Self::NAME)
+    }
+}
+/// SubmitWithdrawalBatchResponse is a message that represents a response to a request to submit a
+/// withdrawal batch. It's currently an empty response which can be evolved in the future to include
+/// more information.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitWithdrawalBatchResponse {}
+impl ::prost::Name for SubmitWithdrawalBatchResponse {
+    const NAME: &'static str = "SubmitWithdrawalBatchResponse";
+    const PACKAGE: &'static str = "astria.bridge.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.bridge.v1alpha1.{}", Self::NAME)
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "client")]
+pub mod withdrawal_event_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// WithdrawalEventService is a service that allows rollups to push their withdrawals to the Bridge
+    /// Withdrawer instead of the Bridge Withdrawer watching the rollup for them.
+    #[derive(Debug, Clone)]
+    pub struct WithdrawalEventServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl WithdrawalEventServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> WithdrawalEventServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> WithdrawalEventServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            WithdrawalEventServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// GetNextRollupHeight returns the rollup height at which the rollup should resume pushing
+        /// withdrawals.
+        pub async fn get_next_rollup_height(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetNextRollupHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNextRollupHeightResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.bridge.v1alpha1.WithdrawalEventService/GetNextRollupHeight",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.bridge.v1alpha1.WithdrawalEventService",
+                        "GetNextRollupHeight",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// SubmitWithdrawalBatch submits the withdrawals made at a rollup height to the Bridge
+        /// Withdrawer. Batches must be submitted in order of increasing rollup height. Batches for heights
+        /// that were already accepted are acknowledged without being submitted again.
+        pub async fn submit_withdrawal_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubmitWithdrawalBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitWithdrawalBatchResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.bridge.v1alpha1.WithdrawalEventService/SubmitWithdrawalBatch",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.bridge.v1alpha1.WithdrawalEventService",
+                        "SubmitWithdrawalBatch",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod withdrawal_event_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with WithdrawalEventServiceServer.
+    #[async_trait]
+    pub trait WithdrawalEventService: Send + Sync + 'static {
+        /// GetNextRollupHeight returns the rollup height at which the rollup should resume pushing
+        /// withdrawals.
+        async fn get_next_rollup_height(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::GetNextRollupHeightRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetNextRollupHeightResponse>,
+            tonic::Status,
+        >;
+        /// SubmitWithdrawalBatch submits the withdrawals made at a rollup height to the Bridge
+        /// Withdrawer. Batches must be submitted in order of increasing rollup height. Batches for heights
+        /// that were already accepted are acknowledged without being submitted again.
+        async fn submit_withdrawal_batch(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::SubmitWithdrawalBatchRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitWithdrawalBatchResponse>,
+            tonic::Status,
+        >;
+    }
+    /// WithdrawalEventService is a service that allows rollups to push their withdrawals to the Bridge
+    /// Withdrawer instead of the Bridge Withdrawer watching the rollup for them.
+    #[derive(Debug)]
+    pub struct WithdrawalEventServiceServer<T: WithdrawalEventService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: WithdrawalEventService> WithdrawalEventServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>>
+    for WithdrawalEventServiceServer<T>
+    where
+        T: WithdrawalEventService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/astria.bridge.v1alpha1.WithdrawalEventService/GetNextRollupHeight" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetNextRollupHeightSvc<T: WithdrawalEventService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: WithdrawalEventService,
+                    > tonic::server::UnaryService<super::GetNextRollupHeightRequest>
+                    for GetNextRollupHeightSvc<T> {
+                        type Response = super::GetNextRollupHeightResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::GetNextRollupHeightRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WithdrawalEventService>::get_next_rollup_height(
+                                        inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetNextRollupHeightSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/astria.bridge.v1alpha1.WithdrawalEventService/SubmitWithdrawalBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubmitWithdrawalBatchSvc<T: WithdrawalEventService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: WithdrawalEventService,
+                    > tonic::server::UnaryService<super::SubmitWithdrawalBatchRequest>
+                    for SubmitWithdrawalBatchSvc<T> {
+                        type Response = super::SubmitWithdrawalBatchResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::SubmitWithdrawalBatchRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WithdrawalEventService>::submit_withdrawal_batch(
+                                        inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SubmitWithdrawalBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: WithdrawalEventService> Clone for WithdrawalEventServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: WithdrawalEventService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: WithdrawalEventService> tonic::server::NamedService
+    for WithdrawalEventServiceServer<T> {
+        const NAME: &'static str = "astria.bridge.v1alpha1.WithdrawalEventService";
+    }
+}
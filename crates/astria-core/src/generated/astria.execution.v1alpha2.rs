@@ -152,6 +152,111 @@ impl ::prost::Name for ExecuteBlockRequest {
         ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
     }
 }
+/// The information needed to create one of the rollup blocks requested in
+/// ExecuteBlocks.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BlockToExecute {
+    /// List of transactions to include in the new block.
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<
+        super::super::sequencerblock::v1alpha1::RollupData,
+    >,
+    /// Timestamp to be used for new block.
+    #[prost(message, optional, tag = "2")]
+    pub timestamp: ::core::option::Option<::pbjson_types::Timestamp>,
+}
+impl ::prost::Name for BlockToExecute {
+    const NAME: &'static str = "BlockToExecute";
+    const PACKAGE: &'static str = "astria.execution.v1alpha2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
+    }
+}
+/// ExecuteBlocksRequest contains all the information needed to create several
+/// consecutive rollup blocks in a single call.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecuteBlocksRequest {
+    /// The hash of previous block, which the first new block will be created on
+    /// top of. Every following block is created on top of the block created
+    /// before it.
+    #[prost(bytes = "bytes", tag = "1")]
+    pub prev_block_hash: ::prost::bytes::Bytes,
+    /// The blocks to create, in order.
+    #[prost(message, repeated, tag = "2")]
+    pub blocks: ::prost::alloc::vec::Vec<BlockToExecute>,
+}
+impl ::prost::Name for ExecuteBlocksRequest {
+    const NAME: &'static str = "ExecuteBlocksRequest";
+    const PACKAGE: &'static str = "astria.execution.v1alpha2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
+    }
+}
+/// Describes why a block requested in ExecuteBlocks could not be created.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecuteBlockError {
+    /// The gRPC status code ExecuteBlock would have returned for the block.
+    #[prost(uint32, tag = "1")]
+    pub code: u32,
+    /// A description of the error.
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+impl ::prost::Name for ExecuteBlockError {
+    const NAME: &'static str = "ExecuteBlockError";
+    const PACKAGE: &'static str = "astria.execution.v1alpha2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
+    }
+}
+/// The outcome of creating one of the blocks requested in ExecuteBlocks.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecuteBlockResult {
+    #[prost(oneof = "execute_block_result::Value", tags = "1, 2")]
+    pub value: ::core::option::Option<execute_block_result::Value>,
+}
+/// Nested message and enum types in `ExecuteBlockResult`.
+pub mod execute_block_result {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        /// The block that was created.
+        #[prost(message, tag = "1")]
+        Block(super::Block),
+        /// The reason the block could not be created.
+        #[prost(message, tag = "2")]
+        Error(super::ExecuteBlockError),
+    }
+}
+impl ::prost::Name for ExecuteBlockResult {
+    const NAME: &'static str = "ExecuteBlockResult";
+    const PACKAGE: &'static str = "astria.execution.v1alpha2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
+    }
+}
+/// The list of results in response to ExecuteBlocks.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ExecuteBlocksResponse {
+    /// The result of every block that was attempted, in the order they were
+    /// requested. Execution stops at the first block that cannot be created, so
+    /// only the last result can be an error and the blocks after it have no
+    /// result.
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<ExecuteBlockResult>,
+}
+impl ::prost::Name for ExecuteBlocksResponse {
+    const NAME: &'static str = "ExecuteBlocksResponse";
+    const PACKAGE: &'static str = "astria.execution.v1alpha2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.execution.v1alpha2.{}", Self::NAME)
+    }
+}
 /// The CommitmentState holds the block at each stage of sequencer commitment
 /// level
 ///
@@ -413,6 +518,39 @@ pub mod execution_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// ExecuteBlocks is called to deterministically derive several consecutive
+        /// rollup blocks in a single call, such as when catching up to the sequencer.
+        /// It is equivalent to calling ExecuteBlock for each block in order.
+        pub async fn execute_blocks(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ExecuteBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExecuteBlocksResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.execution.v1alpha2.ExecutionService/ExecuteBlocks",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.execution.v1alpha2.ExecutionService",
+                        "ExecuteBlocks",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         /// GetCommitmentState fetches the current CommitmentState of the chain.
         pub async fn get_commitment_state(
             &mut self,
@@ -511,6 +649,16 @@ pub mod execution_service_server {
             self: std::sync::Arc<Self>,
             request: tonic::Request<super::ExecuteBlockRequest>,
         ) -> std::result::Result<tonic::Response<super::Block>, tonic::Status>;
+        /// ExecuteBlocks is called to deterministically derive several consecutive
+        /// rollup blocks in a single call, such as when catching up to the sequencer.
+        /// It is equivalent to calling ExecuteBlock for each block in order.
+        async fn execute_blocks(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::ExecuteBlocksRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ExecuteBlocksResponse>,
+            tonic::Status,
+        >;
         /// GetCommitmentState fetches the current CommitmentState of the chain.
         async fn get_commitment_state(
             self: std::sync::Arc<Self>,
@@ -793,6 +941,52 @@ pub mod execution_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/astria.execution.v1alpha2.ExecutionService/ExecuteBlocks" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExecuteBlocksSvc<T: ExecutionService>(pub Arc<T>);
+                    impl<
+                        T: ExecutionService,
+                    > tonic::server::UnaryService<super::ExecuteBlocksRequest>
+                    for ExecuteBlocksSvc<T> {
+                        type Response = super::ExecuteBlocksResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ExecuteBlocksRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ExecutionService>::execute_blocks(inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = ExecuteBlocksSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/astria.execution.v1alpha2.ExecutionService/GetCommitmentState" => {
                     #[allow(non_camel_case_types)]
                     struct GetCommitmentStateSvc<T: ExecutionService>(pub Arc<T>);
@@ -15,3 +15,47 @@ impl ::prost::Name for BridgeAccountLastTxHashResponse {
         ::prost::alloc::format!("astria.protocol.bridge.v1alpha1.{}", Self::NAME)
     }
 }
+/// The details of a bridge account.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeAccountInfo {
+    /// The address of the bridge account.
+    #[prost(message, optional, tag = "1")]
+    pub address: ::core::option::Option<super::super::super::primitive::v1::Address>,
+    /// The rollup that the bridge account deposits to.
+    #[prost(message, optional, tag = "2")]
+    pub rollup_id: ::core::option::Option<super::super::super::primitive::v1::RollupId>,
+    /// The ID of the asset that can be bridged through the bridge account.
+    #[prost(bytes = "vec", tag = "3")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// The address that can change the bridge account's settings.
+    #[prost(message, optional, tag = "4")]
+    pub sudo_address: ::core::option::Option<super::super::super::primitive::v1::Address>,
+    /// The address that can withdraw funds from the bridge account.
+    #[prost(message, optional, tag = "5")]
+    pub withdrawer_address: ::core::option::Option<super::super::super::primitive::v1::Address>,
+}
+impl ::prost::Name for BridgeAccountInfo {
+    const NAME: &'static str = "BridgeAccountInfo";
+    const PACKAGE: &'static str = "astria.protocol.bridge.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.bridge.v1alpha1.{}", Self::NAME)
+    }
+}
+/// A response containing the bridge accounts matching a query, either the bridge account at a
+/// given address or all bridge accounts of a given rollup.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeAccountInfoResponse {
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub accounts: ::prost::alloc::vec::Vec<BridgeAccountInfo>,
+}
+impl ::prost::Name for BridgeAccountInfoResponse {
+    const NAME: &'static str = "BridgeAccountInfoResponse";
+    const PACKAGE: &'static str = "astria.protocol.bridge.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.bridge.v1alpha1.{}", Self::NAME)
+    }
+}
@@ -0,0 +1,610 @@
+/// A transaction waiting in the sequencer's app-side mempool.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MempoolTransaction {
+    /// The sha256 hash of the protobuf encoded `SignedTransaction`.
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// The account that signed the transaction.
+    #[prost(message, optional, tag = "2")]
+    pub signer: ::core::option::Option<super::super::super::primitive::v1::Address>,
+    /// The nonce of the transaction.
+    #[prost(uint32, tag = "3")]
+    pub nonce: u32,
+    /// The protobuf encoded `astria.protocol.transactions.v1alpha1.SignedTransaction`.
+    #[prost(bytes = "vec", tag = "4")]
+    pub transaction: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for MempoolTransaction {
+    const NAME: &'static str = "MempoolTransaction";
+    const PACKAGE: &'static str = "astria.protocol.mempool.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.mempool.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTransactionsRequest {
+    /// If set, only transactions signed by this account are returned.
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<super::super::super::primitive::v1::Address>,
+    /// The maximum number of transactions returned. The sequencer caps this value and uses its
+    /// maximum if it is 0.
+    #[prost(uint32, tag = "2")]
+    pub page_size: u32,
+    /// The `next_page_token` of the previous response. Empty to request the first page.
+    #[prost(bytes = "vec", tag = "3")]
+    pub page_token: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for GetTransactionsRequest {
+    const NAME: &'static str = "GetTransactionsRequest";
+    const PACKAGE: &'static str = "astria.protocol.mempool.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.mempool.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetTransactionsResponse {
+    /// The transactions of the page, ordered by their hash.
+    #[prost(message, repeated, tag = "1")]
+    pub transactions: ::prost::alloc::vec::Vec<MempoolTransaction>,
+    /// The token to request the next page with. Empty if this is the last page.
+    #[prost(bytes = "vec", tag = "2")]
+    pub next_page_token: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for GetTransactionsResponse {
+    const NAME: &'static str = "GetTransactionsResponse";
+    const PACKAGE: &'static str = "astria.protocol.mempool.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.mempool.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPendingNonceRequest {
+    /// The account to retrieve the pending nonce for.
+    #[prost(message, optional, tag = "1")]
+    pub account: ::core::option::Option<super::super::super::primitive::v1::Address>,
+}
+impl ::prost::Name for GetPendingNonceRequest {
+    const NAME: &'static str = "GetPendingNonceRequest";
+    const PACKAGE: &'static str = "astria.protocol.mempool.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.mempool.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetPendingNonceResponse {
+    /// The highest nonce of the account's transactions in the mempool, or its current nonce if it
+    /// has none.
+    #[prost(uint32, tag = "1")]
+    pub pending_nonce: u32,
+}
+impl ::prost::Name for GetPendingNonceResponse {
+    const NAME: &'static str = "GetPendingNonceResponse";
+    const PACKAGE: &'static str = "astria.protocol.mempool.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.mempool.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitTransactionRequest {
+    /// The protobuf encoded `astria.protocol.transactions.v1alpha1.SignedTransaction`.
+    #[prost(bytes = "vec", tag = "1")]
+    pub transaction: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for SubmitTransactionRequest {
+    const NAME: &'static str = "SubmitTransactionRequest";
+    const PACKAGE: &'static str = "astria.protocol.mempool.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.mempool.v1alpha1.{}", Self::NAME)
+    }
+}
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitTransactionResponse {
+    /// The sha256 hash of the submitted transaction.
+    #[prost(bytes = "vec", tag = "1")]
+    pub hash: ::prost::alloc::vec::Vec<u8>,
+    /// The result code of the checks, using the same codes as a CometBFT `CheckTx` response.
+    /// 0 means the transaction was accepted into the mempool.
+    #[prost(uint32, tag = "2")]
+    pub code: u32,
+    /// Additional information about why the transaction was rejected.
+    #[prost(string, tag = "3")]
+    pub info: ::prost::alloc::string::String,
+    /// The error that caused the transaction to be rejected.
+    #[prost(string, tag = "4")]
+    pub log: ::prost::alloc::string::String,
+}
+impl ::prost::Name for SubmitTransactionResponse {
+    const NAME: &'static str = "SubmitTransactionResponse";
+    const PACKAGE: &'static str = "astria.protocol.mempool.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.mempool.v1alpha1.{}", Self::NAME)
+    }
+}
+/// Generated client implementations.
+#[cfg(feature = "client")]
+pub mod mempool_service_client {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// MempoolService allows inspecting the sequencer's app-side mempool and submitting transactions
+    /// to it directly.
+    #[derive(Debug, Clone)]
+    pub struct MempoolServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl MempoolServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> MempoolServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> MempoolServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::BoxBody>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::BoxBody>,
+            >>::Error: Into<StdError> + Send + Sync,
+        {
+            MempoolServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        /// GetTransactions returns a page of the transactions in the mempool.
+        pub async fn get_transactions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetTransactionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTransactionsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.protocol.mempool.v1alpha1.MempoolService/GetTransactions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.protocol.mempool.v1alpha1.MempoolService",
+                        "GetTransactions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// GetPendingNonce returns the pending nonce of an account.
+        pub async fn get_pending_nonce(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetPendingNonceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetPendingNonceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.protocol.mempool.v1alpha1.MempoolService/GetPendingNonce",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.protocol.mempool.v1alpha1.MempoolService",
+                        "GetPendingNonce",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// SubmitTransaction runs the checks of CometBFT's `CheckTx` on the transaction and inserts it
+        /// into the mempool if it passes them. The transaction is not gossiped to other nodes, so it is
+        /// only included in blocks proposed by this node.
+        pub async fn submit_transaction(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubmitTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitTransactionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::new(
+                        tonic::Code::Unknown,
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/astria.protocol.mempool.v1alpha1.MempoolService/SubmitTransaction",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "astria.protocol.mempool.v1alpha1.MempoolService",
+                        "SubmitTransaction",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+#[cfg(feature = "server")]
+pub mod mempool_service_server {
+    #![allow(unused_variables, dead_code, missing_docs, clippy::let_unit_value)]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with MempoolServiceServer.
+    #[async_trait]
+    pub trait MempoolService: Send + Sync + 'static {
+        /// GetTransactions returns a page of the transactions in the mempool.
+        async fn get_transactions(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::GetTransactionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetTransactionsResponse>,
+            tonic::Status,
+        >;
+        /// GetPendingNonce returns the pending nonce of an account.
+        async fn get_pending_nonce(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::GetPendingNonceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetPendingNonceResponse>,
+            tonic::Status,
+        >;
+        /// SubmitTransaction runs the checks of CometBFT's `CheckTx` on the transaction and inserts it
+        /// into the mempool if it passes them. The transaction is not gossiped to other nodes, so it is
+        /// only included in blocks proposed by this node.
+        async fn submit_transaction(
+            self: std::sync::Arc<Self>,
+            request: tonic::Request<super::SubmitTransactionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SubmitTransactionResponse>,
+            tonic::Status,
+        >;
+    }
+    /// MempoolService allows inspecting the sequencer's app-side mempool and submitting transactions
+    /// to it directly.
+    #[derive(Debug)]
+    pub struct MempoolServiceServer<T: MempoolService> {
+        inner: _Inner<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    struct _Inner<T>(Arc<T>);
+    impl<T: MempoolService> MempoolServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            let inner = _Inner(inner);
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>>
+    for MempoolServiceServer<T>
+    where
+        T: MempoolService,
+        B: Body + Send + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/astria.protocol.mempool.v1alpha1.MempoolService/GetTransactions" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetTransactionsSvc<T: MempoolService>(pub Arc<T>);
+                    impl<
+                        T: MempoolService,
+                    > tonic::server::UnaryService<super::GetTransactionsRequest>
+                    for GetTransactionsSvc<T> {
+                        type Response = super::GetTransactionsResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetTransactionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MempoolService>::get_transactions(inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetTransactionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/astria.protocol.mempool.v1alpha1.MempoolService/GetPendingNonce" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetPendingNonceSvc<T: MempoolService>(pub Arc<T>);
+                    impl<
+                        T: MempoolService,
+                    > tonic::server::UnaryService<super::GetPendingNonceRequest>
+                    for GetPendingNonceSvc<T> {
+                        type Response = super::GetPendingNonceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetPendingNonceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MempoolService>::get_pending_nonce(inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = GetPendingNonceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/astria.protocol.mempool.v1alpha1.MempoolService/SubmitTransaction" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubmitTransactionSvc<T: MempoolService>(pub Arc<T>);
+                    impl<
+                        T: MempoolService,
+                    > tonic::server::UnaryService<super::SubmitTransactionRequest>
+                    for SubmitTransactionSvc<T> {
+                        type Response = super::SubmitTransactionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubmitTransactionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MempoolService>::submit_transaction(inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let inner = inner.0;
+                        let method = SubmitTransactionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => {
+                    Box::pin(async move {
+                        Ok(
+                            http::Response::builder()
+                                .status(200)
+                                .header("grpc-status", "12")
+                                .header("content-type", "application/grpc")
+                                .body(empty_body())
+                                .unwrap(),
+                        )
+                    })
+                }
+            }
+        }
+    }
+    impl<T: MempoolService> Clone for MempoolServiceServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self {
+                inner,
+                accept_compression_encodings: self.accept_compression_encodings,
+                send_compression_encodings: self.send_compression_encodings,
+                max_decoding_message_size: self.max_decoding_message_size,
+                max_encoding_message_size: self.max_encoding_message_size,
+            }
+        }
+    }
+    impl<T: MempoolService> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(Arc::clone(&self.0))
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: MempoolService> tonic::server::NamedService for MempoolServiceServer<T> {
+        const NAME: &'static str = "astria.protocol.mempool.v1alpha1.MempoolService";
+    }
+}
@@ -59,7 +59,7 @@ impl ::prost::Name for TransactionParams {
 pub struct Action {
     #[prost(
         oneof = "action::Value",
-        tags = "1, 2, 11, 12, 13, 14, 21, 22, 50, 51, 52, 53, 55"
+        tags = "1, 2, 3, 4, 11, 12, 13, 14, 15, 21, 22, 50, 51, 52, 53, 55, 56, 57, 58"
     )]
     pub value: ::core::option::Option<action::Value>,
 }
@@ -73,6 +73,10 @@ pub mod action {
         TransferAction(super::TransferAction),
         #[prost(message, tag = "2")]
         SequenceAction(super::SequenceAction),
+        #[prost(message, tag = "3")]
+        AllowanceChangeAction(super::AllowanceChangeAction),
+        #[prost(message, tag = "4")]
+        TransferFromAction(super::TransferFromAction),
         /// Bridge actions are defined on 11-20
         #[prost(message, tag = "11")]
         InitBridgeAccountAction(super::InitBridgeAccountAction),
@@ -82,6 +86,8 @@ pub mod action {
         BridgeUnlockAction(super::BridgeUnlockAction),
         #[prost(message, tag = "14")]
         BridgeSudoChangeAction(super::BridgeSudoChangeAction),
+        #[prost(message, tag = "15")]
+        BridgeRecoveryVetoAction(super::BridgeRecoveryVetoAction),
         /// IBC user actions are defined on 21-30
         #[prost(message, tag = "21")]
         IbcAction(::penumbra_proto::core::component::ibc::v1::IbcRelay),
@@ -98,6 +104,12 @@ pub mod action {
         FeeAssetChangeAction(super::FeeAssetChangeAction),
         #[prost(message, tag = "55")]
         FeeChangeAction(super::FeeChangeAction),
+        #[prost(message, tag = "56")]
+        AddressPrefixChangeAction(super::AddressPrefixChangeAction),
+        #[prost(message, tag = "57")]
+        BridgeRecoveryAction(super::BridgeRecoveryAction),
+        #[prost(message, tag = "58")]
+        FeeDistributionChangeAction(super::FeeDistributionChangeAction),
     }
 }
 impl ::prost::Name for Action {
@@ -155,6 +167,63 @@ impl ::prost::Name for SequenceAction {
         ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
     }
 }
+/// `AllowanceChangeAction` authorizes `spender` to transfer up to `amount`
+/// of the signer's `asset_id` on the signer's behalf until (and including)
+/// the block at `expiry_height`.
+///
+/// Any existing allowance of the spender for the same asset is replaced.
+/// An `amount` of zero revokes the allowance.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AllowanceChangeAction {
+    #[prost(message, optional, tag = "1")]
+    pub spender: ::core::option::Option<super::super::super::primitive::v1::Address>,
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the asset the spender is allowed to transfer
+    #[prost(bytes = "vec", tag = "3")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// the last block height at which the allowance can be spent
+    #[prost(uint64, tag = "4")]
+    pub expiry_height: u64,
+    /// the asset used to pay the transaction fee
+    #[prost(bytes = "vec", tag = "5")]
+    pub fee_asset_id: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for AllowanceChangeAction {
+    const NAME: &'static str = "AllowanceChangeAction";
+    const PACKAGE: &'static str = "astria.protocol.transactions.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
+    }
+}
+/// `TransferFromAction` transfers funds from `owner` to `to`, spending
+/// an allowance the owner granted to the signer of the transaction.
+///
+/// The transaction fee is paid by the signer, not the owner.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TransferFromAction {
+    #[prost(message, optional, tag = "1")]
+    pub owner: ::core::option::Option<super::super::super::primitive::v1::Address>,
+    #[prost(message, optional, tag = "2")]
+    pub to: ::core::option::Option<super::super::super::primitive::v1::Address>,
+    #[prost(message, optional, tag = "3")]
+    pub amount: ::core::option::Option<super::super::super::primitive::v1::Uint128>,
+    /// the asset to be transferred
+    #[prost(bytes = "vec", tag = "4")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// the asset used to pay the transaction fee
+    #[prost(bytes = "vec", tag = "5")]
+    pub fee_asset_id: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for TransferFromAction {
+    const NAME: &'static str = "TransferFromAction";
+    const PACKAGE: &'static str = "astria.protocol.transactions.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
+    }
+}
 /// / `SudoAddressChangeAction` represents a transaction that changes
 /// / the sudo address of the chain, which is the address authorized to
 /// / make validator update actions.
@@ -298,6 +367,34 @@ impl ::prost::Name for FeeAssetChangeAction {
         ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
     }
 }
+/// `AddressPrefixChangeAction` represents a transaction that adds
+/// or removes a bech32 prefix that is accepted in addresses
+/// in addition to the chain's base prefix.
+/// The base prefix itself cannot be removed.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddressPrefixChangeAction {
+    #[prost(oneof = "address_prefix_change_action::Value", tags = "1, 2")]
+    pub value: ::core::option::Option<address_prefix_change_action::Value>,
+}
+/// Nested message and enum types in `AddressPrefixChangeAction`.
+pub mod address_prefix_change_action {
+    #[allow(clippy::derive_partial_eq_without_eq)]
+    #[derive(Clone, PartialEq, ::prost::Oneof)]
+    pub enum Value {
+        #[prost(string, tag = "1")]
+        Addition(::prost::alloc::string::String),
+        #[prost(string, tag = "2")]
+        Removal(::prost::alloc::string::String),
+    }
+}
+impl ::prost::Name for AddressPrefixChangeAction {
+    const NAME: &'static str = "AddressPrefixChangeAction";
+    const PACKAGE: &'static str = "astria.protocol.transactions.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
+    }
+}
 /// `InitBridgeAccountAction` represents a transaction that initializes
 /// a bridge account for the given rollup on the chain.
 ///
@@ -433,6 +530,60 @@ impl ::prost::Name for BridgeSudoChangeAction {
         ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
     }
 }
+/// `BridgeRecoveryAction` schedules the recovery of a bridge account whose
+/// sudo or withdrawer keys were lost.
+///
+/// It can only be sent by the sudo address of the chain. The new addresses
+/// are assigned once the recovery delay of the chain has passed, unless the
+/// bridge account's current sudo address vetoes the recovery before then.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeRecoveryAction {
+    /// the address of the bridge account to recover
+    #[prost(message, optional, tag = "1")]
+    pub bridge_address: ::core::option::Option<
+        super::super::super::primitive::v1::Address,
+    >,
+    /// the new sudo address; unchanged if unset
+    #[prost(message, optional, tag = "2")]
+    pub new_sudo_address: ::core::option::Option<
+        super::super::super::primitive::v1::Address,
+    >,
+    /// the new withdrawer address; unchanged if unset
+    #[prost(message, optional, tag = "3")]
+    pub new_withdrawer_address: ::core::option::Option<
+        super::super::super::primitive::v1::Address,
+    >,
+}
+impl ::prost::Name for BridgeRecoveryAction {
+    const NAME: &'static str = "BridgeRecoveryAction";
+    const PACKAGE: &'static str = "astria.protocol.transactions.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
+    }
+}
+/// `BridgeRecoveryVetoAction` cancels the pending recovery of a bridge account.
+///
+/// It can only be sent by the bridge account's current sudo address.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BridgeRecoveryVetoAction {
+    /// the address of the bridge account to cancel the recovery of
+    #[prost(message, optional, tag = "1")]
+    pub bridge_address: ::core::option::Option<
+        super::super::super::primitive::v1::Address,
+    >,
+    /// the asset used to pay the transaction fee
+    #[prost(bytes = "vec", tag = "2")]
+    pub fee_asset_id: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for BridgeRecoveryVetoAction {
+    const NAME: &'static str = "BridgeRecoveryVetoAction";
+    const PACKAGE: &'static str = "astria.protocol.transactions.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
+    }
+}
 #[allow(clippy::derive_partial_eq_without_eq)]
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct FeeChangeAction {
@@ -474,3 +625,34 @@ impl ::prost::Name for FeeChangeAction {
         ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
     }
 }
+/// `FeeDistributionChangeAction` sets how the fees collected in a block are
+/// distributed at the end of the block.
+///
+/// It can only be sent by the sudo address of the chain. The shares are
+/// percentages of the fees collected in each asset and must sum to 100.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeDistributionChangeAction {
+    /// the percentage of the fees paid to the block proposer
+    #[prost(uint32, tag = "1")]
+    pub proposer_share: u32,
+    /// the percentage of the fees paid to the treasury address
+    #[prost(uint32, tag = "2")]
+    pub treasury_share: u32,
+    /// the percentage of the fees that are burned
+    #[prost(uint32, tag = "3")]
+    pub burn_share: u32,
+    /// the address receiving the treasury share; required if `treasury_share` is
+    /// not zero
+    #[prost(message, optional, tag = "4")]
+    pub treasury_address: ::core::option::Option<
+        super::super::super::primitive::v1::Address,
+    >,
+}
+impl ::prost::Name for FeeDistributionChangeAction {
+    const NAME: &'static str = "FeeDistributionChangeAction";
+    const PACKAGE: &'static str = "astria.protocol.transactions.v1alpha1";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.transactions.v1alpha1.{}", Self::NAME)
+    }
+}
@@ -0,0 +1,3339 @@
+impl serde::Serialize for Action {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.Action", len)?;
+        if let Some(v) = self.value.as_ref() {
+            match v {
+                action::Value::TransferAction(v) => {
+                    struct_ser.serialize_field("transfer_action", v)?;
+                }
+                action::Value::SequenceAction(v) => {
+                    struct_ser.serialize_field("sequence_action", v)?;
+                }
+                action::Value::AllowanceChangeAction(v) => {
+                    struct_ser.serialize_field("allowance_change_action", v)?;
+                }
+                action::Value::TransferFromAction(v) => {
+                    struct_ser.serialize_field("transfer_from_action", v)?;
+                }
+                action::Value::InitBridgeAccountAction(v) => {
+                    struct_ser.serialize_field("init_bridge_account_action", v)?;
+                }
+                action::Value::BridgeLockAction(v) => {
+                    struct_ser.serialize_field("bridge_lock_action", v)?;
+                }
+                action::Value::BridgeUnlockAction(v) => {
+                    struct_ser.serialize_field("bridge_unlock_action", v)?;
+                }
+                action::Value::BridgeSudoChangeAction(v) => {
+                    struct_ser.serialize_field("bridge_sudo_change_action", v)?;
+                }
+                action::Value::BridgeRecoveryVetoAction(v) => {
+                    struct_ser.serialize_field("bridge_recovery_veto_action", v)?;
+                }
+                action::Value::IbcAction(v) => {
+                    struct_ser.serialize_field("ibc_action", v)?;
+                }
+                action::Value::Ics20Withdrawal(v) => {
+                    struct_ser.serialize_field("ics20_withdrawal", v)?;
+                }
+                action::Value::SudoAddressChangeAction(v) => {
+                    struct_ser.serialize_field("sudo_address_change_action", v)?;
+                }
+                action::Value::ValidatorUpdateAction(v) => {
+                    struct_ser.serialize_field("validator_update_action", v)?;
+                }
+                action::Value::IbcRelayerChangeAction(v) => {
+                    struct_ser.serialize_field("ibc_relayer_change_action", v)?;
+                }
+                action::Value::FeeAssetChangeAction(v) => {
+                    struct_ser.serialize_field("fee_asset_change_action", v)?;
+                }
+                action::Value::FeeChangeAction(v) => {
+                    struct_ser.serialize_field("fee_change_action", v)?;
+                }
+                action::Value::AddressPrefixChangeAction(v) => {
+                    struct_ser.serialize_field("address_prefix_change_action", v)?;
+                }
+                action::Value::BridgeRecoveryAction(v) => {
+                    struct_ser.serialize_field("bridge_recovery_action", v)?;
+                }
+                action::Value::FeeDistributionChangeAction(v) => {
+                    struct_ser.serialize_field("fee_distribution_change_action", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Action {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transfer_action",
+            "transferAction",
+            "sequence_action",
+            "sequenceAction",
+            "allowance_change_action",
+            "allowanceChangeAction",
+            "transfer_from_action",
+            "transferFromAction",
+            "init_bridge_account_action",
+            "initBridgeAccountAction",
+            "bridge_lock_action",
+            "bridgeLockAction",
+            "bridge_unlock_action",
+            "bridgeUnlockAction",
+            "bridge_sudo_change_action",
+            "bridgeSudoChangeAction",
+            "bridge_recovery_veto_action",
+            "bridgeRecoveryVetoAction",
+            "ibc_action",
+            "ibcAction",
+            "ics20_withdrawal",
+            "ics20Withdrawal",
+            "sudo_address_change_action",
+            "sudoAddressChangeAction",
+            "validator_update_action",
+            "validatorUpdateAction",
+            "ibc_relayer_change_action",
+            "ibcRelayerChangeAction",
+            "fee_asset_change_action",
+            "feeAssetChangeAction",
+            "fee_change_action",
+            "feeChangeAction",
+            "address_prefix_change_action",
+            "addressPrefixChangeAction",
+            "bridge_recovery_action",
+            "bridgeRecoveryAction",
+            "fee_distribution_change_action",
+            "feeDistributionChangeAction",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TransferAction,
+            SequenceAction,
+            AllowanceChangeAction,
+            TransferFromAction,
+            InitBridgeAccountAction,
+            BridgeLockAction,
+            BridgeUnlockAction,
+            BridgeSudoChangeAction,
+            BridgeRecoveryVetoAction,
+            IbcAction,
+            Ics20Withdrawal,
+            SudoAddressChangeAction,
+            ValidatorUpdateAction,
+            IbcRelayerChangeAction,
+            FeeAssetChangeAction,
+            FeeChangeAction,
+            AddressPrefixChangeAction,
+            BridgeRecoveryAction,
+            FeeDistributionChangeAction,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "transferAction" | "transfer_action" => Ok(GeneratedField::TransferAction),
+                            "sequenceAction" | "sequence_action" => Ok(GeneratedField::SequenceAction),
+                            "allowanceChangeAction" | "allowance_change_action" => Ok(GeneratedField::AllowanceChangeAction),
+                            "transferFromAction" | "transfer_from_action" => Ok(GeneratedField::TransferFromAction),
+                            "initBridgeAccountAction" | "init_bridge_account_action" => Ok(GeneratedField::InitBridgeAccountAction),
+                            "bridgeLockAction" | "bridge_lock_action" => Ok(GeneratedField::BridgeLockAction),
+                            "bridgeUnlockAction" | "bridge_unlock_action" => Ok(GeneratedField::BridgeUnlockAction),
+                            "bridgeSudoChangeAction" | "bridge_sudo_change_action" => Ok(GeneratedField::BridgeSudoChangeAction),
+                            "bridgeRecoveryVetoAction" | "bridge_recovery_veto_action" => Ok(GeneratedField::BridgeRecoveryVetoAction),
+                            "ibcAction" | "ibc_action" => Ok(GeneratedField::IbcAction),
+                            "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
+                            "sudoAddressChangeAction" | "sudo_address_change_action" => Ok(GeneratedField::SudoAddressChangeAction),
+                            "validatorUpdateAction" | "validator_update_action" => Ok(GeneratedField::ValidatorUpdateAction),
+                            "ibcRelayerChangeAction" | "ibc_relayer_change_action" => Ok(GeneratedField::IbcRelayerChangeAction),
+                            "feeAssetChangeAction" | "fee_asset_change_action" => Ok(GeneratedField::FeeAssetChangeAction),
+                            "feeChangeAction" | "fee_change_action" => Ok(GeneratedField::FeeChangeAction),
+                            "addressPrefixChangeAction" | "address_prefix_change_action" => Ok(GeneratedField::AddressPrefixChangeAction),
+                            "bridgeRecoveryAction" | "bridge_recovery_action" => Ok(GeneratedField::BridgeRecoveryAction),
+                            "feeDistributionChangeAction" | "fee_distribution_change_action" => Ok(GeneratedField::FeeDistributionChangeAction),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Action;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.Action")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Action, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TransferAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transferAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::TransferAction)
+;
+                        }
+                        GeneratedField::SequenceAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequenceAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::SequenceAction)
+;
+                        }
+                        GeneratedField::AllowanceChangeAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("allowanceChangeAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::AllowanceChangeAction)
+;
+                        }
+                        GeneratedField::TransferFromAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transferFromAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::TransferFromAction)
+;
+                        }
+                        GeneratedField::InitBridgeAccountAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initBridgeAccountAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::InitBridgeAccountAction)
+;
+                        }
+                        GeneratedField::BridgeLockAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeLockAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::BridgeLockAction)
+;
+                        }
+                        GeneratedField::BridgeUnlockAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeUnlockAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::BridgeUnlockAction)
+;
+                        }
+                        GeneratedField::BridgeSudoChangeAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeSudoChangeAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::BridgeSudoChangeAction)
+;
+                        }
+                        GeneratedField::BridgeRecoveryVetoAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeRecoveryVetoAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::BridgeRecoveryVetoAction)
+;
+                        }
+                        GeneratedField::IbcAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::IbcAction)
+;
+                        }
+                        GeneratedField::Ics20Withdrawal => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20Withdrawal"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::Ics20Withdrawal)
+;
+                        }
+                        GeneratedField::SudoAddressChangeAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sudoAddressChangeAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::SudoAddressChangeAction)
+;
+                        }
+                        GeneratedField::ValidatorUpdateAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorUpdateAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::ValidatorUpdateAction)
+;
+                        }
+                        GeneratedField::IbcRelayerChangeAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ibcRelayerChangeAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::IbcRelayerChangeAction)
+;
+                        }
+                        GeneratedField::FeeAssetChangeAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetChangeAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::FeeAssetChangeAction)
+;
+                        }
+                        GeneratedField::FeeChangeAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeChangeAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::FeeChangeAction)
+;
+                        }
+                        GeneratedField::AddressPrefixChangeAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addressPrefixChangeAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::AddressPrefixChangeAction)
+;
+                        }
+                        GeneratedField::BridgeRecoveryAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeRecoveryAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::BridgeRecoveryAction)
+;
+                        }
+                        GeneratedField::FeeDistributionChangeAction => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeDistributionChangeAction"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Value::FeeDistributionChangeAction)
+;
+                        }
+                    }
+                }
+                Ok(Action {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.Action", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AddressPrefixChangeAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.AddressPrefixChangeAction", len)?;
+        if let Some(v) = self.value.as_ref() {
+            match v {
+                address_prefix_change_action::Value::Addition(v) => {
+                    struct_ser.serialize_field("addition", v)?;
+                }
+                address_prefix_change_action::Value::Removal(v) => {
+                    struct_ser.serialize_field("removal", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AddressPrefixChangeAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "addition",
+            "removal",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Addition,
+            Removal,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "addition" => Ok(GeneratedField::Addition),
+                            "removal" => Ok(GeneratedField::Removal),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AddressPrefixChangeAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.AddressPrefixChangeAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AddressPrefixChangeAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Addition => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addition"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(address_prefix_change_action::Value::Addition);
+                        }
+                        GeneratedField::Removal => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("removal"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(address_prefix_change_action::Value::Removal);
+                        }
+                    }
+                }
+                Ok(AddressPrefixChangeAction {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.AddressPrefixChangeAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for AllowanceChangeAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.spender.is_some() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.asset_id.is_empty() {
+            len += 1;
+        }
+        if self.expiry_height != 0 {
+            len += 1;
+        }
+        if !self.fee_asset_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.AllowanceChangeAction", len)?;
+        if let Some(v) = self.spender.as_ref() {
+            struct_ser.serialize_field("spender", v)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("asset_id", pbjson::private::base64::encode(&self.asset_id).as_str())?;
+        }
+        if self.expiry_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("expiry_height", ToString::to_string(&self.expiry_height).as_str())?;
+        }
+        if !self.fee_asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fee_asset_id", pbjson::private::base64::encode(&self.fee_asset_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AllowanceChangeAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "spender",
+            "amount",
+            "asset_id",
+            "assetId",
+            "expiry_height",
+            "expiryHeight",
+            "fee_asset_id",
+            "feeAssetId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Spender,
+            Amount,
+            AssetId,
+            ExpiryHeight,
+            FeeAssetId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "spender" => Ok(GeneratedField::Spender),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "expiryHeight" | "expiry_height" => Ok(GeneratedField::ExpiryHeight),
+                            "feeAssetId" | "fee_asset_id" => Ok(GeneratedField::FeeAssetId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AllowanceChangeAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.AllowanceChangeAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AllowanceChangeAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut spender__ = None;
+                let mut amount__ = None;
+                let mut asset_id__ = None;
+                let mut expiry_height__ = None;
+                let mut fee_asset_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Spender => {
+                            if spender__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spender"));
+                            }
+                            spender__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ExpiryHeight => {
+                            if expiry_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiryHeight"));
+                            }
+                            expiry_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FeeAssetId => {
+                            if fee_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetId"));
+                            }
+                            fee_asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(AllowanceChangeAction {
+                    spender: spender__,
+                    amount: amount__,
+                    asset_id: asset_id__.unwrap_or_default(),
+                    expiry_height: expiry_height__.unwrap_or_default(),
+                    fee_asset_id: fee_asset_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.AllowanceChangeAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BridgeLockAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.to.is_some() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.asset_id.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset_id.is_empty() {
+            len += 1;
+        }
+        if !self.destination_chain_address.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.BridgeLockAction", len)?;
+        if let Some(v) = self.to.as_ref() {
+            struct_ser.serialize_field("to", v)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("asset_id", pbjson::private::base64::encode(&self.asset_id).as_str())?;
+        }
+        if !self.fee_asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fee_asset_id", pbjson::private::base64::encode(&self.fee_asset_id).as_str())?;
+        }
+        if !self.destination_chain_address.is_empty() {
+            struct_ser.serialize_field("destination_chain_address", &self.destination_chain_address)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BridgeLockAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "to",
+            "amount",
+            "asset_id",
+            "assetId",
+            "fee_asset_id",
+            "feeAssetId",
+            "destination_chain_address",
+            "destinationChainAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            To,
+            Amount,
+            AssetId,
+            FeeAssetId,
+            DestinationChainAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "to" => Ok(GeneratedField::To),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "feeAssetId" | "fee_asset_id" => Ok(GeneratedField::FeeAssetId),
+                            "destinationChainAddress" | "destination_chain_address" => Ok(GeneratedField::DestinationChainAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BridgeLockAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.BridgeLockAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BridgeLockAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut to__ = None;
+                let mut amount__ = None;
+                let mut asset_id__ = None;
+                let mut fee_asset_id__ = None;
+                let mut destination_chain_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::To => {
+                            if to__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("to"));
+                            }
+                            to__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FeeAssetId => {
+                            if fee_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetId"));
+                            }
+                            fee_asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::DestinationChainAddress => {
+                            if destination_chain_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destinationChainAddress"));
+                            }
+                            destination_chain_address__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(BridgeLockAction {
+                    to: to__,
+                    amount: amount__,
+                    asset_id: asset_id__.unwrap_or_default(),
+                    fee_asset_id: fee_asset_id__.unwrap_or_default(),
+                    destination_chain_address: destination_chain_address__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.BridgeLockAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BridgeRecoveryAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.bridge_address.is_some() {
+            len += 1;
+        }
+        if self.new_sudo_address.is_some() {
+            len += 1;
+        }
+        if self.new_withdrawer_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.BridgeRecoveryAction", len)?;
+        if let Some(v) = self.bridge_address.as_ref() {
+            struct_ser.serialize_field("bridge_address", v)?;
+        }
+        if let Some(v) = self.new_sudo_address.as_ref() {
+            struct_ser.serialize_field("new_sudo_address", v)?;
+        }
+        if let Some(v) = self.new_withdrawer_address.as_ref() {
+            struct_ser.serialize_field("new_withdrawer_address", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BridgeRecoveryAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "bridge_address",
+            "bridgeAddress",
+            "new_sudo_address",
+            "newSudoAddress",
+            "new_withdrawer_address",
+            "newWithdrawerAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BridgeAddress,
+            NewSudoAddress,
+            NewWithdrawerAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "bridgeAddress" | "bridge_address" => Ok(GeneratedField::BridgeAddress),
+                            "newSudoAddress" | "new_sudo_address" => Ok(GeneratedField::NewSudoAddress),
+                            "newWithdrawerAddress" | "new_withdrawer_address" => Ok(GeneratedField::NewWithdrawerAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BridgeRecoveryAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.BridgeRecoveryAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BridgeRecoveryAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut bridge_address__ = None;
+                let mut new_sudo_address__ = None;
+                let mut new_withdrawer_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BridgeAddress => {
+                            if bridge_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeAddress"));
+                            }
+                            bridge_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewSudoAddress => {
+                            if new_sudo_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newSudoAddress"));
+                            }
+                            new_sudo_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewWithdrawerAddress => {
+                            if new_withdrawer_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newWithdrawerAddress"));
+                            }
+                            new_withdrawer_address__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(BridgeRecoveryAction {
+                    bridge_address: bridge_address__,
+                    new_sudo_address: new_sudo_address__,
+                    new_withdrawer_address: new_withdrawer_address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.BridgeRecoveryAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BridgeRecoveryVetoAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.bridge_address.is_some() {
+            len += 1;
+        }
+        if !self.fee_asset_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.BridgeRecoveryVetoAction", len)?;
+        if let Some(v) = self.bridge_address.as_ref() {
+            struct_ser.serialize_field("bridge_address", v)?;
+        }
+        if !self.fee_asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fee_asset_id", pbjson::private::base64::encode(&self.fee_asset_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BridgeRecoveryVetoAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "bridge_address",
+            "bridgeAddress",
+            "fee_asset_id",
+            "feeAssetId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BridgeAddress,
+            FeeAssetId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "bridgeAddress" | "bridge_address" => Ok(GeneratedField::BridgeAddress),
+                            "feeAssetId" | "fee_asset_id" => Ok(GeneratedField::FeeAssetId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BridgeRecoveryVetoAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.BridgeRecoveryVetoAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BridgeRecoveryVetoAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut bridge_address__ = None;
+                let mut fee_asset_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BridgeAddress => {
+                            if bridge_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeAddress"));
+                            }
+                            bridge_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeeAssetId => {
+                            if fee_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetId"));
+                            }
+                            fee_asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(BridgeRecoveryVetoAction {
+                    bridge_address: bridge_address__,
+                    fee_asset_id: fee_asset_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.BridgeRecoveryVetoAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BridgeSudoChangeAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.bridge_address.is_some() {
+            len += 1;
+        }
+        if self.new_sudo_address.is_some() {
+            len += 1;
+        }
+        if self.new_withdrawer_address.is_some() {
+            len += 1;
+        }
+        if !self.fee_asset_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.BridgeSudoChangeAction", len)?;
+        if let Some(v) = self.bridge_address.as_ref() {
+            struct_ser.serialize_field("bridge_address", v)?;
+        }
+        if let Some(v) = self.new_sudo_address.as_ref() {
+            struct_ser.serialize_field("new_sudo_address", v)?;
+        }
+        if let Some(v) = self.new_withdrawer_address.as_ref() {
+            struct_ser.serialize_field("new_withdrawer_address", v)?;
+        }
+        if !self.fee_asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fee_asset_id", pbjson::private::base64::encode(&self.fee_asset_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BridgeSudoChangeAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "bridge_address",
+            "bridgeAddress",
+            "new_sudo_address",
+            "newSudoAddress",
+            "new_withdrawer_address",
+            "newWithdrawerAddress",
+            "fee_asset_id",
+            "feeAssetId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            BridgeAddress,
+            NewSudoAddress,
+            NewWithdrawerAddress,
+            FeeAssetId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "bridgeAddress" | "bridge_address" => Ok(GeneratedField::BridgeAddress),
+                            "newSudoAddress" | "new_sudo_address" => Ok(GeneratedField::NewSudoAddress),
+                            "newWithdrawerAddress" | "new_withdrawer_address" => Ok(GeneratedField::NewWithdrawerAddress),
+                            "feeAssetId" | "fee_asset_id" => Ok(GeneratedField::FeeAssetId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BridgeSudoChangeAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.BridgeSudoChangeAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BridgeSudoChangeAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut bridge_address__ = None;
+                let mut new_sudo_address__ = None;
+                let mut new_withdrawer_address__ = None;
+                let mut fee_asset_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::BridgeAddress => {
+                            if bridge_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeAddress"));
+                            }
+                            bridge_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewSudoAddress => {
+                            if new_sudo_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newSudoAddress"));
+                            }
+                            new_sudo_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewWithdrawerAddress => {
+                            if new_withdrawer_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newWithdrawerAddress"));
+                            }
+                            new_withdrawer_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeeAssetId => {
+                            if fee_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetId"));
+                            }
+                            fee_asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(BridgeSudoChangeAction {
+                    bridge_address: bridge_address__,
+                    new_sudo_address: new_sudo_address__,
+                    new_withdrawer_address: new_withdrawer_address__,
+                    fee_asset_id: fee_asset_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.BridgeSudoChangeAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for BridgeUnlockAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.to.is_some() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.fee_asset_id.is_empty() {
+            len += 1;
+        }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
+        if self.bridge_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.BridgeUnlockAction", len)?;
+        if let Some(v) = self.to.as_ref() {
+            struct_ser.serialize_field("to", v)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.fee_asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fee_asset_id", pbjson::private::base64::encode(&self.fee_asset_id).as_str())?;
+        }
+        if !self.memo.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("memo", pbjson::private::base64::encode(&self.memo).as_str())?;
+        }
+        if let Some(v) = self.bridge_address.as_ref() {
+            struct_ser.serialize_field("bridge_address", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for BridgeUnlockAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "to",
+            "amount",
+            "fee_asset_id",
+            "feeAssetId",
+            "memo",
+            "bridge_address",
+            "bridgeAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            To,
+            Amount,
+            FeeAssetId,
+            Memo,
+            BridgeAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "to" => Ok(GeneratedField::To),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "feeAssetId" | "fee_asset_id" => Ok(GeneratedField::FeeAssetId),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "bridgeAddress" | "bridge_address" => Ok(GeneratedField::BridgeAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = BridgeUnlockAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.BridgeUnlockAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<BridgeUnlockAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut to__ = None;
+                let mut amount__ = None;
+                let mut fee_asset_id__ = None;
+                let mut memo__ = None;
+                let mut bridge_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::To => {
+                            if to__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("to"));
+                            }
+                            to__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeeAssetId => {
+                            if fee_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetId"));
+                            }
+                            fee_asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BridgeAddress => {
+                            if bridge_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeAddress"));
+                            }
+                            bridge_address__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(BridgeUnlockAction {
+                    to: to__,
+                    amount: amount__,
+                    fee_asset_id: fee_asset_id__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                    bridge_address: bridge_address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.BridgeUnlockAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeAssetChangeAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.FeeAssetChangeAction", len)?;
+        if let Some(v) = self.value.as_ref() {
+            match v {
+                fee_asset_change_action::Value::Addition(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser.serialize_field("addition", pbjson::private::base64::encode(&v).as_str())?;
+                }
+                fee_asset_change_action::Value::Removal(v) => {
+                    #[allow(clippy::needless_borrow)]
+                    struct_ser.serialize_field("removal", pbjson::private::base64::encode(&v).as_str())?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeAssetChangeAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "addition",
+            "removal",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Addition,
+            Removal,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "addition" => Ok(GeneratedField::Addition),
+                            "removal" => Ok(GeneratedField::Removal),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeAssetChangeAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.FeeAssetChangeAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeAssetChangeAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Addition => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addition"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>()?.map(|x| fee_asset_change_action::Value::Addition(x.0));
+                        }
+                        GeneratedField::Removal => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("removal"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<::pbjson::private::BytesDeserialize<_>>>()?.map(|x| fee_asset_change_action::Value::Removal(x.0));
+                        }
+                    }
+                }
+                Ok(FeeAssetChangeAction {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.FeeAssetChangeAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeChangeAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.FeeChangeAction", len)?;
+        if let Some(v) = self.value.as_ref() {
+            match v {
+                fee_change_action::Value::TransferBaseFee(v) => {
+                    struct_ser.serialize_field("transfer_base_fee", v)?;
+                }
+                fee_change_action::Value::SequenceBaseFee(v) => {
+                    struct_ser.serialize_field("sequence_base_fee", v)?;
+                }
+                fee_change_action::Value::SequenceByteCostMultiplier(v) => {
+                    struct_ser.serialize_field("sequence_byte_cost_multiplier", v)?;
+                }
+                fee_change_action::Value::InitBridgeAccountBaseFee(v) => {
+                    struct_ser.serialize_field("init_bridge_account_base_fee", v)?;
+                }
+                fee_change_action::Value::BridgeLockByteCostMultiplier(v) => {
+                    struct_ser.serialize_field("bridge_lock_byte_cost_multiplier", v)?;
+                }
+                fee_change_action::Value::BridgeSudoChangeBaseFee(v) => {
+                    struct_ser.serialize_field("bridge_sudo_change_base_fee", v)?;
+                }
+                fee_change_action::Value::Ics20WithdrawalBaseFee(v) => {
+                    struct_ser.serialize_field("ics20_withdrawal_base_fee", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeChangeAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "transfer_base_fee",
+            "transferBaseFee",
+            "sequence_base_fee",
+            "sequenceBaseFee",
+            "sequence_byte_cost_multiplier",
+            "sequenceByteCostMultiplier",
+            "init_bridge_account_base_fee",
+            "initBridgeAccountBaseFee",
+            "bridge_lock_byte_cost_multiplier",
+            "bridgeLockByteCostMultiplier",
+            "bridge_sudo_change_base_fee",
+            "bridgeSudoChangeBaseFee",
+            "ics20_withdrawal_base_fee",
+            "ics20WithdrawalBaseFee",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TransferBaseFee,
+            SequenceBaseFee,
+            SequenceByteCostMultiplier,
+            InitBridgeAccountBaseFee,
+            BridgeLockByteCostMultiplier,
+            BridgeSudoChangeBaseFee,
+            Ics20WithdrawalBaseFee,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "transferBaseFee" | "transfer_base_fee" => Ok(GeneratedField::TransferBaseFee),
+                            "sequenceBaseFee" | "sequence_base_fee" => Ok(GeneratedField::SequenceBaseFee),
+                            "sequenceByteCostMultiplier" | "sequence_byte_cost_multiplier" => Ok(GeneratedField::SequenceByteCostMultiplier),
+                            "initBridgeAccountBaseFee" | "init_bridge_account_base_fee" => Ok(GeneratedField::InitBridgeAccountBaseFee),
+                            "bridgeLockByteCostMultiplier" | "bridge_lock_byte_cost_multiplier" => Ok(GeneratedField::BridgeLockByteCostMultiplier),
+                            "bridgeSudoChangeBaseFee" | "bridge_sudo_change_base_fee" => Ok(GeneratedField::BridgeSudoChangeBaseFee),
+                            "ics20WithdrawalBaseFee" | "ics20_withdrawal_base_fee" => Ok(GeneratedField::Ics20WithdrawalBaseFee),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeChangeAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.FeeChangeAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeChangeAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TransferBaseFee => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transferBaseFee"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change_action::Value::TransferBaseFee)
+;
+                        }
+                        GeneratedField::SequenceBaseFee => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequenceBaseFee"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change_action::Value::SequenceBaseFee)
+;
+                        }
+                        GeneratedField::SequenceByteCostMultiplier => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequenceByteCostMultiplier"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change_action::Value::SequenceByteCostMultiplier)
+;
+                        }
+                        GeneratedField::InitBridgeAccountBaseFee => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initBridgeAccountBaseFee"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change_action::Value::InitBridgeAccountBaseFee)
+;
+                        }
+                        GeneratedField::BridgeLockByteCostMultiplier => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeLockByteCostMultiplier"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change_action::Value::BridgeLockByteCostMultiplier)
+;
+                        }
+                        GeneratedField::BridgeSudoChangeBaseFee => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeSudoChangeBaseFee"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change_action::Value::BridgeSudoChangeBaseFee)
+;
+                        }
+                        GeneratedField::Ics20WithdrawalBaseFee => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("ics20WithdrawalBaseFee"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(fee_change_action::Value::Ics20WithdrawalBaseFee)
+;
+                        }
+                    }
+                }
+                Ok(FeeChangeAction {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.FeeChangeAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for FeeDistributionChangeAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposer_share != 0 {
+            len += 1;
+        }
+        if self.treasury_share != 0 {
+            len += 1;
+        }
+        if self.burn_share != 0 {
+            len += 1;
+        }
+        if self.treasury_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.FeeDistributionChangeAction", len)?;
+        if self.proposer_share != 0 {
+            struct_ser.serialize_field("proposer_share", &self.proposer_share)?;
+        }
+        if self.treasury_share != 0 {
+            struct_ser.serialize_field("treasury_share", &self.treasury_share)?;
+        }
+        if self.burn_share != 0 {
+            struct_ser.serialize_field("burn_share", &self.burn_share)?;
+        }
+        if let Some(v) = self.treasury_address.as_ref() {
+            struct_ser.serialize_field("treasury_address", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeDistributionChangeAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposer_share",
+            "proposerShare",
+            "treasury_share",
+            "treasuryShare",
+            "burn_share",
+            "burnShare",
+            "treasury_address",
+            "treasuryAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProposerShare,
+            TreasuryShare,
+            BurnShare,
+            TreasuryAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposerShare" | "proposer_share" => Ok(GeneratedField::ProposerShare),
+                            "treasuryShare" | "treasury_share" => Ok(GeneratedField::TreasuryShare),
+                            "burnShare" | "burn_share" => Ok(GeneratedField::BurnShare),
+                            "treasuryAddress" | "treasury_address" => Ok(GeneratedField::TreasuryAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeDistributionChangeAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.FeeDistributionChangeAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeDistributionChangeAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposer_share__ = None;
+                let mut treasury_share__ = None;
+                let mut burn_share__ = None;
+                let mut treasury_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProposerShare => {
+                            if proposer_share__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposerShare"));
+                            }
+                            proposer_share__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TreasuryShare => {
+                            if treasury_share__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("treasuryShare"));
+                            }
+                            treasury_share__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::BurnShare => {
+                            if burn_share__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("burnShare"));
+                            }
+                            burn_share__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TreasuryAddress => {
+                            if treasury_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("treasuryAddress"));
+                            }
+                            treasury_address__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(FeeDistributionChangeAction {
+                    proposer_share: proposer_share__.unwrap_or_default(),
+                    treasury_share: treasury_share__.unwrap_or_default(),
+                    burn_share: burn_share__.unwrap_or_default(),
+                    treasury_address: treasury_address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.FeeDistributionChangeAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IbcHeight {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.revision_number != 0 {
+            len += 1;
+        }
+        if self.revision_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.IbcHeight", len)?;
+        if self.revision_number != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("revision_number", ToString::to_string(&self.revision_number).as_str())?;
+        }
+        if self.revision_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("revision_height", ToString::to_string(&self.revision_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IbcHeight {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "revision_number",
+            "revisionNumber",
+            "revision_height",
+            "revisionHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RevisionNumber,
+            RevisionHeight,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "revisionNumber" | "revision_number" => Ok(GeneratedField::RevisionNumber),
+                            "revisionHeight" | "revision_height" => Ok(GeneratedField::RevisionHeight),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IbcHeight;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.IbcHeight")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcHeight, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut revision_number__ = None;
+                let mut revision_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RevisionNumber => {
+                            if revision_number__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("revisionNumber"));
+                            }
+                            revision_number__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::RevisionHeight => {
+                            if revision_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("revisionHeight"));
+                            }
+                            revision_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(IbcHeight {
+                    revision_number: revision_number__.unwrap_or_default(),
+                    revision_height: revision_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.IbcHeight", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for IbcRelayerChangeAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.IbcRelayerChangeAction", len)?;
+        if let Some(v) = self.value.as_ref() {
+            match v {
+                ibc_relayer_change_action::Value::Addition(v) => {
+                    struct_ser.serialize_field("addition", v)?;
+                }
+                ibc_relayer_change_action::Value::Removal(v) => {
+                    struct_ser.serialize_field("removal", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for IbcRelayerChangeAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "addition",
+            "removal",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Addition,
+            Removal,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "addition" => Ok(GeneratedField::Addition),
+                            "removal" => Ok(GeneratedField::Removal),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = IbcRelayerChangeAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.IbcRelayerChangeAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<IbcRelayerChangeAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Addition => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("addition"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(ibc_relayer_change_action::Value::Addition)
+;
+                        }
+                        GeneratedField::Removal => {
+                            if value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("removal"));
+                            }
+                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(ibc_relayer_change_action::Value::Removal)
+;
+                        }
+                    }
+                }
+                Ok(IbcRelayerChangeAction {
+                    value: value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.IbcRelayerChangeAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Ics20Withdrawal {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.denom.is_empty() {
+            len += 1;
+        }
+        if !self.destination_chain_address.is_empty() {
+            len += 1;
+        }
+        if self.return_address.is_some() {
+            len += 1;
+        }
+        if self.timeout_height.is_some() {
+            len += 1;
+        }
+        if self.timeout_time != 0 {
+            len += 1;
+        }
+        if !self.source_channel.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset_id.is_empty() {
+            len += 1;
+        }
+        if !self.memo.is_empty() {
+            len += 1;
+        }
+        if self.bridge_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.Ics20Withdrawal", len)?;
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.denom.is_empty() {
+            struct_ser.serialize_field("denom", &self.denom)?;
+        }
+        if !self.destination_chain_address.is_empty() {
+            struct_ser.serialize_field("destination_chain_address", &self.destination_chain_address)?;
+        }
+        if let Some(v) = self.return_address.as_ref() {
+            struct_ser.serialize_field("return_address", v)?;
+        }
+        if let Some(v) = self.timeout_height.as_ref() {
+            struct_ser.serialize_field("timeout_height", v)?;
+        }
+        if self.timeout_time != 0 {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("timeout_time", ToString::to_string(&self.timeout_time).as_str())?;
+        }
+        if !self.source_channel.is_empty() {
+            struct_ser.serialize_field("source_channel", &self.source_channel)?;
+        }
+        if !self.fee_asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fee_asset_id", pbjson::private::base64::encode(&self.fee_asset_id).as_str())?;
+        }
+        if !self.memo.is_empty() {
+            struct_ser.serialize_field("memo", &self.memo)?;
+        }
+        if let Some(v) = self.bridge_address.as_ref() {
+            struct_ser.serialize_field("bridge_address", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Ics20Withdrawal {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "amount",
+            "denom",
+            "destination_chain_address",
+            "destinationChainAddress",
+            "return_address",
+            "returnAddress",
+            "timeout_height",
+            "timeoutHeight",
+            "timeout_time",
+            "timeoutTime",
+            "source_channel",
+            "sourceChannel",
+            "fee_asset_id",
+            "feeAssetId",
+            "memo",
+            "bridge_address",
+            "bridgeAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Amount,
+            Denom,
+            DestinationChainAddress,
+            ReturnAddress,
+            TimeoutHeight,
+            TimeoutTime,
+            SourceChannel,
+            FeeAssetId,
+            Memo,
+            BridgeAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "amount" => Ok(GeneratedField::Amount),
+                            "denom" => Ok(GeneratedField::Denom),
+                            "destinationChainAddress" | "destination_chain_address" => Ok(GeneratedField::DestinationChainAddress),
+                            "returnAddress" | "return_address" => Ok(GeneratedField::ReturnAddress),
+                            "timeoutHeight" | "timeout_height" => Ok(GeneratedField::TimeoutHeight),
+                            "timeoutTime" | "timeout_time" => Ok(GeneratedField::TimeoutTime),
+                            "sourceChannel" | "source_channel" => Ok(GeneratedField::SourceChannel),
+                            "feeAssetId" | "fee_asset_id" => Ok(GeneratedField::FeeAssetId),
+                            "memo" => Ok(GeneratedField::Memo),
+                            "bridgeAddress" | "bridge_address" => Ok(GeneratedField::BridgeAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Ics20Withdrawal;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.Ics20Withdrawal")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Ics20Withdrawal, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut amount__ = None;
+                let mut denom__ = None;
+                let mut destination_chain_address__ = None;
+                let mut return_address__ = None;
+                let mut timeout_height__ = None;
+                let mut timeout_time__ = None;
+                let mut source_channel__ = None;
+                let mut fee_asset_id__ = None;
+                let mut memo__ = None;
+                let mut bridge_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::Denom => {
+                            if denom__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("denom"));
+                            }
+                            denom__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::DestinationChainAddress => {
+                            if destination_chain_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("destinationChainAddress"));
+                            }
+                            destination_chain_address__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ReturnAddress => {
+                            if return_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("returnAddress"));
+                            }
+                            return_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimeoutHeight => {
+                            if timeout_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutHeight"));
+                            }
+                            timeout_height__ = map_.next_value()?;
+                        }
+                        GeneratedField::TimeoutTime => {
+                            if timeout_time__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("timeoutTime"));
+                            }
+                            timeout_time__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SourceChannel => {
+                            if source_channel__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sourceChannel"));
+                            }
+                            source_channel__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::FeeAssetId => {
+                            if fee_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetId"));
+                            }
+                            fee_asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Memo => {
+                            if memo__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("memo"));
+                            }
+                            memo__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::BridgeAddress => {
+                            if bridge_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bridgeAddress"));
+                            }
+                            bridge_address__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(Ics20Withdrawal {
+                    amount: amount__,
+                    denom: denom__.unwrap_or_default(),
+                    destination_chain_address: destination_chain_address__.unwrap_or_default(),
+                    return_address: return_address__,
+                    timeout_height: timeout_height__,
+                    timeout_time: timeout_time__.unwrap_or_default(),
+                    source_channel: source_channel__.unwrap_or_default(),
+                    fee_asset_id: fee_asset_id__.unwrap_or_default(),
+                    memo: memo__.unwrap_or_default(),
+                    bridge_address: bridge_address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.Ics20Withdrawal", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for InitBridgeAccountAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rollup_id.is_some() {
+            len += 1;
+        }
+        if !self.asset_id.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset_id.is_empty() {
+            len += 1;
+        }
+        if self.sudo_address.is_some() {
+            len += 1;
+        }
+        if self.withdrawer_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.InitBridgeAccountAction", len)?;
+        if let Some(v) = self.rollup_id.as_ref() {
+            struct_ser.serialize_field("rollup_id", v)?;
+        }
+        if !self.asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("asset_id", pbjson::private::base64::encode(&self.asset_id).as_str())?;
+        }
+        if !self.fee_asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fee_asset_id", pbjson::private::base64::encode(&self.fee_asset_id).as_str())?;
+        }
+        if let Some(v) = self.sudo_address.as_ref() {
+            struct_ser.serialize_field("sudo_address", v)?;
+        }
+        if let Some(v) = self.withdrawer_address.as_ref() {
+            struct_ser.serialize_field("withdrawer_address", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for InitBridgeAccountAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rollup_id",
+            "rollupId",
+            "asset_id",
+            "assetId",
+            "fee_asset_id",
+            "feeAssetId",
+            "sudo_address",
+            "sudoAddress",
+            "withdrawer_address",
+            "withdrawerAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RollupId,
+            AssetId,
+            FeeAssetId,
+            SudoAddress,
+            WithdrawerAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rollupId" | "rollup_id" => Ok(GeneratedField::RollupId),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "feeAssetId" | "fee_asset_id" => Ok(GeneratedField::FeeAssetId),
+                            "sudoAddress" | "sudo_address" => Ok(GeneratedField::SudoAddress),
+                            "withdrawerAddress" | "withdrawer_address" => Ok(GeneratedField::WithdrawerAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = InitBridgeAccountAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.InitBridgeAccountAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<InitBridgeAccountAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rollup_id__ = None;
+                let mut asset_id__ = None;
+                let mut fee_asset_id__ = None;
+                let mut sudo_address__ = None;
+                let mut withdrawer_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RollupId => {
+                            if rollup_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupId"));
+                            }
+                            rollup_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FeeAssetId => {
+                            if fee_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetId"));
+                            }
+                            fee_asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::SudoAddress => {
+                            if sudo_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sudoAddress"));
+                            }
+                            sudo_address__ = map_.next_value()?;
+                        }
+                        GeneratedField::WithdrawerAddress => {
+                            if withdrawer_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawerAddress"));
+                            }
+                            withdrawer_address__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(InitBridgeAccountAction {
+                    rollup_id: rollup_id__,
+                    asset_id: asset_id__.unwrap_or_default(),
+                    fee_asset_id: fee_asset_id__.unwrap_or_default(),
+                    sudo_address: sudo_address__,
+                    withdrawer_address: withdrawer_address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.InitBridgeAccountAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SequenceAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rollup_id.is_some() {
+            len += 1;
+        }
+        if !self.data.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.SequenceAction", len)?;
+        if let Some(v) = self.rollup_id.as_ref() {
+            struct_ser.serialize_field("rollup_id", v)?;
+        }
+        if !self.data.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("data", pbjson::private::base64::encode(&self.data).as_str())?;
+        }
+        if !self.fee_asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fee_asset_id", pbjson::private::base64::encode(&self.fee_asset_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SequenceAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rollup_id",
+            "rollupId",
+            "data",
+            "fee_asset_id",
+            "feeAssetId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RollupId,
+            Data,
+            FeeAssetId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rollupId" | "rollup_id" => Ok(GeneratedField::RollupId),
+                            "data" => Ok(GeneratedField::Data),
+                            "feeAssetId" | "fee_asset_id" => Ok(GeneratedField::FeeAssetId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SequenceAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.SequenceAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SequenceAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rollup_id__ = None;
+                let mut data__ = None;
+                let mut fee_asset_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RollupId => {
+                            if rollup_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rollupId"));
+                            }
+                            rollup_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FeeAssetId => {
+                            if fee_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetId"));
+                            }
+                            fee_asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(SequenceAction {
+                    rollup_id: rollup_id__,
+                    data: data__.unwrap_or_default(),
+                    fee_asset_id: fee_asset_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.SequenceAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SignedTransaction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.signature.is_empty() {
+            len += 1;
+        }
+        if !self.public_key.is_empty() {
+            len += 1;
+        }
+        if self.transaction.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.SignedTransaction", len)?;
+        if !self.signature.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("signature", pbjson::private::base64::encode(&self.signature).as_str())?;
+        }
+        if !self.public_key.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("public_key", pbjson::private::base64::encode(&self.public_key).as_str())?;
+        }
+        if let Some(v) = self.transaction.as_ref() {
+            struct_ser.serialize_field("transaction", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SignedTransaction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "signature",
+            "public_key",
+            "publicKey",
+            "transaction",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Signature,
+            PublicKey,
+            Transaction,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "signature" => Ok(GeneratedField::Signature),
+                            "publicKey" | "public_key" => Ok(GeneratedField::PublicKey),
+                            "transaction" => Ok(GeneratedField::Transaction),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SignedTransaction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.SignedTransaction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SignedTransaction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut signature__ = None;
+                let mut public_key__ = None;
+                let mut transaction__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Signature => {
+                            if signature__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("signature"));
+                            }
+                            signature__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::PublicKey => {
+                            if public_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("publicKey"));
+                            }
+                            public_key__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Transaction => {
+                            if transaction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("transaction"));
+                            }
+                            transaction__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SignedTransaction {
+                    signature: signature__.unwrap_or_default(),
+                    public_key: public_key__.unwrap_or_default(),
+                    transaction: transaction__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.SignedTransaction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SudoAddressChangeAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.new_address.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.SudoAddressChangeAction", len)?;
+        if let Some(v) = self.new_address.as_ref() {
+            struct_ser.serialize_field("new_address", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SudoAddressChangeAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "new_address",
+            "newAddress",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            NewAddress,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "newAddress" | "new_address" => Ok(GeneratedField::NewAddress),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SudoAddressChangeAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.SudoAddressChangeAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SudoAddressChangeAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut new_address__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::NewAddress => {
+                            if new_address__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newAddress"));
+                            }
+                            new_address__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(SudoAddressChangeAction {
+                    new_address: new_address__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.SudoAddressChangeAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransactionParams {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.nonce != 0 {
+            len += 1;
+        }
+        if !self.chain_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.TransactionParams", len)?;
+        if self.nonce != 0 {
+            struct_ser.serialize_field("nonce", &self.nonce)?;
+        }
+        if !self.chain_id.is_empty() {
+            struct_ser.serialize_field("chain_id", &self.chain_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransactionParams {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "nonce",
+            "chain_id",
+            "chainId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Nonce,
+            ChainId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "chainId" | "chain_id" => Ok(GeneratedField::ChainId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransactionParams;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.TransactionParams")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransactionParams, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut nonce__ = None;
+                let mut chain_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ChainId => {
+                            if chain_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("chainId"));
+                            }
+                            chain_id__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(TransactionParams {
+                    nonce: nonce__.unwrap_or_default(),
+                    chain_id: chain_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.TransactionParams", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransferAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.to.is_some() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.asset_id.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.TransferAction", len)?;
+        if let Some(v) = self.to.as_ref() {
+            struct_ser.serialize_field("to", v)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("asset_id", pbjson::private::base64::encode(&self.asset_id).as_str())?;
+        }
+        if !self.fee_asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fee_asset_id", pbjson::private::base64::encode(&self.fee_asset_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransferAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "to",
+            "amount",
+            "asset_id",
+            "assetId",
+            "fee_asset_id",
+            "feeAssetId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            To,
+            Amount,
+            AssetId,
+            FeeAssetId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "to" => Ok(GeneratedField::To),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "feeAssetId" | "fee_asset_id" => Ok(GeneratedField::FeeAssetId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransferAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.TransferAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransferAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut to__ = None;
+                let mut amount__ = None;
+                let mut asset_id__ = None;
+                let mut fee_asset_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::To => {
+                            if to__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("to"));
+                            }
+                            to__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FeeAssetId => {
+                            if fee_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetId"));
+                            }
+                            fee_asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TransferAction {
+                    to: to__,
+                    amount: amount__,
+                    asset_id: asset_id__.unwrap_or_default(),
+                    fee_asset_id: fee_asset_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.TransferAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TransferFromAction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.owner.is_some() {
+            len += 1;
+        }
+        if self.to.is_some() {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if !self.asset_id.is_empty() {
+            len += 1;
+        }
+        if !self.fee_asset_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.TransferFromAction", len)?;
+        if let Some(v) = self.owner.as_ref() {
+            struct_ser.serialize_field("owner", v)?;
+        }
+        if let Some(v) = self.to.as_ref() {
+            struct_ser.serialize_field("to", v)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if !self.asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("asset_id", pbjson::private::base64::encode(&self.asset_id).as_str())?;
+        }
+        if !self.fee_asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field("fee_asset_id", pbjson::private::base64::encode(&self.fee_asset_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TransferFromAction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "owner",
+            "to",
+            "amount",
+            "asset_id",
+            "assetId",
+            "fee_asset_id",
+            "feeAssetId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Owner,
+            To,
+            Amount,
+            AssetId,
+            FeeAssetId,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "owner" => Ok(GeneratedField::Owner),
+                            "to" => Ok(GeneratedField::To),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "feeAssetId" | "fee_asset_id" => Ok(GeneratedField::FeeAssetId),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TransferFromAction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.TransferFromAction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TransferFromAction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut owner__ = None;
+                let mut to__ = None;
+                let mut amount__ = None;
+                let mut asset_id__ = None;
+                let mut fee_asset_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Owner => {
+                            if owner__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("owner"));
+                            }
+                            owner__ = map_.next_value()?;
+                        }
+                        GeneratedField::To => {
+                            if to__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("to"));
+                            }
+                            to__ = map_.next_value()?;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::FeeAssetId => {
+                            if fee_asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeAssetId"));
+                            }
+                            fee_asset_id__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                    }
+                }
+                Ok(TransferFromAction {
+                    owner: owner__,
+                    to: to__,
+                    amount: amount__,
+                    asset_id: asset_id__.unwrap_or_default(),
+                    fee_asset_id: fee_asset_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.TransferFromAction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for UnsignedTransaction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.actions.is_empty() {
+            len += 1;
+        }
+        if self.params.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("astria.protocol.transactions.v1alpha1.UnsignedTransaction", len)?;
+        if !self.actions.is_empty() {
+            struct_ser.serialize_field("actions", &self.actions)?;
+        }
+        if let Some(v) = self.params.as_ref() {
+            struct_ser.serialize_field("params", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for UnsignedTransaction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "actions",
+            "params",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Actions,
+            Params,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "actions" => Ok(GeneratedField::Actions),
+                            "params" => Ok(GeneratedField::Params),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = UnsignedTransaction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.protocol.transactions.v1alpha1.UnsignedTransaction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<UnsignedTransaction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut actions__ = None;
+                let mut params__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Actions => {
+                            if actions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actions"));
+                            }
+                            actions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Params => {
+                            if params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("params"));
+                            }
+                            params__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(UnsignedTransaction {
+                    actions: actions__.unwrap_or_default(),
+                    params: params__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("astria.protocol.transactions.v1alpha1.UnsignedTransaction", FIELDS, GeneratedVisitor)
+    }
+}
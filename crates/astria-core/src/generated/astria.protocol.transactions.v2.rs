@@ -0,0 +1,43 @@
+/// `SignedTransaction` is a transaction that has
+/// been signed by the given public key.
+/// It wraps an `UnsignedTransaction` with a
+/// signature and public key.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SignedTransaction {
+    #[prost(bytes = "vec", tag = "1")]
+    pub signature: ::prost::alloc::vec::Vec<u8>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub public_key: ::prost::alloc::vec::Vec<u8>,
+    #[prost(message, optional, tag = "3")]
+    pub transaction: ::core::option::Option<::pbjson_types::Any>,
+}
+impl ::prost::Name for SignedTransaction {
+    const NAME: &'static str = "SignedTransaction";
+    const PACKAGE: &'static str = "astria.protocol.transactions.v2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.transactions.v2.{}", Self::NAME)
+    }
+}
+/// `UnsignedTransaction` is a transaction that does
+/// not have an attached signature.
+///
+/// Each action is wrapped in an envelope of its type URL and its
+/// protobuf encoding. This allows new action types to be introduced
+/// without breaking clients that only decode, verify and relay
+/// transactions, because they can carry actions they do not know about.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UnsignedTransaction {
+    #[prost(message, repeated, tag = "1")]
+    pub actions: ::prost::alloc::vec::Vec<::pbjson_types::Any>,
+    #[prost(message, optional, tag = "2")]
+    pub params: ::core::option::Option<super::v1alpha1::TransactionParams>,
+}
+impl ::prost::Name for UnsignedTransaction {
+    const NAME: &'static str = "UnsignedTransaction";
+    const PACKAGE: &'static str = "astria.protocol.transactions.v2";
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.protocol.transactions.v2.{}", Self::NAME)
+    }
+}
@@ -59,6 +59,18 @@ pub struct SequencerBlock {
     /// / The block hash of the cometbft block that corresponds to this sequencer block.
     #[prost(bytes = "vec", tag = "5")]
     pub block_hash: ::prost::alloc::vec::Vec<u8>,
+    /// The fees paid in this block, summed up per asset. Only set for blocks at or after the
+    /// fee summary activation height, which commit to their fee summary in their data.
+    #[prost(message, optional, tag = "6")]
+    pub fee_summary: ::core::option::Option<FeeSummary>,
+    /// The proof that the fee summary is included in the CometBFT block this
+    /// sequencer block is derived from.
+    ///
+    /// This proof together with `Sha256(MTH(fee_summary.assets))` must match `header.data_hash`.
+    /// `MTH(fee_summary.assets)` is the Merkle Tree Hash derived from the protobuf encoded
+    /// entries of the fee summary. Set if and only if `fee_summary` is set.
+    #[prost(message, optional, tag = "7")]
+    pub fee_summary_proof: ::core::option::Option<super::super::primitive::v1::Proof>,
 }
 impl ::prost::Name for SequencerBlock {
     const NAME: &'static str = "SequencerBlock";
@@ -134,6 +146,45 @@ impl ::prost::Name for Deposit {
         ::prost::alloc::format!("astria.sequencerblock.v1alpha1.{}", Self::NAME)
     }
 }
+/// The fees paid in a single asset over the course of a sequencer block.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AssetFees {
+    /// the ID of the asset the fees were paid in
+    #[prost(bytes = "vec", tag = "1")]
+    pub asset_id: ::prost::alloc::vec::Vec<u8>,
+    /// the amount that was paid to the block's fee recipient
+    #[prost(message, optional, tag = "2")]
+    pub distributed: ::core::option::Option<super::super::primitive::v1::Uint128>,
+    /// the amount that was removed from circulation
+    #[prost(message, optional, tag = "3")]
+    pub burned: ::core::option::Option<super::super::primitive::v1::Uint128>,
+}
+impl ::prost::Name for AssetFees {
+    const NAME: &'static str = "AssetFees";
+    const PACKAGE: &'static str = "astria.sequencerblock.v1alpha1";
+
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.sequencerblock.v1alpha1.{}", Self::NAME)
+    }
+}
+/// `FeeSummary` is the total of all fees paid in a sequencer block.
+///
+/// The entries are sorted by asset ID, and there is at most one entry per asset.
+#[allow(clippy::derive_partial_eq_without_eq)]
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FeeSummary {
+    #[prost(message, repeated, tag = "1")]
+    pub assets: ::prost::alloc::vec::Vec<AssetFees>,
+}
+impl ::prost::Name for FeeSummary {
+    const NAME: &'static str = "FeeSummary";
+    const PACKAGE: &'static str = "astria.sequencerblock.v1alpha1";
+
+    fn full_name() -> ::prost::alloc::string::String {
+        ::prost::alloc::format!("astria.sequencerblock.v1alpha1.{}", Self::NAME)
+    }
+}
 /// `FilteredSequencerBlock` is similar to `SequencerBlock` but with a subset
 /// of the rollup transactions.
 #[allow(clippy::derive_partial_eq_without_eq)]
@@ -1,3 +1,139 @@
+impl serde::Serialize for AssetFees {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.asset_id.is_empty() {
+            len += 1;
+        }
+        if self.distributed.is_some() {
+            len += 1;
+        }
+        if self.burned.is_some() {
+            len += 1;
+        }
+        let mut struct_ser =
+            serializer.serialize_struct("astria.sequencerblock.v1alpha1.AssetFees", len)?;
+        if !self.asset_id.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            struct_ser.serialize_field(
+                "asset_id",
+                pbjson::private::base64::encode(&self.asset_id).as_str(),
+            )?;
+        }
+        if let Some(v) = self.distributed.as_ref() {
+            struct_ser.serialize_field("distributed", v)?;
+        }
+        if let Some(v) = self.burned.as_ref() {
+            struct_ser.serialize_field("burned", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for AssetFees {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["asset_id", "assetId", "distributed", "burned"];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AssetId,
+            Distributed,
+            Burned,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "distributed" => Ok(GeneratedField::Distributed),
+                            "burned" => Ok(GeneratedField::Burned),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = AssetFees;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.sequencerblock.v1alpha1.AssetFees")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AssetFees, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_id__ = None;
+                let mut distributed__ = None;
+                let mut burned__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::Distributed => {
+                            if distributed__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("distributed"));
+                            }
+                            distributed__ = map_.next_value()?;
+                        }
+                        GeneratedField::Burned => {
+                            if burned__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("burned"));
+                            }
+                            burned__ = map_.next_value()?;
+                        }
+                    }
+                }
+                Ok(AssetFees {
+                    asset_id: asset_id__.unwrap_or_default(),
+                    distributed: distributed__,
+                    burned: burned__,
+                })
+            }
+        }
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.AssetFees",
+            FIELDS,
+            GeneratedVisitor,
+        )
+    }
+}
 impl serde::Serialize for Deposit {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -21,7 +157,8 @@ impl serde::Serialize for Deposit {
         if !self.destination_chain_address.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.Deposit", len)?;
+        let mut struct_ser =
+            serializer.serialize_struct("astria.sequencerblock.v1alpha1.Deposit", len)?;
         if let Some(v) = self.bridge_address.as_ref() {
             struct_ser.serialize_field("bridge_address", v)?;
         }
@@ -33,10 +170,14 @@ impl serde::Serialize for Deposit {
         }
         if !self.asset_id.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("asset_id", pbjson::private::base64::encode(&self.asset_id).as_str())?;
+            struct_ser.serialize_field(
+                "asset_id",
+                pbjson::private::base64::encode(&self.asset_id).as_str(),
+            )?;
         }
         if !self.destination_chain_address.is_empty() {
-            struct_ser.serialize_field("destination_chain_address", &self.destination_chain_address)?;
+            struct_ser
+                .serialize_field("destination_chain_address", &self.destination_chain_address)?;
         }
         struct_ser.end()
     }
@@ -77,7 +218,10 @@ impl<'de> serde::Deserialize<'de> for Deposit {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -91,7 +235,9 @@ impl<'de> serde::Deserialize<'de> for Deposit {
                             "rollupId" | "rollup_id" => Ok(GeneratedField::RollupId),
                             "amount" => Ok(GeneratedField::Amount),
                             "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
-                            "destinationChainAddress" | "destination_chain_address" => Ok(GeneratedField::DestinationChainAddress),
+                            "destinationChainAddress" | "destination_chain_address" => {
+                                Ok(GeneratedField::DestinationChainAddress)
+                            }
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -108,8 +254,8 @@ impl<'de> serde::Deserialize<'de> for Deposit {
             }
 
             fn visit_map<V>(self, mut map_: V) -> std::result::Result<Deposit, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut bridge_address__ = None;
                 let mut rollup_id__ = None;
@@ -140,13 +286,16 @@ impl<'de> serde::Deserialize<'de> for Deposit {
                             if asset_id__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("assetId"));
                             }
-                            asset_id__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            asset_id__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                         GeneratedField::DestinationChainAddress => {
                             if destination_chain_address__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("destinationChainAddress"));
+                                return Err(serde::de::Error::duplicate_field(
+                                    "destinationChainAddress",
+                                ));
                             }
                             destination_chain_address__ = Some(map_.next_value()?);
                         }
@@ -161,7 +310,108 @@ impl<'de> serde::Deserialize<'de> for Deposit {
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.Deposit", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.Deposit",
+            FIELDS,
+            GeneratedVisitor,
+        )
+    }
+}
+impl serde::Serialize for FeeSummary {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.assets.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser =
+            serializer.serialize_struct("astria.sequencerblock.v1alpha1.FeeSummary", len)?;
+        if !self.assets.is_empty() {
+            struct_ser.serialize_field("assets", &self.assets)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for FeeSummary {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["assets"];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Assets,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assets" => Ok(GeneratedField::Assets),
+                            _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = FeeSummary;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct astria.sequencerblock.v1alpha1.FeeSummary")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FeeSummary, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
+            {
+                let mut assets__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Assets => {
+                            if assets__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assets"));
+                            }
+                            assets__ = Some(map_.next_value()?);
+                        }
+                    }
+                }
+                Ok(FeeSummary {
+                    assets: assets__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.FeeSummary",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
 impl serde::Serialize for FilteredSequencerBlock {
@@ -190,10 +440,14 @@ impl serde::Serialize for FilteredSequencerBlock {
         if self.rollup_ids_proof.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.FilteredSequencerBlock", len)?;
+        let mut struct_ser = serializer
+            .serialize_struct("astria.sequencerblock.v1alpha1.FilteredSequencerBlock", len)?;
         if !self.block_hash.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("block_hash", pbjson::private::base64::encode(&self.block_hash).as_str())?;
+            struct_ser.serialize_field(
+                "block_hash",
+                pbjson::private::base64::encode(&self.block_hash).as_str(),
+            )?;
         }
         if let Some(v) = self.header.as_ref() {
             struct_ser.serialize_field("header", v)?;
@@ -205,7 +459,14 @@ impl serde::Serialize for FilteredSequencerBlock {
             struct_ser.serialize_field("rollup_transactions_proof", v)?;
         }
         if !self.all_rollup_ids.is_empty() {
-            struct_ser.serialize_field("all_rollup_ids", &self.all_rollup_ids.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+            struct_ser.serialize_field(
+                "all_rollup_ids",
+                &self
+                    .all_rollup_ids
+                    .iter()
+                    .map(pbjson::private::base64::encode)
+                    .collect::<Vec<_>>(),
+            )?;
         }
         if let Some(v) = self.rollup_ids_proof.as_ref() {
             struct_ser.serialize_field("rollup_ids_proof", v)?;
@@ -252,7 +513,10 @@ impl<'de> serde::Deserialize<'de> for FilteredSequencerBlock {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -264,10 +528,16 @@ impl<'de> serde::Deserialize<'de> for FilteredSequencerBlock {
                         match value {
                             "blockHash" | "block_hash" => Ok(GeneratedField::BlockHash),
                             "header" => Ok(GeneratedField::Header),
-                            "rollupTransactions" | "rollup_transactions" => Ok(GeneratedField::RollupTransactions),
-                            "rollupTransactionsProof" | "rollup_transactions_proof" => Ok(GeneratedField::RollupTransactionsProof),
+                            "rollupTransactions" | "rollup_transactions" => {
+                                Ok(GeneratedField::RollupTransactions)
+                            }
+                            "rollupTransactionsProof" | "rollup_transactions_proof" => {
+                                Ok(GeneratedField::RollupTransactionsProof)
+                            }
                             "allRollupIds" | "all_rollup_ids" => Ok(GeneratedField::AllRollupIds),
-                            "rollupIdsProof" | "rollup_ids_proof" => Ok(GeneratedField::RollupIdsProof),
+                            "rollupIdsProof" | "rollup_ids_proof" => {
+                                Ok(GeneratedField::RollupIdsProof)
+                            }
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -283,9 +553,12 @@ impl<'de> serde::Deserialize<'de> for FilteredSequencerBlock {
                 formatter.write_str("struct astria.sequencerblock.v1alpha1.FilteredSequencerBlock")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<FilteredSequencerBlock, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            fn visit_map<V>(
+                self,
+                mut map_: V,
+            ) -> std::result::Result<FilteredSequencerBlock, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut block_hash__ = None;
                 let mut header__ = None;
@@ -299,9 +572,10 @@ impl<'de> serde::Deserialize<'de> for FilteredSequencerBlock {
                             if block_hash__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("blockHash"));
                             }
-                            block_hash__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            block_hash__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                         GeneratedField::Header => {
                             if header__.is_some() {
@@ -311,13 +585,17 @@ impl<'de> serde::Deserialize<'de> for FilteredSequencerBlock {
                         }
                         GeneratedField::RollupTransactions => {
                             if rollup_transactions__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rollupTransactions"));
+                                return Err(serde::de::Error::duplicate_field(
+                                    "rollupTransactions",
+                                ));
                             }
                             rollup_transactions__ = Some(map_.next_value()?);
                         }
                         GeneratedField::RollupTransactionsProof => {
                             if rollup_transactions_proof__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rollupTransactionsProof"));
+                                return Err(serde::de::Error::duplicate_field(
+                                    "rollupTransactionsProof",
+                                ));
                             }
                             rollup_transactions_proof__ = map_.next_value()?;
                         }
@@ -325,10 +603,12 @@ impl<'de> serde::Deserialize<'de> for FilteredSequencerBlock {
                             if all_rollup_ids__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("allRollupIds"));
                             }
-                            all_rollup_ids__ = 
-                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
-                                    .into_iter().map(|x| x.0).collect())
-                            ;
+                            all_rollup_ids__ = Some(
+                                map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter()
+                                    .map(|x| x.0)
+                                    .collect(),
+                            );
                         }
                         GeneratedField::RollupIdsProof => {
                             if rollup_ids_proof__.is_some() {
@@ -348,7 +628,11 @@ impl<'de> serde::Deserialize<'de> for FilteredSequencerBlock {
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.FilteredSequencerBlock", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.FilteredSequencerBlock",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
 impl serde::Serialize for GetFilteredSequencerBlockRequest {
@@ -365,7 +649,10 @@ impl serde::Serialize for GetFilteredSequencerBlockRequest {
         if !self.rollup_ids.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.GetFilteredSequencerBlockRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct(
+            "astria.sequencerblock.v1alpha1.GetFilteredSequencerBlockRequest",
+            len,
+        )?;
         if self.height != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
@@ -382,11 +669,7 @@ impl<'de> serde::Deserialize<'de> for GetFilteredSequencerBlockRequest {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &[
-            "height",
-            "rollup_ids",
-            "rollupIds",
-        ];
+        const FIELDS: &[&str] = &["height", "rollup_ids", "rollupIds"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
@@ -403,7 +686,10 @@ impl<'de> serde::Deserialize<'de> for GetFilteredSequencerBlockRequest {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -427,12 +713,17 @@ impl<'de> serde::Deserialize<'de> for GetFilteredSequencerBlockRequest {
             type Value = GetFilteredSequencerBlockRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.sequencerblock.v1alpha1.GetFilteredSequencerBlockRequest")
+                formatter.write_str(
+                    "struct astria.sequencerblock.v1alpha1.GetFilteredSequencerBlockRequest",
+                )
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetFilteredSequencerBlockRequest, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            fn visit_map<V>(
+                self,
+                mut map_: V,
+            ) -> std::result::Result<GetFilteredSequencerBlockRequest, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut height__ = None;
                 let mut rollup_ids__ = None;
@@ -442,9 +733,10 @@ impl<'de> serde::Deserialize<'de> for GetFilteredSequencerBlockRequest {
                             if height__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            height__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                         GeneratedField::RollupIds => {
                             if rollup_ids__.is_some() {
@@ -460,7 +752,11 @@ impl<'de> serde::Deserialize<'de> for GetFilteredSequencerBlockRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.GetFilteredSequencerBlockRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.GetFilteredSequencerBlockRequest",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
 impl serde::Serialize for GetPendingNonceRequest {
@@ -474,7 +770,8 @@ impl serde::Serialize for GetPendingNonceRequest {
         if self.address.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.GetPendingNonceRequest", len)?;
+        let mut struct_ser = serializer
+            .serialize_struct("astria.sequencerblock.v1alpha1.GetPendingNonceRequest", len)?;
         if let Some(v) = self.address.as_ref() {
             struct_ser.serialize_field("address", v)?;
         }
@@ -487,9 +784,7 @@ impl<'de> serde::Deserialize<'de> for GetPendingNonceRequest {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &[
-            "address",
-        ];
+        const FIELDS: &[&str] = &["address"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
@@ -505,7 +800,10 @@ impl<'de> serde::Deserialize<'de> for GetPendingNonceRequest {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -531,9 +829,12 @@ impl<'de> serde::Deserialize<'de> for GetPendingNonceRequest {
                 formatter.write_str("struct astria.sequencerblock.v1alpha1.GetPendingNonceRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetPendingNonceRequest, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            fn visit_map<V>(
+                self,
+                mut map_: V,
+            ) -> std::result::Result<GetPendingNonceRequest, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut address__ = None;
                 while let Some(k) = map_.next_key()? {
@@ -551,7 +852,11 @@ impl<'de> serde::Deserialize<'de> for GetPendingNonceRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.GetPendingNonceRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.GetPendingNonceRequest",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
 impl serde::Serialize for GetPendingNonceResponse {
@@ -565,7 +870,10 @@ impl serde::Serialize for GetPendingNonceResponse {
         if self.inner != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.GetPendingNonceResponse", len)?;
+        let mut struct_ser = serializer.serialize_struct(
+            "astria.sequencerblock.v1alpha1.GetPendingNonceResponse",
+            len,
+        )?;
         if self.inner != 0 {
             struct_ser.serialize_field("inner", &self.inner)?;
         }
@@ -578,9 +886,7 @@ impl<'de> serde::Deserialize<'de> for GetPendingNonceResponse {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &[
-            "inner",
-        ];
+        const FIELDS: &[&str] = &["inner"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
@@ -596,7 +902,10 @@ impl<'de> serde::Deserialize<'de> for GetPendingNonceResponse {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -622,9 +931,12 @@ impl<'de> serde::Deserialize<'de> for GetPendingNonceResponse {
                 formatter.write_str("struct astria.sequencerblock.v1alpha1.GetPendingNonceResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetPendingNonceResponse, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            fn visit_map<V>(
+                self,
+                mut map_: V,
+            ) -> std::result::Result<GetPendingNonceResponse, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut inner__ = None;
                 while let Some(k) = map_.next_key()? {
@@ -633,9 +945,10 @@ impl<'de> serde::Deserialize<'de> for GetPendingNonceResponse {
                             if inner__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("inner"));
                             }
-                            inner__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            inner__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                     }
                 }
@@ -644,7 +957,11 @@ impl<'de> serde::Deserialize<'de> for GetPendingNonceResponse {
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.GetPendingNonceResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.GetPendingNonceResponse",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
 impl serde::Serialize for GetSequencerBlockRequest {
@@ -658,7 +975,10 @@ impl serde::Serialize for GetSequencerBlockRequest {
         if self.height != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.GetSequencerBlockRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct(
+            "astria.sequencerblock.v1alpha1.GetSequencerBlockRequest",
+            len,
+        )?;
         if self.height != 0 {
             #[allow(clippy::needless_borrow)]
             struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
@@ -672,9 +992,7 @@ impl<'de> serde::Deserialize<'de> for GetSequencerBlockRequest {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &[
-            "height",
-        ];
+        const FIELDS: &[&str] = &["height"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
@@ -690,7 +1008,10 @@ impl<'de> serde::Deserialize<'de> for GetSequencerBlockRequest {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -713,12 +1034,16 @@ impl<'de> serde::Deserialize<'de> for GetSequencerBlockRequest {
             type Value = GetSequencerBlockRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct astria.sequencerblock.v1alpha1.GetSequencerBlockRequest")
+                formatter
+                    .write_str("struct astria.sequencerblock.v1alpha1.GetSequencerBlockRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GetSequencerBlockRequest, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            fn visit_map<V>(
+                self,
+                mut map_: V,
+            ) -> std::result::Result<GetSequencerBlockRequest, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut height__ = None;
                 while let Some(k) = map_.next_key()? {
@@ -727,9 +1052,10 @@ impl<'de> serde::Deserialize<'de> for GetSequencerBlockRequest {
                             if height__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            height__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                     }
                 }
@@ -738,7 +1064,11 @@ impl<'de> serde::Deserialize<'de> for GetSequencerBlockRequest {
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.GetSequencerBlockRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.GetSequencerBlockRequest",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
 impl serde::Serialize for RollupData {
@@ -752,12 +1082,16 @@ impl serde::Serialize for RollupData {
         if self.value.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.RollupData", len)?;
+        let mut struct_ser =
+            serializer.serialize_struct("astria.sequencerblock.v1alpha1.RollupData", len)?;
         if let Some(v) = self.value.as_ref() {
             match v {
                 rollup_data::Value::SequencedData(v) => {
                     #[allow(clippy::needless_borrow)]
-                    struct_ser.serialize_field("sequenced_data", pbjson::private::base64::encode(&v).as_str())?;
+                    struct_ser.serialize_field(
+                        "sequenced_data",
+                        pbjson::private::base64::encode(&v).as_str(),
+                    )?;
                 }
                 rollup_data::Value::Deposit(v) => {
                     struct_ser.serialize_field("deposit", v)?;
@@ -773,11 +1107,7 @@ impl<'de> serde::Deserialize<'de> for RollupData {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &[
-            "sequenced_data",
-            "sequencedData",
-            "deposit",
-        ];
+        const FIELDS: &[&str] = &["sequenced_data", "sequencedData", "deposit"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
@@ -794,7 +1124,10 @@ impl<'de> serde::Deserialize<'de> for RollupData {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -822,8 +1155,8 @@ impl<'de> serde::Deserialize<'de> for RollupData {
             }
 
             fn visit_map<V>(self, mut map_: V) -> std::result::Result<RollupData, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut value__ = None;
                 while let Some(k) = map_.next_key()? {
@@ -838,8 +1171,9 @@ impl<'de> serde::Deserialize<'de> for RollupData {
                             if value__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("deposit"));
                             }
-                            value__ = map_.next_value::<::std::option::Option<_>>()?.map(rollup_data::Value::Deposit)
-;
+                            value__ = map_
+                                .next_value::<::std::option::Option<_>>()?
+                                .map(rollup_data::Value::Deposit);
                         }
                     }
                 }
@@ -848,7 +1182,11 @@ impl<'de> serde::Deserialize<'de> for RollupData {
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.RollupData", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.RollupData",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
 impl serde::Serialize for RollupTransactions {
@@ -868,12 +1206,20 @@ impl serde::Serialize for RollupTransactions {
         if self.proof.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.RollupTransactions", len)?;
+        let mut struct_ser = serializer
+            .serialize_struct("astria.sequencerblock.v1alpha1.RollupTransactions", len)?;
         if let Some(v) = self.rollup_id.as_ref() {
             struct_ser.serialize_field("rollup_id", v)?;
         }
         if !self.transactions.is_empty() {
-            struct_ser.serialize_field("transactions", &self.transactions.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+            struct_ser.serialize_field(
+                "transactions",
+                &self
+                    .transactions
+                    .iter()
+                    .map(pbjson::private::base64::encode)
+                    .collect::<Vec<_>>(),
+            )?;
         }
         if let Some(v) = self.proof.as_ref() {
             struct_ser.serialize_field("proof", v)?;
@@ -887,12 +1233,7 @@ impl<'de> serde::Deserialize<'de> for RollupTransactions {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &[
-            "rollup_id",
-            "rollupId",
-            "transactions",
-            "proof",
-        ];
+        const FIELDS: &[&str] = &["rollup_id", "rollupId", "transactions", "proof"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
@@ -910,7 +1251,10 @@ impl<'de> serde::Deserialize<'de> for RollupTransactions {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -939,8 +1283,8 @@ impl<'de> serde::Deserialize<'de> for RollupTransactions {
             }
 
             fn visit_map<V>(self, mut map_: V) -> std::result::Result<RollupTransactions, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut rollup_id__ = None;
                 let mut transactions__ = None;
@@ -957,10 +1301,12 @@ impl<'de> serde::Deserialize<'de> for RollupTransactions {
                             if transactions__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("transactions"));
                             }
-                            transactions__ = 
-                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
-                                    .into_iter().map(|x| x.0).collect())
-                            ;
+                            transactions__ = Some(
+                                map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter()
+                                    .map(|x| x.0)
+                                    .collect(),
+                            );
                         }
                         GeneratedField::Proof => {
                             if proof__.is_some() {
@@ -977,7 +1323,11 @@ impl<'de> serde::Deserialize<'de> for RollupTransactions {
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.RollupTransactions", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.RollupTransactions",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
 impl serde::Serialize for SequencerBlock {
@@ -1003,7 +1353,14 @@ impl serde::Serialize for SequencerBlock {
         if !self.block_hash.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.SequencerBlock", len)?;
+        if self.fee_summary.is_some() {
+            len += 1;
+        }
+        if self.fee_summary_proof.is_some() {
+            len += 1;
+        }
+        let mut struct_ser =
+            serializer.serialize_struct("astria.sequencerblock.v1alpha1.SequencerBlock", len)?;
         if let Some(v) = self.header.as_ref() {
             struct_ser.serialize_field("header", v)?;
         }
@@ -1018,7 +1375,16 @@ impl serde::Serialize for SequencerBlock {
         }
         if !self.block_hash.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("block_hash", pbjson::private::base64::encode(&self.block_hash).as_str())?;
+            struct_ser.serialize_field(
+                "block_hash",
+                pbjson::private::base64::encode(&self.block_hash).as_str(),
+            )?;
+        }
+        if let Some(v) = self.fee_summary.as_ref() {
+            struct_ser.serialize_field("fee_summary", v)?;
+        }
+        if let Some(v) = self.fee_summary_proof.as_ref() {
+            struct_ser.serialize_field("fee_summary_proof", v)?;
         }
         struct_ser.end()
     }
@@ -1039,6 +1405,10 @@ impl<'de> serde::Deserialize<'de> for SequencerBlock {
             "rollupIdsProof",
             "block_hash",
             "blockHash",
+            "fee_summary",
+            "feeSummary",
+            "fee_summary_proof",
+            "feeSummaryProof",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1048,6 +1418,8 @@ impl<'de> serde::Deserialize<'de> for SequencerBlock {
             RollupTransactionsProof,
             RollupIdsProof,
             BlockHash,
+            FeeSummary,
+            FeeSummaryProof,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
             fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
@@ -1059,7 +1431,10 @@ impl<'de> serde::Deserialize<'de> for SequencerBlock {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -1070,10 +1445,20 @@ impl<'de> serde::Deserialize<'de> for SequencerBlock {
                     {
                         match value {
                             "header" => Ok(GeneratedField::Header),
-                            "rollupTransactions" | "rollup_transactions" => Ok(GeneratedField::RollupTransactions),
-                            "rollupTransactionsProof" | "rollup_transactions_proof" => Ok(GeneratedField::RollupTransactionsProof),
-                            "rollupIdsProof" | "rollup_ids_proof" => Ok(GeneratedField::RollupIdsProof),
+                            "rollupTransactions" | "rollup_transactions" => {
+                                Ok(GeneratedField::RollupTransactions)
+                            }
+                            "rollupTransactionsProof" | "rollup_transactions_proof" => {
+                                Ok(GeneratedField::RollupTransactionsProof)
+                            }
+                            "rollupIdsProof" | "rollup_ids_proof" => {
+                                Ok(GeneratedField::RollupIdsProof)
+                            }
                             "blockHash" | "block_hash" => Ok(GeneratedField::BlockHash),
+                            "feeSummary" | "fee_summary" => Ok(GeneratedField::FeeSummary),
+                            "feeSummaryProof" | "fee_summary_proof" => {
+                                Ok(GeneratedField::FeeSummaryProof)
+                            }
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1090,14 +1475,16 @@ impl<'de> serde::Deserialize<'de> for SequencerBlock {
             }
 
             fn visit_map<V>(self, mut map_: V) -> std::result::Result<SequencerBlock, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut header__ = None;
                 let mut rollup_transactions__ = None;
                 let mut rollup_transactions_proof__ = None;
                 let mut rollup_ids_proof__ = None;
                 let mut block_hash__ = None;
+                let mut fee_summary__ = None;
+                let mut fee_summary_proof__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Header => {
@@ -1108,13 +1495,17 @@ impl<'de> serde::Deserialize<'de> for SequencerBlock {
                         }
                         GeneratedField::RollupTransactions => {
                             if rollup_transactions__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rollupTransactions"));
+                                return Err(serde::de::Error::duplicate_field(
+                                    "rollupTransactions",
+                                ));
                             }
                             rollup_transactions__ = Some(map_.next_value()?);
                         }
                         GeneratedField::RollupTransactionsProof => {
                             if rollup_transactions_proof__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rollupTransactionsProof"));
+                                return Err(serde::de::Error::duplicate_field(
+                                    "rollupTransactionsProof",
+                                ));
                             }
                             rollup_transactions_proof__ = map_.next_value()?;
                         }
@@ -1128,9 +1519,22 @@ impl<'de> serde::Deserialize<'de> for SequencerBlock {
                             if block_hash__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("blockHash"));
                             }
-                            block_hash__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            block_hash__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
+                        }
+                        GeneratedField::FeeSummary => {
+                            if fee_summary__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeSummary"));
+                            }
+                            fee_summary__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeeSummaryProof => {
+                            if fee_summary_proof__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feeSummaryProof"));
+                            }
+                            fee_summary_proof__ = map_.next_value()?;
                         }
                     }
                 }
@@ -1140,10 +1544,16 @@ impl<'de> serde::Deserialize<'de> for SequencerBlock {
                     rollup_transactions_proof: rollup_transactions_proof__,
                     rollup_ids_proof: rollup_ids_proof__,
                     block_hash: block_hash__.unwrap_or_default(),
+                    fee_summary: fee_summary__,
+                    fee_summary_proof: fee_summary_proof__,
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.SequencerBlock", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.SequencerBlock",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
 impl serde::Serialize for SequencerBlockHeader {
@@ -1172,7 +1582,8 @@ impl serde::Serialize for SequencerBlockHeader {
         if !self.rollup_transactions_root.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.SequencerBlockHeader", len)?;
+        let mut struct_ser = serializer
+            .serialize_struct("astria.sequencerblock.v1alpha1.SequencerBlockHeader", len)?;
         if !self.chain_id.is_empty() {
             struct_ser.serialize_field("chain_id", &self.chain_id)?;
         }
@@ -1185,15 +1596,24 @@ impl serde::Serialize for SequencerBlockHeader {
         }
         if !self.data_hash.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("data_hash", pbjson::private::base64::encode(&self.data_hash).as_str())?;
+            struct_ser.serialize_field(
+                "data_hash",
+                pbjson::private::base64::encode(&self.data_hash).as_str(),
+            )?;
         }
         if !self.proposer_address.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("proposer_address", pbjson::private::base64::encode(&self.proposer_address).as_str())?;
+            struct_ser.serialize_field(
+                "proposer_address",
+                pbjson::private::base64::encode(&self.proposer_address).as_str(),
+            )?;
         }
         if !self.rollup_transactions_root.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("rollup_transactions_root", pbjson::private::base64::encode(&self.rollup_transactions_root).as_str())?;
+            struct_ser.serialize_field(
+                "rollup_transactions_root",
+                pbjson::private::base64::encode(&self.rollup_transactions_root).as_str(),
+            )?;
         }
         struct_ser.end()
     }
@@ -1236,7 +1656,10 @@ impl<'de> serde::Deserialize<'de> for SequencerBlockHeader {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -1250,8 +1673,12 @@ impl<'de> serde::Deserialize<'de> for SequencerBlockHeader {
                             "height" => Ok(GeneratedField::Height),
                             "time" => Ok(GeneratedField::Time),
                             "dataHash" | "data_hash" => Ok(GeneratedField::DataHash),
-                            "proposerAddress" | "proposer_address" => Ok(GeneratedField::ProposerAddress),
-                            "rollupTransactionsRoot" | "rollup_transactions_root" => Ok(GeneratedField::RollupTransactionsRoot),
+                            "proposerAddress" | "proposer_address" => {
+                                Ok(GeneratedField::ProposerAddress)
+                            }
+                            "rollupTransactionsRoot" | "rollup_transactions_root" => {
+                                Ok(GeneratedField::RollupTransactionsRoot)
+                            }
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1267,9 +1694,12 @@ impl<'de> serde::Deserialize<'de> for SequencerBlockHeader {
                 formatter.write_str("struct astria.sequencerblock.v1alpha1.SequencerBlockHeader")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SequencerBlockHeader, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            fn visit_map<V>(
+                self,
+                mut map_: V,
+            ) -> std::result::Result<SequencerBlockHeader, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut chain_id__ = None;
                 let mut height__ = None;
@@ -1289,9 +1719,10 @@ impl<'de> serde::Deserialize<'de> for SequencerBlockHeader {
                             if height__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            height__ = Some(
+                                map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                         GeneratedField::Time => {
                             if time__.is_some() {
@@ -1303,25 +1734,30 @@ impl<'de> serde::Deserialize<'de> for SequencerBlockHeader {
                             if data_hash__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("dataHash"));
                             }
-                            data_hash__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            data_hash__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                         GeneratedField::ProposerAddress => {
                             if proposer_address__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("proposerAddress"));
                             }
-                            proposer_address__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            proposer_address__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                         GeneratedField::RollupTransactionsRoot => {
                             if rollup_transactions_root__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rollupTransactionsRoot"));
+                                return Err(serde::de::Error::duplicate_field(
+                                    "rollupTransactionsRoot",
+                                ));
                             }
-                            rollup_transactions_root__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            rollup_transactions_root__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                     }
                 }
@@ -1335,7 +1771,11 @@ impl<'de> serde::Deserialize<'de> for SequencerBlockHeader {
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.SequencerBlockHeader", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.SequencerBlockHeader",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
 impl serde::Serialize for SubmittedMetadata {
@@ -1361,10 +1801,14 @@ impl serde::Serialize for SubmittedMetadata {
         if self.rollup_ids_proof.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.SubmittedMetadata", len)?;
+        let mut struct_ser =
+            serializer.serialize_struct("astria.sequencerblock.v1alpha1.SubmittedMetadata", len)?;
         if !self.block_hash.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("block_hash", pbjson::private::base64::encode(&self.block_hash).as_str())?;
+            struct_ser.serialize_field(
+                "block_hash",
+                pbjson::private::base64::encode(&self.block_hash).as_str(),
+            )?;
         }
         if let Some(v) = self.header.as_ref() {
             struct_ser.serialize_field("header", v)?;
@@ -1417,7 +1861,10 @@ impl<'de> serde::Deserialize<'de> for SubmittedMetadata {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -1430,8 +1877,12 @@ impl<'de> serde::Deserialize<'de> for SubmittedMetadata {
                             "blockHash" | "block_hash" => Ok(GeneratedField::BlockHash),
                             "header" => Ok(GeneratedField::Header),
                             "rollupIds" | "rollup_ids" => Ok(GeneratedField::RollupIds),
-                            "rollupTransactionsProof" | "rollup_transactions_proof" => Ok(GeneratedField::RollupTransactionsProof),
-                            "rollupIdsProof" | "rollup_ids_proof" => Ok(GeneratedField::RollupIdsProof),
+                            "rollupTransactionsProof" | "rollup_transactions_proof" => {
+                                Ok(GeneratedField::RollupTransactionsProof)
+                            }
+                            "rollupIdsProof" | "rollup_ids_proof" => {
+                                Ok(GeneratedField::RollupIdsProof)
+                            }
                             _ => Err(serde::de::Error::unknown_field(value, FIELDS)),
                         }
                     }
@@ -1448,8 +1899,8 @@ impl<'de> serde::Deserialize<'de> for SubmittedMetadata {
             }
 
             fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmittedMetadata, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut block_hash__ = None;
                 let mut header__ = None;
@@ -1462,9 +1913,10 @@ impl<'de> serde::Deserialize<'de> for SubmittedMetadata {
                             if block_hash__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("blockHash"));
                             }
-                            block_hash__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            block_hash__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                         GeneratedField::Header => {
                             if header__.is_some() {
@@ -1480,7 +1932,9 @@ impl<'de> serde::Deserialize<'de> for SubmittedMetadata {
                         }
                         GeneratedField::RollupTransactionsProof => {
                             if rollup_transactions_proof__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rollupTransactionsProof"));
+                                return Err(serde::de::Error::duplicate_field(
+                                    "rollupTransactionsProof",
+                                ));
                             }
                             rollup_transactions_proof__ = map_.next_value()?;
                         }
@@ -1501,7 +1955,11 @@ impl<'de> serde::Deserialize<'de> for SubmittedMetadata {
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.SubmittedMetadata", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.SubmittedMetadata",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
 impl serde::Serialize for SubmittedMetadataList {
@@ -1515,7 +1973,8 @@ impl serde::Serialize for SubmittedMetadataList {
         if !self.entries.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.SubmittedMetadataList", len)?;
+        let mut struct_ser = serializer
+            .serialize_struct("astria.sequencerblock.v1alpha1.SubmittedMetadataList", len)?;
         if !self.entries.is_empty() {
             struct_ser.serialize_field("entries", &self.entries)?;
         }
@@ -1528,9 +1987,7 @@ impl<'de> serde::Deserialize<'de> for SubmittedMetadataList {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &[
-            "entries",
-        ];
+        const FIELDS: &[&str] = &["entries"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
@@ -1546,7 +2003,10 @@ impl<'de> serde::Deserialize<'de> for SubmittedMetadataList {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -1572,9 +2032,12 @@ impl<'de> serde::Deserialize<'de> for SubmittedMetadataList {
                 formatter.write_str("struct astria.sequencerblock.v1alpha1.SubmittedMetadataList")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmittedMetadataList, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            fn visit_map<V>(
+                self,
+                mut map_: V,
+            ) -> std::result::Result<SubmittedMetadataList, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut entries__ = None;
                 while let Some(k) = map_.next_key()? {
@@ -1592,7 +2055,11 @@ impl<'de> serde::Deserialize<'de> for SubmittedMetadataList {
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.SubmittedMetadataList", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.SubmittedMetadataList",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
 impl serde::Serialize for SubmittedRollupData {
@@ -1615,16 +2082,27 @@ impl serde::Serialize for SubmittedRollupData {
         if self.proof.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.SubmittedRollupData", len)?;
+        let mut struct_ser = serializer
+            .serialize_struct("astria.sequencerblock.v1alpha1.SubmittedRollupData", len)?;
         if !self.sequencer_block_hash.is_empty() {
             #[allow(clippy::needless_borrow)]
-            struct_ser.serialize_field("sequencer_block_hash", pbjson::private::base64::encode(&self.sequencer_block_hash).as_str())?;
+            struct_ser.serialize_field(
+                "sequencer_block_hash",
+                pbjson::private::base64::encode(&self.sequencer_block_hash).as_str(),
+            )?;
         }
         if let Some(v) = self.rollup_id.as_ref() {
             struct_ser.serialize_field("rollup_id", v)?;
         }
         if !self.transactions.is_empty() {
-            struct_ser.serialize_field("transactions", &self.transactions.iter().map(pbjson::private::base64::encode).collect::<Vec<_>>())?;
+            struct_ser.serialize_field(
+                "transactions",
+                &self
+                    .transactions
+                    .iter()
+                    .map(pbjson::private::base64::encode)
+                    .collect::<Vec<_>>(),
+            )?;
         }
         if let Some(v) = self.proof.as_ref() {
             struct_ser.serialize_field("proof", v)?;
@@ -1664,7 +2142,10 @@ impl<'de> serde::Deserialize<'de> for SubmittedRollupData {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -1674,7 +2155,9 @@ impl<'de> serde::Deserialize<'de> for SubmittedRollupData {
                         E: serde::de::Error,
                     {
                         match value {
-                            "sequencerBlockHash" | "sequencer_block_hash" => Ok(GeneratedField::SequencerBlockHash),
+                            "sequencerBlockHash" | "sequencer_block_hash" => {
+                                Ok(GeneratedField::SequencerBlockHash)
+                            }
                             "rollupId" | "rollup_id" => Ok(GeneratedField::RollupId),
                             "transactions" => Ok(GeneratedField::Transactions),
                             "proof" => Ok(GeneratedField::Proof),
@@ -1694,8 +2177,8 @@ impl<'de> serde::Deserialize<'de> for SubmittedRollupData {
             }
 
             fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmittedRollupData, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut sequencer_block_hash__ = None;
                 let mut rollup_id__ = None;
@@ -1705,11 +2188,14 @@ impl<'de> serde::Deserialize<'de> for SubmittedRollupData {
                     match k {
                         GeneratedField::SequencerBlockHash => {
                             if sequencer_block_hash__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequencerBlockHash"));
+                                return Err(serde::de::Error::duplicate_field(
+                                    "sequencerBlockHash",
+                                ));
                             }
-                            sequencer_block_hash__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            sequencer_block_hash__ = Some(
+                                map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?
+                                    .0,
+                            );
                         }
                         GeneratedField::RollupId => {
                             if rollup_id__.is_some() {
@@ -1721,10 +2207,12 @@ impl<'de> serde::Deserialize<'de> for SubmittedRollupData {
                             if transactions__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("transactions"));
                             }
-                            transactions__ = 
-                                Some(map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
-                                    .into_iter().map(|x| x.0).collect())
-                            ;
+                            transactions__ = Some(
+                                map_.next_value::<Vec<::pbjson::private::BytesDeserialize<_>>>()?
+                                    .into_iter()
+                                    .map(|x| x.0)
+                                    .collect(),
+                            );
                         }
                         GeneratedField::Proof => {
                             if proof__.is_some() {
@@ -1742,7 +2230,11 @@ impl<'de> serde::Deserialize<'de> for SubmittedRollupData {
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.SubmittedRollupData", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.SubmittedRollupData",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
 impl serde::Serialize for SubmittedRollupDataList {
@@ -1756,7 +2248,10 @@ impl serde::Serialize for SubmittedRollupDataList {
         if !self.entries.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("astria.sequencerblock.v1alpha1.SubmittedRollupDataList", len)?;
+        let mut struct_ser = serializer.serialize_struct(
+            "astria.sequencerblock.v1alpha1.SubmittedRollupDataList",
+            len,
+        )?;
         if !self.entries.is_empty() {
             struct_ser.serialize_field("entries", &self.entries)?;
         }
@@ -1769,9 +2264,7 @@ impl<'de> serde::Deserialize<'de> for SubmittedRollupDataList {
     where
         D: serde::Deserializer<'de>,
     {
-        const FIELDS: &[&str] = &[
-            "entries",
-        ];
+        const FIELDS: &[&str] = &["entries"];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
@@ -1787,7 +2280,10 @@ impl<'de> serde::Deserialize<'de> for SubmittedRollupDataList {
                 impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
                     type Value = GeneratedField;
 
-                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    fn expecting(
+                        &self,
+                        formatter: &mut std::fmt::Formatter<'_>,
+                    ) -> std::fmt::Result {
                         write!(formatter, "expected one of: {:?}", &FIELDS)
                     }
 
@@ -1813,9 +2309,12 @@ impl<'de> serde::Deserialize<'de> for SubmittedRollupDataList {
                 formatter.write_str("struct astria.sequencerblock.v1alpha1.SubmittedRollupDataList")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SubmittedRollupDataList, V::Error>
-                where
-                    V: serde::de::MapAccess<'de>,
+            fn visit_map<V>(
+                self,
+                mut map_: V,
+            ) -> std::result::Result<SubmittedRollupDataList, V::Error>
+            where
+                V: serde::de::MapAccess<'de>,
             {
                 let mut entries__ = None;
                 while let Some(k) = map_.next_key()? {
@@ -1833,6 +2332,10 @@ impl<'de> serde::Deserialize<'de> for SubmittedRollupDataList {
                 })
             }
         }
-        deserializer.deserialize_struct("astria.sequencerblock.v1alpha1.SubmittedRollupDataList", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct(
+            "astria.sequencerblock.v1alpha1.SubmittedRollupDataList",
+            FIELDS,
+            GeneratedVisitor,
+        )
     }
 }
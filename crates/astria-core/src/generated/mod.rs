@@ -58,10 +58,31 @@ pub mod protocol {
         pub mod v1alpha1;
     }
     #[path = ""]
-    pub mod transaction {
-        #[path = "astria.protocol.transactions.v1alpha1.rs"]
+    pub mod mempool {
+        #[path = "astria.protocol.mempool.v1alpha1.rs"]
         pub mod v1alpha1;
     }
+    pub mod transaction {
+        pub mod v1alpha1 {
+            include!("astria.protocol.transactions.v1alpha1.rs");
+
+            #[cfg(feature = "serde")]
+            mod _serde_impl {
+                use super::*;
+                include!("astria.protocol.transactions.v1alpha1.serde.rs");
+            }
+        }
+
+        pub mod v2 {
+            include!("astria.protocol.transactions.v2.rs");
+
+            #[cfg(feature = "serde")]
+            mod _serde_impl {
+                use super::*;
+                include!("astria.protocol.transactions.v2.serde.rs");
+            }
+        }
+    }
 }
 
 #[path = ""]
@@ -75,6 +96,18 @@ pub mod sequencerblock {
             include!("astria.sequencerblock.v1alpha1.serde.rs");
         }
     }
+
+    #[path = ""]
+    pub mod optimistic {
+        #[path = "astria.sequencerblock.optimistic.v1alpha1.rs"]
+        pub mod v1alpha1;
+    }
+}
+
+#[path = ""]
+pub mod bridge {
+    #[path = "astria.bridge.v1alpha1.rs"]
+    pub mod v1alpha1;
 }
 
 #[path = ""]
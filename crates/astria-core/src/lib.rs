@@ -12,11 +12,16 @@ pub mod execution;
 pub mod primitive;
 pub mod protocol;
 pub mod sequencerblock;
+pub mod validation;
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
 #[cfg(feature = "brotli")]
 pub mod brotli;
 #[cfg(feature = "celestia")]
 pub mod celestia;
+#[cfg(feature = "client")]
+pub mod client;
 #[cfg(feature = "serde")]
 pub(crate) mod serde;
 
@@ -20,6 +20,10 @@ impl AbciErrorCode {
     pub const VALUE_NOT_FOUND: Self = Self(8);
     pub const TRANSACTION_EXPIRED: Self = Self(9);
     pub const TRANSACTION_FAILED: Self = Self(10);
+    pub const NODE_OVERLOADED: Self = Self(11);
+    pub const ACTION_NOT_ACTIVATED: Self = Self(12);
+    pub const QUERY_CANCELLED: Self = Self(13);
+    pub const ACCOUNT_TRANSACTION_LIMIT_REACHED: Self = Self(14);
 }
 
 impl AbciErrorCode {
@@ -37,6 +41,13 @@ impl AbciErrorCode {
             8 => "the requested value was not found".into(),
             9 => "the transaction expired in the app's mempool".into(),
             10 => "the transaction failed to execute in prepare_proposal()".into(),
+            11 => "the node is overloaded and rejected the transaction for paying too low a fee"
+                .into(),
+            12 => "the transaction contains an action that is not yet activated".into(),
+            13 => "the query exceeded a resource limit of the node and was cancelled".into(),
+            14 => {
+                "the account already has the maximum number of transactions in the mempool".into()
+            }
             other => format!("unknown non-zero abci error code: {other}").into(),
         }
     }
@@ -67,6 +78,10 @@ impl From<NonZeroU32> for AbciErrorCode {
             8 => Self::VALUE_NOT_FOUND,
             9 => Self::TRANSACTION_EXPIRED,
             10 => Self::TRANSACTION_FAILED,
+            11 => Self::NODE_OVERLOADED,
+            12 => Self::ACTION_NOT_ACTIVATED,
+            13 => Self::QUERY_CANCELLED,
+            14 => Self::ACCOUNT_TRANSACTION_LIMIT_REACHED,
             other => Self(other),
         }
     }
@@ -1,4 +1,11 @@
 use super::raw;
+use crate::primitive::v1::{
+    asset,
+    Address,
+    AddressError,
+    IncorrectRollupIdLength,
+    RollupId,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BridgeAccountLastTxHashResponse {
@@ -76,3 +83,156 @@ enum BridgeAccountLastTxHashResponseErrorKind {
     #[error("invalid tx hash; must be 32 bytes, got {0} bytes")]
     InvalidTxHash(usize),
 }
+
+/// The details of a bridge account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgeAccountInfo {
+    pub address: Address,
+    pub rollup_id: RollupId,
+    pub asset_id: asset::Id,
+    pub sudo_address: Option<Address>,
+    pub withdrawer_address: Option<Address>,
+}
+
+impl BridgeAccountInfo {
+    /// Converts a protobuf [`raw::BridgeAccountInfo`] to a native [`BridgeAccountInfo`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `address` or `rollup_id` field is not set
+    /// - if any of the addresses is invalid
+    /// - if the rollup ID or the asset ID is not 32 bytes
+    pub fn try_from_raw(raw: raw::BridgeAccountInfo) -> Result<Self, BridgeAccountInfoError> {
+        let raw::BridgeAccountInfo {
+            address,
+            rollup_id,
+            asset_id,
+            sudo_address,
+            withdrawer_address,
+        } = raw;
+        let Some(address) = address else {
+            return Err(BridgeAccountInfoError::field_not_set("address"));
+        };
+        let address = Address::try_from_raw(&address).map_err(BridgeAccountInfoError::address)?;
+        let Some(rollup_id) = rollup_id else {
+            return Err(BridgeAccountInfoError::field_not_set("rollup_id"));
+        };
+        let rollup_id =
+            RollupId::try_from_raw(&rollup_id).map_err(BridgeAccountInfoError::rollup_id)?;
+        let asset_id =
+            asset::Id::try_from_slice(&asset_id).map_err(BridgeAccountInfoError::asset_id)?;
+        let sudo_address = sudo_address
+            .map(|address| Address::try_from_raw(&address))
+            .transpose()
+            .map_err(BridgeAccountInfoError::address)?;
+        let withdrawer_address = withdrawer_address
+            .map(|address| Address::try_from_raw(&address))
+            .transpose()
+            .map_err(BridgeAccountInfoError::address)?;
+        Ok(Self {
+            address,
+            rollup_id,
+            asset_id,
+            sudo_address,
+            withdrawer_address,
+        })
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::BridgeAccountInfo {
+        raw::BridgeAccountInfo {
+            address: Some(self.address.into_raw()),
+            rollup_id: Some(self.rollup_id.into_raw()),
+            asset_id: self.asset_id.get().to_vec(),
+            sudo_address: self.sudo_address.map(Address::into_raw),
+            withdrawer_address: self.withdrawer_address.map(Address::into_raw),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BridgeAccountInfoError(BridgeAccountInfoErrorKind);
+
+impl BridgeAccountInfoError {
+    #[must_use]
+    fn field_not_set(field: &'static str) -> Self {
+        Self(BridgeAccountInfoErrorKind::FieldNotSet(field))
+    }
+
+    #[must_use]
+    fn address(source: AddressError) -> Self {
+        Self(BridgeAccountInfoErrorKind::Address {
+            source,
+        })
+    }
+
+    #[must_use]
+    fn rollup_id(source: IncorrectRollupIdLength) -> Self {
+        Self(BridgeAccountInfoErrorKind::RollupId {
+            source,
+        })
+    }
+
+    #[must_use]
+    fn asset_id(source: asset::IncorrectAssetIdLength) -> Self {
+        Self(BridgeAccountInfoErrorKind::AssetId {
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum BridgeAccountInfoErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("an address was invalid")]
+    Address { source: AddressError },
+    #[error("the rollup ID was invalid")]
+    RollupId { source: IncorrectRollupIdLength },
+    #[error("the asset ID was invalid")]
+    AssetId {
+        source: asset::IncorrectAssetIdLength,
+    },
+}
+
+/// A response containing the bridge accounts matching a query, either the bridge account at a
+/// given address or all bridge accounts of a given rollup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BridgeAccountInfoResponse {
+    pub height: u64,
+    pub accounts: Vec<BridgeAccountInfo>,
+}
+
+impl BridgeAccountInfoResponse {
+    /// Converts a protobuf [`raw::BridgeAccountInfoResponse`] to a native
+    /// [`BridgeAccountInfoResponse`].
+    ///
+    /// # Errors
+    ///
+    /// - if any of the accounts is invalid
+    pub fn try_from_raw(
+        raw: raw::BridgeAccountInfoResponse,
+    ) -> Result<Self, BridgeAccountInfoError> {
+        Ok(Self {
+            height: raw.height,
+            accounts: raw
+                .accounts
+                .into_iter()
+                .map(BridgeAccountInfo::try_from_raw)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::BridgeAccountInfoResponse {
+        raw::BridgeAccountInfoResponse {
+            height: self.height,
+            accounts: self
+                .accounts
+                .into_iter()
+                .map(BridgeAccountInfo::into_raw)
+                .collect(),
+        }
+    }
+}
@@ -21,6 +21,7 @@ use crate::{
     },
     sequencerblock::v1alpha1::{
         block::Deposit,
+        FeeSummary,
         SequencerBlock,
     },
 };
@@ -53,6 +54,9 @@ pub struct ConfigureSequencerBlock {
     pub signing_key: Option<SigningKey>,
     pub sequence_data: Vec<(RollupId, Vec<u8>)>,
     pub deposits: Vec<Deposit>,
+    /// The fee summary the block commits to. If not set, the block is constructed like a block
+    /// before the fee summary activation height.
+    pub fee_summary: Option<FeeSummary>,
     pub unix_timestamp: UnixTimeStamp,
 }
 
@@ -77,6 +81,7 @@ impl ConfigureSequencerBlock {
             sequence_data,
             unix_timestamp,
             deposits,
+            fee_summary,
         } = self;
 
         let block_hash = block_hash.unwrap_or_default();
@@ -145,6 +150,11 @@ impl ConfigureSequencerBlock {
             rollup_transactions_tree.root().to_vec(),
             rollup_ids_root.to_vec(),
         ];
+        data.extend(
+            fee_summary
+                .as_ref()
+                .map(|fee_summary| fee_summary.root().to_vec()),
+        );
         data.extend(txs.into_iter().map(|tx| tx.into_raw().encode_to_vec()));
 
         SequencerBlock::try_from_block_info_and_data(
@@ -155,6 +165,7 @@ impl ConfigureSequencerBlock {
             proposer_address,
             data,
             deposits_map,
+            fee_summary,
         )
         .unwrap()
     }
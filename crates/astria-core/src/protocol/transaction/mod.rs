@@ -1,3 +1,14 @@
 pub mod v1alpha1;
+pub mod v2;
+mod version;
 
-use crate::generated::protocol::transaction::v1alpha1 as raw;
+pub use version::{
+    TransactionVersion,
+    TransactionVersionError,
+    VersionedSignedTransaction,
+};
+
+use crate::generated::protocol::transaction::{
+    v1alpha1 as raw,
+    v2 as raw_v2,
+};
@@ -7,6 +7,7 @@ use ibc_types::{
 };
 use penumbra_ibc::IbcRelay;
 use penumbra_proto::penumbra::core::component::ibc::v1::FungibleTokenPacketData;
+use prost::Message as _;
 
 use super::raw;
 use crate::{
@@ -19,14 +20,30 @@ use crate::{
         AddressError,
         IncorrectRollupIdLength,
         RollupId,
+        ADDRESS_LEN,
+        FEE_ASSET_ID_LEN,
+        ROLLUP_ID_LEN,
+    },
+    sequencerblock::v1alpha1::block::Deposit,
+    validation::{
+        ValidationError,
+        ValidationErrorCategory,
+        ValidationErrorCode,
     },
     Protobuf,
 };
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::Action", try_from = "raw::Action")
+)]
 pub enum Action {
     Sequence(SequenceAction),
     Transfer(TransferAction),
+    AllowanceChange(AllowanceChangeAction),
+    TransferFrom(TransferFromAction),
     ValidatorUpdate(tendermint::validator::Update),
     SudoAddressChange(SudoAddressChangeAction),
     Ibc(IbcRelay),
@@ -37,16 +54,88 @@ pub enum Action {
     BridgeLock(BridgeLockAction),
     BridgeUnlock(BridgeUnlockAction),
     BridgeSudoChange(BridgeSudoChangeAction),
+    BridgeRecoveryVeto(BridgeRecoveryVetoAction),
     FeeChange(FeeChangeAction),
+    AddressPrefixChange(AddressPrefixChangeAction),
+    BridgeRecovery(BridgeRecoveryAction),
+    FeeDistributionChange(FeeDistributionChangeAction),
+}
+
+impl From<Action> for raw::Action {
+    fn from(value: Action) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::Action> for Action {
+    type Error = ActionError;
+
+    fn try_from(raw: raw::Action) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
 }
 
 impl Action {
+    /// The names of all action types, as returned by [`Action::name`].
+    pub const NAMES: [&'static str; 19] = [
+        "sequence",
+        "transfer",
+        "allowance_change",
+        "transfer_from",
+        "validator_update",
+        "sudo_address_change",
+        "fee_change",
+        "ibc",
+        "ics20_withdrawal",
+        "ibc_relayer_change",
+        "fee_asset_change",
+        "init_bridge_account",
+        "bridge_lock",
+        "bridge_unlock",
+        "bridge_sudo_change",
+        "bridge_recovery_veto",
+        "address_prefix_change",
+        "bridge_recovery",
+        "fee_distribution_change",
+    ];
+
+    /// Returns the name of the type of the action, such as `bridge_lock`.
+    ///
+    /// The sequencer keys the activation heights of actions in its genesis state by these names,
+    /// so they must not be changed.
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        match self {
+            Action::Sequence(_) => "sequence",
+            Action::Transfer(_) => "transfer",
+            Action::AllowanceChange(_) => "allowance_change",
+            Action::TransferFrom(_) => "transfer_from",
+            Action::ValidatorUpdate(_) => "validator_update",
+            Action::SudoAddressChange(_) => "sudo_address_change",
+            Action::FeeChange(_) => "fee_change",
+            Action::Ibc(_) => "ibc",
+            Action::Ics20Withdrawal(_) => "ics20_withdrawal",
+            Action::IbcRelayerChange(_) => "ibc_relayer_change",
+            Action::FeeAssetChange(_) => "fee_asset_change",
+            Action::InitBridgeAccount(_) => "init_bridge_account",
+            Action::BridgeLock(_) => "bridge_lock",
+            Action::BridgeUnlock(_) => "bridge_unlock",
+            Action::BridgeSudoChange(_) => "bridge_sudo_change",
+            Action::BridgeRecoveryVeto(_) => "bridge_recovery_veto",
+            Action::AddressPrefixChange(_) => "address_prefix_change",
+            Action::BridgeRecovery(_) => "bridge_recovery",
+            Action::FeeDistributionChange(_) => "fee_distribution_change",
+        }
+    }
+
     #[must_use]
     pub fn into_raw(self) -> raw::Action {
         use raw::action::Value;
         let kind = match self {
             Action::Sequence(act) => Value::SequenceAction(act.into_raw()),
             Action::Transfer(act) => Value::TransferAction(act.into_raw()),
+            Action::AllowanceChange(act) => Value::AllowanceChangeAction(act.into_raw()),
+            Action::TransferFrom(act) => Value::TransferFromAction(act.into_raw()),
             Action::ValidatorUpdate(act) => Value::ValidatorUpdateAction(act.into()),
             Action::SudoAddressChange(act) => Value::SudoAddressChangeAction(act.into_raw()),
             Action::Ibc(act) => Value::IbcAction(act.into()),
@@ -57,7 +146,13 @@ impl Action {
             Action::BridgeLock(act) => Value::BridgeLockAction(act.into_raw()),
             Action::BridgeUnlock(act) => Value::BridgeUnlockAction(act.into_raw()),
             Action::BridgeSudoChange(act) => Value::BridgeSudoChangeAction(act.into_raw()),
+            Action::BridgeRecoveryVeto(act) => Value::BridgeRecoveryVetoAction(act.into_raw()),
             Action::FeeChange(act) => Value::FeeChangeAction(act.into_raw()),
+            Action::AddressPrefixChange(act) => Value::AddressPrefixChangeAction(act.into_raw()),
+            Action::BridgeRecovery(act) => Value::BridgeRecoveryAction(act.into_raw()),
+            Action::FeeDistributionChange(act) => {
+                Value::FeeDistributionChangeAction(act.into_raw())
+            }
         };
         raw::Action {
             value: Some(kind),
@@ -70,6 +165,8 @@ impl Action {
         let kind = match self {
             Action::Sequence(act) => Value::SequenceAction(act.to_raw()),
             Action::Transfer(act) => Value::TransferAction(act.to_raw()),
+            Action::AllowanceChange(act) => Value::AllowanceChangeAction(act.to_raw()),
+            Action::TransferFrom(act) => Value::TransferFromAction(act.to_raw()),
             Action::ValidatorUpdate(act) => Value::ValidatorUpdateAction(act.clone().into()),
             Action::SudoAddressChange(act) => {
                 Value::SudoAddressChangeAction(act.clone().into_raw())
@@ -82,7 +179,11 @@ impl Action {
             Action::BridgeLock(act) => Value::BridgeLockAction(act.to_raw()),
             Action::BridgeUnlock(act) => Value::BridgeUnlockAction(act.to_raw()),
             Action::BridgeSudoChange(act) => Value::BridgeSudoChangeAction(act.to_raw()),
+            Action::BridgeRecoveryVeto(act) => Value::BridgeRecoveryVetoAction(act.to_raw()),
             Action::FeeChange(act) => Value::FeeChangeAction(act.to_raw()),
+            Action::AddressPrefixChange(act) => Value::AddressPrefixChangeAction(act.to_raw()),
+            Action::BridgeRecovery(act) => Value::BridgeRecoveryAction(act.to_raw()),
+            Action::FeeDistributionChange(act) => Value::FeeDistributionChangeAction(act.to_raw()),
         };
         raw::Action {
             value: Some(kind),
@@ -110,6 +211,12 @@ impl Action {
             Value::TransferAction(act) => {
                 Self::Transfer(TransferAction::try_from_raw(act).map_err(ActionError::transfer)?)
             }
+            Value::AllowanceChangeAction(act) => Self::AllowanceChange(
+                AllowanceChangeAction::try_from_raw(act).map_err(ActionError::allowance_change)?,
+            ),
+            Value::TransferFromAction(act) => Self::TransferFrom(
+                TransferFromAction::try_from_raw(act).map_err(ActionError::transfer_from)?,
+            ),
             Value::ValidatorUpdateAction(act) => {
                 Self::ValidatorUpdate(act.try_into().map_err(ActionError::validator_update)?)
             }
@@ -144,13 +251,64 @@ impl Action {
                 BridgeSudoChangeAction::try_from_raw(act)
                     .map_err(ActionError::bridge_sudo_change)?,
             ),
+            Value::BridgeRecoveryVetoAction(act) => Self::BridgeRecoveryVeto(
+                BridgeRecoveryVetoAction::try_from_raw(act)
+                    .map_err(ActionError::bridge_recovery_veto)?,
+            ),
             Value::FeeChangeAction(act) => Self::FeeChange(
                 FeeChangeAction::try_from_raw(&act).map_err(ActionError::fee_change)?,
             ),
+            Value::AddressPrefixChangeAction(act) => Self::AddressPrefixChange(
+                AddressPrefixChangeAction::try_from_raw(&act)
+                    .map_err(ActionError::address_prefix_change)?,
+            ),
+            Value::BridgeRecoveryAction(act) => Self::BridgeRecovery(
+                BridgeRecoveryAction::try_from_raw(act).map_err(ActionError::bridge_recovery)?,
+            ),
+            Value::FeeDistributionChangeAction(act) => Self::FeeDistributionChange(
+                FeeDistributionChangeAction::try_from_raw(act)
+                    .map_err(ActionError::fee_distribution_change)?,
+            ),
         };
         Ok(action)
     }
 
+    /// Returns the length of the protobuf encoding of the action.
+    #[must_use]
+    pub fn encoded_size(&self) -> usize {
+        self.to_raw().encoded_len()
+    }
+
+    /// Returns the number of bytes for which the sequencer charges a per-byte fee when executing
+    /// the action.
+    ///
+    /// This is the data of sequence actions and the deposit created by bridge lock actions. All
+    /// other actions are only charged a base fee, and have a fee weight of zero.
+    #[must_use]
+    pub fn fee_weight(&self) -> usize {
+        match self {
+            Action::Sequence(act) => act.fee_weight(),
+            Action::BridgeLock(act) => act.fee_weight(),
+            Action::Transfer(_)
+            | Action::AllowanceChange(_)
+            | Action::TransferFrom(_)
+            | Action::ValidatorUpdate(_)
+            | Action::SudoAddressChange(_)
+            | Action::Ibc(_)
+            | Action::Ics20Withdrawal(_)
+            | Action::IbcRelayerChange(_)
+            | Action::FeeAssetChange(_)
+            | Action::InitBridgeAccount(_)
+            | Action::BridgeUnlock(_)
+            | Action::BridgeSudoChange(_)
+            | Action::BridgeRecoveryVeto(_)
+            | Action::FeeChange(_)
+            | Action::AddressPrefixChange(_)
+            | Action::BridgeRecovery(_)
+            | Action::FeeDistributionChange(_) => 0,
+        }
+    }
+
     #[must_use]
     pub fn as_sequence(&self) -> Option<&SequenceAction> {
         let Self::Sequence(sequence_action) = self else {
@@ -180,6 +338,18 @@ impl From<TransferAction> for Action {
     }
 }
 
+impl From<AllowanceChangeAction> for Action {
+    fn from(value: AllowanceChangeAction) -> Self {
+        Self::AllowanceChange(value)
+    }
+}
+
+impl From<TransferFromAction> for Action {
+    fn from(value: TransferFromAction) -> Self {
+        Self::TransferFrom(value)
+    }
+}
+
 impl From<SudoAddressChangeAction> for Action {
     fn from(value: SudoAddressChangeAction) -> Self {
         Self::SudoAddressChange(value)
@@ -234,12 +404,36 @@ impl From<BridgeSudoChangeAction> for Action {
     }
 }
 
+impl From<BridgeRecoveryVetoAction> for Action {
+    fn from(value: BridgeRecoveryVetoAction) -> Self {
+        Self::BridgeRecoveryVeto(value)
+    }
+}
+
 impl From<FeeChangeAction> for Action {
     fn from(value: FeeChangeAction) -> Self {
         Self::FeeChange(value)
     }
 }
 
+impl From<AddressPrefixChangeAction> for Action {
+    fn from(value: AddressPrefixChangeAction) -> Self {
+        Self::AddressPrefixChange(value)
+    }
+}
+
+impl From<BridgeRecoveryAction> for Action {
+    fn from(value: BridgeRecoveryAction) -> Self {
+        Self::BridgeRecovery(value)
+    }
+}
+
+impl From<FeeDistributionChangeAction> for Action {
+    fn from(value: FeeDistributionChangeAction) -> Self {
+        Self::FeeDistributionChange(value)
+    }
+}
+
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
@@ -258,6 +452,14 @@ impl ActionError {
         Self(ActionErrorKind::Transfer(inner))
     }
 
+    fn allowance_change(inner: AllowanceChangeActionError) -> Self {
+        Self(ActionErrorKind::AllowanceChange(inner))
+    }
+
+    fn transfer_from(inner: TransferFromActionError) -> Self {
+        Self(ActionErrorKind::TransferFrom(inner))
+    }
+
     fn validator_update(inner: tendermint::error::Error) -> Self {
         Self(ActionErrorKind::ValidatorUpdate(inner))
     }
@@ -298,9 +500,25 @@ impl ActionError {
         Self(ActionErrorKind::BridgeSudoChange(inner))
     }
 
+    fn bridge_recovery_veto(inner: BridgeRecoveryVetoActionError) -> Self {
+        Self(ActionErrorKind::BridgeRecoveryVeto(inner))
+    }
+
     fn fee_change(inner: FeeChangeActionError) -> Self {
         Self(ActionErrorKind::FeeChange(inner))
     }
+
+    fn address_prefix_change(inner: AddressPrefixChangeActionError) -> Self {
+        Self(ActionErrorKind::AddressPrefixChange(inner))
+    }
+
+    fn bridge_recovery(inner: BridgeRecoveryActionError) -> Self {
+        Self(ActionErrorKind::BridgeRecovery(inner))
+    }
+
+    fn fee_distribution_change(inner: FeeDistributionChangeActionError) -> Self {
+        Self(ActionErrorKind::FeeDistributionChange(inner))
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -311,6 +529,10 @@ enum ActionErrorKind {
     Sequence(#[source] SequenceActionError),
     #[error("transfer action was not valid")]
     Transfer(#[source] TransferActionError),
+    #[error("allowance change action was not valid")]
+    AllowanceChange(#[source] AllowanceChangeActionError),
+    #[error("transfer from action was not valid")]
+    TransferFrom(#[source] TransferFromActionError),
     #[error("validator update action was not valid")]
     ValidatorUpdate(#[source] tendermint::error::Error),
     #[error("sudo address change action was not valid")]
@@ -331,8 +553,49 @@ enum ActionErrorKind {
     BridgeUnlock(#[source] BridgeUnlockActionError),
     #[error("bridge sudo change action was not valid")]
     BridgeSudoChange(#[source] BridgeSudoChangeActionError),
+    #[error("bridge recovery veto action was not valid")]
+    BridgeRecoveryVeto(#[source] BridgeRecoveryVetoActionError),
     #[error("fee change action was not valid")]
     FeeChange(#[source] FeeChangeActionError),
+    #[error("address prefix change action was not valid")]
+    AddressPrefixChange(#[source] AddressPrefixChangeActionError),
+    #[error("bridge recovery action was not valid")]
+    BridgeRecovery(#[source] BridgeRecoveryActionError),
+    #[error("fee distribution change action was not valid")]
+    FeeDistributionChange(#[source] FeeDistributionChangeActionError),
+}
+
+impl ValidationError for ActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            ActionErrorKind::Unset => {
+                ValidationErrorCode::new(1201, ValidationErrorCategory::MissingField)
+            }
+            ActionErrorKind::Sequence(source) => source.validation_code(),
+            ActionErrorKind::Transfer(source) => source.validation_code(),
+            ActionErrorKind::AllowanceChange(source) => source.validation_code(),
+            ActionErrorKind::TransferFrom(source) => source.validation_code(),
+            ActionErrorKind::ValidatorUpdate(..) => {
+                ValidationErrorCode::new(1204, ValidationErrorCategory::InvalidField)
+            }
+            ActionErrorKind::SudoAddressChange(source) => source.validation_code(),
+            ActionErrorKind::Ibc(..) => {
+                ValidationErrorCode::new(1206, ValidationErrorCategory::InvalidField)
+            }
+            ActionErrorKind::Ics20Withdrawal(source) => source.validation_code(),
+            ActionErrorKind::IbcRelayerChange(source) => source.validation_code(),
+            ActionErrorKind::FeeAssetChange(source) => source.validation_code(),
+            ActionErrorKind::InitBridgeAccount(source) => source.validation_code(),
+            ActionErrorKind::BridgeLock(source) => source.validation_code(),
+            ActionErrorKind::BridgeUnlock(source) => source.validation_code(),
+            ActionErrorKind::BridgeSudoChange(source) => source.validation_code(),
+            ActionErrorKind::BridgeRecoveryVeto(source) => source.validation_code(),
+            ActionErrorKind::FeeChange(source) => source.validation_code(),
+            ActionErrorKind::AddressPrefixChange(source) => source.validation_code(),
+            ActionErrorKind::BridgeRecovery(source) => source.validation_code(),
+            ActionErrorKind::FeeDistributionChange(source) => source.validation_code(),
+        }
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -363,8 +626,35 @@ enum SequenceActionErrorKind {
     FeeAssetId(asset::IncorrectAssetIdLength),
 }
 
+impl ValidationError for SequenceActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            SequenceActionErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(1301, ValidationErrorCategory::MissingField)
+            }
+            SequenceActionErrorKind::RollupIdLength(..) => {
+                ValidationErrorCode::new(1302, ValidationErrorCategory::InvalidField)
+            }
+            SequenceActionErrorKind::FeeAssetId(..) => {
+                ValidationErrorCode::new(1303, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
+}
+
+/// Marks a required field of an action builder which has not been set yet.
+///
+/// A builder's `build` method is only available once none of its fields are `Unset`, so a missing
+/// required field is caught at compile time.
+pub struct Unset;
+
 #[derive(Clone, Debug)]
 #[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::SequenceAction", try_from = "raw::SequenceAction")
+)]
 pub struct SequenceAction {
     pub rollup_id: RollupId,
     pub data: Vec<u8>,
@@ -372,7 +662,54 @@ pub struct SequenceAction {
     pub fee_asset_id: asset::Id,
 }
 
+impl From<SequenceAction> for raw::SequenceAction {
+    fn from(value: SequenceAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::SequenceAction> for SequenceAction {
+    type Error = SequenceActionError;
+
+    fn try_from(raw: raw::SequenceAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
 impl SequenceAction {
+    /// Returns a builder of a sequence action, which can only be built once all required fields are
+    /// set.
+    #[must_use = "the builder must be built to construct a sequence action to be useful"]
+    pub fn builder() -> SequenceActionBuilder {
+        SequenceActionBuilder::new()
+    }
+
+    /// Returns the length of the protobuf encoding of the action.
+    #[must_use]
+    pub fn encoded_size(&self) -> usize {
+        self.to_raw().encoded_len()
+    }
+
+    /// Returns the size of the action's payload: its data, rollup ID, and fee asset ID.
+    ///
+    /// This is a lower bound of [`SequenceAction::encoded_size`] that does not depend on the
+    /// protobuf framing. It is used to limit the number of actions that are bundled into a
+    /// single transaction.
+    #[must_use]
+    pub fn payload_size(&self) -> usize {
+        self.data
+            .len()
+            .saturating_add(ROLLUP_ID_LEN)
+            .saturating_add(FEE_ASSET_ID_LEN)
+    }
+
+    /// Returns the number of bytes for which the sequencer charges its per-byte fee, which is
+    /// the length of the action's data.
+    #[must_use]
+    pub fn fee_weight(&self) -> usize {
+        self.data.len()
+    }
+
     #[must_use]
     pub fn into_raw(self) -> raw::SequenceAction {
         let Self {
@@ -426,8 +763,87 @@ impl SequenceAction {
     }
 }
 
+/// A builder of [`SequenceAction`], returned by [`SequenceAction::builder`].
+///
+/// [`SequenceActionBuilder::build`] is only available once all required fields are set.
+pub struct SequenceActionBuilder<TRollupId = Unset, TData = Unset, TFeeAssetId = Unset> {
+    rollup_id: TRollupId,
+    data: TData,
+    fee_asset_id: TFeeAssetId,
+}
+
+impl SequenceActionBuilder {
+    fn new() -> Self {
+        Self {
+            rollup_id: Unset,
+            data: Unset,
+            fee_asset_id: Unset,
+        }
+    }
+}
+
+impl<TRollupId, TData, TFeeAssetId> SequenceActionBuilder<TRollupId, TData, TFeeAssetId> {
+    #[must_use = "the builder must be built to construct a sequence action to be useful"]
+    pub fn rollup_id(
+        self,
+        rollup_id: RollupId,
+    ) -> SequenceActionBuilder<RollupId, TData, TFeeAssetId> {
+        SequenceActionBuilder {
+            rollup_id,
+            data: self.data,
+            fee_asset_id: self.fee_asset_id,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a sequence action to be useful"]
+    pub fn data<T: Into<Vec<u8>>>(
+        self,
+        data: T,
+    ) -> SequenceActionBuilder<TRollupId, Vec<u8>, TFeeAssetId> {
+        SequenceActionBuilder {
+            rollup_id: self.rollup_id,
+            data: data.into(),
+            fee_asset_id: self.fee_asset_id,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a sequence action to be useful"]
+    pub fn fee_asset_id(
+        self,
+        fee_asset_id: asset::Id,
+    ) -> SequenceActionBuilder<TRollupId, TData, asset::Id> {
+        SequenceActionBuilder {
+            rollup_id: self.rollup_id,
+            data: self.data,
+            fee_asset_id,
+        }
+    }
+}
+
+impl SequenceActionBuilder<RollupId, Vec<u8>, asset::Id> {
+    /// Constructs a sequence action from the configured builder.
+    #[must_use]
+    pub fn build(self) -> SequenceAction {
+        let Self {
+            rollup_id,
+            data,
+            fee_asset_id,
+        } = self;
+        SequenceAction {
+            rollup_id,
+            data,
+            fee_asset_id,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 #[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::TransferAction", try_from = "raw::TransferAction")
+)]
 pub struct TransferAction {
     pub to: Address,
     pub amount: u128,
@@ -437,7 +853,28 @@ pub struct TransferAction {
     pub fee_asset_id: asset::Id,
 }
 
+impl From<TransferAction> for raw::TransferAction {
+    fn from(value: TransferAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::TransferAction> for TransferAction {
+    type Error = TransferActionError;
+
+    fn try_from(raw: raw::TransferAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
 impl TransferAction {
+    /// Returns a builder of a transfer action, which can only be built once all required fields are
+    /// set.
+    #[must_use = "the builder must be built to construct a transfer action to be useful"]
+    pub fn builder() -> TransferActionBuilder {
+        TransferActionBuilder::new()
+    }
+
     #[must_use]
     pub fn into_raw(self) -> raw::TransferAction {
         let Self {
@@ -502,6 +939,101 @@ impl TransferAction {
     }
 }
 
+/// A builder of [`TransferAction`], returned by [`TransferAction::builder`].
+///
+/// [`TransferActionBuilder::build`] is only available once all required fields are set.
+pub struct TransferActionBuilder<
+    TTo = Unset,
+    TAmount = Unset,
+    TAssetId = Unset,
+    TFeeAssetId = Unset,
+> {
+    to: TTo,
+    amount: TAmount,
+    asset_id: TAssetId,
+    fee_asset_id: TFeeAssetId,
+}
+
+impl TransferActionBuilder {
+    fn new() -> Self {
+        Self {
+            to: Unset,
+            amount: Unset,
+            asset_id: Unset,
+            fee_asset_id: Unset,
+        }
+    }
+}
+
+impl<TTo, TAmount, TAssetId, TFeeAssetId>
+    TransferActionBuilder<TTo, TAmount, TAssetId, TFeeAssetId>
+{
+    #[must_use = "the builder must be built to construct a transfer action to be useful"]
+    pub fn to(self, to: Address) -> TransferActionBuilder<Address, TAmount, TAssetId, TFeeAssetId> {
+        TransferActionBuilder {
+            to,
+            amount: self.amount,
+            asset_id: self.asset_id,
+            fee_asset_id: self.fee_asset_id,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a transfer action to be useful"]
+    pub fn amount(self, amount: u128) -> TransferActionBuilder<TTo, u128, TAssetId, TFeeAssetId> {
+        TransferActionBuilder {
+            to: self.to,
+            amount,
+            asset_id: self.asset_id,
+            fee_asset_id: self.fee_asset_id,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a transfer action to be useful"]
+    pub fn asset_id(
+        self,
+        asset_id: asset::Id,
+    ) -> TransferActionBuilder<TTo, TAmount, asset::Id, TFeeAssetId> {
+        TransferActionBuilder {
+            to: self.to,
+            amount: self.amount,
+            asset_id,
+            fee_asset_id: self.fee_asset_id,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a transfer action to be useful"]
+    pub fn fee_asset_id(
+        self,
+        fee_asset_id: asset::Id,
+    ) -> TransferActionBuilder<TTo, TAmount, TAssetId, asset::Id> {
+        TransferActionBuilder {
+            to: self.to,
+            amount: self.amount,
+            asset_id: self.asset_id,
+            fee_asset_id,
+        }
+    }
+}
+
+impl TransferActionBuilder<Address, u128, asset::Id, asset::Id> {
+    /// Constructs a transfer action from the configured builder.
+    #[must_use]
+    pub fn build(self) -> TransferAction {
+        let Self {
+            to,
+            amount,
+            asset_id,
+            fee_asset_id,
+        } = self;
+        TransferAction {
+            to,
+            amount,
+            asset_id,
+            fee_asset_id,
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
 pub struct TransferActionError(TransferActionErrorKind);
@@ -536,471 +1068,1617 @@ enum TransferActionErrorKind {
     FeeAsset(#[source] asset::IncorrectAssetIdLength),
 }
 
+impl ValidationError for TransferActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            TransferActionErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(1401, ValidationErrorCategory::MissingField)
+            }
+            TransferActionErrorKind::Address(..) => {
+                ValidationErrorCode::new(1402, ValidationErrorCategory::InvalidField)
+            }
+            TransferActionErrorKind::Asset(..) => {
+                ValidationErrorCode::new(1403, ValidationErrorCategory::InvalidField)
+            }
+            TransferActionErrorKind::FeeAsset(..) => {
+                ValidationErrorCode::new(1404, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
+}
+
+/// Authorizes a spender to transfer up to an amount of the signer's asset until an expiry height.
+///
+/// Any existing allowance of the spender for the same asset is replaced. An `amount` of zero
+/// revokes the allowance.
 #[derive(Clone, Debug)]
 #[allow(clippy::module_name_repetitions)]
-pub struct SudoAddressChangeAction {
-    pub new_address: Address,
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::AllowanceChangeAction",
+        try_from = "raw::AllowanceChangeAction"
+    )
+)]
+pub struct AllowanceChangeAction {
+    pub spender: Address,
+    pub amount: u128,
+    // asset the spender is allowed to transfer.
+    pub asset_id: asset::Id,
+    /// the last block height at which the allowance can be spent.
+    pub expiry_height: u64,
+    /// asset to use for fee payment.
+    pub fee_asset_id: asset::Id,
 }
 
-impl SudoAddressChangeAction {
+impl From<AllowanceChangeAction> for raw::AllowanceChangeAction {
+    fn from(value: AllowanceChangeAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::AllowanceChangeAction> for AllowanceChangeAction {
+    type Error = AllowanceChangeActionError;
+
+    fn try_from(raw: raw::AllowanceChangeAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl AllowanceChangeAction {
+    /// Returns a builder of an allowance change action, which can only be built once all required
+    /// fields are set.
+    #[must_use = "the builder must be built to construct an allowance change action to be useful"]
+    pub fn builder() -> AllowanceChangeActionBuilder {
+        AllowanceChangeActionBuilder::new()
+    }
+
     #[must_use]
-    pub fn into_raw(self) -> raw::SudoAddressChangeAction {
+    pub fn into_raw(self) -> raw::AllowanceChangeAction {
         let Self {
-            new_address,
+            spender,
+            amount,
+            asset_id,
+            expiry_height,
+            fee_asset_id,
         } = self;
-        raw::SudoAddressChangeAction {
-            new_address: Some(new_address.into_raw()),
+        raw::AllowanceChangeAction {
+            spender: Some(spender.into_raw()),
+            amount: Some(amount.into()),
+            asset_id: asset_id.get().to_vec(),
+            expiry_height,
+            fee_asset_id: fee_asset_id.get().to_vec(),
         }
     }
 
     #[must_use]
-    pub fn to_raw(&self) -> raw::SudoAddressChangeAction {
+    pub fn to_raw(&self) -> raw::AllowanceChangeAction {
         let Self {
-            new_address,
+            spender,
+            amount,
+            asset_id,
+            expiry_height,
+            fee_asset_id,
         } = self;
-        raw::SudoAddressChangeAction {
-            new_address: Some(new_address.to_raw()),
+        raw::AllowanceChangeAction {
+            spender: Some(spender.to_raw()),
+            amount: Some((*amount).into()),
+            asset_id: asset_id.get().to_vec(),
+            expiry_height: *expiry_height,
+            fee_asset_id: fee_asset_id.get().to_vec(),
         }
     }
 
-    /// Convert from a raw, unchecked protobuf [`raw::SudoAddressChangeAction`].
+    /// Convert from a raw, unchecked protobuf [`raw::AllowanceChangeAction`].
     ///
     /// # Errors
     ///
-    /// Returns an error if the raw action's `new_address` did not have the expected
-    /// length.
+    /// - if the `spender` field is not set
+    /// - if the `spender` field is invalid
+    /// - if the `asset_id` field is invalid
+    /// - if the `fee_asset_id` field is invalid
     pub fn try_from_raw(
-        proto: raw::SudoAddressChangeAction,
-    ) -> Result<Self, SudoAddressChangeActionError> {
-        let raw::SudoAddressChangeAction {
-            new_address,
+        proto: raw::AllowanceChangeAction,
+    ) -> Result<Self, AllowanceChangeActionError> {
+        let raw::AllowanceChangeAction {
+            spender,
+            amount,
+            asset_id,
+            expiry_height,
+            fee_asset_id,
         } = proto;
-        let Some(new_address) = new_address else {
-            return Err(SudoAddressChangeActionError::field_not_set("new_address"));
+        let Some(spender) = spender else {
+            return Err(AllowanceChangeActionError::field_not_set("spender"));
         };
-        let new_address =
-            Address::try_from_raw(&new_address).map_err(SudoAddressChangeActionError::address)?;
+        let spender =
+            Address::try_from_raw(&spender).map_err(AllowanceChangeActionError::address)?;
+        let amount = amount.map_or(0, Into::into);
+        let asset_id =
+            asset::Id::try_from_slice(&asset_id).map_err(AllowanceChangeActionError::asset_id)?;
+        let fee_asset_id = asset::Id::try_from_slice(&fee_asset_id)
+            .map_err(AllowanceChangeActionError::fee_asset_id)?;
+
         Ok(Self {
-            new_address,
+            spender,
+            amount,
+            asset_id,
+            expiry_height,
+            fee_asset_id,
         })
     }
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error(transparent)]
-pub struct SudoAddressChangeActionError(SudoAddressChangeActionErrorKind);
+/// A builder of [`AllowanceChangeAction`], returned by [`AllowanceChangeAction::builder`].
+///
+/// [`AllowanceChangeActionBuilder::build`] is only available once all required fields are set.
+pub struct AllowanceChangeActionBuilder<
+    TSpender = Unset,
+    TAmount = Unset,
+    TAssetId = Unset,
+    TExpiryHeight = Unset,
+    TFeeAssetId = Unset,
+> {
+    spender: TSpender,
+    amount: TAmount,
+    asset_id: TAssetId,
+    expiry_height: TExpiryHeight,
+    fee_asset_id: TFeeAssetId,
+}
 
-impl SudoAddressChangeActionError {
-    fn field_not_set(field: &'static str) -> Self {
-        Self(SudoAddressChangeActionErrorKind::FieldNotSet(field))
+impl AllowanceChangeActionBuilder {
+    fn new() -> Self {
+        Self {
+            spender: Unset,
+            amount: Unset,
+            asset_id: Unset,
+            expiry_height: Unset,
+            fee_asset_id: Unset,
+        }
     }
+}
 
-    fn address(source: AddressError) -> Self {
-        Self(SudoAddressChangeActionErrorKind::Address {
-            source,
-        })
+impl<TSpender, TAmount, TAssetId, TExpiryHeight, TFeeAssetId>
+    AllowanceChangeActionBuilder<TSpender, TAmount, TAssetId, TExpiryHeight, TFeeAssetId>
+{
+    #[must_use = "the builder must be built to construct an allowance change action to be useful"]
+    pub fn spender(
+        self,
+        spender: Address,
+    ) -> AllowanceChangeActionBuilder<Address, TAmount, TAssetId, TExpiryHeight, TFeeAssetId> {
+        AllowanceChangeActionBuilder {
+            spender,
+            amount: self.amount,
+            asset_id: self.asset_id,
+            expiry_height: self.expiry_height,
+            fee_asset_id: self.fee_asset_id,
+        }
     }
-}
 
-#[derive(Debug, thiserror::Error)]
-enum SudoAddressChangeActionErrorKind {
-    #[error("the expected field in the raw source type was not set: `{0}`")]
-    FieldNotSet(&'static str),
-    #[error("`new_address` field did not contain a valid address")]
-    Address { source: AddressError },
-}
+    #[must_use = "the builder must be built to construct an allowance change action to be useful"]
+    pub fn amount(
+        self,
+        amount: u128,
+    ) -> AllowanceChangeActionBuilder<TSpender, u128, TAssetId, TExpiryHeight, TFeeAssetId> {
+        AllowanceChangeActionBuilder {
+            spender: self.spender,
+            amount,
+            asset_id: self.asset_id,
+            expiry_height: self.expiry_height,
+            fee_asset_id: self.fee_asset_id,
+        }
+    }
 
-/// Represents an IBC withdrawal of an asset from a source chain to a destination chain.
-///
-/// The parameters match the arguments to the `sendFungibleTokens` function in the
-/// [ICS 20 spec](https://github.com/cosmos/ibc/blob/fe150abb629de5c1a598e8c7896a7568f2083681/spec/app/ics-020-fungible-token-transfer/README.md#packet-relay).
-///
-/// Note that it does not contain `source_port` as that is implicit (it uses the `transfer`) port.
-///
-/// It also contains a `return_address` field which may or may not be the same as the signer
-/// of the packet. The funds will be returned to the `return_address` in the case of a timeout.
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct Ics20Withdrawal {
-    // a transparent value consisting of an amount and a denom.
-    pub amount: u128,
-    pub denom: Denom,
-    // the address on the destination chain to send the transfer to.
-    pub destination_chain_address: String,
-    // an Astria address to use to return funds from this withdrawal
-    // in the case it fails.
-    pub return_address: Address,
-    // the height (on Astria) at which this transfer expires.
-    pub timeout_height: IbcHeight,
-    // the unix timestamp (in nanoseconds) at which this transfer expires.
-    pub timeout_time: u64,
-    // the source channel used for the withdrawal.
-    pub source_channel: ChannelId,
-    // the asset to use for fee payment.
-    pub fee_asset_id: asset::Id,
-    // a memo to include with the transfer
-    pub memo: String,
-    // the address of the bridge account to transfer from, if this is a withdrawal
-    // from a bridge account and the sender of the tx is the bridge's withdrawer,
-    // which differs from the bridge account's address.
-    //
-    // if unset, and the transaction sender is not a bridge account, the withdrawal
-    // is treated as a user (non-bridge) withdrawal.
-    //
-    // if unset, and the transaction sender is a bridge account, the withdrawal is
-    // treated as a bridge withdrawal (ie. the bridge account's withdrawer address is checked).
-    pub bridge_address: Option<Address>,
-}
+    #[must_use = "the builder must be built to construct an allowance change action to be useful"]
+    pub fn asset_id(
+        self,
+        asset_id: asset::Id,
+    ) -> AllowanceChangeActionBuilder<TSpender, TAmount, asset::Id, TExpiryHeight, TFeeAssetId>
+    {
+        AllowanceChangeActionBuilder {
+            spender: self.spender,
+            amount: self.amount,
+            asset_id,
+            expiry_height: self.expiry_height,
+            fee_asset_id: self.fee_asset_id,
+        }
+    }
 
-impl Ics20Withdrawal {
-    #[must_use]
-    pub fn amount(&self) -> u128 {
-        self.amount
+    #[must_use = "the builder must be built to construct an allowance change action to be useful"]
+    pub fn expiry_height(
+        self,
+        expiry_height: u64,
+    ) -> AllowanceChangeActionBuilder<TSpender, TAmount, TAssetId, u64, TFeeAssetId> {
+        AllowanceChangeActionBuilder {
+            spender: self.spender,
+            amount: self.amount,
+            asset_id: self.asset_id,
+            expiry_height,
+            fee_asset_id: self.fee_asset_id,
+        }
     }
 
-    #[must_use]
-    pub fn denom(&self) -> &Denom {
-        &self.denom
+    #[must_use = "the builder must be built to construct an allowance change action to be useful"]
+    pub fn fee_asset_id(
+        self,
+        fee_asset_id: asset::Id,
+    ) -> AllowanceChangeActionBuilder<TSpender, TAmount, TAssetId, TExpiryHeight, asset::Id> {
+        AllowanceChangeActionBuilder {
+            spender: self.spender,
+            amount: self.amount,
+            asset_id: self.asset_id,
+            expiry_height: self.expiry_height,
+            fee_asset_id,
+        }
     }
+}
 
+impl AllowanceChangeActionBuilder<Address, u128, asset::Id, u64, asset::Id> {
+    /// Constructs an allowance change action from the configured builder.
     #[must_use]
-    pub fn destination_chain_address(&self) -> &str {
-        &self.destination_chain_address
+    pub fn build(self) -> AllowanceChangeAction {
+        let Self {
+            spender,
+            amount,
+            asset_id,
+            expiry_height,
+            fee_asset_id,
+        } = self;
+        AllowanceChangeAction {
+            spender,
+            amount,
+            asset_id,
+            expiry_height,
+            fee_asset_id,
+        }
     }
+}
 
-    #[must_use]
-    pub fn return_address(&self) -> &Address {
-        &self.return_address
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct AllowanceChangeActionError(AllowanceChangeActionErrorKind);
+
+impl AllowanceChangeActionError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(AllowanceChangeActionErrorKind::FieldNotSet(field))
     }
 
-    #[must_use]
-    pub fn timeout_height(&self) -> &IbcHeight {
-        &self.timeout_height
+    fn address(inner: AddressError) -> Self {
+        Self(AllowanceChangeActionErrorKind::Address(inner))
     }
 
-    #[must_use]
-    pub fn timeout_time(&self) -> u64 {
-        self.timeout_time
+    fn asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(AllowanceChangeActionErrorKind::Asset(inner))
     }
 
-    #[must_use]
-    pub fn source_channel(&self) -> &ChannelId {
-        &self.source_channel
+    fn fee_asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(AllowanceChangeActionErrorKind::FeeAsset(inner))
     }
+}
 
-    #[must_use]
-    pub fn fee_asset_id(&self) -> &asset::Id {
-        &self.fee_asset_id
+#[derive(Debug, thiserror::Error)]
+enum AllowanceChangeActionErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`spender` field did not contain a valid address")]
+    Address(#[source] AddressError),
+    #[error("`asset_id` field did not contain a valid asset ID")]
+    Asset(#[source] asset::IncorrectAssetIdLength),
+    #[error("`fee_asset_id` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::IncorrectAssetIdLength),
+}
+
+impl ValidationError for AllowanceChangeActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            AllowanceChangeActionErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(2601, ValidationErrorCategory::MissingField)
+            }
+            AllowanceChangeActionErrorKind::Address(..) => {
+                ValidationErrorCode::new(2602, ValidationErrorCategory::InvalidField)
+            }
+            AllowanceChangeActionErrorKind::Asset(..) => {
+                ValidationErrorCode::new(2603, ValidationErrorCategory::InvalidField)
+            }
+            AllowanceChangeActionErrorKind::FeeAsset(..) => {
+                ValidationErrorCode::new(2604, ValidationErrorCategory::InvalidField)
+            }
+        }
     }
+}
 
-    #[must_use]
-    pub fn memo(&self) -> &str {
-        &self.memo
+/// Transfers funds from an owner to a recipient, spending an allowance the owner granted to the
+/// signer of the transaction.
+///
+/// The fee is paid by the signer, not the owner.
+#[derive(Clone, Debug)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::TransferFromAction", try_from = "raw::TransferFromAction")
+)]
+pub struct TransferFromAction {
+    pub owner: Address,
+    pub to: Address,
+    pub amount: u128,
+    // asset to be transferred.
+    pub asset_id: asset::Id,
+    /// asset to use for fee payment.
+    pub fee_asset_id: asset::Id,
+}
+
+impl From<TransferFromAction> for raw::TransferFromAction {
+    fn from(value: TransferFromAction) -> Self {
+        value.into_raw()
     }
+}
 
-    #[must_use]
-    pub fn to_fungible_token_packet_data(&self) -> FungibleTokenPacketData {
-        FungibleTokenPacketData {
-            amount: self.amount.to_string(),
-            denom: self.denom.to_string(),
-            sender: self.return_address.to_string(),
-            receiver: self.destination_chain_address.clone(),
-            memo: self.memo.clone(),
-        }
+impl TryFrom<raw::TransferFromAction> for TransferFromAction {
+    type Error = TransferFromActionError;
+
+    fn try_from(raw: raw::TransferFromAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl TransferFromAction {
+    /// Returns a builder of a transfer-from action, which can only be built once all required
+    /// fields are set.
+    #[must_use = "the builder must be built to construct a transfer-from action to be useful"]
+    pub fn builder() -> TransferFromActionBuilder {
+        TransferFromActionBuilder::new()
     }
 
     #[must_use]
-    pub fn to_raw(&self) -> raw::Ics20Withdrawal {
-        raw::Ics20Withdrawal {
-            amount: Some(self.amount.into()),
-            denom: self.denom.to_string(),
-            destination_chain_address: self.destination_chain_address.clone(),
-            return_address: Some(self.return_address.into_raw()),
-            timeout_height: Some(self.timeout_height.into_raw()),
-            timeout_time: self.timeout_time,
-            source_channel: self.source_channel.to_string(),
-            fee_asset_id: self.fee_asset_id.get().to_vec(),
-            memo: self.memo.clone(),
-            bridge_address: self.bridge_address.as_ref().map(Address::to_raw),
+    pub fn into_raw(self) -> raw::TransferFromAction {
+        let Self {
+            owner,
+            to,
+            amount,
+            asset_id,
+            fee_asset_id,
+        } = self;
+        raw::TransferFromAction {
+            owner: Some(owner.into_raw()),
+            to: Some(to.into_raw()),
+            amount: Some(amount.into()),
+            asset_id: asset_id.get().to_vec(),
+            fee_asset_id: fee_asset_id.get().to_vec(),
         }
     }
 
     #[must_use]
-    pub fn into_raw(self) -> raw::Ics20Withdrawal {
-        raw::Ics20Withdrawal {
-            amount: Some(self.amount.into()),
-            denom: self.denom.to_string(),
-            destination_chain_address: self.destination_chain_address,
-            return_address: Some(self.return_address.into_raw()),
-            timeout_height: Some(self.timeout_height.into_raw()),
-            timeout_time: self.timeout_time,
-            source_channel: self.source_channel.to_string(),
-            fee_asset_id: self.fee_asset_id.get().to_vec(),
-            memo: self.memo,
-            bridge_address: self.bridge_address.map(Address::into_raw),
+    pub fn to_raw(&self) -> raw::TransferFromAction {
+        let Self {
+            owner,
+            to,
+            amount,
+            asset_id,
+            fee_asset_id,
+        } = self;
+        raw::TransferFromAction {
+            owner: Some(owner.to_raw()),
+            to: Some(to.to_raw()),
+            amount: Some((*amount).into()),
+            asset_id: asset_id.get().to_vec(),
+            fee_asset_id: fee_asset_id.get().to_vec(),
         }
     }
 
-    /// Convert from a raw, unchecked protobuf [`raw::Ics20Withdrawal`].
+    /// Convert from a raw, unchecked protobuf [`raw::TransferFromAction`].
     ///
     /// # Errors
     ///
-    /// - if the `amount` field is missing
-    /// - if the `denom` field is invalid
-    /// - if the `return_address` field is invalid or missing
-    /// - if the `timeout_height` field is missing
-    /// - if the `source_channel` field is invalid
-    pub fn try_from_raw(proto: raw::Ics20Withdrawal) -> Result<Self, Ics20WithdrawalError> {
-        let raw::Ics20Withdrawal {
+    /// - if the `owner` or `to` field is not set
+    /// - if the `owner` or `to` field is invalid
+    /// - if the `asset_id` field is invalid
+    /// - if the `fee_asset_id` field is invalid
+    pub fn try_from_raw(proto: raw::TransferFromAction) -> Result<Self, TransferFromActionError> {
+        let raw::TransferFromAction {
+            owner,
+            to,
             amount,
-            denom,
-            destination_chain_address,
-            return_address,
-            timeout_height,
-            timeout_time,
-            source_channel,
+            asset_id,
             fee_asset_id,
-            memo,
-            bridge_address,
         } = proto;
-        let amount = amount.ok_or(Ics20WithdrawalError::field_not_set("amount"))?;
-        let return_address = Address::try_from_raw(
-            &return_address.ok_or(Ics20WithdrawalError::field_not_set("return_address"))?,
-        )
-        .map_err(Ics20WithdrawalError::return_address)?;
-
-        let timeout_height = timeout_height
-            .ok_or(Ics20WithdrawalError::field_not_set("timeout_height"))?
-            .into();
-        let bridge_address = bridge_address
-            .as_ref()
-            .map(Address::try_from_raw)
-            .transpose()
-            .map_err(Ics20WithdrawalError::invalid_bridge_address)?;
+        let Some(owner) = owner else {
+            return Err(TransferFromActionError::field_not_set("owner"));
+        };
+        let owner = Address::try_from_raw(&owner).map_err(TransferFromActionError::owner)?;
+        let Some(to) = to else {
+            return Err(TransferFromActionError::field_not_set("to"));
+        };
+        let to = Address::try_from_raw(&to).map_err(TransferFromActionError::to)?;
+        let amount = amount.map_or(0, Into::into);
+        let asset_id =
+            asset::Id::try_from_slice(&asset_id).map_err(TransferFromActionError::asset_id)?;
+        let fee_asset_id = asset::Id::try_from_slice(&fee_asset_id)
+            .map_err(TransferFromActionError::fee_asset_id)?;
 
         Ok(Self {
-            amount: amount.into(),
-            denom: denom.parse().map_err(Ics20WithdrawalError::invalid_denom)?,
-            destination_chain_address,
-            return_address,
-            timeout_height,
-            timeout_time,
-            source_channel: source_channel
-                .parse()
-                .map_err(Ics20WithdrawalError::invalid_source_channel)?,
-            fee_asset_id: asset::Id::try_from_slice(&fee_asset_id)
-                .map_err(Ics20WithdrawalError::invalid_fee_asset_id)?,
-            memo,
-            bridge_address,
+            owner,
+            to,
+            amount,
+            asset_id,
+            fee_asset_id,
         })
     }
 }
 
-impl From<raw::IbcHeight> for IbcHeight {
-    fn from(h: raw::IbcHeight) -> Self {
+/// A builder of [`TransferFromAction`], returned by [`TransferFromAction::builder`].
+///
+/// [`TransferFromActionBuilder::build`] is only available once all required fields are set.
+pub struct TransferFromActionBuilder<
+    TOwner = Unset,
+    TTo = Unset,
+    TAmount = Unset,
+    TAssetId = Unset,
+    TFeeAssetId = Unset,
+> {
+    owner: TOwner,
+    to: TTo,
+    amount: TAmount,
+    asset_id: TAssetId,
+    fee_asset_id: TFeeAssetId,
+}
+
+impl TransferFromActionBuilder {
+    fn new() -> Self {
         Self {
-            revision_number: h.revision_number,
-            revision_height: h.revision_height,
+            owner: Unset,
+            to: Unset,
+            amount: Unset,
+            asset_id: Unset,
+            fee_asset_id: Unset,
         }
     }
 }
 
-impl Protobuf for IbcHeight {
-    type Error = ::std::convert::Infallible;
-    type Raw = raw::IbcHeight;
+impl<TOwner, TTo, TAmount, TAssetId, TFeeAssetId>
+    TransferFromActionBuilder<TOwner, TTo, TAmount, TAssetId, TFeeAssetId>
+{
+    #[must_use = "the builder must be built to construct a transfer-from action to be useful"]
+    pub fn owner(
+        self,
+        owner: Address,
+    ) -> TransferFromActionBuilder<Address, TTo, TAmount, TAssetId, TFeeAssetId> {
+        TransferFromActionBuilder {
+            owner,
+            to: self.to,
+            amount: self.amount,
+            asset_id: self.asset_id,
+            fee_asset_id: self.fee_asset_id,
+        }
+    }
 
-    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
-        Ok(Self {
-            revision_number: raw.revision_number,
-            revision_height: raw.revision_height,
-        })
+    #[must_use = "the builder must be built to construct a transfer-from action to be useful"]
+    pub fn to(
+        self,
+        to: Address,
+    ) -> TransferFromActionBuilder<TOwner, Address, TAmount, TAssetId, TFeeAssetId> {
+        TransferFromActionBuilder {
+            owner: self.owner,
+            to,
+            amount: self.amount,
+            asset_id: self.asset_id,
+            fee_asset_id: self.fee_asset_id,
+        }
     }
 
-    fn to_raw(&self) -> Self::Raw {
-        Self::Raw {
-            revision_number: self.revision_number,
-            revision_height: self.revision_height,
+    #[must_use = "the builder must be built to construct a transfer-from action to be useful"]
+    pub fn amount(
+        self,
+        amount: u128,
+    ) -> TransferFromActionBuilder<TOwner, TTo, u128, TAssetId, TFeeAssetId> {
+        TransferFromActionBuilder {
+            owner: self.owner,
+            to: self.to,
+            amount,
+            asset_id: self.asset_id,
+            fee_asset_id: self.fee_asset_id,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a transfer-from action to be useful"]
+    pub fn asset_id(
+        self,
+        asset_id: asset::Id,
+    ) -> TransferFromActionBuilder<TOwner, TTo, TAmount, asset::Id, TFeeAssetId> {
+        TransferFromActionBuilder {
+            owner: self.owner,
+            to: self.to,
+            amount: self.amount,
+            asset_id,
+            fee_asset_id: self.fee_asset_id,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a transfer-from action to be useful"]
+    pub fn fee_asset_id(
+        self,
+        fee_asset_id: asset::Id,
+    ) -> TransferFromActionBuilder<TOwner, TTo, TAmount, TAssetId, asset::Id> {
+        TransferFromActionBuilder {
+            owner: self.owner,
+            to: self.to,
+            amount: self.amount,
+            asset_id: self.asset_id,
+            fee_asset_id,
+        }
+    }
+}
+
+impl TransferFromActionBuilder<Address, Address, u128, asset::Id, asset::Id> {
+    /// Constructs a transfer-from action from the configured builder.
+    #[must_use]
+    pub fn build(self) -> TransferFromAction {
+        let Self {
+            owner,
+            to,
+            amount,
+            asset_id,
+            fee_asset_id,
+        } = self;
+        TransferFromAction {
+            owner,
+            to,
+            amount,
+            asset_id,
+            fee_asset_id,
         }
     }
 }
 
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct Ics20WithdrawalError(Ics20WithdrawalErrorKind);
+pub struct TransferFromActionError(TransferFromActionErrorKind);
 
-impl Ics20WithdrawalError {
-    #[must_use]
+impl TransferFromActionError {
     fn field_not_set(field: &'static str) -> Self {
-        Self(Ics20WithdrawalErrorKind::FieldNotSet {
-            field,
-        })
-    }
-
-    #[must_use]
-    fn return_address(source: AddressError) -> Self {
-        Self(Ics20WithdrawalErrorKind::ReturnAddress {
-            source,
-        })
+        Self(TransferFromActionErrorKind::FieldNotSet(field))
     }
 
-    #[must_use]
-    fn invalid_source_channel(err: IdentifierError) -> Self {
-        Self(Ics20WithdrawalErrorKind::InvalidSourceChannel(err))
+    fn owner(inner: AddressError) -> Self {
+        Self(TransferFromActionErrorKind::Owner(inner))
     }
 
-    #[must_use]
-    fn invalid_fee_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
-        Self(Ics20WithdrawalErrorKind::InvalidFeeAssetId(err))
+    fn to(inner: AddressError) -> Self {
+        Self(TransferFromActionErrorKind::To(inner))
     }
 
-    #[must_use]
-    fn invalid_bridge_address(err: AddressError) -> Self {
-        Self(Ics20WithdrawalErrorKind::InvalidBridgeAddress(err))
+    fn asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(TransferFromActionErrorKind::Asset(inner))
     }
 
-    fn invalid_denom(source: asset::ParseDenomError) -> Self {
-        Self(Ics20WithdrawalErrorKind::InvalidDenom {
-            source,
-        })
+    fn fee_asset_id(inner: asset::IncorrectAssetIdLength) -> Self {
+        Self(TransferFromActionErrorKind::FeeAsset(inner))
     }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum Ics20WithdrawalErrorKind {
-    #[error("expected field `{field}` was not set`")]
-    FieldNotSet { field: &'static str },
-    #[error("`return_address` field was invalid")]
-    ReturnAddress { source: AddressError },
-    #[error("`source_channel` field was invalid")]
-    InvalidSourceChannel(#[source] IdentifierError),
-    #[error("`fee_asset_id` field was invalid")]
-    InvalidFeeAssetId(#[source] asset::IncorrectAssetIdLength),
-    #[error("`bridge_address` field was invalid")]
-    InvalidBridgeAddress(#[source] AddressError),
-    #[error("`denom` field was invalid")]
-    InvalidDenom { source: asset::ParseDenomError },
+enum TransferFromActionErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`owner` field did not contain a valid address")]
+    Owner(#[source] AddressError),
+    #[error("`to` field did not contain a valid address")]
+    To(#[source] AddressError),
+    #[error("`asset_id` field did not contain a valid asset ID")]
+    Asset(#[source] asset::IncorrectAssetIdLength),
+    #[error("`fee_asset_id` field did not contain a valid asset ID")]
+    FeeAsset(#[source] asset::IncorrectAssetIdLength),
+}
+
+impl ValidationError for TransferFromActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            TransferFromActionErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(2701, ValidationErrorCategory::MissingField)
+            }
+            TransferFromActionErrorKind::Owner(..) => {
+                ValidationErrorCode::new(2702, ValidationErrorCategory::InvalidField)
+            }
+            TransferFromActionErrorKind::To(..) => {
+                ValidationErrorCode::new(2703, ValidationErrorCategory::InvalidField)
+            }
+            TransferFromActionErrorKind::Asset(..) => {
+                ValidationErrorCode::new(2704, ValidationErrorCategory::InvalidField)
+            }
+            TransferFromActionErrorKind::FeeAsset(..) => {
+                ValidationErrorCode::new(2705, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
 }
 
+#[derive(Clone, Debug)]
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Clone)]
-pub enum IbcRelayerChangeAction {
-    Addition(Address),
-    Removal(Address),
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::SudoAddressChangeAction",
+        try_from = "raw::SudoAddressChangeAction"
+    )
+)]
+pub struct SudoAddressChangeAction {
+    pub new_address: Address,
 }
 
-impl IbcRelayerChangeAction {
+impl From<SudoAddressChangeAction> for raw::SudoAddressChangeAction {
+    fn from(value: SudoAddressChangeAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::SudoAddressChangeAction> for SudoAddressChangeAction {
+    type Error = SudoAddressChangeActionError;
+
+    fn try_from(raw: raw::SudoAddressChangeAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl SudoAddressChangeAction {
+    /// Returns a builder of a sudo address change action, which can only be built once all required
+    /// fields are set.
+    #[must_use = "the builder must be built to construct a sudo address change action to be useful"]
+    pub fn builder() -> SudoAddressChangeActionBuilder {
+        SudoAddressChangeActionBuilder::new()
+    }
+
     #[must_use]
-    pub fn into_raw(self) -> raw::IbcRelayerChangeAction {
-        match self {
-            IbcRelayerChangeAction::Addition(address) => raw::IbcRelayerChangeAction {
-                value: Some(raw::ibc_relayer_change_action::Value::Addition(
-                    address.to_raw(),
-                )),
-            },
-            IbcRelayerChangeAction::Removal(address) => raw::IbcRelayerChangeAction {
-                value: Some(raw::ibc_relayer_change_action::Value::Removal(
-                    address.to_raw(),
-                )),
-            },
+    pub fn into_raw(self) -> raw::SudoAddressChangeAction {
+        let Self {
+            new_address,
+        } = self;
+        raw::SudoAddressChangeAction {
+            new_address: Some(new_address.into_raw()),
         }
     }
 
     #[must_use]
-    pub fn to_raw(&self) -> raw::IbcRelayerChangeAction {
-        match self {
-            IbcRelayerChangeAction::Addition(address) => raw::IbcRelayerChangeAction {
-                value: Some(raw::ibc_relayer_change_action::Value::Addition(
-                    address.to_raw(),
-                )),
-            },
-            IbcRelayerChangeAction::Removal(address) => raw::IbcRelayerChangeAction {
-                value: Some(raw::ibc_relayer_change_action::Value::Removal(
-                    address.to_raw(),
-                )),
-            },
+    pub fn to_raw(&self) -> raw::SudoAddressChangeAction {
+        let Self {
+            new_address,
+        } = self;
+        raw::SudoAddressChangeAction {
+            new_address: Some(new_address.to_raw()),
         }
     }
 
-    /// Convert from a raw, unchecked protobuf [`raw::IbcRelayerChangeAction`].
+    /// Convert from a raw, unchecked protobuf [`raw::SudoAddressChangeAction`].
     ///
     /// # Errors
     ///
-    /// - if the `address` field is invalid
+    /// Returns an error if the raw action's `new_address` did not have the expected
+    /// length.
     pub fn try_from_raw(
-        raw: &raw::IbcRelayerChangeAction,
-    ) -> Result<Self, IbcRelayerChangeActionError> {
-        match raw {
-            raw::IbcRelayerChangeAction {
-                value: Some(raw::ibc_relayer_change_action::Value::Addition(address)),
-            } => {
-                let address =
-                    Address::try_from_raw(address).map_err(IbcRelayerChangeActionError::address)?;
-                Ok(IbcRelayerChangeAction::Addition(address))
-            }
-            raw::IbcRelayerChangeAction {
-                value: Some(raw::ibc_relayer_change_action::Value::Removal(address)),
-            } => {
-                let address =
-                    Address::try_from_raw(address).map_err(IbcRelayerChangeActionError::address)?;
-                Ok(IbcRelayerChangeAction::Removal(address))
-            }
-            _ => Err(IbcRelayerChangeActionError::missing_address()),
+        proto: raw::SudoAddressChangeAction,
+    ) -> Result<Self, SudoAddressChangeActionError> {
+        let raw::SudoAddressChangeAction {
+            new_address,
+        } = proto;
+        let Some(new_address) = new_address else {
+            return Err(SudoAddressChangeActionError::field_not_set("new_address"));
+        };
+        let new_address =
+            Address::try_from_raw(&new_address).map_err(SudoAddressChangeActionError::address)?;
+        Ok(Self {
+            new_address,
+        })
+    }
+}
+
+/// A builder of [`SudoAddressChangeAction`], returned by [`SudoAddressChangeAction::builder`].
+///
+/// [`SudoAddressChangeActionBuilder::build`] is only available once all required fields are set.
+pub struct SudoAddressChangeActionBuilder<TNewAddress = Unset> {
+    new_address: TNewAddress,
+}
+
+impl SudoAddressChangeActionBuilder {
+    fn new() -> Self {
+        Self {
+            new_address: Unset,
+        }
+    }
+}
+
+impl<TNewAddress> SudoAddressChangeActionBuilder<TNewAddress> {
+    #[must_use = "the builder must be built to construct a sudo address change action to be useful"]
+    pub fn new_address(self, new_address: Address) -> SudoAddressChangeActionBuilder<Address> {
+        SudoAddressChangeActionBuilder {
+            new_address,
+        }
+    }
+}
+
+impl SudoAddressChangeActionBuilder<Address> {
+    /// Constructs a sudo address change action from the configured builder.
+    #[must_use]
+    pub fn build(self) -> SudoAddressChangeAction {
+        let Self {
+            new_address,
+        } = self;
+        SudoAddressChangeAction {
+            new_address,
         }
     }
 }
 
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct IbcRelayerChangeActionError(IbcRelayerChangeActionErrorKind);
+pub struct SudoAddressChangeActionError(SudoAddressChangeActionErrorKind);
+
+impl SudoAddressChangeActionError {
+    fn field_not_set(field: &'static str) -> Self {
+        Self(SudoAddressChangeActionErrorKind::FieldNotSet(field))
+    }
 
-impl IbcRelayerChangeActionError {
-    #[must_use]
     fn address(source: AddressError) -> Self {
-        Self(IbcRelayerChangeActionErrorKind::Address {
+        Self(SudoAddressChangeActionErrorKind::Address {
             source,
         })
     }
-
-    #[must_use]
-    fn missing_address() -> Self {
-        Self(IbcRelayerChangeActionErrorKind::MissingAddress)
-    }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum IbcRelayerChangeActionErrorKind {
-    #[error("the `address` was invalid")]
+enum SudoAddressChangeActionErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("`new_address` field did not contain a valid address")]
     Address { source: AddressError },
-    #[error("the `address` was not set")]
-    MissingAddress,
-}
-
-#[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Clone)]
-pub enum FeeAssetChangeAction {
-    Addition(asset::Id),
-    Removal(asset::Id),
 }
 
-impl FeeAssetChangeAction {
-    #[must_use]
-    pub fn into_raw(self) -> raw::FeeAssetChangeAction {
-        match self {
-            FeeAssetChangeAction::Addition(asset_id) => raw::FeeAssetChangeAction {
-                value: Some(raw::fee_asset_change_action::Value::Addition(
-                    asset_id.get().to_vec(),
-                )),
-            },
-            FeeAssetChangeAction::Removal(asset_id) => raw::FeeAssetChangeAction {
-                value: Some(raw::fee_asset_change_action::Value::Removal(
-                    asset_id.get().to_vec(),
-                )),
-            },
+impl ValidationError for SudoAddressChangeActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            SudoAddressChangeActionErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(1501, ValidationErrorCategory::MissingField)
+            }
+            SudoAddressChangeActionErrorKind::Address {
+                ..
+            } => ValidationErrorCode::new(1502, ValidationErrorCategory::InvalidField),
         }
     }
+}
 
-    #[must_use]
-    pub fn to_raw(&self) -> raw::FeeAssetChangeAction {
-        match self {
+/// Represents an IBC withdrawal of an asset from a source chain to a destination chain.
+///
+/// The parameters match the arguments to the `sendFungibleTokens` function in the
+/// [ICS 20 spec](https://github.com/cosmos/ibc/blob/fe150abb629de5c1a598e8c7896a7568f2083681/spec/app/ics-020-fungible-token-transfer/README.md#packet-relay).
+///
+/// Note that it does not contain `source_port` as that is implicit (it uses the `transfer`) port.
+///
+/// It also contains a `return_address` field which may or may not be the same as the signer
+/// of the packet. The funds will be returned to the `return_address` in the case of a timeout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::Ics20Withdrawal", try_from = "raw::Ics20Withdrawal")
+)]
+pub struct Ics20Withdrawal {
+    // a transparent value consisting of an amount and a denom.
+    pub amount: u128,
+    pub denom: Denom,
+    // the address on the destination chain to send the transfer to.
+    pub destination_chain_address: String,
+    // an Astria address to use to return funds from this withdrawal
+    // in the case it fails.
+    pub return_address: Address,
+    // the height (on Astria) at which this transfer expires.
+    pub timeout_height: IbcHeight,
+    // the unix timestamp (in nanoseconds) at which this transfer expires.
+    pub timeout_time: u64,
+    // the source channel used for the withdrawal.
+    pub source_channel: ChannelId,
+    // the asset to use for fee payment.
+    pub fee_asset_id: asset::Id,
+    // a memo to include with the transfer
+    pub memo: String,
+    // the address of the bridge account to transfer from, if this is a withdrawal
+    // from a bridge account and the sender of the tx is the bridge's withdrawer,
+    // which differs from the bridge account's address.
+    //
+    // if unset, and the transaction sender is not a bridge account, the withdrawal
+    // is treated as a user (non-bridge) withdrawal.
+    //
+    // if unset, and the transaction sender is a bridge account, the withdrawal is
+    // treated as a bridge withdrawal (ie. the bridge account's withdrawer address is checked).
+    pub bridge_address: Option<Address>,
+}
+
+impl From<Ics20Withdrawal> for raw::Ics20Withdrawal {
+    fn from(value: Ics20Withdrawal) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::Ics20Withdrawal> for Ics20Withdrawal {
+    type Error = Ics20WithdrawalError;
+
+    fn try_from(raw: raw::Ics20Withdrawal) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl Ics20Withdrawal {
+    /// Returns a builder of an ics20 withdrawal, which can only be built once all required fields
+    /// are set.
+    #[must_use = "the builder must be built to construct an ics20 withdrawal to be useful"]
+    pub fn builder() -> Ics20WithdrawalBuilder {
+        Ics20WithdrawalBuilder::new()
+    }
+
+    #[must_use]
+    pub fn amount(&self) -> u128 {
+        self.amount
+    }
+
+    #[must_use]
+    pub fn denom(&self) -> &Denom {
+        &self.denom
+    }
+
+    #[must_use]
+    pub fn destination_chain_address(&self) -> &str {
+        &self.destination_chain_address
+    }
+
+    #[must_use]
+    pub fn return_address(&self) -> &Address {
+        &self.return_address
+    }
+
+    #[must_use]
+    pub fn timeout_height(&self) -> &IbcHeight {
+        &self.timeout_height
+    }
+
+    #[must_use]
+    pub fn timeout_time(&self) -> u64 {
+        self.timeout_time
+    }
+
+    #[must_use]
+    pub fn source_channel(&self) -> &ChannelId {
+        &self.source_channel
+    }
+
+    #[must_use]
+    pub fn fee_asset_id(&self) -> &asset::Id {
+        &self.fee_asset_id
+    }
+
+    #[must_use]
+    pub fn memo(&self) -> &str {
+        &self.memo
+    }
+
+    #[must_use]
+    pub fn to_fungible_token_packet_data(&self) -> FungibleTokenPacketData {
+        FungibleTokenPacketData {
+            amount: self.amount.to_string(),
+            denom: self.denom.to_string(),
+            sender: self.return_address.to_string(),
+            receiver: self.destination_chain_address.clone(),
+            memo: self.memo.clone(),
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::Ics20Withdrawal {
+        raw::Ics20Withdrawal {
+            amount: Some(self.amount.into()),
+            denom: self.denom.to_string(),
+            destination_chain_address: self.destination_chain_address.clone(),
+            return_address: Some(self.return_address.into_raw()),
+            timeout_height: Some(self.timeout_height.into_raw()),
+            timeout_time: self.timeout_time,
+            source_channel: self.source_channel.to_string(),
+            fee_asset_id: self.fee_asset_id.get().to_vec(),
+            memo: self.memo.clone(),
+            bridge_address: self.bridge_address.as_ref().map(Address::to_raw),
+        }
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::Ics20Withdrawal {
+        raw::Ics20Withdrawal {
+            amount: Some(self.amount.into()),
+            denom: self.denom.to_string(),
+            destination_chain_address: self.destination_chain_address,
+            return_address: Some(self.return_address.into_raw()),
+            timeout_height: Some(self.timeout_height.into_raw()),
+            timeout_time: self.timeout_time,
+            source_channel: self.source_channel.to_string(),
+            fee_asset_id: self.fee_asset_id.get().to_vec(),
+            memo: self.memo,
+            bridge_address: self.bridge_address.map(Address::into_raw),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::Ics20Withdrawal`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `amount` field is missing
+    /// - if the `denom` field is invalid
+    /// - if the `return_address` field is invalid or missing
+    /// - if the `timeout_height` field is missing
+    /// - if the `source_channel` field is invalid
+    pub fn try_from_raw(proto: raw::Ics20Withdrawal) -> Result<Self, Ics20WithdrawalError> {
+        let raw::Ics20Withdrawal {
+            amount,
+            denom,
+            destination_chain_address,
+            return_address,
+            timeout_height,
+            timeout_time,
+            source_channel,
+            fee_asset_id,
+            memo,
+            bridge_address,
+        } = proto;
+        let amount = amount.ok_or(Ics20WithdrawalError::field_not_set("amount"))?;
+        let return_address = Address::try_from_raw(
+            &return_address.ok_or(Ics20WithdrawalError::field_not_set("return_address"))?,
+        )
+        .map_err(Ics20WithdrawalError::return_address)?;
+
+        let timeout_height = timeout_height
+            .ok_or(Ics20WithdrawalError::field_not_set("timeout_height"))?
+            .into();
+        let bridge_address = bridge_address
+            .as_ref()
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(Ics20WithdrawalError::invalid_bridge_address)?;
+
+        Ok(Self {
+            amount: amount.into(),
+            denom: denom.parse().map_err(Ics20WithdrawalError::invalid_denom)?,
+            destination_chain_address,
+            return_address,
+            timeout_height,
+            timeout_time,
+            source_channel: source_channel
+                .parse()
+                .map_err(Ics20WithdrawalError::invalid_source_channel)?,
+            fee_asset_id: asset::Id::try_from_slice(&fee_asset_id)
+                .map_err(Ics20WithdrawalError::invalid_fee_asset_id)?,
+            memo,
+            bridge_address,
+        })
+    }
+}
+
+/// A builder of [`Ics20Withdrawal`], returned by [`Ics20Withdrawal::builder`].
+///
+/// [`Ics20WithdrawalBuilder::build`] is only available once all required fields are set.
+pub struct Ics20WithdrawalBuilder<
+    TAmount = Unset,
+    TDenom = Unset,
+    TDestinationChainAddress = Unset,
+    TReturnAddress = Unset,
+    TTimeoutHeight = Unset,
+    TTimeoutTime = Unset,
+    TSourceChannel = Unset,
+    TFeeAssetId = Unset,
+> {
+    amount: TAmount,
+    denom: TDenom,
+    destination_chain_address: TDestinationChainAddress,
+    return_address: TReturnAddress,
+    timeout_height: TTimeoutHeight,
+    timeout_time: TTimeoutTime,
+    source_channel: TSourceChannel,
+    fee_asset_id: TFeeAssetId,
+    memo: String,
+    bridge_address: Option<Address>,
+}
+
+impl Ics20WithdrawalBuilder {
+    fn new() -> Self {
+        Self {
+            amount: Unset,
+            denom: Unset,
+            destination_chain_address: Unset,
+            return_address: Unset,
+            timeout_height: Unset,
+            timeout_time: Unset,
+            source_channel: Unset,
+            fee_asset_id: Unset,
+            memo: String::new(),
+            bridge_address: None,
+        }
+    }
+}
+
+impl<
+        TAmount,
+        TDenom,
+        TDestinationChainAddress,
+        TReturnAddress,
+        TTimeoutHeight,
+        TTimeoutTime,
+        TSourceChannel,
+        TFeeAssetId,
+    >
+    Ics20WithdrawalBuilder<
+        TAmount,
+        TDenom,
+        TDestinationChainAddress,
+        TReturnAddress,
+        TTimeoutHeight,
+        TTimeoutTime,
+        TSourceChannel,
+        TFeeAssetId,
+    >
+{
+    #[must_use = "the builder must be built to construct an ics20 withdrawal to be useful"]
+    pub fn amount(
+        self,
+        amount: u128,
+    ) -> Ics20WithdrawalBuilder<
+        u128,
+        TDenom,
+        TDestinationChainAddress,
+        TReturnAddress,
+        TTimeoutHeight,
+        TTimeoutTime,
+        TSourceChannel,
+        TFeeAssetId,
+    > {
+        Ics20WithdrawalBuilder {
+            amount,
+            denom: self.denom,
+            destination_chain_address: self.destination_chain_address,
+            return_address: self.return_address,
+            timeout_height: self.timeout_height,
+            timeout_time: self.timeout_time,
+            source_channel: self.source_channel,
+            fee_asset_id: self.fee_asset_id,
+            memo: self.memo,
+            bridge_address: self.bridge_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct an ics20 withdrawal to be useful"]
+    pub fn denom(
+        self,
+        denom: Denom,
+    ) -> Ics20WithdrawalBuilder<
+        TAmount,
+        Denom,
+        TDestinationChainAddress,
+        TReturnAddress,
+        TTimeoutHeight,
+        TTimeoutTime,
+        TSourceChannel,
+        TFeeAssetId,
+    > {
+        Ics20WithdrawalBuilder {
+            amount: self.amount,
+            denom,
+            destination_chain_address: self.destination_chain_address,
+            return_address: self.return_address,
+            timeout_height: self.timeout_height,
+            timeout_time: self.timeout_time,
+            source_channel: self.source_channel,
+            fee_asset_id: self.fee_asset_id,
+            memo: self.memo,
+            bridge_address: self.bridge_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct an ics20 withdrawal to be useful"]
+    pub fn destination_chain_address<T: Into<String>>(
+        self,
+        destination_chain_address: T,
+    ) -> Ics20WithdrawalBuilder<
+        TAmount,
+        TDenom,
+        String,
+        TReturnAddress,
+        TTimeoutHeight,
+        TTimeoutTime,
+        TSourceChannel,
+        TFeeAssetId,
+    > {
+        Ics20WithdrawalBuilder {
+            amount: self.amount,
+            denom: self.denom,
+            destination_chain_address: destination_chain_address.into(),
+            return_address: self.return_address,
+            timeout_height: self.timeout_height,
+            timeout_time: self.timeout_time,
+            source_channel: self.source_channel,
+            fee_asset_id: self.fee_asset_id,
+            memo: self.memo,
+            bridge_address: self.bridge_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct an ics20 withdrawal to be useful"]
+    pub fn return_address(
+        self,
+        return_address: Address,
+    ) -> Ics20WithdrawalBuilder<
+        TAmount,
+        TDenom,
+        TDestinationChainAddress,
+        Address,
+        TTimeoutHeight,
+        TTimeoutTime,
+        TSourceChannel,
+        TFeeAssetId,
+    > {
+        Ics20WithdrawalBuilder {
+            amount: self.amount,
+            denom: self.denom,
+            destination_chain_address: self.destination_chain_address,
+            return_address,
+            timeout_height: self.timeout_height,
+            timeout_time: self.timeout_time,
+            source_channel: self.source_channel,
+            fee_asset_id: self.fee_asset_id,
+            memo: self.memo,
+            bridge_address: self.bridge_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct an ics20 withdrawal to be useful"]
+    pub fn timeout_height(
+        self,
+        timeout_height: IbcHeight,
+    ) -> Ics20WithdrawalBuilder<
+        TAmount,
+        TDenom,
+        TDestinationChainAddress,
+        TReturnAddress,
+        IbcHeight,
+        TTimeoutTime,
+        TSourceChannel,
+        TFeeAssetId,
+    > {
+        Ics20WithdrawalBuilder {
+            amount: self.amount,
+            denom: self.denom,
+            destination_chain_address: self.destination_chain_address,
+            return_address: self.return_address,
+            timeout_height,
+            timeout_time: self.timeout_time,
+            source_channel: self.source_channel,
+            fee_asset_id: self.fee_asset_id,
+            memo: self.memo,
+            bridge_address: self.bridge_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct an ics20 withdrawal to be useful"]
+    pub fn timeout_time(
+        self,
+        timeout_time: u64,
+    ) -> Ics20WithdrawalBuilder<
+        TAmount,
+        TDenom,
+        TDestinationChainAddress,
+        TReturnAddress,
+        TTimeoutHeight,
+        u64,
+        TSourceChannel,
+        TFeeAssetId,
+    > {
+        Ics20WithdrawalBuilder {
+            amount: self.amount,
+            denom: self.denom,
+            destination_chain_address: self.destination_chain_address,
+            return_address: self.return_address,
+            timeout_height: self.timeout_height,
+            timeout_time,
+            source_channel: self.source_channel,
+            fee_asset_id: self.fee_asset_id,
+            memo: self.memo,
+            bridge_address: self.bridge_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct an ics20 withdrawal to be useful"]
+    pub fn source_channel(
+        self,
+        source_channel: ChannelId,
+    ) -> Ics20WithdrawalBuilder<
+        TAmount,
+        TDenom,
+        TDestinationChainAddress,
+        TReturnAddress,
+        TTimeoutHeight,
+        TTimeoutTime,
+        ChannelId,
+        TFeeAssetId,
+    > {
+        Ics20WithdrawalBuilder {
+            amount: self.amount,
+            denom: self.denom,
+            destination_chain_address: self.destination_chain_address,
+            return_address: self.return_address,
+            timeout_height: self.timeout_height,
+            timeout_time: self.timeout_time,
+            source_channel,
+            fee_asset_id: self.fee_asset_id,
+            memo: self.memo,
+            bridge_address: self.bridge_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct an ics20 withdrawal to be useful"]
+    pub fn fee_asset_id(
+        self,
+        fee_asset_id: asset::Id,
+    ) -> Ics20WithdrawalBuilder<
+        TAmount,
+        TDenom,
+        TDestinationChainAddress,
+        TReturnAddress,
+        TTimeoutHeight,
+        TTimeoutTime,
+        TSourceChannel,
+        asset::Id,
+    > {
+        Ics20WithdrawalBuilder {
+            amount: self.amount,
+            denom: self.denom,
+            destination_chain_address: self.destination_chain_address,
+            return_address: self.return_address,
+            timeout_height: self.timeout_height,
+            timeout_time: self.timeout_time,
+            source_channel: self.source_channel,
+            fee_asset_id,
+            memo: self.memo,
+            bridge_address: self.bridge_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct an ics20 withdrawal to be useful"]
+    pub fn memo<T: Into<String>>(self, memo: T) -> Self {
+        Self {
+            memo: memo.into(),
+            ..self
+        }
+    }
+
+    #[must_use = "the builder must be built to construct an ics20 withdrawal to be useful"]
+    pub fn bridge_address(self, bridge_address: Address) -> Self {
+        Self {
+            bridge_address: Some(bridge_address),
+            ..self
+        }
+    }
+}
+
+impl Ics20WithdrawalBuilder<u128, Denom, String, Address, IbcHeight, u64, ChannelId, asset::Id> {
+    /// Constructs an ics20 withdrawal from the configured builder.
+    #[must_use]
+    pub fn build(self) -> Ics20Withdrawal {
+        let Self {
+            amount,
+            denom,
+            destination_chain_address,
+            return_address,
+            timeout_height,
+            timeout_time,
+            source_channel,
+            fee_asset_id,
+            memo,
+            bridge_address,
+        } = self;
+        Ics20Withdrawal {
+            amount,
+            denom,
+            destination_chain_address,
+            return_address,
+            timeout_height,
+            timeout_time,
+            source_channel,
+            fee_asset_id,
+            memo,
+            bridge_address,
+        }
+    }
+}
+
+impl From<raw::IbcHeight> for IbcHeight {
+    fn from(h: raw::IbcHeight) -> Self {
+        Self {
+            revision_number: h.revision_number,
+            revision_height: h.revision_height,
+        }
+    }
+}
+
+impl Protobuf for IbcHeight {
+    type Error = ::std::convert::Infallible;
+    type Raw = raw::IbcHeight;
+
+    fn try_from_raw_ref(raw: &Self::Raw) -> Result<Self, Self::Error> {
+        Ok(Self {
+            revision_number: raw.revision_number,
+            revision_height: raw.revision_height,
+        })
+    }
+
+    fn to_raw(&self) -> Self::Raw {
+        Self::Raw {
+            revision_number: self.revision_number,
+            revision_height: self.revision_height,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct Ics20WithdrawalError(Ics20WithdrawalErrorKind);
+
+impl Ics20WithdrawalError {
+    #[must_use]
+    fn field_not_set(field: &'static str) -> Self {
+        Self(Ics20WithdrawalErrorKind::FieldNotSet {
+            field,
+        })
+    }
+
+    #[must_use]
+    fn return_address(source: AddressError) -> Self {
+        Self(Ics20WithdrawalErrorKind::ReturnAddress {
+            source,
+        })
+    }
+
+    #[must_use]
+    fn invalid_source_channel(err: IdentifierError) -> Self {
+        Self(Ics20WithdrawalErrorKind::InvalidSourceChannel(err))
+    }
+
+    #[must_use]
+    fn invalid_fee_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
+        Self(Ics20WithdrawalErrorKind::InvalidFeeAssetId(err))
+    }
+
+    #[must_use]
+    fn invalid_bridge_address(err: AddressError) -> Self {
+        Self(Ics20WithdrawalErrorKind::InvalidBridgeAddress(err))
+    }
+
+    fn invalid_denom(source: asset::ParseDenomError) -> Self {
+        Self(Ics20WithdrawalErrorKind::InvalidDenom {
+            source,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum Ics20WithdrawalErrorKind {
+    #[error("expected field `{field}` was not set`")]
+    FieldNotSet { field: &'static str },
+    #[error("`return_address` field was invalid")]
+    ReturnAddress { source: AddressError },
+    #[error("`source_channel` field was invalid")]
+    InvalidSourceChannel(#[source] IdentifierError),
+    #[error("`fee_asset_id` field was invalid")]
+    InvalidFeeAssetId(#[source] asset::IncorrectAssetIdLength),
+    #[error("`bridge_address` field was invalid")]
+    InvalidBridgeAddress(#[source] AddressError),
+    #[error("`denom` field was invalid")]
+    InvalidDenom { source: asset::ParseDenomError },
+}
+
+impl ValidationError for Ics20WithdrawalError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            Ics20WithdrawalErrorKind::FieldNotSet {
+                ..
+            } => ValidationErrorCode::new(1601, ValidationErrorCategory::MissingField),
+            Ics20WithdrawalErrorKind::ReturnAddress {
+                ..
+            } => ValidationErrorCode::new(1602, ValidationErrorCategory::InvalidField),
+            Ics20WithdrawalErrorKind::InvalidSourceChannel(..) => {
+                ValidationErrorCode::new(1603, ValidationErrorCategory::InvalidField)
+            }
+            Ics20WithdrawalErrorKind::InvalidFeeAssetId(..) => {
+                ValidationErrorCode::new(1604, ValidationErrorCategory::InvalidField)
+            }
+            Ics20WithdrawalErrorKind::InvalidBridgeAddress(..) => {
+                ValidationErrorCode::new(1605, ValidationErrorCategory::InvalidField)
+            }
+            Ics20WithdrawalErrorKind::InvalidDenom {
+                ..
+            } => ValidationErrorCode::new(1606, ValidationErrorCategory::InvalidField),
+        }
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::IbcRelayerChangeAction",
+        try_from = "raw::IbcRelayerChangeAction"
+    )
+)]
+pub enum IbcRelayerChangeAction {
+    Addition(Address),
+    Removal(Address),
+}
+
+impl From<IbcRelayerChangeAction> for raw::IbcRelayerChangeAction {
+    fn from(value: IbcRelayerChangeAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::IbcRelayerChangeAction> for IbcRelayerChangeAction {
+    type Error = IbcRelayerChangeActionError;
+
+    fn try_from(raw: raw::IbcRelayerChangeAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl IbcRelayerChangeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::IbcRelayerChangeAction {
+        match self {
+            IbcRelayerChangeAction::Addition(address) => raw::IbcRelayerChangeAction {
+                value: Some(raw::ibc_relayer_change_action::Value::Addition(
+                    address.to_raw(),
+                )),
+            },
+            IbcRelayerChangeAction::Removal(address) => raw::IbcRelayerChangeAction {
+                value: Some(raw::ibc_relayer_change_action::Value::Removal(
+                    address.to_raw(),
+                )),
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::IbcRelayerChangeAction {
+        match self {
+            IbcRelayerChangeAction::Addition(address) => raw::IbcRelayerChangeAction {
+                value: Some(raw::ibc_relayer_change_action::Value::Addition(
+                    address.to_raw(),
+                )),
+            },
+            IbcRelayerChangeAction::Removal(address) => raw::IbcRelayerChangeAction {
+                value: Some(raw::ibc_relayer_change_action::Value::Removal(
+                    address.to_raw(),
+                )),
+            },
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::IbcRelayerChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `address` field is invalid
+    pub fn try_from_raw(
+        raw: &raw::IbcRelayerChangeAction,
+    ) -> Result<Self, IbcRelayerChangeActionError> {
+        match raw {
+            raw::IbcRelayerChangeAction {
+                value: Some(raw::ibc_relayer_change_action::Value::Addition(address)),
+            } => {
+                let address =
+                    Address::try_from_raw(address).map_err(IbcRelayerChangeActionError::address)?;
+                Ok(IbcRelayerChangeAction::Addition(address))
+            }
+            raw::IbcRelayerChangeAction {
+                value: Some(raw::ibc_relayer_change_action::Value::Removal(address)),
+            } => {
+                let address =
+                    Address::try_from_raw(address).map_err(IbcRelayerChangeActionError::address)?;
+                Ok(IbcRelayerChangeAction::Removal(address))
+            }
+            _ => Err(IbcRelayerChangeActionError::missing_address()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct IbcRelayerChangeActionError(IbcRelayerChangeActionErrorKind);
+
+impl IbcRelayerChangeActionError {
+    #[must_use]
+    fn address(source: AddressError) -> Self {
+        Self(IbcRelayerChangeActionErrorKind::Address {
+            source,
+        })
+    }
+
+    #[must_use]
+    fn missing_address() -> Self {
+        Self(IbcRelayerChangeActionErrorKind::MissingAddress)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum IbcRelayerChangeActionErrorKind {
+    #[error("the `address` was invalid")]
+    Address { source: AddressError },
+    #[error("the `address` was not set")]
+    MissingAddress,
+}
+
+impl ValidationError for IbcRelayerChangeActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            IbcRelayerChangeActionErrorKind::Address {
+                ..
+            } => ValidationErrorCode::new(1701, ValidationErrorCategory::InvalidField),
+            IbcRelayerChangeActionErrorKind::MissingAddress => {
+                ValidationErrorCode::new(1702, ValidationErrorCategory::MissingField)
+            }
+        }
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::FeeAssetChangeAction",
+        try_from = "raw::FeeAssetChangeAction"
+    )
+)]
+pub enum FeeAssetChangeAction {
+    Addition(asset::Id),
+    Removal(asset::Id),
+}
+
+impl From<FeeAssetChangeAction> for raw::FeeAssetChangeAction {
+    fn from(value: FeeAssetChangeAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::FeeAssetChangeAction> for FeeAssetChangeAction {
+    type Error = FeeAssetChangeActionError;
+
+    fn try_from(raw: raw::FeeAssetChangeAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl FeeAssetChangeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::FeeAssetChangeAction {
+        match self {
+            FeeAssetChangeAction::Addition(asset_id) => raw::FeeAssetChangeAction {
+                value: Some(raw::fee_asset_change_action::Value::Addition(
+                    asset_id.get().to_vec(),
+                )),
+            },
+            FeeAssetChangeAction::Removal(asset_id) => raw::FeeAssetChangeAction {
+                value: Some(raw::fee_asset_change_action::Value::Removal(
+                    asset_id.get().to_vec(),
+                )),
+            },
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::FeeAssetChangeAction {
+        match self {
             FeeAssetChangeAction::Addition(asset_id) => raw::FeeAssetChangeAction {
                 value: Some(raw::fee_asset_change_action::Value::Addition(
                     asset_id.get().to_vec(),
@@ -1014,499 +2692,1633 @@ impl FeeAssetChangeAction {
         }
     }
 
-    /// Convert from a raw, unchecked protobuf [`raw::FeeAssetChangeAction`].
+    /// Convert from a raw, unchecked protobuf [`raw::FeeAssetChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `asset_id` field is invalid
+    pub fn try_from_raw(
+        raw: &raw::FeeAssetChangeAction,
+    ) -> Result<Self, FeeAssetChangeActionError> {
+        match raw {
+            raw::FeeAssetChangeAction {
+                value: Some(raw::fee_asset_change_action::Value::Addition(asset_id)),
+            } => {
+                let asset_id = asset::Id::try_from_slice(asset_id)
+                    .map_err(FeeAssetChangeActionError::invalid_asset_id)?;
+                Ok(FeeAssetChangeAction::Addition(asset_id))
+            }
+            raw::FeeAssetChangeAction {
+                value: Some(raw::fee_asset_change_action::Value::Removal(asset_id)),
+            } => {
+                let asset_id = asset::Id::try_from_slice(asset_id)
+                    .map_err(FeeAssetChangeActionError::invalid_asset_id)?;
+                Ok(FeeAssetChangeAction::Removal(asset_id))
+            }
+            _ => Err(FeeAssetChangeActionError::missing_asset_id()),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct FeeAssetChangeActionError(FeeAssetChangeActionErrorKind);
+
+impl FeeAssetChangeActionError {
+    #[must_use]
+    fn invalid_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
+        Self(FeeAssetChangeActionErrorKind::InvalidAssetId(err))
+    }
+
+    #[must_use]
+    fn missing_asset_id() -> Self {
+        Self(FeeAssetChangeActionErrorKind::MissingAssetId)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum FeeAssetChangeActionErrorKind {
+    #[error("the asset_id was invalid")]
+    InvalidAssetId(#[source] asset::IncorrectAssetIdLength),
+    #[error("the asset_id was missing")]
+    MissingAssetId,
+}
+
+impl ValidationError for FeeAssetChangeActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            FeeAssetChangeActionErrorKind::InvalidAssetId(..) => {
+                ValidationErrorCode::new(1801, ValidationErrorCategory::InvalidField)
+            }
+            FeeAssetChangeActionErrorKind::MissingAssetId => {
+                ValidationErrorCode::new(1802, ValidationErrorCategory::MissingField)
+            }
+        }
+    }
+}
+
+/// Adds a prefix to or removes it from the set of address prefixes accepted by the sequencer in
+/// addition to its base prefix.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::AddressPrefixChangeAction",
+        try_from = "raw::AddressPrefixChangeAction"
+    )
+)]
+pub enum AddressPrefixChangeAction {
+    Addition(String),
+    Removal(String),
+}
+
+impl From<AddressPrefixChangeAction> for raw::AddressPrefixChangeAction {
+    fn from(value: AddressPrefixChangeAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::AddressPrefixChangeAction> for AddressPrefixChangeAction {
+    type Error = AddressPrefixChangeActionError;
+
+    fn try_from(raw: raw::AddressPrefixChangeAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(&raw)
+    }
+}
+
+impl AddressPrefixChangeAction {
+    /// Returns the prefix being added or removed.
+    #[must_use]
+    pub fn prefix(&self) -> &str {
+        match self {
+            AddressPrefixChangeAction::Addition(prefix)
+            | AddressPrefixChangeAction::Removal(prefix) => prefix,
+        }
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::AddressPrefixChangeAction {
+        let value = match self {
+            AddressPrefixChangeAction::Addition(prefix) => {
+                raw::address_prefix_change_action::Value::Addition(prefix)
+            }
+            AddressPrefixChangeAction::Removal(prefix) => {
+                raw::address_prefix_change_action::Value::Removal(prefix)
+            }
+        };
+        raw::AddressPrefixChangeAction {
+            value: Some(value),
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::AddressPrefixChangeAction {
+        self.clone().into_raw()
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::AddressPrefixChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if no prefix is set
+    /// - if the prefix is not a valid, lowercase bech32 human readable part
+    pub fn try_from_raw(
+        raw: &raw::AddressPrefixChangeAction,
+    ) -> Result<Self, AddressPrefixChangeActionError> {
+        let action = match &raw.value {
+            Some(raw::address_prefix_change_action::Value::Addition(prefix)) => {
+                AddressPrefixChangeAction::Addition(prefix.clone())
+            }
+            Some(raw::address_prefix_change_action::Value::Removal(prefix)) => {
+                AddressPrefixChangeAction::Removal(prefix.clone())
+            }
+            None => return Err(AddressPrefixChangeActionError::missing_prefix()),
+        };
+        validate_address_prefix(action.prefix())?;
+        Ok(action)
+    }
+}
+
+fn validate_address_prefix(prefix: &str) -> Result<(), AddressPrefixChangeActionError> {
+    if prefix.chars().any(|c| c.is_ascii_uppercase()) {
+        return Err(AddressPrefixChangeActionError::uppercase_prefix());
+    }
+    Address::builder()
+        .array([0; ADDRESS_LEN])
+        .prefix(prefix)
+        .try_build()
+        .map_err(AddressPrefixChangeActionError::invalid_prefix)?;
+    Ok(())
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct AddressPrefixChangeActionError(AddressPrefixChangeActionErrorKind);
+
+impl AddressPrefixChangeActionError {
+    #[must_use]
+    fn invalid_prefix(err: AddressError) -> Self {
+        Self(AddressPrefixChangeActionErrorKind::InvalidPrefix(err))
+    }
+
+    #[must_use]
+    fn missing_prefix() -> Self {
+        Self(AddressPrefixChangeActionErrorKind::MissingPrefix)
+    }
+
+    #[must_use]
+    fn uppercase_prefix() -> Self {
+        Self(AddressPrefixChangeActionErrorKind::UppercasePrefix)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum AddressPrefixChangeActionErrorKind {
+    #[error("the prefix was not a valid bech32 human readable part")]
+    InvalidPrefix(#[source] AddressError),
+    #[error("the prefix was missing")]
+    MissingPrefix,
+    #[error("the prefix contained uppercase characters")]
+    UppercasePrefix,
+}
+
+impl ValidationError for AddressPrefixChangeActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            AddressPrefixChangeActionErrorKind::InvalidPrefix(..) => {
+                ValidationErrorCode::new(2501, ValidationErrorCategory::InvalidField)
+            }
+            AddressPrefixChangeActionErrorKind::MissingPrefix => {
+                ValidationErrorCode::new(2502, ValidationErrorCategory::MissingField)
+            }
+            AddressPrefixChangeActionErrorKind::UppercasePrefix => {
+                ValidationErrorCode::new(2503, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::InitBridgeAccountAction",
+        try_from = "raw::InitBridgeAccountAction"
+    )
+)]
+pub struct InitBridgeAccountAction {
+    // the rollup ID to register for the sender of this action
+    pub rollup_id: RollupId,
+    // the assets accepted by the bridge account
+    pub asset_id: asset::Id,
+    // the fee asset which to pay this action's fees with
+    pub fee_asset_id: asset::Id,
+    // the address corresponding to the key which has sudo capabilities;
+    // ie. can change the sudo and withdrawer addresses for this bridge account.
+    // if unset, this is set to the sender of the transaction.
+    pub sudo_address: Option<Address>,
+    // the address corresponding to the key which can withdraw funds from this bridge account.
+    // if unset, this is set to the sender of the transaction.
+    pub withdrawer_address: Option<Address>,
+}
+
+impl From<InitBridgeAccountAction> for raw::InitBridgeAccountAction {
+    fn from(value: InitBridgeAccountAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::InitBridgeAccountAction> for InitBridgeAccountAction {
+    type Error = InitBridgeAccountActionError;
+
+    fn try_from(raw: raw::InitBridgeAccountAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl InitBridgeAccountAction {
+    /// Returns a builder of an init bridge account action, which can only be built once all
+    /// required fields are set.
+    #[must_use = "the builder must be built to construct an init bridge account action to be useful"]
+    pub fn builder() -> InitBridgeAccountActionBuilder {
+        InitBridgeAccountActionBuilder::new()
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::InitBridgeAccountAction {
+        raw::InitBridgeAccountAction {
+            rollup_id: Some(self.rollup_id.to_raw()),
+            asset_id: self.asset_id.get().to_vec(),
+            fee_asset_id: self.fee_asset_id.get().to_vec(),
+            sudo_address: self.sudo_address.map(Address::into_raw),
+            withdrawer_address: self.withdrawer_address.map(Address::into_raw),
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::InitBridgeAccountAction {
+        raw::InitBridgeAccountAction {
+            rollup_id: Some(self.rollup_id.to_raw()),
+            asset_id: self.asset_id.get().to_vec(),
+            fee_asset_id: self.fee_asset_id.get().to_vec(),
+            sudo_address: self.sudo_address.as_ref().map(Address::to_raw),
+            withdrawer_address: self.withdrawer_address.as_ref().map(Address::to_raw),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::InitBridgeAccountAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `rollup_id` field is not set
+    /// - if the `rollup_id` field is invalid
+    /// - if the `sudo_address` field is invalid
+    /// - if the `withdrawer_address` field is invalid
+    pub fn try_from_raw(
+        proto: raw::InitBridgeAccountAction,
+    ) -> Result<Self, InitBridgeAccountActionError> {
+        let Some(rollup_id) = proto.rollup_id else {
+            return Err(InitBridgeAccountActionError::field_not_set("rollup_id"));
+        };
+        let rollup_id = RollupId::try_from_raw(&rollup_id)
+            .map_err(InitBridgeAccountActionError::invalid_rollup_id)?;
+        let asset_id = asset::Id::try_from_slice(&proto.asset_id)
+            .map_err(InitBridgeAccountActionError::invalid_asset_id)?;
+        let fee_asset_id = asset::Id::try_from_slice(&proto.fee_asset_id)
+            .map_err(InitBridgeAccountActionError::invalid_fee_asset_id)?;
+        let sudo_address = proto
+            .sudo_address
+            .as_ref()
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(InitBridgeAccountActionError::invalid_sudo_address)?;
+        let withdrawer_address = proto
+            .withdrawer_address
+            .as_ref()
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(InitBridgeAccountActionError::invalid_withdrawer_address)?;
+
+        Ok(Self {
+            rollup_id,
+            asset_id,
+            fee_asset_id,
+            sudo_address,
+            withdrawer_address,
+        })
+    }
+}
+
+/// A builder of [`InitBridgeAccountAction`], returned by [`InitBridgeAccountAction::builder`].
+///
+/// [`InitBridgeAccountActionBuilder::build`] is only available once all required fields are set.
+pub struct InitBridgeAccountActionBuilder<TRollupId = Unset, TAssetId = Unset, TFeeAssetId = Unset>
+{
+    rollup_id: TRollupId,
+    asset_id: TAssetId,
+    fee_asset_id: TFeeAssetId,
+    sudo_address: Option<Address>,
+    withdrawer_address: Option<Address>,
+}
+
+impl InitBridgeAccountActionBuilder {
+    fn new() -> Self {
+        Self {
+            rollup_id: Unset,
+            asset_id: Unset,
+            fee_asset_id: Unset,
+            sudo_address: None,
+            withdrawer_address: None,
+        }
+    }
+}
+
+impl<TRollupId, TAssetId, TFeeAssetId>
+    InitBridgeAccountActionBuilder<TRollupId, TAssetId, TFeeAssetId>
+{
+    #[must_use = "the builder must be built to construct an init bridge account action to be useful"]
+    pub fn rollup_id(
+        self,
+        rollup_id: RollupId,
+    ) -> InitBridgeAccountActionBuilder<RollupId, TAssetId, TFeeAssetId> {
+        InitBridgeAccountActionBuilder {
+            rollup_id,
+            asset_id: self.asset_id,
+            fee_asset_id: self.fee_asset_id,
+            sudo_address: self.sudo_address,
+            withdrawer_address: self.withdrawer_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct an init bridge account action to be useful"]
+    pub fn asset_id(
+        self,
+        asset_id: asset::Id,
+    ) -> InitBridgeAccountActionBuilder<TRollupId, asset::Id, TFeeAssetId> {
+        InitBridgeAccountActionBuilder {
+            rollup_id: self.rollup_id,
+            asset_id,
+            fee_asset_id: self.fee_asset_id,
+            sudo_address: self.sudo_address,
+            withdrawer_address: self.withdrawer_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct an init bridge account action to be useful"]
+    pub fn fee_asset_id(
+        self,
+        fee_asset_id: asset::Id,
+    ) -> InitBridgeAccountActionBuilder<TRollupId, TAssetId, asset::Id> {
+        InitBridgeAccountActionBuilder {
+            rollup_id: self.rollup_id,
+            asset_id: self.asset_id,
+            fee_asset_id,
+            sudo_address: self.sudo_address,
+            withdrawer_address: self.withdrawer_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct an init bridge account action to be useful"]
+    pub fn sudo_address(self, sudo_address: Address) -> Self {
+        Self {
+            sudo_address: Some(sudo_address),
+            ..self
+        }
+    }
+
+    #[must_use = "the builder must be built to construct an init bridge account action to be useful"]
+    pub fn withdrawer_address(self, withdrawer_address: Address) -> Self {
+        Self {
+            withdrawer_address: Some(withdrawer_address),
+            ..self
+        }
+    }
+}
+
+impl InitBridgeAccountActionBuilder<RollupId, asset::Id, asset::Id> {
+    /// Constructs an init bridge account action from the configured builder.
+    #[must_use]
+    pub fn build(self) -> InitBridgeAccountAction {
+        let Self {
+            rollup_id,
+            asset_id,
+            fee_asset_id,
+            sudo_address,
+            withdrawer_address,
+        } = self;
+        InitBridgeAccountAction {
+            rollup_id,
+            asset_id,
+            fee_asset_id,
+            sudo_address,
+            withdrawer_address,
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct InitBridgeAccountActionError(InitBridgeAccountActionErrorKind);
+
+impl InitBridgeAccountActionError {
+    #[must_use]
+    fn field_not_set(field: &'static str) -> Self {
+        Self(InitBridgeAccountActionErrorKind::FieldNotSet(field))
+    }
+
+    #[must_use]
+    fn invalid_rollup_id(err: IncorrectRollupIdLength) -> Self {
+        Self(InitBridgeAccountActionErrorKind::InvalidRollupId(err))
+    }
+
+    #[must_use]
+    fn invalid_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
+        Self(InitBridgeAccountActionErrorKind::InvalidAssetId(err))
+    }
+
+    #[must_use]
+    fn invalid_fee_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
+        Self(InitBridgeAccountActionErrorKind::InvalidFeeAssetId(err))
+    }
+
+    #[must_use]
+    fn invalid_sudo_address(err: AddressError) -> Self {
+        Self(InitBridgeAccountActionErrorKind::InvalidSudoAddress(err))
+    }
+
+    #[must_use]
+    fn invalid_withdrawer_address(err: AddressError) -> Self {
+        Self(InitBridgeAccountActionErrorKind::InvalidWithdrawerAddress(
+            err,
+        ))
+    }
+}
+
+// allow pedantic clippy as the errors have the same prefix (for consistency
+// with other error types) as well as the same postfix (due to the types the
+// errors are referencing), both of which cause clippy to complain.
+#[derive(Debug, thiserror::Error)]
+#[allow(clippy::enum_variant_names)]
+enum InitBridgeAccountActionErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("the `rollup_id` field was invalid")]
+    InvalidRollupId(#[source] IncorrectRollupIdLength),
+    #[error("an asset ID was invalid")]
+    InvalidAssetId(#[source] asset::IncorrectAssetIdLength),
+    #[error("the `fee_asset_id` field was invalid")]
+    InvalidFeeAssetId(#[source] asset::IncorrectAssetIdLength),
+    #[error("the `sudo_address` field was invalid")]
+    InvalidSudoAddress(#[source] AddressError),
+    #[error("the `withdrawer_address` field was invalid")]
+    InvalidWithdrawerAddress(#[source] AddressError),
+}
+
+impl ValidationError for InitBridgeAccountActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            InitBridgeAccountActionErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(1901, ValidationErrorCategory::MissingField)
+            }
+            InitBridgeAccountActionErrorKind::InvalidRollupId(..) => {
+                ValidationErrorCode::new(1902, ValidationErrorCategory::InvalidField)
+            }
+            InitBridgeAccountActionErrorKind::InvalidAssetId(..) => {
+                ValidationErrorCode::new(1903, ValidationErrorCategory::InvalidField)
+            }
+            InitBridgeAccountActionErrorKind::InvalidFeeAssetId(..) => {
+                ValidationErrorCode::new(1904, ValidationErrorCategory::InvalidField)
+            }
+            InitBridgeAccountActionErrorKind::InvalidSudoAddress(..) => {
+                ValidationErrorCode::new(1905, ValidationErrorCategory::InvalidField)
+            }
+            InitBridgeAccountActionErrorKind::InvalidWithdrawerAddress(..) => {
+                ValidationErrorCode::new(1906, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
+}
+
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::BridgeLockAction", try_from = "raw::BridgeLockAction")
+)]
+pub struct BridgeLockAction {
+    pub to: Address,
+    pub amount: u128,
+    // asset to be transferred.
+    pub asset_id: asset::Id,
+    // asset to use for fee payment.
+    pub fee_asset_id: asset::Id,
+    // the address on the destination chain to send the transfer to.
+    pub destination_chain_address: String,
+}
+
+impl From<BridgeLockAction> for raw::BridgeLockAction {
+    fn from(value: BridgeLockAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::BridgeLockAction> for BridgeLockAction {
+    type Error = BridgeLockActionError;
+
+    fn try_from(raw: raw::BridgeLockAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl BridgeLockAction {
+    /// Returns a builder of a bridge lock action, which can only be built once all required fields
+    /// are set.
+    #[must_use = "the builder must be built to construct a bridge lock action to be useful"]
+    pub fn builder() -> BridgeLockActionBuilder {
+        BridgeLockActionBuilder::new()
+    }
+
+    /// Returns the length of the protobuf encoding of the action.
+    #[must_use]
+    pub fn encoded_size(&self) -> usize {
+        self.to_raw().encoded_len()
+    }
+
+    /// Returns the number of bytes for which the sequencer charges its per-byte fee, which is
+    /// the length of the protobuf encoding of the deposit created by the action.
+    #[must_use]
+    pub fn fee_weight(&self) -> usize {
+        // the rollup ID of the deposit is read from the sequencer state, but it has a fixed
+        // length and so does not affect the size of the deposit
+        Deposit::new(
+            self.to,
+            RollupId::new([0; ROLLUP_ID_LEN]),
+            self.amount,
+            self.asset_id,
+            self.destination_chain_address.clone(),
+        )
+        .into_raw()
+        .encoded_len()
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::BridgeLockAction {
+        raw::BridgeLockAction {
+            to: Some(self.to.to_raw()),
+            amount: Some(self.amount.into()),
+            asset_id: self.asset_id.get().to_vec(),
+            fee_asset_id: self.fee_asset_id.as_ref().to_vec(),
+            destination_chain_address: self.destination_chain_address,
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::BridgeLockAction {
+        raw::BridgeLockAction {
+            to: Some(self.to.to_raw()),
+            amount: Some(self.amount.into()),
+            asset_id: self.asset_id.get().to_vec(),
+            fee_asset_id: self.fee_asset_id.as_ref().to_vec(),
+            destination_chain_address: self.destination_chain_address.clone(),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::BridgeLockAction`].
     ///
     /// # Errors
     ///
+    /// - if the `to` field is not set
+    /// - if the `to` field is invalid
     /// - if the `asset_id` field is invalid
-    pub fn try_from_raw(
-        raw: &raw::FeeAssetChangeAction,
-    ) -> Result<Self, FeeAssetChangeActionError> {
-        match raw {
-            raw::FeeAssetChangeAction {
-                value: Some(raw::fee_asset_change_action::Value::Addition(asset_id)),
-            } => {
-                let asset_id = asset::Id::try_from_slice(asset_id)
-                    .map_err(FeeAssetChangeActionError::invalid_asset_id)?;
-                Ok(FeeAssetChangeAction::Addition(asset_id))
-            }
-            raw::FeeAssetChangeAction {
-                value: Some(raw::fee_asset_change_action::Value::Removal(asset_id)),
-            } => {
-                let asset_id = asset::Id::try_from_slice(asset_id)
-                    .map_err(FeeAssetChangeActionError::invalid_asset_id)?;
-                Ok(FeeAssetChangeAction::Removal(asset_id))
-            }
-            _ => Err(FeeAssetChangeActionError::missing_asset_id()),
+    /// - if the `fee_asset_id` field is invalid
+    pub fn try_from_raw(proto: raw::BridgeLockAction) -> Result<Self, BridgeLockActionError> {
+        let Some(to) = proto.to else {
+            return Err(BridgeLockActionError::field_not_set("to"));
+        };
+        let to = Address::try_from_raw(&to).map_err(BridgeLockActionError::address)?;
+        let amount = proto
+            .amount
+            .ok_or(BridgeLockActionError::missing_amount())?;
+        let asset_id = asset::Id::try_from_slice(&proto.asset_id)
+            .map_err(BridgeLockActionError::invalid_asset_id)?;
+        let fee_asset_id = asset::Id::try_from_slice(&proto.fee_asset_id)
+            .map_err(BridgeLockActionError::invalid_fee_asset_id)?;
+        Ok(Self {
+            to,
+            amount: amount.into(),
+            asset_id,
+            fee_asset_id,
+            destination_chain_address: proto.destination_chain_address,
+        })
+    }
+}
+
+/// A builder of [`BridgeLockAction`], returned by [`BridgeLockAction::builder`].
+///
+/// [`BridgeLockActionBuilder::build`] is only available once all required fields are set.
+pub struct BridgeLockActionBuilder<
+    TTo = Unset,
+    TAmount = Unset,
+    TAssetId = Unset,
+    TFeeAssetId = Unset,
+    TDestinationChainAddress = Unset,
+> {
+    to: TTo,
+    amount: TAmount,
+    asset_id: TAssetId,
+    fee_asset_id: TFeeAssetId,
+    destination_chain_address: TDestinationChainAddress,
+}
+
+impl BridgeLockActionBuilder {
+    fn new() -> Self {
+        Self {
+            to: Unset,
+            amount: Unset,
+            asset_id: Unset,
+            fee_asset_id: Unset,
+            destination_chain_address: Unset,
+        }
+    }
+}
+
+impl<TTo, TAmount, TAssetId, TFeeAssetId, TDestinationChainAddress>
+    BridgeLockActionBuilder<TTo, TAmount, TAssetId, TFeeAssetId, TDestinationChainAddress>
+{
+    #[must_use = "the builder must be built to construct a bridge lock action to be useful"]
+    pub fn to(
+        self,
+        to: Address,
+    ) -> BridgeLockActionBuilder<Address, TAmount, TAssetId, TFeeAssetId, TDestinationChainAddress>
+    {
+        BridgeLockActionBuilder {
+            to,
+            amount: self.amount,
+            asset_id: self.asset_id,
+            fee_asset_id: self.fee_asset_id,
+            destination_chain_address: self.destination_chain_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a bridge lock action to be useful"]
+    pub fn amount(
+        self,
+        amount: u128,
+    ) -> BridgeLockActionBuilder<TTo, u128, TAssetId, TFeeAssetId, TDestinationChainAddress> {
+        BridgeLockActionBuilder {
+            to: self.to,
+            amount,
+            asset_id: self.asset_id,
+            fee_asset_id: self.fee_asset_id,
+            destination_chain_address: self.destination_chain_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a bridge lock action to be useful"]
+    pub fn asset_id(
+        self,
+        asset_id: asset::Id,
+    ) -> BridgeLockActionBuilder<TTo, TAmount, asset::Id, TFeeAssetId, TDestinationChainAddress>
+    {
+        BridgeLockActionBuilder {
+            to: self.to,
+            amount: self.amount,
+            asset_id,
+            fee_asset_id: self.fee_asset_id,
+            destination_chain_address: self.destination_chain_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a bridge lock action to be useful"]
+    pub fn fee_asset_id(
+        self,
+        fee_asset_id: asset::Id,
+    ) -> BridgeLockActionBuilder<TTo, TAmount, TAssetId, asset::Id, TDestinationChainAddress> {
+        BridgeLockActionBuilder {
+            to: self.to,
+            amount: self.amount,
+            asset_id: self.asset_id,
+            fee_asset_id,
+            destination_chain_address: self.destination_chain_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a bridge lock action to be useful"]
+    pub fn destination_chain_address<T: Into<String>>(
+        self,
+        destination_chain_address: T,
+    ) -> BridgeLockActionBuilder<TTo, TAmount, TAssetId, TFeeAssetId, String> {
+        BridgeLockActionBuilder {
+            to: self.to,
+            amount: self.amount,
+            asset_id: self.asset_id,
+            fee_asset_id: self.fee_asset_id,
+            destination_chain_address: destination_chain_address.into(),
+        }
+    }
+}
+
+impl BridgeLockActionBuilder<Address, u128, asset::Id, asset::Id, String> {
+    /// Constructs a bridge lock action from the configured builder.
+    #[must_use]
+    pub fn build(self) -> BridgeLockAction {
+        let Self {
+            to,
+            amount,
+            asset_id,
+            fee_asset_id,
+            destination_chain_address,
+        } = self;
+        BridgeLockAction {
+            to,
+            amount,
+            asset_id,
+            fee_asset_id,
+            destination_chain_address,
         }
     }
-}
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BridgeLockActionError(BridgeLockActionErrorKind);
+
+impl BridgeLockActionError {
+    #[must_use]
+    fn field_not_set(field: &'static str) -> Self {
+        Self(BridgeLockActionErrorKind::FieldNotSet(field))
+    }
+
+    #[must_use]
+    fn address(source: AddressError) -> Self {
+        Self(BridgeLockActionErrorKind::Address {
+            source,
+        })
+    }
 
-#[derive(Debug, thiserror::Error)]
-#[error(transparent)]
-pub struct FeeAssetChangeActionError(FeeAssetChangeActionErrorKind);
+    #[must_use]
+    fn missing_amount() -> Self {
+        Self(BridgeLockActionErrorKind::MissingAmount)
+    }
 
-impl FeeAssetChangeActionError {
     #[must_use]
     fn invalid_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
-        Self(FeeAssetChangeActionErrorKind::InvalidAssetId(err))
+        Self(BridgeLockActionErrorKind::InvalidAssetId(err))
     }
 
     #[must_use]
-    fn missing_asset_id() -> Self {
-        Self(FeeAssetChangeActionErrorKind::MissingAssetId)
+    fn invalid_fee_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
+        Self(BridgeLockActionErrorKind::InvalidFeeAssetId(err))
     }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum FeeAssetChangeActionErrorKind {
-    #[error("the asset_id was invalid")]
+enum BridgeLockActionErrorKind {
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("the `to` field was invalid")]
+    Address { source: AddressError },
+    #[error("the `amount` field was not set")]
+    MissingAmount,
+    #[error("the `asset_id` field was invalid")]
     InvalidAssetId(#[source] asset::IncorrectAssetIdLength),
-    #[error("the asset_id was missing")]
-    MissingAssetId,
+    #[error("the `fee_asset_id` field was invalid")]
+    InvalidFeeAssetId(#[source] asset::IncorrectAssetIdLength),
+}
+
+impl ValidationError for BridgeLockActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            BridgeLockActionErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(2001, ValidationErrorCategory::MissingField)
+            }
+            BridgeLockActionErrorKind::Address {
+                ..
+            } => ValidationErrorCode::new(2002, ValidationErrorCategory::InvalidField),
+            BridgeLockActionErrorKind::MissingAmount => {
+                ValidationErrorCode::new(2003, ValidationErrorCategory::MissingField)
+            }
+            BridgeLockActionErrorKind::InvalidAssetId(..) => {
+                ValidationErrorCode::new(2004, ValidationErrorCategory::InvalidField)
+            }
+            BridgeLockActionErrorKind::InvalidFeeAssetId(..) => {
+                ValidationErrorCode::new(2005, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Clone)]
-pub struct InitBridgeAccountAction {
-    // the rollup ID to register for the sender of this action
-    pub rollup_id: RollupId,
-    // the assets accepted by the bridge account
-    pub asset_id: asset::Id,
-    // the fee asset which to pay this action's fees with
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::BridgeUnlockAction", try_from = "raw::BridgeUnlockAction")
+)]
+pub struct BridgeUnlockAction {
+    pub to: Address,
+    pub amount: u128,
+    // asset to use for fee payment.
     pub fee_asset_id: asset::Id,
-    // the address corresponding to the key which has sudo capabilities;
-    // ie. can change the sudo and withdrawer addresses for this bridge account.
-    // if unset, this is set to the sender of the transaction.
-    pub sudo_address: Option<Address>,
-    // the address corresponding to the key which can withdraw funds from this bridge account.
-    // if unset, this is set to the sender of the transaction.
-    pub withdrawer_address: Option<Address>,
+    // memo for double spend protection.
+    pub memo: Vec<u8>,
+    // the address of the bridge account to transfer from,
+    // if the bridge account's withdrawer address is not the same as the bridge address.
+    // if unset, the signer of the transaction is used.
+    pub bridge_address: Option<Address>,
 }
 
-impl InitBridgeAccountAction {
+impl From<BridgeUnlockAction> for raw::BridgeUnlockAction {
+    fn from(value: BridgeUnlockAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::BridgeUnlockAction> for BridgeUnlockAction {
+    type Error = BridgeUnlockActionError;
+
+    fn try_from(raw: raw::BridgeUnlockAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl BridgeUnlockAction {
+    /// Returns a builder of a bridge unlock action, which can only be built once all required
+    /// fields are set.
+    #[must_use = "the builder must be built to construct a bridge unlock action to be useful"]
+    pub fn builder() -> BridgeUnlockActionBuilder {
+        BridgeUnlockActionBuilder::new()
+    }
+
     #[must_use]
-    pub fn into_raw(self) -> raw::InitBridgeAccountAction {
-        raw::InitBridgeAccountAction {
-            rollup_id: Some(self.rollup_id.to_raw()),
-            asset_id: self.asset_id.get().to_vec(),
-            fee_asset_id: self.fee_asset_id.get().to_vec(),
-            sudo_address: self.sudo_address.map(Address::into_raw),
-            withdrawer_address: self.withdrawer_address.map(Address::into_raw),
+    pub fn into_raw(self) -> raw::BridgeUnlockAction {
+        raw::BridgeUnlockAction {
+            to: Some(self.to.to_raw()),
+            amount: Some(self.amount.into()),
+            fee_asset_id: self.fee_asset_id.as_ref().to_vec(),
+            memo: self.memo,
+            bridge_address: self.bridge_address.map(Address::into_raw),
         }
     }
 
     #[must_use]
-    pub fn to_raw(&self) -> raw::InitBridgeAccountAction {
-        raw::InitBridgeAccountAction {
-            rollup_id: Some(self.rollup_id.to_raw()),
-            asset_id: self.asset_id.get().to_vec(),
-            fee_asset_id: self.fee_asset_id.get().to_vec(),
-            sudo_address: self.sudo_address.as_ref().map(Address::to_raw),
-            withdrawer_address: self.withdrawer_address.as_ref().map(Address::to_raw),
+    pub fn to_raw(&self) -> raw::BridgeUnlockAction {
+        raw::BridgeUnlockAction {
+            to: Some(self.to.to_raw()),
+            amount: Some(self.amount.into()),
+            fee_asset_id: self.fee_asset_id.as_ref().to_vec(),
+            memo: self.memo.clone(),
+            bridge_address: self.bridge_address.as_ref().map(Address::to_raw),
         }
     }
 
-    /// Convert from a raw, unchecked protobuf [`raw::InitBridgeAccountAction`].
+    /// Convert from a raw, unchecked protobuf [`raw::BridgeUnlockAction`].
     ///
     /// # Errors
     ///
-    /// - if the `rollup_id` field is not set
-    /// - if the `rollup_id` field is invalid
-    /// - if the `sudo_address` field is invalid
-    /// - if the `withdrawer_address` field is invalid
-    pub fn try_from_raw(
-        proto: raw::InitBridgeAccountAction,
-    ) -> Result<Self, InitBridgeAccountActionError> {
-        let Some(rollup_id) = proto.rollup_id else {
-            return Err(InitBridgeAccountActionError::field_not_set("rollup_id"));
+    /// - if the `to` field is not set
+    /// - if the `to` field is invalid
+    /// - if the `amount` field is invalid
+    /// - if the `fee_asset_id` field is invalid
+    /// - if the `from` field is invalid
+    pub fn try_from_raw(proto: raw::BridgeUnlockAction) -> Result<Self, BridgeUnlockActionError> {
+        let Some(to) = proto.to else {
+            return Err(BridgeUnlockActionError::field_not_set("to"));
         };
-        let rollup_id = RollupId::try_from_raw(&rollup_id)
-            .map_err(InitBridgeAccountActionError::invalid_rollup_id)?;
-        let asset_id = asset::Id::try_from_slice(&proto.asset_id)
-            .map_err(InitBridgeAccountActionError::invalid_asset_id)?;
+        let to = Address::try_from_raw(&to).map_err(BridgeUnlockActionError::address)?;
+        let amount = proto
+            .amount
+            .ok_or(BridgeUnlockActionError::missing_amount())?;
         let fee_asset_id = asset::Id::try_from_slice(&proto.fee_asset_id)
-            .map_err(InitBridgeAccountActionError::invalid_fee_asset_id)?;
-        let sudo_address = proto
-            .sudo_address
-            .as_ref()
-            .map(Address::try_from_raw)
-            .transpose()
-            .map_err(InitBridgeAccountActionError::invalid_sudo_address)?;
-        let withdrawer_address = proto
-            .withdrawer_address
+            .map_err(BridgeUnlockActionError::invalid_fee_asset_id)?;
+        let bridge_address = proto
+            .bridge_address
             .as_ref()
             .map(Address::try_from_raw)
             .transpose()
-            .map_err(InitBridgeAccountActionError::invalid_withdrawer_address)?;
-
+            .map_err(BridgeUnlockActionError::invalid_bridge_address)?;
         Ok(Self {
-            rollup_id,
-            asset_id,
+            to,
+            amount: amount.into(),
             fee_asset_id,
-            sudo_address,
-            withdrawer_address,
+            memo: proto.memo,
+            bridge_address,
         })
     }
 }
 
+/// A builder of [`BridgeUnlockAction`], returned by [`BridgeUnlockAction::builder`].
+///
+/// [`BridgeUnlockActionBuilder::build`] is only available once all required fields are set.
+pub struct BridgeUnlockActionBuilder<TTo = Unset, TAmount = Unset, TFeeAssetId = Unset> {
+    to: TTo,
+    amount: TAmount,
+    fee_asset_id: TFeeAssetId,
+    memo: Vec<u8>,
+    bridge_address: Option<Address>,
+}
+
+impl BridgeUnlockActionBuilder {
+    fn new() -> Self {
+        Self {
+            to: Unset,
+            amount: Unset,
+            fee_asset_id: Unset,
+            memo: Vec::new(),
+            bridge_address: None,
+        }
+    }
+}
+
+impl<TTo, TAmount, TFeeAssetId> BridgeUnlockActionBuilder<TTo, TAmount, TFeeAssetId> {
+    #[must_use = "the builder must be built to construct a bridge unlock action to be useful"]
+    pub fn to(self, to: Address) -> BridgeUnlockActionBuilder<Address, TAmount, TFeeAssetId> {
+        BridgeUnlockActionBuilder {
+            to,
+            amount: self.amount,
+            fee_asset_id: self.fee_asset_id,
+            memo: self.memo,
+            bridge_address: self.bridge_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a bridge unlock action to be useful"]
+    pub fn amount(self, amount: u128) -> BridgeUnlockActionBuilder<TTo, u128, TFeeAssetId> {
+        BridgeUnlockActionBuilder {
+            to: self.to,
+            amount,
+            fee_asset_id: self.fee_asset_id,
+            memo: self.memo,
+            bridge_address: self.bridge_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a bridge unlock action to be useful"]
+    pub fn fee_asset_id(
+        self,
+        fee_asset_id: asset::Id,
+    ) -> BridgeUnlockActionBuilder<TTo, TAmount, asset::Id> {
+        BridgeUnlockActionBuilder {
+            to: self.to,
+            amount: self.amount,
+            fee_asset_id,
+            memo: self.memo,
+            bridge_address: self.bridge_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a bridge unlock action to be useful"]
+    pub fn memo<T: Into<Vec<u8>>>(self, memo: T) -> Self {
+        Self {
+            memo: memo.into(),
+            ..self
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a bridge unlock action to be useful"]
+    pub fn bridge_address(self, bridge_address: Address) -> Self {
+        Self {
+            bridge_address: Some(bridge_address),
+            ..self
+        }
+    }
+}
+
+impl BridgeUnlockActionBuilder<Address, u128, asset::Id> {
+    /// Constructs a bridge unlock action from the configured builder.
+    #[must_use]
+    pub fn build(self) -> BridgeUnlockAction {
+        let Self {
+            to,
+            amount,
+            fee_asset_id,
+            memo,
+            bridge_address,
+        } = self;
+        BridgeUnlockAction {
+            to,
+            amount,
+            fee_asset_id,
+            memo,
+            bridge_address,
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct InitBridgeAccountActionError(InitBridgeAccountActionErrorKind);
+pub struct BridgeUnlockActionError(BridgeUnlockActionErrorKind);
 
-impl InitBridgeAccountActionError {
+impl BridgeUnlockActionError {
     #[must_use]
     fn field_not_set(field: &'static str) -> Self {
-        Self(InitBridgeAccountActionErrorKind::FieldNotSet(field))
+        Self(BridgeUnlockActionErrorKind::FieldNotSet(field))
     }
 
     #[must_use]
-    fn invalid_rollup_id(err: IncorrectRollupIdLength) -> Self {
-        Self(InitBridgeAccountActionErrorKind::InvalidRollupId(err))
+    fn address(source: AddressError) -> Self {
+        Self(BridgeUnlockActionErrorKind::Address {
+            source,
+        })
     }
 
     #[must_use]
-    fn invalid_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
-        Self(InitBridgeAccountActionErrorKind::InvalidAssetId(err))
+    fn missing_amount() -> Self {
+        Self(BridgeUnlockActionErrorKind::MissingAmount)
     }
 
     #[must_use]
     fn invalid_fee_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
-        Self(InitBridgeAccountActionErrorKind::InvalidFeeAssetId(err))
-    }
-
-    #[must_use]
-    fn invalid_sudo_address(err: AddressError) -> Self {
-        Self(InitBridgeAccountActionErrorKind::InvalidSudoAddress(err))
+        Self(BridgeUnlockActionErrorKind::InvalidFeeAssetId(err))
     }
 
     #[must_use]
-    fn invalid_withdrawer_address(err: AddressError) -> Self {
-        Self(InitBridgeAccountActionErrorKind::InvalidWithdrawerAddress(
-            err,
-        ))
+    fn invalid_bridge_address(err: AddressError) -> Self {
+        Self(BridgeUnlockActionErrorKind::InvalidBridgeAddress(err))
     }
 }
 
-// allow pedantic clippy as the errors have the same prefix (for consistency
-// with other error types) as well as the same postfix (due to the types the
-// errors are referencing), both of which cause clippy to complain.
 #[derive(Debug, thiserror::Error)]
-#[allow(clippy::enum_variant_names)]
-enum InitBridgeAccountActionErrorKind {
+enum BridgeUnlockActionErrorKind {
     #[error("the expected field in the raw source type was not set: `{0}`")]
     FieldNotSet(&'static str),
-    #[error("the `rollup_id` field was invalid")]
-    InvalidRollupId(#[source] IncorrectRollupIdLength),
-    #[error("an asset ID was invalid")]
-    InvalidAssetId(#[source] asset::IncorrectAssetIdLength),
+    #[error("the `to` field was invalid")]
+    Address { source: AddressError },
+    #[error("the `amount` field was not set")]
+    MissingAmount,
     #[error("the `fee_asset_id` field was invalid")]
     InvalidFeeAssetId(#[source] asset::IncorrectAssetIdLength),
-    #[error("the `sudo_address` field was invalid")]
-    InvalidSudoAddress(#[source] AddressError),
-    #[error("the `withdrawer_address` field was invalid")]
-    InvalidWithdrawerAddress(#[source] AddressError),
+    #[error("the `bridge_address` field was invalid")]
+    InvalidBridgeAddress(#[source] AddressError),
+}
+
+impl ValidationError for BridgeUnlockActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            BridgeUnlockActionErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(2101, ValidationErrorCategory::MissingField)
+            }
+            BridgeUnlockActionErrorKind::Address {
+                ..
+            } => ValidationErrorCode::new(2102, ValidationErrorCategory::InvalidField),
+            BridgeUnlockActionErrorKind::MissingAmount => {
+                ValidationErrorCode::new(2103, ValidationErrorCategory::MissingField)
+            }
+            BridgeUnlockActionErrorKind::InvalidFeeAssetId(..) => {
+                ValidationErrorCode::new(2104, ValidationErrorCategory::InvalidField)
+            }
+            BridgeUnlockActionErrorKind::InvalidBridgeAddress(..) => {
+                ValidationErrorCode::new(2105, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
 }
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone)]
-pub struct BridgeLockAction {
-    pub to: Address,
-    pub amount: u128,
-    // asset to be transferred.
-    pub asset_id: asset::Id,
-    // asset to use for fee payment.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::BridgeSudoChangeAction",
+        try_from = "raw::BridgeSudoChangeAction"
+    )
+)]
+pub struct BridgeSudoChangeAction {
+    pub bridge_address: Address,
+    pub new_sudo_address: Option<Address>,
+    pub new_withdrawer_address: Option<Address>,
     pub fee_asset_id: asset::Id,
-    // the address on the destination chain to send the transfer to.
-    pub destination_chain_address: String,
 }
 
-impl BridgeLockAction {
+impl From<BridgeSudoChangeAction> for raw::BridgeSudoChangeAction {
+    fn from(value: BridgeSudoChangeAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::BridgeSudoChangeAction> for BridgeSudoChangeAction {
+    type Error = BridgeSudoChangeActionError;
+
+    fn try_from(raw: raw::BridgeSudoChangeAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl BridgeSudoChangeAction {
+    /// Returns a builder of a bridge sudo change action, which can only be built once all required
+    /// fields are set.
+    #[must_use = "the builder must be built to construct a bridge sudo change action to be useful"]
+    pub fn builder() -> BridgeSudoChangeActionBuilder {
+        BridgeSudoChangeActionBuilder::new()
+    }
+
     #[must_use]
-    pub fn into_raw(self) -> raw::BridgeLockAction {
-        raw::BridgeLockAction {
-            to: Some(self.to.to_raw()),
-            amount: Some(self.amount.into()),
-            asset_id: self.asset_id.get().to_vec(),
-            fee_asset_id: self.fee_asset_id.as_ref().to_vec(),
-            destination_chain_address: self.destination_chain_address,
+    pub fn into_raw(self) -> raw::BridgeSudoChangeAction {
+        raw::BridgeSudoChangeAction {
+            bridge_address: Some(self.bridge_address.to_raw()),
+            new_sudo_address: self.new_sudo_address.map(Address::into_raw),
+            new_withdrawer_address: self.new_withdrawer_address.map(Address::into_raw),
+            fee_asset_id: self.fee_asset_id.get().to_vec(),
         }
     }
 
     #[must_use]
-    pub fn to_raw(&self) -> raw::BridgeLockAction {
-        raw::BridgeLockAction {
-            to: Some(self.to.to_raw()),
-            amount: Some(self.amount.into()),
-            asset_id: self.asset_id.get().to_vec(),
-            fee_asset_id: self.fee_asset_id.as_ref().to_vec(),
-            destination_chain_address: self.destination_chain_address.clone(),
+    pub fn to_raw(&self) -> raw::BridgeSudoChangeAction {
+        raw::BridgeSudoChangeAction {
+            bridge_address: Some(self.bridge_address.to_raw()),
+            new_sudo_address: self.new_sudo_address.as_ref().map(Address::to_raw),
+            new_withdrawer_address: self.new_withdrawer_address.as_ref().map(Address::to_raw),
+            fee_asset_id: self.fee_asset_id.get().to_vec(),
         }
     }
 
-    /// Convert from a raw, unchecked protobuf [`raw::BridgeLockAction`].
+    /// Convert from a raw, unchecked protobuf [`raw::BridgeSudoChangeAction`].
     ///
     /// # Errors
     ///
-    /// - if the `to` field is not set
-    /// - if the `to` field is invalid
-    /// - if the `asset_id` field is invalid
+    /// - if the `bridge_address` field is not set
+    /// - if the `bridge_address` field is invalid
+    /// - if the `new_sudo_address` field is invalid
+    /// - if the `new_withdrawer_address` field is invalid
     /// - if the `fee_asset_id` field is invalid
-    pub fn try_from_raw(proto: raw::BridgeLockAction) -> Result<Self, BridgeLockActionError> {
-        let Some(to) = proto.to else {
-            return Err(BridgeLockActionError::field_not_set("to"));
+    pub fn try_from_raw(
+        proto: raw::BridgeSudoChangeAction,
+    ) -> Result<Self, BridgeSudoChangeActionError> {
+        let Some(bridge_address) = proto.bridge_address else {
+            return Err(BridgeSudoChangeActionError::field_not_set("bridge_address"));
         };
-        let to = Address::try_from_raw(&to).map_err(BridgeLockActionError::address)?;
-        let amount = proto
-            .amount
-            .ok_or(BridgeLockActionError::missing_amount())?;
-        let asset_id = asset::Id::try_from_slice(&proto.asset_id)
-            .map_err(BridgeLockActionError::invalid_asset_id)?;
+        let bridge_address = Address::try_from_raw(&bridge_address)
+            .map_err(BridgeSudoChangeActionError::invalid_bridge_address)?;
+        let new_sudo_address = proto
+            .new_sudo_address
+            .as_ref()
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(BridgeSudoChangeActionError::invalid_new_sudo_address)?;
+        let new_withdrawer_address = proto
+            .new_withdrawer_address
+            .as_ref()
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(BridgeSudoChangeActionError::invalid_new_withdrawer_address)?;
         let fee_asset_id = asset::Id::try_from_slice(&proto.fee_asset_id)
-            .map_err(BridgeLockActionError::invalid_fee_asset_id)?;
+            .map_err(BridgeSudoChangeActionError::invalid_fee_asset_id)?;
+
         Ok(Self {
-            to,
-            amount: amount.into(),
-            asset_id,
+            bridge_address,
+            new_sudo_address,
+            new_withdrawer_address,
             fee_asset_id,
-            destination_chain_address: proto.destination_chain_address,
         })
     }
 }
 
+/// A builder of [`BridgeSudoChangeAction`], returned by [`BridgeSudoChangeAction::builder`].
+///
+/// [`BridgeSudoChangeActionBuilder::build`] is only available once all required fields are set.
+pub struct BridgeSudoChangeActionBuilder<TBridgeAddress = Unset, TFeeAssetId = Unset> {
+    bridge_address: TBridgeAddress,
+    fee_asset_id: TFeeAssetId,
+    new_sudo_address: Option<Address>,
+    new_withdrawer_address: Option<Address>,
+}
+
+impl BridgeSudoChangeActionBuilder {
+    fn new() -> Self {
+        Self {
+            bridge_address: Unset,
+            fee_asset_id: Unset,
+            new_sudo_address: None,
+            new_withdrawer_address: None,
+        }
+    }
+}
+
+impl<TBridgeAddress, TFeeAssetId> BridgeSudoChangeActionBuilder<TBridgeAddress, TFeeAssetId> {
+    #[must_use = "the builder must be built to construct a bridge sudo change action to be useful"]
+    pub fn bridge_address(
+        self,
+        bridge_address: Address,
+    ) -> BridgeSudoChangeActionBuilder<Address, TFeeAssetId> {
+        BridgeSudoChangeActionBuilder {
+            bridge_address,
+            fee_asset_id: self.fee_asset_id,
+            new_sudo_address: self.new_sudo_address,
+            new_withdrawer_address: self.new_withdrawer_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a bridge sudo change action to be useful"]
+    pub fn fee_asset_id(
+        self,
+        fee_asset_id: asset::Id,
+    ) -> BridgeSudoChangeActionBuilder<TBridgeAddress, asset::Id> {
+        BridgeSudoChangeActionBuilder {
+            bridge_address: self.bridge_address,
+            fee_asset_id,
+            new_sudo_address: self.new_sudo_address,
+            new_withdrawer_address: self.new_withdrawer_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a bridge sudo change action to be useful"]
+    pub fn new_sudo_address(self, new_sudo_address: Address) -> Self {
+        Self {
+            new_sudo_address: Some(new_sudo_address),
+            ..self
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a bridge sudo change action to be useful"]
+    pub fn new_withdrawer_address(self, new_withdrawer_address: Address) -> Self {
+        Self {
+            new_withdrawer_address: Some(new_withdrawer_address),
+            ..self
+        }
+    }
+}
+
+impl BridgeSudoChangeActionBuilder<Address, asset::Id> {
+    /// Constructs a bridge sudo change action from the configured builder.
+    #[must_use]
+    pub fn build(self) -> BridgeSudoChangeAction {
+        let Self {
+            bridge_address,
+            fee_asset_id,
+            new_sudo_address,
+            new_withdrawer_address,
+        } = self;
+        BridgeSudoChangeAction {
+            bridge_address,
+            fee_asset_id,
+            new_sudo_address,
+            new_withdrawer_address,
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct BridgeLockActionError(BridgeLockActionErrorKind);
+pub struct BridgeSudoChangeActionError(BridgeSudoChangeActionErrorKind);
 
-impl BridgeLockActionError {
+impl BridgeSudoChangeActionError {
     #[must_use]
     fn field_not_set(field: &'static str) -> Self {
-        Self(BridgeLockActionErrorKind::FieldNotSet(field))
+        Self(BridgeSudoChangeActionErrorKind::FieldNotSet(field))
     }
 
     #[must_use]
-    fn address(source: AddressError) -> Self {
-        Self(BridgeLockActionErrorKind::Address {
-            source,
-        })
+    fn invalid_bridge_address(err: AddressError) -> Self {
+        Self(BridgeSudoChangeActionErrorKind::InvalidBridgeAddress(err))
     }
 
     #[must_use]
-    fn missing_amount() -> Self {
-        Self(BridgeLockActionErrorKind::MissingAmount)
+    fn invalid_new_sudo_address(err: AddressError) -> Self {
+        Self(BridgeSudoChangeActionErrorKind::InvalidNewSudoAddress(err))
     }
 
     #[must_use]
-    fn invalid_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
-        Self(BridgeLockActionErrorKind::InvalidAssetId(err))
+    fn invalid_new_withdrawer_address(err: AddressError) -> Self {
+        Self(BridgeSudoChangeActionErrorKind::InvalidNewWithdrawerAddress(err))
     }
 
     #[must_use]
     fn invalid_fee_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
-        Self(BridgeLockActionErrorKind::InvalidFeeAssetId(err))
+        Self(BridgeSudoChangeActionErrorKind::InvalidFeeAssetId(err))
     }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum BridgeLockActionErrorKind {
+enum BridgeSudoChangeActionErrorKind {
     #[error("the expected field in the raw source type was not set: `{0}`")]
     FieldNotSet(&'static str),
-    #[error("the `to` field was invalid")]
-    Address { source: AddressError },
-    #[error("the `amount` field was not set")]
-    MissingAmount,
-    #[error("the `asset_id` field was invalid")]
-    InvalidAssetId(#[source] asset::IncorrectAssetIdLength),
+    #[error("the `bridge_address` field was invalid")]
+    InvalidBridgeAddress(#[source] AddressError),
+    #[error("the `new_sudo_address` field was invalid")]
+    InvalidNewSudoAddress(#[source] AddressError),
+    #[error("the `new_withdrawer_address` field was invalid")]
+    InvalidNewWithdrawerAddress(#[source] AddressError),
     #[error("the `fee_asset_id` field was invalid")]
     InvalidFeeAssetId(#[source] asset::IncorrectAssetIdLength),
 }
 
+impl ValidationError for BridgeSudoChangeActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            BridgeSudoChangeActionErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(2201, ValidationErrorCategory::MissingField)
+            }
+            BridgeSudoChangeActionErrorKind::InvalidBridgeAddress(..) => {
+                ValidationErrorCode::new(2202, ValidationErrorCategory::InvalidField)
+            }
+            BridgeSudoChangeActionErrorKind::InvalidNewSudoAddress(..) => {
+                ValidationErrorCode::new(2203, ValidationErrorCategory::InvalidField)
+            }
+            BridgeSudoChangeActionErrorKind::InvalidNewWithdrawerAddress(..) => {
+                ValidationErrorCode::new(2204, ValidationErrorCategory::InvalidField)
+            }
+            BridgeSudoChangeActionErrorKind::InvalidFeeAssetId(..) => {
+                ValidationErrorCode::new(2205, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
+}
+
+/// Schedules the recovery of a bridge account whose sudo or withdrawer keys were lost.
+///
+/// Only the sudo address of the chain can send this action. The new addresses are assigned once
+/// the recovery delay of the chain has passed, unless the bridge account's current sudo address
+/// vetoes the recovery with a [`BridgeRecoveryVetoAction`] before then.
 #[allow(clippy::module_name_repetitions)]
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub struct BridgeUnlockAction {
-    pub to: Address,
-    pub amount: u128,
-    // asset to use for fee payment.
-    pub fee_asset_id: asset::Id,
-    // memo for double spend protection.
-    pub memo: Vec<u8>,
-    // the address of the bridge account to transfer from,
-    // if the bridge account's withdrawer address is not the same as the bridge address.
-    // if unset, the signer of the transaction is used.
-    pub bridge_address: Option<Address>,
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::BridgeRecoveryAction",
+        try_from = "raw::BridgeRecoveryAction"
+    )
+)]
+pub struct BridgeRecoveryAction {
+    pub bridge_address: Address,
+    pub new_sudo_address: Option<Address>,
+    pub new_withdrawer_address: Option<Address>,
 }
 
-impl BridgeUnlockAction {
+impl From<BridgeRecoveryAction> for raw::BridgeRecoveryAction {
+    fn from(value: BridgeRecoveryAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::BridgeRecoveryAction> for BridgeRecoveryAction {
+    type Error = BridgeRecoveryActionError;
+
+    fn try_from(raw: raw::BridgeRecoveryAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl BridgeRecoveryAction {
+    /// Returns a builder of a bridge recovery action, which can only be built once all required
+    /// fields are set.
+    #[must_use = "the builder must be built to construct a bridge recovery action to be useful"]
+    pub fn builder() -> BridgeRecoveryActionBuilder {
+        BridgeRecoveryActionBuilder::new()
+    }
+
     #[must_use]
-    pub fn into_raw(self) -> raw::BridgeUnlockAction {
-        raw::BridgeUnlockAction {
-            to: Some(self.to.to_raw()),
-            amount: Some(self.amount.into()),
-            fee_asset_id: self.fee_asset_id.as_ref().to_vec(),
-            memo: self.memo,
-            bridge_address: self.bridge_address.map(Address::into_raw),
+    pub fn into_raw(self) -> raw::BridgeRecoveryAction {
+        raw::BridgeRecoveryAction {
+            bridge_address: Some(self.bridge_address.into_raw()),
+            new_sudo_address: self.new_sudo_address.map(Address::into_raw),
+            new_withdrawer_address: self.new_withdrawer_address.map(Address::into_raw),
         }
     }
 
     #[must_use]
-    pub fn to_raw(&self) -> raw::BridgeUnlockAction {
-        raw::BridgeUnlockAction {
-            to: Some(self.to.to_raw()),
-            amount: Some(self.amount.into()),
-            fee_asset_id: self.fee_asset_id.as_ref().to_vec(),
-            memo: self.memo.clone(),
-            bridge_address: self.bridge_address.as_ref().map(Address::to_raw),
+    pub fn to_raw(&self) -> raw::BridgeRecoveryAction {
+        raw::BridgeRecoveryAction {
+            bridge_address: Some(self.bridge_address.to_raw()),
+            new_sudo_address: self.new_sudo_address.as_ref().map(Address::to_raw),
+            new_withdrawer_address: self.new_withdrawer_address.as_ref().map(Address::to_raw),
         }
     }
 
-    /// Convert from a raw, unchecked protobuf [`raw::BridgeUnlockAction`].
+    /// Convert from a raw, unchecked protobuf [`raw::BridgeRecoveryAction`].
     ///
     /// # Errors
     ///
-    /// - if the `to` field is not set
-    /// - if the `to` field is invalid
-    /// - if the `amount` field is invalid
-    /// - if the `fee_asset_id` field is invalid
-    /// - if the `from` field is invalid
-    pub fn try_from_raw(proto: raw::BridgeUnlockAction) -> Result<Self, BridgeUnlockActionError> {
-        let Some(to) = proto.to else {
-            return Err(BridgeUnlockActionError::field_not_set("to"));
+    /// - if the `bridge_address` field is not set
+    /// - if the `bridge_address` field is invalid
+    /// - if the `new_sudo_address` field is invalid
+    /// - if the `new_withdrawer_address` field is invalid
+    pub fn try_from_raw(
+        proto: raw::BridgeRecoveryAction,
+    ) -> Result<Self, BridgeRecoveryActionError> {
+        let Some(bridge_address) = proto.bridge_address else {
+            return Err(BridgeRecoveryActionError::field_not_set("bridge_address"));
         };
-        let to = Address::try_from_raw(&to).map_err(BridgeUnlockActionError::address)?;
-        let amount = proto
-            .amount
-            .ok_or(BridgeUnlockActionError::missing_amount())?;
-        let fee_asset_id = asset::Id::try_from_slice(&proto.fee_asset_id)
-            .map_err(BridgeUnlockActionError::invalid_fee_asset_id)?;
-        let bridge_address = proto
-            .bridge_address
+        let bridge_address = Address::try_from_raw(&bridge_address)
+            .map_err(BridgeRecoveryActionError::invalid_bridge_address)?;
+        let new_sudo_address = proto
+            .new_sudo_address
             .as_ref()
             .map(Address::try_from_raw)
             .transpose()
-            .map_err(BridgeUnlockActionError::invalid_bridge_address)?;
+            .map_err(BridgeRecoveryActionError::invalid_new_sudo_address)?;
+        let new_withdrawer_address = proto
+            .new_withdrawer_address
+            .as_ref()
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(BridgeRecoveryActionError::invalid_new_withdrawer_address)?;
+
         Ok(Self {
-            to,
-            amount: amount.into(),
-            fee_asset_id,
-            memo: proto.memo,
             bridge_address,
+            new_sudo_address,
+            new_withdrawer_address,
         })
     }
 }
 
-#[derive(Debug, thiserror::Error)]
-#[error(transparent)]
-pub struct BridgeUnlockActionError(BridgeUnlockActionErrorKind);
+/// A builder of [`BridgeRecoveryAction`], returned by [`BridgeRecoveryAction::builder`].
+///
+/// [`BridgeRecoveryActionBuilder::build`] is only available once all required fields are set.
+pub struct BridgeRecoveryActionBuilder<TBridgeAddress = Unset> {
+    bridge_address: TBridgeAddress,
+    new_sudo_address: Option<Address>,
+    new_withdrawer_address: Option<Address>,
+}
 
-impl BridgeUnlockActionError {
+impl BridgeRecoveryActionBuilder {
+    fn new() -> Self {
+        Self {
+            bridge_address: Unset,
+            new_sudo_address: None,
+            new_withdrawer_address: None,
+        }
+    }
+}
+
+impl<TBridgeAddress> BridgeRecoveryActionBuilder<TBridgeAddress> {
+    #[must_use = "the builder must be built to construct a bridge recovery action to be useful"]
+    pub fn bridge_address(self, bridge_address: Address) -> BridgeRecoveryActionBuilder<Address> {
+        BridgeRecoveryActionBuilder {
+            bridge_address,
+            new_sudo_address: self.new_sudo_address,
+            new_withdrawer_address: self.new_withdrawer_address,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a bridge recovery action to be useful"]
+    pub fn new_sudo_address(self, new_sudo_address: Address) -> Self {
+        Self {
+            new_sudo_address: Some(new_sudo_address),
+            ..self
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a bridge recovery action to be useful"]
+    pub fn new_withdrawer_address(self, new_withdrawer_address: Address) -> Self {
+        Self {
+            new_withdrawer_address: Some(new_withdrawer_address),
+            ..self
+        }
+    }
+}
+
+impl BridgeRecoveryActionBuilder<Address> {
+    /// Constructs a bridge recovery action from the configured builder.
     #[must_use]
-    fn field_not_set(field: &'static str) -> Self {
-        Self(BridgeUnlockActionErrorKind::FieldNotSet(field))
+    pub fn build(self) -> BridgeRecoveryAction {
+        let Self {
+            bridge_address,
+            new_sudo_address,
+            new_withdrawer_address,
+        } = self;
+        BridgeRecoveryAction {
+            bridge_address,
+            new_sudo_address,
+            new_withdrawer_address,
+        }
     }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BridgeRecoveryActionError(BridgeRecoveryActionErrorKind);
 
+impl BridgeRecoveryActionError {
     #[must_use]
-    fn address(source: AddressError) -> Self {
-        Self(BridgeUnlockActionErrorKind::Address {
-            source,
-        })
+    fn field_not_set(field: &'static str) -> Self {
+        Self(BridgeRecoveryActionErrorKind::FieldNotSet(field))
     }
 
     #[must_use]
-    fn missing_amount() -> Self {
-        Self(BridgeUnlockActionErrorKind::MissingAmount)
+    fn invalid_bridge_address(err: AddressError) -> Self {
+        Self(BridgeRecoveryActionErrorKind::InvalidBridgeAddress(err))
     }
 
     #[must_use]
-    fn invalid_fee_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
-        Self(BridgeUnlockActionErrorKind::InvalidFeeAssetId(err))
+    fn invalid_new_sudo_address(err: AddressError) -> Self {
+        Self(BridgeRecoveryActionErrorKind::InvalidNewSudoAddress(err))
     }
 
     #[must_use]
-    fn invalid_bridge_address(err: AddressError) -> Self {
-        Self(BridgeUnlockActionErrorKind::InvalidBridgeAddress(err))
+    fn invalid_new_withdrawer_address(err: AddressError) -> Self {
+        Self(BridgeRecoveryActionErrorKind::InvalidNewWithdrawerAddress(err))
     }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum BridgeUnlockActionErrorKind {
+enum BridgeRecoveryActionErrorKind {
     #[error("the expected field in the raw source type was not set: `{0}`")]
     FieldNotSet(&'static str),
-    #[error("the `to` field was invalid")]
-    Address { source: AddressError },
-    #[error("the `amount` field was not set")]
-    MissingAmount,
-    #[error("the `fee_asset_id` field was invalid")]
-    InvalidFeeAssetId(#[source] asset::IncorrectAssetIdLength),
     #[error("the `bridge_address` field was invalid")]
     InvalidBridgeAddress(#[source] AddressError),
+    #[error("the `new_sudo_address` field was invalid")]
+    InvalidNewSudoAddress(#[source] AddressError),
+    #[error("the `new_withdrawer_address` field was invalid")]
+    InvalidNewWithdrawerAddress(#[source] AddressError),
+}
+
+impl ValidationError for BridgeRecoveryActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            BridgeRecoveryActionErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(2801, ValidationErrorCategory::MissingField)
+            }
+            BridgeRecoveryActionErrorKind::InvalidBridgeAddress(..) => {
+                ValidationErrorCode::new(2802, ValidationErrorCategory::InvalidField)
+            }
+            BridgeRecoveryActionErrorKind::InvalidNewSudoAddress(..) => {
+                ValidationErrorCode::new(2803, ValidationErrorCategory::InvalidField)
+            }
+            BridgeRecoveryActionErrorKind::InvalidNewWithdrawerAddress(..) => {
+                ValidationErrorCode::new(2804, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
 }
 
+/// Cancels the pending recovery of a bridge account.
+///
+/// Only the bridge account's current sudo address can send this action.
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone)]
-pub struct BridgeSudoChangeAction {
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::BridgeRecoveryVetoAction",
+        try_from = "raw::BridgeRecoveryVetoAction"
+    )
+)]
+pub struct BridgeRecoveryVetoAction {
     pub bridge_address: Address,
-    pub new_sudo_address: Option<Address>,
-    pub new_withdrawer_address: Option<Address>,
     pub fee_asset_id: asset::Id,
 }
 
-impl BridgeSudoChangeAction {
+impl From<BridgeRecoveryVetoAction> for raw::BridgeRecoveryVetoAction {
+    fn from(value: BridgeRecoveryVetoAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::BridgeRecoveryVetoAction> for BridgeRecoveryVetoAction {
+    type Error = BridgeRecoveryVetoActionError;
+
+    fn try_from(raw: raw::BridgeRecoveryVetoAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl BridgeRecoveryVetoAction {
     #[must_use]
-    pub fn into_raw(self) -> raw::BridgeSudoChangeAction {
-        raw::BridgeSudoChangeAction {
-            bridge_address: Some(self.bridge_address.to_raw()),
-            new_sudo_address: self.new_sudo_address.map(Address::into_raw),
-            new_withdrawer_address: self.new_withdrawer_address.map(Address::into_raw),
+    pub fn into_raw(self) -> raw::BridgeRecoveryVetoAction {
+        raw::BridgeRecoveryVetoAction {
+            bridge_address: Some(self.bridge_address.into_raw()),
             fee_asset_id: self.fee_asset_id.get().to_vec(),
         }
     }
 
     #[must_use]
-    pub fn to_raw(&self) -> raw::BridgeSudoChangeAction {
-        raw::BridgeSudoChangeAction {
+    pub fn to_raw(&self) -> raw::BridgeRecoveryVetoAction {
+        raw::BridgeRecoveryVetoAction {
             bridge_address: Some(self.bridge_address.to_raw()),
-            new_sudo_address: self.new_sudo_address.as_ref().map(Address::to_raw),
-            new_withdrawer_address: self.new_withdrawer_address.as_ref().map(Address::to_raw),
             fee_asset_id: self.fee_asset_id.get().to_vec(),
         }
     }
 
-    /// Convert from a raw, unchecked protobuf [`raw::BridgeSudoChangeAction`].
+    /// Convert from a raw, unchecked protobuf [`raw::BridgeRecoveryVetoAction`].
     ///
     /// # Errors
     ///
     /// - if the `bridge_address` field is not set
     /// - if the `bridge_address` field is invalid
-    /// - if the `new_sudo_address` field is invalid
-    /// - if the `new_withdrawer_address` field is invalid
     /// - if the `fee_asset_id` field is invalid
     pub fn try_from_raw(
-        proto: raw::BridgeSudoChangeAction,
-    ) -> Result<Self, BridgeSudoChangeActionError> {
+        proto: raw::BridgeRecoveryVetoAction,
+    ) -> Result<Self, BridgeRecoveryVetoActionError> {
         let Some(bridge_address) = proto.bridge_address else {
-            return Err(BridgeSudoChangeActionError::field_not_set("bridge_address"));
+            return Err(BridgeRecoveryVetoActionError::field_not_set(
+                "bridge_address",
+            ));
         };
         let bridge_address = Address::try_from_raw(&bridge_address)
-            .map_err(BridgeSudoChangeActionError::invalid_bridge_address)?;
-        let new_sudo_address = proto
-            .new_sudo_address
-            .as_ref()
-            .map(Address::try_from_raw)
-            .transpose()
-            .map_err(BridgeSudoChangeActionError::invalid_new_sudo_address)?;
-        let new_withdrawer_address = proto
-            .new_withdrawer_address
-            .as_ref()
-            .map(Address::try_from_raw)
-            .transpose()
-            .map_err(BridgeSudoChangeActionError::invalid_new_withdrawer_address)?;
+            .map_err(BridgeRecoveryVetoActionError::invalid_bridge_address)?;
         let fee_asset_id = asset::Id::try_from_slice(&proto.fee_asset_id)
-            .map_err(BridgeSudoChangeActionError::invalid_fee_asset_id)?;
+            .map_err(BridgeRecoveryVetoActionError::invalid_fee_asset_id)?;
 
         Ok(Self {
             bridge_address,
-            new_sudo_address,
-            new_withdrawer_address,
             fee_asset_id,
         })
     }
@@ -1514,49 +4326,51 @@ impl BridgeSudoChangeAction {
 
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
-pub struct BridgeSudoChangeActionError(BridgeSudoChangeActionErrorKind);
+pub struct BridgeRecoveryVetoActionError(BridgeRecoveryVetoActionErrorKind);
 
-impl BridgeSudoChangeActionError {
+impl BridgeRecoveryVetoActionError {
     #[must_use]
     fn field_not_set(field: &'static str) -> Self {
-        Self(BridgeSudoChangeActionErrorKind::FieldNotSet(field))
+        Self(BridgeRecoveryVetoActionErrorKind::FieldNotSet(field))
     }
 
     #[must_use]
     fn invalid_bridge_address(err: AddressError) -> Self {
-        Self(BridgeSudoChangeActionErrorKind::InvalidBridgeAddress(err))
-    }
-
-    #[must_use]
-    fn invalid_new_sudo_address(err: AddressError) -> Self {
-        Self(BridgeSudoChangeActionErrorKind::InvalidNewSudoAddress(err))
-    }
-
-    #[must_use]
-    fn invalid_new_withdrawer_address(err: AddressError) -> Self {
-        Self(BridgeSudoChangeActionErrorKind::InvalidNewWithdrawerAddress(err))
+        Self(BridgeRecoveryVetoActionErrorKind::InvalidBridgeAddress(err))
     }
 
     #[must_use]
     fn invalid_fee_asset_id(err: asset::IncorrectAssetIdLength) -> Self {
-        Self(BridgeSudoChangeActionErrorKind::InvalidFeeAssetId(err))
+        Self(BridgeRecoveryVetoActionErrorKind::InvalidFeeAssetId(err))
     }
 }
 
 #[derive(Debug, thiserror::Error)]
-enum BridgeSudoChangeActionErrorKind {
+enum BridgeRecoveryVetoActionErrorKind {
     #[error("the expected field in the raw source type was not set: `{0}`")]
     FieldNotSet(&'static str),
     #[error("the `bridge_address` field was invalid")]
     InvalidBridgeAddress(#[source] AddressError),
-    #[error("the `new_sudo_address` field was invalid")]
-    InvalidNewSudoAddress(#[source] AddressError),
-    #[error("the `new_withdrawer_address` field was invalid")]
-    InvalidNewWithdrawerAddress(#[source] AddressError),
     #[error("the `fee_asset_id` field was invalid")]
     InvalidFeeAssetId(#[source] asset::IncorrectAssetIdLength),
 }
 
+impl ValidationError for BridgeRecoveryVetoActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            BridgeRecoveryVetoActionErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(2901, ValidationErrorCategory::MissingField)
+            }
+            BridgeRecoveryVetoActionErrorKind::InvalidBridgeAddress(..) => {
+                ValidationErrorCode::new(2902, ValidationErrorCategory::InvalidField)
+            }
+            BridgeRecoveryVetoActionErrorKind::InvalidFeeAssetId(..) => {
+                ValidationErrorCode::new(2903, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum FeeChange {
     TransferBaseFee,
@@ -1570,12 +4384,38 @@ pub enum FeeChange {
 
 #[allow(clippy::module_name_repetitions)]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::FeeChangeAction", try_from = "raw::FeeChangeAction")
+)]
 pub struct FeeChangeAction {
     pub fee_change: FeeChange,
     pub new_value: u128,
 }
 
+impl From<FeeChangeAction> for raw::FeeChangeAction {
+    fn from(value: FeeChangeAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::FeeChangeAction> for FeeChangeAction {
+    type Error = FeeChangeActionError;
+
+    fn try_from(raw: raw::FeeChangeAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(&raw)
+    }
+}
+
 impl FeeChangeAction {
+    /// Returns a builder of a fee change action, which can only be built once all required fields
+    /// are set.
+    #[must_use = "the builder must be built to construct a fee change action to be useful"]
+    pub fn builder() -> FeeChangeActionBuilder {
+        FeeChangeActionBuilder::new()
+    }
+
     #[must_use]
     pub fn into_raw(self) -> raw::FeeChangeAction {
         self.to_raw()
@@ -1651,6 +4491,56 @@ impl FeeChangeAction {
     }
 }
 
+/// A builder of [`FeeChangeAction`], returned by [`FeeChangeAction::builder`].
+///
+/// [`FeeChangeActionBuilder::build`] is only available once all required fields are set.
+pub struct FeeChangeActionBuilder<TFeeChange = Unset, TNewValue = Unset> {
+    fee_change: TFeeChange,
+    new_value: TNewValue,
+}
+
+impl FeeChangeActionBuilder {
+    fn new() -> Self {
+        Self {
+            fee_change: Unset,
+            new_value: Unset,
+        }
+    }
+}
+
+impl<TFeeChange, TNewValue> FeeChangeActionBuilder<TFeeChange, TNewValue> {
+    #[must_use = "the builder must be built to construct a fee change action to be useful"]
+    pub fn fee_change(self, fee_change: FeeChange) -> FeeChangeActionBuilder<FeeChange, TNewValue> {
+        FeeChangeActionBuilder {
+            fee_change,
+            new_value: self.new_value,
+        }
+    }
+
+    #[must_use = "the builder must be built to construct a fee change action to be useful"]
+    pub fn new_value(self, new_value: u128) -> FeeChangeActionBuilder<TFeeChange, u128> {
+        FeeChangeActionBuilder {
+            fee_change: self.fee_change,
+            new_value,
+        }
+    }
+}
+
+impl FeeChangeActionBuilder<FeeChange, u128> {
+    /// Constructs a fee change action from the configured builder.
+    #[must_use]
+    pub fn build(self) -> FeeChangeAction {
+        let Self {
+            fee_change,
+            new_value,
+        } = self;
+        FeeChangeAction {
+            fee_change,
+            new_value,
+        }
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
 pub struct FeeChangeActionError(FeeChangeActionErrorKind);
@@ -1666,3 +4556,171 @@ enum FeeChangeActionErrorKind {
     #[error("the value which to change was missing")]
     MissingValueToChange,
 }
+
+impl ValidationError for FeeChangeActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            FeeChangeActionErrorKind::MissingValueToChange => {
+                ValidationErrorCode::new(2301, ValidationErrorCategory::MissingField)
+            }
+        }
+    }
+}
+
+/// Sets how the fees collected in a block are distributed at the end of the block.
+///
+/// Only the sudo address of the chain can send this action. The shares are percentages of the
+/// fees collected in each asset and sum to 100.
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::FeeDistributionChangeAction",
+        try_from = "raw::FeeDistributionChangeAction"
+    )
+)]
+pub struct FeeDistributionChangeAction {
+    pub proposer_share: u32,
+    pub treasury_share: u32,
+    pub burn_share: u32,
+    pub treasury_address: Option<Address>,
+}
+
+impl From<FeeDistributionChangeAction> for raw::FeeDistributionChangeAction {
+    fn from(value: FeeDistributionChangeAction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::FeeDistributionChangeAction> for FeeDistributionChangeAction {
+    type Error = FeeDistributionChangeActionError;
+
+    fn try_from(raw: raw::FeeDistributionChangeAction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl FeeDistributionChangeAction {
+    #[must_use]
+    pub fn into_raw(self) -> raw::FeeDistributionChangeAction {
+        raw::FeeDistributionChangeAction {
+            proposer_share: self.proposer_share,
+            treasury_share: self.treasury_share,
+            burn_share: self.burn_share,
+            treasury_address: self.treasury_address.map(Address::into_raw),
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::FeeDistributionChangeAction {
+        raw::FeeDistributionChangeAction {
+            proposer_share: self.proposer_share,
+            treasury_share: self.treasury_share,
+            burn_share: self.burn_share,
+            treasury_address: self.treasury_address.as_ref().map(Address::to_raw),
+        }
+    }
+
+    /// Convert from a raw, unchecked protobuf [`raw::FeeDistributionChangeAction`].
+    ///
+    /// # Errors
+    ///
+    /// - if the `treasury_address` field is invalid
+    /// - if the shares do not sum to 100
+    /// - if the `treasury_share` is not zero but the `treasury_address` field is not set
+    pub fn try_from_raw(
+        proto: raw::FeeDistributionChangeAction,
+    ) -> Result<Self, FeeDistributionChangeActionError> {
+        let raw::FeeDistributionChangeAction {
+            proposer_share,
+            treasury_share,
+            burn_share,
+            treasury_address,
+        } = proto;
+        let treasury_address = treasury_address
+            .as_ref()
+            .map(Address::try_from_raw)
+            .transpose()
+            .map_err(FeeDistributionChangeActionError::invalid_treasury_address)?;
+        let total = proposer_share
+            .checked_add(treasury_share)
+            .and_then(|total| total.checked_add(burn_share));
+        if total != Some(100) {
+            return Err(FeeDistributionChangeActionError::shares_do_not_sum_to_100(
+                proposer_share,
+                treasury_share,
+                burn_share,
+            ));
+        }
+        if treasury_share > 0 && treasury_address.is_none() {
+            return Err(FeeDistributionChangeActionError::missing_treasury_address());
+        }
+
+        Ok(Self {
+            proposer_share,
+            treasury_share,
+            burn_share,
+            treasury_address,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct FeeDistributionChangeActionError(FeeDistributionChangeActionErrorKind);
+
+impl FeeDistributionChangeActionError {
+    #[must_use]
+    fn invalid_treasury_address(err: AddressError) -> Self {
+        Self(FeeDistributionChangeActionErrorKind::InvalidTreasuryAddress(err))
+    }
+
+    #[must_use]
+    fn shares_do_not_sum_to_100(proposer: u32, treasury: u32, burn: u32) -> Self {
+        Self(FeeDistributionChangeActionErrorKind::SharesDoNotSumTo100 {
+            proposer,
+            treasury,
+            burn,
+        })
+    }
+
+    #[must_use]
+    fn missing_treasury_address() -> Self {
+        Self(FeeDistributionChangeActionErrorKind::MissingTreasuryAddress)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum FeeDistributionChangeActionErrorKind {
+    #[error("the `treasury_address` field was invalid")]
+    InvalidTreasuryAddress(#[source] AddressError),
+    #[error(
+        "the proposer share `{proposer}`, treasury share `{treasury}` and burn share `{burn}` do \
+         not sum to 100"
+    )]
+    SharesDoNotSumTo100 {
+        proposer: u32,
+        treasury: u32,
+        burn: u32,
+    },
+    #[error("the `treasury_share` was not zero, but the `treasury_address` field was not set")]
+    MissingTreasuryAddress,
+}
+
+impl ValidationError for FeeDistributionChangeActionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            FeeDistributionChangeActionErrorKind::InvalidTreasuryAddress(..) => {
+                ValidationErrorCode::new(3401, ValidationErrorCategory::InvalidField)
+            }
+            FeeDistributionChangeActionErrorKind::SharesDoNotSumTo100 {
+                ..
+            } => ValidationErrorCode::new(3402, ValidationErrorCategory::InvalidField),
+            FeeDistributionChangeActionErrorKind::MissingTreasuryAddress => {
+                ValidationErrorCode::new(3403, ValidationErrorCategory::MissingField)
+            }
+        }
+    }
+}
@@ -0,0 +1,232 @@
+//! A canonical JSON encoding of [`UnsignedTransaction`] for off-chain signers.
+//!
+//! Hardware wallets and threshold signers display the transaction they are asked to sign, and so
+//! need an encoding that is both human-readable and byte-for-byte reproducible. The canonical JSON
+//! of a transaction is the protobuf JSON encoding of its [`raw::UnsignedTransaction`] (with proto
+//! field names and default values omitted), with the keys of all objects sorted by their UTF-8
+//! bytes and without any insignificant whitespace.
+//!
+//! The encoding is part of the protocol: it must not change for a given transaction, which is
+//! guaranteed by the test vectors below.
+
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+
+use super::{
+    raw,
+    UnsignedTransaction,
+};
+use crate::validation::{
+    ValidationError,
+    ValidationErrorCategory,
+    ValidationErrorCode,
+};
+
+/// The domain separator prepended to the canonical JSON before hashing it.
+///
+/// It ensures that the hash of a canonical JSON transaction can never collide with the hash of a
+/// message of a different type or of a transaction of a different protocol version.
+pub const CANONICAL_JSON_DOMAIN: &[u8] =
+    b"astria.protocol.transactions.v1alpha1.UnsignedTransaction/canonical-json\n";
+
+impl UnsignedTransaction {
+    /// Returns the canonical JSON encoding of the transaction.
+    ///
+    /// See the [module level documentation](self) for its definition.
+    #[must_use]
+    pub fn to_canonical_json(&self) -> String {
+        let value = serde_json::to_value(self.to_raw())
+            .expect("protobuf JSON serialization of an unsigned transaction is infallible");
+        let mut out = String::new();
+        write_canonical(&value, &mut out);
+        out
+    }
+
+    /// Returns the domain-separated sha256 hash of the canonical JSON of the transaction.
+    ///
+    /// This is `sha256(CANONICAL_JSON_DOMAIN || canonical_json)`, see [`CANONICAL_JSON_DOMAIN`].
+    #[must_use]
+    pub fn sha256_of_canonical_json(&self) -> [u8; 32] {
+        let mut hasher = Sha256::new();
+        hasher.update(CANONICAL_JSON_DOMAIN);
+        hasher.update(self.to_canonical_json().as_bytes());
+        hasher.finalize().into()
+    }
+}
+
+/// Attempts to parse `json` as a canonical JSON transaction.
+///
+/// # Errors
+///
+/// Returns an error if `json` is not the JSON encoding of a [`raw::UnsignedTransaction`], if it
+/// cannot be converted to a native [`UnsignedTransaction`], or if `json` is not in canonical form.
+pub fn try_from_canonical_json(json: &str) -> Result<UnsignedTransaction, CanonicalJsonError> {
+    let raw: raw::UnsignedTransaction =
+        serde_json::from_str(json).map_err(CanonicalJsonError::json)?;
+    let tx = UnsignedTransaction::try_from_raw(raw).map_err(CanonicalJsonError::transaction)?;
+    if tx.to_canonical_json() != json {
+        return Err(CanonicalJsonError::not_canonical());
+    }
+    Ok(tx)
+}
+
+/// Writes `value` to `out` with sorted object keys and without whitespace.
+fn write_canonical(value: &serde_json::Value, out: &mut String) {
+    use serde_json::Value;
+    match value {
+        Value::Null => out.push_str("null"),
+        Value::Bool(b) => out.push_str(if *b { "true" } else { "false" }),
+        Value::Number(n) => out.push_str(&n.to_string()),
+        Value::String(s) => write_string(s, out),
+        Value::Array(items) => {
+            out.push('[');
+            for (i, item) in items.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_canonical(item, out);
+            }
+            out.push(']');
+        }
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_unstable_by(|(a, _), (b, _)| a.as_bytes().cmp(b.as_bytes()));
+            out.push('{');
+            for (i, (key, value)) in entries.into_iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                write_string(key, out);
+                out.push(':');
+                write_canonical(value, out);
+            }
+            out.push('}');
+        }
+    }
+}
+
+fn write_string(s: &str, out: &mut String) {
+    out.push_str(&serde_json::to_string(s).expect("serializing a string to JSON is infallible"));
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct CanonicalJsonError(CanonicalJsonErrorKind);
+
+impl CanonicalJsonError {
+    fn json(inner: serde_json::Error) -> Self {
+        Self(CanonicalJsonErrorKind::Json(inner))
+    }
+
+    fn transaction(inner: super::UnsignedTransactionError) -> Self {
+        Self(CanonicalJsonErrorKind::Transaction(inner))
+    }
+
+    fn not_canonical() -> Self {
+        Self(CanonicalJsonErrorKind::NotCanonical)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum CanonicalJsonErrorKind {
+    #[error("failed to parse the JSON as an unsigned transaction")]
+    Json(#[source] serde_json::Error),
+    #[error("the JSON does not contain a valid unsigned transaction")]
+    Transaction(#[source] super::UnsignedTransactionError),
+    #[error("the JSON is a valid unsigned transaction, but not in canonical form")]
+    NotCanonical,
+}
+
+impl ValidationError for CanonicalJsonError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            CanonicalJsonErrorKind::Json(..) => {
+                ValidationErrorCode::new(2401, ValidationErrorCategory::Decode)
+            }
+            CanonicalJsonErrorKind::Transaction(source) => source.validation_code(),
+            CanonicalJsonErrorKind::NotCanonical => {
+                ValidationErrorCode::new(2403, ValidationErrorCategory::Decode)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        primitive::v1::{
+            asset,
+            Address,
+            RollupId,
+        },
+        protocol::transaction::v1alpha1::{
+            action::{
+                SequenceAction,
+                TransferAction,
+            },
+            TransactionParams,
+        },
+    };
+
+    fn transaction() -> UnsignedTransaction {
+        UnsignedTransaction {
+            actions: vec![
+                TransferAction {
+                    to: Address::builder()
+                        .array([0; 20])
+                        .prefix("astria")
+                        .try_build()
+                        .unwrap(),
+                    amount: 10,
+                    asset_id: asset::Id::new([1; 32]),
+                    fee_asset_id: asset::Id::new([2; 32]),
+                }
+                .into(),
+                SequenceAction {
+                    rollup_id: RollupId::new([3; 32]),
+                    data: b"hello".to_vec(),
+                    fee_asset_id: asset::Id::new([2; 32]),
+                }
+                .into(),
+            ],
+            params: TransactionParams::builder()
+                .nonce(7)
+                .chain_id("astria-1")
+                .build(),
+        }
+    }
+
+    // These are test vectors: if this test fails, the canonical encoding changed, which
+    // invalidates signatures made by off-chain signers.
+    const EXPECTED_JSON: &str = r#"{"actions":[{"transfer_action":{"amount":{"lo":"10"},"asset_id":"AQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQEBAQE=","fee_asset_id":"AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI=","to":{"bech32m":"astria1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq007erg"}}},{"sequence_action":{"data":"aGVsbG8=","fee_asset_id":"AgICAgICAgICAgICAgICAgICAgICAgICAgICAgICAgI=","rollup_id":{"inner":"AwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwMDAwM="}}}],"params":{"chain_id":"astria-1","nonce":7}}"#;
+    const EXPECTED_HASH: &str = "32b140e7645dd9971f17f24e61ceed3c6698e6a64b107c65e39cdf45bb803185";
+
+    #[test]
+    fn canonical_json_matches_test_vector() {
+        assert_eq!(EXPECTED_JSON, transaction().to_canonical_json());
+    }
+
+    #[test]
+    fn canonical_json_hash_matches_test_vector() {
+        assert_eq!(
+            EXPECTED_HASH,
+            hex::encode(transaction().sha256_of_canonical_json())
+        );
+    }
+
+    #[test]
+    fn canonical_json_roundtrips() {
+        let tx = try_from_canonical_json(EXPECTED_JSON).unwrap();
+        assert_eq!(transaction().to_raw(), tx.to_raw());
+    }
+
+    #[test]
+    fn non_canonical_json_is_rejected() {
+        let pretty = serde_json::to_string_pretty(&transaction().to_raw()).unwrap();
+        let err = try_from_canonical_json(&pretty).unwrap_err();
+        assert!(matches!(err.0, CanonicalJsonErrorKind::NotCanonical));
+    }
+}
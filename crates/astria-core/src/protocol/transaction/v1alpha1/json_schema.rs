@@ -0,0 +1,682 @@
+//! JSON Schemas of the protobuf JSON encoding of transactions.
+//!
+//! External tooling like the CLI or web wallets can use these schemas to validate a transaction
+//! before submitting it. The schemas follow JSON Schema draft 2020-12 and describe the encoding
+//! emitted by the serde implementations of [`raw::SignedTransaction`] and
+//! [`raw::UnsignedTransaction`]: proto field names, 64 bit integers as decimal strings (numbers
+//! are accepted as well), and bytes as base64 strings. Note that the deserializers additionally
+//! accept camelCase field names, which the schemas reject.
+//!
+//! Types defined outside of astria, namely penumbra's IBC relay messages and tendermint's
+//! validator updates, are not constrained by the schemas.
+//!
+//! [`raw::SignedTransaction`]: super::raw::SignedTransaction
+//! [`raw::UnsignedTransaction`]: super::raw::UnsignedTransaction
+
+use serde_json::{
+    json,
+    Map,
+    Value,
+};
+
+/// The JSON Schema dialect the schemas are written in.
+pub const SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Returns the JSON Schema of a [`raw::SignedTransaction`](super::raw::SignedTransaction).
+#[must_use]
+pub fn transaction() -> Value {
+    document(&SIGNED_TRANSACTION)
+}
+
+/// Returns the JSON Schema of a [`raw::UnsignedTransaction`](super::raw::UnsignedTransaction),
+/// the body of a transaction.
+#[must_use]
+pub fn transaction_body() -> Value {
+    document(&UNSIGNED_TRANSACTION)
+}
+
+/// Returns all schemas exported by this module, keyed by the full protobuf name of the type they
+/// describe.
+#[must_use]
+pub fn all() -> Vec<(&'static str, Value)> {
+    [&SIGNED_TRANSACTION, &UNSIGNED_TRANSACTION]
+        .into_iter()
+        .map(|message| (message.full_name, document(message)))
+        .collect()
+}
+
+#[derive(Clone, Copy)]
+enum Kind {
+    Bytes,
+    String,
+    Uint32,
+    Uint64,
+    Message(&'static MessageDef),
+    /// A message defined outside of astria whose encoding is not constrained.
+    External(&'static str),
+}
+
+struct FieldDef {
+    name: &'static str,
+    kind: Kind,
+    repeated: bool,
+}
+
+struct MessageDef {
+    full_name: &'static str,
+    fields: &'static [FieldDef],
+    /// Whether the fields of the message form a single `oneof`, so that at most one is set.
+    is_oneof: bool,
+}
+
+const fn field(name: &'static str, kind: Kind) -> FieldDef {
+    FieldDef {
+        name,
+        kind,
+        repeated: false,
+    }
+}
+
+const fn repeated(name: &'static str, kind: Kind) -> FieldDef {
+    FieldDef {
+        name,
+        kind,
+        repeated: true,
+    }
+}
+
+/// Builds a self-contained schema document with `root` at its top level and all messages it
+/// references under `$defs`.
+fn document(root: &'static MessageDef) -> Value {
+    let mut defs = Map::new();
+    collect_defs(root, &mut defs);
+    json!({
+        "$schema": SCHEMA_DIALECT,
+        "title": root.full_name,
+        "$ref": reference(root),
+        "$defs": defs,
+    })
+}
+
+fn collect_defs(message: &'static MessageDef, defs: &mut Map<String, Value>) {
+    if defs.contains_key(message.full_name) {
+        return;
+    }
+    defs.insert(message.full_name.to_string(), message_schema(message));
+    for field in message.fields {
+        if let Kind::Message(referenced) = field.kind {
+            collect_defs(referenced, defs);
+        }
+    }
+}
+
+fn reference(message: &MessageDef) -> String {
+    format!("#/$defs/{}", message.full_name)
+}
+
+fn message_schema(message: &MessageDef) -> Value {
+    let properties: Map<String, Value> = message
+        .fields
+        .iter()
+        .map(|field| (field.name.to_string(), field_schema(field)))
+        .collect();
+    let mut schema = json!({
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+    });
+    if message.is_oneof {
+        schema["maxProperties"] = json!(1);
+    }
+    schema
+}
+
+fn field_schema(field: &FieldDef) -> Value {
+    let schema = match field.kind {
+        Kind::Bytes => json!({
+            "type": "string",
+            "contentEncoding": "base64",
+        }),
+        Kind::String => json!({
+            "type": "string",
+        }),
+        Kind::Uint32 => json!({
+            "type": "integer",
+            "minimum": 0,
+            "maximum": u32::MAX,
+        }),
+        Kind::Uint64 => json!({
+            "type": ["string", "integer"],
+            "pattern": "^[0-9]+$",
+            "minimum": 0,
+        }),
+        Kind::Message(message) => json!({
+            "$ref": reference(message),
+        }),
+        Kind::External(full_name) => json!({
+            "description": format!("the protobuf JSON encoding of `{full_name}`"),
+        }),
+    };
+    if field.repeated {
+        json!({
+            "type": "array",
+            "items": schema,
+        })
+    } else {
+        schema
+    }
+}
+
+// The definitions below mirror the protobuf definitions of the `astria.primitive.v1` and
+// `astria.protocol.transactions.v1alpha1` packages and must be updated together with them.
+
+// `pbjson_types` encodes the fields of well-known types in camelCase.
+static ANY: MessageDef = MessageDef {
+    full_name: "google.protobuf.Any",
+    fields: &[field("typeUrl", Kind::String), field("value", Kind::Bytes)],
+    is_oneof: false,
+};
+
+static ADDRESS: MessageDef = MessageDef {
+    full_name: "astria.primitive.v1.Address",
+    fields: &[field("bech32m", Kind::String)],
+    is_oneof: false,
+};
+
+static ROLLUP_ID: MessageDef = MessageDef {
+    full_name: "astria.primitive.v1.RollupId",
+    fields: &[field("inner", Kind::Bytes)],
+    is_oneof: false,
+};
+
+static UINT128: MessageDef = MessageDef {
+    full_name: "astria.primitive.v1.Uint128",
+    fields: &[field("lo", Kind::Uint64), field("hi", Kind::Uint64)],
+    is_oneof: false,
+};
+
+static SIGNED_TRANSACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.SignedTransaction",
+    fields: &[
+        field("signature", Kind::Bytes),
+        field("public_key", Kind::Bytes),
+        field("transaction", Kind::Message(&ANY)),
+    ],
+    is_oneof: false,
+};
+
+static UNSIGNED_TRANSACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.UnsignedTransaction",
+    fields: &[
+        repeated("actions", Kind::Message(&ACTION)),
+        field("params", Kind::Message(&TRANSACTION_PARAMS)),
+    ],
+    is_oneof: false,
+};
+
+static TRANSACTION_PARAMS: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.TransactionParams",
+    fields: &[
+        field("nonce", Kind::Uint32),
+        field("chain_id", Kind::String),
+    ],
+    is_oneof: false,
+};
+
+static ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.Action",
+    fields: &[
+        field("transfer_action", Kind::Message(&TRANSFER_ACTION)),
+        field("sequence_action", Kind::Message(&SEQUENCE_ACTION)),
+        field(
+            "allowance_change_action",
+            Kind::Message(&ALLOWANCE_CHANGE_ACTION),
+        ),
+        field("transfer_from_action", Kind::Message(&TRANSFER_FROM_ACTION)),
+        field(
+            "init_bridge_account_action",
+            Kind::Message(&INIT_BRIDGE_ACCOUNT_ACTION),
+        ),
+        field("bridge_lock_action", Kind::Message(&BRIDGE_LOCK_ACTION)),
+        field("bridge_unlock_action", Kind::Message(&BRIDGE_UNLOCK_ACTION)),
+        field(
+            "bridge_sudo_change_action",
+            Kind::Message(&BRIDGE_SUDO_CHANGE_ACTION),
+        ),
+        field(
+            "bridge_recovery_veto_action",
+            Kind::Message(&BRIDGE_RECOVERY_VETO_ACTION),
+        ),
+        field(
+            "ibc_action",
+            Kind::External("penumbra.core.component.ibc.v1.IbcRelay"),
+        ),
+        field("ics20_withdrawal", Kind::Message(&ICS20_WITHDRAWAL)),
+        field(
+            "sudo_address_change_action",
+            Kind::Message(&SUDO_ADDRESS_CHANGE_ACTION),
+        ),
+        field(
+            "validator_update_action",
+            Kind::External("tendermint.abci.ValidatorUpdate"),
+        ),
+        field(
+            "ibc_relayer_change_action",
+            Kind::Message(&IBC_RELAYER_CHANGE_ACTION),
+        ),
+        field(
+            "fee_asset_change_action",
+            Kind::Message(&FEE_ASSET_CHANGE_ACTION),
+        ),
+        field("fee_change_action", Kind::Message(&FEE_CHANGE_ACTION)),
+        field(
+            "address_prefix_change_action",
+            Kind::Message(&ADDRESS_PREFIX_CHANGE_ACTION),
+        ),
+        field(
+            "bridge_recovery_action",
+            Kind::Message(&BRIDGE_RECOVERY_ACTION),
+        ),
+        field(
+            "fee_distribution_change_action",
+            Kind::Message(&FEE_DISTRIBUTION_CHANGE_ACTION),
+        ),
+    ],
+    is_oneof: true,
+};
+
+static TRANSFER_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.TransferAction",
+    fields: &[
+        field("to", Kind::Message(&ADDRESS)),
+        field("amount", Kind::Message(&UINT128)),
+        field("asset_id", Kind::Bytes),
+        field("fee_asset_id", Kind::Bytes),
+    ],
+    is_oneof: false,
+};
+
+static SEQUENCE_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.SequenceAction",
+    fields: &[
+        field("rollup_id", Kind::Message(&ROLLUP_ID)),
+        field("data", Kind::Bytes),
+        field("fee_asset_id", Kind::Bytes),
+    ],
+    is_oneof: false,
+};
+
+static ALLOWANCE_CHANGE_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.AllowanceChangeAction",
+    fields: &[
+        field("spender", Kind::Message(&ADDRESS)),
+        field("amount", Kind::Message(&UINT128)),
+        field("asset_id", Kind::Bytes),
+        field("expiry_height", Kind::Uint64),
+        field("fee_asset_id", Kind::Bytes),
+    ],
+    is_oneof: false,
+};
+
+static TRANSFER_FROM_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.TransferFromAction",
+    fields: &[
+        field("owner", Kind::Message(&ADDRESS)),
+        field("to", Kind::Message(&ADDRESS)),
+        field("amount", Kind::Message(&UINT128)),
+        field("asset_id", Kind::Bytes),
+        field("fee_asset_id", Kind::Bytes),
+    ],
+    is_oneof: false,
+};
+
+static SUDO_ADDRESS_CHANGE_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.SudoAddressChangeAction",
+    fields: &[field("new_address", Kind::Message(&ADDRESS))],
+    is_oneof: false,
+};
+
+static ICS20_WITHDRAWAL: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.Ics20Withdrawal",
+    fields: &[
+        field("amount", Kind::Message(&UINT128)),
+        field("denom", Kind::String),
+        field("destination_chain_address", Kind::String),
+        field("return_address", Kind::Message(&ADDRESS)),
+        field("timeout_height", Kind::Message(&IBC_HEIGHT)),
+        field("timeout_time", Kind::Uint64),
+        field("source_channel", Kind::String),
+        field("fee_asset_id", Kind::Bytes),
+        field("memo", Kind::String),
+        field("bridge_address", Kind::Message(&ADDRESS)),
+    ],
+    is_oneof: false,
+};
+
+static IBC_HEIGHT: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.IbcHeight",
+    fields: &[
+        field("revision_number", Kind::Uint64),
+        field("revision_height", Kind::Uint64),
+    ],
+    is_oneof: false,
+};
+
+static IBC_RELAYER_CHANGE_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.IbcRelayerChangeAction",
+    fields: &[
+        field("addition", Kind::Message(&ADDRESS)),
+        field("removal", Kind::Message(&ADDRESS)),
+    ],
+    is_oneof: true,
+};
+
+static FEE_ASSET_CHANGE_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.FeeAssetChangeAction",
+    fields: &[
+        field("addition", Kind::Bytes),
+        field("removal", Kind::Bytes),
+    ],
+    is_oneof: true,
+};
+
+static ADDRESS_PREFIX_CHANGE_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.AddressPrefixChangeAction",
+    fields: &[
+        field("addition", Kind::String),
+        field("removal", Kind::String),
+    ],
+    is_oneof: true,
+};
+
+static INIT_BRIDGE_ACCOUNT_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.InitBridgeAccountAction",
+    fields: &[
+        field("rollup_id", Kind::Message(&ROLLUP_ID)),
+        field("asset_id", Kind::Bytes),
+        field("fee_asset_id", Kind::Bytes),
+        field("sudo_address", Kind::Message(&ADDRESS)),
+        field("withdrawer_address", Kind::Message(&ADDRESS)),
+    ],
+    is_oneof: false,
+};
+
+static BRIDGE_LOCK_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.BridgeLockAction",
+    fields: &[
+        field("to", Kind::Message(&ADDRESS)),
+        field("amount", Kind::Message(&UINT128)),
+        field("asset_id", Kind::Bytes),
+        field("fee_asset_id", Kind::Bytes),
+        field("destination_chain_address", Kind::String),
+    ],
+    is_oneof: false,
+};
+
+static BRIDGE_UNLOCK_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.BridgeUnlockAction",
+    fields: &[
+        field("to", Kind::Message(&ADDRESS)),
+        field("amount", Kind::Message(&UINT128)),
+        field("fee_asset_id", Kind::Bytes),
+        field("memo", Kind::Bytes),
+        field("bridge_address", Kind::Message(&ADDRESS)),
+    ],
+    is_oneof: false,
+};
+
+static BRIDGE_SUDO_CHANGE_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.BridgeSudoChangeAction",
+    fields: &[
+        field("bridge_address", Kind::Message(&ADDRESS)),
+        field("new_sudo_address", Kind::Message(&ADDRESS)),
+        field("new_withdrawer_address", Kind::Message(&ADDRESS)),
+        field("fee_asset_id", Kind::Bytes),
+    ],
+    is_oneof: false,
+};
+
+static BRIDGE_RECOVERY_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.BridgeRecoveryAction",
+    fields: &[
+        field("bridge_address", Kind::Message(&ADDRESS)),
+        field("new_sudo_address", Kind::Message(&ADDRESS)),
+        field("new_withdrawer_address", Kind::Message(&ADDRESS)),
+    ],
+    is_oneof: false,
+};
+
+static BRIDGE_RECOVERY_VETO_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.BridgeRecoveryVetoAction",
+    fields: &[
+        field("bridge_address", Kind::Message(&ADDRESS)),
+        field("fee_asset_id", Kind::Bytes),
+    ],
+    is_oneof: false,
+};
+
+static FEE_CHANGE_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.FeeChangeAction",
+    fields: &[
+        field("transfer_base_fee", Kind::Message(&UINT128)),
+        field("sequence_base_fee", Kind::Message(&UINT128)),
+        field("sequence_byte_cost_multiplier", Kind::Message(&UINT128)),
+        field("init_bridge_account_base_fee", Kind::Message(&UINT128)),
+        field("bridge_lock_byte_cost_multiplier", Kind::Message(&UINT128)),
+        field("bridge_sudo_change_base_fee", Kind::Message(&UINT128)),
+        field("ics20_withdrawal_base_fee", Kind::Message(&UINT128)),
+    ],
+    is_oneof: true,
+};
+
+static FEE_DISTRIBUTION_CHANGE_ACTION: MessageDef = MessageDef {
+    full_name: "astria.protocol.transactions.v1alpha1.FeeDistributionChangeAction",
+    fields: &[
+        field("proposer_share", Kind::Uint32),
+        field("treasury_share", Kind::Uint32),
+        field("burn_share", Kind::Uint32),
+        field("treasury_address", Kind::Message(&ADDRESS)),
+    ],
+    is_oneof: false,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generated::{
+        primitive::v1 as primitive,
+        protocol::transaction::v1alpha1 as raw,
+    };
+
+    /// Checks `value` against `schema`, supporting only the keywords used by this module.
+    fn conforms(schema: &Value, defs: &Value, value: &Value) -> Result<(), String> {
+        if let Some(reference) = schema.get("$ref").and_then(Value::as_str) {
+            let name = reference.strip_prefix("#/$defs/").unwrap();
+            return conforms(&defs[name], defs, value);
+        }
+        if let Some(types) = schema.get("type") {
+            let types: Vec<&str> = match types {
+                Value::String(ty) => vec![ty.as_str()],
+                Value::Array(tys) => tys.iter().map(|ty| ty.as_str().unwrap()).collect(),
+                other => panic!("unexpected `type` keyword: {other}"),
+            };
+            let matches = types.iter().any(|ty| match *ty {
+                "object" => value.is_object(),
+                "array" => value.is_array(),
+                "string" => value.is_string(),
+                "integer" => value.is_u64() || value.is_i64(),
+                other => panic!("unexpected type: {other}"),
+            });
+            if !matches {
+                return Err(format!("`{value}` is not of type {types:?}"));
+            }
+        }
+        if let (Some(max), Some(value)) = (schema.get("maximum"), value.as_u64()) {
+            if value > max.as_u64().unwrap() {
+                return Err(format!("`{value}` exceeds maximum `{max}`"));
+            }
+        }
+        if let (Some(items), Some(values)) = (schema.get("items"), value.as_array()) {
+            for item in values {
+                conforms(items, defs, item)?;
+            }
+        }
+        if let (Some(properties), Some(object)) = (schema.get("properties"), value.as_object()) {
+            if let Some(max) = schema.get("maxProperties") {
+                if u64::try_from(object.len()).unwrap() > max.as_u64().unwrap() {
+                    return Err(format!("`{value}` has more than `{max}` properties"));
+                }
+            }
+            for (key, value) in object {
+                let Some(property) = properties.get(key) else {
+                    return Err(format!("`{key}` is not a known property"));
+                };
+                conforms(property, defs, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn assert_conforms(schema: &Value, value: &Value) {
+        if let Err(error) = conforms(schema, &schema["$defs"], value) {
+            panic!("`{value}` does not conform to schema: {error}");
+        }
+    }
+
+    fn address() -> Option<primitive::Address> {
+        Some(primitive::Address {
+            bech32m: "astria1qqqqqqqqqqqqqqqqqqqqqqqqqqqqqqqq007erg".to_string(),
+        })
+    }
+
+    fn amount() -> Option<primitive::Uint128> {
+        Some(primitive::Uint128 {
+            lo: 10,
+            hi: 1,
+        })
+    }
+
+    fn action(value: raw::action::Value) -> raw::Action {
+        raw::Action {
+            value: Some(value),
+        }
+    }
+
+    fn unsigned_transaction() -> raw::UnsignedTransaction {
+        use raw::action::Value;
+        raw::UnsignedTransaction {
+            actions: vec![
+                action(Value::TransferAction(raw::TransferAction {
+                    to: address(),
+                    amount: amount(),
+                    asset_id: vec![1; 32],
+                    fee_asset_id: vec![2; 32],
+                })),
+                action(Value::SequenceAction(raw::SequenceAction {
+                    rollup_id: Some(primitive::RollupId {
+                        inner: vec![3; 32].into(),
+                    }),
+                    data: b"hello".to_vec(),
+                    fee_asset_id: vec![2; 32],
+                })),
+                action(Value::AllowanceChangeAction(raw::AllowanceChangeAction {
+                    spender: address(),
+                    amount: amount(),
+                    asset_id: vec![1; 32],
+                    expiry_height: 100,
+                    fee_asset_id: vec![2; 32],
+                })),
+                action(Value::Ics20Withdrawal(raw::Ics20Withdrawal {
+                    amount: amount(),
+                    denom: "nria".to_string(),
+                    destination_chain_address: "cosmos1".to_string(),
+                    return_address: address(),
+                    timeout_height: Some(raw::IbcHeight {
+                        revision_number: 1,
+                        revision_height: 2,
+                    }),
+                    timeout_time: 3,
+                    source_channel: "channel-0".to_string(),
+                    fee_asset_id: vec![2; 32],
+                    memo: "memo".to_string(),
+                    bridge_address: address(),
+                })),
+                action(Value::FeeAssetChangeAction(raw::FeeAssetChangeAction {
+                    value: Some(raw::fee_asset_change_action::Value::Addition(vec![2; 32])),
+                })),
+                action(Value::FeeChangeAction(raw::FeeChangeAction {
+                    value: Some(raw::fee_change_action::Value::TransferBaseFee(
+                        amount().unwrap(),
+                    )),
+                })),
+                action(Value::FeeDistributionChangeAction(
+                    raw::FeeDistributionChangeAction {
+                        proposer_share: 50,
+                        treasury_share: 30,
+                        burn_share: 20,
+                        treasury_address: address(),
+                    },
+                )),
+            ],
+            params: Some(raw::TransactionParams {
+                nonce: 7,
+                chain_id: "astria-1".to_string(),
+            }),
+        }
+    }
+
+    #[test]
+    fn transaction_body_schema_accepts_serialized_transaction() {
+        let json = serde_json::to_value(unsigned_transaction()).unwrap();
+        assert_conforms(&transaction_body(), &json);
+    }
+
+    #[test]
+    fn transaction_schema_accepts_serialized_transaction() {
+        let signed = raw::SignedTransaction {
+            signature: vec![4; 64],
+            public_key: vec![5; 32],
+            transaction: Some(pbjson_types::Any {
+                type_url: "/astria.protocol.transactions.v1alpha1.UnsignedTransaction".to_string(),
+                value: prost::Message::encode_to_vec(&unsigned_transaction()).into(),
+            }),
+        };
+        let json = serde_json::to_value(signed).unwrap();
+        assert_conforms(&transaction(), &json);
+    }
+
+    #[test]
+    fn transaction_body_schema_rejects_unknown_fields_and_several_actions_in_one() {
+        let schema = transaction_body();
+        let unknown_field = json!({ "params": { "nonce": 1, "gas": 2 } });
+        assert!(conforms(&schema, &schema["$defs"], &unknown_field).is_err());
+        let several_actions = json!({
+            "actions": [{ "transfer_action": {}, "sequence_action": {} }],
+        });
+        assert!(conforms(&schema, &schema["$defs"], &several_actions).is_err());
+    }
+
+    #[test]
+    fn all_references_are_defined() {
+        fn check_references(value: &Value, defs: &Value) {
+            match value {
+                Value::Object(object) => {
+                    if let Some(reference) = object.get("$ref").and_then(Value::as_str) {
+                        let name = reference.strip_prefix("#/$defs/").unwrap();
+                        assert!(defs.get(name).is_some(), "`{reference}` is not defined");
+                    }
+                    object
+                        .values()
+                        .for_each(|value| check_references(value, defs));
+                }
+                Value::Array(values) => values
+                    .iter()
+                    .for_each(|value| check_references(value, defs)),
+                _ => {}
+            }
+        }
+        for (_, schema) in all() {
+            check_references(&schema, &schema["$defs"]);
+        }
+    }
+}
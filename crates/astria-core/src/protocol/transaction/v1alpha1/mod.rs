@@ -12,9 +12,18 @@ use crate::{
         VerificationKey,
     },
     primitive::v1::ADDRESS_LEN,
+    validation::{
+        ValidationError,
+        ValidationErrorCategory,
+        ValidationErrorCode,
+    },
 };
 
 pub mod action;
+#[cfg(feature = "serde")]
+pub mod canonical;
+#[cfg(feature = "serde")]
+pub mod json_schema;
 pub use action::Action;
 
 #[derive(Debug, thiserror::Error)]
@@ -57,6 +66,26 @@ enum SignedTransactionErrorKind {
     Verification(crypto::Error),
 }
 
+impl ValidationError for SignedTransactionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            SignedTransactionErrorKind::UnsetTransaction => {
+                ValidationErrorCode::new(1001, ValidationErrorCategory::MissingField)
+            }
+            SignedTransactionErrorKind::Signature(..) => {
+                ValidationErrorCode::new(1002, ValidationErrorCategory::Signature)
+            }
+            SignedTransactionErrorKind::Transaction(source) => source.validation_code(),
+            SignedTransactionErrorKind::VerificationKey(..) => {
+                ValidationErrorCode::new(1004, ValidationErrorCategory::Signature)
+            }
+            SignedTransactionErrorKind::Verification(..) => {
+                ValidationErrorCode::new(1005, ValidationErrorCategory::Signature)
+            }
+        }
+    }
+}
+
 /// The individual parts of a [`SignedTransaction`].
 #[derive(Debug)]
 pub struct SignedTransactionParts {
@@ -71,6 +100,11 @@ pub struct SignedTransactionParts {
 /// with its signature and public key.
 #[derive(Clone, Debug)]
 #[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::SignedTransaction", try_from = "raw::SignedTransaction")
+)]
 pub struct SignedTransaction {
     signature: Signature,
     verification_key: VerificationKey,
@@ -78,6 +112,73 @@ pub struct SignedTransaction {
     transaction_bytes: bytes::Bytes,
 }
 
+/// A signed transaction whose fields were parsed but whose signature was not yet verified.
+struct UnverifiedSignedTransaction {
+    signature: Signature,
+    verification_key: VerificationKey,
+    transaction: pbjson_types::Any,
+}
+
+impl UnverifiedSignedTransaction {
+    fn try_from_raw(proto: raw::SignedTransaction) -> Result<Self, SignedTransactionError> {
+        let raw::SignedTransaction {
+            signature,
+            public_key,
+            transaction,
+        } = proto;
+        let signature =
+            Signature::try_from(&*signature).map_err(SignedTransactionError::signature)?;
+        let verification_key = VerificationKey::try_from(&*public_key)
+            .map_err(SignedTransactionError::verification_key)?;
+        let Some(transaction) = transaction else {
+            return Err(SignedTransactionError::unset_transaction());
+        };
+        Ok(Self {
+            signature,
+            verification_key,
+            transaction,
+        })
+    }
+
+    fn verify(&self) -> Result<(), SignedTransactionError> {
+        self.verification_key
+            .verify(&self.signature, &self.transaction.value)
+            .map_err(SignedTransactionError::verification)
+    }
+
+    /// Decodes the inner transaction. Must only be called after the signature was verified.
+    fn into_verified(self) -> Result<SignedTransaction, SignedTransactionError> {
+        let Self {
+            signature,
+            verification_key,
+            transaction,
+        } = self;
+        let bytes = transaction.value.clone();
+        let transaction = UnsignedTransaction::try_from_any(transaction)
+            .map_err(SignedTransactionError::transaction)?;
+        Ok(SignedTransaction {
+            signature,
+            verification_key,
+            transaction,
+            transaction_bytes: bytes,
+        })
+    }
+}
+
+impl From<SignedTransaction> for raw::SignedTransaction {
+    fn from(value: SignedTransaction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::SignedTransaction> for SignedTransaction {
+    type Error = SignedTransactionError;
+
+    fn try_from(raw: raw::SignedTransaction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
 impl SignedTransaction {
     pub fn address_bytes(&self) -> [u8; ADDRESS_LEN] {
         self.verification_key.address_bytes()
@@ -97,6 +198,12 @@ impl SignedTransaction {
         Sha256::digest(bytes).into()
     }
 
+    /// Returns the length of the protobuf encoding of the transaction.
+    #[must_use]
+    pub fn encoded_size(&self) -> usize {
+        self.to_raw().encoded_len()
+    }
+
     #[must_use]
     pub fn into_raw(self) -> raw::SignedTransaction {
         let Self {
@@ -143,30 +250,44 @@ impl SignedTransaction {
     /// if the native [`UnsignedTransaction`] could not be created from the inner raw
     /// [`raw::UnsignedTransaction`].
     pub fn try_from_raw(proto: raw::SignedTransaction) -> Result<Self, SignedTransactionError> {
-        let raw::SignedTransaction {
-            signature,
-            public_key,
-            transaction,
-        } = proto;
-        let signature =
-            Signature::try_from(&*signature).map_err(SignedTransactionError::signature)?;
-        let verification_key = VerificationKey::try_from(&*public_key)
-            .map_err(SignedTransactionError::verification_key)?;
-        let Some(transaction) = transaction else {
-            return Err(SignedTransactionError::unset_transaction());
-        };
-        let bytes = transaction.value.clone();
-        verification_key
-            .verify(&signature, &bytes)
-            .map_err(SignedTransactionError::verification)?;
-        let transaction = UnsignedTransaction::try_from_any(transaction)
-            .map_err(SignedTransactionError::transaction)?;
-        Ok(Self {
-            signature,
-            verification_key,
-            transaction,
-            transaction_bytes: bytes,
-        })
+        let unverified = UnverifiedSignedTransaction::try_from_raw(proto)?;
+        unverified.verify()?;
+        unverified.into_verified()
+    }
+
+    /// Attempt to convert many raw, unchecked protobuf [`raw::SignedTransaction`]s, verifying
+    /// their signatures as a single batch.
+    ///
+    /// Returns one result per input in the same order, each identical to what
+    /// [`SignedTransaction::try_from_raw`] would have returned for that input. If the batch
+    /// contains an invalid signature, the signatures are verified one by one to attribute the
+    /// failure to the offending transactions.
+    #[must_use]
+    pub fn try_from_raw_batch<I>(protos: I) -> Vec<Result<Self, SignedTransactionError>>
+    where
+        I: IntoIterator<Item = raw::SignedTransaction>,
+    {
+        let unverified: Vec<_> = protos
+            .into_iter()
+            .map(UnverifiedSignedTransaction::try_from_raw)
+            .collect();
+
+        let mut batch = crypto::BatchVerifier::new();
+        for tx in unverified.iter().flatten() {
+            batch.queue(&tx.verification_key, &tx.signature, &tx.transaction.value);
+        }
+        let all_verified = batch.verify(rand::rngs::OsRng).is_ok();
+
+        unverified
+            .into_iter()
+            .map(|tx| {
+                let tx = tx?;
+                if !all_verified {
+                    tx.verify()?;
+                }
+                tx.into_verified()
+            })
+            .collect()
     }
 
     /// Converts a [`SignedTransaction`] into its [`SignedTransactionParts`].
@@ -222,11 +343,33 @@ impl SignedTransaction {
 
 #[derive(Clone, Debug)]
 #[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::UnsignedTransaction",
+        try_from = "raw::UnsignedTransaction"
+    )
+)]
 pub struct UnsignedTransaction {
     pub actions: Vec<Action>,
     pub params: TransactionParams,
 }
 
+impl From<UnsignedTransaction> for raw::UnsignedTransaction {
+    fn from(value: UnsignedTransaction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::UnsignedTransaction> for UnsignedTransaction {
+    type Error = UnsignedTransactionError;
+
+    fn try_from(raw: raw::UnsignedTransaction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
 impl UnsignedTransaction {
     #[must_use]
     pub fn nonce(&self) -> u32 {
@@ -238,6 +381,23 @@ impl UnsignedTransaction {
         &self.params.chain_id
     }
 
+    /// Returns the length of the protobuf encoding of the transaction.
+    #[must_use]
+    pub fn encoded_size(&self) -> usize {
+        self.to_raw().encoded_len()
+    }
+
+    /// Returns the total length of the data of the transaction's sequence actions.
+    ///
+    /// This is the size counted against the sequenced data limit of a sequencer block.
+    #[must_use]
+    pub fn sequence_data_size(&self) -> usize {
+        self.actions
+            .iter()
+            .filter_map(Action::as_sequence)
+            .fold(0usize, |acc, seq| acc.saturating_add(seq.data.len()))
+    }
+
     #[must_use]
     pub fn into_signed(self, signing_key: &SigningKey) -> SignedTransaction {
         let bytes = self.to_raw().encode_to_vec();
@@ -377,6 +537,23 @@ enum UnsignedTransactionErrorKind {
     DecodeAny(#[source] prost::DecodeError),
 }
 
+impl ValidationError for UnsignedTransactionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            UnsignedTransactionErrorKind::Action(source) => source.validation_code(),
+            UnsignedTransactionErrorKind::UnsetParams(..) => {
+                ValidationErrorCode::new(1102, ValidationErrorCategory::MissingField)
+            }
+            UnsignedTransactionErrorKind::InvalidTypeUrl {
+                ..
+            } => ValidationErrorCode::new(1103, ValidationErrorCategory::Decode),
+            UnsignedTransactionErrorKind::DecodeAny(..) => {
+                ValidationErrorCode::new(1104, ValidationErrorCategory::Decode)
+            }
+        }
+    }
+}
+
 pub struct TransactionParamsBuilder<TChainId = std::borrow::Cow<'static, str>> {
     nonce: u32,
     chain_id: TChainId,
@@ -432,17 +609,44 @@ impl<'a> TransactionParamsBuilder<std::borrow::Cow<'a, str>> {
 }
 
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::TransactionParams", from = "raw::TransactionParams")
+)]
 pub struct TransactionParams {
     nonce: u32,
     chain_id: String,
 }
 
+impl From<TransactionParams> for raw::TransactionParams {
+    fn from(value: TransactionParams) -> Self {
+        value.into_raw()
+    }
+}
+
+impl From<raw::TransactionParams> for TransactionParams {
+    fn from(raw: raw::TransactionParams) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
 impl TransactionParams {
     #[must_use = "the transaction params builder must be built to be useful"]
     pub fn builder() -> TransactionParamsBuilder {
         TransactionParamsBuilder::new()
     }
 
+    #[must_use]
+    pub fn nonce(&self) -> u32 {
+        self.nonce
+    }
+
+    #[must_use]
+    pub fn chain_id(&self) -> &str {
+        &self.chain_id
+    }
+
     #[must_use]
     pub fn into_raw(self) -> raw::TransactionParams {
         let Self {
@@ -526,6 +730,32 @@ mod test {
         insta::assert_json_snapshot!(tx.sha256_of_proto_encoding());
     }
 
+    #[test]
+    fn sizes_and_fee_weights_of_actions() {
+        use crate::primitive::v1::RollupId;
+
+        let sequence = action::SequenceAction {
+            rollup_id: RollupId::new([1; 32]),
+            data: vec![0; 10],
+            fee_asset_id: default_native_asset().id(),
+        };
+        assert_eq!(10, sequence.fee_weight());
+        assert_eq!(74, sequence.payload_size());
+        assert!(sequence.encoded_size() > sequence.payload_size());
+
+        let unsigned = UnsignedTransaction {
+            actions: vec![sequence.clone().into(), sequence.into()],
+            params: TransactionParams::builder()
+                .nonce(1)
+                .chain_id("test-1")
+                .build(),
+        };
+        assert_eq!(20, unsigned.sequence_data_size());
+        assert_eq!(unsigned.to_raw().encoded_len(), unsigned.encoded_size());
+        let weights: usize = unsigned.actions.iter().map(Action::fee_weight).sum();
+        assert_eq!(20, weights);
+    }
+
     #[test]
     fn signed_transaction_verification_roundtrip() {
         let signing_key = SigningKey::from([
@@ -533,16 +763,16 @@ mod test {
             178, 63, 69, 238, 27, 96, 95, 213, 135, 120, 87, 106, 196,
         ]);
 
-        let transfer = TransferAction {
-            to: Address::builder()
+        let transfer = TransferAction::builder()
+            .to(Address::builder()
                 .array([0; 20])
                 .prefix(ASTRIA_ADDRESS_PREFIX)
                 .try_build()
-                .unwrap(),
-            amount: 0,
-            asset_id: default_native_asset().id(),
-            fee_asset_id: default_native_asset().id(),
-        };
+                .unwrap())
+            .amount(0)
+            .asset_id(default_native_asset().id())
+            .fee_asset_id(default_native_asset().id())
+            .build();
 
         let params = TransactionParams::from_raw(raw::TransactionParams {
             nonce: 1,
@@ -559,4 +789,80 @@ mod test {
         // `try_from_raw` verifies the signature
         SignedTransaction::try_from_raw(raw).unwrap();
     }
+
+    #[test]
+    fn batch_verification_attributes_invalid_signatures() {
+        use crate::primitive::v1::RollupId;
+
+        let signing_key = SigningKey::from([7; 32]);
+        let mut raws: Vec<_> = (0..4u32)
+            .map(|nonce| {
+                UnsignedTransaction {
+                    actions: vec![action::SequenceAction {
+                        rollup_id: RollupId::new([1; 32]),
+                        data: vec![0; 10],
+                        fee_asset_id: default_native_asset().id(),
+                    }
+                    .into()],
+                    params: TransactionParams::builder()
+                        .nonce(nonce)
+                        .chain_id("test-1")
+                        .build(),
+                }
+                .into_signed(&signing_key)
+                .into_raw()
+            })
+            .collect();
+
+        let all_valid = SignedTransaction::try_from_raw_batch(raws.clone());
+        assert!(all_valid.iter().all(Result::is_ok));
+
+        raws[2].signature = raws[1].signature.clone();
+        raws[3].transaction = None;
+        let results = SignedTransaction::try_from_raw_batch(raws);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_ok());
+        assert_eq!(
+            1005,
+            results[2].as_ref().unwrap_err().validation_code().code()
+        );
+        assert_eq!(
+            1001,
+            results[3].as_ref().unwrap_err().validation_code().code()
+        );
+    }
+
+    #[test]
+    fn signed_transaction_json_roundtrip() {
+        let signing_key = SigningKey::from([
+            213, 191, 74, 63, 204, 231, 23, 176, 56, 139, 204, 39, 73, 235, 193, 72, 173, 153, 105,
+            178, 63, 69, 238, 27, 96, 95, 213, 135, 120, 87, 106, 196,
+        ]);
+
+        let transfer = TransferAction::builder()
+            .to(Address::builder()
+                .array([0; 20])
+                .prefix(ASTRIA_ADDRESS_PREFIX)
+                .try_build()
+                .unwrap())
+            .amount(0)
+            .asset_id(default_native_asset().id())
+            .fee_asset_id(default_native_asset().id())
+            .build();
+
+        let params = TransactionParams::from_raw(raw::TransactionParams {
+            nonce: 1,
+            chain_id: "test-1".to_string(),
+        });
+        let unsigned = UnsignedTransaction {
+            actions: vec![transfer.into()],
+            params,
+        };
+
+        let signed_tx = unsigned.into_signed(&signing_key);
+        let json = serde_json::to_string(&signed_tx).unwrap();
+        // deserializing verifies the signature, just as `try_from_raw` does
+        let from_json: SignedTransaction = serde_json::from_str(&json).unwrap();
+        assert_eq!(signed_tx.to_raw(), from_json.to_raw());
+    }
 }
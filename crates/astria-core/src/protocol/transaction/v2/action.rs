@@ -0,0 +1,351 @@
+use bytes::Bytes;
+use prost::{
+    Message,
+    Name,
+};
+
+use crate::{
+    generated::protocol::transaction::v1alpha1 as raw_v1alpha1,
+    protocol::transaction::v1alpha1::{
+        action::ActionError,
+        Action,
+    },
+    validation::{
+        ValidationError,
+        ValidationErrorCategory,
+        ValidationErrorCode,
+    },
+};
+
+/// The type URL of validator updates, which are defined in the vendored tendermint protos.
+pub const VALIDATOR_UPDATE_TYPE_URL: &str = "/tendermint.abci.ValidatorUpdate";
+/// The type URL of IBC relay actions, which are defined in the vendored penumbra protos.
+pub const IBC_RELAY_TYPE_URL: &str = "/penumbra.core.component.ibc.v1.IbcRelay";
+
+/// An action wrapped in its type URL and its protobuf encoding.
+///
+/// An envelope can hold any action, including ones that are not known to this version of the
+/// crate. Envelopes are carried through a transaction unchanged, so that a transaction with new
+/// action types can be decoded, verified, and relayed by clients that cannot interpret them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "pbjson_types::Any", from = "pbjson_types::Any")
+)]
+pub struct ActionEnvelope {
+    type_url: String,
+    value: Bytes,
+}
+
+impl From<ActionEnvelope> for pbjson_types::Any {
+    fn from(value: ActionEnvelope) -> Self {
+        value.into_raw()
+    }
+}
+
+impl From<pbjson_types::Any> for ActionEnvelope {
+    fn from(raw: pbjson_types::Any) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
+impl From<Action> for ActionEnvelope {
+    fn from(value: Action) -> Self {
+        Self::from_action(value)
+    }
+}
+
+impl ActionEnvelope {
+    /// Constructs an envelope from a type URL and the protobuf encoding of the action.
+    ///
+    /// The value is not checked against the type URL.
+    pub fn new<TUrl: Into<String>, TValue: Into<Bytes>>(type_url: TUrl, value: TValue) -> Self {
+        Self {
+            type_url: type_url.into(),
+            value: value.into(),
+        }
+    }
+
+    /// Wraps the protobuf message `message` in an envelope.
+    #[must_use]
+    pub fn pack<T: Message + Name>(message: &T) -> Self {
+        Self::new(T::type_url(), message.encode_to_vec())
+    }
+
+    /// Wraps a native action in an envelope.
+    #[must_use]
+    pub fn from_action(action: Action) -> Self {
+        use raw_v1alpha1::action::Value;
+        let Some(value) = action.into_raw().value else {
+            unreachable!("converting a native action to raw always sets the action value");
+        };
+        match value {
+            Value::TransferAction(act) => Self::pack(&act),
+            Value::SequenceAction(act) => Self::pack(&act),
+            Value::AllowanceChangeAction(act) => Self::pack(&act),
+            Value::TransferFromAction(act) => Self::pack(&act),
+            Value::InitBridgeAccountAction(act) => Self::pack(&act),
+            Value::BridgeLockAction(act) => Self::pack(&act),
+            Value::BridgeUnlockAction(act) => Self::pack(&act),
+            Value::BridgeSudoChangeAction(act) => Self::pack(&act),
+            Value::BridgeRecoveryVetoAction(act) => Self::pack(&act),
+            Value::IbcAction(act) => Self::new(IBC_RELAY_TYPE_URL, act.encode_to_vec()),
+            Value::Ics20Withdrawal(act) => Self::pack(&act),
+            Value::SudoAddressChangeAction(act) => Self::pack(&act),
+            Value::ValidatorUpdateAction(act) => {
+                Self::new(VALIDATOR_UPDATE_TYPE_URL, act.encode_to_vec())
+            }
+            Value::IbcRelayerChangeAction(act) => Self::pack(&act),
+            Value::FeeAssetChangeAction(act) => Self::pack(&act),
+            Value::FeeChangeAction(act) => Self::pack(&act),
+            Value::AddressPrefixChangeAction(act) => Self::pack(&act),
+            Value::BridgeRecoveryAction(act) => Self::pack(&act),
+            Value::FeeDistributionChangeAction(act) => Self::pack(&act),
+        }
+    }
+
+    #[must_use]
+    pub fn type_url(&self) -> &str {
+        &self.type_url
+    }
+
+    #[must_use]
+    pub fn value(&self) -> &Bytes {
+        &self.value
+    }
+
+    /// Returns if the envelope holds a message of type `T`.
+    #[must_use]
+    pub fn is<T: Name>(&self) -> bool {
+        self.type_url == T::type_url()
+    }
+
+    /// Decodes the message held by the envelope as `T`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the envelope does not hold a `T`, or if its value could not be decoded
+    /// as one.
+    pub fn unpack<T: Message + Name + Default>(&self) -> Result<T, ActionEnvelopeError> {
+        if !self.is::<T>() {
+            return Err(ActionEnvelopeError::type_url_mismatch(
+                T::type_url(),
+                self.type_url.clone(),
+            ));
+        }
+        self.decode()
+    }
+
+    /// Returns if the envelope holds an action known to this version of the protocol.
+    #[must_use]
+    pub fn is_known(&self) -> bool {
+        self.known_kind().is_some()
+    }
+
+    /// Attempts to convert the envelope to a native action.
+    ///
+    /// Returns `Ok(None)` if the envelope holds an action that is not known to this version of
+    /// the protocol.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the envelope holds a known action, but its value could not be decoded
+    /// or converted to a native [`Action`].
+    pub fn try_to_action(&self) -> Result<Option<Action>, ActionEnvelopeError> {
+        use raw_v1alpha1::action::Value;
+        let Some(kind) = self.known_kind() else {
+            return Ok(None);
+        };
+        let value = match kind {
+            KnownAction::Transfer => Value::TransferAction(self.decode()?),
+            KnownAction::Sequence => Value::SequenceAction(self.decode()?),
+            KnownAction::AllowanceChange => Value::AllowanceChangeAction(self.decode()?),
+            KnownAction::TransferFrom => Value::TransferFromAction(self.decode()?),
+            KnownAction::InitBridgeAccount => Value::InitBridgeAccountAction(self.decode()?),
+            KnownAction::BridgeLock => Value::BridgeLockAction(self.decode()?),
+            KnownAction::BridgeUnlock => Value::BridgeUnlockAction(self.decode()?),
+            KnownAction::BridgeSudoChange => Value::BridgeSudoChangeAction(self.decode()?),
+            KnownAction::BridgeRecoveryVeto => Value::BridgeRecoveryVetoAction(self.decode()?),
+            KnownAction::Ibc => Value::IbcAction(self.decode()?),
+            KnownAction::Ics20Withdrawal => Value::Ics20Withdrawal(self.decode()?),
+            KnownAction::SudoAddressChange => Value::SudoAddressChangeAction(self.decode()?),
+            KnownAction::ValidatorUpdate => Value::ValidatorUpdateAction(self.decode()?),
+            KnownAction::IbcRelayerChange => Value::IbcRelayerChangeAction(self.decode()?),
+            KnownAction::FeeAssetChange => Value::FeeAssetChangeAction(self.decode()?),
+            KnownAction::FeeChange => Value::FeeChangeAction(self.decode()?),
+            KnownAction::AddressPrefixChange => Value::AddressPrefixChangeAction(self.decode()?),
+            KnownAction::BridgeRecovery => Value::BridgeRecoveryAction(self.decode()?),
+            KnownAction::FeeDistributionChange => {
+                Value::FeeDistributionChangeAction(self.decode()?)
+            }
+        };
+        Action::try_from_raw(raw_v1alpha1::Action {
+            value: Some(value),
+        })
+        .map(Some)
+        .map_err(ActionEnvelopeError::action)
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> pbjson_types::Any {
+        let Self {
+            type_url,
+            value,
+        } = self;
+        pbjson_types::Any {
+            type_url,
+            value,
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> pbjson_types::Any {
+        self.clone().into_raw()
+    }
+
+    /// Convert from a raw protobuf [`pbjson_types::Any`].
+    ///
+    /// This conversion is infallible because the envelope's contents are only interpreted when
+    /// they are unpacked.
+    #[must_use]
+    pub fn from_raw(raw: pbjson_types::Any) -> Self {
+        let pbjson_types::Any {
+            type_url,
+            value,
+        } = raw;
+        Self {
+            type_url,
+            value,
+        }
+    }
+
+    fn decode<T: Message + Default>(&self) -> Result<T, ActionEnvelopeError> {
+        T::decode(self.value.clone())
+            .map_err(|source| ActionEnvelopeError::decode(self.type_url.clone(), source))
+    }
+
+    fn known_kind(&self) -> Option<KnownAction> {
+        use raw_v1alpha1 as raw;
+        let type_url = self.type_url.as_str();
+        let kind = if self.is::<raw::TransferAction>() {
+            KnownAction::Transfer
+        } else if self.is::<raw::SequenceAction>() {
+            KnownAction::Sequence
+        } else if self.is::<raw::AllowanceChangeAction>() {
+            KnownAction::AllowanceChange
+        } else if self.is::<raw::TransferFromAction>() {
+            KnownAction::TransferFrom
+        } else if self.is::<raw::InitBridgeAccountAction>() {
+            KnownAction::InitBridgeAccount
+        } else if self.is::<raw::BridgeLockAction>() {
+            KnownAction::BridgeLock
+        } else if self.is::<raw::BridgeUnlockAction>() {
+            KnownAction::BridgeUnlock
+        } else if self.is::<raw::BridgeSudoChangeAction>() {
+            KnownAction::BridgeSudoChange
+        } else if self.is::<raw::BridgeRecoveryVetoAction>() {
+            KnownAction::BridgeRecoveryVeto
+        } else if type_url == IBC_RELAY_TYPE_URL {
+            KnownAction::Ibc
+        } else if self.is::<raw::Ics20Withdrawal>() {
+            KnownAction::Ics20Withdrawal
+        } else if self.is::<raw::SudoAddressChangeAction>() {
+            KnownAction::SudoAddressChange
+        } else if type_url == VALIDATOR_UPDATE_TYPE_URL {
+            KnownAction::ValidatorUpdate
+        } else if self.is::<raw::IbcRelayerChangeAction>() {
+            KnownAction::IbcRelayerChange
+        } else if self.is::<raw::FeeAssetChangeAction>() {
+            KnownAction::FeeAssetChange
+        } else if self.is::<raw::FeeChangeAction>() {
+            KnownAction::FeeChange
+        } else if self.is::<raw::AddressPrefixChangeAction>() {
+            KnownAction::AddressPrefixChange
+        } else if self.is::<raw::BridgeRecoveryAction>() {
+            KnownAction::BridgeRecovery
+        } else if self.is::<raw::FeeDistributionChangeAction>() {
+            KnownAction::FeeDistributionChange
+        } else {
+            return None;
+        };
+        Some(kind)
+    }
+}
+
+/// The actions known to this version of the protocol, identified by their type URLs.
+#[derive(Clone, Copy)]
+enum KnownAction {
+    Transfer,
+    Sequence,
+    AllowanceChange,
+    TransferFrom,
+    InitBridgeAccount,
+    BridgeLock,
+    BridgeUnlock,
+    BridgeSudoChange,
+    BridgeRecoveryVeto,
+    Ibc,
+    Ics20Withdrawal,
+    SudoAddressChange,
+    ValidatorUpdate,
+    IbcRelayerChange,
+    FeeAssetChange,
+    FeeChange,
+    AddressPrefixChange,
+    BridgeRecovery,
+    FeeDistributionChange,
+}
+
+#[derive(Debug, thiserror::Error)]
+#[allow(clippy::module_name_repetitions)]
+#[error(transparent)]
+pub struct ActionEnvelopeError(ActionEnvelopeErrorKind);
+
+impl ActionEnvelopeError {
+    fn action(inner: ActionError) -> Self {
+        Self(ActionEnvelopeErrorKind::Action(inner))
+    }
+
+    fn decode(type_url: String, source: prost::DecodeError) -> Self {
+        Self(ActionEnvelopeErrorKind::Decode {
+            type_url,
+            source,
+        })
+    }
+
+    fn type_url_mismatch(expected: String, got: String) -> Self {
+        Self(ActionEnvelopeErrorKind::TypeUrlMismatch {
+            expected,
+            got,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum ActionEnvelopeErrorKind {
+    #[error("the envelope holds an invalid action")]
+    Action(#[source] ActionError),
+    #[error("failed to decode the value of the envelope as `{type_url}`")]
+    Decode {
+        type_url: String,
+        source: prost::DecodeError,
+    },
+    #[error("the envelope holds `{got}`, expected `{expected}`")]
+    TypeUrlMismatch { expected: String, got: String },
+}
+
+impl ValidationError for ActionEnvelopeError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            ActionEnvelopeErrorKind::Action(source) => source.validation_code(),
+            ActionEnvelopeErrorKind::Decode {
+                ..
+            } => ValidationErrorCode::new(3202, ValidationErrorCategory::Decode),
+            ActionEnvelopeErrorKind::TypeUrlMismatch {
+                ..
+            } => ValidationErrorCode::new(3203, ValidationErrorCategory::Decode),
+        }
+    }
+}
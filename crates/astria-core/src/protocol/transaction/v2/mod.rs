@@ -0,0 +1,570 @@
+//! Version 2 of the transaction protocol.
+//!
+//! The only difference to [`v1alpha1`](super::v1alpha1) is that the actions of an
+//! [`UnsignedTransaction`] are wrapped in [`ActionEnvelope`]s (a type URL together with the
+//! protobuf encoding of the action) instead of a closed `oneof`. New action types can so be
+//! added without breaking clients that only decode, verify, and relay transactions: actions they
+//! don't know about are carried along unchanged, and the signature stays valid.
+
+use prost::{
+    Message as _,
+    Name as _,
+};
+
+use super::{
+    raw_v2 as raw,
+    v1alpha1::{
+        self,
+        Action,
+        TransactionParams,
+    },
+};
+use crate::{
+    crypto::{
+        self,
+        Signature,
+        SigningKey,
+        VerificationKey,
+    },
+    primitive::v1::ADDRESS_LEN,
+    validation::{
+        ValidationError,
+        ValidationErrorCategory,
+        ValidationErrorCode,
+    },
+};
+
+pub mod action;
+pub use action::{
+    ActionEnvelope,
+    ActionEnvelopeError,
+};
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct SignedTransactionError(SignedTransactionErrorKind);
+
+impl SignedTransactionError {
+    fn signature(inner: crypto::Error) -> Self {
+        Self(SignedTransactionErrorKind::Signature(inner))
+    }
+
+    fn transaction(inner: UnsignedTransactionError) -> Self {
+        Self(SignedTransactionErrorKind::Transaction(inner))
+    }
+
+    fn verification(inner: crypto::Error) -> Self {
+        Self(SignedTransactionErrorKind::Verification(inner))
+    }
+
+    fn verification_key(inner: crypto::Error) -> Self {
+        Self(SignedTransactionErrorKind::VerificationKey(inner))
+    }
+
+    fn unset_transaction() -> Self {
+        Self(SignedTransactionErrorKind::UnsetTransaction)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum SignedTransactionErrorKind {
+    #[error("`transaction` field not set")]
+    UnsetTransaction,
+    #[error("`signature` field invalid")]
+    Signature(#[source] crypto::Error),
+    #[error("`transaction` field invalid")]
+    Transaction(#[source] UnsignedTransactionError),
+    #[error("`public_key` field invalid")]
+    VerificationKey(#[source] crypto::Error),
+    #[error("transaction could not be verified given the signature and verification key")]
+    Verification(crypto::Error),
+}
+
+impl ValidationError for SignedTransactionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            SignedTransactionErrorKind::UnsetTransaction => {
+                ValidationErrorCode::new(3001, ValidationErrorCategory::MissingField)
+            }
+            SignedTransactionErrorKind::Signature(..) => {
+                ValidationErrorCode::new(3002, ValidationErrorCategory::Signature)
+            }
+            SignedTransactionErrorKind::Transaction(source) => source.validation_code(),
+            SignedTransactionErrorKind::VerificationKey(..) => {
+                ValidationErrorCode::new(3004, ValidationErrorCategory::Signature)
+            }
+            SignedTransactionErrorKind::Verification(..) => {
+                ValidationErrorCode::new(3005, ValidationErrorCategory::Signature)
+            }
+        }
+    }
+}
+
+/// A signed version 2 transaction.
+///
+/// [`SignedTransaction`] contains an [`UnsignedTransaction`] together
+/// with its signature and public key.
+#[derive(Clone, Debug)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::SignedTransaction", try_from = "raw::SignedTransaction")
+)]
+pub struct SignedTransaction {
+    signature: Signature,
+    verification_key: VerificationKey,
+    transaction: UnsignedTransaction,
+    transaction_bytes: bytes::Bytes,
+}
+
+impl From<SignedTransaction> for raw::SignedTransaction {
+    fn from(value: SignedTransaction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::SignedTransaction> for SignedTransaction {
+    type Error = SignedTransactionError;
+
+    fn try_from(raw: raw::SignedTransaction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl SignedTransaction {
+    pub fn address_bytes(&self) -> [u8; ADDRESS_LEN] {
+        self.verification_key.address_bytes()
+    }
+
+    /// Returns the transaction hash.
+    ///
+    /// The transaction hash is calculated by protobuf-encoding the transaction
+    /// and hashing the resulting bytes with sha256.
+    #[must_use]
+    pub fn sha256_of_proto_encoding(&self) -> [u8; 32] {
+        use sha2::{
+            Digest as _,
+            Sha256,
+        };
+        let bytes = self.to_raw().encode_to_vec();
+        Sha256::digest(bytes).into()
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::SignedTransaction {
+        let Self {
+            signature,
+            verification_key,
+            transaction_bytes,
+            ..
+        } = self;
+        raw::SignedTransaction {
+            signature: signature.to_bytes().to_vec(),
+            public_key: verification_key.to_bytes().to_vec(),
+            transaction: Some(pbjson_types::Any {
+                type_url: raw::UnsignedTransaction::type_url(),
+                value: transaction_bytes,
+            }),
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::SignedTransaction {
+        self.clone().into_raw()
+    }
+
+    /// Attempt to convert from a raw, unchecked protobuf [`raw::SignedTransaction`].
+    ///
+    /// Actions that are not known to this version of the protocol are not an error; they are
+    /// kept as opaque [`ActionEnvelope`]s.
+    ///
+    /// # Errors
+    ///
+    /// Will return an error if signature or verification key cannot be reconstructed from the bytes
+    /// contained in the raw input, if the transaction field was empty (meaning it was mapped to
+    /// `None`), if the inner transaction could not be verified given the key and signature, or
+    /// if the native [`UnsignedTransaction`] could not be created from the inner raw
+    /// [`raw::UnsignedTransaction`].
+    pub fn try_from_raw(proto: raw::SignedTransaction) -> Result<Self, SignedTransactionError> {
+        let raw::SignedTransaction {
+            signature,
+            public_key,
+            transaction,
+        } = proto;
+        let signature =
+            Signature::try_from(&*signature).map_err(SignedTransactionError::signature)?;
+        let verification_key = VerificationKey::try_from(&*public_key)
+            .map_err(SignedTransactionError::verification_key)?;
+        let Some(transaction) = transaction else {
+            return Err(SignedTransactionError::unset_transaction());
+        };
+        let bytes = transaction.value.clone();
+        verification_key
+            .verify(&signature, &bytes)
+            .map_err(SignedTransactionError::verification)?;
+        let transaction = UnsignedTransaction::try_from_any(transaction)
+            .map_err(SignedTransactionError::transaction)?;
+        Ok(Self {
+            signature,
+            verification_key,
+            transaction,
+            transaction_bytes: bytes,
+        })
+    }
+
+    #[must_use]
+    pub fn into_unsigned(self) -> UnsignedTransaction {
+        self.transaction
+    }
+
+    #[must_use]
+    pub fn actions(&self) -> &[ActionEnvelope] {
+        &self.transaction.actions
+    }
+
+    #[must_use]
+    pub fn signature(&self) -> Signature {
+        self.signature
+    }
+
+    #[must_use]
+    pub fn verification_key(&self) -> &VerificationKey {
+        &self.verification_key
+    }
+
+    #[must_use]
+    pub fn unsigned_transaction(&self) -> &UnsignedTransaction {
+        &self.transaction
+    }
+
+    pub fn chain_id(&self) -> &str {
+        self.transaction.chain_id()
+    }
+
+    #[must_use]
+    pub fn nonce(&self) -> u32 {
+        self.transaction.nonce()
+    }
+}
+
+#[derive(Clone, Debug)]
+#[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::UnsignedTransaction",
+        try_from = "raw::UnsignedTransaction"
+    )
+)]
+pub struct UnsignedTransaction {
+    pub actions: Vec<ActionEnvelope>,
+    pub params: TransactionParams,
+}
+
+impl From<UnsignedTransaction> for raw::UnsignedTransaction {
+    fn from(value: UnsignedTransaction) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::UnsignedTransaction> for UnsignedTransaction {
+    type Error = UnsignedTransactionError;
+
+    fn try_from(raw: raw::UnsignedTransaction) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl From<v1alpha1::UnsignedTransaction> for UnsignedTransaction {
+    fn from(value: v1alpha1::UnsignedTransaction) -> Self {
+        let v1alpha1::UnsignedTransaction {
+            actions,
+            params,
+        } = value;
+        Self {
+            actions: actions
+                .into_iter()
+                .map(ActionEnvelope::from_action)
+                .collect(),
+            params,
+        }
+    }
+}
+
+impl UnsignedTransaction {
+    #[must_use]
+    pub fn nonce(&self) -> u32 {
+        self.params.nonce()
+    }
+
+    #[must_use]
+    pub fn chain_id(&self) -> &str {
+        self.params.chain_id()
+    }
+
+    /// Returns if all actions of the transaction are known to this version of the protocol.
+    #[must_use]
+    pub fn has_only_known_actions(&self) -> bool {
+        self.actions.iter().all(ActionEnvelope::is_known)
+    }
+
+    /// Attempts to convert all actions of the transaction to native [`Action`]s.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an action is not known to this version of the protocol, or if it could
+    /// not be converted to a native [`Action`].
+    pub fn try_to_actions(&self) -> Result<Vec<Action>, UnsignedTransactionError> {
+        self.actions
+            .iter()
+            .map(|envelope| match envelope.try_to_action() {
+                Ok(Some(action)) => Ok(action),
+                Ok(None) => Err(UnsignedTransactionError::unknown_action(
+                    envelope.type_url().to_string(),
+                )),
+                Err(err) => Err(UnsignedTransactionError::action(err)),
+            })
+            .collect()
+    }
+
+    /// Attempts to convert the transaction to a [`v1alpha1::UnsignedTransaction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`UnsignedTransaction::try_to_actions`].
+    pub fn try_to_v1alpha1(
+        &self,
+    ) -> Result<v1alpha1::UnsignedTransaction, UnsignedTransactionError> {
+        Ok(v1alpha1::UnsignedTransaction {
+            actions: self.try_to_actions()?,
+            params: self.params.clone(),
+        })
+    }
+
+    #[must_use]
+    pub fn into_signed(self, signing_key: &SigningKey) -> SignedTransaction {
+        let bytes = self.to_raw().encode_to_vec();
+        let signature = signing_key.sign(&bytes);
+        let verification_key = signing_key.verification_key();
+        SignedTransaction {
+            signature,
+            verification_key,
+            transaction: self,
+            transaction_bytes: bytes.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::UnsignedTransaction {
+        let Self {
+            actions,
+            params,
+        } = self;
+        raw::UnsignedTransaction {
+            actions: actions.into_iter().map(ActionEnvelope::into_raw).collect(),
+            params: Some(params.into_raw()),
+        }
+    }
+
+    #[must_use]
+    pub fn to_raw(&self) -> raw::UnsignedTransaction {
+        self.clone().into_raw()
+    }
+
+    #[must_use]
+    pub fn into_any(self) -> pbjson_types::Any {
+        let raw = self.into_raw();
+        pbjson_types::Any {
+            type_url: raw::UnsignedTransaction::type_url(),
+            value: raw.encode_to_vec().into(),
+        }
+    }
+
+    #[must_use]
+    pub fn to_any(&self) -> pbjson_types::Any {
+        self.clone().into_any()
+    }
+
+    /// Attempt to convert from a raw, unchecked protobuf [`raw::UnsignedTransaction`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `params` field is unset.
+    pub fn try_from_raw(proto: raw::UnsignedTransaction) -> Result<Self, UnsignedTransactionError> {
+        let raw::UnsignedTransaction {
+            actions,
+            params,
+        } = proto;
+        let Some(params) = params else {
+            return Err(UnsignedTransactionError::unset_params());
+        };
+        Ok(Self {
+            actions: actions.into_iter().map(ActionEnvelope::from_raw).collect(),
+            params: TransactionParams::from_raw(params),
+        })
+    }
+
+    /// Attempt to convert from a protobuf [`pbjson_types::Any`].
+    ///
+    /// # Errors
+    ///
+    /// - if the type URL is not the expected type URL
+    /// - if the bytes in the [`Any`] do not decode to an [`UnsignedTransaction`]
+    pub fn try_from_any(any: pbjson_types::Any) -> Result<Self, UnsignedTransactionError> {
+        if any.type_url != raw::UnsignedTransaction::type_url() {
+            return Err(UnsignedTransactionError::invalid_type_url(any.type_url));
+        }
+
+        let raw = raw::UnsignedTransaction::decode(any.value)
+            .map_err(UnsignedTransactionError::decode_any)?;
+        Self::try_from_raw(raw)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct UnsignedTransactionError(UnsignedTransactionErrorKind);
+
+impl UnsignedTransactionError {
+    fn action(inner: ActionEnvelopeError) -> Self {
+        Self(UnsignedTransactionErrorKind::Action(inner))
+    }
+
+    fn unknown_action(type_url: String) -> Self {
+        Self(UnsignedTransactionErrorKind::UnknownAction {
+            type_url,
+        })
+    }
+
+    fn unset_params() -> Self {
+        Self(UnsignedTransactionErrorKind::UnsetParams())
+    }
+
+    fn invalid_type_url(got: String) -> Self {
+        Self(UnsignedTransactionErrorKind::InvalidTypeUrl {
+            got,
+        })
+    }
+
+    fn decode_any(inner: prost::DecodeError) -> Self {
+        Self(UnsignedTransactionErrorKind::DecodeAny(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum UnsignedTransactionErrorKind {
+    #[error("`actions` field contains an invalid action")]
+    Action(#[source] ActionEnvelopeError),
+    #[error("`actions` field contains the action `{type_url}` unknown to this protocol version")]
+    UnknownAction { type_url: String },
+    #[error("`params` field is unset")]
+    UnsetParams(),
+    #[error(
+        "encountered invalid type URL when converting from `google.protobuf.Any`; got `{got}`, \
+         expected `{}`",
+        raw::UnsignedTransaction::type_url()
+    )]
+    InvalidTypeUrl { got: String },
+    #[error(
+        "failed to decode `google.protobuf.Any` to `{}`",
+        raw::UnsignedTransaction::type_url()
+    )]
+    DecodeAny(#[source] prost::DecodeError),
+}
+
+impl ValidationError for UnsignedTransactionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            UnsignedTransactionErrorKind::Action(source) => source.validation_code(),
+            UnsignedTransactionErrorKind::UnknownAction {
+                ..
+            } => ValidationErrorCode::new(3102, ValidationErrorCategory::Unknown),
+            UnsignedTransactionErrorKind::UnsetParams(..) => {
+                ValidationErrorCode::new(3103, ValidationErrorCategory::MissingField)
+            }
+            UnsignedTransactionErrorKind::InvalidTypeUrl {
+                ..
+            } => ValidationErrorCode::new(3104, ValidationErrorCategory::Decode),
+            UnsignedTransactionErrorKind::DecodeAny(..) => {
+                ValidationErrorCode::new(3105, ValidationErrorCategory::Decode)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generated::protocol::transaction::v1alpha1 as raw_v1alpha1,
+        primitive::v1::{
+            asset::default_native_asset,
+            Address,
+        },
+        protocol::transaction::v1alpha1::action::TransferAction,
+    };
+
+    fn transfer() -> Action {
+        TransferAction {
+            to: Address::builder()
+                .array([0; 20])
+                .prefix("astria")
+                .try_build()
+                .unwrap(),
+            amount: 10,
+            asset_id: default_native_asset().id(),
+            fee_asset_id: default_native_asset().id(),
+        }
+        .into()
+    }
+
+    fn params() -> TransactionParams {
+        TransactionParams::builder()
+            .nonce(1)
+            .chain_id("test-1")
+            .build()
+    }
+
+    #[test]
+    fn known_action_roundtrips_through_envelope() {
+        let action = transfer();
+        let envelope = ActionEnvelope::from_action(action.clone());
+        assert!(envelope.is::<raw_v1alpha1::TransferAction>());
+        assert!(envelope.is_known());
+        let unpacked = envelope.try_to_action().unwrap().unwrap();
+        assert_eq!(action.to_raw(), unpacked.to_raw());
+    }
+
+    #[test]
+    fn unknown_action_is_carried_through_signed_transaction() {
+        let unknown = ActionEnvelope::new("/astria.future.v1.NewAction", vec![1, 2, 3]);
+        let unsigned = UnsignedTransaction {
+            actions: vec![ActionEnvelope::from_action(transfer()), unknown.clone()],
+            params: params(),
+        };
+        assert!(!unsigned.has_only_known_actions());
+        assert!(unsigned.try_to_actions().is_err());
+
+        let signed = unsigned.into_signed(&SigningKey::from([1; 32]));
+        let bytes = signed.to_raw().encode_to_vec();
+        let decoded =
+            SignedTransaction::try_from_raw(raw::SignedTransaction::decode(&*bytes).unwrap())
+                .unwrap();
+        assert_eq!(unknown, decoded.actions()[1]);
+        assert!(decoded.actions()[1].try_to_action().unwrap().is_none());
+        assert_eq!(bytes, decoded.to_raw().encode_to_vec());
+    }
+
+    #[test]
+    fn v1alpha1_transaction_converts_to_v2_and_back() {
+        let v1alpha1 = v1alpha1::UnsignedTransaction {
+            actions: vec![transfer()],
+            params: params(),
+        };
+        let v2 = UnsignedTransaction::from(v1alpha1.clone());
+        assert!(v2.has_only_known_actions());
+        assert_eq!(v1alpha1.to_raw(), v2.try_to_v1alpha1().unwrap().to_raw());
+    }
+}
@@ -0,0 +1,263 @@
+//! Detection and negotiation of transaction protocol versions.
+//!
+//! The signed transactions of all versions share the same wire layout: a signature, a public key,
+//! and the unsigned transaction wrapped in a `google.protobuf.Any`. The version of an encoded
+//! transaction is therefore identified by the type URL of its unsigned transaction.
+
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use prost::{
+    Message as _,
+    Name as _,
+};
+
+use super::{
+    raw,
+    raw_v2,
+    v1alpha1,
+    v2,
+};
+use crate::validation::{
+    ValidationError,
+    ValidationErrorCategory,
+    ValidationErrorCode,
+};
+
+/// A version of the transaction protocol.
+///
+/// Versions are ordered from oldest to newest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TransactionVersion {
+    V1Alpha1,
+    V2,
+}
+
+impl TransactionVersion {
+    /// All versions known to this crate, from oldest to newest.
+    pub const ALL: [Self; 2] = [Self::V1Alpha1, Self::V2];
+
+    /// Returns the type URL of the unsigned transaction of this version.
+    #[must_use]
+    pub fn type_url(self) -> String {
+        match self {
+            Self::V1Alpha1 => raw::UnsignedTransaction::type_url(),
+            Self::V2 => raw_v2::UnsignedTransaction::type_url(),
+        }
+    }
+
+    /// Returns the version whose unsigned transaction has the type URL `type_url`.
+    #[must_use]
+    pub fn from_type_url(type_url: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|version| version.type_url() == type_url)
+    }
+
+    /// Detects the version of a protobuf-encoded signed transaction.
+    ///
+    /// Only the envelope of the transaction is decoded; neither the signature nor the actions are
+    /// checked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `bytes` do not decode to a signed transaction, if its `transaction`
+    /// field is unset, or if the type URL of the unsigned transaction is not known.
+    pub fn detect(bytes: &[u8]) -> Result<Self, TransactionVersionError> {
+        let raw =
+            raw_v2::SignedTransaction::decode(bytes).map_err(TransactionVersionError::decode)?;
+        let Some(transaction) = raw.transaction else {
+            return Err(TransactionVersionError::unset_transaction());
+        };
+        Self::from_type_url(&transaction.type_url)
+            .ok_or_else(|| TransactionVersionError::unknown_type_url(transaction.type_url))
+    }
+
+    /// Returns the newest version supported by both this crate and a peer supporting `peer`.
+    ///
+    /// Returns `None` if there is no common version.
+    pub fn negotiate<I: IntoIterator<Item = Self>>(peer: I) -> Option<Self> {
+        peer.into_iter()
+            .filter(|version| Self::ALL.contains(version))
+            .max()
+    }
+}
+
+impl Display for TransactionVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::V1Alpha1 => f.write_str("v1alpha1"),
+            Self::V2 => f.write_str("v2"),
+        }
+    }
+}
+
+/// A signed transaction of any known version.
+#[derive(Clone, Debug)]
+pub enum VersionedSignedTransaction {
+    V1Alpha1(v1alpha1::SignedTransaction),
+    V2(v2::SignedTransaction),
+}
+
+impl VersionedSignedTransaction {
+    /// Decodes and verifies a protobuf-encoded signed transaction of any known version.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the version of the transaction cannot be detected, or if it is not a
+    /// valid signed transaction of that version.
+    pub fn try_from_bytes(bytes: &[u8]) -> Result<Self, TransactionVersionError> {
+        match TransactionVersion::detect(bytes)? {
+            TransactionVersion::V1Alpha1 => {
+                let raw = raw::SignedTransaction::decode(bytes)
+                    .map_err(TransactionVersionError::decode)?;
+                v1alpha1::SignedTransaction::try_from_raw(raw)
+                    .map(Self::V1Alpha1)
+                    .map_err(TransactionVersionError::v1alpha1)
+            }
+            TransactionVersion::V2 => {
+                let raw = raw_v2::SignedTransaction::decode(bytes)
+                    .map_err(TransactionVersionError::decode)?;
+                v2::SignedTransaction::try_from_raw(raw)
+                    .map(Self::V2)
+                    .map_err(TransactionVersionError::v2)
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn version(&self) -> TransactionVersion {
+        match self {
+            Self::V1Alpha1(_) => TransactionVersion::V1Alpha1,
+            Self::V2(_) => TransactionVersion::V2,
+        }
+    }
+
+    /// Returns the protobuf encoding of the transaction.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            Self::V1Alpha1(tx) => tx.to_raw().encode_to_vec(),
+            Self::V2(tx) => tx.to_raw().encode_to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct TransactionVersionError(TransactionVersionErrorKind);
+
+impl TransactionVersionError {
+    fn decode(inner: prost::DecodeError) -> Self {
+        Self(TransactionVersionErrorKind::Decode(inner))
+    }
+
+    fn unset_transaction() -> Self {
+        Self(TransactionVersionErrorKind::UnsetTransaction)
+    }
+
+    fn unknown_type_url(type_url: String) -> Self {
+        Self(TransactionVersionErrorKind::UnknownTypeUrl {
+            type_url,
+        })
+    }
+
+    fn v1alpha1(inner: v1alpha1::SignedTransactionError) -> Self {
+        Self(TransactionVersionErrorKind::V1Alpha1(inner))
+    }
+
+    fn v2(inner: v2::SignedTransactionError) -> Self {
+        Self(TransactionVersionErrorKind::V2(inner))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum TransactionVersionErrorKind {
+    #[error("failed to decode bytes as a signed transaction")]
+    Decode(#[source] prost::DecodeError),
+    #[error("`transaction` field not set")]
+    UnsetTransaction,
+    #[error("the unsigned transaction has the unknown type URL `{type_url}`")]
+    UnknownTypeUrl { type_url: String },
+    #[error("invalid v1alpha1 signed transaction")]
+    V1Alpha1(#[source] v1alpha1::SignedTransactionError),
+    #[error("invalid v2 signed transaction")]
+    V2(#[source] v2::SignedTransactionError),
+}
+
+impl ValidationError for TransactionVersionError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            TransactionVersionErrorKind::Decode(..) => {
+                ValidationErrorCode::new(3301, ValidationErrorCategory::Decode)
+            }
+            TransactionVersionErrorKind::UnsetTransaction => {
+                ValidationErrorCode::new(3302, ValidationErrorCategory::MissingField)
+            }
+            TransactionVersionErrorKind::UnknownTypeUrl {
+                ..
+            } => ValidationErrorCode::new(3303, ValidationErrorCategory::Unknown),
+            TransactionVersionErrorKind::V1Alpha1(source) => source.validation_code(),
+            TransactionVersionErrorKind::V2(source) => source.validation_code(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crypto::SigningKey;
+
+    fn params() -> v1alpha1::TransactionParams {
+        v1alpha1::TransactionParams::builder()
+            .nonce(1)
+            .chain_id("test-1")
+            .build()
+    }
+
+    #[test]
+    fn versions_are_detected_from_encoded_transactions() {
+        let signing_key = SigningKey::from([1; 32]);
+        let v1alpha1 = v1alpha1::UnsignedTransaction {
+            actions: vec![],
+            params: params(),
+        }
+        .into_signed(&signing_key);
+        let v2 = v2::UnsignedTransaction {
+            actions: vec![],
+            params: params(),
+        }
+        .into_signed(&signing_key);
+
+        let v1alpha1_bytes = v1alpha1.to_raw().encode_to_vec();
+        let v2_bytes = v2.to_raw().encode_to_vec();
+        assert_eq!(
+            TransactionVersion::V1Alpha1,
+            TransactionVersion::detect(&v1alpha1_bytes).unwrap()
+        );
+        assert_eq!(
+            TransactionVersion::V2,
+            TransactionVersion::detect(&v2_bytes).unwrap()
+        );
+
+        let decoded = VersionedSignedTransaction::try_from_bytes(&v2_bytes).unwrap();
+        assert_eq!(TransactionVersion::V2, decoded.version());
+        assert_eq!(v2_bytes, decoded.to_bytes());
+    }
+
+    #[test]
+    fn newest_common_version_is_negotiated() {
+        assert_eq!(
+            Some(TransactionVersion::V2),
+            TransactionVersion::negotiate(TransactionVersion::ALL)
+        );
+        assert_eq!(
+            Some(TransactionVersion::V1Alpha1),
+            TransactionVersion::negotiate([TransactionVersion::V1Alpha1])
+        );
+        assert_eq!(None, TransactionVersion::negotiate([]));
+    }
+}
@@ -15,6 +15,10 @@ use super::{
         SubmittedMetadata,
         SubmittedRollupData,
     },
+    fee_summary::{
+        FeeSummary,
+        FeeSummaryError,
+    },
     raw,
 };
 use crate::{
@@ -31,9 +35,51 @@ use crate::{
         SignedTransaction,
         SignedTransactionError,
     },
+    validation::{
+        ValidationError,
+        ValidationErrorCategory,
+        ValidationErrorCode,
+    },
     Protobuf as _,
 };
 
+/// The number of commitments at the start of the data of a sequencer block committing to its fee
+/// summary: the merkle roots of the rollup transactions, of the rollup IDs, and of the fee summary.
+/// The transactions of the block follow them.
+pub const BLOCK_COMMITMENT_COUNT: usize = 3;
+
+/// The number of commitments at the start of the data of a sequencer block not committing to its
+/// fee summary, as is the case for blocks before the fee summary activation height: the merkle
+/// roots of the rollup transactions and of the rollup IDs.
+pub const BLOCK_COMMITMENT_COUNT_WITHOUT_FEE_SUMMARY: usize = 2;
+
+/// The length in bytes of each commitment at the start of the data of a sequencer block.
+const BLOCK_COMMITMENT_LEN: usize = 32;
+
+/// Returns the number of commitments at the start of the data of a sequencer block, depending on
+/// whether the block commits to its fee summary.
+#[must_use]
+pub const fn block_commitment_count(commits_to_fee_summary: bool) -> usize {
+    if commits_to_fee_summary {
+        BLOCK_COMMITMENT_COUNT
+    } else {
+        BLOCK_COMMITMENT_COUNT_WITHOUT_FEE_SUMMARY
+    }
+}
+
+/// Returns the number of commitments at the start of `data`, the data of a sequencer block.
+///
+/// This is for consumers of blocks that do not know the fee summary activation height. Every
+/// transaction in a sequencer block is a signed transaction, whose encoding is longer than a
+/// commitment, so a third entry of the length of a commitment is the fee summary commitment.
+#[must_use]
+pub fn block_commitment_count_of_data<T: AsRef<[u8]>>(data: &[T]) -> usize {
+    let commits_to_fee_summary = data
+        .get(BLOCK_COMMITMENT_COUNT_WITHOUT_FEE_SUMMARY)
+        .is_some_and(|entry| entry.as_ref().len() == BLOCK_COMMITMENT_LEN);
+    block_commitment_count(commits_to_fee_summary)
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error(transparent)]
 pub struct RollupTransactionsError(RollupTransactionsErrorKind);
@@ -62,6 +108,22 @@ enum RollupTransactionsErrorKind {
     ProofInvalid(#[source] merkle::audit::InvalidProof),
 }
 
+impl ValidationError for RollupTransactionsError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            RollupTransactionsErrorKind::RollupId(..) => {
+                ValidationErrorCode::new(5001, ValidationErrorCategory::InvalidField)
+            }
+            RollupTransactionsErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(5002, ValidationErrorCategory::MissingField)
+            }
+            RollupTransactionsErrorKind::ProofInvalid(..) => {
+                ValidationErrorCode::new(5003, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
+}
+
 /// The individual parts that make up a [`RollupTransactions`] type.
 ///
 /// Provides convenient access to the fields of [`RollupTransactions`].
@@ -74,6 +136,11 @@ pub struct RollupTransactionsParts {
 
 /// The opaque transactions belonging to a rollup identified by its rollup ID.
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::RollupTransactions", try_from = "raw::RollupTransactions")
+)]
 pub struct RollupTransactions {
     /// The 32 bytes identifying a rollup. Usually the sha256 hash of a plain rollup name.
     rollup_id: RollupId,
@@ -83,6 +150,20 @@ pub struct RollupTransactions {
     proof: merkle::Proof,
 }
 
+impl From<RollupTransactions> for raw::RollupTransactions {
+    fn from(value: RollupTransactions) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::RollupTransactions> for RollupTransactions {
+    type Error = RollupTransactionsError;
+
+    fn try_from(raw: raw::RollupTransactions) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
 impl RollupTransactions {
     /// Returns the [`RollupId`] identifying the rollup these transactions belong to.
     #[must_use]
@@ -243,6 +324,30 @@ impl SequencerBlockError {
     fn invalid_rollup_ids_proof() -> Self {
         Self(SequencerBlockErrorKind::InvalidRollupIdsProof)
     }
+
+    fn fee_summary(source: FeeSummaryError) -> Self {
+        Self(SequencerBlockErrorKind::FeeSummary(source))
+    }
+
+    fn fee_summary_proof_invalid(source: merkle::audit::InvalidProof) -> Self {
+        Self(SequencerBlockErrorKind::FeeSummaryProofInvalid(source))
+    }
+
+    fn no_fee_summary_root() -> Self {
+        Self(SequencerBlockErrorKind::NoFeeSummaryRoot)
+    }
+
+    fn incorrect_fee_summary_root_length(len: usize) -> Self {
+        Self(SequencerBlockErrorKind::IncorrectFeeSummaryRootLength(len))
+    }
+
+    fn fee_summary_root_does_not_match_reconstructed() -> Self {
+        Self(SequencerBlockErrorKind::FeeSummaryRootDoesNotMatchReconstructed)
+    }
+
+    fn invalid_fee_summary_proof() -> Self {
+        Self(SequencerBlockErrorKind::InvalidFeeSummaryProof)
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -319,6 +424,100 @@ enum SequencerBlockErrorKind {
          data_hash given the rollup IDs proof"
     )]
     InvalidRollupIdsProof,
+    #[error("failed constructing a fee summary from the raw protobuf fee summary")]
+    FeeSummary(#[source] FeeSummaryError),
+    #[error("failed constructing a fee summary proof from the raw protobuf fee summary proof")]
+    FeeSummaryProofInvalid(#[source] merkle::audit::InvalidProof),
+    #[error("the cometbft block.data field was too short and did not contain the fee summary root")]
+    NoFeeSummaryRoot,
+    #[error(
+        "the fee summary root in the cometbft block.data field was expected to be 32 bytes long, \
+         but was actually `{0}`"
+    )]
+    IncorrectFeeSummaryRootLength(usize),
+    #[error(
+        "the root derived from the fees paid in the block did not match the root stored in the \
+         cometbft block.data field"
+    )]
+    FeeSummaryRootDoesNotMatchReconstructed,
+    #[error(
+        "the fee summary root constructed from the block's fee summary did not verify against \
+         data_hash given the fee summary proof"
+    )]
+    InvalidFeeSummaryProof,
+}
+
+impl ValidationError for SequencerBlockError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            SequencerBlockErrorKind::InvalidBlockHash(..) => {
+                ValidationErrorCode::new(5101, ValidationErrorCategory::InvalidField)
+            }
+            SequencerBlockErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(5102, ValidationErrorCategory::MissingField)
+            }
+            SequencerBlockErrorKind::Header(source) => source.validation_code(),
+            SequencerBlockErrorKind::ParseRollupTransactions(source) => source.validation_code(),
+            SequencerBlockErrorKind::TransactionProofInvalid(..) => {
+                ValidationErrorCode::new(5105, ValidationErrorCategory::InvalidField)
+            }
+            SequencerBlockErrorKind::IdProofInvalid(..) => {
+                ValidationErrorCode::new(5106, ValidationErrorCategory::InvalidField)
+            }
+            SequencerBlockErrorKind::NoRollupTransactionsRoot => {
+                ValidationErrorCode::new(5107, ValidationErrorCategory::MissingField)
+            }
+            SequencerBlockErrorKind::IncorrectRollupTransactionsRootLength(..) => {
+                ValidationErrorCode::new(5108, ValidationErrorCategory::InvalidField)
+            }
+            SequencerBlockErrorKind::NoRollupIdsRoot => {
+                ValidationErrorCode::new(5109, ValidationErrorCategory::MissingField)
+            }
+            SequencerBlockErrorKind::IncorrectRollupIdsRootLength(..) => {
+                ValidationErrorCode::new(5110, ValidationErrorCategory::InvalidField)
+            }
+            SequencerBlockErrorKind::RollupTransactionsNotInSequencerBlock => {
+                ValidationErrorCode::new(5111, ValidationErrorCategory::Proof)
+            }
+            SequencerBlockErrorKind::RollupIdsNotInSequencerBlock => {
+                ValidationErrorCode::new(5112, ValidationErrorCategory::Proof)
+            }
+            SequencerBlockErrorKind::SignedTransactionProtobufDecode(..) => {
+                ValidationErrorCode::new(5113, ValidationErrorCategory::Decode)
+            }
+            SequencerBlockErrorKind::RawSignedTransactionConversion(source) => {
+                source.validation_code()
+            }
+            SequencerBlockErrorKind::RollupTransactionsRootDoesNotMatchReconstructed => {
+                ValidationErrorCode::new(5115, ValidationErrorCategory::Proof)
+            }
+            SequencerBlockErrorKind::RollupIdsRootDoesNotMatchReconstructed => {
+                ValidationErrorCode::new(5116, ValidationErrorCategory::Proof)
+            }
+            SequencerBlockErrorKind::InvalidRollupTransactionsRoot => {
+                ValidationErrorCode::new(5117, ValidationErrorCategory::Proof)
+            }
+            SequencerBlockErrorKind::InvalidRollupIdsProof => {
+                ValidationErrorCode::new(5118, ValidationErrorCategory::Proof)
+            }
+            SequencerBlockErrorKind::FeeSummary(source) => source.validation_code(),
+            SequencerBlockErrorKind::FeeSummaryProofInvalid(..) => {
+                ValidationErrorCode::new(5119, ValidationErrorCategory::InvalidField)
+            }
+            SequencerBlockErrorKind::NoFeeSummaryRoot => {
+                ValidationErrorCode::new(5120, ValidationErrorCategory::MissingField)
+            }
+            SequencerBlockErrorKind::IncorrectFeeSummaryRootLength(..) => {
+                ValidationErrorCode::new(5121, ValidationErrorCategory::InvalidField)
+            }
+            SequencerBlockErrorKind::FeeSummaryRootDoesNotMatchReconstructed => {
+                ValidationErrorCode::new(5122, ValidationErrorCategory::Proof)
+            }
+            SequencerBlockErrorKind::InvalidFeeSummaryProof => {
+                ValidationErrorCode::new(5123, ValidationErrorCategory::Proof)
+            }
+        }
+    }
 }
 
 /// The individual parts that make up a [`SequencerBlockHeader`].
@@ -336,6 +535,14 @@ pub struct SequencerBlockHeaderParts {
 }
 
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::SequencerBlockHeader",
+        try_from = "raw::SequencerBlockHeader"
+    )
+)]
 pub struct SequencerBlockHeader {
     chain_id: tendermint::chain::Id,
     height: tendermint::block::Height,
@@ -346,6 +553,20 @@ pub struct SequencerBlockHeader {
     proposer_address: account::Id,
 }
 
+impl From<SequencerBlockHeader> for raw::SequencerBlockHeader {
+    fn from(value: SequencerBlockHeader) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::SequencerBlockHeader> for SequencerBlockHeader {
+    type Error = SequencerBlockHeaderError;
+
+    fn try_from(raw: raw::SequencerBlockHeader) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
 impl SequencerBlockHeader {
     #[must_use]
     pub fn chain_id(&self) -> &tendermint::chain::Id {
@@ -521,6 +742,31 @@ enum SequencerBlockHeaderErrorKind {
     ProposerAddress(#[source] tendermint::Error),
 }
 
+impl ValidationError for SequencerBlockHeaderError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            SequencerBlockHeaderErrorKind::InvalidChainId(..) => {
+                ValidationErrorCode::new(5201, ValidationErrorCategory::InvalidField)
+            }
+            SequencerBlockHeaderErrorKind::InvalidHeight(..) => {
+                ValidationErrorCode::new(5202, ValidationErrorCategory::InvalidField)
+            }
+            SequencerBlockHeaderErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(5203, ValidationErrorCategory::MissingField)
+            }
+            SequencerBlockHeaderErrorKind::Time(..) => {
+                ValidationErrorCode::new(5204, ValidationErrorCategory::InvalidField)
+            }
+            SequencerBlockHeaderErrorKind::IncorrectRollupTransactionsRootLength(..) => {
+                ValidationErrorCode::new(5205, ValidationErrorCategory::InvalidField)
+            }
+            SequencerBlockHeaderErrorKind::ProposerAddress(..) => {
+                ValidationErrorCode::new(5206, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
+}
+
 /// The individual parts that make up a [`SequencerBlock`].
 ///
 /// Exists to provide convenient access to fields of a [`SequencerBlock`].
@@ -532,12 +778,19 @@ pub struct SequencerBlockParts {
     pub rollup_transactions: IndexMap<RollupId, RollupTransactions>,
     pub rollup_transactions_proof: merkle::Proof,
     pub rollup_ids_proof: merkle::Proof,
+    pub fee_summary: Option<FeeSummary>,
+    pub fee_summary_proof: Option<merkle::Proof>,
 }
 
 /// `SequencerBlock` is constructed from a tendermint/cometbft block by
 /// converting its opaque `data` bytes into sequencer specific types.
 #[derive(Clone, Debug, PartialEq)]
 #[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::SequencerBlock", try_from = "raw::SequencerBlock")
+)]
 pub struct SequencerBlock {
     /// The result of hashing the cometbft header. Guaranteed to not be `None` as compared to
     /// the cometbft/tendermint-rs return type.
@@ -559,6 +812,27 @@ pub struct SequencerBlock {
     // `MTH(rollup_ids)` is the Merkle Tree Hash derived from the rollup IDs listed in
     // the rollup transactions.
     rollup_ids_proof: merkle::Proof,
+    /// The fees paid in this block, summed up per asset. Only set if the block commits to its
+    /// fee summary, i.e. if it is at or after the fee summary activation height.
+    fee_summary: Option<FeeSummary>,
+    // The proof that the fee summary is included in the `CometBFT` block this sequencer
+    // block is derived from. This proof together with `Sha256(fee_summary.root())` must
+    // match `header.data_hash`. Set if and only if `fee_summary` is set.
+    fee_summary_proof: Option<merkle::Proof>,
+}
+
+impl From<SequencerBlock> for raw::SequencerBlock {
+    fn from(value: SequencerBlock) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::SequencerBlock> for SequencerBlock {
+    type Error = SequencerBlockError;
+
+    fn try_from(raw: raw::SequencerBlock) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
 }
 
 impl SequencerBlock {
@@ -586,6 +860,23 @@ impl SequencerBlock {
         &self.rollup_transactions
     }
 
+    /// The fees paid in this block, summed up per asset.
+    ///
+    /// Returns `None` if the block does not commit to its fee summary, as is the case for blocks
+    /// before the fee summary activation height.
+    #[must_use]
+    pub fn fee_summary(&self) -> Option<&FeeSummary> {
+        self.fee_summary.as_ref()
+    }
+
+    /// The proof that the fee summary is included in the block's data hash.
+    ///
+    /// Returns `None` if and only if [`SequencerBlock::fee_summary`] does.
+    #[must_use]
+    pub fn fee_summary_proof(&self) -> Option<&merkle::Proof> {
+        self.fee_summary_proof.as_ref()
+    }
+
     /// Converts a [`SequencerBlock`] into its [`SequencerBlockParts`].
     #[must_use]
     pub fn into_parts(self) -> SequencerBlockParts {
@@ -595,6 +886,8 @@ impl SequencerBlock {
             rollup_transactions,
             rollup_transactions_proof,
             rollup_ids_proof,
+            fee_summary,
+            fee_summary_proof,
         } = self;
         SequencerBlockParts {
             block_hash,
@@ -602,6 +895,8 @@ impl SequencerBlock {
             rollup_transactions,
             rollup_transactions_proof,
             rollup_ids_proof,
+            fee_summary,
+            fee_summary_proof,
         }
     }
 
@@ -611,6 +906,15 @@ impl SequencerBlock {
         self.rollup_transactions
     }
 
+    /// Returns the length of the protobuf encoding of the block.
+    ///
+    /// This clones the block to encode it, and so is as expensive as [`SequencerBlock::into_raw`].
+    #[must_use]
+    pub fn encoded_size(&self) -> usize {
+        use prost::Message as _;
+        self.clone().into_raw().encoded_len()
+    }
+
     #[must_use]
     pub fn into_raw(self) -> raw::SequencerBlock {
         let Self {
@@ -619,6 +923,8 @@ impl SequencerBlock {
             rollup_transactions,
             rollup_transactions_proof,
             rollup_ids_proof,
+            fee_summary,
+            fee_summary_proof,
         } = self;
         raw::SequencerBlock {
             block_hash: block_hash.to_vec(),
@@ -629,6 +935,8 @@ impl SequencerBlock {
                 .collect(),
             rollup_transactions_proof: Some(rollup_transactions_proof.into_raw()),
             rollup_ids_proof: Some(rollup_ids_proof.into_raw()),
+            fee_summary: fee_summary.map(FeeSummary::into_raw),
+            fee_summary_proof: fee_summary_proof.map(merkle::Proof::into_raw),
         }
     }
 
@@ -684,6 +992,30 @@ impl SequencerBlock {
         }
     }
 
+    /// Returns the rollup data of `rollup_ids` as a [`BlockDelta`].
+    ///
+    /// Rollups without data in this block are omitted, so the delta of a block in which none of
+    /// `rollup_ids` were active is empty.
+    #[must_use]
+    pub fn to_block_delta<I, R>(&self, rollup_ids: I) -> BlockDelta
+    where
+        I: IntoIterator<Item = R>,
+        RollupId: From<R>,
+    {
+        let mut rollup_transactions = IndexMap::new();
+        for id in rollup_ids {
+            let id = id.into();
+            if let Some(txs) = self.rollup_transactions.get(&id).cloned() {
+                rollup_transactions.insert(id, txs);
+            }
+        }
+        BlockDelta {
+            block_hash: self.block_hash,
+            height: self.header.height,
+            rollup_transactions,
+        }
+    }
+
     /// Turn the sequencer block into a [`SubmittedMetadata`] and list of [`SubmittedRollupData`].
     #[must_use]
     pub fn split_for_celestia(self) -> (SubmittedMetadata, Vec<SubmittedRollupData>) {
@@ -692,6 +1024,11 @@ impl SequencerBlock {
 
     /// Converts from relevant header fields and the block data.
     ///
+    /// `deposits` and `fee_summary` are the results of executing the block, and are verified
+    /// against the commitments at the start of `data`. `fee_summary` must be set if and only if
+    /// the block commits to its fee summary, i.e. if `data` starts with [`BLOCK_COMMITMENT_COUNT`]
+    /// rather than [`BLOCK_COMMITMENT_COUNT_WITHOUT_FEE_SUMMARY`] commitments.
+    ///
     /// # Errors
     /// TODO(https://github.com/astriaorg/astria/issues/612)
     ///
@@ -706,6 +1043,7 @@ impl SequencerBlock {
         proposer_address: account::Id,
         data: Vec<Vec<u8>>,
         deposits: HashMap<RollupId, Vec<Deposit>>,
+        fee_summary: Option<FeeSummary>,
     ) -> Result<Self, SequencerBlockError> {
         use prost::Message as _;
 
@@ -727,6 +1065,19 @@ impl SequencerBlock {
             .try_into()
             .map_err(|e: Vec<_>| SequencerBlockError::incorrect_rollup_ids_root_length(e.len()))?;
 
+        if let Some(fee_summary) = &fee_summary {
+            let fee_summary_root: [u8; 32] = data_list
+                .next()
+                .ok_or(SequencerBlockError::no_fee_summary_root())?
+                .try_into()
+                .map_err(|e: Vec<_>| {
+                    SequencerBlockError::incorrect_fee_summary_root_length(e.len())
+                })?;
+            if fee_summary_root != fee_summary.root() {
+                return Err(SequencerBlockError::fee_summary_root_does_not_match_reconstructed());
+            }
+        }
+
         let mut rollup_datas = IndexMap::new();
         for elem in data_list {
             let raw_tx =
@@ -801,6 +1152,14 @@ impl SequencerBlock {
              returns None it means that the short circuiting checks above it have been removed",
         );
 
+        let fee_summary_proof = fee_summary.is_some().then(|| {
+            tree.construct_proof(2).expect(
+                "the tree has at least three leaves if the fee summary is set; if this line is \
+                 reached and `construct_proof` returns None it means that the short circuiting \
+                 checks above it have been removed",
+            )
+        });
+
         Ok(Self {
             block_hash,
             header: SequencerBlockHeader {
@@ -814,6 +1173,8 @@ impl SequencerBlock {
             rollup_transactions,
             rollup_transactions_proof,
             rollup_ids_proof,
+            fee_summary,
+            fee_summary_proof,
         })
     }
 
@@ -837,6 +1198,8 @@ impl SequencerBlock {
             rollup_transactions,
             rollup_transactions_proof,
             rollup_ids_proof,
+            fee_summary,
+            fee_summary_proof,
         } = raw;
 
         let block_hash = block_hash
@@ -859,6 +1222,24 @@ impl SequencerBlock {
             merkle::Proof::try_from_raw(rollup_ids_proof)
                 .map_err(SequencerBlockError::id_proof_invalid)
         }?;
+        // blocks before the fee summary activation height carry neither
+        let (fee_summary, fee_summary_proof) = match (fee_summary, fee_summary_proof) {
+            (None, None) => (None, None),
+            (Some(fee_summary), Some(fee_summary_proof)) => (
+                Some(
+                    FeeSummary::try_from_raw(fee_summary)
+                        .map_err(SequencerBlockError::fee_summary)?,
+                ),
+                Some(
+                    merkle::Proof::try_from_raw(fee_summary_proof)
+                        .map_err(SequencerBlockError::fee_summary_proof_invalid)?,
+                ),
+            ),
+            (Some(_), None) => {
+                return Err(SequencerBlockError::field_not_set("fee_summary_proof"));
+            }
+            (None, Some(_)) => return Err(SequencerBlockError::field_not_set("fee_summary")),
+        };
         let header = 'header: {
             let Some(header) = header else {
                 break 'header Err(SequencerBlockError::field_not_set("header"));
@@ -885,7 +1266,19 @@ impl SequencerBlock {
             return Err(SequencerBlockError::invalid_rollup_ids_proof());
         };
 
-        if !are_rollup_txs_included(&rollup_transactions, &rollup_transactions_proof, data_hash) {
+        if let (Some(fee_summary), Some(fee_summary_proof)) = (&fee_summary, &fee_summary_proof) {
+            if !fee_summary_proof.verify(&Sha256::digest(fee_summary.root()), data_hash) {
+                return Err(SequencerBlockError::invalid_fee_summary_proof());
+            }
+        }
+
+        if !are_rollup_txs_included(
+            rollup_transactions
+                .iter()
+                .map(|(rollup_id, txs)| (rollup_id, txs.transactions())),
+            &rollup_transactions_proof,
+            data_hash,
+        ) {
             return Err(SequencerBlockError::rollup_transactions_not_in_sequencer_block());
         }
         if !are_rollup_ids_included(
@@ -902,6 +1295,274 @@ impl SequencerBlock {
             rollup_transactions,
             rollup_transactions_proof,
             rollup_ids_proof,
+            fee_summary,
+            fee_summary_proof,
+        })
+    }
+}
+
+/// A borrowed view of the transactions of a single rollup in a raw [`raw::SequencerBlock`].
+///
+/// Unlike [`RollupTransactions`], the transactions are not copied out of the raw type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RollupTransactionsRef<'a> {
+    rollup_id: RollupId,
+    transactions: &'a [Vec<u8>],
+    proof: merkle::Proof,
+}
+
+impl<'a> RollupTransactionsRef<'a> {
+    /// Returns the [`RollupId`] identifying the rollup these transactions belong to.
+    #[must_use]
+    pub fn rollup_id(&self) -> RollupId {
+        self.rollup_id
+    }
+
+    /// Returns the block data for this rollup, borrowed from the raw sequencer block.
+    #[must_use]
+    pub fn transactions(&self) -> &'a [Vec<u8>] {
+        self.transactions
+    }
+
+    /// Returns the merkle proof that these transactions were included
+    /// in the `action_tree_commitment`.
+    #[must_use]
+    pub fn proof(&self) -> &merkle::Proof {
+        &self.proof
+    }
+
+    /// Copies the borrowed transactions into an owned [`RollupTransactions`].
+    #[must_use]
+    pub fn to_rollup_transactions(&self) -> RollupTransactions {
+        RollupTransactions {
+            rollup_id: self.rollup_id,
+            transactions: self.transactions.to_vec(),
+            proof: self.proof.clone(),
+        }
+    }
+
+    /// Attempts to construct a view of the rollup transactions from their raw representation.
+    ///
+    /// # Errors
+    /// Returns an error if the rollup ID bytes could not be turned into a [`RollupId`], or if
+    /// the proof is missing or invalid.
+    pub fn try_from_raw(raw: &'a raw::RollupTransactions) -> Result<Self, RollupTransactionsError> {
+        let raw::RollupTransactions {
+            rollup_id,
+            transactions,
+            proof,
+        } = raw;
+        let Some(rollup_id) = rollup_id else {
+            return Err(RollupTransactionsError::field_not_set("rollup_id"));
+        };
+        let rollup_id =
+            RollupId::try_from_raw(rollup_id).map_err(RollupTransactionsError::rollup_id)?;
+        let Some(proof) = proof else {
+            return Err(RollupTransactionsError::field_not_set("proof"));
+        };
+        let proof = merkle::Proof::try_from_raw_ref(proof)
+            .map_err(RollupTransactionsError::proof_invalid)?;
+        Ok(Self {
+            rollup_id,
+            transactions,
+            proof,
+        })
+    }
+}
+
+/// A borrowed view of a raw [`raw::SequencerBlock`], verified like a [`SequencerBlock`].
+///
+/// Constructing a [`SequencerBlockRef`] performs the same validation of the header and the
+/// rollup transactions and rollup IDs proofs as [`SequencerBlock::try_from_raw`], but borrows
+/// the rollup transactions from the raw block instead of taking ownership of them. This makes
+/// it suitable for verifying blocks that are only inspected, or of which only a few rollups
+/// are of interest.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SequencerBlockRef<'a> {
+    block_hash: [u8; 32],
+    header: SequencerBlockHeader,
+    rollup_transactions: IndexMap<RollupId, RollupTransactionsRef<'a>>,
+    rollup_transactions_proof: merkle::Proof,
+    rollup_ids_proof: merkle::Proof,
+    fee_summary: Option<FeeSummary>,
+    fee_summary_proof: Option<merkle::Proof>,
+}
+
+impl<'a> SequencerBlockRef<'a> {
+    /// Returns the hash of the `CometBFT` block this sequencer block is derived from.
+    #[must_use]
+    pub fn block_hash(&self) -> [u8; 32] {
+        self.block_hash
+    }
+
+    #[must_use]
+    pub fn header(&self) -> &SequencerBlockHeader {
+        &self.header
+    }
+
+    #[must_use]
+    pub fn height(&self) -> tendermint::block::Height {
+        self.header.height
+    }
+
+    /// The rollup transactions contained in the block, keyed by their rollup ID.
+    #[must_use]
+    pub fn rollup_transactions(&self) -> &IndexMap<RollupId, RollupTransactionsRef<'a>> {
+        &self.rollup_transactions
+    }
+
+    #[must_use]
+    pub fn rollup_transactions_proof(&self) -> &merkle::Proof {
+        &self.rollup_transactions_proof
+    }
+
+    #[must_use]
+    pub fn rollup_ids_proof(&self) -> &merkle::Proof {
+        &self.rollup_ids_proof
+    }
+
+    #[must_use]
+    pub fn fee_summary(&self) -> Option<&FeeSummary> {
+        self.fee_summary.as_ref()
+    }
+
+    #[must_use]
+    pub fn fee_summary_proof(&self) -> Option<&merkle::Proof> {
+        self.fee_summary_proof.as_ref()
+    }
+
+    /// Copies the borrowed rollup transactions into an owned [`SequencerBlock`].
+    #[must_use]
+    pub fn to_sequencer_block(&self) -> SequencerBlock {
+        SequencerBlock {
+            block_hash: self.block_hash,
+            header: self.header.clone(),
+            rollup_transactions: self
+                .rollup_transactions
+                .iter()
+                .map(|(rollup_id, txs)| (*rollup_id, txs.to_rollup_transactions()))
+                .collect(),
+            rollup_transactions_proof: self.rollup_transactions_proof.clone(),
+            rollup_ids_proof: self.rollup_ids_proof.clone(),
+            fee_summary: self.fee_summary.clone(),
+            fee_summary_proof: self.fee_summary_proof.clone(),
+        }
+    }
+
+    /// Verifies a raw sequencer block without copying its rollup transactions.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`SequencerBlock::try_from_raw`].
+    pub fn try_from_raw(raw: &'a raw::SequencerBlock) -> Result<Self, SequencerBlockError> {
+        use sha2::Digest as _;
+
+        let raw::SequencerBlock {
+            block_hash,
+            header,
+            rollup_transactions,
+            rollup_transactions_proof,
+            rollup_ids_proof,
+            fee_summary,
+            fee_summary_proof,
+        } = raw;
+
+        let block_hash = block_hash
+            .as_slice()
+            .try_into()
+            .map_err(|_| SequencerBlockError::invalid_block_hash(block_hash.len()))?;
+
+        let Some(rollup_transactions_proof) = rollup_transactions_proof else {
+            return Err(SequencerBlockError::field_not_set(
+                "rollup_transactions_proof",
+            ));
+        };
+        let rollup_transactions_proof = merkle::Proof::try_from_raw_ref(rollup_transactions_proof)
+            .map_err(SequencerBlockError::transaction_proof_invalid)?;
+        let Some(rollup_ids_proof) = rollup_ids_proof else {
+            return Err(SequencerBlockError::field_not_set("rollup_ids_proof"));
+        };
+        let rollup_ids_proof = merkle::Proof::try_from_raw_ref(rollup_ids_proof)
+            .map_err(SequencerBlockError::id_proof_invalid)?;
+        // blocks before the fee summary activation height carry neither
+        let (fee_summary, fee_summary_proof) = match (fee_summary, fee_summary_proof) {
+            (None, None) => (None, None),
+            (Some(fee_summary), Some(fee_summary_proof)) => (
+                // a block pays fees in few assets, so cloning the summary is cheap
+                Some(
+                    FeeSummary::try_from_raw(fee_summary.clone())
+                        .map_err(SequencerBlockError::fee_summary)?,
+                ),
+                Some(
+                    merkle::Proof::try_from_raw_ref(fee_summary_proof)
+                        .map_err(SequencerBlockError::fee_summary_proof_invalid)?,
+                ),
+            ),
+            (Some(_), None) => {
+                return Err(SequencerBlockError::field_not_set("fee_summary_proof"));
+            }
+            (None, Some(_)) => return Err(SequencerBlockError::field_not_set("fee_summary")),
+        };
+        let Some(header) = header else {
+            return Err(SequencerBlockError::field_not_set("header"));
+        };
+        // the header holds no rollup data, so cloning it is cheap
+        let header = SequencerBlockHeader::try_from_raw(header.clone())
+            .map_err(SequencerBlockError::header)?;
+
+        let rollup_transactions: IndexMap<RollupId, RollupTransactionsRef<'a>> =
+            rollup_transactions
+                .iter()
+                .map(|raw| {
+                    let rollup_transactions = RollupTransactionsRef::try_from_raw(raw)?;
+                    Ok((rollup_transactions.rollup_id, rollup_transactions))
+                })
+                .collect::<Result<_, _>>()
+                .map_err(SequencerBlockError::parse_rollup_transactions)?;
+
+        let data_hash = header.data_hash;
+
+        if !rollup_transactions_proof
+            .verify(&Sha256::digest(header.rollup_transactions_root), data_hash)
+        {
+            return Err(SequencerBlockError::invalid_rollup_transactions_root());
+        };
+
+        let rollup_ids_root = merkle::Tree::from_leaves(rollup_transactions.keys()).root();
+        if !rollup_ids_proof.verify(&Sha256::digest(rollup_ids_root), data_hash) {
+            return Err(SequencerBlockError::invalid_rollup_ids_proof());
+        };
+
+        if let (Some(fee_summary), Some(fee_summary_proof)) = (&fee_summary, &fee_summary_proof) {
+            if !fee_summary_proof.verify(&Sha256::digest(fee_summary.root()), data_hash) {
+                return Err(SequencerBlockError::invalid_fee_summary_proof());
+            }
+        }
+
+        if !are_rollup_txs_included(
+            rollup_transactions
+                .iter()
+                .map(|(rollup_id, txs)| (rollup_id, txs.transactions)),
+            &rollup_transactions_proof,
+            data_hash,
+        ) {
+            return Err(SequencerBlockError::rollup_transactions_not_in_sequencer_block());
+        }
+        if !are_rollup_ids_included(
+            rollup_transactions.keys().copied(),
+            &rollup_ids_proof,
+            data_hash,
+        ) {
+            return Err(SequencerBlockError::rollup_ids_not_in_sequencer_block());
+        }
+
+        Ok(Self {
+            block_hash,
+            header,
+            rollup_transactions,
+            rollup_transactions_proof,
+            rollup_ids_proof,
+            fee_summary,
+            fee_summary_proof,
         })
     }
 }
@@ -940,6 +1601,14 @@ pub struct FilteredSequencerBlockParts {
 
 #[derive(Debug, Clone, PartialEq)]
 #[allow(clippy::module_name_repetitions)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(
+        into = "raw::FilteredSequencerBlock",
+        try_from = "raw::FilteredSequencerBlock"
+    )
+)]
 pub struct FilteredSequencerBlock {
     block_hash: [u8; 32],
     header: SequencerBlockHeader,
@@ -953,6 +1622,20 @@ pub struct FilteredSequencerBlock {
     rollup_ids_proof: merkle::Proof,
 }
 
+impl From<FilteredSequencerBlock> for raw::FilteredSequencerBlock {
+    fn from(value: FilteredSequencerBlock) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::FilteredSequencerBlock> for FilteredSequencerBlock {
+    type Error = FilteredSequencerBlockError;
+
+    fn try_from(raw: raw::FilteredSequencerBlock) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
 impl FilteredSequencerBlock {
     #[must_use]
     pub fn block_hash(&self) -> [u8; 32] {
@@ -1138,6 +1821,65 @@ impl FilteredSequencerBlock {
         })
     }
 
+    /// Returns those of `rollup_ids` that have data in the sequencer block but whose rollup
+    /// transactions are not contained in this filtered block.
+    ///
+    /// These are the rollups for which a [`BlockDelta`] has to be fetched.
+    #[must_use]
+    pub fn missing_rollup_ids<I, R>(&self, rollup_ids: I) -> Vec<RollupId>
+    where
+        I: IntoIterator<Item = R>,
+        RollupId: From<R>,
+    {
+        rollup_ids
+            .into_iter()
+            .map(RollupId::from)
+            .filter(|id| {
+                self.all_rollup_ids.contains(id) && !self.rollup_transactions.contains_key(id)
+            })
+            .collect()
+    }
+
+    /// Verifies `delta` against this filtered block and adds its rollup transactions.
+    ///
+    /// Rollup transactions already contained in this filtered block are replaced by those in
+    /// the delta.
+    ///
+    /// # Errors
+    /// Returns an error and leaves the filtered block unchanged if:
+    /// - the delta is for a different block
+    /// - the delta contains rollup transactions for a rollup ID without data in the block
+    /// - the rollup transactions of the delta could not be verified against the rollup transactions
+    ///   root
+    pub fn apply_delta(&mut self, delta: BlockDelta) -> Result<(), BlockDeltaError> {
+        let BlockDelta {
+            block_hash,
+            rollup_transactions,
+            ..
+        } = delta;
+        if block_hash != self.block_hash {
+            return Err(BlockDeltaError::block_hash_mismatch(
+                self.block_hash,
+                block_hash,
+            ));
+        }
+        for (id, txs) in &rollup_transactions {
+            if !self.all_rollup_ids.contains(id) {
+                return Err(BlockDeltaError::rollup_id_not_in_block(*id));
+            }
+            if txs.rollup_id() != *id
+                || !super::do_rollup_transaction_match_root(
+                    txs,
+                    self.header.rollup_transactions_root,
+                )
+            {
+                return Err(BlockDeltaError::rollup_transactions_not_in_block(*id));
+            }
+        }
+        self.rollup_transactions.extend(rollup_transactions);
+        Ok(())
+    }
+
     /// Transforms the filtered blocks into its constituent parts.
     #[must_use]
     pub fn into_parts(self) -> FilteredSequencerBlockParts {
@@ -1196,6 +1938,41 @@ enum FilteredSequencerBlockErrorKind {
     IdProofInvalid(merkle::audit::InvalidProof),
 }
 
+impl ValidationError for FilteredSequencerBlockError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            FilteredSequencerBlockErrorKind::InvalidBlockHash(..) => {
+                ValidationErrorCode::new(5301, ValidationErrorCategory::InvalidField)
+            }
+            FilteredSequencerBlockErrorKind::InvalidHeader(source) => source.validation_code(),
+            FilteredSequencerBlockErrorKind::InvalidRollupId(..) => {
+                ValidationErrorCode::new(5303, ValidationErrorCategory::InvalidField)
+            }
+            FilteredSequencerBlockErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(5304, ValidationErrorCategory::MissingField)
+            }
+            FilteredSequencerBlockErrorKind::ParseRollupTransactions(source) => {
+                source.validation_code()
+            }
+            FilteredSequencerBlockErrorKind::RollupTransactionsNotInSequencerBlock => {
+                ValidationErrorCode::new(5306, ValidationErrorCategory::Proof)
+            }
+            FilteredSequencerBlockErrorKind::RollupTransactionForIdNotInSequencerBlock {
+                ..
+            } => ValidationErrorCode::new(5307, ValidationErrorCategory::Proof),
+            FilteredSequencerBlockErrorKind::RollupIdsNotInSequencerBlock => {
+                ValidationErrorCode::new(5308, ValidationErrorCategory::Proof)
+            }
+            FilteredSequencerBlockErrorKind::TransactionProofInvalid(..) => {
+                ValidationErrorCode::new(5309, ValidationErrorCategory::InvalidField)
+            }
+            FilteredSequencerBlockErrorKind::IdProofInvalid(..) => {
+                ValidationErrorCode::new(5310, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
+}
+
 impl FilteredSequencerBlockError {
     fn invalid_block_hash(len: usize) -> Self {
         Self(FilteredSequencerBlockErrorKind::InvalidBlockHash(len))
@@ -1246,15 +2023,116 @@ impl FilteredSequencerBlockError {
     }
 }
 
+/// The rollup data of a sequencer block for a set of rollups that were not part of a prior
+/// fetch of the block.
+///
+/// A client holding a [`FilteredSequencerBlock`] can extend it to further rollups by applying a
+/// delta with [`FilteredSequencerBlock::apply_delta`], which verifies the delta's rollup
+/// transactions against the header of the filtered block. The delta itself carries no header,
+/// so that streaming the blocks of rollups with sparse activity only costs the data of those
+/// rollups that were actually active.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockDelta {
+    block_hash: [u8; 32],
+    height: tendermint::block::Height,
+    rollup_transactions: IndexMap<RollupId, RollupTransactions>,
+}
+
+impl BlockDelta {
+    /// Returns the hash of the sequencer block this delta is for.
+    #[must_use]
+    pub fn block_hash(&self) -> [u8; 32] {
+        self.block_hash
+    }
+
+    /// Returns the height of the sequencer block this delta is for.
+    #[must_use]
+    pub fn height(&self) -> tendermint::block::Height {
+        self.height
+    }
+
+    /// Returns the rollup transactions contained in this delta.
+    #[must_use]
+    pub fn rollup_transactions(&self) -> &IndexMap<RollupId, RollupTransactions> {
+        &self.rollup_transactions
+    }
+
+    /// Returns `true` if none of the rollups of this delta had data in the block.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.rollup_transactions.is_empty()
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct BlockDeltaError(BlockDeltaErrorKind);
+
+impl BlockDeltaError {
+    fn block_hash_mismatch(expected: [u8; 32], actual: [u8; 32]) -> Self {
+        Self(BlockDeltaErrorKind::BlockHashMismatch {
+            expected: hex::encode(expected),
+            actual: hex::encode(actual),
+        })
+    }
+
+    fn rollup_id_not_in_block(id: RollupId) -> Self {
+        Self(BlockDeltaErrorKind::RollupIdNotInBlock {
+            id,
+        })
+    }
+
+    fn rollup_transactions_not_in_block(id: RollupId) -> Self {
+        Self(BlockDeltaErrorKind::RollupTransactionsNotInBlock {
+            id,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum BlockDeltaErrorKind {
+    #[error("the delta is for block `{actual}`, but the filtered block is `{expected}`")]
+    BlockHashMismatch { expected: String, actual: String },
+    #[error(
+        "the delta contains rollup transactions for rollup ID `{id}` which has no data in the \
+         block"
+    )]
+    RollupIdNotInBlock { id: RollupId },
+    #[error(
+        "the rollup transactions for rollup ID `{id}` contained in the delta could not be \
+         verified against the rollup transactions root"
+    )]
+    RollupTransactionsNotInBlock { id: RollupId },
+}
+
+impl ValidationError for BlockDeltaError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            BlockDeltaErrorKind::BlockHashMismatch {
+                ..
+            } => ValidationErrorCode::new(5901, ValidationErrorCategory::InvalidField),
+            BlockDeltaErrorKind::RollupIdNotInBlock {
+                ..
+            } => ValidationErrorCode::new(5902, ValidationErrorCategory::Proof),
+            BlockDeltaErrorKind::RollupTransactionsNotInBlock {
+                ..
+            } => ValidationErrorCode::new(5903, ValidationErrorCategory::Proof),
+        }
+    }
+}
+
 /// [`Deposit`] represents a deposit from the sequencer to a rollup.
 ///
 /// A [`Deposit`] is constructed whenever a [`BridgeLockAction`] is executed
 /// and stored as part of the block's events.
 #[derive(Debug, Clone, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[cfg_attr(
     feature = "serde",
-    serde(into = "crate::generated::sequencerblock::v1alpha1::Deposit")
+    serde(
+        into = "crate::generated::sequencerblock::v1alpha1::Deposit",
+        try_from = "crate::generated::sequencerblock::v1alpha1::Deposit"
+    )
 )]
 pub struct Deposit {
     // the address on the sequencer to which the funds were sent to.
@@ -1275,6 +2153,16 @@ impl From<Deposit> for crate::generated::sequencerblock::v1alpha1::Deposit {
     }
 }
 
+impl TryFrom<crate::generated::sequencerblock::v1alpha1::Deposit> for Deposit {
+    type Error = DepositError;
+
+    fn try_from(
+        raw: crate::generated::sequencerblock::v1alpha1::Deposit,
+    ) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
 impl Deposit {
     #[must_use]
     pub fn new(
@@ -1411,6 +2299,25 @@ enum DepositErrorKind {
     IncorrectAssetIdLength(#[source] asset::IncorrectAssetIdLength),
 }
 
+impl ValidationError for DepositError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            DepositErrorKind::Address {
+                ..
+            } => ValidationErrorCode::new(5401, ValidationErrorCategory::InvalidField),
+            DepositErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(5402, ValidationErrorCategory::MissingField)
+            }
+            DepositErrorKind::IncorrectRollupIdLength(..) => {
+                ValidationErrorCode::new(5403, ValidationErrorCategory::InvalidField)
+            }
+            DepositErrorKind::IncorrectAssetIdLength(..) => {
+                ValidationErrorCode::new(5404, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
+}
+
 /// A piece of data that is sent to a rollup execution node.
 ///
 /// The data can be either sequenced data (originating from a [`SequenceAction`]
@@ -1419,11 +2326,30 @@ enum DepositErrorKind {
 /// The rollup node receives this type as opaque, protobuf-encoded bytes from conductor,
 /// and must decode it accordingly.
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::RollupData", try_from = "raw::RollupData")
+)]
 pub enum RollupData {
     SequencedData(Vec<u8>),
     Deposit(Deposit),
 }
 
+impl From<RollupData> for raw::RollupData {
+    fn from(value: RollupData) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::RollupData> for RollupData {
+    type Error = RollupDataError;
+
+    fn try_from(raw: raw::RollupData) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
 impl RollupData {
     #[must_use]
     pub fn into_raw(self) -> raw::RollupData {
@@ -1478,3 +2404,208 @@ enum RollupDataErrorKind {
     #[error("failed to validate `deposit` field")]
     Deposit(#[source] DepositError),
 }
+
+impl ValidationError for RollupDataError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            RollupDataErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(5501, ValidationErrorCategory::MissingField)
+            }
+            RollupDataErrorKind::Deposit(source) => source.validation_code(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::protocol::test_utils::ConfigureSequencerBlock;
+
+    #[test]
+    fn sequencer_block_json_roundtrip() {
+        let sequencer_block = ConfigureSequencerBlock::default().make();
+        let json = serde_json::to_string(&sequencer_block).unwrap();
+        let from_json: SequencerBlock = serde_json::from_str(&json).unwrap();
+        assert_eq!(sequencer_block, from_json);
+    }
+
+    fn block_with_rollup_data() -> SequencerBlock {
+        let rollup_id = RollupId::from_unhashed_bytes(b"rollup");
+        ConfigureSequencerBlock {
+            sequence_data: vec![
+                (rollup_id, b"hello".to_vec()),
+                (
+                    RollupId::from_unhashed_bytes(b"other rollup"),
+                    b"world".to_vec(),
+                ),
+            ],
+            ..ConfigureSequencerBlock::default()
+        }
+        .make()
+    }
+
+    #[test]
+    fn sequencer_block_ref_borrows_verified_block() {
+        let sequencer_block = block_with_rollup_data();
+        let raw = sequencer_block.clone().into_raw();
+        let block_ref = SequencerBlockRef::try_from_raw(&raw).unwrap();
+
+        assert_eq!(2, block_ref.rollup_transactions().len());
+        for (raw_txs, txs) in raw
+            .rollup_transactions
+            .iter()
+            .zip(block_ref.rollup_transactions().values())
+        {
+            assert!(std::ptr::eq(
+                raw_txs.transactions.as_slice(),
+                txs.transactions()
+            ));
+        }
+        assert_eq!(sequencer_block, block_ref.to_sequencer_block());
+    }
+
+    #[test]
+    fn block_delta_completes_filtered_block() {
+        let sequencer_block = block_with_rollup_data();
+        let rollup_id = RollupId::from_unhashed_bytes(b"rollup");
+        let other_rollup_id = RollupId::from_unhashed_bytes(b"other rollup");
+        let absent_rollup_id = RollupId::from_unhashed_bytes(b"absent rollup");
+
+        let mut filtered = sequencer_block.to_filtered_block([rollup_id]);
+        assert_eq!(
+            vec![other_rollup_id],
+            filtered.missing_rollup_ids([rollup_id, other_rollup_id, absent_rollup_id]),
+        );
+
+        let delta = sequencer_block.to_block_delta([other_rollup_id, absent_rollup_id]);
+        assert_eq!(1, delta.rollup_transactions().len());
+        filtered.apply_delta(delta).unwrap();
+        assert_eq!(
+            sequencer_block.to_filtered_block([rollup_id, other_rollup_id]),
+            filtered,
+        );
+
+        assert!(sequencer_block
+            .to_block_delta([absent_rollup_id])
+            .is_empty());
+    }
+
+    #[test]
+    fn block_delta_for_other_block_is_rejected() {
+        let sequencer_block = block_with_rollup_data();
+        let other_block = ConfigureSequencerBlock {
+            block_hash: Some([1; 32]),
+            ..ConfigureSequencerBlock::default()
+        }
+        .make();
+        let mut filtered = other_block.to_filtered_block(Vec::<RollupId>::new());
+        let error = filtered
+            .apply_delta(
+                sequencer_block
+                    .to_block_delta(sequencer_block.rollup_transactions().keys().copied()),
+            )
+            .unwrap_err();
+        assert_eq!(5901, error.validation_code().code());
+    }
+
+    #[test]
+    fn block_delta_with_tampered_transactions_is_rejected() {
+        let sequencer_block = block_with_rollup_data();
+        let rollup_id = RollupId::from_unhashed_bytes(b"rollup");
+        let mut filtered = sequencer_block.to_filtered_block(Vec::<RollupId>::new());
+        let mut delta = sequencer_block.to_block_delta([rollup_id]);
+        delta
+            .rollup_transactions
+            .values_mut()
+            .next()
+            .unwrap()
+            .transactions[0]
+            .push(0);
+        let error = filtered.apply_delta(delta).unwrap_err();
+        assert_eq!(5903, error.validation_code().code());
+        assert!(filtered.rollup_transactions().is_empty());
+    }
+
+    #[test]
+    fn sequencer_block_ref_rejects_tampered_transactions() {
+        let mut raw = block_with_rollup_data().into_raw();
+        raw.rollup_transactions[0].transactions[0].push(0);
+        SequencerBlockRef::try_from_raw(&raw).unwrap_err();
+    }
+
+    #[test]
+    fn tampered_fee_summary_is_rejected() {
+        let asset = crate::primitive::v1::asset::Id::from_str_unchecked("nria");
+        let mut fee_summary = FeeSummary::new();
+        fee_summary.add_distributed(asset, 10);
+        fee_summary.add_burned(asset, 5);
+        let sequencer_block = ConfigureSequencerBlock {
+            fee_summary: Some(fee_summary.clone()),
+            ..ConfigureSequencerBlock::default()
+        }
+        .make();
+        assert_eq!(Some(&fee_summary), sequencer_block.fee_summary());
+
+        let mut raw = sequencer_block.into_raw();
+        raw.fee_summary.as_mut().unwrap().assets[0].burned = Some(0u128.into());
+        let error = SequencerBlock::try_from_raw(raw.clone()).unwrap_err();
+        assert_eq!(5123, error.validation_code().code());
+        let error = SequencerBlockRef::try_from_raw(&raw).unwrap_err();
+        assert_eq!(5123, error.validation_code().code());
+    }
+
+    #[test]
+    fn block_without_fee_summary_roundtrips() {
+        let sequencer_block = block_with_rollup_data();
+        assert!(sequencer_block.fee_summary().is_none());
+        assert!(sequencer_block.fee_summary_proof().is_none());
+
+        let raw = sequencer_block.clone().into_raw();
+        assert!(raw.fee_summary.is_none());
+        assert!(raw.fee_summary_proof.is_none());
+        assert_eq!(
+            sequencer_block,
+            SequencerBlock::try_from_raw(raw.clone()).unwrap()
+        );
+        assert_eq!(
+            sequencer_block,
+            SequencerBlockRef::try_from_raw(&raw)
+                .unwrap()
+                .to_sequencer_block()
+        );
+    }
+
+    #[test]
+    fn fee_summary_without_proof_is_rejected() {
+        let mut raw = ConfigureSequencerBlock {
+            fee_summary: Some(FeeSummary::new()),
+            ..ConfigureSequencerBlock::default()
+        }
+        .make()
+        .into_raw();
+        raw.fee_summary_proof = None;
+        let error = SequencerBlock::try_from_raw(raw.clone()).unwrap_err();
+        assert_eq!(5102, error.validation_code().code());
+        let error = SequencerBlockRef::try_from_raw(&raw).unwrap_err();
+        assert_eq!(5102, error.validation_code().code());
+    }
+
+    #[test]
+    fn block_commitment_count_is_detected_from_data() {
+        let signed_tx = vec![0; 33];
+        let without_fee_summary = vec![vec![0; 32], vec![0; 32], signed_tx.clone()];
+        assert_eq!(
+            BLOCK_COMMITMENT_COUNT_WITHOUT_FEE_SUMMARY,
+            block_commitment_count_of_data(&without_fee_summary),
+        );
+        assert_eq!(
+            BLOCK_COMMITMENT_COUNT_WITHOUT_FEE_SUMMARY,
+            block_commitment_count_of_data(&without_fee_summary[..2]),
+        );
+        let with_fee_summary = vec![vec![0; 32], vec![0; 32], vec![0; 32], signed_tx];
+        assert_eq!(
+            BLOCK_COMMITMENT_COUNT,
+            block_commitment_count_of_data(&with_fee_summary),
+        );
+    }
+}
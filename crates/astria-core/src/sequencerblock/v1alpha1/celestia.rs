@@ -14,7 +14,14 @@ use super::{
     IncorrectRollupIdLength,
     RollupId,
 };
-use crate::Protobuf;
+use crate::{
+    validation::{
+        ValidationError,
+        ValidationErrorCategory,
+        ValidationErrorCode,
+    },
+    Protobuf,
+};
 
 /// A [`super::SequencerBlock`] split and prepared for submission to a data availability provider.
 ///
@@ -34,6 +41,7 @@ impl PreparedBlock {
             rollup_transactions,
             rollup_transactions_proof,
             rollup_ids_proof,
+            ..
         } = block.into_parts();
 
         let head = SubmittedMetadata {
@@ -127,6 +135,25 @@ enum SubmittedRollupDataErrorKind {
     SequencerBlockHash(usize),
 }
 
+impl ValidationError for SubmittedRollupDataError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.kind {
+            SubmittedRollupDataErrorKind::FieldNotSet {
+                ..
+            } => ValidationErrorCode::new(5601, ValidationErrorCategory::MissingField),
+            SubmittedRollupDataErrorKind::RollupId {
+                ..
+            } => ValidationErrorCode::new(5602, ValidationErrorCategory::InvalidField),
+            SubmittedRollupDataErrorKind::Proof {
+                ..
+            } => ValidationErrorCode::new(5603, ValidationErrorCategory::InvalidField),
+            SubmittedRollupDataErrorKind::SequencerBlockHash(..) => {
+                ValidationErrorCode::new(5604, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
+}
+
 /// A shadow of [`SubmittedRollupData`] with public access to all its fields.
 ///
 /// At the moment there are no invariants upheld by [`SubmittedRollupData`] so
@@ -377,6 +404,37 @@ enum SubmittedMetadataErrorKind {
     RollupIdsNotInCometBftBlock,
 }
 
+impl ValidationError for SubmittedMetadataError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.kind {
+            SubmittedMetadataErrorKind::BlockHash(..) => {
+                ValidationErrorCode::new(5701, ValidationErrorCategory::InvalidField)
+            }
+            SubmittedMetadataErrorKind::Header {
+                source,
+            } => source.validation_code(),
+            SubmittedMetadataErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(5703, ValidationErrorCategory::MissingField)
+            }
+            SubmittedMetadataErrorKind::RollupIds {
+                ..
+            } => ValidationErrorCode::new(5704, ValidationErrorCategory::InvalidField),
+            SubmittedMetadataErrorKind::RollupTransactionsProof {
+                ..
+            } => ValidationErrorCode::new(5705, ValidationErrorCategory::InvalidField),
+            SubmittedMetadataErrorKind::RollupIdsProof {
+                ..
+            } => ValidationErrorCode::new(5706, ValidationErrorCategory::InvalidField),
+            SubmittedMetadataErrorKind::RollupTransactionsNotInCometBftBlock => {
+                ValidationErrorCode::new(5707, ValidationErrorCategory::Proof)
+            }
+            SubmittedMetadataErrorKind::RollupIdsNotInCometBftBlock => {
+                ValidationErrorCode::new(5708, ValidationErrorCategory::Proof)
+            }
+        }
+    }
+}
+
 /// A shadow of [`SubmittedMetadata`] with public access to its fields.
 ///
 /// This type does not guarantee any invariants and is mainly useful to get
@@ -0,0 +1,347 @@
+//! Merkle commitments over the deposits a sequencer block makes to a rollup.
+//!
+//! A [`DepositCommitment`] is the merkle tree over all [`Deposit`]s destined for one rollup in
+//! one block, in the order they appear in the block. Its root can be handed to a rollup together
+//! with a [`DepositInclusionProof`] for every deposit the rollup wants to act on, so that the
+//! rollup can verify individual deposits without consuming the full list.
+//!
+//! The leaves of the tree are the protobuf encodings of the deposits.
+
+use prost::Message as _;
+
+use super::{
+    block::{
+        Deposit,
+        RollupData,
+        RollupDataError,
+    },
+    raw,
+    RollupTransactions,
+    SequencerBlock,
+};
+use crate::{
+    primitive::v1::RollupId,
+    validation::{
+        ValidationError,
+        ValidationErrorCategory,
+        ValidationErrorCode,
+    },
+};
+
+/// A merkle commitment to the deposits destined for a single rollup in a single block.
+pub struct DepositCommitment {
+    rollup_id: RollupId,
+    deposits: Vec<Deposit>,
+    tree: merkle::Tree,
+}
+
+impl std::fmt::Debug for DepositCommitment {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DepositCommitment")
+            .field("rollup_id", &self.rollup_id)
+            .field("deposits", &self.deposits)
+            .field("root", &hex::encode(self.root()))
+            .finish()
+    }
+}
+
+impl DepositCommitment {
+    /// Constructs a commitment to `deposits`, all of which must be destined for `rollup_id`.
+    ///
+    /// # Errors
+    /// Returns an error if a deposit is destined for a rollup other than `rollup_id`.
+    pub fn try_from_deposits<I>(
+        rollup_id: RollupId,
+        deposits: I,
+    ) -> Result<Self, DepositCommitmentError>
+    where
+        I: IntoIterator<Item = Deposit>,
+    {
+        let deposits: Vec<_> = deposits.into_iter().collect();
+        if let Some(deposit) = deposits
+            .iter()
+            .find(|deposit| *deposit.rollup_id() != rollup_id)
+        {
+            return Err(DepositCommitmentError::rollup_id_mismatch(
+                rollup_id,
+                *deposit.rollup_id(),
+            ));
+        }
+        let tree = merkle::Tree::from_leaves(deposits.iter().map(deposit_leaf));
+        Ok(Self {
+            rollup_id,
+            deposits,
+            tree,
+        })
+    }
+
+    /// Constructs a commitment to the deposits contained in `rollup_transactions`.
+    ///
+    /// Sequenced data is skipped, so that the commitment only covers the deposits.
+    ///
+    /// # Errors
+    /// Returns an error if an item of the rollup transactions could not be decoded as
+    /// [`RollupData`], or if a deposit is destined for a rollup other than the one the rollup
+    /// transactions belong to.
+    pub fn try_from_rollup_transactions(
+        rollup_transactions: &RollupTransactions,
+    ) -> Result<Self, DepositCommitmentError> {
+        let mut deposits = Vec::new();
+        for bytes in rollup_transactions.transactions() {
+            let raw = raw::RollupData::decode(bytes.as_slice())
+                .map_err(DepositCommitmentError::rollup_data_protobuf_decode)?;
+            if let RollupData::Deposit(deposit) =
+                RollupData::try_from_raw(raw).map_err(DepositCommitmentError::rollup_data)?
+            {
+                deposits.push(deposit);
+            }
+        }
+        Self::try_from_deposits(rollup_transactions.rollup_id(), deposits)
+    }
+
+    /// Constructs a commitment to the deposits `block` makes to `rollup_id`.
+    ///
+    /// If the block contains no data for `rollup_id` the commitment is empty.
+    ///
+    /// # Errors
+    /// Returns the same errors as [`DepositCommitment::try_from_rollup_transactions`].
+    pub fn try_from_sequencer_block(
+        block: &SequencerBlock,
+        rollup_id: RollupId,
+    ) -> Result<Self, DepositCommitmentError> {
+        match block.rollup_transactions().get(&rollup_id) {
+            Some(rollup_transactions) => Self::try_from_rollup_transactions(rollup_transactions),
+            None => Self::try_from_deposits(rollup_id, []),
+        }
+    }
+
+    /// Returns the [`RollupId`] of the rollup the committed deposits are destined for.
+    #[must_use]
+    pub fn rollup_id(&self) -> RollupId {
+        self.rollup_id
+    }
+
+    /// Returns the committed deposits in the order they appear in the block.
+    #[must_use]
+    pub fn deposits(&self) -> &[Deposit] {
+        &self.deposits
+    }
+
+    /// Returns the number of committed deposits.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.deposits.len()
+    }
+
+    /// Returns `true` if no deposits were committed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.deposits.is_empty()
+    }
+
+    /// Returns the merkle root of the commitment.
+    #[must_use]
+    pub fn root(&self) -> [u8; 32] {
+        self.tree.root()
+    }
+
+    /// Constructs the proof that the deposit at `index` is included in the commitment.
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    #[must_use]
+    pub fn inclusion_proof(&self, index: usize) -> Option<DepositInclusionProof> {
+        let deposit = self.deposits.get(index)?.clone();
+        let proof = self.tree.construct_proof(index)?;
+        Some(DepositInclusionProof {
+            deposit,
+            proof,
+        })
+    }
+
+    /// Constructs the inclusion proofs of all committed deposits, in order.
+    pub fn inclusion_proofs(&self) -> impl Iterator<Item = DepositInclusionProof> + '_ {
+        (0..self.len()).filter_map(|index| self.inclusion_proof(index))
+    }
+}
+
+/// A [`Deposit`] together with the proof of its inclusion in a [`DepositCommitment`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DepositInclusionProof {
+    deposit: Deposit,
+    proof: merkle::Proof,
+}
+
+impl DepositInclusionProof {
+    /// Returns the deposit whose inclusion is proven.
+    #[must_use]
+    pub fn deposit(&self) -> &Deposit {
+        &self.deposit
+    }
+
+    /// Returns the merkle proof of the deposit's inclusion.
+    #[must_use]
+    pub fn proof(&self) -> &merkle::Proof {
+        &self.proof
+    }
+
+    /// Returns `true` if the deposit is included in the commitment with the merkle root `root`.
+    #[must_use]
+    pub fn verify(&self, root: [u8; 32]) -> bool {
+        self.proof.verify(&deposit_leaf(&self.deposit), root)
+    }
+
+    /// Converts the inclusion proof into its deposit and merkle proof.
+    #[must_use]
+    pub fn into_parts(self) -> (Deposit, merkle::Proof) {
+        (self.deposit, self.proof)
+    }
+}
+
+fn deposit_leaf(deposit: &Deposit) -> Vec<u8> {
+    deposit.clone().into_raw().encode_to_vec()
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct DepositCommitmentError(DepositCommitmentErrorKind);
+
+impl DepositCommitmentError {
+    fn rollup_data_protobuf_decode(source: prost::DecodeError) -> Self {
+        Self(DepositCommitmentErrorKind::RollupDataProtobufDecode(source))
+    }
+
+    fn rollup_data(source: RollupDataError) -> Self {
+        Self(DepositCommitmentErrorKind::RollupData(source))
+    }
+
+    fn rollup_id_mismatch(expected: RollupId, actual: RollupId) -> Self {
+        Self(DepositCommitmentErrorKind::RollupIdMismatch {
+            expected,
+            actual,
+        })
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum DepositCommitmentErrorKind {
+    #[error("failed to decode rollup data as protobuf")]
+    RollupDataProtobufDecode(#[source] prost::DecodeError),
+    #[error("failed to validate rollup data")]
+    RollupData(#[source] RollupDataError),
+    #[error(
+        "deposit is destined for rollup `{actual}`, but the commitment is for rollup `{expected}`"
+    )]
+    RollupIdMismatch {
+        expected: RollupId,
+        actual: RollupId,
+    },
+}
+
+impl ValidationError for DepositCommitmentError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            DepositCommitmentErrorKind::RollupDataProtobufDecode(..) => {
+                ValidationErrorCode::new(5801, ValidationErrorCategory::Decode)
+            }
+            DepositCommitmentErrorKind::RollupData(source) => source.validation_code(),
+            DepositCommitmentErrorKind::RollupIdMismatch {
+                ..
+            } => ValidationErrorCode::new(5802, ValidationErrorCategory::InvalidField),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        primitive::v1::{
+            asset::default_native_asset,
+            Address,
+        },
+        protocol::test_utils::ConfigureSequencerBlock,
+    };
+
+    fn deposit(rollup_id: RollupId, amount: u128) -> Deposit {
+        Deposit::new(
+            Address::builder()
+                .array([42; 20])
+                .prefix("astria")
+                .try_build()
+                .unwrap(),
+            rollup_id,
+            amount,
+            default_native_asset().id(),
+            "0xdeadbeef".to_string(),
+        )
+    }
+
+    #[test]
+    fn every_deposit_is_proven_against_root() {
+        let rollup_id = RollupId::from_unhashed_bytes(b"rollup");
+        let deposits: Vec<_> = (1..=5).map(|amount| deposit(rollup_id, amount)).collect();
+        let commitment = DepositCommitment::try_from_deposits(rollup_id, deposits.clone()).unwrap();
+
+        assert_eq!(5, commitment.len());
+        let proofs: Vec<_> = commitment.inclusion_proofs().collect();
+        assert_eq!(5, proofs.len());
+        for (proof, deposit) in proofs.iter().zip(&deposits) {
+            assert_eq!(deposit, proof.deposit());
+            assert!(proof.verify(commitment.root()));
+        }
+        assert!(commitment.inclusion_proof(5).is_none());
+    }
+
+    #[test]
+    fn tampered_deposit_is_rejected() {
+        let rollup_id = RollupId::from_unhashed_bytes(b"rollup");
+        let commitment = DepositCommitment::try_from_deposits(
+            rollup_id,
+            [deposit(rollup_id, 1), deposit(rollup_id, 2)],
+        )
+        .unwrap();
+        let (_, proof) = commitment.inclusion_proof(0).unwrap().into_parts();
+        let tampered = DepositInclusionProof {
+            deposit: deposit(rollup_id, 1000),
+            proof,
+        };
+        assert!(!tampered.verify(commitment.root()));
+    }
+
+    #[test]
+    fn deposit_for_other_rollup_is_rejected() {
+        let rollup_id = RollupId::from_unhashed_bytes(b"rollup");
+        let other_rollup_id = RollupId::from_unhashed_bytes(b"other rollup");
+        let error = DepositCommitment::try_from_deposits(
+            rollup_id,
+            [deposit(rollup_id, 1), deposit(other_rollup_id, 2)],
+        )
+        .unwrap_err();
+        assert_eq!(5802, error.validation_code().code());
+    }
+
+    #[test]
+    fn commitment_from_block_skips_sequenced_data() {
+        let rollup_id = RollupId::from_unhashed_bytes(b"rollup");
+        let deposits = vec![deposit(rollup_id, 1), deposit(rollup_id, 2)];
+        let block = ConfigureSequencerBlock {
+            sequence_data: vec![(rollup_id, b"hello".to_vec())],
+            deposits: deposits.clone(),
+            ..ConfigureSequencerBlock::default()
+        }
+        .make();
+
+        let commitment = DepositCommitment::try_from_sequencer_block(&block, rollup_id).unwrap();
+        assert_eq!(deposits, commitment.deposits());
+        assert_eq!(
+            DepositCommitment::try_from_deposits(rollup_id, deposits)
+                .unwrap()
+                .root(),
+            commitment.root(),
+        );
+
+        let absent_rollup_id = RollupId::from_unhashed_bytes(b"absent rollup");
+        let empty = DepositCommitment::try_from_sequencer_block(&block, absent_rollup_id).unwrap();
+        assert!(empty.is_empty());
+    }
+}
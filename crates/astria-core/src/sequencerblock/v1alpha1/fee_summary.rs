@@ -0,0 +1,278 @@
+//! The total of all fees paid in a sequencer block.
+//!
+//! A [`FeeSummary`] records, per asset, how much was paid to the block's fee recipient and how
+//! much was burned. The sequencer commits to the summary in the block data by including the
+//! merkle root over its entries, so that the fee flows of a block can be verified against its
+//! data hash without re-executing the block.
+//!
+//! The leaves of the tree are the protobuf encodings of the entries, sorted by asset ID.
+
+use std::collections::BTreeMap;
+
+use prost::Message as _;
+
+use super::raw;
+use crate::{
+    primitive::v1::asset,
+    validation::{
+        ValidationError,
+        ValidationErrorCategory,
+        ValidationErrorCode,
+    },
+};
+
+/// The fees paid in a single asset over the course of a sequencer block.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AssetFees {
+    distributed: u128,
+    burned: u128,
+}
+
+impl AssetFees {
+    #[must_use]
+    pub fn new(distributed: u128, burned: u128) -> Self {
+        Self {
+            distributed,
+            burned,
+        }
+    }
+
+    /// Returns the amount that was paid to the block's fee recipient.
+    #[must_use]
+    pub fn distributed(&self) -> u128 {
+        self.distributed
+    }
+
+    /// Returns the amount that was removed from circulation.
+    #[must_use]
+    pub fn burned(&self) -> u128 {
+        self.burned
+    }
+
+    /// Returns the sum of the distributed and burned amounts, saturating at `u128::MAX`.
+    #[must_use]
+    pub fn total(&self) -> u128 {
+        self.distributed.saturating_add(self.burned)
+    }
+}
+
+/// The fees paid in a sequencer block, summed up per asset.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "raw::FeeSummary", try_from = "raw::FeeSummary")
+)]
+pub struct FeeSummary {
+    assets: BTreeMap<asset::Id, AssetFees>,
+}
+
+impl From<FeeSummary> for raw::FeeSummary {
+    fn from(value: FeeSummary) -> Self {
+        value.into_raw()
+    }
+}
+
+impl TryFrom<raw::FeeSummary> for FeeSummary {
+    type Error = FeeSummaryError;
+
+    fn try_from(raw: raw::FeeSummary) -> Result<Self, Self::Error> {
+        Self::try_from_raw(raw)
+    }
+}
+
+impl FeeSummary {
+    /// Returns a summary without any fees.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `amount` to the fees in `asset` that were paid to the block's fee recipient.
+    ///
+    /// The total saturates at `u128::MAX`.
+    pub fn add_distributed(&mut self, asset: asset::Id, amount: u128) {
+        let fees = self.assets.entry(asset).or_default();
+        fees.distributed = fees.distributed.saturating_add(amount);
+    }
+
+    /// Adds `amount` to the fees in `asset` that were removed from circulation.
+    ///
+    /// The total saturates at `u128::MAX`.
+    pub fn add_burned(&mut self, asset: asset::Id, amount: u128) {
+        let fees = self.assets.entry(asset).or_default();
+        fees.burned = fees.burned.saturating_add(amount);
+    }
+
+    /// Returns the fees paid in `asset`, if any.
+    #[must_use]
+    pub fn get(&self, asset: &asset::Id) -> Option<&AssetFees> {
+        self.assets.get(asset)
+    }
+
+    /// Returns the fees per asset, sorted by asset ID.
+    pub fn iter(&self) -> impl Iterator<Item = (&asset::Id, &AssetFees)> {
+        self.assets.iter()
+    }
+
+    /// Returns `true` if no fees were paid.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+    }
+
+    /// Returns the merkle root over the summary, which is included in the block data.
+    #[must_use]
+    pub fn root(&self) -> [u8; 32] {
+        merkle::Tree::from_leaves(
+            self.assets
+                .iter()
+                .map(|(asset, fees)| asset_fees_to_raw(*asset, *fees).encode_to_vec()),
+        )
+        .root()
+    }
+
+    #[must_use]
+    pub fn into_raw(self) -> raw::FeeSummary {
+        raw::FeeSummary {
+            assets: self
+                .assets
+                .into_iter()
+                .map(|(asset, fees)| asset_fees_to_raw(asset, fees))
+                .collect(),
+        }
+    }
+
+    /// Converts from the raw decoded protobuf representation of this type.
+    ///
+    /// # Errors
+    ///
+    /// - if an asset ID is not 32 bytes long
+    /// - if the distributed or burned amount of an entry is unset
+    /// - if the entries are not sorted by asset ID, or an asset ID occurs more than once
+    pub fn try_from_raw(raw: raw::FeeSummary) -> Result<Self, FeeSummaryError> {
+        let mut assets = BTreeMap::new();
+        for raw::AssetFees {
+            asset_id,
+            distributed,
+            burned,
+        } in raw.assets
+        {
+            let asset = asset::Id::try_from_slice(&asset_id)
+                .map_err(FeeSummaryError::incorrect_asset_id_length)?;
+            let distributed = distributed
+                .ok_or(FeeSummaryError::field_not_set("distributed"))?
+                .into();
+            let burned = burned
+                .ok_or(FeeSummaryError::field_not_set("burned"))?
+                .into();
+            // the root is only canonical if the entries are strictly ordered by asset ID
+            if assets
+                .last_key_value()
+                .is_some_and(|(last, _)| *last >= asset)
+            {
+                return Err(FeeSummaryError::assets_not_sorted(asset));
+            }
+            assets.insert(asset, AssetFees::new(distributed, burned));
+        }
+        Ok(Self {
+            assets,
+        })
+    }
+}
+
+fn asset_fees_to_raw(asset: asset::Id, fees: AssetFees) -> raw::AssetFees {
+    raw::AssetFees {
+        asset_id: asset.get().to_vec(),
+        distributed: Some(fees.distributed.into()),
+        burned: Some(fees.burned.into()),
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+#[error(transparent)]
+pub struct FeeSummaryError(FeeSummaryErrorKind);
+
+impl FeeSummaryError {
+    fn incorrect_asset_id_length(source: asset::IncorrectAssetIdLength) -> Self {
+        Self(FeeSummaryErrorKind::IncorrectAssetIdLength(source))
+    }
+
+    fn field_not_set(field: &'static str) -> Self {
+        Self(FeeSummaryErrorKind::FieldNotSet(field))
+    }
+
+    fn assets_not_sorted(asset: asset::Id) -> Self {
+        Self(FeeSummaryErrorKind::AssetsNotSorted(asset))
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+enum FeeSummaryErrorKind {
+    #[error("the asset ID length is not 32 bytes")]
+    IncorrectAssetIdLength(#[source] asset::IncorrectAssetIdLength),
+    #[error("the expected field in the raw source type was not set: `{0}`")]
+    FieldNotSet(&'static str),
+    #[error("the entry for asset `{0}` was not sorted by asset ID or occurred more than once")]
+    AssetsNotSorted(asset::Id),
+}
+
+impl ValidationError for FeeSummaryError {
+    fn validation_code(&self) -> ValidationErrorCode {
+        match &self.0 {
+            FeeSummaryErrorKind::IncorrectAssetIdLength(..) => {
+                ValidationErrorCode::new(6001, ValidationErrorCategory::InvalidField)
+            }
+            FeeSummaryErrorKind::FieldNotSet(..) => {
+                ValidationErrorCode::new(6002, ValidationErrorCategory::MissingField)
+            }
+            FeeSummaryErrorKind::AssetsNotSorted(..) => {
+                ValidationErrorCode::new(6003, ValidationErrorCategory::InvalidField)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fees_are_summed_per_asset() {
+        let nria = asset::Id::from_str_unchecked("nria");
+        let other = asset::Id::from_str_unchecked("other");
+        let mut summary = FeeSummary::new();
+        summary.add_distributed(nria, 10);
+        summary.add_burned(nria, 5);
+        summary.add_distributed(nria, 1);
+        summary.add_burned(other, 3);
+
+        assert_eq!(Some(&AssetFees::new(11, 5)), summary.get(&nria));
+        assert_eq!(16, summary.get(&nria).unwrap().total());
+        assert_eq!(Some(&AssetFees::new(0, 3)), summary.get(&other));
+    }
+
+    #[test]
+    fn raw_roundtrip_preserves_root() {
+        let mut summary = FeeSummary::new();
+        summary.add_distributed(asset::Id::from_str_unchecked("nria"), 10);
+        summary.add_burned(asset::Id::from_str_unchecked("other"), 3);
+        let root = summary.root();
+
+        let roundtripped = FeeSummary::try_from_raw(summary.clone().into_raw()).unwrap();
+        assert_eq!(summary, roundtripped);
+        assert_eq!(root, roundtripped.root());
+    }
+
+    #[test]
+    fn unsorted_entries_are_rejected() {
+        let mut summary = FeeSummary::new();
+        summary.add_distributed(asset::Id::from_str_unchecked("nria"), 10);
+        summary.add_burned(asset::Id::from_str_unchecked("other"), 3);
+        let mut raw = summary.into_raw();
+        raw.assets.reverse();
+
+        let error = FeeSummary::try_from_raw(raw).unwrap_err();
+        assert_eq!(6003, error.validation_code().code());
+    }
+}
@@ -1,15 +1,31 @@
 pub mod block;
 pub mod celestia;
+pub mod deposits;
+pub mod fee_summary;
 
 pub use block::{
+    block_commitment_count,
+    block_commitment_count_of_data,
+    BlockDelta,
     RollupTransactions,
+    RollupTransactionsRef,
     SequencerBlock,
+    SequencerBlockRef,
+    BLOCK_COMMITMENT_COUNT,
+    BLOCK_COMMITMENT_COUNT_WITHOUT_FEE_SUMMARY,
 };
 pub use celestia::{
     SubmittedMetadata,
     SubmittedRollupData,
 };
-use indexmap::IndexMap;
+pub use deposits::{
+    DepositCommitment,
+    DepositInclusionProof,
+};
+pub use fee_summary::{
+    AssetFees,
+    FeeSummary,
+};
 use sha2::{
     Digest as _,
     Sha256,
@@ -37,14 +53,14 @@ where
     proof.verify(&hash_of_root, data_hash)
 }
 
-pub(crate) fn are_rollup_txs_included(
-    rollup_datas: &IndexMap<RollupId, RollupTransactions>,
+pub(crate) fn are_rollup_txs_included<'a, TRollupDatas>(
+    rollup_datas: TRollupDatas,
     rollup_proof: &merkle::Proof,
     data_hash: [u8; 32],
-) -> bool {
-    let rollup_datas = rollup_datas
-        .iter()
-        .map(|(rollup_id, tx_data)| (rollup_id, tx_data.transactions()));
+) -> bool
+where
+    TRollupDatas: IntoIterator<Item = (&'a RollupId, &'a [Vec<u8>])>,
+{
     let rollup_tree = derive_merkle_tree_from_rollup_txs(rollup_datas);
     let hash_of_rollup_root = Sha256::digest(rollup_tree.root());
     rollup_proof.verify(&hash_of_rollup_root, data_hash)
@@ -0,0 +1,197 @@
+//! Stable codes for validation failures of protocol types.
+//!
+//! Every error returned when converting a raw protobuf transaction or sequencer block type to its
+//! native counterpart implements [`ValidationError`]. Its [`ValidationErrorCode`] identifies the
+//! specific failure, so that services can map it to metrics labels and ABCI error codes without
+//! matching on error messages.
+//!
+//! Errors that wrap another validation error report the code of the innermost one. Codes are
+//! grouped in blocks of 100 per error type:
+//!
+//! | block  | error type                                          |
+//! |--------|-----------------------------------------------------|
+//! | 1000   | `transaction::v1alpha1::SignedTransactionError`      |
+//! | 1100   | `transaction::v1alpha1::UnsignedTransactionError`    |
+//! | 1200   | `transaction::v1alpha1::action::ActionError`         |
+//! | 1300   | `SequenceActionError`                               |
+//! | 1400   | `TransferActionError`                               |
+//! | 1500   | `SudoAddressChangeActionError`                      |
+//! | 1600   | `Ics20WithdrawalError`                              |
+//! | 1700   | `IbcRelayerChangeActionError`                       |
+//! | 1800   | `FeeAssetChangeActionError`                         |
+//! | 1900   | `InitBridgeAccountActionError`                      |
+//! | 2000   | `BridgeLockActionError`                             |
+//! | 2100   | `BridgeUnlockActionError`                           |
+//! | 2200   | `BridgeSudoChangeActionError`                       |
+//! | 2300   | `FeeChangeActionError`                              |
+//! | 2400   | `transaction::v1alpha1::canonical::CanonicalJsonError` |
+//! | 2500   | `AddressPrefixChangeActionError`                    |
+//! | 2600   | `AllowanceChangeActionError`                        |
+//! | 2700   | `TransferFromActionError`                           |
+//! | 2800   | `BridgeRecoveryActionError`                         |
+//! | 2900   | `BridgeRecoveryVetoActionError`                     |
+//! | 3000   | `transaction::v2::SignedTransactionError`            |
+//! | 3100   | `transaction::v2::UnsignedTransactionError`          |
+//! | 3200   | `transaction::v2::ActionEnvelopeError`               |
+//! | 3300   | `transaction::TransactionVersionError`               |
+//! | 3400   | `FeeDistributionChangeActionError`                  |
+//! | 5000   | `RollupTransactionsError`                           |
+//! | 5100   | `SequencerBlockError`                               |
+//! | 5200   | `SequencerBlockHeaderError`                         |
+//! | 5300   | `FilteredSequencerBlockError`                       |
+//! | 5400   | `DepositError`                                      |
+//! | 5500   | `RollupDataError`                                   |
+//! | 5600   | `SubmittedRollupDataError`                          |
+//! | 5700   | `SubmittedMetadataError`                            |
+//! | 5800   | `DepositCommitmentError`                            |
+//! | 5900   | `BlockDeltaError`                                   |
+//! | 6000   | `FeeSummaryError`                                   |
+//!
+//! Codes must never be reassigned: new failures get new codes, and the codes of removed failures
+//! are retired.
+
+use std::{
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+    num::NonZeroU32,
+};
+
+use crate::protocol::abci::AbciErrorCode;
+
+/// A validation failure of a protocol type with a stable code.
+pub trait ValidationError: std::error::Error {
+    /// Returns the code identifying the failure.
+    fn validation_code(&self) -> ValidationErrorCode;
+}
+
+/// The broad category of a validation failure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum ValidationErrorCategory {
+    /// Bytes could not be decoded, or had an unexpected type URL.
+    Decode,
+    /// A required field was not set.
+    MissingField,
+    /// A field was set to an invalid value.
+    InvalidField,
+    /// A signature or verification key was invalid, or the signature could not be verified.
+    Signature,
+    /// Data could not be verified against a merkle proof or root.
+    Proof,
+    /// The data is of a type or version not known to this implementation.
+    Unknown,
+}
+
+impl ValidationErrorCategory {
+    /// Returns the category as a string suitable as a metrics label.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Decode => "decode",
+            Self::MissingField => "missing_field",
+            Self::InvalidField => "invalid_field",
+            Self::Signature => "signature",
+            Self::Proof => "proof",
+            Self::Unknown => "unknown",
+        }
+    }
+}
+
+impl Display for ValidationErrorCategory {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The code and category of a validation failure.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ValidationErrorCode {
+    code: NonZeroU32,
+    category: ValidationErrorCategory,
+}
+
+impl ValidationErrorCode {
+    pub(crate) const fn new(code: u32, category: ValidationErrorCategory) -> Self {
+        let Some(code) = NonZeroU32::new(code) else {
+            panic!("validation error codes must not be zero");
+        };
+        Self {
+            code,
+            category,
+        }
+    }
+
+    #[must_use]
+    pub fn code(self) -> u32 {
+        self.code.get()
+    }
+
+    #[must_use]
+    pub fn category(self) -> ValidationErrorCategory {
+        self.category
+    }
+
+    /// Returns the ABCI error code of the failure.
+    ///
+    /// Validation codes start at 1000, and so never overlap with the predefined
+    /// [`AbciErrorCode`]s.
+    #[must_use]
+    pub fn to_abci_code(self) -> AbciErrorCode {
+        AbciErrorCode::from(self.code)
+    }
+}
+
+impl Display for ValidationErrorCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.code, self.category)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        generated::protocol::transaction::v1alpha1 as raw,
+        protocol::transaction::v1alpha1::{
+            SignedTransaction,
+            UnsignedTransaction,
+        },
+    };
+
+    #[test]
+    fn unset_transaction_has_its_own_code() {
+        let signing_key = crate::crypto::SigningKey::from([1; 32]);
+        let err = SignedTransaction::try_from_raw(raw::SignedTransaction {
+            signature: signing_key.sign(b"").to_bytes().to_vec(),
+            public_key: signing_key.verification_key().to_bytes().to_vec(),
+            transaction: None,
+        })
+        .unwrap_err();
+        let code = err.validation_code();
+        assert_eq!(1001, code.code());
+        assert_eq!(ValidationErrorCategory::MissingField, code.category());
+    }
+
+    #[test]
+    fn wrapped_errors_report_the_innermost_code() {
+        let err = UnsignedTransaction::try_from_raw(raw::UnsignedTransaction {
+            actions: vec![raw::Action {
+                value: None,
+            }],
+            params: Some(raw::TransactionParams {
+                nonce: 1,
+                chain_id: "test-1".to_string(),
+            }),
+        })
+        .unwrap_err();
+        let code = err.validation_code();
+        assert_eq!(1201, code.code());
+        assert_eq!("missing_field", code.category().as_str());
+        assert_eq!(
+            "unknown non-zero abci error code: 1201",
+            code.to_abci_code().info()
+        );
+    }
+}
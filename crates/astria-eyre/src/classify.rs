@@ -0,0 +1,140 @@
+use std::{
+    fmt,
+    process::ExitCode,
+};
+
+use eyre::Report;
+
+/// The class of an error, determining the exit code of a process that fails with it.
+///
+/// The exit codes follow the BSD conventions of `sysexits.h`, see also
+/// <https://freedesktop.org/software/systemd/man/systemd.exec.html#Process%20Exit%20Codes>.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorClass {
+    /// The configuration is invalid. Restarting with the same configuration will fail again.
+    Config,
+    /// A remote service could not be reached or did not respond.
+    Network,
+    /// The service observed state that violates consensus. Restarting will not resolve it
+    /// without operator intervention.
+    ConsensusFatal,
+    /// A transient failure that is expected to resolve itself after a restart.
+    Recoverable,
+}
+
+impl ErrorClass {
+    /// Returns the class of `report`, or `None` if it was not classified.
+    ///
+    /// If several layers of `report` were classified, the outermost classification is returned.
+    #[must_use]
+    pub fn of(report: &Report) -> Option<Self> {
+        report.downcast_ref::<Classified>().map(|classified| classified.0)
+    }
+
+    /// Returns the process exit code for errors of this class.
+    #[must_use]
+    pub const fn exit_code(self) -> u8 {
+        match self {
+            // EX_CONFIG
+            Self::Config => 78,
+            // EX_UNAVAILABLE
+            Self::Network => 69,
+            // EX_SOFTWARE
+            Self::ConsensusFatal => 70,
+            // EX_TEMPFAIL
+            Self::Recoverable => 75,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Config => "configuration error",
+            Self::Network => "network error",
+            Self::ConsensusFatal => "consensus-fatal error",
+            Self::Recoverable => "recoverable error",
+        }
+    }
+}
+
+/// Returns the process exit code for `report`: the exit code of its [`ErrorClass`], or
+/// [`ExitCode::FAILURE`] if it was not classified.
+#[must_use]
+pub fn exit_code(report: &Report) -> ExitCode {
+    ErrorClass::of(report).map_or(ExitCode::FAILURE, |class| {
+        ExitCode::from(class.exit_code())
+    })
+}
+
+/// The layer added to a report by [`Classify::classify`].
+#[derive(Debug)]
+struct Classified(ErrorClass);
+
+impl fmt::Display for Classified {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.0.as_str())
+    }
+}
+
+/// Provides the `classify` method for `Result`.
+pub trait Classify<T> {
+    /// Marks the error as being of `class`, which can be read back with [`ErrorClass::of`].
+    ///
+    /// # Errors
+    /// Returns the error, wrapped in a layer describing its class.
+    fn classify(self, class: ErrorClass) -> Result<T, Report>;
+}
+
+impl<T, E> Classify<T> for Result<T, E>
+where
+    E: Into<Report>,
+{
+    fn classify(self, class: ErrorClass) -> Result<T, Report> {
+        self.map_err(|error| error.into().wrap_err(Classified(class)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use eyre::{
+        eyre,
+        WrapErr as _,
+    };
+
+    use super::*;
+
+    #[test]
+    fn class_is_found_below_context() {
+        let report = Err::<(), _>(eyre!("connection refused"))
+            .classify(ErrorClass::Network)
+            .wrap_err("failed fetching block")
+            .unwrap_err();
+        assert_eq!(ErrorClass::of(&report), Some(ErrorClass::Network));
+        assert_eq!(exit_code(&report), ExitCode::from(69));
+    }
+
+    #[test]
+    fn outermost_class_wins() {
+        let report = Err::<(), _>(eyre!("invalid URL"))
+            .classify(ErrorClass::Network)
+            .classify(ErrorClass::Config)
+            .unwrap_err();
+        assert_eq!(ErrorClass::of(&report), Some(ErrorClass::Config));
+    }
+
+    #[test]
+    fn unclassified_report_fails_with_generic_exit_code() {
+        let report = eyre!("unexpected");
+        assert_eq!(ErrorClass::of(&report), None);
+        assert_eq!(exit_code(&report), ExitCode::FAILURE);
+    }
+
+    #[test]
+    fn std_errors_can_be_classified() {
+        let report = "not a number"
+            .parse::<u64>()
+            .classify(ErrorClass::Config)
+            .unwrap_err();
+        assert_eq!(ErrorClass::of(&report), Some(ErrorClass::Config));
+    }
+}
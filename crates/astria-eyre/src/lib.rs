@@ -5,6 +5,12 @@ use std::{
     fmt::Write as _,
 };
 
+mod classify;
+pub use classify::{
+    exit_code,
+    Classify,
+    ErrorClass,
+};
 pub use eyre;
 #[doc(hidden)]
 pub use eyre::Result;
@@ -34,7 +34,10 @@ use std::{
 };
 
 use astria_core::protocol::{
-    asset::v1alpha1::AllowedFeeAssetIdsResponse,
+    asset::v1alpha1::{
+        AllowedFeeAssetIdsResponse,
+        DenomResponse,
+    },
     bridge::v1alpha1::BridgeAccountLastTxHashResponse,
 };
 pub use astria_core::{
@@ -466,6 +469,41 @@ pub trait SequencerClientExt: Client {
         self.get_balance(address, 0u32).await
     }
 
+    /// Returns the denomination of the given asset ID at the latest height.
+    ///
+    /// # Errors
+    ///
+    /// - If calling tendermint `abci_query` RPC fails.
+    /// - If the bytes contained in the abci query response cannot be deserialized as an
+    ///  `astria.protocol.asset.v1alpha1.DenomResponse`.
+    /// - If the raw response cannot be converted to the native type.
+    async fn get_denom(
+        &self,
+        asset_id: astria_core::primitive::v1::asset::Id,
+    ) -> Result<DenomResponse, Error> {
+        const PREFIX: &str = "asset/denom";
+        let path = format!("{PREFIX}/{}", hex::encode(asset_id));
+
+        let response = self
+            .abci_query(Some(path), vec![], None, false)
+            .await
+            .map_err(|e| Error::tendermint_rpc("abci_query", e))?;
+
+        let proto_response =
+            astria_core::generated::protocol::asset::v1alpha1::DenomResponse::decode(
+                &*response.value,
+            )
+            .map_err(|e| {
+                Error::abci_query_deserialization(
+                    "astria.protocol.asset.v1alpha1.DenomResponse",
+                    response,
+                    e,
+                )
+            })?;
+        DenomResponse::try_from_raw(&proto_response)
+            .map_err(|e| Error::native_conversion("DenomResponse", Arc::new(e)))
+    }
+
     /// Returns the allowed fee assets at a given height.
     ///
     /// # Errors
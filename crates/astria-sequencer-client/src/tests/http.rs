@@ -266,6 +266,32 @@ async fn get_allowed_fee_assets() {
     assert_eq!(expected_response, actual_response);
 }
 
+#[tokio::test]
+async fn get_denom() {
+    use astria_core::generated::protocol::asset::v1alpha1::DenomResponse;
+
+    let MockSequencer {
+        server,
+        client,
+    } = MockSequencer::start().await;
+
+    let expected_response = DenomResponse {
+        height: 10,
+        denom: "transfer/channel-0/utia".to_string(),
+    };
+
+    let _guard =
+        register_abci_query_response(&server, "asset/denom", expected_response.clone()).await;
+
+    let actual_response = client
+        .get_denom(asset::Id::from_str_unchecked("transfer/channel-0/utia"))
+        .await
+        .unwrap()
+        .into_raw();
+
+    assert_eq!(expected_response, actual_response);
+}
+
 #[tokio::test]
 async fn get_bridge_account_last_transaction_hash() {
     use astria_core::generated::protocol::bridge::v1alpha1::BridgeAccountLastTxHashResponse;
@@ -1,8 +1,13 @@
-use std::net::SocketAddr;
+use std::{
+    net::SocketAddr,
+    sync::Arc,
+    time::Duration,
+};
 
 use axum::{
     extract::{
         FromRef,
+        Query,
         State,
     },
     response::{
@@ -18,10 +23,22 @@ use axum::{
 };
 use http::status::StatusCode;
 use hyper::server::conn::AddrIncoming;
-use serde::Serialize;
+use serde::{
+    Deserialize,
+    Serialize,
+};
 use tokio::sync::watch;
 
-use crate::relayer;
+use crate::{
+    costs::{
+        CostLedger,
+        CostSummary,
+    },
+    relayer,
+};
+
+/// The window summarized by `/costs` if none is provided.
+const DEFAULT_COSTS_WINDOW: Duration = Duration::from_secs(60 * 60);
 
 pub(crate) type ApiServer = axum::Server<AddrIncoming, IntoMakeService<Router>>;
 
@@ -31,6 +48,7 @@ type RelayerState = watch::Receiver<relayer::StateSnapshot>;
 /// `AppState` is used for as an axum extractor in its method handlers.
 struct AppState {
     relayer_state: RelayerState,
+    cost_ledger: Arc<CostLedger>,
 }
 
 impl FromRef<AppState> for RelayerState {
@@ -39,13 +57,25 @@ impl FromRef<AppState> for RelayerState {
     }
 }
 
-pub(crate) fn start(socket_addr: SocketAddr, relayer_state: RelayerState) -> ApiServer {
+impl FromRef<AppState> for Arc<CostLedger> {
+    fn from_ref(app_state: &AppState) -> Self {
+        app_state.cost_ledger.clone()
+    }
+}
+
+pub(crate) fn start(
+    socket_addr: SocketAddr,
+    relayer_state: RelayerState,
+    cost_ledger: Arc<CostLedger>,
+) -> ApiServer {
     let app = Router::new()
         .route("/healthz", get(get_healthz))
         .route("/readyz", get(get_readyz))
         .route("/status", get(get_status))
+        .route("/costs", get(get_costs))
         .with_state(AppState {
             relayer_state,
+            cost_ledger,
         });
     axum::Server::bind(&socket_addr).serve(app.into_make_service())
 }
@@ -80,6 +110,34 @@ async fn get_status(State(relayer_state): State<RelayerState>) -> Json<relayer::
     Json(*relayer_state.borrow())
 }
 
+#[derive(Debug, Deserialize)]
+struct CostsQuery {
+    /// The rolling window to summarize, formatted as a human readable duration (e.g. `1h 30m`).
+    window: Option<String>,
+}
+
+/// Handler of a call to `/costs`.
+///
+/// Returns the blobs, bytes and fees submitted to Celestia per namespace over the rolling window
+/// given by the `window` query parameter, defaulting to one hour. The window is capped at
+/// [`crate::costs::MAX_WINDOW`].
+#[allow(clippy::unused_async)] // Permit because axum handlers must be async
+async fn get_costs(
+    State(cost_ledger): State<Arc<CostLedger>>,
+    Query(query): Query<CostsQuery>,
+) -> Result<Json<CostSummary>, (StatusCode, String)> {
+    let window = match query.window {
+        None => DEFAULT_COSTS_WINDOW,
+        Some(window) => humantime::parse_duration(&window).map_err(|error| {
+            (
+                StatusCode::BAD_REQUEST,
+                format!("failed to parse `window` query parameter as duration: {error}"),
+            )
+        })?,
+    };
+    Ok(Json(cost_ledger.summarize(window)))
+}
+
 enum Healthz {
     Ok,
     Degraded,
@@ -2,6 +2,7 @@ use std::{
     collections::HashSet,
     path::PathBuf,
     sync::Arc,
+    time::Duration,
 };
 
 use astria_core::primitive::v1::RollupId;
@@ -18,6 +19,8 @@ use serde::{
     Serialize,
 };
 
+use crate::relayer::SubmissionWindow;
+
 // Allowed `struct_excessive_bools` because this is used as a container
 // for deserialization. Making this a builder-pattern is not actionable.
 #[allow(clippy::struct_excessive_bools)]
@@ -52,6 +55,16 @@ pub struct Config {
     pub pre_submit_path: PathBuf,
     /// The path to which relayer will write its state after submitting to Celestia.
     pub post_submit_path: PathBuf,
+    /// The minimum duration in milliseconds between the starts of two submissions to Celestia.
+    /// Sequencer blocks received in between are batched into the next submission. 0 submits as
+    /// soon as the previous submission finished.
+    pub submission_interval_ms: u64,
+    /// The compressed size in bytes of batched data at which it is submitted without waiting for
+    /// the submission interval to elapse. 0 disables submitting early.
+    pub submission_min_bytes: usize,
+    /// A comma-separated list of `HH:MM-HH:MM` UTC time ranges during which no submissions to
+    /// Celestia are made.
+    pub submission_quiet_periods: String,
 }
 
 impl Config {
@@ -63,6 +76,18 @@ impl Config {
     pub fn only_include_rollups(&self) -> eyre::Result<IncludeRollup> {
         IncludeRollup::parse(&self.only_include_rollups)
     }
+
+    /// Returns the rules for when submissions to Celestia are made.
+    ///
+    /// # Errors
+    /// Returns an error if any of the configured quiet periods cannot be parsed.
+    pub(crate) fn submission_window(&self) -> eyre::Result<SubmissionWindow> {
+        SubmissionWindow::new(
+            Duration::from_millis(self.submission_interval_ms),
+            self.submission_min_bytes,
+            &self.submission_quiet_periods,
+        )
+    }
 }
 
 impl config::Config for Config {
@@ -0,0 +1,333 @@
+//! Per-namespace accounting of what the relayer submits to Celestia.
+//!
+//! Every successful submission is broken down by Celestia namespace (one per rollup, plus the
+//! sequencer namespace holding block metadata) and recorded in the [`CostLedger`]. The fee paid
+//! for a submission is attributed to each namespace in proportion to the number of blob bytes
+//! submitted under it.
+//!
+//! The ledger retains records for [`MAX_WINDOW`] and can summarize them over any rolling window
+//! up to that length. The summary is served by the `/costs` endpoint of the relayer API.
+use std::{
+    collections::{
+        HashMap,
+        VecDeque,
+    },
+    sync::Mutex,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use astria_core::{
+    celestia::Namespace,
+    primitive::v1::RollupId,
+};
+use celestia_types::Blob;
+
+/// The longest window over which the ledger can summarize submissions.
+///
+/// Records older than this are dropped from the ledger.
+pub(crate) const MAX_WINDOW: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// The blobs, bytes, and fees attributed to a namespace.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, serde::Serialize)]
+pub(crate) struct Usage {
+    pub(crate) blobs: u64,
+    pub(crate) bytes: u64,
+    pub(crate) fee_utia: u64,
+}
+
+impl Usage {
+    fn accumulate(&mut self, other: Usage) {
+        self.blobs = self.blobs.saturating_add(other.blobs);
+        self.bytes = self.bytes.saturating_add(other.bytes);
+        self.fee_utia = self.fee_utia.saturating_add(other.fee_utia);
+    }
+}
+
+/// The usage of a single namespace in a single Celestia submission.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct NamespaceUsage {
+    pub(crate) namespace: celestia_types::nmt::Namespace,
+    /// The rollup writing to `namespace`, or `None` if it is the sequencer namespace.
+    pub(crate) rollup_id: Option<RollupId>,
+    pub(crate) usage: Usage,
+}
+
+/// Breaks down `blobs` by namespace and attributes `fee_utia` to each in proportion to its bytes.
+///
+/// Namespaces that are not found in `rollups` are assumed to be the sequencer namespace. Any
+/// remainder of the integer division of the fee is attributed to the namespace with the most
+/// bytes so that the sum of the attributed fees always equals `fee_utia`.
+pub(crate) fn attribute<'a>(
+    blobs: impl IntoIterator<Item = &'a Blob>,
+    rollups: &HashMap<RollupId, Namespace>,
+    fee_utia: u64,
+) -> Vec<NamespaceUsage> {
+    let mut usages: Vec<NamespaceUsage> = vec![];
+    for blob in blobs {
+        let bytes = u64::try_from(blob.data.len()).unwrap_or(u64::MAX);
+        if let Some(entry) = usages
+            .iter_mut()
+            .find(|usage| usage.namespace == blob.namespace)
+        {
+            entry.usage.blobs = entry.usage.blobs.saturating_add(1);
+            entry.usage.bytes = entry.usage.bytes.saturating_add(bytes);
+        } else {
+            let rollup_id = rollups
+                .iter()
+                .find_map(|(id, namespace)| (namespace.get() == blob.namespace).then_some(*id));
+            usages.push(NamespaceUsage {
+                namespace: blob.namespace,
+                rollup_id,
+                usage: Usage {
+                    blobs: 1,
+                    bytes,
+                    fee_utia: 0,
+                },
+            });
+        }
+    }
+
+    let total_bytes = usages.iter().fold(0u128, |acc, entry| {
+        acc.saturating_add(entry.usage.bytes.into())
+    });
+    if total_bytes == 0 {
+        return usages;
+    }
+    let mut attributed = 0u64;
+    for entry in &mut usages {
+        // allow: the share is at most `fee_utia` because `bytes <= total_bytes`, so it fits in a
+        // u64.
+        #[allow(clippy::cast_possible_truncation)]
+        let share = u128::from(fee_utia)
+            .saturating_mul(u128::from(entry.usage.bytes))
+            .checked_div(total_bytes)
+            .unwrap_or(0) as u64;
+        entry.usage.fee_utia = share;
+        attributed = attributed.saturating_add(share);
+    }
+    if let Some(largest) = usages.iter_mut().max_by_key(|entry| entry.usage.bytes) {
+        largest.usage.fee_utia = largest
+            .usage
+            .fee_utia
+            .saturating_add(fee_utia.saturating_sub(attributed));
+    }
+    usages
+}
+
+struct Record {
+    recorded_at: Instant,
+    celestia_height: u64,
+    namespaces: Vec<NamespaceUsage>,
+}
+
+/// A rolling record of the costs of all Celestia submissions made within [`MAX_WINDOW`].
+pub(crate) struct CostLedger {
+    records: Mutex<VecDeque<Record>>,
+}
+
+impl CostLedger {
+    pub(crate) fn new() -> Self {
+        Self {
+            records: Mutex::new(VecDeque::new()),
+        }
+    }
+
+    /// Records a submission included at `celestia_height`.
+    pub(crate) fn record(&self, celestia_height: u64, namespaces: Vec<NamespaceUsage>) {
+        self.record_at(Instant::now(), celestia_height, namespaces);
+    }
+
+    fn record_at(&self, now: Instant, celestia_height: u64, namespaces: Vec<NamespaceUsage>) {
+        let mut records = self.lock();
+        records.push_back(Record {
+            recorded_at: now,
+            celestia_height,
+            namespaces,
+        });
+        while records
+            .front()
+            .is_some_and(|record| now.saturating_duration_since(record.recorded_at) > MAX_WINDOW)
+        {
+            records.pop_front();
+        }
+    }
+
+    /// Summarizes all submissions recorded within `window` of now.
+    ///
+    /// `window` is capped at [`MAX_WINDOW`].
+    pub(crate) fn summarize(&self, window: Duration) -> CostSummary {
+        self.summarize_at(Instant::now(), window)
+    }
+
+    fn summarize_at(&self, now: Instant, window: Duration) -> CostSummary {
+        let window = window.min(MAX_WINDOW);
+        let mut summary = CostSummary {
+            window_secs: window.as_secs(),
+            ..CostSummary::default()
+        };
+        let records = self.lock();
+        for record in records
+            .iter()
+            .rev()
+            .take_while(|record| now.saturating_duration_since(record.recorded_at) <= window)
+        {
+            summary.submissions = summary.submissions.saturating_add(1);
+            summary.latest_celestia_height = summary
+                .latest_celestia_height
+                .max(Some(record.celestia_height));
+            for entry in &record.namespaces {
+                summary.total.accumulate(entry.usage);
+                summary
+                    .namespaces
+                    .entry(telemetry::display::base64(entry.namespace.as_bytes()).to_string())
+                    .or_insert_with(|| NamespaceSummary {
+                        rollup_id: entry.rollup_id,
+                        usage: Usage::default(),
+                    })
+                    .usage
+                    .accumulate(entry.usage);
+            }
+        }
+        summary
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, VecDeque<Record>> {
+        // The lock is never held across operations that could panic, so recovering the inner
+        // value from a poisoned mutex is safe.
+        self.records
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+/// The costs of all submissions made within a rolling window, keyed by base64 namespace.
+#[derive(Debug, Default, PartialEq, Eq, serde::Serialize)]
+pub(crate) struct CostSummary {
+    window_secs: u64,
+    submissions: u64,
+    latest_celestia_height: Option<u64>,
+    total: Usage,
+    namespaces: HashMap<String, NamespaceSummary>,
+}
+
+#[derive(Debug, PartialEq, Eq, serde::Serialize)]
+struct NamespaceSummary {
+    rollup_id: Option<RollupId>,
+    #[serde(flatten)]
+    usage: Usage,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        collections::HashMap,
+        time::{
+            Duration,
+            Instant,
+        },
+    };
+
+    use astria_core::{
+        celestia::Namespace,
+        primitive::v1::RollupId,
+    };
+    use celestia_types::Blob;
+
+    use super::{
+        attribute,
+        CostLedger,
+        Usage,
+        MAX_WINDOW,
+    };
+
+    fn sequencer_namespace() -> Namespace {
+        Namespace::from_chain_id("sequencer-0")
+    }
+
+    fn rollup() -> (RollupId, Namespace) {
+        let rollup_id = RollupId::from_unhashed_bytes(b"rollup-0");
+        (rollup_id, Namespace::from_rollup_id(rollup_id))
+    }
+
+    #[test]
+    fn fee_is_attributed_in_proportion_to_bytes() {
+        let (rollup_id, rollup_namespace) = rollup();
+        let blobs = [
+            Blob::new(sequencer_namespace().get(), vec![0; 100]).unwrap(),
+            Blob::new(rollup_namespace.get(), vec![0; 300]).unwrap(),
+        ];
+        let rollups = HashMap::from([(rollup_id, rollup_namespace)]);
+        let usages = attribute(&blobs, &rollups, 1001);
+
+        assert_eq!(2, usages.len());
+        assert_eq!(None, usages[0].rollup_id);
+        assert_eq!(
+            Usage {
+                blobs: 1,
+                bytes: 100,
+                fee_utia: 250,
+            },
+            usages[0].usage
+        );
+        // the rounding remainder goes to the namespace with the most bytes.
+        assert_eq!(Some(rollup_id), usages[1].rollup_id);
+        assert_eq!(
+            Usage {
+                blobs: 1,
+                bytes: 300,
+                fee_utia: 751,
+            },
+            usages[1].usage
+        );
+    }
+
+    #[test]
+    fn summary_only_includes_submissions_within_window() {
+        let (rollup_id, rollup_namespace) = rollup();
+        let rollups = HashMap::from([(rollup_id, rollup_namespace)]);
+        let blobs = [Blob::new(rollup_namespace.get(), vec![0; 10]).unwrap()];
+        let ledger = CostLedger::new();
+
+        let start = Instant::now();
+        ledger.record_at(start, 1, attribute(&blobs, &rollups, 5));
+        let later = start.checked_add(Duration::from_secs(60)).unwrap();
+        ledger.record_at(later, 2, attribute(&blobs, &rollups, 7));
+
+        let summary = ledger.summarize_at(later, Duration::from_secs(30));
+        assert_eq!(1, summary.submissions);
+        assert_eq!(Some(2), summary.latest_celestia_height);
+        assert_eq!(
+            Usage {
+                blobs: 1,
+                bytes: 10,
+                fee_utia: 7,
+            },
+            summary.total
+        );
+
+        let summary = ledger.summarize_at(later, Duration::from_secs(120));
+        assert_eq!(2, summary.submissions);
+        assert_eq!(12, summary.total.fee_utia);
+        assert_eq!(1, summary.namespaces.len());
+    }
+
+    #[test]
+    fn records_older_than_max_window_are_dropped() {
+        let (rollup_id, rollup_namespace) = rollup();
+        let rollups = HashMap::from([(rollup_id, rollup_namespace)]);
+        let blobs = [Blob::new(rollup_namespace.get(), vec![0; 10]).unwrap()];
+        let ledger = CostLedger::new();
+
+        let start = Instant::now();
+        ledger.record_at(start, 1, attribute(&blobs, &rollups, 5));
+        let much_later = start
+            .checked_add(MAX_WINDOW)
+            .and_then(|instant| instant.checked_add(Duration::from_secs(1)))
+            .unwrap();
+        ledger.record_at(much_later, 2, attribute(&blobs, &rollups, 5));
+        assert_eq!(1, ledger.lock().len());
+    }
+}
@@ -1,6 +1,7 @@
 pub(crate) mod api;
 mod build_info;
 pub mod config;
+pub(crate) mod costs;
 pub(crate) mod metrics;
 pub(crate) mod relayer;
 pub mod sequencer_relayer;
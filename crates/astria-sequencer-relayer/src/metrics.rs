@@ -14,6 +14,10 @@ use metrics::{
 };
 use telemetry::metric_names;
 
+use crate::costs::NamespaceUsage;
+
+const NAMESPACE_LABEL: &str = "namespace";
+
 pub(crate) struct Metrics {
     celestia_submission_height: Counter,
     celestia_submission_count: Counter,
@@ -27,6 +31,7 @@ pub(crate) struct Metrics {
     sequencer_height_fetch_failure_count: Counter,
     sequencer_submission_height: Counter,
     compression_ratio_for_astria_block: Gauge,
+    celestia_fees_total_utia: Counter,
 }
 
 impl Metrics {
@@ -119,6 +124,30 @@ impl Metrics {
         );
         let compression_ratio_for_astria_block = gauge!(COMPRESSION_RATIO_FOR_ASTRIA_BLOCK);
 
+        describe_counter!(
+            CELESTIA_FEES_TOTAL_UTIA,
+            Unit::Count,
+            "The total fees in utia paid for successful submissions to Celestia"
+        );
+        let celestia_fees_total_utia = counter!(CELESTIA_FEES_TOTAL_UTIA);
+
+        describe_counter!(
+            CELESTIA_BLOBS_PER_NAMESPACE,
+            Unit::Count,
+            "The number of blobs successfully submitted to Celestia, labelled by namespace"
+        );
+        describe_counter!(
+            CELESTIA_BYTES_PER_NAMESPACE,
+            Unit::Bytes,
+            "The number of blob bytes successfully submitted to Celestia, labelled by namespace"
+        );
+        describe_counter!(
+            CELESTIA_FEES_PER_NAMESPACE_UTIA,
+            Unit::Count,
+            "The fees in utia attributed to each namespace in proportion to its share of the \
+             bytes of a successful Celestia submission, labelled by namespace"
+        );
+
         Self {
             celestia_submission_height,
             celestia_submission_count,
@@ -132,6 +161,7 @@ impl Metrics {
             sequencer_height_fetch_failure_count,
             sequencer_submission_height,
             compression_ratio_for_astria_block,
+            celestia_fees_total_utia,
         }
     }
 
@@ -188,6 +218,26 @@ impl Metrics {
     pub(crate) fn set_compression_ratio_for_astria_block(&self, ratio: f64) {
         self.compression_ratio_for_astria_block.set(ratio);
     }
+
+    pub(crate) fn increment_celestia_fees_total_utia(&self, fee: u64) {
+        self.celestia_fees_total_utia.increment(fee);
+    }
+
+    /// Records the blobs, bytes and fees of a single namespace in a successful submission.
+    ///
+    /// The namespaces are not known ahead of time, so the counters are looked up on every call.
+    // allow: the counters are not stored on `self`, but this is kept as a method so that all
+    // metrics are recorded through `Metrics`.
+    #[allow(clippy::unused_self)]
+    pub(crate) fn record_namespace_usage(&self, entry: &NamespaceUsage) {
+        let namespace = telemetry::display::base64(entry.namespace.as_bytes()).to_string();
+        counter!(CELESTIA_BLOBS_PER_NAMESPACE, NAMESPACE_LABEL => namespace.clone())
+            .increment(entry.usage.blobs);
+        counter!(CELESTIA_BYTES_PER_NAMESPACE, NAMESPACE_LABEL => namespace.clone())
+            .increment(entry.usage.bytes);
+        counter!(CELESTIA_FEES_PER_NAMESPACE_UTIA, NAMESPACE_LABEL => namespace)
+            .increment(entry.usage.fee_utia);
+    }
 }
 
 metric_names!(pub const METRICS_NAMES:
@@ -202,7 +252,11 @@ metric_names!(pub const METRICS_NAMES:
     SEQUENCER_BLOCK_FETCH_FAILURE_COUNT,
     SEQUENCER_HEIGHT_FETCH_FAILURE_COUNT,
     SEQUENCER_SUBMISSION_HEIGHT,
-    COMPRESSION_RATIO_FOR_ASTRIA_BLOCK
+    COMPRESSION_RATIO_FOR_ASTRIA_BLOCK,
+    CELESTIA_FEES_TOTAL_UTIA,
+    CELESTIA_BLOBS_PER_NAMESPACE,
+    CELESTIA_BYTES_PER_NAMESPACE,
+    CELESTIA_FEES_PER_NAMESPACE_UTIA
 );
 
 #[cfg(test)]
@@ -211,6 +265,10 @@ mod tests {
         BLOBS_PER_CELESTIA_TX,
         BLOCKS_PER_CELESTIA_TX,
         BYTES_PER_CELESTIA_TX,
+        CELESTIA_BLOBS_PER_NAMESPACE,
+        CELESTIA_BYTES_PER_NAMESPACE,
+        CELESTIA_FEES_PER_NAMESPACE_UTIA,
+        CELESTIA_FEES_TOTAL_UTIA,
         CELESTIA_PAYLOAD_CREATION_LATENCY,
         CELESTIA_SUBMISSION_COUNT,
         CELESTIA_SUBMISSION_FAILURE_COUNT,
@@ -259,5 +317,12 @@ mod tests {
             COMPRESSION_RATIO_FOR_ASTRIA_BLOCK,
             "compression_ratio_for_astria_block",
         );
+        assert_const(CELESTIA_FEES_TOTAL_UTIA, "celestia_fees_total_utia");
+        assert_const(CELESTIA_BLOBS_PER_NAMESPACE, "celestia_blobs_per_namespace");
+        assert_const(CELESTIA_BYTES_PER_NAMESPACE, "celestia_bytes_per_namespace");
+        assert_const(
+            CELESTIA_FEES_PER_NAMESPACE_UTIA,
+            "celestia_fees_per_namespace_utia",
+        );
     }
 }
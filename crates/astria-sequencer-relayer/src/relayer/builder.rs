@@ -19,8 +19,10 @@ use super::{
     state::State,
     CelestiaClientBuilder,
     CelestiaKeys,
+    SubmissionWindow,
 };
 use crate::{
+    costs::CostLedger,
     metrics::Metrics,
     IncludeRollup,
 };
@@ -35,8 +37,10 @@ pub(crate) struct Builder {
     pub(crate) sequencer_poll_period: Duration,
     pub(crate) sequencer_grpc_endpoint: String,
     pub(crate) rollup_filter: IncludeRollup,
+    pub(crate) submission_window: SubmissionWindow,
     pub(crate) pre_submit_path: PathBuf,
     pub(crate) post_submit_path: PathBuf,
+    pub(crate) cost_ledger: Arc<CostLedger>,
     pub(crate) metrics: &'static Metrics,
 }
 
@@ -53,8 +57,10 @@ impl Builder {
             sequencer_poll_period,
             sequencer_grpc_endpoint,
             rollup_filter,
+            submission_window,
             pre_submit_path,
             post_submit_path,
+            cost_ledger,
             metrics,
         } = self;
 
@@ -92,9 +98,11 @@ impl Builder {
             sequencer_poll_period,
             celestia_client_builder,
             rollup_filter,
+            submission_window,
             state,
             pre_submit_path,
             post_submit_path,
+            cost_ledger,
             metrics,
         })
     }
@@ -98,6 +98,15 @@ use tracing::{
 // From https://github.com/celestiaorg/cosmos-sdk/blob/v1.18.3-sdk-v0.46.14/types/errors/errors.go#L75
 const INSUFFICIENT_FEE_CODE: u32 = 13;
 
+/// The outcome of a successful submission of blobs to Celestia.
+#[derive(Clone, Copy, Debug)]
+pub(super) struct SubmissionReceipt {
+    /// The Celestia height at which the blobs were included.
+    pub(super) height: u64,
+    /// The fee in utia paid for the blob transaction.
+    pub(super) fee_utia: u64,
+}
+
 /// A client using the gRPC interface of a remote Celestia app to submit blob data to the Celestia
 /// chain.
 ///
@@ -123,12 +132,14 @@ impl CelestiaClient {
     /// the first attempt for these blobs, or `None` if it is the first attempt.  The error can be
     /// used to obtain the appropriate fee in the case that the previous attempt failed due to a
     /// low fee.
+    ///
+    /// Returns the Celestia height at which the blobs were included along with the fee paid.
     // Copied from https://github.com/celestiaorg/celestia-app/blob/v1.4.0/x/blob/payforblob.go
     pub(super) async fn try_submit(
         mut self,
         blobs: Arc<Vec<Blob>>,
         last_error_receiver: watch::Receiver<Option<TrySubmitError>>,
-    ) -> Result<u64, TrySubmitError> {
+    ) -> Result<SubmissionReceipt, TrySubmitError> {
         info!("fetching cost params and account info from celestia app");
         let (blob_params, auth_params, min_gas_price, base_account) = tokio::try_join!(
             self.fetch_blob_params(),
@@ -177,7 +188,10 @@ impl CelestiaClient {
         info!(tx_hash = %tx_hash.0, "broadcast blob transaction succeeded");
 
         let height = self.confirm_submission(tx_hash).await;
-        Ok(height)
+        Ok(SubmissionReceipt {
+            height,
+            fee_utia: fee,
+        })
     }
 
     async fn fetch_account(&self) -> Result<BaseAccount, TrySubmitError> {
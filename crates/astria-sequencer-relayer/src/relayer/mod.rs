@@ -65,13 +65,16 @@ use celestia_client::{
     BuilderError,
     CelestiaClientBuilder,
     CelestiaKeys,
+    SubmissionReceipt,
     TrySubmitError,
 };
 use state::State;
 pub(crate) use state::StateSnapshot;
 use submission::SubmissionState;
+pub(crate) use write::SubmissionWindow;
 
 use crate::{
+    costs::CostLedger,
     metrics::Metrics,
     IncludeRollup,
 };
@@ -103,11 +106,18 @@ pub(crate) struct Relayer {
     /// The rollups whose data should be included in submissions.
     rollup_filter: IncludeRollup,
 
+    /// The rules for when submissions to Celestia are made.
+    submission_window: SubmissionWindow,
+
     /// A watch channel to track the state of the relayer. Used by the API service.
     state: Arc<State>,
 
     pre_submit_path: PathBuf,
     post_submit_path: PathBuf,
+
+    /// The per-namespace record of Celestia submission costs. Shared with the API service.
+    cost_ledger: Arc<CostLedger>,
+
     metrics: &'static Metrics,
 }
 
@@ -147,9 +157,11 @@ impl Relayer {
         let (mut submitter_task, submitter) = spawn_submitter(
             self.celestia_client_builder.clone(),
             self.rollup_filter.clone(),
+            self.submission_window.clone(),
             self.state.clone(),
             submission_state,
             self.submitter_shutdown_token.clone(),
+            self.cost_ledger.clone(),
             self.metrics,
         );
 
@@ -370,9 +382,11 @@ async fn read_submission_state<P1: AsRef<Path>, P2: AsRef<Path>>(
 fn spawn_submitter(
     client_builder: CelestiaClientBuilder,
     rollup_filter: IncludeRollup,
+    submission_window: SubmissionWindow,
     state: Arc<State>,
     submission_state: SubmissionState,
     submitter_shutdown_token: CancellationToken,
+    cost_ledger: Arc<CostLedger>,
     metrics: &'static Metrics,
 ) -> (
     Fuse<JoinHandle<eyre::Result<()>>>,
@@ -381,9 +395,11 @@ fn spawn_submitter(
     let (submitter, handle) = write::BlobSubmitter::new(
         client_builder,
         rollup_filter,
+        submission_window,
         state,
         submission_state,
         submitter_shutdown_token,
+        cost_ledger,
         metrics,
     );
     (tokio::spawn(submitter.run()).fuse(), handle)
@@ -9,7 +9,11 @@ use std::{
 };
 
 use astria_core::{
-    brotli::compress_bytes,
+    celestia::{
+        Blob,
+        BlobError,
+        Namespace,
+    },
     generated::sequencerblock::v1alpha1::{
         SubmittedMetadata,
         SubmittedMetadataList,
@@ -18,10 +22,6 @@ use astria_core::{
     },
     primitive::v1::RollupId,
 };
-use celestia_types::{
-    nmt::Namespace,
-    Blob,
-};
 use futures::Future;
 use pin_project_lite::pin_project;
 use sequencer_client::SequencerBlock;
@@ -48,7 +48,7 @@ pub(super) struct Submission {
 }
 
 impl Submission {
-    pub(super) fn into_blobs(self) -> Vec<Blob> {
+    pub(super) fn into_blobs(self) -> Vec<celestia_types::Blob> {
         self.payload.blobs
     }
 
@@ -56,6 +56,11 @@ impl Submission {
         self.input.meta()
     }
 
+    /// The Celestia namespaces of the rollups included in this submission.
+    pub(super) fn rollup_namespaces(&self) -> &HashMap<RollupId, Namespace> {
+        &self.input.meta().rollups_included
+    }
+
     pub(super) fn num_blobs(&self) -> usize {
         self.payload.num_blobs()
     }
@@ -90,17 +95,15 @@ impl Submission {
 
 #[derive(Debug, thiserror::Error)]
 pub(super) enum PayloadError {
-    #[error("failed to compress protobuf encoded bytes")]
-    Compress(#[from] std::io::Error),
-    #[error("failed to create Celestia blob from compressed bytes")]
-    Blob(#[from] celestia_types::Error),
+    #[error("failed to create Celestia blob from protobuf encoded bytes")]
+    Blob(#[from] BlobError),
 }
 
 #[derive(Debug, Default)]
 struct Payload {
     compressed_size: usize,
     uncompressed_size: usize,
-    blobs: Vec<Blob>,
+    blobs: Vec<celestia_types::Blob>,
 }
 
 impl Payload {
@@ -133,32 +136,31 @@ impl Payload {
         namespace: Namespace,
         value: &T,
     ) -> Result<(), PayloadError> {
-        let encoded = value.encode_to_vec();
-        let compressed = compress_bytes(&encoded)?;
-        let blob = Blob::new(namespace, compressed)?;
+        let encoded_len = value.encoded_len();
+        let blob = Blob::try_from_message(namespace, value)?;
         self.uncompressed_size = self
             .uncompressed_size
-            .checked_add(encoded.len())
+            .checked_add(encoded_len)
             .unwrap_or_else(|| {
                 error!(
                     uncompressed_size = self.uncompressed_size,
-                    encoded_len = encoded.len(),
+                    encoded_len,
                     "overflowed uncompressed size while adding new value; setting to `usize::MAX`"
                 );
                 usize::MAX
             });
         self.compressed_size = self
             .compressed_size
-            .checked_add(blob.data.len())
+            .checked_add(blob.data().len())
             .unwrap_or_else(|| {
                 error!(
                     compressed_size = self.compressed_size,
-                    blob_data_len = blob.data.len(),
+                    blob_data_len = blob.data().len(),
                     "overflowed compressed size while adding new value; setting to `usize::MAX`"
                 );
                 usize::MAX
             });
-        self.blobs.push(blob);
+        self.blobs.push(blob.into_inner());
         Ok(())
     }
 
@@ -241,8 +243,7 @@ impl Input {
         self.metadata.push(metadata);
         for elem in rollup_data {
             if rollup_filter.should_include(&elem.rollup_id()) {
-                let namespace =
-                    astria_core::celestia::namespace_v0_from_rollup_id(elem.rollup_id());
+                let namespace = Namespace::from_rollup_id(elem.rollup_id());
                 self.meta
                     .rollups_included
                     .insert(elem.rollup_id(), namespace);
@@ -367,6 +368,11 @@ impl NextSubmission {
         }
     }
 
+    /// Returns the compressed size of the data accumulated for the next submission.
+    pub(super) fn compressed_size(&self) -> usize {
+        self.payload.compressed_size
+    }
+
     /// Lazily move the currently items out of the next submission.
     ///
     /// The main reason for this method to exist is to work around async-cancellation.
@@ -429,14 +435,7 @@ fn sequencer_namespace(metadata: &SubmittedMetadata) -> Namespace {
     const HEADER_EXPECT_MSG: &str =
         concatcp!(SubmittedMetadata::PACKAGE, ".", SubmittedMetadata::NAME,);
 
-    astria_core::celestia::namespace_v0_from_sha256_of_bytes(
-        metadata
-            .header
-            .as_ref()
-            .expect(HEADER_EXPECT_MSG)
-            .chain_id
-            .as_bytes(),
-    )
+    Namespace::from_chain_id(&metadata.header.as_ref().expect(HEADER_EXPECT_MSG).chain_id)
 }
 
 fn serialize_opt_namespace<S>(
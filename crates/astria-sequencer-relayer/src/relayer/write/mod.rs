@@ -10,7 +10,10 @@
 //! they will be written in that order.
 use std::{
     sync::Arc,
-    time::Duration,
+    time::{
+        Duration,
+        Instant,
+    },
 };
 
 use astria_eyre::eyre::{
@@ -54,16 +57,23 @@ use super::{
     celestia_client::CelestiaClient,
     BuilderError,
     CelestiaClientBuilder,
+    SubmissionReceipt,
     SubmissionState,
     TrySubmitError,
 };
 use crate::{
+    costs::{
+        self,
+        CostLedger,
+    },
     metrics::Metrics,
     IncludeRollup,
 };
 
 mod conversion;
 use conversion::NextSubmission;
+mod window;
+pub(crate) use window::SubmissionWindow;
 
 #[derive(Clone)]
 pub(super) struct BlobSubmitterHandle {
@@ -121,6 +131,12 @@ pub(super) struct BlobSubmitter {
     /// hardcoded limit.
     pending_block: Option<SequencerBlock>,
 
+    /// The rules for when the next submission is made.
+    submission_window: SubmissionWindow,
+
+    /// The per-namespace record of what was submitted to Celestia and at what cost.
+    cost_ledger: Arc<CostLedger>,
+
     metrics: &'static Metrics,
 }
 
@@ -128,9 +144,11 @@ impl BlobSubmitter {
     pub(super) fn new(
         client_builder: CelestiaClientBuilder,
         rollup_filter: IncludeRollup,
+        submission_window: SubmissionWindow,
         state: Arc<super::State>,
         submission_state: SubmissionState,
         submitter_shutdown_token: CancellationToken,
+        cost_ledger: Arc<CostLedger>,
         metrics: &'static Metrics,
     ) -> (Self, BlobSubmitterHandle) {
         // XXX: The channel size here is just a number. It should probably be based on some
@@ -144,6 +162,8 @@ impl BlobSubmitter {
             submission_state,
             submitter_shutdown_token,
             pending_block: None,
+            submission_window,
+            cost_ledger,
             metrics,
         };
         let handle = BlobSubmitterHandle {
@@ -166,7 +186,18 @@ impl BlobSubmitter {
         // A submission to Celestia that is currently in-flight.
         let mut ongoing_submission = Fuse::terminated();
 
+        // The instant at which the last submission to Celestia was started.
+        let mut last_submission_start: Option<Instant> = None;
+
         let reason = loop {
+            // Evaluated on every iteration so that it accounts for newly added blocks.
+            let hold_submission = self.submission_window.hold_for(
+                last_submission_start.map(|start| start.elapsed()),
+                self.next_submission.compressed_size(),
+                !self.has_capacity(),
+                window::utc_time_of_day(),
+            );
+
             select!(
                 biased;
 
@@ -193,15 +224,19 @@ impl BlobSubmitter {
                     };
                 }
 
-                // submit blocks to Celestia, if no submission in flight
+                // submit blocks to Celestia, if no submission in flight and the submission
+                // window is open
                 Some(submission) = self.next_submission.take(),
                                     if ongoing_submission.is_terminated()
+                                        && hold_submission.is_none()
                                     => {
+                    last_submission_start = Some(Instant::now());
                     ongoing_submission = submit_blobs(
                         client.clone(),
                         submission,
                         self.state.clone(),
                         self.submission_state.clone(),
+                        self.cost_ledger.clone(),
                         self.metrics,
                     ).boxed().fuse();
                     if let Some(block) = self.pending_block.take() {
@@ -213,6 +248,10 @@ impl BlobSubmitter {
                     }
                 }
 
+                // re-evaluate the submission window once a held back submission can be made
+                () = tokio::time::sleep(hold_submission.unwrap_or_default()),
+                    if hold_submission.is_some() => {}
+
                 // add new blocks to the next submission if there is space.
                 Some(block) = self.blocks.recv(), if self.has_capacity() => {
                     if let Err(error) = self.add_sequencer_block_to_next_submission(block) {
@@ -276,6 +315,7 @@ async fn submit_blobs(
     data: conversion::Submission,
     state: Arc<super::State>,
     submission_state: SubmissionState,
+    cost_ledger: Arc<CostLedger>,
     metrics: &'static Metrics,
 ) -> eyre::Result<SubmissionState> {
     info!(
@@ -295,7 +335,8 @@ async fn submit_blobs(
     metrics.record_blobs_per_celestia_tx(data.num_blobs());
 
     let largest_sequencer_height = data.greatest_sequencer_height();
-    let blobs = data.into_blobs();
+    let rollup_namespaces = data.rollup_namespaces().clone();
+    let blobs = Arc::new(data.into_blobs());
 
     let submission_started = match crate::utils::flatten(
         tokio::task::spawn_blocking(move || submission_state.initialize(largest_sequencer_height))
@@ -309,19 +350,29 @@ async fn submit_blobs(
         Ok(state) => state,
     };
 
-    let celestia_height = match submit_with_retry(client, blobs, state.clone(), metrics).await {
+    let SubmissionReceipt {
+        height: celestia_height,
+        fee_utia,
+    } = match submit_with_retry(client, blobs.clone(), state.clone(), metrics).await {
         Err(error) => {
             let message = "failed submitting blobs to Celestia";
             error!(%error, message);
             return Err(error.wrap_err(message));
         }
-        Ok(height) => height,
+        Ok(receipt) => receipt,
     };
     metrics.absolute_set_sequencer_submission_height(largest_sequencer_height.value());
     metrics.absolute_set_celestia_submission_height(celestia_height);
     metrics.record_celestia_submission_latency(start.elapsed());
+    metrics.increment_celestia_fees_total_utia(fee_utia);
 
-    info!(%celestia_height, "successfully submitted blobs to Celestia");
+    let namespace_usages = costs::attribute(blobs.iter(), &rollup_namespaces, fee_utia);
+    for entry in &namespace_usages {
+        metrics.record_namespace_usage(entry);
+    }
+    cost_ledger.record(celestia_height, namespace_usages);
+
+    info!(%celestia_height, fee_utia, "successfully submitted blobs to Celestia");
 
     state.set_celestia_connected(true);
     state.set_latest_confirmed_celestia_height(celestia_height);
@@ -385,10 +436,10 @@ async fn init_with_retry(client_builder: CelestiaClientBuilder) -> eyre::Result<
 
 async fn submit_with_retry(
     client: CelestiaClient,
-    blobs: Vec<Blob>,
+    blobs: Arc<Vec<Blob>>,
     state: Arc<super::State>,
     metrics: &'static Metrics,
-) -> eyre::Result<u64> {
+) -> eyre::Result<SubmissionReceipt> {
     // Moving the span into `on_retry`, because tryhard spawns these in a tokio
     // task, losing the span.
     let span = Span::current();
@@ -424,9 +475,7 @@ async fn submit_with_retry(
             },
         );
 
-    let blobs = Arc::new(blobs);
-
-    let height = tryhard::retry_fn(move || {
+    let receipt = tryhard::retry_fn(move || {
         client
             .clone()
             .try_submit(blobs.clone(), last_error_receiver.clone())
@@ -435,5 +484,5 @@ async fn submit_with_retry(
     .in_current_span()
     .await
     .wrap_err("retry attempts exhausted; bailing")?;
-    Ok(height)
+    Ok(receipt)
 }
@@ -0,0 +1,266 @@
+//! Rules for when accumulated sequencer blocks are submitted to Celestia.
+//!
+//! By default, a submission is made as soon as the previous one has finished and there is data to
+//! submit, minimizing the time until sequencer blocks are firmly committed. As every submission is
+//! a Celestia transaction with its own fee, operators can instead trade latency for lower costs:
+//!
+//! + by batching blocks over an interval, submitting at most once per interval;
+//! + by submitting before the interval elapsed once enough data has accumulated;
+//! + by not submitting at all during quiet periods, given as UTC times of day.
+use std::{
+    str::FromStr,
+    time::{
+        Duration,
+        SystemTime,
+    },
+};
+
+use astria_eyre::eyre::{
+    self,
+    ensure,
+    eyre,
+    WrapErr as _,
+};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+const DAY: Duration = Duration::from_secs(SECONDS_PER_DAY);
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub(crate) struct SubmissionWindow {
+    /// The minimum duration between the starts of two submissions. Zero submits as soon as
+    /// possible.
+    interval: Duration,
+    /// The compressed size of accumulated data at which it is submitted before `interval`
+    /// elapsed. Zero disables submitting early.
+    min_bytes: usize,
+    /// The periods of the day during which no submissions are made.
+    quiet_periods: Vec<QuietPeriod>,
+}
+
+impl SubmissionWindow {
+    /// Constructs a submission window from its configured values.
+    ///
+    /// `quiet_periods` is a comma-separated list of `HH:MM-HH:MM` ranges of UTC times of day.
+    ///
+    /// # Errors
+    /// Returns an error if any of the quiet periods cannot be parsed.
+    pub(crate) fn new(
+        interval: Duration,
+        min_bytes: usize,
+        quiet_periods: &str,
+    ) -> eyre::Result<Self> {
+        let quiet_periods = quiet_periods
+            .split(',')
+            .map(str::trim)
+            .filter(|period| !period.is_empty())
+            .map(|period| {
+                period.parse().wrap_err_with(|| {
+                    format!(
+                        "failed to parse `{period}` as a quiet period in configured \
+                         submission_quiet_periods"
+                    )
+                })
+            })
+            .collect::<eyre::Result<_>>()?;
+        Ok(Self {
+            interval,
+            min_bytes,
+            quiet_periods,
+        })
+    }
+
+    /// Returns for how long the next submission must be held back, or `None` if it can be made
+    /// now.
+    ///
+    /// `since_last_submission` is `None` if no submission was made yet. `pending_bytes` is the
+    /// compressed size of the next submission, and `is_full` whether it cannot take any more
+    /// blocks. `time_of_day` is the current UTC time of day.
+    pub(super) fn hold_for(
+        &self,
+        since_last_submission: Option<Duration>,
+        pending_bytes: usize,
+        is_full: bool,
+        time_of_day: Duration,
+    ) -> Option<Duration> {
+        if let Some(remaining) = self
+            .quiet_periods
+            .iter()
+            .find_map(|period| period.remaining(time_of_day))
+        {
+            return Some(remaining);
+        }
+        if is_full || (self.min_bytes > 0 && pending_bytes >= self.min_bytes) {
+            return None;
+        }
+        self.interval
+            .checked_sub(since_last_submission?)
+            .filter(|remaining| !remaining.is_zero())
+    }
+}
+
+/// Returns the current UTC time of day.
+pub(super) fn utc_time_of_day() -> Duration {
+    let since_epoch = SystemTime::UNIX_EPOCH.elapsed().unwrap_or_default();
+    Duration::from_secs(since_epoch.as_secs() % SECONDS_PER_DAY)
+}
+
+/// A period of the day, which wraps around midnight if it ends before it starts.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct QuietPeriod {
+    start: Duration,
+    end: Duration,
+}
+
+impl QuietPeriod {
+    /// Returns the time until the end of the period if `time_of_day` falls within it.
+    fn remaining(self, time_of_day: Duration) -> Option<Duration> {
+        let is_within = if self.start < self.end {
+            self.start <= time_of_day && time_of_day < self.end
+        } else {
+            self.start <= time_of_day || time_of_day < self.end
+        };
+        is_within.then(|| {
+            if time_of_day < self.end {
+                self.end.saturating_sub(time_of_day)
+            } else {
+                DAY.saturating_sub(time_of_day).saturating_add(self.end)
+            }
+        })
+    }
+}
+
+impl FromStr for QuietPeriod {
+    type Err = eyre::Report;
+
+    fn from_str(s: &str) -> eyre::Result<Self> {
+        let (start, end) = s
+            .split_once('-')
+            .ok_or_else(|| eyre!("expected a period of the form `HH:MM-HH:MM`"))?;
+        let start = parse_time_of_day(start.trim()).wrap_err("invalid start of period")?;
+        let end = parse_time_of_day(end.trim()).wrap_err("invalid end of period")?;
+        ensure!(
+            start != end,
+            "period must not start and end at the same time"
+        );
+        Ok(Self {
+            start,
+            end,
+        })
+    }
+}
+
+/// Parses a time of day of the form `HH:MM`.
+fn parse_time_of_day(s: &str) -> eyre::Result<Duration> {
+    let (hours, minutes) = s
+        .split_once(':')
+        .ok_or_else(|| eyre!("expected a time of the form `HH:MM`, got `{s}`"))?;
+    let hours: u64 = hours
+        .parse()
+        .wrap_err_with(|| format!("failed to parse hours of `{s}`"))?;
+    let minutes: u64 = minutes
+        .parse()
+        .wrap_err_with(|| format!("failed to parse minutes of `{s}`"))?;
+    ensure!(
+        hours < 24 && minutes < 60,
+        "`{s}` is not a valid time of day"
+    );
+    Ok(Duration::from_secs(
+        hours
+            .saturating_mul(3600)
+            .saturating_add(minutes.saturating_mul(60)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hm(hours: u64, minutes: u64) -> Duration {
+        parse_time_of_day(&format!("{hours:02}:{minutes:02}")).unwrap()
+    }
+
+    #[test]
+    fn quiet_periods_are_parsed() {
+        let window =
+            SubmissionWindow::new(Duration::ZERO, 0, " 22:00-06:30, 12:00-12:15,").unwrap();
+        assert_eq!(
+            window.quiet_periods,
+            vec![
+                QuietPeriod {
+                    start: hm(22, 0),
+                    end: hm(6, 30),
+                },
+                QuietPeriod {
+                    start: hm(12, 0),
+                    end: hm(12, 15),
+                },
+            ]
+        );
+        assert!(
+            SubmissionWindow::new(Duration::ZERO, 0, "")
+                .unwrap()
+                .quiet_periods
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn invalid_quiet_periods_are_rejected() {
+        for input in [
+            "22:00",
+            "22:00-",
+            "24:00-01:00",
+            "01:60-02:00",
+            "1-2",
+            "03:00-03:00",
+        ] {
+            assert!(
+                SubmissionWindow::new(Duration::ZERO, 0, input).is_err(),
+                "`{input}` should have been rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn default_window_never_holds() {
+        let window = SubmissionWindow::default();
+        assert_eq!(window.hold_for(None, 0, false, hm(3, 0)), None);
+        assert_eq!(
+            window.hold_for(Some(Duration::ZERO), 0, false, hm(3, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn submissions_are_held_for_remaining_interval() {
+        let window = SubmissionWindow::new(Duration::from_secs(60), 0, "").unwrap();
+        assert_eq!(window.hold_for(None, 10, false, hm(3, 0)), None);
+        assert_eq!(
+            window.hold_for(Some(Duration::from_secs(20)), 10, false, hm(3, 0)),
+            Some(Duration::from_secs(40))
+        );
+        assert_eq!(
+            window.hold_for(Some(Duration::from_secs(60)), 10, false, hm(3, 0)),
+            None
+        );
+    }
+
+    #[test]
+    fn enough_or_full_data_is_submitted_before_interval() {
+        let window = SubmissionWindow::new(Duration::from_secs(60), 100, "").unwrap();
+        let since_last = Some(Duration::from_secs(20));
+        assert!(window.hold_for(since_last, 99, false, hm(3, 0)).is_some());
+        assert_eq!(window.hold_for(since_last, 100, false, hm(3, 0)), None);
+        assert_eq!(window.hold_for(since_last, 10, true, hm(3, 0)), None);
+    }
+
+    #[test]
+    fn submissions_are_held_until_end_of_quiet_period() {
+        let window = SubmissionWindow::new(Duration::ZERO, 0, "22:00-06:00,12:00-12:30").unwrap();
+        assert_eq!(window.hold_for(None, 0, true, hm(12, 10)), Some(hm(0, 20)));
+        assert_eq!(window.hold_for(None, 0, true, hm(23, 0)), Some(hm(7, 0)));
+        assert_eq!(window.hold_for(None, 0, true, hm(1, 0)), Some(hm(5, 0)));
+        assert_eq!(window.hold_for(None, 0, true, hm(6, 0)), None);
+        assert_eq!(window.hold_for(None, 0, true, hm(12, 30)), None);
+    }
+}
@@ -1,6 +1,9 @@
 use std::{
     net::SocketAddr,
-    sync::OnceLock,
+    sync::{
+        Arc,
+        OnceLock,
+    },
     time::Duration,
 };
 
@@ -29,6 +32,7 @@ use tracing::{
 use crate::{
     api,
     config::Config,
+    costs::CostLedger,
     metrics::Metrics,
     relayer::{
         self,
@@ -54,6 +58,7 @@ impl SequencerRelayer {
 
         let shutdown_handle = ShutdownHandle::new();
         let rollup_filter = cfg.only_include_rollups()?;
+        let submission_window = cfg.submission_window()?;
         let Config {
             sequencer_chain_id,
             celestia_chain_id,
@@ -68,6 +73,8 @@ impl SequencerRelayer {
             ..
         } = cfg;
 
+        let cost_ledger = Arc::new(CostLedger::new());
+
         let relayer = relayer::Builder {
             relayer_shutdown_token: shutdown_handle.token.child_token(),
             sequencer_chain_id,
@@ -78,8 +85,10 @@ impl SequencerRelayer {
             sequencer_poll_period: Duration::from_millis(block_time),
             sequencer_grpc_endpoint,
             rollup_filter,
+            submission_window,
             pre_submit_path,
             post_submit_path,
+            cost_ledger: cost_ledger.clone(),
             metrics,
         }
         .build()
@@ -89,7 +98,7 @@ impl SequencerRelayer {
         let api_socket_addr = api_addr.parse::<SocketAddr>().wrap_err_with(|| {
             format!("failed to parse provided `api_addr` string as socket address: `{api_addr}`",)
         })?;
-        let api_server = api::start(api_socket_addr, state_rx);
+        let api_server = api::start(api_socket_addr, state_rx, cost_ledger);
         let relayer = Self {
             api_server,
             relayer,
@@ -734,6 +734,9 @@ impl TestSequencerRelayerConfig {
             pretty_print: true,
             pre_submit_path: pre_submit_file.path().to_owned(),
             post_submit_path: post_submit_file.path().to_owned(),
+            submission_interval_ms: 0,
+            submission_min_bytes: 0,
+            submission_quiet_periods: String::new(),
         };
 
         info!(config = serde_json::to_string(&config).unwrap());
@@ -0,0 +1,369 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr,
+};
+use astria_sequencer_client::{
+    tendermint::Time,
+    Client as _,
+    HttpClient,
+};
+use serde::Serialize;
+
+use crate::blob_parser::{
+    colored,
+    colored_ln,
+    Format,
+};
+
+/// The maximum number of block metas returned by CometBFT's `blockchain` endpoint per request.
+const BLOCKCHAIN_PAGE_SIZE: u64 = 20;
+
+/// The two-sided z-score of a 95% confidence interval.
+const Z_95: f64 = 1.96;
+
+const NANOS_PER_SEC: f64 = 1e9;
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The URL of the sequencer's CometBFT RPC endpoint
+    #[arg(long, value_name = "URL")]
+    sequencer_url: String,
+
+    /// The time at which the activation should happen, in RFC 3339 format (e.g.
+    /// `2024-07-01T12:00:00Z`)
+    #[arg(long, value_name = "TIME")]
+    target_time: String,
+
+    /// The number of most recent blocks over which the block time is estimated
+    #[arg(long, default_value_t = 1000)]
+    window: u32,
+
+    /// Configure formatting of output
+    #[arg(short, long, default_value_t = Format::Display, value_enum)]
+    format: Format,
+}
+
+/// Calculates the block height at which the sequencer is expected to reach a target time.
+///
+/// The block times are estimated by a linear regression of the timestamps of the most recent
+/// blocks against their heights, and the uncertainty of the fit is reported as a 95% confidence
+/// interval around the target time.
+///
+/// # Errors
+///
+/// Returns an error if the target time is invalid or not in the future, if the block timestamps
+/// cannot be fetched, or if they do not allow a block time to be estimated.
+pub fn run(
+    Args {
+        sequencer_url,
+        target_time,
+        window,
+        format,
+    }: Args,
+) -> Result<()> {
+    ensure!(window >= 3, "`--window` must be at least 3 blocks");
+    let target_time = Time::parse_from_rfc3339(&target_time)
+        .wrap_err("`--target-time` is not a valid RFC 3339 timestamp")?;
+
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .wrap_err("failed to create tokio runtime")?;
+    let samples = runtime.block_on(fetch_block_times(&sequencer_url, window))?;
+    let activation_point = ActivationPoint::new(&samples, target_time)?;
+
+    match format {
+        Format::Display => println!("{activation_point}"),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(&activation_point).wrap_err("failed to json-encode")?
+        ),
+    }
+    Ok(())
+}
+
+/// Returns the heights and unix timestamps (in seconds) of the most recent `window` blocks.
+async fn fetch_block_times(sequencer_url: &str, window: u32) -> Result<Vec<(u64, f64)>> {
+    let client =
+        HttpClient::new(sequencer_url).wrap_err("failed constructing http sequencer client")?;
+    let latest_height = client
+        .latest_block()
+        .await
+        .wrap_err("failed to get latest cometbft block")?
+        .block
+        .header
+        .height
+        .value();
+    let first_height = latest_height
+        .saturating_sub(u64::from(window))
+        .saturating_add(1)
+        .max(1);
+
+    let mut samples = Vec::with_capacity(usize::try_from(window).unwrap_or_default());
+    let mut min_height = first_height;
+    while min_height <= latest_height {
+        let max_height = min_height
+            .saturating_add(BLOCKCHAIN_PAGE_SIZE - 1)
+            .min(latest_height);
+        let response = client
+            .blockchain(
+                u32::try_from(min_height).wrap_err("height does not fit into u32")?,
+                u32::try_from(max_height).wrap_err("height does not fit into u32")?,
+            )
+            .await
+            .wrap_err_with(|| {
+                format!("failed to get block metas from height {min_height} to {max_height}")
+            })?;
+        samples.extend(
+            response
+                .block_metas
+                .into_iter()
+                .map(|meta| (meta.header.height.value(), to_secs(meta.header.time))),
+        );
+        min_height = max_height.saturating_add(1);
+    }
+    samples.sort_unstable_by_key(|(height, _)| *height);
+    Ok(samples)
+}
+
+// allow: converting current unix timestamps in nanoseconds to f64 loses precision well below a
+// microsecond, which is irrelevant for block times.
+#[allow(clippy::cast_precision_loss)]
+fn to_secs(time: Time) -> f64 {
+    time.unix_timestamp_nanos() as f64 / NANOS_PER_SEC
+}
+
+// allow: the values are positive unix timestamps, far from the bounds of the target types.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn from_secs(secs: f64) -> String {
+    Time::from_unix_timestamp(secs.trunc() as i64, (secs.fract() * NANOS_PER_SEC) as u32)
+        .map_or_else(
+            |_| format!("{secs} seconds after the unix epoch"),
+            |time| time.to_rfc3339(),
+        )
+}
+
+/// A least squares fit of block timestamps (in seconds) against block heights.
+#[derive(Debug)]
+struct BlockTimeFit {
+    samples: f64,
+    mean_height: f64,
+    mean_time: f64,
+    /// The sum of squared deviations of the heights from their mean.
+    height_variation: f64,
+    /// The average block time in seconds.
+    slope: f64,
+    /// The standard deviation of the timestamps around the fitted line.
+    residual_std: f64,
+}
+
+impl BlockTimeFit {
+    // allow: block heights and sample counts are far below 2^52 and so are represented exactly.
+    #[allow(clippy::cast_precision_loss)]
+    fn new(samples: &[(u64, f64)]) -> Result<Self> {
+        ensure!(
+            samples.len() >= 3,
+            "at least 3 blocks are required to estimate the block time, got {}",
+            samples.len()
+        );
+        let count = samples.len() as f64;
+        let mean_height = samples
+            .iter()
+            .map(|(height, _)| *height as f64)
+            .sum::<f64>()
+            / count;
+        let mean_time = samples.iter().map(|(_, time)| time).sum::<f64>() / count;
+        let (height_variation, covariation) = samples.iter().fold(
+            (0.0, 0.0),
+            |(height_variation, covariation), (height, time)| {
+                let height_delta = *height as f64 - mean_height;
+                (
+                    height_variation + height_delta * height_delta,
+                    covariation + height_delta * (time - mean_time),
+                )
+            },
+        );
+        let slope = covariation / height_variation;
+        ensure!(
+            slope > 0.0,
+            "the block timestamps do not increase with height; cannot estimate the block time"
+        );
+        let squared_residuals: f64 = samples
+            .iter()
+            .map(|(height, time)| {
+                let residual = time - (mean_time + slope * (*height as f64 - mean_height));
+                residual * residual
+            })
+            .sum();
+        Ok(Self {
+            samples: count,
+            mean_height,
+            mean_time,
+            height_variation,
+            slope,
+            residual_std: (squared_residuals / (count - 2.0)).sqrt(),
+        })
+    }
+
+    fn height_at(&self, time: f64) -> f64 {
+        self.mean_height + (time - self.mean_time) / self.slope
+    }
+
+    fn time_at(&self, height: f64) -> f64 {
+        self.mean_time + self.slope * (height - self.mean_height)
+    }
+
+    /// The half-width of the 95% confidence interval of the fitted time at `height`.
+    fn time_margin(&self, height: f64) -> f64 {
+        let height_delta = height - self.mean_height;
+        Z_95 * self.residual_std
+            * (1.0 / self.samples + height_delta * height_delta / self.height_variation).sqrt()
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct ActivationPoint {
+    latest_height: u64,
+    latest_time: String,
+    blocks_sampled: usize,
+    average_block_time_secs: f64,
+    target_time: String,
+    /// The first height expected to be reached at or after the target time.
+    activation_height: u64,
+    /// The 95% confidence interval of the time at which `activation_height` is reached.
+    earliest_activation_time: String,
+    latest_activation_time: String,
+    /// The 95% confidence interval of the height reached at the target time.
+    min_height_at_target: u64,
+    max_height_at_target: u64,
+}
+
+impl ActivationPoint {
+    // allow: the heights are positive and far below 2^52; rounding them to integers is intended.
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    fn new(samples: &[(u64, f64)], target_time: Time) -> Result<Self> {
+        let fit = BlockTimeFit::new(samples)?;
+        let &(latest_height, latest_time) =
+            samples.last().expect("the fit ensures there are samples");
+        let target = to_secs(target_time);
+        ensure!(
+            target > latest_time,
+            "target time {} is not after the latest block time {}",
+            target_time.to_rfc3339(),
+            from_secs(latest_time),
+        );
+
+        let activation_height = (fit.height_at(target).ceil() as u64).max(latest_height + 1);
+        let activation_time = fit.time_at(activation_height as f64);
+        let time_margin = fit.time_margin(activation_height as f64);
+        let height_margin = time_margin / fit.slope;
+        let height_at_target = fit.height_at(target);
+        Ok(Self {
+            latest_height,
+            latest_time: from_secs(latest_time),
+            blocks_sampled: samples.len(),
+            average_block_time_secs: fit.slope,
+            target_time: target_time.to_rfc3339(),
+            activation_height,
+            earliest_activation_time: from_secs(activation_time - time_margin),
+            latest_activation_time: from_secs(activation_time + time_margin),
+            min_height_at_target: ((height_at_target - height_margin).floor() as u64)
+                .max(latest_height),
+            max_height_at_target: (height_at_target + height_margin).ceil() as u64,
+        })
+    }
+}
+
+impl Display for ActivationPoint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        colored_ln(
+            f,
+            "latest block",
+            format!("{} at {}", self.latest_height, self.latest_time),
+        )?;
+        colored_ln(
+            f,
+            "average block time",
+            format!(
+                "{:.3}s over the last {} blocks",
+                self.average_block_time_secs, self.blocks_sampled
+            ),
+        )?;
+        colored_ln(f, "target time", &self.target_time)?;
+        colored_ln(f, "activation height", self.activation_height)?;
+        colored_ln(
+            f,
+            "expected activation time (95% confidence)",
+            format!(
+                "{} to {}",
+                self.earliest_activation_time, self.latest_activation_time
+            ),
+        )?;
+        colored(
+            f,
+            "expected height at target time (95% confidence)",
+            format!(
+                "{} to {}",
+                self.min_height_at_target, self.max_height_at_target
+            ),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // allow: the test values are small and exactly representable.
+    #[allow(clippy::cast_precision_loss)]
+    fn samples(block_time: f64, jitter: f64) -> Vec<(u64, f64)> {
+        (1..=100)
+            .map(|height| {
+                let jitter = if height % 2 == 0 { jitter } else { -jitter };
+                (
+                    height,
+                    1_700_000_000.0 + block_time * height as f64 + jitter,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn block_time_is_fitted() {
+        let fit = BlockTimeFit::new(&samples(2.0, 0.0)).unwrap();
+        assert!((fit.slope - 2.0).abs() < 1e-9);
+        assert!(fit.residual_std < 1e-6);
+
+        let fit = BlockTimeFit::new(&samples(2.0, 0.5)).unwrap();
+        assert!((fit.slope - 2.0).abs() < 0.01);
+        assert!(fit.residual_std > 0.4);
+    }
+
+    #[test]
+    fn activation_height_is_predicted_with_interval() {
+        // the 100th block is at 1_700_000_200, so 200 seconds later is 100 blocks later
+        let target = Time::from_unix_timestamp(1_700_000_400, 0).unwrap();
+        let point = ActivationPoint::new(&samples(2.0, 0.5), target).unwrap();
+        assert!((199..=201).contains(&point.activation_height));
+        assert!(point.min_height_at_target < point.max_height_at_target);
+        assert!(point.min_height_at_target <= point.activation_height);
+        assert!(point.activation_height <= point.max_height_at_target);
+    }
+
+    #[test]
+    fn target_time_in_the_past_is_rejected() {
+        let target = Time::from_unix_timestamp(1_700_000_100, 0).unwrap();
+        ActivationPoint::new(&samples(2.0, 0.0), target).unwrap_err();
+    }
+}
@@ -0,0 +1,191 @@
+use std::{
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use astria_eyre::eyre::{
+    ensure,
+    eyre,
+    Result,
+    WrapErr,
+};
+use base64::{
+    prelude::BASE64_STANDARD,
+    Engine,
+};
+use celestia_types::{
+    nmt::{
+        NamespaceProof,
+        NamespacedHash,
+    },
+    Blob,
+    ExtendedHeader,
+    Share,
+};
+use serde::{
+    de::DeserializeOwned,
+    Serialize,
+};
+
+use crate::blob_parser::{
+    colored,
+    colored_ln,
+    Format,
+};
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Path to a JSON-encoded Celestia extended header, as returned by
+    /// `celestia header get-by-height`
+    #[arg(long, value_name = "PATH")]
+    header: PathBuf,
+
+    /// Path to a JSON-encoded Celestia blob, as returned by `celestia blob get`
+    #[arg(long, value_name = "PATH")]
+    blob: PathBuf,
+
+    /// Path to the JSON-encoded inclusion proof of the blob, as returned by
+    /// `celestia blob get-proof`
+    #[arg(long, value_name = "PATH")]
+    proof: PathBuf,
+
+    /// Configure formatting of output
+    #[arg(short, long, default_value_t = Format::Display, value_enum)]
+    format: Format,
+}
+
+/// Verifies the inclusion proof of a Celestia blob against the data availability header of a
+/// Celestia extended header.
+///
+/// # Errors
+///
+/// Returns an error if any of the files cannot be read or decoded, if the header is invalid, or
+/// if the proof does not show that the blob is included in the block of the header.
+pub fn run(
+    Args {
+        header,
+        blob,
+        proof,
+        format,
+    }: Args,
+) -> Result<()> {
+    let header: ExtendedHeader = read_json(&header, "celestia extended header")?;
+    let blob: Blob = read_json(&blob, "celestia blob")?;
+    let proofs: Vec<NamespaceProof> = read_json(&proof, "blob inclusion proof")?;
+
+    header
+        .validate()
+        .wrap_err("the celestia extended header is invalid")?;
+    let shares = blob
+        .to_shares()
+        .wrap_err("failed to split the blob into shares")?;
+    let rows = verify_inclusion(&header.dah.row_roots, &shares, &proofs, &blob)?;
+
+    let verified = VerifiedInclusion {
+        celestia_height: header.height().value(),
+        namespace: BASE64_STANDARD.encode(blob.namespace.as_bytes()),
+        share_count: shares.len(),
+        rows,
+    };
+    match format {
+        Format::Display => println!("{verified}"),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(&verified).wrap_err("failed to json-encode")?
+        ),
+    }
+    Ok(())
+}
+
+fn read_json<T: DeserializeOwned>(path: &Path, what: &str) -> Result<T> {
+    let contents = fs::read_to_string(path)
+        .wrap_err_with(|| format!("failed to read file `{}`", path.display()))?;
+    serde_json::from_str(&contents)
+        .wrap_err_with(|| format!("failed to decode `{}` as a {what}", path.display()))
+}
+
+/// Verifies that `proofs` (one per row spanned by the blob, in order) prove the inclusion of
+/// `shares` under the given row roots, returning the indices of the spanned rows.
+fn verify_inclusion(
+    row_roots: &[NamespacedHash],
+    shares: &[Share],
+    proofs: &[NamespaceProof],
+    blob: &Blob,
+) -> Result<Vec<usize>> {
+    ensure!(!proofs.is_empty(), "the inclusion proof is empty");
+    let mut remaining = shares;
+    let mut next_row = 0;
+    let mut rows = Vec::with_capacity(proofs.len());
+    for (index, proof) in proofs.iter().enumerate() {
+        let share_count = usize::try_from(proof.end_idx().saturating_sub(proof.start_idx()))
+            .expect("a u32 should always convert to a usize");
+        ensure!(
+            share_count <= remaining.len(),
+            "proof {index} covers {share_count} shares, but only {} shares of the blob are left",
+            remaining.len(),
+        );
+        let (row_shares, rest) = remaining.split_at(share_count);
+        // the blob's shares are laid out in consecutive rows, so each proof must match a row
+        // after the one matched by the previous proof
+        let row = (next_row..row_roots.len())
+            .find(|&row| {
+                proof
+                    .verify_range(&row_roots[row], row_shares, blob.namespace.into_inner())
+                    .is_ok()
+            })
+            .ok_or_else(|| {
+                eyre!(
+                    "proof {index} does not prove the inclusion of shares {} to {} of the blob in \
+                     any row of the data availability header",
+                    shares.len() - remaining.len(),
+                    shares.len() - rest.len(),
+                )
+            })?;
+        rows.push(row);
+        next_row = row.saturating_add(1);
+        remaining = rest;
+    }
+    ensure!(
+        remaining.is_empty(),
+        "the inclusion proof only covers {} of the {} shares of the blob",
+        shares.len() - remaining.len(),
+        shares.len(),
+    );
+    Ok(rows)
+}
+
+#[derive(Serialize, Debug)]
+struct VerifiedInclusion {
+    celestia_height: u64,
+    /// The base64-encoded namespace of the blob.
+    namespace: String,
+    share_count: usize,
+    /// The indices of the rows of the extended data square spanned by the blob.
+    rows: Vec<usize>,
+}
+
+impl Display for VerifiedInclusion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        colored_ln(f, "celestia height", self.celestia_height)?;
+        colored_ln(f, "namespace", &self.namespace)?;
+        colored_ln(f, "share count", self.share_count)?;
+        colored_ln(
+            f,
+            "rows",
+            self.rows
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+        )?;
+        colored(f, "inclusion", "verified")
+    }
+}
@@ -781,7 +781,7 @@ impl Display for ParsedBlob {
     }
 }
 
-fn indent<'a, 'b>(f: &'a mut Formatter<'b>) -> indenter::Indented<'a, Formatter<'b>> {
+pub(crate) fn indent<'a, 'b>(f: &'a mut Formatter<'b>) -> indenter::Indented<'a, Formatter<'b>> {
     indented(f).with_str("    ")
 }
 
@@ -791,22 +791,22 @@ fn none_or_value<T: ToString>(maybe_value: &Option<T>) -> String {
         .map_or("none".to_string(), T::to_string)
 }
 
-fn colored_label(f: &mut Formatter<'_>, label: &str) -> fmt::Result {
+pub(crate) fn colored_label(f: &mut Formatter<'_>, label: &str) -> fmt::Result {
     write_blue!(f, "{label}")?;
     write!(f, ":")
 }
 
-fn colored_label_ln(f: &mut Formatter<'_>, label: &str) -> fmt::Result {
+pub(crate) fn colored_label_ln(f: &mut Formatter<'_>, label: &str) -> fmt::Result {
     write_blue!(f, "{label}")?;
     writeln!(f, ":")
 }
 
-fn colored<T: Display>(f: &mut Formatter<'_>, label: &str, item: T) -> fmt::Result {
+pub(crate) fn colored<T: Display>(f: &mut Formatter<'_>, label: &str, item: T) -> fmt::Result {
     write_blue!(f, "{label}")?;
     write!(f, ": {item}")
 }
 
-fn colored_ln<T: Display>(f: &mut Formatter<'_>, label: &str, item: T) -> fmt::Result {
+pub(crate) fn colored_ln<T: Display>(f: &mut Formatter<'_>, label: &str, item: T) -> fmt::Result {
     write_blue!(f, "{label}")?;
     writeln!(f, ": {item}")
 }
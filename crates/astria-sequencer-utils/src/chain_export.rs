@@ -0,0 +1,446 @@
+use std::{
+    fs::{
+        self,
+        File,
+    },
+    io::{
+        BufWriter,
+        Write as _,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use astria_core::{
+    generated::{
+        protocol::transaction::v1alpha1 as raw_tx,
+        sequencerblock::v1alpha1::{
+            rollup_data::Value as RawRollupDataValue,
+            sequencer_service_client::SequencerServiceClient,
+            GetSequencerBlockRequest,
+            RollupData as RawRollupData,
+        },
+    },
+    primitive::v1::Address,
+    protocol::transaction::v1alpha1::{
+        Action,
+        SignedTransaction,
+    },
+    sequencerblock::v1alpha1::{
+        block::Deposit,
+        block_commitment_count_of_data,
+        SequencerBlock,
+    },
+};
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr,
+};
+use astria_sequencer_client::{
+    tendermint,
+    Client as _,
+    HttpClient,
+};
+use prost::Message;
+
+const BLOCKS_HEADER: [&str; 8] = [
+    "height",
+    "block_hash",
+    "time",
+    "chain_id",
+    "proposer_address",
+    "rollup_count",
+    "sequenced_data_count",
+    "deposit_count",
+];
+const DEPOSITS_HEADER: [&str; 6] = [
+    "height",
+    "rollup_id",
+    "bridge_address",
+    "amount",
+    "asset_id",
+    "destination_chain_address",
+];
+const TRANSACTIONS_HEADER: [&str; 7] = [
+    "height",
+    "index",
+    "hash",
+    "signer",
+    "chain_id",
+    "nonce",
+    "action_count",
+];
+const TRANSFERS_HEADER: [&str; 8] = [
+    "height",
+    "transaction_hash",
+    "action_index",
+    "from",
+    "to",
+    "amount",
+    "asset_id",
+    "fee_asset_id",
+];
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// The URL of the sequencer's gRPC endpoint, from which blocks and deposits are read
+    #[arg(long, value_name = "URL")]
+    sequencer_grpc_url: String,
+
+    /// The URL of the sequencer's CometBFT RPC endpoint, from which transactions and transfers
+    /// are read; if not given, only blocks and deposits are exported
+    #[arg(long, value_name = "URL")]
+    sequencer_url: Option<String>,
+
+    /// The first height to export
+    #[arg(long)]
+    from_height: u64,
+
+    /// The last height to export
+    #[arg(long)]
+    to_height: u64,
+
+    /// Path to the directory into which the CSV files are written
+    #[arg(long, short, value_name = "PATH")]
+    output_dir: PathBuf,
+
+    /// The bech32m prefix used for the addresses of transaction signers
+    #[arg(long, default_value = "astria")]
+    prefix: String,
+}
+
+/// Exports the blocks, deposits, transactions and transfers of a height range of the sequencer
+/// to CSV files.
+///
+/// # Errors
+///
+/// Returns an error if the height range is invalid, if a block cannot be fetched or decoded, or if
+/// the CSV files cannot be written.
+pub fn run(args: Args) -> Result<()> {
+    ensure!(
+        args.from_height >= 1 && args.from_height <= args.to_height,
+        "`--from-height` must be at least 1 and not greater than `--to-height`"
+    );
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .wrap_err("failed to create tokio runtime")?;
+    runtime.block_on(export(args))
+}
+
+async fn export(
+    Args {
+        sequencer_grpc_url,
+        sequencer_url,
+        from_height,
+        to_height,
+        output_dir,
+        prefix,
+    }: Args,
+) -> Result<()> {
+    let mut grpc_client = SequencerServiceClient::connect(sequencer_grpc_url)
+        .await
+        .wrap_err("failed to connect to the sequencer gRPC endpoint")?;
+    let cometbft_client = sequencer_url
+        .as_deref()
+        .map(HttpClient::new)
+        .transpose()
+        .wrap_err("failed constructing http sequencer client")?;
+
+    fs::create_dir_all(&output_dir)
+        .wrap_err_with(|| format!("failed to create `{}`", output_dir.display()))?;
+    let mut blocks = CsvWriter::create(&output_dir.join("blocks.csv"), &BLOCKS_HEADER)?;
+    let mut deposits = CsvWriter::create(&output_dir.join("deposits.csv"), &DEPOSITS_HEADER)?;
+    let mut transactions_and_transfers = if cometbft_client.is_some() {
+        Some((
+            CsvWriter::create(&output_dir.join("transactions.csv"), &TRANSACTIONS_HEADER)?,
+            CsvWriter::create(&output_dir.join("transfers.csv"), &TRANSFERS_HEADER)?,
+        ))
+    } else {
+        None
+    };
+
+    for height in from_height..=to_height {
+        let raw_block = grpc_client
+            .get_sequencer_block(GetSequencerBlockRequest {
+                height,
+            })
+            .await
+            .wrap_err_with(|| format!("failed to get sequencer block at height {height}"))?
+            .into_inner();
+        let block = SequencerBlock::try_from_raw(raw_block)
+            .wrap_err_with(|| format!("invalid sequencer block at height {height}"))?;
+        let (block_row, deposit_rows) = block_rows(&block)
+            .wrap_err_with(|| format!("invalid rollup data at height {height}"))?;
+        blocks.write_row(&block_row)?;
+        for row in &deposit_rows {
+            deposits.write_row(row)?;
+        }
+
+        let (Some(client), Some((transactions, transfers))) =
+            (&cometbft_client, &mut transactions_and_transfers)
+        else {
+            continue;
+        };
+        let cometbft_block = client
+            .block(
+                tendermint::block::Height::try_from(height)
+                    .wrap_err("height is not a valid cometbft height")?,
+            )
+            .await
+            .wrap_err_with(|| format!("failed to get cometbft block at height {height}"))?
+            .block;
+        // the block data starts with the block commitments, which are not transactions
+        for (index, tx_bytes) in cometbft_block
+            .data
+            .iter()
+            .enumerate()
+            .skip(block_commitment_count_of_data(&cometbft_block.data))
+        {
+            let (transaction_row, transfer_rows) =
+                transaction_rows(height, index, tx_bytes, &prefix).wrap_err_with(|| {
+                    format!("failed to decode transaction {index} at height {height}")
+                })?;
+            transactions.write_row(&transaction_row)?;
+            for row in &transfer_rows {
+                transfers.write_row(row)?;
+            }
+        }
+    }
+
+    blocks.finish()?;
+    deposits.finish()?;
+    if let Some((transactions, transfers)) = transactions_and_transfers {
+        transactions.finish()?;
+        transfers.finish()?;
+    }
+    println!(
+        "exported heights {from_height} to {to_height} to {}",
+        output_dir.display()
+    );
+    Ok(())
+}
+
+/// Returns the row of `block` in the blocks file, and the rows of its deposits.
+fn block_rows(block: &SequencerBlock) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    let height = block.height().value().to_string();
+    let mut sequenced_data_count: usize = 0;
+    let mut deposit_rows = vec![];
+    for (rollup_id, rollup_transactions) in block.rollup_transactions() {
+        for item in rollup_transactions.transactions() {
+            let raw =
+                RawRollupData::decode(item.as_slice()).wrap_err("failed to decode rollup data")?;
+            match raw.value {
+                Some(RawRollupDataValue::SequencedData(_)) => {
+                    sequenced_data_count = sequenced_data_count.saturating_add(1);
+                }
+                Some(RawRollupDataValue::Deposit(raw_deposit)) => {
+                    let deposit =
+                        Deposit::try_from_raw(raw_deposit).wrap_err("failed to decode deposit")?;
+                    deposit_rows.push(vec![
+                        height.clone(),
+                        rollup_id.to_string(),
+                        deposit.bridge_address().to_string(),
+                        deposit.amount().to_string(),
+                        hex::encode(deposit.asset_id()),
+                        deposit.destination_chain_address().to_string(),
+                    ]);
+                }
+                None => {}
+            }
+        }
+    }
+
+    let header = block.header();
+    let block_row = vec![
+        height,
+        hex::encode(block.block_hash()),
+        header.time().to_rfc3339(),
+        header.chain_id().to_string(),
+        hex::encode(header.proposer_address()),
+        block.rollup_transactions().len().to_string(),
+        sequenced_data_count.to_string(),
+        deposit_rows.len().to_string(),
+    ];
+    Ok((block_row, deposit_rows))
+}
+
+/// Returns the row of the transaction `tx_bytes` in the transactions file, and the rows of its
+/// transfer actions.
+fn transaction_rows(
+    height: u64,
+    index: usize,
+    tx_bytes: &[u8],
+    prefix: &str,
+) -> Result<(Vec<String>, Vec<Vec<String>>)> {
+    let raw = raw_tx::SignedTransaction::decode(tx_bytes)
+        .wrap_err("failed to decode signed transaction")?;
+    let tx = SignedTransaction::try_from_raw(raw).wrap_err("invalid signed transaction")?;
+    let signer = Address::builder()
+        .array(tx.address_bytes())
+        .prefix(prefix)
+        .try_build()
+        .wrap_err("failed to construct signer address with the given prefix")?
+        .to_string();
+    let hash = hex::encode(tx.sha256_of_proto_encoding());
+
+    let transfer_rows = tx
+        .actions()
+        .iter()
+        .enumerate()
+        .filter_map(|(action_index, action)| {
+            let Action::Transfer(transfer) = action else {
+                return None;
+            };
+            Some(vec![
+                height.to_string(),
+                hash.clone(),
+                action_index.to_string(),
+                signer.clone(),
+                transfer.to.to_string(),
+                transfer.amount.to_string(),
+                hex::encode(transfer.asset_id),
+                hex::encode(transfer.fee_asset_id),
+            ])
+        })
+        .collect();
+    let transaction_row = vec![
+        height.to_string(),
+        index.to_string(),
+        hash,
+        signer,
+        tx.chain_id().to_string(),
+        tx.nonce().to_string(),
+        tx.actions().len().to_string(),
+    ];
+    Ok((transaction_row, transfer_rows))
+}
+
+struct CsvWriter {
+    path: PathBuf,
+    writer: BufWriter<File>,
+}
+
+impl CsvWriter {
+    fn create(path: &Path, header: &[&str]) -> Result<Self> {
+        let file = File::create(path)
+            .wrap_err_with(|| format!("failed to create `{}`", path.display()))?;
+        let mut csv_writer = Self {
+            path: path.to_path_buf(),
+            writer: BufWriter::new(file),
+        };
+        csv_writer.write_line(header.iter().copied())?;
+        Ok(csv_writer)
+    }
+
+    fn write_row(&mut self, row: &[String]) -> Result<()> {
+        self.write_line(row.iter().map(String::as_str))
+    }
+
+    fn write_line<'a>(&mut self, fields: impl Iterator<Item = &'a str>) -> Result<()> {
+        let line = fields.map(escape).collect::<Vec<_>>().join(",");
+        writeln!(self.writer, "{line}")
+            .wrap_err_with(|| format!("failed to write to `{}`", self.path.display()))
+    }
+
+    fn finish(mut self) -> Result<()> {
+        self.writer
+            .flush()
+            .wrap_err_with(|| format!("failed to write to `{}`", self.path.display()))
+    }
+}
+
+/// Quotes `field` if it contains a character with special meaning in CSV.
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        primitive::v1::{
+            asset,
+            RollupId,
+        },
+        protocol::{
+            test_utils::ConfigureSequencerBlock,
+            transaction::v1alpha1::{
+                action::TransferAction,
+                TransactionParams,
+                UnsignedTransaction,
+            },
+        },
+    };
+
+    use super::*;
+
+    const ALICE: &str = "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm";
+
+    #[test]
+    fn fields_are_escaped() {
+        assert_eq!("plain", escape("plain"));
+        assert_eq!("\"a,b\"", escape("a,b"));
+        assert_eq!("\"say \"\"hi\"\"\"", escape("say \"hi\""));
+    }
+
+    #[test]
+    fn blocks_are_split_into_rows() {
+        let rollup_id = RollupId::from_unhashed_bytes(b"rollup");
+        let deposit = Deposit::new(
+            ALICE.parse().unwrap(),
+            rollup_id,
+            100,
+            asset::default_native_asset().id(),
+            "0x1234".to_string(),
+        );
+        let block = ConfigureSequencerBlock {
+            height: 5,
+            signing_key: Some(SigningKey::from([1; 32])),
+            sequence_data: vec![(rollup_id, vec![1, 2, 3])],
+            deposits: vec![deposit],
+            ..ConfigureSequencerBlock::default()
+        }
+        .make();
+
+        let (block_row, deposit_rows) = block_rows(&block).unwrap();
+        assert_eq!(BLOCKS_HEADER.len(), block_row.len());
+        assert_eq!(["1", "1", "1"], block_row[5..]);
+        assert_eq!(1, deposit_rows.len());
+        assert_eq!(DEPOSITS_HEADER.len(), deposit_rows[0].len());
+        assert_eq!("100", deposit_rows[0][3]);
+    }
+
+    #[test]
+    fn transfers_are_extracted_from_transactions() {
+        let asset_id = asset::default_native_asset().id();
+        let tx = UnsignedTransaction {
+            params: TransactionParams::builder()
+                .nonce(2)
+                .chain_id("test-1")
+                .build(),
+            actions: vec![Action::Transfer(TransferAction {
+                to: ALICE.parse().unwrap(),
+                amount: 7,
+                asset_id,
+                fee_asset_id: asset_id,
+            })],
+        }
+        .into_signed(&SigningKey::from([1; 32]));
+
+        let (transaction_row, transfer_rows) =
+            transaction_rows(9, 3, &tx.into_raw().encode_to_vec(), "astria").unwrap();
+        assert_eq!(TRANSACTIONS_HEADER.len(), transaction_row.len());
+        assert_eq!(1, transfer_rows.len());
+        assert_eq!(TRANSFERS_HEADER.len(), transfer_rows[0].len());
+        assert_eq!(ALICE, transfer_rows[0][4]);
+        assert_eq!("7", transfer_rows[0][5]);
+    }
+}
@@ -4,8 +4,17 @@ use clap::{
 };
 
 use super::{
+    activation_point,
+    blob_inclusion,
     blob_parser,
+    chain_export,
+    genesis_fork,
+    genesis_merger,
     genesis_parser,
+    namespace_calculator,
+    state_diff,
+    tx_parser,
+    vector_generator,
 };
 
 /// Utilities for working with the Astria sequencer network
@@ -22,9 +31,44 @@ pub enum Command {
     #[command(arg_required_else_help = true)]
     CopyGenesisState(genesis_parser::Args),
 
+    /// Merge genesis fragments into a validated genesis state
+    #[command(arg_required_else_help = true)]
+    MergeGenesis(genesis_merger::Args),
+
+    /// Produce a genesis state from a sequencer storage database for a forked network
+    #[command(arg_required_else_help = true)]
+    ForkGenesis(genesis_fork::Args),
+
     /// Parse blob data from an arg, a file, or stdin
     #[command(arg_required_else_help = true)]
     ParseBlob(blob_parser::Args),
+
+    /// Parse a sequencer transaction from an arg, a file, or stdin
+    #[command(arg_required_else_help = true)]
+    ParseTx(tx_parser::Args),
+
+    /// Compare the state of two sequencer storage databases
+    #[command(arg_required_else_help = true)]
+    DiffState(state_diff::Args),
+
+    /// Compute the Celestia namespace of a rollup or a sequencer network
+    #[command(arg_required_else_help = true)]
+    CelestiaNamespace(namespace_calculator::Args),
+
+    /// Verify the inclusion proof of a Celestia blob against a Celestia header
+    #[command(arg_required_else_help = true)]
+    VerifyBlobInclusion(blob_inclusion::Args),
+
+    /// Generate test vectors of the encodings of core types
+    GenVectors(vector_generator::Args),
+
+    /// Calculate the block height at which the sequencer reaches a target time
+    #[command(arg_required_else_help = true)]
+    ActivationPoint(activation_point::Args),
+
+    /// Export blocks, deposits, transactions and transfers of a height range to CSV files
+    #[command(arg_required_else_help = true)]
+    Export(chain_export::Args),
 }
 
 #[must_use]
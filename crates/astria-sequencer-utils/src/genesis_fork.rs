@@ -0,0 +1,490 @@
+use std::{
+    fmt::{
+        self,
+        Display,
+        Formatter,
+        Write,
+    },
+    fs::File,
+    path::PathBuf,
+};
+
+use astria_core::primitive::v1::{
+    asset,
+    Address,
+};
+use astria_eyre::eyre::{
+    ensure,
+    eyre,
+    Result,
+    WrapErr,
+};
+use serde::Serialize;
+use serde_json::{
+    json,
+    Value,
+};
+
+use crate::{
+    blob_parser::{
+        colored,
+        colored_label_ln,
+        colored_ln,
+        indent,
+        Format,
+    },
+    genesis_merger,
+    storage::{
+        self,
+        borsh_string,
+        State,
+        Store,
+    },
+};
+
+/// The fee components of the genesis state, keyed by their storage keys.
+const FEES: [(&str, &str); 7] = [
+    ("transferfee", "transfer_base_fee"),
+    ("seqbasefee", "sequence_base_fee"),
+    ("seqmultiplier", "sequence_byte_cost_multiplier"),
+    ("initbridgeaccfee", "init_bridge_account_base_fee"),
+    ("bridgelockmultiplier", "bridge_lock_byte_cost_multiplier"),
+    ("bridgesudofee", "bridge_sudo_change_fee"),
+    ("ics20withdrawalfee", "ics20_withdrawal_base_fee"),
+];
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Path to the storage database of a stopped sequencer node (or a copy of it)
+    #[arg(long, value_name = "PATH")]
+    storage: PathBuf,
+
+    /// The block height the database is expected to be at; only the latest state of a database
+    /// can be read, so a copy taken at the desired height must be provided
+    #[arg(long)]
+    height: Option<u64>,
+
+    /// Path to write the genesis app state to, which can then be copied into a CometBFT genesis
+    /// file with `copy-genesis-state`
+    #[arg(long, short, value_name = "PATH")]
+    output: PathBuf,
+
+    /// Configure formatting of the report of the state which cannot be carried over
+    #[arg(short, long, default_value_t = Format::Display, value_enum)]
+    format: Format,
+}
+
+/// Produces a genesis app state reproducing the latest state of a sequencer storage database, for
+/// spinning up a forked network.
+///
+/// The native asset balances of all accounts (including bridge accounts), the sudo and IBC
+/// relayer addresses, the allowed fee assets and the fees are carried over. State which the
+/// genesis app state cannot express, such as bridge account registrations, balances of other
+/// assets, and the IBC clients, connections and channels, is listed in a report instead.
+///
+/// # Errors
+///
+/// Returns an error if the database cannot be opened or read, if it is not at the expected
+/// height, or if it lacks state required by the genesis app state.
+pub fn run(
+    Args {
+        storage,
+        height,
+        output,
+        format,
+    }: Args,
+) -> Result<()> {
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .wrap_err("failed to create tokio runtime")?;
+    let state = runtime.block_on(storage::load(&storage))?;
+
+    let fork = fork(&state)?;
+    genesis_merger::validate(&fork.app_state, None)
+        .wrap_err("the forked state is not a valid genesis app state")?;
+    if let Some(height) = height {
+        ensure!(
+            fork.report.height == height,
+            "the database is at height {}, not at the expected height {height}",
+            fork.report.height
+        );
+    }
+
+    let dest_file = File::create(&output)
+        .wrap_err_with(|| format!("failed to create `{}`", output.display()))?;
+    serde_json::to_writer_pretty(dest_file, &fork.app_state)
+        .wrap_err("failed to write genesis app state")?;
+
+    match format {
+        Format::Display => println!("{}", fork.report),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(&fork.report).wrap_err("failed to json-encode")?
+        ),
+    }
+    Ok(())
+}
+
+struct Fork {
+    app_state: Value,
+    report: ForkReport,
+}
+
+/// A summary of the forked state, listing what could not be carried over into the genesis app
+/// state.
+#[derive(Serialize, Debug, Default)]
+struct ForkReport {
+    height: u64,
+    chain_id: String,
+    accounts: usize,
+    bridge_accounts: Vec<BridgeAccount>,
+    omitted_balances: Vec<OmittedBalance>,
+    ibc_channel_balances: Vec<OmittedBalance>,
+    /// The fee assets whose denominations are not known to the sequencer.
+    omitted_fee_assets: Vec<String>,
+    /// The number of entries in the IBC substore (clients, connections, channels and packets).
+    ibc_substore_entries: usize,
+}
+
+#[derive(Serialize, Debug)]
+struct BridgeAccount {
+    address: String,
+    /// The hex-encoded rollup ID.
+    rollup_id: String,
+    /// The hex-encoded ID of the asset bridged to the rollup.
+    asset_id: String,
+}
+
+#[derive(Serialize, Debug)]
+struct OmittedBalance {
+    /// The address of an account, or the ID of an IBC channel.
+    holder: String,
+    /// The hex-encoded asset ID.
+    asset_id: String,
+    amount: u128,
+}
+
+fn fork(state: &State) -> Result<Fork> {
+    let text = |store, key: &str| -> Result<String> {
+        let value = state
+            .get(store, key)
+            .ok_or_else(|| eyre!("storage holds no value at key `{key}`"))?;
+        String::from_utf8(value.to_vec())
+            .wrap_err_with(|| format!("value at key `{key}` is not valid utf8"))
+    };
+    let base_prefix = text(Store::Verifiable, "prefixes/base")?;
+    let native_asset: asset::Denom = text(Store::Nonverifiable, "nativeasset")?
+        .parse()
+        .wrap_err("stored native asset is not a valid denomination")?;
+    let native_asset_id = hex::encode(native_asset.id());
+    let address = |hex_bytes: &str| -> Result<Address> {
+        let bytes: [u8; 20] = hex::decode(hex_bytes)
+            .ok()
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or_else(|| eyre!("`{hex_bytes}` is not a hex-encoded address"))?;
+        Address::builder()
+            .array(bytes)
+            .prefix(&base_prefix)
+            .try_build()
+            .wrap_err("failed to construct address")
+    };
+    let stored_address = |key: &str| -> Result<Address> {
+        let value = state
+            .get(Store::Verifiable, key)
+            .ok_or_else(|| eyre!("storage holds no value at key `{key}`"))?;
+        address(&hex::encode(value))
+    };
+
+    let mut report = ForkReport {
+        height: state
+            .get(Store::Verifiable, "block_height")
+            .and_then(|value| value.try_into().ok())
+            .map(u64::from_be_bytes)
+            .ok_or_else(|| eyre!("storage holds no valid block height"))?,
+        chain_id: text(Store::Verifiable, "chain_id")?,
+        ..ForkReport::default()
+    };
+
+    let mut accounts = Vec::new();
+    for (key, value) in state.prefixed(Store::Verifiable, "accounts/") {
+        let ["accounts", holder, "balance", asset_id] = key.split('/').collect::<Vec<_>>()[..]
+        else {
+            continue;
+        };
+        let balance = amount(key, value)?;
+        if asset_id == native_asset_id {
+            accounts.push(json!({
+                "address": { "bech32m": address(holder)?.to_string() },
+                "balance": json_amount(key, balance)?,
+            }));
+        } else {
+            report.omitted_balances.push(OmittedBalance {
+                holder: address(holder)?.to_string(),
+                asset_id: asset_id.to_string(),
+                amount: balance,
+            });
+        }
+    }
+    report.accounts = accounts.len();
+
+    for (key, value) in state.prefixed(Store::Verifiable, "bridgeacc/") {
+        let ["bridgeacc", holder, "rollupid"] = key.split('/').collect::<Vec<_>>()[..] else {
+            continue;
+        };
+        let asset_id = state
+            .get(Store::Verifiable, &format!("bridgeacc/{holder}/assetid"))
+            .ok_or_else(|| eyre!("bridge account `{holder}` has no asset ID"))?;
+        report.bridge_accounts.push(BridgeAccount {
+            address: address(holder)?.to_string(),
+            rollup_id: hex::encode(value),
+            asset_id: hex::encode(asset_id),
+        });
+    }
+
+    for (key, value) in state.prefixed(Store::Verifiable, "ibc-data/") {
+        if let ["ibc-data", channel, "balance", asset_id] = key.split('/').collect::<Vec<_>>()[..] {
+            report.ibc_channel_balances.push(OmittedBalance {
+                holder: channel.to_string(),
+                asset_id: asset_id.to_string(),
+                amount: amount(key, value)?,
+            });
+        }
+    }
+    report.ibc_substore_entries = state.prefixed(Store::Verifiable, "ibc_data/").count();
+
+    let ibc_relayer_addresses = state
+        .prefixed(Store::Verifiable, "ibc-relayer/")
+        .map(|(key, _)| {
+            let holder = key.trim_start_matches("ibc-relayer/");
+            Ok(json!({ "bech32m": address(holder)?.to_string() }))
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut allowed_fee_assets = Vec::new();
+    for (key, _) in state.prefixed(Store::Nonverifiable, "fee_asset/") {
+        let asset_id = key.trim_start_matches("fee_asset/");
+        if asset_id == native_asset_id {
+            allowed_fee_assets.push(native_asset.to_string());
+        } else if let Some(denom) = state
+            .get(Store::Verifiable, &format!("asset/{asset_id}"))
+            .and_then(borsh_string)
+        {
+            allowed_fee_assets.push(denom);
+        } else {
+            report.omitted_fee_assets.push(asset_id.to_string());
+        }
+    }
+
+    let mut fees = serde_json::Map::new();
+    for (key, field) in FEES {
+        let value = state
+            .get(Store::Verifiable, key)
+            .ok_or_else(|| eyre!("storage holds no value at key `{key}`"))?;
+        fees.insert(field.to_string(), json_amount(key, amount(key, value)?)?);
+    }
+
+    let app_state = json!({
+        "address_prefixes": { "base": base_prefix },
+        "accounts": accounts,
+        "authority_sudo_address": { "bech32m": stored_address("sudo")?.to_string() },
+        "ibc_sudo_address": { "bech32m": stored_address("ibcsudo")?.to_string() },
+        "ibc_relayer_addresses": ibc_relayer_addresses,
+        "native_asset_base_denomination": native_asset.to_string(),
+        // the ibc parameters are not readable from storage, so they are all enabled
+        "ibc_params": {
+            "ibc_enabled": true,
+            "inbound_ics20_transfers_enabled": true,
+            "outbound_ics20_transfers_enabled": true,
+        },
+        "allowed_fee_assets": allowed_fee_assets,
+        "fees": fees,
+    });
+    Ok(Fork {
+        app_state,
+        report,
+    })
+}
+
+/// Converts an amount to a JSON number, which without arbitrary precision is limited to `u64`.
+fn json_amount(key: &str, amount: u128) -> Result<Value> {
+    u64::try_from(amount)
+        .map(Value::from)
+        .map_err(|_| eyre!("amount {amount} at key `{key}` is too large to be written as json"))
+}
+
+/// Decodes an amount stored as a borsh-encoded `u128`.
+fn amount(key: &str, value: &[u8]) -> Result<u128> {
+    let bytes = value
+        .try_into()
+        .map_err(|_| eyre!("value at key `{key}` is not a valid amount"))?;
+    Ok(u128::from_le_bytes(bytes))
+}
+
+impl Display for ForkReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        colored_ln(f, "height", self.height)?;
+        colored_ln(f, "chain id", &self.chain_id)?;
+        colored_ln(f, "accounts carried over", self.accounts)?;
+        if !self.bridge_accounts.is_empty() {
+            colored_label_ln(f, "bridge accounts (not registered in the fork)")?;
+            for account in &self.bridge_accounts {
+                writeln!(
+                    indent(f),
+                    "{}: rollup {}, asset {}",
+                    account.address,
+                    account.rollup_id,
+                    account.asset_id
+                )?;
+            }
+        }
+        for (label, balances) in [
+            ("balances of other assets (omitted)", &self.omitted_balances),
+            ("ibc channel balances (omitted)", &self.ibc_channel_balances),
+        ] {
+            if balances.is_empty() {
+                continue;
+            }
+            colored_label_ln(f, label)?;
+            for balance in balances {
+                writeln!(
+                    indent(f),
+                    "{}: {} of {}",
+                    balance.holder,
+                    balance.amount,
+                    balance.asset_id
+                )?;
+            }
+        }
+        if !self.omitted_fee_assets.is_empty() {
+            colored_ln(
+                f,
+                "unknown fee assets (omitted)",
+                self.omitted_fee_assets.join(", "),
+            )?;
+        }
+        colored(
+            f,
+            "ibc substore entries (omitted)",
+            self.ibc_substore_entries,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    const ALICE: [u8; 20] = [1; 20];
+    const BOB: [u8; 20] = [2; 20];
+
+    fn state() -> State {
+        let native_asset_id = hex::encode(asset::default_native_asset().id());
+        let other_asset_id = hex::encode([9; 32]);
+        let mut entries = BTreeMap::new();
+        let mut put = |store, key: String, value: Vec<u8>| {
+            entries.insert((store, key), value);
+        };
+        put(
+            Store::Verifiable,
+            "prefixes/base".into(),
+            b"astria".to_vec(),
+        );
+        put(Store::Nonverifiable, "nativeasset".into(), b"nria".to_vec());
+        put(
+            Store::Verifiable,
+            "block_height".into(),
+            42_u64.to_be_bytes().to_vec(),
+        );
+        put(Store::Verifiable, "chain_id".into(), b"astria-1".to_vec());
+        for (holder, amount) in [(ALICE, 10_u128), (BOB, 20)] {
+            put(
+                Store::Verifiable,
+                format!("accounts/{}/balance/{native_asset_id}", hex::encode(holder)),
+                amount.to_le_bytes().to_vec(),
+            );
+            put(
+                Store::Verifiable,
+                format!("accounts/{}/nonce", hex::encode(holder)),
+                3_u32.to_le_bytes().to_vec(),
+            );
+        }
+        put(
+            Store::Verifiable,
+            format!("accounts/{}/balance/{other_asset_id}", hex::encode(BOB)),
+            5_u128.to_le_bytes().to_vec(),
+        );
+        put(
+            Store::Verifiable,
+            format!("bridgeacc/{}/rollupid", hex::encode(BOB)),
+            vec![7; 32],
+        );
+        put(
+            Store::Verifiable,
+            format!("bridgeacc/{}/assetid", hex::encode(BOB)),
+            asset::default_native_asset().id().get().to_vec(),
+        );
+        put(
+            Store::Verifiable,
+            format!("ibc-data/channel-0/balance/{other_asset_id}"),
+            5_u128.to_le_bytes().to_vec(),
+        );
+        put(Store::Verifiable, "sudo".into(), ALICE.to_vec());
+        put(Store::Verifiable, "ibcsudo".into(), ALICE.to_vec());
+        put(
+            Store::Verifiable,
+            format!("ibc-relayer/{}", hex::encode(BOB)),
+            vec![],
+        );
+        put(
+            Store::Nonverifiable,
+            format!("fee_asset/{native_asset_id}"),
+            vec![],
+        );
+        put(
+            Store::Nonverifiable,
+            format!("fee_asset/{other_asset_id}"),
+            vec![],
+        );
+        for (key, _) in FEES {
+            put(Store::Verifiable, key.into(), 1_u128.to_le_bytes().to_vec());
+        }
+        State {
+            version: 42,
+            entries,
+        }
+    }
+
+    #[test]
+    fn state_is_forked_into_genesis() {
+        let Fork {
+            app_state,
+            report,
+        } = fork(&state()).unwrap();
+        assert_eq!(2, app_state["accounts"].as_array().unwrap().len());
+        assert_eq!(json!(["nria"]), app_state["allowed_fee_assets"]);
+        assert_eq!(
+            1,
+            app_state["ibc_relayer_addresses"].as_array().unwrap().len()
+        );
+        assert_eq!(json!(1), app_state["fees"]["ics20_withdrawal_base_fee"]);
+        genesis_merger::validate(&app_state, None).unwrap();
+
+        assert_eq!(42, report.height);
+        assert_eq!(1, report.bridge_accounts.len());
+        assert_eq!(1, report.omitted_balances.len());
+        assert_eq!(1, report.ibc_channel_balances.len());
+        assert_eq!(1, report.omitted_fee_assets.len());
+    }
+
+    #[test]
+    fn missing_state_is_an_error() {
+        let mut state = state();
+        state
+            .entries
+            .remove(&(Store::Verifiable, "ibcsudo".to_string()));
+        fork(&state).unwrap_err();
+    }
+}
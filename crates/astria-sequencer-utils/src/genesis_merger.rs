@@ -0,0 +1,559 @@
+use std::{
+    collections::{
+        BTreeMap,
+        HashSet,
+    },
+    fs::File,
+    num::{
+        NonZeroU32,
+        NonZeroU64,
+    },
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use astria_core::{
+    primitive::v1::{
+        asset,
+        Address,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1alpha1::Action,
+};
+use astria_eyre::eyre::{
+    bail,
+    ensure,
+    eyre,
+    Result,
+    WrapErr,
+};
+use serde::Deserialize;
+use serde_json::{
+    Map,
+    Value,
+};
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+
+/// The fields of a fragment which are concatenated across all fragments rather than being
+/// required to be set by exactly one of them.
+const LIST_FIELDS: [&str; 4] = [
+    "accounts",
+    "ibc_relayer_addresses",
+    "allowed_fee_assets",
+    VALIDATORS,
+];
+
+/// The field of a fragment holding the CometBFT genesis validators rather than app state.
+const VALIDATORS: &str = "validators";
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Path to a genesis fragment; can be given multiple times
+    #[arg(long = "fragment", value_name = "PATH", required = true)]
+    fragments: Vec<PathBuf>,
+
+    /// Path to a file of sha256 checksums of the fragments, in the format written by
+    /// `sha256sum`; if given, every fragment must be listed and match its checksum
+    #[arg(long, value_name = "PATH")]
+    checksums: Option<PathBuf>,
+
+    /// Path to a CometBFT genesis file to write the merged app state and validators into;
+    /// if not given, the merged state is written to stdout
+    #[arg(long, short, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+/// Merges genesis fragments into a single validated genesis state.
+///
+/// Each fragment is a JSON object holding a subset of the fields of the sequencer's genesis app
+/// state, and optionally the CometBFT genesis `validators`. The list fields (`accounts`,
+/// `ibc_relayer_addresses`, `allowed_fee_assets` and `validators`) are concatenated, while every
+/// other field must be set by only one fragment, or by several with identical values.
+///
+/// # Errors
+///
+/// An `eyre::Result` is returned if a fragment cannot be read or does not match its checksum,
+/// if the fragments conflict, if the merged state is not a valid genesis state, or if the
+/// output cannot be written.
+pub fn run(
+    Args {
+        fragments,
+        checksums,
+        output,
+    }: Args,
+) -> Result<()> {
+    let checksums = checksums.as_deref().map(read_checksums).transpose()?;
+    let mut loaded = vec![];
+    for path in &fragments {
+        let contents =
+            std::fs::read(path).wrap_err_with(|| format!("failed to read `{}`", path.display()))?;
+        if let Some(checksums) = &checksums {
+            verify_checksum(checksums, path, &contents)?;
+        }
+        let fragment: Value = serde_json::from_slice(&contents).wrap_err_with(|| {
+            format!("failed deserializing genesis fragment `{}`", path.display())
+        })?;
+        loaded.push((path.display().to_string(), fragment));
+    }
+
+    let mut merged = merge(loaded)?;
+    let validators = merged.remove(VALIDATORS);
+    let app_state = Value::Object(merged);
+    validate(&app_state, validators.as_ref())?;
+
+    let Some(output) = output else {
+        let mut genesis = Map::new();
+        genesis.insert("app_state".to_string(), app_state);
+        if let Some(validators) = validators {
+            genesis.insert(VALIDATORS.to_string(), validators);
+        }
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&genesis)
+                .wrap_err("failed to serialize merged genesis state")?
+        );
+        return Ok(());
+    };
+
+    let genesis_file = File::open(&output).wrap_err("failed to open cometbft genesis file")?;
+    let mut genesis: Value = serde_json::from_reader(genesis_file)
+        .wrap_err("failed deserializing cometbft genesis state from file")?;
+    let Value::Object(genesis_object) = &mut genesis else {
+        bail!(
+            "cometbft genesis file `{}` is not a JSON object",
+            output.display()
+        );
+    };
+    genesis_object.insert("app_state".to_string(), app_state);
+    if let Some(validators) = validators {
+        genesis_object.insert(VALIDATORS.to_string(), validators);
+    }
+    let dest_file = File::create(&output).wrap_err("failed to open destination genesis file")?;
+    serde_json::to_writer_pretty(dest_file, &genesis)
+        .wrap_err("failed to write to output json file")?;
+    println!(
+        "merged {} fragments into {}",
+        fragments.len(),
+        output.display()
+    );
+    Ok(())
+}
+
+/// Reads a file in the format written by `sha256sum`, mapping each file name to its checksum.
+fn read_checksums(path: &Path) -> Result<BTreeMap<String, String>> {
+    let contents = std::fs::read_to_string(path)
+        .wrap_err_with(|| format!("failed to read checksums file `{}`", path.display()))?;
+    contents
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| {
+            let (checksum, file) = line
+                .split_once(char::is_whitespace)
+                .ok_or_else(|| eyre!("invalid line in checksums file: `{line}`"))?;
+            // `sha256sum` marks files read in binary mode with a leading `*`
+            let file = file.trim_start().trim_start_matches('*');
+            Ok((file.to_string(), checksum.to_lowercase()))
+        })
+        .collect()
+}
+
+fn verify_checksum(
+    checksums: &BTreeMap<String, String>,
+    path: &Path,
+    contents: &[u8],
+) -> Result<()> {
+    let display = path.display().to_string();
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned());
+    let expected = checksums
+        .get(&display)
+        .or_else(|| file_name.and_then(|name| checksums.get(&name)))
+        .ok_or_else(|| eyre!("fragment `{display}` is not listed in the checksums file"))?;
+    let actual = hex::encode(Sha256::digest(contents));
+    ensure!(
+        &actual == expected,
+        "checksum mismatch for fragment `{display}`: expected {expected}, got {actual}"
+    );
+    Ok(())
+}
+
+/// Merges the fragments, given with the names used to refer to them in errors.
+fn merge(fragments: Vec<(String, Value)>) -> Result<Map<String, Value>> {
+    let mut merged = Map::new();
+    let mut set_by: BTreeMap<String, String> = BTreeMap::new();
+    for (name, fragment) in fragments {
+        let Value::Object(fields) = fragment else {
+            bail!("genesis fragment `{name}` is not a JSON object");
+        };
+        for (field, value) in fields {
+            if LIST_FIELDS.contains(&field.as_str()) {
+                let Value::Array(items) = value else {
+                    bail!("field `{field}` of genesis fragment `{name}` is not a list");
+                };
+                merged
+                    .entry(field)
+                    .or_insert_with(|| Value::Array(vec![]))
+                    .as_array_mut()
+                    .expect("list fields are only ever inserted as arrays")
+                    .extend(items);
+                continue;
+            }
+            if let Some(existing) = merged.get(&field) {
+                ensure!(
+                    *existing == value,
+                    "field `{field}` is set to different values by genesis fragments `{}` and \
+                     `{name}`",
+                    set_by[&field],
+                );
+                continue;
+            }
+            set_by.insert(field.clone(), name.clone());
+            merged.insert(field, value);
+        }
+    }
+    Ok(merged)
+}
+
+/// A mirror of the sequencer's genesis app state, used to validate the merged fragments.
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GenesisState {
+    address_prefixes: AddressPrefixes,
+    accounts: Vec<Account>,
+    authority_sudo_address: Address,
+    ibc_sudo_address: Address,
+    ibc_relayer_addresses: Vec<Address>,
+    // allow: the fields below are only deserialized to check that they are well-formed
+    #[allow(dead_code)]
+    native_asset_base_denomination: String,
+    #[allow(dead_code)]
+    ibc_params: IbcParameters,
+    allowed_fee_assets: Vec<asset::Denom>,
+    fees: Fees,
+    #[allow(dead_code)]
+    ica_host: Option<IcaHostParams>,
+    #[allow(dead_code)]
+    bridge_recovery_delay: Option<NonZeroU64>,
+    #[allow(dead_code)]
+    deposit_limits: Option<DepositLimits>,
+    #[serde(default)]
+    action_activation_heights: BTreeMap<String, NonZeroU64>,
+    #[allow(dead_code)]
+    fee_summary_activation_height: Option<NonZeroU64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AddressPrefixes {
+    base: String,
+    #[serde(default)]
+    allowed: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Account {
+    address: Address,
+    #[allow(dead_code)]
+    balance: u128,
+}
+
+// allow: the parameters are only deserialized to check that they are well-formed
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IbcParameters {
+    ibc_enabled: bool,
+    inbound_ics20_transfers_enabled: bool,
+    outbound_ics20_transfers_enabled: bool,
+}
+
+// allow: the fees are only deserialized to check that they are well-formed
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Fees {
+    transfer_base_fee: u128,
+    sequence_base_fee: u128,
+    sequence_byte_cost_multiplier: u128,
+    init_bridge_account_base_fee: u128,
+    bridge_lock_byte_cost_multiplier: u128,
+    bridge_sudo_change_fee: u128,
+    ics20_withdrawal_base_fee: u128,
+    #[serde(default)]
+    ics20_forward_fee_basis_points: u16,
+}
+
+// allow: the parameters are only deserialized to check that they are well-formed
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct IcaHostParams {
+    enabled: bool,
+    #[serde(default)]
+    allowed_messages: Vec<String>,
+}
+
+// allow: the limits are only deserialized to check that they are well-formed
+#[allow(dead_code)]
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct DepositLimits {
+    max_deposits_per_rollup: NonZeroU32,
+    max_deposit_bytes_per_rollup: NonZeroU64,
+}
+
+/// A validator as listed in the CometBFT genesis file.
+#[derive(Debug, Deserialize)]
+struct Validator {
+    pub_key: PubKey,
+    power: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PubKey {
+    value: String,
+}
+
+pub(crate) fn validate(app_state: &Value, validators: Option<&Value>) -> Result<()> {
+    let genesis = GenesisState::deserialize(app_state)
+        .wrap_err("merged fragments are not a valid genesis app state")?;
+
+    let base = &genesis.address_prefixes.base;
+    let check_prefix = |address: &Address, field: &str| -> Result<()> {
+        ensure!(
+            address.prefix() == base,
+            "address `{address}` at `{field}` does not have base prefix `{base}`"
+        );
+        Ok(())
+    };
+    let mut accounts = HashSet::new();
+    for (i, account) in genesis.accounts.iter().enumerate() {
+        check_prefix(&account.address, &format!(".accounts[{i}].address"))?;
+        ensure!(
+            accounts.insert(account.address),
+            "duplicate account `{}` at `.accounts[{i}]`",
+            account.address
+        );
+    }
+    check_prefix(&genesis.authority_sudo_address, ".authority_sudo_address")?;
+    check_prefix(&genesis.ibc_sudo_address, ".ibc_sudo_address")?;
+    let mut relayers = HashSet::new();
+    for (i, address) in genesis.ibc_relayer_addresses.iter().enumerate() {
+        check_prefix(address, &format!(".ibc_relayer_addresses[{i}]"))?;
+        ensure!(
+            relayers.insert(*address),
+            "duplicate ibc relayer address `{address}` at `.ibc_relayer_addresses[{i}]`"
+        );
+    }
+    let mut fee_assets = HashSet::new();
+    for (i, denom) in genesis.allowed_fee_assets.iter().enumerate() {
+        ensure!(
+            fee_assets.insert(denom.to_string()),
+            "duplicate fee asset `{denom}` at `.allowed_fee_assets[{i}]`"
+        );
+    }
+    for (i, prefix) in genesis.address_prefixes.allowed.iter().enumerate() {
+        let field = format!(".address_prefixes.allowed[{i}]");
+        ensure!(
+            prefix != base,
+            "allowed address prefix `{prefix}` at `{field}` is the same as the base prefix"
+        );
+        let is_valid = !prefix.chars().any(|c| c.is_ascii_uppercase())
+            && Address::builder()
+                .array([0; ADDRESS_LEN])
+                .prefix(prefix.as_str())
+                .try_build()
+                .is_ok();
+        ensure!(
+            is_valid,
+            "allowed address prefix `{prefix}` at `{field}` is not a valid bech32m prefix"
+        );
+    }
+    let basis_points = genesis.fees.ics20_forward_fee_basis_points;
+    ensure!(
+        basis_points <= 10_000,
+        "ics20 forward fee of {basis_points} basis points at \
+         `.fees.ics20_forward_fee_basis_points` exceeds 10000"
+    );
+    for action in genesis.action_activation_heights.keys() {
+        ensure!(
+            Action::NAMES.contains(&action.as_str()),
+            "action `{action}` at `.action_activation_heights.{action}` is not a known action"
+        );
+    }
+
+    let Some(validators) = validators else {
+        return Ok(());
+    };
+    let validators = Vec::<Validator>::deserialize(validators)
+        .wrap_err("merged fragments do not hold valid cometbft genesis validators")?;
+    let mut keys = HashSet::new();
+    for (i, validator) in validators.iter().enumerate() {
+        ensure!(
+            keys.insert(validator.pub_key.value.as_str()),
+            "duplicate validator `{}` at `.validators[{i}]`",
+            validator.pub_key.value
+        );
+        let power: u64 = validator
+            .power
+            .parse()
+            .wrap_err_with(|| format!("invalid power of validator at `.validators[{i}]`"))?;
+        ensure!(power > 0, "validator at `.validators[{i}]` has zero power");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const ALICE: &str = "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm";
+    const BOB: &str = "astria1xnlvg0rle2u6auane79t4p27g8hxnj36ja960z";
+
+    fn fragments() -> Vec<(String, Value)> {
+        vec![
+            (
+                "balances".to_string(),
+                json!({
+                    "accounts": [
+                        { "address": { "bech32m": ALICE }, "balance": 10 },
+                    ],
+                }),
+            ),
+            (
+                "validators".to_string(),
+                json!({
+                    "validators": [{
+                        "address": "",
+                        "pub_key": {
+                            "type": "tendermint/PubKeyEd25519",
+                            "value": "ykqoLyAOaWJmuwd+ijPLJe9XzobDDcsbOiqD7vFfjCs="
+                        },
+                        "power": "10",
+                        "name": "node0"
+                    }],
+                }),
+            ),
+            (
+                "params".to_string(),
+                json!({
+                    "address_prefixes": { "base": "astria" },
+                    "accounts": [
+                        { "address": { "bech32m": BOB }, "balance": 20 },
+                    ],
+                    "authority_sudo_address": { "bech32m": ALICE },
+                    "ibc_sudo_address": { "bech32m": ALICE },
+                    "ibc_relayer_addresses": [{ "bech32m": BOB }],
+                    "native_asset_base_denomination": "nria",
+                    "ibc_params": {
+                        "ibc_enabled": true,
+                        "inbound_ics20_transfers_enabled": true,
+                        "outbound_ics20_transfers_enabled": true
+                    },
+                    "allowed_fee_assets": ["nria"],
+                    "fees": {
+                        "transfer_base_fee": 12,
+                        "sequence_base_fee": 32,
+                        "sequence_byte_cost_multiplier": 1,
+                        "init_bridge_account_base_fee": 48,
+                        "bridge_lock_byte_cost_multiplier": 1,
+                        "bridge_sudo_change_fee": 24,
+                        "ics20_withdrawal_base_fee": 24
+                    }
+                }),
+            ),
+        ]
+    }
+
+    fn merge_and_validate(fragments: Vec<(String, Value)>) -> Result<Map<String, Value>> {
+        let mut merged = merge(fragments)?;
+        let validators = merged.remove(VALIDATORS);
+        let app_state = Value::Object(merged.clone());
+        validate(&app_state, validators.as_ref())?;
+        Ok(merged)
+    }
+
+    #[test]
+    fn fragments_are_merged() {
+        let merged = merge_and_validate(fragments()).unwrap();
+        assert_eq!(2, merged["accounts"].as_array().unwrap().len());
+    }
+
+    #[test]
+    fn duplicate_accounts_are_caught() {
+        let mut fragments = fragments();
+        fragments[1].1["accounts"] = json!([{ "address": { "bech32m": BOB }, "balance": 5 }]);
+        let error = merge_and_validate(fragments).unwrap_err();
+        assert!(format!("{error:#}").contains("duplicate account"));
+    }
+
+    #[test]
+    fn newer_genesis_fields_are_accepted() {
+        let mut fragments = fragments();
+        fragments.push((
+            "upgrades".to_string(),
+            json!({
+                "ica_host": {
+                    "enabled": true,
+                    "allowed_messages": ["/astria.protocol.transactions.v1alpha1.TransferAction"]
+                },
+                "bridge_recovery_delay": 10,
+                "deposit_limits": {
+                    "max_deposits_per_rollup": 100,
+                    "max_deposit_bytes_per_rollup": 65536
+                },
+                "action_activation_heights": { "bridge_recovery": 100 },
+                "fee_summary_activation_height": 100
+            }),
+        ));
+        fragments[2].1["address_prefixes"]["allowed"] = json!(["other"]);
+        fragments[2].1["fees"]["ics20_forward_fee_basis_points"] = json!(10);
+        let merged = merge_and_validate(fragments).unwrap();
+        assert_eq!(json!(["other"]), merged["address_prefixes"]["allowed"]);
+    }
+
+    #[test]
+    fn invalid_newer_genesis_fields_are_caught() {
+        let mut same_as_base = fragments();
+        same_as_base[2].1["address_prefixes"]["allowed"] = json!(["astria"]);
+        let error = merge_and_validate(same_as_base).unwrap_err();
+        assert!(format!("{error:#}").contains("same as the base prefix"));
+
+        let mut excessive_fee = fragments();
+        excessive_fee[2].1["fees"]["ics20_forward_fee_basis_points"] = json!(10_001);
+        let error = merge_and_validate(excessive_fee).unwrap_err();
+        assert!(format!("{error:#}").contains("exceeds 10000"));
+
+        let mut unknown_action = fragments();
+        unknown_action[0].1["action_activation_heights"] = json!({ "unknown": 100 });
+        let error = merge_and_validate(unknown_action).unwrap_err();
+        assert!(format!("{error:#}").contains("is not a known action"));
+    }
+
+    #[test]
+    fn conflicting_fields_are_caught() {
+        let mut fragments = fragments();
+        fragments[0].1["native_asset_base_denomination"] = json!("other");
+        let error = merge(fragments).unwrap_err();
+        assert!(error.to_string().contains("`balances` and `params`"));
+    }
+
+    #[test]
+    fn checksum_mismatches_are_caught() {
+        let contents = b"{}";
+        let checksums = BTreeMap::from([(
+            "fragment.json".to_string(),
+            hex::encode(Sha256::digest(contents)),
+        )]);
+        verify_checksum(&checksums, Path::new("dir/fragment.json"), contents).unwrap();
+        verify_checksum(&checksums, Path::new("dir/fragment.json"), b"[]").unwrap_err();
+        verify_checksum(&checksums, Path::new("other.json"), contents).unwrap_err();
+    }
+}
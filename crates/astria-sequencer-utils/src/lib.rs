@@ -1,3 +1,13 @@
+pub mod activation_point;
+pub mod blob_inclusion;
 pub mod blob_parser;
+pub mod chain_export;
 pub mod cli;
+pub mod genesis_fork;
+pub mod genesis_merger;
 pub mod genesis_parser;
+pub mod namespace_calculator;
+pub mod state_diff;
+mod storage;
+pub mod tx_parser;
+pub mod vector_generator;
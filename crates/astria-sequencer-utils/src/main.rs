@@ -1,11 +1,20 @@
 use astria_eyre::eyre::Result;
 use astria_sequencer_utils::{
+    activation_point,
+    blob_inclusion,
     blob_parser,
+    chain_export,
     cli::{
         self,
         Command,
     },
+    genesis_fork,
+    genesis_merger,
     genesis_parser,
+    namespace_calculator,
+    state_diff,
+    tx_parser,
+    vector_generator,
 };
 
 fn main() -> Result<()> {
@@ -13,6 +22,15 @@ fn main() -> Result<()> {
         .expect("the astria eyre install hook must be called before eyre reports are constructed");
     match cli::get() {
         Command::CopyGenesisState(args) => genesis_parser::run(args),
+        Command::MergeGenesis(args) => genesis_merger::run(args),
+        Command::ForkGenesis(args) => genesis_fork::run(args),
         Command::ParseBlob(args) => blob_parser::run(args),
+        Command::ParseTx(args) => tx_parser::run(args),
+        Command::DiffState(args) => state_diff::run(args),
+        Command::CelestiaNamespace(args) => namespace_calculator::run(args),
+        Command::VerifyBlobInclusion(args) => blob_inclusion::run(args),
+        Command::GenVectors(args) => vector_generator::run(args),
+        Command::ActivationPoint(args) => activation_point::run(args),
+        Command::Export(args) => chain_export::run(args),
     }
 }
@@ -0,0 +1,157 @@
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use astria_core::{
+    celestia::Namespace,
+    primitive::v1::RollupId,
+};
+use astria_eyre::eyre::{
+    Result,
+    WrapErr,
+};
+use base64::{
+    prelude::BASE64_STANDARD,
+    Engine,
+};
+use serde::Serialize;
+
+use crate::blob_parser::{
+    colored,
+    colored_ln,
+    Format,
+};
+
+#[derive(clap::Args, Debug)]
+#[command(group(clap::ArgGroup::new("source").required(true)))]
+pub struct Args {
+    /// The name of a rollup, from which its rollup ID is derived
+    #[arg(long, group = "source")]
+    rollup_name: Option<String>,
+
+    /// A hex- or base64-encoded rollup ID
+    #[arg(long, group = "source")]
+    rollup_id: Option<String>,
+
+    /// The chain ID of a sequencer network, whose block metadata is posted under the computed
+    /// namespace
+    #[arg(long, group = "source")]
+    chain_id: Option<String>,
+
+    /// Configure formatting of output
+    #[arg(short, long, default_value_t = Format::Display, value_enum)]
+    format: Format,
+}
+
+/// Computes the Celestia namespace under which the relayer posts the data of a rollup, or the
+/// block metadata of a sequencer network.
+///
+/// # Errors
+///
+/// Returns an error if the rollup ID cannot be parsed.
+pub fn run(
+    Args {
+        rollup_name,
+        rollup_id,
+        chain_id,
+        format,
+    }: Args,
+) -> Result<()> {
+    let computed = if let Some(chain_id) = chain_id {
+        ComputedNamespace::new(
+            None,
+            Some(chain_id.clone()),
+            Namespace::from_chain_id(&chain_id),
+        )
+    } else {
+        let rollup_id = match (rollup_name, rollup_id) {
+            (Some(rollup_name), _) => RollupId::from_unhashed_bytes(rollup_name.as_bytes()),
+            (None, Some(rollup_id)) => parse_rollup_id(&rollup_id)?,
+            (None, None) => unreachable!("clap requires one of the source args"),
+        };
+        ComputedNamespace::new(Some(rollup_id), None, Namespace::from_rollup_id(rollup_id))
+    };
+    match format {
+        Format::Display => println!("{computed}"),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(&computed).wrap_err("failed to json-encode")?
+        ),
+    }
+    Ok(())
+}
+
+fn parse_rollup_id(input: &str) -> Result<RollupId> {
+    let bytes = hex::decode(input.strip_prefix("0x").unwrap_or(input))
+        .or_else(|_| BASE64_STANDARD.decode(input))
+        .wrap_err("rollup ID is neither valid hex nor valid base64")?;
+    RollupId::try_from_slice(&bytes).wrap_err("invalid rollup ID")
+}
+
+#[derive(Serialize, Debug)]
+struct ComputedNamespace {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rollup_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    chain_id: Option<String>,
+    /// The hex-encoded namespace, including its version byte.
+    namespace_hex: String,
+    /// The base64-encoded namespace, including its version byte, as accepted by Celestia nodes.
+    namespace_base64: String,
+}
+
+impl ComputedNamespace {
+    fn new(rollup_id: Option<RollupId>, chain_id: Option<String>, namespace: Namespace) -> Self {
+        Self {
+            rollup_id: rollup_id.map(|rollup_id| rollup_id.to_string()),
+            chain_id,
+            namespace_hex: hex::encode(namespace.as_bytes()),
+            namespace_base64: namespace.to_string(),
+        }
+    }
+}
+
+impl Display for ComputedNamespace {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(rollup_id) = &self.rollup_id {
+            colored_ln(f, "rollup id", rollup_id)?;
+        }
+        if let Some(chain_id) = &self.chain_id {
+            colored_ln(f, "chain id", chain_id)?;
+        }
+        colored_ln(f, "namespace (hex)", &self.namespace_hex)?;
+        colored(f, "namespace (base64)", &self.namespace_base64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rollup_ids_are_parsed_from_hex_and_base64() {
+        let rollup_id = RollupId::from_unhashed_bytes(b"steezechain");
+        assert_eq!(
+            rollup_id,
+            parse_rollup_id(&hex::encode(rollup_id.get())).unwrap()
+        );
+        assert_eq!(rollup_id, parse_rollup_id(&rollup_id.to_string()).unwrap());
+        parse_rollup_id("not a rollup id").unwrap_err();
+    }
+
+    #[test]
+    fn namespace_is_first_ten_bytes_of_rollup_id() {
+        let rollup_id = RollupId::from_unhashed_bytes(b"steezechain");
+        let computed =
+            ComputedNamespace::new(Some(rollup_id), None, Namespace::from_rollup_id(rollup_id));
+        // a version 0 namespace is the version byte and 18 zero bytes, followed by the 10 ID bytes
+        let expected = format!(
+            "00{}{}",
+            "00".repeat(18),
+            hex::encode(&rollup_id.get()[..10])
+        );
+        assert_eq!(expected, computed.namespace_hex);
+    }
+}
@@ -0,0 +1,261 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+        Write,
+    },
+    path::PathBuf,
+};
+
+use astria_eyre::eyre::{
+    ensure,
+    Result,
+    WrapErr,
+};
+use serde::Serialize;
+
+use crate::{
+    blob_parser::{
+        colored_label_ln,
+        colored_ln,
+        indent,
+        Format,
+    },
+    storage::{
+        self,
+        module_of,
+        State,
+        Store,
+        StoredValue,
+    },
+};
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Path to the storage database before the change, e.g. a copy taken before an upgrade
+    #[arg(long, value_name = "PATH")]
+    old: PathBuf,
+
+    /// Path to the storage database after the change
+    #[arg(long, value_name = "PATH")]
+    new: PathBuf,
+
+    /// Configure formatting of output
+    #[arg(short, long, default_value_t = Format::Display, value_enum)]
+    format: Format,
+
+    /// Display verbose output (the decoded old and new values of every differing key)
+    #[arg(short, long)]
+    verbose: bool,
+}
+
+/// Compares the latest state of two sequencer storage databases, printing the added, removed and
+/// changed keys grouped by the sequencer module owning them.
+///
+/// # Errors
+///
+/// Returns an error if either database cannot be opened or read.
+pub fn run(
+    Args {
+        old,
+        new,
+        format,
+        verbose,
+    }: Args,
+) -> Result<()> {
+    ensure!(
+        old != new,
+        "`--old` and `--new` must be different databases; copy the database before changing it"
+    );
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .wrap_err("failed to create tokio runtime")?;
+    let (old_state, new_state) = runtime.block_on(async {
+        let old_state = storage::load(&old).await?;
+        let new_state = storage::load(&new).await?;
+        Ok::<_, astria_eyre::eyre::Report>((old_state, new_state))
+    })?;
+
+    let state_diff = diff(&old_state, &new_state, verbose);
+    match format {
+        Format::Display => println!("\n{state_diff}"),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(&state_diff).wrap_err("failed to json-encode")?
+        ),
+    }
+    Ok(())
+}
+
+fn diff(old: &State, new: &State, verbose: bool) -> StateDiff {
+    let mut modules: BTreeMap<&'static str, ModuleDiff> = BTreeMap::new();
+    let mut record = |kind: Change, (store, key): &(Store, String), old, new| {
+        let module = modules.entry(module_of(key)).or_default();
+        let decode = |value: Option<&Vec<u8>>| {
+            value
+                .filter(|_| verbose)
+                .map(|value| StoredValue::decode(*store, key, value))
+        };
+        let key_diff = KeyDiff {
+            key: key.clone(),
+            store: *store,
+            old: decode(old),
+            new: decode(new),
+        };
+        match kind {
+            Change::Added => module.added.push(key_diff),
+            Change::Removed => module.removed.push(key_diff),
+            Change::Changed => module.changed.push(key_diff),
+        }
+    };
+
+    for (entry, old_value) in &old.entries {
+        match new.entries.get(entry) {
+            None => record(Change::Removed, entry, Some(old_value), None),
+            Some(new_value) if new_value != old_value => {
+                record(Change::Changed, entry, Some(old_value), Some(new_value));
+            }
+            Some(_) => {}
+        }
+    }
+    for (entry, new_value) in &new.entries {
+        if !old.entries.contains_key(entry) {
+            record(Change::Added, entry, None, Some(new_value));
+        }
+    }
+
+    StateDiff {
+        old_version: old.version,
+        new_version: new.version,
+        modules,
+    }
+}
+
+enum Change {
+    Added,
+    Removed,
+    Changed,
+}
+
+#[derive(Serialize, Debug)]
+struct KeyDiff {
+    key: String,
+    store: Store,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    old: Option<StoredValue>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new: Option<StoredValue>,
+}
+
+impl Display for KeyDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.key)?;
+        if self.store == Store::Nonverifiable {
+            write!(f, " (nonverifiable)")?;
+        }
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) => write!(f, ": {old} -> {new}"),
+            (Some(value), None) | (None, Some(value)) => write!(f, ": {value}"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Default)]
+struct ModuleDiff {
+    added: Vec<KeyDiff>,
+    removed: Vec<KeyDiff>,
+    changed: Vec<KeyDiff>,
+}
+
+impl Display for ModuleDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (label, key_diffs) in [
+            ("added", &self.added),
+            ("removed", &self.removed),
+            ("changed", &self.changed),
+        ] {
+            if key_diffs.is_empty() {
+                continue;
+            }
+            colored_label_ln(f, &format!("{label} ({})", key_diffs.len()))?;
+            for key_diff in key_diffs {
+                writeln!(indent(f), "{key_diff}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct StateDiff {
+    old_version: u64,
+    new_version: u64,
+    modules: BTreeMap<&'static str, ModuleDiff>,
+}
+
+impl Display for StateDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        colored_ln(f, "old version", self.old_version)?;
+        colored_ln(f, "new version", self.new_version)?;
+        if self.modules.is_empty() {
+            return write!(f, "no differences");
+        }
+        for (module, module_diff) in &self.modules {
+            colored_label_ln(f, &format!("module {module}"))?;
+            write!(indent(f), "{module_diff}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(entries: &[(Store, &str, &[u8])]) -> State {
+        State {
+            version: 1,
+            entries: entries
+                .iter()
+                .map(|(store, key, value)| ((*store, (*key).to_string()), value.to_vec()))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn diff_groups_changes_by_module() {
+        let old = state(&[
+            (Store::Verifiable, "chain_id", b"astria"),
+            (Store::Verifiable, "sudo", &[1; 20]),
+            (Store::Nonverifiable, "nativeasset", b"nria"),
+        ]);
+        let new = state(&[
+            (Store::Verifiable, "chain_id", b"astria-1"),
+            (Store::Verifiable, "bsudo/abcd", &[2; 20]),
+            (Store::Nonverifiable, "nativeasset", b"nria"),
+        ]);
+
+        let state_diff = diff(&old, &new, true);
+        assert_eq!(
+            vec!["app", "authority", "bridge"],
+            state_diff.modules.keys().copied().collect::<Vec<_>>()
+        );
+        let app = &state_diff.modules["app"];
+        assert_eq!(
+            (
+                Some(StoredValue::Text("astria".to_string())),
+                Some(StoredValue::Text("astria-1".to_string()))
+            ),
+            (app.changed[0].old.clone(), app.changed[0].new.clone())
+        );
+        assert_eq!(1, state_diff.modules["authority"].removed.len());
+        assert_eq!(1, state_diff.modules["bridge"].added.len());
+
+        let brief = diff(&old, &new, false);
+        assert!(brief.modules["app"].changed[0].old.is_none());
+    }
+}
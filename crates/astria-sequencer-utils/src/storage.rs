@@ -0,0 +1,302 @@
+//! Read-only access to the storage database of a sequencer node.
+//!
+//! The node must be stopped while its database is opened.
+
+use std::{
+    collections::BTreeMap,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+    },
+    path::Path,
+};
+
+use astria_eyre::eyre::{
+    ensure,
+    eyre,
+    Result,
+    WrapErr,
+};
+use cnidarium::{
+    Snapshot,
+    StateRead as _,
+    Storage,
+};
+use futures::StreamExt as _;
+use serde::Serialize;
+
+/// The prefix of the substore holding the IBC state, as configured by the sequencer.
+const IBC_SUBSTORE_PREFIX: &str = "ibc_data";
+
+/// The keys of the fee components, each stored as a borsh-encoded `u128`.
+const FEE_KEYS: [&str; 7] = [
+    "transferfee",
+    "seqbasefee",
+    "seqmultiplier",
+    "initbridgeaccfee",
+    "bridgelockmultiplier",
+    "bridgesudofee",
+    "ics20withdrawalfee",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum Store {
+    /// The merkleized store whose root is the app hash
+    Verifiable,
+    /// The store for data not committed to by the app hash
+    Nonverifiable,
+}
+
+/// All entries of a storage database at its latest version.
+///
+/// Keys of the nonverifiable store are converted to strings, which is lossless for all keys
+/// written by the sequencer.
+pub(crate) struct State {
+    pub(crate) version: u64,
+    pub(crate) entries: BTreeMap<(Store, String), Vec<u8>>,
+}
+
+impl State {
+    pub(crate) fn get(&self, store: Store, key: &str) -> Option<&[u8]> {
+        self.entries
+            .get(&(store, key.to_string()))
+            .map(Vec::as_slice)
+    }
+
+    /// Returns the entries of `store` whose keys start with `prefix`.
+    pub(crate) fn prefixed<'a>(
+        &'a self,
+        store: Store,
+        prefix: &'a str,
+    ) -> impl Iterator<Item = (&'a str, &'a [u8])> {
+        self.entries
+            .range((store, prefix.to_string())..)
+            .take_while(move |((entry_store, key), _)| {
+                *entry_store == store && key.starts_with(prefix)
+            })
+            .map(|((_, key), value)| (key.as_str(), value.as_slice()))
+    }
+}
+
+/// Opens the storage database at `path` and reads all of its entries.
+///
+/// # Errors
+///
+/// Returns an error if `path` is not a directory, or if the database cannot be opened or read.
+pub(crate) async fn load(path: &Path) -> Result<State> {
+    // `Storage::load` would create a new database at a nonexistent path
+    ensure!(
+        path.is_dir(),
+        "`{}` is not a storage database directory",
+        path.display()
+    );
+    let storage = Storage::load(path.to_path_buf(), vec![IBC_SUBSTORE_PREFIX.to_string()])
+        .await
+        .map_err(|error| eyre!("{error:#}"))
+        .wrap_err_with(|| format!("failed to open storage database at `{}`", path.display()))?;
+    let state = read(storage.latest_snapshot()).await;
+    storage.release().await;
+    state.wrap_err_with(|| format!("failed to read storage database at `{}`", path.display()))
+}
+
+async fn read(snapshot: Snapshot) -> Result<State> {
+    let mut entries = BTreeMap::new();
+    // the main store and each substore are iterated separately
+    for prefix in [String::new(), format!("{IBC_SUBSTORE_PREFIX}/")] {
+        let mut stream = std::pin::pin!(snapshot.prefix_raw(&prefix));
+        while let Some(entry) = stream.next().await {
+            let (key, value) = entry.map_err(|error| eyre!("{error:#}"))?;
+            entries.insert((Store::Verifiable, key), value);
+        }
+    }
+    let mut stream = std::pin::pin!(snapshot.nonverifiable_prefix_raw(&[]));
+    while let Some(entry) = stream.next().await {
+        let (key, value) = entry.map_err(|error| eyre!("{error:#}"))?;
+        let key = String::from_utf8_lossy(&key).into_owned();
+        entries.insert((Store::Nonverifiable, key), value);
+    }
+    Ok(State {
+        version: snapshot.version(),
+        entries,
+    })
+}
+
+/// Returns the sequencer module owning `key`.
+pub(crate) fn module_of(key: &str) -> &'static str {
+    let first_segment = key.split('/').next().unwrap_or_default();
+    match first_segment {
+        "accounts" | "transferfee" => "accounts",
+        "asset" | "nativeasset" => "asset",
+        "sudo" | "valset" | "valupdates" => "authority",
+        "bridgeacc"
+        | "bsudo"
+        | "bwithdrawer"
+        | "deposit"
+        | "depositnonce"
+        | "initbridgeaccfee"
+        | "bridgelockmultiplier"
+        | "bridgesudofee" => "bridge",
+        "ibcsudo" | "ibc-relayer" | "ibc-data" | "ics20withdrawalfee" | IBC_SUBSTORE_PREFIX => {
+            "ibc"
+        }
+        "seqbasefee" | "seqmultiplier" => "sequence",
+        "blockhash" | "blockheader" | "rollupdata" | "rollupids" | "rolluptxsproof"
+        | "rollupidsproof" => "api",
+        "prefixes" => "address",
+        "chain_id" | "revision_number" | "block_height" | "block_timestamp" | "storage_version"
+        | "block_fees" | "fee_asset" => "app",
+        _ => "other",
+    }
+}
+
+/// A value read from storage, decoded according to the encoding the sequencer uses for its key.
+///
+/// Values of unknown keys, or whose length does not match the expected encoding, are kept as
+/// bytes.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub(crate) enum StoredValue {
+    Amount(u128),
+    Integer(u64),
+    /// A hex-encoded address
+    Address(String),
+    /// A hex-encoded asset or rollup ID
+    Id(String),
+    Text(String),
+    Json(serde_json::Value),
+    Empty,
+    /// Hex-encoded bytes
+    Bytes(String),
+}
+
+impl StoredValue {
+    pub(crate) fn decode(store: Store, key: &str, value: &[u8]) -> Self {
+        if value.is_empty() {
+            return Self::Empty;
+        }
+        let segments: Vec<&str> = key.split('/').collect();
+        let decoded = match (store, segments.as_slice()) {
+            (Store::Verifiable, ["accounts", _, "balance", _] | ["ibc-data", _, "balance", _]) => {
+                array(value).map(|bytes| Self::Amount(u128::from_le_bytes(bytes)))
+            }
+            (Store::Verifiable, [fee]) if FEE_KEYS.contains(fee) => {
+                array(value).map(|bytes| Self::Amount(u128::from_le_bytes(bytes)))
+            }
+            (Store::Verifiable, ["accounts", _, "nonce"]) => {
+                array(value).map(|bytes| Self::Integer(u32::from_le_bytes(bytes).into()))
+            }
+            (Store::Verifiable, ["sudo" | "ibcsudo"] | ["bsudo" | "bwithdrawer", _]) => {
+                Some(Self::Address(hex::encode(value)))
+            }
+            (Store::Verifiable, ["bridgeacc", _, "rollupid" | "assetid"]) => {
+                array::<32>(value).map(|bytes| Self::Id(hex::encode(bytes)))
+            }
+            (Store::Verifiable, ["asset", _]) => borsh_string(value).map(Self::Text),
+            (Store::Verifiable, ["revision_number" | "block_height"])
+            | (Store::Nonverifiable, ["storage_version", _]) => {
+                array(value).map(|bytes| Self::Integer(u64::from_be_bytes(bytes)))
+            }
+            (Store::Verifiable, ["chain_id" | "block_timestamp"] | ["prefixes", _])
+            | (Store::Nonverifiable, ["nativeasset"]) => {
+                String::from_utf8(value.to_vec()).ok().map(Self::Text)
+            }
+            (Store::Verifiable, ["valset"]) | (Store::Nonverifiable, ["valupdates"]) => {
+                serde_json::from_slice(value).ok().map(Self::Json)
+            }
+            (Store::Nonverifiable, ["block_fees", _]) => {
+                array(value).map(|bytes| Self::Amount(u128::from_be_bytes(bytes)))
+            }
+            (Store::Nonverifiable, ["depositnonce", _]) => {
+                array(value).map(|bytes| Self::Integer(u32::from_be_bytes(bytes).into()))
+            }
+            _ => None,
+        };
+        decoded.unwrap_or_else(|| Self::Bytes(hex::encode(value)))
+    }
+}
+
+impl Display for StoredValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            StoredValue::Amount(amount) => write!(f, "{amount}"),
+            StoredValue::Integer(integer) => write!(f, "{integer}"),
+            StoredValue::Address(hex) | StoredValue::Id(hex) => write!(f, "{hex}"),
+            StoredValue::Text(text) => write!(f, "{text:?}"),
+            StoredValue::Json(json) => write!(f, "{json}"),
+            StoredValue::Empty => write!(f, "<empty>"),
+            StoredValue::Bytes(hex) => write!(f, "0x{hex}"),
+        }
+    }
+}
+
+fn array<const N: usize>(value: &[u8]) -> Option<[u8; N]> {
+    value.try_into().ok()
+}
+
+/// Decodes a borsh-encoded string: a little-endian `u32` length followed by UTF-8 bytes.
+pub(crate) fn borsh_string(value: &[u8]) -> Option<String> {
+    if value.len() < 4 {
+        return None;
+    }
+    let (len, bytes) = value.split_at(4);
+    let len = usize::try_from(u32::from_le_bytes(len.try_into().ok()?)).ok()?;
+    if bytes.len() != len {
+        return None;
+    }
+    String::from_utf8(bytes.to_vec()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn values_are_decoded_by_key() {
+        let address = "1c0c490f1b5528d8173c5de46d131160e4b2c0c3";
+        let balance_key = format!("accounts/{address}/balance/{}", "ab".repeat(32));
+        assert_eq!(
+            StoredValue::Amount(1_000),
+            StoredValue::decode(Store::Verifiable, &balance_key, &1_000u128.to_le_bytes())
+        );
+        assert_eq!(
+            StoredValue::Integer(7),
+            StoredValue::decode(
+                Store::Verifiable,
+                &format!("accounts/{address}/nonce"),
+                &7u32.to_le_bytes()
+            )
+        );
+        assert_eq!(
+            StoredValue::Text("transfer/channel-0/utia".to_string()),
+            StoredValue::decode(
+                Store::Verifiable,
+                &format!("asset/{}", "ab".repeat(32)),
+                &[&23u32.to_le_bytes()[..], b"transfer/channel-0/utia"].concat()
+            )
+        );
+        assert_eq!(
+            StoredValue::Amount(5),
+            StoredValue::decode(
+                Store::Nonverifiable,
+                "block_fees/abcd",
+                &5u128.to_be_bytes()
+            )
+        );
+        // a value not matching the expected encoding is kept as bytes
+        assert_eq!(
+            StoredValue::Bytes("0102".to_string()),
+            StoredValue::decode(Store::Verifiable, &balance_key, &[1, 2])
+        );
+    }
+
+    #[test]
+    fn keys_are_grouped_by_module() {
+        assert_eq!("accounts", module_of("accounts/abcd/balance/ef01"));
+        assert_eq!("bridge", module_of("bsudo/abcd"));
+        assert_eq!("ibc", module_of("ibc_data/clients/07-tendermint-0"));
+        assert_eq!("app", module_of("chain_id"));
+        assert_eq!("other", module_of("unknown/key"));
+    }
+}
@@ -0,0 +1,466 @@
+use std::{
+    collections::BTreeMap,
+    fmt::{
+        self,
+        Display,
+        Formatter,
+        Write,
+    },
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+use astria_core::{
+    generated::protocol::transaction::v1alpha1 as raw,
+    primitive::v1::{
+        asset,
+        Address,
+    },
+    protocol::transaction::v1alpha1::{
+        Action,
+        SignedTransaction,
+    },
+};
+use astria_eyre::eyre::{
+    Result,
+    WrapErr,
+};
+use base64::{
+    prelude::BASE64_STANDARD,
+    Engine,
+};
+use prost::Message;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use crate::blob_parser::{
+    colored,
+    colored_label_ln,
+    colored_ln,
+    indent,
+    Format,
+};
+
+/// The length of the merkle roots forming the first three entries of a sequencer block's data.
+const MERKLE_ROOT_LEN: usize = 32;
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Base64- or hex-encoded transaction or block data entry, or a file containing this, or
+    /// stdin if `-`
+    #[arg(value_name = "TX|PATH")]
+    input: String,
+
+    /// Configure formatting of output
+    #[arg(short, long, default_value_t = Format::Display, value_enum)]
+    format: Format,
+
+    /// Display verbose output (i.e. displays the full contents of every action)
+    #[arg(short, long)]
+    verbose: bool,
+
+    /// The bech32m prefix used to display the signer's address
+    #[arg(long, default_value = "astria")]
+    prefix: String,
+
+    /// Path to a genesis app state file whose fee components are used to estimate the fees
+    /// of the actions
+    #[arg(long, value_name = "PATH")]
+    genesis_app_state_file: Option<PathBuf>,
+}
+
+/// Parses `input` (an encoded CometBFT transaction or sequencer block data entry) to the given
+/// format.
+///
+/// # Errors
+///
+/// Returns an error if `input` cannot be parsed, or if the genesis app state file cannot be
+/// read.
+pub fn run(
+    Args {
+        input,
+        format,
+        verbose,
+        prefix,
+        genesis_app_state_file,
+    }: Args,
+) -> Result<()> {
+    let fees = genesis_app_state_file
+        .as_deref()
+        .map(read_fee_components)
+        .transpose()?;
+    let parsed = parse(
+        &get_decoded_tx_data(&input)?,
+        &prefix,
+        fees.as_ref(),
+        verbose,
+    )?;
+    match format {
+        Format::Display => println!("\n{parsed}"),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string(&parsed).wrap_err("failed to json-encode")?
+        ),
+    }
+    Ok(())
+}
+
+fn get_decoded_tx_data(input: &str) -> Result<Vec<u8>> {
+    let encoded = if input == "-" {
+        io::read_to_string(io::stdin().lock()).wrap_err("failed to read stdin")?
+    } else if Path::new(input).is_file() {
+        fs::read_to_string(input).wrap_err_with(|| format!("failed to read file `{input}`"))?
+    } else {
+        input.to_string()
+    };
+    decode(encoded.trim())
+}
+
+/// Decodes `encoded` as hex, or as base64 if it is not valid hex.
+fn decode(encoded: &str) -> Result<Vec<u8>> {
+    let encoded = encoded.strip_prefix("0x").unwrap_or(encoded);
+    hex::decode(encoded).or_else(|_| {
+        BASE64_STANDARD
+            .decode(encoded)
+            .wrap_err("failed to decode transaction data as either hex or base64")
+    })
+}
+
+fn read_fee_components(path: &Path) -> Result<FeeComponents> {
+    #[derive(Deserialize)]
+    struct AppState {
+        fees: FeeComponents,
+    }
+
+    let contents = fs::read_to_string(path)
+        .wrap_err_with(|| format!("failed to read file `{}`", path.display()))?;
+    let app_state: AppState = serde_json::from_str(&contents).wrap_err_with(|| {
+        format!(
+            "failed to read fee components from genesis app state `{}`",
+            path.display()
+        )
+    })?;
+    Ok(app_state.fees)
+}
+
+fn parse(
+    bytes: &[u8],
+    prefix: &str,
+    fees: Option<&FeeComponents>,
+    verbose: bool,
+) -> Result<ParsedTx> {
+    // The first three entries of a block's data are the rollup transactions, rollup IDs and fee
+    // summary merkle roots, and are the only entries of this length.
+    if bytes.len() == MERKLE_ROOT_LEN {
+        return Ok(ParsedTx::MerkleRoot(BASE64_STANDARD.encode(bytes)));
+    }
+
+    let raw_tx = raw::SignedTransaction::decode(bytes)
+        .wrap_err("failed to decode as a signed transaction")?;
+    let tx = SignedTransaction::try_from_raw(raw_tx).wrap_err("invalid signed transaction")?;
+    let signer = Address::builder()
+        .array(tx.address_bytes())
+        .prefix(prefix)
+        .try_build()
+        .wrap_err("failed to construct signer address with the given prefix")?;
+
+    let mut actions = vec![];
+    let mut total_fees: BTreeMap<String, u128> = BTreeMap::new();
+    for action in tx.actions() {
+        let fee = fees
+            .and_then(|fees| fees.fee(action))
+            .map(|(asset, amount)| {
+                let asset = asset.to_string();
+                let total = total_fees.entry(asset.clone()).or_default();
+                *total = total.saturating_add(amount);
+                PrintableFee {
+                    asset,
+                    amount,
+                }
+            });
+        let contents = if verbose {
+            Some(serde_json::to_value(action.to_raw()).wrap_err("failed to json-encode action")?)
+        } else {
+            None
+        };
+        actions.push(PrintableAction {
+            name: action.name(),
+            fee,
+            contents,
+        });
+    }
+
+    Ok(ParsedTx::Transaction(PrintableTransaction {
+        hash: hex::encode(tx.sha256_of_proto_encoding()),
+        signer: signer.to_string(),
+        verification_key: BASE64_STANDARD.encode(tx.verification_key().to_bytes()),
+        chain_id: tx.chain_id().to_string(),
+        nonce: tx.nonce(),
+        actions,
+        total_fees: fees.map(|_| {
+            total_fees
+                .into_iter()
+                .map(|(asset, amount)| PrintableFee {
+                    asset,
+                    amount,
+                })
+                .collect()
+        }),
+    }))
+}
+
+/// The fee components of the sequencer, named as in its genesis app state.
+#[derive(Debug, Deserialize)]
+struct FeeComponents {
+    transfer_base_fee: u128,
+    sequence_base_fee: u128,
+    sequence_byte_cost_multiplier: u128,
+    init_bridge_account_base_fee: u128,
+    bridge_lock_byte_cost_multiplier: u128,
+    bridge_sudo_change_fee: u128,
+    ics20_withdrawal_base_fee: u128,
+}
+
+impl FeeComponents {
+    /// Returns the asset and amount of the fee charged for `action`, mirroring the fee
+    /// calculation of the sequencer.
+    fn fee(&self, action: &Action) -> Option<(asset::Id, u128)> {
+        let fee = match action {
+            Action::Transfer(act) => (act.fee_asset_id, self.transfer_base_fee),
+            Action::AllowanceChange(act) => (act.fee_asset_id, self.transfer_base_fee),
+            Action::TransferFrom(act) => (act.fee_asset_id, self.transfer_base_fee),
+            Action::Sequence(act) => (
+                act.fee_asset_id,
+                self.sequence_byte_cost_multiplier
+                    .saturating_mul(byte_len(act.fee_weight()))
+                    .saturating_add(self.sequence_base_fee),
+            ),
+            Action::Ics20Withdrawal(act) => (*act.fee_asset_id(), self.ics20_withdrawal_base_fee),
+            Action::InitBridgeAccount(act) => (act.fee_asset_id, self.init_bridge_account_base_fee),
+            Action::BridgeLock(act) => (
+                act.fee_asset_id,
+                self.bridge_lock_byte_cost_multiplier
+                    .saturating_mul(byte_len(act.fee_weight()))
+                    .saturating_add(self.transfer_base_fee),
+            ),
+            Action::BridgeUnlock(act) => (act.fee_asset_id, self.transfer_base_fee),
+            Action::BridgeSudoChange(act) => (act.fee_asset_id, self.bridge_sudo_change_fee),
+            Action::BridgeRecoveryVeto(act) => (act.fee_asset_id, self.bridge_sudo_change_fee),
+            Action::ValidatorUpdate(_)
+            | Action::SudoAddressChange(_)
+            | Action::Ibc(_)
+            | Action::IbcRelayerChange(_)
+            | Action::FeeAssetChange(_)
+            | Action::FeeChange(_)
+            | Action::AddressPrefixChange(_)
+            | Action::BridgeRecovery(_)
+            | Action::FeeDistributionChange(_) => return None,
+        };
+        Some(fee)
+    }
+}
+
+fn byte_len(len: usize) -> u128 {
+    len.try_into()
+        .expect("a usize should always convert to a u128")
+}
+
+#[derive(Serialize, Debug)]
+struct PrintableFee {
+    asset: String,
+    amount: u128,
+}
+
+impl Display for PrintableFee {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.amount, self.asset)
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct PrintableAction {
+    name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    fee: Option<PrintableFee>,
+    /// The action encoded as pbjson; only populated if verbose output is requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    contents: Option<serde_json::Value>,
+}
+
+impl Display for PrintableAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        colored(f, "name", self.name)?;
+        if let Some(fee) = &self.fee {
+            writeln!(f)?;
+            colored(f, "estimated fee", fee)?;
+        }
+        if let Some(contents) = &self.contents {
+            let json = serde_json::to_string_pretty(contents).map_err(|_| fmt::Error)?;
+            writeln!(f)?;
+            colored_label_ln(f, "contents")?;
+            write!(indent(f), "{json}")?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Debug)]
+struct PrintableTransaction {
+    hash: String,
+    signer: String,
+    verification_key: String,
+    chain_id: String,
+    nonce: u32,
+    actions: Vec<PrintableAction>,
+    /// The estimated fees by fee asset; only populated if fee components were provided.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    total_fees: Option<Vec<PrintableFee>>,
+}
+
+impl Display for PrintableTransaction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        colored_ln(f, "hash", &self.hash)?;
+        colored_ln(f, "signer", &self.signer)?;
+        colored_ln(f, "verification key", &self.verification_key)?;
+        colored_ln(f, "chain id", &self.chain_id)?;
+        colored_ln(f, "nonce", self.nonce)?;
+        for (index, action) in self.actions.iter().enumerate() {
+            colored_label_ln(f, &format!("action {index}"))?;
+            writeln!(indent(f), "{action}")?;
+        }
+        colored(f, "action count", self.actions.len())?;
+        if let Some(total_fees) = &self.total_fees {
+            writeln!(f)?;
+            colored_label_ln(f, "estimated total fees")?;
+            if total_fees.is_empty() {
+                write!(indent(f), "none")?;
+            }
+            for (index, fee) in total_fees.iter().enumerate() {
+                if index > 0 {
+                    writeln!(f)?;
+                }
+                write!(indent(f), "{fee}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize, Debug)]
+enum ParsedTx {
+    #[serde(rename = "transaction")]
+    Transaction(PrintableTransaction),
+    /// One of the merkle roots at the start of a block's data.  Wrapped value is the
+    /// base-64-encoded root.
+    #[serde(rename = "merkle_root")]
+    MerkleRoot(String),
+}
+
+impl Display for ParsedTx {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParsedTx::Transaction(tx) => {
+                colored_label_ln(f, "transaction")?;
+                write!(indent(f), "{tx}")
+            }
+            ParsedTx::MerkleRoot(root) => {
+                colored(f, "rollup transactions or rollup ids merkle root", root)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        crypto::SigningKey,
+        primitive::v1::RollupId,
+        protocol::transaction::v1alpha1::{
+            action::{
+                SequenceAction,
+                TransferAction,
+            },
+            TransactionParams,
+            UnsignedTransaction,
+        },
+    };
+
+    use super::*;
+
+    fn fee_components() -> FeeComponents {
+        FeeComponents {
+            transfer_base_fee: 12,
+            sequence_base_fee: 32,
+            sequence_byte_cost_multiplier: 1,
+            init_bridge_account_base_fee: 48,
+            bridge_lock_byte_cost_multiplier: 1,
+            bridge_sudo_change_fee: 24,
+            ics20_withdrawal_base_fee: 24,
+        }
+    }
+
+    fn encoded_tx() -> Vec<u8> {
+        let fee_asset_id = asset::default_native_asset().id();
+        let tx = UnsignedTransaction {
+            params: TransactionParams::builder()
+                .nonce(1)
+                .chain_id("test-1")
+                .build(),
+            actions: vec![
+                Action::Transfer(TransferAction {
+                    to: "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm"
+                        .parse()
+                        .unwrap(),
+                    amount: 100,
+                    asset_id: fee_asset_id,
+                    fee_asset_id,
+                }),
+                Action::Sequence(SequenceAction {
+                    rollup_id: RollupId::from_unhashed_bytes(b"rollup"),
+                    data: vec![0; 10],
+                    fee_asset_id,
+                }),
+            ],
+        };
+        tx.into_signed(&SigningKey::from([1; 32]))
+            .into_raw()
+            .encode_to_vec()
+    }
+
+    #[test]
+    fn transaction_is_decoded_from_hex_and_base64() {
+        let bytes = encoded_tx();
+        assert_eq!(bytes, decode(&hex::encode(&bytes)).unwrap());
+        assert_eq!(bytes, decode(&BASE64_STANDARD.encode(&bytes)).unwrap());
+    }
+
+    #[test]
+    fn transaction_is_parsed_with_fee_estimates() {
+        let fees = fee_components();
+        let ParsedTx::Transaction(tx) = parse(&encoded_tx(), "astria", Some(&fees), false).unwrap()
+        else {
+            panic!("a transaction should have been parsed");
+        };
+        assert_eq!(1, tx.nonce);
+        assert_eq!("test-1", tx.chain_id);
+        let names: Vec<_> = tx.actions.iter().map(|action| action.name).collect();
+        assert_eq!(vec!["transfer", "sequence"], names);
+        let total_fees = tx.total_fees.unwrap();
+        assert_eq!(1, total_fees.len());
+        assert_eq!(54, total_fees[0].amount);
+    }
+
+    #[test]
+    fn merkle_roots_are_recognized() {
+        assert!(matches!(
+            parse(&[1; 32], "astria", None, false).unwrap(),
+            ParsedTx::MerkleRoot(_)
+        ));
+    }
+}
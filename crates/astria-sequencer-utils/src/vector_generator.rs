@@ -0,0 +1,256 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::PathBuf,
+};
+
+use astria_core::{
+    crypto::SigningKey,
+    primitive::v1::{
+        asset,
+        Address,
+        RollupId,
+    },
+    protocol::{
+        test_utils::ConfigureSequencerBlock,
+        transaction::v1alpha1::{
+            action::{
+                SequenceAction,
+                TransferAction,
+            },
+            Action,
+            TransactionParams,
+            UnsignedTransaction,
+        },
+    },
+    sequencerblock::v1alpha1::block::Deposit,
+};
+use astria_eyre::eyre::{
+    Result,
+    WrapErr,
+};
+use prost::{
+    Message,
+    Name,
+};
+use serde::Serialize;
+
+/// The signing key used for every vector. It is publicly known and must never hold funds.
+const SIGNING_KEY: [u8; 32] = [1; 32];
+const CHAIN_ID: &str = "test-vectors-1";
+const ADDRESS_PREFIX: &str = "astria";
+
+#[derive(clap::Args, Debug)]
+pub struct Args {
+    /// Path to a directory to write one JSON file per vector into; if not given, all vectors
+    /// are written to stdout as a JSON array
+    #[arg(long, short, value_name = "PATH")]
+    output_dir: Option<PathBuf>,
+}
+
+/// Generates test vectors of the canonical protobuf and pbjson encodings of core types.
+///
+/// All vectors are derived from fixed inputs and a fixed signing key, so that running the
+/// command again produces identical output.
+///
+/// # Errors
+///
+/// Returns an error if the vectors cannot be encoded or written.
+pub fn run(
+    Args {
+        output_dir,
+    }: Args,
+) -> Result<()> {
+    let vectors = generate()?;
+    let Some(output_dir) = output_dir else {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&vectors).wrap_err("failed to json-encode")?
+        );
+        return Ok(());
+    };
+
+    fs::create_dir_all(&output_dir)
+        .wrap_err_with(|| format!("failed to create `{}`", output_dir.display()))?;
+    for vector in &vectors {
+        let path = output_dir.join(format!("{}.json", vector.name));
+        let contents = serde_json::to_string_pretty(vector).wrap_err("failed to json-encode")?;
+        fs::write(&path, contents)
+            .wrap_err_with(|| format!("failed to write `{}`", path.display()))?;
+    }
+    println!(
+        "wrote {} test vectors to {}",
+        vectors.len(),
+        output_dir.display()
+    );
+    Ok(())
+}
+
+#[derive(Serialize, Debug)]
+struct TestVector {
+    name: &'static str,
+    description: &'static str,
+    /// The fully qualified protobuf message name.
+    proto_type: String,
+    /// The hex-encoded protobuf encoding.
+    protobuf_hex: String,
+    /// The pbjson encoding.
+    json: serde_json::Value,
+    /// Values derived from the encoded message, such as hashes and addresses.
+    derived: BTreeMap<&'static str, String>,
+}
+
+impl TestVector {
+    fn new<T: Message + Name + Serialize>(
+        name: &'static str,
+        description: &'static str,
+        message: &T,
+    ) -> Result<Self> {
+        Ok(Self {
+            name,
+            description,
+            proto_type: T::full_name(),
+            protobuf_hex: hex::encode(message.encode_to_vec()),
+            json: serde_json::to_value(message)
+                .wrap_err_with(|| format!("failed to json-encode `{name}`"))?,
+            derived: BTreeMap::new(),
+        })
+    }
+
+    fn with(mut self, key: &'static str, value: String) -> Self {
+        self.derived.insert(key, value);
+        self
+    }
+}
+
+fn address(bytes: [u8; 20]) -> Address {
+    Address::builder()
+        .array(bytes)
+        .prefix(ADDRESS_PREFIX)
+        .try_build()
+        .expect("the address prefix is a valid bech32m prefix")
+}
+
+fn generate() -> Result<Vec<TestVector>> {
+    let signing_key = SigningKey::from(SIGNING_KEY);
+    let signer = address(signing_key.verification_key().address_bytes());
+    let recipient = address([2; 20]);
+    let rollup_id = RollupId::from_unhashed_bytes(b"test-vectors-rollup");
+    let native_asset = asset::default_native_asset().id();
+
+    let unsigned = UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(1)
+            .chain_id(CHAIN_ID)
+            .build(),
+        actions: vec![
+            Action::Transfer(TransferAction {
+                to: recipient,
+                amount: 1_000_000,
+                asset_id: native_asset,
+                fee_asset_id: native_asset,
+            }),
+            Action::Sequence(SequenceAction {
+                rollup_id,
+                data: b"hello rollup".to_vec(),
+                fee_asset_id: native_asset,
+            }),
+        ],
+    };
+    let signed = unsigned.clone().into_signed(&signing_key);
+
+    let deposit = Deposit::new(
+        recipient,
+        rollup_id,
+        500,
+        native_asset,
+        "0x0000000000000000000000000000000000000001".to_string(),
+    );
+
+    let block = ConfigureSequencerBlock {
+        block_hash: Some([3; 32]),
+        chain_id: Some(CHAIN_ID.to_string()),
+        height: 1,
+        signing_key: Some(SigningKey::from(SIGNING_KEY)),
+        sequence_data: vec![(rollup_id, b"hello rollup".to_vec())],
+        deposits: vec![deposit.clone()],
+        unix_timestamp: (1_700_000_000, 0).into(),
+        ..ConfigureSequencerBlock::default()
+    }
+    .make();
+
+    Ok(vec![
+        TestVector::new(
+            "unsigned_transaction",
+            "a transfer and a sequence action with nonce 1",
+            &unsigned.to_raw(),
+        )?
+        .with("native_asset_id", hex::encode(native_asset))
+        .with("rollup_id", hex::encode(rollup_id.get()))
+        .with("recipient", recipient.to_string()),
+        TestVector::new(
+            "signed_transaction",
+            "`unsigned_transaction` signed by the ed25519 key `signing_key`",
+            &signed.to_raw(),
+        )?
+        .with("signing_key", hex::encode(SIGNING_KEY))
+        .with(
+            "verification_key",
+            hex::encode(signing_key.verification_key().to_bytes()),
+        )
+        .with("signer", signer.to_string())
+        .with(
+            "transaction_hash",
+            hex::encode(signed.sha256_of_proto_encoding()),
+        ),
+        TestVector::new(
+            "deposit",
+            "a deposit of 500 of the native asset into a bridge account",
+            &deposit.into_raw(),
+        )?,
+        TestVector::new(
+            "sequencer_block",
+            "a block at height 1 holding `signed_transaction`'s sequence data as a separately \
+             signed transaction, and `deposit`",
+            &block.clone().into_raw(),
+        )?
+        .with("block_hash", hex::encode(block.block_hash())),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::{
+        generated::{
+            protocol::transaction::v1alpha1 as raw_tx,
+            sequencerblock::v1alpha1 as raw_block,
+        },
+        protocol::transaction::v1alpha1::SignedTransaction,
+        sequencerblock::v1alpha1::SequencerBlock,
+    };
+
+    use super::*;
+
+    #[test]
+    fn vectors_are_deterministic() {
+        let first = serde_json::to_string(&generate().unwrap()).unwrap();
+        let second = serde_json::to_string(&generate().unwrap()).unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn vectors_decode_to_valid_types() {
+        let vectors = generate().unwrap();
+        let bytes = |name: &str| {
+            let vector = vectors.iter().find(|vector| vector.name == name).unwrap();
+            hex::decode(&vector.protobuf_hex).unwrap()
+        };
+
+        let raw = raw_tx::SignedTransaction::decode(&*bytes("signed_transaction")).unwrap();
+        SignedTransaction::try_from_raw(raw).unwrap();
+        let raw = raw_block::Deposit::decode(&*bytes("deposit")).unwrap();
+        Deposit::try_from_raw(raw).unwrap();
+        let raw = raw_block::SequencerBlock::decode(&*bytes("sequencer_block")).unwrap();
+        SequencerBlock::try_from_raw(raw).unwrap();
+    }
+}
@@ -4,16 +4,25 @@ use anyhow::{
     Result,
 };
 use astria_core::{
-    primitive::v1::Address,
-    protocol::transaction::v1alpha1::action::TransferAction,
+    primitive::v1::{
+        asset,
+        Address,
+    },
+    protocol::transaction::v1alpha1::action::{
+        AllowanceChangeAction,
+        TransferAction,
+        TransferFromAction,
+    },
 };
 use tracing::instrument;
 
 use crate::{
     accounts::state_ext::{
+        Allowance,
         StateReadExt,
         StateWriteExt,
     },
+    address::StateReadExt as _,
     bridge::state_ext::StateReadExt as _,
     state_ext::{
         StateReadExt as _,
@@ -81,16 +90,15 @@ pub(crate) async fn transfer_check_stateful<S: StateReadExt + 'static>(
 
 #[async_trait::async_trait]
 impl ActionHandler for TransferAction {
-    async fn check_stateless(&self) -> Result<()> {
-        crate::address::ensure_base_prefix(&self.to).context("destination address is invalid")?;
-        Ok(())
-    }
-
     async fn check_stateful<S: StateReadExt + 'static>(
         &self,
         state: &S,
         from: Address,
     ) -> Result<()> {
+        state
+            .ensure_allowed_prefix(&self.to)
+            .await
+            .context("destination address is invalid")?;
         ensure!(
             state
                 .get_bridge_account_rollup_id(&from)
@@ -163,3 +171,215 @@ impl ActionHandler for TransferAction {
         Ok(())
     }
 }
+
+/// Checks that `from` is allowed to pay the transfer base fee in `fee_asset_id`, which both
+/// allowance actions are charged.
+async fn allowance_fee_check_stateful<S: StateReadExt + 'static>(
+    fee_asset_id: asset::Id,
+    state: &S,
+    from: Address,
+) -> Result<()> {
+    ensure!(
+        state
+            .is_allowed_fee_asset(fee_asset_id)
+            .await
+            .context("failed to check allowed fee assets in state")?,
+        "invalid fee asset",
+    );
+    let fee = state
+        .get_transfer_base_fee()
+        .await
+        .context("failed to get transfer base fee")?;
+    let from_fee_balance = state
+        .get_account_balance(from, fee_asset_id)
+        .await
+        .context("failed getting `from` account balance for fee payment")?;
+    ensure!(
+        from_fee_balance >= fee,
+        "insufficient funds for fee payment"
+    );
+    Ok(())
+}
+
+/// Charges `from` the transfer base fee in `fee_asset_id`.
+async fn allowance_fee_execute<S: StateWriteExt>(
+    fee_asset_id: asset::Id,
+    state: &mut S,
+    from: Address,
+) -> Result<()> {
+    let fee = state
+        .get_transfer_base_fee()
+        .await
+        .context("failed to get transfer base fee")?;
+    state
+        .get_and_increase_block_fees(fee_asset_id, fee)
+        .await
+        .context("failed to add to block fees")?;
+    state
+        .decrease_balance(from, fee_asset_id, fee)
+        .await
+        .context("failed decreasing `from` account balance for fee payment")?;
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl ActionHandler for AllowanceChangeAction {
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        state
+            .ensure_allowed_prefix(&self.spender)
+            .await
+            .context("spender address is invalid")?;
+        ensure!(
+            self.spender != from,
+            "an account cannot grant an allowance to itself"
+        );
+        if self.amount > 0 {
+            let height = state
+                .get_block_height()
+                .await
+                .context("failed to get block height")?;
+            ensure!(
+                self.expiry_height >= height,
+                "allowance would already be expired at the current block height `{height}`",
+            );
+        }
+
+        allowance_fee_check_stateful(self.fee_asset_id, state, from)
+            .await
+            .context("stateful fee check failed")
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            spender = self.spender.to_string(),
+            amount = self.amount,
+            expiry_height = self.expiry_height,
+        )
+    )]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
+        allowance_fee_execute(self.fee_asset_id, state, from).await?;
+
+        if self.amount == 0 {
+            state.delete_allowance(from, self.spender, self.asset_id);
+        } else {
+            state
+                .put_allowance(
+                    from,
+                    self.spender,
+                    self.asset_id,
+                    Allowance {
+                        amount: self.amount,
+                        expiry_height: self.expiry_height,
+                    },
+                )
+                .context("failed to put allowance")?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ActionHandler for TransferFromAction {
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        state
+            .ensure_allowed_prefix(&self.to)
+            .await
+            .context("destination address is invalid")?;
+        ensure!(
+            state
+                .get_bridge_account_rollup_id(&self.owner)
+                .await
+                .context("failed to get bridge account rollup id")?
+                .is_none(),
+            "cannot transfer out of bridge account; BridgeUnlock must be used",
+        );
+
+        let allowance = state
+            .get_allowance(self.owner, from, self.asset_id)
+            .await
+            .context("failed to get allowance")?
+            .context("signer has no allowance for the owner's asset")?;
+        let height = state
+            .get_block_height()
+            .await
+            .context("failed to get block height")?;
+        ensure!(
+            !allowance.is_expired(height),
+            "allowance expired at block height `{}`",
+            allowance.expiry_height,
+        );
+        ensure!(
+            allowance.amount >= self.amount,
+            "transfer amount exceeds the remaining allowance",
+        );
+
+        let owner_balance = state
+            .get_account_balance(self.owner, self.asset_id)
+            .await
+            .context("failed getting `owner` account balance")?;
+        ensure!(
+            owner_balance >= self.amount,
+            "insufficient funds for transfer"
+        );
+
+        allowance_fee_check_stateful(self.fee_asset_id, state, from)
+            .await
+            .context("stateful fee check failed")
+    }
+
+    #[instrument(
+        skip_all,
+        fields(
+            owner = self.owner.to_string(),
+            to = self.to.to_string(),
+            amount = self.amount,
+        )
+    )]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> Result<()> {
+        allowance_fee_execute(self.fee_asset_id, state, from).await?;
+
+        let allowance = state
+            .get_allowance(self.owner, from, self.asset_id)
+            .await
+            .context("failed to get allowance")?
+            .context("signer has no allowance for the owner's asset")?;
+        let remaining = allowance
+            .amount
+            .checked_sub(self.amount)
+            .context("transfer amount exceeds the remaining allowance")?;
+        if remaining == 0 {
+            state.delete_allowance(self.owner, from, self.asset_id);
+        } else {
+            state
+                .put_allowance(
+                    self.owner,
+                    from,
+                    self.asset_id,
+                    Allowance {
+                        amount: remaining,
+                        ..allowance
+                    },
+                )
+                .context("failed to put allowance")?;
+        }
+
+        state
+            .decrease_balance(self.owner, self.asset_id, self.amount)
+            .await
+            .context("failed decreasing `owner` account balance")?;
+        state
+            .increase_balance(self.to, self.asset_id, self.amount)
+            .await
+            .context("failed increasing `to` account balance")?;
+        Ok(())
+    }
+}
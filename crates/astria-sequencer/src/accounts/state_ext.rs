@@ -34,6 +34,20 @@ struct Balance(u128);
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 struct Fee(u128);
 
+/// An amount of an asset that a spender may transfer on behalf of an owner.
+#[derive(BorshSerialize, BorshDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct Allowance {
+    pub(crate) amount: u128,
+    /// The last block height at which the allowance can be spent.
+    pub(crate) expiry_height: u64,
+}
+
+impl Allowance {
+    pub(crate) fn is_expired(&self, height: u64) -> bool {
+        height > self.expiry_height
+    }
+}
+
 const ACCOUNTS_PREFIX: &str = "accounts";
 const TRANSFER_BASE_FEE_STORAGE_KEY: &str = "transferfee";
 
@@ -61,6 +75,15 @@ fn nonce_storage_key(address: Address) -> String {
     format!("{}/nonce", StorageKey(&address))
 }
 
+fn allowance_storage_key(owner: Address, spender: Address, asset: asset::Id) -> String {
+    format!(
+        "{}/allowance/{}/{}",
+        StorageKey(&owner),
+        spender.bytes().encode_hex::<String>(),
+        asset.encode_hex::<String>()
+    )
+}
+
 #[async_trait]
 pub(crate) trait StateReadExt: StateRead {
     #[instrument(skip_all, fields(address=%address))]
@@ -143,6 +166,24 @@ pub(crate) trait StateReadExt: StateRead {
         Ok(nonce)
     }
 
+    #[instrument(skip_all, fields(owner=%owner, spender=%spender, asset_id=%asset))]
+    async fn get_allowance(
+        &self,
+        owner: Address,
+        spender: Address,
+        asset: asset::Id,
+    ) -> Result<Option<Allowance>> {
+        let Some(bytes) = self
+            .get_raw(&allowance_storage_key(owner, spender, asset))
+            .await
+            .context("failed reading raw allowance from state")?
+        else {
+            return Ok(None);
+        };
+        let allowance = Allowance::try_from_slice(&bytes).context("invalid allowance bytes")?;
+        Ok(Some(allowance))
+    }
+
     #[instrument(skip_all)]
     async fn get_transfer_base_fee(&self) -> Result<u128> {
         let bytes = self
@@ -225,6 +266,24 @@ pub(crate) trait StateWriteExt: StateWrite {
         Ok(())
     }
 
+    #[instrument(skip(self))]
+    fn put_allowance(
+        &mut self,
+        owner: Address,
+        spender: Address,
+        asset: asset::Id,
+        allowance: Allowance,
+    ) -> Result<()> {
+        let bytes = borsh::to_vec(&allowance).context("failed to serialize allowance")?;
+        self.put_raw(allowance_storage_key(owner, spender, asset), bytes);
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn delete_allowance(&mut self, owner: Address, spender: Address, asset: asset::Id) {
+        self.delete(allowance_storage_key(owner, spender, asset));
+    }
+
     #[instrument(skip(self))]
     fn put_transfer_base_fee(&mut self, fee: u128) -> Result<()> {
         let bytes = borsh::to_vec(&Fee(fee)).context("failed to serialize fee")?;
@@ -257,8 +316,10 @@ mod test {
     };
     use crate::{
         accounts::state_ext::{
+            allowance_storage_key,
             balance_storage_key,
             nonce_storage_key,
+            Allowance,
         },
         asset,
     };
@@ -724,6 +785,58 @@ mod test {
             .expect_err("should not be able to subtract larger balance than what existed");
     }
 
+    #[tokio::test]
+    async fn allowance_round_trip() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let owner = crate::address::base_prefixed([42u8; 20]);
+        let spender = crate::address::base_prefixed([43u8; 20]);
+        let asset = Id::from_str_unchecked("asset_0");
+        let allowance = Allowance {
+            amount: 100,
+            expiry_height: 10,
+        };
+
+        assert_eq!(
+            state.get_allowance(owner, spender, asset).await.unwrap(),
+            None,
+            "no allowance should exist before one is put"
+        );
+
+        state
+            .put_allowance(owner, spender, asset, allowance)
+            .unwrap();
+        assert_eq!(
+            state.get_allowance(owner, spender, asset).await.unwrap(),
+            Some(allowance),
+        );
+        // allowances are not shared between spenders or with the reverse direction
+        assert_eq!(
+            state.get_allowance(spender, owner, asset).await.unwrap(),
+            None,
+        );
+
+        state.delete_allowance(owner, spender, asset);
+        assert_eq!(
+            state.get_allowance(owner, spender, asset).await.unwrap(),
+            None,
+            "the allowance should be removed after it is deleted"
+        );
+    }
+
+    #[test]
+    fn allowance_expires_after_expiry_height() {
+        let allowance = Allowance {
+            amount: 1,
+            expiry_height: 10,
+        };
+        assert!(!allowance.is_expired(9));
+        assert!(!allowance.is_expired(10));
+        assert!(allowance.is_expired(11));
+    }
+
     #[test]
     fn storage_keys_have_not_changed() {
         let address: Address = "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm"
@@ -737,5 +850,6 @@ mod test {
         }));
         assert_snapshot!(balance_storage_key(address, id));
         assert_snapshot!(nonce_storage_key(address));
+        assert_snapshot!(allowance_storage_key(address, address, id));
     }
 }
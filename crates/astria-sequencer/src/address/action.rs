@@ -0,0 +1,102 @@
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::{
+    primitive::v1::Address,
+    protocol::transaction::v1alpha1::action::AddressPrefixChangeAction,
+};
+use async_trait::async_trait;
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+
+use crate::{
+    address::state_ext::StateWriteExt as _,
+    authority::state_ext::StateReadExt as _,
+    transaction::action_handler::ActionHandler,
+};
+
+#[async_trait]
+impl ActionHandler for AddressPrefixChangeAction {
+    async fn check_stateful<S: StateRead + 'static>(&self, state: &S, from: Address) -> Result<()> {
+        let authority_sudo_address = state
+            .get_sudo_address()
+            .await
+            .context("failed to get authority sudo address")?;
+        ensure!(
+            authority_sudo_address == from,
+            "unauthorized address for address prefix change"
+        );
+        // the base prefix is always accepted and is used to display all addresses, so it cannot
+        // be added to or removed from the allowed prefixes
+        ensure!(
+            self.prefix() != crate::address::get_base_prefix(),
+            "the base prefix cannot be changed by an address prefix change action"
+        );
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, state: &mut S, _from: Address) -> Result<()> {
+        match self {
+            AddressPrefixChangeAction::Addition(prefix) => state.put_allowed_prefix(prefix),
+            AddressPrefixChangeAction::Removal(prefix) => state.delete_allowed_prefix(prefix),
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::{
+        address::state_ext::StateReadExt as _,
+        authority::state_ext::StateWriteExt as _,
+    };
+
+    #[tokio::test]
+    async fn address_prefix_change_is_only_allowed_for_sudo_address() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let sudo_address = crate::address::base_prefixed([1; 20]);
+        state.put_sudo_address(sudo_address).unwrap();
+
+        let action = AddressPrefixChangeAction::Addition("other".to_string());
+        action.check_stateful(&state, sudo_address).await.unwrap();
+        assert!(
+            action
+                .check_stateful(&state, crate::address::base_prefixed([2; 20]))
+                .await
+                .is_err()
+        );
+
+        let base = AddressPrefixChangeAction::Removal(crate::address::get_base_prefix().into());
+        assert!(base.check_stateful(&state, sudo_address).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn address_prefix_change_adds_and_removes_allowed_prefix() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        let from = crate::address::base_prefixed([1; 20]);
+
+        AddressPrefixChangeAction::Addition("other".to_string())
+            .execute(&mut state, from)
+            .await
+            .unwrap();
+        assert!(state.is_allowed_prefix("other").await.unwrap());
+
+        AddressPrefixChangeAction::Removal("other".to_string())
+            .execute(&mut state, from)
+            .await
+            .unwrap();
+        assert!(!state.is_allowed_prefix("other").await.unwrap());
+    }
+}
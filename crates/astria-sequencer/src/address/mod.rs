@@ -1,10 +1,10 @@
-use anyhow::ensure;
 use astria_core::primitive::v1::{
     Address,
     AddressError,
     ADDRESS_LEN,
 };
 
+mod action;
 mod state_ext;
 #[cfg(not(test))]
 pub(crate) use regular::*;
@@ -30,16 +30,6 @@ pub(crate) fn try_base_prefixed(slice: &[u8]) -> Result<Address, AddressError> {
         .try_build()
 }
 
-pub(crate) fn ensure_base_prefix(address: &Address) -> anyhow::Result<()> {
-    ensure!(
-        get_base_prefix() == address.prefix(),
-        "address has prefix `{}` but only `{}` is permitted",
-        address.prefix(),
-        crate::address::get_base_prefix(),
-    );
-    Ok(())
-}
-
 #[cfg(not(test))]
 mod regular {
     //! Logic to be used for a normal debug or release build of sequencer.
@@ -1,19 +1,28 @@
 use anyhow::{
     bail,
+    ensure,
     Context as _,
     Result,
 };
+use astria_core::primitive::v1::Address;
 use async_trait::async_trait;
 use cnidarium::{
     StateRead,
     StateWrite,
 };
+use futures::StreamExt as _;
 use tracing::instrument;
 
+const ALLOWED_PREFIX_PREFIX: &str = "prefixes/allowed/";
+
 fn base_prefix_key() -> &'static str {
     "prefixes/base"
 }
 
+fn allowed_prefix_key(prefix: &str) -> String {
+    format!("{ALLOWED_PREFIX_PREFIX}{prefix}")
+}
+
 #[async_trait]
 pub(crate) trait StateReadExt: StateRead {
     #[instrument(skip(self))]
@@ -27,6 +36,52 @@ pub(crate) trait StateReadExt: StateRead {
         };
         String::from_utf8(bytes).context("prefix retrieved from storage is not valid utf8")
     }
+
+    /// Returns if `prefix` is accepted in addition to the base prefix.
+    #[instrument(skip(self))]
+    async fn is_allowed_prefix(&self, prefix: &str) -> Result<bool> {
+        Ok(self
+            .nonverifiable_get_raw(allowed_prefix_key(prefix).as_bytes())
+            .await
+            .context("failed to read allowed address prefix from state")?
+            .is_some())
+    }
+
+    /// Returns the prefixes accepted in addition to the base prefix.
+    #[instrument(skip(self))]
+    async fn get_allowed_prefixes(&self) -> Result<Vec<String>> {
+        let mut prefixes = Vec::new();
+        let mut stream =
+            std::pin::pin!(self.nonverifiable_prefix_raw(ALLOWED_PREFIX_PREFIX.as_bytes()));
+        while let Some(Ok((key, _))) = stream.next().await {
+            // if the key isn't of the form `prefixes/allowed/{prefix}`, then we have a bug
+            // in `put_allowed_prefix`
+            let prefix = key
+                .strip_prefix(ALLOWED_PREFIX_PREFIX.as_bytes())
+                .expect("prefix must always be present");
+            prefixes.push(
+                String::from_utf8(prefix.to_vec())
+                    .context("allowed prefix retrieved from storage is not valid utf8")?,
+            );
+        }
+        Ok(prefixes)
+    }
+
+    /// Ensures that `address` has the base prefix or one of the allowed prefixes.
+    #[instrument(skip_all)]
+    async fn ensure_allowed_prefix(&self, address: &Address) -> Result<()> {
+        let prefix = address.prefix();
+        if prefix == crate::address::get_base_prefix() {
+            return Ok(());
+        }
+        ensure!(
+            self.is_allowed_prefix(prefix).await?,
+            "address has prefix `{prefix}` but only `{}` or one of the allowed prefixes is \
+             permitted",
+            crate::address::get_base_prefix(),
+        );
+        Ok(())
+    }
 }
 
 impl<T: ?Sized + StateRead> StateReadExt for T {}
@@ -37,12 +92,24 @@ pub(crate) trait StateWriteExt: StateWrite {
     fn put_base_prefix(&mut self, prefix: &str) {
         self.put_raw(base_prefix_key().into(), prefix.into());
     }
+
+    /// Adds `prefix` to the prefixes accepted in addition to the base prefix.
+    #[instrument(skip(self))]
+    fn put_allowed_prefix(&mut self, prefix: &str) {
+        self.nonverifiable_put_raw(allowed_prefix_key(prefix).into_bytes(), vec![]);
+    }
+
+    #[instrument(skip(self))]
+    fn delete_allowed_prefix(&mut self, prefix: &str) {
+        self.nonverifiable_delete(allowed_prefix_key(prefix).into_bytes());
+    }
 }
 
 impl<T: StateWrite> StateWriteExt for T {}
 
 #[cfg(test)]
 mod test {
+    use astria_core::primitive::v1::Address;
     use cnidarium::StateDelta;
 
     use super::{
@@ -59,4 +126,52 @@ mod test {
         state.put_base_prefix("astria");
         assert_eq!("astria", &state.get_base_prefix().await.unwrap());
     }
+
+    #[tokio::test]
+    async fn put_get_and_delete_allowed_prefixes() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert!(state.get_allowed_prefixes().await.unwrap().is_empty());
+
+        state.put_allowed_prefix("astriacompat");
+        state.put_allowed_prefix("other");
+        assert!(state.is_allowed_prefix("other").await.unwrap());
+        let mut prefixes = state.get_allowed_prefixes().await.unwrap();
+        prefixes.sort();
+        assert_eq!(vec!["astriacompat", "other"], prefixes);
+
+        state.delete_allowed_prefix("other");
+        assert!(!state.is_allowed_prefix("other").await.unwrap());
+        assert_eq!(
+            vec!["astriacompat"],
+            state.get_allowed_prefixes().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn ensure_allowed_prefix_accepts_base_and_allowed_prefixes() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address_with_prefix = |prefix: &str| {
+            Address::builder()
+                .array([1; 20])
+                .prefix(prefix)
+                .try_build()
+                .unwrap()
+        };
+
+        state
+            .ensure_allowed_prefix(&crate::address::base_prefixed([1; 20]))
+            .await
+            .unwrap();
+        let other = address_with_prefix("other");
+        assert!(state.ensure_allowed_prefix(&other).await.is_err());
+
+        state.put_allowed_prefix("other");
+        state.ensure_allowed_prefix(&other).await.unwrap();
+    }
 }
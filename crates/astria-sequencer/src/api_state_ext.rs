@@ -54,6 +54,14 @@ fn rollup_ids_proof_by_hash_key(hash: &[u8]) -> String {
     format!("rollupidsproof/{}", crate::utils::Hex(hash))
 }
 
+fn fee_summary_by_hash_key(hash: &[u8]) -> String {
+    format!("feesummary/{}", crate::utils::Hex(hash))
+}
+
+fn fee_summary_proof_by_hash_key(hash: &[u8]) -> String {
+    format!("feesummaryproof/{}", crate::utils::Hex(hash))
+}
+
 #[derive(BorshSerialize, BorshDeserialize)]
 struct RollupIdSeq(
     #[borsh(
@@ -241,12 +249,31 @@ pub(crate) trait StateReadExt: StateRead {
         let rollup_ids_proof = primitiveRaw::Proof::decode(rollup_ids_proof.as_slice())
             .context("failed to decode rollup IDs proof from raw bytes")?;
 
+        // blocks before the fee summary activation height are stored without fee summary
+        let fee_summary = self
+            .get_raw(&fee_summary_by_hash_key(hash))
+            .await
+            .context("failed to read fee summary by block hash from state")?
+            .map(|fee_summary| raw::FeeSummary::decode(fee_summary.as_slice()))
+            .transpose()
+            .context("failed to decode fee summary from raw bytes")?;
+
+        let fee_summary_proof = self
+            .get_raw(&fee_summary_proof_by_hash_key(hash))
+            .await
+            .context("failed to read fee summary proof by block hash from state")?
+            .map(|fee_summary_proof| primitiveRaw::Proof::decode(fee_summary_proof.as_slice()))
+            .transpose()
+            .context("failed to decode fee summary proof from raw bytes")?;
+
         let raw = raw::SequencerBlock {
             block_hash: hash.to_vec(),
             header: header_raw.into(),
             rollup_transactions,
             rollup_transactions_proof: rollup_transactions_proof.into(),
             rollup_ids_proof: rollup_ids_proof.into(),
+            fee_summary,
+            fee_summary_proof,
         };
 
         let block = SequencerBlock::try_from_raw(raw)
@@ -333,6 +360,8 @@ pub(crate) trait StateWriteExt: StateWrite {
         // 4. for each rollup ID in the block, map block hash + rollup ID to rollup data
         // 5. block hash to rollup transactions proof
         // 6. block hash to rollup IDs proof
+        // 7. block hash to fee summary
+        // 8. block hash to fee summary proof
 
         let key = block_hash_by_height_key(block.height().into());
         self.put_raw(key, block.block_hash().to_vec());
@@ -359,6 +388,8 @@ pub(crate) trait StateWriteExt: StateWrite {
             rollup_transactions,
             rollup_transactions_proof,
             rollup_ids_proof,
+            fee_summary,
+            fee_summary_proof,
         } = block.into_parts();
         let header = header.into_raw();
         self.put_raw(key, header.encode_to_vec());
@@ -374,6 +405,16 @@ pub(crate) trait StateWriteExt: StateWrite {
         let key = rollup_ids_proof_by_hash_key(&block_hash);
         self.put_raw(key, rollup_ids_proof.into_raw().encode_to_vec());
 
+        if let Some(fee_summary) = fee_summary {
+            let key = fee_summary_by_hash_key(&block_hash);
+            self.put_raw(key, fee_summary.into_raw().encode_to_vec());
+        }
+
+        if let Some(fee_summary_proof) = fee_summary_proof {
+            let key = fee_summary_proof_by_hash_key(&block_hash);
+            self.put_raw(key, fee_summary_proof.into_raw().encode_to_vec());
+        }
+
         Ok(())
     }
 }
@@ -385,7 +426,10 @@ mod test {
     use astria_core::{
         primitive::v1::asset::Id,
         protocol::test_utils::ConfigureSequencerBlock,
-        sequencerblock::v1alpha1::block::Deposit,
+        sequencerblock::v1alpha1::{
+            block::Deposit,
+            FeeSummary,
+        },
     };
     use cnidarium::StateDelta;
     use rand::Rng;
@@ -415,10 +459,15 @@ mod test {
             deposits.push(deposit);
         }
 
+        let mut fee_summary = FeeSummary::new();
+        fee_summary.add_distributed(Id::from_str_unchecked("nria"), rng.gen());
+        fee_summary.add_burned(Id::from_str_unchecked("nria"), rng.gen());
+
         ConfigureSequencerBlock {
             block_hash: Some(block_hash),
             height,
             deposits,
+            fee_summary: Some(fee_summary),
             ..Default::default()
         }
         .make()
@@ -468,6 +517,33 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn put_sequencer_block_without_fee_summary() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        // blocks before the fee summary activation height do not commit to their fee summary
+        let block = ConfigureSequencerBlock {
+            block_hash: Some([1; 32]),
+            height: 2,
+            ..Default::default()
+        }
+        .make();
+        assert!(block.fee_summary().is_none());
+        state
+            .put_sequencer_block(block.clone())
+            .expect("writing block to database should work");
+        assert_eq!(
+            state
+                .get_sequencer_block_by_height(block.height().into())
+                .await
+                .expect("a block was written to the database and should exist"),
+            block,
+            "stored block does not match expected"
+        );
+    }
+
     #[tokio::test]
     async fn put_sequencer_block_update() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
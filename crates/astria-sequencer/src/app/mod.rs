@@ -6,6 +6,8 @@ mod tests_app;
 mod tests_breaking_changes;
 #[cfg(test)]
 mod tests_execute_transaction;
+#[cfg(test)]
+mod tests_fuzz;
 
 use std::{
     collections::VecDeque,
@@ -22,12 +24,12 @@ use astria_core::{
     primitive::v1::Address,
     protocol::{
         abci::AbciErrorCode,
-        transaction::v1alpha1::{
-            Action,
-            SignedTransaction,
-        },
+        transaction::v1alpha1::SignedTransaction,
+    },
+    sequencerblock::v1alpha1::{
+        block::SequencerBlock,
+        block_commitment_count,
     },
-    sequencerblock::v1alpha1::block::SequencerBlock,
 };
 use cnidarium::{
     ArcStateDeltaExt,
@@ -36,6 +38,7 @@ use cnidarium::{
     StateDelta,
     Storage,
 };
+use ibc_types::core::channel::Packet;
 use prost::Message as _;
 use sha2::{
     Digest as _,
@@ -53,10 +56,12 @@ use tendermint::{
     AppHash,
     Hash,
 };
+use tokio::sync::broadcast;
 use tracing::{
     debug,
     info,
     instrument,
+    warn,
 };
 
 use crate::{
@@ -81,21 +86,39 @@ use crate::{
     },
     bridge::{
         component::BridgeComponent,
-        state_ext::{
-            StateReadExt as _,
-            StateWriteExt,
+        deposit_limits::{
+            get_block_deposits_within_limits,
+            BlockDeposits,
         },
+        state_ext::StateWriteExt,
     },
     component::Component as _,
+    fee_distribution::{
+        FeeDistribution,
+        FeeSplit,
+    },
     genesis::GenesisState,
-    ibc::component::IbcComponent,
+    ibc::{
+        component::IbcComponent,
+        ica_host::{
+            self,
+            PendingTx,
+        },
+        state_ext::StateWriteExt as _,
+    },
     mempool::{
         Mempool,
         RemovalReason,
     },
-    metrics::Metrics,
+    metrics::{
+        BlockActionTotals,
+        Metrics,
+    },
     proposal::{
-        block_size_constraints::BlockSizeConstraints,
+        block_size_constraints::{
+            cometbft_encoded_len,
+            BlockSizeConstraints,
+        },
         commitment::{
             generate_rollup_datas_commitment,
             GeneratedCommitments,
@@ -108,13 +131,23 @@ use crate::{
     },
     transaction::{
         self,
+        ActionNotActivated,
         InvalidNonce,
     },
+    tx_archive::{
+        archived_transactions,
+        ArchivedBlock,
+        TxArchive,
+    },
 };
 
 /// The inter-block state being written to by the application.
 type InterBlockState = Arc<StateDelta<Snapshot>>;
 
+/// The number of blocks accepted in `process_proposal` that are buffered for each subscriber of
+/// the optimistic block stream before older blocks are skipped.
+const OPTIMISTIC_BLOCK_CHANNEL_CAPACITY: usize = 16;
+
 /// The Sequencer application, written as a bundle of [`Component`]s.
 ///
 /// Note: this is called `App` because this is a Tendermint ABCI application,
@@ -154,6 +187,10 @@ pub(crate) struct App {
     // cleared at the end of each block.
     execution_results: Option<Vec<tendermint::abci::types::ExecTxResult>>,
 
+    // the events of the interchain account transactions executed at the start of the block.
+    // set in `pre_execute_transactions` and emitted in `finalize_block`.
+    interchain_account_events: Vec<Event>,
+
     // the current `StagedWriteBatch` which contains the rocksdb write batch
     // of the current block being executed, created from the state delta,
     // and set after `finalize_block`.
@@ -167,6 +204,23 @@ pub(crate) struct App {
     #[allow(clippy::struct_field_names)]
     app_hash: AppHash,
 
+    // the number of actions executed and the time spent executing them, by action type, since
+    // the execution of the current block's transactions started.
+    // reset in `pre_execute_transactions` and recorded in `finalize_block`.
+    block_action_totals: BlockActionTotals,
+
+    // the sink to which executed transactions are archived after each commit, if configured.
+    tx_archive: Option<TxArchive>,
+
+    // the transactions of the block being finalized and their results, to be archived once the
+    // block is committed.
+    // set in `finalize_block` if `tx_archive` is set, and taken in `commit`.
+    pending_archived_block: Option<ArchivedBlock>,
+
+    // the blocks accepted in `process_proposal`, sent to the subscribers of the optimistic block
+    // stream before they are committed.
+    optimistic_blocks: broadcast::Sender<Arc<SequencerBlock>>,
+
     metrics: &'static Metrics,
 }
 
@@ -174,6 +228,7 @@ impl App {
     pub(crate) async fn new(
         snapshot: Snapshot,
         mempool: Mempool,
+        tx_archive: Option<TxArchive>,
         metrics: &'static Metrics,
     ) -> anyhow::Result<Self> {
         debug!("initializing App instance");
@@ -191,18 +246,31 @@ impl App {
         // there should be no unexpected copies elsewhere.
         let state = Arc::new(StateDelta::new(snapshot));
 
+        let (optimistic_blocks, _) = broadcast::channel(OPTIMISTIC_BLOCK_CHANNEL_CAPACITY);
+
         Ok(Self {
             state,
             mempool,
             validator_address: None,
             executed_proposal_hash: Hash::default(),
             execution_results: None,
+            interchain_account_events: Vec::new(),
             write_batch: None,
             app_hash,
+            block_action_totals: BlockActionTotals::default(),
+            tx_archive,
+            pending_archived_block: None,
+            optimistic_blocks,
             metrics,
         })
     }
 
+    /// Returns the sender of the blocks accepted in `process_proposal`, which is used to subscribe
+    /// to them.
+    pub(crate) fn optimistic_block_sender(&self) -> broadcast::Sender<Arc<SequencerBlock>> {
+        self.optimistic_blocks.clone()
+    }
+
     #[instrument(name = "App:init_chain", skip_all)]
     pub(crate) async fn init_chain(
         &mut self,
@@ -219,6 +287,9 @@ impl App {
         crate::address::initialize_base_prefix(&genesis_state.address_prefixes.base)
             .context("failed setting global base prefix")?;
         state_tx.put_base_prefix(&genesis_state.address_prefixes.base);
+        for prefix in &genesis_state.address_prefixes.allowed {
+            state_tx.put_allowed_prefix(prefix);
+        }
 
         crate::asset::initialize_native_asset(&genesis_state.native_asset_base_denomination);
         state_tx.put_native_asset_denom(&genesis_state.native_asset_base_denomination);
@@ -228,6 +299,12 @@ impl App {
         for fee_asset in &genesis_state.allowed_fee_assets {
             state_tx.put_allowed_fee_asset(fee_asset.id());
         }
+        for (action, height) in &genesis_state.action_activation_heights {
+            state_tx.put_action_activation_height(action, height.get());
+        }
+        if let Some(height) = genesis_state.fee_summary_activation_height {
+            state_tx.put_fee_summary_activation_height(height.get());
+        }
 
         // call init_chain on all components
         AccountsComponent::init_chain(&mut state_tx, &genesis_state)
@@ -291,11 +368,16 @@ impl App {
         self.validator_address = Some(prepare_proposal.proposer_address);
         self.update_state_for_new_round(&storage);
 
-        let mut block_size_constraints = BlockSizeConstraints::new(
-            usize::try_from(prepare_proposal.max_tx_bytes)
-                .context("failed to convert max_tx_bytes to usize")?,
-        )
-        .context("failed to create block size constraints")?;
+        let commits_to_fee_summary = self
+            .state
+            .commits_to_fee_summary(prepare_proposal.height.value())
+            .await
+            .context("failed to determine whether the proposal commits to its fee summary")?;
+        let max_tx_bytes = usize::try_from(prepare_proposal.max_tx_bytes)
+            .context("failed to convert max_tx_bytes to usize")?;
+        let mut block_size_constraints =
+            BlockSizeConstraints::new(max_tx_bytes, block_commitment_count(commits_to_fee_summary))
+                .context("failed to create block size constraints")?;
 
         let block_data = BlockData {
             misbehavior: prepare_proposal.misbehavior,
@@ -317,19 +399,49 @@ impl App {
         self.metrics
             .record_proposal_transactions(signed_txs_included.len());
 
-        let deposits = self
-            .state
-            .get_block_deposits()
+        let deposits = get_block_deposits_within_limits(&self.state)
             .await
-            .context("failed to get block deposits in prepare_proposal")?;
+            .context("failed to get block deposits in prepare_proposal")?
+            .included;
         self.metrics.record_proposal_deposits(deposits.len());
 
-        // generate commitment to sequence::Actions and deposits and commitment to the rollup IDs
-        // included in the block
-        let res = generate_rollup_datas_commitment(&signed_txs_included, deposits);
+        let fee_summary = if commits_to_fee_summary {
+            Some(
+                self.state
+                    .get_fee_summary()
+                    .await
+                    .context("failed to get fee summary in prepare_proposal")?,
+            )
+        } else {
+            None
+        };
+
+        // generate commitment to sequence::Actions and deposits, commitment to the rollup IDs
+        // included in the block, and commitment to the fees paid in the block if past the fee
+        // summary activation height
+        let res =
+            generate_rollup_datas_commitment(&signed_txs_included, deposits, fee_summary.as_ref());
+        let txs = res.into_transactions(included_tx_bytes);
+
+        // cometbft rejects a proposal exceeding `max_tx_bytes`, so make sure that the accounting
+        // done while executing the transactions matches the proposal that is returned.
+        let proposal_size = txs
+            .iter()
+            .map(|tx| cometbft_encoded_len(tx.len()))
+            .fold(0_usize, usize::saturating_add);
+        ensure!(
+            proposal_size == block_size_constraints.cometbft_size(),
+            "proposal size of {proposal_size} bytes does not match the accounted size of {} bytes",
+            block_size_constraints.cometbft_size(),
+        );
+        ensure!(
+            proposal_size <= max_tx_bytes,
+            "proposal size of {proposal_size} bytes exceeds max_tx_bytes of {max_tx_bytes}",
+        );
+        self.metrics.record_proposal_cometbft_bytes(proposal_size);
 
         Ok(abci::response::PrepareProposal {
-            txs: res.into_transactions(included_tx_bytes),
+            txs,
         })
     }
 
@@ -352,6 +464,7 @@ impl App {
                 debug!("skipping process_proposal as we are the proposer for this block");
                 self.validator_address = None;
                 self.executed_proposal_hash = process_proposal.hash;
+                self.send_optimistic_block(&process_proposal).await;
                 return Ok(());
             }
             self.metrics.increment_process_proposal_skipped_proposal();
@@ -364,7 +477,7 @@ impl App {
 
         self.update_state_for_new_round(&storage);
 
-        let mut txs = VecDeque::from(process_proposal.txs);
+        let mut txs = VecDeque::from(process_proposal.txs.clone());
         let received_rollup_datas_root: [u8; 32] = txs
             .pop_front()
             .context("no transaction commitment in proposal")?
@@ -379,10 +492,27 @@ impl App {
             .try_into()
             .map_err(|_| anyhow!("chain IDs commitment must be 32 bytes"))?;
 
+        let commits_to_fee_summary = self
+            .state
+            .commits_to_fee_summary(process_proposal.height.value())
+            .await
+            .context("failed to determine whether the proposal commits to its fee summary")?;
+        let received_fee_summary_root: Option<[u8; 32]> = if commits_to_fee_summary {
+            Some(
+                txs.pop_front()
+                    .context("no fee summary commitment in proposal")?
+                    .to_vec()
+                    .try_into()
+                    .map_err(|_| anyhow!("fee summary commitment must be 32 bytes"))?,
+            )
+        } else {
+            None
+        };
+
         let expected_txs_len = txs.len();
 
         let block_data = BlockData {
-            misbehavior: process_proposal.misbehavior,
+            misbehavior: process_proposal.misbehavior.clone(),
             height: process_proposal.height,
             time: process_proposal.time,
             next_validators_hash: process_proposal.next_validators_hash,
@@ -397,15 +527,20 @@ impl App {
         // rejected the proposal if it was too large.
         // however, we should still validate the other constraints, namely
         // the max sequenced data bytes.
-        let mut block_size_constraints = BlockSizeConstraints::new_unlimited_cometbft();
+        let mut block_size_constraints = BlockSizeConstraints::new_unlimited_cometbft(
+            block_commitment_count(commits_to_fee_summary),
+        );
 
-        // deserialize txs into `SignedTransaction`s;
+        // deserialize txs into `SignedTransaction`s, verifying their signatures as one batch;
         // this does not error if any txs fail to be deserialized, but the `execution_results.len()`
         // check below ensures that all txs in the proposal are deserializable (and
         // executable).
-        let signed_txs = txs
+        let raw_txs = txs
             .into_iter()
-            .filter_map(|bytes| signed_transaction_from_bytes(bytes.as_ref()).ok())
+            .filter_map(|bytes| raw::SignedTransaction::decode(bytes.as_ref()).ok());
+        let signed_txs = SignedTransaction::try_from_raw_batch(raw_txs)
+            .into_iter()
+            .filter_map(Result::ok)
             .collect::<Vec<_>>();
 
         self.execute_transactions_process_proposal(signed_txs.clone(), &mut block_size_constraints)
@@ -426,17 +561,28 @@ impl App {
         );
         self.metrics.record_proposal_transactions(signed_txs.len());
 
-        let deposits = self
-            .state
-            .get_block_deposits()
+        let deposits = get_block_deposits_within_limits(&self.state)
             .await
-            .context("failed to get block deposits in process_proposal")?;
+            .context("failed to get block deposits in process_proposal")?
+            .included;
         self.metrics.record_proposal_deposits(deposits.len());
 
+        let fee_summary = if commits_to_fee_summary {
+            Some(
+                self.state
+                    .get_fee_summary()
+                    .await
+                    .context("failed to get fee summary in process_proposal")?,
+            )
+        } else {
+            None
+        };
+
         let GeneratedCommitments {
             rollup_datas_root: expected_rollup_datas_root,
             rollup_ids_root: expected_rollup_ids_root,
-        } = generate_rollup_datas_commitment(&signed_txs, deposits);
+            fee_summary_root: expected_fee_summary_root,
+        } = generate_rollup_datas_commitment(&signed_txs, deposits, fee_summary.as_ref());
         ensure!(
             received_rollup_datas_root == expected_rollup_datas_root,
             "transaction commitment does not match expected",
@@ -447,11 +593,82 @@ impl App {
             "chain IDs commitment does not match expected",
         );
 
+        ensure!(
+            received_fee_summary_root == expected_fee_summary_root,
+            "fee summary commitment does not match expected",
+        );
+
         self.executed_proposal_hash = process_proposal.hash;
+        self.send_optimistic_block(&process_proposal).await;
 
         Ok(())
     }
 
+    /// Sends the block accepted in `process_proposal` to the subscribers of the optimistic block
+    /// stream, if there are any.
+    ///
+    /// Failing to construct the block is only logged, as serving the stream must not affect
+    /// consensus.
+    async fn send_optimistic_block(&self, process_proposal: &abci::request::ProcessProposal) {
+        if self.optimistic_blocks.receiver_count() == 0 {
+            return;
+        }
+        match self.construct_optimistic_block(process_proposal).await {
+            // an error means that all subscribers disconnected since checking for them above
+            Ok(block) => {
+                let _ = self.optimistic_blocks.send(Arc::new(block));
+            }
+            Err(e) => warn!(
+                error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                "failed to construct optimistic block from proposal; not sending it to subscribers",
+            ),
+        }
+    }
+
+    async fn construct_optimistic_block(
+        &self,
+        process_proposal: &abci::request::ProcessProposal,
+    ) -> anyhow::Result<SequencerBlock> {
+        let Hash::Sha256(block_hash) = process_proposal.hash else {
+            anyhow::bail!("proposed block hash is empty")
+        };
+        let chain_id = self
+            .state
+            .get_chain_id()
+            .await
+            .context("failed to get chain ID from state")?;
+        let deposits = get_block_deposits_within_limits(&self.state)
+            .await
+            .context("failed to get block deposits")?
+            .included;
+        let fee_summary = if self
+            .state
+            .commits_to_fee_summary(process_proposal.height.value())
+            .await
+            .context("failed to determine whether the proposal commits to its fee summary")?
+        {
+            Some(
+                self.state
+                    .get_fee_summary()
+                    .await
+                    .context("failed to get fee summary")?,
+            )
+        } else {
+            None
+        };
+        SequencerBlock::try_from_block_info_and_data(
+            block_hash,
+            chain_id,
+            process_proposal.height,
+            process_proposal.time,
+            process_proposal.proposer_address,
+            process_proposal.txs.iter().map(|tx| tx.to_vec()).collect(),
+            deposits,
+            fee_summary,
+        )
+        .context("failed to convert proposal to SequencerBlock")
+    }
+
     /// Executes transactions from the app's mempool until the block is full,
     /// writing to the app's `StateDelta`.
     ///
@@ -482,9 +699,14 @@ impl App {
         let mut validated_txs: Vec<bytes::Bytes> = Vec::new();
         let mut included_signed_txs = Vec::new();
         let mut failed_tx_count: usize = 0;
+        let mut removed_tx_count: usize = 0;
         let mut execution_results = Vec::new();
         let mut txs_to_readd_to_mempool = Vec::new();
 
+        // transactions are taken from the mempool in priority order until the first one that
+        // doesn't fit into the cometbft block, at which point the block is truncated. this keeps
+        // the proposal deterministic for a given mempool, rather than filling the remaining space
+        // with whichever later transactions happen to be small enough.
         while let Some((enqueued_tx, priority)) = self.mempool.pop().await {
             let tx_hash_base64 = telemetry::display::base64(&enqueued_tx.tx_hash()).to_string();
             let tx = enqueued_tx.signed_tx();
@@ -500,6 +722,7 @@ impl App {
                     transaction_hash = %tx_hash_base64,
                     block_size_constraints = %json(&block_size_constraints),
                     tx_data_bytes = tx_len,
+                    tx_encoded_bytes = cometbft_encoded_len(tx_len),
                     "excluding remaining transactions: max cometBFT data limit reached"
                 );
                 txs_to_readd_to_mempool.push((enqueued_tx, priority));
@@ -509,14 +732,11 @@ impl App {
             }
 
             // check if tx's sequence data will fit into sequence block
-            let tx_sequence_data_bytes = tx
-                .unsigned_transaction()
-                .actions
-                .iter()
-                .filter_map(Action::as_sequence)
-                .fold(0usize, |acc, seq| acc.saturating_add(seq.data.len()));
+            let tx_sequence_data_bytes = tx.unsigned_transaction().sequence_data_size();
 
             if !block_size_constraints.sequencer_has_space(tx_sequence_data_bytes) {
+                self.metrics
+                    .increment_prepare_proposal_excluded_transactions_sequencer_space();
                 debug!(
                     transaction_hash = %tx_hash_base64,
                     block_size_constraints = %json(&block_size_constraints),
@@ -563,6 +783,7 @@ impl App {
                         txs_to_readd_to_mempool.push((enqueued_tx, priority));
                     } else {
                         // the transaction should be removed from the cometbft mempool
+                        removed_tx_count = removed_tx_count.saturating_add(1);
                         self.mempool
                             .track_removal_comet_bft(
                                 enqueued_tx.tx_hash(),
@@ -582,9 +803,20 @@ impl App {
             );
         }
 
+        let readded_tx_count = txs_to_readd_to_mempool.len();
+        let excluded_tx_count = readded_tx_count.saturating_add(removed_tx_count);
+        // allow: precision loss is unlikely (values too small) but also unimportant in gauges.
+        #[allow(clippy::cast_precision_loss)]
+        self.metrics
+            .set_prepare_proposal_excluded_transactions(excluded_tx_count as f64);
         self.mempool.insert_all(txs_to_readd_to_mempool).await;
         let mempool_len = self.mempool.len().await;
-        debug!(mempool_len, "finished executing transactions from mempool");
+        debug!(
+            mempool_len,
+            readded_tx_count,
+            cometbft_block_bytes = block_size_constraints.cometbft_size(),
+            "finished executing transactions from mempool"
+        );
 
         self.execution_results = Some(execution_results);
         Ok((validated_txs, included_signed_txs))
@@ -620,12 +852,7 @@ impl App {
             let tx_len = bytes.len();
 
             // check if tx's sequence data will fit into sequence block
-            let tx_sequence_data_bytes = tx
-                .unsigned_transaction()
-                .actions
-                .iter()
-                .filter_map(Action::as_sequence)
-                .fold(0usize, |acc, seq| acc.saturating_add(seq.data.len()));
+            let tx_sequence_data_bytes = tx.unsigned_transaction().sequence_data_size();
 
             if !block_size_constraints.sequencer_has_space(tx_sequence_data_bytes) {
                 self.metrics
@@ -682,12 +909,15 @@ impl App {
     }
 
     /// sets up the state for execution of the block's transactions.
-    /// set the current height and timestamp, and calls `begin_block` on all components.
+    /// set the current height and timestamp, calls `begin_block` on all components, and executes
+    /// the interchain account transactions received in the previous block.
     ///
     /// this *must* be called anytime before a block's txs are executed, whether it's
     /// during the proposal phase, or finalize_block phase.
     #[instrument(name = "App::pre_execute_transactions", skip_all)]
     async fn pre_execute_transactions(&mut self, block_data: BlockData) -> anyhow::Result<()> {
+        self.block_action_totals = BlockActionTotals::default();
+
         let chain_id = self
             .state
             .get_chain_id()
@@ -731,6 +961,11 @@ impl App {
             .await
             .context("failed to call begin_block")?;
 
+        self.interchain_account_events = self
+            .execute_interchain_account_txs()
+            .await
+            .context("failed to execute interchain account transactions")?;
+
         Ok(())
     }
 
@@ -751,11 +986,6 @@ impl App {
             .get_chain_id()
             .await
             .context("failed to get chain ID from state")?;
-        let sudo_address = self
-            .state
-            .get_sudo_address()
-            .await
-            .context("failed to get sudo address from state")?;
 
         // convert tendermint id to astria address; this assumes they are
         // the same address, as they are both ed25519 keys
@@ -773,16 +1003,23 @@ impl App {
             self.update_state_for_new_round(&storage);
         }
 
+        let commits_to_fee_summary = self
+            .state
+            .commits_to_fee_summary(height.value())
+            .await
+            .context("failed to determine whether the block commits to its fee summary")?;
+        let commitment_count = block_commitment_count(commits_to_fee_summary);
         ensure!(
-            finalize_block.txs.len() >= 2,
-            "block must contain at least two transactions: the rollup transactions commitment and
-             rollup IDs commitment"
+            finalize_block.txs.len() >= commitment_count,
+            "block must contain at least {commitment_count} transactions: the rollup transactions \
+             commitment, the rollup IDs commitment and, from the fee summary activation height, \
+             the fee summary commitment"
         );
 
         // cometbft expects a result for every tx in the block, so we need to return a
         // tx result for the commitments, even though they're not actually user txs.
         let mut tx_results: Vec<ExecTxResult> = Vec::with_capacity(finalize_block.txs.len());
-        tx_results.extend(std::iter::repeat(ExecTxResult::default()).take(2));
+        tx_results.extend(std::iter::repeat(ExecTxResult::default()).take(commitment_count));
 
         // When the hash is not empty, we have already executed and cached the results
         if self.executed_proposal_hash.is_empty() {
@@ -799,8 +1036,8 @@ impl App {
                 .await
                 .context("failed to execute block")?;
 
-            // skip the first two transactions, as they are the rollup data commitments
-            for tx in finalize_block.txs.iter().skip(2) {
+            // skip the block data commitments at the start of the block data
+            for tx in finalize_block.txs.iter().skip(commitment_count) {
                 // remove any included txs from the mempool
                 let tx_hash = Sha256::digest(tx).into();
                 self.mempool.remove(tx_hash).await;
@@ -821,6 +1058,8 @@ impl App {
                         );
                         let code = if e.downcast_ref::<InvalidNonce>().is_some() {
                             AbciErrorCode::INVALID_NONCE
+                        } else if e.downcast_ref::<ActionNotActivated>().is_some() {
+                            AbciErrorCode::ACTION_NOT_ACTIVATED
                         } else {
                             AbciErrorCode::INTERNAL_ERROR
                         };
@@ -840,20 +1079,48 @@ impl App {
             );
             tx_results.extend(execution_results);
         };
+        self.metrics
+            .record_block_action_totals(&self.block_action_totals);
+
+        let transactions_to_archive = self
+            .tx_archive
+            .is_some()
+            .then(|| archived_transactions(&finalize_block.txs, &tx_results, commitment_count));
+
+        // the fees are distributed and cleared in `end_block`, so read them beforehand
+        let fee_summary = if commits_to_fee_summary {
+            Some(
+                self.state
+                    .get_fee_summary()
+                    .await
+                    .context("failed to get fee summary")?,
+            )
+        } else {
+            None
+        };
 
-        let end_block = self.end_block(height.value(), sudo_address).await?;
+        let proposer_astria_address =
+            crate::address::try_base_prefixed(proposer_address.as_bytes())
+                .context("failed to convert proposer address to an astria address")?;
+        let end_block = self
+            .end_block(height.value(), proposer_astria_address)
+            .await?;
 
         // get and clear block deposits from state
         let mut state_tx = StateDelta::new(self.state.clone());
-        let deposits = self
-            .state
-            .get_block_deposits()
+        let BlockDeposits {
+            included: deposits,
+            queued: queued_deposits,
+        } = get_block_deposits_within_limits(&self.state)
             .await
             .context("failed to get block deposits in end_block")?;
         state_tx
             .clear_block_deposits()
             .await
             .context("failed to clear block deposits")?;
+        for (rollup_id, queued_deposits) in queued_deposits {
+            state_tx.put_queued_deposits(&rollup_id, queued_deposits);
+        }
         debug!(
             deposits = %telemetry::display::json(&deposits),
             "got block deposits from state"
@@ -871,6 +1138,7 @@ impl App {
                 .map(std::convert::Into::into)
                 .collect(),
             deposits,
+            fee_summary,
         )
         .context("failed to convert block info and data to SequencerBlock")?;
         state_tx
@@ -886,13 +1154,23 @@ impl App {
             .await
             .context("failed to prepare commit")?;
 
+        self.pending_archived_block = transactions_to_archive.map(|transactions| ArchivedBlock {
+            height: height.value(),
+            block_hash: hex::encode(block_hash),
+            time: time.to_rfc3339(),
+            app_hash: hex::encode(app_hash.as_bytes()),
+            transactions,
+        });
+
         // update the priority of any txs in the mempool based on the updated app state
         update_mempool_after_finalization(&mut self.mempool, self.state.clone())
             .await
             .context("failed to update mempool after finalization")?;
 
+        let mut events = std::mem::take(&mut self.interchain_account_events);
+        events.extend(end_block.events);
         Ok(abci::response::FinalizeBlock {
-            events: end_block.events,
+            events,
             validator_updates: end_block.validator_updates,
             consensus_param_updates: end_block.consensus_param_updates,
             tx_results,
@@ -978,13 +1256,15 @@ impl App {
         &mut self,
         signed_tx: Arc<SignedTransaction>,
     ) -> anyhow::Result<Vec<Event>> {
+        let metrics = self.metrics;
         let signed_tx_2 = signed_tx.clone();
         let stateless =
-            tokio::spawn(async move { transaction::check_stateless(&signed_tx_2).await });
+            tokio::spawn(async move { transaction::check_stateless(&signed_tx_2, metrics).await });
         let signed_tx_2 = signed_tx.clone();
         let state2 = self.state.clone();
-        let stateful =
-            tokio::spawn(async move { transaction::check_stateful(&signed_tx_2, &state2).await });
+        let stateful = tokio::spawn(async move {
+            transaction::check_stateful(&signed_tx_2, &state2, metrics).await
+        });
 
         stateless
             .await
@@ -1001,20 +1281,97 @@ impl App {
             .try_begin_transaction()
             .expect("state Arc should be present and unique");
 
-        transaction::execute(&signed_tx, &mut state_tx)
-            .await
-            .context("failed executing transaction")?;
+        // only count the actions of transactions that executed successfully towards the block
+        // totals, as the state changes of failed transactions are discarded.
+        let mut tx_action_totals = BlockActionTotals::default();
+        transaction::execute(
+            &signed_tx,
+            &mut state_tx,
+            self.metrics,
+            &mut tx_action_totals,
+        )
+        .await
+        .context("failed executing transaction")?;
         let (_, events) = state_tx.apply();
+        self.block_action_totals.merge(tx_action_totals);
 
         info!(event_count = events.len(), "executed transaction");
         Ok(events)
     }
 
+    /// Executes the interchain account transactions received in the previous block and writes
+    /// their acknowledgements.
+    ///
+    /// This runs before the signed transactions of the block, so that the deposits and fees of
+    /// the interchain account transactions are part of the block's commitments.
+    ///
+    /// As for signed transactions, the stateful checks of all messages of a transaction run
+    /// before any of them is executed, and the state changes of a transaction are discarded if
+    /// any of its messages fails. Failed transactions are acknowledged with an error.
+    #[instrument(name = "App::execute_interchain_account_txs", skip_all)]
+    async fn execute_interchain_account_txs(&mut self) -> anyhow::Result<Vec<Event>> {
+        let mut state_tx = self
+            .state
+            .try_begin_transaction()
+            .expect("state Arc should be present and unique");
+        let packets = state_tx
+            .take_pending_interchain_account_packets()
+            .await
+            .context("failed to get pending interchain account packets")?;
+        state_tx.apply();
+
+        let mut events = vec![];
+        for packet in packets {
+            let result = match self.execute_interchain_account_tx(&packet).await {
+                Ok((tx, tx_events)) => {
+                    events.extend(tx_events);
+                    Ok(tx)
+                }
+                Err(e) => {
+                    debug!(
+                        channel = %packet.chan_on_b,
+                        sequence = %packet.sequence,
+                        error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                        "failed to execute interchain account transaction"
+                    );
+                    Err(e)
+                }
+            };
+
+            let mut state_tx = self
+                .state
+                .try_begin_transaction()
+                .expect("state Arc should be present and unique");
+            ica_host::write_acknowledgement(&mut state_tx, &packet, &result).await?;
+            events.extend(state_tx.apply().1);
+        }
+        Ok(events)
+    }
+
+    /// Executes the interchain account transaction received in `packet`, returning the
+    /// transaction and the events of executing it.
+    ///
+    /// The state changes are only applied if the transaction executed successfully.
+    async fn execute_interchain_account_tx(
+        &mut self,
+        packet: &Packet,
+    ) -> anyhow::Result<(PendingTx, Vec<Event>)> {
+        let tx = ica_host::pending_tx(&self.state, packet).await?;
+        ica_host::check_stateful(&tx, &self.state).await?;
+        let mut state_tx = self
+            .state
+            .try_begin_transaction()
+            .expect("state Arc should be present and unique");
+        ica_host::execute(&tx, &mut state_tx).await?;
+        let (_, events) = state_tx.apply();
+        Ok((tx, events))
+    }
+
     #[instrument(name = "App::end_block", skip_all)]
     pub(crate) async fn end_block(
         &mut self,
         height: u64,
-        fee_recipient: Address,
+        proposer_address: Address,
     ) -> anyhow::Result<abci::response::EndBlock> {
         let state_tx = StateDelta::new(self.state.clone());
         let mut arc_state_tx = Arc::new(state_tx);
@@ -1055,7 +1412,22 @@ impl App {
         // clear validator updates
         state_tx.clear_validator_updates();
 
-        // gather block fees and transfer them to the block proposer
+        // gather block fees and distribute them; the burn share is not paid to anyone
+        let fee_distribution = match self
+            .state
+            .get_fee_distribution()
+            .await
+            .context("failed to get fee distribution")?
+        {
+            Some(fee_distribution) => fee_distribution,
+            // without a fee distribution all fees are paid to the sudo address
+            None => FeeDistribution::all_to_treasury(
+                self.state
+                    .get_sudo_address()
+                    .await
+                    .context("failed to get sudo address from state")?,
+            ),
+        };
         let fees = self
             .state
             .get_block_fees()
@@ -1063,14 +1435,31 @@ impl App {
             .context("failed to get block fees")?;
 
         for (asset, amount) in fees {
-            state_tx
-                .increase_balance(fee_recipient, asset, amount)
-                .await
-                .context("failed to increase fee recipient balance")?;
+            let FeeSplit {
+                proposer,
+                treasury,
+                burned: _,
+            } = fee_distribution.split(amount);
+            if proposer > 0 {
+                state_tx
+                    .increase_balance(proposer_address, asset, proposer)
+                    .await
+                    .context("failed to increase proposer balance")?;
+            }
+            if treasury > 0 {
+                let treasury_address = fee_distribution
+                    .treasury_address()
+                    .expect("a treasury address is set if the treasury share is not zero");
+                state_tx
+                    .increase_balance(treasury_address, asset, treasury)
+                    .await
+                    .context("failed to increase treasury balance")?;
+            }
         }
 
         // clear block fees
         state_tx.clear_block_fees().await;
+        state_tx.clear_burned_fees().await;
 
         let events = self.apply(state_tx);
         Ok(abci::response::EndBlock {
@@ -1100,6 +1489,12 @@ impl App {
 
         // Get the latest version of the state, now that we've committed it.
         self.state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+
+        if let (Some(tx_archive), Some(block)) =
+            (&self.tx_archive, self.pending_archived_block.take())
+        {
+            tx_archive.send(block);
+        }
     }
 
     // StateDelta::apply only works when the StateDelta wraps an underlying
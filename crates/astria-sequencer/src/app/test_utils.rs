@@ -1,3 +1,8 @@
+use std::{
+    collections::BTreeMap,
+    num::NonZeroU64,
+};
+
 use astria_core::{
     crypto::SigningKey,
     primitive::v1::{
@@ -15,8 +20,12 @@ use astria_core::{
         TransactionParams,
         UnsignedTransaction,
     },
+    sequencerblock::v1alpha1::FeeSummary,
+};
+use cnidarium::{
+    StateDelta,
+    Storage,
 };
-use cnidarium::Storage;
 use penumbra_ibc::params::IBCParameters;
 
 use crate::{
@@ -29,7 +38,10 @@ use crate::{
         UncheckedGenesisState,
     },
     mempool::Mempool,
-    metrics::Metrics,
+    metrics::{
+        BlockActionTotals,
+        Metrics,
+    },
 };
 
 // attempts to decode the given hex string into an address.
@@ -95,6 +107,7 @@ pub(crate) fn default_fees() -> genesis::Fees {
         bridge_lock_byte_cost_multiplier: 1,
         bridge_sudo_change_fee: 24,
         ics20_withdrawal_base_fee: 24,
+        ics20_forward_fee_basis_points: 0,
     }
 }
 
@@ -103,6 +116,7 @@ pub(crate) fn unchecked_genesis_state() -> UncheckedGenesisState {
         accounts: default_genesis_accounts(),
         address_prefixes: AddressPrefixes {
             base: crate::address::get_base_prefix().to_string(),
+            allowed: vec![],
         },
         authority_sudo_address: address_from_hex_string(JUDY_ADDRESS),
         ibc_sudo_address: address_from_hex_string(TED_ADDRESS),
@@ -111,6 +125,11 @@ pub(crate) fn unchecked_genesis_state() -> UncheckedGenesisState {
         ibc_params: IBCParameters::default(),
         allowed_fee_assets: vec![default_native_asset()],
         fees: default_fees(),
+        ica_host: None,
+        bridge_recovery_delay: None,
+        deposit_limits: None,
+        action_activation_heights: BTreeMap::new(),
+        fee_summary_activation_height: NonZeroU64::new(1),
     }
 }
 
@@ -128,7 +147,7 @@ pub(crate) async fn initialize_app_with_storage(
     let snapshot = storage.latest_snapshot();
     let mempool = Mempool::new();
     let metrics = Box::leak(Box::new(Metrics::new()));
-    let mut app = App::new(snapshot, mempool, metrics).await.unwrap();
+    let mut app = App::new(snapshot, mempool, None, metrics).await.unwrap();
 
     let genesis_state = genesis_state.unwrap_or_else(self::genesis_state);
 
@@ -172,3 +191,22 @@ pub(crate) fn get_mock_tx(nonce: u32) -> SignedTransaction {
 
     tx.into_signed(&alice_signing_key)
 }
+
+/// Returns the fee summary of a block containing `txs`, found by executing them against a copy
+/// of the app's state.
+pub(crate) async fn fee_summary_of_txs(app: &App, txs: &[SignedTransaction]) -> FeeSummary {
+    use crate::state_ext::StateReadExt as _;
+
+    let mut state_tx = StateDelta::new(app.state.clone());
+    for tx in txs {
+        crate::transaction::execute(
+            tx,
+            &mut state_tx,
+            app.metrics,
+            &mut BlockActionTotals::default(),
+        )
+        .await
+        .unwrap();
+    }
+    state_tx.get_fee_summary().await.unwrap()
+}
@@ -14,7 +14,10 @@ use astria_core::{
         TransactionParams,
         UnsignedTransaction,
     },
-    sequencerblock::v1alpha1::block::Deposit,
+    sequencerblock::v1alpha1::{
+        block::Deposit,
+        FeeSummary,
+    },
 };
 use cnidarium::StateDelta;
 use prost::Message as _;
@@ -255,7 +258,9 @@ async fn app_transfer_block_fees_to_sudo() {
 
     let proposer_address: tendermint::account::Id = [99u8; 20].to_vec().try_into().unwrap();
 
-    let commitments = generate_rollup_datas_commitment(&[signed_tx.clone()], HashMap::new());
+    let fee_summary = fee_summary_of_txs(&app, &[signed_tx.clone()]).await;
+    let commitments =
+        generate_rollup_datas_commitment(&[signed_tx.clone()], HashMap::new(), Some(&fee_summary));
 
     let finalize_block = abci::request::FinalizeBlock {
         hash: Hash::try_from([0u8; 32].to_vec()).unwrap(),
@@ -287,6 +292,97 @@ async fn app_transfer_block_fees_to_sudo() {
     assert_eq!(app.state.get_block_fees().await.unwrap().len(), 0);
 }
 
+#[tokio::test]
+async fn app_distribute_block_fees_per_fee_distribution() {
+    use crate::{
+        fee_distribution::FeeDistribution,
+        state_ext::StateWriteExt as _,
+    };
+
+    let (mut app, storage) = initialize_app_with_storage(None, vec![]).await;
+
+    let (alice_signing_key, _) = get_alice_signing_key_and_address();
+    let native_asset = get_native_asset().id();
+    let treasury_address = address_from_hex_string(CAROL_ADDRESS);
+    let fee_distribution = FeeDistribution::try_new(50, 30, 20, Some(treasury_address)).unwrap();
+
+    let mut state_tx = StateDelta::new(app.state.clone());
+    state_tx.put_fee_distribution(&fee_distribution);
+    app.apply(state_tx);
+    let treasury_balance = app
+        .state
+        .get_account_balance(treasury_address, native_asset)
+        .await
+        .unwrap();
+
+    let tx = UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(0)
+            .chain_id("test")
+            .build(),
+        actions: vec![
+            TransferAction {
+                to: address_from_hex_string(BOB_ADDRESS),
+                amount: 333_333,
+                asset_id: native_asset,
+                fee_asset_id: native_asset,
+            }
+            .into(),
+        ],
+    };
+    let signed_tx = tx.into_signed(&alice_signing_key);
+
+    let fee_summary = fee_summary_of_txs(&app, &[signed_tx.clone()]).await;
+    let commitments =
+        generate_rollup_datas_commitment(&[signed_tx.clone()], HashMap::new(), Some(&fee_summary));
+
+    let finalize_block = abci::request::FinalizeBlock {
+        hash: Hash::try_from([0u8; 32].to_vec()).unwrap(),
+        height: 1u32.into(),
+        time: Time::now(),
+        next_validators_hash: Hash::default(),
+        proposer_address: account::Id::new([99u8; 20]),
+        txs: commitments.into_transactions(vec![signed_tx.to_raw().encode_to_vec().into()]),
+        decided_last_commit: CommitInfo {
+            votes: vec![],
+            round: Round::default(),
+        },
+        misbehavior: vec![],
+    };
+    app.finalize_block(finalize_block, storage.clone())
+        .await
+        .unwrap();
+    app.commit(storage).await;
+
+    let transfer_fee = app.state.get_transfer_base_fee().await.unwrap();
+    let split = fee_distribution.split(transfer_fee);
+    assert_eq!(
+        app.state
+            .get_account_balance(crate::address::base_prefixed([99u8; 20]), native_asset)
+            .await
+            .unwrap(),
+        split.proposer,
+    );
+    assert_eq!(
+        app.state
+            .get_account_balance(treasury_address, native_asset)
+            .await
+            .unwrap(),
+        treasury_balance + split.treasury,
+    );
+    // the sudo address no longer receives the fees
+    assert_eq!(
+        app.state
+            .get_account_balance(address_from_hex_string(JUDY_ADDRESS), native_asset)
+            .await
+            .unwrap(),
+        0,
+    );
+    let fees = fee_summary.get(&native_asset).unwrap();
+    assert_eq!(fees.burned(), split.burned);
+    assert_eq!(fees.distributed(), split.proposer + split.treasury);
+}
+
 #[tokio::test]
 async fn app_create_sequencer_block_with_sequenced_data_and_deposits() {
     use astria_core::{
@@ -343,7 +439,12 @@ async fn app_create_sequencer_block_with_sequenced_data_and_deposits() {
         "nootwashere".to_string(),
     );
     let deposits = HashMap::from_iter(vec![(rollup_id, vec![expected_deposit.clone()])]);
-    let commitments = generate_rollup_datas_commitment(&[signed_tx.clone()], deposits.clone());
+    let fee_summary = fee_summary_of_txs(&app, &[signed_tx.clone()]).await;
+    let commitments = generate_rollup_datas_commitment(
+        &[signed_tx.clone()],
+        deposits.clone(),
+        Some(&fee_summary),
+    );
 
     let finalize_block = abci::request::FinalizeBlock {
         hash: Hash::try_from([0u8; 32].to_vec()).unwrap(),
@@ -382,6 +483,467 @@ async fn app_create_sequencer_block_with_sequenced_data_and_deposits() {
     assert_eq!(deposits[0], expected_deposit);
 }
 
+#[tokio::test]
+async fn app_queues_deposits_exceeding_deposit_limits() {
+    use std::num::{
+        NonZeroU32,
+        NonZeroU64,
+    };
+
+    use astria_core::{
+        generated::sequencerblock::v1alpha1::RollupData as RawRollupData,
+        sequencerblock::v1alpha1::block::RollupData,
+    };
+
+    use crate::{
+        api_state_ext::StateReadExt as _,
+        bridge::DepositLimits,
+    };
+
+    async fn block_deposits(app: &App, height: u64) -> Vec<Deposit> {
+        let block = app
+            .state
+            .get_sequencer_block_by_height(height)
+            .await
+            .unwrap();
+        let mut deposits = vec![];
+        for (_, rollup_data) in block.rollup_transactions() {
+            for tx in rollup_data.transactions() {
+                let rollup_data =
+                    RollupData::try_from_raw(RawRollupData::decode(tx.as_slice()).unwrap())
+                        .unwrap();
+                if let RollupData::Deposit(deposit) = rollup_data {
+                    deposits.push(deposit);
+                }
+            }
+        }
+        deposits
+    }
+
+    fn finalize_block(height: u32, txs: Vec<bytes::Bytes>) -> abci::request::FinalizeBlock {
+        abci::request::FinalizeBlock {
+            hash: Hash::try_from([0u8; 32].to_vec()).unwrap(),
+            height: height.into(),
+            time: Time::now(),
+            next_validators_hash: Hash::default(),
+            proposer_address: [0u8; 20].to_vec().try_into().unwrap(),
+            txs,
+            decided_last_commit: CommitInfo {
+                votes: vec![],
+                round: Round::default(),
+            },
+            misbehavior: vec![],
+        }
+    }
+
+    let mut genesis_state = unchecked_genesis_state();
+    genesis_state.deposit_limits = Some(DepositLimits {
+        max_deposits_per_rollup: NonZeroU32::new(1).unwrap(),
+        max_deposit_bytes_per_rollup: NonZeroU64::new(u64::MAX).unwrap(),
+    });
+    let (alice_signing_key, _) = get_alice_signing_key_and_address();
+    let (mut app, storage) =
+        initialize_app_with_storage(Some(genesis_state.try_into().unwrap()), vec![]).await;
+
+    let bridge_address = crate::address::base_prefixed([99; 20]);
+    let rollup_id = RollupId::from_unhashed_bytes(b"testchainid");
+    let asset_id = get_native_asset().id();
+
+    let mut state_tx = StateDelta::new(app.state.clone());
+    state_tx.put_bridge_account_rollup_id(&bridge_address, &rollup_id);
+    state_tx
+        .put_bridge_account_asset_id(&bridge_address, &asset_id)
+        .unwrap();
+    app.apply(state_tx);
+    app.prepare_commit(storage.clone()).await.unwrap();
+    app.commit(storage.clone()).await;
+
+    let lock_action = |amount| BridgeLockAction {
+        to: bridge_address,
+        amount,
+        asset_id,
+        fee_asset_id: asset_id,
+        destination_chain_address: "nootwashere".to_string(),
+    };
+    let tx = UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(0)
+            .chain_id("test")
+            .build(),
+        actions: vec![lock_action(100).into(), lock_action(200).into()],
+    };
+    let signed_tx = tx.into_signed(&alice_signing_key);
+
+    let first_deposit = Deposit::new(
+        bridge_address,
+        rollup_id,
+        100,
+        asset_id,
+        "nootwashere".to_string(),
+    );
+    let second_deposit = Deposit::new(
+        bridge_address,
+        rollup_id,
+        200,
+        asset_id,
+        "nootwashere".to_string(),
+    );
+
+    // only the first deposit fits into the first block
+    let deposits = HashMap::from_iter(vec![(rollup_id, vec![first_deposit.clone()])]);
+    let fee_summary = fee_summary_of_txs(&app, &[signed_tx.clone()]).await;
+    let commitments =
+        generate_rollup_datas_commitment(&[signed_tx.clone()], deposits, Some(&fee_summary));
+    app.finalize_block(
+        finalize_block(
+            1,
+            commitments.into_transactions(vec![signed_tx.to_raw().encode_to_vec().into()]),
+        ),
+        storage.clone(),
+    )
+    .await
+    .unwrap();
+    app.commit(storage.clone()).await;
+    assert_eq!(block_deposits(&app, 1).await, vec![first_deposit]);
+
+    // the second deposit is queued and included in the next block
+    let deposits = HashMap::from_iter(vec![(rollup_id, vec![second_deposit.clone()])]);
+    let commitments = generate_rollup_datas_commitment(&[], deposits, Some(&FeeSummary::new()));
+    app.finalize_block(
+        finalize_block(2, commitments.into_transactions(vec![])),
+        storage.clone(),
+    )
+    .await
+    .unwrap();
+    app.commit(storage).await;
+    assert_eq!(block_deposits(&app, 2).await, vec![second_deposit]);
+    assert!(app.state.get_queued_deposits().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn app_commits_to_fee_summary_from_activation_height() {
+    use std::num::NonZeroU64;
+
+    use crate::api_state_ext::StateReadExt as _;
+
+    fn finalize_block(height: u32, txs: Vec<bytes::Bytes>) -> abci::request::FinalizeBlock {
+        abci::request::FinalizeBlock {
+            hash: Hash::try_from([height.to_le_bytes()[0]; 32].to_vec()).unwrap(),
+            height: height.into(),
+            time: Time::now(),
+            next_validators_hash: Hash::default(),
+            proposer_address: [0u8; 20].to_vec().try_into().unwrap(),
+            txs,
+            decided_last_commit: CommitInfo {
+                votes: vec![],
+                round: Round::default(),
+            },
+            misbehavior: vec![],
+        }
+    }
+
+    let mut genesis_state = unchecked_genesis_state();
+    genesis_state.fee_summary_activation_height = NonZeroU64::new(2);
+    let (mut app, storage) =
+        initialize_app_with_storage(Some(genesis_state.try_into().unwrap()), vec![]).await;
+
+    // blocks before the activation height only commit to the rollup data and rollup IDs
+    let prepare_args = abci::request::PrepareProposal {
+        max_tx_bytes: 200_000,
+        txs: vec![],
+        local_last_commit: None,
+        misbehavior: vec![],
+        height: 1u32.into(),
+        time: Time::now(),
+        next_validators_hash: Hash::default(),
+        proposer_address: account::Id::new([1u8; 20]),
+    };
+    let result = app
+        .prepare_proposal(prepare_args, storage.clone())
+        .await
+        .unwrap();
+    assert_eq!(2, result.txs.len());
+
+    let signed_tx = get_mock_tx(0);
+    let commitments = generate_rollup_datas_commitment(&[signed_tx.clone()], HashMap::new(), None);
+    let response = app
+        .finalize_block(
+            finalize_block(
+                1,
+                commitments.into_transactions(vec![signed_tx.to_raw().encode_to_vec().into()]),
+            ),
+            storage.clone(),
+        )
+        .await
+        .unwrap();
+    app.commit(storage.clone()).await;
+    assert_eq!(3, response.tx_results.len());
+    assert!(response.tx_results[2].code.is_ok());
+    let block = app.state.get_sequencer_block_by_height(1).await.unwrap();
+    assert!(block.fee_summary().is_none());
+
+    // blocks from the activation height also commit to their fee summary
+    let signed_tx = get_mock_tx(1);
+    let fee_summary = fee_summary_of_txs(&app, &[signed_tx.clone()]).await;
+    let commitments =
+        generate_rollup_datas_commitment(&[signed_tx.clone()], HashMap::new(), Some(&fee_summary));
+    let response = app
+        .finalize_block(
+            finalize_block(
+                2,
+                commitments.into_transactions(vec![signed_tx.to_raw().encode_to_vec().into()]),
+            ),
+            storage.clone(),
+        )
+        .await
+        .unwrap();
+    app.commit(storage).await;
+    assert_eq!(4, response.tx_results.len());
+    assert!(response.tx_results[3].code.is_ok());
+    let block = app.state.get_sequencer_block_by_height(2).await.unwrap();
+    assert_eq!(Some(&fee_summary), block.fee_summary());
+}
+
+// it's a test, so allow a lot of lines
+#[tokio::test]
+#[allow(clippy::too_many_lines)]
+async fn app_executes_interchain_account_bridge_lock_before_commitments() {
+    use astria_core::{
+        generated::sequencerblock::v1alpha1::RollupData as RawRollupData,
+        sequencerblock::v1alpha1::block::RollupData,
+    };
+    use base64::{
+        engine::general_purpose::STANDARD,
+        Engine as _,
+    };
+    use ibc_proto::{
+        google::protobuf::Any,
+        ibc::{
+            applications::interchain_accounts::v1::CosmosTx,
+            core::{
+                channel::v1::{
+                    Channel as RawChannel,
+                    Counterparty as RawCounterparty,
+                    Order,
+                    Packet as RawPacket,
+                    State,
+                },
+                client::v1::Height as RawHeight,
+            },
+        },
+    };
+    use ibc_types::core::{
+        channel::{
+            ChannelEnd,
+            ChannelId,
+            PortId,
+        },
+        connection::ConnectionId,
+    };
+    use penumbra_ibc::component::ChannelStateWriteExt as _;
+
+    use crate::{
+        accounts::state_ext::StateWriteExt as _,
+        api_state_ext::StateReadExt as _,
+        ibc::{
+            ica_host::HostParams,
+            state_ext::StateReadExt as _,
+        },
+    };
+
+    let lock_type_url = "/astria.protocol.transactions.v1alpha1.BridgeLockAction";
+    let mut genesis_state = unchecked_genesis_state();
+    genesis_state.ica_host = Some(HostParams {
+        enabled: true,
+        allowed_messages: vec![lock_type_url.to_string()],
+    });
+    let (mut app, storage) =
+        initialize_app_with_storage(Some(genesis_state.try_into().unwrap()), vec![]).await;
+
+    let connection: ConnectionId = "connection-0".parse().unwrap();
+    let controller_port: PortId = "icacontroller-cosmos1owner".parse().unwrap();
+    let host_channel: ChannelId = "channel-0".parse().unwrap();
+    let host_port: PortId = ica_host::PORT.parse().unwrap();
+    let interchain_account = ica_host::interchain_account_address(&connection, &controller_port);
+
+    let bridge_address = crate::address::base_prefixed([99; 20]);
+    let rollup_id = RollupId::from_unhashed_bytes(b"testchainid");
+    let asset_id = get_native_asset().id();
+    let amount = 100;
+    let lock_action = BridgeLockAction {
+        to: bridge_address,
+        amount,
+        asset_id,
+        fee_asset_id: asset_id,
+        destination_chain_address: "nootwashere".to_string(),
+    };
+
+    // queue an interchain account transaction as if it was received in the previous block
+    let cosmos_tx = CosmosTx {
+        messages: vec![Any {
+            type_url: lock_type_url.to_string(),
+            value: lock_action.clone().into_raw().encode_to_vec(),
+        }],
+    };
+    let packet_data = serde_json::json!({
+        "type": "TYPE_EXECUTE_TX",
+        "data": STANDARD.encode(cosmos_tx.encode_to_vec()),
+        "memo": "",
+    });
+    let packet = Packet::try_from(RawPacket {
+        sequence: 1,
+        source_port: controller_port.to_string(),
+        source_channel: "channel-3".to_string(),
+        destination_port: host_port.to_string(),
+        destination_channel: host_channel.to_string(),
+        data: serde_json::to_vec(&packet_data).unwrap(),
+        timeout_height: Some(RawHeight {
+            revision_number: 0,
+            revision_height: 100,
+        }),
+        timeout_timestamp: 0,
+    })
+    .unwrap();
+    let channel = ChannelEnd::try_from(RawChannel {
+        state: State::Open as i32,
+        ordering: Order::Ordered as i32,
+        counterparty: Some(RawCounterparty {
+            port_id: controller_port.to_string(),
+            channel_id: "channel-3".to_string(),
+        }),
+        connection_hops: vec![connection.to_string()],
+        version: "ics27-1".to_string(),
+        ..RawChannel::default()
+    })
+    .unwrap();
+
+    let mut state_tx = StateDelta::new(app.state.clone());
+    state_tx.put_bridge_account_rollup_id(&bridge_address, &rollup_id);
+    state_tx
+        .put_bridge_account_asset_id(&bridge_address, &asset_id)
+        .unwrap();
+    state_tx.put_channel(&host_channel, &host_port, channel);
+    state_tx
+        .put_account_balance(interchain_account, asset_id, 10u128.pow(19))
+        .unwrap();
+    state_tx
+        .put_pending_interchain_account_packet(&packet)
+        .await
+        .unwrap();
+    app.apply(state_tx);
+    app.prepare_commit(storage.clone()).await.unwrap();
+    app.commit(storage.clone()).await;
+
+    // the deposit and fees of the interchain account transaction are part of the commitments
+    let expected_deposit = Deposit::new(
+        bridge_address,
+        rollup_id,
+        amount,
+        asset_id,
+        "nootwashere".to_string(),
+    );
+    let deposits = HashMap::from_iter(vec![(rollup_id, vec![expected_deposit.clone()])]);
+    let fee_summary = {
+        let mut state_tx = StateDelta::new(app.state.clone());
+        crate::transaction::execute_action(&lock_action.into(), &mut state_tx, interchain_account)
+            .await
+            .unwrap();
+        state_tx.get_fee_summary().await.unwrap()
+    };
+    let commitments = generate_rollup_datas_commitment(&[], deposits, Some(&fee_summary));
+    let txs = commitments.into_transactions(vec![]);
+
+    let timestamp = Time::now();
+    let block_hash = Hash::try_from([99u8; 32].to_vec()).unwrap();
+    let finalize_block = abci::request::FinalizeBlock {
+        hash: block_hash,
+        height: 1u32.into(),
+        time: timestamp,
+        next_validators_hash: Hash::default(),
+        proposer_address: [0u8; 20].to_vec().try_into().unwrap(),
+        txs: txs.clone(),
+        decided_last_commit: CommitInfo {
+            votes: vec![],
+            round: Round::default(),
+        },
+        misbehavior: vec![],
+    };
+
+    // execute the block as a full node
+    let finalize_block_result = app
+        .finalize_block(finalize_block.clone(), storage.clone())
+        .await
+        .unwrap();
+
+    // execute the same block as the proposer
+    let prepare_proposal = PrepareProposal {
+        height: 1u32.into(),
+        time: timestamp,
+        next_validators_hash: Hash::default(),
+        proposer_address: [88u8; 20].to_vec().try_into().unwrap(),
+        txs: vec![],
+        max_tx_bytes: 1_000_000,
+        local_last_commit: None,
+        misbehavior: vec![],
+    };
+    let prepare_proposal_result = app
+        .prepare_proposal(prepare_proposal, storage.clone())
+        .await
+        .unwrap();
+    assert_eq!(prepare_proposal_result.txs, txs);
+
+    // execute the same block as a validator that did not propose it
+    let process_proposal = abci::request::ProcessProposal {
+        hash: block_hash,
+        height: 1u32.into(),
+        time: timestamp,
+        next_validators_hash: Hash::default(),
+        proposer_address: [0u8; 20].to_vec().try_into().unwrap(),
+        txs,
+        proposed_last_commit: None,
+        misbehavior: vec![],
+    };
+    app.process_proposal(process_proposal, storage.clone())
+        .await
+        .unwrap();
+    assert_eq!(app.executed_proposal_hash, block_hash);
+    let finalize_block_after_process_proposal_result = app
+        .finalize_block(finalize_block, storage.clone())
+        .await
+        .unwrap();
+    assert_eq!(
+        finalize_block_after_process_proposal_result.app_hash,
+        finalize_block_result.app_hash
+    );
+    assert!(
+        finalize_block_after_process_proposal_result
+            .events
+            .iter()
+            .any(|event| event.kind == "write_acknowledgement")
+    );
+    app.commit(storage).await;
+
+    assert!(
+        app.state
+            .get_pending_interchain_account_packets()
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    let block = app.state.get_sequencer_block_by_height(1).await.unwrap();
+    let mut deposits = vec![];
+    for (_, rollup_data) in block.rollup_transactions() {
+        for tx in rollup_data.transactions() {
+            let rollup_data =
+                RollupData::try_from_raw(RawRollupData::decode(tx.as_slice()).unwrap()).unwrap();
+            if let RollupData::Deposit(deposit) = rollup_data {
+                deposits.push(deposit);
+            }
+        }
+    }
+    assert_eq!(deposits, vec![expected_deposit]);
+}
+
 // it's a test, so allow a lot of lines
 #[tokio::test]
 #[allow(clippy::too_many_lines)]
@@ -433,7 +995,12 @@ async fn app_execution_results_match_proposal_vs_after_proposal() {
         "nootwashere".to_string(),
     );
     let deposits = HashMap::from_iter(vec![(rollup_id, vec![expected_deposit.clone()])]);
-    let commitments = generate_rollup_datas_commitment(&[signed_tx.clone()], deposits.clone());
+    let fee_summary = fee_summary_of_txs(&app, &[signed_tx.clone()]).await;
+    let commitments = generate_rollup_datas_commitment(
+        &[signed_tx.clone()],
+        deposits.clone(),
+        Some(&fee_summary),
+    );
 
     let timestamp = Time::now();
     let block_hash = Hash::try_from([99u8; 32].to_vec()).unwrap();
@@ -593,8 +1160,8 @@ async fn app_prepare_proposal_cometbft_max_bytes_overflow_ok() {
     // see only first tx made it in
     assert_eq!(
         result.txs.len(),
-        3,
-        "total transaction length should be three, including the two commitments and the one tx \
+        4,
+        "total transaction length should be four, including the three commitments and the one tx \
          that fit"
     );
     assert_eq!(
@@ -666,8 +1233,8 @@ async fn app_prepare_proposal_sequencer_max_bytes_overflow_ok() {
     // see only first tx made it in
     assert_eq!(
         result.txs.len(),
-        3,
-        "total transaction length should be three, including the two commitments and the one tx \
+        4,
+        "total transaction length should be four, including the three commitments and the one tx \
          that fit"
     );
     assert_eq!(
@@ -677,6 +1244,63 @@ async fn app_prepare_proposal_sequencer_max_bytes_overflow_ok() {
     );
 }
 
+#[tokio::test]
+async fn app_prepare_proposal_accounts_for_cometbft_encoding_overhead() {
+    let (alice_signing_key, _) = get_alice_signing_key_and_address();
+    let tx = UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(0)
+            .chain_id("test")
+            .build(),
+        actions: vec![
+            SequenceAction {
+                rollup_id: RollupId::from([1u8; 32]),
+                data: vec![1u8; 1_000],
+                fee_asset_id: get_native_asset().id(),
+            }
+            .into(),
+        ],
+    }
+    .into_signed(&alice_signing_key);
+    let tx_len = tx.to_raw().encode_to_vec().len();
+    // the three 32-byte commitments, each with a 1-byte tag and a 1-byte length prefix
+    let commitments_len = 3 * 34;
+    let exact_fit = commitments_len + cometbft_encoded_len(tx_len);
+
+    // the transaction only fits if its tag and length prefix are accounted for
+    for (max_tx_bytes, expected_txs) in [(exact_fit - 1, 3), (exact_fit, 4)] {
+        let (mut app, storage) = initialize_app_with_storage(None, vec![]).await;
+        app.prepare_commit(storage.clone()).await.unwrap();
+        app.commit(storage.clone()).await;
+        app.mempool.insert(tx.clone(), 0).await.unwrap();
+
+        let prepare_args = abci::request::PrepareProposal {
+            max_tx_bytes: max_tx_bytes.try_into().unwrap(),
+            txs: vec![],
+            local_last_commit: None,
+            misbehavior: vec![],
+            height: Height::default(),
+            time: Time::now(),
+            next_validators_hash: Hash::default(),
+            proposer_address: account::Id::new([1u8; 20]),
+        };
+        let result = app.prepare_proposal(prepare_args, storage).await.unwrap();
+
+        assert_eq!(result.txs.len(), expected_txs);
+        let proposal_len: usize = result
+            .txs
+            .iter()
+            .map(|tx| cometbft_encoded_len(tx.len()))
+            .sum();
+        assert!(proposal_len <= max_tx_bytes);
+        assert_eq!(
+            app.mempool.len().await,
+            4 - expected_txs,
+            "an excluded transaction should be re-added to the mempool"
+        );
+    }
+}
+
 #[tokio::test]
 async fn app_end_block_validator_updates() {
     use tendermint::validator;
@@ -10,7 +10,10 @@
 //! If changes are made to the execution results of these actions, manual testing is required.
 
 use std::{
-    collections::HashMap,
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
     sync::Arc,
 };
 
@@ -53,6 +56,7 @@ use crate::{
         address_from_hex_string,
         default_fees,
         default_genesis_accounts,
+        fee_summary_of_txs,
         get_alice_signing_key_and_address,
         get_bridge_signing_key_and_address,
         initialize_app,
@@ -77,6 +81,7 @@ fn unchecked_genesis_state() -> UncheckedGenesisState {
         accounts: vec![],
         address_prefixes: AddressPrefixes {
             base: crate::address::get_base_prefix().to_string(),
+            allowed: vec![],
         },
         authority_sudo_address: alice_address,
         ibc_sudo_address: alice_address,
@@ -85,6 +90,10 @@ fn unchecked_genesis_state() -> UncheckedGenesisState {
         ibc_params: IBCParameters::default(),
         allowed_fee_assets: vec![default_native_asset()],
         fees: default_fees(),
+        ica_host: None,
+        bridge_recovery_delay: None,
+        deposit_limits: None,
+        action_activation_heights: BTreeMap::new(),
     }
 }
 
@@ -146,7 +155,12 @@ async fn app_finalize_block_snapshot() {
         "nootwashere".to_string(),
     );
     let deposits = HashMap::from_iter(vec![(rollup_id, vec![expected_deposit.clone()])]);
-    let commitments = generate_rollup_datas_commitment(&[signed_tx.clone()], deposits.clone());
+    let fee_summary = fee_summary_of_txs(&app, &[signed_tx.clone()]).await;
+    let commitments = generate_rollup_datas_commitment(
+        &[signed_tx.clone()],
+        deposits.clone(),
+        Some(&fee_summary),
+    );
 
     let timestamp = Time::unix_epoch();
     let block_hash = Hash::try_from([99u8; 32].to_vec()).unwrap();
@@ -178,18 +192,26 @@ async fn app_finalize_block_snapshot() {
 #[allow(clippy::too_many_lines)]
 #[tokio::test]
 async fn app_execute_transaction_with_every_action_snapshot() {
+    use std::num::NonZeroU64;
+
     use astria_core::{
         primitive::v1::asset,
         protocol::transaction::v1alpha1::action::{
+            AddressPrefixChangeAction,
+            AllowanceChangeAction,
+            BridgeRecoveryAction,
+            BridgeRecoveryVetoAction,
             FeeAssetChangeAction,
+            FeeDistributionChangeAction,
             InitBridgeAccountAction,
             SudoAddressChangeAction,
+            TransferFromAction,
         },
     };
 
     use crate::genesis::Account;
 
-    let (alice_signing_key, _) = get_alice_signing_key_and_address();
+    let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
     let (bridge_signing_key, bridge_address) = get_bridge_signing_key_and_address();
     let bob_address = address_from_hex_string(BOB_ADDRESS);
     let carol_address = address_from_hex_string(CAROL_ADDRESS);
@@ -201,6 +223,7 @@ async fn app_execute_transaction_with_every_action_snapshot() {
 
     let genesis_state = UncheckedGenesisState {
         accounts,
+        bridge_recovery_delay: Some(NonZeroU64::new(10).unwrap()),
         ..unchecked_genesis_state()
     }
     .try_into()
@@ -244,8 +267,22 @@ async fn app_execute_transaction_with_every_action_snapshot() {
             FeeAssetChangeAction::Addition(asset::Id::from_str_unchecked("test-0")).into(),
             FeeAssetChangeAction::Addition(asset::Id::from_str_unchecked("test-1")).into(),
             FeeAssetChangeAction::Removal(asset::Id::from_str_unchecked("test-0")).into(),
-            SudoAddressChangeAction {
-                new_address: bob_address,
+            AddressPrefixChangeAction::Addition("other".to_string()).into(),
+            AddressPrefixChangeAction::Addition("another".to_string()).into(),
+            AddressPrefixChangeAction::Removal("other".to_string()).into(),
+            FeeDistributionChangeAction {
+                proposer_share: 50,
+                treasury_share: 30,
+                burn_share: 20,
+                treasury_address: Some(carol_address),
+            }
+            .into(),
+            AllowanceChangeAction {
+                spender: bridge_address,
+                amount: 1_000,
+                asset_id,
+                expiry_height: 100,
+                fee_asset_id: asset_id,
             }
             .into(),
         ],
@@ -260,6 +297,14 @@ async fn app_execute_transaction_with_every_action_snapshot() {
             .chain_id("test")
             .build(),
         actions: vec![
+            TransferFromAction {
+                owner: alice_address,
+                to: bob_address,
+                amount: 400,
+                asset_id,
+                fee_asset_id: asset_id,
+            }
+            .into(),
             InitBridgeAccountAction {
                 rollup_id,
                 asset_id,
@@ -273,12 +318,38 @@ async fn app_execute_transaction_with_every_action_snapshot() {
     let signed_tx = Arc::new(tx.into_signed(&bridge_signing_key));
     app.execute_transaction(signed_tx).await.unwrap();
 
+    let tx = UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(1)
+            .chain_id("test")
+            .build(),
+        actions: vec![
+            BridgeRecoveryAction {
+                bridge_address,
+                new_sudo_address: Some(carol_address),
+                new_withdrawer_address: None,
+            }
+            .into(),
+            SudoAddressChangeAction {
+                new_address: bob_address,
+            }
+            .into(),
+        ],
+    };
+    let signed_tx = Arc::new(tx.into_signed(&alice_signing_key));
+    app.execute_transaction(signed_tx).await.unwrap();
+
     let tx = UnsignedTransaction {
         params: TransactionParams::builder()
             .chain_id("test")
             .nonce(1)
             .build(),
         actions: vec![
+            BridgeRecoveryVetoAction {
+                bridge_address,
+                fee_asset_id: asset_id,
+            }
+            .into(),
             BridgeLockAction {
                 to: bridge_address,
                 amount: 100,
@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    collections::BTreeMap,
+    sync::Arc,
+};
 
 use astria_core::{
     crypto::SigningKey,
@@ -13,12 +16,16 @@ use astria_core::{
     },
     protocol::transaction::v1alpha1::{
         action::{
+            AllowanceChangeAction,
             BridgeLockAction,
+            BridgeRecoveryAction,
+            BridgeRecoveryVetoAction,
             BridgeUnlockAction,
             IbcRelayerChangeAction,
             SequenceAction,
             SudoAddressChangeAction,
             TransferAction,
+            TransferFromAction,
         },
         Action,
         TransactionParams,
@@ -31,7 +38,10 @@ use penumbra_ibc::params::IBCParameters;
 
 use crate::{
     accounts::state_ext::StateReadExt as _,
-    app::test_utils::*,
+    app::{
+        test_utils::*,
+        App,
+    },
     asset::get_native_asset,
     authority::state_ext::StateReadExt as _,
     bridge::state_ext::{
@@ -47,6 +57,7 @@ use crate::{
     sequence::calculate_fee_from_state,
     state_ext::StateReadExt as _,
     transaction::{
+        ActionNotActivated,
         InvalidChainId,
         InvalidNonce,
     },
@@ -60,6 +71,7 @@ fn unchecked_genesis_state() -> UncheckedGenesisState {
         accounts: default_genesis_accounts(),
         address_prefixes: AddressPrefixes {
             base: crate::address::get_base_prefix().to_string(),
+            allowed: vec![],
         },
         authority_sudo_address: alice_address,
         ibc_sudo_address: alice_address,
@@ -68,6 +80,10 @@ fn unchecked_genesis_state() -> UncheckedGenesisState {
         ibc_params: IBCParameters::default(),
         allowed_fee_assets: vec![default_native_asset()],
         fees: default_fees(),
+        ica_host: None,
+        bridge_recovery_delay: None,
+        deposit_limits: None,
+        action_activation_heights: BTreeMap::new(),
     }
 }
 
@@ -232,6 +248,190 @@ async fn app_execute_transaction_transfer_balance_too_low_for_fee() {
     assert!(res.contains("insufficient funds"));
 }
 
+/// Initializes an app in which Alice granted a funded spender an allowance of `amount` of the
+/// native asset until `expiry_height`.
+async fn initialize_app_with_allowance(amount: u128, expiry_height: u64) -> (App, SigningKey) {
+    use rand::rngs::OsRng;
+
+    use crate::accounts::state_ext::StateWriteExt as _;
+
+    let mut app = initialize_app(None, vec![]).await;
+    let (alice_signing_key, _) = get_alice_signing_key_and_address();
+    let spender_signing_key = SigningKey::new(OsRng);
+    let spender = crate::address::base_prefixed(spender_signing_key.address_bytes());
+
+    let mut state_tx = StateDelta::new(app.state.clone());
+    state_tx
+        .put_account_balance(spender, get_native_asset().id(), 10u128.pow(19))
+        .unwrap();
+    app.apply(state_tx);
+
+    let tx = UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(0)
+            .chain_id("test")
+            .build(),
+        actions: vec![
+            AllowanceChangeAction {
+                spender,
+                amount,
+                asset_id: get_native_asset().id(),
+                expiry_height,
+                fee_asset_id: get_native_asset().id(),
+            }
+            .into(),
+        ],
+    };
+    app.execute_transaction(Arc::new(tx.into_signed(&alice_signing_key)))
+        .await
+        .unwrap();
+    (app, spender_signing_key)
+}
+
+fn transfer_from_alice_to_bob(amount: u128) -> UnsignedTransaction {
+    let (_, alice_address) = get_alice_signing_key_and_address();
+    UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(0)
+            .chain_id("test")
+            .build(),
+        actions: vec![
+            TransferFromAction {
+                owner: alice_address,
+                to: address_from_hex_string(BOB_ADDRESS),
+                amount,
+                asset_id: get_native_asset().id(),
+                fee_asset_id: get_native_asset().id(),
+            }
+            .into(),
+        ],
+    }
+}
+
+#[tokio::test]
+async fn app_execute_transaction_transfer_from_spends_allowance() {
+    let (mut app, spender_signing_key) = initialize_app_with_allowance(500, 10).await;
+    let (_, alice_address) = get_alice_signing_key_and_address();
+    let spender = crate::address::base_prefixed(spender_signing_key.address_bytes());
+    let bob_address = address_from_hex_string(BOB_ADDRESS);
+    let native_asset = get_native_asset().id();
+
+    let signed_tx = Arc::new(transfer_from_alice_to_bob(200).into_signed(&spender_signing_key));
+    app.execute_transaction(signed_tx).await.unwrap();
+
+    let transfer_fee = app.state.get_transfer_base_fee().await.unwrap();
+    assert_eq!(
+        app.state
+            .get_account_balance(bob_address, native_asset)
+            .await
+            .unwrap(),
+        10u128.pow(19) + 200,
+    );
+    // Alice sent the 200 transferred and paid the fee of granting the allowance
+    assert_eq!(
+        app.state
+            .get_account_balance(alice_address, native_asset)
+            .await
+            .unwrap(),
+        10u128.pow(19) - 200 - transfer_fee,
+    );
+    // the spender paid the fee of the transfer
+    assert_eq!(
+        app.state
+            .get_account_balance(spender, native_asset)
+            .await
+            .unwrap(),
+        10u128.pow(19) - transfer_fee,
+    );
+    assert_eq!(
+        app.state
+            .get_allowance(alice_address, spender, native_asset)
+            .await
+            .unwrap()
+            .expect("the allowance should not be used up")
+            .amount,
+        300,
+    );
+    assert_eq!(app.state.get_account_nonce(spender).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn app_execute_transaction_transfer_from_exceeding_allowance_fails() {
+    let (mut app, spender_signing_key) = initialize_app_with_allowance(100, 10).await;
+
+    let signed_tx = Arc::new(transfer_from_alice_to_bob(101).into_signed(&spender_signing_key));
+    let err = app
+        .execute_transaction(signed_tx)
+        .await
+        .unwrap_err()
+        .root_cause()
+        .to_string();
+    assert!(err.contains("exceeds the remaining allowance"), "{err}");
+}
+
+#[tokio::test]
+async fn app_execute_transaction_transfer_from_expired_allowance_fails() {
+    use crate::state_ext::StateWriteExt as _;
+
+    let (mut app, spender_signing_key) = initialize_app_with_allowance(100, 5).await;
+
+    let mut state_tx = StateDelta::new(app.state.clone());
+    state_tx.put_block_height(6);
+    app.apply(state_tx);
+
+    let signed_tx = Arc::new(transfer_from_alice_to_bob(100).into_signed(&spender_signing_key));
+    let err = app
+        .execute_transaction(signed_tx)
+        .await
+        .unwrap_err()
+        .root_cause()
+        .to_string();
+    assert!(err.contains("allowance expired"), "{err}");
+}
+
+#[tokio::test]
+async fn app_execute_transaction_allowance_change_zero_amount_revokes_allowance() {
+    let (mut app, spender_signing_key) = initialize_app_with_allowance(100, 10).await;
+    let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+    let spender = crate::address::base_prefixed(spender_signing_key.address_bytes());
+
+    let tx = UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(1)
+            .chain_id("test")
+            .build(),
+        actions: vec![
+            AllowanceChangeAction {
+                spender,
+                amount: 0,
+                asset_id: get_native_asset().id(),
+                expiry_height: 0,
+                fee_asset_id: get_native_asset().id(),
+            }
+            .into(),
+        ],
+    };
+    app.execute_transaction(Arc::new(tx.into_signed(&alice_signing_key)))
+        .await
+        .unwrap();
+    assert_eq!(
+        app.state
+            .get_allowance(alice_address, spender, get_native_asset().id())
+            .await
+            .unwrap(),
+        None,
+    );
+
+    let signed_tx = Arc::new(transfer_from_alice_to_bob(1).into_signed(&spender_signing_key));
+    let err = app
+        .execute_transaction(signed_tx)
+        .await
+        .unwrap_err()
+        .root_cause()
+        .to_string();
+    assert!(err.contains("no allowance"), "{err}");
+}
+
 #[tokio::test]
 async fn app_execute_transaction_sequence() {
     use crate::sequence::state_ext::StateWriteExt as _;
@@ -554,6 +754,77 @@ async fn app_execute_transaction_fee_asset_change_invalid() {
     assert!(res.contains("cannot remove last allowed fee asset"));
 }
 
+#[tokio::test]
+async fn app_execute_transaction_fee_distribution_change() {
+    use astria_core::protocol::transaction::v1alpha1::action::FeeDistributionChangeAction;
+
+    use crate::fee_distribution::FeeDistribution;
+
+    let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+
+    let mut app = initialize_app(Some(genesis_state()), vec![]).await;
+
+    let treasury_address = address_from_hex_string(CAROL_ADDRESS);
+    let tx = UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(0)
+            .chain_id("test")
+            .build(),
+        actions: vec![
+            FeeDistributionChangeAction {
+                proposer_share: 50,
+                treasury_share: 30,
+                burn_share: 20,
+                treasury_address: Some(treasury_address),
+            }
+            .into(),
+        ],
+    };
+
+    let signed_tx = Arc::new(tx.into_signed(&alice_signing_key));
+    app.execute_transaction(signed_tx).await.unwrap();
+    assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
+
+    assert_eq!(
+        app.state.get_fee_distribution().await.unwrap(),
+        Some(FeeDistribution::try_new(50, 30, 20, Some(treasury_address)).unwrap()),
+    );
+}
+
+#[tokio::test]
+async fn app_execute_transaction_fee_distribution_change_unauthorized() {
+    use astria_core::protocol::transaction::v1alpha1::action::FeeDistributionChangeAction;
+
+    let mut app = initialize_app(Some(genesis_state()), vec![]).await;
+
+    let tx = UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(0)
+            .chain_id("test")
+            .build(),
+        actions: vec![
+            FeeDistributionChangeAction {
+                proposer_share: 100,
+                treasury_share: 0,
+                burn_share: 0,
+                treasury_address: None,
+            }
+            .into(),
+        ],
+    };
+
+    let (bridge_signing_key, _) = get_bridge_signing_key_and_address();
+    let signed_tx = Arc::new(tx.into_signed(&bridge_signing_key));
+    let res = app
+        .execute_transaction(signed_tx)
+        .await
+        .unwrap_err()
+        .root_cause()
+        .to_string();
+    assert!(res.contains("unauthorized address for fee distribution change"));
+    assert_eq!(app.state.get_fee_distribution().await.unwrap(), None);
+}
+
 #[tokio::test]
 async fn app_execute_transaction_init_bridge_account_ok() {
     use astria_core::protocol::transaction::v1alpha1::action::InitBridgeAccountAction;
@@ -868,6 +1139,60 @@ async fn app_execute_transaction_invalid_chain_id() {
     );
 }
 
+#[tokio::test]
+async fn app_execute_transaction_action_before_activation_height_fails() {
+    use crate::state_ext::StateWriteExt as _;
+
+    let genesis_state = UncheckedGenesisState {
+        action_activation_heights: [(
+            "transfer".to_string(),
+            std::num::NonZeroU64::new(2).unwrap(),
+        )]
+        .into(),
+        ..unchecked_genesis_state()
+    }
+    .try_into()
+    .unwrap();
+    let mut app = initialize_app(Some(genesis_state), vec![]).await;
+
+    let (alice_signing_key, alice_address) = get_alice_signing_key_and_address();
+    let transfer = |nonce| {
+        let tx = UnsignedTransaction {
+            params: TransactionParams::builder()
+                .nonce(nonce)
+                .chain_id("test")
+                .build(),
+            actions: vec![
+                TransferAction {
+                    to: address_from_hex_string(BOB_ADDRESS),
+                    amount: 1,
+                    asset_id: get_native_asset().id(),
+                    fee_asset_id: get_native_asset().id(),
+                }
+                .into(),
+            ],
+        };
+        Arc::new(tx.into_signed(&alice_signing_key))
+    };
+
+    let mut state_tx = StateDelta::new(app.state.clone());
+    state_tx.put_block_height(1);
+    app.apply(state_tx);
+    let err = app.execute_transaction(transfer(0)).await.unwrap_err();
+    let not_activated = err
+        .downcast_ref::<ActionNotActivated>()
+        .expect("transfer should be rejected before its activation height");
+    assert_eq!(not_activated.action, "transfer");
+    assert_eq!(not_activated.activation_height, 2);
+    assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 0);
+
+    let mut state_tx = StateDelta::new(app.state.clone());
+    state_tx.put_block_height(2);
+    app.apply(state_tx);
+    app.execute_transaction(transfer(0)).await.unwrap();
+    assert_eq!(app.state.get_account_nonce(alice_address).await.unwrap(), 1);
+}
+
 #[tokio::test]
 async fn app_stateful_check_fails_insufficient_total_balance() {
     use rand::rngs::OsRng;
@@ -1040,3 +1365,126 @@ async fn app_execute_transaction_bridge_lock_unlock_action_ok() {
         "bridge should've transferred out whole balance"
     );
 }
+
+/// Initializes an app with a bridge recovery delay of 2 blocks at height 1, in which Alice (the
+/// sudo address) scheduled a recovery of the bridge account, assigning it a new sudo address.
+async fn initialize_app_with_bridge_recovery() -> App {
+    use crate::{
+        accounts::state_ext::StateWriteExt as _,
+        state_ext::StateWriteExt as _,
+    };
+
+    let mut genesis_state = unchecked_genesis_state();
+    genesis_state.bridge_recovery_delay = std::num::NonZeroU64::new(2);
+    let mut app = initialize_app(Some(genesis_state.try_into().unwrap()), vec![]).await;
+    let (alice_signing_key, _) = get_alice_signing_key_and_address();
+    let (_, bridge_address) = get_bridge_signing_key_and_address();
+    let asset_id = get_native_asset().id();
+
+    let mut state_tx = StateDelta::new(app.state.clone());
+    state_tx.put_block_height(1);
+    state_tx
+        .put_account_balance(bridge_address, asset_id, 10u128.pow(19))
+        .unwrap();
+    state_tx.put_bridge_account_rollup_id(&bridge_address, &RollupId::new([0; 32]));
+    state_tx.put_bridge_account_sudo_address(&bridge_address, &bridge_address);
+    app.apply(state_tx);
+
+    let tx = UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(0)
+            .chain_id("test")
+            .build(),
+        actions: vec![
+            BridgeRecoveryAction {
+                bridge_address,
+                new_sudo_address: Some(address_from_hex_string(BOB_ADDRESS)),
+                new_withdrawer_address: None,
+            }
+            .into(),
+        ],
+    };
+    app.execute_transaction(Arc::new(tx.into_signed(&alice_signing_key)))
+        .await
+        .unwrap();
+    app
+}
+
+#[tokio::test]
+async fn app_execute_transaction_bridge_recovery_executes_after_delay() {
+    use crate::state_ext::StateWriteExt as _;
+
+    let mut app = initialize_app_with_bridge_recovery().await;
+    let (_, bridge_address) = get_bridge_signing_key_and_address();
+    let proposer_address = crate::address::base_prefixed([0u8; 20]);
+
+    // the recovery is not executed before the delay has passed
+    let mut state_tx = StateDelta::new(app.state.clone());
+    state_tx.put_block_height(2);
+    app.apply(state_tx);
+    app.end_block(2, proposer_address).await.unwrap();
+    assert_eq!(
+        app.state
+            .get_bridge_account_sudo_address(&bridge_address)
+            .await
+            .unwrap(),
+        Some(bridge_address),
+    );
+
+    let mut state_tx = StateDelta::new(app.state.clone());
+    state_tx.put_block_height(3);
+    app.apply(state_tx);
+    app.end_block(3, proposer_address).await.unwrap();
+    assert_eq!(
+        app.state
+            .get_bridge_account_sudo_address(&bridge_address)
+            .await
+            .unwrap(),
+        Some(address_from_hex_string(BOB_ADDRESS)),
+    );
+    assert_eq!(
+        app.state
+            .get_bridge_account_recovery(&bridge_address)
+            .await
+            .unwrap(),
+        None,
+    );
+}
+
+#[tokio::test]
+async fn app_execute_transaction_bridge_recovery_veto_cancels_recovery() {
+    use crate::state_ext::StateWriteExt as _;
+
+    let mut app = initialize_app_with_bridge_recovery().await;
+    let (bridge_signing_key, bridge_address) = get_bridge_signing_key_and_address();
+    let proposer_address = crate::address::base_prefixed([0u8; 20]);
+
+    let tx = UnsignedTransaction {
+        params: TransactionParams::builder()
+            .nonce(0)
+            .chain_id("test")
+            .build(),
+        actions: vec![
+            BridgeRecoveryVetoAction {
+                bridge_address,
+                fee_asset_id: get_native_asset().id(),
+            }
+            .into(),
+        ],
+    };
+    app.execute_transaction(Arc::new(tx.into_signed(&bridge_signing_key)))
+        .await
+        .unwrap();
+
+    let mut state_tx = StateDelta::new(app.state.clone());
+    state_tx.put_block_height(3);
+    app.apply(state_tx);
+    app.end_block(3, proposer_address).await.unwrap();
+    assert_eq!(
+        app.state
+            .get_bridge_account_sudo_address(&bridge_address)
+            .await
+            .unwrap(),
+        Some(bridge_address),
+    );
+}
@@ -0,0 +1,266 @@
+//! Property-based fuzzing of the ABCI block lifecycle.
+//!
+//! Blocks of generated transactions are proposed through `prepare_proposal`, `process_proposal`
+//! and `finalize_block`, and then executed again by independent apps as a non-proposing validator
+//! and as a full node. All three executions must accept the block and agree on its app hash and
+//! execution results.
+//!
+//! Each case runs against fresh temporary storages, so only a few cases are run by default. Set
+//! `PROPTEST_CASES` to fuzz for longer.
+
+use astria_core::{
+    arbitrary,
+    crypto::SigningKey,
+    primitive::v1::asset::default_native_asset,
+    protocol::transaction::v1alpha1::{
+        action::{
+            SequenceAction,
+            TransferAction,
+        },
+        Action,
+        SignedTransaction,
+        TransactionParams,
+        UnsignedTransaction,
+    },
+};
+use bytes::Bytes;
+use proptest::{
+    collection::vec,
+    prelude::*,
+};
+use tendermint::{
+    abci::{
+        self,
+        request::PrepareProposal,
+        types::CommitInfo,
+    },
+    account,
+    block::Round,
+    Hash,
+    Time,
+};
+
+use crate::{
+    app::test_utils::{
+        get_alice_signing_key_and_address,
+        get_bridge_signing_key_and_address,
+        initialize_app_with_storage,
+        unchecked_genesis_state,
+    },
+    genesis::{
+        Account,
+        GenesisState,
+    },
+};
+
+/// The number of cases run unless `PROPTEST_CASES` is set.
+const DEFAULT_CASES: u32 = 16;
+
+const BLOCK_HASH: [u8; 32] = [99; 32];
+const PROPOSER_ADDRESS: [u8; 20] = [88; 20];
+
+fn proptest_config() -> ProptestConfig {
+    if std::env::var_os("PROPTEST_CASES").is_some() {
+        ProptestConfig::default()
+    } else {
+        ProptestConfig::with_cases(DEFAULT_CASES)
+    }
+}
+
+/// The signers of generated transactions, all of which are funded at genesis.
+fn signers() -> [SigningKey; 2] {
+    [
+        get_alice_signing_key_and_address().0,
+        get_bridge_signing_key_and_address().0,
+    ]
+}
+
+fn genesis_state() -> GenesisState {
+    let (_, bridge) = get_bridge_signing_key_and_address();
+    let mut genesis_state = unchecked_genesis_state();
+    genesis_state.accounts.push(Account {
+        address: bridge,
+        balance: 10u128.pow(19),
+    });
+    genesis_state.try_into().unwrap()
+}
+
+/// Generates actions, most of which pass execution when signed by a funded account.
+fn action() -> impl Strategy<Value = Action> {
+    let native_asset = default_native_asset().id();
+    prop_oneof![
+        3 => (arbitrary::address(), 0..1_000u128).prop_map(move |(to, amount)| {
+            Action::Transfer(TransferAction {
+                to,
+                amount,
+                asset_id: native_asset,
+                fee_asset_id: native_asset,
+            })
+        }),
+        3 => (arbitrary::rollup_id(), vec(any::<u8>(), 0..64)).prop_map(
+            move |(rollup_id, data)| {
+                Action::Sequence(SequenceAction {
+                    rollup_id,
+                    data,
+                    fee_asset_id: native_asset,
+                })
+            }
+        ),
+        1 => arbitrary::action(),
+    ]
+}
+
+/// Generates the transactions offered to the proposer.
+///
+/// The transactions of each signer use consecutive nonces starting at 0. A few transactions by
+/// unfunded signers for arbitrary chains are mixed in, which the proposer must exclude.
+fn transactions() -> impl Strategy<Value = Vec<SignedTransaction>> {
+    (
+        vec((0..2usize, vec(action(), 1..4)), 0..8),
+        vec(arbitrary::signed_transaction(), 0..2),
+    )
+        .prop_map(|(txs, mut invalid_txs)| {
+            let signers = signers();
+            let mut nonces = [0u32; 2];
+            let mut txs: Vec<_> = txs
+                .into_iter()
+                .map(|(signer, actions)| {
+                    let nonce = nonces[signer];
+                    nonces[signer] += 1;
+                    UnsignedTransaction {
+                        params: TransactionParams::builder()
+                            .nonce(nonce)
+                            .chain_id("test")
+                            .build(),
+                        actions,
+                    }
+                    .into_signed(&signers[signer])
+                })
+                .collect();
+            txs.append(&mut invalid_txs);
+            txs
+        })
+}
+
+fn block_time() -> Time {
+    Time::from_unix_timestamp(1_700_000_000, 0).unwrap()
+}
+
+fn proposer_address() -> account::Id {
+    account::Id::try_from(PROPOSER_ADDRESS.to_vec()).unwrap()
+}
+
+fn process_proposal(txs: Vec<Bytes>) -> abci::request::ProcessProposal {
+    abci::request::ProcessProposal {
+        hash: Hash::try_from(BLOCK_HASH.to_vec()).unwrap(),
+        height: 1u32.into(),
+        time: block_time(),
+        next_validators_hash: Hash::default(),
+        proposer_address: proposer_address(),
+        txs,
+        proposed_last_commit: None,
+        misbehavior: vec![],
+    }
+}
+
+fn finalize_block(txs: Vec<Bytes>) -> abci::request::FinalizeBlock {
+    abci::request::FinalizeBlock {
+        hash: Hash::try_from(BLOCK_HASH.to_vec()).unwrap(),
+        height: 1u32.into(),
+        time: block_time(),
+        next_validators_hash: Hash::default(),
+        proposer_address: proposer_address(),
+        txs,
+        decided_last_commit: CommitInfo {
+            votes: vec![],
+            round: Round::default(),
+        },
+        misbehavior: vec![],
+    }
+}
+
+/// Proposes a block from `txs` and finalizes it, returning the block's transactions and the
+/// result of finalizing it.
+async fn execute_as_proposer(
+    txs: Vec<SignedTransaction>,
+) -> (Vec<Bytes>, abci::response::FinalizeBlock) {
+    let (mut app, storage) = initialize_app_with_storage(Some(genesis_state()), vec![]).await;
+    for tx in txs {
+        app.mempool.insert(tx, 0).await.unwrap();
+    }
+
+    let prepare_proposal = PrepareProposal {
+        height: 1u32.into(),
+        time: block_time(),
+        next_validators_hash: Hash::default(),
+        proposer_address: proposer_address(),
+        txs: vec![],
+        max_tx_bytes: 1_000_000,
+        local_last_commit: None,
+        misbehavior: vec![],
+    };
+    let block_txs = app
+        .prepare_proposal(prepare_proposal, storage.clone())
+        .await
+        .unwrap()
+        .txs;
+    app.process_proposal(process_proposal(block_txs.clone()), storage.clone())
+        .await
+        .unwrap();
+    let finalize_block_result = app
+        .finalize_block(finalize_block(block_txs.clone()), storage.clone())
+        .await
+        .unwrap();
+    (block_txs, finalize_block_result)
+}
+
+/// Executes the proposed block as a validator that did not propose it.
+async fn execute_as_validator(
+    block_txs: Vec<Bytes>,
+) -> anyhow::Result<abci::response::FinalizeBlock> {
+    let (mut app, storage) = initialize_app_with_storage(Some(genesis_state()), vec![]).await;
+    app.process_proposal(process_proposal(block_txs.clone()), storage.clone())
+        .await?;
+    app.finalize_block(finalize_block(block_txs), storage.clone())
+        .await
+}
+
+/// Executes the decided block as a full node, which never sees the proposal.
+async fn execute_as_full_node(
+    block_txs: Vec<Bytes>,
+) -> anyhow::Result<abci::response::FinalizeBlock> {
+    let (mut app, storage) = initialize_app_with_storage(Some(genesis_state()), vec![]).await;
+    app.finalize_block(finalize_block(block_txs), storage.clone())
+        .await
+}
+
+proptest! {
+    #![proptest_config(proptest_config())]
+
+    #[test]
+    fn block_execution_is_deterministic(txs in transactions()) {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let (block_txs, proposed, validated, fully_synced) = runtime.block_on(async {
+            let (block_txs, proposed) = execute_as_proposer(txs).await;
+            let validated = execute_as_validator(block_txs.clone()).await;
+            let fully_synced = execute_as_full_node(block_txs.clone()).await;
+            (block_txs, proposed, validated, fully_synced)
+        });
+
+        // the first three transactions are the block data commitments, every other transaction
+        // included by the proposer must have executed successfully.
+        prop_assert_eq!(block_txs.len(), proposed.tx_results.len());
+        prop_assert!(proposed.tx_results.iter().all(|result| result.code.is_ok()));
+
+        let validated = validated.expect("validators must accept the proposed block");
+        prop_assert_eq!(&proposed.app_hash, &validated.app_hash);
+        prop_assert_eq!(&proposed.tx_results, &validated.tx_results);
+
+        let fully_synced = fully_synced.expect("full nodes must accept the decided block");
+        prop_assert_eq!(&proposed.app_hash, &fully_synced.app_hash);
+        prop_assert_eq!(&proposed.tx_results, &fully_synced.tx_results);
+    }
+}
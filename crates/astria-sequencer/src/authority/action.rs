@@ -16,6 +16,7 @@ use tendermint::account;
 use tracing::instrument;
 
 use crate::{
+    address::StateReadExt as _,
     authority::state_ext::{
         StateReadExt,
         StateWriteExt,
@@ -74,12 +75,6 @@ impl ActionHandler for tendermint::validator::Update {
 
 #[async_trait::async_trait]
 impl ActionHandler for SudoAddressChangeAction {
-    async fn check_stateless(&self) -> Result<()> {
-        crate::address::ensure_base_prefix(&self.new_address)
-            .context("desired new sudo address has an unsupported prefix")?;
-        Ok(())
-    }
-
     /// check that the signer of the transaction is the current sudo address,
     /// as only that address can change the sudo address
     async fn check_stateful<S: StateReadExt + 'static>(
@@ -87,6 +82,10 @@ impl ActionHandler for SudoAddressChangeAction {
         state: &S,
         from: Address,
     ) -> Result<()> {
+        state
+            .ensure_allowed_prefix(&self.new_address)
+            .await
+            .context("desired new sudo address has an unsupported prefix")?;
         // ensure signer is the valid `sudo` key in state
         let sudo_address = state
             .get_sudo_address()
@@ -21,6 +21,7 @@ use crate::{
             StateWriteExt as _,
         },
     },
+    address::StateReadExt as _,
     bridge::state_ext::{
         StateReadExt as _,
         StateWriteExt as _,
@@ -34,17 +35,16 @@ use crate::{
 
 #[async_trait::async_trait]
 impl ActionHandler for BridgeLockAction {
-    async fn check_stateless(&self) -> Result<()> {
-        crate::address::ensure_base_prefix(&self.to)
-            .context("destination address has an unsupported prefix")?;
-        Ok(())
-    }
-
     async fn check_stateful<S: StateReadExt + 'static>(
         &self,
         state: &S,
         from: Address,
     ) -> Result<()> {
+        state
+            .ensure_allowed_prefix(&self.to)
+            .await
+            .context("destination address has an unsupported prefix")?;
+
         let transfer_action = TransferAction {
             to: self.to,
             asset_id: self.asset_id,
@@ -138,6 +138,10 @@ impl ActionHandler for BridgeLockAction {
             .decrease_balance(from, self.fee_asset_id, fee)
             .await
             .context("failed to deduct fee from account balance")?;
+        state
+            .get_and_increase_burned_fees(self.fee_asset_id, fee)
+            .await
+            .context("failed to add to burned fees")?;
 
         state
             .put_deposit_event(deposit)
@@ -0,0 +1,417 @@
+use anyhow::{
+    bail,
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::{
+    primitive::v1::Address,
+    protocol::transaction::v1alpha1::action::{
+        BridgeRecoveryAction,
+        BridgeRecoveryVetoAction,
+    },
+};
+use tracing::instrument;
+
+use crate::{
+    accounts::state_ext::StateWriteExt as _,
+    address::StateReadExt as _,
+    authority::state_ext::StateReadExt as _,
+    bridge::state_ext::{
+        BridgeRecovery,
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    state_ext::{
+        StateReadExt,
+        StateWriteExt,
+    },
+    transaction::action_handler::ActionHandler,
+};
+
+#[async_trait::async_trait]
+impl ActionHandler for BridgeRecoveryAction {
+    async fn check_stateless(&self) -> Result<()> {
+        ensure!(
+            self.new_sudo_address.is_some() || self.new_withdrawer_address.is_some(),
+            "bridge account recovery must set a new sudo or withdrawer address",
+        );
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        // ensure signer is the valid `sudo` key in state
+        let sudo_address = state
+            .get_sudo_address()
+            .await
+            .context("failed to get sudo address from state")?;
+        ensure!(sudo_address == from, "signer is not the sudo key");
+
+        ensure!(
+            state
+                .get_bridge_recovery_delay()
+                .await
+                .context("failed to get bridge recovery delay")?
+                .is_some(),
+            "bridge account recovery is not enabled",
+        );
+
+        state
+            .ensure_allowed_prefix(&self.bridge_address)
+            .await
+            .context("bridge address has an unsupported prefix")?;
+        if let Some(new_sudo_address) = &self.new_sudo_address {
+            state
+                .ensure_allowed_prefix(new_sudo_address)
+                .await
+                .context("new sudo address has an unsupported prefix")?;
+        }
+        if let Some(new_withdrawer_address) = &self.new_withdrawer_address {
+            state
+                .ensure_allowed_prefix(new_withdrawer_address)
+                .await
+                .context("new withdrawer address has an unsupported prefix")?;
+        }
+
+        ensure!(
+            state
+                .get_bridge_account_rollup_id(&self.bridge_address)
+                .await
+                .context("failed to get bridge account rollup id")?
+                .is_some(),
+            "address is not a bridge account",
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, _: Address) -> Result<()> {
+        let delay = state
+            .get_bridge_recovery_delay()
+            .await
+            .context("failed to get bridge recovery delay")?
+            .context("bridge account recovery is not enabled")?;
+        let height = state
+            .get_block_height()
+            .await
+            .context("failed to get block height")?;
+        let execution_height = height
+            .checked_add(delay)
+            .context("bridge account recovery execution height overflowed")?;
+
+        // any recovery already pending for the bridge account is replaced
+        state.put_bridge_account_recovery(
+            &self.bridge_address,
+            &BridgeRecovery {
+                new_sudo_address: self.new_sudo_address,
+                new_withdrawer_address: self.new_withdrawer_address,
+                execution_height,
+            },
+        );
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ActionHandler for BridgeRecoveryVetoAction {
+    async fn check_stateful<S: StateReadExt + 'static>(
+        &self,
+        state: &S,
+        from: Address,
+    ) -> Result<()> {
+        state
+            .ensure_allowed_prefix(&self.bridge_address)
+            .await
+            .context("bridge address has an unsupported prefix")?;
+
+        ensure!(
+            state
+                .is_allowed_fee_asset(self.fee_asset_id)
+                .await
+                .context("failed to check allowed fee assets in state")?,
+            "invalid fee asset",
+        );
+
+        // check that the sender of this tx is the authorized sudo address for the bridge account
+        let Some(sudo_address) = state
+            .get_bridge_account_sudo_address(&self.bridge_address)
+            .await
+            .context("failed to get bridge account sudo address")?
+        else {
+            bail!("bridge account does not have an associated sudo address");
+        };
+        ensure!(
+            sudo_address == from,
+            "unauthorized for bridge recovery veto action",
+        );
+
+        ensure!(
+            state
+                .get_bridge_account_recovery(&self.bridge_address)
+                .await
+                .context("failed to get bridge account recovery")?
+                .is_some(),
+            "bridge account has no pending recovery",
+        );
+
+        Ok(())
+    }
+
+    #[instrument(skip_all)]
+    async fn execute<S: StateWriteExt>(&self, state: &mut S, _: Address) -> Result<()> {
+        let fee = state
+            .get_bridge_sudo_change_base_fee()
+            .await
+            .context("failed to get bridge sudo change fee")?;
+        state
+            .decrease_balance(self.bridge_address, self.fee_asset_id, fee)
+            .await
+            .context("failed to decrease balance for bridge recovery veto fee")?;
+        state
+            .get_and_increase_burned_fees(self.fee_asset_id, fee)
+            .await
+            .context("failed to add to burned fees")?;
+
+        state.delete_bridge_account_recovery(&self.bridge_address);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use astria_core::primitive::v1::{
+        asset::Id,
+        RollupId,
+    };
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::authority::state_ext::StateWriteExt as _;
+
+    #[tokio::test]
+    async fn bridge_recovery_check_stateless_requires_new_address() {
+        let action = BridgeRecoveryAction {
+            bridge_address: crate::address::base_prefixed([99; 20]),
+            new_sudo_address: None,
+            new_withdrawer_address: None,
+        };
+
+        assert!(
+            action
+                .check_stateless()
+                .await
+                .unwrap_err()
+                .to_string()
+                .contains("must set a new sudo or withdrawer address")
+        );
+    }
+
+    #[tokio::test]
+    async fn bridge_recovery_check_stateful_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let sudo_address = crate::address::base_prefixed([1; 20]);
+        state.put_sudo_address(sudo_address).unwrap();
+        state.put_bridge_recovery_delay(10);
+
+        let bridge_address = crate::address::base_prefixed([99; 20]);
+        state.put_bridge_account_rollup_id(&bridge_address, &RollupId::new([0; 32]));
+
+        let action = BridgeRecoveryAction {
+            bridge_address,
+            new_sudo_address: Some(crate::address::base_prefixed([98; 20])),
+            new_withdrawer_address: None,
+        };
+
+        action.check_stateful(&state, sudo_address).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn bridge_recovery_check_stateful_unauthorized() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        state
+            .put_sudo_address(crate::address::base_prefixed([1; 20]))
+            .unwrap();
+        state.put_bridge_recovery_delay(10);
+
+        let bridge_address = crate::address::base_prefixed([99; 20]);
+        state.put_bridge_account_rollup_id(&bridge_address, &RollupId::new([0; 32]));
+
+        let action = BridgeRecoveryAction {
+            bridge_address,
+            new_sudo_address: Some(crate::address::base_prefixed([98; 20])),
+            new_withdrawer_address: None,
+        };
+
+        assert!(
+            action
+                .check_stateful(&state, bridge_address)
+                .await
+                .unwrap_err()
+                .to_string()
+                .contains("signer is not the sudo key")
+        );
+    }
+
+    #[tokio::test]
+    async fn bridge_recovery_check_stateful_disabled() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let sudo_address = crate::address::base_prefixed([1; 20]);
+        state.put_sudo_address(sudo_address).unwrap();
+
+        let bridge_address = crate::address::base_prefixed([99; 20]);
+        state.put_bridge_account_rollup_id(&bridge_address, &RollupId::new([0; 32]));
+
+        let action = BridgeRecoveryAction {
+            bridge_address,
+            new_sudo_address: Some(crate::address::base_prefixed([98; 20])),
+            new_withdrawer_address: None,
+        };
+
+        assert!(
+            action
+                .check_stateful(&state, sudo_address)
+                .await
+                .unwrap_err()
+                .to_string()
+                .contains("bridge account recovery is not enabled")
+        );
+    }
+
+    #[tokio::test]
+    async fn bridge_recovery_execute_schedules_recovery() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        state.put_block_height(5);
+        state.put_bridge_recovery_delay(10);
+
+        let bridge_address = crate::address::base_prefixed([99; 20]);
+        let new_sudo_address = crate::address::base_prefixed([98; 20]);
+        let action = BridgeRecoveryAction {
+            bridge_address,
+            new_sudo_address: Some(new_sudo_address),
+            new_withdrawer_address: None,
+        };
+
+        action.execute(&mut state, bridge_address).await.unwrap();
+
+        assert_eq!(
+            state
+                .get_bridge_account_recovery(&bridge_address)
+                .await
+                .unwrap(),
+            Some(BridgeRecovery {
+                new_sudo_address: Some(new_sudo_address),
+                new_withdrawer_address: None,
+                execution_height: 15,
+            }),
+        );
+    }
+
+    #[tokio::test]
+    async fn bridge_recovery_veto_check_stateful_unauthorized() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let asset_id = Id::from_str_unchecked("test");
+        state.put_allowed_fee_asset(asset_id);
+
+        let bridge_address = crate::address::base_prefixed([99; 20]);
+        let sudo_address = crate::address::base_prefixed([98; 20]);
+        state.put_bridge_account_sudo_address(&bridge_address, &sudo_address);
+
+        let action = BridgeRecoveryVetoAction {
+            bridge_address,
+            fee_asset_id: asset_id,
+        };
+
+        assert!(
+            action
+                .check_stateful(&state, bridge_address)
+                .await
+                .unwrap_err()
+                .to_string()
+                .contains("unauthorized for bridge recovery veto action")
+        );
+    }
+
+    #[tokio::test]
+    async fn bridge_recovery_veto_check_stateful_no_pending_recovery() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let asset_id = Id::from_str_unchecked("test");
+        state.put_allowed_fee_asset(asset_id);
+
+        let bridge_address = crate::address::base_prefixed([99; 20]);
+        let sudo_address = crate::address::base_prefixed([98; 20]);
+        state.put_bridge_account_sudo_address(&bridge_address, &sudo_address);
+
+        let action = BridgeRecoveryVetoAction {
+            bridge_address,
+            fee_asset_id: asset_id,
+        };
+
+        assert!(
+            action
+                .check_stateful(&state, sudo_address)
+                .await
+                .unwrap_err()
+                .to_string()
+                .contains("bridge account has no pending recovery")
+        );
+    }
+
+    #[tokio::test]
+    async fn bridge_recovery_veto_execute_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        state.put_bridge_sudo_change_base_fee(10);
+
+        let fee_asset_id = Id::from_str_unchecked("test");
+        let bridge_address = crate::address::base_prefixed([99; 20]);
+        state
+            .put_account_balance(bridge_address, fee_asset_id, 10)
+            .unwrap();
+        state.put_bridge_account_recovery(
+            &bridge_address,
+            &BridgeRecovery {
+                new_sudo_address: Some(crate::address::base_prefixed([98; 20])),
+                new_withdrawer_address: None,
+                execution_height: 15,
+            },
+        );
+
+        let action = BridgeRecoveryVetoAction {
+            bridge_address,
+            fee_asset_id,
+        };
+
+        action.execute(&mut state, bridge_address).await.unwrap();
+
+        assert_eq!(
+            state
+                .get_bridge_account_recovery(&bridge_address)
+                .await
+                .unwrap(),
+            None,
+        );
+    }
+}
@@ -11,6 +11,7 @@ use tracing::instrument;
 
 use crate::{
     accounts::state_ext::StateWriteExt as _,
+    address::StateReadExt as _,
     bridge::state_ext::{
         StateReadExt as _,
         StateWriteExt as _,
@@ -24,27 +25,28 @@ use crate::{
 
 #[async_trait::async_trait]
 impl ActionHandler for BridgeSudoChangeAction {
-    async fn check_stateless(&self) -> Result<()> {
-        crate::address::ensure_base_prefix(&self.bridge_address)
-            .context("bridge address has an unsupported prefix")?;
-        self.new_sudo_address
-            .as_ref()
-            .map(crate::address::ensure_base_prefix)
-            .transpose()
-            .context("new sudo address has an unsupported prefix")?;
-        self.new_withdrawer_address
-            .as_ref()
-            .map(crate::address::ensure_base_prefix)
-            .transpose()
-            .context("new withdrawer address has an unsupported prefix")?;
-        Ok(())
-    }
-
     async fn check_stateful<S: StateReadExt + 'static>(
         &self,
         state: &S,
         from: Address,
     ) -> Result<()> {
+        state
+            .ensure_allowed_prefix(&self.bridge_address)
+            .await
+            .context("bridge address has an unsupported prefix")?;
+        if let Some(new_sudo_address) = &self.new_sudo_address {
+            state
+                .ensure_allowed_prefix(new_sudo_address)
+                .await
+                .context("new sudo address has an unsupported prefix")?;
+        }
+        if let Some(new_withdrawer_address) = &self.new_withdrawer_address {
+            state
+                .ensure_allowed_prefix(new_withdrawer_address)
+                .await
+                .context("new withdrawer address has an unsupported prefix")?;
+        }
+
         ensure!(
             state
                 .is_allowed_fee_asset(self.fee_asset_id)
@@ -82,6 +84,10 @@ impl ActionHandler for BridgeSudoChangeAction {
             .decrease_balance(self.bridge_address, self.fee_asset_id, fee)
             .await
             .context("failed to decrease balance for bridge sudo change fee")?;
+        state
+            .get_and_increase_burned_fees(self.fee_asset_id, fee)
+            .await
+            .context("failed to add to burned fees")?;
 
         if let Some(sudo_address) = self.new_sudo_address {
             state.put_bridge_account_sudo_address(&self.bridge_address, &sudo_address);
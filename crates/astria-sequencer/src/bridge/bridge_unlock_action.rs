@@ -15,6 +15,7 @@ use tracing::instrument;
 
 use crate::{
     accounts::action::transfer_check_stateful,
+    address::StateReadExt as _,
     bridge::state_ext::StateReadExt as _,
     state_ext::{
         StateReadExt,
@@ -25,22 +26,22 @@ use crate::{
 
 #[async_trait::async_trait]
 impl ActionHandler for BridgeUnlockAction {
-    async fn check_stateless(&self) -> Result<()> {
-        crate::address::ensure_base_prefix(&self.to)
-            .context("destination address has an unsupported prefix")?;
-        self.bridge_address
-            .as_ref()
-            .map(crate::address::ensure_base_prefix)
-            .transpose()
-            .context("bridge address has an unsupported prefix")?;
-        Ok(())
-    }
-
     async fn check_stateful<S: StateReadExt + 'static>(
         &self,
         state: &S,
         from: Address,
     ) -> Result<()> {
+        state
+            .ensure_allowed_prefix(&self.to)
+            .await
+            .context("destination address has an unsupported prefix")?;
+        if let Some(bridge_address) = &self.bridge_address {
+            state
+                .ensure_allowed_prefix(bridge_address)
+                .await
+                .context("bridge address has an unsupported prefix")?;
+        }
+
         // the bridge address to withdraw funds from
         // if unset, use the tx sender's address
         let bridge_address = self.bridge_address.unwrap_or(from);
@@ -1,16 +1,23 @@
 use std::sync::Arc;
 
-use anyhow::Result;
+use anyhow::{
+    Context as _,
+    Result,
+};
 use tendermint::abci::request::{
     BeginBlock,
     EndBlock,
 };
 use tracing::instrument;
 
-use super::state_ext::StateWriteExt;
+use super::state_ext::{
+    StateReadExt as _,
+    StateWriteExt,
+};
 use crate::{
     component::Component,
     genesis::GenesisState,
+    state_ext::StateReadExt as _,
 };
 
 #[derive(Default)]
@@ -25,6 +32,12 @@ impl Component for BridgeComponent {
         state.put_init_bridge_account_base_fee(app_state.fees.init_bridge_account_base_fee);
         state.put_bridge_lock_byte_cost_multiplier(app_state.fees.bridge_lock_byte_cost_multiplier);
         state.put_bridge_sudo_change_base_fee(app_state.fees.bridge_sudo_change_fee);
+        if let Some(delay) = app_state.bridge_recovery_delay {
+            state.put_bridge_recovery_delay(delay.get());
+        }
+        if let Some(limits) = &app_state.deposit_limits {
+            state.put_deposit_limits(limits);
+        }
         Ok(())
     }
 
@@ -36,11 +49,34 @@ impl Component for BridgeComponent {
         Ok(())
     }
 
-    #[instrument(name = "BridgeComponent::end_block", skip(_state))]
+    #[instrument(name = "BridgeComponent::end_block", skip(state))]
     async fn end_block<S: StateWriteExt + 'static>(
-        _state: &mut Arc<S>,
+        state: &mut Arc<S>,
         _end_block: &EndBlock,
     ) -> Result<()> {
+        let height = state
+            .get_block_height()
+            .await
+            .context("failed to get block height")?;
+        let recoveries = state
+            .get_due_bridge_account_recoveries(height)
+            .await
+            .context("failed to get due bridge account recoveries")?;
+        if recoveries.is_empty() {
+            return Ok(());
+        }
+
+        let state = Arc::get_mut(state)
+            .context("must only have one reference to the state; this is a bug")?;
+        for (bridge_address, recovery) in recoveries {
+            if let Some(sudo_address) = recovery.new_sudo_address {
+                state.put_bridge_account_sudo_address(&bridge_address, &sudo_address);
+            }
+            if let Some(withdrawer_address) = recovery.new_withdrawer_address {
+                state.put_bridge_account_withdrawer_address(&bridge_address, &withdrawer_address);
+            }
+            state.delete_bridge_account_recovery(&bridge_address);
+        }
         Ok(())
     }
 }
@@ -0,0 +1,204 @@
+//! Limits on the deposits included in a rollup's data per block.
+//!
+//! If [`DepositLimits`] are configured, deposits exceeding them are queued and included in
+//! subsequent blocks, ahead of the deposits made in those blocks. The queue is ordered by the
+//! order in which the deposits were made, so every node splits a block's deposits identically.
+
+use std::{
+    collections::HashMap,
+    num::{
+        NonZeroU32,
+        NonZeroU64,
+    },
+};
+
+use anyhow::{
+    Context as _,
+    Result,
+};
+use astria_core::{
+    primitive::v1::RollupId,
+    sequencerblock::v1alpha1::block::Deposit,
+};
+use cnidarium::StateRead;
+use prost::Message as _;
+use serde::{
+    Deserialize,
+    Serialize,
+};
+
+use super::state_ext::StateReadExt as _;
+
+/// The genesis limits on the deposits included in a rollup's data per block.
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, Eq)]
+pub(crate) struct DepositLimits {
+    /// The maximum number of deposits included in a rollup's data per block.
+    pub(crate) max_deposits_per_rollup: NonZeroU32,
+    /// The maximum total length of the protobuf encoded deposits included in a rollup's data
+    /// per block. The first deposit queued for a rollup is always included, even if it exceeds
+    /// this limit on its own.
+    pub(crate) max_deposit_bytes_per_rollup: NonZeroU64,
+}
+
+impl DepositLimits {
+    /// Splits `deposits` into those included in the current block and those queued for
+    /// subsequent blocks.
+    fn split(&self, mut deposits: Vec<Deposit>) -> (Vec<Deposit>, Vec<Deposit>) {
+        let max_count = usize::try_from(self.max_deposits_per_rollup.get()).unwrap_or(usize::MAX);
+        let mut total_bytes = 0u64;
+        let mut included_count = 0;
+        for deposit in &deposits {
+            let encoded_len = deposit.clone().into_raw().encoded_len() as u64;
+            total_bytes = total_bytes.saturating_add(encoded_len);
+            if included_count == max_count
+                || (included_count > 0 && total_bytes > self.max_deposit_bytes_per_rollup.get())
+            {
+                break;
+            }
+            included_count += 1;
+        }
+        let queued = deposits.split_off(included_count);
+        (deposits, queued)
+    }
+}
+
+/// The deposits included in the rollup data of the current block, and those queued for
+/// subsequent blocks.
+#[derive(Debug, Default)]
+pub(crate) struct BlockDeposits {
+    pub(crate) included: HashMap<RollupId, Vec<Deposit>>,
+    /// The deposits queued for each rollup which had queued or new deposits in the current
+    /// block. A rollup's queue is empty once all of its deposits have been included.
+    pub(crate) queued: HashMap<RollupId, Vec<Deposit>>,
+}
+
+/// Returns the deposits to include in the current block.
+///
+/// The deposits queued in previous blocks are followed by the deposits made in the current block,
+/// and included up to the configured [`DepositLimits`]. All deposits are included if no limits
+/// are configured.
+pub(crate) async fn get_block_deposits_within_limits<S: StateRead>(
+    state: &S,
+) -> Result<BlockDeposits> {
+    let mut deposits = state
+        .get_queued_deposits()
+        .await
+        .context("failed to get queued deposits")?;
+    let new_deposits = state
+        .get_block_deposits()
+        .await
+        .context("failed to get block deposits")?;
+    for (rollup_id, new_deposits) in new_deposits {
+        deposits.entry(rollup_id).or_default().extend(new_deposits);
+    }
+
+    let Some(limits) = state
+        .get_deposit_limits()
+        .await
+        .context("failed to get deposit limits")?
+    else {
+        return Ok(BlockDeposits {
+            included: deposits,
+            queued: HashMap::new(),
+        });
+    };
+
+    let mut block_deposits = BlockDeposits::default();
+    for (rollup_id, deposits) in deposits {
+        let (included, queued) = limits.split(deposits);
+        block_deposits.included.insert(rollup_id, included);
+        block_deposits.queued.insert(rollup_id, queued);
+    }
+    Ok(block_deposits)
+}
+
+#[cfg(test)]
+mod test {
+    use astria_core::primitive::v1::asset;
+    use cnidarium::StateDelta;
+
+    use super::*;
+    use crate::bridge::state_ext::StateWriteExt as _;
+
+    fn deposit(rollup_id: RollupId, amount: u128) -> Deposit {
+        Deposit::new(
+            crate::address::base_prefixed([42; 20]),
+            rollup_id,
+            amount,
+            asset::Id::from_str_unchecked("asset_0"),
+            "0xdeadbeef".to_string(),
+        )
+    }
+
+    fn limits(max_deposits: u32, max_bytes: u64) -> DepositLimits {
+        DepositLimits {
+            max_deposits_per_rollup: NonZeroU32::new(max_deposits).unwrap(),
+            max_deposit_bytes_per_rollup: NonZeroU64::new(max_bytes).unwrap(),
+        }
+    }
+
+    #[test]
+    fn split_respects_max_deposits() {
+        let rollup_id = RollupId::new([1; 32]);
+        let deposits: Vec<_> = (1..=5).map(|amount| deposit(rollup_id, amount)).collect();
+
+        let (included, queued) = limits(2, u64::MAX).split(deposits.clone());
+        assert_eq!(included, deposits[..2]);
+        assert_eq!(queued, deposits[2..]);
+    }
+
+    #[test]
+    fn split_respects_max_bytes_but_includes_first_deposit() {
+        let rollup_id = RollupId::new([1; 32]);
+        let deposits: Vec<_> = (1..=3).map(|amount| deposit(rollup_id, amount)).collect();
+        let encoded_len = deposits[0].clone().into_raw().encoded_len() as u64;
+
+        let (included, queued) = limits(10, 1).split(deposits.clone());
+        assert_eq!(included, deposits[..1]);
+        assert_eq!(queued, deposits[1..]);
+
+        let (included, queued) = limits(10, encoded_len * 2).split(deposits.clone());
+        assert_eq!(included, deposits[..2]);
+        assert_eq!(queued, deposits[2..]);
+    }
+
+    #[tokio::test]
+    async fn queued_deposits_are_included_before_new_deposits() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+        state.put_deposit_limits(&limits(2, u64::MAX));
+
+        let rollup_id = RollupId::new([1; 32]);
+        let queued = vec![deposit(rollup_id, 1)];
+        state.put_queued_deposits(&rollup_id, queued.clone());
+        let new: Vec<_> = (2..=4).map(|amount| deposit(rollup_id, amount)).collect();
+        for deposit in new.clone() {
+            state.put_deposit_event(deposit).await.unwrap();
+        }
+
+        let block_deposits = get_block_deposits_within_limits(&state).await.unwrap();
+        assert_eq!(
+            block_deposits.included[&rollup_id],
+            vec![queued[0].clone(), new[0].clone()],
+        );
+        assert_eq!(block_deposits.queued[&rollup_id], new[1..]);
+    }
+
+    #[tokio::test]
+    async fn all_deposits_are_included_without_limits() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let rollup_id = RollupId::new([1; 32]);
+        let new: Vec<_> = (1..=3).map(|amount| deposit(rollup_id, amount)).collect();
+        for deposit in new.clone() {
+            state.put_deposit_event(deposit).await.unwrap();
+        }
+
+        let block_deposits = get_block_deposits_within_limits(&state).await.unwrap();
+        assert_eq!(block_deposits.included[&rollup_id], new);
+        assert!(block_deposits.queued.is_empty());
+    }
+}
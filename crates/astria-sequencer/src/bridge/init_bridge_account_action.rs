@@ -15,6 +15,7 @@ use crate::{
         StateReadExt as _,
         StateWriteExt as _,
     },
+    address::StateReadExt as _,
     bridge::state_ext::{
         StateReadExt as _,
         StateWriteExt as _,
@@ -28,27 +29,24 @@ use crate::{
 
 #[async_trait::async_trait]
 impl ActionHandler for InitBridgeAccountAction {
-    async fn check_stateless(&self) -> Result<()> {
-        self.withdrawer_address
-            .as_ref()
-            .map(crate::address::ensure_base_prefix)
-            .transpose()
-            .context("the withdrawer address has an unsupported prefix")?;
-
-        self.sudo_address
-            .as_ref()
-            .map(crate::address::ensure_base_prefix)
-            .transpose()
-            .context("the sudo address has an unsupported")?;
-
-        Ok(())
-    }
-
     async fn check_stateful<S: StateReadExt + 'static>(
         &self,
         state: &S,
         from: Address,
     ) -> Result<()> {
+        if let Some(withdrawer_address) = &self.withdrawer_address {
+            state
+                .ensure_allowed_prefix(withdrawer_address)
+                .await
+                .context("the withdrawer address has an unsupported prefix")?;
+        }
+        if let Some(sudo_address) = &self.sudo_address {
+            state
+                .ensure_allowed_prefix(sudo_address)
+                .await
+                .context("the sudo address has an unsupported prefix")?;
+        }
+
         ensure!(
             state.is_allowed_fee_asset(self.fee_asset_id).await?,
             "invalid fee asset",
@@ -106,6 +104,10 @@ impl ActionHandler for InitBridgeAccountAction {
             .decrease_balance(from, self.fee_asset_id, fee)
             .await
             .context("failed to deduct fee from account balance")?;
+        state
+            .get_and_increase_burned_fees(self.fee_asset_id, fee)
+            .await
+            .context("failed to add to burned fees")?;
         Ok(())
     }
 }
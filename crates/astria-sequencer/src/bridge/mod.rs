@@ -1,9 +1,12 @@
 mod bridge_lock_action;
+mod bridge_recovery_action;
 mod bridge_sudo_change_action;
 mod bridge_unlock_action;
 pub(crate) mod component;
+pub(crate) mod deposit_limits;
 pub(crate) mod init_bridge_account_action;
 pub(crate) mod query;
 pub(crate) mod state_ext;
 
 pub(crate) use bridge_lock_action::get_deposit_byte_len;
+pub(crate) use deposit_limits::DepositLimits;
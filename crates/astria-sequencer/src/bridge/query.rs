@@ -1,9 +1,21 @@
 use anyhow::Context as _;
 use astria_core::{
-    primitive::v1::Address,
-    protocol::abci::AbciErrorCode,
+    primitive::v1::{
+        Address,
+        RollupId,
+    },
+    protocol::{
+        abci::AbciErrorCode,
+        bridge::v1alpha1::{
+            BridgeAccountInfo,
+            BridgeAccountInfoResponse,
+        },
+    },
+};
+use cnidarium::{
+    StateRead,
+    Storage,
 };
-use cnidarium::Storage;
 use prost::Message as _;
 use tendermint::abci::{
     request,
@@ -74,6 +86,164 @@ pub(crate) async fn bridge_account_last_tx_hash_request(
     }
 }
 
+pub(crate) async fn bridge_account_info_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let address = match preprocess_request(&params) {
+        Ok(address) => address,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let snapshot = storage.latest_snapshot();
+    let accounts = match get_bridge_account_info(&snapshot, address).await {
+        Ok(info) => info.into_iter().collect(),
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting bridge account info for provided address: {err:#}"),
+                ..response::Query::default()
+            };
+        }
+    };
+    bridge_account_info_response(&snapshot, &request, accounts).await
+}
+
+pub(crate) async fn rollup_bridge_accounts_request(
+    storage: Storage,
+    request: request::Query,
+    params: Vec<(String, String)>,
+) -> response::Query {
+    let rollup_id = match preprocess_rollup_id_request(&params) {
+        Ok(rollup_id) => rollup_id,
+        Err(err_rsp) => return err_rsp,
+    };
+
+    let snapshot = storage.latest_snapshot();
+    let accounts = match get_rollup_bridge_account_infos(&snapshot, &rollup_id).await {
+        Ok(accounts) => accounts,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting bridge accounts for provided rollup ID: {err:#}"),
+                ..response::Query::default()
+            };
+        }
+    };
+    bridge_account_info_response(&snapshot, &request, accounts).await
+}
+
+async fn get_rollup_bridge_account_infos<S: StateRead>(
+    state: &S,
+    rollup_id: &RollupId,
+) -> anyhow::Result<Vec<BridgeAccountInfo>> {
+    let addresses = state
+        .get_rollup_bridge_accounts(rollup_id)
+        .await
+        .context("failed getting bridge accounts of rollup")?;
+    let mut accounts = Vec::with_capacity(addresses.len());
+    for address in addresses {
+        if let Some(info) = get_bridge_account_info(state, address).await? {
+            accounts.push(info);
+        }
+    }
+    Ok(accounts)
+}
+
+/// Returns the details of the bridge account at `address`, or `None` if it is not a bridge
+/// account.
+async fn get_bridge_account_info<S: StateRead>(
+    state: &S,
+    address: Address,
+) -> anyhow::Result<Option<BridgeAccountInfo>> {
+    let Some(rollup_id) = state
+        .get_bridge_account_rollup_id(&address)
+        .await
+        .context("failed getting rollup ID of bridge account")?
+    else {
+        return Ok(None);
+    };
+    let asset_id = state
+        .get_bridge_account_asset_id(&address)
+        .await
+        .context("failed getting asset ID of bridge account")?;
+    let sudo_address = state
+        .get_bridge_account_sudo_address(&address)
+        .await
+        .context("failed getting sudo address of bridge account")?;
+    let withdrawer_address = state
+        .get_bridge_account_withdrawer_address(&address)
+        .await
+        .context("failed getting withdrawer address of bridge account")?;
+    Ok(Some(BridgeAccountInfo {
+        address,
+        rollup_id,
+        asset_id,
+        sudo_address,
+        withdrawer_address,
+    }))
+}
+
+async fn bridge_account_info_response<S: StateRead>(
+    state: &S,
+    request: &request::Query,
+    accounts: Vec<BridgeAccountInfo>,
+) -> response::Query {
+    let height = match state.get_block_height().await {
+        Ok(height) => height,
+        Err(err) => {
+            return response::Query {
+                code: AbciErrorCode::INTERNAL_ERROR.into(),
+                info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                log: format!("failed getting block height: {err:#}"),
+                ..response::Query::default()
+            };
+        }
+    };
+    let payload = BridgeAccountInfoResponse {
+        height,
+        accounts,
+    }
+    .into_raw()
+    .encode_to_vec()
+    .into();
+
+    let height = tendermint::block::Height::try_from(height).expect("height must fit into an i64");
+    response::Query {
+        code: 0.into(),
+        key: request.path.clone().into_bytes().into(),
+        value: payload,
+        height,
+        ..response::Query::default()
+    }
+}
+
+fn preprocess_rollup_id_request(params: &[(String, String)]) -> Result<RollupId, response::Query> {
+    let Some(rollup_id) = params
+        .iter()
+        .find_map(|(k, v)| (k == "rollup_id").then_some(v))
+    else {
+        return Err(response::Query {
+            code: AbciErrorCode::INVALID_PARAMETER.into(),
+            info: AbciErrorCode::INVALID_PARAMETER.to_string(),
+            log: "path did not contain rollup_id parameter".into(),
+            ..response::Query::default()
+        });
+    };
+    hex::decode(rollup_id)
+        .context("failed decoding hex encoded bytes")
+        .and_then(|bytes| RollupId::try_from_slice(&bytes).context("invalid rollup ID bytes"))
+        .map_err(|err| response::Query {
+            code: AbciErrorCode::INVALID_PARAMETER.into(),
+            info: AbciErrorCode::INVALID_PARAMETER.to_string(),
+            log: format!("rollup ID could not be constructed from provided parameter: {err:#}"),
+            ..response::Query::default()
+        })
+}
+
 fn preprocess_request(params: &[(String, String)]) -> anyhow::Result<Address, response::Query> {
     let Some(address) = params
         .iter()
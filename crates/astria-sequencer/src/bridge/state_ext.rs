@@ -1,6 +1,12 @@
-use std::collections::{
-    HashMap,
-    HashSet,
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    num::{
+        NonZeroU32,
+        NonZeroU64,
+    },
 };
 
 use anyhow::{
@@ -34,6 +40,8 @@ use tracing::{
     instrument,
 };
 
+use super::DepositLimits;
+
 /// Newtype wrapper to read and write a u128 from rocksdb.
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 struct Balance(u128);
@@ -56,13 +64,113 @@ impl From<&asset::Id> for AssetId {
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 struct Fee(u128);
 
+/// Newtype wrapper to read and write a u64 from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct RecoveryDelay(u64);
+
+/// A scheduled reassignment of a bridge account's sudo and/or withdrawer address.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct BridgeRecovery {
+    pub(crate) new_sudo_address: Option<Address>,
+    pub(crate) new_withdrawer_address: Option<Address>,
+    /// The height at the end of which the recovery is executed unless vetoed.
+    pub(crate) execution_height: u64,
+}
+
+impl BridgeRecovery {
+    pub(crate) fn is_due(&self, height: u64) -> bool {
+        height >= self.execution_height
+    }
+}
+
+/// The storage representation of the [`DepositLimits`].
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct StoredDepositLimits {
+    max_deposits_per_rollup: u32,
+    max_deposit_bytes_per_rollup: u64,
+}
+
+impl From<&DepositLimits> for StoredDepositLimits {
+    fn from(limits: &DepositLimits) -> Self {
+        Self {
+            max_deposits_per_rollup: limits.max_deposits_per_rollup.get(),
+            max_deposit_bytes_per_rollup: limits.max_deposit_bytes_per_rollup.get(),
+        }
+    }
+}
+
+impl TryFrom<StoredDepositLimits> for DepositLimits {
+    type Error = anyhow::Error;
+
+    fn try_from(stored: StoredDepositLimits) -> Result<Self> {
+        Ok(Self {
+            max_deposits_per_rollup: NonZeroU32::new(stored.max_deposits_per_rollup)
+                .context("max deposits per rollup must not be zero")?,
+            max_deposit_bytes_per_rollup: NonZeroU64::new(stored.max_deposit_bytes_per_rollup)
+                .context("max deposit bytes per rollup must not be zero")?,
+        })
+    }
+}
+
+/// Newtype wrapper to read and write a rollup's queued deposits, each encoded as its protobuf
+/// representation, from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct QueuedDeposits(Vec<Vec<u8>>);
+
+/// The storage representation of a [`BridgeRecovery`].
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct StoredBridgeRecovery {
+    new_sudo_address: Option<[u8; 20]>,
+    new_withdrawer_address: Option<[u8; 20]>,
+    execution_height: u64,
+}
+
+impl From<&BridgeRecovery> for StoredBridgeRecovery {
+    fn from(recovery: &BridgeRecovery) -> Self {
+        Self {
+            new_sudo_address: recovery.new_sudo_address.map(|address| address.bytes()),
+            new_withdrawer_address: recovery
+                .new_withdrawer_address
+                .map(|address| address.bytes()),
+            execution_height: recovery.execution_height,
+        }
+    }
+}
+
+impl TryFrom<StoredBridgeRecovery> for BridgeRecovery {
+    type Error = anyhow::Error;
+
+    fn try_from(stored: StoredBridgeRecovery) -> Result<Self> {
+        let new_sudo_address = stored
+            .new_sudo_address
+            .map(|bytes| crate::address::try_base_prefixed(&bytes))
+            .transpose()
+            .context("invalid new sudo address bytes")?;
+        let new_withdrawer_address = stored
+            .new_withdrawer_address
+            .map(|bytes| crate::address::try_base_prefixed(&bytes))
+            .transpose()
+            .context("invalid new withdrawer address bytes")?;
+        Ok(Self {
+            new_sudo_address,
+            new_withdrawer_address,
+            execution_height: stored.execution_height,
+        })
+    }
+}
+
 const BRIDGE_ACCOUNT_PREFIX: &str = "bridgeacc";
 const BRIDGE_ACCOUNT_SUDO_PREFIX: &str = "bsudo";
 const BRIDGE_ACCOUNT_WITHDRAWER_PREFIX: &str = "bwithdrawer";
+const BRIDGE_ACCOUNT_RECOVERY_PREFIX: &str = "brecovery";
 const DEPOSIT_PREFIX: &str = "deposit";
+const DEPOSIT_QUEUE_PREFIX: &str = "depositqueue";
+const DEPOSIT_LIMITS_STORAGE_KEY: &str = "depositlimits";
+const ROLLUP_BRIDGE_ACCOUNTS_PREFIX: &str = "rollupbridgeaccs";
 const INIT_BRIDGE_ACCOUNT_BASE_FEE_STORAGE_KEY: &str = "initbridgeaccfee";
 const BRIDGE_LOCK_BYTE_COST_MULTIPLIER_STORAGE_KEY: &str = "bridgelockmultiplier";
 const BRIDGE_SUDO_CHANGE_FEE_STORAGE_KEY: &str = "bridgesudofee";
+const BRIDGE_RECOVERY_DELAY_STORAGE_KEY: &str = "bridgerecoverydelay";
 
 struct BridgeAccountKey<'a> {
     prefix: &'static str,
@@ -100,6 +208,22 @@ fn asset_id_storage_key(address: &Address) -> String {
     )
 }
 
+fn rollup_bridge_accounts_storage_key_prefix(rollup_id: &RollupId) -> String {
+    format!(
+        "{ROLLUP_BRIDGE_ACCOUNTS_PREFIX}/{}/",
+        rollup_id.encode_hex::<String>()
+    )
+}
+
+fn rollup_bridge_account_storage_key(rollup_id: &RollupId, address: &Address) -> Vec<u8> {
+    format!(
+        "{}{}",
+        rollup_bridge_accounts_storage_key_prefix(rollup_id),
+        address.bytes().encode_hex::<String>()
+    )
+    .into()
+}
+
 fn deposit_storage_key_prefix(rollup_id: &RollupId) -> String {
     format!("{DEPOSIT_PREFIX}/{}", rollup_id.encode_hex::<String>())
 }
@@ -108,6 +232,13 @@ fn deposit_storage_key(rollup_id: &RollupId, nonce: u32) -> Vec<u8> {
     format!("{}/{}", deposit_storage_key_prefix(rollup_id), nonce).into()
 }
 
+fn deposit_queue_storage_key(rollup_id: &RollupId) -> String {
+    format!(
+        "{DEPOSIT_QUEUE_PREFIX}/{}",
+        rollup_id.encode_hex::<String>()
+    )
+}
+
 fn deposit_nonce_storage_key(rollup_id: &RollupId) -> Vec<u8> {
     format!("depositnonce/{}", rollup_id.encode_hex::<String>()).into()
 }
@@ -132,6 +263,16 @@ fn bridge_account_withdrawer_address_storage_key(address: &Address) -> String {
     )
 }
 
+fn bridge_account_recovery_storage_key(address: &Address) -> String {
+    format!(
+        "{}",
+        BridgeAccountKey {
+            prefix: BRIDGE_ACCOUNT_RECOVERY_PREFIX,
+            address
+        }
+    )
+}
+
 fn last_transaction_hash_for_bridge_account_storage_key(address: &Address) -> Vec<u8> {
     format!(
         "{}/lasttx",
@@ -162,6 +303,88 @@ pub(crate) trait StateReadExt: StateRead {
         Ok(Some(rollup_id))
     }
 
+    /// Returns the bridge accounts that deposit to `rollup_id`.
+    ///
+    /// Only bridge accounts whose rollup ID was written since this index was introduced are
+    /// returned. Accounts whose rollup ID was since overwritten with a different one are skipped.
+    #[instrument(skip(self))]
+    async fn get_rollup_bridge_accounts(&self, rollup_id: &RollupId) -> Result<Vec<Address>> {
+        let mut stream = std::pin::pin!(self.nonverifiable_prefix_raw(
+            rollup_bridge_accounts_storage_key_prefix(rollup_id).as_bytes()
+        ));
+        let mut addresses = Vec::new();
+        while let Some(entry) = stream.next().await {
+            let (_, address_bytes) =
+                entry.context("failed reading rollup bridge accounts from state")?;
+            let address = crate::address::try_base_prefixed(&address_bytes)
+                .context("invalid bridge account address bytes")?;
+            addresses.push(address);
+        }
+        let mut current = Vec::with_capacity(addresses.len());
+        for address in addresses {
+            if self.get_bridge_account_rollup_id(&address).await? == Some(*rollup_id) {
+                current.push(address);
+            }
+        }
+        Ok(current)
+    }
+
+    /// Returns all bridge accounts and the rollup IDs they deposit to, as recorded in the
+    /// verifiable state.
+    #[instrument(skip(self))]
+    async fn get_bridge_accounts(&self) -> Result<Vec<(Address, RollupId)>> {
+        let mut stream = std::pin::pin!(self.prefix_raw(&format!("{BRIDGE_ACCOUNT_PREFIX}/")));
+        let mut bridge_accounts = Vec::new();
+        while let Some(entry) = stream.next().await {
+            let (key, rollup_id_bytes) =
+                entry.context("failed reading bridge accounts from state")?;
+            // the rollup ID key is of the form "bridgeacc/{address}/rollupid"; the other fields
+            // of a bridge account are skipped
+            let Some(address_hex) = key
+                .strip_prefix(&format!("{BRIDGE_ACCOUNT_PREFIX}/"))
+                .and_then(|rest| rest.strip_suffix("/rollupid"))
+            else {
+                continue;
+            };
+            let address_bytes =
+                hex::decode(address_hex).context("invalid bridge address hex string")?;
+            let address = crate::address::try_base_prefixed(&address_bytes)
+                .context("invalid bridge address bytes")?;
+            let rollup_id =
+                RollupId::try_from_slice(&rollup_id_bytes).context("invalid rollup ID bytes")?;
+            bridge_accounts.push((address, rollup_id));
+        }
+        Ok(bridge_accounts)
+    }
+
+    /// Returns all entries of the nonverifiable index of bridge accounts by rollup ID,
+    /// including stale entries of accounts whose rollup ID was since overwritten.
+    #[instrument(skip(self))]
+    async fn get_rollup_bridge_account_index(&self) -> Result<Vec<(RollupId, Address)>> {
+        let mut stream = std::pin::pin!(
+            self.nonverifiable_prefix_raw(format!("{ROLLUP_BRIDGE_ACCOUNTS_PREFIX}/").as_bytes())
+        );
+        let mut entries = Vec::new();
+        while let Some(entry) = stream.next().await {
+            let (key, address_bytes) =
+                entry.context("failed reading rollup bridge accounts from state")?;
+            // the index key is of the form "rollupbridgeaccs/{rollup_id}/{address}"
+            let key = String::from_utf8(key)
+                .context("failed to convert rollup bridge account key to string")?;
+            let Some(rollup_id_hex) = key.split('/').nth(1) else {
+                continue;
+            };
+            let rollup_id_bytes =
+                hex::decode(rollup_id_hex).context("invalid rollup ID hex string")?;
+            let rollup_id =
+                RollupId::try_from_slice(&rollup_id_bytes).context("invalid rollup ID bytes")?;
+            let address = crate::address::try_base_prefixed(&address_bytes)
+                .context("invalid bridge account address bytes")?;
+            entries.push((rollup_id, address));
+        }
+        Ok(entries)
+    }
+
     #[instrument(skip(self))]
     async fn get_bridge_account_asset_id(&self, address: &Address) -> Result<asset::Id> {
         let bytes = self
@@ -283,6 +506,48 @@ pub(crate) trait StateReadExt: StateRead {
         Ok(deposit_events)
     }
 
+    /// Returns the limits on the deposits included in a rollup's data per block, or `None` if
+    /// the deposits are unbounded.
+    #[instrument(skip(self))]
+    async fn get_deposit_limits(&self) -> Result<Option<DepositLimits>> {
+        let Some(bytes) = self
+            .get_raw(DEPOSIT_LIMITS_STORAGE_KEY)
+            .await
+            .context("failed reading raw deposit limits from state")?
+        else {
+            return Ok(None);
+        };
+        let stored =
+            StoredDepositLimits::try_from_slice(&bytes).context("invalid deposit limits bytes")?;
+        let limits = DepositLimits::try_from(stored)?;
+        Ok(Some(limits))
+    }
+
+    /// Returns the deposits which exceeded the limits of previous blocks, in the order they
+    /// are to be included in subsequent blocks.
+    #[instrument(skip(self))]
+    async fn get_queued_deposits(&self) -> Result<HashMap<RollupId, Vec<Deposit>>> {
+        let mut stream = std::pin::pin!(self.prefix_raw(&format!("{DEPOSIT_QUEUE_PREFIX}/")));
+        let mut queued_deposits = HashMap::new();
+        while let Some(entry) = stream.next().await {
+            let (_, bytes) = entry.context("failed reading queued deposits from state")?;
+            let QueuedDeposits(raw_deposits) =
+                QueuedDeposits::try_from_slice(&bytes).context("invalid queued deposits bytes")?;
+            let mut deposits = Vec::with_capacity(raw_deposits.len());
+            for raw_deposit in raw_deposits {
+                let raw = RawDeposit::decode(raw_deposit.as_slice())
+                    .context("invalid queued deposit bytes")?;
+                let deposit =
+                    Deposit::try_from_raw(raw).context("invalid queued deposit raw proto")?;
+                deposits.push(deposit);
+            }
+            if let Some(deposit) = deposits.first() {
+                queued_deposits.insert(*deposit.rollup_id(), deposits);
+            }
+        }
+        Ok(queued_deposits)
+    }
+
     #[instrument(skip(self))]
     async fn get_init_bridge_account_base_fee(&self) -> Result<u128> {
         let bytes = self
@@ -316,6 +581,104 @@ pub(crate) trait StateReadExt: StateRead {
         Ok(fee)
     }
 
+    /// Returns the number of blocks between the scheduling and execution of a bridge account
+    /// recovery, or `None` if bridge account recovery is disabled.
+    #[instrument(skip(self))]
+    async fn get_bridge_recovery_delay(&self) -> Result<Option<u64>> {
+        let Some(bytes) = self
+            .get_raw(BRIDGE_RECOVERY_DELAY_STORAGE_KEY)
+            .await
+            .context("failed reading raw bridge recovery delay from state")?
+        else {
+            return Ok(None);
+        };
+        let RecoveryDelay(delay) =
+            RecoveryDelay::try_from_slice(&bytes).context("invalid bridge recovery delay bytes")?;
+        Ok(Some(delay))
+    }
+
+    #[instrument(skip(self))]
+    async fn get_bridge_account_recovery(
+        &self,
+        bridge_address: &Address,
+    ) -> Result<Option<BridgeRecovery>> {
+        let Some(bytes) = self
+            .get_raw(&bridge_account_recovery_storage_key(bridge_address))
+            .await
+            .context("failed reading raw bridge account recovery from state")?
+        else {
+            return Ok(None);
+        };
+        let stored = StoredBridgeRecovery::try_from_slice(&bytes)
+            .context("invalid bridge account recovery bytes")?;
+        let recovery = BridgeRecovery::try_from(stored)?;
+        Ok(Some(recovery))
+    }
+
+    /// Returns all pending bridge account recoveries which are due for execution at `height`,
+    /// keyed by the address of the bridge account they recover.
+    #[instrument(skip(self))]
+    async fn get_due_bridge_account_recoveries(
+        &self,
+        height: u64,
+    ) -> Result<Vec<(Address, BridgeRecovery)>> {
+        let mut stream =
+            std::pin::pin!(self.prefix_raw(&format!("{BRIDGE_ACCOUNT_RECOVERY_PREFIX}/")));
+        let mut recoveries = Vec::new();
+        while let Some(entry) = stream.next().await {
+            let (key, bytes) =
+                entry.context("failed reading bridge account recoveries from state")?;
+            // the recovery key is of the form "brecovery/{bridge_address}"
+            let Some((_, address_hex)) = key.split_once('/') else {
+                continue;
+            };
+            let address_bytes =
+                hex::decode(address_hex).context("invalid bridge address hex string")?;
+            let bridge_address = crate::address::try_base_prefixed(&address_bytes)
+                .context("invalid bridge address bytes")?;
+            let stored = StoredBridgeRecovery::try_from_slice(&bytes)
+                .context("invalid bridge account recovery bytes")?;
+            let recovery = BridgeRecovery::try_from(stored)?;
+            if recovery.is_due(height) {
+                recoveries.push((bridge_address, recovery));
+            }
+        }
+        Ok(recoveries)
+    }
+
+    /// Returns the hash of the last transaction of every bridge account which has one.
+    #[instrument(skip(self))]
+    async fn get_last_transaction_hashes_for_bridge_accounts(
+        &self,
+    ) -> Result<Vec<(Address, [u8; 32])>> {
+        let mut stream = std::pin::pin!(
+            self.nonverifiable_prefix_raw(format!("{BRIDGE_ACCOUNT_PREFIX}/").as_bytes())
+        );
+        let mut tx_hashes = Vec::new();
+        while let Some(entry) = stream.next().await {
+            let (key, tx_hash_bytes) =
+                entry.context("failed reading last transaction hashes from state")?;
+            // the key is of the form "bridgeacc/{address}/lasttx"
+            let key = String::from_utf8(key)
+                .context("failed to convert last transaction hash key to string")?;
+            let Some(address_hex) = key
+                .strip_prefix(&format!("{BRIDGE_ACCOUNT_PREFIX}/"))
+                .and_then(|rest| rest.strip_suffix("/lasttx"))
+            else {
+                continue;
+            };
+            let address_bytes =
+                hex::decode(address_hex).context("invalid bridge address hex string")?;
+            let address = crate::address::try_base_prefixed(&address_bytes)
+                .context("invalid bridge address bytes")?;
+            let tx_hash = tx_hash_bytes
+                .try_into()
+                .expect("all transaction hashes stored should be 32 bytes; this is a bug");
+            tx_hashes.push((address, tx_hash));
+        }
+        Ok(tx_hashes)
+    }
+
     #[instrument(skip(self))]
     async fn get_last_transaction_hash_for_bridge_account(
         &self,
@@ -345,6 +708,22 @@ pub(crate) trait StateWriteExt: StateWrite {
     #[instrument(skip(self))]
     fn put_bridge_account_rollup_id(&mut self, address: &Address, rollup_id: &RollupId) {
         self.put_raw(rollup_id_storage_key(address), rollup_id.to_vec());
+        self.put_rollup_bridge_account_index(rollup_id, address);
+    }
+
+    /// Indexes the bridge account by its rollup ID for lookups by rollup. The index is kept out
+    /// of the verifiable state so that it does not affect the app hash.
+    #[instrument(skip(self))]
+    fn put_rollup_bridge_account_index(&mut self, rollup_id: &RollupId, address: &Address) {
+        self.nonverifiable_put_raw(
+            rollup_bridge_account_storage_key(rollup_id, address),
+            address.bytes().to_vec(),
+        );
+    }
+
+    #[instrument(skip(self))]
+    fn delete_rollup_bridge_account_index(&mut self, rollup_id: &RollupId, address: &Address) {
+        self.nonverifiable_delete(rollup_bridge_account_storage_key(rollup_id, address));
     }
 
     #[instrument(skip(self))]
@@ -432,6 +811,37 @@ pub(crate) trait StateWriteExt: StateWrite {
         Ok(())
     }
 
+    #[instrument(skip(self))]
+    fn put_deposit_limits(&mut self, limits: &DepositLimits) {
+        self.put_raw(
+            DEPOSIT_LIMITS_STORAGE_KEY.to_string(),
+            borsh::to_vec(&StoredDepositLimits::from(limits))
+                .expect("failed to serialize deposit limits"),
+        );
+    }
+
+    /// Replaces the deposits queued for `rollup_id`, removing the queue if `deposits` is empty.
+    ///
+    /// The queue is part of the verifiable state as it determines the contents of subsequent
+    /// blocks.
+    #[instrument(skip_all, fields(%rollup_id, deposits = deposits.len()))]
+    fn put_queued_deposits(&mut self, rollup_id: &RollupId, deposits: Vec<Deposit>) {
+        let key = deposit_queue_storage_key(rollup_id);
+        if deposits.is_empty() {
+            self.delete(key);
+            return;
+        }
+        let raw_deposits = deposits
+            .into_iter()
+            .map(|deposit| deposit.into_raw().encode_to_vec())
+            .collect();
+        self.put_raw(
+            key,
+            borsh::to_vec(&QueuedDeposits(raw_deposits))
+                .expect("failed to serialize queued deposits"),
+        );
+    }
+
     #[instrument(skip(self))]
     fn put_init_bridge_account_base_fee(&mut self, fee: u128) {
         self.put_raw(
@@ -456,6 +866,29 @@ pub(crate) trait StateWriteExt: StateWrite {
         );
     }
 
+    #[instrument(skip(self))]
+    fn put_bridge_recovery_delay(&mut self, delay: u64) {
+        self.put_raw(
+            BRIDGE_RECOVERY_DELAY_STORAGE_KEY.to_string(),
+            borsh::to_vec(&RecoveryDelay(delay)).expect("failed to serialize recovery delay"),
+        );
+    }
+
+    /// Schedules `recovery` for the bridge account, replacing any recovery already pending.
+    #[instrument(skip(self))]
+    fn put_bridge_account_recovery(&mut self, bridge_address: &Address, recovery: &BridgeRecovery) {
+        self.put_raw(
+            bridge_account_recovery_storage_key(bridge_address),
+            borsh::to_vec(&StoredBridgeRecovery::from(recovery))
+                .expect("failed to serialize bridge account recovery"),
+        );
+    }
+
+    #[instrument(skip(self))]
+    fn delete_bridge_account_recovery(&mut self, bridge_address: &Address) {
+        self.delete(bridge_account_recovery_storage_key(bridge_address));
+    }
+
     #[instrument(skip(self))]
     fn put_last_transaction_hash_for_bridge_account(
         &mut self,
@@ -467,6 +900,13 @@ pub(crate) trait StateWriteExt: StateWrite {
             tx_hash.to_vec(),
         );
     }
+
+    #[instrument(skip(self))]
+    fn delete_last_transaction_hash_for_bridge_account(&mut self, address: &Address) {
+        self.nonverifiable_delete(last_transaction_hash_for_bridge_account_storage_key(
+            address,
+        ));
+    }
 }
 
 impl<T: StateWrite> StateWriteExt for T {}
@@ -486,9 +926,11 @@ mod test {
 
     use super::{
         asset_id_storage_key,
+        bridge_account_recovery_storage_key,
         bridge_account_sudo_address_storage_key,
         bridge_account_withdrawer_address_storage_key,
         rollup_id_storage_key,
+        BridgeRecovery,
         StateReadExt as _,
         StateWriteExt as _,
     };
@@ -570,6 +1012,110 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn get_rollup_bridge_accounts() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let rollup_id = RollupId::new([1u8; 32]);
+        let other_rollup_id = RollupId::new([2u8; 32]);
+        assert!(state
+            .get_rollup_bridge_accounts(&rollup_id)
+            .await
+            .unwrap()
+            .is_empty());
+
+        let address_0 = crate::address::base_prefixed([40u8; 20]);
+        let address_1 = crate::address::base_prefixed([41u8; 20]);
+        let address_2 = crate::address::base_prefixed([42u8; 20]);
+        state.put_bridge_account_rollup_id(&address_0, &rollup_id);
+        state.put_bridge_account_rollup_id(&address_1, &rollup_id);
+        state.put_bridge_account_rollup_id(&address_2, &other_rollup_id);
+        assert_eq!(
+            vec![address_0, address_1],
+            state.get_rollup_bridge_accounts(&rollup_id).await.unwrap()
+        );
+
+        // an account moved to another rollup is no longer returned for the first
+        state.put_bridge_account_rollup_id(&address_0, &other_rollup_id);
+        assert_eq!(
+            vec![address_1],
+            state.get_rollup_bridge_accounts(&rollup_id).await.unwrap()
+        );
+        assert_eq!(
+            vec![address_0, address_2],
+            state
+                .get_rollup_bridge_accounts(&other_rollup_id)
+                .await
+                .unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_bridge_accounts_and_rollup_bridge_account_index() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let rollup_id = RollupId::new([1u8; 32]);
+        let other_rollup_id = RollupId::new([2u8; 32]);
+        let address_0 = crate::address::base_prefixed([40u8; 20]);
+        let address_1 = crate::address::base_prefixed([41u8; 20]);
+        state.put_bridge_account_rollup_id(&address_0, &rollup_id);
+        state.put_bridge_account_rollup_id(&address_1, &rollup_id);
+        state.put_bridge_account_sudo_address(&address_0, &address_1);
+        state.put_bridge_account_rollup_id(&address_1, &other_rollup_id);
+
+        assert_eq!(
+            vec![(address_0, rollup_id), (address_1, other_rollup_id)],
+            state.get_bridge_accounts().await.unwrap()
+        );
+        // the index still holds the entry for the rollup ID which was overwritten
+        assert_eq!(
+            vec![
+                (rollup_id, address_0),
+                (rollup_id, address_1),
+                (other_rollup_id, address_1),
+            ],
+            state.get_rollup_bridge_account_index().await.unwrap()
+        );
+
+        state.delete_rollup_bridge_account_index(&rollup_id, &address_1);
+        assert_eq!(
+            vec![(rollup_id, address_0), (other_rollup_id, address_1)],
+            state.get_rollup_bridge_account_index().await.unwrap()
+        );
+    }
+
+    #[tokio::test]
+    async fn get_last_transaction_hashes_for_bridge_accounts() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let address_0 = crate::address::base_prefixed([40u8; 20]);
+        let address_1 = crate::address::base_prefixed([41u8; 20]);
+        state.put_last_transaction_hash_for_bridge_account(&address_0, &[1u8; 32]);
+        state.put_last_transaction_hash_for_bridge_account(&address_1, &[2u8; 32]);
+        assert_eq!(
+            vec![(address_0, [1u8; 32]), (address_1, [2u8; 32])],
+            state
+                .get_last_transaction_hashes_for_bridge_accounts()
+                .await
+                .unwrap()
+        );
+
+        state.delete_last_transaction_hash_for_bridge_account(&address_0);
+        assert_eq!(
+            vec![(address_1, [2u8; 32])],
+            state
+                .get_last_transaction_hashes_for_bridge_accounts()
+                .await
+                .unwrap()
+        );
+    }
+
     #[tokio::test]
     async fn get_bridge_account_asset_id_none_should_fail() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
@@ -1145,6 +1691,95 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn bridge_recovery_delay_uninitialized_ok() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(state.get_bridge_recovery_delay().await.unwrap(), None);
+        state.put_bridge_recovery_delay(10);
+        assert_eq!(state.get_bridge_recovery_delay().await.unwrap(), Some(10));
+    }
+
+    #[tokio::test]
+    async fn put_and_delete_bridge_account_recovery() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let bridge_address = crate::address::base_prefixed([42u8; 20]);
+        assert_eq!(
+            state
+                .get_bridge_account_recovery(&bridge_address)
+                .await
+                .unwrap(),
+            None
+        );
+
+        let recovery = BridgeRecovery {
+            new_sudo_address: Some(crate::address::base_prefixed([41u8; 20])),
+            new_withdrawer_address: None,
+            execution_height: 10,
+        };
+        state.put_bridge_account_recovery(&bridge_address, &recovery);
+        assert_eq!(
+            state
+                .get_bridge_account_recovery(&bridge_address)
+                .await
+                .unwrap(),
+            Some(recovery)
+        );
+
+        state.delete_bridge_account_recovery(&bridge_address);
+        assert_eq!(
+            state
+                .get_bridge_account_recovery(&bridge_address)
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn get_due_bridge_account_recoveries() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let bridge_address_0 = crate::address::base_prefixed([40u8; 20]);
+        let bridge_address_1 = crate::address::base_prefixed([41u8; 20]);
+        let recovery_0 = BridgeRecovery {
+            new_sudo_address: None,
+            new_withdrawer_address: Some(crate::address::base_prefixed([1u8; 20])),
+            execution_height: 5,
+        };
+        let recovery_1 = BridgeRecovery {
+            new_sudo_address: Some(crate::address::base_prefixed([2u8; 20])),
+            new_withdrawer_address: Some(crate::address::base_prefixed([3u8; 20])),
+            execution_height: 10,
+        };
+        state.put_bridge_account_recovery(&bridge_address_0, &recovery_0);
+        state.put_bridge_account_recovery(&bridge_address_1, &recovery_1);
+
+        assert!(state
+            .get_due_bridge_account_recoveries(4)
+            .await
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            state.get_due_bridge_account_recoveries(5).await.unwrap(),
+            vec![(bridge_address_0, recovery_0.clone())]
+        );
+        assert_eq!(
+            state.get_due_bridge_account_recoveries(10).await.unwrap(),
+            vec![
+                (bridge_address_0, recovery_0),
+                (bridge_address_1, recovery_1)
+            ]
+        );
+    }
+
     #[test]
     fn storage_keys_have_not_changed() {
         let address: Address = "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm"
@@ -1155,5 +1790,6 @@ mod test {
         assert_snapshot!(asset_id_storage_key(&address));
         assert_snapshot!(bridge_account_sudo_address_storage_key(&address));
         assert_snapshot!(bridge_account_withdrawer_address_storage_key(&address));
+        assert_snapshot!(bridge_account_recovery_storage_key(&address));
     }
 }
@@ -29,8 +29,52 @@ pub struct Config {
     pub no_metrics: bool,
     /// The endpoint which will be listened on for serving prometheus metrics
     pub metrics_http_listener_addr: String,
+    /// The endpoint which will be listened on for serving CPU and heap profiles, or empty to
+    /// disable them. Requires the `profiling` feature.
+    pub profiling_http_listener_addr: String,
     /// Writes a human readable format to stdout instead of JSON formatted OTEL trace data.
     pub pretty_print: bool,
+    /// The number of transactions in the app-side mempool at which the node is considered
+    /// overloaded. 0 disables this check.
+    pub overload_mempool_transactions: usize,
+    /// The average `CheckTx` latency in milliseconds at which the node is considered overloaded.
+    /// 0 disables this check.
+    pub overload_check_tx_latency_ms: u64,
+    /// The resident memory in MiB at which the node is considered overloaded. 0 disables this
+    /// check.
+    pub overload_memory_mib: u64,
+    /// The minimum total fee a new transaction must pay to pass `CheckTx` while the node is
+    /// overloaded.
+    pub overload_min_fee: u128,
+    /// The maximum number of transactions a single account can have in the app-side mempool.
+    /// 0 disables this limit.
+    pub mempool_max_transactions_per_account: usize,
+    /// A comma-separated list of addresses exempt from
+    /// `mempool_max_transactions_per_account`.
+    pub mempool_account_limit_exempt_addresses: String,
+    /// The time in milliseconds after which an ABCI query is cancelled. 0 disables the deadline.
+    pub abci_query_timeout_ms: u64,
+    /// The growth in MiB of the resident memory while an ABCI query runs above which the query is
+    /// cancelled. 0 disables this limit.
+    pub abci_query_memory_mib: u64,
+    /// The maximum size in bytes of the value returned by an ABCI query. Queries with larger
+    /// results fail. 0 disables this limit.
+    pub abci_query_max_response_bytes: usize,
+    /// The sink to which executed transactions are archived after each commit, or empty to
+    /// disable archival. Only `file://<path>` is supported.
+    pub tx_archive_sink: String,
+    /// Set to true to run as a read-only query replica, which serves gRPC queries from a db
+    /// restored from a state snapshot and does not start the ABCI server. A replica does not
+    /// sync state itself and serves the state it was started with.
+    pub query_replica: bool,
+    /// The maximum number of gRPC requests handled at the same time. Requests above it are
+    /// rejected with `RESOURCE_EXHAUSTED`. 0 disables this limit.
+    pub grpc_max_concurrent_requests: usize,
+    /// The number of gRPC requests per second a single peer IP address may make. Requests above
+    /// it are rejected with `RESOURCE_EXHAUSTED`. 0 disables this limit.
+    pub grpc_requests_per_second_per_peer: u32,
+    /// The maximum size in bytes of a decoded gRPC request message. 0 disables this limit.
+    pub grpc_max_request_bytes: usize,
 }
 
 impl config::Config for Config {
@@ -0,0 +1,243 @@
+use anyhow::{
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::{
+    primitive::v1::Address,
+    protocol::transaction::v1alpha1::action::FeeDistributionChangeAction,
+};
+use async_trait::async_trait;
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+
+use crate::{
+    address::StateReadExt as _,
+    authority::state_ext::StateReadExt as _,
+    state_ext::StateWriteExt as _,
+    transaction::action_handler::ActionHandler,
+};
+
+/// How the fees collected in a block are distributed at the end of the block.
+///
+/// The shares are percentages which sum to 100. If the treasury share is not zero, a treasury
+/// address is set.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct FeeDistribution {
+    proposer_share: u32,
+    treasury_share: u32,
+    burn_share: u32,
+    treasury_address: Option<Address>,
+}
+
+/// The amounts a block fee is split into by a [`FeeDistribution`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) struct FeeSplit {
+    pub(crate) proposer: u128,
+    pub(crate) treasury: u128,
+    pub(crate) burned: u128,
+}
+
+impl FeeDistribution {
+    /// Returns a distribution of the given shares.
+    ///
+    /// # Errors
+    ///
+    /// - if the shares do not sum to 100
+    /// - if the treasury share is not zero but no treasury address is given
+    pub(crate) fn try_new(
+        proposer_share: u32,
+        treasury_share: u32,
+        burn_share: u32,
+        treasury_address: Option<Address>,
+    ) -> Result<Self> {
+        let total = proposer_share
+            .checked_add(treasury_share)
+            .and_then(|total| total.checked_add(burn_share));
+        ensure!(
+            total == Some(100),
+            "fee distribution shares must sum to 100, but proposer share `{proposer_share}`, \
+             treasury share `{treasury_share}` and burn share `{burn_share}` do not"
+        );
+        ensure!(
+            treasury_share == 0 || treasury_address.is_some(),
+            "a treasury address is required if the treasury share is not zero"
+        );
+        Ok(Self {
+            proposer_share,
+            treasury_share,
+            burn_share,
+            treasury_address,
+        })
+    }
+
+    /// Returns the distribution which pays all fees to `treasury_address`.
+    ///
+    /// This is the distribution of a chain on which no [`FeeDistributionChangeAction`] was
+    /// executed yet, with the sudo address as the treasury.
+    pub(crate) fn all_to_treasury(treasury_address: Address) -> Self {
+        Self {
+            proposer_share: 0,
+            treasury_share: 100,
+            burn_share: 0,
+            treasury_address: Some(treasury_address),
+        }
+    }
+
+    pub(crate) fn proposer_share(&self) -> u32 {
+        self.proposer_share
+    }
+
+    pub(crate) fn treasury_share(&self) -> u32 {
+        self.treasury_share
+    }
+
+    pub(crate) fn burn_share(&self) -> u32 {
+        self.burn_share
+    }
+
+    pub(crate) fn treasury_address(&self) -> Option<Address> {
+        self.treasury_address
+    }
+
+    /// Splits `amount` into the proposer, treasury and burned parts.
+    ///
+    /// The proposer and treasury parts are rounded down, so that the remainder of the division
+    /// is burned.
+    pub(crate) fn split(&self, amount: u128) -> FeeSplit {
+        let proposer = share_of(amount, self.proposer_share);
+        let treasury = share_of(amount, self.treasury_share);
+        FeeSplit {
+            proposer,
+            treasury,
+            burned: amount - proposer - treasury,
+        }
+    }
+}
+
+/// Returns `share` percent of `amount`, rounded down.
+///
+/// The division is done first so that the multiplication cannot overflow for any `share` of at
+/// most 100.
+fn share_of(amount: u128, share: u32) -> u128 {
+    let share = u128::from(share);
+    amount / 100 * share + amount % 100 * share / 100
+}
+
+impl TryFrom<&FeeDistributionChangeAction> for FeeDistribution {
+    type Error = anyhow::Error;
+
+    fn try_from(action: &FeeDistributionChangeAction) -> Result<Self> {
+        Self::try_new(
+            action.proposer_share,
+            action.treasury_share,
+            action.burn_share,
+            action.treasury_address,
+        )
+    }
+}
+
+#[async_trait]
+impl ActionHandler for FeeDistributionChangeAction {
+    async fn check_stateless(&self) -> Result<()> {
+        FeeDistribution::try_from(self).context("invalid fee distribution")?;
+        Ok(())
+    }
+
+    async fn check_stateful<S: StateRead + 'static>(&self, state: &S, from: Address) -> Result<()> {
+        let authority_sudo_address = state
+            .get_sudo_address()
+            .await
+            .context("failed to get authority sudo address")?;
+        ensure!(
+            authority_sudo_address == from,
+            "unauthorized address for fee distribution change"
+        );
+        if let Some(treasury_address) = &self.treasury_address {
+            state
+                .ensure_allowed_prefix(treasury_address)
+                .await
+                .context("treasury address has an unsupported prefix")?;
+        }
+        Ok(())
+    }
+
+    async fn execute<S: StateWrite>(&self, state: &mut S, _from: Address) -> Result<()> {
+        let fee_distribution =
+            FeeDistribution::try_from(self).context("invalid fee distribution")?;
+        state.put_fee_distribution(&fee_distribution);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn distribution(proposer_share: u32, treasury_share: u32, burn_share: u32) -> FeeDistribution {
+        FeeDistribution::try_new(
+            proposer_share,
+            treasury_share,
+            burn_share,
+            Some(crate::address::base_prefixed([1; 20])),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn shares_must_sum_to_100() {
+        assert!(FeeDistribution::try_new(50, 0, 49, None).is_err());
+        assert!(FeeDistribution::try_new(50, 0, 51, None).is_err());
+        assert!(FeeDistribution::try_new(u32::MAX, 0, 101, None).is_err());
+        assert!(FeeDistribution::try_new(50, 0, 50, None).is_ok());
+    }
+
+    #[test]
+    fn treasury_share_requires_treasury_address() {
+        assert!(FeeDistribution::try_new(50, 50, 0, None).is_err());
+        assert!(
+            FeeDistribution::try_new(50, 50, 0, Some(crate::address::base_prefixed([1; 20])))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn split_burns_rounding_remainder() {
+        assert_eq!(
+            distribution(50, 30, 20).split(1000),
+            FeeSplit {
+                proposer: 500,
+                treasury: 300,
+                burned: 200,
+            }
+        );
+        assert_eq!(
+            distribution(33, 33, 34).split(10),
+            FeeSplit {
+                proposer: 3,
+                treasury: 3,
+                burned: 4,
+            }
+        );
+        assert_eq!(
+            distribution(50, 50, 0).split(1),
+            FeeSplit {
+                proposer: 0,
+                treasury: 0,
+                burned: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn split_does_not_overflow() {
+        let split = distribution(100, 0, 0).split(u128::MAX);
+        assert_eq!(split.proposer, u128::MAX);
+        assert_eq!(split.burned, 0);
+
+        let split = distribution(99, 1, 0).split(u128::MAX);
+        assert_eq!(split.proposer + split.treasury + split.burned, u128::MAX);
+    }
+}
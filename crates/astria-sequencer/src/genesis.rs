@@ -1,6 +1,14 @@
-use astria_core::primitive::v1::{
-    asset,
-    Address,
+use std::{
+    collections::BTreeMap,
+    num::NonZeroU64,
+};
+
+use astria_core::{
+    primitive::v1::{
+        asset,
+        Address,
+    },
+    protocol::transaction::v1alpha1::Action,
 };
 use penumbra_ibc::params::IBCParameters;
 use serde::{
@@ -8,10 +16,15 @@ use serde::{
     Serialize,
 };
 
+use crate::{
+    bridge::DepositLimits,
+    ibc::ica_host,
+};
+
 /// The genesis state for the application.
 ///
 /// Verified to only contain valid fields (right now, addresses that have the same base prefix
-/// as set in `GenesisState::address_prefixes::base`).
+/// as set in `GenesisState::address_prefixes::base`, and valid allowed prefixes).
 ///
 /// **NOTE:** The fields should not be publicly accessible to guarantee invariants. However,
 /// it's easy to just go along with this for now.
@@ -27,6 +40,11 @@ pub(crate) struct GenesisState {
     pub(crate) ibc_params: IBCParameters,
     pub(crate) allowed_fee_assets: Vec<asset::Denom>,
     pub(crate) fees: Fees,
+    pub(crate) ica_host: Option<ica_host::HostParams>,
+    pub(crate) bridge_recovery_delay: Option<NonZeroU64>,
+    pub(crate) deposit_limits: Option<DepositLimits>,
+    pub(crate) action_activation_heights: BTreeMap<String, NonZeroU64>,
+    pub(crate) fee_summary_activation_height: Option<NonZeroU64>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -40,13 +58,30 @@ pub(crate) enum VerifyGenesisError {
         address: Address,
         field: String,
     },
+    #[error(
+        "allowed address prefix `{prefix}` at `{field}` is not a valid lowercase bech32 prefix"
+    )]
+    InvalidAllowedPrefix { prefix: String, field: String },
+    #[error("allowed address prefix `{prefix}` at `{field}` is the same as the base prefix")]
+    AllowedPrefixIsBase { prefix: String, field: String },
+    #[error(
+        "ics20 forward fee of {basis_points} basis points at \
+         `.fees.ics20_forward_fee_basis_points` exceeds 10000"
+    )]
+    InvalidForwardFee { basis_points: u16 },
+    #[error("message type `{type_url}` at `{field}` cannot be executed by interchain accounts")]
+    UnsupportedIcaHostMessage { type_url: String, field: String },
+    #[error("action `{action}` at `{field}` is not a known action")]
+    UnknownAction { action: String, field: String },
 }
 
 impl TryFrom<UncheckedGenesisState> for GenesisState {
     type Error = VerifyGenesisError;
 
     fn try_from(value: UncheckedGenesisState) -> Result<Self, Self::Error> {
-        value.ensure_all_addresses_have_base_prefix()?;
+        if let Some(error) = value.verification_errors().into_iter().next() {
+            return Err(error);
+        }
 
         let UncheckedGenesisState {
             address_prefixes,
@@ -58,6 +93,11 @@ impl TryFrom<UncheckedGenesisState> for GenesisState {
             ibc_params,
             allowed_fee_assets,
             fees,
+            ica_host,
+            bridge_recovery_delay,
+            deposit_limits,
+            action_activation_heights,
+            fee_summary_activation_height,
         } = value;
 
         Ok(Self {
@@ -70,6 +110,11 @@ impl TryFrom<UncheckedGenesisState> for GenesisState {
             ibc_params,
             allowed_fee_assets,
             fees,
+            ica_host,
+            bridge_recovery_delay,
+            deposit_limits,
+            action_activation_heights,
+            fee_summary_activation_height,
         })
     }
 }
@@ -86,9 +131,45 @@ pub(crate) struct UncheckedGenesisState {
     pub(crate) ibc_params: IBCParameters,
     pub(crate) allowed_fee_assets: Vec<asset::Denom>,
     pub(crate) fees: Fees,
+    /// The parameters of the interchain accounts host, which is disabled if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) ica_host: Option<ica_host::HostParams>,
+    /// The number of blocks between the scheduling of a bridge account recovery and its
+    /// execution, during which the bridge account's sudo address can veto it. Bridge account
+    /// recovery is disabled if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) bridge_recovery_delay: Option<NonZeroU64>,
+    /// The limits on the deposits included in a rollup's data per block. Deposits are unbounded
+    /// if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) deposit_limits: Option<DepositLimits>,
+    /// The heights at which actions become available, keyed by action name. Transactions
+    /// containing an action are rejected before its activation height. Actions not listed are
+    /// available from genesis.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) action_activation_heights: BTreeMap<String, NonZeroU64>,
+    /// The height from which blocks commit to their fee summary in their data. Blocks do not
+    /// commit to their fee summary if not set.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(crate) fee_summary_activation_height: Option<NonZeroU64>,
 }
 
 impl UncheckedGenesisState {
+    /// Runs the checks of the conversion to [`GenesisState`], returning the first failure of
+    /// each check. The conversion fails with the first of these.
+    pub(crate) fn verification_errors(&self) -> Vec<VerifyGenesisError> {
+        [
+            self.ensure_all_addresses_have_base_prefix(),
+            self.ensure_allowed_prefixes_are_valid(),
+            self.ensure_forward_fee_is_valid(),
+            self.ensure_ica_host_messages_are_supported(),
+            self.ensure_activated_actions_are_known(),
+        ]
+        .into_iter()
+        .filter_map(Result::err)
+        .collect()
+    }
+
     // allow: as for the enum definition itself: this only happens at init-chain and is negligible
     #[allow(clippy::result_large_err)]
     fn ensure_address_has_base_prefix(
@@ -125,6 +206,76 @@ impl UncheckedGenesisState {
         }
         Ok(())
     }
+
+    // allow: as for the enum definition itself: this only happens at init-chain and is negligible
+    #[allow(clippy::result_large_err)]
+    fn ensure_allowed_prefixes_are_valid(&self) -> Result<(), VerifyGenesisError> {
+        for (i, prefix) in self.address_prefixes.allowed.iter().enumerate() {
+            let field = format!(".address_prefixes.allowed[{i}]");
+            if *prefix == self.address_prefixes.base {
+                return Err(VerifyGenesisError::AllowedPrefixIsBase {
+                    prefix: prefix.clone(),
+                    field,
+                });
+            }
+            let is_valid = !prefix.chars().any(|c| c.is_ascii_uppercase())
+                && Address::builder()
+                    .array([0; astria_core::primitive::v1::ADDRESS_LEN])
+                    .prefix(prefix.as_str())
+                    .try_build()
+                    .is_ok();
+            if !is_valid {
+                return Err(VerifyGenesisError::InvalidAllowedPrefix {
+                    prefix: prefix.clone(),
+                    field,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    // allow: as for the enum definition itself: this only happens at init-chain and is negligible
+    #[allow(clippy::result_large_err)]
+    fn ensure_forward_fee_is_valid(&self) -> Result<(), VerifyGenesisError> {
+        let basis_points = self.fees.ics20_forward_fee_basis_points;
+        if basis_points > 10_000 {
+            return Err(VerifyGenesisError::InvalidForwardFee {
+                basis_points,
+            });
+        }
+        Ok(())
+    }
+
+    // allow: as for the enum definition itself: this only happens at init-chain and is negligible
+    #[allow(clippy::result_large_err)]
+    fn ensure_ica_host_messages_are_supported(&self) -> Result<(), VerifyGenesisError> {
+        let Some(params) = &self.ica_host else {
+            return Ok(());
+        };
+        for (i, type_url) in params.allowed_messages.iter().enumerate() {
+            if !ica_host::is_supported_message(type_url) {
+                return Err(VerifyGenesisError::UnsupportedIcaHostMessage {
+                    type_url: type_url.clone(),
+                    field: format!(".ica_host.allowed_messages[{i}]"),
+                });
+            }
+        }
+        Ok(())
+    }
+
+    // allow: as for the enum definition itself: this only happens at init-chain and is negligible
+    #[allow(clippy::result_large_err)]
+    fn ensure_activated_actions_are_known(&self) -> Result<(), VerifyGenesisError> {
+        for action in self.action_activation_heights.keys() {
+            if !Action::NAMES.contains(&action.as_str()) {
+                return Err(VerifyGenesisError::UnknownAction {
+                    action: action.clone(),
+                    field: format!(".action_activation_heights.{action}"),
+                });
+            }
+        }
+        Ok(())
+    }
 }
 
 impl From<GenesisState> for UncheckedGenesisState {
@@ -139,6 +290,11 @@ impl From<GenesisState> for UncheckedGenesisState {
             ibc_params,
             allowed_fee_assets,
             fees,
+            ica_host,
+            bridge_recovery_delay,
+            deposit_limits,
+            action_activation_heights,
+            fee_summary_activation_height,
         } = value;
         Self {
             address_prefixes,
@@ -150,6 +306,11 @@ impl From<GenesisState> for UncheckedGenesisState {
             ibc_params,
             allowed_fee_assets,
             fees,
+            ica_host,
+            bridge_recovery_delay,
+            deposit_limits,
+            action_activation_heights,
+            fee_summary_activation_height,
         }
     }
 }
@@ -163,6 +324,16 @@ pub(crate) struct Fees {
     pub(crate) bridge_lock_byte_cost_multiplier: u128,
     pub(crate) bridge_sudo_change_fee: u128,
     pub(crate) ics20_withdrawal_base_fee: u128,
+    /// The fee taken from ICS20 transfers forwarded to another chain, in basis points of the
+    /// transferred amount.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub(crate) ics20_forward_fee_basis_points: u16,
+}
+
+// allow: serde's `skip_serializing_if` requires a reference
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn is_zero(value: &u16) -> bool {
+    *value == 0
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -173,7 +344,11 @@ pub(crate) struct Account {
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub(crate) struct AddressPrefixes {
+    /// The canonical prefix, used for all addresses created or displayed by the sequencer.
     pub(crate) base: String,
+    /// Prefixes accepted in addition to the base prefix in the addresses of actions.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) allowed: Vec<String>,
 }
 
 #[cfg(test)]
@@ -234,6 +409,7 @@ mod test {
             ],
             address_prefixes: AddressPrefixes {
                 base: "astria".into(),
+                allowed: vec![],
             },
             authority_sudo_address: alice(),
             ibc_sudo_address: alice(),
@@ -253,7 +429,13 @@ mod test {
                 bridge_lock_byte_cost_multiplier: 1,
                 bridge_sudo_change_fee: 24,
                 ics20_withdrawal_base_fee: 24,
+                ics20_forward_fee_basis_points: 0,
             },
+            ica_host: None,
+            bridge_recovery_delay: None,
+            deposit_limits: None,
+            action_activation_heights: BTreeMap::new(),
+            fee_summary_activation_height: None,
         }
     }
 
@@ -278,6 +460,7 @@ mod test {
                     assert_eq!(address, mallory());
                     assert_eq!(field, bad_field);
                 }
+                other => panic!("expected a mismatched address error, but got: {other}"),
             };
         }
         assert_bad_prefix(
@@ -319,6 +502,83 @@ mod test {
         );
     }
 
+    #[test]
+    fn invalid_allowed_prefixes_are_caught() {
+        let with_allowed = |allowed: &[&str]| UncheckedGenesisState {
+            address_prefixes: AddressPrefixes {
+                base: ASTRIA_ADDRESS_PREFIX.into(),
+                allowed: allowed.iter().map(ToString::to_string).collect(),
+            },
+            ..unchecked_genesis_state()
+        };
+        GenesisState::try_from(with_allowed(&["astriacompat"])).unwrap();
+        assert!(matches!(
+            GenesisState::try_from(with_allowed(&["astriacompat", ASTRIA_ADDRESS_PREFIX])),
+            Err(VerifyGenesisError::AllowedPrefixIsBase { .. })
+        ));
+        assert!(matches!(
+            GenesisState::try_from(with_allowed(&["Other"])),
+            Err(VerifyGenesisError::InvalidAllowedPrefix { .. })
+        ));
+        assert!(matches!(
+            GenesisState::try_from(with_allowed(&[""])),
+            Err(VerifyGenesisError::InvalidAllowedPrefix { .. })
+        ));
+    }
+
+    #[test]
+    fn invalid_forward_fee_is_caught() {
+        let with_forward_fee = |basis_points| {
+            let mut unchecked = unchecked_genesis_state();
+            unchecked.fees.ics20_forward_fee_basis_points = basis_points;
+            unchecked
+        };
+        GenesisState::try_from(with_forward_fee(10_000)).unwrap();
+        assert!(matches!(
+            GenesisState::try_from(with_forward_fee(10_001)),
+            Err(VerifyGenesisError::InvalidForwardFee {
+                basis_points: 10_001
+            })
+        ));
+    }
+
+    #[test]
+    fn unsupported_ica_host_messages_are_caught() {
+        let with_allowed_messages = |allowed_messages: &[&str]| UncheckedGenesisState {
+            ica_host: Some(ica_host::HostParams {
+                enabled: true,
+                allowed_messages: allowed_messages.iter().map(ToString::to_string).collect(),
+            }),
+            ..unchecked_genesis_state()
+        };
+        GenesisState::try_from(with_allowed_messages(&[
+            "/astria.protocol.transactions.v1alpha1.TransferAction"
+        ]))
+        .unwrap();
+        assert!(matches!(
+            GenesisState::try_from(with_allowed_messages(&[
+                "/astria.protocol.transactions.v1alpha1.TransferAction",
+                "/astria.protocol.transactions.v1alpha1.FeeChangeAction",
+            ])),
+            Err(VerifyGenesisError::UnsupportedIcaHostMessage { field, .. })
+                if field == ".ica_host.allowed_messages[1]"
+        ));
+    }
+
+    #[test]
+    fn unknown_activated_actions_are_caught() {
+        let with_activation = |action: &str| UncheckedGenesisState {
+            action_activation_heights: [(action.to_string(), NonZeroU64::new(10).unwrap())].into(),
+            ..unchecked_genesis_state()
+        };
+        GenesisState::try_from(with_activation("bridge_recovery")).unwrap();
+        assert!(matches!(
+            GenesisState::try_from(with_activation("bridge_rescue")),
+            Err(VerifyGenesisError::UnknownAction { field, .. })
+                if field == ".action_activation_heights.bridge_rescue"
+        ));
+    }
+
     #[test]
     fn genesis_state_is_unchanged() {
         insta::assert_json_snapshot!(genesis_state());
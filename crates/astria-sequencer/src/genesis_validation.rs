@@ -0,0 +1,437 @@
+//! Offline validation of a genesis file.
+//!
+//! The sequencer only parses its genesis state once cometbft calls `InitChain`, at which point a
+//! mistake in the genesis file halts the network at launch. This module parses the app state of a
+//! genesis file the same way `InitChain` does and additionally checks it for mistakes that would
+//! not stop the chain from starting, but would leave it in an unintended state:
+//!
+//! - accounts listed more than once, of which only the last balance takes effect;
+//! - a native asset that is not a valid denomination, or fee assets that are listed more than once
+//!   or do not include the native asset;
+//! - IBC parameters that enable ICS20 transfers or the interchain accounts host while IBC itself is
+//!   disabled;
+//! - fees of zero, and total account balances that overflow.
+//!
+//! Findings are reported as errors if the genesis file would be rejected by `InitChain` or cannot
+//! work as intended, and as warnings otherwise.
+
+use std::{
+    collections::HashSet,
+    fmt,
+    path::Path,
+};
+
+use anyhow::{
+    Context as _,
+    Result,
+};
+use astria_core::primitive::v1::asset;
+use serde::Deserialize;
+
+use crate::genesis::UncheckedGenesisState;
+
+/// How severe a problem found in the genesis file is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Severity {
+    /// The genesis file is rejected at `InitChain` or does not work as intended.
+    Error,
+    /// The genesis file is likely not what was intended.
+    Warning,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => f.write_str("error"),
+            Self::Warning => f.write_str("warning"),
+        }
+    }
+}
+
+/// A single problem found in the genesis file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Finding {
+    pub severity: Severity,
+    pub message: String,
+}
+
+/// The problems found in the genesis file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub findings: Vec<Finding>,
+}
+
+impl Report {
+    /// Returns true if any finding is an error.
+    #[must_use]
+    pub fn has_errors(&self) -> bool {
+        self.findings
+            .iter()
+            .any(|finding| finding.severity == Severity::Error)
+    }
+
+    fn error(&mut self, message: impl Into<String>) {
+        self.findings.push(Finding {
+            severity: Severity::Error,
+            message: message.into(),
+        });
+    }
+
+    fn warning(&mut self, message: impl Into<String>) {
+        self.findings.push(Finding {
+            severity: Severity::Warning,
+            message: message.into(),
+        });
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.findings.is_empty() {
+            return f.write_str("no problems found");
+        }
+        for (i, finding) in self.findings.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}: {}", finding.severity, finding.message)?;
+        }
+        Ok(())
+    }
+}
+
+/// Validates the genesis file at `path`.
+///
+/// The file can either be a full cometbft genesis file, in which case its `app_state` is
+/// validated, or hold only the app state.
+///
+/// # Errors
+/// Returns an error if the file cannot be read. Problems with its contents are returned as
+/// findings in the report.
+pub fn validate_file(path: &Path) -> Result<Report> {
+    let contents = std::fs::read(path)
+        .with_context(|| format!("failed to read genesis file at `{}`", path.display()))?;
+    Ok(validate(&contents))
+}
+
+/// Validates the JSON encoded genesis file in `contents`.
+#[must_use]
+pub fn validate(contents: &[u8]) -> Report {
+    // The app state is deserialized straight from `contents` as `serde_json::Value` cannot hold
+    // balances above `u64::MAX`.
+    #[derive(Deserialize)]
+    struct CometbftGenesis {
+        app_state: UncheckedGenesisState,
+    }
+
+    let mut report = Report::default();
+    let json: serde_json::Value = match serde_json::from_slice(contents) {
+        Ok(json) => json,
+        Err(e) => {
+            report.error(format!("genesis file is not valid JSON: {e}"));
+            return report;
+        }
+    };
+    let state = if json.get("app_state").is_some() {
+        serde_json::from_slice::<CometbftGenesis>(contents).map(|genesis| genesis.app_state)
+    } else {
+        serde_json::from_slice::<UncheckedGenesisState>(contents)
+    };
+    let state = match state {
+        Ok(state) => state,
+        Err(e) => {
+            report.error(format!("failed to parse app state: {e}"));
+            return report;
+        }
+    };
+
+    for error in state.verification_errors() {
+        report.error(error.to_string());
+    }
+    check_accounts(&state, &mut report);
+    check_assets(&state, &mut report);
+    check_ibc_params(&state, &mut report);
+    check_fees(&state, &mut report);
+    report
+}
+
+fn check_accounts(state: &UncheckedGenesisState, report: &mut Report) {
+    let mut seen = HashSet::new();
+    let mut total_balance = Some(0u128);
+    for (i, account) in state.accounts.iter().enumerate() {
+        if !seen.insert(account.address.bytes()) {
+            report.error(format!(
+                "account `{}` at `.accounts[{i}]` is listed more than once; only its last balance \
+                 takes effect",
+                account.address
+            ));
+        }
+        if account.balance == 0 {
+            report.warning(format!(
+                "account `{}` at `.accounts[{i}]` has a balance of zero",
+                account.address
+            ));
+        }
+        total_balance = total_balance.and_then(|total| total.checked_add(account.balance));
+    }
+    if total_balance.is_none() {
+        report.error("the sum of the balances at `.accounts` overflows a u128");
+    }
+
+    let mut seen = HashSet::new();
+    for (i, address) in state.ibc_relayer_addresses.iter().enumerate() {
+        if !seen.insert(address.bytes()) {
+            report.warning(format!(
+                "ibc relayer `{address}` at `.ibc_relayer_addresses[{i}]` is listed more than once"
+            ));
+        }
+    }
+}
+
+fn check_assets(state: &UncheckedGenesisState, report: &mut Report) {
+    match state.native_asset_base_denomination.parse::<asset::Denom>() {
+        Err(e) => report.error(format!(
+            "native asset `{}` at `.native_asset_base_denomination` is not a valid denomination: \
+             {e}",
+            state.native_asset_base_denomination
+        )),
+        Ok(native) => {
+            if native.is_ibc_prefixed() {
+                report.warning(format!(
+                    "native asset `{native}` at `.native_asset_base_denomination` is an ibc \
+                     prefixed denomination, which cannot be mapped back to its trace"
+                ));
+            }
+            if !state
+                .allowed_fee_assets
+                .iter()
+                .any(|fee_asset| fee_asset.id() == native.id())
+            {
+                report.warning(format!(
+                    "native asset `{native}` is not listed in `.allowed_fee_assets`"
+                ));
+            }
+        }
+    }
+
+    if state.allowed_fee_assets.is_empty() {
+        report.error("`.allowed_fee_assets` is empty, so no transaction can pay its fees");
+    }
+    let mut seen = HashSet::new();
+    for (i, fee_asset) in state.allowed_fee_assets.iter().enumerate() {
+        if !seen.insert(fee_asset.id()) {
+            report.warning(format!(
+                "fee asset `{fee_asset}` at `.allowed_fee_assets[{i}]` is listed more than once"
+            ));
+        }
+    }
+}
+
+fn check_ibc_params(state: &UncheckedGenesisState, report: &mut Report) {
+    let params = &state.ibc_params;
+    if params.ibc_enabled {
+        return;
+    }
+    if params.inbound_ics20_transfers_enabled {
+        report.error(
+            "`.ibc_params.inbound_ics20_transfers_enabled` is set, but IBC is disabled at \
+             `.ibc_params.ibc_enabled`",
+        );
+    }
+    if params.outbound_ics20_transfers_enabled {
+        report.error(
+            "`.ibc_params.outbound_ics20_transfers_enabled` is set, but IBC is disabled at \
+             `.ibc_params.ibc_enabled`",
+        );
+    }
+    if state.ica_host.as_ref().is_some_and(|host| host.enabled) {
+        report
+            .error("`.ica_host.enabled` is set, but IBC is disabled at `.ibc_params.ibc_enabled`");
+    }
+    if !state.ibc_relayer_addresses.is_empty() {
+        report.warning(
+            "`.ibc_relayer_addresses` is not empty, but IBC is disabled at \
+             `.ibc_params.ibc_enabled`",
+        );
+    }
+}
+
+fn check_fees(state: &UncheckedGenesisState, report: &mut Report) {
+    let fees = &state.fees;
+    for (field, fee) in [
+        ("transfer_base_fee", fees.transfer_base_fee),
+        ("sequence_base_fee", fees.sequence_base_fee),
+        (
+            "init_bridge_account_base_fee",
+            fees.init_bridge_account_base_fee,
+        ),
+        ("bridge_sudo_change_fee", fees.bridge_sudo_change_fee),
+        ("ics20_withdrawal_base_fee", fees.ics20_withdrawal_base_fee),
+    ] {
+        if fee == 0 {
+            report.warning(format!(
+                "`.fees.{field}` is zero, so the action it applies to is free"
+            ));
+        }
+    }
+    if fees.sequence_base_fee == 0 && fees.sequence_byte_cost_multiplier == 0 {
+        report.error(
+            "both `.fees.sequence_base_fee` and `.fees.sequence_byte_cost_multiplier` are zero, \
+             so rollup data can be sequenced for free",
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    const ALICE: &str = "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm";
+    const BOB: &str = "astria1xnlvg0rle2u6auane79t4p27g8hxnj36ja960z";
+
+    fn app_state() -> serde_json::Value {
+        json!({
+            "address_prefixes": { "base": "astria" },
+            "accounts": [
+                { "address": { "bech32m": ALICE }, "balance": 1_000_000_000_000_000_000u64 },
+                { "address": { "bech32m": BOB }, "balance": 1_000_000_000_000_000_000u64 },
+            ],
+            "authority_sudo_address": { "bech32m": ALICE },
+            "ibc_sudo_address": { "bech32m": ALICE },
+            "ibc_relayer_addresses": [{ "bech32m": ALICE }],
+            "native_asset_base_denomination": "nria",
+            "ibc_params": {
+                "ibcEnabled": true,
+                "inboundIcs20TransfersEnabled": true,
+                "outboundIcs20TransfersEnabled": true,
+            },
+            "allowed_fee_assets": ["nria"],
+            "fees": {
+                "transfer_base_fee": 12,
+                "sequence_base_fee": 32,
+                "sequence_byte_cost_multiplier": 1,
+                "init_bridge_account_base_fee": 48,
+                "bridge_lock_byte_cost_multiplier": 1,
+                "bridge_sudo_change_fee": 24,
+                "ics20_withdrawal_base_fee": 24,
+            },
+        })
+    }
+
+    fn validate_json(json: &serde_json::Value) -> Report {
+        validate(&serde_json::to_vec(json).unwrap())
+    }
+
+    #[track_caller]
+    fn assert_single_finding(report: &Report, severity: Severity, contains: &str) {
+        assert_eq!(1, report.findings.len(), "{report}");
+        assert_eq!(severity, report.findings[0].severity, "{report}");
+        assert!(report.findings[0].message.contains(contains), "{report}");
+    }
+
+    #[test]
+    fn valid_genesis_has_no_findings() {
+        let report = validate_json(&app_state());
+        assert!(report.findings.is_empty(), "{report}");
+        let report = validate_json(&json!({
+            "chain_id": "astria",
+            "app_state": app_state(),
+        }));
+        assert!(report.findings.is_empty(), "{report}");
+    }
+
+    #[test]
+    fn unparseable_genesis_is_reported() {
+        let report = validate(b"{");
+        assert_single_finding(&report, Severity::Error, "not valid JSON");
+        let mut state = app_state();
+        state["accounts"][0]["address"] = json!({ "bech32m": "not-an-address" });
+        assert_single_finding(&validate_json(&state), Severity::Error, "parse app state");
+    }
+
+    #[test]
+    fn all_verification_errors_are_reported() {
+        let mut state = app_state();
+        state["fees"]["ics20_forward_fee_basis_points"] = json!(10_001);
+        state["action_activation_heights"] = json!({ "bridge_rescue": 10 });
+        let report = validate_json(&state);
+        assert_eq!(2, report.findings.len(), "{report}");
+        assert!(report.has_errors());
+    }
+
+    #[test]
+    fn duplicate_accounts_are_reported() {
+        let mut state = app_state();
+        state["accounts"][1]["address"] = state["accounts"][0]["address"].clone();
+        assert_single_finding(
+            &validate_json(&state),
+            Severity::Error,
+            "`.accounts[1]` is listed more than once",
+        );
+    }
+
+    #[test]
+    fn overflowing_balances_are_reported() {
+        // `serde_json::Value` cannot hold numbers above `u64::MAX`, so the balance is substituted
+        // into the encoded JSON.
+        let mut state = app_state();
+        state["accounts"][0]["balance"] = json!(42);
+        let json = serde_json::to_string(&state)
+            .unwrap()
+            .replace(r#""balance":42"#, &format!(r#""balance":{}"#, u128::MAX));
+        assert_single_finding(&validate(json.as_bytes()), Severity::Error, "overflows");
+    }
+
+    #[test]
+    fn invalid_native_asset_is_reported() {
+        let mut state = app_state();
+        state["native_asset_base_denomination"] = json!("");
+        let report = validate_json(&state);
+        assert!(report.has_errors(), "{report}");
+        assert!(
+            report.findings[0]
+                .message
+                .contains("not a valid denomination"),
+            "{report}"
+        );
+    }
+
+    #[test]
+    fn native_asset_missing_from_fee_assets_is_reported() {
+        let mut state = app_state();
+        state["allowed_fee_assets"] = json!(["transfer/channel-0/utia"]);
+        assert_single_finding(
+            &validate_json(&state),
+            Severity::Warning,
+            "not listed in `.allowed_fee_assets`",
+        );
+    }
+
+    #[test]
+    fn inconsistent_ibc_params_are_reported() {
+        let mut state = app_state();
+        state["ibc_params"]["ibcEnabled"] = json!(false);
+        state["ibc_relayer_addresses"] = json!([]);
+        let report = validate_json(&state);
+        assert_eq!(2, report.findings.len(), "{report}");
+        assert!(report.has_errors());
+    }
+
+    #[test]
+    fn free_sequencing_is_reported() {
+        let mut state = app_state();
+        state["fees"]["sequence_base_fee"] = json!(0);
+        state["fees"]["sequence_byte_cost_multiplier"] = json!(0);
+        let report = validate_json(&state);
+        assert!(report.has_errors(), "{report}");
+        assert!(
+            report
+                .findings
+                .iter()
+                .any(|finding| finding.message.contains("sequenced for free")),
+            "{report}"
+        );
+    }
+}
@@ -0,0 +1,504 @@
+//! Limits on the load gRPC clients can put on the sequencer.
+//!
+//! The [`LimitsLayer`] sees every request to the gRPC server before it is routed to a service. A
+//! request is rejected with [`Code::ResourceExhausted`] if its peer has exceeded its rate limit, or
+//! if the number of requests being handled is at the configured maximum. This keeps misbehaving
+//! conductors and indexers from starving a validator of resources.
+//!
+//! Rejections carry a retry hint in the `grpc-retry-pushback-ms` metadata entry, as specified by
+//! the gRPC retry design, and repeat it in their message for clients that do not read it.
+//!
+//! Peers are rate limited by their IP address with a token bucket that holds up to one second
+//! worth of requests. IPv6 peers are rate limited by their /64 prefix, as a single host is
+//! commonly assigned a whole /64. At most [`MAX_TRACKED_PEERS`] buckets are kept; beyond that,
+//! idle peers are forgotten first, then the least recently seen ones. A request counts against the
+//! concurrency limit until its response starts, so long-lived server streams do not hold on to a
+//! slot.
+//!
+//! The size of request messages is capped separately by the decoders of the services, see
+//! [`Limits::max_request_bytes`].
+//!
+//! [`Code::ResourceExhausted`]: tonic::Code::ResourceExhausted
+
+use std::{
+    collections::HashMap,
+    net::{
+        IpAddr,
+        Ipv6Addr,
+    },
+    sync::{
+        Arc,
+        Mutex,
+    },
+    task::{
+        Context,
+        Poll,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use futures::{
+    future::BoxFuture,
+    FutureExt as _,
+};
+use tokio::sync::{
+    OwnedSemaphorePermit,
+    Semaphore,
+};
+use tonic::{
+    body::BoxBody,
+    codegen::http,
+    metadata::MetadataValue,
+    transport::server::TcpConnectInfo,
+    Status,
+};
+use tracing::debug;
+
+use crate::{
+    config::Config,
+    metrics::{
+        GrpcRejectReason,
+        Metrics,
+    },
+};
+
+/// The metadata key of the retry hint attached to rejected requests.
+const RETRY_PUSHBACK_KEY: &str = "grpc-retry-pushback-ms";
+
+/// The retry hint given to requests rejected because too many requests are being handled.
+const CONCURRENCY_RETRY_AFTER: Duration = Duration::from_millis(100);
+
+/// The maximum number of rate limited peers that are tracked.
+///
+/// Once reached, peers that have been idle for long enough to have a full bucket are forgotten to
+/// make room for a new peer. If there are none, the least recently seen peer is forgotten.
+const MAX_TRACKED_PEERS: usize = 4096;
+
+/// The mask applied to the addresses of IPv6 peers, keeping their /64 prefix.
+const IPV6_RATE_LIMIT_PREFIX_MASK: u128 = 0xffff_ffff_ffff_ffff_0000_0000_0000_0000;
+
+/// The limits above which gRPC requests are rejected.
+///
+/// A limit of `None` disables the corresponding check.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Limits {
+    pub(crate) max_concurrent_requests: Option<usize>,
+    pub(crate) requests_per_second_per_peer: Option<u32>,
+    /// The maximum size of a decoded request message. Larger messages are rejected by the
+    /// services with `OUT_OF_RANGE` and no retry hint, as retrying cannot succeed.
+    pub(crate) max_request_bytes: Option<usize>,
+}
+
+impl Limits {
+    pub(crate) fn from_config(config: &Config) -> Self {
+        Self {
+            max_concurrent_requests: (config.grpc_max_concurrent_requests > 0)
+                .then_some(config.grpc_max_concurrent_requests),
+            requests_per_second_per_peer: (config.grpc_requests_per_second_per_peer > 0)
+                .then_some(config.grpc_requests_per_second_per_peer),
+            max_request_bytes: (config.grpc_max_request_bytes > 0)
+                .then_some(config.grpc_max_request_bytes),
+        }
+    }
+}
+
+/// A [`tower::Layer`] rejecting requests that exceed the configured [`Limits`].
+#[derive(Clone)]
+pub(crate) struct LimitsLayer {
+    limiter: Arc<Limiter>,
+}
+
+impl LimitsLayer {
+    pub(crate) fn new(limits: Limits, metrics: &'static Metrics) -> Self {
+        Self {
+            limiter: Arc::new(Limiter::new(limits, metrics)),
+        }
+    }
+}
+
+impl<S> tower::Layer<S> for LimitsLayer {
+    type Service = LimitsService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        LimitsService {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct LimitsService<S> {
+    inner: S,
+    limiter: Arc<Limiter>,
+}
+
+impl<S, B> tower::Service<http::Request<B>> for LimitsService<S>
+where
+    S: tower::Service<http::Request<B>, Response = http::Response<BoxBody>>,
+    S::Error: Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+    type Response = http::Response<BoxBody>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: http::Request<B>) -> Self::Future {
+        let peer = request
+            .extensions()
+            .get::<TcpConnectInfo>()
+            .and_then(TcpConnectInfo::remote_addr)
+            .map(|addr| addr.ip());
+        let permit = match self.limiter.admit(peer, Instant::now()) {
+            Ok(permit) => permit,
+            Err(rejection) => {
+                debug!(
+                    peer = peer.map(tracing::field::display),
+                    path = request.uri().path(),
+                    reason = rejection.reason().as_str(),
+                    "rejected gRPC request",
+                );
+                let response = rejection.into_status().to_http();
+                return futures::future::ready(Ok(response)).boxed();
+            }
+        };
+        let response = self.inner.call(request);
+        async move {
+            let response = response.await;
+            drop(permit);
+            response
+        }
+        .boxed()
+    }
+}
+
+/// The reason a request was rejected.
+#[derive(Debug, PartialEq)]
+enum Rejection {
+    Concurrency { max: usize },
+    RateLimit { limit: u32, retry_after: Duration },
+}
+
+impl Rejection {
+    fn reason(&self) -> GrpcRejectReason {
+        match self {
+            Self::Concurrency {
+                ..
+            } => GrpcRejectReason::Concurrency,
+            Self::RateLimit {
+                ..
+            } => GrpcRejectReason::RateLimit,
+        }
+    }
+
+    fn into_status(self) -> Status {
+        let (message, retry_after) = match self {
+            Self::Concurrency {
+                max,
+            } => (
+                format!("the node is handling its maximum of {max} concurrent requests"),
+                CONCURRENCY_RETRY_AFTER,
+            ),
+            Self::RateLimit {
+                limit,
+                retry_after,
+            } => (
+                format!("rate limit of {limit} requests per second exceeded"),
+                retry_after,
+            ),
+        };
+        let retry_after_ms = u64::try_from(retry_after.as_millis())
+            .unwrap_or(u64::MAX)
+            .max(1);
+        let mut status =
+            Status::resource_exhausted(format!("{message}; retry after {retry_after_ms}ms"));
+        status
+            .metadata_mut()
+            .insert(RETRY_PUSHBACK_KEY, MetadataValue::from(retry_after_ms));
+        status
+    }
+}
+
+struct Limiter {
+    limits: Limits,
+    concurrency: Option<Arc<Semaphore>>,
+    peers: Mutex<HashMap<IpAddr, TokenBucket>>,
+    metrics: &'static Metrics,
+}
+
+impl Limiter {
+    fn new(limits: Limits, metrics: &'static Metrics) -> Self {
+        Self {
+            limits,
+            concurrency: limits
+                .max_concurrent_requests
+                .map(|max| Arc::new(Semaphore::new(max.min(Semaphore::MAX_PERMITS)))),
+            peers: Mutex::new(HashMap::new()),
+            metrics,
+        }
+    }
+
+    /// Admits a request of `peer`, returning the permit it holds against the concurrency limit
+    /// while it is handled.
+    fn admit(
+        &self,
+        peer: Option<IpAddr>,
+        now: Instant,
+    ) -> Result<Option<OwnedSemaphorePermit>, Rejection> {
+        let result = self.check_rate(peer, now).and_then(|()| self.acquire());
+        if let Err(rejection) = &result {
+            self.metrics
+                .increment_grpc_requests_rejected(rejection.reason());
+        }
+        result
+    }
+
+    fn check_rate(&self, peer: Option<IpAddr>, now: Instant) -> Result<(), Rejection> {
+        let (Some(limit), Some(peer)) = (self.limits.requests_per_second_per_peer, peer) else {
+            return Ok(());
+        };
+        let peer = rate_limit_key(peer);
+        let rate = f64::from(limit);
+        let mut peers = self
+            .peers
+            .lock()
+            .expect("the lock is never held across a panic");
+        if peers.len() >= MAX_TRACKED_PEERS && !peers.contains_key(&peer) {
+            peers.retain(|_, bucket| !bucket.is_full(rate, now));
+            if peers.len() >= MAX_TRACKED_PEERS {
+                let least_recently_seen = peers
+                    .iter()
+                    .min_by_key(|(_, bucket)| bucket.last_seen_at)
+                    .map(|(peer, _)| *peer);
+                if let Some(least_recently_seen) = least_recently_seen {
+                    peers.remove(&least_recently_seen);
+                }
+            }
+        }
+        peers
+            .entry(peer)
+            .or_insert_with(|| TokenBucket::full(rate, now))
+            .try_take(rate, now)
+            .map_err(|retry_after| Rejection::RateLimit {
+                limit,
+                retry_after,
+            })
+    }
+
+    fn acquire(&self) -> Result<Option<OwnedSemaphorePermit>, Rejection> {
+        let (Some(semaphore), Some(max)) = (&self.concurrency, self.limits.max_concurrent_requests)
+        else {
+            return Ok(None);
+        };
+        semaphore
+            .clone()
+            .try_acquire_owned()
+            .map(Some)
+            .map_err(|_| Rejection::Concurrency {
+                max,
+            })
+    }
+}
+
+/// Returns the address by which `peer` is rate limited: IPv4 addresses as they are, including
+/// those mapped into IPv6, and IPv6 addresses truncated to their /64 prefix.
+fn rate_limit_key(peer: IpAddr) -> IpAddr {
+    match peer {
+        IpAddr::V4(_) => peer,
+        IpAddr::V6(addr) => match addr.to_ipv4_mapped() {
+            Some(addr) => IpAddr::V4(addr),
+            None => IpAddr::V6(Ipv6Addr::from(
+                u128::from(addr) & IPV6_RATE_LIMIT_PREFIX_MASK,
+            )),
+        },
+    }
+}
+
+/// A bucket refilled with `rate` tokens per second, holding at most `rate` tokens.
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    refilled_at: Instant,
+    /// When the peer last made a request.
+    last_seen_at: Instant,
+}
+
+impl TokenBucket {
+    fn full(rate: f64, now: Instant) -> Self {
+        Self {
+            tokens: rate,
+            refilled_at: now,
+            last_seen_at: now,
+        }
+    }
+
+    fn refill(&mut self, rate: f64, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.refilled_at);
+        self.tokens = elapsed.as_secs_f64().mul_add(rate, self.tokens).min(rate);
+        self.refilled_at = now;
+    }
+
+    fn is_full(&mut self, rate: f64, now: Instant) -> bool {
+        self.refill(rate, now);
+        self.tokens >= rate
+    }
+
+    /// Takes a token from the bucket, or returns the time until one is available.
+    fn try_take(&mut self, rate: f64, now: Instant) -> Result<(), Duration> {
+        self.refill(rate, now);
+        self.last_seen_at = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / rate))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::Ipv4Addr;
+
+    use super::*;
+
+    fn limiter(limits: Limits) -> Limiter {
+        Limiter::new(limits, Box::leak(Box::new(Metrics::new())))
+    }
+
+    const PEER: Option<IpAddr> = Some(IpAddr::V4(Ipv4Addr::LOCALHOST));
+
+    #[test]
+    fn concurrent_requests_above_limit_are_rejected() {
+        let limiter = limiter(Limits {
+            max_concurrent_requests: Some(2),
+            ..Limits::default()
+        });
+        let now = Instant::now();
+        let first = limiter.admit(PEER, now).unwrap();
+        let _second = limiter.admit(PEER, now).unwrap();
+        assert_eq!(
+            limiter.admit(PEER, now).unwrap_err(),
+            Rejection::Concurrency {
+                max: 2
+            }
+        );
+
+        drop(first);
+        assert!(limiter.admit(PEER, now).is_ok());
+    }
+
+    #[test]
+    fn peers_above_rate_limit_are_rejected_until_refilled() {
+        let limiter = limiter(Limits {
+            requests_per_second_per_peer: Some(2),
+            ..Limits::default()
+        });
+        let now = Instant::now();
+        assert!(limiter.admit(PEER, now).is_ok());
+        assert!(limiter.admit(PEER, now).is_ok());
+        assert_eq!(
+            limiter.admit(PEER, now).unwrap_err(),
+            Rejection::RateLimit {
+                limit: 2,
+                retry_after: Duration::from_millis(500),
+            }
+        );
+
+        // other peers have their own buckets
+        let other_peer = Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1)));
+        assert!(limiter.admit(other_peer, now).is_ok());
+
+        let later = now.checked_add(Duration::from_millis(500)).unwrap();
+        assert!(limiter.admit(PEER, later).is_ok());
+    }
+
+    #[test]
+    fn ipv6_peers_are_rate_limited_by_prefix() {
+        let limiter = limiter(Limits {
+            requests_per_second_per_peer: Some(1),
+            ..Limits::default()
+        });
+        let now = Instant::now();
+        let peer = |last_segment| {
+            Some(IpAddr::V6(Ipv6Addr::new(
+                0x2001,
+                0xdb8,
+                0,
+                1,
+                0,
+                0,
+                0,
+                last_segment,
+            )))
+        };
+        assert!(limiter.admit(peer(1), now).is_ok());
+        assert!(limiter.admit(peer(2), now).is_err());
+
+        let other_prefix = Some(IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 2, 0, 0, 0, 1)));
+        assert!(limiter.admit(other_prefix, now).is_ok());
+    }
+
+    #[test]
+    fn least_recently_seen_peer_is_forgotten_once_full() {
+        let limiter = limiter(Limits {
+            requests_per_second_per_peer: Some(1),
+            ..Limits::default()
+        });
+        let start = Instant::now();
+        let peer = |index: usize| Some(IpAddr::V4(Ipv4Addr::from(u32::try_from(index).unwrap())));
+        // every peer drains its bucket, so none of them can be forgotten for being idle
+        for index in 0..MAX_TRACKED_PEERS {
+            let now = start
+                .checked_add(Duration::from_micros(u64::try_from(index).unwrap()))
+                .unwrap();
+            limiter.admit(peer(index), now).unwrap();
+        }
+        let now = start.checked_add(Duration::from_millis(100)).unwrap();
+        limiter.admit(peer(MAX_TRACKED_PEERS), now).unwrap();
+
+        let peers = limiter.peers.lock().unwrap();
+        assert_eq!(peers.len(), MAX_TRACKED_PEERS);
+        assert!(!peers.contains_key(&peer(0).unwrap()));
+        assert!(peers.contains_key(&peer(1).unwrap()));
+    }
+
+    #[test]
+    fn requests_without_peer_are_not_rate_limited() {
+        let limiter = limiter(Limits {
+            requests_per_second_per_peer: Some(1),
+            ..Limits::default()
+        });
+        let now = Instant::now();
+        for _ in 0..10 {
+            assert!(limiter.admit(None, now).is_ok());
+        }
+    }
+
+    #[test]
+    fn rejection_carries_retry_hint() {
+        let status = Rejection::RateLimit {
+            limit: 2,
+            retry_after: Duration::from_millis(250),
+        }
+        .into_status();
+        assert_eq!(status.code(), tonic::Code::ResourceExhausted);
+        assert_eq!(
+            status
+                .metadata()
+                .get(RETRY_PUSHBACK_KEY)
+                .unwrap()
+                .to_str()
+                .unwrap(),
+            "250"
+        );
+        assert!(status.message().contains("retry after 250ms"));
+    }
+}
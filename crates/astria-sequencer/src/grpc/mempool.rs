@@ -0,0 +1,274 @@
+use std::sync::Arc;
+
+use astria_core::generated::protocol::mempool::v1alpha1::{
+    mempool_service_server::MempoolService,
+    GetPendingNonceRequest,
+    GetPendingNonceResponse,
+    GetTransactionsRequest,
+    GetTransactionsResponse,
+    MempoolTransaction,
+    SubmitTransactionRequest,
+    SubmitTransactionResponse,
+};
+use cnidarium::Storage;
+use prost::Message as _;
+use tendermint::{
+    abci::request::CheckTxKind,
+    v0_38::abci::request,
+};
+use tonic::{
+    Request,
+    Response,
+    Status,
+};
+use tracing::instrument;
+
+use crate::{
+    mempool::Mempool,
+    metrics::Metrics,
+    service::mempool::{
+        handle_check_tx,
+        LoadShedder,
+    },
+};
+
+/// The maximum number of transactions returned by a single `GetTransactions` call.
+const MAX_PAGE_SIZE: u32 = 100;
+
+pub(crate) struct MempoolServer {
+    storage: Storage,
+    mempool: Mempool,
+    load_shedder: LoadShedder,
+    metrics: &'static Metrics,
+}
+
+impl MempoolServer {
+    pub(crate) fn new(
+        storage: Storage,
+        mempool: Mempool,
+        load_shedder: LoadShedder,
+        metrics: &'static Metrics,
+    ) -> Self {
+        Self {
+            storage,
+            mempool,
+            load_shedder,
+            metrics,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl MempoolService for MempoolServer {
+    /// Returns a page of the transactions in the mempool, ordered by their hash and optionally
+    /// filtered by signer.
+    #[instrument(skip_all)]
+    async fn get_transactions(
+        self: Arc<Self>,
+        request: Request<GetTransactionsRequest>,
+    ) -> Result<Response<GetTransactionsResponse>, Status> {
+        let GetTransactionsRequest {
+            account,
+            page_size,
+            page_token,
+        } = request.into_inner();
+
+        let account = account
+            .map(|account| super::parse_address_field("account", Some(account)))
+            .transpose()?;
+        let after: Option<[u8; 32]> = if page_token.is_empty() {
+            None
+        } else {
+            Some(page_token.try_into().map_err(|_| {
+                Status::invalid_argument("page token must be empty or 32 bytes long")
+            })?)
+        };
+        let page_size = if page_size == 0 {
+            MAX_PAGE_SIZE
+        } else {
+            page_size.min(MAX_PAGE_SIZE)
+        };
+
+        let mut transactions: Vec<_> = self
+            .mempool
+            .transactions()
+            .await
+            .into_iter()
+            .filter(|tx| account.map_or(true, |account| tx.address().bytes() == account.bytes()))
+            .filter(|tx| after.map_or(true, |after| tx.tx_hash() > after))
+            .collect();
+        transactions.sort_unstable_by_key(crate::mempool::EnqueuedTransaction::tx_hash);
+
+        let page_size = usize::try_from(page_size).expect("u32 must fit into usize");
+        let next_page_token = if transactions.len() > page_size {
+            transactions.truncate(page_size);
+            transactions
+                .last()
+                .map(|tx| tx.tx_hash().to_vec())
+                .unwrap_or_default()
+        } else {
+            vec![]
+        };
+
+        let transactions = transactions
+            .into_iter()
+            .map(|tx| {
+                let signed_tx = tx.signed_tx();
+                MempoolTransaction {
+                    hash: tx.tx_hash().to_vec(),
+                    signer: Some(tx.address().to_raw()),
+                    nonce: signed_tx.nonce(),
+                    transaction: signed_tx.to_raw().encode_to_vec(),
+                }
+            })
+            .collect();
+
+        Ok(Response::new(GetTransactionsResponse {
+            transactions,
+            next_page_token,
+        }))
+    }
+
+    /// Returns the highest nonce of the account's transactions in the mempool, or its current
+    /// nonce if it has none.
+    #[instrument(skip_all)]
+    async fn get_pending_nonce(
+        self: Arc<Self>,
+        request: Request<GetPendingNonceRequest>,
+    ) -> Result<Response<GetPendingNonceResponse>, Status> {
+        let address = super::parse_address_field("account", request.into_inner().account)?;
+        let pending_nonce = super::pending_nonce(&self.storage, &self.mempool, address).await?;
+        Ok(Response::new(GetPendingNonceResponse {
+            pending_nonce,
+        }))
+    }
+
+    /// Runs the same checks as CometBFT's `CheckTx` on the transaction, inserting it into the
+    /// mempool if it passes them.
+    ///
+    /// Rejections are reported through the response's code rather than as an error status.
+    #[instrument(skip_all)]
+    async fn submit_transaction(
+        self: Arc<Self>,
+        request: Request<SubmitTransactionRequest>,
+    ) -> Result<Response<SubmitTransactionResponse>, Status> {
+        use sha2::Digest as _;
+
+        let tx = request.into_inner().transaction;
+        let hash = sha2::Sha256::digest(&tx).to_vec();
+        let mut mempool = self.mempool.clone();
+        let rsp = handle_check_tx(
+            request::CheckTx {
+                tx: tx.into(),
+                kind: CheckTxKind::New,
+            },
+            self.storage.latest_snapshot(),
+            &mut mempool,
+            &self.load_shedder,
+            self.metrics,
+        )
+        .await;
+
+        Ok(Response::new(SubmitTransactionResponse {
+            hash,
+            code: rsp.code.value(),
+            info: rsp.info,
+            log: rsp.log,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::app::test_utils::{
+        get_alice_signing_key_and_address,
+        get_bridge_signing_key_and_address,
+        get_mock_tx,
+    };
+
+    async fn server_with_mock_txs(count: u32) -> (Arc<MempoolServer>, cnidarium::TempStorage) {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mempool = Mempool::new();
+        for nonce in 0..count {
+            mempool.insert(get_mock_tx(nonce), 0).await.unwrap();
+        }
+        let metrics = Box::leak(Box::new(Metrics::new()));
+        let server = Arc::new(MempoolServer::new(
+            storage.clone(),
+            mempool,
+            LoadShedder::disabled(),
+            metrics,
+        ));
+        (server, storage)
+    }
+
+    #[tokio::test]
+    async fn get_transactions_pages_through_mempool() {
+        let (server, _storage) = server_with_mock_txs(5).await;
+
+        let mut hashes = vec![];
+        let mut page_token = vec![];
+        loop {
+            let rsp = server
+                .clone()
+                .get_transactions(Request::new(GetTransactionsRequest {
+                    account: None,
+                    page_size: 2,
+                    page_token,
+                }))
+                .await
+                .unwrap()
+                .into_inner();
+            assert!(rsp.transactions.len() <= 2);
+            hashes.extend(rsp.transactions.into_iter().map(|tx| tx.hash));
+            if rsp.next_page_token.is_empty() {
+                break;
+            }
+            page_token = rsp.next_page_token;
+        }
+
+        assert_eq!(5, hashes.len());
+        assert!(hashes.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[tokio::test]
+    async fn get_transactions_filters_by_account() {
+        let (server, _storage) = server_with_mock_txs(3).await;
+        let (_, alice) = get_alice_signing_key_and_address();
+        let (_, bridge) = get_bridge_signing_key_and_address();
+
+        let get = |account: astria_core::primitive::v1::Address| {
+            server
+                .clone()
+                .get_transactions(Request::new(GetTransactionsRequest {
+                    account: Some(account.to_raw()),
+                    page_size: 0,
+                    page_token: vec![],
+                }))
+        };
+        assert_eq!(3, get(alice).await.unwrap().into_inner().transactions.len());
+        assert!(
+            get(bridge)
+                .await
+                .unwrap()
+                .into_inner()
+                .transactions
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn submit_transaction_reports_rejection() {
+        let (server, _storage) = server_with_mock_txs(0).await;
+        let rsp = server
+            .submit_transaction(Request::new(SubmitTransactionRequest {
+                transaction: vec![1, 2, 3],
+            }))
+            .await
+            .unwrap()
+            .into_inner();
+        assert_ne!(0, rsp.code);
+        assert!(!rsp.log.is_empty());
+    }
+}
@@ -1 +1,59 @@
+pub(crate) mod limits;
+pub(crate) mod mempool;
+pub(crate) mod optimistic;
 pub(crate) mod sequencer;
+
+use astria_core::{
+    generated::primitive::v1 as raw,
+    primitive::v1::Address,
+};
+use cnidarium::Storage;
+use tonic::Status;
+use tracing::{
+    error,
+    info,
+};
+
+use crate::{
+    accounts::state_ext::StateReadExt as _,
+    mempool::Mempool,
+};
+
+/// Parses the required address field `name` of a request.
+fn parse_address_field(name: &str, address: Option<raw::Address>) -> Result<Address, Status> {
+    let Some(address) = address else {
+        info!(field = name, "required field was not set");
+        return Err(Status::invalid_argument(format!(
+            "required field {name} was not set"
+        )));
+    };
+    Address::try_from_raw(&address).map_err(|e| {
+        info!(
+            error = %e,
+            "failed to parse address from request",
+        );
+        Status::invalid_argument(format!("invalid address: {e}"))
+    })
+}
+
+/// Returns the highest nonce of `address`'s transactions in the mempool, falling back to its
+/// current nonce in storage if it has none.
+async fn pending_nonce(
+    storage: &Storage,
+    mempool: &Mempool,
+    address: Address,
+) -> Result<u32, Status> {
+    if let Some(nonce) = mempool.pending_nonce(&address).await {
+        return Ok(nonce);
+    }
+
+    // nonce wasn't in mempool, so just look it up from storage
+    let snapshot = storage.latest_snapshot();
+    snapshot.get_account_nonce(address).await.map_err(|e| {
+        error!(
+            error = AsRef::<dyn std::error::Error>::as_ref(&e),
+            "failed to parse get account nonce from storage",
+        );
+        Status::internal(format!("failed to get account nonce from storage: {e}"))
+    })
+}
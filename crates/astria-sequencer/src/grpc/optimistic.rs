@@ -0,0 +1,139 @@
+use std::sync::Arc;
+
+use astria_core::{
+    generated::sequencerblock::optimistic::v1alpha1::{
+        optimistic_block_service_server::OptimisticBlockService,
+        StreamOptimisticBlockRequest,
+        StreamOptimisticBlockResponse,
+    },
+    primitive::v1::RollupId,
+    sequencerblock::v1alpha1::block::SequencerBlock,
+};
+use futures::{
+    stream::BoxStream,
+    StreamExt as _,
+};
+use tokio::sync::broadcast::{
+    self,
+    error::RecvError,
+};
+use tonic::{
+    Request,
+    Response,
+    Status,
+};
+use tracing::{
+    info,
+    instrument,
+    warn,
+};
+
+pub(crate) struct OptimisticBlockServer {
+    blocks: broadcast::Sender<Arc<SequencerBlock>>,
+}
+
+impl OptimisticBlockServer {
+    /// Creates a server streaming the blocks sent over `blocks`, which are the blocks accepted by
+    /// the app in `process_proposal`.
+    pub(crate) fn new(blocks: broadcast::Sender<Arc<SequencerBlock>>) -> Self {
+        Self {
+            blocks,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl OptimisticBlockService for OptimisticBlockServer {
+    type StreamOptimisticBlockStream =
+        BoxStream<'static, Result<StreamOptimisticBlockResponse, Status>>;
+
+    /// Streams the blocks accepted in `process_proposal`, filtered to contain only the
+    /// transactions of the requested rollup.
+    ///
+    /// Blocks are skipped if the subscriber falls behind.
+    #[instrument(skip_all)]
+    async fn stream_optimistic_block(
+        self: Arc<Self>,
+        request: Request<StreamOptimisticBlockRequest>,
+    ) -> Result<Response<Self::StreamOptimisticBlockStream>, Status> {
+        let Some(rollup_id) = request.into_inner().rollup_id else {
+            info!("required field rollup_id was not set");
+            return Err(Status::invalid_argument(
+                "required field rollup_id was not set",
+            ));
+        };
+        let rollup_id = RollupId::try_from_raw(&rollup_id)
+            .map_err(|e| Status::invalid_argument(format!("invalid rollup ID: {e}")))?;
+
+        let stream =
+            futures::stream::unfold(self.blocks.subscribe(), move |mut blocks| async move {
+                loop {
+                    match blocks.recv().await {
+                        Ok(block) => {
+                            let response = StreamOptimisticBlockResponse {
+                                block: Some(block.to_filtered_block([rollup_id]).into_raw()),
+                            };
+                            return Some((Ok(response), blocks));
+                        }
+                        Err(RecvError::Lagged(skipped)) => {
+                            warn!(
+                                %rollup_id,
+                                skipped,
+                                "optimistic block subscriber fell behind; skipping blocks",
+                            );
+                        }
+                        Err(RecvError::Closed) => return None,
+                    }
+                }
+            });
+        Ok(Response::new(stream.boxed()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use astria_core::protocol::test_utils::ConfigureSequencerBlock;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn streamed_blocks_are_filtered_to_requested_rollup() {
+        let rollup_id = RollupId::new([1; 32]);
+        let other_rollup_id = RollupId::new([2; 32]);
+        let block = ConfigureSequencerBlock {
+            height: 1,
+            sequence_data: vec![(rollup_id, vec![1, 2, 3]), (other_rollup_id, vec![4, 5, 6])],
+            ..Default::default()
+        }
+        .make();
+
+        let (blocks, _) = broadcast::channel(1);
+        let server = Arc::new(OptimisticBlockServer::new(blocks.clone()));
+        let request = Request::new(StreamOptimisticBlockRequest {
+            rollup_id: Some(rollup_id.to_raw()),
+        });
+        let mut stream = server
+            .stream_optimistic_block(request)
+            .await
+            .unwrap()
+            .into_inner();
+
+        blocks.send(Arc::new(block.clone())).unwrap();
+        let streamed = stream.next().await.unwrap().unwrap().block.unwrap();
+        assert_eq!(streamed, block.to_filtered_block([rollup_id]).into_raw());
+        assert_eq!(streamed.rollup_transactions.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn request_without_rollup_id_is_rejected() {
+        let (blocks, _) = broadcast::channel(1);
+        let server = Arc::new(OptimisticBlockServer::new(blocks));
+        let request = Request::new(StreamOptimisticBlockRequest {
+            rollup_id: None,
+        });
+        let Err(status) = server.stream_optimistic_block(request).await else {
+            panic!("request without rollup ID should be rejected");
+        };
+        assert_eq!(status.code(), tonic::Code::InvalidArgument);
+    }
+}
@@ -18,11 +18,7 @@ use tonic::{
     Response,
     Status,
 };
-use tracing::{
-    error,
-    info,
-    instrument,
-};
+use tracing::instrument;
 
 use crate::{
     api_state_ext::StateReadExt as _,
@@ -167,43 +163,8 @@ impl SequencerService for SequencerServer {
         self: Arc<Self>,
         request: Request<GetPendingNonceRequest>,
     ) -> Result<Response<GetPendingNonceResponse>, Status> {
-        use astria_core::primitive::v1::Address;
-
-        use crate::accounts::state_ext::StateReadExt as _;
-
-        let request = request.into_inner();
-        let Some(address) = request.address else {
-            info!("required field address was not set",);
-            return Err(Status::invalid_argument(
-                "required field address was not set",
-            ));
-        };
-
-        let address = Address::try_from_raw(&address).map_err(|e| {
-            info!(
-                error = %e,
-                "failed to parse address from request",
-            );
-            Status::invalid_argument(format!("invalid address: {e}"))
-        })?;
-        let nonce = self.mempool.pending_nonce(&address).await;
-
-        if let Some(nonce) = nonce {
-            return Ok(Response::new(GetPendingNonceResponse {
-                inner: nonce,
-            }));
-        }
-
-        // nonce wasn't in mempool, so just look it up from storage
-        let snapshot = self.storage.latest_snapshot();
-        let nonce = snapshot.get_account_nonce(address).await.map_err(|e| {
-            error!(
-                error = AsRef::<dyn std::error::Error>::as_ref(&e),
-                "failed to parse get account nonce from storage",
-            );
-            Status::internal(format!("failed to get account nonce from storage: {e}"))
-        })?;
-
+        let address = super::parse_address_field("address", request.into_inner().address)?;
+        let nonce = super::pending_nonce(&self.storage, &self.mempool, address).await?;
         Ok(Response::new(GetPendingNonceResponse {
             inner: nonce,
         }))
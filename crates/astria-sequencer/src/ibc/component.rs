@@ -51,6 +51,21 @@ impl Component for IbcComponent {
         state
             .put_ics20_withdrawal_base_fee(app_state.fees.ics20_withdrawal_base_fee)
             .context("failed to put ics20 withdrawal base fee")?;
+
+        // only stored if set, so that the state of chains without a forward fee is unchanged.
+        if app_state.fees.ics20_forward_fee_basis_points > 0 {
+            state
+                .put_ics20_forward_fee_basis_points(app_state.fees.ics20_forward_fee_basis_points)
+                .context("failed to put ics20 forward fee")?;
+        }
+
+        // only stored if set, so that the state of chains without an interchain accounts host
+        // is unchanged.
+        if let Some(params) = &app_state.ica_host {
+            state
+                .put_ica_host_params(params)
+                .context("failed to put interchain accounts host params")?;
+        }
         Ok(())
     }
 
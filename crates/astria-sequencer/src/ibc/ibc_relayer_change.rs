@@ -14,6 +14,7 @@ use cnidarium::{
 };
 
 use crate::{
+    address::StateReadExt as _,
     ibc::state_ext::{
         StateReadExt,
         StateWriteExt,
@@ -23,18 +24,15 @@ use crate::{
 
 #[async_trait]
 impl ActionHandler for IbcRelayerChangeAction {
-    async fn check_stateless(&self) -> Result<()> {
+    async fn check_stateful<S: StateRead + 'static>(&self, state: &S, from: Address) -> Result<()> {
         match self {
             IbcRelayerChangeAction::Addition(addr) | IbcRelayerChangeAction::Removal(addr) => {
-                crate::address::ensure_base_prefix(addr)
+                state
+                    .ensure_allowed_prefix(addr)
+                    .await
                     .context("provided address to be added or removed has an unsupported prefix")?;
             }
         }
-
-        Ok(())
-    }
-
-    async fn check_stateful<S: StateRead + 'static>(&self, state: &S, from: Address) -> Result<()> {
         let ibc_sudo_address = state
             .get_ibc_sudo_address()
             .await
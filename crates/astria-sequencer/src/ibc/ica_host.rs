@@ -0,0 +1,686 @@
+//! The ICS27 interchain accounts host.
+//!
+//! This lets controller chains operate accounts on the sequencer as described in the
+//! [interchain accounts specification]. A controller opens a channel to the `icahost` port and
+//! sends transactions over it, each of which is executed on behalf of the interchain account
+//! bound to the channel's connection and controller port.
+//!
+//! The host is gated by its genesis parameters: unless it is enabled, channel handshakes are
+//! rejected and received transactions are acknowledged with an error. Only messages whose type
+//! URLs are in the allowed list are executed. Messages are the protobuf encoded sequencer actions,
+//! e.g. `/astria.protocol.transactions.v1alpha1.TransferAction`.
+//!
+//! Received transactions are not executed right away, but at the start of the next block, before
+//! its signed transactions, where they are checked and executed like signed transactions sent by
+//! the interchain account. This way the deposits and fees of a transaction are part of the
+//! commitments of the block it is executed in. Their acknowledgements are written once they were
+//! executed.
+//!
+//! The IBC implementation does not allow the host to set the channel version during the
+//! handshake, so controllers must propose version metadata that already contains the address of
+//! the interchain account, see [`interchain_account_address`].
+//!
+//! [interchain accounts specification]: https://github.com/cosmos/ibc/blob/main/spec/app/ics-027-interchain-accounts/README.md
+
+use anyhow::{
+    bail,
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::{
+    generated::protocol::transaction::v1alpha1 as raw,
+    primitive::v1::{
+        Address,
+        ADDRESS_LEN,
+    },
+    protocol::transaction::v1alpha1::Action,
+};
+use base64::{
+    engine::general_purpose::STANDARD,
+    Engine as _,
+};
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use ibc_proto::{
+    cosmos::base::abci::v1beta1::TxMsgData,
+    google::protobuf::Any,
+    ibc::applications::interchain_accounts::v1::CosmosTx,
+};
+use ibc_types::core::{
+    channel::{
+        msgs::{
+            MsgAcknowledgement,
+            MsgChannelCloseConfirm,
+            MsgChannelCloseInit,
+            MsgChannelOpenAck,
+            MsgChannelOpenConfirm,
+            MsgChannelOpenInit,
+            MsgChannelOpenTry,
+            MsgRecvPacket,
+            MsgTimeout,
+        },
+        Packet,
+        PortId,
+    },
+    connection::ConnectionId,
+};
+use penumbra_ibc::component::{
+    app_handler::{
+        AppHandler,
+        AppHandlerCheck,
+        AppHandlerExecute,
+    },
+    packet::WriteAcknowledgement as _,
+    ChannelStateReadExt as _,
+};
+use prost::{
+    Message as _,
+    Name,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+
+use crate::{
+    ibc::state_ext::{
+        StateReadExt,
+        StateWriteExt as _,
+    },
+    transaction,
+};
+
+/// The port interchain account channels are opened on.
+pub(crate) const PORT: &str = "icahost";
+
+const VERSION: &str = "ics27-1";
+const ENCODING: &str = "proto3";
+const TX_TYPE: &str = "sdk_multi_msg";
+const CONTROLLER_PORT_PREFIX: &str = "icacontroller-";
+const EXECUTE_TX: &str = "TYPE_EXECUTE_TX";
+
+/// The maximum length of the encoded interchain account packet data in bytes.
+const MAX_PACKET_DATA_BYTE_LENGTH: usize = 65_536;
+
+/// The genesis parameters of the interchain accounts host.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub(crate) struct HostParams {
+    /// Whether controller chains may open channels to and execute transactions on the host.
+    pub(crate) enabled: bool,
+    /// The type URLs of the messages interchain accounts are allowed to execute.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(crate) allowed_messages: Vec<String>,
+}
+
+/// An interchain account transaction that was received, ready to be executed.
+#[derive(Clone, Debug)]
+pub(crate) struct PendingTx {
+    /// The interchain account executing the transaction.
+    pub(crate) address: Address,
+    /// The type URL and decoded action of each message of the transaction.
+    pub(crate) messages: Vec<(String, Action)>,
+}
+
+/// The version metadata of an interchain accounts channel.
+///
+/// See [here](https://github.com/cosmos/ibc-go/blob/main/proto/ibc/applications/interchain_accounts/v1/metadata.proto).
+#[derive(Debug, Deserialize)]
+struct Metadata {
+    version: String,
+    controller_connection_id: String,
+    host_connection_id: String,
+    #[serde(default)]
+    address: String,
+    encoding: String,
+    tx_type: String,
+}
+
+/// The JSON encoded `InterchainAccountPacketData`.
+#[derive(Debug, Deserialize)]
+struct PacketData {
+    #[serde(rename = "type")]
+    kind: String,
+    /// The base64 encoded protobuf `CosmosTx`.
+    #[serde(default)]
+    data: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Acknowledgement {
+    /// The base64 encoded protobuf `TxMsgData`.
+    Result(String),
+    Error(String),
+}
+
+/// Returns the interchain account controlled over channels on `connection` to `controller_port`.
+///
+/// The address is the first 20 bytes of the sha256 hash of the ICS27 version, the connection and
+/// the controller port, with the base prefix.
+pub(crate) fn interchain_account_address(
+    connection: &ConnectionId,
+    controller_port: &PortId,
+) -> Address {
+    let mut hasher = Sha256::new();
+    hasher.update(VERSION.as_bytes());
+    hasher.update(connection.as_str().as_bytes());
+    hasher.update(b"/");
+    hasher.update(controller_port.as_str().as_bytes());
+    let hash = hasher.finalize();
+    let mut bytes = [0u8; ADDRESS_LEN];
+    bytes.copy_from_slice(&hash[..ADDRESS_LEN]);
+    crate::address::base_prefixed(bytes)
+}
+
+fn type_url<T: Name>() -> String {
+    format!("/{}", T::full_name())
+}
+
+/// Returns whether interchain accounts can be allowed to execute messages with `type_url`.
+///
+/// Sequence actions are not supported, as their data only reaches rollups if it is part of a
+/// signed transaction included in a block.
+pub(crate) fn is_supported_message(type_url: &str) -> bool {
+    [
+        self::type_url::<raw::TransferAction>(),
+        self::type_url::<raw::InitBridgeAccountAction>(),
+        self::type_url::<raw::BridgeLockAction>(),
+        self::type_url::<raw::BridgeUnlockAction>(),
+        self::type_url::<raw::BridgeSudoChangeAction>(),
+        self::type_url::<raw::Ics20Withdrawal>(),
+    ]
+    .iter()
+    .any(|supported| supported == type_url)
+}
+
+fn decode_message(message: &Any) -> Result<Action> {
+    use raw::action::Value;
+
+    fn decode<T: prost::Message + Default>(bytes: &[u8]) -> Result<T> {
+        T::decode(bytes).context("failed to decode message")
+    }
+
+    let url = message.type_url.as_str();
+    let bytes = message.value.as_slice();
+    let value = if url == type_url::<raw::TransferAction>() {
+        Value::TransferAction(decode(bytes)?)
+    } else if url == type_url::<raw::InitBridgeAccountAction>() {
+        Value::InitBridgeAccountAction(decode(bytes)?)
+    } else if url == type_url::<raw::BridgeLockAction>() {
+        Value::BridgeLockAction(decode(bytes)?)
+    } else if url == type_url::<raw::BridgeUnlockAction>() {
+        Value::BridgeUnlockAction(decode(bytes)?)
+    } else if url == type_url::<raw::BridgeSudoChangeAction>() {
+        Value::BridgeSudoChangeAction(decode(bytes)?)
+    } else if url == type_url::<raw::Ics20Withdrawal>() {
+        Value::Ics20Withdrawal(decode(bytes)?)
+    } else {
+        bail!("unsupported message type `{url}`");
+    };
+    Action::try_from_raw(raw::Action {
+        value: Some(value),
+    })
+    .with_context(|| format!("invalid message of type `{url}`"))
+}
+
+async fn enabled_params<S: StateRead>(state: &S) -> Result<HostParams> {
+    match state
+        .get_ica_host_params()
+        .await
+        .context("failed to read interchain accounts host params")?
+    {
+        Some(params) if params.enabled => Ok(params),
+        _ => bail!("interchain accounts host is disabled"),
+    }
+}
+
+fn ensure_valid_metadata(
+    version: &str,
+    host_connection: &ConnectionId,
+    controller_port: &PortId,
+) -> Result<()> {
+    ensure!(
+        controller_port.as_str().starts_with(CONTROLLER_PORT_PREFIX),
+        "counterparty port must start with `{CONTROLLER_PORT_PREFIX}`"
+    );
+    let metadata: Metadata = serde_json::from_str(version)
+        .context("channel version must be JSON encoded interchain accounts metadata")?;
+    ensure!(
+        metadata.version == VERSION,
+        "metadata version must be {VERSION}"
+    );
+    ensure!(
+        metadata.encoding == ENCODING,
+        "metadata encoding must be {ENCODING}"
+    );
+    ensure!(
+        metadata.tx_type == TX_TYPE,
+        "metadata tx type must be {TX_TYPE}"
+    );
+    ensure!(
+        !metadata.controller_connection_id.is_empty(),
+        "metadata must contain the controller connection ID"
+    );
+    ensure!(
+        metadata.host_connection_id == host_connection.as_str(),
+        "metadata host connection ID must be `{host_connection}`"
+    );
+    let address = interchain_account_address(host_connection, controller_port);
+    ensure!(
+        metadata.address == address.to_string(),
+        "metadata address must be the interchain account address `{address}`"
+    );
+    Ok(())
+}
+
+/// Decodes the transaction in `packet` and runs the checks that do not depend on the state it
+/// will be executed against.
+pub(crate) async fn pending_tx<S: StateRead>(state: &S, packet: &Packet) -> Result<PendingTx> {
+    let params = enabled_params(state).await?;
+
+    let data: PacketData = serde_json::from_slice(&packet.data)
+        .context("failed to decode interchain account packet data json")?;
+    ensure!(
+        data.kind == EXECUTE_TX,
+        "unsupported interchain account packet type `{}`",
+        data.kind
+    );
+    let tx_bytes = STANDARD
+        .decode(data.data)
+        .context("packet data must be base64 encoded")?;
+    let tx = CosmosTx::decode(tx_bytes.as_slice()).context("failed to decode cosmos tx")?;
+    ensure!(
+        !tx.messages.is_empty(),
+        "interchain account transaction must contain at least one message"
+    );
+
+    let mut messages = Vec::with_capacity(tx.messages.len());
+    for message in tx.messages {
+        ensure!(
+            params.allowed_messages.contains(&message.type_url),
+            "message type `{}` is not allowed for interchain accounts",
+            message.type_url
+        );
+        let action = decode_message(&message)?;
+        transaction::check_action_stateless(&action)
+            .await
+            .context("stateless check failed")?;
+        messages.push((message.type_url, action));
+    }
+
+    let channel = state
+        .get_channel(&packet.chan_on_b, &packet.port_on_b)
+        .await
+        .context("failed to read channel")?
+        .context("channel not found")?;
+    let connection = channel
+        .connection_hops
+        .first()
+        .context("channel has no connection hop")?;
+    Ok(PendingTx {
+        address: interchain_account_address(connection, &packet.port_on_a),
+        messages,
+    })
+}
+
+/// Runs the stateful checks of all messages of `tx`.
+pub(crate) async fn check_stateful<S: StateRead + 'static>(
+    tx: &PendingTx,
+    state: &S,
+) -> Result<()> {
+    for (_, action) in &tx.messages {
+        transaction::check_action_stateful(action, state, tx.address).await?;
+    }
+    Ok(())
+}
+
+/// Executes all messages of `tx`.
+pub(crate) async fn execute<S: StateWrite>(tx: &PendingTx, state: &mut S) -> Result<()> {
+    for (_, action) in &tx.messages {
+        transaction::execute_action(action, state, tx.address).await?;
+    }
+    Ok(())
+}
+
+/// Writes the acknowledgement of the transaction received in `packet` given the `result` of
+/// executing it.
+pub(crate) async fn write_acknowledgement<S: StateWrite>(
+    state: &mut S,
+    packet: &Packet,
+    result: &Result<PendingTx>,
+) -> Result<()> {
+    let ack = match result {
+        Ok(tx) => success_acknowledgement(tx),
+        Err(e) => error_acknowledgement(e),
+    };
+    state
+        .write_acknowledgement(packet, &ack)
+        .await
+        .context("failed to write acknowledgement")
+}
+
+/// Sequencer actions have no responses, so the type URL of each executed message is returned with
+/// an empty value.
+fn success_acknowledgement(tx: &PendingTx) -> Vec<u8> {
+    let data = TxMsgData {
+        msg_responses: tx
+            .messages
+            .iter()
+            .map(|(type_url, _)| Any {
+                type_url: type_url.clone(),
+                value: vec![],
+            })
+            .collect(),
+        ..TxMsgData::default()
+    };
+    serde_json::to_vec(&Acknowledgement::Result(
+        STANDARD.encode(data.encode_to_vec()),
+    ))
+    .expect("serializing a string enum to JSON must not fail")
+}
+
+fn error_acknowledgement(error: &anyhow::Error) -> Vec<u8> {
+    serde_json::to_vec(&Acknowledgement::Error(format!("{error:#}")))
+        .expect("serializing a string enum to JSON must not fail")
+}
+
+/// The interchain accounts host handler.
+#[derive(Clone)]
+pub(crate) struct IcaHost;
+
+#[async_trait::async_trait]
+impl AppHandlerCheck for IcaHost {
+    async fn chan_open_init_check<S: StateRead>(_: S, _: &MsgChannelOpenInit) -> Result<()> {
+        bail!("interchain account channels must be initialized by the controller chain");
+    }
+
+    async fn chan_open_try_check<S: StateRead>(state: S, msg: &MsgChannelOpenTry) -> Result<()> {
+        enabled_params(&state).await?;
+        let connection = msg
+            .connection_hops_on_b
+            .first()
+            .context("channel must have a connection hop")?;
+        ensure_valid_metadata(
+            msg.version_supported_on_a.as_str(),
+            connection,
+            &msg.port_id_on_a,
+        )
+    }
+
+    async fn chan_open_ack_check<S: StateRead>(_: S, _: &MsgChannelOpenAck) -> Result<()> {
+        bail!("interchain account channels must be initialized by the controller chain");
+    }
+
+    async fn chan_open_confirm_check<S: StateRead>(_: S, _: &MsgChannelOpenConfirm) -> Result<()> {
+        // the version has already been validated in `chan_open_try_check`
+        Ok(())
+    }
+
+    async fn chan_close_init_check<S: StateRead>(_: S, _: &MsgChannelCloseInit) -> Result<()> {
+        bail!("interchain account channels cannot be closed by the host");
+    }
+
+    async fn chan_close_confirm_check<S: StateRead>(
+        _: S,
+        _: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        Ok(())
+    }
+
+    async fn recv_packet_check<S: StateRead>(_: S, msg: &MsgRecvPacket) -> Result<()> {
+        // invalid transactions are acknowledged with an error in `execute`
+        ensure!(
+            msg.packet.data.len() <= MAX_PACKET_DATA_BYTE_LENGTH,
+            "packet data is too long: exceeds MAX_PACKET_DATA_BYTE_LENGTH"
+        );
+        Ok(())
+    }
+
+    async fn timeout_packet_check<S: StateRead>(_: S, _: &MsgTimeout) -> Result<()> {
+        bail!("the interchain accounts host does not send packets");
+    }
+
+    async fn acknowledge_packet_check<S: StateRead>(_: S, _: &MsgAcknowledgement) -> Result<()> {
+        bail!("the interchain accounts host does not send packets");
+    }
+}
+
+#[async_trait::async_trait]
+impl AppHandlerExecute for IcaHost {
+    async fn chan_open_init_execute<S: StateWrite>(_: S, _: &MsgChannelOpenInit) {}
+
+    async fn chan_open_try_execute<S: StateWrite>(_: S, _: &MsgChannelOpenTry) {}
+
+    async fn chan_open_ack_execute<S: StateWrite>(_: S, _: &MsgChannelOpenAck) {}
+
+    async fn chan_open_confirm_execute<S: StateWrite>(_: S, _: &MsgChannelOpenConfirm) {}
+
+    async fn chan_close_confirm_execute<S: StateWrite>(_: S, _: &MsgChannelCloseConfirm) {}
+
+    async fn chan_close_init_execute<S: StateWrite>(_: S, _: &MsgChannelCloseInit) {}
+
+    async fn recv_packet_execute<S: StateWrite>(mut state: S, msg: &MsgRecvPacket) -> Result<()> {
+        match pending_tx(&state, &msg.packet).await {
+            Ok(_) => {
+                // the transaction is executed and acknowledged at the start of the next block
+                state
+                    .put_pending_interchain_account_packet(&msg.packet)
+                    .await
+                    .context("failed to queue interchain account transaction")
+            }
+            Err(e) => {
+                tracing::debug!(
+                    error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                    "rejected interchain account transaction"
+                );
+                state
+                    .write_acknowledgement(&msg.packet, &error_acknowledgement(&e))
+                    .await
+                    .context("failed to write acknowledgement")
+            }
+        }
+    }
+
+    async fn timeout_packet_execute<S: StateWrite>(_: S, _: &MsgTimeout) -> Result<()> {
+        Ok(())
+    }
+
+    async fn acknowledge_packet_execute<S: StateWrite>(_: S, _: &MsgAcknowledgement) {}
+}
+
+#[async_trait::async_trait]
+impl AppHandler for IcaHost {}
+
+#[cfg(test)]
+mod test {
+    use astria_core::{
+        primitive::v1::asset::default_native_asset,
+        protocol::transaction::v1alpha1::action::TransferAction,
+    };
+    use cnidarium::StateDelta;
+    use ibc_proto::ibc::core::{
+        channel::v1::Packet as RawPacket,
+        client::v1::Height as RawHeight,
+    };
+
+    use super::*;
+
+    fn connection() -> ConnectionId {
+        "connection-0".parse().unwrap()
+    }
+
+    fn controller_port() -> PortId {
+        "icacontroller-cosmos1owner".parse().unwrap()
+    }
+
+    fn metadata(address: &Address) -> String {
+        serde_json::json!({
+            "version": "ics27-1",
+            "controller_connection_id": "connection-7",
+            "host_connection_id": "connection-0",
+            "address": address.to_string(),
+            "encoding": "proto3",
+            "tx_type": "sdk_multi_msg",
+        })
+        .to_string()
+    }
+
+    fn packet(data: Vec<u8>) -> Packet {
+        Packet::try_from(RawPacket {
+            sequence: 1,
+            source_port: controller_port().to_string(),
+            source_channel: "channel-3".to_string(),
+            destination_port: PORT.to_string(),
+            destination_channel: "channel-0".to_string(),
+            data,
+            timeout_height: Some(RawHeight {
+                revision_number: 0,
+                revision_height: 100,
+            }),
+            timeout_timestamp: 0,
+        })
+        .unwrap()
+    }
+
+    fn transfer_message() -> Any {
+        let action = TransferAction {
+            to: crate::address::base_prefixed([2; ADDRESS_LEN]),
+            amount: 100,
+            asset_id: default_native_asset().id(),
+            fee_asset_id: default_native_asset().id(),
+        };
+        Any {
+            type_url: "/astria.protocol.transactions.v1alpha1.TransferAction".to_string(),
+            value: action.into_raw().encode_to_vec(),
+        }
+    }
+
+    #[test]
+    fn interchain_account_address_depends_on_connection_and_port() {
+        let address = interchain_account_address(&connection(), &controller_port());
+        assert_eq!(
+            address,
+            interchain_account_address(&connection(), &controller_port())
+        );
+        assert_ne!(
+            address,
+            interchain_account_address(&"connection-1".parse().unwrap(), &controller_port())
+        );
+        assert_ne!(
+            address,
+            interchain_account_address(&connection(), &"icacontroller-other".parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn metadata_must_contain_interchain_account_address() {
+        let address = interchain_account_address(&connection(), &controller_port());
+        ensure_valid_metadata(&metadata(&address), &connection(), &controller_port()).unwrap();
+
+        let other = crate::address::base_prefixed([1; ADDRESS_LEN]);
+        assert!(
+            ensure_valid_metadata(&metadata(&other), &connection(), &controller_port()).is_err()
+        );
+        assert!(
+            ensure_valid_metadata(
+                &metadata(&address),
+                &"connection-1".parse().unwrap(),
+                &controller_port()
+            )
+            .is_err()
+        );
+        assert!(
+            ensure_valid_metadata(
+                &metadata(&address),
+                &connection(),
+                &"transfer".parse().unwrap()
+            )
+            .is_err()
+        );
+        assert!(ensure_valid_metadata("ics27-1", &connection(), &controller_port()).is_err());
+    }
+
+    #[test]
+    fn supported_messages_are_decoded() {
+        assert!(is_supported_message(
+            "/astria.protocol.transactions.v1alpha1.TransferAction"
+        ));
+        assert!(!is_supported_message(
+            "/astria.protocol.transactions.v1alpha1.SequenceAction"
+        ));
+        assert!(!is_supported_message(
+            "/astria.protocol.transactions.v1alpha1.SudoAddressChangeAction"
+        ));
+        assert!(!is_supported_message("/cosmos.bank.v1beta1.MsgSend"));
+
+        assert!(matches!(
+            decode_message(&transfer_message()).unwrap(),
+            Action::Transfer(_)
+        ));
+        let unsupported = Any {
+            type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+            value: vec![],
+        };
+        assert!(decode_message(&unsupported).is_err());
+    }
+
+    #[tokio::test]
+    async fn transactions_are_rejected_unless_enabled_and_allowed() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let tx = CosmosTx {
+            messages: vec![transfer_message()],
+        };
+        let data = serde_json::json!({
+            "type": "TYPE_EXECUTE_TX",
+            "data": STANDARD.encode(tx.encode_to_vec()),
+            "memo": "",
+        });
+        let packet = packet(serde_json::to_vec(&data).unwrap());
+
+        let error = pending_tx(&state, &packet).await.unwrap_err();
+        assert!(error.to_string().contains("disabled"));
+
+        state
+            .put_ica_host_params(&HostParams {
+                enabled: true,
+                allowed_messages: vec![
+                    "/astria.protocol.transactions.v1alpha1.BridgeLockAction".to_string(),
+                ],
+            })
+            .unwrap();
+        let error = pending_tx(&state, &packet).await.unwrap_err();
+        assert!(error.to_string().contains("not allowed"));
+    }
+
+    #[test]
+    fn acknowledgements_are_json_encoded() {
+        let ack = error_acknowledgement(&anyhow::anyhow!("failed"));
+        assert_eq!(br#"{"error":"failed"}"#.to_vec(), ack);
+
+        let tx = PendingTx {
+            address: crate::address::base_prefixed([1; ADDRESS_LEN]),
+            messages: vec![(
+                transfer_message().type_url,
+                decode_message(&transfer_message()).unwrap(),
+            )],
+        };
+        let ack: serde_json::Value = serde_json::from_slice(&success_acknowledgement(&tx)).unwrap();
+        let data = STANDARD.decode(ack["result"].as_str().unwrap()).unwrap();
+        let data = TxMsgData::decode(data.as_slice()).unwrap();
+        assert_eq!(1, data.msg_responses.len());
+        assert_eq!(
+            "/astria.protocol.transactions.v1alpha1.TransferAction",
+            data.msg_responses[0].type_url
+        );
+    }
+}
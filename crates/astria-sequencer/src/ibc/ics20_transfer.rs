@@ -71,9 +71,12 @@ use crate::{
         StateReadExt as _,
         StateWriteExt as _,
     },
-    ibc::state_ext::{
-        StateReadExt,
-        StateWriteExt,
+    ibc::{
+        packet_forward,
+        state_ext::{
+            StateReadExt,
+            StateWriteExt,
+        },
     },
 };
 
@@ -250,17 +253,30 @@ impl AppHandlerExecute for Ics20Transfer {
     ) -> anyhow::Result<()> {
         use penumbra_ibc::component::packet::WriteAcknowledgement as _;
 
-        let ack = match execute_ics20_transfer(
-            &mut state,
-            &msg.packet.data,
-            &msg.packet.port_on_a,
-            &msg.packet.chan_on_a,
-            &msg.packet.port_on_b,
-            &msg.packet.chan_on_b,
-            false,
-        )
-        .await
-        {
+        let result = match packet_forward::forward_metadata(&msg.packet.data) {
+            Ok(Some(metadata)) => {
+                match packet_forward::execute_forward(&mut state, &msg.packet, metadata).await {
+                    // the acknowledgement is written once the forwarded packet completes
+                    Ok(()) => return Ok(()),
+                    Err(e) => Err(e.context("failed to forward ics20 transfer")),
+                }
+            }
+            Ok(None) => {
+                execute_ics20_transfer(
+                    &mut state,
+                    &msg.packet.data,
+                    &msg.packet.port_on_a,
+                    &msg.packet.chan_on_a,
+                    &msg.packet.port_on_b,
+                    &msg.packet.chan_on_b,
+                    false,
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+
+        let ack = match result {
             Ok(()) => TokenTransferAcknowledgement::success(),
             Err(e) => {
                 tracing::debug!(
@@ -283,6 +299,13 @@ impl AppHandlerExecute for Ics20Transfer {
         mut state: S,
         msg: &MsgTimeout,
     ) -> anyhow::Result<()> {
+        if packet_forward::complete_forward(&mut state, &msg.packet, Some("packet timed out"))
+            .await
+            .context("failed to complete forward during timeout_packet_execute")?
+        {
+            return Ok(());
+        }
+
         // we put source and dest as chain_a (the source) as we're refunding tokens,
         // and the destination chain of the refund is the source.
         execute_ics20_transfer(
@@ -303,6 +326,22 @@ impl AppHandlerExecute for Ics20Transfer {
             msg.acknowledgement.as_slice(),
         )
         .expect("valid acknowledgement, should have been checked in acknowledge_packet_check");
+        let failure = match &ack {
+            TokenTransferAcknowledgement::Error(error) => Some(error.as_str()),
+            TokenTransferAcknowledgement::Success(_) => None,
+        };
+        match packet_forward::complete_forward(&mut state, &msg.packet, failure).await {
+            Ok(true) => return,
+            Ok(false) => {}
+            Err(e) => {
+                let error: &dyn std::error::Error = e.as_ref();
+                tracing::error!(
+                    error,
+                    "failed to complete forward during acknowledge_packet_execute",
+                );
+                return;
+            }
+        }
         if ack.is_successful() {
             return;
         }
@@ -332,7 +371,7 @@ impl AppHandlerExecute for Ics20Transfer {
 #[async_trait::async_trait]
 impl AppHandler for Ics20Transfer {}
 
-async fn convert_denomination_if_ibc_prefixed<S: StateReadExt>(
+pub(super) async fn convert_denomination_if_ibc_prefixed<S: StateReadExt>(
     state: &mut S,
     packet_denom: Denom,
 ) -> Result<denom::TracePrefixed> {
@@ -350,7 +389,7 @@ async fn convert_denomination_if_ibc_prefixed<S: StateReadExt>(
     Ok(denom)
 }
 
-fn prepend_denom_if_not_refund<'a>(
+pub(super) fn prepend_denom_if_not_refund<'a>(
     packet_denom: &'a denom::TracePrefixed,
     dest_port: &PortId,
     dest_channel: &ChannelId,
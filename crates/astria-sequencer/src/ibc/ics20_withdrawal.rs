@@ -29,11 +29,13 @@ use crate::{
         StateReadExt,
         StateWriteExt,
     },
+    address::StateReadExt as _,
     bridge::state_ext::StateReadExt as _,
     ibc::state_ext::{
         StateReadExt as _,
         StateWriteExt as _,
     },
+    state_ext::StateWriteExt as _,
     transaction::action_handler::ActionHandler,
 };
 
@@ -102,14 +104,6 @@ impl ActionHandler for action::Ics20Withdrawal {
     async fn check_stateless(&self) -> Result<()> {
         ensure!(self.timeout_time() != 0, "timeout time must be non-zero",);
 
-        crate::address::ensure_base_prefix(&self.return_address)
-            .context("return address has an unsupported prefix")?;
-        self.bridge_address
-            .as_ref()
-            .map(crate::address::ensure_base_prefix)
-            .transpose()
-            .context("bridge address has an unsupported prefix")?;
-
         // NOTE (from penumbra): we could validate the destination chain address as bech32 to
         // prevent mistyped addresses, but this would preclude sending to chains that don't
         // use bech32 addresses.
@@ -122,6 +116,17 @@ impl ActionHandler for action::Ics20Withdrawal {
         state: &S,
         from: Address,
     ) -> Result<()> {
+        state
+            .ensure_allowed_prefix(&self.return_address)
+            .await
+            .context("return address has an unsupported prefix")?;
+        if let Some(bridge_address) = &self.bridge_address {
+            state
+                .ensure_allowed_prefix(bridge_address)
+                .await
+                .context("bridge address has an unsupported prefix")?;
+        }
+
         ics20_withdrawal_check_stateful_bridge_account(self, state, from).await?;
 
         let fee = state
@@ -192,6 +197,10 @@ impl ActionHandler for action::Ics20Withdrawal {
             .decrease_balance(from, *self.fee_asset_id(), fee)
             .await
             .context("failed to subtract fee from sender balance")?;
+        state
+            .get_and_increase_burned_fees(*self.fee_asset_id(), fee)
+            .await
+            .context("failed to add to burned fees")?;
 
         // if we're the source, move tokens to the escrow account,
         // otherwise the tokens are just burned
@@ -1,6 +1,9 @@
 pub(crate) mod component;
 pub(crate) mod host_interface;
 pub(crate) mod ibc_relayer_change;
+pub(crate) mod ica_host;
 pub(crate) mod ics20_transfer;
 pub(crate) mod ics20_withdrawal;
+pub(crate) mod packet_forward;
+pub(crate) mod router;
 pub(crate) mod state_ext;
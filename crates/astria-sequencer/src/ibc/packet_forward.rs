@@ -0,0 +1,628 @@
+//! Forwarding of incoming ICS20 transfers to another chain.
+//!
+//! This implements the semantics of the [packet forward middleware]. An incoming transfer whose
+//! memo contains a `forward` object is not credited to its receiver on the sequencer. The tokens
+//! are instead sent on over the given channel to the forward receiver, with the memo's `next`
+//! field as the memo of the forwarded packet, so that multiple hops can be chained.
+//!
+//! The acknowledgement of the incoming packet is written once the forwarded packet was
+//! acknowledged or timed out. If forwarding failed, the sequencer reverts its side of the
+//! transfer and acknowledges the incoming packet with an error, so that the chain it came from
+//! refunds the original sender.
+//!
+//! A fee, configured in basis points of the transferred amount, is deducted from the forwarded
+//! tokens and added to the block fees once the forward succeeded. Failed forwards are not charged.
+//!
+//! Unlike the middleware, the sequencer does not retry forwards that timed out; the `retries`
+//! field is ignored.
+//!
+//! [packet forward middleware]: https://github.com/cosmos/ibc-apps/tree/main/middleware/packet-forward-middleware
+
+use std::time::Duration;
+
+use anyhow::{
+    bail,
+    ensure,
+    Context as _,
+    Result,
+};
+use astria_core::primitive::v1::asset::{
+    self,
+    Denom,
+};
+use ibc_types::{
+    core::{
+        channel::{
+            ChannelId,
+            Packet,
+            PortId,
+        },
+        client::Height,
+    },
+    transfer::acknowledgement::TokenTransferAcknowledgement,
+};
+use penumbra_ibc::component::{
+    packet::{
+        IBCPacket,
+        SendPacketRead as _,
+        SendPacketWrite as _,
+        WriteAcknowledgement as _,
+    },
+    ChannelStateReadExt as _,
+};
+use penumbra_proto::penumbra::core::component::ibc::v1::FungibleTokenPacketData;
+use serde::Deserialize;
+
+use crate::{
+    asset::state_ext::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    ibc::{
+        ics20_transfer::{
+            convert_denomination_if_ibc_prefixed,
+            prepend_denom_if_not_refund,
+        },
+        state_ext::{
+            StateReadExt,
+            StateWriteExt,
+        },
+    },
+    state_ext::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+};
+
+/// The timeout of forwarded packets if the forward metadata does not set one.
+const DEFAULT_FORWARD_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// The denominator of fees given in basis points.
+const BASIS_POINTS_PER_UNIT: u128 = 10_000;
+
+/// The `forward` object of an ICS20 packet memo.
+#[derive(Debug, Deserialize)]
+pub(crate) struct ForwardMetadata {
+    receiver: String,
+    port: String,
+    channel: String,
+    #[serde(default)]
+    timeout: Option<Timeout>,
+    #[serde(default)]
+    next: Option<serde_json::Value>,
+}
+
+/// The timeout of a forwarded packet, either in nanoseconds or as a Go duration string like
+/// `"10m"` or `"1h30m"`.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Timeout {
+    Nanos(u64),
+    Duration(String),
+}
+
+impl ForwardMetadata {
+    fn timeout(&self) -> Result<Duration> {
+        match &self.timeout {
+            None => Ok(DEFAULT_FORWARD_TIMEOUT),
+            Some(Timeout::Nanos(nanos)) => Ok(Duration::from_nanos(*nanos)),
+            Some(Timeout::Duration(duration)) => parse_go_duration(duration),
+        }
+    }
+
+    /// Returns the memo of the forwarded packet.
+    fn next_memo(&self) -> String {
+        match &self.next {
+            None => String::new(),
+            Some(serde_json::Value::String(next)) => next.clone(),
+            Some(next) => next.to_string(),
+        }
+    }
+}
+
+/// A forwarded transfer waiting for the forwarded packet to be acknowledged.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct PendingForward {
+    /// The incoming packet, which is acknowledged once the forward completed.
+    pub(crate) packet: Packet,
+    /// The asset received by the sequencer.
+    pub(crate) asset: asset::Id,
+    /// The amount of the incoming transfer.
+    pub(crate) amount: u128,
+    /// The part of `amount` kept as fee; the rest was forwarded.
+    pub(crate) fee: u128,
+    /// The incoming channel whose escrow released the tokens, if the sequencer was their source.
+    pub(crate) unescrowed_from: Option<ChannelId>,
+    /// The outgoing channel whose escrow holds the forwarded tokens, if the sequencer is their
+    /// source with respect to that channel.
+    pub(crate) escrowed_to: Option<ChannelId>,
+}
+
+/// Returns the forward metadata of the memo of the ICS20 packet `data`, or `None` if the packet
+/// is not to be forwarded.
+///
+/// Packets which cannot be decoded are not forwarded; the regular transfer reports their error.
+///
+/// # Errors
+/// Returns an error if the memo has a `forward` object which is invalid.
+pub(crate) fn forward_metadata(data: &[u8]) -> Result<Option<ForwardMetadata>> {
+    let Ok(packet_data) = serde_json::from_slice::<FungibleTokenPacketData>(data) else {
+        return Ok(None);
+    };
+    let Ok(serde_json::Value::Object(mut memo)) =
+        serde_json::from_str::<serde_json::Value>(&packet_data.memo)
+    else {
+        return Ok(None);
+    };
+    let Some(forward) = memo.remove("forward") else {
+        return Ok(None);
+    };
+    serde_json::from_value(forward)
+        .context("failed to parse forward metadata in packet memo")
+        .map(Some)
+}
+
+/// Receives the tokens of the incoming `packet` and sends them on as described by `metadata`.
+///
+/// No acknowledgement is written for `packet`; it is written by [`complete_forward`] once the
+/// forwarded packet is acknowledged or timed out.
+pub(crate) async fn execute_forward<S: StateWriteExt>(
+    state: &mut S,
+    packet: &Packet,
+    metadata: ForwardMetadata,
+) -> Result<()> {
+    ensure!(
+        metadata.port == PortId::transfer().as_str(),
+        "packets can only be forwarded over the `transfer` port",
+    );
+    ensure!(
+        !metadata.receiver.is_empty(),
+        "forward receiver must be set"
+    );
+    let forward_channel: ChannelId = metadata
+        .channel
+        .parse()
+        .context("invalid forward channel")?;
+    let timeout = metadata.timeout().context("invalid forward timeout")?;
+
+    let packet_data: FungibleTokenPacketData =
+        serde_json::from_slice(&packet.data).context("failed to decode FungibleTokenPacketData")?;
+    let amount: u128 = packet_data
+        .amount
+        .parse()
+        .context("failed to parse packet data amount to u128")?;
+    let denom = {
+        let denom = packet_data
+            .denom
+            .parse::<Denom>()
+            .context("failed parsing denom in packet data as Denom")?;
+        convert_denomination_if_ibc_prefixed(state, denom)
+            .await
+            .context("failed to convert denomination if ibc/ prefixed")?
+    };
+
+    // receive the tokens: release them from the escrow of the incoming channel if we are their
+    // source, or mint vouchers for them otherwise. neither is credited to an account.
+    let is_source = denom.starts_with_str(&format!("{}/{}", packet.port_on_a, packet.chan_on_a));
+    let (received, unescrowed_from) = if is_source {
+        let mut received = denom;
+        received.pop_trace_segment().context(
+            "there must be a source segment because above it was checked if the denom trace \
+             contains a segment",
+        )?;
+        (received, Some(packet.chan_on_b.clone()))
+    } else {
+        let received =
+            prepend_denom_if_not_refund(&denom, &packet.port_on_b, &packet.chan_on_b, false)
+                .into_owned();
+        (received, None)
+    };
+
+    let fee = forward_fee(
+        amount,
+        state
+            .get_ics20_forward_fee_basis_points()
+            .await
+            .context("failed to get ics20 forward fee")?,
+    );
+    let forward_amount = amount
+        .checked_sub(fee)
+        .context("forward fee exceeds transferred amount")?;
+    ensure!(
+        forward_amount > 0,
+        "transferred amount does not cover the forward fee"
+    );
+
+    // send the tokens on: escrow them in the outgoing channel if we are their source with
+    // respect to it, or burn them otherwise.
+    let escrowed_to = (!received
+        .starts_with_str(&format!("{}/{forward_channel}", PortId::transfer())))
+    .then(|| forward_channel.clone());
+
+    let unescrowed_balance = match &unescrowed_from {
+        Some(channel) => Some(
+            state
+                .get_ibc_channel_balance(channel, received.id())
+                .await
+                .context("failed to get IBC channel balance")?
+                .checked_sub(amount)
+                .context("insufficient balance in escrow account to forward tokens")?,
+        ),
+        None => None,
+    };
+    let escrowed_balance = match &escrowed_to {
+        Some(channel) => Some(
+            state
+                .get_ibc_channel_balance(channel, received.id())
+                .await
+                .context("failed to get IBC channel balance")?
+                .checked_add(forward_amount)
+                .context("overflow when adding to channel balance")?,
+        ),
+        None => None,
+    };
+
+    let forwarded_data = FungibleTokenPacketData {
+        denom: received.to_string(),
+        amount: forward_amount.to_string(),
+        sender: packet_data.receiver,
+        receiver: metadata.receiver.clone(),
+        memo: metadata.next_memo(),
+    };
+    let timeout_time = state
+        .get_block_timestamp()
+        .await
+        .context("failed to get block timestamp")?
+        .unix_timestamp_nanos()
+        .checked_add(
+            timeout
+                .as_nanos()
+                .try_into()
+                .context("forward timeout is too long")?,
+        )
+        .and_then(|nanos| u64::try_from(nanos).ok())
+        .context("forward timeout overflowed")?;
+    // forwarded packets only time out by time; the maximum height disables the height timeout.
+    let timeout_height =
+        Height::new(u64::MAX, u64::MAX).expect("the maximum height is a valid height");
+    let forwarded_packet = state
+        .send_packet_check(IBCPacket::new(
+            PortId::transfer(),
+            forward_channel.clone(),
+            timeout_height,
+            timeout_time,
+            serde_json::to_vec(&forwarded_data)
+                .context("failed to serialize forwarded packet data")?,
+        ))
+        .await
+        .context("forwarded packet failed send check")?;
+    let sequence = state
+        .get_send_sequence(&forward_channel, &PortId::transfer())
+        .await
+        .context("failed to get send sequence of forward channel")?;
+
+    if let (Some(channel), Some(balance)) = (&unescrowed_from, unescrowed_balance) {
+        state
+            .put_ibc_channel_balance(channel, received.id(), balance)
+            .context("failed to update escrow account balance")?;
+    } else if !state
+        .has_ibc_asset(received.id())
+        .await
+        .context("failed to check if ibc asset exists in state")?
+    {
+        state
+            .put_ibc_asset(received.id(), &received)
+            .context("failed to put IBC asset in storage")?;
+    }
+    if let (Some(channel), Some(balance)) = (&escrowed_to, escrowed_balance) {
+        state
+            .put_ibc_channel_balance(channel, received.id(), balance)
+            .context("failed to update escrow account balance")?;
+    }
+
+    state.send_packet_execute(forwarded_packet).await;
+    state
+        .put_pending_forward(
+            &forward_channel,
+            sequence,
+            &PendingForward {
+                packet: packet.clone(),
+                asset: received.id(),
+                amount,
+                fee,
+                unescrowed_from,
+                escrowed_to,
+            },
+        )
+        .context("failed to put pending forward")?;
+    Ok(())
+}
+
+/// Completes the forward of which `forwarded` is the outgoing packet, acknowledging the incoming
+/// packet.
+///
+/// `failure` is the reason the forwarded packet failed, or `None` if it was acknowledged
+/// successfully. On failure, the tokens are returned to where they were before the incoming
+/// packet was received.
+///
+/// Returns `false` if `forwarded` is not a forwarded packet.
+pub(crate) async fn complete_forward<S: StateWriteExt>(
+    state: &mut S,
+    forwarded: &Packet,
+    failure: Option<&str>,
+) -> Result<bool> {
+    let sequence = u64::from(forwarded.sequence);
+    let Some(forward) = state
+        .get_pending_forward(&forwarded.chan_on_a, sequence)
+        .await
+        .context("failed to get pending forward")?
+    else {
+        return Ok(false);
+    };
+    state.delete_pending_forward(&forwarded.chan_on_a, sequence);
+
+    let ack = if let Some(failure) = failure {
+        revert_forward(state, &forward)
+            .await
+            .context("failed to revert forward")?;
+        TokenTransferAcknowledgement::Error(format!("failed to forward packet: {failure}"))
+    } else {
+        if forward.fee > 0 {
+            state
+                .get_and_increase_block_fees(forward.asset, forward.fee)
+                .await
+                .context("failed to add forward fee to block fees")?;
+        }
+        TokenTransferAcknowledgement::success()
+    };
+
+    let ack_bytes: Vec<u8> = ack.into();
+    state
+        .write_acknowledgement(&forward.packet, &ack_bytes)
+        .await
+        .context("failed to write acknowledgement of forwarded packet")?;
+    Ok(true)
+}
+
+async fn revert_forward<S: StateWriteExt>(state: &mut S, forward: &PendingForward) -> Result<()> {
+    if let Some(channel) = &forward.escrowed_to {
+        let forward_amount = forward
+            .amount
+            .checked_sub(forward.fee)
+            .context("forward fee exceeds transferred amount")?;
+        let balance = state
+            .get_ibc_channel_balance(channel, forward.asset)
+            .await
+            .context("failed to get IBC channel balance")?
+            .checked_sub(forward_amount)
+            .context("insufficient balance in escrow account to revert forward")?;
+        state
+            .put_ibc_channel_balance(channel, forward.asset, balance)
+            .context("failed to update escrow account balance")?;
+    }
+    if let Some(channel) = &forward.unescrowed_from {
+        let balance = state
+            .get_ibc_channel_balance(channel, forward.asset)
+            .await
+            .context("failed to get IBC channel balance")?
+            .checked_add(forward.amount)
+            .context("overflow when adding to channel balance")?;
+        state
+            .put_ibc_channel_balance(channel, forward.asset, balance)
+            .context("failed to update escrow account balance")?;
+    }
+    Ok(())
+}
+
+/// Returns `basis_points` of `amount`, rounded down.
+fn forward_fee(amount: u128, basis_points: u16) -> u128 {
+    let basis_points = u128::from(basis_points);
+    // split `amount` so that neither product can overflow for fees of up to 100%.
+    (amount / BASIS_POINTS_PER_UNIT) * basis_points
+        + (amount % BASIS_POINTS_PER_UNIT) * basis_points / BASIS_POINTS_PER_UNIT
+}
+
+/// Parses a non-negative Go duration string like `"10m"` or `"1h30m15s"`.
+fn parse_go_duration(duration: &str) -> Result<Duration> {
+    const UNITS: [(&str, u64); 8] = [
+        ("ns", 1),
+        ("us", 1_000),
+        // go accepts both the micro sign and the greek letter mu
+        ("µs", 1_000),
+        ("μs", 1_000),
+        ("ms", 1_000_000),
+        ("s", 1_000_000_000),
+        ("m", 60 * 1_000_000_000),
+        ("h", 60 * 60 * 1_000_000_000),
+    ];
+
+    ensure!(!duration.is_empty(), "duration is empty");
+    let mut rest = duration;
+    let mut nanos: u64 = 0;
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        ensure!(digits > 0, "expected a number in duration `{duration}`");
+        let value: u64 = rest[..digits]
+            .parse()
+            .with_context(|| format!("number in duration `{duration}` is too large"))?;
+        rest = &rest[digits..];
+
+        let unit_len = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let unit = &rest[..unit_len];
+        rest = &rest[unit_len..];
+        let Some((_, unit_nanos)) = UNITS.iter().find(|(name, _)| *name == unit) else {
+            bail!("unknown unit `{unit}` in duration `{duration}`");
+        };
+        nanos = value
+            .checked_mul(*unit_nanos)
+            .and_then(|value| nanos.checked_add(value))
+            .with_context(|| format!("duration `{duration}` is too long"))?;
+    }
+    Ok(Duration::from_nanos(nanos))
+}
+
+#[cfg(test)]
+mod test {
+    use cnidarium::StateDelta;
+    use ibc_proto::ibc::core::{
+        channel::v1::Packet as RawPacket,
+        client::v1::Height as RawHeight,
+    };
+
+    use super::*;
+
+    fn packet_data(memo: &str) -> Vec<u8> {
+        serde_json::to_vec(&FungibleTokenPacketData {
+            denom: "nootasset".to_string(),
+            amount: "100".to_string(),
+            sender: "sender".to_string(),
+            receiver: "pfm".to_string(),
+            memo: memo.to_string(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn forward_metadata_is_parsed_from_memo() {
+        assert!(forward_metadata(&packet_data("")).unwrap().is_none());
+        assert!(
+            forward_metadata(&packet_data("not json"))
+                .unwrap()
+                .is_none()
+        );
+        assert!(
+            forward_metadata(&packet_data(r#"{"rollupAddress":"rollupaddress"}"#))
+                .unwrap()
+                .is_none()
+        );
+        assert!(forward_metadata(&packet_data(r#"{"forward":{"receiver":"cosmos1"}}"#)).is_err());
+
+        let metadata = forward_metadata(&packet_data(
+            r#"{"forward":{"receiver":"cosmos1","port":"transfer","channel":"channel-1","timeout":"1m30s","retries":2,"next":{"forward":{"receiver":"osmo1","port":"transfer","channel":"channel-2"}}}}"#,
+        ))
+        .unwrap()
+        .unwrap();
+        assert_eq!("cosmos1", metadata.receiver);
+        assert_eq!("channel-1", metadata.channel);
+        assert_eq!(Duration::from_secs(90), metadata.timeout().unwrap());
+        assert_eq!(
+            r#"{"forward":{"channel":"channel-2","port":"transfer","receiver":"osmo1"}}"#,
+            metadata.next_memo()
+        );
+    }
+
+    #[test]
+    fn forward_timeout_defaults_and_accepts_nanos() {
+        let metadata: ForwardMetadata = serde_json::from_str(
+            r#"{"receiver":"cosmos1","port":"transfer","channel":"channel-1"}"#,
+        )
+        .unwrap();
+        assert_eq!(DEFAULT_FORWARD_TIMEOUT, metadata.timeout().unwrap());
+        assert_eq!("", metadata.next_memo());
+
+        let metadata: ForwardMetadata = serde_json::from_str(
+            r#"{"receiver":"cosmos1","port":"transfer","channel":"channel-1","timeout":5000,"next":"memo"}"#,
+        )
+        .unwrap();
+        assert_eq!(Duration::from_nanos(5000), metadata.timeout().unwrap());
+        assert_eq!("memo", metadata.next_memo());
+    }
+
+    #[test]
+    fn go_durations_are_parsed() {
+        assert_eq!(Duration::from_secs(600), parse_go_duration("10m").unwrap());
+        assert_eq!(
+            Duration::from_secs(3600 + 1800 + 15),
+            parse_go_duration("1h30m15s").unwrap()
+        );
+        assert_eq!(
+            Duration::from_micros(1500),
+            parse_go_duration("1ms500us").unwrap()
+        );
+        assert!(parse_go_duration("").is_err());
+        assert!(parse_go_duration("10").is_err());
+        assert!(parse_go_duration("m").is_err());
+        assert!(parse_go_duration("10d").is_err());
+        assert!(parse_go_duration("99999999999999999999h").is_err());
+    }
+
+    #[test]
+    fn forward_fee_is_rounded_down() {
+        assert_eq!(0, forward_fee(1_000, 0));
+        assert_eq!(1, forward_fee(1_000, 10));
+        assert_eq!(0, forward_fee(999, 10));
+        assert_eq!(1_000, forward_fee(1_000, 10_000));
+        assert_eq!(u128::MAX, forward_fee(u128::MAX, 10_000));
+        assert_eq!(u128::MAX / 2, forward_fee(u128::MAX, 5_000));
+    }
+
+    #[tokio::test]
+    async fn failed_forward_is_reverted() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let asset = asset::Id::new([1; 32]);
+        let incoming_channel = ChannelId::new(0);
+        let outgoing_channel = ChannelId::new(1);
+        state
+            .put_ibc_channel_balance(&incoming_channel, asset, 0)
+            .unwrap();
+        state
+            .put_ibc_channel_balance(&outgoing_channel, asset, 90)
+            .unwrap();
+
+        let forward = PendingForward {
+            packet: Packet::try_from(RawPacket {
+                sequence: 1,
+                source_port: "transfer".to_string(),
+                source_channel: "channel-5".to_string(),
+                destination_port: "transfer".to_string(),
+                destination_channel: incoming_channel.to_string(),
+                data: packet_data(""),
+                timeout_height: Some(RawHeight {
+                    revision_number: 1,
+                    revision_height: 100,
+                }),
+                timeout_timestamp: 0,
+            })
+            .unwrap(),
+            asset,
+            amount: 100,
+            fee: 10,
+            unescrowed_from: Some(incoming_channel.clone()),
+            escrowed_to: Some(outgoing_channel.clone()),
+        };
+        state
+            .put_pending_forward(&outgoing_channel, 3, &forward)
+            .unwrap();
+        assert_eq!(
+            Some(&forward),
+            state
+                .get_pending_forward(&outgoing_channel, 3)
+                .await
+                .unwrap()
+                .as_ref()
+        );
+
+        revert_forward(&mut state, &forward).await.unwrap();
+        assert_eq!(
+            100,
+            state
+                .get_ibc_channel_balance(&incoming_channel, asset)
+                .await
+                .unwrap()
+        );
+        assert_eq!(
+            0,
+            state
+                .get_ibc_channel_balance(&outgoing_channel, asset)
+                .await
+                .unwrap()
+        );
+    }
+}
@@ -0,0 +1,211 @@
+//! Routing of IBC channel and packet messages to the application bound to their port.
+//!
+//! The Penumbra IBC implementation is generic over a single [`AppHandler`]. The [`AppRouter`]
+//! is that handler: it dispatches messages for the [`ica_host::PORT`] to the [`IcaHost`], and
+//! all other messages to the [`Ics20Transfer`] handler.
+
+use anyhow::Result;
+use cnidarium::{
+    StateRead,
+    StateWrite,
+};
+use ibc_types::core::channel::{
+    msgs::{
+        MsgAcknowledgement,
+        MsgChannelCloseConfirm,
+        MsgChannelCloseInit,
+        MsgChannelOpenAck,
+        MsgChannelOpenConfirm,
+        MsgChannelOpenInit,
+        MsgChannelOpenTry,
+        MsgRecvPacket,
+        MsgTimeout,
+    },
+    PortId,
+};
+use penumbra_ibc::component::app_handler::{
+    AppHandler,
+    AppHandlerCheck,
+    AppHandlerExecute,
+};
+
+use crate::ibc::{
+    ica_host::{
+        self,
+        IcaHost,
+    },
+    ics20_transfer::Ics20Transfer,
+};
+
+fn is_ica_host(port: &PortId) -> bool {
+    port.as_str() == ica_host::PORT
+}
+
+/// The IBC application handler dispatching to the application bound to a message's port.
+#[derive(Clone)]
+pub(crate) struct AppRouter;
+
+#[async_trait::async_trait]
+impl AppHandlerCheck for AppRouter {
+    async fn chan_open_init_check<S: StateRead>(state: S, msg: &MsgChannelOpenInit) -> Result<()> {
+        if is_ica_host(&msg.port_id_on_a) {
+            IcaHost::chan_open_init_check(state, msg).await
+        } else {
+            Ics20Transfer::chan_open_init_check(state, msg).await
+        }
+    }
+
+    async fn chan_open_try_check<S: StateRead>(state: S, msg: &MsgChannelOpenTry) -> Result<()> {
+        if is_ica_host(&msg.port_id_on_b) {
+            IcaHost::chan_open_try_check(state, msg).await
+        } else {
+            Ics20Transfer::chan_open_try_check(state, msg).await
+        }
+    }
+
+    async fn chan_open_ack_check<S: StateRead>(state: S, msg: &MsgChannelOpenAck) -> Result<()> {
+        if is_ica_host(&msg.port_id_on_a) {
+            IcaHost::chan_open_ack_check(state, msg).await
+        } else {
+            Ics20Transfer::chan_open_ack_check(state, msg).await
+        }
+    }
+
+    async fn chan_open_confirm_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelOpenConfirm,
+    ) -> Result<()> {
+        if is_ica_host(&msg.port_id_on_b) {
+            IcaHost::chan_open_confirm_check(state, msg).await
+        } else {
+            Ics20Transfer::chan_open_confirm_check(state, msg).await
+        }
+    }
+
+    async fn chan_close_init_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelCloseInit,
+    ) -> Result<()> {
+        if is_ica_host(&msg.port_id_on_a) {
+            IcaHost::chan_close_init_check(state, msg).await
+        } else {
+            Ics20Transfer::chan_close_init_check(state, msg).await
+        }
+    }
+
+    async fn chan_close_confirm_check<S: StateRead>(
+        state: S,
+        msg: &MsgChannelCloseConfirm,
+    ) -> Result<()> {
+        if is_ica_host(&msg.port_id_on_b) {
+            IcaHost::chan_close_confirm_check(state, msg).await
+        } else {
+            Ics20Transfer::chan_close_confirm_check(state, msg).await
+        }
+    }
+
+    async fn recv_packet_check<S: StateRead>(state: S, msg: &MsgRecvPacket) -> Result<()> {
+        if is_ica_host(&msg.packet.port_on_b) {
+            IcaHost::recv_packet_check(state, msg).await
+        } else {
+            Ics20Transfer::recv_packet_check(state, msg).await
+        }
+    }
+
+    async fn timeout_packet_check<S: StateRead>(state: S, msg: &MsgTimeout) -> Result<()> {
+        if is_ica_host(&msg.packet.port_on_a) {
+            IcaHost::timeout_packet_check(state, msg).await
+        } else {
+            Ics20Transfer::timeout_packet_check(state, msg).await
+        }
+    }
+
+    async fn acknowledge_packet_check<S: StateRead>(
+        state: S,
+        msg: &MsgAcknowledgement,
+    ) -> Result<()> {
+        if is_ica_host(&msg.packet.port_on_a) {
+            IcaHost::acknowledge_packet_check(state, msg).await
+        } else {
+            Ics20Transfer::acknowledge_packet_check(state, msg).await
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AppHandlerExecute for AppRouter {
+    async fn chan_open_init_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenInit) {
+        if is_ica_host(&msg.port_id_on_a) {
+            IcaHost::chan_open_init_execute(state, msg).await;
+        } else {
+            Ics20Transfer::chan_open_init_execute(state, msg).await;
+        }
+    }
+
+    async fn chan_open_try_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenTry) {
+        if is_ica_host(&msg.port_id_on_b) {
+            IcaHost::chan_open_try_execute(state, msg).await;
+        } else {
+            Ics20Transfer::chan_open_try_execute(state, msg).await;
+        }
+    }
+
+    async fn chan_open_ack_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenAck) {
+        if is_ica_host(&msg.port_id_on_a) {
+            IcaHost::chan_open_ack_execute(state, msg).await;
+        } else {
+            Ics20Transfer::chan_open_ack_execute(state, msg).await;
+        }
+    }
+
+    async fn chan_open_confirm_execute<S: StateWrite>(state: S, msg: &MsgChannelOpenConfirm) {
+        if is_ica_host(&msg.port_id_on_b) {
+            IcaHost::chan_open_confirm_execute(state, msg).await;
+        } else {
+            Ics20Transfer::chan_open_confirm_execute(state, msg).await;
+        }
+    }
+
+    async fn chan_close_confirm_execute<S: StateWrite>(state: S, msg: &MsgChannelCloseConfirm) {
+        if is_ica_host(&msg.port_id_on_b) {
+            IcaHost::chan_close_confirm_execute(state, msg).await;
+        } else {
+            Ics20Transfer::chan_close_confirm_execute(state, msg).await;
+        }
+    }
+
+    async fn chan_close_init_execute<S: StateWrite>(state: S, msg: &MsgChannelCloseInit) {
+        if is_ica_host(&msg.port_id_on_a) {
+            IcaHost::chan_close_init_execute(state, msg).await;
+        } else {
+            Ics20Transfer::chan_close_init_execute(state, msg).await;
+        }
+    }
+
+    async fn recv_packet_execute<S: StateWrite>(state: S, msg: &MsgRecvPacket) -> Result<()> {
+        if is_ica_host(&msg.packet.port_on_b) {
+            IcaHost::recv_packet_execute(state, msg).await
+        } else {
+            Ics20Transfer::recv_packet_execute(state, msg).await
+        }
+    }
+
+    async fn timeout_packet_execute<S: StateWrite>(state: S, msg: &MsgTimeout) -> Result<()> {
+        if is_ica_host(&msg.packet.port_on_a) {
+            IcaHost::timeout_packet_execute(state, msg).await
+        } else {
+            Ics20Transfer::timeout_packet_execute(state, msg).await
+        }
+    }
+
+    async fn acknowledge_packet_execute<S: StateWrite>(state: S, msg: &MsgAcknowledgement) {
+        if is_ica_host(&msg.packet.port_on_a) {
+            IcaHost::acknowledge_packet_execute(state, msg).await;
+        } else {
+            Ics20Transfer::acknowledge_packet_execute(state, msg).await;
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AppHandler for AppRouter {}
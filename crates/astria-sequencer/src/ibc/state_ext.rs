@@ -1,4 +1,5 @@
 use anyhow::{
+    anyhow,
     bail,
     Context,
     Result,
@@ -18,12 +19,22 @@ use cnidarium::{
     StateWrite,
 };
 use hex::ToHex as _;
-use ibc_types::core::channel::ChannelId;
+use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
+use ibc_types::core::channel::{
+    ChannelId,
+    Packet,
+};
+use prost::Message as _;
 use tracing::{
     debug,
     instrument,
 };
 
+use crate::ibc::{
+    ica_host::HostParams,
+    packet_forward::PendingForward,
+};
+
 /// Newtype wrapper to read and write a u128 from rocksdb.
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 struct Balance(u128);
@@ -36,8 +47,95 @@ struct SudoAddress([u8; ADDRESS_LEN]);
 #[derive(BorshSerialize, BorshDeserialize, Debug)]
 struct Fee(u128);
 
+/// Newtype wrapper to read and write a fee in basis points from rocksdb.
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct BasisPoints(u16);
+
+/// Storage representation of a [`PendingForward`].
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct StoredPendingForward {
+    /// The protobuf encoded incoming packet.
+    packet: Vec<u8>,
+    asset: [u8; 32],
+    amount: u128,
+    fee: u128,
+    unescrowed_from: Option<String>,
+    escrowed_to: Option<String>,
+}
+
+impl From<&PendingForward> for StoredPendingForward {
+    fn from(forward: &PendingForward) -> Self {
+        Self {
+            packet: RawPacket::from(forward.packet.clone()).encode_to_vec(),
+            asset: forward.asset.get(),
+            amount: forward.amount,
+            fee: forward.fee,
+            unescrowed_from: forward.unescrowed_from.as_ref().map(ToString::to_string),
+            escrowed_to: forward.escrowed_to.as_ref().map(ToString::to_string),
+        }
+    }
+}
+
+impl TryFrom<StoredPendingForward> for PendingForward {
+    type Error = anyhow::Error;
+
+    fn try_from(stored: StoredPendingForward) -> Result<Self> {
+        let raw_packet =
+            RawPacket::decode(stored.packet.as_slice()).context("invalid raw packet bytes")?;
+        let packet =
+            Packet::try_from(raw_packet).map_err(|e| anyhow!("invalid forwarded packet: {e}"))?;
+        let parse_channel = |channel: Option<String>| {
+            channel
+                .map(|channel| channel.parse::<ChannelId>())
+                .transpose()
+                .map_err(|e| anyhow!("invalid channel ID: {e}"))
+        };
+        Ok(Self {
+            packet,
+            asset: asset::Id::new(stored.asset),
+            amount: stored.amount,
+            fee: stored.fee,
+            unescrowed_from: parse_channel(stored.unescrowed_from)?,
+            escrowed_to: parse_channel(stored.escrowed_to)?,
+        })
+    }
+}
+
+/// Storage representation of the packets of the interchain account transactions waiting to be
+/// executed, each protobuf encoded.
+#[derive(BorshSerialize, BorshDeserialize, Debug, Default)]
+struct StoredPendingInterchainAccountPackets(Vec<Vec<u8>>);
+
+/// Storage representation of the interchain accounts [`HostParams`].
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct StoredHostParams {
+    enabled: bool,
+    allowed_messages: Vec<String>,
+}
+
+impl From<&HostParams> for StoredHostParams {
+    fn from(params: &HostParams) -> Self {
+        Self {
+            enabled: params.enabled,
+            allowed_messages: params.allowed_messages.clone(),
+        }
+    }
+}
+
+impl From<StoredHostParams> for HostParams {
+    fn from(stored: StoredHostParams) -> Self {
+        Self {
+            enabled: stored.enabled,
+            allowed_messages: stored.allowed_messages,
+        }
+    }
+}
+
 const IBC_SUDO_STORAGE_KEY: &str = "ibcsudo";
 const ICS20_WITHDRAWAL_BASE_FEE_STORAGE_KEY: &str = "ics20withdrawalfee";
+const ICS20_FORWARD_FEE_STORAGE_KEY: &str = "ics20forwardfee";
+const ICA_HOST_PARAMS_STORAGE_KEY: &str = "icahostparams";
+const PENDING_ICA_PACKETS_STORAGE_KEY: &str = "icahostpendingpackets";
 
 struct IbcRelayerKey<'a>(&'a Address);
 
@@ -63,6 +161,10 @@ fn ibc_relayer_key(address: &Address) -> String {
     IbcRelayerKey(address).to_string()
 }
 
+fn pending_forward_key(channel: &ChannelId, sequence: u64) -> String {
+    format!("ibc-data/{channel}/forward/{sequence}")
+}
+
 #[async_trait]
 pub(crate) trait StateReadExt: StateRead {
     #[instrument(skip(self))]
@@ -115,6 +217,81 @@ pub(crate) trait StateReadExt: StateRead {
         let Fee(fee) = Fee::try_from_slice(&bytes).context("invalid fee bytes")?;
         Ok(fee)
     }
+
+    /// Returns the fee taken from forwarded ICS20 transfers in basis points, or 0 if none is set.
+    #[instrument(skip(self))]
+    async fn get_ics20_forward_fee_basis_points(&self) -> Result<u16> {
+        let Some(bytes) = self
+            .get_raw(ICS20_FORWARD_FEE_STORAGE_KEY)
+            .await
+            .context("failed reading ics20 forward fee from state")?
+        else {
+            return Ok(0);
+        };
+        let BasisPoints(basis_points) =
+            BasisPoints::try_from_slice(&bytes).context("invalid forward fee bytes")?;
+        Ok(basis_points)
+    }
+
+    /// Returns the forward waiting for the packet sent over `channel` with `sequence` to be
+    /// acknowledged, if any.
+    #[instrument(skip(self))]
+    async fn get_pending_forward(
+        &self,
+        channel: &ChannelId,
+        sequence: u64,
+    ) -> Result<Option<PendingForward>> {
+        let Some(bytes) = self
+            .get_raw(&pending_forward_key(channel, sequence))
+            .await
+            .context("failed reading pending forward from state")?
+        else {
+            return Ok(None);
+        };
+        let stored = StoredPendingForward::try_from_slice(&bytes)
+            .context("invalid pending forward bytes")?;
+        PendingForward::try_from(stored).map(Some)
+    }
+
+    /// Returns the interchain accounts host params, or `None` if they were never set.
+    #[instrument(skip(self))]
+    async fn get_ica_host_params(&self) -> Result<Option<HostParams>> {
+        let Some(bytes) = self
+            .get_raw(ICA_HOST_PARAMS_STORAGE_KEY)
+            .await
+            .context("failed reading interchain accounts host params from state")?
+        else {
+            return Ok(None);
+        };
+        let stored = StoredHostParams::try_from_slice(&bytes)
+            .context("invalid interchain accounts host params bytes")?;
+        Ok(Some(stored.into()))
+    }
+
+    /// Returns the packets of the interchain account transactions waiting to be executed, in the
+    /// order they were received.
+    #[instrument(skip_all)]
+    async fn get_pending_interchain_account_packets(&self) -> Result<Vec<Packet>> {
+        let Some(bytes) = self
+            .get_raw(PENDING_ICA_PACKETS_STORAGE_KEY)
+            .await
+            .context("failed reading pending interchain account packets from state")?
+        else {
+            return Ok(vec![]);
+        };
+        let StoredPendingInterchainAccountPackets(packets) =
+            StoredPendingInterchainAccountPackets::try_from_slice(&bytes)
+                .context("invalid pending interchain account packets bytes")?;
+        packets
+            .into_iter()
+            .map(|bytes| {
+                let raw_packet =
+                    RawPacket::decode(bytes.as_slice()).context("invalid raw packet bytes")?;
+                Packet::try_from(raw_packet)
+                    .map_err(|e| anyhow!("invalid interchain account packet: {e}"))
+            })
+            .collect()
+    }
 }
 
 impl<T: StateRead> StateReadExt for T {}
@@ -161,6 +338,82 @@ pub(crate) trait StateWriteExt: StateWrite {
         );
         Ok(())
     }
+
+    #[instrument(skip(self))]
+    fn put_ics20_forward_fee_basis_points(&mut self, basis_points: u16) -> Result<()> {
+        self.put_raw(
+            ICS20_FORWARD_FEE_STORAGE_KEY.to_string(),
+            borsh::to_vec(&BasisPoints(basis_points)).context("failed to serialize forward fee")?,
+        );
+        Ok(())
+    }
+
+    #[instrument(skip(self, forward))]
+    fn put_pending_forward(
+        &mut self,
+        channel: &ChannelId,
+        sequence: u64,
+        forward: &PendingForward,
+    ) -> Result<()> {
+        self.put_raw(
+            pending_forward_key(channel, sequence),
+            borsh::to_vec(&StoredPendingForward::from(forward))
+                .context("failed to serialize pending forward")?,
+        );
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    fn delete_pending_forward(&mut self, channel: &ChannelId, sequence: u64) {
+        self.delete(pending_forward_key(channel, sequence));
+    }
+
+    #[instrument(skip(self))]
+    fn put_ica_host_params(&mut self, params: &HostParams) -> Result<()> {
+        self.put_raw(
+            ICA_HOST_PARAMS_STORAGE_KEY.to_string(),
+            borsh::to_vec(&StoredHostParams::from(params))
+                .context("failed to serialize interchain accounts host params")?,
+        );
+        Ok(())
+    }
+
+    /// Queues the interchain account transaction received in `packet` for execution at the start
+    /// of the next block.
+    #[instrument(skip_all)]
+    async fn put_pending_interchain_account_packet(&mut self, packet: &Packet) -> Result<()> {
+        let mut packets = match self
+            .get_raw(PENDING_ICA_PACKETS_STORAGE_KEY)
+            .await
+            .context("failed reading pending interchain account packets from state")?
+        {
+            Some(bytes) => StoredPendingInterchainAccountPackets::try_from_slice(&bytes)
+                .context("invalid pending interchain account packets bytes")?,
+            None => StoredPendingInterchainAccountPackets::default(),
+        };
+        packets
+            .0
+            .push(RawPacket::from(packet.clone()).encode_to_vec());
+        self.put_raw(
+            PENDING_ICA_PACKETS_STORAGE_KEY.to_string(),
+            borsh::to_vec(&packets)
+                .context("failed to serialize pending interchain account packets")?,
+        );
+        Ok(())
+    }
+
+    /// Removes and returns the packets of the interchain account transactions waiting to be
+    /// executed, in the order they were received.
+    #[instrument(skip_all)]
+    async fn take_pending_interchain_account_packets(&mut self) -> Result<Vec<Packet>> {
+        let packets = self.get_pending_interchain_account_packets().await?;
+        // only delete the key if it was written, so that blocks without interchain account
+        // transactions leave the state untouched
+        if !packets.is_empty() {
+            self.delete(PENDING_ICA_PACKETS_STORAGE_KEY.to_string());
+        }
+        Ok(packets)
+    }
 }
 
 impl<T: StateWrite> StateWriteExt for T {}
@@ -440,6 +693,60 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn pending_interchain_account_packets_are_taken_in_order() {
+        use ibc_proto::ibc::core::channel::v1::Packet as RawPacket;
+        use ibc_types::core::channel::Packet;
+
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let packet = |sequence| {
+            Packet::try_from(RawPacket {
+                sequence,
+                source_port: "icacontroller-cosmos1owner".to_string(),
+                source_channel: "channel-3".to_string(),
+                destination_port: "icahost".to_string(),
+                destination_channel: "channel-0".to_string(),
+                data: vec![1, 2, 3],
+                timeout_height: None,
+                timeout_timestamp: 1,
+            })
+            .unwrap()
+        };
+
+        assert!(
+            state
+                .take_pending_interchain_account_packets()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        state
+            .put_pending_interchain_account_packet(&packet(1))
+            .await
+            .unwrap();
+        state
+            .put_pending_interchain_account_packet(&packet(2))
+            .await
+            .unwrap();
+        assert_eq!(
+            state
+                .take_pending_interchain_account_packets()
+                .await
+                .unwrap(),
+            vec![packet(1), packet(2)]
+        );
+        assert!(
+            state
+                .get_pending_interchain_account_packets()
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
     #[test]
     fn storage_keys_have_not_changed() {
         let address: Address = "astria1rsxyjrcm255ds9euthjx6yc3vrjt9sxrm9cfgm"
@@ -0,0 +1,512 @@
+//! Offline verification and repair of the nonverifiable indexes.
+//!
+//! The nonverifiable state holds indexes which are derived from the verifiable state or from
+//! executed transactions, and which do not contribute to the app hash. Since nothing checks them
+//! during consensus, a bug or an interrupted write can leave them out of sync with the chain
+//! without anyone noticing. This module rebuilds them and reports any drift found:
+//!
+//! - the index of bridge accounts by rollup ID is rebuilt from the bridge accounts in the
+//!   verifiable state;
+//! - the last transaction hash of every bridge account is rebuilt by replaying the blocks in the
+//!   transaction archive. This is only done if the archive holds every block from height 1 up to
+//!   the latest committed height, and is skipped with a warning otherwise.
+//!
+//! This must be run while the sequencer is stopped. Repairs are written in place to the latest
+//! version of the db, so the app hash and block height are unchanged.
+
+use std::{
+    collections::{
+        HashMap,
+        HashSet,
+    },
+    fmt,
+    io::BufRead,
+};
+
+use anyhow::{
+    bail,
+    Context as _,
+    Result,
+};
+use astria_core::{
+    generated::protocol::transaction::v1alpha1 as raw,
+    primitive::v1::Address,
+    protocol::transaction::v1alpha1::{
+        Action,
+        SignedTransaction,
+    },
+};
+use cnidarium::{
+    StateDelta,
+    StateWrite,
+};
+use prost::Message as _;
+use tracing::{
+    info,
+    warn,
+};
+
+use crate::{
+    address::StateReadExt as _,
+    bridge::state_ext::{
+        StateReadExt as _,
+        StateWriteExt as _,
+    },
+    config::Config,
+    state_ext::StateReadExt as _,
+    tx_archive::{
+        ArchivedBlock,
+        Sink,
+    },
+};
+
+/// Whether drift found in the indexes is repaired or only reported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Mode {
+    Repair,
+    CheckOnly,
+}
+
+/// The drift found in a single index.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IndexDrift {
+    /// The number of entries which were absent from the index.
+    pub missing: usize,
+    /// The number of entries which held a different value than the rebuilt one.
+    pub mismatched: usize,
+    /// The number of entries which should not have been in the index.
+    pub stale: usize,
+}
+
+impl IndexDrift {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.missing == 0 && self.mismatched == 0 && self.stale == 0
+    }
+}
+
+impl fmt::Display for IndexDrift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} missing, {} mismatched, {} stale",
+            self.missing, self.mismatched, self.stale
+        )
+    }
+}
+
+/// The drift found in the nonverifiable indexes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Report {
+    pub rollup_bridge_accounts: IndexDrift,
+    /// `None` if the transaction archive could not be replayed.
+    pub bridge_account_last_transactions: Option<IndexDrift>,
+}
+
+impl Report {
+    /// Returns true if any of the checked indexes had drifted.
+    #[must_use]
+    pub fn has_drift(&self) -> bool {
+        !self.rollup_bridge_accounts.is_empty()
+            || self
+                .bridge_account_last_transactions
+                .is_some_and(|drift| !drift.is_empty())
+    }
+}
+
+/// Rebuilds the nonverifiable indexes of the db at `config.db_filepath`, replaying the blocks in
+/// the archive at `config.tx_archive_sink` if one is configured.
+///
+/// # Errors
+/// Returns an error if the db cannot be read or written, or if the transaction archive cannot be
+/// read or contains invalid blocks.
+pub async fn run(config: &Config, mode: Mode) -> Result<Report> {
+    let storage = cnidarium::Storage::load(
+        config.db_filepath.clone(),
+        vec![penumbra_ibc::IBC_SUBSTORE_PREFIX.to_string()],
+    )
+    .await
+    .context("failed to load storage backing chain state")?;
+    if storage.latest_version() == u64::MAX {
+        bail!("storage db has no committed state; there are no indexes to rebuild");
+    }
+
+    let snapshot = storage.latest_snapshot();
+    let base_prefix = snapshot
+        .get_base_prefix()
+        .await
+        .context("failed to get address base prefix from storage")?;
+    crate::address::initialize_base_prefix(&base_prefix)
+        .context("failed to initialize global address base prefix")?;
+    let block_height = snapshot
+        .get_block_height()
+        .await
+        .context("failed to get block height from storage")?;
+
+    let mut state = StateDelta::new(snapshot);
+    let rollup_bridge_accounts = rebuild_rollup_bridge_account_index(&mut state)
+        .await
+        .context("failed to rebuild rollup bridge account index")?;
+    info!(%rollup_bridge_accounts, "checked rollup bridge account index");
+
+    let bridge_account_last_transactions = match replay_tx_archive(config, block_height)? {
+        Some(last_tx_hashes) => {
+            let drift = rebuild_last_transaction_hashes(&mut state, &last_tx_hashes)
+                .await
+                .context("failed to rebuild bridge account last transaction hashes")?;
+            info!(%drift, "checked bridge account last transaction hashes");
+            Some(drift)
+        }
+        None => None,
+    };
+
+    let report = Report {
+        rollup_bridge_accounts,
+        bridge_account_last_transactions,
+    };
+    if mode == Mode::Repair && report.has_drift() {
+        storage
+            .commit_in_place(state)
+            .await
+            .context("failed to write repaired indexes to storage")?;
+        info!("repaired nonverifiable indexes");
+    }
+    storage.release().await;
+    Ok(report)
+}
+
+/// Rebuilds the index of bridge accounts by rollup ID from the verifiable state.
+async fn rebuild_rollup_bridge_account_index<S: StateWrite>(state: &mut S) -> Result<IndexDrift> {
+    let expected: HashSet<_> = state
+        .get_bridge_accounts()
+        .await
+        .context("failed to get bridge accounts")?
+        .into_iter()
+        .map(|(address, rollup_id)| (rollup_id, address))
+        .collect();
+    let actual: HashSet<_> = state
+        .get_rollup_bridge_account_index()
+        .await
+        .context("failed to get rollup bridge account index")?
+        .into_iter()
+        .collect();
+
+    let mut drift = IndexDrift::default();
+    for (rollup_id, address) in expected.difference(&actual) {
+        warn!(%rollup_id, %address, "bridge account missing from rollup index");
+        state.put_rollup_bridge_account_index(rollup_id, address);
+        drift.missing += 1;
+    }
+    for (rollup_id, address) in actual.difference(&expected) {
+        warn!(%rollup_id, %address, "stale bridge account in rollup index");
+        state.delete_rollup_bridge_account_index(rollup_id, address);
+        drift.stale += 1;
+    }
+    Ok(drift)
+}
+
+/// Rebuilds the last transaction hash of every bridge account from the replayed
+/// `last_tx_hashes`.
+async fn rebuild_last_transaction_hashes<S: StateWrite>(
+    state: &mut S,
+    last_tx_hashes: &HashMap<Address, [u8; 32]>,
+) -> Result<IndexDrift> {
+    let actual: HashMap<_, _> = state
+        .get_last_transaction_hashes_for_bridge_accounts()
+        .await
+        .context("failed to get bridge account last transaction hashes")?
+        .into_iter()
+        .collect();
+
+    let mut drift = IndexDrift::default();
+    for (address, tx_hash) in last_tx_hashes {
+        match actual.get(address) {
+            Some(actual_tx_hash) if actual_tx_hash == tx_hash => continue,
+            Some(_) => {
+                warn!(%address, "mismatched bridge account last transaction hash");
+                drift.mismatched += 1;
+            }
+            None => {
+                warn!(%address, "missing bridge account last transaction hash");
+                drift.missing += 1;
+            }
+        }
+        state.put_last_transaction_hash_for_bridge_account(address, tx_hash);
+    }
+    for address in actual.keys() {
+        if !last_tx_hashes.contains_key(address) {
+            warn!(%address, "stale bridge account last transaction hash");
+            state.delete_last_transaction_hash_for_bridge_account(address);
+            drift.stale += 1;
+        }
+    }
+    Ok(drift)
+}
+
+/// Replays the configured transaction archive up to `block_height`.
+///
+/// Returns `None` if no archive is configured or it does not hold every block.
+fn replay_tx_archive(
+    config: &Config,
+    block_height: u64,
+) -> Result<Option<HashMap<Address, [u8; 32]>>> {
+    if config.tx_archive_sink.is_empty() {
+        warn!("no transaction archive configured; skipping bridge account last transaction hashes");
+        return Ok(None);
+    }
+    let Sink::File(path) =
+        Sink::parse(&config.tx_archive_sink).context("failed to parse transaction archive sink")?;
+    let file = std::fs::File::open(&path).with_context(|| {
+        format!(
+            "failed to open transaction archive file at `{}`",
+            path.display()
+        )
+    })?;
+    replay_blocks(std::io::BufReader::new(file), block_height)
+}
+
+/// Returns the hash of the last successful transaction signed by every bridge account after it
+/// was initialized, as recorded while executing the blocks read from `archive`.
+///
+/// Blocks above `block_height` are ignored, and blocks archived more than once are only replayed
+/// the first time. Returns `None` if a block up to `block_height` is missing.
+fn replay_blocks(
+    archive: impl BufRead,
+    block_height: u64,
+) -> Result<Option<HashMap<Address, [u8; 32]>>> {
+    let mut bridge_accounts = HashSet::new();
+    let mut last_tx_hashes = HashMap::new();
+    let mut next_height = 1;
+    for line in archive.lines() {
+        let line = line.context("failed to read line from transaction archive")?;
+        if line.is_empty() {
+            continue;
+        }
+        let block: ArchivedBlock = serde_json::from_str(&line)
+            .context("failed to deserialize block from transaction archive")?;
+        if block.height < next_height {
+            continue;
+        }
+        if block.height > block_height {
+            break;
+        }
+        if block.height != next_height {
+            warn!(
+                height = next_height,
+                "transaction archive is missing a block; skipping bridge account last transaction \
+                 hashes",
+            );
+            return Ok(None);
+        }
+
+        // failed transactions are not executed, so they are not recorded either
+        for archived in block.transactions.into_iter().filter(|tx| tx.code == 0) {
+            let bytes = hex::decode(&archived.transaction)
+                .context("invalid archived transaction hex string")?;
+            let raw = raw::SignedTransaction::decode(bytes.as_slice())
+                .context("failed to decode protobuf to signed transaction")?;
+            let tx = SignedTransaction::try_from_raw(raw)
+                .context("failed to transform raw signed transaction to verified type")?;
+            let signer = crate::address::base_prefixed(tx.verification_key().address_bytes());
+            // mirrors `transaction::execute`, which records the hash before executing the actions
+            if bridge_accounts.contains(&signer) {
+                last_tx_hashes.insert(signer, tx.sha256_of_proto_encoding());
+            }
+            if tx
+                .actions()
+                .iter()
+                .any(|action| matches!(action, Action::InitBridgeAccount(_)))
+            {
+                bridge_accounts.insert(signer);
+            }
+        }
+        next_height += 1;
+    }
+
+    if next_height <= block_height {
+        warn!(
+            height = next_height,
+            "transaction archive ends before the latest block; skipping bridge account last \
+             transaction hashes",
+        );
+        return Ok(None);
+    }
+    Ok(Some(last_tx_hashes))
+}
+
+#[cfg(test)]
+mod test {
+    use astria_core::{
+        crypto::SigningKey,
+        primitive::v1::{
+            asset,
+            RollupId,
+        },
+        protocol::transaction::v1alpha1::{
+            action::{
+                InitBridgeAccountAction,
+                TransferAction,
+            },
+            TransactionParams,
+            UnsignedTransaction,
+        },
+    };
+
+    use super::*;
+    use crate::tx_archive::ArchivedTransaction;
+
+    fn signed_tx(signing_key: &SigningKey, nonce: u32, action: Action) -> SignedTransaction {
+        UnsignedTransaction {
+            params: TransactionParams::builder()
+                .nonce(nonce)
+                .chain_id("test")
+                .build(),
+            actions: vec![action],
+        }
+        .into_signed(signing_key)
+    }
+
+    fn archived_block(height: u64, txs: &[(&SignedTransaction, u32)]) -> String {
+        let block = ArchivedBlock {
+            height,
+            block_hash: "00".to_string(),
+            time: "2024-01-01T00:00:00Z".to_string(),
+            app_hash: "11".to_string(),
+            transactions: txs
+                .iter()
+                .map(|(tx, code)| ArchivedTransaction {
+                    hash: hex::encode(tx.sha256_of_proto_encoding()),
+                    transaction: hex::encode(tx.to_raw().encode_to_vec()),
+                    code: *code,
+                    log: String::new(),
+                    events: vec![],
+                })
+                .collect(),
+        };
+        serde_json::to_string(&block).unwrap()
+    }
+
+    fn init_bridge_account() -> Action {
+        InitBridgeAccountAction {
+            rollup_id: RollupId::new([1; 32]),
+            asset_id: asset::Id::from_str_unchecked("test"),
+            fee_asset_id: asset::Id::from_str_unchecked("test"),
+            sudo_address: None,
+            withdrawer_address: None,
+        }
+        .into()
+    }
+
+    fn transfer() -> Action {
+        TransferAction {
+            to: crate::address::base_prefixed([2; 20]),
+            amount: 1,
+            asset_id: asset::Id::from_str_unchecked("test"),
+            fee_asset_id: asset::Id::from_str_unchecked("test"),
+        }
+        .into()
+    }
+
+    #[tokio::test]
+    async fn rollup_bridge_account_index_is_repaired() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let rollup_id = RollupId::new([1; 32]);
+        let bridge_address = crate::address::base_prefixed([1; 20]);
+        let stale_address = crate::address::base_prefixed([2; 20]);
+        state.put_bridge_account_rollup_id(&bridge_address, &rollup_id);
+        state.delete_rollup_bridge_account_index(&rollup_id, &bridge_address);
+        state.put_rollup_bridge_account_index(&rollup_id, &stale_address);
+
+        let drift = rebuild_rollup_bridge_account_index(&mut state)
+            .await
+            .unwrap();
+        assert_eq!(
+            drift,
+            IndexDrift {
+                missing: 1,
+                mismatched: 0,
+                stale: 1,
+            }
+        );
+        assert_eq!(
+            state.get_rollup_bridge_account_index().await.unwrap(),
+            vec![(rollup_id, bridge_address)],
+        );
+        assert!(
+            rebuild_rollup_bridge_account_index(&mut state)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn last_transaction_hashes_are_repaired() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let missing_address = crate::address::base_prefixed([1; 20]);
+        let mismatched_address = crate::address::base_prefixed([2; 20]);
+        let stale_address = crate::address::base_prefixed([3; 20]);
+        state.put_last_transaction_hash_for_bridge_account(&mismatched_address, &[0; 32]);
+        state.put_last_transaction_hash_for_bridge_account(&stale_address, &[0; 32]);
+        let last_tx_hashes =
+            HashMap::from([(missing_address, [1; 32]), (mismatched_address, [2; 32])]);
+
+        let drift = rebuild_last_transaction_hashes(&mut state, &last_tx_hashes)
+            .await
+            .unwrap();
+        assert_eq!(
+            drift,
+            IndexDrift {
+                missing: 1,
+                mismatched: 1,
+                stale: 1,
+            }
+        );
+        let actual: HashMap<_, _> = state
+            .get_last_transaction_hashes_for_bridge_accounts()
+            .await
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(actual, last_tx_hashes);
+    }
+
+    #[test]
+    fn replay_records_successful_transactions_of_bridge_accounts() {
+        let bridge_key = SigningKey::from([1; 32]);
+        let bridge_address = crate::address::base_prefixed(bridge_key.address_bytes());
+        let other_key = SigningKey::from([2; 32]);
+
+        let init = signed_tx(&bridge_key, 0, init_bridge_account());
+        let succeeded = signed_tx(&bridge_key, 1, transfer());
+        let failed = signed_tx(&bridge_key, 2, transfer());
+        let other = signed_tx(&other_key, 0, transfer());
+        let archive = [
+            archived_block(1, &[(&init, 0)]),
+            archived_block(2, &[(&succeeded, 0), (&failed, 1), (&other, 0)]),
+            archived_block(2, &[]),
+            archived_block(3, &[(&failed, 0)]),
+        ]
+        .join("\n");
+
+        let last_tx_hashes = replay_blocks(archive.as_bytes(), 2).unwrap().unwrap();
+        assert_eq!(
+            last_tx_hashes,
+            HashMap::from([(bridge_address, succeeded.sha256_of_proto_encoding())]),
+        );
+    }
+
+    #[test]
+    fn replay_is_skipped_for_incomplete_archive() {
+        let archive = [archived_block(1, &[]), archived_block(3, &[])].join("\n");
+        assert!(replay_blocks(archive.as_bytes(), 3).unwrap().is_none());
+        assert!(replay_blocks(archive.as_bytes(), 4).unwrap().is_none());
+        assert!(replay_blocks(archive.as_bytes(), 1).unwrap().is_some());
+    }
+}
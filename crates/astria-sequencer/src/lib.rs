@@ -9,17 +9,22 @@ mod build_info;
 pub(crate) mod component;
 pub mod config;
 pub(crate) mod fee_asset_change;
+pub(crate) mod fee_distribution;
 pub(crate) mod genesis;
+pub mod genesis_validation;
 pub(crate) mod grpc;
 pub(crate) mod ibc;
+pub mod index_rebuild;
 mod mempool;
 pub(crate) mod metrics;
+mod profiling;
 pub(crate) mod proposal;
 pub(crate) mod sequence;
 mod sequencer;
 pub(crate) mod service;
 pub(crate) mod state_ext;
 pub(crate) mod transaction;
+mod tx_archive;
 mod utils;
 
 pub use build_info::BUILD_INFO;
@@ -1,17 +1,47 @@
-use std::process::ExitCode;
+use std::{
+    path::Path,
+    process::ExitCode,
+};
 
 use anyhow::Context as _;
 use astria_sequencer::{
+    genesis_validation,
+    index_rebuild::{
+        self,
+        Mode,
+    },
     Config,
     Sequencer,
     BUILD_INFO,
 };
-use tracing::info;
+use tracing::{
+    error,
+    info,
+    warn,
+};
+
+#[cfg(feature = "profiling")]
+#[global_allocator]
+static ALLOCATOR: tikv_jemallocator::Jemalloc = tikv_jemallocator::Jemalloc;
+
+/// Compiles heap profiling into jemalloc at startup, sampling an allocation every 512 KiB on
+/// average. Sampling is only activated once the profiling endpoints are started.
+#[cfg(feature = "profiling")]
+#[allow(non_upper_case_globals)]
+#[export_name = "_rjem_malloc_conf"]
+pub static malloc_conf: &[u8] = b"prof:true,prof_active:false,lg_prof_sample:19\0";
 
 // Following the BSD convention for failing to read config
 // See here: https://freedesktop.org/software/systemd/man/systemd.exec.html#Process%20Exit%20Codes
 const EX_CONFIG: u8 = 78;
 
+/// Rebuilds the nonverifiable indexes of the stopped node's db instead of running the sequencer.
+const REBUILD_INDEXES_FLAG: &str = "--rebuild-indexes";
+/// Used with `--rebuild-indexes` to only report drift, exiting with failure if any is found.
+const CHECK_ONLY_FLAG: &str = "--check-only";
+/// Validates the genesis file at the path following the flag instead of running the sequencer.
+const VALIDATE_GENESIS_FLAG: &str = "--validate-genesis";
+
 #[tokio::main]
 async fn main() -> ExitCode {
     eprintln!(
@@ -20,6 +50,15 @@ async fn main() -> ExitCode {
             .expect("build info is serializable because it contains only unicode fields")
     );
 
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if let Some(pos) = args.iter().position(|arg| arg == VALIDATE_GENESIS_FLAG) {
+        let Some(path) = args.get(pos.saturating_add(1)) else {
+            eprintln!("{VALIDATE_GENESIS_FLAG} requires the path to a genesis file");
+            return ExitCode::FAILURE;
+        };
+        return validate_genesis(Path::new(path));
+    }
+
     let cfg: Config = match config::get() {
         Ok(cfg) => cfg,
         Err(e) => {
@@ -49,6 +88,15 @@ async fn main() -> ExitCode {
         Ok(guard) => guard,
     };
 
+    if args.iter().any(|arg| arg == REBUILD_INDEXES_FLAG) {
+        let mode = if args.iter().any(|arg| arg == CHECK_ONLY_FLAG) {
+            Mode::CheckOnly
+        } else {
+            Mode::Repair
+        };
+        return rebuild_indexes(&cfg, mode).await;
+    }
+
     info!(
         config = serde_json::to_string(&cfg).expect("serializing to a string cannot fail"),
         "initializing sequencer"
@@ -61,3 +109,44 @@ async fn main() -> ExitCode {
     info!("Sequencer stopped");
     ExitCode::SUCCESS
 }
+
+async fn rebuild_indexes(cfg: &Config, mode: Mode) -> ExitCode {
+    info!(?mode, "rebuilding nonverifiable indexes");
+    match index_rebuild::run(cfg, mode).await {
+        Ok(report) if mode == Mode::CheckOnly && report.has_drift() => {
+            warn!(?report, "nonverifiable indexes have drifted");
+            ExitCode::FAILURE
+        }
+        Ok(report) => {
+            info!(?report, "finished rebuilding nonverifiable indexes");
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            error!(
+                error = AsRef::<dyn std::error::Error>::as_ref(&e),
+                "failed to rebuild nonverifiable indexes"
+            );
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Validates the genesis file at `path`, printing the report to stdout.
+///
+/// This runs before the config is read and telemetry is set up, so it can be used without a
+/// configured node.
+fn validate_genesis(path: &Path) -> ExitCode {
+    let report = match genesis_validation::validate_file(path) {
+        Ok(report) => report,
+        Err(e) => {
+            eprintln!("failed to validate genesis file:\n{e:?}");
+            return ExitCode::FAILURE;
+        }
+    };
+    println!("{report}");
+    if report.has_errors() {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
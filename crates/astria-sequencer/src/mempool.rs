@@ -4,7 +4,9 @@ use std::{
         Ordering,
     },
     collections::{
+        hash_map::Entry,
         HashMap,
+        HashSet,
         VecDeque,
     },
     future::Future,
@@ -18,7 +20,10 @@ use std::{
 use anyhow::Context;
 use astria_core::{
     crypto::SigningKey,
-    primitive::v1::Address,
+    primitive::v1::{
+        Address,
+        ADDRESS_LEN,
+    },
     protocol::transaction::v1alpha1::{
         SignedTransaction,
         TransactionParams,
@@ -139,7 +144,124 @@ pub(crate) enum RemovalReason {
     FailedPrepareProposal(String),
 }
 
-const TX_TTL: Duration = Duration::from_secs(600); // 10 minutes 
+/// Limits on the number of transactions a single account can have in the mempool.
+///
+/// This keeps one account from filling the mempool with transactions, whether they are
+/// executable now or parked behind a nonce gap.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AccountLimits {
+    /// The maximum number of transactions of a single account. `None` disables the limit.
+    pub(crate) max_transactions: Option<usize>,
+    /// The accounts exempt from the limit, such as bridge withdrawers that submit many
+    /// transactions on behalf of their users.
+    pub(crate) exempt_accounts: HashSet<[u8; ADDRESS_LEN]>,
+}
+
+impl AccountLimits {
+    /// Returns an error if adding `enqueued_tx` to `queue` would take its account over the limit.
+    ///
+    /// Transactions already in the queue are always accepted so that re-inserting them is not
+    /// affected by the limit.
+    fn check(
+        &self,
+        queue: &Queue,
+        enqueued_tx: &EnqueuedTransaction,
+    ) -> Result<(), AccountLimitReached> {
+        let Some(limit) = self.max_transactions else {
+            return Ok(());
+        };
+        let address = *enqueued_tx.address();
+        if self.exempt_accounts.contains(&address.bytes())
+            || queue.transactions.get(enqueued_tx).is_some()
+        {
+            return Ok(());
+        }
+        if queue.account_transaction_count(&address) >= limit {
+            return Err(AccountLimitReached {
+                address,
+                limit,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// The error returned when inserting a transaction would take its account over
+/// [`AccountLimits::max_transactions`].
+#[derive(Debug, thiserror::Error)]
+#[error(
+    "account {address} already has the maximum of {limit} transactions in the mempool; wait for \
+     some of them to be included in a block before submitting more"
+)]
+pub(crate) struct AccountLimitReached {
+    address: Address,
+    limit: usize,
+}
+
+/// The prioritized queue of transactions, together with the number of transactions of each
+/// account in it.
+///
+/// Transactions must only be added and removed through the methods of this type so that the
+/// counts stay in sync with the queue.
+#[derive(Default)]
+struct Queue {
+    transactions: MempoolQueue,
+    counts_per_account: HashMap<[u8; ADDRESS_LEN], usize>,
+}
+
+impl Queue {
+    fn account_transaction_count(&self, address: &Address) -> usize {
+        self.counts_per_account
+            .get(&address.bytes())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Pushes `enqueued_tx` with `priority`, returning its previous priority if it was already in
+    /// the queue.
+    fn push(
+        &mut self,
+        enqueued_tx: EnqueuedTransaction,
+        priority: TransactionPriority,
+    ) -> Option<TransactionPriority> {
+        let address = enqueued_tx.address().bytes();
+        let previous = self.transactions.push(enqueued_tx, priority);
+        if previous.is_none() {
+            let count = self.counts_per_account.entry(address).or_default();
+            *count = count.saturating_add(1);
+        }
+        previous
+    }
+
+    fn pop(&mut self) -> Option<(EnqueuedTransaction, TransactionPriority)> {
+        let popped = self.transactions.pop()?;
+        self.decrement_count(popped.0.address());
+        Some(popped)
+    }
+
+    fn remove(
+        &mut self,
+        enqueued_tx: &EnqueuedTransaction,
+    ) -> Option<(EnqueuedTransaction, TransactionPriority)> {
+        let removed = self.transactions.remove(enqueued_tx)?;
+        // the removed transaction carries the actual address, unlike a lookup by hash
+        self.decrement_count(removed.0.address());
+        Some(removed)
+    }
+
+    fn decrement_count(&mut self, address: &Address) {
+        if let Entry::Occupied(mut count) = self.counts_per_account.entry(address.bytes()) {
+            let remaining = count.get().saturating_sub(1);
+            if remaining == 0 {
+                count.remove();
+            } else {
+                count.insert(remaining);
+            }
+        }
+    }
+}
+
+const TX_TTL: Duration = Duration::from_secs(600); // 10 minutes
 const REMOVAL_CACHE_SIZE: usize = 4096;
 
 /// `RemovalCache` is used to signal to `CometBFT` that a
@@ -195,38 +317,54 @@ impl RemovalCache {
 /// The priority is calculated as the difference between the transaction nonce and the current
 /// account nonce. The lower the difference, the higher the priority.
 ///
+/// The number of transactions of a single account can be limited through [`AccountLimits`].
+///
 /// Future extensions to this mempool can include:
 /// - maximum mempool size
 /// - fee-based ordering
 /// - transaction expiration
 #[derive(Clone)]
 pub(crate) struct Mempool {
-    queue: Arc<RwLock<MempoolQueue>>,
+    queue: Arc<RwLock<Queue>>,
     comet_bft_removal_cache: Arc<RwLock<RemovalCache>>,
     tx_ttl: Duration,
+    account_limits: Arc<AccountLimits>,
 }
 
 impl Mempool {
     #[must_use]
     pub(crate) fn new() -> Self {
         Self {
-            queue: Arc::new(RwLock::new(MempoolQueue::new())),
+            queue: Arc::new(RwLock::new(Queue::default())),
             comet_bft_removal_cache: Arc::new(RwLock::new(RemovalCache::new(
                 NonZeroUsize::try_from(REMOVAL_CACHE_SIZE)
                     .expect("Removal cache cannot be zero sized"),
             ))),
             tx_ttl: TX_TTL,
+            account_limits: Arc::new(AccountLimits::default()),
+        }
+    }
+
+    /// Sets the limits on the number of transactions of a single account.
+    #[must_use]
+    pub(crate) fn with_account_limits(self, account_limits: AccountLimits) -> Self {
+        Self {
+            account_limits: Arc::new(account_limits),
+            ..self
         }
     }
 
     /// returns the number of transactions in the mempool
     #[must_use]
     pub(crate) async fn len(&self) -> usize {
-        self.queue.read().await.len()
+        self.queue.read().await.transactions.len()
     }
 
     /// inserts a transaction into the mempool
     ///
+    /// returns an [`AccountLimitReached`] error if the transaction's account already has the
+    /// maximum number of transactions in the mempool.
+    ///
     /// note: the oldest timestamp from found priorities is maintained.
     pub(crate) async fn insert(
         &self,
@@ -235,13 +373,18 @@ impl Mempool {
     ) -> anyhow::Result<()> {
         let enqueued_tx = EnqueuedTransaction::new(tx);
         let fresh_priority = enqueued_tx.priority(current_account_nonce, None)?;
-        Self::update_or_insert(&mut *self.queue.write().await, enqueued_tx, &fresh_priority);
+        let mut queue = self.queue.write().await;
+        self.account_limits.check(&queue, &enqueued_tx)?;
+        Self::update_or_insert(&mut queue, enqueued_tx, &fresh_priority);
 
         Ok(())
     }
 
     /// inserts all the given transactions into the mempool
     ///
+    /// this is used to return transactions taken out of the mempool, and so does not apply the
+    /// [`AccountLimits`].
+    ///
     /// note: the oldest timestamp from found priorities for an `EnqueuedTransaction` is maintained.
     pub(crate) async fn insert_all(&self, txs: Vec<(EnqueuedTransaction, TransactionPriority)>) {
         let mut queue = self.queue.write().await;
@@ -255,11 +398,11 @@ impl Mempool {
     ///
     /// note: updates the priority using the `possible_priority`'s nonce diff.
     fn update_or_insert(
-        queue: &mut PriorityQueue<EnqueuedTransaction, TransactionPriority>,
+        queue: &mut Queue,
         enqueued_tx: EnqueuedTransaction,
         possible_priority: &TransactionPriority,
     ) {
-        let oldest_timestamp = queue.get_priority(&enqueued_tx).map_or(
+        let oldest_timestamp = queue.transactions.get_priority(&enqueued_tx).map_or(
             possible_priority.time_first_seen,
             |prev_priority| {
                 possible_priority
@@ -335,7 +478,7 @@ impl Mempool {
 
         let mut queue = self.queue.write().await;
         let mut removal_cache = self.comet_bft_removal_cache.write().await;
-        for (enqueued_tx, priority) in queue.iter_mut() {
+        for (enqueued_tx, priority) in queue.transactions.iter_mut() {
             let address = enqueued_tx.address();
 
             // check if the transactions has expired
@@ -377,12 +520,23 @@ impl Mempool {
         Ok(())
     }
 
+    /// returns a snapshot of the transactions in the mempool, in no particular order
+    pub(crate) async fn transactions(&self) -> Vec<EnqueuedTransaction> {
+        self.queue
+            .read()
+            .await
+            .transactions
+            .iter()
+            .map(|(tx, _priority)| tx.clone())
+            .collect()
+    }
+
     /// returns the pending nonce for the given address,
     /// if it exists in the mempool.
     pub(crate) async fn pending_nonce(&self, address: &Address) -> Option<u32> {
         let inner = self.queue.read().await;
         let mut nonce = None;
-        for (tx, _priority) in inner.iter() {
+        for (tx, _priority) in inner.transactions.iter() {
             if tx.address() == address {
                 nonce = Some(cmp::max(nonce.unwrap_or_default(), tx.signed_tx.nonce()));
             }
@@ -780,6 +934,69 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn should_limit_transactions_per_account() {
+        let mempool = Mempool::new().with_account_limits(AccountLimits {
+            max_transactions: Some(2),
+            exempt_accounts: HashSet::new(),
+        });
+
+        mempool.insert(get_mock_tx(0), 0).await.unwrap();
+        mempool.insert(get_mock_tx(2), 0).await.unwrap();
+        let error = mempool.insert(get_mock_tx(1), 0).await.unwrap_err();
+        assert!(error.downcast_ref::<AccountLimitReached>().is_some());
+
+        // re-inserting a transaction that is already in the mempool is not affected by the limit
+        mempool.insert(get_mock_tx(0), 0).await.unwrap();
+
+        // other accounts are not affected by alice's transactions
+        let other_signing_key = SigningKey::from([1; 32]);
+        let other_tx = UnsignedTransaction {
+            params: TransactionParams::builder()
+                .nonce(0)
+                .chain_id("test")
+                .build(),
+            actions: get_mock_tx(0).actions().to_vec(),
+        }
+        .into_signed(&other_signing_key);
+        mempool.insert(other_tx, 0).await.unwrap();
+        assert_eq!(mempool.len().await, 3);
+    }
+
+    #[tokio::test]
+    async fn account_limit_is_freed_by_pop_and_remove() {
+        let mempool = Mempool::new().with_account_limits(AccountLimits {
+            max_transactions: Some(1),
+            exempt_accounts: HashSet::new(),
+        });
+
+        mempool.insert(get_mock_tx(0), 0).await.unwrap();
+        mempool.insert(get_mock_tx(1), 0).await.unwrap_err();
+        mempool.pop().await.unwrap();
+
+        let tx = get_mock_tx(1);
+        let tx_hash = tx.sha256_of_proto_encoding();
+        mempool.insert(tx, 0).await.unwrap();
+        mempool.insert(get_mock_tx(2), 0).await.unwrap_err();
+        mempool.remove(tx_hash).await;
+
+        mempool.insert(get_mock_tx(2), 0).await.unwrap();
+        assert_eq!(mempool.len().await, 1);
+    }
+
+    #[tokio::test]
+    async fn exempt_accounts_are_not_limited() {
+        let alice_address = crate::app::test_utils::get_alice_signing_key_and_address().1;
+        let mempool = Mempool::new().with_account_limits(AccountLimits {
+            max_transactions: Some(1),
+            exempt_accounts: HashSet::from([alice_address.bytes()]),
+        });
+
+        mempool.insert(get_mock_tx(0), 0).await.unwrap();
+        mempool.insert(get_mock_tx(1), 0).await.unwrap();
+        assert_eq!(mempool.len().await, 2);
+    }
+
     #[tokio::test]
     async fn tx_cache_size() {
         let mut tx_cache = RemovalCache::new(NonZeroUsize::try_from(2).unwrap());
@@ -1,3 +1,12 @@
+use std::{
+    collections::{
+        BTreeMap,
+        HashMap,
+    },
+    time::Duration,
+};
+
+use astria_core::protocol::transaction::v1alpha1::Action;
 use metrics::{
     counter,
     describe_counter,
@@ -12,6 +21,108 @@ use metrics::{
 };
 use telemetry::metric_names;
 
+const ACTION_LABEL: &str = "action";
+const PHASE_LABEL: &str = "phase";
+const REASON_LABEL: &str = "reason";
+
+/// The phase of transaction processing an action passed through.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum ActionPhase {
+    CheckStateless,
+    CheckStateful,
+    Execute,
+}
+
+impl ActionPhase {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::CheckStateless => "check_stateless",
+            Self::CheckStateful => "check_stateful",
+            Self::Execute => "execute",
+        }
+    }
+}
+
+/// The reason an ABCI query was cancelled, used as the value of the `reason` label.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum QueryCancelReason {
+    Deadline,
+    Memory,
+    ResponseTooLarge,
+}
+
+impl QueryCancelReason {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Deadline => "deadline",
+            Self::Memory => "memory",
+            Self::ResponseTooLarge => "response_too_large",
+        }
+    }
+}
+
+/// The reason a gRPC request was rejected, used as the value of the `reason` label.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum GrpcRejectReason {
+    Concurrency,
+    RateLimit,
+}
+
+impl GrpcRejectReason {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            Self::Concurrency => "concurrency",
+            Self::RateLimit => "rate_limit",
+        }
+    }
+}
+
+/// The number of actions executed and the time spent executing them while executing a block,
+/// by action type.
+#[derive(Debug, Default)]
+pub(crate) struct BlockActionTotals {
+    totals: BTreeMap<&'static str, (u64, Duration)>,
+}
+
+impl BlockActionTotals {
+    pub(crate) fn add(&mut self, action_name: &'static str, duration: Duration) {
+        let (count, total_duration) = self.totals.entry(action_name).or_default();
+        *count = count.saturating_add(1);
+        *total_duration = total_duration.saturating_add(duration);
+    }
+
+    pub(crate) fn merge(&mut self, other: Self) {
+        for (action_name, (count, duration)) in other.totals {
+            let (total_count, total_duration) = self.totals.entry(action_name).or_default();
+            *total_count = total_count.saturating_add(count);
+            *total_duration = total_duration.saturating_add(duration);
+        }
+    }
+}
+
+struct PhaseMetrics {
+    count: Counter,
+    duration: Histogram,
+}
+
+struct ActionMetrics {
+    check_stateless: PhaseMetrics,
+    check_stateful: PhaseMetrics,
+    execute: PhaseMetrics,
+    block_count: Histogram,
+    block_execution_duration: Histogram,
+}
+
+impl ActionMetrics {
+    fn phase(&self, phase: ActionPhase) -> &PhaseMetrics {
+        match phase {
+            ActionPhase::CheckStateless => &self.check_stateless,
+            ActionPhase::CheckStateful => &self.check_stateful,
+            ActionPhase::Execute => &self.execute,
+        }
+    }
+}
+
 pub(crate) struct Metrics {
     prepare_proposal_excluded_transactions_decode_failure: Counter,
     prepare_proposal_excluded_transactions_cometbft_space: Counter,
@@ -20,6 +131,7 @@ pub(crate) struct Metrics {
     prepare_proposal_excluded_transactions: Gauge,
     proposal_deposits: Histogram,
     proposal_transactions: Histogram,
+    proposal_cometbft_bytes: Histogram,
     process_proposal_skipped_proposal: Counter,
     check_tx_removed_too_large: Counter,
     check_tx_removed_expired: Counter,
@@ -27,6 +139,15 @@ pub(crate) struct Metrics {
     check_tx_removed_failed_stateless: Counter,
     check_tx_removed_stale_nonce: Counter,
     check_tx_removed_account_balance: Counter,
+    check_tx_rejected_overloaded: Counter,
+    check_tx_rejected_account_limit: Counter,
+    abci_query_duration: Histogram,
+    abci_queries_cancelled_deadline: Counter,
+    abci_queries_cancelled_memory: Counter,
+    abci_queries_cancelled_response_too_large: Counter,
+    grpc_requests_rejected_concurrency: Counter,
+    grpc_requests_rejected_rate_limit: Counter,
+    actions: HashMap<&'static str, ActionMetrics>,
 }
 
 impl Metrics {
@@ -90,6 +211,13 @@ impl Metrics {
         );
         let proposal_transactions = histogram!(PROPOSAL_TRANSACTIONS);
 
+        describe_histogram!(
+            PROPOSAL_COMETBFT_BYTES,
+            Unit::Bytes,
+            "The number of bytes of a prepared proposal counted against cometbft's max_tx_bytes"
+        );
+        let proposal_cometbft_bytes = histogram!(PROPOSAL_COMETBFT_BYTES);
+
         describe_counter!(
             PROCESS_PROPOSAL_SKIPPED_PROPOSAL,
             Unit::Count,
@@ -145,6 +273,52 @@ impl Metrics {
         );
         let check_tx_removed_expired = counter!(CHECK_TX_REMOVED_EXPIRED);
 
+        describe_counter!(
+            CHECK_TX_REJECTED_OVERLOADED,
+            Unit::Count,
+            "The number of new transactions that have been rejected in CheckTx due to paying too \
+             low a fee while the node was overloaded"
+        );
+        let check_tx_rejected_overloaded = counter!(CHECK_TX_REJECTED_OVERLOADED);
+
+        describe_counter!(
+            CHECK_TX_REJECTED_ACCOUNT_LIMIT,
+            Unit::Count,
+            "The number of new transactions that have been rejected in CheckTx due to their \
+             account already having the maximum number of transactions in the mempool"
+        );
+        let check_tx_rejected_account_limit = counter!(CHECK_TX_REJECTED_ACCOUNT_LIMIT);
+
+        describe_histogram!(
+            ABCI_QUERY_DURATION,
+            Unit::Seconds,
+            "The time spent handling an ABCI query, in seconds"
+        );
+        let abci_query_duration = histogram!(ABCI_QUERY_DURATION);
+
+        describe_counter!(
+            ABCI_QUERIES_CANCELLED,
+            Unit::Count,
+            "The number of ABCI queries that have been cancelled for exceeding a resource limit, \
+             labelled by the limit that was exceeded"
+        );
+        let abci_queries_cancelled_deadline = abci_queries_cancelled(QueryCancelReason::Deadline);
+        let abci_queries_cancelled_memory = abci_queries_cancelled(QueryCancelReason::Memory);
+        let abci_queries_cancelled_response_too_large =
+            abci_queries_cancelled(QueryCancelReason::ResponseTooLarge);
+
+        describe_counter!(
+            GRPC_REQUESTS_REJECTED,
+            Unit::Count,
+            "The number of gRPC requests that have been rejected with RESOURCE_EXHAUSTED, \
+             labelled by the limit that was exceeded"
+        );
+        let grpc_requests_rejected_concurrency =
+            grpc_requests_rejected(GrpcRejectReason::Concurrency);
+        let grpc_requests_rejected_rate_limit = grpc_requests_rejected(GrpcRejectReason::RateLimit);
+
+        let actions = register_actions();
+
         Self {
             prepare_proposal_excluded_transactions_decode_failure,
             prepare_proposal_excluded_transactions_cometbft_space,
@@ -153,6 +327,7 @@ impl Metrics {
             prepare_proposal_excluded_transactions,
             proposal_deposits,
             proposal_transactions,
+            proposal_cometbft_bytes,
             process_proposal_skipped_proposal,
             check_tx_removed_too_large,
             check_tx_removed_expired,
@@ -160,6 +335,15 @@ impl Metrics {
             check_tx_removed_failed_stateless,
             check_tx_removed_stale_nonce,
             check_tx_removed_account_balance,
+            check_tx_rejected_overloaded,
+            check_tx_rejected_account_limit,
+            abci_query_duration,
+            abci_queries_cancelled_deadline,
+            abci_queries_cancelled_memory,
+            abci_queries_cancelled_response_too_large,
+            grpc_requests_rejected_concurrency,
+            grpc_requests_rejected_rate_limit,
+            actions,
         }
     }
 
@@ -199,6 +383,12 @@ impl Metrics {
         self.proposal_transactions.record(count as f64);
     }
 
+    pub(crate) fn record_proposal_cometbft_bytes(&self, bytes: usize) {
+        // allow: precision loss is unlikely (values too small) but also unimportant in histograms.
+        #[allow(clippy::cast_precision_loss)]
+        self.proposal_cometbft_bytes.record(bytes as f64);
+    }
+
     pub(crate) fn increment_process_proposal_skipped_proposal(&self) {
         self.process_proposal_skipped_proposal.increment(1);
     }
@@ -226,6 +416,119 @@ impl Metrics {
     pub(crate) fn increment_check_tx_removed_account_balance(&self) {
         self.check_tx_removed_account_balance.increment(1);
     }
+
+    pub(crate) fn increment_check_tx_rejected_overloaded(&self) {
+        self.check_tx_rejected_overloaded.increment(1);
+    }
+
+    pub(crate) fn increment_check_tx_rejected_account_limit(&self) {
+        self.check_tx_rejected_account_limit.increment(1);
+    }
+
+    pub(crate) fn record_abci_query_duration(&self, duration: Duration) {
+        self.abci_query_duration.record(duration);
+    }
+
+    pub(crate) fn increment_abci_queries_cancelled(&self, reason: QueryCancelReason) {
+        match reason {
+            QueryCancelReason::Deadline => &self.abci_queries_cancelled_deadline,
+            QueryCancelReason::Memory => &self.abci_queries_cancelled_memory,
+            QueryCancelReason::ResponseTooLarge => &self.abci_queries_cancelled_response_too_large,
+        }
+        .increment(1);
+    }
+
+    pub(crate) fn increment_grpc_requests_rejected(&self, reason: GrpcRejectReason) {
+        match reason {
+            GrpcRejectReason::Concurrency => &self.grpc_requests_rejected_concurrency,
+            GrpcRejectReason::RateLimit => &self.grpc_requests_rejected_rate_limit,
+        }
+        .increment(1);
+    }
+
+    pub(crate) fn record_action(&self, action: &Action, phase: ActionPhase, duration: Duration) {
+        let Some(metrics) = self.actions.get(action.name()) else {
+            return;
+        };
+        let metrics = metrics.phase(phase);
+        metrics.count.increment(1);
+        metrics.duration.record(duration);
+    }
+
+    pub(crate) fn record_block_action_totals(&self, totals: &BlockActionTotals) {
+        for (name, metrics) in &self.actions {
+            let (count, duration) = totals.totals.get(name).copied().unwrap_or_default();
+            // allow: precision loss is unlikely (values too small) but also unimportant in
+            // histograms.
+            #[allow(clippy::cast_precision_loss)]
+            metrics.block_count.record(count as f64);
+            metrics.block_execution_duration.record(duration);
+        }
+    }
+}
+
+fn register_actions() -> HashMap<&'static str, ActionMetrics> {
+    describe_counter!(
+        ACTIONS_PROCESSED,
+        Unit::Count,
+        "The number of actions that passed through a phase of transaction processing, labelled by \
+         action type and phase"
+    );
+    describe_histogram!(
+        ACTION_DURATION,
+        Unit::Seconds,
+        "The time spent on a single action in a phase of transaction processing, in seconds, \
+         labelled by action type and phase"
+    );
+    describe_histogram!(
+        BLOCK_ACTIONS,
+        Unit::Count,
+        "The number of actions executed in a block, labelled by action type"
+    );
+    describe_histogram!(
+        BLOCK_ACTIONS_EXECUTION_DURATION,
+        Unit::Seconds,
+        "The total time spent executing the actions of a block, in seconds, labelled by action \
+         type"
+    );
+
+    let phase = |name: &'static str, phase: ActionPhase| PhaseMetrics {
+        count: counter!(
+            ACTIONS_PROCESSED,
+            ACTION_LABEL => name,
+            PHASE_LABEL => phase.as_str(),
+        ),
+        duration: histogram!(
+            ACTION_DURATION,
+            ACTION_LABEL => name,
+            PHASE_LABEL => phase.as_str(),
+        ),
+    };
+
+    Action::NAMES
+        .into_iter()
+        .map(|name| {
+            let metrics = ActionMetrics {
+                check_stateless: phase(name, ActionPhase::CheckStateless),
+                check_stateful: phase(name, ActionPhase::CheckStateful),
+                execute: phase(name, ActionPhase::Execute),
+                block_count: histogram!(BLOCK_ACTIONS, ACTION_LABEL => name),
+                block_execution_duration: histogram!(
+                    BLOCK_ACTIONS_EXECUTION_DURATION,
+                    ACTION_LABEL => name,
+                ),
+            };
+            (name, metrics)
+        })
+        .collect()
+}
+
+fn abci_queries_cancelled(reason: QueryCancelReason) -> Counter {
+    counter!(ABCI_QUERIES_CANCELLED, REASON_LABEL => reason.as_str())
+}
+
+fn grpc_requests_rejected(reason: GrpcRejectReason) -> Counter {
+    counter!(GRPC_REQUESTS_REJECTED, REASON_LABEL => reason.as_str())
 }
 
 metric_names!(pub const METRICS_NAMES:
@@ -236,6 +539,7 @@ metric_names!(pub const METRICS_NAMES:
     PREPARE_PROPOSAL_EXCLUDED_TRANSACTIONS,
     PROPOSAL_DEPOSITS,
     PROPOSAL_TRANSACTIONS,
+    PROPOSAL_COMETBFT_BYTES,
     PROCESS_PROPOSAL_SKIPPED_PROPOSAL,
     CHECK_TX_REMOVED_TOO_LARGE,
     CHECK_TX_REMOVED_EXPIRED,
@@ -243,23 +547,42 @@ metric_names!(pub const METRICS_NAMES:
     CHECK_TX_REMOVED_FAILED_STATELESS,
     CHECK_TX_REMOVED_STALE_NONCE,
     CHECK_TX_REMOVED_ACCOUNT_BALANCE,
+    CHECK_TX_REJECTED_OVERLOADED,
+    CHECK_TX_REJECTED_ACCOUNT_LIMIT,
+    ABCI_QUERY_DURATION,
+    ABCI_QUERIES_CANCELLED,
+    GRPC_REQUESTS_REJECTED,
+    ACTIONS_PROCESSED,
+    ACTION_DURATION,
+    BLOCK_ACTIONS,
+    BLOCK_ACTIONS_EXECUTION_DURATION,
 );
 
 #[cfg(test)]
 mod tests {
     use super::{
+        ABCI_QUERIES_CANCELLED,
+        ABCI_QUERY_DURATION,
+        ACTIONS_PROCESSED,
+        ACTION_DURATION,
+        BLOCK_ACTIONS,
+        BLOCK_ACTIONS_EXECUTION_DURATION,
+        CHECK_TX_REJECTED_ACCOUNT_LIMIT,
+        CHECK_TX_REJECTED_OVERLOADED,
         CHECK_TX_REMOVED_ACCOUNT_BALANCE,
         CHECK_TX_REMOVED_EXPIRED,
         CHECK_TX_REMOVED_FAILED_EXECUTION,
         CHECK_TX_REMOVED_FAILED_STATELESS,
         CHECK_TX_REMOVED_STALE_NONCE,
         CHECK_TX_REMOVED_TOO_LARGE,
+        GRPC_REQUESTS_REJECTED,
         PREPARE_PROPOSAL_EXCLUDED_TRANSACTIONS,
         PREPARE_PROPOSAL_EXCLUDED_TRANSACTIONS_COMETBFT_SPACE,
         PREPARE_PROPOSAL_EXCLUDED_TRANSACTIONS_DECODE_FAILURE,
         PREPARE_PROPOSAL_EXCLUDED_TRANSACTIONS_FAILED_EXECUTION,
         PREPARE_PROPOSAL_EXCLUDED_TRANSACTIONS_SEQUENCER_SPACE,
         PROCESS_PROPOSAL_SKIPPED_PROPOSAL,
+        PROPOSAL_COMETBFT_BYTES,
         PROPOSAL_DEPOSITS,
         PROPOSAL_TRANSACTIONS,
     };
@@ -296,6 +619,7 @@ mod tests {
         );
         assert_const(PROPOSAL_DEPOSITS, "proposal_deposits");
         assert_const(PROPOSAL_TRANSACTIONS, "proposal_transactions");
+        assert_const(PROPOSAL_COMETBFT_BYTES, "proposal_cometbft_bytes");
         assert_const(
             PROCESS_PROPOSAL_SKIPPED_PROPOSAL,
             "process_proposal_skipped_proposal",
@@ -315,5 +639,20 @@ mod tests {
             CHECK_TX_REMOVED_ACCOUNT_BALANCE,
             "check_tx_removed_account_balance",
         );
+        assert_const(CHECK_TX_REJECTED_OVERLOADED, "check_tx_rejected_overloaded");
+        assert_const(
+            CHECK_TX_REJECTED_ACCOUNT_LIMIT,
+            "check_tx_rejected_account_limit",
+        );
+        assert_const(ABCI_QUERY_DURATION, "abci_query_duration");
+        assert_const(ABCI_QUERIES_CANCELLED, "abci_queries_cancelled");
+        assert_const(GRPC_REQUESTS_REJECTED, "grpc_requests_rejected");
+        assert_const(ACTIONS_PROCESSED, "actions_processed");
+        assert_const(ACTION_DURATION, "action_duration");
+        assert_const(BLOCK_ACTIONS, "block_actions");
+        assert_const(
+            BLOCK_ACTIONS_EXECUTION_DURATION,
+            "block_actions_execution_duration",
+        );
     }
 }
@@ -0,0 +1,234 @@
+//! Optional HTTP endpoints for on-demand profiling of a running sequencer.
+//!
+//! If `ASTRIA_SEQUENCER_PROFILING_HTTP_LISTENER_ADDR` is set, the following endpoints are served
+//! following the conventions of Go's `net/http/pprof`:
+//!
+//! + `GET /debug/pprof/profile?seconds=<N>`: a CPU profile sampled over `N` seconds (30 if not
+//!   given, at most 300), encoded as an uncompressed pprof protobuf. Only one CPU profile can be
+//!   taken at a time.
+//! + `GET /debug/pprof/heap`: a snapshot of the sampled live heap allocations, dumped by jemalloc
+//!   in the format read by `jeprof`.
+//!
+//! The endpoints are only available if the sequencer is built with the `profiling` feature, which
+//! also makes jemalloc the global allocator with heap profiling compiled in. Heap allocations are
+//! only sampled once the endpoints are started.
+//!
+//! The endpoints expose internals of the process and taking a CPU profile slows it down, so they
+//! should only be reachable by operators.
+
+#[cfg(feature = "profiling")]
+use anyhow::Context as _;
+use tokio::task::JoinHandle;
+
+/// Starts serving the profiling endpoints on `addr`.
+///
+/// # Errors
+///
+/// Returns an error if `addr` is not a valid socket address, if the server cannot bind to it, or
+/// if jemalloc's heap profiling cannot be activated.
+#[cfg(feature = "profiling")]
+pub(crate) fn start(addr: &str) -> anyhow::Result<JoinHandle<()>> {
+    let addr = addr
+        .parse()
+        .context("failed to parse profiling_http_listener_addr address")?;
+    server::start(addr)
+}
+
+/// Fails, as the sequencer was built without the `profiling` feature.
+#[cfg(not(feature = "profiling"))]
+pub(crate) fn start(_addr: &str) -> anyhow::Result<JoinHandle<()>> {
+    anyhow::bail!(
+        "profiling_http_listener_addr is set, but the sequencer was built without the `profiling` \
+         feature"
+    )
+}
+
+#[cfg(feature = "profiling")]
+mod server {
+    use std::{
+        ffi::CString,
+        net::SocketAddr,
+        os::unix::ffi::OsStrExt as _,
+        sync::{
+            atomic::{
+                AtomicU64,
+                Ordering,
+            },
+            Arc,
+        },
+        time::Duration,
+    };
+
+    use anyhow::{
+        anyhow,
+        Context as _,
+    };
+    use axum::{
+        extract::{
+            Query,
+            State,
+        },
+        http::{
+            header,
+            StatusCode,
+        },
+        response::{
+            IntoResponse,
+            Response,
+        },
+        routing::get,
+        Router,
+    };
+    use pprof::protos::Message as _;
+    use serde::Deserialize;
+    use tokio::{
+        sync::Mutex,
+        task::JoinHandle,
+    };
+    use tracing::{
+        error,
+        info,
+        instrument,
+    };
+
+    /// The duration of a CPU profile if none is requested.
+    const DEFAULT_CPU_PROFILE_DURATION: Duration = Duration::from_secs(30);
+
+    /// The longest CPU profile that can be requested.
+    const MAX_CPU_PROFILE_DURATION: Duration = Duration::from_secs(300);
+
+    /// The frequency in Hz at which the CPU profiler samples stack traces.
+    const CPU_PROFILE_FREQUENCY: i32 = 99;
+
+    /// Libraries whose frames are skipped while sampling, as unwinding through them can deadlock.
+    const CPU_PROFILE_BLOCKLIST: &[&str] = &["libc", "libgcc", "pthread", "vdso"];
+
+    pub(super) fn start(addr: SocketAddr) -> anyhow::Result<JoinHandle<()>> {
+        // safety: `prof.active` takes a bool.
+        unsafe { tikv_jemalloc_ctl::raw::write(b"prof.active\0", true) }
+            .map_err(|e| anyhow!("failed to activate jemalloc heap profiling: {e}"))?;
+
+        let app = Router::new()
+            .route("/debug/pprof/profile", get(get_cpu_profile))
+            .route("/debug/pprof/heap", get(get_heap_profile))
+            .with_state(Arc::new(Mutex::new(())));
+        let server = axum::Server::try_bind(&addr)
+            .context("failed to bind profiling server")?
+            .serve(app.into_make_service());
+        info!(%addr, "serving profiling endpoints");
+        Ok(tokio::spawn(async move {
+            if let Err(e) = server.await {
+                error!(
+                    error = &e as &dyn std::error::Error,
+                    "profiling server exited with error"
+                );
+            }
+        }))
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct CpuProfileQuery {
+        /// The number of seconds to sample for.
+        seconds: Option<u64>,
+    }
+
+    /// Handler of a call to `/debug/pprof/profile`.
+    ///
+    /// The lock held in the state ensures that only one CPU profile is taken at a time.
+    #[instrument(skip_all)]
+    async fn get_cpu_profile(
+        State(lock): State<Arc<Mutex<()>>>,
+        Query(query): Query<CpuProfileQuery>,
+    ) -> Response {
+        let duration = query
+            .seconds
+            .map_or(DEFAULT_CPU_PROFILE_DURATION, Duration::from_secs);
+        if duration.is_zero() || duration > MAX_CPU_PROFILE_DURATION {
+            return (
+                StatusCode::BAD_REQUEST,
+                format!(
+                    "`seconds` must be between 1 and {}",
+                    MAX_CPU_PROFILE_DURATION.as_secs()
+                ),
+            )
+                .into_response();
+        }
+        let Ok(_guard) = lock.try_lock() else {
+            return (
+                StatusCode::CONFLICT,
+                "a CPU profile is already being taken".to_string(),
+            )
+                .into_response();
+        };
+
+        info!(duration_secs = duration.as_secs(), "taking CPU profile");
+        match tokio::task::spawn_blocking(move || take_cpu_profile(duration)).await {
+            Ok(Ok(profile)) => (
+                [(header::CONTENT_TYPE, "application/octet-stream")],
+                profile,
+            )
+                .into_response(),
+            Ok(Err(e)) => internal_error(&e),
+            Err(e) => internal_error(&anyhow!(e).context("CPU profiling task failed")),
+        }
+    }
+
+    fn take_cpu_profile(duration: Duration) -> anyhow::Result<Vec<u8>> {
+        let guard = pprof::ProfilerGuardBuilder::default()
+            .frequency(CPU_PROFILE_FREQUENCY)
+            .blocklist(CPU_PROFILE_BLOCKLIST)
+            .build()
+            .context("failed to start CPU profiler")?;
+        std::thread::sleep(duration);
+        let profile = guard
+            .report()
+            .build()
+            .context("failed to build CPU profile report")?
+            .pprof()
+            .context("failed to convert CPU profile report to pprof")?;
+        Ok(profile.encode_to_vec())
+    }
+
+    /// Handler of a call to `/debug/pprof/heap`.
+    #[instrument(skip_all)]
+    async fn get_heap_profile() -> Response {
+        info!("dumping heap profile");
+        match tokio::task::spawn_blocking(dump_heap_profile).await {
+            Ok(Ok(profile)) => (
+                [(header::CONTENT_TYPE, "application/octet-stream")],
+                profile,
+            )
+                .into_response(),
+            Ok(Err(e)) => internal_error(&e),
+            Err(e) => internal_error(&anyhow!(e).context("heap profiling task failed")),
+        }
+    }
+
+    /// Dumps jemalloc's heap profile to a temporary file and returns its contents.
+    fn dump_heap_profile() -> anyhow::Result<Vec<u8>> {
+        // distinguishes the files of concurrent dumps
+        static DUMP_COUNT: AtomicU64 = AtomicU64::new(0);
+        let path = std::env::temp_dir().join(format!(
+            "astria-sequencer-{}-{}.heap",
+            std::process::id(),
+            DUMP_COUNT.fetch_add(1, Ordering::Relaxed),
+        ));
+        let c_path = CString::new(path.as_os_str().as_bytes())
+            .context("heap profile path contains a nul byte")?;
+        // safety: `prof.dump` takes a pointer to a nul-terminated path, which outlives the call.
+        unsafe { tikv_jemalloc_ctl::raw::write(b"prof.dump\0", c_path.as_ptr()) }
+            .map_err(|e| anyhow!("failed to dump jemalloc heap profile: {e}"))?;
+        let profile = std::fs::read(&path)
+            .with_context(|| format!("failed to read heap profile from `{}`", path.display()));
+        let _ = std::fs::remove_file(&path);
+        profile
+    }
+
+    fn internal_error(error: &anyhow::Error) -> Response {
+        error!(
+            error = AsRef::<dyn std::error::Error>::as_ref(error),
+            "failed to take profile"
+        );
+        (StatusCode::INTERNAL_SERVER_ERROR, format!("{error:#}")).into_response()
+    }
+}
@@ -9,7 +9,33 @@ use super::commitment::GeneratedCommitments;
 /// The maximum number of bytes allowed in sequencer action data.
 const MAX_SEQUENCE_DATA_BYTES_PER_BLOCK: usize = 256_000;
 
+/// The protobuf field number of the transactions in a CometBFT block's `Data`.
+const COMETBFT_DATA_TXS_FIELD: u32 = 1;
+
+/// Returns the number of bytes a transaction of `tx_len` bytes takes up in a CometBFT block.
+///
+/// CometBFT checks the transactions returned from `prepare_proposal` against `max_tx_bytes` using
+/// their protobuf encoding as the repeated `txs` field of the block's `Data`, so every transaction
+/// is charged its field tag and length prefix in addition to its own length.
+pub(crate) fn cometbft_encoded_len(tx_len: usize) -> usize {
+    let tx_len_prefix =
+        prost::encoding::encoded_len_varint(u64::try_from(tx_len).unwrap_or(u64::MAX));
+    prost::encoding::key_len(COMETBFT_DATA_TXS_FIELD)
+        .saturating_add(tx_len_prefix)
+        .saturating_add(tx_len)
+}
+
+/// Returns the number of bytes the `commitment_count` commitments placed at the start of a block
+/// take up in a CometBFT block.
+fn commitments_cometbft_size(commitment_count: usize) -> usize {
+    cometbft_encoded_len(GeneratedCommitments::COMMITMENT_LEN).saturating_mul(commitment_count)
+}
+
 /// Struct for organizing block size constraints in prepare proposal
+///
+/// The cometBFT sizes are the encoded sizes of the block's transactions as counted by cometBFT
+/// against `max_tx_bytes` (see [`cometbft_encoded_len`]), and include the commitments placed at
+/// the start of the block.
 #[derive(serde::Serialize)]
 pub(crate) struct BlockSizeConstraints {
     max_size_sequencer: usize,
@@ -19,10 +45,13 @@ pub(crate) struct BlockSizeConstraints {
 }
 
 impl BlockSizeConstraints {
-    pub(crate) fn new(cometbft_max_size: usize) -> anyhow::Result<Self> {
-        if cometbft_max_size < GeneratedCommitments::TOTAL_SIZE {
+    /// Creates the constraints of a block starting with `commitment_count` commitments.
+    pub(crate) fn new(cometbft_max_size: usize, commitment_count: usize) -> anyhow::Result<Self> {
+        let commitments_size = commitments_cometbft_size(commitment_count);
+        if cometbft_max_size < commitments_size {
             return Err(anyhow!(
-                "cometbft_max_size must be at least GeneratedCommitments::TOTAL_SIZE"
+                "cometbft_max_size must be at least {commitments_size} bytes to fit the \
+                 commitments, but is {cometbft_max_size}"
             ));
         }
 
@@ -30,29 +59,36 @@ impl BlockSizeConstraints {
             max_size_sequencer: MAX_SEQUENCE_DATA_BYTES_PER_BLOCK,
             max_size_cometbft: cometbft_max_size,
             current_size_sequencer: 0,
-            current_size_cometbft: GeneratedCommitments::TOTAL_SIZE,
+            current_size_cometbft: commitments_size,
         })
     }
 
-    pub(crate) fn new_unlimited_cometbft() -> Self {
+    pub(crate) fn new_unlimited_cometbft(commitment_count: usize) -> Self {
         BlockSizeConstraints {
             max_size_sequencer: MAX_SEQUENCE_DATA_BYTES_PER_BLOCK,
             max_size_cometbft: usize::MAX,
             current_size_sequencer: 0,
-            current_size_cometbft: GeneratedCommitments::TOTAL_SIZE,
+            current_size_cometbft: commitments_cometbft_size(commitment_count),
         }
     }
 
+    /// Returns the number of bytes of the cometBFT block used so far, including the commitments.
+    pub(crate) fn cometbft_size(&self) -> usize {
+        self.current_size_cometbft
+    }
+
     pub(crate) fn sequencer_has_space(&self, size: usize) -> bool {
         size <= self
             .max_size_sequencer
             .saturating_sub(self.current_size_sequencer)
     }
 
-    pub(crate) fn cometbft_has_space(&self, size: usize) -> bool {
-        size <= self
-            .max_size_cometbft
-            .saturating_sub(self.current_size_cometbft)
+    /// Returns whether a transaction of `tx_len` bytes fits into the cometBFT block.
+    pub(crate) fn cometbft_has_space(&self, tx_len: usize) -> bool {
+        cometbft_encoded_len(tx_len)
+            <= self
+                .max_size_cometbft
+                .saturating_sub(self.current_size_cometbft)
     }
 
     pub(crate) fn sequencer_checked_add(&mut self, size: usize) -> anyhow::Result<()> {
@@ -68,10 +104,11 @@ impl BlockSizeConstraints {
         Ok(())
     }
 
-    pub(crate) fn cometbft_checked_add(&mut self, size: usize) -> anyhow::Result<()> {
+    /// Adds a transaction of `tx_len` bytes to the cometBFT block.
+    pub(crate) fn cometbft_checked_add(&mut self, tx_len: usize) -> anyhow::Result<()> {
         let new_size = self
             .current_size_cometbft
-            .checked_add(size)
+            .checked_add(cometbft_encoded_len(tx_len))
             .context("overflow adding to cometBFT size")?;
         ensure!(
             new_size <= self.max_size_cometbft,
@@ -84,13 +121,51 @@ impl BlockSizeConstraints {
 
 #[cfg(test)]
 mod tests {
+    use astria_core::sequencerblock::v1alpha1::{
+        BLOCK_COMMITMENT_COUNT,
+        BLOCK_COMMITMENT_COUNT_WITHOUT_FEE_SUMMARY,
+    };
+
     use super::*;
 
+    #[test]
+    fn cometbft_encoded_len_includes_tag_and_length_prefix() {
+        assert_eq!(cometbft_encoded_len(0), 2);
+        assert_eq!(cometbft_encoded_len(32), 34);
+        assert_eq!(cometbft_encoded_len(127), 129);
+        assert_eq!(cometbft_encoded_len(128), 131);
+        assert_eq!(commitments_cometbft_size(BLOCK_COMMITMENT_COUNT), 102);
+        assert_eq!(
+            commitments_cometbft_size(BLOCK_COMMITMENT_COUNT_WITHOUT_FEE_SUMMARY),
+            68
+        );
+    }
+
+    #[test]
+    fn commitments_must_fit() {
+        let size = commitments_cometbft_size(BLOCK_COMMITMENT_COUNT);
+        assert!(BlockSizeConstraints::new(size, BLOCK_COMMITMENT_COUNT).is_ok());
+        assert!(
+            BlockSizeConstraints::new(GeneratedCommitments::TOTAL_SIZE, BLOCK_COMMITMENT_COUNT)
+                .is_err()
+        );
+        assert!(
+            BlockSizeConstraints::new(
+                GeneratedCommitments::TOTAL_SIZE,
+                BLOCK_COMMITMENT_COUNT_WITHOUT_FEE_SUMMARY
+            )
+            .is_ok(),
+            "the commitments of a block not committing to its fee summary should fit"
+        );
+    }
+
     #[test]
     fn cometbft_checks() {
-        let mut block_size_constraints =
-            BlockSizeConstraints::new(10 + GeneratedCommitments::TOTAL_SIZE)
-                .expect("should be able to create block constraints with this size");
+        let mut block_size_constraints = BlockSizeConstraints::new(
+            cometbft_encoded_len(10) + commitments_cometbft_size(BLOCK_COMMITMENT_COUNT),
+            BLOCK_COMMITMENT_COUNT,
+        )
+        .expect("should be able to create block constraints with this size");
         assert!(
             block_size_constraints.cometbft_has_space(10),
             "cometBFT has space"
@@ -111,9 +186,11 @@ mod tests {
 
     #[test]
     fn sequencer_checks() {
-        let mut block_size_constraints =
-            BlockSizeConstraints::new(GeneratedCommitments::TOTAL_SIZE)
-                .expect("should be able to create block constraints with this size");
+        let mut block_size_constraints = BlockSizeConstraints::new(
+            commitments_cometbft_size(BLOCK_COMMITMENT_COUNT),
+            BLOCK_COMMITMENT_COUNT,
+        )
+        .expect("should be able to create block constraints with this size");
         assert!(
             block_size_constraints.sequencer_has_space(MAX_SEQUENCE_DATA_BYTES_PER_BLOCK),
             "sequencer has space"
@@ -6,9 +6,14 @@ use astria_core::{
         group_sequence_actions_in_signed_transaction_transactions_by_rollup_id,
         transaction::v1alpha1::SignedTransaction,
     },
-    sequencerblock::v1alpha1::block::{
-        Deposit,
-        RollupData,
+    sequencerblock::v1alpha1::{
+        block::{
+            Deposit,
+            RollupData,
+        },
+        block_commitment_count,
+        FeeSummary,
+        BLOCK_COMMITMENT_COUNT,
     },
 };
 use bytes::Bytes;
@@ -17,19 +22,31 @@ use bytes::Bytes;
 pub(crate) struct GeneratedCommitments {
     pub(crate) rollup_datas_root: [u8; 32],
     pub(crate) rollup_ids_root: [u8; 32],
+    /// Only set if the block commits to its fee summary.
+    pub(crate) fee_summary_root: Option<[u8; 32]>,
 }
 
 impl GeneratedCommitments {
-    /// The total size of the commitments in bytes.
-    pub(crate) const TOTAL_SIZE: usize = 64;
+    /// The size of a single commitment in bytes.
+    pub(crate) const COMMITMENT_LEN: usize = 32;
+    /// The total size in bytes of the commitments of a block committing to its fee summary.
+    pub(crate) const TOTAL_SIZE: usize = Self::COMMITMENT_LEN * BLOCK_COMMITMENT_COUNT;
+
+    /// The number of commitments placed at the start of the block.
+    pub(crate) fn count(&self) -> usize {
+        block_commitment_count(self.fee_summary_root.is_some())
+    }
 
     /// Converts the commitments plus external transaction data into a vector of bytes
     /// which can be used as the block's transactions.
     #[must_use]
     pub(crate) fn into_transactions(self, mut tx_data: Vec<Bytes>) -> Vec<Bytes> {
-        let mut txs = Vec::with_capacity(tx_data.len().saturating_add(2));
+        let mut txs = Vec::with_capacity(tx_data.len().saturating_add(self.count()));
         txs.push(self.rollup_datas_root.to_vec().into());
         txs.push(self.rollup_ids_root.to_vec().into());
+        if let Some(fee_summary_root) = self.fee_summary_root {
+            txs.push(fee_summary_root.to_vec().into());
+        }
         txs.append(&mut tx_data);
         txs
     }
@@ -54,9 +71,14 @@ impl GeneratedCommitments {
 /// implemented as ( `rollup_id` || root of merkle tree of the `sequence::Action`s ).
 /// This is somewhat arbitrary, but could be useful for proof of an action within the rollup datas
 /// tree.
+///
+/// Finally, if `fee_summary` is set, it commits to the fees paid in the block through the root of
+/// `fee_summary`, so that the fee flows of a block can be verified without re-executing it. It
+/// must be set if and only if the block is at or after the fee summary activation height.
 pub(crate) fn generate_rollup_datas_commitment(
     signed_txs: &[SignedTransaction],
     deposits: HashMap<RollupId, Vec<Deposit>>,
+    fee_summary: Option<&FeeSummary>,
 ) -> GeneratedCommitments {
     use prost::Message as _;
 
@@ -82,6 +104,7 @@ pub(crate) fn generate_rollup_datas_commitment(
     GeneratedCommitments {
         rollup_datas_root,
         rollup_ids_root,
+        fee_summary_root: fee_summary.map(FeeSummary::root),
     }
 }
 
@@ -137,7 +160,7 @@ mod test {
         let GeneratedCommitments {
             rollup_datas_root: commitment_0,
             ..
-        } = generate_rollup_datas_commitment(&txs, HashMap::new());
+        } = generate_rollup_datas_commitment(&txs, HashMap::new(), None);
 
         let signing_key = SigningKey::new(OsRng);
         let tx = UnsignedTransaction {
@@ -153,7 +176,7 @@ mod test {
         let GeneratedCommitments {
             rollup_datas_root: commitment_1,
             ..
-        } = generate_rollup_datas_commitment(&txs, HashMap::new());
+        } = generate_rollup_datas_commitment(&txs, HashMap::new(), None);
         assert_eq!(commitment_0, commitment_1);
     }
 
@@ -192,7 +215,7 @@ mod test {
         let GeneratedCommitments {
             rollup_datas_root: actual,
             ..
-        } = generate_rollup_datas_commitment(&txs, HashMap::new());
+        } = generate_rollup_datas_commitment(&txs, HashMap::new(), None);
 
         let expected: [u8; 32] = [
             189, 156, 127, 228, 51, 249, 64, 237, 150, 91, 219, 216, 1, 99, 135, 28, 235, 15, 249,
@@ -200,4 +223,23 @@ mod test {
         ];
         assert_eq!(expected, actual);
     }
+
+    #[test]
+    fn fee_summary_root_is_only_placed_if_set() {
+        let txs = vec![Bytes::from_static(b"tx")];
+
+        let commitments = generate_rollup_datas_commitment(&[], HashMap::new(), None);
+        assert_eq!(2, commitments.count());
+        let block_txs = commitments.into_transactions(txs.clone());
+        assert_eq!(3, block_txs.len());
+        assert_eq!(txs[0], block_txs[2]);
+
+        let fee_summary = FeeSummary::new();
+        let commitments = generate_rollup_datas_commitment(&[], HashMap::new(), Some(&fee_summary));
+        assert_eq!(3, commitments.count());
+        let block_txs = commitments.into_transactions(txs.clone());
+        assert_eq!(4, block_txs.len());
+        assert_eq!(fee_summary.root().as_slice(), &block_txs[2][..]);
+        assert_eq!(txs[0], block_txs[3]);
+    }
 }
@@ -2,10 +2,21 @@ use std::sync::OnceLock;
 
 use anyhow::{
     anyhow,
+    bail,
+    ensure,
     Context as _,
     Result,
 };
-use astria_core::generated::sequencerblock::v1alpha1::sequencer_service_server::SequencerServiceServer;
+use astria_core::{
+    generated::{
+        protocol::mempool::v1alpha1::mempool_service_server::MempoolServiceServer,
+        sequencerblock::{
+            optimistic::v1alpha1::optimistic_block_service_server::OptimisticBlockServiceServer,
+            v1alpha1::sequencer_service_server::SequencerServiceServer,
+        },
+    },
+    primitive::v1::Address,
+};
 use penumbra_tower_trace::{
     trace::request_span,
     v038::RequestExt as _,
@@ -34,12 +45,31 @@ use crate::{
     address::StateReadExt as _,
     app::App,
     config::Config,
-    grpc::sequencer::SequencerServer,
+    grpc::{
+        limits::{
+            Limits as GrpcLimits,
+            LimitsLayer,
+        },
+        mempool::MempoolServer,
+        optimistic::OptimisticBlockServer,
+        sequencer::SequencerServer,
+    },
     ibc::host_interface::AstriaHost,
-    mempool::Mempool,
+    mempool::{
+        AccountLimits,
+        Mempool,
+    },
     metrics::Metrics,
-    service,
+    service::{
+        self,
+        info::QueryLimits,
+        mempool::{
+            LoadShedder,
+            OverloadThresholds,
+        },
+    },
     state_ext::StateReadExt as _,
+    tx_archive::TxArchive,
 };
 
 pub struct Sequencer;
@@ -100,10 +130,20 @@ impl Sequencer {
                 .context("failed to initialize global address base prefix")?;
         }
 
-        let mempool = Mempool::new();
-        let app = App::new(snapshot, mempool.clone(), metrics)
+        if config.query_replica {
+            return run_query_replica(&config, &storage, signals, metrics).await;
+        }
+
+        let tx_archive = TxArchive::spawn_from_config(&config.tx_archive_sink)
+            .context("failed to start transaction archive")?;
+
+        let account_limits = account_limits_from_config(&config)
+            .context("failed to parse mempool account limits")?;
+        let mempool = Mempool::new().with_account_limits(account_limits);
+        let app = App::new(snapshot, mempool.clone(), tx_archive, metrics)
             .await
             .context("failed to initialize app")?;
+        let optimistic_block_api = OptimisticBlockServer::new(app.optimistic_block_sender());
 
         let consensus_service = tower::ServiceBuilder::new()
             .layer(request_span::layer(|req: &ConsensusRequest| {
@@ -113,9 +153,16 @@ impl Sequencer {
                 let storage = storage.clone();
                 async move { service::Consensus::new(storage, app, queue).run().await }
             }));
-        let mempool_service = service::Mempool::new(storage.clone(), mempool.clone(), metrics);
+        let load_shedder = LoadShedder::new(OverloadThresholds::from_config(&config));
+        let mempool_service = service::Mempool::new(
+            storage.clone(),
+            mempool.clone(),
+            load_shedder.clone(),
+            metrics,
+        );
         let info_service =
-            service::Info::new(storage.clone()).context("failed initializing info service")?;
+            service::Info::new(storage.clone(), QueryLimits::from_config(&config), metrics)
+                .context("failed initializing info service")?;
         let snapshot_service = service::Snapshot;
 
         let server = Server::builder()
@@ -133,7 +180,27 @@ impl Sequencer {
             .grpc_addr
             .parse()
             .context("failed to parse grpc_addr address")?;
-        let grpc_server_handle = start_grpc_server(&storage, mempool, grpc_addr, shutdown_rx);
+        let mempool_api =
+            MempoolServer::new(storage.clone(), mempool.clone(), load_shedder, metrics);
+        let grpc_server_handle = start_grpc_server(
+            &storage,
+            mempool,
+            Some(mempool_api),
+            Some(optimistic_block_api),
+            grpc_addr,
+            GrpcLimits::from_config(&config),
+            metrics,
+            shutdown_rx,
+        );
+
+        let profiling_server_handle = if config.profiling_http_listener_addr.is_empty() {
+            None
+        } else {
+            Some(
+                crate::profiling::start(&config.profiling_http_listener_addr)
+                    .context("failed to start profiling server")?,
+            )
+        };
 
         info!(config.listen_addr, "starting sequencer");
         let server_handle = tokio::spawn(async move {
@@ -167,14 +234,89 @@ impl Sequencer {
             .context("grpc server task failed")?
             .context("grpc server failed")?;
         server_handle.abort();
+        if let Some(profiling_server_handle) = profiling_server_handle {
+            profiling_server_handle.abort();
+        }
         Ok(())
     }
 }
 
+/// Serves the gRPC queries from `storage` without starting the ABCI server.
+///
+/// A query replica takes no part in consensus and never executes blocks. It serves the state held
+/// by its db, which must have been restored from a state snapshot of another node before it is
+/// started. The mempool service is not available, so transactions cannot be submitted to a
+/// replica.
+///
+/// The replica does not sync state: CometBFT state-sync snapshots are neither served nor applied
+/// by the sequencer, as the ABCI snapshot service is a stub and cnidarium has no chunked export
+/// or import of its state. The replica keeps serving the state of the db it was started with
+/// until it is restarted on a newer copy.
+async fn run_query_replica(
+    config: &Config,
+    storage: &cnidarium::Storage,
+    mut signals: SignalReceiver,
+    metrics: &'static Metrics,
+) -> Result<()> {
+    ensure!(
+        storage.latest_version() != u64::MAX,
+        "query replica requires a db holding chain state; restore it from a state snapshot before \
+         starting the replica"
+    );
+
+    let (shutdown_tx, shutdown_rx) = oneshot::channel();
+    let grpc_addr = config
+        .grpc_addr
+        .parse()
+        .context("failed to parse grpc_addr address")?;
+    let mut grpc_server_handle = start_grpc_server(
+        storage,
+        Mempool::new(),
+        None,
+        None,
+        grpc_addr,
+        GrpcLimits::from_config(config),
+        metrics,
+        shutdown_rx,
+    );
+
+    info!(
+        height = storage.latest_version(),
+        "starting sequencer as query replica"
+    );
+    select! {
+        _ = signals.stop_rx.changed() => {
+            info!("shutting down query replica");
+        }
+
+        res = &mut grpc_server_handle => {
+            res.context("grpc server task failed")?
+                .context("grpc server failed")?;
+            bail!("grpc server exited unexpectedly");
+        }
+    }
+
+    shutdown_tx
+        .send(())
+        .map_err(|()| anyhow!("failed to send shutdown signal to grpc server"))?;
+    grpc_server_handle
+        .await
+        .context("grpc server task failed")?
+        .context("grpc server failed")
+}
+
+/// Starts the gRPC server. The mempool and optimistic block services are only served if
+/// `mempool_api` and `optimistic_block_api` are set.
+// allow: the arguments are the distinct parts the server is assembled from
+#[allow(clippy::too_many_arguments)]
 fn start_grpc_server(
     storage: &cnidarium::Storage,
     mempool: Mempool,
+    mempool_api: Option<MempoolServer>,
+    optimistic_block_api: Option<OptimisticBlockServer>,
     grpc_addr: std::net::SocketAddr,
+    limits: GrpcLimits,
+    metrics: &'static Metrics,
     shutdown_rx: oneshot::Receiver<()>,
 ) -> JoinHandle<Result<(), tonic::transport::Error>> {
     use futures::TryFutureExt as _;
@@ -189,6 +331,7 @@ fn start_grpc_server(
     let ibc = penumbra_ibc::component::rpc::IbcQuery::<AstriaHost>::new(storage.clone());
     let sequencer_api = SequencerServer::new(storage.clone(), mempool);
     let cors_layer: CorsLayer = CorsLayer::permissive();
+    let max_request_bytes = limits.max_request_bytes.unwrap_or(usize::MAX);
 
     // TODO: setup HTTPS?
     let grpc_server = tonic::transport::Server::builder()
@@ -208,10 +351,29 @@ fn start_grpc_server(
         // (from Penumbra) Add permissive CORS headers, so pd's gRPC services are accessible
         // from arbitrary web contexts, including from localhost.
         .layer(cors_layer)
-        .add_service(ClientQueryServer::new(ibc.clone()))
-        .add_service(ChannelQueryServer::new(ibc.clone()))
-        .add_service(ConnectionQueryServer::new(ibc.clone()))
-        .add_service(SequencerServiceServer::new(sequencer_api));
+        // Rejects requests exceeding the configured limits. Added after the CORS layer so that
+        // rejections carry CORS headers as well.
+        .layer(LimitsLayer::new(limits, metrics))
+        .add_service(
+            ClientQueryServer::new(ibc.clone()).max_decoding_message_size(max_request_bytes),
+        )
+        .add_service(
+            ChannelQueryServer::new(ibc.clone()).max_decoding_message_size(max_request_bytes),
+        )
+        .add_service(
+            ConnectionQueryServer::new(ibc.clone()).max_decoding_message_size(max_request_bytes),
+        )
+        .add_service(
+            SequencerServiceServer::new(sequencer_api).max_decoding_message_size(max_request_bytes),
+        )
+        .add_optional_service(
+            mempool_api.map(|api| {
+                MempoolServiceServer::new(api).max_decoding_message_size(max_request_bytes)
+            }),
+        )
+        .add_optional_service(optimistic_block_api.map(|api| {
+            OptimisticBlockServiceServer::new(api).max_decoding_message_size(max_request_bytes)
+        }));
 
     info!(grpc_addr = grpc_addr.to_string(), "starting grpc server");
     tokio::task::spawn(
@@ -219,6 +381,26 @@ fn start_grpc_server(
     )
 }
 
+fn account_limits_from_config(config: &Config) -> Result<AccountLimits> {
+    let exempt_accounts = config
+        .mempool_account_limit_exempt_addresses
+        .split(',')
+        .map(str::trim)
+        .filter(|address| !address.is_empty())
+        .map(|address| {
+            address
+                .parse::<Address>()
+                .map(Address::bytes)
+                .with_context(|| format!("invalid exempt address `{address}`"))
+        })
+        .collect::<Result<_>>()?;
+    Ok(AccountLimits {
+        max_transactions: (config.mempool_max_transactions_per_account > 0)
+            .then_some(config.mempool_max_transactions_per_account),
+        exempt_accounts,
+    })
+}
+
 struct SignalReceiver {
     stop_rx: watch::Receiver<()>,
 }
@@ -214,7 +214,11 @@ impl Consensus {
 #[cfg(test)]
 mod test {
     use std::{
-        collections::HashMap,
+        collections::{
+            BTreeMap,
+            HashMap,
+        },
+        num::NonZeroU64,
         str::FromStr,
     };
 
@@ -235,6 +239,7 @@ mod test {
             TransactionParams,
             UnsignedTransaction,
         },
+        sequencerblock::v1alpha1::FeeSummary,
     };
     use bytes::Bytes;
     use prost::Message as _;
@@ -256,6 +261,7 @@ mod test {
         mempool::Mempool,
         metrics::Metrics,
         proposal::commitment::generate_rollup_datas_commitment,
+        sequence::calculate_fee_from_state,
     };
 
     fn make_unsigned_tx() -> UnsignedTransaction {
@@ -275,6 +281,18 @@ mod test {
         }
     }
 
+    /// Returns the fee summary of a block containing only the transaction returned by
+    /// [`make_unsigned_tx`].
+    async fn fee_summary_of_unsigned_tx(consensus_service: &Consensus) -> FeeSummary {
+        let fee =
+            calculate_fee_from_state(b"helloworld", &consensus_service.storage.latest_snapshot())
+                .await
+                .unwrap();
+        let mut fee_summary = FeeSummary::new();
+        fee_summary.add_distributed(get_native_asset().id(), fee);
+        fee_summary
+    }
+
     fn new_prepare_proposal_request() -> request::PrepareProposal {
         request::PrepareProposal {
             txs: vec![],
@@ -312,7 +330,9 @@ mod test {
         let txs = vec![tx_bytes.into()];
         mempool.insert(signed_tx.clone(), 0).await.unwrap();
 
-        let res = generate_rollup_datas_commitment(&vec![signed_tx], HashMap::new());
+        let fee_summary = fee_summary_of_unsigned_tx(&consensus_service).await;
+        let res =
+            generate_rollup_datas_commitment(&vec![signed_tx], HashMap::new(), Some(&fee_summary));
 
         let prepare_proposal = new_prepare_proposal_request();
         let prepare_proposal_response = consensus_service
@@ -344,7 +364,9 @@ mod test {
         let signed_tx = tx.into_signed(&signing_key);
         let tx_bytes = signed_tx.clone().into_raw().encode_to_vec();
         let txs = vec![tx_bytes.into()];
-        let res = generate_rollup_datas_commitment(&vec![signed_tx], HashMap::new());
+        let fee_summary = fee_summary_of_unsigned_tx(&consensus_service).await;
+        let res =
+            generate_rollup_datas_commitment(&vec![signed_tx], HashMap::new(), Some(&fee_summary));
         let process_proposal = new_process_proposal_request(res.into_transactions(txs));
         consensus_service
             .handle_process_proposal(process_proposal)
@@ -388,6 +410,7 @@ mod test {
         let process_proposal = new_process_proposal_request(vec![
             [99u8; 32].to_vec().into(),
             [99u8; 32].to_vec().into(),
+            [99u8; 32].to_vec().into(),
         ]);
         assert!(
             consensus_service
@@ -404,7 +427,11 @@ mod test {
     async fn prepare_proposal_empty_block() {
         let (mut consensus_service, _) = new_consensus_service(None).await;
         let txs = vec![];
-        let res = generate_rollup_datas_commitment(&txs.clone(), HashMap::new());
+        let res = generate_rollup_datas_commitment(
+            &txs.clone(),
+            HashMap::new(),
+            Some(&FeeSummary::new()),
+        );
         let prepare_proposal = new_prepare_proposal_request();
 
         let prepare_proposal_response = consensus_service
@@ -423,7 +450,7 @@ mod test {
     async fn process_proposal_ok_empty_block() {
         let (mut consensus_service, _) = new_consensus_service(None).await;
         let txs = vec![];
-        let res = generate_rollup_datas_commitment(&txs, HashMap::new());
+        let res = generate_rollup_datas_commitment(&txs, HashMap::new(), Some(&FeeSummary::new()));
         let process_proposal = new_process_proposal_request(res.into_transactions(vec![]));
         consensus_service
             .handle_process_proposal(process_proposal)
@@ -477,6 +504,7 @@ mod test {
             accounts,
             address_prefixes: AddressPrefixes {
                 base: crate::address::get_base_prefix().to_string(),
+                allowed: vec![],
             },
             authority_sudo_address: crate::address::base_prefixed([0; 20]),
             ibc_sudo_address: crate::address::base_prefixed([0; 20]),
@@ -485,6 +513,11 @@ mod test {
             ibc_params: penumbra_ibc::params::IBCParameters::default(),
             allowed_fee_assets: vec![default_native_asset()],
             fees: default_fees(),
+            ica_host: None,
+            bridge_recovery_delay: None,
+            deposit_limits: None,
+            action_activation_heights: BTreeMap::new(),
+            fee_summary_activation_height: NonZeroU64::new(1),
         }
         .try_into()
         .unwrap();
@@ -493,7 +526,9 @@ mod test {
         let snapshot = storage.latest_snapshot();
         let mempool = Mempool::new();
         let metrics = Box::leak(Box::new(Metrics::new()));
-        let mut app = App::new(snapshot, mempool.clone(), metrics).await.unwrap();
+        let mut app = App::new(snapshot, mempool.clone(), None, metrics)
+            .await
+            .unwrap();
         app.init_chain(storage.clone(), genesis_state, vec![], "test".to_string())
             .await
             .unwrap();
@@ -515,7 +550,12 @@ mod test {
         let signed_tx = tx.into_signed(&signing_key);
         let tx_bytes = signed_tx.clone().into_raw().encode_to_vec();
         let txs = vec![tx_bytes.clone().into()];
-        let res = generate_rollup_datas_commitment(&vec![signed_tx.clone()], HashMap::new());
+        let fee_summary = fee_summary_of_unsigned_tx(&consensus_service).await;
+        let res = generate_rollup_datas_commitment(
+            &vec![signed_tx.clone()],
+            HashMap::new(),
+            Some(&fee_summary),
+        );
 
         let block_data = res.into_transactions(txs.clone());
         let data_hash =
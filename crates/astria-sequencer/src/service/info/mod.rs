@@ -8,7 +8,10 @@ use std::{
 
 use anyhow::Context as _;
 use astria_core::protocol::abci::AbciErrorCode;
-use cnidarium::Storage;
+use cnidarium::{
+    StateRead,
+    Storage,
+};
 use futures::{
     Future,
     FutureExt,
@@ -31,17 +34,77 @@ use tracing::{
 };
 
 mod abci_query_router;
+mod watchdog;
+
+pub(crate) use watchdog::Limits as QueryLimits;
+use watchdog::Watchdog;
+
+use crate::{
+    address::StateReadExt as _,
+    metrics::Metrics,
+    state_ext::StateReadExt,
+};
 
-use crate::state_ext::StateReadExt;
+/// The version of the application's state machine reported to CometBFT.
+const APP_VERSION: u64 = 1;
+
+/// The features of the application that clients can rely on, reported in the `data` of an ABCI
+/// `Info` response.
+const FEATURES: &[&str] = &[
+    "bridge",
+    "ibc",
+    "fee_asset_change",
+    "mempool_grpc",
+    "ibc_packet_forwarding",
+    "ica_host",
+    "allowances",
+    "bridge_recovery",
+    "deposit_limits",
+    "fee_distribution",
+];
+
+/// The application details that are JSON encoded into the `data` of an ABCI `Info` response, so
+/// that clients can configure themselves from the chain.
+#[derive(Debug, serde::Serialize, serde::Deserialize, PartialEq)]
+struct AppInfo {
+    name: String,
+    app_version: u64,
+    /// The names of the upgrades applied to the chain, in the order they were applied.
+    applied_upgrades: Vec<String>,
+    /// The native asset of the chain. Not set before the chain is initialized.
+    native_asset: Option<String>,
+    /// The base prefix of the chain's addresses. Not set before the chain is initialized.
+    address_base_prefix: Option<String>,
+    features: Vec<String>,
+}
+
+impl AppInfo {
+    async fn from_state<S: StateRead>(state: &S) -> Self {
+        Self {
+            name: "astria_sequencer".to_string(),
+            app_version: APP_VERSION,
+            // the sequencer has no upgrade mechanism yet, so no upgrades can have been applied
+            applied_upgrades: vec![],
+            native_asset: state.get_native_asset_denom().await.ok(),
+            address_base_prefix: state.get_base_prefix().await.ok(),
+            features: FEATURES.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
 
 #[derive(Clone)]
 pub(crate) struct Info {
     storage: Storage,
     query_router: abci_query_router::Router,
+    watchdog: Watchdog,
 }
 
 impl Info {
-    pub(crate) fn new(storage: Storage) -> anyhow::Result<Self> {
+    pub(crate) fn new(
+        storage: Storage,
+        query_limits: QueryLimits,
+        metrics: &'static Metrics,
+    ) -> anyhow::Result<Self> {
         let mut query_router = abci_query_router::Router::new();
         query_router
             .insert(
@@ -70,9 +133,22 @@ impl Info {
                 crate::bridge::query::bridge_account_last_tx_hash_request,
             )
             .context("invalid path: `bridge/account_last_tx_hash/:address`")?;
+        query_router
+            .insert(
+                "bridge/account_info/:address",
+                crate::bridge::query::bridge_account_info_request,
+            )
+            .context("invalid path: `bridge/account_info/:address`")?;
+        query_router
+            .insert(
+                "bridge/rollup_accounts/:rollup_id",
+                crate::bridge::query::rollup_bridge_accounts_request,
+            )
+            .context("invalid path: `bridge/rollup_accounts/:rollup_id`")?;
         Ok(Self {
             storage,
             query_router,
+            watchdog: Watchdog::new(query_limits, metrics),
         })
     }
 
@@ -80,27 +156,23 @@ impl Info {
     async fn handle_info_request(self, request: InfoRequest) -> Result<InfoResponse, BoxError> {
         match request {
             InfoRequest::Info(_) => {
-                let block_height = self
-                    .storage
-                    .latest_snapshot()
-                    .get_block_height()
-                    .await
-                    .unwrap_or(0);
-                let app_hash = self
-                    .storage
-                    .latest_snapshot()
+                let snapshot = self.storage.latest_snapshot();
+                let block_height = snapshot.get_block_height().await.unwrap_or(0);
+                let app_hash = snapshot
                     .root_hash()
                     .await
                     .context("failed to get app hash")?;
+                let app_info = serde_json::to_string(&AppInfo::from_state(&snapshot).await)
+                    .context("failed to encode app info as JSON")?;
 
                 let response = InfoResponse::Info(response::Info {
                     version: env!("CARGO_PKG_VERSION").to_string(),
-                    app_version: 1,
+                    app_version: APP_VERSION,
                     last_block_height: u32::try_from(block_height)
                         .expect("block height must fit into u32")
                         .into(),
                     last_block_app_hash: app_hash.0.to_vec().try_into()?,
-                    data: "astria_sequencer".to_string(),
+                    data: app_info,
                 });
                 Ok(response)
             }
@@ -112,6 +184,8 @@ impl Info {
     }
 
     /// Handles `abci_query` RPCs.
+    ///
+    /// Queries that exceed the configured limits are cancelled, see [`Watchdog`].
     async fn handle_abci_query(self, request: request::Query) -> response::Query {
         let (handler, params) = match self.query_router.at(&request.path) {
             Err(err) => {
@@ -135,7 +209,10 @@ impl Info {
                 (handler, params)
             }
         };
-        handler.call(self.storage.clone(), request, params).await
+        let path = request.path.clone();
+        self.watchdog
+            .run(&path, handler.call(self.storage.clone(), request, params))
+            .await
     }
 }
 
@@ -179,20 +256,71 @@ mod test {
         InfoResponse,
     };
 
-    use super::Info;
+    use super::{
+        AppInfo,
+        Info,
+        QueryLimits,
+        APP_VERSION,
+        FEATURES,
+    };
     use crate::{
         accounts::state_ext::StateWriteExt as _,
+        address::StateWriteExt as _,
         asset::{
             get_native_asset,
             initialize_native_asset,
             state_ext::StateWriteExt,
         },
+        metrics::Metrics,
         state_ext::{
             StateReadExt,
             StateWriteExt as _,
         },
     };
 
+    fn new_info(storage: cnidarium::Storage) -> Info {
+        let metrics = Box::leak(Box::new(Metrics::new()));
+        Info::new(storage, QueryLimits::default(), metrics).unwrap()
+    }
+
+    #[tokio::test]
+    async fn info_reports_app_info() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_native_asset_denom("nria");
+        state.put_base_prefix("astria");
+        state.put_block_height(5);
+        storage.commit(state).await.unwrap();
+
+        let response = new_info((*storage).clone())
+            .handle_info_request(InfoRequest::Info(request::Info {
+                version: "test".to_string(),
+                block_version: 1,
+                p2p_version: 1,
+                abci_version: "test".to_string(),
+            }))
+            .await
+            .unwrap();
+        let info = match response {
+            InfoResponse::Info(info) => info,
+            other => panic!("expected InfoResponse::Info, got {other:?}"),
+        };
+        assert_eq!(APP_VERSION, info.app_version);
+
+        let app_info: AppInfo = serde_json::from_str(&info.data).unwrap();
+        assert_eq!(
+            AppInfo {
+                name: "astria_sequencer".to_string(),
+                app_version: APP_VERSION,
+                applied_upgrades: vec![],
+                native_asset: Some("nria".to_string()),
+                address_base_prefix: Some("astria".to_string()),
+                features: FEATURES.iter().map(ToString::to_string).collect(),
+            },
+            app_info
+        );
+    }
+
     #[tokio::test]
     async fn handle_balance_query() {
         use astria_core::{
@@ -231,7 +359,7 @@ mod test {
 
         let response = {
             let storage = (*storage).clone();
-            let info_service = Info::new(storage).unwrap();
+            let info_service = new_info(storage);
             info_service
                 .handle_info_request(info_request)
                 .await
@@ -280,7 +408,7 @@ mod test {
 
         let response = {
             let storage = (*storage).clone();
-            let info_service = Info::new(storage).unwrap();
+            let info_service = new_info(storage);
             info_service
                 .handle_info_request(info_request)
                 .await
@@ -335,7 +463,7 @@ mod test {
 
         let response = {
             let storage = (*storage).clone();
-            let info_service = Info::new(storage).unwrap();
+            let info_service = new_info(storage);
             info_service
                 .handle_info_request(info_request)
                 .await
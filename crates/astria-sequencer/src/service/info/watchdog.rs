@@ -0,0 +1,245 @@
+//! Resource limits for ABCI queries.
+//!
+//! The [`Watchdog`] runs every query on its own task and aborts it if it exceeds its deadline, or
+//! if the resident memory of the process grows by more than the configured limit while it runs.
+//! Growth is measured from the resident memory at the start of the query, so that a node whose
+//! memory is already high does not cancel every query. Results larger than the configured maximum
+//! are discarded. This keeps a pathological query, such as a prefix scan over a huge number of
+//! keys, from stalling the node.
+//!
+//! Cancelled queries fail with [`AbciErrorCode::QUERY_CANCELLED`].
+
+use std::{
+    future::Future,
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use astria_core::protocol::abci::AbciErrorCode;
+use tendermint::v0_38::abci::response;
+use tokio::{
+    select,
+    time::{
+        interval,
+        sleep,
+        MissedTickBehavior,
+    },
+};
+use tracing::warn;
+
+use crate::{
+    config::Config,
+    metrics::{
+        Metrics,
+        QueryCancelReason,
+    },
+    utils::read_resident_memory_bytes,
+};
+
+/// How often the resident memory of the process is sampled while a query runs.
+const MEMORY_SAMPLE_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The limits above which a query is cancelled.
+///
+/// A limit of `None` disables the corresponding check.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Limits {
+    pub(crate) timeout: Option<Duration>,
+    /// The growth of the resident memory of the process while a query runs.
+    pub(crate) memory_growth_bytes: Option<u64>,
+    pub(crate) max_response_bytes: Option<usize>,
+}
+
+impl Limits {
+    pub(crate) fn from_config(config: &Config) -> Self {
+        Self {
+            timeout: (config.abci_query_timeout_ms > 0)
+                .then(|| Duration::from_millis(config.abci_query_timeout_ms)),
+            memory_growth_bytes: (config.abci_query_memory_mib > 0)
+                .then(|| config.abci_query_memory_mib.saturating_mul(1024 * 1024)),
+            max_response_bytes: (config.abci_query_max_response_bytes > 0)
+                .then_some(config.abci_query_max_response_bytes),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub(super) struct Watchdog {
+    limits: Limits,
+    metrics: &'static Metrics,
+}
+
+impl Watchdog {
+    pub(super) fn new(limits: Limits, metrics: &'static Metrics) -> Self {
+        Self {
+            limits,
+            metrics,
+        }
+    }
+
+    /// Runs `query`, cancelling it if it exceeds any of the limits.
+    pub(super) async fn run<F>(self, path: &str, query: F) -> response::Query
+    where
+        F: Future<Output = response::Query> + Send + 'static,
+    {
+        let start = Instant::now();
+        let outcome = self.watch(query).await;
+        self.metrics.record_abci_query_duration(start.elapsed());
+        match outcome {
+            Ok(response) => response,
+            Err(reason) => {
+                self.metrics.increment_abci_queries_cancelled(reason);
+                warn!(
+                    path,
+                    reason = reason.as_str(),
+                    "ABCI query exceeded a limit and was cancelled"
+                );
+                response::Query {
+                    code: AbciErrorCode::QUERY_CANCELLED.into(),
+                    info: AbciErrorCode::QUERY_CANCELLED.to_string(),
+                    log: format!("query `{path}` was cancelled: {}", describe(reason)),
+                    ..response::Query::default()
+                }
+            }
+        }
+    }
+
+    async fn watch<F>(self, query: F) -> Result<response::Query, QueryCancelReason>
+    where
+        F: Future<Output = response::Query> + Send + 'static,
+    {
+        let mut task = tokio::spawn(query);
+        let deadline = sleep(self.limits.timeout.unwrap_or_default());
+        tokio::pin!(deadline);
+        let baseline_memory = self
+            .limits
+            .memory_growth_bytes
+            .and_then(|_| read_resident_memory_bytes());
+        let mut memory_samples = interval(MEMORY_SAMPLE_INTERVAL);
+        memory_samples.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        // the first tick completes immediately; the memory at that point is the baseline
+        memory_samples.tick().await;
+
+        let cancelled = loop {
+            select! {
+                joined = &mut task => {
+                    let response = match joined {
+                        Ok(response) => response,
+                        Err(error) => response::Query {
+                            code: AbciErrorCode::INTERNAL_ERROR.into(),
+                            info: AbciErrorCode::INTERNAL_ERROR.to_string(),
+                            log: format!("query task failed: {error}"),
+                            ..response::Query::default()
+                        },
+                    };
+                    return match self.limits.max_response_bytes {
+                        Some(max) if response.value.len() > max => {
+                            Err(QueryCancelReason::ResponseTooLarge)
+                        }
+                        _ => Ok(response),
+                    };
+                }
+
+                () = &mut deadline, if self.limits.timeout.is_some() => {
+                    break QueryCancelReason::Deadline;
+                }
+
+                _ = memory_samples.tick(), if baseline_memory.is_some() => {
+                    let exceeded = read_resident_memory_bytes()
+                        .zip(baseline_memory)
+                        .zip(self.limits.memory_growth_bytes)
+                        .is_some_and(|((resident, baseline), limit)| {
+                            resident.saturating_sub(baseline) > limit
+                        });
+                    if exceeded {
+                        break QueryCancelReason::Memory;
+                    }
+                }
+            }
+        };
+        task.abort();
+        Err(cancelled)
+    }
+}
+
+fn describe(reason: QueryCancelReason) -> &'static str {
+    match reason {
+        QueryCancelReason::Deadline => "it did not complete before its deadline",
+        QueryCancelReason::Memory => "the resident memory of the node grew by more than its limit",
+        QueryCancelReason::ResponseTooLarge => "its result exceeded the maximum response size",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn watchdog(limits: Limits) -> Watchdog {
+        Watchdog::new(limits, Box::leak(Box::new(Metrics::new())))
+    }
+
+    fn response_of_len(len: usize) -> response::Query {
+        response::Query {
+            value: vec![0; len].into(),
+            ..response::Query::default()
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn query_exceeding_deadline_is_cancelled() {
+        let watchdog = watchdog(Limits {
+            timeout: Some(Duration::from_secs(1)),
+            ..Limits::default()
+        });
+        let response = watchdog
+            .run("slow", async {
+                sleep(Duration::from_secs(2)).await;
+                response_of_len(0)
+            })
+            .await;
+        assert_eq!(response.code, AbciErrorCode::QUERY_CANCELLED.into());
+
+        let response = watchdog
+            .run("fast", async {
+                sleep(Duration::from_millis(500)).await;
+                response_of_len(0)
+            })
+            .await;
+        assert!(response.code.is_ok());
+    }
+
+    #[tokio::test]
+    async fn oversized_response_is_rejected() {
+        let watchdog = watchdog(Limits {
+            max_response_bytes: Some(4),
+            ..Limits::default()
+        });
+        let response = watchdog.run("large", async { response_of_len(5) }).await;
+        assert_eq!(response.code, AbciErrorCode::QUERY_CANCELLED.into());
+
+        let response = watchdog.run("small", async { response_of_len(4) }).await;
+        assert!(response.code.is_ok());
+        assert_eq!(response.value.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn query_is_cancelled_if_memory_grows_beyond_limit() {
+        if read_resident_memory_bytes().is_none() {
+            return;
+        }
+        let watchdog = watchdog(Limits {
+            memory_growth_bytes: Some(16 * 1024 * 1024),
+            ..Limits::default()
+        });
+        let response = watchdog
+            .run("hungry", async {
+                let allocation = vec![1_u8; 64 * 1024 * 1024];
+                std::future::pending::<()>().await;
+                response_of_len(allocation.len())
+            })
+            .await;
+        assert_eq!(response.code, AbciErrorCode::QUERY_CANCELLED.into());
+    }
+}
@@ -1,9 +1,12 @@
+mod overload;
+
 use std::{
     pin::Pin,
     task::{
         Context,
         Poll,
     },
+    time::Instant,
 };
 
 use astria_core::{
@@ -18,12 +21,19 @@ use futures::{
     Future,
     FutureExt,
 };
+pub(crate) use overload::{
+    LoadShedder,
+    Thresholds as OverloadThresholds,
+};
 use prost::Message as _;
-use tendermint::v0_38::abci::{
-    request,
-    response,
-    MempoolRequest,
-    MempoolResponse,
+use tendermint::{
+    abci::request::CheckTxKind,
+    v0_38::abci::{
+        request,
+        response,
+        MempoolRequest,
+        MempoolResponse,
+    },
 };
 use tower::Service;
 use tower_abci::BoxError;
@@ -32,6 +42,7 @@ use tracing::Instrument as _;
 use crate::{
     accounts::state_ext::StateReadExt,
     mempool::{
+        AccountLimitReached,
         Mempool as AppMempool,
         RemovalReason,
     },
@@ -49,14 +60,21 @@ const MAX_TX_SIZE: usize = 256_000; // 256 KB
 pub(crate) struct Mempool {
     storage: Storage,
     inner: AppMempool,
+    load_shedder: LoadShedder,
     metrics: &'static Metrics,
 }
 
 impl Mempool {
-    pub(crate) fn new(storage: Storage, mempool: AppMempool, metrics: &'static Metrics) -> Self {
+    pub(crate) fn new(
+        storage: Storage,
+        mempool: AppMempool,
+        load_shedder: LoadShedder,
+        metrics: &'static Metrics,
+    ) -> Self {
         Self {
             storage,
             inner: mempool,
+            load_shedder,
             metrics,
         }
     }
@@ -76,13 +94,23 @@ impl Service<MempoolRequest> for Mempool {
         let span = req.create_span();
         let storage = self.storage.clone();
         let mut mempool = self.inner.clone();
+        let load_shedder = self.load_shedder.clone();
         let metrics = self.metrics;
         async move {
+            let received_at = Instant::now();
             let rsp = match req {
                 MempoolRequest::CheckTx(req) => MempoolResponse::CheckTx(
-                    handle_check_tx(req, storage.latest_snapshot(), &mut mempool, metrics).await,
+                    handle_check_tx(
+                        req,
+                        storage.latest_snapshot(),
+                        &mut mempool,
+                        &load_shedder,
+                        metrics,
+                    )
+                    .await,
                 ),
             };
+            load_shedder.record_check_tx_latency(received_at.elapsed());
             Ok(rsp)
         }
         .instrument(span)
@@ -95,12 +123,16 @@ impl Service<MempoolRequest> for Mempool {
 /// Performs stateless checks (decoding and signature check),
 /// as well as stateful checks (nonce and balance checks).
 ///
+/// New transactions are rejected early if the node is overloaded and they do not pay the minimum
+/// fee required by `load_shedder`.
+///
 /// If the tx passes all checks, status code 0 is returned.
 #[allow(clippy::too_many_lines)]
-async fn handle_check_tx<S: StateReadExt + 'static>(
+pub(crate) async fn handle_check_tx<S: StateReadExt + 'static>(
     req: request::CheckTx,
     state: S,
     mempool: &mut AppMempool,
+    load_shedder: &LoadShedder,
     metrics: &'static Metrics,
 ) -> response::CheckTx {
     use sha2::Digest as _;
@@ -108,7 +140,8 @@ async fn handle_check_tx<S: StateReadExt + 'static>(
     let tx_hash = sha2::Sha256::digest(&req.tx).into();
 
     let request::CheckTx {
-        tx, ..
+        tx,
+        kind,
     } = req;
     if tx.len() > MAX_TX_SIZE {
         mempool.remove(tx_hash).await;
@@ -151,7 +184,21 @@ async fn handle_check_tx<S: StateReadExt + 'static>(
         }
     };
 
-    if let Err(e) = transaction::check_stateless(&signed_tx).await {
+    // only shed new transactions; rechecks of transactions already in the mempool are cheap
+    // compared to admitting new ones, and the mempool is drained by block production.
+    if kind == CheckTxKind::New {
+        if let Some(reason) = load_shedder.shed(&signed_tx, &state, mempool).await {
+            metrics.increment_check_tx_rejected_overloaded();
+            return response::CheckTx {
+                code: AbciErrorCode::NODE_OVERLOADED.into(),
+                info: AbciErrorCode::NODE_OVERLOADED.to_string(),
+                log: reason,
+                ..response::CheckTx::default()
+            };
+        }
+    }
+
+    if let Err(e) = transaction::check_stateless(&signed_tx, metrics).await {
         mempool.remove(tx_hash).await;
         metrics.increment_check_tx_removed_failed_stateless();
         return response::CheckTx {
@@ -162,6 +209,16 @@ async fn handle_check_tx<S: StateReadExt + 'static>(
         };
     };
 
+    if let Err(e) = transaction::check_actions_activated_mempool(&signed_tx, &state).await {
+        mempool.remove(tx_hash).await;
+        return response::CheckTx {
+            code: AbciErrorCode::ACTION_NOT_ACTIVATED.into(),
+            info: "transaction contains an action that is not yet activated".into(),
+            log: e.to_string(),
+            ..response::CheckTx::default()
+        };
+    }
+
     if let Err(e) = transaction::check_nonce_mempool(&signed_tx, &state).await {
         mempool.remove(tx_hash).await;
         metrics.increment_check_tx_removed_stale_nonce();
@@ -227,12 +284,23 @@ async fn handle_check_tx<S: StateReadExt + 'static>(
         .await
         .expect("can fetch account nonce");
 
-    mempool
-        .insert(signed_tx, current_account_nonce)
-        .await
-        .expect(
-            "tx nonce is greater than or equal to current account nonce; this was checked in \
-             check_nonce_mempool",
-        );
+    let inserted = mempool.insert(signed_tx, current_account_nonce).await;
+    if let Some(e) = inserted
+        .as_ref()
+        .err()
+        .and_then(|e| e.downcast_ref::<AccountLimitReached>())
+    {
+        metrics.increment_check_tx_rejected_account_limit();
+        return response::CheckTx {
+            code: AbciErrorCode::ACCOUNT_TRANSACTION_LIMIT_REACHED.into(),
+            info: AbciErrorCode::ACCOUNT_TRANSACTION_LIMIT_REACHED.to_string(),
+            log: e.to_string(),
+            ..response::CheckTx::default()
+        };
+    }
+    inserted.expect(
+        "tx nonce is greater than or equal to current account nonce; this was checked in \
+         check_nonce_mempool",
+    );
     response::CheckTx::default()
 }
@@ -0,0 +1,310 @@
+//! Load shedding for `CheckTx`.
+//!
+//! The [`LoadShedder`] watches the size of the app-side mempool, the latency of `CheckTx`
+//! requests and the resident memory of the process. While any of them is above its configured
+//! threshold the node is considered overloaded, and new transactions whose total fee is below the
+//! configured minimum are rejected before their stateful checks run. This keeps the node
+//! responsive during spam events while still admitting transactions that pay for priority.
+//!
+//! Fees are summed across all fee assets of a transaction without conversion.
+
+use std::{
+    fmt,
+    sync::{
+        atomic::{
+            AtomicU64,
+            Ordering,
+        },
+        Arc,
+        Mutex,
+    },
+    time::{
+        Duration,
+        Instant,
+    },
+};
+
+use astria_core::protocol::transaction::v1alpha1::SignedTransaction;
+
+use crate::{
+    accounts::state_ext::StateReadExt,
+    config::Config,
+    mempool::Mempool as AppMempool,
+    transaction,
+    utils::read_resident_memory_bytes,
+};
+
+/// The weight of a new sample in the moving average of the `CheckTx` latency.
+const LATENCY_SMOOTHING_FACTOR: f64 = 0.1;
+
+/// How long a sample of the process's resident memory is reused before it is taken again.
+const MEMORY_SAMPLE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The thresholds above which the node is considered overloaded.
+///
+/// A threshold of `None` disables the corresponding check.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Thresholds {
+    pub(crate) mempool_transactions: Option<usize>,
+    pub(crate) check_tx_latency: Option<Duration>,
+    pub(crate) resident_memory_bytes: Option<u64>,
+    /// The minimum total fee a new transaction must pay to be admitted while overloaded.
+    pub(crate) min_fee: u128,
+}
+
+impl Thresholds {
+    pub(crate) fn from_config(config: &Config) -> Self {
+        Self {
+            mempool_transactions: (config.overload_mempool_transactions > 0)
+                .then_some(config.overload_mempool_transactions),
+            check_tx_latency: (config.overload_check_tx_latency_ms > 0)
+                .then(|| Duration::from_millis(config.overload_check_tx_latency_ms)),
+            resident_memory_bytes: (config.overload_memory_mib > 0)
+                .then(|| config.overload_memory_mib.saturating_mul(1024 * 1024)),
+            min_fee: config.overload_min_fee,
+        }
+    }
+
+    fn is_disabled(&self) -> bool {
+        self.mempool_transactions.is_none()
+            && self.check_tx_latency.is_none()
+            && self.resident_memory_bytes.is_none()
+    }
+}
+
+/// The resource whose usage exceeded its threshold.
+#[derive(Debug)]
+pub(crate) enum Pressure {
+    MempoolSize {
+        transactions: usize,
+        threshold: usize,
+    },
+    CheckTxLatency {
+        latency: Duration,
+        threshold: Duration,
+    },
+    Memory {
+        resident_bytes: u64,
+        threshold: u64,
+    },
+}
+
+impl fmt::Display for Pressure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MempoolSize {
+                transactions,
+                threshold,
+            } => write!(
+                f,
+                "mempool holds {transactions} transactions, threshold is {threshold}"
+            ),
+            Self::CheckTxLatency {
+                latency,
+                threshold,
+            } => write!(
+                f,
+                "average CheckTx latency is {}ms, threshold is {}ms",
+                latency.as_millis(),
+                threshold.as_millis()
+            ),
+            Self::Memory {
+                resident_bytes,
+                threshold,
+            } => write!(
+                f,
+                "resident memory is {resident_bytes} bytes, threshold is {threshold} bytes"
+            ),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub(crate) struct LoadShedder {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    thresholds: Thresholds,
+    /// The exponential moving average of the `CheckTx` latency, in microseconds.
+    check_tx_latency_micros: AtomicU64,
+    memory: Mutex<Option<MemorySample>>,
+}
+
+#[derive(Clone, Copy)]
+struct MemorySample {
+    taken_at: Instant,
+    resident_bytes: Option<u64>,
+}
+
+impl LoadShedder {
+    pub(crate) fn new(thresholds: Thresholds) -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                thresholds,
+                check_tx_latency_micros: AtomicU64::new(0),
+                memory: Mutex::new(None),
+            }),
+        }
+    }
+
+    /// Returns a load shedder that never considers the node overloaded.
+    pub(crate) fn disabled() -> Self {
+        Self::new(Thresholds::default())
+    }
+
+    /// Adds the latency of a handled `CheckTx` request to the moving average.
+    pub(crate) fn record_check_tx_latency(&self, latency: Duration) {
+        let sample = u64::try_from(latency.as_micros()).unwrap_or(u64::MAX);
+        let previous = self.inner.check_tx_latency_micros.load(Ordering::Relaxed);
+        // allow: precision loss is irrelevant for a smoothed latency in microseconds, and the
+        // result is non-negative and bounded by the larger of the two inputs.
+        #[allow(
+            clippy::cast_precision_loss,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss
+        )]
+        let average = if previous == 0 {
+            sample
+        } else {
+            (previous as f64)
+                .mul_add(
+                    1.0 - LATENCY_SMOOTHING_FACTOR,
+                    sample as f64 * LATENCY_SMOOTHING_FACTOR,
+                )
+                .round() as u64
+        };
+        self.inner
+            .check_tx_latency_micros
+            .store(average, Ordering::Relaxed);
+    }
+
+    /// Returns the first resource found to be above its threshold, or `None` if the node is not
+    /// overloaded.
+    pub(crate) async fn pressure(&self, mempool: &AppMempool) -> Option<Pressure> {
+        let thresholds = &self.inner.thresholds;
+        if let Some(threshold) = thresholds.mempool_transactions {
+            let transactions = mempool.len().await;
+            if transactions >= threshold {
+                return Some(Pressure::MempoolSize {
+                    transactions,
+                    threshold,
+                });
+            }
+        }
+        if let Some(threshold) = thresholds.check_tx_latency {
+            let latency =
+                Duration::from_micros(self.inner.check_tx_latency_micros.load(Ordering::Relaxed));
+            if latency >= threshold {
+                return Some(Pressure::CheckTxLatency {
+                    latency,
+                    threshold,
+                });
+            }
+        }
+        if let Some(threshold) = thresholds.resident_memory_bytes {
+            if let Some(resident_bytes) = self.resident_memory_bytes() {
+                if resident_bytes >= threshold {
+                    return Some(Pressure::Memory {
+                        resident_bytes,
+                        threshold,
+                    });
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the reason for rejecting `tx` if the node is overloaded and the transaction does
+    /// not pay at least the minimum fee, or `None` if it should be checked as usual.
+    pub(crate) async fn shed<S: StateReadExt + 'static>(
+        &self,
+        tx: &SignedTransaction,
+        state: &S,
+        mempool: &AppMempool,
+    ) -> Option<String> {
+        if self.inner.thresholds.is_disabled() {
+            return None;
+        }
+        let pressure = self.pressure(mempool).await?;
+        let min_fee = self.inner.thresholds.min_fee;
+        let fee = match transaction::total_fees(tx.unsigned_transaction(), state).await {
+            Ok(fee) => fee,
+            // leave the transaction to the regular checks, which will report the error
+            Err(_) => return None,
+        };
+        if fee >= min_fee {
+            return None;
+        }
+        Some(format!(
+            "node is overloaded ({pressure}); transaction pays a total fee of {fee}, but at least \
+             {min_fee} is required until the load decreases"
+        ))
+    }
+
+    fn resident_memory_bytes(&self) -> Option<u64> {
+        let mut sample = self
+            .inner
+            .memory
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        match *sample {
+            Some(sample) if sample.taken_at.elapsed() < MEMORY_SAMPLE_INTERVAL => {
+                sample.resident_bytes
+            }
+            _ => {
+                let resident_bytes = read_resident_memory_bytes();
+                *sample = Some(MemorySample {
+                    taken_at: Instant::now(),
+                    resident_bytes,
+                });
+                resident_bytes
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::app::test_utils::get_mock_tx;
+
+    #[tokio::test]
+    async fn pressure_is_reported_for_full_mempool() {
+        let mempool = AppMempool::new();
+        let shedder = LoadShedder::new(Thresholds {
+            mempool_transactions: Some(2),
+            ..Thresholds::default()
+        });
+        mempool.insert(get_mock_tx(0), 0).await.unwrap();
+        assert!(shedder.pressure(&mempool).await.is_none());
+
+        mempool.insert(get_mock_tx(1), 0).await.unwrap();
+        assert!(matches!(
+            shedder.pressure(&mempool).await,
+            Some(Pressure::MempoolSize {
+                transactions: 2,
+                threshold: 2,
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn pressure_is_reported_for_slow_check_tx() {
+        let mempool = AppMempool::new();
+        let shedder = LoadShedder::new(Thresholds {
+            check_tx_latency: Some(Duration::from_millis(100)),
+            ..Thresholds::default()
+        });
+        shedder.record_check_tx_latency(Duration::from_millis(10));
+        assert!(shedder.pressure(&mempool).await.is_none());
+
+        for _ in 0..100 {
+            shedder.record_check_tx_latency(Duration::from_millis(500));
+        }
+        assert!(matches!(
+            shedder.pressure(&mempool).await,
+            Some(Pressure::CheckTxLatency { .. })
+        ));
+    }
+}
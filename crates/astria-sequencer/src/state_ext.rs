@@ -3,8 +3,15 @@ use anyhow::{
     Context as _,
     Result,
 };
-use astria_core::primitive::v1::asset;
+use astria_core::{
+    primitive::v1::asset,
+    sequencerblock::v1alpha1::FeeSummary,
+};
 use async_trait::async_trait;
+use borsh::{
+    BorshDeserialize,
+    BorshSerialize,
+};
 use cnidarium::{
     StateRead,
     StateWrite,
@@ -13,10 +20,56 @@ use futures::StreamExt as _;
 use tendermint::Time;
 use tracing::instrument;
 
+use crate::fee_distribution::FeeDistribution;
+
 const NATIVE_ASSET_KEY: &[u8] = b"nativeasset";
 const REVISION_NUMBER_KEY: &str = "revision_number";
 const BLOCK_FEES_PREFIX: &str = "block_fees/";
+const BURNED_FEES_PREFIX: &str = "burned_fees/";
 const FEE_ASSET_PREFIX: &str = "fee_asset/";
+const ACTION_ACTIVATION_PREFIX: &str = "action_activation/";
+const FEE_DISTRIBUTION_KEY: &str = "fee_distribution";
+const FEE_SUMMARY_ACTIVATION_KEY: &str = "fee_summary_activation";
+
+/// The storage representation of a [`FeeDistribution`].
+#[derive(BorshSerialize, BorshDeserialize, Debug)]
+struct StoredFeeDistribution {
+    proposer_share: u32,
+    treasury_share: u32,
+    burn_share: u32,
+    treasury_address: Option<[u8; 20]>,
+}
+
+impl From<&FeeDistribution> for StoredFeeDistribution {
+    fn from(distribution: &FeeDistribution) -> Self {
+        Self {
+            proposer_share: distribution.proposer_share(),
+            treasury_share: distribution.treasury_share(),
+            burn_share: distribution.burn_share(),
+            treasury_address: distribution
+                .treasury_address()
+                .map(|address| address.bytes()),
+        }
+    }
+}
+
+impl TryFrom<StoredFeeDistribution> for FeeDistribution {
+    type Error = anyhow::Error;
+
+    fn try_from(stored: StoredFeeDistribution) -> Result<Self> {
+        let treasury_address = stored
+            .treasury_address
+            .map(|bytes| crate::address::try_base_prefixed(&bytes))
+            .transpose()
+            .context("invalid treasury address bytes")?;
+        Self::try_new(
+            stored.proposer_share,
+            stored.treasury_share,
+            stored.burn_share,
+            treasury_address,
+        )
+    }
+}
 
 fn storage_version_by_height_key(height: u64) -> Vec<u8> {
     format!("storage_version/{height}").into()
@@ -26,10 +79,18 @@ fn block_fees_key(asset: asset::Id) -> Vec<u8> {
     format!("{BLOCK_FEES_PREFIX}{}", crate::utils::Hex(asset.as_ref())).into()
 }
 
+fn burned_fees_key(asset: asset::Id) -> Vec<u8> {
+    format!("{BURNED_FEES_PREFIX}{}", crate::utils::Hex(asset.as_ref())).into()
+}
+
 fn fee_asset_key(asset: asset::Id) -> Vec<u8> {
     format!("{FEE_ASSET_PREFIX}{}", crate::utils::Hex(asset.as_ref())).into()
 }
 
+fn action_activation_key(action: &str) -> String {
+    format!("{ACTION_ACTIVATION_PREFIX}{action}")
+}
+
 #[async_trait]
 pub(crate) trait StateReadExt: StateRead {
     #[instrument(skip(self))]
@@ -128,28 +189,49 @@ pub(crate) trait StateReadExt: StateRead {
 
     #[instrument(skip(self))]
     async fn get_block_fees(&self) -> Result<Vec<(asset::Id, u128)>> {
-        let mut fees: Vec<(asset::Id, u128)> = Vec::new();
-
-        let mut stream =
-            std::pin::pin!(self.nonverifiable_prefix_raw(BLOCK_FEES_PREFIX.as_bytes()));
-        while let Some(Ok((key, value))) = stream.next().await {
-            // if the key isn't of the form `block_fees/{asset_id}`, then we have a bug
-            // in `put_block_fees`
-            let id_str = key
-                .strip_prefix(BLOCK_FEES_PREFIX.as_bytes())
-                .expect("prefix must always be present");
-            let id =
-                asset::Id::try_from_slice(&hex::decode(id_str).expect("key must be hex encoded"))
-                    .context("failed to parse asset id from hex key")?;
+        get_fees_by_prefix(self, BLOCK_FEES_PREFIX).await
+    }
 
-            let Ok(bytes): Result<[u8; 16], _> = value.try_into() else {
-                bail!("failed turning raw block fees bytes into u128; not 16 bytes?");
-            };
+    /// Returns the fees burned in the current block, i.e. those that were deducted from the
+    /// payer without being added to the block fees.
+    #[instrument(skip(self))]
+    async fn get_burned_fees(&self) -> Result<Vec<(asset::Id, u128)>> {
+        get_fees_by_prefix(self, BURNED_FEES_PREFIX).await
+    }
 
-            fees.push((id, u128::from_be_bytes(bytes)));
+    /// Returns the fees distributed and burned in the current block.
+    ///
+    /// The block fees are split according to the fee distribution, with the burn share counted
+    /// as burned.
+    #[instrument(skip(self))]
+    async fn get_fee_summary(&self) -> Result<FeeSummary> {
+        let fee_distribution = self
+            .get_fee_distribution()
+            .await
+            .context("failed to get fee distribution")?;
+        let mut summary = FeeSummary::new();
+        for (asset, amount) in self
+            .get_block_fees()
+            .await
+            .context("failed to get block fees")?
+        {
+            // without a fee distribution all fees are paid to the sudo address
+            let burned = fee_distribution
+                .as_ref()
+                .map_or(0, |distribution| distribution.split(amount).burned);
+            summary.add_distributed(asset, amount - burned);
+            if burned > 0 {
+                summary.add_burned(asset, burned);
+            }
         }
-
-        Ok(fees)
+        for (asset, amount) in self
+            .get_burned_fees()
+            .await
+            .context("failed to get burned fees")?
+        {
+            summary.add_burned(asset, amount);
+        }
+        Ok(summary)
     }
 
     #[instrument(skip(self))]
@@ -181,10 +263,97 @@ pub(crate) trait StateReadExt: StateRead {
 
         Ok(assets)
     }
+
+    /// Returns the fee distribution set by the last `FeeDistributionChangeAction`, or `None` if
+    /// none was executed yet.
+    #[instrument(skip(self))]
+    async fn get_fee_distribution(&self) -> Result<Option<FeeDistribution>> {
+        let Some(bytes) = self
+            .get_raw(FEE_DISTRIBUTION_KEY)
+            .await
+            .context("failed to read raw fee distribution from state")?
+        else {
+            return Ok(None);
+        };
+        let stored = StoredFeeDistribution::try_from_slice(&bytes)
+            .context("invalid fee distribution bytes")?;
+        let distribution = FeeDistribution::try_from(stored)?;
+        Ok(Some(distribution))
+    }
+
+    /// Returns the height at which the action named `action` becomes available, or `None` if it
+    /// is available from genesis.
+    #[instrument(skip(self))]
+    async fn get_action_activation_height(&self, action: &str) -> Result<Option<u64>> {
+        let Some(bytes) = self
+            .get_raw(&action_activation_key(action))
+            .await
+            .context("failed to read raw action activation height from state")?
+        else {
+            return Ok(None);
+        };
+        let Ok(bytes): Result<[u8; 8], _> = bytes.try_into() else {
+            bail!("failed turning raw action activation height bytes into u64; not 8 bytes?");
+        };
+        Ok(Some(u64::from_be_bytes(bytes)))
+    }
+
+    /// Returns the height from which blocks commit to their fee summary, or `None` if they never
+    /// do.
+    #[instrument(skip(self))]
+    async fn get_fee_summary_activation_height(&self) -> Result<Option<u64>> {
+        let Some(bytes) = self
+            .get_raw(FEE_SUMMARY_ACTIVATION_KEY)
+            .await
+            .context("failed to read raw fee summary activation height from state")?
+        else {
+            return Ok(None);
+        };
+        let Ok(bytes): Result<[u8; 8], _> = bytes.try_into() else {
+            bail!("failed turning raw fee summary activation height bytes into u64; not 8 bytes?");
+        };
+        Ok(Some(u64::from_be_bytes(bytes)))
+    }
+
+    /// Returns whether the block at `height` commits to its fee summary.
+    #[instrument(skip(self))]
+    async fn commits_to_fee_summary(&self, height: u64) -> Result<bool> {
+        let activation_height = self
+            .get_fee_summary_activation_height()
+            .await
+            .context("failed to get fee summary activation height")?;
+        Ok(activation_height.is_some_and(|activation_height| height >= activation_height))
+    }
 }
 
 impl<T: StateRead> StateReadExt for T {}
 
+async fn get_fees_by_prefix<S: StateRead + ?Sized>(
+    state: &S,
+    prefix: &str,
+) -> Result<Vec<(asset::Id, u128)>> {
+    let mut fees: Vec<(asset::Id, u128)> = Vec::new();
+
+    let mut stream = std::pin::pin!(state.nonverifiable_prefix_raw(prefix.as_bytes()));
+    while let Some(Ok((key, value))) = stream.next().await {
+        // if the key isn't of the form `{prefix}{asset_id}`, then we have a bug
+        // in `get_and_increase_block_fees` or `get_and_increase_burned_fees`
+        let id_str = key
+            .strip_prefix(prefix.as_bytes())
+            .expect("prefix must always be present");
+        let id = asset::Id::try_from_slice(&hex::decode(id_str).expect("key must be hex encoded"))
+            .context("failed to parse asset id from hex key")?;
+
+        let Ok(bytes): Result<[u8; 16], _> = value.try_into() else {
+            bail!("failed turning raw fees bytes into u128; not 16 bytes?");
+        };
+
+        fees.push((id, u128::from_be_bytes(bytes)));
+    }
+
+    Ok(fees)
+}
+
 #[async_trait]
 pub(crate) trait StateWriteExt: StateWrite {
     #[instrument(skip(self))]
@@ -259,6 +428,43 @@ pub(crate) trait StateWriteExt: StateWrite {
         }
     }
 
+    /// Adds `amount` to the fees burned in the current block for `asset`.
+    ///
+    /// Burned fees are only recorded for the block's fee summary; the caller is responsible
+    /// for deducting them from the payer.
+    #[instrument(skip(self))]
+    async fn get_and_increase_burned_fees(&mut self, asset: asset::Id, amount: u128) -> Result<()> {
+        let current_amount = self
+            .nonverifiable_get_raw(&burned_fees_key(asset))
+            .await
+            .context("failed to read raw burned fees from state")?
+            .map(|bytes| {
+                let Ok(bytes): Result<[u8; 16], _> = bytes.try_into() else {
+                    // this shouldn't happen
+                    bail!("failed turning raw burned fees bytes into u128; not 16 bytes?");
+                };
+                Ok(u128::from_be_bytes(bytes))
+            })
+            .transpose()?
+            .unwrap_or_default();
+
+        let new_amount = current_amount
+            .checked_add(amount)
+            .context("burned fees overflowed u128")?;
+
+        self.nonverifiable_put_raw(burned_fees_key(asset), new_amount.to_be_bytes().to_vec());
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn clear_burned_fees(&mut self) {
+        let mut stream =
+            std::pin::pin!(self.nonverifiable_prefix_raw(BURNED_FEES_PREFIX.as_bytes()));
+        while let Some(Ok((key, _))) = stream.next().await {
+            self.nonverifiable_delete(key);
+        }
+    }
+
     #[instrument(skip(self))]
     fn put_allowed_fee_asset(&mut self, asset: asset::Id) {
         self.nonverifiable_put_raw(fee_asset_key(asset), vec![]);
@@ -268,6 +474,36 @@ pub(crate) trait StateWriteExt: StateWrite {
     fn delete_allowed_fee_asset(&mut self, asset: asset::Id) {
         self.nonverifiable_delete(fee_asset_key(asset));
     }
+
+    #[instrument(skip(self))]
+    fn put_fee_distribution(&mut self, distribution: &FeeDistribution) {
+        self.put_raw(
+            FEE_DISTRIBUTION_KEY.to_string(),
+            borsh::to_vec(&StoredFeeDistribution::from(distribution))
+                .expect("failed to serialize fee distribution"),
+        );
+    }
+
+    /// Sets the height at which the action named `action` becomes available.
+    ///
+    /// Activation heights are part of the verifiable state, as they determine which
+    /// transactions are valid.
+    #[instrument(skip(self))]
+    fn put_action_activation_height(&mut self, action: &str, height: u64) {
+        self.put_raw(action_activation_key(action), height.to_be_bytes().to_vec());
+    }
+
+    /// Sets the height from which blocks commit to their fee summary.
+    ///
+    /// The activation height is part of the verifiable state, as it determines which block
+    /// proposals are valid.
+    #[instrument(skip(self))]
+    fn put_fee_summary_activation_height(&mut self, height: u64) {
+        self.put_raw(
+            FEE_SUMMARY_ACTIVATION_KEY.to_string(),
+            height.to_be_bytes().to_vec(),
+        );
+    }
 }
 
 impl<T: StateWrite> StateWriteExt for T {}
@@ -299,6 +535,7 @@ mod test {
         StateReadExt as _,
         StateWriteExt as _,
     };
+    use crate::fee_distribution::FeeDistribution;
 
     #[test]
     fn revision_number_from_chain_id_regex() {
@@ -434,6 +671,63 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn action_activation_height() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(
+            state
+                .get_action_activation_height("transfer")
+                .await
+                .unwrap(),
+            None,
+            "actions without activation height should be available from genesis"
+        );
+
+        state.put_action_activation_height("bridge_recovery", 42);
+        assert_eq!(
+            state
+                .get_action_activation_height("bridge_recovery")
+                .await
+                .unwrap(),
+            Some(42),
+        );
+        assert_eq!(
+            state
+                .get_action_activation_height("transfer")
+                .await
+                .unwrap(),
+            None,
+        );
+    }
+
+    #[tokio::test]
+    async fn fee_summary_activation_height() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(
+            state.get_fee_summary_activation_height().await.unwrap(),
+            None,
+        );
+        assert!(
+            !state.commits_to_fee_summary(1).await.unwrap(),
+            "blocks should not commit to their fee summary without activation height"
+        );
+
+        state.put_fee_summary_activation_height(10);
+        assert_eq!(
+            state.get_fee_summary_activation_height().await.unwrap(),
+            Some(10),
+        );
+        assert!(!state.commits_to_fee_summary(9).await.unwrap());
+        assert!(state.commits_to_fee_summary(10).await.unwrap());
+        assert!(state.commits_to_fee_summary(11).await.unwrap());
+    }
+
     #[tokio::test]
     async fn block_timestamp() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
@@ -633,6 +927,90 @@ mod test {
         );
     }
 
+    #[tokio::test]
+    async fn fee_summary_separates_distributed_and_burned_fees() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let asset_first = astria_core::primitive::v1::asset::Id::from_str_unchecked("asset_0");
+        let asset_second = astria_core::primitive::v1::asset::Id::from_str_unchecked("asset_1");
+        state
+            .get_and_increase_block_fees(asset_first, 100)
+            .await
+            .unwrap();
+        state
+            .get_and_increase_burned_fees(asset_first, 10)
+            .await
+            .unwrap();
+        state
+            .get_and_increase_burned_fees(asset_second, 20)
+            .await
+            .unwrap();
+        state
+            .get_and_increase_burned_fees(asset_second, 5)
+            .await
+            .unwrap();
+
+        let summary = state.get_fee_summary().await.unwrap();
+        let first = summary.get(&asset_first).unwrap();
+        assert_eq!((100, 10), (first.distributed(), first.burned()));
+        let second = summary.get(&asset_second).unwrap();
+        assert_eq!((0, 25), (second.distributed(), second.burned()));
+
+        state.clear_block_fees().await;
+        state.clear_burned_fees().await;
+        assert!(state.get_fee_summary().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn fee_distribution() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        assert_eq!(state.get_fee_distribution().await.unwrap(), None);
+
+        let distribution =
+            FeeDistribution::try_new(50, 30, 20, Some(crate::address::base_prefixed([1; 20])))
+                .unwrap();
+        state.put_fee_distribution(&distribution);
+        assert_eq!(
+            state.get_fee_distribution().await.unwrap(),
+            Some(distribution)
+        );
+
+        let distribution = FeeDistribution::try_new(100, 0, 0, None).unwrap();
+        state.put_fee_distribution(&distribution);
+        assert_eq!(
+            state.get_fee_distribution().await.unwrap(),
+            Some(distribution)
+        );
+    }
+
+    #[tokio::test]
+    async fn fee_summary_counts_burn_share_as_burned() {
+        let storage = cnidarium::TempStorage::new().await.unwrap();
+        let snapshot = storage.latest_snapshot();
+        let mut state = StateDelta::new(snapshot);
+
+        let asset = astria_core::primitive::v1::asset::Id::from_str_unchecked("asset_0");
+        state
+            .get_and_increase_block_fees(asset, 1001)
+            .await
+            .unwrap();
+        state.get_and_increase_burned_fees(asset, 10).await.unwrap();
+        state.put_fee_distribution(
+            &FeeDistribution::try_new(50, 30, 20, Some(crate::address::base_prefixed([1; 20])))
+                .unwrap(),
+        );
+
+        // the rounding remainder of the proposer and treasury shares is burned
+        let summary = state.get_fee_summary().await.unwrap();
+        let fees = summary.get(&asset).unwrap();
+        assert_eq!((800, 211), (fees.distributed(), fees.burned()));
+    }
+
     #[tokio::test]
     async fn is_allowed_fee_asset() {
         let storage = cnidarium::TempStorage::new().await.unwrap();
@@ -8,18 +8,22 @@ use astria_core::{
     primitive::v1::{
         asset,
         Address,
-        RollupId,
     },
     protocol::transaction::v1alpha1::{
         action::{
             Action,
+            AllowanceChangeAction,
             BridgeLockAction,
+            BridgeRecoveryVetoAction,
+            BridgeSudoChangeAction,
+            TransferFromAction,
         },
         SignedTransaction,
         UnsignedTransaction,
     },
 };
 
+use super::ActionNotActivated;
 use crate::{
     accounts::state_ext::StateReadExt,
     bridge::state_ext::StateReadExt as _,
@@ -61,6 +65,47 @@ pub(crate) async fn check_balance_mempool<S: StateReadExt + 'static>(
     Ok(())
 }
 
+/// Checks that all actions of `tx` are available in the next block, which is the earliest block
+/// `tx` can be included in.
+pub(crate) async fn check_actions_activated_mempool<S: StateReadExt + 'static>(
+    tx: &SignedTransaction,
+    state: &S,
+) -> anyhow::Result<()> {
+    let next_height = state
+        .get_block_height()
+        .await
+        .context("failed to get block height")?
+        .saturating_add(1);
+    for action in &tx.unsigned_transaction().actions {
+        check_action_activated(action, state, next_height).await?;
+    }
+    Ok(())
+}
+
+/// Checks that `action` is available in the block at `height`, i.e. that `height` is not before
+/// the activation height of the action set in the genesis state.
+pub(crate) async fn check_action_activated<S: StateReadExt + 'static>(
+    action: &Action,
+    state: &S,
+    height: u64,
+) -> anyhow::Result<()> {
+    let action = action.name();
+    let activation_height = state
+        .get_action_activation_height(action)
+        .await
+        .context("failed to get action activation height")?;
+    if let Some(activation_height) = activation_height {
+        ensure!(
+            height >= activation_height,
+            ActionNotActivated {
+                action,
+                activation_height,
+            }
+        );
+    }
+    Ok(())
+}
+
 // Checks that the account has enough balance to cover the total fees and transferred values
 // for all actions in the transaction.
 pub(crate) async fn check_balance_for_total_fees<S: StateReadExt + 'static>(
@@ -133,18 +178,38 @@ pub(crate) async fn check_balance_for_total_fees<S: StateReadExt + 'static>(
                 )
                 .await?;
             }
-            Action::BridgeSudoChange(act) => {
+            Action::BridgeSudoChange(BridgeSudoChangeAction {
+                fee_asset_id, ..
+            })
+            | Action::BridgeRecoveryVeto(BridgeRecoveryVetoAction {
+                fee_asset_id, ..
+            }) => {
                 fees_by_asset
-                    .entry(act.fee_asset_id)
+                    .entry(*fee_asset_id)
                     .and_modify(|amt| *amt = amt.saturating_add(bridge_sudo_change_fee))
                     .or_insert(bridge_sudo_change_fee);
             }
+            // the transferred value of a transfer-from is paid by the owner, not the signer
+            Action::AllowanceChange(AllowanceChangeAction {
+                fee_asset_id, ..
+            })
+            | Action::TransferFrom(TransferFromAction {
+                fee_asset_id, ..
+            }) => {
+                fees_by_asset
+                    .entry(*fee_asset_id)
+                    .and_modify(|amt| *amt = amt.saturating_add(transfer_fee))
+                    .or_insert(transfer_fee);
+            }
             Action::ValidatorUpdate(_)
             | Action::SudoAddressChange(_)
             | Action::Ibc(_)
             | Action::IbcRelayerChange(_)
             | Action::FeeAssetChange(_)
-            | Action::FeeChange(_) => {
+            | Action::FeeChange(_)
+            | Action::AddressPrefixChange(_)
+            | Action::BridgeRecovery(_)
+            | Action::FeeDistributionChange(_) => {
                 continue;
             }
         }
@@ -164,6 +229,66 @@ pub(crate) async fn check_balance_for_total_fees<S: StateReadExt + 'static>(
     Ok(())
 }
 
+/// Returns the sum of the fees of all actions in the transaction.
+///
+/// Fees paid in different assets are added up without conversion, and transferred values are not
+/// included.
+pub(crate) async fn total_fees<S: StateReadExt + 'static>(
+    tx: &UnsignedTransaction,
+    state: &S,
+) -> anyhow::Result<u128> {
+    let transfer_fee = state
+        .get_transfer_base_fee()
+        .await
+        .context("failed to get transfer base fee")?;
+    let mut total: u128 = 0;
+    for action in &tx.actions {
+        let fee = match action {
+            Action::Transfer(_)
+            | Action::AllowanceChange(_)
+            | Action::TransferFrom(_)
+            | Action::BridgeUnlock(_) => transfer_fee,
+            Action::Sequence(act) => crate::sequence::calculate_fee_from_state(&act.data, state)
+                .await
+                .context("fee for sequence action overflowed; data too large")?,
+            Action::Ics20Withdrawal(_) => state
+                .get_ics20_withdrawal_base_fee()
+                .await
+                .context("failed to get ics20 withdrawal base fee")?,
+            Action::InitBridgeAccount(_) => state
+                .get_init_bridge_account_base_fee()
+                .await
+                .context("failed to get init bridge account base fee")?,
+            Action::BridgeLock(act) => {
+                let bridge_lock_byte_cost_multiplier = state
+                    .get_bridge_lock_byte_cost_multiplier()
+                    .await
+                    .context("failed to get bridge lock byte cost multiplier")?;
+                transfer_fee.saturating_add(
+                    u128::try_from(act.fee_weight())
+                        .expect("a usize should always convert to a u128")
+                        .saturating_mul(bridge_lock_byte_cost_multiplier),
+                )
+            }
+            Action::BridgeSudoChange(_) | Action::BridgeRecoveryVeto(_) => state
+                .get_bridge_sudo_change_base_fee()
+                .await
+                .context("failed to get bridge sudo change fee")?,
+            Action::ValidatorUpdate(_)
+            | Action::SudoAddressChange(_)
+            | Action::Ibc(_)
+            | Action::IbcRelayerChange(_)
+            | Action::FeeAssetChange(_)
+            | Action::FeeChange(_)
+            | Action::AddressPrefixChange(_)
+            | Action::BridgeRecovery(_)
+            | Action::FeeDistributionChange(_) => 0,
+        };
+        total = total.saturating_add(fee);
+    }
+    Ok(total)
+}
+
 fn transfer_update_fees(
     asset_id: asset::Id,
     fee_asset_id: asset::Id,
@@ -220,18 +345,10 @@ fn bridge_lock_update_fees(
     transfer_fee: u128,
     bridge_lock_byte_cost_multiplier: u128,
 ) {
-    use astria_core::sequencerblock::v1alpha1::block::Deposit;
-
     let expected_deposit_fee = transfer_fee.saturating_add(
-        crate::bridge::get_deposit_byte_len(&Deposit::new(
-            act.to,
-            // rollup ID doesn't matter here, as this is only used as a size-check
-            RollupId::from_unhashed_bytes([0; 32]),
-            act.amount,
-            act.asset_id,
-            act.destination_chain_address.clone(),
-        ))
-        .saturating_mul(bridge_lock_byte_cost_multiplier),
+        u128::try_from(act.fee_weight())
+            .expect("a usize should always convert to a u128")
+            .saturating_mul(bridge_lock_byte_cost_multiplier),
     );
 
     fees_by_asset
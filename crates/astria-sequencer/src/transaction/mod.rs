@@ -1,7 +1,10 @@
 pub(crate) mod action_handler;
 mod checks;
 
-use std::fmt;
+use std::{
+    fmt,
+    time::Instant,
+};
 
 pub(crate) use action_handler::ActionHandler;
 use anyhow::{
@@ -13,14 +16,15 @@ use astria_core::{
     protocol::transaction::v1alpha1::{
         action::Action,
         SignedTransaction,
-        UnsignedTransaction,
     },
 };
 pub(crate) use checks::{
+    check_actions_activated_mempool,
     check_balance_for_total_fees,
     check_balance_mempool,
     check_chain_id_mempool,
     check_nonce_mempool,
+    total_fees,
 };
 use tracing::instrument;
 
@@ -31,54 +35,119 @@ use crate::{
     },
     ibc::{
         host_interface::AstriaHost,
+        router::AppRouter,
         state_ext::StateReadExt as _,
     },
+    metrics::{
+        ActionPhase,
+        BlockActionTotals,
+        Metrics,
+    },
     state_ext::StateReadExt as _,
 };
 
-pub(crate) async fn check_stateless(tx: &SignedTransaction) -> anyhow::Result<()> {
-    tx.unsigned_transaction()
-        .check_stateless()
-        .await
-        .context("stateless check failed")
+pub(crate) async fn check_stateless(
+    tx: &SignedTransaction,
+    metrics: &Metrics,
+) -> anyhow::Result<()> {
+    let actions = &tx.unsigned_transaction().actions;
+    ensure!(!actions.is_empty(), "must have at least one action");
+
+    for action in actions {
+        let start = Instant::now();
+        check_action_stateless(action)
+            .await
+            .context("stateless check failed")?;
+        metrics.record_action(action, ActionPhase::CheckStateless, start.elapsed());
+    }
+    Ok(())
 }
 
 pub(crate) async fn check_stateful<S: StateReadExt + 'static>(
     tx: &SignedTransaction,
     state: &S,
+    metrics: &Metrics,
 ) -> anyhow::Result<()> {
-    let signer_address = crate::address::base_prefixed(tx.verification_key().address_bytes());
-    tx.unsigned_transaction()
-        .check_stateful(state, signer_address)
-        .await
+    let from = crate::address::base_prefixed(tx.verification_key().address_bytes());
+    let unsigned_tx = tx.unsigned_transaction();
+
+    // Transactions must match the chain id of the node.
+    let chain_id = state.get_chain_id().await?;
+    ensure!(
+        unsigned_tx.chain_id() == chain_id.as_str(),
+        InvalidChainId(unsigned_tx.chain_id().to_string())
+    );
+
+    // Nonce should be equal to the number of executed transactions before this tx.
+    // First tx has nonce 0.
+    let curr_nonce = state.get_account_nonce(from).await?;
+    ensure!(
+        curr_nonce == unsigned_tx.nonce(),
+        InvalidNonce(unsigned_tx.nonce())
+    );
+
+    // Should have enough balance to cover all actions.
+    check_balance_for_total_fees(unsigned_tx, from, state).await?;
+
+    for action in &unsigned_tx.actions {
+        let start = Instant::now();
+        check_action_stateful(action, state, from).await?;
+        metrics.record_action(action, ActionPhase::CheckStateful, start.elapsed());
+    }
+
+    Ok(())
 }
 
+/// Executes `tx`, adding the time spent on each of its actions to `action_totals`.
+#[instrument(
+    skip_all,
+    fields(
+        nonce = tx.nonce(),
+        from = %telemetry::display::base64(&tx.address_bytes()),
+    )
+)]
 pub(crate) async fn execute<S: StateWriteExt>(
     tx: &SignedTransaction,
     state: &mut S,
+    metrics: &Metrics,
+    action_totals: &mut BlockActionTotals,
 ) -> anyhow::Result<()> {
     use crate::bridge::state_ext::{
         StateReadExt as _,
         StateWriteExt as _,
     };
 
-    let signer_address = crate::address::base_prefixed(tx.verification_key().address_bytes());
+    let from = crate::address::base_prefixed(tx.verification_key().address_bytes());
 
     if state
-        .get_bridge_account_rollup_id(&signer_address)
+        .get_bridge_account_rollup_id(&from)
         .await
         .context("failed to check account rollup id")?
         .is_some()
     {
-        state.put_last_transaction_hash_for_bridge_account(
-            &signer_address,
-            &tx.sha256_of_proto_encoding(),
-        );
+        state.put_last_transaction_hash_for_bridge_account(&from, &tx.sha256_of_proto_encoding());
     }
 
-    tx.unsigned_transaction()
-        .execute(state, signer_address)
+    let from_nonce = state
+        .get_account_nonce(from)
         .await
+        .context("failed getting `from` nonce")?;
+    let next_nonce = from_nonce
+        .checked_add(1)
+        .context("overflow occurred incrementing stored nonce")?;
+    state
+        .put_account_nonce(from, next_nonce)
+        .context("failed updating `from` nonce")?;
+
+    for action in &tx.unsigned_transaction().actions {
+        let start = Instant::now();
+        execute_action(action, state, from).await?;
+        let elapsed = start.elapsed();
+        metrics.record_action(action, ActionPhase::Execute, elapsed);
+        action_totals.add(action.name(), elapsed);
+    }
+
+    Ok(())
 }
 
 #[derive(Debug)]
@@ -111,253 +180,309 @@ impl fmt::Display for InvalidNonce {
 
 impl std::error::Error for InvalidNonce {}
 
-#[async_trait::async_trait]
-impl ActionHandler for UnsignedTransaction {
-    async fn check_stateless(&self) -> anyhow::Result<()> {
-        ensure!(!self.actions.is_empty(), "must have at least one action");
+/// An action was used before its activation height.
+#[derive(Debug)]
+pub(crate) struct ActionNotActivated {
+    pub(crate) action: &'static str,
+    pub(crate) activation_height: u64,
+}
 
-        for action in &self.actions {
-            match action {
-                Action::Transfer(act) => act
-                    .check_stateless()
-                    .await
-                    .context("stateless check failed for TransferAction")?,
-                Action::Sequence(act) => act
-                    .check_stateless()
-                    .await
-                    .context("stateless check failed for SequenceAction")?,
-                Action::ValidatorUpdate(act) => act
-                    .check_stateless()
-                    .await
-                    .context("stateless check failed for ValidatorUpdateAction")?,
-                Action::SudoAddressChange(act) => act
-                    .check_stateless()
-                    .await
-                    .context("stateless check failed for SudoAddressChangeAction")?,
-                Action::FeeChange(act) => act
-                    .check_stateless()
-                    .await
-                    .context("stateless check failed for FeeChangeAction")?,
-                Action::Ibc(act) => {
-                    let action = act
-                        .clone()
-                        .with_handler::<crate::ibc::ics20_transfer::Ics20Transfer, AstriaHost>();
-                    action
-                        .check_stateless(())
-                        .await
-                        .context("stateless check failed for IbcAction")?;
-                }
-                Action::Ics20Withdrawal(act) => act
-                    .check_stateless()
-                    .await
-                    .context("stateless check failed for Ics20WithdrawalAction")?,
-                Action::IbcRelayerChange(act) => act
-                    .check_stateless()
-                    .await
-                    .context("stateless check failed for IbcRelayerChangeAction")?,
-                Action::FeeAssetChange(act) => act
-                    .check_stateless()
-                    .await
-                    .context("stateless check failed for FeeAssetChangeAction")?,
-                Action::InitBridgeAccount(act) => act
-                    .check_stateless()
-                    .await
-                    .context("stateless check failed for InitBridgeAccountAction")?,
-                Action::BridgeLock(act) => act
-                    .check_stateless()
-                    .await
-                    .context("stateless check failed for BridgeLockAction")?,
-                Action::BridgeUnlock(act) => act
-                    .check_stateless()
-                    .await
-                    .context("stateless check failed for BridgeLockAction")?,
-                Action::BridgeSudoChange(act) => act
-                    .check_stateless()
-                    .await
-                    .context("stateless check failed for BridgeSudoChangeAction")?,
-            }
-        }
-        Ok(())
+impl fmt::Display for ActionNotActivated {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "action `{}` is not available before its activation height {}",
+            self.action, self.activation_height,
+        )
     }
+}
 
-    async fn check_stateful<S: StateReadExt + 'static>(
-        &self,
-        state: &S,
-        from: Address,
-    ) -> anyhow::Result<()> {
-        // Transactions must match the chain id of the node.
-        let chain_id = state.get_chain_id().await?;
-        ensure!(
-            self.chain_id() == chain_id.as_str(),
-            InvalidChainId(self.chain_id().to_string())
-        );
-
-        // Nonce should be equal to the number of executed transactions before this tx.
-        // First tx has nonce 0.
-        let curr_nonce = state.get_account_nonce(from).await?;
-        ensure!(curr_nonce == self.nonce(), InvalidNonce(self.nonce()));
+impl std::error::Error for ActionNotActivated {}
 
-        // Should have enough balance to cover all actions.
-        check_balance_for_total_fees(self, from, state).await?;
+pub(crate) async fn check_action_stateless(action: &Action) -> anyhow::Result<()> {
+    match action {
+        Action::Transfer(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for TransferAction")?,
+        Action::AllowanceChange(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for AllowanceChangeAction")?,
+        Action::TransferFrom(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for TransferFromAction")?,
+        Action::Sequence(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for SequenceAction")?,
+        Action::ValidatorUpdate(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for ValidatorUpdateAction")?,
+        Action::SudoAddressChange(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for SudoAddressChangeAction")?,
+        Action::FeeChange(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for FeeChangeAction")?,
+        Action::Ibc(act) => {
+            let action = act.clone().with_handler::<AppRouter, AstriaHost>();
+            action
+                .check_stateless(())
+                .await
+                .context("stateless check failed for IbcAction")?;
+        }
+        Action::Ics20Withdrawal(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for Ics20WithdrawalAction")?,
+        Action::IbcRelayerChange(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for IbcRelayerChangeAction")?,
+        Action::FeeAssetChange(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for FeeAssetChangeAction")?,
+        Action::AddressPrefixChange(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for AddressPrefixChangeAction")?,
+        Action::InitBridgeAccount(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for InitBridgeAccountAction")?,
+        Action::BridgeLock(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for BridgeLockAction")?,
+        Action::BridgeUnlock(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for BridgeLockAction")?,
+        Action::BridgeSudoChange(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for BridgeSudoChangeAction")?,
+        Action::BridgeRecoveryVeto(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for BridgeRecoveryVetoAction")?,
+        Action::BridgeRecovery(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for BridgeRecoveryAction")?,
+        Action::FeeDistributionChange(act) => act
+            .check_stateless()
+            .await
+            .context("stateless check failed for FeeDistributionChangeAction")?,
+    }
+    Ok(())
+}
 
-        for action in &self.actions {
-            match action {
-                Action::Transfer(act) => act
-                    .check_stateful(state, from)
-                    .await
-                    .context("stateful check failed for TransferAction")?,
-                Action::Sequence(act) => act
-                    .check_stateful(state, from)
-                    .await
-                    .context("stateful check failed for SequenceAction")?,
-                Action::ValidatorUpdate(act) => act
-                    .check_stateful(state, from)
-                    .await
-                    .context("stateful check failed for ValidatorUpdateAction")?,
-                Action::SudoAddressChange(act) => act
-                    .check_stateful(state, from)
-                    .await
-                    .context("stateful check failed for SudoAddressChangeAction")?,
-                Action::FeeChange(act) => act
-                    .check_stateful(state, from)
-                    .await
-                    .context("stateful check failed for FeeChangeAction")?,
-                Action::Ibc(_) => {
-                    ensure!(
-                        state
-                            .is_ibc_relayer(&from)
-                            .await
-                            .context("failed to check if address is IBC relayer")?,
-                        "only IBC sudo address can execute IBC actions"
-                    );
-                }
-                Action::Ics20Withdrawal(act) => act
-                    .check_stateful(state, from)
-                    .await
-                    .context("stateful check failed for Ics20WithdrawalAction")?,
-                Action::IbcRelayerChange(act) => act
-                    .check_stateful(state, from)
-                    .await
-                    .context("stateful check failed for IbcRelayerChangeAction")?,
-                Action::FeeAssetChange(act) => act
-                    .check_stateful(state, from)
-                    .await
-                    .context("stateful check failed for FeeAssetChangeAction")?,
-                Action::InitBridgeAccount(act) => act
-                    .check_stateful(state, from)
-                    .await
-                    .context("stateful check failed for InitBridgeAccountAction")?,
-                Action::BridgeLock(act) => act
-                    .check_stateful(state, from)
-                    .await
-                    .context("stateful check failed for BridgeLockAction")?,
-                Action::BridgeUnlock(act) => act
-                    .check_stateful(state, from)
-                    .await
-                    .context("stateful check failed for BridgeUnlockAction")?,
-                Action::BridgeSudoChange(act) => act
-                    .check_stateful(state, from)
+pub(crate) async fn check_action_stateful<S: StateReadExt + 'static>(
+    action: &Action,
+    state: &S,
+    from: Address,
+) -> anyhow::Result<()> {
+    let height = state
+        .get_block_height()
+        .await
+        .context("failed to get block height")?;
+    checks::check_action_activated(action, state, height).await?;
+    match action {
+        Action::Transfer(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for TransferAction")?,
+        Action::AllowanceChange(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for AllowanceChangeAction")?,
+        Action::TransferFrom(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for TransferFromAction")?,
+        Action::Sequence(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for SequenceAction")?,
+        Action::ValidatorUpdate(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for ValidatorUpdateAction")?,
+        Action::SudoAddressChange(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for SudoAddressChangeAction")?,
+        Action::FeeChange(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for FeeChangeAction")?,
+        Action::Ibc(_) => {
+            ensure!(
+                state
+                    .is_ibc_relayer(&from)
                     .await
-                    .context("stateful check failed for BridgeSudoChangeAction")?,
-            }
+                    .context("failed to check if address is IBC relayer")?,
+                "only IBC sudo address can execute IBC actions"
+            );
         }
-
-        Ok(())
+        Action::Ics20Withdrawal(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for Ics20WithdrawalAction")?,
+        Action::IbcRelayerChange(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for IbcRelayerChangeAction")?,
+        Action::FeeAssetChange(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for FeeAssetChangeAction")?,
+        Action::AddressPrefixChange(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for AddressPrefixChangeAction")?,
+        Action::InitBridgeAccount(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for InitBridgeAccountAction")?,
+        Action::BridgeLock(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for BridgeLockAction")?,
+        Action::BridgeUnlock(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for BridgeUnlockAction")?,
+        Action::BridgeSudoChange(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for BridgeSudoChangeAction")?,
+        Action::BridgeRecoveryVeto(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for BridgeRecoveryVetoAction")?,
+        Action::BridgeRecovery(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for BridgeRecoveryAction")?,
+        Action::FeeDistributionChange(act) => act
+            .check_stateful(state, from)
+            .await
+            .context("stateful check failed for FeeDistributionChangeAction")?,
     }
+    Ok(())
+}
 
-    #[instrument(
-        skip_all,
-        fields(
-            nonce = self.nonce(),
-            from = %from,
-        )
-    )]
-    async fn execute<S: StateWriteExt>(&self, state: &mut S, from: Address) -> anyhow::Result<()> {
-        let from_nonce = state
-            .get_account_nonce(from)
-            .await
-            .context("failed getting `from` nonce")?;
-        let next_nonce = from_nonce
-            .checked_add(1)
-            .context("overflow occurred incrementing stored nonce")?;
-        state
-            .put_account_nonce(from, next_nonce)
-            .context("failed updating `from` nonce")?;
-
-        for action in &self.actions {
-            match action {
-                Action::Transfer(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for TransferAction")?;
-                }
-                Action::Sequence(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for SequenceAction")?;
-                }
-                Action::ValidatorUpdate(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for ValidatorUpdateAction")?;
-                }
-                Action::SudoAddressChange(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for SudoAddressChangeAction")?;
-                }
-                Action::FeeChange(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for FeeChangeAction")?;
-                }
-                Action::Ibc(act) => {
-                    let action = act
-                        .clone()
-                        .with_handler::<crate::ibc::ics20_transfer::Ics20Transfer, AstriaHost>();
-                    action
-                        .check_and_execute(&mut *state)
-                        .await
-                        .context("execution failed for IbcAction")?;
-                }
-                Action::Ics20Withdrawal(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for Ics20WithdrawalAction")?;
-                }
-                Action::IbcRelayerChange(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for IbcRelayerChangeAction")?;
-                }
-                Action::FeeAssetChange(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for FeeAssetChangeAction")?;
-                }
-                Action::InitBridgeAccount(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for InitBridgeAccountAction")?;
-                }
-                Action::BridgeLock(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for BridgeLockAction")?;
-                }
-                Action::BridgeUnlock(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for BridgeUnlockAction")?;
-                }
-                Action::BridgeSudoChange(act) => {
-                    act.execute(state, from)
-                        .await
-                        .context("execution failed for BridgeSudoChangeAction")?;
-                }
-            }
+pub(crate) async fn execute_action<S: StateWriteExt>(
+    action: &Action,
+    state: &mut S,
+    from: Address,
+) -> anyhow::Result<()> {
+    match action {
+        Action::Transfer(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for TransferAction")?;
+        }
+        Action::AllowanceChange(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for AllowanceChangeAction")?;
+        }
+        Action::TransferFrom(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for TransferFromAction")?;
+        }
+        Action::Sequence(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for SequenceAction")?;
+        }
+        Action::ValidatorUpdate(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for ValidatorUpdateAction")?;
+        }
+        Action::SudoAddressChange(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for SudoAddressChangeAction")?;
+        }
+        Action::FeeChange(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for FeeChangeAction")?;
+        }
+        Action::Ibc(act) => {
+            let action = act.clone().with_handler::<AppRouter, AstriaHost>();
+            action
+                .check_and_execute(&mut *state)
+                .await
+                .context("execution failed for IbcAction")?;
+        }
+        Action::Ics20Withdrawal(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for Ics20WithdrawalAction")?;
+        }
+        Action::IbcRelayerChange(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for IbcRelayerChangeAction")?;
+        }
+        Action::FeeAssetChange(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for FeeAssetChangeAction")?;
+        }
+        Action::AddressPrefixChange(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for AddressPrefixChangeAction")?;
+        }
+        Action::InitBridgeAccount(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for InitBridgeAccountAction")?;
+        }
+        Action::BridgeLock(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for BridgeLockAction")?;
+        }
+        Action::BridgeUnlock(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for BridgeUnlockAction")?;
+        }
+        Action::BridgeSudoChange(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for BridgeSudoChangeAction")?;
+        }
+        Action::BridgeRecoveryVeto(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for BridgeRecoveryVetoAction")?;
+        }
+        Action::BridgeRecovery(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for BridgeRecoveryAction")?;
+        }
+        Action::FeeDistributionChange(act) => {
+            act.execute(state, from)
+                .await
+                .context("execution failed for FeeDistributionChangeAction")?;
         }
-
-        Ok(())
     }
+    Ok(())
 }
@@ -0,0 +1,310 @@
+//! Archival of executed transactions to an external sink.
+//!
+//! When configured, the [`TxArchive`] is handed the transactions of every committed block
+//! together with their execution results, and writes them to its sink from a background task.
+//! This lets indexers consume executed transactions without polling CometBFT.
+//!
+//! Blocks are handed over after they were committed. If the sink falls behind by more than
+//! [`QUEUE_CAPACITY`] blocks, further blocks are dropped instead of stalling consensus.
+//!
+//! Only file sinks, given as `file://<path>`, are currently supported. Blocks are appended to the
+//! file as one JSON object per line.
+
+use std::path::PathBuf;
+
+use anyhow::{
+    bail,
+    Context as _,
+};
+use serde::{
+    Deserialize,
+    Serialize,
+};
+use sha2::{
+    Digest as _,
+    Sha256,
+};
+use tendermint::abci::{
+    types::ExecTxResult,
+    Event,
+};
+use tokio::{
+    io::AsyncWriteExt as _,
+    sync::mpsc,
+};
+use tracing::{
+    error,
+    info,
+    warn,
+};
+
+/// The number of committed blocks that can wait to be written to the sink.
+const QUEUE_CAPACITY: usize = 128;
+
+/// A committed block's transactions and their execution results.
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct ArchivedBlock {
+    pub(crate) height: u64,
+    /// The hex-encoded block hash.
+    pub(crate) block_hash: String,
+    /// The block time in RFC 3339 format.
+    pub(crate) time: String,
+    /// The hex-encoded app hash after executing the block.
+    pub(crate) app_hash: String,
+    pub(crate) transactions: Vec<ArchivedTransaction>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct ArchivedTransaction {
+    /// The hex-encoded sha256 hash of the transaction.
+    pub(crate) hash: String,
+    /// The hex-encoded protobuf `SignedTransaction`.
+    pub(crate) transaction: String,
+    /// The result code of the execution; 0 on success.
+    pub(crate) code: u32,
+    pub(crate) log: String,
+    pub(crate) events: Vec<ArchivedEvent>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct ArchivedEvent {
+    pub(crate) kind: String,
+    pub(crate) attributes: Vec<ArchivedEventAttribute>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub(crate) struct ArchivedEventAttribute {
+    pub(crate) key: String,
+    pub(crate) value: String,
+}
+
+impl From<&Event> for ArchivedEvent {
+    fn from(event: &Event) -> Self {
+        Self {
+            kind: event.kind.clone(),
+            attributes: event
+                .attributes
+                .iter()
+                .map(|attribute| ArchivedEventAttribute {
+                    key: attribute.key.clone(),
+                    value: attribute.value.clone(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Pairs the user transactions of a block with their execution results.
+///
+/// `txs` and `tx_results` are those of the whole block, whose leading `commitment_count` block
+/// data commitments are skipped.
+pub(crate) fn archived_transactions<T: AsRef<[u8]>>(
+    txs: &[T],
+    tx_results: &[ExecTxResult],
+    commitment_count: usize,
+) -> Vec<ArchivedTransaction> {
+    txs.iter()
+        .zip(tx_results)
+        .skip(commitment_count)
+        .map(|(tx, result)| ArchivedTransaction {
+            hash: hex::encode(Sha256::digest(tx.as_ref())),
+            transaction: hex::encode(tx.as_ref()),
+            code: result.code.value(),
+            log: result.log.clone(),
+            events: result.events.iter().map(ArchivedEvent::from).collect(),
+        })
+        .collect()
+}
+
+/// The destination of archived blocks.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Sink {
+    File(PathBuf),
+}
+
+impl Sink {
+    pub(crate) fn parse(sink: &str) -> anyhow::Result<Self> {
+        let Some((scheme, target)) = sink.split_once("://") else {
+            bail!("transaction archive sink must be of the form `<scheme>://<target>`");
+        };
+        match scheme {
+            "file" if !target.is_empty() => Ok(Self::File(PathBuf::from(target))),
+            "file" => bail!("transaction archive file sink requires a path"),
+            other => bail!("unsupported transaction archive sink scheme `{other}`"),
+        }
+    }
+}
+
+/// A handle to the background task writing committed blocks to the sink.
+#[derive(Clone)]
+pub(crate) struct TxArchive {
+    queue: mpsc::Sender<ArchivedBlock>,
+}
+
+impl TxArchive {
+    /// Starts the archival task for `sink`, or returns `None` if `sink` is empty.
+    ///
+    /// # Errors
+    /// Returns an error if `sink` is not a supported sink.
+    pub(crate) fn spawn_from_config(sink: &str) -> anyhow::Result<Option<Self>> {
+        if sink.is_empty() {
+            return Ok(None);
+        }
+        let sink = Sink::parse(sink).context("failed to parse transaction archive sink")?;
+        Ok(Some(Self::spawn(sink)))
+    }
+
+    fn spawn(sink: Sink) -> Self {
+        let (queue, rx) = mpsc::channel(QUEUE_CAPACITY);
+        tokio::spawn(run(sink, rx));
+        Self {
+            queue,
+        }
+    }
+
+    /// Queues a committed block for archival without waiting for it to be written.
+    pub(crate) fn send(&self, block: ArchivedBlock) {
+        let height = block.height;
+        if let Err(error) = self.queue.try_send(block) {
+            warn!(
+                height,
+                %error,
+                "failed to queue block for transaction archival; dropping it"
+            );
+        }
+    }
+}
+
+async fn run(sink: Sink, mut rx: mpsc::Receiver<ArchivedBlock>) {
+    let Sink::File(path) = sink;
+    info!(path = %path.display(), "archiving executed transactions to file");
+    let mut file = match tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .await
+    {
+        Ok(file) => file,
+        Err(error) => {
+            error!(
+                path = %path.display(),
+                %error,
+                "failed to open transaction archive file; executed transactions will not be \
+                 archived",
+            );
+            return;
+        }
+    };
+    while let Some(block) = rx.recv().await {
+        let height = block.height;
+        if let Err(error) = write_block(&mut file, &block).await {
+            error!(
+                height,
+                error = AsRef::<dyn std::error::Error>::as_ref(&error),
+                "failed to archive block",
+            );
+        }
+    }
+}
+
+async fn write_block(file: &mut tokio::fs::File, block: &ArchivedBlock) -> anyhow::Result<()> {
+    let mut line = serde_json::to_vec(block).context("failed to serialize block as JSON")?;
+    line.push(b'\n');
+    file.write_all(&line)
+        .await
+        .context("failed to write block to file")?;
+    file.flush().await.context("failed to flush file")
+}
+
+#[cfg(test)]
+mod test {
+    use astria_core::sequencerblock::v1alpha1::{
+        BLOCK_COMMITMENT_COUNT,
+        BLOCK_COMMITMENT_COUNT_WITHOUT_FEE_SUMMARY,
+    };
+    use tendermint::abci::{
+        types::ExecTxResult,
+        Code,
+    };
+
+    use super::*;
+
+    #[test]
+    fn sink_is_parsed() {
+        assert_eq!(
+            Sink::File(PathBuf::from("/tmp/archive.jsonl")),
+            Sink::parse("file:///tmp/archive.jsonl").unwrap()
+        );
+        assert!(Sink::parse("file://").is_err());
+        assert!(Sink::parse("nats://localhost:4222").is_err());
+        assert!(Sink::parse("/tmp/archive.jsonl").is_err());
+    }
+
+    #[test]
+    fn commitments_are_not_archived() {
+        let txs = vec![vec![0u8], vec![1], vec![2], vec![3], vec![4]];
+        let tx_results = vec![
+            ExecTxResult::default(),
+            ExecTxResult::default(),
+            ExecTxResult::default(),
+            ExecTxResult::default(),
+            ExecTxResult {
+                code: Code::from(4),
+                log: "failed".to_string(),
+                ..ExecTxResult::default()
+            },
+        ];
+        let archived = archived_transactions(&txs, &tx_results, BLOCK_COMMITMENT_COUNT);
+        assert_eq!(2, archived.len());
+        assert_eq!("03", archived[0].transaction);
+        assert_eq!(0, archived[0].code);
+        assert_eq!(hex::encode(Sha256::digest([4u8])), archived[1].hash);
+        assert_eq!(4, archived[1].code);
+        assert_eq!("failed", archived[1].log);
+
+        let archived = archived_transactions(
+            &txs,
+            &tx_results,
+            BLOCK_COMMITMENT_COUNT_WITHOUT_FEE_SUMMARY,
+        );
+        assert_eq!(3, archived.len());
+        assert_eq!("02", archived[0].transaction);
+    }
+
+    #[tokio::test]
+    async fn blocks_are_appended_to_file_sink() {
+        let path = std::env::temp_dir().join(format!(
+            "astria-sequencer-tx-archive-{}.jsonl",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+
+        let archive = TxArchive::spawn(Sink::File(path.clone()));
+        for height in 1..=2 {
+            archive.send(ArchivedBlock {
+                height,
+                block_hash: "00".to_string(),
+                time: "2024-01-01T00:00:00Z".to_string(),
+                app_hash: "11".to_string(),
+                transactions: vec![],
+            });
+        }
+        drop(archive);
+
+        let mut lines = vec![];
+        for _ in 0..100 {
+            let contents = std::fs::read_to_string(&path).unwrap_or_default();
+            lines = contents.lines().map(ToString::to_string).collect();
+            if lines.len() == 2 {
+                break;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(2, lines.len());
+        let first: serde_json::Value = serde_json::from_str(&lines[0]).unwrap();
+        assert_eq!(1, first["height"]);
+        assert_eq!("11", first["app_hash"]);
+    }
+}
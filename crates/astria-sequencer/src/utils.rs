@@ -1,3 +1,5 @@
+use tracing::warn;
+
 pub(crate) struct Hex<'a>(pub(crate) &'a [u8]);
 
 impl<'a> std::fmt::Display for Hex<'a> {
@@ -8,3 +10,44 @@ impl<'a> std::fmt::Display for Hex<'a> {
         Ok(())
     }
 }
+
+/// Reads the resident set size of this process from `/proc/self/status`.
+///
+/// Returns `None` on platforms without procfs, in which case memory thresholds have no effect.
+pub(crate) fn read_resident_memory_bytes() -> Option<u64> {
+    let status = match std::fs::read_to_string("/proc/self/status") {
+        Ok(status) => status,
+        Err(error) => {
+            warn!(%error, "failed to read process status; cannot check resident memory");
+            return None;
+        }
+    };
+    parse_resident_memory_bytes(&status)
+}
+
+fn parse_resident_memory_bytes(status: &str) -> Option<u64> {
+    let kib = status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .strip_suffix("kB")?
+        .trim()
+        .parse::<u64>()
+        .ok()?;
+    Some(kib.saturating_mul(1024))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resident_memory_is_parsed_from_status() {
+        let status = "Name:\tastria-sequencer\nVmPeak:\t  20000 kB\nVmRSS:\t   1234 kB\n";
+        assert_eq!(Some(1234 * 1024), parse_resident_memory_bytes(status));
+        assert_eq!(
+            None,
+            parse_resident_memory_bytes("Name:\tastria-sequencer\n")
+        );
+    }
+}
@@ -0,0 +1,166 @@
+//! Changing the tracing filter directives of a running service.
+
+use std::sync::{
+    Arc,
+    Mutex,
+};
+
+use tracing_subscriber::{
+    filter::LevelFilter,
+    reload,
+    EnvFilter,
+};
+
+use crate::Error;
+
+type ReloadFn = dyn Fn(EnvFilter) -> Result<(), reload::Error> + Send + Sync;
+
+/// A handle to change the filter directives of the tracing subscriber installed by
+/// [`crate::Config::try_init`] at runtime.
+///
+/// New directives are validated before they are applied. If they cannot be parsed or the
+/// subscriber cannot be updated, the directives in effect before the call remain active.
+#[derive(Clone)]
+pub struct FilterHandle {
+    reload: Arc<ReloadFn>,
+    state: Arc<Mutex<Directives>>,
+}
+
+struct Directives {
+    initial: String,
+    current: String,
+}
+
+impl FilterHandle {
+    /// Returns a reloadable layer filtering with `directives`, and a handle to change them.
+    pub(crate) fn new<S>(directives: String) -> Result<(reload::Layer<EnvFilter, S>, Self), Error>
+    where
+        S: 'static,
+    {
+        let (layer, handle) = reload::Layer::new(parse(&directives)?);
+        let this = Self {
+            reload: Arc::new(move |filter| handle.reload(filter)),
+            state: Arc::new(Mutex::new(Directives {
+                initial: directives.clone(),
+                current: directives,
+            })),
+        };
+        Ok((layer, this))
+    }
+
+    /// Returns the filter directives currently in effect.
+    ///
+    /// # Panics
+    /// Panics if the lock guarding the directives was poisoned.
+    #[must_use]
+    pub fn directives(&self) -> String {
+        self.state
+            .lock()
+            .expect("lock is never poisoned as it is not held across panics")
+            .current
+            .clone()
+    }
+
+    /// Replaces the filter directives with `directives`, returning the directives they replaced.
+    ///
+    /// # Errors
+    /// Returns an error if `directives` could not be parsed, or if the tracing subscriber has
+    /// been dropped. The previous directives remain in effect in both cases.
+    ///
+    /// # Panics
+    /// Panics if the lock guarding the directives was poisoned.
+    pub fn set_directives(&self, directives: &str) -> Result<String, Error> {
+        let filter = parse(directives)?;
+        let mut state = self
+            .state
+            .lock()
+            .expect("lock is never poisoned as it is not held across panics");
+        (self.reload)(filter).map_err(Error::reload_filter)?;
+        Ok(std::mem::replace(
+            &mut state.current,
+            directives.to_string(),
+        ))
+    }
+
+    /// Restores the filter directives that telemetry was initialized with, returning the
+    /// directives they replaced.
+    ///
+    /// # Errors
+    /// Returns an error if the tracing subscriber has been dropped.
+    ///
+    /// # Panics
+    /// Panics if the lock guarding the directives was poisoned.
+    pub fn reset(&self) -> Result<String, Error> {
+        let initial = self
+            .state
+            .lock()
+            .expect("lock is never poisoned as it is not held across panics")
+            .initial
+            .clone();
+        self.set_directives(&initial)
+    }
+}
+
+impl std::fmt::Debug for FilterHandle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FilterHandle")
+            .field("directives", &self.directives())
+            .finish_non_exhaustive()
+    }
+}
+
+fn parse(directives: &str) -> Result<EnvFilter, Error> {
+    EnvFilter::builder()
+        .with_default_directive(LevelFilter::INFO.into())
+        .parse(directives)
+        .map_err(Error::filter_directives)
+}
+
+#[cfg(test)]
+mod tests {
+    use tracing_subscriber::{
+        layer::SubscriberExt as _,
+        Registry,
+    };
+
+    use super::FilterHandle;
+
+    #[test]
+    fn directives_are_changed_and_reset() {
+        let (layer, handle) = FilterHandle::new::<Registry>("info".to_string()).unwrap();
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            assert!(!tracing::enabled!(tracing::Level::DEBUG));
+
+            let previous = handle.set_directives("debug").unwrap();
+            assert_eq!(previous, "info");
+            assert_eq!(handle.directives(), "debug");
+            assert!(tracing::enabled!(tracing::Level::DEBUG));
+
+            let previous = handle.reset().unwrap();
+            assert_eq!(previous, "debug");
+            assert_eq!(handle.directives(), "info");
+            assert!(!tracing::enabled!(tracing::Level::DEBUG));
+        });
+    }
+
+    #[test]
+    fn invalid_directives_keep_previous_filter() {
+        let (layer, handle) = FilterHandle::new::<Registry>("info".to_string()).unwrap();
+        let subscriber = tracing_subscriber::registry().with(layer);
+        tracing::subscriber::with_default(subscriber, || {
+            assert!(handle.set_directives("astria=notalevel").is_err());
+            assert_eq!(handle.directives(), "info");
+            assert!(tracing::enabled!(tracing::Level::INFO));
+            assert!(!tracing::enabled!(tracing::Level::DEBUG));
+        });
+    }
+
+    #[test]
+    fn dropped_subscriber_is_reported() {
+        let (layer, handle) = FilterHandle::new::<Registry>("info".to_string()).unwrap();
+        drop(layer);
+        assert!(handle.set_directives("debug").is_err());
+        assert_eq!(handle.directives(), "info");
+    }
+}
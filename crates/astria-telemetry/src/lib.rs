@@ -8,6 +8,19 @@
 //!     .expect("must be able to initialize telemetry");
 //! tracing::info!("telemetry initialized");
 //! ```
+//!
+//! The filter directives can be changed while the service is running through the
+//! [`FilterHandle`] returned by [`Guard::filter_handle`]:
+//! ```no_run
+//! let guard = astria_telemetry::configure()
+//!     .filter_directives("info")
+//!     .try_init()
+//!     .expect("must be able to initialize telemetry");
+//! guard
+//!     .filter_handle()
+//!     .set_directives("info,astria_sequencer=debug")
+//!     .expect("directives are valid");
+//! ```
 use std::{
     io::IsTerminal as _,
     net::{
@@ -26,27 +39,34 @@ use opentelemetry::{
 };
 use opentelemetry_sdk::{
     runtime::Tokio,
-    trace::TracerProvider,
+    trace::{
+        self,
+        TracerProvider,
+    },
 };
 use opentelemetry_stdout::SpanExporter;
 use tracing_subscriber::{
-    filter::{
-        LevelFilter,
-        ParseError,
-    },
+    filter::ParseError,
     layer::SubscriberExt as _,
+    reload,
     util::{
         SubscriberInitExt as _,
         TryInitError,
     },
-    EnvFilter,
 };
 
 #[cfg(feature = "display")]
 pub mod display;
 
+mod filter;
+pub use filter::FilterHandle;
+
+mod sampler;
+pub use sampler::Sampler;
+
 #[doc(hidden)]
 pub mod macros;
+pub mod metrics;
 
 /// The errors that can occur when initializing telemetry.
 #[derive(Debug, thiserror::Error)]
@@ -62,6 +82,10 @@ impl Error {
         Self(ErrorKind::FilterDirectives(source))
     }
 
+    fn reload_filter(source: reload::Error) -> Self {
+        Self(ErrorKind::ReloadFilter(source))
+    }
+
     fn init_subscriber(source: TryInitError) -> Self {
         Self(ErrorKind::InitSubscriber(source))
     }
@@ -81,6 +105,8 @@ enum ErrorKind {
     Otlp(#[source] opentelemetry::trace::TraceError),
     #[error("failed to parse filter directives")]
     FilterDirectives(#[source] ParseError),
+    #[error("failed reloading filter directives")]
+    ReloadFilter(#[source] reload::Error),
     #[error("failed installing global tracing subscriber")]
     InitSubscriber(#[source] TryInitError),
     #[error("failed to parse metrics address")]
@@ -132,6 +158,7 @@ pub struct Config {
     stdout_writer: BoxedMakeWriter,
     metrics_addr: Option<String>,
     service_name: String,
+    sampler: Option<Sampler>,
 }
 
 impl Config {
@@ -145,6 +172,7 @@ impl Config {
             stdout_writer: BoxedMakeWriter::new(std::io::stdout),
             metrics_addr: None,
             service_name: String::new(),
+            sampler: None,
         }
     }
 }
@@ -224,6 +252,18 @@ impl Config {
         }
     }
 
+    /// Sets the sampler deciding which traces are exported.
+    ///
+    /// If not set, spans follow the sampling decision of their parent and all root spans are
+    /// sampled.
+    #[must_use = "telemetry must be initialized to be useful"]
+    pub fn sampler(self, sampler: Sampler) -> Self {
+        Self {
+            sampler: Some(sampler),
+            ..self
+        }
+    }
+
     /// Initialize telemetry, consuming the config.
     ///
     /// # Errors
@@ -238,16 +278,15 @@ impl Config {
             stdout_writer,
             metrics_addr,
             service_name,
+            sampler,
         } = self;
 
-        let env_filter = {
-            let builder = EnvFilter::builder().with_default_directive(LevelFilter::INFO.into());
-            builder
-                .parse(filter_directives)
-                .map_err(Error::filter_directives)?
-        };
+        let (env_filter, filter_handle) = FilterHandle::new(filter_directives)?;
 
         let mut tracer_provider = TracerProvider::builder();
+        if let Some(sampler) = sampler {
+            tracer_provider = tracer_provider.with_config(trace::config().with_sampler(sampler));
+        }
         if !no_otel {
             // XXX: the endpoint is set by a hardcoded environment variable. This is a
             //      full list of variables that opentelemetry_otlp currently reads:
@@ -307,6 +346,7 @@ impl Config {
 
         Ok(Guard {
             run_otel_shutdown: !no_otel,
+            filter_handle,
         })
     }
 }
@@ -317,6 +357,15 @@ impl Config {
 /// indefinitely.
 pub struct Guard {
     run_otel_shutdown: bool,
+    filter_handle: FilterHandle,
+}
+
+impl Guard {
+    /// Returns a handle to change the filter directives at runtime.
+    #[must_use]
+    pub fn filter_handle(&self) -> FilterHandle {
+        self.filter_handle.clone()
+    }
 }
 
 impl Drop for Guard {
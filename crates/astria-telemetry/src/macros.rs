@@ -33,18 +33,36 @@ pub use const_format::{
 /// assert_eq!(EXAMPLE_GAUGE, "astria_telemetry_example_gauge");
 /// assert_eq!(ALL_METRICS, [EXAMPLE_COUNTER, EXAMPLE_GAUGE]);
 /// ```
+///
+/// Metrics of a subsystem can be grouped under a scope by naming it after the collection. The
+/// scope is inserted between the crate name and the metric name:
+/// ```
+/// use astria_telemetry::metric_names;
+/// metric_names!(pub const EXECUTOR_METRICS in executor: NONCE_FETCH_COUNT, CURRENT_NONCE);
+/// assert_eq!(NONCE_FETCH_COUNT, "astria_telemetry_executor_nonce_fetch_count");
+/// assert_eq!(CURRENT_NONCE, "astria_telemetry_executor_current_nonce");
+/// assert_eq!(EXECUTOR_METRICS, [NONCE_FETCH_COUNT, CURRENT_NONCE]);
+/// ```
 #[macro_export]
 macro_rules! metric_names {
     ($vis:vis const $collection_name:ident: $($name:ident),* $(,)?) => {
         $(
             $crate::__metric_name_internal!($vis[$name][::core::stringify!($name)]);
         )*
-        $vis const $collection_name: [&str; $crate::__count!($($name)*)] = [
-            $(
-                $name,
-            )*
-        ];
-    }
+        $crate::__metric_collection_internal!($vis $collection_name: $($name),*);
+    };
+    ($vis:vis const $collection_name:ident in $scope:ident: $($name:ident),* $(,)?) => {
+        $(
+            $crate::__metric_name_internal!(
+                $vis[$name][$crate::macros::__concatcp!(
+                    ::core::stringify!($scope),
+                    "_",
+                    ::core::stringify!($name),
+                )]
+            );
+        )*
+        $crate::__metric_collection_internal!($vis $collection_name: $($name),*);
+    };
 }
 
 #[doc(hidden)]
@@ -59,6 +77,18 @@ macro_rules! __metric_name_internal {
     };
 }
 
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __metric_collection_internal {
+    ($vis:vis $collection_name:ident: $($name:ident),*) => {
+        $vis const $collection_name: [&str; $crate::__count!($($name)*)] = [
+            $(
+                $name,
+            )*
+        ];
+    };
+}
+
 #[doc(hidden)]
 #[macro_export]
 macro_rules! __count {
@@ -101,4 +131,12 @@ mod tests {
         assert_eq!("astria_telemetry_a", A);
         assert_eq!(TRAILING_COMMA, [A]);
     }
+
+    #[test]
+    fn scope_is_inserted_between_crate_and_metric_name() {
+        metric_names!(const SCOPED_METRICS in mempool: REMOVED_COUNT, SIZE,);
+        assert_eq!("astria_telemetry_mempool_removed_count", REMOVED_COUNT);
+        assert_eq!("astria_telemetry_mempool_size", SIZE);
+        assert_eq!(SCOPED_METRICS, [REMOVED_COUNT, SIZE]);
+    }
 }
@@ -0,0 +1,163 @@
+//! Scoped registration of metrics with common labels.
+//!
+//! Metric names are declared per subsystem with [`crate::metric_names`], which checks at compile
+//! time that each name matches its constant. A [`Scope`] then registers these metrics with the
+//! labels shared by all metrics of a subsystem instance, such as the rollup it serves, so that
+//! they are not repeated at every registration.
+//!
+//! # Examples
+//! ```
+//! use astria_telemetry::{
+//!     metric_names,
+//!     metrics::{
+//!         labels,
+//!         Scope,
+//!     },
+//! };
+//! metric_names!(const COLLECTOR_METRICS in collectors: TRANSACTIONS_RECEIVED);
+//!
+//! let rollup = Scope::new()
+//!     .with_label(labels::ROLLUP_CHAIN_NAME, "astria")
+//!     .with_label(labels::ROLLUP_ID, "<rollup id>");
+//! let geth_transactions_received =
+//!     rollup.counter_with(TRANSACTIONS_RECEIVED, &[(labels::COLLECTOR_TYPE, "geth")]);
+//! geth_transactions_received.increment(1);
+//! ```
+
+use ::metrics::{
+    counter,
+    gauge,
+    histogram,
+    Counter,
+    Gauge,
+    Histogram,
+    Label,
+};
+
+/// The label keys shared across services.
+///
+/// Using these instead of crate-local strings ensures that metrics of different services can be
+/// joined on the same labels.
+pub mod labels {
+    /// The action of a sequencer transaction, e.g. `transfer`.
+    pub const ACTION: &str = "action";
+    /// The kind of collector a rollup transaction was received from, e.g. `geth` or `grpc`.
+    pub const COLLECTOR_TYPE: &str = "collector_type";
+    /// The Celestia namespace type of a blob, i.e. `metadata` or `rollup_data`.
+    pub const NAMESPACE_TYPE: &str = "namespace_type";
+    /// The phase of block execution, e.g. `prepare_proposal`.
+    pub const PHASE: &str = "phase";
+    /// The human readable name of a rollup chain.
+    pub const ROLLUP_CHAIN_NAME: &str = "rollup_chain_name";
+    /// The base64 encoded ID of a rollup.
+    pub const ROLLUP_ID: &str = "rollup_id";
+}
+
+/// A set of labels attached to every metric registered through it.
+#[derive(Clone, Debug, Default)]
+pub struct Scope {
+    labels: Vec<Label>,
+}
+
+impl Scope {
+    /// Returns a scope without labels.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the label `key` with `value` to all metrics registered through this scope.
+    #[must_use]
+    pub fn with_label(mut self, key: &'static str, value: impl Into<String>) -> Self {
+        self.labels.push(Label::new(key, value.into()));
+        self
+    }
+
+    /// Registers the counter `name` with this scope's labels.
+    #[must_use]
+    pub fn counter(&self, name: &'static str) -> Counter {
+        counter!(name, &self.labels)
+    }
+
+    /// Registers the counter `name` with this scope's labels and `extra_labels`.
+    #[must_use]
+    pub fn counter_with(
+        &self,
+        name: &'static str,
+        extra_labels: &[(&'static str, &'static str)],
+    ) -> Counter {
+        counter!(name, &self.labels_with(extra_labels))
+    }
+
+    /// Registers the gauge `name` with this scope's labels.
+    #[must_use]
+    pub fn gauge(&self, name: &'static str) -> Gauge {
+        gauge!(name, &self.labels)
+    }
+
+    /// Registers the gauge `name` with this scope's labels and `extra_labels`.
+    #[must_use]
+    pub fn gauge_with(
+        &self,
+        name: &'static str,
+        extra_labels: &[(&'static str, &'static str)],
+    ) -> Gauge {
+        gauge!(name, &self.labels_with(extra_labels))
+    }
+
+    /// Registers the histogram `name` with this scope's labels.
+    #[must_use]
+    pub fn histogram(&self, name: &'static str) -> Histogram {
+        histogram!(name, &self.labels)
+    }
+
+    /// Registers the histogram `name` with this scope's labels and `extra_labels`.
+    #[must_use]
+    pub fn histogram_with(
+        &self,
+        name: &'static str,
+        extra_labels: &[(&'static str, &'static str)],
+    ) -> Histogram {
+        histogram!(name, &self.labels_with(extra_labels))
+    }
+
+    fn labels_with(&self, extra_labels: &[(&'static str, &'static str)]) -> Vec<Label> {
+        self.labels
+            .iter()
+            .cloned()
+            .chain(
+                extra_labels
+                    .iter()
+                    .map(|(key, value)| Label::from_static_parts(key, value)),
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        labels,
+        Scope,
+    };
+
+    #[test]
+    fn extra_labels_are_appended_to_scope_labels() {
+        let scope = Scope::new()
+            .with_label(labels::ROLLUP_CHAIN_NAME, "astria")
+            .with_label(labels::ROLLUP_ID, "id");
+        let labels = scope.labels_with(&[(labels::COLLECTOR_TYPE, "geth")]);
+        let labels: Vec<_> = labels
+            .iter()
+            .map(|label| (label.key(), label.value()))
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                ("rollup_chain_name", "astria"),
+                ("rollup_id", "id"),
+                ("collector_type", "geth"),
+            ]
+        );
+    }
+}
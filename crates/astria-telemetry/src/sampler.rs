@@ -0,0 +1,305 @@
+//! Strategies for sampling the traces exported over OTLP.
+
+use std::{
+    sync::{
+        Arc,
+        Mutex,
+    },
+    time::Instant,
+};
+
+use opentelemetry::{
+    trace::{
+        Link,
+        SamplingDecision,
+        SamplingResult,
+        SpanKind,
+        TraceContextExt as _,
+        TraceId,
+    },
+    Context,
+    KeyValue,
+};
+use opentelemetry_sdk::trace::{
+    Sampler as SdkSampler,
+    ShouldSample,
+};
+
+/// The span attribute holding the module path of a span, which is also the default target of
+/// spans created with `tracing`. Set by `tracing-opentelemetry`.
+const TARGET_ATTRIBUTE: &str = "code.namespace";
+
+/// Decides which traces are recorded and exported.
+///
+/// Note that with `tracing` only root spans are passed to the sampler: child spans always follow
+/// the decision made for their root.
+///
+/// # Examples
+/// Sample 10% of all traces, except those started in the mempool, of which 1% are sampled:
+/// ```
+/// use astria_telemetry::Sampler;
+/// let sampler = Sampler::parent_based(Sampler::ratio(0.1))
+///     .with_target_override("astria_sequencer::mempool", Sampler::ratio(0.01));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sampler(Kind);
+
+#[derive(Clone, Debug)]
+enum Kind {
+    AlwaysOn,
+    AlwaysOff,
+    Ratio(SdkSampler),
+    RateLimited(RateLimiter),
+    ParentBased {
+        root: Box<Kind>,
+        target_overrides: Vec<(String, Kind)>,
+    },
+}
+
+impl Sampler {
+    /// Samples every trace.
+    #[must_use]
+    pub fn always_on() -> Self {
+        Self(Kind::AlwaysOn)
+    }
+
+    /// Samples no trace.
+    #[must_use]
+    pub fn always_off() -> Self {
+        Self(Kind::AlwaysOff)
+    }
+
+    /// Samples the given `ratio` of traces, based on their trace ID.
+    ///
+    /// Ratios of 1 or more sample all traces, ratios of 0 or less sample none.
+    #[must_use]
+    pub fn ratio(ratio: f64) -> Self {
+        Self(Kind::Ratio(SdkSampler::TraceIdRatioBased(ratio)))
+    }
+
+    /// Samples at most `traces_per_second` traces per second, allowing bursts of up to one
+    /// second's worth of traces.
+    #[must_use]
+    pub fn rate_limited(traces_per_second: f64) -> Self {
+        Self(Kind::RateLimited(RateLimiter::new(
+            traces_per_second,
+            Instant::now(),
+        )))
+    }
+
+    /// Follows the sampling decision of a span's parent, and samples spans without a parent
+    /// using `root`.
+    ///
+    /// Overrides for specific targets can be added with [`Sampler::with_target_override`].
+    #[must_use]
+    pub fn parent_based(root: Self) -> Self {
+        Self(Kind::ParentBased {
+            root: Box::new(root.0),
+            target_overrides: Vec::new(),
+        })
+    }
+
+    /// Samples spans without a parent whose target starts with `target` using `sampler` instead
+    /// of the root sampler.
+    ///
+    /// If several overrides match a span, the one with the longest target is used. Has no effect
+    /// unless this sampler was constructed with [`Sampler::parent_based`].
+    #[must_use]
+    pub fn with_target_override(mut self, target: &str, sampler: Self) -> Self {
+        if let Kind::ParentBased {
+            target_overrides, ..
+        } = &mut self.0
+        {
+            target_overrides.push((target.to_string(), sampler.0));
+            target_overrides.sort_by_key(|(target, _)| std::cmp::Reverse(target.len()));
+        }
+        self
+    }
+}
+
+impl ShouldSample for Sampler {
+    fn should_sample(
+        &self,
+        parent_context: Option<&Context>,
+        trace_id: TraceId,
+        name: &str,
+        span_kind: &SpanKind,
+        attributes: &[KeyValue],
+        links: &[Link],
+    ) -> SamplingResult {
+        let decision = self
+            .0
+            .decide(parent_context, trace_id, name, span_kind, attributes, links);
+        SamplingResult {
+            decision,
+            attributes: Vec::new(),
+            trace_state: parent_context
+                .map(|cx| cx.span().span_context().trace_state().clone())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+impl Kind {
+    fn decide(
+        &self,
+        parent_context: Option<&Context>,
+        trace_id: TraceId,
+        name: &str,
+        span_kind: &SpanKind,
+        attributes: &[KeyValue],
+        links: &[Link],
+    ) -> SamplingDecision {
+        match self {
+            Self::AlwaysOn => SamplingDecision::RecordAndSample,
+            Self::AlwaysOff => SamplingDecision::Drop,
+            Self::Ratio(sampler) => {
+                sampler
+                    .should_sample(parent_context, trace_id, name, span_kind, attributes, links)
+                    .decision
+            }
+            Self::RateLimited(limiter) => {
+                if limiter.try_acquire(Instant::now()) {
+                    SamplingDecision::RecordAndSample
+                } else {
+                    SamplingDecision::Drop
+                }
+            }
+            Self::ParentBased {
+                root,
+                target_overrides,
+            } => {
+                if let Some(parent) = parent_context.filter(|cx| cx.has_active_span()) {
+                    return if parent.span().span_context().is_sampled() {
+                        SamplingDecision::RecordAndSample
+                    } else {
+                        SamplingDecision::Drop
+                    };
+                }
+                let target = attributes
+                    .iter()
+                    .find(|attribute| attribute.key.as_str() == TARGET_ATTRIBUTE)
+                    .map(|attribute| attribute.value.as_str());
+                let sampler = target
+                    .and_then(|target| {
+                        target_overrides
+                            .iter()
+                            .find(|(prefix, _)| target.starts_with(prefix.as_str()))
+                    })
+                    .map_or(&**root, |(_, sampler)| sampler);
+                sampler.decide(parent_context, trace_id, name, span_kind, attributes, links)
+            }
+        }
+    }
+}
+
+/// A token bucket refilled at `rate` tokens per second, holding at most `rate` tokens.
+#[derive(Clone, Debug)]
+struct RateLimiter {
+    rate: f64,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    fn new(rate: f64, now: Instant) -> Self {
+        let rate = rate.max(0.0);
+        Self {
+            rate,
+            bucket: Arc::new(Mutex::new(Bucket {
+                tokens: rate,
+                last_refill: now,
+            })),
+        }
+    }
+
+    fn try_acquire(&self, now: Instant) -> bool {
+        let mut bucket = self
+            .bucket
+            .lock()
+            .expect("lock is never poisoned as it is not held across panics");
+        let elapsed = now.saturating_duration_since(bucket.last_refill);
+        bucket.tokens = elapsed
+            .as_secs_f64()
+            .mul_add(self.rate, bucket.tokens)
+            .min(self.rate);
+        bucket.last_refill = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn decide(sampler: &Sampler, target: &str) -> SamplingDecision {
+        sampler
+            .should_sample(
+                None,
+                TraceId::from_u128(1),
+                "span",
+                &SpanKind::Internal,
+                &[KeyValue::new(TARGET_ATTRIBUTE, target.to_string())],
+                &[],
+            )
+            .decision
+    }
+
+    #[test]
+    fn rate_limiter_refills_over_time() {
+        let start = Instant::now();
+        let limiter = RateLimiter::new(2.0, start);
+        assert!(limiter.try_acquire(start));
+        assert!(limiter.try_acquire(start));
+        assert!(!limiter.try_acquire(start));
+        assert!(!limiter.try_acquire(start + Duration::from_millis(250)));
+        assert!(limiter.try_acquire(start + Duration::from_millis(500)));
+        // the bucket never holds more than one second's worth of tokens
+        let later = start + Duration::from_secs(10);
+        assert!(limiter.try_acquire(later));
+        assert!(limiter.try_acquire(later));
+        assert!(!limiter.try_acquire(later));
+    }
+
+    #[test]
+    fn longest_matching_target_override_is_used() {
+        let sampler = Sampler::parent_based(Sampler::always_on())
+            .with_target_override("astria_sequencer", Sampler::always_on())
+            .with_target_override("astria_sequencer::mempool", Sampler::always_off());
+        assert_eq!(
+            decide(&sampler, "astria_sequencer::mempool::transactions"),
+            SamplingDecision::Drop,
+        );
+        assert_eq!(
+            decide(&sampler, "astria_sequencer::app"),
+            SamplingDecision::RecordAndSample,
+        );
+        assert_eq!(
+            decide(&sampler, "astria_composer"),
+            SamplingDecision::RecordAndSample,
+        );
+    }
+
+    #[test]
+    fn root_sampler_is_used_without_matching_override() {
+        let sampler = Sampler::parent_based(Sampler::always_off())
+            .with_target_override("astria_composer", Sampler::always_on());
+        assert_eq!(decide(&sampler, "astria_sequencer"), SamplingDecision::Drop);
+        assert_eq!(
+            decide(&sampler, "astria_composer::executor"),
+            SamplingDecision::RecordAndSample,
+        );
+    }
+}
@@ -112,6 +112,8 @@ fn main() {
         .build(&[
             ".astria.execution.v1alpha2",
             ".astria.primitive.v1",
+            ".astria.protocol.transactions.v1alpha1",
+            ".astria.protocol.transactions.v2",
             ".astria.sequencerblock.v1alpha1",
             ".celestia",
             ".cosmos",